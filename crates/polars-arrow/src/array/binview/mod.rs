@@ -3,6 +3,7 @@ mod ffi;
 pub(super) mod fmt;
 mod iterator;
 mod mutable;
+mod pool;
 mod view;
 
 use std::any::Any;
@@ -27,6 +28,10 @@ mod private {
 pub use iterator::BinaryViewValueIter;
 pub use mutable::MutableBinaryViewArray;
 use polars_utils::slice::GetSaferUnchecked;
+pub use pool::{
+    disable_string_pool, enable_string_pool, intern_in_string_pool, release_string_pool,
+    set_string_pool_capacity, string_pool_stats, using_string_pool, StringPoolStats,
+};
 use private::Sealed;
 
 use crate::array::binview::view::{validate_binary_view, validate_utf8_only, validate_utf8_view};
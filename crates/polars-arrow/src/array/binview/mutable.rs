@@ -7,7 +7,9 @@ use polars_utils::slice::GetSaferUnchecked;
 
 use crate::array::binview::iterator::MutableBinaryViewValueIter;
 use crate::array::binview::view::validate_utf8_only;
-use crate::array::binview::{BinaryViewArrayGeneric, ViewType};
+use crate::array::binview::{
+    intern_in_string_pool, using_string_pool, BinaryViewArrayGeneric, ViewType,
+};
 use crate::array::{Array, MutableArray, TryExtend, TryPush, View};
 use crate::bitmap::MutableBitmap;
 use crate::buffer::Buffer;
@@ -59,7 +61,7 @@ impl<T: ViewType + ?Sized> Default for MutableBinaryViewArray<T> {
 impl<T: ViewType + ?Sized> From<MutableBinaryViewArray<T>> for BinaryViewArrayGeneric<T> {
     fn from(mut value: MutableBinaryViewArray<T>) -> Self {
         value.finish_in_progress();
-        unsafe {
+        let arr = unsafe {
             Self::new_unchecked(
                 T::DATA_TYPE,
                 value.views.into(),
@@ -68,6 +70,11 @@ impl<T: ViewType + ?Sized> From<MutableBinaryViewArray<T>> for BinaryViewArrayGe
                 value.total_bytes_len,
                 value.total_buffer_len,
             )
+        };
+        if T::IS_UTF8 && arr.total_buffer_len() > 0 && using_string_pool() {
+            intern_in_string_pool(&arr)
+        } else {
+            arr
         }
     }
 }
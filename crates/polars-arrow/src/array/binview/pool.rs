@@ -0,0 +1,346 @@
+//! A process-level pool of data buffers that `Utf8View` arrays can share.
+//!
+//! When the same (long) strings are loaded over and over, e.g. a symbol column that is read
+//! per day, every array owns a copy of those bytes. With the pool enabled, the data of newly
+//! built `Utf8View` arrays is deduplicated against the pool and the arrays only hold views
+//! into the pooled buffers.
+//!
+//! Values that aren't pooled yet are not copied: the pool keeps the data buffer of the array
+//! that added them. The pool holds at most [`set_string_pool_capacity`] bytes; a shard that is
+//! full is emptied before it takes new values, and arrays that point into it keep their buffers.
+//!
+//! The pool is split in shards by the hash of the values, each behind its own lock, so that
+//! threads that build arrays at the same time rarely wait for each other. Values that are
+//! already pooled are found under a read lock; a shard is only locked exclusively to add values.
+use std::hash::{BuildHasherDefault, Hash, Hasher};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use hashbrown::hash_map::RawEntryMut;
+use hashbrown::HashMap;
+use polars_utils::slice::GetSaferUnchecked;
+
+use super::{BinaryViewArrayGeneric, View, ViewType, INLINE_VIEW_SIZE};
+use crate::array::dictionary::PassthroughHasher;
+use crate::array::Array;
+use crate::buffer::Buffer;
+
+const N_SHARDS: usize = 16;
+
+static STRING_POOL_ENABLED: AtomicBool = AtomicBool::new(false);
+// 1 GiB by default.
+static STRING_POOL_CAPACITY: AtomicUsize = AtomicUsize::new(1 << 30);
+static STRING_POOL: OnceLock<StringPool> = OnceLock::new();
+
+fn string_pool() -> &'static StringPool {
+    STRING_POOL.get_or_init(StringPool::default)
+}
+
+/// Enable the global string pool.
+///
+/// `Utf8View` arrays that are built while the pool is enabled store their data in the pool.
+/// This introduces hashing overhead on construction, so only enable it when the same values
+/// are expected to be loaded many times.
+pub fn enable_string_pool() {
+    STRING_POOL_ENABLED.store(true, Ordering::Release);
+}
+
+/// Disable the global string pool.
+///
+/// This does not release the pooled buffers, see [`release_string_pool`].
+pub fn disable_string_pool() {
+    STRING_POOL_ENABLED.store(false, Ordering::Release);
+}
+
+/// Check whether the global string pool is enabled.
+pub fn using_string_pool() -> bool {
+    STRING_POOL_ENABLED.load(Ordering::Acquire)
+}
+
+/// Set the maximum number of bytes of the buffers that the global string pool holds.
+pub fn set_string_pool_capacity(n_bytes: usize) {
+    STRING_POOL_CAPACITY.store(n_bytes, Ordering::Relaxed);
+}
+
+/// Release the buffers held by the global string pool.
+///
+/// Arrays that point into the pool keep their buffers alive, the memory is freed once the
+/// last of those arrays is dropped.
+pub fn release_string_pool() {
+    if let Some(pool) = STRING_POOL.get() {
+        for shard in pool.shards.iter() {
+            *shard.write().unwrap() = Shard::default();
+        }
+    }
+}
+
+/// Statistics of the global string pool.
+pub fn string_pool_stats() -> StringPoolStats {
+    STRING_POOL
+        .get()
+        .map(|pool| pool.stats())
+        .unwrap_or_default()
+}
+
+/// Store the data of `arr` in the global string pool and return an array that points into it.
+pub fn intern_in_string_pool<T: ViewType + ?Sized>(
+    arr: &BinaryViewArrayGeneric<T>,
+) -> BinaryViewArrayGeneric<T> {
+    string_pool().intern(arr)
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct StringPoolStats {
+    /// Number of distinct values in the pool.
+    pub n_values: usize,
+    /// Number of data buffers held by the pool.
+    pub n_buffers: usize,
+    /// Total number of bytes of the buffers held by the pool. A buffer that holds values of
+    /// several shards of the pool is counted for each of them.
+    pub total_bytes: usize,
+    /// Number of values that were already present in the pool.
+    pub hits: usize,
+    /// Number of values that were added to the pool.
+    pub misses: usize,
+}
+
+// The hash and the location of the value in its shard.
+#[derive(Copy, Clone)]
+struct Key {
+    hash: u64,
+    buffer_idx: u32,
+    offset: u32,
+    len: u32,
+}
+
+impl Hash for Key {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash)
+    }
+}
+
+#[derive(Default)]
+struct Shard {
+    map: HashMap<Key, (), BuildHasherDefault<PassthroughHasher>>,
+    buffers: Vec<Buffer<u8>>,
+    n_bytes: usize,
+}
+
+/// Whether `key` is the value `bytes`.
+fn is_pooled_value(key: &Key, hash: u64, bytes: &[u8], buffers: &[Buffer<u8>]) -> bool {
+    key.hash == hash && key.len as usize == bytes.len() && {
+        let start = key.offset as usize;
+        let end = start + key.len as usize;
+        let pooled = unsafe {
+            buffers
+                .get_unchecked_release(key.buffer_idx as usize)
+                .get_unchecked_release(start..end)
+        };
+        pooled == bytes
+    }
+}
+
+impl Shard {
+    fn find(&self, hash: u64, bytes: &[u8]) -> Option<Key> {
+        self.map
+            .raw_entry()
+            .from_hash(hash, |key| is_pooled_value(key, hash, bytes, &self.buffers))
+            .map(|(key, _)| *key)
+    }
+
+    /// Add the value of `view` if it isn't pooled yet, keeping the buffer of `arr_buffers`
+    /// that holds it. `added_buffers` maps the buffers of the array that this shard already
+    /// keeps to their index in the shard.
+    ///
+    /// Returns whether the value was added.
+    fn insert(
+        &mut self,
+        hash: u64,
+        view: &View,
+        arr_buffers: &[Buffer<u8>],
+        added_buffers: &mut HashMap<u32, u32>,
+    ) -> (Key, bool) {
+        let Shard {
+            map,
+            buffers,
+            n_bytes,
+        } = self;
+        // SAFETY: the view is valid for the buffers of its array.
+        let bytes = unsafe { view.get_slice_unchecked(arr_buffers) };
+        let entry = map
+            .raw_entry_mut()
+            .from_hash(hash, |key| is_pooled_value(key, hash, bytes, buffers));
+        match entry {
+            RawEntryMut::Occupied(entry) => (*entry.key(), false),
+            RawEntryMut::Vacant(entry) => {
+                let buffer_idx = *added_buffers.entry(view.buffer_idx).or_insert_with(|| {
+                    let buffer = arr_buffers[view.buffer_idx as usize].clone();
+                    *n_bytes += buffer.len();
+                    buffers.push(buffer);
+                    buffers.len() as u32 - 1
+                });
+                let key = Key {
+                    hash,
+                    buffer_idx,
+                    offset: view.offset,
+                    len: view.length,
+                };
+                entry.insert_hashed_nocheck(hash, key, ());
+                (key, true)
+            },
+        }
+    }
+}
+
+struct StringPool {
+    shards: Vec<RwLock<Shard>>,
+    random_state: ahash::RandomState,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl Default for StringPool {
+    fn default() -> Self {
+        Self {
+            shards: (0..N_SHARDS).map(|_| RwLock::default()).collect(),
+            random_state: ahash::RandomState::default(),
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+        }
+    }
+}
+
+fn shard_idx(hash: u64) -> usize {
+    // The low bits select the bucket in the shard and the high bits are its tag, so the shard
+    // is taken from the bits in between.
+    (hash >> 32) as usize % N_SHARDS
+}
+
+impl StringPool {
+    fn shard_capacity(&self) -> usize {
+        STRING_POOL_CAPACITY.load(Ordering::Relaxed) / N_SHARDS
+    }
+
+    fn stats(&self) -> StringPoolStats {
+        let mut stats = StringPoolStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            ..Default::default()
+        };
+        for shard in self.shards.iter() {
+            let shard = shard.read().unwrap();
+            stats.n_values += shard.map.len();
+            stats.n_buffers += shard.buffers.len();
+            stats.total_bytes += shard.n_bytes;
+        }
+        stats
+    }
+
+    fn intern<T: ViewType + ?Sized>(
+        &self,
+        arr: &BinaryViewArrayGeneric<T>,
+    ) -> BinaryViewArrayGeneric<T> {
+        let mut views = arr.views().to_vec();
+
+        // Hash the values before any lock is taken and group them by shard.
+        let mut hashes = vec![0u64; views.len()];
+        let mut per_shard: Vec<Vec<usize>> = vec![vec![]; N_SHARDS];
+        for (i, view) in views.iter_mut().enumerate() {
+            if view.length <= INLINE_VIEW_SIZE {
+                continue;
+            }
+            if arr.is_null(i) {
+                *view = View::default();
+                continue;
+            }
+            // SAFETY: the view is valid for the buffers of `arr`.
+            let bytes = unsafe { view.get_slice_unchecked(arr.data_buffers()) };
+            hashes[i] = self.random_state.hash_one(bytes);
+            per_shard[shard_idx(hashes[i])].push(i);
+        }
+
+        let mut buffers = vec![];
+        let mut n_values = 0;
+        let mut misses = 0;
+        for (shard, idxs) in self.shards.iter().zip(per_shard) {
+            if idxs.is_empty() {
+                continue;
+            }
+            let mut keys = Vec::with_capacity(idxs.len());
+            // SAFETY: the views are valid for the buffers of `arr`.
+            let bytes_at =
+                |i: usize| unsafe { arr.views()[i].get_slice_unchecked(arr.data_buffers()) };
+
+            // Most values are expected to be pooled already, which only needs a read lock.
+            let read = shard.read().unwrap();
+            keys.extend(idxs.iter().map(|&i| read.find(hashes[i], bytes_at(i))));
+            let shard = if keys.iter().all(|key| key.is_some()) {
+                ShardGuard::Read(read)
+            } else {
+                drop(read);
+                let mut write = shard.write().unwrap();
+                if write.n_bytes > self.shard_capacity() {
+                    *write = Shard::default();
+                }
+                let mut added_buffers = HashMap::new();
+                for (key, &i) in keys.iter_mut().zip(&idxs) {
+                    // The value may have been added since the read lock was released.
+                    let (new_key, added) = write.insert(
+                        hashes[i],
+                        &arr.views()[i],
+                        arr.data_buffers(),
+                        &mut added_buffers,
+                    );
+                    misses += added as usize;
+                    *key = Some(new_key);
+                }
+                ShardGuard::Write(write)
+            };
+            n_values += idxs.len();
+
+            // Only reference the pooled buffers that are used by this array.
+            let mut local_idx = HashMap::<u32, u32>::new();
+            for (key, &i) in keys.iter().zip(&idxs) {
+                let key = key.unwrap();
+                let idx = *local_idx.entry(key.buffer_idx).or_insert_with(|| {
+                    buffers.push(shard.buffers()[key.buffer_idx as usize].clone());
+                    buffers.len() as u32 - 1
+                });
+                views[i] = View {
+                    buffer_idx: idx,
+                    offset: key.offset,
+                    ..views[i]
+                };
+            }
+        }
+        self.hits.fetch_add(n_values - misses, Ordering::Relaxed);
+        self.misses.fetch_add(misses, Ordering::Relaxed);
+        let total_buffer_len = buffers.iter().map(|b| b.len()).sum();
+
+        // SAFETY: the views point to the same bytes as before, just in other buffers.
+        unsafe {
+            BinaryViewArrayGeneric::new_unchecked_unknown_md(
+                arr.data_type().clone(),
+                views.into(),
+                Arc::from(buffers),
+                arr.validity().cloned(),
+                Some(total_buffer_len),
+            )
+        }
+    }
+}
+
+enum ShardGuard<'a> {
+    Read(RwLockReadGuard<'a, Shard>),
+    Write(RwLockWriteGuard<'a, Shard>),
+}
+
+impl ShardGuard<'_> {
+    fn buffers(&self) -> &[Buffer<u8>] {
+        match self {
+            ShardGuard::Read(shard) => &shard.buffers,
+            ShardGuard::Write(shard) => &shard.buffers,
+        }
+    }
+}
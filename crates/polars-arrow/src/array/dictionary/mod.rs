@@ -21,6 +21,7 @@ mod value_map;
 pub use iterator::*;
 pub use mutable::*;
 use polars_error::{polars_bail, PolarsResult};
+pub(crate) use value_map::PassthroughHasher;
 
 use super::primitive::PrimitiveArray;
 use super::specification::check_indexes;
@@ -762,8 +762,10 @@ mod values;
 
 pub use binary::{BinaryArray, BinaryValueIter, MutableBinaryArray, MutableBinaryValuesArray};
 pub use binview::{
-    BinaryViewArray, BinaryViewArrayGeneric, MutableBinaryViewArray, MutablePlBinary,
-    MutablePlString, Utf8ViewArray, View, ViewType, INLINE_VIEW_SIZE,
+    disable_string_pool, enable_string_pool, intern_in_string_pool, release_string_pool,
+    set_string_pool_capacity, string_pool_stats, using_string_pool, BinaryViewArray,
+    BinaryViewArrayGeneric, MutableBinaryViewArray, MutablePlBinary, MutablePlString,
+    StringPoolStats, Utf8ViewArray, View, ViewType, INLINE_VIEW_SIZE,
 };
 pub use boolean::{BooleanArray, MutableBooleanArray};
 pub use dictionary::{DictionaryArray, DictionaryKey, MutableDictionaryArray};
@@ -30,7 +30,7 @@ unsafe impl ToFfi for StructArray {
 
 impl<A: ffi::ArrowArrayRef> FromFfi<A> for StructArray {
     unsafe fn try_from_ffi(array: A) -> PolarsResult<Self> {
-        let data_type = array.data_type().clone();
+        let data_type = array.data_type().run_end_decoded();
         let fields = Self::get_fields(&data_type);
 
         let arrow_array = array.array();
@@ -129,3 +129,200 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
         },
     }
 }
+
+/// The estimated size of the buffers of an array, split by the kind of buffer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BufferSizes {
+    /// The values, or the views of a view array.
+    pub data: usize,
+    pub validity: usize,
+    pub offsets: usize,
+    /// The data buffers that the views of a view array point into.
+    pub heap: usize,
+}
+
+impl BufferSizes {
+    pub fn total(&self) -> usize {
+        self.data + self.validity + self.offsets + self.heap
+    }
+}
+
+impl std::ops::Add for BufferSizes {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            data: self.data + rhs.data,
+            validity: self.validity + rhs.validity,
+            offsets: self.offsets + rhs.offsets,
+            heap: self.heap + rhs.heap,
+        }
+    }
+}
+
+impl std::ops::AddAssign for BufferSizes {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl std::iter::Sum for BufferSizes {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, x| acc + x)
+    }
+}
+
+fn binview_buffer_sizes<T: ViewType + ?Sized>(
+    array: &BinaryViewArrayGeneric<T>,
+    deep: bool,
+) -> BufferSizes {
+    let heap = if deep {
+        array.total_buffer_len()
+    } else {
+        array
+            .views()
+            .iter()
+            .filter(|v| v.length > INLINE_VIEW_SIZE)
+            .map(|v| v.length as usize)
+            .sum()
+    };
+    BufferSizes {
+        data: array.views().len() * std::mem::size_of::<View>(),
+        validity: validity_size(array.validity()),
+        offsets: 0,
+        heap,
+    }
+}
+
+macro_rules! dyn_binary_buffer_sizes {
+    ($array:expr, $ty:ty, $o:ty) => {{
+        let array = $array.as_any().downcast_ref::<$ty>().unwrap();
+        let offsets = array.offsets().buffer();
+        BufferSizes {
+            data: offsets[offsets.len() - 1] as usize - offsets[0] as usize,
+            validity: validity_size(array.validity()),
+            offsets: offsets.len() * std::mem::size_of::<$o>(),
+            heap: 0,
+        }
+    }};
+}
+
+/// Returns the estimated size of the buffers of the array in bytes, split by the kind of buffer.
+///
+/// The sizes of nested arrays are added to the sizes of their parent. Like
+/// [`estimated_bytes_size`], this counts the visible part of the buffers. If `deep` is set, the
+/// heap of a view array counts all the data buffers that it keeps alive, rather than only the
+/// bytes that its views refer to.
+pub fn estimated_buffer_sizes(array: &dyn Array, deep: bool) -> BufferSizes {
+    use PhysicalType::*;
+    let validity = BufferSizes {
+        validity: validity_size(array.validity()),
+        ..Default::default()
+    };
+    match array.data_type().to_physical_type() {
+        Null => BufferSizes::default(),
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            BufferSizes {
+                data: array.values().as_slice().0.len(),
+                ..validity
+            }
+        },
+        Primitive(PrimitiveType::DaysMs) => {
+            let array = array.as_any().downcast_ref::<DaysMsArray>().unwrap();
+            BufferSizes {
+                data: array.values().len() * std::mem::size_of::<i32>() * 2,
+                ..validity
+            }
+        },
+        Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<$T>>()
+                .unwrap();
+            BufferSizes {
+                data: array.values().len() * std::mem::size_of::<$T>(),
+                ..validity
+            }
+        }),
+        Binary => dyn_binary_buffer_sizes!(array, BinaryArray<i32>, i32),
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            BufferSizes {
+                data: array.values().len(),
+                ..validity
+            }
+        },
+        LargeBinary => dyn_binary_buffer_sizes!(array, BinaryArray<i64>, i64),
+        Utf8 => dyn_binary_buffer_sizes!(array, Utf8Array<i32>, i32),
+        LargeUtf8 => dyn_binary_buffer_sizes!(array, Utf8Array<i64>, i64),
+        List => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let offsets = BufferSizes {
+                offsets: array.offsets().buffer().len() * std::mem::size_of::<i32>(),
+                ..validity
+            };
+            offsets + estimated_buffer_sizes(array.values().as_ref(), deep)
+        },
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            validity + estimated_buffer_sizes(array.values().as_ref(), deep)
+        },
+        LargeList => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let offsets = BufferSizes {
+                offsets: array.offsets().buffer().len() * std::mem::size_of::<i64>(),
+                ..validity
+            };
+            offsets + estimated_buffer_sizes(array.values().as_ref(), deep)
+        },
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            validity
+                + array
+                    .values()
+                    .iter()
+                    .map(|x| estimated_buffer_sizes(x.as_ref(), deep))
+                    .sum::<BufferSizes>()
+        },
+        Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            let types = BufferSizes {
+                data: array.types().len() * std::mem::size_of::<i8>(),
+                offsets: array
+                    .offsets()
+                    .as_ref()
+                    .map(|x| x.len() * std::mem::size_of::<i32>())
+                    .unwrap_or_default(),
+                ..Default::default()
+            };
+            types
+                + array
+                    .fields()
+                    .iter()
+                    .map(|x| estimated_buffer_sizes(x.as_ref(), deep))
+                    .sum::<BufferSizes>()
+        },
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<$T>>()
+                .unwrap();
+            estimated_buffer_sizes(array.keys(), deep)
+                + estimated_buffer_sizes(array.values().as_ref(), deep)
+        }),
+        Utf8View => binview_buffer_sizes::<str>(array.as_any().downcast_ref().unwrap(), deep),
+        BinaryView => binview_buffer_sizes::<[u8]>(array.as_any().downcast_ref().unwrap(), deep),
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let offsets = BufferSizes {
+                offsets: array.offsets().buffer().len() * std::mem::size_of::<i32>(),
+                ..validity
+            };
+            offsets + estimated_buffer_sizes(array.field().as_ref(), deep)
+        },
+    }
+}
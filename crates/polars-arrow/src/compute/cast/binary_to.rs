@@ -89,6 +89,21 @@ pub fn binary_to_utf8<O: Offset>(
     )
 }
 
+/// Conversion to utf8 without validating the values.
+/// # Safety
+/// The caller must ensure the values are valid utf8.
+pub unsafe fn binary_to_utf8_unchecked<O: Offset>(
+    from: &BinaryArray<O>,
+    to_data_type: ArrowDataType,
+) -> Utf8Array<O> {
+    Utf8Array::<O>::new_unchecked(
+        to_data_type,
+        from.offsets().clone(),
+        from.values().clone(),
+        from.validity().cloned(),
+    )
+}
+
 /// Conversion to utf8
 /// # Errors
 /// This function errors if the values are not valid utf8
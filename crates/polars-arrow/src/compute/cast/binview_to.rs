@@ -1,13 +1,14 @@
 use chrono::Datelike;
-use polars_error::PolarsResult;
+use polars_error::{polars_ensure, PolarsResult};
 
 use crate::array::*;
-use crate::compute::cast::binary_to::Parse;
+use crate::buffer::Buffer;
+use crate::compute::cast::binary_to::{binary_to_list, Parse};
 use crate::compute::cast::CastOptionsImpl;
 #[cfg(feature = "dtype-decimal")]
 use crate::compute::decimal::deserialize_decimal;
 use crate::datatypes::{ArrowDataType, TimeUnit};
-use crate::offset::Offset;
+use crate::offset::{Offset, Offsets, OffsetsBuffer};
 use crate::temporal_conversions::EPOCH_DAYS_FROM_CE;
 use crate::types::NativeType;
 
@@ -47,6 +48,90 @@ pub(super) fn view_to_binary<O: Offset>(array: &BinaryViewArray) -> BinaryArray<
     out.with_validity(array.validity().cloned())
 }
 
+/// If the bytes of all views are stored back-to-back in the first data buffer, return that region
+/// of the buffer and the offsets of the views into it.
+fn contiguous_values<O: Offset>(from: &BinaryViewArray) -> Option<(Buffer<u8>, OffsetsBuffer<O>)> {
+    let mut offsets = Offsets::<O>::with_capacity(from.len());
+    let mut range: Option<(usize, usize)> = None;
+    for view in from.views().iter() {
+        if view.length > 0 {
+            if view.length <= INLINE_VIEW_SIZE || view.buffer_idx != 0 {
+                return None;
+            }
+            let offset = view.offset as usize;
+            let (_, end) = range.get_or_insert((offset, offset));
+            if offset != *end {
+                return None;
+            }
+            *end += view.length as usize;
+        }
+        offsets.try_push(view.length as usize).ok()?;
+    }
+    let values = match range {
+        Some((start, end)) => from.data_buffers()[0].clone().sliced(start, end - start),
+        None => Buffer::new(),
+    };
+    Some((values, offsets.into()))
+}
+
+/// Cast a [`BinaryViewArray`] to a list of bytes.
+///
+/// The data buffer is reused if the values are stored back-to-back, which is the case for
+/// values of more than 12 bytes that were appended in order.
+pub(super) fn binview_to_list<O: Offset>(
+    from: &BinaryViewArray,
+    to_data_type: ArrowDataType,
+) -> ListArray<O> {
+    match contiguous_values::<O>(from) {
+        Some((values, offsets)) => {
+            let values = PrimitiveArray::new(ArrowDataType::UInt8, values, None);
+            ListArray::<O>::new(
+                to_data_type,
+                offsets,
+                values.boxed(),
+                from.validity().cloned(),
+            )
+        },
+        None => binary_to_list(&view_to_binary::<O>(from), to_data_type),
+    }
+}
+
+/// Cast a [`BinaryViewArray`] to a fixed-size list of bytes.
+///
+/// # Errors
+/// This function errors if a valid value is not `size` bytes long.
+pub(super) fn binview_to_fixed_size_list(
+    from: &BinaryViewArray,
+    size: usize,
+    to_data_type: ArrowDataType,
+) -> PolarsResult<FixedSizeListArray> {
+    let all_sized = from.views().iter().all(|view| view.length as usize == size);
+    polars_ensure!(
+        all_sized || from.non_null_views_iter().all(|view| view.length as usize == size),
+        ComputeError: "not all elements have the specified width {size}"
+    );
+
+    let values = match contiguous_values::<i64>(from).filter(|_| all_sized) {
+        Some((values, _)) => values,
+        None => {
+            let mut values = Vec::with_capacity(from.len() * size);
+            for value in from.iter() {
+                match value {
+                    Some(value) => values.extend_from_slice(value),
+                    None => values.resize(values.len() + size, 0),
+                }
+            }
+            values.into()
+        },
+    };
+    let values = PrimitiveArray::new(ArrowDataType::UInt8, values, None);
+    Ok(FixedSizeListArray::new(
+        to_data_type,
+        values.boxed(),
+        from.validity().cloned(),
+    ))
+}
+
 pub fn utf8view_to_utf8<O: Offset>(array: &Utf8ViewArray) -> Utf8Array<O> {
     let array = array.to_binview();
     let out = view_to_binary::<O>(&array);
@@ -8,6 +8,8 @@ mod dictionary_to;
 mod primitive_to;
 mod utf8_to;
 
+use std::sync::Arc;
+
 pub use binary_to::*;
 #[cfg(feature = "dtype-decimal")]
 pub use binview_to::binview_to_decimal;
@@ -22,9 +24,10 @@ pub use primitive_to::*;
 pub use utf8_to::*;
 
 use crate::array::*;
+use crate::bitmap::Bitmap;
 use crate::compute::cast::binview_to::{
-    binview_to_dictionary, utf8view_to_date32_dyn, utf8view_to_dictionary,
-    utf8view_to_naive_timestamp_dyn, view_to_binary,
+    binview_to_dictionary, binview_to_fixed_size_list, binview_to_list, utf8view_to_date32_dyn,
+    utf8view_to_dictionary, utf8view_to_naive_timestamp_dyn,
 };
 use crate::datatypes::*;
 use crate::match_integer_type;
@@ -240,6 +243,58 @@ fn cast_list_to_fixed_size_list<O: Offset>(
     .map_err(|_| polars_err!(ComputeError: "not all elements have the specified width {size}"))
 }
 
+/// Create views into `values` for the given byte ranges, so that the bytes are not copied.
+fn byte_ranges_to_binview(
+    values: &PrimitiveArray<u8>,
+    ranges: impl Iterator<Item = (usize, usize)>,
+    validity: Option<&Bitmap>,
+) -> PolarsResult<BinaryViewArray> {
+    polars_ensure!(
+        values.null_count() == 0,
+        InvalidOperation: "cannot cast bytes containing nulls to binary"
+    );
+    let buffer = values.values();
+    let out = if buffer.len() <= u32::MAX as usize {
+        let views: Vec<View> = ranges
+            .map(|(start, end)| View::new_from_bytes(&buffer[start..end], 0, start as u32))
+            .collect();
+        // SAFETY: the views point into the single data buffer.
+        unsafe {
+            BinaryViewArray::new_unchecked_unknown_md(
+                ArrowDataType::BinaryView,
+                views.into(),
+                Arc::from([buffer.clone()]),
+                None,
+                None,
+            )
+        }
+    } else {
+        // Views can't address offsets beyond `u32::MAX`, so copy the bytes.
+        let mut mutable = MutableBinaryViewArray::<[u8]>::with_capacity(values.len());
+        for (start, end) in ranges {
+            mutable.push_value(&buffer[start..end]);
+        }
+        mutable.freeze()
+    };
+    Ok(out.with_validity(validity.cloned()))
+}
+
+fn list_to_binview<O: Offset>(list: &ListArray<O>) -> PolarsResult<BinaryViewArray> {
+    let values = list.values().as_any().downcast_ref().unwrap();
+    let ranges = list
+        .offsets()
+        .windows(2)
+        .map(|w| (w[0].to_usize(), w[1].to_usize()));
+    byte_ranges_to_binview(values, ranges, list.validity())
+}
+
+fn fixed_size_list_to_binview(list: &FixedSizeListArray) -> PolarsResult<BinaryViewArray> {
+    let values = list.values().as_any().downcast_ref().unwrap();
+    let size = list.size();
+    let ranges = (0..list.len()).map(|i| (i * size, (i + 1) * size));
+    byte_ranges_to_binview(values, ranges, list.validity())
+}
+
 pub fn cast_default(array: &dyn Array, to_type: &ArrowDataType) -> PolarsResult<Box<dyn Array>> {
     cast(array, to_type, Default::default())
 }
@@ -263,6 +318,7 @@ pub fn cast_unchecked(array: &dyn Array, to_type: &ArrowDataType) -> PolarsResul
 ///   underlying type is cast.
 /// * Struct to Struct: the underlying fields are cast.
 /// * PrimitiveArray to List: a list array with 1 value per slot is created
+/// * BinaryView to and from (Fixed Size) List of UInt8: the bytes are reused where the layout allows
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
@@ -350,14 +406,34 @@ pub fn cast(
             Int64 => binview_to_primitive_dyn::<i64>(array, to_type, options),
             Float32 => binview_to_primitive_dyn::<f32>(array, to_type, options),
             Float64 => binview_to_primitive_dyn::<f64>(array, to_type, options),
-            LargeList(inner) if matches!(inner.data_type, ArrowDataType::UInt8) => {
-                let bin_array = view_to_binary::<i64>(array.as_any().downcast_ref().unwrap());
-                Ok(binary_to_list(&bin_array, to_type.clone()).boxed())
+            LargeList(inner) if matches!(inner.data_type, ArrowDataType::UInt8) => Ok(
+                binview_to_list::<i64>(array.as_any().downcast_ref().unwrap(), to_type.clone())
+                    .boxed(),
+            ),
+            FixedSizeList(inner, size) if matches!(inner.data_type, ArrowDataType::UInt8) => {
+                binview_to_fixed_size_list(
+                    array.as_any().downcast_ref().unwrap(),
+                    *size,
+                    to_type.clone(),
+                )
+                .map(|arr| arr.boxed())
             },
             _ => polars_bail!(InvalidOperation:
                 "casting from {from_type:?} to {to_type:?} not supported",
             ),
         },
+        (List(inner), BinaryView) if matches!(inner.data_type, ArrowDataType::UInt8) => {
+            list_to_binview::<i32>(array.as_any().downcast_ref().unwrap()).map(|arr| arr.boxed())
+        },
+        (LargeList(inner), BinaryView) if matches!(inner.data_type, ArrowDataType::UInt8) => {
+            list_to_binview::<i64>(array.as_any().downcast_ref().unwrap()).map(|arr| arr.boxed())
+        },
+        (FixedSizeList(inner, _), BinaryView)
+            if matches!(inner.data_type, ArrowDataType::UInt8) =>
+        {
+            fixed_size_list_to_binview(array.as_any().downcast_ref().unwrap())
+                .map(|arr| arr.boxed())
+        },
         (LargeList(_), LargeList(_)) => {
             cast_list::<i64>(array.as_any().downcast_ref().unwrap(), to_type, options)
                 .map(|x| x.boxed())
@@ -170,6 +170,12 @@ pub enum ArrowDataType {
     /// A string type that inlines small values
     /// and can intern strings.
     Utf8View,
+    /// A run-end encoded array (`run_ends_type`, `values`), where the `i`-th element of the
+    /// values repeats up to the logical index given by the `i`-th run end.
+    ///
+    /// Arrays of this type are decoded into plain arrays of the values' type when they are
+    /// imported, so this type only occurs in imported schemas.
+    RunEndEncoded(IntegerType, Box<Field>),
     /// A type unknown to Arrow.
     Unknown,
 }
@@ -236,6 +242,14 @@ impl From<ArrowDataType> for arrow_schema::DataType {
             ArrowDataType::BinaryView | ArrowDataType::Utf8View => {
                 panic!("view datatypes not supported by arrow-rs")
             },
+            ArrowDataType::RunEndEncoded(run_ends, values) => Self::RunEndEncoded(
+                Arc::new(ArrowField::new(
+                    "run_ends",
+                    ArrowDataType::from(run_ends).into(),
+                    false,
+                )),
+                Arc::new((*values).into()),
+            ),
             ArrowDataType::Unknown => unimplemented!(),
         }
     }
@@ -299,8 +313,14 @@ impl From<arrow_schema::DataType> for ArrowDataType {
             },
             DataType::Decimal128(precision, scale) => Self::Decimal(precision as _, scale as _),
             DataType::Decimal256(precision, scale) => Self::Decimal256(precision as _, scale as _),
-            DataType::RunEndEncoded(_, _) => {
-                panic!("Run-end encoding not supported by polars_arrow")
+            DataType::RunEndEncoded(run_ends, values) => {
+                let run_ends = match run_ends.data_type() {
+                    DataType::Int16 => IntegerType::Int16,
+                    DataType::Int32 => IntegerType::Int32,
+                    DataType::Int64 => IntegerType::Int64,
+                    d => panic!("illegal run end type: {d}"),
+                };
+                Self::RunEndEncoded(run_ends, Box::new(values.into()))
             },
             // This ensures that it doesn't fail to compile when new variants are added to Arrow
             #[allow(unreachable_patterns)]
@@ -477,6 +497,8 @@ impl ArrowDataType {
             Map(_, _) => PhysicalType::Map,
             Dictionary(key, _, _) => PhysicalType::Dictionary(*key),
             Extension(_, key, _) => key.to_physical_type(),
+            // Run-end encoded arrays are decoded on import into arrays of the values' type.
+            RunEndEncoded(_, values) => values.data_type().to_physical_type(),
             Unknown => unimplemented!(),
         }
     }
@@ -536,6 +558,46 @@ impl ArrowDataType {
         }
     }
 
+    /// The data type that arrays of this type have once their run-end encoded arrays are
+    /// decoded, which happens when they are imported.
+    pub fn run_end_decoded(&self) -> ArrowDataType {
+        use ArrowDataType::*;
+        let decode_field = |field: &Field| Field {
+            data_type: field.data_type.run_end_decoded(),
+            ..field.clone()
+        };
+        match self {
+            RunEndEncoded(_, values) => values.data_type.run_end_decoded(),
+            List(field) => List(Box::new(decode_field(field))),
+            LargeList(field) => LargeList(Box::new(decode_field(field))),
+            FixedSizeList(field, width) => FixedSizeList(Box::new(decode_field(field)), *width),
+            Map(field, ordered) => Map(Box::new(decode_field(field)), *ordered),
+            Struct(fields) => Struct(fields.iter().map(decode_field).collect()),
+            Extension(name, inner, metadata) => Extension(
+                name.clone(),
+                Box::new(inner.run_end_decoded()),
+                metadata.clone(),
+            ),
+            _ => self.clone(),
+        }
+    }
+
+    /// Whether this data type is or contains a run-end encoded type.
+    pub fn contains_run_end_encoded(&self) -> bool {
+        use ArrowDataType::*;
+        match self {
+            RunEndEncoded(_, _) => true,
+            List(field) | LargeList(field) | FixedSizeList(field, _) | Map(field, _) => {
+                field.data_type.contains_run_end_encoded()
+            },
+            Struct(fields) | Union(fields, _, _) => fields
+                .iter()
+                .any(|field| field.data_type.contains_run_end_encoded()),
+            Dictionary(_, values, _) | Extension(_, values, _) => values.contains_run_end_encoded(),
+            _ => false,
+        }
+    }
+
     pub fn inner_dtype(&self) -> Option<&ArrowDataType> {
         match self {
             ArrowDataType::List(inner) => Some(inner.data_type()),
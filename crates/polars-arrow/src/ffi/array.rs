@@ -1,14 +1,15 @@
 //! Contains functionality to load an ArrayData from the C Data Interface
 use std::sync::Arc;
 
-use polars_error::{polars_bail, PolarsResult};
+use polars_error::{polars_bail, polars_ensure, PolarsResult};
 
 use super::ArrowArray;
+use crate::array::growable::make_growable;
 use crate::array::*;
 use crate::bitmap::utils::bytes_for;
 use crate::bitmap::Bitmap;
 use crate::buffer::{Buffer, Bytes, BytesAllocator};
-use crate::datatypes::{ArrowDataType, PhysicalType};
+use crate::datatypes::{ArrowDataType, IntegerType, PhysicalType};
 use crate::ffi::schema::get_child;
 use crate::types::NativeType;
 use crate::{match_integer_type, with_match_primitive_type_full};
@@ -19,6 +20,9 @@ use crate::{match_integer_type, with_match_primitive_type_full};
 /// * the interface is not valid (e.g. a null pointer)
 pub unsafe fn try_from<A: ArrowArrayRef>(array: A) -> PolarsResult<Box<dyn Array>> {
     use PhysicalType::*;
+    if let ArrowDataType::RunEndEncoded(run_ends_type, _) = array.data_type().to_logical_type() {
+        return run_end_decode(&array, *run_ends_type);
+    }
     Ok(match array.data_type().to_physical_type() {
         Null => Box::new(NullArray::try_from_ffi(array)?),
         Boolean => Box::new(BooleanArray::try_from_ffi(array)?),
@@ -46,6 +50,53 @@ pub unsafe fn try_from<A: ArrowArrayRef>(array: A) -> PolarsResult<Box<dyn Array
     })
 }
 
+/// Decodes a run-end encoded array into a plain array of its values.
+///
+/// # Safety
+/// The run ends must be valid, i.e. increasing and in bounds of the values.
+unsafe fn run_end_decode<A: ArrowArrayRef>(
+    array: &A,
+    run_ends_type: IntegerType,
+) -> PolarsResult<Box<dyn Array>> {
+    let run_ends = try_from(array.child(0)?)?;
+    let values = try_from(array.child(1)?)?;
+    let run_ends: Vec<usize> = match_integer_type!(run_ends_type, |$T| {
+        run_ends
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$T>>()
+            .unwrap()
+            .values()
+            .iter()
+            .map(|end| *end as usize)
+            .collect()
+    });
+    polars_ensure!(
+        run_ends.len() == values.len(),
+        ComputeError: "a run-end encoded array must have as many run ends as values"
+    );
+
+    // The offset and length of the array are logical, the children are not sliced by them.
+    let start = array.array().offset();
+    let end = start + array.array().len();
+    let mut growable = make_growable(&[values.as_ref()], values.null_count() > 0, end - start);
+    let mut run_start = 0;
+    for (i, run_end) in run_ends.into_iter().enumerate() {
+        let copies = run_end.min(end).saturating_sub(run_start.max(start));
+        if copies > 0 {
+            growable.extend_copies(0, i, 1, copies);
+        }
+        run_start = run_end;
+        if run_start >= end {
+            break;
+        }
+    }
+    polars_ensure!(
+        growable.len() == end - start,
+        ComputeError: "the run ends of a run-end encoded array must cover its length"
+    );
+    Ok(growable.as_box())
+}
+
 // Sound because the arrow specification does not allow multiple implementations
 // to change this struct
 // This is intrinsically impossible to prove because the implementations agree
@@ -171,6 +222,41 @@ impl ArrowArray {
         }
     }
 
+    /// Creates a run-end encoded `ArrowArray` of `length` elements from its run ends and values.
+    pub(crate) fn new_run_end_encoded(
+        length: usize,
+        run_ends: Box<dyn Array>,
+        values: Box<dyn Array>,
+    ) -> Self {
+        let children_ptr = [run_ends.clone(), values]
+            .into_iter()
+            .map(|child| Box::into_raw(Box::new(ArrowArray::new(child))))
+            .collect::<Box<_>>();
+
+        // A run-end encoded array has no buffers and no validity of its own, its children own
+        // all of its data.
+        let mut private_data = Box::new(PrivateData {
+            array: run_ends,
+            buffers_ptr: Box::from([]),
+            children_ptr,
+            dictionary_ptr: None,
+            variadic_buffer_sizes: Box::from([]),
+        });
+
+        Self {
+            length: length as i64,
+            null_count: 0,
+            offset: 0,
+            n_buffers: 0,
+            n_children: 2,
+            buffers: private_data.buffers_ptr.as_mut_ptr(),
+            children: private_data.children_ptr.as_mut_ptr(),
+            dictionary: std::ptr::null_mut(),
+            release: Some(c_release_array),
+            private_data: Box::into_raw(private_data) as *mut ::std::os::raw::c_void,
+        }
+    }
+
     /// creates an empty [`ArrowArray`], which can be used to import data into
     pub fn empty() -> Self {
         Self {
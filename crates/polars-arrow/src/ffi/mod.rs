@@ -13,7 +13,7 @@ use polars_error::PolarsResult;
 pub use stream::{export_iterator, ArrowArrayStreamReader};
 
 use self::schema::to_field;
-use crate::array::Array;
+use crate::array::{Array, PrimitiveArray};
 use crate::datatypes::{ArrowDataType, Field};
 
 /// Exports an [`Box<dyn Array>`] to the C data interface.
@@ -21,6 +21,20 @@ pub fn export_array_to_c(array: Box<dyn Array>) -> ArrowArray {
     ArrowArray::new(bridge::align_to_c_data_interface(array))
 }
 
+/// Exports a run-end encoded array of `run_ends` and `values` to the C data interface, see
+/// [`ArrowDataType::RunEndEncoded`]. The length of the array is its last run end.
+pub fn export_run_end_encoded_array_to_c(
+    run_ends: PrimitiveArray<i32>,
+    values: Box<dyn Array>,
+) -> ArrowArray {
+    let length = run_ends.values().last().map_or(0, |end| *end as usize);
+    ArrowArray::new_run_end_encoded(
+        length,
+        bridge::align_to_c_data_interface(run_ends.boxed()),
+        bridge::align_to_c_data_interface(values),
+    )
+}
+
 /// Exports a [`Field`] to the C data interface.
 pub fn export_field_to_c(field: &Field) -> ArrowSchema {
     ArrowSchema::new(field)
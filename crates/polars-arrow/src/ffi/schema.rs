@@ -53,6 +53,13 @@ fn schema_children(data_type: &ArrowDataType, flags: &mut i64) -> Box<[*mut Arro
             .iter()
             .map(|field| Box::into_raw(Box::new(ArrowSchema::new(field))))
             .collect::<Box<[_]>>(),
+        ArrowDataType::RunEndEncoded(run_ends, values) => {
+            let run_ends = Field::new("run_ends", (*run_ends).into(), false);
+            Box::new([
+                Box::into_raw(Box::new(ArrowSchema::new(&run_ends))),
+                Box::into_raw(Box::new(ArrowSchema::new(values.as_ref()))),
+            ])
+        },
         ArrowDataType::Extension(_, inner, _) => schema_children(inner, flags),
         _ => Box::new([]),
     }
@@ -292,6 +299,16 @@ unsafe fn to_data_type(schema: &ArrowSchema) -> PolarsResult<ArrowDataType> {
                 .collect::<PolarsResult<Vec<_>>>()?;
             ArrowDataType::Struct(children)
         },
+        "+r" => {
+            let run_ends = match schema.child(0).format() {
+                "s" => IntegerType::Int16,
+                "i" => IntegerType::Int32,
+                "l" => IntegerType::Int64,
+                _ => polars_bail!(ComputeError: "run ends can only be Int16, Int32 or Int64"),
+            };
+            let values = to_field(schema.child(1))?;
+            ArrowDataType::RunEndEncoded(run_ends, Box::new(values))
+        },
         other => {
             match other.splitn(2, ':').collect::<Vec<_>>()[..] {
                 // Timestamps with no timezone
@@ -479,6 +496,7 @@ fn to_format(data_type: &ArrowDataType) -> String {
         ArrowDataType::Map(_, _) => "+m".to_string(),
         ArrowDataType::Dictionary(index, _, _) => to_format(&(*index).into()),
         ArrowDataType::Extension(_, inner, _) => to_format(inner.as_ref()),
+        ArrowDataType::RunEndEncoded(_, _) => "+r".to_string(),
         ArrowDataType::Unknown => unimplemented!(),
     }
 }
@@ -491,6 +509,8 @@ pub(super) fn get_child(data_type: &ArrowDataType, index: usize) -> PolarsResult
         (0, ArrowDataType::Map(field, _)) => Ok(field.data_type().clone()),
         (index, ArrowDataType::Struct(fields)) => Ok(fields[index].data_type().clone()),
         (index, ArrowDataType::Union(fields, _, _)) => Ok(fields[index].data_type().clone()),
+        (0, ArrowDataType::RunEndEncoded(run_ends, _)) => Ok((*run_ends).into()),
+        (1, ArrowDataType::RunEndEncoded(_, values)) => Ok(values.data_type().clone()),
         (index, ArrowDataType::Extension(_, subtype, _)) => get_child(subtype, index),
         (child, data_type) => polars_bail!(ComputeError:
             "Requested child {child} to type {data_type:?} that has no such child",
@@ -8,7 +8,7 @@ use super::{
     ArrowArrayStream, ArrowSchema,
 };
 use crate::array::Array;
-use crate::datatypes::Field;
+use crate::datatypes::{ArrowDataType, Field};
 
 impl Drop for ArrowArrayStream {
     fn drop(&mut self) {
@@ -47,6 +47,9 @@ unsafe fn handle_error(iter: &mut ArrowArrayStream) -> PolarsError {
 pub struct ArrowArrayStreamReader<Iter: DerefMut<Target = ArrowArrayStream>> {
     iter: Iter,
     field: Field,
+    /// The data type the arrays are imported with, which differs from the field's data type if
+    /// the stream has run-end encoded arrays.
+    data_type: ArrowDataType,
 }
 
 impl<Iter: DerefMut<Target = ArrowArrayStream>> ArrowArrayStreamReader<Iter> {
@@ -88,8 +91,17 @@ impl<Iter: DerefMut<Target = ArrowArrayStream>> ArrowArrayStreamReader<Iter> {
         }
 
         let field = unsafe { import_field_from_c(&field)? };
+        let data_type = field.data_type.clone();
+        let field = Field {
+            data_type: data_type.run_end_decoded(),
+            ..field
+        };
 
-        Ok(Self { iter, field })
+        Ok(Self {
+            iter,
+            field,
+            data_type,
+        })
     }
 
     /// Returns the field provided by the stream
@@ -118,7 +130,7 @@ impl<Iter: DerefMut<Target = ArrowArrayStream>> ArrowArrayStreamReader<Iter> {
         array.release?;
 
         // SAFETY: assumed from the C stream interface
-        unsafe { import_array_from_c(array, self.field.data_type.clone()) }
+        unsafe { import_array_from_c(array, self.data_type.clone()) }
             .map(Some)
             .transpose()
     }
@@ -57,18 +57,21 @@ impl From<EncodedData> for FlightData {
 pub fn serialize_schema_to_result(
     schema: &ArrowSchema,
     ipc_fields: Option<&[IpcField]>,
-) -> SchemaResult {
-    SchemaResult {
-        schema: _serialize_schema(schema, ipc_fields),
-    }
+) -> PolarsResult<SchemaResult> {
+    Ok(SchemaResult {
+        schema: _serialize_schema(schema, ipc_fields)?,
+    })
 }
 
 /// Serializes a [`ArrowSchema`] to [`FlightData`].
-pub fn serialize_schema(schema: &ArrowSchema, ipc_fields: Option<&[IpcField]>) -> FlightData {
-    FlightData {
-        data_header: _serialize_schema(schema, ipc_fields),
+pub fn serialize_schema(
+    schema: &ArrowSchema,
+    ipc_fields: Option<&[IpcField]>,
+) -> PolarsResult<FlightData> {
+    Ok(FlightData {
+        data_header: _serialize_schema(schema, ipc_fields)?,
         ..Default::default()
-    }
+    })
 }
 
 /// Convert a [`ArrowSchema`] to bytes in the format expected in [`arrow_format::flight::data::FlightInfo`].
@@ -77,10 +80,10 @@ pub fn serialize_schema_to_info(
     ipc_fields: Option<&[IpcField]>,
 ) -> PolarsResult<Vec<u8>> {
     let encoded_data = if let Some(ipc_fields) = ipc_fields {
-        schema_as_encoded_data(schema, ipc_fields)
+        schema_as_encoded_data(schema, ipc_fields)?
     } else {
         let ipc_fields = default_ipc_fields(&schema.fields);
-        schema_as_encoded_data(schema, &ipc_fields)
+        schema_as_encoded_data(schema, &ipc_fields)?
     };
 
     let mut schema = vec![];
@@ -88,7 +91,10 @@ pub fn serialize_schema_to_info(
     Ok(schema)
 }
 
-fn _serialize_schema(schema: &ArrowSchema, ipc_fields: Option<&[IpcField]>) -> Vec<u8> {
+fn _serialize_schema(
+    schema: &ArrowSchema,
+    ipc_fields: Option<&[IpcField]>,
+) -> PolarsResult<Vec<u8>> {
     if let Some(ipc_fields) = ipc_fields {
         write::schema_to_bytes(schema, ipc_fields)
     } else {
@@ -97,11 +103,14 @@ fn _serialize_schema(schema: &ArrowSchema, ipc_fields: Option<&[IpcField]>) -> V
     }
 }
 
-fn schema_as_encoded_data(schema: &ArrowSchema, ipc_fields: &[IpcField]) -> EncodedData {
-    EncodedData {
-        ipc_message: write::schema_to_bytes(schema, ipc_fields),
+fn schema_as_encoded_data(
+    schema: &ArrowSchema,
+    ipc_fields: &[IpcField],
+) -> PolarsResult<EncodedData> {
+    Ok(EncodedData {
+        ipc_message: write::schema_to_bytes(schema, ipc_fields)?,
         arrow_data: vec![],
-    }
+    })
 }
 
 /// Deserialize an IPC message into [`ArrowSchema`], [`IpcSchema`].
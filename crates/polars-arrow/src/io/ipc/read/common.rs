@@ -7,6 +7,7 @@ use polars_error::{polars_bail, polars_err, PolarsResult};
 use super::deserialize::{read, skip};
 use super::Dictionaries;
 use crate::array::*;
+use crate::compute::concatenate::concatenate;
 use crate::datatypes::{ArrowDataType, Field};
 use crate::io::ipc::read::OutOfSpecKind;
 use crate::io::ipc::{IpcField, IpcSchema};
@@ -241,7 +242,9 @@ pub(crate) fn first_dict_field<'a>(
 }
 
 /// Reads a dictionary from the reader,
-/// updating `dictionaries` with the resulting dictionary
+/// updating `dictionaries` with the resulting dictionary.
+///
+/// The values of a delta dictionary batch are appended to the dictionary with the same id.
 #[allow(clippy::too_many_arguments)]
 pub fn read_dictionary<R: Read + Seek>(
     batch: arrow_format::ipc::DictionaryBatchRef,
@@ -253,12 +256,9 @@ pub fn read_dictionary<R: Read + Seek>(
     file_size: u64,
     scratch: &mut Vec<u8>,
 ) -> PolarsResult<()> {
-    if batch
+    let is_delta = batch
         .is_delta()
-        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferIsDelta(err)))?
-    {
-        polars_bail!(ComputeError: "delta dictionary batches not supported")
-    }
+        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferIsDelta(err)))?;
 
     let id = batch
         .id()
@@ -298,7 +298,12 @@ pub fn read_dictionary<R: Read + Seek>(
         scratch,
     )?;
 
-    dictionaries.insert(id, chunk.into_arrays().pop().unwrap());
+    let values = chunk.into_arrays().pop().unwrap();
+    let values = match dictionaries.get(&id) {
+        Some(existing) if is_delta => concatenate(&[existing.as_ref(), values.as_ref()])?,
+        _ => values,
+    };
+    dictionaries.insert(id, values);
 
     Ok(())
 }
@@ -30,6 +30,7 @@ pub mod file_async;
 pub(crate) use common::first_dict_field;
 #[cfg(feature = "io_flight")]
 pub(crate) use common::{read_dictionary, read_record_batch};
+pub(crate) use deserialize::skip;
 pub use file::{
     deserialize_footer, read_batch, read_file_dictionaries, read_file_metadata, FileMetadata,
 };
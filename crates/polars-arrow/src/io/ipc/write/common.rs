@@ -4,7 +4,7 @@ use arrow_format::ipc::planus::Builder;
 use polars_error::{polars_bail, polars_err, PolarsResult};
 
 use super::super::IpcField;
-use super::{write, write_dictionary};
+use super::write;
 use crate::array::*;
 use crate::datatypes::*;
 use crate::io::ipc::endianness::is_native_little_endian;
@@ -28,6 +28,10 @@ pub struct WriteOptions {
     /// Whether the buffers should be compressed and which codec to use.
     /// Note: to use compression the crate must be compiled with feature `io_ipc_compression`.
     pub compression: Option<Compression>,
+    /// Whether a dictionary that only grew since it was last written is written as a delta
+    /// dictionary batch, with only the new values, instead of being written again in full.
+    /// Note: not all readers support delta dictionary batches.
+    pub emit_dictionary_deltas: bool,
 }
 
 fn encode_dictionary(
@@ -45,7 +49,7 @@ fn encode_dictionary(
             let dict_id = field.dictionary_id
                 .ok_or_else(|| polars_err!(InvalidOperation: "Dictionaries must have an associated id"))?;
 
-            let emit = dictionary_tracker.insert(dict_id, array)?;
+            let emit = dictionary_tracker.insert(dict_id, array, options.emit_dictionary_deltas)?;
 
             let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
             let values = array.values();
@@ -56,13 +60,22 @@ fn encode_dictionary(
                 encoded_dictionaries
             )?;
 
-            if emit {
-                encoded_dictionaries.push(dictionary_batch_to_bytes::<$T>(
+            match emit {
+                EmitDictionary::Skip => {},
+                EmitDictionary::Full => encoded_dictionaries.push(dictionary_batch_to_bytes(
                     dict_id,
-                    array,
+                    values.as_ref(),
+                    false,
                     options,
                     is_native_little_endian(),
-                ));
+                )),
+                EmitDictionary::Delta(offset) => encoded_dictionaries.push(dictionary_batch_to_bytes(
+                    dict_id,
+                    values.split_at_boxed(offset).1.as_ref(),
+                    true,
+                    options,
+                    is_native_little_endian(),
+                )),
             };
             Ok(())
         }),
@@ -345,10 +358,11 @@ fn chunk_to_bytes_amortized(
 }
 
 /// Write dictionary values into two sets of bytes, one for the header (ipc::Schema::Message) and the
-/// other for the data
-fn dictionary_batch_to_bytes<K: DictionaryKey>(
+/// other for the data. If `is_delta`, the values are appended to the dictionary by readers.
+fn dictionary_batch_to_bytes(
     dict_id: i64,
-    array: &DictionaryArray<K>,
+    values: &dyn Array,
+    is_delta: bool,
     options: &WriteOptions,
     is_little_endian: bool,
 ) -> EncodedData {
@@ -356,7 +370,7 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
     let mut buffers: Vec<arrow_format::ipc::Buffer> = vec![];
     let mut arrow_data: Vec<u8> = vec![];
     let mut variadic_buffer_counts = vec![];
-    set_variadic_buffer_counts(&mut variadic_buffer_counts, array.values().as_ref());
+    set_variadic_buffer_counts(&mut variadic_buffer_counts, values);
 
     let variadic_buffer_counts = if variadic_buffer_counts.is_empty() {
         None
@@ -364,16 +378,16 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
         Some(variadic_buffer_counts)
     };
 
-    let length = write_dictionary(
-        array,
+    write(
+        values,
         &mut buffers,
         &mut arrow_data,
         &mut nodes,
         &mut 0,
         is_little_endian,
         options.compression,
-        false,
     );
+    let length = values.len();
 
     let compression = serialize_compression(options.compression);
 
@@ -389,7 +403,7 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
                     compression,
                     variadic_buffer_counts,
                 })),
-                is_delta: false,
+                is_delta,
             },
         ))),
         body_length: arrow_data.len() as i64,
@@ -405,6 +419,17 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
     }
 }
 
+/// How a dictionary has to be written, see [`DictionaryTracker::insert`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmitDictionary {
+    /// The dictionary was already written.
+    Skip,
+    /// The whole dictionary has to be written.
+    Full,
+    /// Only the values from this offset on have to be written, as a delta dictionary batch.
+    Delta(usize),
+}
+
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
 /// multiple times. Can optionally error if an update to an existing dictionary is attempted, which
 /// isn't allowed in the `FileWriter`.
@@ -416,14 +441,23 @@ pub struct DictionaryTracker {
 impl DictionaryTracker {
     /// Keep track of the dictionary with the given ID and values. Behavior:
     ///
-    /// * If this ID has been written already and has the same data, return `Ok(false)` to indicate
-    ///   that the dictionary was not actually inserted (because it's already been seen).
+    /// * If this ID has been written already and has the same data, return
+    ///   `Ok(EmitDictionary::Skip)` to indicate that the dictionary was not actually inserted
+    ///   (because it's already been seen).
+    /// * If this ID has been written already, `allow_delta` is set and the written values are a
+    ///   prefix of the new values, return `Ok(EmitDictionary::Delta(offset))` with the number of
+    ///   values that were already written.
     /// * If this ID has been written already but with different data, and this tracker is
     ///   configured to return an error, return an error.
     /// * If the tracker has not been configured to error on replacement or this dictionary
-    ///   has never been seen before, return `Ok(true)` to indicate that the dictionary was just
-    ///   inserted.
-    pub fn insert(&mut self, dict_id: i64, array: &dyn Array) -> PolarsResult<bool> {
+    ///   has never been seen before, return `Ok(EmitDictionary::Full)` to indicate that the
+    ///   dictionary was just inserted.
+    pub fn insert(
+        &mut self,
+        dict_id: i64,
+        array: &dyn Array,
+        allow_delta: bool,
+    ) -> PolarsResult<EmitDictionary> {
         let values = match array.data_type() {
             ArrowDataType::Dictionary(key_type, _, _) => {
                 match_integer_type!(key_type, |$T| {
@@ -438,10 +472,17 @@ impl DictionaryTracker {
         };
 
         // If a dictionary with this id was already emitted, check if it was the same.
+        let mut emit = EmitDictionary::Full;
         if let Some(last) = self.dictionaries.get(&dict_id) {
             if last.as_ref() == values.as_ref() {
                 // Same dictionary values => no need to emit it again
-                return Ok(false);
+                return Ok(EmitDictionary::Skip);
+            } else if allow_delta
+                && last.len() < values.len()
+                && values.sliced(0, last.len()).as_ref() == last.as_ref()
+            {
+                // The dictionary only grew => emit the new values
+                emit = EmitDictionary::Delta(last.len());
             } else if self.cannot_replace {
                 polars_bail!(InvalidOperation:
                     "Dictionary replacement detected when writing IPC file format. \
@@ -452,7 +493,7 @@ impl DictionaryTracker {
         };
 
         self.dictionaries.insert(dict_id, values.clone());
-        Ok(true)
+        Ok(emit)
     }
 }
 
@@ -45,11 +45,8 @@ where
         options: WriteOptions,
     ) -> Self {
         let fields = ipc_fields.unwrap_or_else(|| default_ipc_fields(&schema.fields));
-        let encoded = EncodedData {
-            ipc_message: schema_to_bytes(&schema, &fields),
-            arrow_data: vec![],
-        };
-        let task = Some(Self::start(writer, encoded).boxed());
+        let ipc_message = schema_to_bytes(&schema, &fields);
+        let task = Some(Self::start(writer, ipc_message).boxed());
         Self {
             writer: None,
             task,
@@ -66,7 +63,14 @@ where
         }
     }
 
-    async fn start(mut writer: W, encoded: EncodedData) -> PolarsResult<WriteOutput<W>> {
+    async fn start(
+        mut writer: W,
+        ipc_message: PolarsResult<Vec<u8>>,
+    ) -> PolarsResult<WriteOutput<W>> {
+        let encoded = EncodedData {
+            ipc_message: ipc_message?,
+            arrow_data: vec![],
+        };
         writer.write_all(&ARROW_MAGIC_V2[..]).await?;
         writer.write_all(&[0, 0]).await?;
         let (meta, data) = write_message(&mut writer, encoded).await?;
@@ -190,7 +194,7 @@ where
         match futures::ready!(this.poll_write(cx)) {
             Ok(()) => {
                 if let Some(writer) = this.writer.take() {
-                    let schema = serialize_schema(&this.schema, &this.fields);
+                    let schema = serialize_schema(&this.schema, &this.fields)?;
                     let footer = Footer {
                         version: MetadataVersion::V5,
                         schema: Some(Box::new(schema)),
@@ -8,7 +8,6 @@ pub(crate) mod writer;
 pub use common::{Compression, Record, WriteOptions};
 pub use schema::schema_to_bytes;
 pub use serialize::write;
-use serialize::write_dictionary;
 pub use stream::StreamWriter;
 pub use writer::FileWriter;
 
@@ -1,4 +1,5 @@
 use arrow_format::ipc::planus::Builder;
+use polars_error::{polars_bail, PolarsResult};
 
 use super::super::IpcField;
 use crate::datatypes::{
@@ -7,8 +8,8 @@ use crate::datatypes::{
 use crate::io::ipc::endianness::is_native_little_endian;
 
 /// Converts a [ArrowSchema] and [IpcField]s to a flatbuffers-encoded [arrow_format::ipc::Message].
-pub fn schema_to_bytes(schema: &ArrowSchema, ipc_fields: &[IpcField]) -> Vec<u8> {
-    let schema = serialize_schema(schema, ipc_fields);
+pub fn schema_to_bytes(schema: &ArrowSchema, ipc_fields: &[IpcField]) -> PolarsResult<Vec<u8>> {
+    let schema = serialize_schema(schema, ipc_fields)?;
 
     let message = arrow_format::ipc::Message {
         version: arrow_format::ipc::MetadataVersion::V5,
@@ -18,13 +19,25 @@ pub fn schema_to_bytes(schema: &ArrowSchema, ipc_fields: &[IpcField]) -> Vec<u8>
     };
     let mut builder = Builder::new();
     let footer_data = builder.finish(&message, None);
-    footer_data.to_vec()
+    Ok(footer_data.to_vec())
 }
 
 pub fn serialize_schema(
     schema: &ArrowSchema,
     ipc_fields: &[IpcField],
-) -> arrow_format::ipc::Schema {
+) -> PolarsResult<arrow_format::ipc::Schema> {
+    // Run-end encoded arrays are decoded on import, so no arrays of these types can be written.
+    if let Some(field) = schema
+        .fields
+        .iter()
+        .find(|field| field.data_type().contains_run_end_encoded())
+    {
+        polars_bail!(
+            ComputeError: "cannot write field '{}' of run-end encoded type {:?} to IPC",
+            field.name, field.data_type()
+        );
+    }
+
     let endianness = if is_native_little_endian() {
         arrow_format::ipc::Endianness::Little
     } else {
@@ -46,12 +59,12 @@ pub fn serialize_schema(
 
     let custom_metadata = (!custom_metadata.is_empty()).then_some(custom_metadata);
 
-    arrow_format::ipc::Schema {
+    Ok(arrow_format::ipc::Schema {
         endianness,
         fields: Some(fields),
         custom_metadata,
         features: None, // todo add this one
-    }
+    })
 }
 
 fn key_value(key: impl Into<String>, val: impl Into<String>) -> arrow_format::ipc::KeyValue {
@@ -247,6 +260,9 @@ fn serialize_type(data_type: &ArrowDataType) -> arrow_format::ipc::Type {
         Extension(_, v, _) => serialize_type(v),
         Utf8View => ipc::Type::Utf8View(Box::new(ipc::Utf8View {})),
         BinaryView => ipc::Type::BinaryView(Box::new(ipc::BinaryView {})),
+        RunEndEncoded(_, _) => {
+            unreachable!("run-end encoded types are rejected by `serialize_schema`")
+        },
         Unknown => unimplemented!(),
     }
 }
@@ -296,6 +312,9 @@ fn serialize_children(
             .collect(),
         Dictionary(_, inner, _) => serialize_children(inner, ipc_field),
         Extension(_, inner, _) => serialize_children(inner, ipc_field),
+        RunEndEncoded(_, _) => {
+            unreachable!("run-end encoded types are rejected by `serialize_schema`")
+        },
         Unknown => unimplemented!(),
     }
 }
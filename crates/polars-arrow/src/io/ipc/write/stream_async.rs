@@ -55,11 +55,12 @@ where
         schema: &ArrowSchema,
         ipc_fields: &[IpcField],
     ) -> BoxFuture<'a, PolarsResult<Option<W>>> {
-        let message = EncodedData {
-            ipc_message: schema_to_bytes(schema, ipc_fields),
-            arrow_data: vec![],
-        };
+        let ipc_message = schema_to_bytes(schema, ipc_fields);
         async move {
+            let message = EncodedData {
+                ipc_message: ipc_message?,
+                arrow_data: vec![],
+            };
             write_message(&mut writer, message).await?;
             Ok(Some(writer))
         }
@@ -109,6 +109,7 @@ impl<W: Write> FileWriter<W> {
         if self.state != State::None {
             polars_bail!(oos = "The IPC file can only be started once");
         }
+        let ipc_message = schema_to_bytes(&self.schema, &self.ipc_fields)?;
         // write magic to header
         self.writer.write_all(&ARROW_MAGIC_V2[..])?;
         // create an 8-byte boundary after the header
@@ -116,7 +117,7 @@ impl<W: Write> FileWriter<W> {
         // write the schema, set the written bytes to the schema
 
         let encoded_message = EncodedData {
-            ipc_message: schema_to_bytes(&self.schema, &self.ipc_fields),
+            ipc_message,
             arrow_data: vec![],
         };
 
@@ -187,7 +188,7 @@ impl<W: Write> FileWriter<W> {
         // write EOS
         write_continuation(&mut self.writer, 0)?;
 
-        let schema = schema::serialize_schema(&self.schema, &self.ipc_fields);
+        let schema = schema::serialize_schema(&self.schema, &self.ipc_fields)?;
 
         let root = arrow_format::ipc::Footer {
             version: arrow_format::ipc::MetadataVersion::V5,
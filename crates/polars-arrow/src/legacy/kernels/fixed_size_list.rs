@@ -2,6 +2,7 @@ use polars_error::{polars_bail, PolarsResult};
 use polars_utils::index::NullCount;
 use polars_utils::IdxSize;
 
+use crate::array::growable::make_growable;
 use crate::array::{ArrayRef, FixedSizeListArray, PrimitiveArray};
 use crate::compute::take::take_unchecked;
 use crate::legacy::prelude::*;
@@ -71,3 +72,37 @@ pub fn sub_fixed_size_list_get(
     // the indices we generate are in bounds
     unsafe { Ok(take_unchecked(&**values, &take_by)) }
 }
+
+/// Take the elements `start..start + width` of every sub-array.
+///
+/// If the sub-arrays are not changed, the values are not copied.
+///
+/// # Panics
+/// Panics if `width == 0` or if `start + width` exceeds the size of `arr`.
+pub fn sub_fixed_size_list_slice(
+    arr: &FixedSizeListArray,
+    start: usize,
+    width: usize,
+) -> FixedSizeListArray {
+    let size = arr.size();
+    assert!(width > 0 && start + width <= size);
+
+    // The rows are stored back to back, so the values can only be a view of the child array if
+    // every row is kept whole or there is at most one row. Otherwise the kept range of every
+    // row is copied out of the child array.
+    let values = if width == size {
+        arr.values().clone()
+    } else if arr.len() <= 1 {
+        arr.values().sliced(start, width * arr.len())
+    } else {
+        let values = &**arr.values();
+        let mut growable = make_growable(&[values], values.null_count() > 0, arr.len() * width);
+        for i in 0..arr.len() {
+            // SAFETY: the range is within the row, which is within the child array.
+            unsafe { growable.extend(0, i * size + start, width) }
+        }
+        growable.as_box()
+    };
+    let data_type = FixedSizeListArray::default_datatype(values.data_type().clone(), width);
+    FixedSizeListArray::new(data_type, values, arr.validity().cloned())
+}
@@ -1,6 +1,7 @@
 pub mod no_nulls;
 pub mod nulls;
 pub mod quantile_filter;
+pub mod quantile_sketch;
 mod window;
 
 use std::any::Any;
@@ -87,3 +88,10 @@ pub struct RollingQuantileParams {
     pub prob: f64,
     pub interpol: QuantileInterpolOptions,
 }
+
+#[derive(Clone, Copy, Debug)]
+pub struct RollingApproxQuantileParams {
+    pub prob: f64,
+    /// The maximum relative error of the estimated quantiles.
+    pub relative_accuracy: f64,
+}
@@ -0,0 +1,79 @@
+use polars_error::polars_bail;
+
+use super::super::quantile_sketch::QuantileSketch;
+use super::*;
+
+pub struct ApproxQuantileWindow<'a, T> {
+    slice: &'a [T],
+    sketch: QuantileSketch,
+    prob: f64,
+    last_start: usize,
+    last_end: usize,
+}
+
+impl<'a, T: NativeType + Float> RollingAggWindowNoNulls<'a, T> for ApproxQuantileWindow<'a, T> {
+    fn new(slice: &'a [T], start: usize, end: usize, params: DynArgs) -> Self {
+        let params = params.unwrap();
+        let params = params
+            .downcast_ref::<RollingApproxQuantileParams>()
+            .unwrap();
+        let mut sketch = QuantileSketch::new(params.relative_accuracy);
+        for v in &slice[start..end] {
+            sketch.insert(v.to_f64().unwrap());
+        }
+        Self {
+            slice,
+            sketch,
+            prob: params.prob,
+            last_start: start,
+            last_end: end,
+        }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        if start >= self.last_end {
+            // The windows don't overlap, start from scratch.
+            self.sketch.clear();
+            for v in self.slice.get_unchecked(start..end) {
+                self.sketch.insert(v.to_f64().unwrap());
+            }
+        } else {
+            for v in self.slice.get_unchecked(self.last_start..start) {
+                self.sketch.remove(v.to_f64().unwrap());
+            }
+            for v in self.slice.get_unchecked(self.last_end..end) {
+                self.sketch.insert(v.to_f64().unwrap());
+            }
+        }
+        self.last_start = start;
+        self.last_end = end;
+        self.sketch.quantile(self.prob).and_then(T::from)
+    }
+}
+
+pub fn rolling_approx_quantile<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: DynArgs,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + Num,
+{
+    if weights.is_some() {
+        polars_bail!(InvalidOperation: "weights are not supported for an approximate rolling quantile")
+    }
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    rolling_apply_agg_window::<ApproxQuantileWindow<_>, _, _>(
+        values,
+        window_size,
+        min_periods,
+        offset_fn,
+        params,
+    )
+}
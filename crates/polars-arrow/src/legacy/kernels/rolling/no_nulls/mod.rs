@@ -1,3 +1,4 @@
+mod approx_quantile;
 mod mean;
 mod min_max;
 mod quantile;
@@ -5,6 +6,7 @@ mod sum;
 mod variance;
 use std::fmt::Debug;
 
+pub use approx_quantile::*;
 pub use mean::*;
 pub use min_max::*;
 use num_traits::{Float, Num, NumCast};
@@ -0,0 +1,102 @@
+use super::super::quantile_sketch::QuantileSketch;
+use super::*;
+
+pub struct ApproxQuantileWindow<'a, T> {
+    slice: &'a [T],
+    validity: &'a Bitmap,
+    sketch: QuantileSketch,
+    prob: f64,
+    last_start: usize,
+    last_end: usize,
+}
+
+impl<'a, T: NativeType + Float> ApproxQuantileWindow<'a, T> {
+    unsafe fn insert_range(&mut self, start: usize, end: usize) {
+        for idx in start..end {
+            if self.validity.get_bit_unchecked(idx) {
+                self.sketch
+                    .insert(self.slice.get_unchecked(idx).to_f64().unwrap());
+            }
+        }
+    }
+
+    unsafe fn remove_range(&mut self, start: usize, end: usize) {
+        for idx in start..end {
+            if self.validity.get_bit_unchecked(idx) {
+                self.sketch
+                    .remove(self.slice.get_unchecked(idx).to_f64().unwrap());
+            }
+        }
+    }
+}
+
+impl<'a, T: NativeType + Float> RollingAggWindowNulls<'a, T> for ApproxQuantileWindow<'a, T> {
+    unsafe fn new(
+        slice: &'a [T],
+        validity: &'a Bitmap,
+        start: usize,
+        end: usize,
+        params: DynArgs,
+    ) -> Self {
+        let params = params.unwrap();
+        let params = params
+            .downcast_ref::<RollingApproxQuantileParams>()
+            .unwrap();
+        let mut out = Self {
+            slice,
+            validity,
+            sketch: QuantileSketch::new(params.relative_accuracy),
+            prob: params.prob,
+            last_start: start,
+            last_end: end,
+        };
+        out.insert_range(start, end);
+        out
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        if start >= self.last_end {
+            // The windows don't overlap, start from scratch.
+            self.sketch.clear();
+            self.insert_range(start, end);
+        } else {
+            self.remove_range(self.last_start, start);
+            self.insert_range(self.last_end, end);
+        }
+        self.last_start = start;
+        self.last_end = end;
+        self.sketch.quantile(self.prob).and_then(T::from)
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        self.sketch.len() as usize >= min_periods
+    }
+}
+
+pub fn rolling_approx_quantile<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: DynArgs,
+) -> ArrayRef
+where
+    T: NativeType + IsFloat + Float,
+{
+    if weights.is_some() {
+        panic!("weights not yet supported on array with null values")
+    }
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    rolling_apply_agg_window::<ApproxQuantileWindow<_>, _, _>(
+        arr.values().as_slice(),
+        arr.validity().as_ref().unwrap(),
+        window_size,
+        min_periods,
+        offset_fn,
+        params,
+    )
+}
@@ -1,9 +1,11 @@
+mod approx_quantile;
 mod mean;
 mod min_max;
 mod quantile;
 mod sum;
 mod variance;
 
+pub use approx_quantile::*;
 pub use mean::*;
 pub use min_max::*;
 pub use quantile::*;
@@ -0,0 +1,263 @@
+//! A mergeable quantile sketch with a relative error guarantee.
+//!
+//! Values are counted in logarithmically sized buckets (as in DDSketch, Masson et al. 2019),
+//! such that every value in a bucket lies within a relative distance `relative_accuracy` of
+//! the value that represents the bucket. The sketch only consists of counts, so sketches of
+//! different segments can be merged and values can be removed again. This makes a rolling
+//! window amortized `O(1)` per step, independent of the window size.
+
+/// Counts of a contiguous range of bucket indices.
+#[derive(Clone, Debug, Default)]
+struct Store {
+    offset: i32,
+    counts: Vec<u64>,
+}
+
+impl Store {
+    fn add(&mut self, idx: i32, n: u64) {
+        if self.counts.is_empty() {
+            self.offset = idx;
+            self.counts.push(0);
+        } else if idx < self.offset {
+            let extra = (self.offset - idx) as usize;
+            self.counts.splice(0..0, std::iter::repeat(0).take(extra));
+            self.offset = idx;
+        } else if idx >= self.offset + self.counts.len() as i32 {
+            self.counts.resize((idx - self.offset) as usize + 1, 0);
+        }
+        self.counts[(idx - self.offset) as usize] += n;
+    }
+
+    fn remove(&mut self, idx: i32) {
+        let count = &mut self.counts[(idx - self.offset) as usize];
+        debug_assert!(*count > 0);
+        *count -= 1;
+    }
+
+    fn clear(&mut self) {
+        self.counts.clear();
+    }
+
+    fn merge(&mut self, other: &Store) {
+        for (i, &n) in other.counts.iter().enumerate() {
+            if n > 0 {
+                self.add(other.offset + i as i32, n);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Bucket {
+    NegInf,
+    Neg(i32),
+    Zero,
+    Pos(i32),
+    PosInf,
+    NaN,
+}
+
+#[derive(Clone, Debug)]
+pub struct QuantileSketch {
+    relative_accuracy: f64,
+    gamma: f64,
+    gamma_ln: f64,
+    negative: Store,
+    positive: Store,
+    zero: u64,
+    neg_inf: u64,
+    pos_inf: u64,
+    nan: u64,
+    count: u64,
+}
+
+impl QuantileSketch {
+    /// Create an empty sketch.
+    ///
+    /// Every quantile returned by the sketch is within `relative_accuracy` of the value at the
+    /// requested rank, i.e. `|estimate - exact| <= relative_accuracy * |exact|`. The memory of
+    /// the sketch grows with `ln(max / min) / relative_accuracy` of the absolute values seen.
+    ///
+    /// # Panics
+    /// If `relative_accuracy` is not in `(0, 1)`.
+    pub fn new(relative_accuracy: f64) -> Self {
+        assert!(
+            relative_accuracy > 0.0 && relative_accuracy < 1.0,
+            "relative accuracy must be in (0, 1)"
+        );
+        let gamma = (1.0 + relative_accuracy) / (1.0 - relative_accuracy);
+        Self {
+            relative_accuracy,
+            gamma,
+            gamma_ln: gamma.ln(),
+            negative: Store::default(),
+            positive: Store::default(),
+            zero: 0,
+            neg_inf: 0,
+            pos_inf: 0,
+            nan: 0,
+            count: 0,
+        }
+    }
+
+    pub fn relative_accuracy(&self) -> f64 {
+        self.relative_accuracy
+    }
+
+    /// Number of values in the sketch.
+    pub fn len(&self) -> u64 {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.negative.clear();
+        self.positive.clear();
+        self.zero = 0;
+        self.neg_inf = 0;
+        self.pos_inf = 0;
+        self.nan = 0;
+        self.count = 0;
+    }
+
+    pub fn insert(&mut self, value: f64) {
+        match self.bucket(value) {
+            Bucket::NegInf => self.neg_inf += 1,
+            Bucket::Neg(idx) => self.negative.add(idx, 1),
+            Bucket::Zero => self.zero += 1,
+            Bucket::Pos(idx) => self.positive.add(idx, 1),
+            Bucket::PosInf => self.pos_inf += 1,
+            Bucket::NaN => self.nan += 1,
+        }
+        self.count += 1;
+    }
+
+    /// Remove a value that was previously inserted.
+    pub fn remove(&mut self, value: f64) {
+        match self.bucket(value) {
+            Bucket::NegInf => self.neg_inf -= 1,
+            Bucket::Neg(idx) => self.negative.remove(idx),
+            Bucket::Zero => self.zero -= 1,
+            Bucket::Pos(idx) => self.positive.remove(idx),
+            Bucket::PosInf => self.pos_inf -= 1,
+            Bucket::NaN => self.nan -= 1,
+        }
+        self.count -= 1;
+    }
+
+    /// Add all values of `other` to this sketch.
+    ///
+    /// # Panics
+    /// If the sketches don't have the same relative accuracy.
+    pub fn merge(&mut self, other: &QuantileSketch) {
+        assert_eq!(
+            self.relative_accuracy, other.relative_accuracy,
+            "cannot merge sketches with a different relative accuracy"
+        );
+        self.negative.merge(&other.negative);
+        self.positive.merge(&other.positive);
+        self.zero += other.zero;
+        self.neg_inf += other.neg_inf;
+        self.pos_inf += other.pos_inf;
+        self.nan += other.nan;
+        self.count += other.count;
+    }
+
+    /// Estimate the `q` quantile, with the value at rank `q * (len - 1)` as reference.
+    ///
+    /// NaN values are ordered after all other values.
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        if self.count == 0 {
+            return None;
+        }
+        let rank = (q.clamp(0.0, 1.0) * (self.count - 1) as f64) as u64;
+        let mut seen = self.neg_inf;
+        if seen > rank {
+            return Some(f64::NEG_INFINITY);
+        }
+        // The largest negative bucket holds the most negative values.
+        for (i, &n) in self.negative.counts.iter().enumerate().rev() {
+            seen += n;
+            if seen > rank {
+                return Some(-self.value(self.negative.offset + i as i32));
+            }
+        }
+        seen += self.zero;
+        if seen > rank {
+            return Some(0.0);
+        }
+        for (i, &n) in self.positive.counts.iter().enumerate() {
+            seen += n;
+            if seen > rank {
+                return Some(self.value(self.positive.offset + i as i32));
+            }
+        }
+        seen += self.pos_inf;
+        if seen > rank {
+            return Some(f64::INFINITY);
+        }
+        Some(f64::NAN)
+    }
+
+    fn bucket(&self, value: f64) -> Bucket {
+        if value.is_nan() {
+            Bucket::NaN
+        } else if value == f64::INFINITY {
+            Bucket::PosInf
+        } else if value == f64::NEG_INFINITY {
+            Bucket::NegInf
+        } else if value.abs() < f64::MIN_POSITIVE {
+            Bucket::Zero
+        } else if value > 0.0 {
+            Bucket::Pos(self.index(value))
+        } else {
+            Bucket::Neg(self.index(-value))
+        }
+    }
+
+    // Bucket `i` holds the values in `(gamma^(i - 1), gamma^i]`.
+    fn index(&self, value: f64) -> i32 {
+        (value.ln() / self.gamma_ln).ceil() as i32
+    }
+
+    fn value(&self, idx: i32) -> f64 {
+        2.0 * (self.gamma_ln * idx as f64).exp() / (1.0 + self.gamma)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_quantile_sketch() {
+        let alpha = 0.01;
+        let values = (1..=1000)
+            .map(|v| (v as f64 - 300.0) * 1.5)
+            .collect::<Vec<_>>();
+
+        let mut sketch = QuantileSketch::new(alpha);
+        values.iter().for_each(|&v| sketch.insert(v));
+        for q in [0.0, 0.1, 0.25, 0.5, 0.9, 1.0] {
+            let exact = values[(q * (values.len() - 1) as f64) as usize];
+            let estimate = sketch.quantile(q).unwrap();
+            assert!((estimate - exact).abs() <= alpha * exact.abs() + 1e-12);
+        }
+
+        // Merging two halves is equal to inserting all values.
+        let mut left = QuantileSketch::new(alpha);
+        let mut right = QuantileSketch::new(alpha);
+        values[..400].iter().for_each(|&v| left.insert(v));
+        values[400..].iter().for_each(|&v| right.insert(v));
+        left.merge(&right);
+        assert_eq!(left.quantile(0.5), sketch.quantile(0.5));
+
+        // Removing values is the inverse of inserting them.
+        values[..400].iter().for_each(|&v| sketch.remove(v));
+        assert_eq!(sketch.len(), 600);
+        assert_eq!(sketch.quantile(0.5), right.quantile(0.5));
+    }
+}
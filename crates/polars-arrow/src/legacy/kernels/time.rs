@@ -10,6 +10,7 @@ use polars_error::{polars_bail, PolarsError};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Ambiguous {
     Earliest,
     Latest,
@@ -6,13 +6,13 @@ mod array;
 
 use arrow_format::ipc::planus::ReadAsRoot;
 use arrow_format::ipc::{Block, MessageRef, RecordBatchRef};
-use polars_error::{polars_bail, polars_err, to_compute_err, PolarsResult};
+use polars_error::{polars_bail, polars_ensure, polars_err, to_compute_err, PolarsResult};
 
 use crate::array::Array;
 use crate::datatypes::{ArrowDataType, Field};
 use crate::io::ipc::read::file::{get_dictionary_batch, get_record_batch};
 use crate::io::ipc::read::{
-    first_dict_field, Dictionaries, FileMetadata, IpcBuffer, Node, OutOfSpecKind,
+    first_dict_field, skip, Dictionaries, FileMetadata, IpcBuffer, Node, OutOfSpecKind,
 };
 use crate::io::ipc::{IpcField, CONTINUATION_MARKER};
 use crate::record_batch::RecordBatchT;
@@ -70,6 +70,8 @@ fn get_buffers_nodes(batch: RecordBatchRef) -> PolarsResult<(VecDeque<IpcBuffer>
     Ok((buffers, field_nodes))
 }
 
+/// Memory maps the arrays of a record batch, or only the arrays at the indices of `projection`
+/// in that order. The buffers of the other arrays are skipped.
 unsafe fn _mmap_record<T: AsRef<[u8]>>(
     fields: &[Field],
     ipc_fields: &[IpcField],
@@ -77,6 +79,7 @@ unsafe fn _mmap_record<T: AsRef<[u8]>>(
     batch: RecordBatchRef,
     offset: usize,
     dictionaries: &Dictionaries,
+    projection: Option<&[usize]>,
 ) -> PolarsResult<RecordBatchT<Box<dyn Array>>> {
     let (mut buffers, mut field_nodes) = get_buffers_nodes(batch)?;
     let mut variadic_buffer_counts = batch
@@ -85,25 +88,54 @@ unsafe fn _mmap_record<T: AsRef<[u8]>>(
         .map(|v| v.iter().map(|v| v as usize).collect::<VecDeque<usize>>())
         .unwrap_or_else(VecDeque::new);
 
-    fields
-        .iter()
-        .map(|f| &f.data_type)
-        .cloned()
-        .zip(ipc_fields)
-        .map(|(data_type, ipc_field)| {
-            array::mmap(
+    let mut selected = vec![projection.is_none(); fields.len()];
+    if let Some(projection) = projection {
+        for i in projection {
+            polars_ensure!(
+                *i < fields.len(),
+                OutOfBounds: "projected field {} is out of bounds for {} fields", i, fields.len()
+            );
+            selected[*i] = true;
+        }
+    }
+    // The fields after the last selected one don't have to be skipped.
+    let n_fields = selected.iter().rposition(|s| *s).map_or(0, |i| i + 1);
+
+    let mut arrays = Vec::with_capacity(n_fields);
+    for ((field, ipc_field), selected) in fields.iter().zip(ipc_fields).zip(selected) {
+        if arrays.len() == n_fields {
+            break;
+        }
+        if selected {
+            arrays.push(Some(array::mmap(
                 data.clone(),
                 offset,
-                data_type,
+                field.data_type.clone(),
                 ipc_field,
                 dictionaries,
                 &mut field_nodes,
                 &mut variadic_buffer_counts,
                 &mut buffers,
-            )
-        })
-        .collect::<PolarsResult<_>>()
-        .and_then(RecordBatchT::try_new)
+            )?));
+        } else {
+            skip(
+                &mut field_nodes,
+                &field.data_type,
+                &mut buffers,
+                &mut variadic_buffer_counts,
+            )?;
+            arrays.push(None);
+        }
+    }
+
+    let arrays = match projection {
+        Some(projection) => projection
+            .iter()
+            .map(|i| arrays[*i].clone().unwrap())
+            .collect(),
+        None => arrays.into_iter().map(Option::unwrap).collect(),
+    };
+    RecordBatchT::try_new(arrays)
 }
 
 unsafe fn _mmap_unchecked<T: AsRef<[u8]>>(
@@ -122,6 +154,7 @@ unsafe fn _mmap_unchecked<T: AsRef<[u8]>>(
         batch,
         offset,
         dictionaries,
+        None,
     )
 }
 
@@ -141,6 +174,25 @@ pub unsafe fn mmap_unchecked<T: AsRef<[u8]>>(
     dictionaries: &Dictionaries,
     data: Arc<T>,
     chunk: usize,
+) -> PolarsResult<RecordBatchT<Box<dyn Array>>> {
+    mmap_projected_unchecked(metadata, dictionaries, data, chunk, None)
+}
+
+/// Memory maps the columns at the indices of `projection` of a record batch from an IPC file
+/// into a [`RecordBatchT`], in the order of `projection`.
+///
+/// The buffers of the other columns are never touched, so a column that isn't projected costs
+/// nothing but skipping over its metadata.
+/// # Errors
+/// This function errors in the same cases as [`mmap_unchecked`].
+/// # Safety
+/// The caller must ensure that `data` contains a valid buffers, like for [`mmap_unchecked`].
+pub unsafe fn mmap_projected_unchecked<T: AsRef<[u8]>>(
+    metadata: &FileMetadata,
+    dictionaries: &Dictionaries,
+    data: Arc<T>,
+    chunk: usize,
+    projection: Option<&[usize]>,
 ) -> PolarsResult<RecordBatchT<Box<dyn Array>>> {
     let block = metadata.blocks[chunk];
 
@@ -153,9 +205,26 @@ pub unsafe fn mmap_unchecked<T: AsRef<[u8]>>(
         batch,
         offset,
         dictionaries,
+        projection,
     )
 }
 
+/// The number of rows of a record batch of an IPC file, read from the header of the batch
+/// without mapping any of its buffers.
+pub fn mmap_batch_len<T: AsRef<[u8]>>(
+    metadata: &FileMetadata,
+    data: &T,
+    chunk: usize,
+) -> PolarsResult<usize> {
+    let (message, _) = read_message(data.as_ref(), metadata.blocks[chunk])?;
+    let batch = get_record_batch(message)?;
+    let len = batch
+        .length()
+        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferRecordBatches(err)))?;
+    len.try_into()
+        .map_err(|_| polars_err!(oos = OutOfSpecKind::NegativeFooterLength))
+}
+
 unsafe fn mmap_dictionary<T: AsRef<[u8]>>(
     metadata: &FileMetadata,
     data: Arc<T>,
@@ -194,6 +263,7 @@ unsafe fn mmap_dictionary<T: AsRef<[u8]>>(
         batch,
         offset,
         dictionaries,
+        None,
     )?;
 
     dictionaries.insert(id, chunk.into_arrays().pop().unwrap());
@@ -0,0 +1,113 @@
+use arrow::array::{Array, PrimitiveArray};
+use arrow::types::NativeType;
+use num_traits::PrimInt;
+
+use crate::arity::prim_unary_values;
+
+// Low-level bitwise kernel for integer arrays.
+pub trait BitwiseKernel: Sized + Array {
+    type Scalar;
+
+    fn bitwise_not(self) -> Self;
+    /// Shift left by `n` bits, shifts by the bit width or more give zero.
+    fn shift_left(self, n: u32) -> Self;
+    /// Shift right by `n` bits, arithmetic for signed types. Shifts by the bit width or more
+    /// give zero, or minus one for negative values.
+    fn shift_right(self, n: u32) -> Self;
+
+    /// The bitwise and of the non-null values, `None` if there are none.
+    fn reduce_and(&self) -> Option<Self::Scalar>;
+    /// The bitwise or of the non-null values, `None` if there are none.
+    fn reduce_or(&self) -> Option<Self::Scalar>;
+}
+
+#[inline(always)]
+fn shl<T: PrimInt>(x: T, n: u32) -> T {
+    if (n as usize) < std::mem::size_of::<T>() * 8 {
+        x << n as usize
+    } else {
+        T::zero()
+    }
+}
+
+#[inline(always)]
+fn shr<T: PrimInt>(x: T, n: u32) -> T {
+    if (n as usize) < std::mem::size_of::<T>() * 8 {
+        x >> n as usize
+    } else if x < T::zero() {
+        !T::zero()
+    } else {
+        T::zero()
+    }
+}
+
+fn reduce_vals<T, F>(v: &PrimitiveArray<T>, f: F) -> Option<T>
+where
+    T: NativeType,
+    F: Fn(T, T) -> T,
+{
+    if v.null_count() == 0 {
+        v.values_iter().copied().reduce(f)
+    } else {
+        v.non_null_values_iter().reduce(f)
+    }
+}
+
+impl<T: NativeType + PrimInt> BitwiseKernel for PrimitiveArray<T> {
+    type Scalar = T;
+
+    fn bitwise_not(self) -> Self {
+        prim_unary_values(self, |x| !x)
+    }
+
+    fn shift_left(self, n: u32) -> Self {
+        prim_unary_values(self, |x| shl(x, n))
+    }
+
+    fn shift_right(self, n: u32) -> Self {
+        prim_unary_values(self, |x| shr(x, n))
+    }
+
+    fn reduce_and(&self) -> Option<T> {
+        reduce_vals(self, |a, b| a & b)
+    }
+
+    fn reduce_or(&self) -> Option<T> {
+        reduce_vals(self, |a, b| a | b)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_shifts() {
+        let arr = PrimitiveArray::from([Some(-8i8), Some(3), None]);
+        assert_eq!(
+            arr.clone().shift_left(2),
+            PrimitiveArray::from([Some(-32i8), Some(12), None])
+        );
+        assert_eq!(
+            arr.clone().shift_right(2),
+            PrimitiveArray::from([Some(-2i8), Some(0), None])
+        );
+        assert_eq!(
+            arr.clone().shift_left(8),
+            PrimitiveArray::from([Some(0i8), Some(0), None])
+        );
+        assert_eq!(
+            arr.shift_right(9),
+            PrimitiveArray::from([Some(-1i8), Some(0), None])
+        );
+    }
+
+    #[test]
+    fn test_reduce() {
+        let arr = PrimitiveArray::from([Some(0b1101u8), None, Some(0b0111)]);
+        assert_eq!(arr.reduce_and(), Some(0b0101));
+        assert_eq!(arr.reduce_or(), Some(0b1111));
+        assert_eq!(arr.clone().bitwise_not().reduce_and(), Some(0b1111_0000));
+        assert_eq!(PrimitiveArray::<u8>::from([None]).reduce_or(), None);
+    }
+}
@@ -8,6 +8,7 @@
 use arrow::types::NativeType;
 
 pub mod arithmetic;
+pub mod bitwise;
 pub mod comparisons;
 pub mod distinct_count;
 pub mod filter;
@@ -1,6 +1,8 @@
 //! Implementations of the ChunkCast Trait.
 
-use arrow::compute::cast::CastOptionsImpl;
+use arrow::compute::cast::{
+    binary_to_utf8, binary_to_utf8_unchecked, utf8_to_binary, utf8_to_utf8view, CastOptionsImpl,
+};
 #[cfg(feature = "serde-lazy")]
 use serde::{Deserialize, Serialize};
 
@@ -75,6 +77,12 @@ fn cast_impl_inner(
     dtype: &DataType,
     options: CastOptions,
 ) -> PolarsResult<Series> {
+    // The physical `UInt16` holds the bits of the values, so cast the values to `f32`.
+    #[cfg(feature = "dtype-f16")]
+    if dtype == &DataType::Float16 {
+        let out = cast_impl_inner(name, chunks, &DataType::Float32, options)?;
+        return Ok(out.f32().unwrap().to_float16().into_series());
+    }
     let chunks = cast_chunks(chunks, &dtype.to_physical(), options)?;
     let out = Series::try_from((name, chunks))?;
     use DataType::*;
@@ -257,6 +265,13 @@ where
                     polars_bail!(ComputeError: "cannot cast numeric types to 'Categorical'");
                 }
             },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 if self.dtype() == &DataType::UInt16 => {
+                // SAFETY:
+                // we are guarded by the type system.
+                let ca = unsafe { &*(self as *const ChunkedArray<T> as *const UInt16Chunked) };
+                Ok(ca.clone().into_float16().into_series())
+            },
             _ => self.cast_impl(data_type, CastOptions::Overflowing),
         }
     }
@@ -319,6 +334,7 @@ impl ChunkCast for StringChunked {
                     polars_bail!(ComputeError: "expected 'precision' or 'scale' when casting to Decimal")
                 },
             },
+            DataType::Binary => Ok(self.as_binary().into_series()),
             #[cfg(feature = "dtype-date")]
             DataType::Date => {
                 let result = cast_chunks(&self.chunks, data_type, options)?;
@@ -417,6 +433,47 @@ impl ChunkCast for BinaryChunked {
     }
 }
 
+impl BinaryOffsetChunked {
+    /// Reinterpret the chunks as [`LargeStringArray`]s, reusing the offsets and values.
+    ///
+    /// # Errors
+    /// Errors if any of the values is not valid UTF-8.
+    pub fn to_large_utf8(&self) -> PolarsResult<Vec<LargeStringArray>> {
+        self.downcast_iter()
+            .map(|arr| binary_to_utf8(arr, ArrowDataType::LargeUtf8))
+            .collect()
+    }
+
+    /// Reinterpret the chunks as [`LargeStringArray`]s without validating UTF-8.
+    ///
+    /// # Safety
+    /// The caller must ensure the values are valid UTF-8.
+    pub unsafe fn to_large_utf8_unchecked(&self) -> Vec<LargeStringArray> {
+        self.downcast_iter()
+            .map(|arr| binary_to_utf8_unchecked(arr, ArrowDataType::LargeUtf8))
+            .collect()
+    }
+
+    /// Create a [`BinaryOffsetChunked`] from [`LargeStringArray`]s, reusing the offsets and
+    /// values.
+    pub fn from_large_utf8<I>(name: &str, chunks: I) -> Self
+    where
+        I: IntoIterator<Item = LargeStringArray>,
+    {
+        let chunks = chunks
+            .into_iter()
+            .map(|arr| utf8_to_binary(&arr, ArrowDataType::LargeBinary));
+        Self::from_chunk_iter(name, chunks)
+    }
+
+    /// Create a [`StringChunked`] whose views point into the values of `chunks`, so that the
+    /// bytes aren't copied.
+    fn large_utf8_to_string(&self, chunks: Vec<LargeStringArray>) -> Series {
+        let chunks = chunks.iter().map(utf8_to_utf8view);
+        StringChunked::from_chunk_iter(self.name(), chunks).into_series()
+    }
+}
+
 impl ChunkCast for BinaryOffsetChunked {
     fn cast_with_options(
         &self,
@@ -428,12 +485,16 @@ impl ChunkCast for BinaryOffsetChunked {
             DataType::Struct(fields) => {
                 cast_single_to_struct(self.name(), &self.chunks, fields, options)
             },
+            DataType::String => Ok(self.large_utf8_to_string(self.to_large_utf8()?)),
             _ => cast_impl(self.name(), &self.chunks, data_type, options),
         }
     }
 
     unsafe fn cast_unchecked(&self, data_type: &DataType) -> PolarsResult<Series> {
-        self.cast_with_options(data_type, CastOptions::Overflowing)
+        match data_type {
+            DataType::String => Ok(self.large_utf8_to_string(self.to_large_utf8_unchecked())),
+            _ => self.cast_with_options(data_type, CastOptions::Overflowing),
+        }
     }
 }
 
@@ -511,6 +572,9 @@ impl ChunkCast for ListChunked {
                     ))
                 }
             },
+            Binary if self.inner_dtype() == &UInt8 => {
+                cast_impl(self.name(), &self.chunks, data_type, options)
+            },
             _ => {
                 polars_bail!(
                     InvalidOperation: "cannot cast List type (inner: '{:?}', to: '{:?}')",
@@ -582,6 +646,9 @@ impl ChunkCast for ArrayChunked {
                     ))
                 }
             },
+            Binary if self.inner_dtype() == &UInt8 => {
+                cast_impl(self.name(), &self.chunks, data_type, options)
+            },
             _ => {
                 polars_bail!(
                     InvalidOperation: "cannot cast Array type (inner: '{:?}', to: '{:?}')",
@@ -704,6 +771,109 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "dtype-array")]
+    fn test_cast_binary_bytes() -> PolarsResult<()> {
+        let values: [Option<&[u8]>; 3] = [
+            Some(b"a value of sixteen"),
+            None,
+            Some(b"another long value"),
+        ];
+        let ca = BinaryChunked::new("a", values);
+        let expected = ca.clone().into_series();
+        let data = ca.downcast_iter().next().unwrap().data_buffers()[0].as_ptr();
+
+        let list_dtype = DataType::List(Box::new(DataType::UInt8));
+        let list = ca.cast(&list_dtype)?;
+        // The bytes are reused, not copied.
+        let list_values = list
+            .list()?
+            .downcast_iter()
+            .next()
+            .unwrap()
+            .values()
+            .clone();
+        let list_values = list_values.as_any().downcast_ref::<UInt8Array>().unwrap();
+        assert_eq!(list_values.values().as_ptr(), data);
+        assert!(list.cast(&DataType::Binary)?.equals_missing(&expected));
+
+        let array_dtype = DataType::Array(Box::new(DataType::UInt8), 18);
+        let array = ca.cast(&array_dtype)?;
+        assert_eq!(array.dtype(), &array_dtype);
+        assert_eq!(array.null_count(), 1);
+        assert!(array.cast(&DataType::Binary)?.equals_missing(&expected));
+        let list = array.cast(&list_dtype)?;
+        assert!(list.cast(&DataType::Binary)?.equals_missing(&expected));
+
+        let array_dtype = DataType::Array(Box::new(DataType::UInt8), 4);
+        assert!(ca.cast(&array_dtype).is_err());
+        let short = BinaryChunked::new("a", [b"ab".as_slice(), b"cd"]).into_series();
+        let array = short.cast(&DataType::Array(Box::new(DataType::UInt8), 2))?;
+        assert!(array.cast(&DataType::Binary)?.equals_missing(&short));
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_string_binary_zero_copy() -> PolarsResult<()> {
+        let ca = StringChunked::new("a", [Some("a value longer than twelve"), None, Some("b")]);
+        let data = ca.downcast_iter().next().unwrap().data_buffers()[0].as_ptr();
+
+        let bin = ca.cast(&DataType::Binary)?;
+        let bin_arr = bin.binary()?.downcast_iter().next().unwrap();
+        assert_eq!(bin_arr.data_buffers()[0].as_ptr(), data);
+        assert_eq!(bin.null_count(), 1);
+
+        let back = bin.cast(&DataType::String)?;
+        let back_arr = back.str()?.downcast_iter().next().unwrap();
+        assert_eq!(back_arr.data_buffers()[0].as_ptr(), data);
+        assert!(back.equals_missing(&ca.into_series()));
+
+        let invalid = BinaryChunked::new("a", [b"\xff".as_slice()]);
+        assert!(invalid.cast(&DataType::String).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_binary_offset_large_utf8() -> PolarsResult<()> {
+        let arr = LargeBinaryArray::from_iter([Some(b"ab".as_slice()), None, Some(b"c")]);
+        let ca = BinaryOffsetChunked::with_chunk("", arr);
+        let data = ca.downcast_iter().next().unwrap().values().as_ptr();
+
+        let utf8 = ca.to_large_utf8()?;
+        assert_eq!(utf8[0].values().as_ptr(), data);
+        assert_eq!(
+            utf8[0].validity(),
+            ca.downcast_iter().next().unwrap().validity()
+        );
+
+        let round_trip = BinaryOffsetChunked::from_large_utf8("", utf8);
+        assert_eq!(
+            round_trip.downcast_iter().next().unwrap().values().as_ptr(),
+            data
+        );
+
+        let s = ca.cast(&DataType::String)?;
+        let expected = StringChunked::new("", [Some("ab"), None, Some("c")]).into_series();
+        assert!(s.equals_missing(&expected));
+
+        // The views of the strings point into the values of the binary array.
+        let long = b"a value longer than twelve".as_slice();
+        let arr = LargeBinaryArray::from_iter([Some(long), None]);
+        let ca = BinaryOffsetChunked::with_chunk("", arr);
+        let data = ca.downcast_iter().next().unwrap().values().as_ptr();
+        let s = ca.cast(&DataType::String)?;
+        let arr = s.str()?.downcast_iter().next().unwrap();
+        assert_eq!(arr.data_buffers()[0].as_ptr(), data);
+        assert_eq!(arr.value(0).as_bytes(), long);
+        assert!(arr.is_null(1));
+
+        let arr = LargeBinaryArray::from_iter([Some(b"\xff".as_slice())]);
+        let invalid = BinaryOffsetChunked::with_chunk("", arr);
+        assert!(invalid.to_large_utf8().is_err());
+        assert!(invalid.cast(&DataType::String).is_err());
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "dtype-categorical")]
     fn test_cast_noop() {
@@ -1,8 +1,6 @@
 use std::borrow::Cow;
 
 use super::*;
-use crate::series::IsSorted;
-use crate::utils::align_chunks_binary;
 
 fn slots_to_mut(slots: &Utf8ViewArray) -> MutablePlString {
     slots.clone().make_mut()
@@ -131,6 +129,69 @@ fn merge_local_rhs_categorical<'a>(
     ))
 }
 
+/// Whether the mappings can only be merged by re-encoding both sides: one of them is built under
+/// the global string cache and the other isn't, or they are built under different string caches.
+fn is_mixed_source(rev_map_left: &RevMapping, rev_map_right: &RevMapping) -> bool {
+    match (rev_map_left, rev_map_right) {
+        (RevMapping::Global(_, _, idl), RevMapping::Global(_, _, idr)) => idl != idr,
+        (RevMapping::Local(_, _), RevMapping::Local(_, _)) => false,
+        _ => true,
+    }
+}
+
+/// The indices of the physicals of `ca` into the categories of its [`RevMapping`].
+fn local_physical(ca: &CategoricalChunked) -> Cow<'_, UInt32Chunked> {
+    match &**ca.get_rev_map() {
+        RevMapping::Global(map, _, _) => Cow::Owned(
+            ca.physical()
+                .apply(|opt_v| opt_v.map(|v| *map.get(&v).unwrap())),
+        ),
+        RevMapping::Local(_, _) => Cow::Borrowed(ca.physical()),
+    }
+}
+
+fn merge_mixed_categoricals(
+    ca_left: &CategoricalChunked,
+    ca_right: &CategoricalChunked,
+) -> (UInt32Chunked, UInt32Chunked, Arc<RevMapping>) {
+    // Both sides are re-encoded to a local mapping that starts with the categories of the left
+    // side, followed by the categories that only occur on the right side.
+
+    polars_warn!(
+        CategoricalRemappingWarning,
+        "Categoricals from different string caches have different encodings, expensive \
+    re-encoding is done to perform this merge operation. Consider creating both under \
+    the same StringCache or using an Enum type if the categories are known in advance"
+    );
+
+    let cats_left = ca_left.get_rev_map().get_categories();
+    let cats_right = ca_right.get_rev_map().get_categories();
+
+    let cats_left_hashmap = PlHashMap::from_iter(
+        cats_left
+            .values_iter()
+            .enumerate()
+            .map(|(k, v)| (v, k as u32)),
+    );
+    let mut new_categories = slots_to_mut(cats_left);
+    let idx_mapping = cats_right
+        .values_iter()
+        .map(|s| match cats_left_hashmap.get(&s) {
+            Some(v) => *v,
+            None => {
+                new_categories.push(Some(s));
+                new_categories.len() as u32 - 1
+            },
+        })
+        .collect::<Vec<_>>();
+
+    let lhs_physical = local_physical(ca_left).into_owned();
+    let rhs_physical =
+        local_physical(ca_right).apply(|opt_v| opt_v.map(|v| idx_mapping[v as usize]));
+    let new_rev_map = Arc::new(RevMapping::build_local(new_categories.into()));
+    (lhs_physical, rhs_physical, new_rev_map)
+}
+
 pub trait CategoricalMergeOperation {
     fn finish(self, lhs: &UInt32Chunked, rhs: &UInt32Chunked) -> PolarsResult<UInt32Chunked>;
 }
@@ -174,6 +235,11 @@ pub fn call_categorical_merge_operation<I: CategoricalMergeOperation>(
         {
             polars_bail!(ComputeError: "can not merge incompatible Enum types")
         },
+        _ if !cat_left.is_enum() && !cat_right.is_enum() => {
+            let (lhs_physical, rhs_physical, rev_map) =
+                merge_mixed_categoricals(cat_left, cat_right);
+            (merge_ops.finish(&lhs_physical, &rhs_physical)?, rev_map)
+        },
         _ => polars_bail!(string_cache_mismatch),
     };
     // SAFETY: physical and rev map are correctly constructed above
@@ -199,6 +265,32 @@ pub fn make_categoricals_compatible(
     ca_left: &CategoricalChunked,
     ca_right: &CategoricalChunked,
 ) -> PolarsResult<(CategoricalChunked, CategoricalChunked)> {
+    // Both physicals change if the mappings come from different sources
+    if !ca_left.is_enum()
+        && !ca_right.is_enum()
+        && is_mixed_source(ca_left.get_rev_map(), ca_right.get_rev_map())
+    {
+        let (lhs_physical, rhs_physical, rev_map) = merge_mixed_categoricals(ca_left, ca_right);
+        let ordering = ca_left.get_ordering();
+        // SAFETY: both physicals are re-encoded to the new rev map
+        unsafe {
+            return Ok((
+                CategoricalChunked::from_cats_and_rev_map_unchecked(
+                    lhs_physical,
+                    rev_map.clone(),
+                    false,
+                    ordering,
+                ),
+                CategoricalChunked::from_cats_and_rev_map_unchecked(
+                    rhs_physical,
+                    rev_map,
+                    false,
+                    ordering,
+                ),
+            ));
+        }
+    }
+
     let new_ca_right = call_categorical_merge_operation(ca_left, ca_right, DoNothing)?;
 
     // Alter rev map of left
@@ -214,41 +306,39 @@ pub fn make_categoricals_compatible(
     Ok((new_ca_left, new_ca_right))
 }
 
+/// Make the categoricals of two lists compatible, like [`make_categoricals_compatible`].
+///
+/// The inner categoricals may be nested in further lists. The categoricals of both sides are
+/// re-encoded if they come from different string caches.
 pub fn make_list_categoricals_compatible(
-    mut list_ca_left: ListChunked,
+    list_ca_left: ListChunked,
     list_ca_right: ListChunked,
 ) -> PolarsResult<(ListChunked, ListChunked)> {
-    // Make categoricals compatible
-
-    let cat_left = list_ca_left.get_inner();
-    let cat_right = list_ca_right.get_inner();
-    let (cat_left, cat_right) =
-        make_categoricals_compatible(cat_left.categorical()?, cat_right.categorical()?)?;
-
-    // we only appended categories to the rev_map at the end, so only change the inner dtype
-    list_ca_left.set_inner_dtype(cat_left.dtype().clone());
-
-    // We changed the physicals and the rev_map, offsets and validity buffers are still good
-    let (list_ca_right, cat_physical): (Cow<ListChunked>, Cow<UInt32Chunked>) =
-        align_chunks_binary(&list_ca_right, cat_right.physical());
-    let mut list_ca_right = list_ca_right.into_owned();
-    // SAFETY:
-    // Chunks are aligned, length / dtype remains correct
-    unsafe {
-        list_ca_right
-            .downcast_iter_mut()
-            .zip(cat_physical.chunks())
-            .for_each(|(arr, new_phys)| {
-                *arr = ListArray::new(
-                    arr.data_type().clone(),
-                    arr.offsets().clone(),
-                    new_phys.clone(),
-                    arr.validity().cloned(),
-                )
-            });
-    }
-    // reset the sorted flag and add extra categories back in
-    list_ca_right.set_sorted_flag(IsSorted::Not);
-    list_ca_right.set_inner_dtype(cat_right.dtype().clone());
+    // `apply_to_inner` replaces the values of the rechunked list.
+    let list_ca_left = list_ca_left.rechunk();
+    let list_ca_right = list_ca_right.rechunk();
+    let inner_left = list_ca_left.get_inner();
+    let inner_right = list_ca_right.get_inner();
+
+    let (inner_left, inner_right) = match (inner_left.dtype(), inner_right.dtype()) {
+        (DataType::List(_), DataType::List(_)) => {
+            let (left, right) = make_list_categoricals_compatible(
+                inner_left.list()?.clone(),
+                inner_right.list()?.clone(),
+            )?;
+            (left.into_series(), right.into_series())
+        },
+        _ => {
+            let (left, right) = make_categoricals_compatible(
+                inner_left.categorical()?,
+                inner_right.categorical()?,
+            )?;
+            (left.into_series(), right.into_series())
+        },
+    };
+
+    // The offsets and validity buffers are unchanged.
+    let list_ca_left = list_ca_left.apply_to_inner(&|_| Ok(inner_left.clone()))?;
+    let list_ca_right = list_ca_right.apply_to_inner(&|_| Ok(inner_right.clone()))?;
     Ok((list_ca_left, list_ca_right))
 }
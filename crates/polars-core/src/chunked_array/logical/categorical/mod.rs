@@ -174,6 +174,34 @@ impl CategoricalChunked {
         }
     }
 
+    /// Append the `new_categories` that are not categories yet to the categories of an Enum.
+    ///
+    /// The physical codes are preserved, as the existing categories keep their position.
+    pub fn extend_enum_categories(&self, new_categories: &Utf8ViewArray) -> PolarsResult<Self> {
+        let DataType::Enum(Some(rev_map), _) = extend_enum_data_type(self.dtype(), new_categories)?
+        else {
+            unreachable!()
+        };
+        let mut out = self.clone();
+        // SAFETY: the existing categories keep their index.
+        unsafe { out.set_rev_map(rev_map, false) };
+        Ok(out)
+    }
+
+    /// Reorder the categories of an Enum, remapping the physical codes.
+    ///
+    /// `categories` must hold every category of the Enum exactly once.
+    pub fn reorder_enum_categories(&self, categories: &Utf8ViewArray) -> PolarsResult<Self> {
+        let DataType::Enum(Some(rev_map), _) = reorder_enum_data_type(self.dtype(), categories)?
+        else {
+            unreachable!()
+        };
+        let RevMapping::Local(categories, hash) = rev_map.as_ref() else {
+            unreachable!()
+        };
+        Ok(self.to_enum(categories, *hash))
+    }
+
     pub(crate) fn get_flags(&self) -> MetadataFlags {
         self.physical().get_flags()
     }
@@ -492,6 +520,30 @@ mod test {
         assert_eq!(appended.str_value(5).unwrap(), "y");
     }
 
+    #[test]
+    fn test_append_categorical_different_sources() {
+        let _lock = SINGLE_LOCK.lock();
+        disable_string_cache();
+        enable_string_cache();
+
+        let mut s1 = Series::new("1", vec!["a", "b", "c"])
+            .cast(&DataType::Categorical(None, Default::default()))
+            .unwrap();
+        disable_string_cache();
+        let s2 = Series::new("2", vec!["c", "x", "a"])
+            .cast(&DataType::Categorical(None, Default::default()))
+            .unwrap();
+        let appended = s1.append(&s2).unwrap();
+        let expected = Series::new("", vec!["a", "b", "c", "c", "x", "a"]);
+        assert_eq!(&appended.cast(&DataType::String).unwrap(), &expected);
+        let categories = appended
+            .categorical()
+            .unwrap()
+            .get_rev_map()
+            .get_categories();
+        assert_eq!(categories.len(), 4);
+    }
+
     #[test]
     fn test_fast_unique() {
         let _lock = SINGLE_LOCK.lock();
@@ -0,0 +1,87 @@
+use arrow::types::f16;
+
+use super::*;
+use crate::prelude::*;
+
+/// Half precision floats, stored as the bits of the `f16` values.
+pub type Float16Chunked = Logical<Float16Type, UInt16Type>;
+
+impl From<UInt16Chunked> for Float16Chunked {
+    fn from(ca: UInt16Chunked) -> Self {
+        Float16Chunked::new_logical(ca)
+    }
+}
+
+impl UInt16Chunked {
+    /// Interpret the values as the bits of `f16` values.
+    pub fn into_float16(self) -> Float16Chunked {
+        Float16Chunked::new_logical(self)
+    }
+}
+
+impl Float32Chunked {
+    /// Round the values to the nearest `f16` value.
+    pub fn to_float16(&self) -> Float16Chunked {
+        self.apply_values_generic::<UInt16Type, _, _>(|v| f16::from_f32(v).to_bits())
+            .into_float16()
+    }
+}
+
+impl Float16Chunked {
+    /// Convert the values to `f32`, which is lossless.
+    pub fn to_float32(&self) -> Float32Chunked {
+        self.0
+            .apply_values_generic::<Float32Type, _, _>(|v| f16::from_bits(v).to_f32())
+    }
+
+    /// Map `-0.0` to `0.0` and all `NaN`s to the same `NaN`, so that equal values have
+    /// equal bits.
+    pub fn to_canonical(&self) -> Self {
+        self.0.apply_values(canonical_f16_bits).into_float16()
+    }
+}
+
+/// The bits of the canonical form of an `f16`, see `polars_utils::total_ord::canonical_f32`.
+#[inline]
+fn canonical_f16_bits(bits: u16) -> u16 {
+    match bits & 0x7fff {
+        // Positive or negative zero.
+        0 => 0,
+        // All exponent bits set with a non-zero mantissa is a `NaN`.
+        abs if abs > 0x7c00 => 0x7e00,
+        _ => bits,
+    }
+}
+
+fn to_f32_any_value(av: AnyValue<'_>) -> AnyValue<'_> {
+    match av {
+        AnyValue::UInt16(v) => AnyValue::Float32(f16::from_bits(v).to_f32()),
+        av => av,
+    }
+}
+
+impl LogicalType for Float16Chunked {
+    fn dtype(&self) -> &'static DataType {
+        &DataType::Float16
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.0.get_any_value(i).map(to_f32_any_value)
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        to_f32_any_value(self.0.get_any_value_unchecked(i))
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Float16 => Ok(self.clone().into_series()),
+            // All other casts go through `f32`, which holds every `f16` value.
+            dt => self.to_float32().cast_with_options(dt, cast_options),
+        }
+    }
+}
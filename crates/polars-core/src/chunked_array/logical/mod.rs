@@ -14,6 +14,10 @@ pub use decimal::*;
 mod duration;
 #[cfg(feature = "dtype-duration")]
 pub use duration::*;
+#[cfg(feature = "dtype-f16")]
+mod float16;
+#[cfg(feature = "dtype-f16")]
+pub use float16::*;
 #[cfg(feature = "dtype-categorical")]
 pub mod categorical;
 #[cfg(feature = "dtype-struct")]
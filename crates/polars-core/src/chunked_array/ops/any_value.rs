@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-f16")]
+use arrow::types::f16;
 #[cfg(feature = "dtype-categorical")]
 use polars_utils::sync::SyncPtr;
 
@@ -93,6 +95,12 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             let v = arr.value_unchecked(idx);
             AnyValue::Datetime(v, *tu, tz)
         },
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            let arr = &*(arr as *const dyn Array as *const UInt16Array);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Float32(f16::from_bits(v).to_f32())
+        },
         #[cfg(feature = "dtype-date")]
         DataType::Date => {
             let arr = &*(arr as *const dyn Array as *const Int32Array);
@@ -148,7 +148,19 @@ where
 #[doc(hidden)]
 impl ListChunked {
     pub fn append(&mut self, other: &Self) -> PolarsResult<()> {
-        let dtype = merge_dtypes(self.dtype(), other.dtype())?;
+        let dtype = match merge_dtypes(self.dtype(), other.dtype()) {
+            Ok(dtype) => dtype,
+            // Categoricals from different string caches are re-encoded to a shared mapping.
+            #[cfg(feature = "dtype-categorical")]
+            Err(_) if self.inner_dtype().contains_categoricals() => {
+                let (left, right) = make_list_categoricals_compatible(self.clone(), other.clone())?;
+                *self = left;
+                // Don't re-encode again if the re-encoded lists still can't be merged.
+                merge_dtypes(self.dtype(), right.dtype())?;
+                return self.append(&right);
+            },
+            Err(e) => return Err(e),
+        };
         self.field = Arc::new(Field::new(self.name(), dtype));
 
         let len = self.len();
@@ -199,6 +199,33 @@ impl<T: PolarsNumericType> ChunkedArray<T> {
         self.compute_len();
         self.set_sorted_flag(IsSorted::Not);
     }
+
+    /// Mutate the values of every chunk with `f`.
+    ///
+    /// The values are mutated in place if their buffers are not shared with another array.
+    /// Otherwise they are copied first, so that other arrays are not affected. The values of
+    /// null slots are passed to `f` as well, but the validity doesn't change.
+    pub fn apply_slices_mut<F>(&mut self, mut f: F) -> Mutation
+    where
+        F: FnMut(&mut [T::Native]),
+    {
+        let mut mutation = Mutation::InPlace;
+        // SAFETY: we don't change the lengths and reset the metadata below.
+        for arr in unsafe { self.downcast_iter_mut() } {
+            if let Some(values) = arr.get_mut_values() {
+                f(values);
+            } else {
+                let mut values = arr.values().to_vec();
+                f(&mut values);
+                let validity = arr.validity().cloned();
+                *arr = PrimitiveArray::new(arr.data_type().clone(), values.into(), validity);
+                mutation = Mutation::Copied;
+            }
+        }
+        // The sortedness and statistics may no longer hold.
+        self.md = None;
+        mutation
+    }
 }
 
 impl<'a, T> ChunkApply<'a, T::Native> for ChunkedArray<T>
@@ -109,11 +109,50 @@ unsafe fn target_get_unchecked<'a, A: StaticArray>(
     arr.get_unchecked(arr_idx)
 }
 
+/// Resolves indices that are sorted in ascending order to their chunk by walking the chunks
+/// alongside the indices, instead of searching the chunk of every index. This also works for
+/// any number of chunks.
+struct SortedChunkWalk<'a, 'b, A: StaticArray> {
+    targets: &'b [&'a A],
+    chunk_idx: usize,
+    chunk_start: IdxSize,
+    chunk_end: IdxSize,
+}
+
+impl<'a, 'b, A: StaticArray> SortedChunkWalk<'a, 'b, A> {
+    fn new(targets: &'b [&'a A]) -> Self {
+        Self {
+            targets,
+            chunk_idx: 0,
+            chunk_start: 0,
+            chunk_end: targets[0].len() as IdxSize,
+        }
+    }
+
+    /// # Safety
+    /// `idx` must be in bounds and not smaller than the previously resolved index.
+    #[inline]
+    unsafe fn resolve(&mut self, idx: IdxSize) -> (&'a A, usize) {
+        while idx >= self.chunk_end {
+            self.chunk_idx += 1;
+            self.chunk_start = self.chunk_end;
+            self.chunk_end += self.targets.get_unchecked(self.chunk_idx).len() as IdxSize;
+        }
+        let arr = *self.targets.get_unchecked(self.chunk_idx);
+        (arr, (idx - self.chunk_start) as usize)
+    }
+}
+
+fn is_ascending(indices: &[IdxSize]) -> bool {
+    indices.windows(2).all(|w| w[0] <= w[1])
+}
+
 unsafe fn gather_idx_array_unchecked<A: StaticArray>(
     dtype: ArrowDataType,
     targets: &[&A],
     has_nulls: bool,
     indices: &[IdxSize],
+    sorted: bool,
 ) -> A {
     let it = indices.iter().copied();
     if targets.len() == 1 {
@@ -129,6 +168,21 @@ unsafe fn gather_idx_array_unchecked<A: StaticArray>(
             it.map(|i| target.value_unchecked(i as usize))
                 .collect_arr_trusted_with_dtype(dtype)
         }
+    } else if sorted {
+        let mut walk = SortedChunkWalk::new(targets);
+        if has_nulls {
+            it.map(|i| {
+                let (arr, i) = walk.resolve(i);
+                arr.get_unchecked(i)
+            })
+            .collect_arr_trusted_with_dtype(dtype)
+        } else {
+            it.map(|i| {
+                let (arr, i) = walk.resolve(i);
+                arr.value_unchecked(i)
+            })
+            .collect_arr_trusted_with_dtype(dtype)
+        }
     } else {
         let cumlens = cumulative_lengths(targets);
         if has_nulls {
@@ -147,9 +201,11 @@ where
 {
     /// Gather values from ChunkedArray by index.
     unsafe fn take_unchecked(&self, indices: &I) -> Self {
+        let indices = indices.as_ref();
+        let sorted = self.chunks().len() > 1 && is_ascending(indices);
         let rechunked;
         let mut ca = self;
-        if self.chunks().len() > BINARY_SEARCH_LIMIT {
+        if self.chunks().len() > BINARY_SEARCH_LIMIT && !sorted {
             rechunked = self.rechunk();
             ca = &rechunked;
         }
@@ -158,7 +214,8 @@ where
             ca.dtype().to_arrow(true),
             &targets,
             ca.null_count() > 0,
-            indices.as_ref(),
+            indices,
+            sorted,
         );
         ChunkedArray::from_chunk_iter_like(ca, [arr])
     }
@@ -182,9 +239,16 @@ where
 {
     /// Gather values from ChunkedArray by index.
     unsafe fn take_unchecked(&self, indices: &IdxCa) -> Self {
+        // The values of null indices are arbitrary, so those can only be relied on to be
+        // sorted if the flag is set.
+        let sorted = self.chunks().len() > 1
+            && (indices.is_sorted_flag() == IsSorted::Ascending
+                || indices
+                    .downcast_iter()
+                    .all(|idx_arr| idx_arr.null_count() == 0 && is_ascending(idx_arr.values())));
         let rechunked;
         let mut ca = self;
-        if self.chunks().len() > BINARY_SEARCH_LIMIT {
+        if self.chunks().len() > BINARY_SEARCH_LIMIT && !sorted {
             rechunked = self.rechunk();
             ca = &rechunked;
         }
@@ -194,7 +258,32 @@ where
         let chunks = indices.downcast_iter().map(|idx_arr| {
             let dtype = ca.dtype().to_arrow(true);
             if idx_arr.null_count() == 0 {
-                gather_idx_array_unchecked(dtype, &targets, targets_have_nulls, idx_arr.values())
+                gather_idx_array_unchecked(
+                    dtype,
+                    &targets,
+                    targets_have_nulls,
+                    idx_arr.values(),
+                    sorted,
+                )
+            } else if sorted && targets.len() > 1 {
+                let mut walk = SortedChunkWalk::new(&targets);
+                if targets_have_nulls {
+                    idx_arr
+                        .iter()
+                        .map(|i| {
+                            let (arr, i) = walk.resolve(*i?);
+                            arr.get_unchecked(i)
+                        })
+                        .collect_arr_trusted_with_dtype(dtype)
+                } else {
+                    idx_arr
+                        .iter()
+                        .map(|i| {
+                            let (arr, i) = walk.resolve(*i?);
+                            Some(arr.value_unchecked(i))
+                        })
+                        .collect_arr_trusted_with_dtype(dtype)
+                }
             } else if targets.len() == 1 {
                 let target = targets.first().unwrap();
                 if targets_have_nulls {
@@ -289,3 +378,41 @@ impl IdxCa {
         f(&ca)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gather_sorted_multiple_chunks() -> PolarsResult<()> {
+        // More chunks than the binary search supports, including empty ones.
+        let mut ca = Int32Chunked::full_null("a", 0);
+        for i in 0..12 {
+            let chunk = (0..i).map(|v| (v % 3 != 0).then_some(i * 100 + v));
+            ca.append(&Int32Chunked::from_iter_options("a", chunk));
+        }
+        let expected: Vec<_> = ca.rechunk().into_iter().collect();
+        assert!(ca.chunks().len() > BINARY_SEARCH_LIMIT);
+
+        let idx = [0, 0, 1, 5, 6, 6, 30, 45, 65];
+        let out = ca.take(&idx[..])?;
+        assert_eq!(out.chunks().len(), 1);
+        let values: Vec<_> = idx.iter().map(|&i| expected[i as usize]).collect();
+        assert_eq!(Vec::from(&out), values);
+
+        let mut idx =
+            IdxCa::from_iter_options("", [None, Some(2), Some(9), None, Some(64)].into_iter());
+        idx.set_sorted_flag(IsSorted::Ascending);
+        let out = ca.take(&idx)?;
+        let values: Vec<_> = idx
+            .into_iter()
+            .map(|i| i.and_then(|i| expected[i as usize]))
+            .collect();
+        assert_eq!(Vec::from(&out), values);
+
+        // Unsorted indices still resolve correctly.
+        let out = ca.take(&[65, 0, 30][..])?;
+        assert_eq!(Vec::from(&out), [expected[65], expected[0], expected[30]]);
+        Ok(())
+    }
+}
@@ -31,37 +31,135 @@ where
     arity::binary_elementwise_values(left, right, op)
 }
 
+// Null-aware variants that only return a null if both values are null.
+fn min_binary_nullable<T>(left: &ChunkedArray<T>, right: &ChunkedArray<T>) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: PartialOrd,
+{
+    let op = |l: Option<T::Native>, r: Option<T::Native>| match (l, r) {
+        (Some(l), Some(r)) => Some(if l < r { l } else { r }),
+        (l, r) => l.or(r),
+    };
+    arity::binary_elementwise(left, right, op)
+}
+
+fn max_binary_nullable<T>(left: &ChunkedArray<T>, right: &ChunkedArray<T>) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: PartialOrd,
+{
+    let op = |l: Option<T::Native>, r: Option<T::Native>| match (l, r) {
+        (Some(l), Some(r)) => Some(if l > r { l } else { r }),
+        (l, r) => l.or(r),
+    };
+    arity::binary_elementwise(left, right, op)
+}
+
+fn min_str<'a>(l: Option<&'a str>, r: Option<&'a str>) -> Option<&'a str> {
+    match (l, r) {
+        (Some(l), Some(r)) => Some(l.min(r)),
+        (l, r) => l.or(r),
+    }
+}
+
+fn max_str<'a>(l: Option<&'a str>, r: Option<&'a str>) -> Option<&'a str> {
+    match (l, r) {
+        (Some(l), Some(r)) => Some(l.max(r)),
+        (l, r) => l.or(r),
+    }
+}
+
 pub(crate) fn min_max_binary_series(
     left: &Series,
     right: &Series,
     min: bool,
 ) -> PolarsResult<Series> {
-    if left.dtype().to_physical().is_numeric()
-        && left.null_count() == 0
-        && right.null_count() == 0
-        && left.len() == right.len()
-    {
+    if left.len() == right.len() {
         let (lhs, rhs) = coerce_lhs_rhs(left, right)?;
         let logical = lhs.dtype();
-        let lhs = lhs.to_physical_repr();
-        let rhs = rhs.to_physical_repr();
+        if logical == rhs.dtype() {
+            // A column that is entirely null doesn't change the result.
+            if lhs.null_count() == lhs.len() {
+                return Ok(rhs.into_owned());
+            }
+            if rhs.null_count() == rhs.len() {
+                return Ok(lhs.into_owned());
+            }
+            let has_nulls = lhs.null_count() > 0 || rhs.null_count() > 0;
 
-        with_match_physical_numeric_polars_type!(lhs.dtype(), |$T| {
-        let a: &ChunkedArray<$T> = lhs.as_ref().as_ref().as_ref();
-        let b: &ChunkedArray<$T> = rhs.as_ref().as_ref().as_ref();
+            // The physical order of categoricals is not their logical order,
+            // so those only take the fast path if there are no nulls.
+            if logical.to_physical().is_numeric()
+                && (!has_nulls || !(logical.is_categorical() || logical.is_enum()))
+            {
+                let lhs = lhs.to_physical_repr();
+                let rhs = rhs.to_physical_repr();
 
-        if min {
-            min_binary(a, b).into_series().cast(logical)
-        } else {
-            max_binary(a, b).into_series().cast(logical)
+                return with_match_physical_numeric_polars_type!(lhs.dtype(), |$T| {
+                    let a: &ChunkedArray<$T> = lhs.as_ref().as_ref().as_ref();
+                    let b: &ChunkedArray<$T> = rhs.as_ref().as_ref().as_ref();
+
+                    let out = match (min, has_nulls) {
+                        (true, false) => min_binary(a, b),
+                        (false, false) => max_binary(a, b),
+                        (true, true) => min_binary_nullable(a, b),
+                        (false, true) => max_binary_nullable(a, b),
+                    };
+                    out.into_series().cast(logical)
+                });
+            }
+            if logical == &DataType::String {
+                let a = lhs.str()?;
+                let b = rhs.str()?;
+                let out: StringChunked = if min {
+                    arity::binary_elementwise(a, b, min_str)
+                } else {
+                    arity::binary_elementwise(a, b, max_str)
+                };
+                return Ok(out.into_series());
             }
-        })
+        }
+    }
+
+    let mask = if min {
+        left.lt(right)? & left.is_not_null() | right.is_null()
     } else {
-        let mask = if min {
-            left.lt(right)? & left.is_not_null() | right.is_null()
-        } else {
-            left.gt(right)? & left.is_not_null() | right.is_null()
-        };
-        left.zip_with(&mask, right)
+        left.gt(right)? & left.is_not_null() | right.is_null()
+    };
+    left.zip_with(&mask, right)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    #[cfg(feature = "zip_with")]
+    fn test_min_max_horizontal_nulls() -> PolarsResult<()> {
+        let df = df![
+            "a" => [Some(1), None, Some(3), None],
+            "b" => [Some(2), Some(0), None, None],
+            "c" => [Some("foo"), None, Some("bar"), None],
+            "d" => [Some("baz"), Some("ham"), None, None],
+            "e" => [None::<i32>, None, None, None],
+        ]?;
+
+        let out = df.select(["a", "b", "e"])?.max_horizontal()?.unwrap();
+        assert_eq!(Vec::from(out.i32()?), &[Some(2), Some(0), Some(3), None]);
+        let out = df.select(["e", "a", "b"])?.min_horizontal()?.unwrap();
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), Some(0), Some(3), None]);
+
+        let out = df.select(["c", "d"])?.max_horizontal()?.unwrap();
+        assert_eq!(
+            Vec::from(out.str()?),
+            &[Some("foo"), Some("ham"), Some("bar"), None]
+        );
+        let out = df.select(["c", "d"])?.min_horizontal()?.unwrap();
+        assert_eq!(
+            Vec::from(out.str()?),
+            &[Some("baz"), Some("ham"), Some("bar"), None]
+        );
+        Ok(())
     }
 }
@@ -379,6 +379,15 @@ pub trait ChunkSort<T: PolarsDataType> {
     }
 }
 
+/// How the buffers of an array were mutated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Mutation {
+    /// The buffers were uniquely owned and mutated in place.
+    InPlace,
+    /// The buffers were shared with another array and copied before they were mutated.
+    Copied,
+}
+
 pub type FillNullLimit = Option<IdxSize>;
 
 #[derive(Copy, Clone, Debug, PartialEq, Hash)]
@@ -38,10 +38,11 @@ pub fn _arg_bottom_k(
     _broadcast_bools(by_column.len(), &mut sort_options.descending);
     _broadcast_bools(by_column.len(), &mut sort_options.nulls_last);
 
-    let encoded = _get_rows_encoded(
+    let encoded = _get_rows_encoded_nan_ordered(
         by_column,
         &sort_options.descending,
         &sort_options.nulls_last,
+        sort_options.nan_ordering,
     )?;
     let arr = encoded.into_array();
     let mut rows = arr
@@ -29,6 +29,7 @@ pub(crate) fn arg_sort_multiple_impl<T: NullOrderCmp + Send + Copy>(
 ) -> PolarsResult<IdxCa> {
     let nulls_last = &options.nulls_last;
     let descending = &options.descending;
+    let by = &nan_ordered_sort_keys(by.to_vec(), options.nan_ordering);
 
     debug_assert_eq!(descending.len() - 1, by.len());
     debug_assert_eq!(nulls_last.len() - 1, by.len());
@@ -222,6 +223,40 @@ pub fn _get_rows_encoded(
     Ok(convert_columns(&cols, &fields))
 }
 
+/// Encode the rows of `by` with [`_get_rows_encoded`], where the `NaN` values of float
+/// columns, including the fields of structs, are ordered as `nan_ordering` dictates.
+pub fn _get_rows_encoded_nan_ordered(
+    by: &[Series],
+    descending: &[bool],
+    nulls_last: &[bool],
+    nan_ordering: NanOrdering,
+) -> PolarsResult<RowsEncoded> {
+    if nan_ordering == NanOrdering::Largest {
+        return _get_rows_encoded(by, descending, nulls_last);
+    }
+    let by = by
+        .iter()
+        .map(|s| nan_ordered_sort_key_nested(s, nan_ordering))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    _get_rows_encoded(&by, descending, nulls_last)
+}
+
+fn nan_ordered_sort_key_nested(s: &Series, nan_ordering: NanOrdering) -> PolarsResult<Series> {
+    match s.dtype() {
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(_) => {
+            let ca = s.struct_()?;
+            let fields = ca
+                .fields()
+                .iter()
+                .map(|s| nan_ordered_sort_key_nested(s, nan_ordering))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            StructChunked::new(ca.name(), &fields).map(|ca| ca.into_series())
+        },
+        _ => Ok(_nan_ordered_sort_key(s, nan_ordering).unwrap_or_else(|| s.clone())),
+    }
+}
+
 pub fn _get_rows_encoded_ca(
     name: &str,
     by: &[Series],
@@ -244,12 +279,13 @@ pub(crate) fn argsort_multiple_row_fmt(
     by: &[Series],
     mut descending: Vec<bool>,
     mut nulls_last: Vec<bool>,
+    nan_ordering: NanOrdering,
     parallel: bool,
 ) -> PolarsResult<IdxCa> {
     _broadcast_bools(by.len(), &mut descending);
     _broadcast_bools(by.len(), &mut nulls_last);
 
-    let rows_encoded = _get_rows_encoded(by, &descending, &nulls_last)?;
+    let rows_encoded = _get_rows_encoded_nan_ordered(by, &descending, &nulls_last, nan_ordering)?;
     let mut items: Vec<_> = rows_encoded.iter().enumerate_idx().collect();
 
     if parallel {
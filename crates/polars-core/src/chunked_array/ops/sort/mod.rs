@@ -15,6 +15,7 @@ use arrow::bitmap::{Bitmap, MutableBitmap};
 use arrow::buffer::Buffer;
 use arrow::legacy::trusted_len::TrustedLenPush;
 use compare_inner::NonNull;
+use polars_utils::total_ord::canonical_f64;
 use rayon::prelude::*;
 pub use slice::*;
 
@@ -25,6 +26,68 @@ use crate::series::IsSorted;
 use crate::utils::NoNull;
 use crate::POOL;
 
+/// Map a float [`Series`] to integers with the same total order, placing `NaN` values as
+/// `nan_ordering` dictates. Returns `None` if no mapping is needed.
+pub fn _nan_ordered_sort_key(s: &Series, nan_ordering: NanOrdering) -> Option<Series> {
+    if !s.dtype().is_float() || nan_ordering == NanOrdering::Largest {
+        return None;
+    }
+    let nan_key = match nan_ordering {
+        NanOrdering::Smallest => Some(i64::MIN),
+        _ => None,
+    };
+    // Casting from `f32` preserves the order and `NaN`s.
+    let s = s.cast(&DataType::Float64).unwrap();
+    let key: Int64Chunked = s.f64().unwrap().apply_generic(|opt_v| {
+        let v = opt_v?;
+        if v.is_nan() {
+            nan_key
+        } else {
+            // https://github.com/rust-lang/rust/blob/9c20b2a8cc7588decb6de25ac6a7912dcef24d65/library/core/src/num/f32.rs#L1176-L1260
+            let bits = canonical_f64(v).to_bits() as i64;
+            Some(bits ^ (((bits >> 63) as u64) >> 1) as i64)
+        }
+    });
+    Some(key.with_name(s.name()).into_series())
+}
+
+/// Map the float columns in `by` with [`_nan_ordered_sort_key`].
+pub(crate) fn nan_ordered_sort_keys(by: Vec<Series>, nan_ordering: NanOrdering) -> Vec<Series> {
+    by.into_iter()
+        .map(|s| _nan_ordered_sort_key(&s, nan_ordering).unwrap_or(s))
+        .collect()
+}
+
+/// Whether the sorted flag of `s` shows that it already is in the order that sorting it in
+/// `descending` order with its nulls at the end given by `nulls_last` would give.
+pub fn is_sorted_by_flag(s: &Series, descending: bool, nulls_last: bool) -> bool {
+    let in_order = match s.is_sorted_flag() {
+        IsSorted::Ascending => !descending,
+        IsSorted::Descending => descending,
+        IsSorted::Not => false,
+    };
+    // The nulls of a sorted series are all at its start or at its end.
+    let null_count = s.null_count();
+    in_order
+        && (null_count == 0
+            || null_count == s.len()
+            || if nulls_last {
+                s.get(s.len() - 1).is_ok_and(|av| av.is_null())
+            } else {
+                s.get(0).is_ok_and(|av| av.is_null())
+            })
+}
+
+fn nan_ordered_key_numeric<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    nan_ordering: NanOrdering,
+) -> Option<Series> {
+    if !T::get_dtype().is_float() || nan_ordering == NanOrdering::Largest {
+        return None;
+    }
+    _nan_ordered_sort_key(&ca.cast(&DataType::Float64).unwrap(), nan_ordering)
+}
+
 fn partition_nulls<T: Copy>(
     values: &mut [T],
     mut validity: Option<Bitmap>,
@@ -133,9 +196,9 @@ macro_rules! sort_with_fast_path {
             // there are nulls
             if $ca.null_count() > 0 {
                 // if the nulls are already last we can clone
-                if $options.nulls_last && $ca.get($ca.len() - 1).is_none()  ||
+                if ($options.nulls_last && $ca.get($ca.len() - 1).is_none())  ||
                 // if the nulls are already first we can clone
-                $ca.get(0).is_none()
+                (!$options.nulls_last && $ca.get(0).is_none())
                 {
                     return $ca.clone();
                 }
@@ -271,6 +334,11 @@ where
     T: PolarsNumericType,
 {
     fn sort_with(&self, options: SortOptions) -> ChunkedArray<T> {
+        if let Some(key) = nan_ordered_key_numeric(self, options.nan_ordering) {
+            let idx = key.arg_sort(options);
+            // SAFETY: the sort indices are in bounds.
+            return unsafe { self.take_unchecked(&idx) };
+        }
         sort_with_numeric(self, options)
     }
 
@@ -282,6 +350,9 @@ where
     }
 
     fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        if let Some(key) = nan_ordered_key_numeric(self, options.nan_ordering) {
+            return key.arg_sort(options);
+        }
         arg_sort_numeric(self, options)
     }
 
@@ -294,6 +365,10 @@ where
         by: &[Series],
         options: &SortMultipleOptions,
     ) -> PolarsResult<IdxCa> {
+        // The other columns are mapped in `arg_sort_multiple_impl`.
+        if let Some(key) = nan_ordered_key_numeric(self, options.nan_ordering) {
+            return key.arg_sort_multiple(by, options);
+        }
         arg_sort_multiple_numeric(self, by, options)
     }
 }
@@ -329,6 +404,7 @@ impl ChunkSort<StringType> for StringChunked {
             nulls_last: false,
             multithreaded: true,
             maintain_order: false,
+            ..Default::default()
         })
     }
 
@@ -399,6 +475,7 @@ impl ChunkSort<BinaryType> for BinaryChunked {
             nulls_last: false,
             multithreaded: true,
             maintain_order: false,
+            ..Default::default()
         })
     }
 
@@ -528,6 +605,7 @@ impl ChunkSort<BinaryOffsetType> for BinaryOffsetChunked {
             nulls_last: false,
             multithreaded: true,
             maintain_order: false,
+            ..Default::default()
         })
     }
 
@@ -588,14 +666,14 @@ impl ChunkSort<BinaryOffsetType> for BinaryOffsetChunked {
 #[cfg(feature = "dtype-struct")]
 impl StructChunked {
     pub(crate) fn arg_sort(&self, options: SortOptions) -> IdxCa {
-        let bin = _get_rows_encoded_ca(
-            self.name(),
+        let rows = _get_rows_encoded_nan_ordered(
             &[self.clone().into_series()],
             &[options.descending],
             &[options.nulls_last],
+            options.nan_ordering,
         )
         .unwrap();
-        bin.arg_sort(Default::default())
+        BinaryOffsetChunked::with_chunk(self.name(), rows.into_array()).arg_sort(Default::default())
     }
 }
 
@@ -641,6 +719,7 @@ impl ChunkSort<BooleanType> for BooleanChunked {
             nulls_last: false,
             multithreaded: true,
             maintain_order: false,
+            ..Default::default()
         })
     }
 
@@ -703,6 +782,9 @@ pub(crate) fn convert_sort_column_multi_sort(s: &Series) -> PolarsResult<Series>
         Decimal(_, _) => s.clone(),
         List(inner) if !inner.is_nested() => s.clone(),
         Null => s.clone(),
+        // The physical bits don't sort like the values.
+        #[cfg(feature = "dtype-f16")]
+        Float16 => s.cast(&Float32).unwrap(),
         _ => {
             let phys = s.to_physical_repr().into_owned();
             polars_ensure!(
@@ -744,6 +826,7 @@ pub(crate) fn prepare_arg_sort(
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
+    use crate::series::IsSorted;
 
     #[test]
     fn test_arg_sort() {
@@ -799,6 +882,7 @@ mod test {
             nulls_last: false,
             multithreaded: true,
             maintain_order: false,
+            ..Default::default()
         });
         assert_eq!(
             Vec::from(&out),
@@ -818,6 +902,7 @@ mod test {
             nulls_last: true,
             multithreaded: true,
             maintain_order: false,
+            ..Default::default()
         });
         assert_eq!(
             Vec::from(&out),
@@ -897,6 +982,87 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_sort_nan_ordering() -> PolarsResult<()> {
+        let ca = Float64Chunked::new(
+            "a",
+            &[
+                Some(1.0),
+                Some(f64::NAN),
+                None,
+                Some(f64::NEG_INFINITY),
+                Some(0.0),
+            ],
+        );
+        let arg_sort = |options: SortOptions| {
+            Vec::from(&ca.arg_sort(options.with_maintain_order(true)))
+                .into_iter()
+                .map(Option::unwrap)
+                .collect::<Vec<_>>()
+        };
+        let options = SortOptions::default();
+        assert_eq!(arg_sort(options), &[2, 3, 4, 0, 1]);
+        let options = options.with_nan_ordering(NanOrdering::Smallest);
+        assert_eq!(arg_sort(options), &[2, 1, 3, 4, 0]);
+        assert_eq!(
+            arg_sort(options.with_order_descending(true)),
+            &[2, 0, 4, 3, 1]
+        );
+        let options = options.with_nan_ordering(NanOrdering::Null);
+        assert_eq!(arg_sort(options), &[1, 2, 3, 4, 0]);
+        assert_eq!(arg_sort(options.with_nulls_last(true)), &[3, 4, 0, 1, 2]);
+
+        let out = ca.sort_with(SortOptions::default().with_nan_ordering(NanOrdering::Smallest));
+        assert!(out.get(1).unwrap().is_nan());
+        assert_eq!(out.is_sorted_flag(), IsSorted::Not);
+
+        let df = df!(
+            "a" => [1.0, f64::NAN, 0.0],
+            "b" => [1, 2, 3]
+        )?;
+        let options = SortMultipleOptions::default().with_nan_ordering(NanOrdering::Smallest);
+        let out = df.sort(["a", "b"], options.clone())?;
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[Some(2), Some(3), Some(1)]
+        );
+        let out = df.select(["a"])?.sort(["a"], options.clone())?;
+        assert!(out.column("a")?.f64()?.get(0).unwrap().is_nan());
+        assert_eq!(out.column("a")?.is_sorted_flag(), IsSorted::Not);
+
+        // Placing all nulls last sorts by the row encoding.
+        let out = df.sort(["a", "b"], options.clone().with_nulls_last(true))?;
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[Some(2), Some(3), Some(1)]
+        );
+
+        // The float column is not the first column to sort by.
+        let df = df!(
+            "a" => [1, 1, 1],
+            "b" => [1.0, f64::NAN, 0.0],
+        )?;
+        let s_a = df.column("a")?;
+        let s_b = df.column("b")?;
+        let idx = s_a.i32()?.arg_sort_multiple(
+            &[s_b.clone()],
+            &options.clone().with_order_descending_multi([false, false]),
+        )?;
+        assert_eq!(Vec::from(&idx), &[Some(1), Some(2), Some(0)]);
+
+        #[cfg(feature = "dtype-struct")]
+        {
+            let s = StructChunked::new("s", &[s_a.clone(), s_b.clone()])?.into_series();
+            let options = SortOptions::default().with_nan_ordering(NanOrdering::Smallest);
+            assert_eq!(
+                Vec::from(&s.arg_sort(options)),
+                &[Some(1), Some(2), Some(0)]
+            );
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_sort_string() {
         let ca = StringChunked::new("a", &[Some("a"), None, Some("c"), None, Some("b")]);
@@ -905,6 +1071,7 @@ mod test {
             nulls_last: false,
             multithreaded: true,
             maintain_order: false,
+            ..Default::default()
         });
         let expected = &[None, None, Some("a"), Some("b"), Some("c")];
         assert_eq!(Vec::from(&out), expected);
@@ -914,6 +1081,7 @@ mod test {
             nulls_last: false,
             multithreaded: true,
             maintain_order: false,
+            ..Default::default()
         });
 
         let expected = &[None, None, Some("c"), Some("b"), Some("a")];
@@ -924,6 +1092,7 @@ mod test {
             nulls_last: true,
             multithreaded: true,
             maintain_order: false,
+            ..Default::default()
         });
         let expected = &[Some("a"), Some("b"), Some("c"), None, None];
         assert_eq!(Vec::from(&out), expected);
@@ -933,6 +1102,7 @@ mod test {
             nulls_last: true,
             multithreaded: true,
             maintain_order: false,
+            ..Default::default()
         });
         let expected = &[Some("c"), Some("b"), Some("a"), None, None];
         assert_eq!(Vec::from(&out), expected);
@@ -4,6 +4,21 @@ pub use slice::*;
 
 use crate::prelude::*;
 
+/// Where floating point `NaN` values are placed relative to other values.
+///
+/// This is honored by sorting, ranking and `min`/`max` of float columns.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub enum NanOrdering {
+    /// `NaN` is larger than any other value, including infinity.
+    #[default]
+    Largest,
+    /// `NaN` is smaller than any other value, including negative infinity.
+    Smallest,
+    /// `NaN` is treated as a missing value.
+    Null,
+}
+
 /// Options for single series sorting.
 ///
 /// Indicating the order of sorting, nulls position, multithreading, and maintaining order.
@@ -41,6 +56,10 @@ pub struct SortOptions {
     /// If true maintain the order of equal elements.
     /// Default `false`.
     pub maintain_order: bool,
+    /// Where `NaN` values of float columns are placed.
+    /// Default [`NanOrdering::Largest`].
+    #[cfg_attr(feature = "serde-lazy", serde(default))]
+    pub nan_ordering: NanOrdering,
 }
 
 /// Sort options for multi-series sorting.
@@ -96,6 +115,9 @@ pub struct SortMultipleOptions {
     pub multithreaded: bool,
     /// Whether maintain the order of equal elements. Default `false`.
     pub maintain_order: bool,
+    /// Where `NaN` values of float columns are placed. Default [`NanOrdering::Largest`].
+    #[cfg_attr(feature = "serde-lazy", serde(default))]
+    pub nan_ordering: NanOrdering,
 }
 
 impl Default for SortOptions {
@@ -105,6 +127,7 @@ impl Default for SortOptions {
             nulls_last: false,
             multithreaded: true,
             maintain_order: false,
+            nan_ordering: NanOrdering::Largest,
         }
     }
 }
@@ -116,6 +139,7 @@ impl Default for SortMultipleOptions {
             nulls_last: vec![false],
             multithreaded: true,
             maintain_order: false,
+            nan_ordering: NanOrdering::Largest,
         }
     }
 }
@@ -173,6 +197,12 @@ impl SortMultipleOptions {
         self
     }
 
+    /// Where to place `NaN` values of float columns. Default [`NanOrdering::Largest`].
+    pub fn with_nan_ordering(mut self, nan_ordering: NanOrdering) -> Self {
+        self.nan_ordering = nan_ordering;
+        self
+    }
+
     /// Reverse the order of sorting for each column.
     pub fn with_order_reversed(mut self) -> Self {
         self.descending.iter_mut().for_each(|x| *x = !*x);
@@ -210,6 +240,12 @@ impl SortOptions {
         self
     }
 
+    /// Where to place `NaN` values of float columns. Default [`NanOrdering::Largest`].
+    pub fn with_nan_ordering(mut self, nan_ordering: NanOrdering) -> Self {
+        self.nan_ordering = nan_ordering;
+        self
+    }
+
     /// Reverse the order of sorting.
     pub fn with_order_reversed(mut self) -> Self {
         self.descending = !self.descending;
@@ -224,6 +260,7 @@ impl From<&SortOptions> for SortMultipleOptions {
             nulls_last: vec![value.nulls_last],
             multithreaded: value.multithreaded,
             maintain_order: value.maintain_order,
+            nan_ordering: value.nan_ordering,
         }
     }
 }
@@ -235,6 +272,7 @@ impl From<&SortMultipleOptions> for SortOptions {
             nulls_last: value.nulls_last.first().copied().unwrap_or(false),
             multithreaded: value.multithreaded,
             maintain_order: value.maintain_order,
+            nan_ordering: value.nan_ordering,
         }
     }
 }
@@ -46,6 +46,14 @@ pub fn get_rg_prefetch_size() -> usize {
         .unwrap_or_else(|_| std::cmp::max(get_file_prefetch_size(), 128))
 }
 
+/// The number of bytes of parquet column chunks that are downloaded or wait to be decoded at a
+/// time.
+pub fn get_column_prefetch_bytes() -> usize {
+    std::env::var("POLARS_COLUMN_PREFETCH_BYTES")
+        .map(|s| s.parse::<usize>().expect("integer"))
+        .unwrap_or(1 << 29)
+}
+
 pub fn force_async() -> bool {
     std::env::var("POLARS_FORCE_ASYNC")
         .map(|value| value == "1")
@@ -84,6 +84,8 @@ enum SerializableDataType {
     Int16,
     Int32,
     Int64,
+    #[cfg(feature = "dtype-f16")]
+    Float16,
     Float32,
     Float64,
     String,
@@ -129,6 +131,8 @@ impl From<&DataType> for SerializableDataType {
             Int16 => Self::Int16,
             Int32 => Self::Int32,
             Int64 => Self::Int64,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Self::Float16,
             Float32 => Self::Float32,
             Float64 => Self::Float64,
             String => Self::String,
@@ -173,6 +177,8 @@ impl From<SerializableDataType> for DataType {
             Int16 => Self::Int16,
             Int32 => Self::Int32,
             Int64 => Self::Int64,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Self::Float16,
             Float32 => Self::Float32,
             Float64 => Self::Float64,
             String => Self::String,
@@ -1,5 +1,9 @@
 use std::collections::BTreeMap;
+#[cfg(feature = "dtype-categorical")]
+use std::fmt::Write;
 
+#[cfg(feature = "dtype-categorical")]
+use arrow::datatypes::Metadata;
 #[cfg(feature = "dtype-array")]
 use polars_utils::format_tuple;
 
@@ -12,6 +16,8 @@ pub type TimeZone = String;
 
 pub static DTYPE_ENUM_KEY: &str = "POLARS.CATEGORICAL_TYPE";
 pub static DTYPE_ENUM_VALUE: &str = "ENUM";
+pub static DTYPE_CATEGORIES_KEY: &str = "POLARS.CATEGORIES";
+pub static DTYPE_CATEGORICAL_ORDERING_KEY: &str = "POLARS.CATEGORICAL_ORDERING";
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(
@@ -51,6 +57,10 @@ pub enum DataType {
     Int16,
     Int32,
     Int64,
+    /// A 16-bit floating point number, backed by its bit pattern in a `UInt16`.
+    /// Computations are done in 32-bit floating point.
+    #[cfg(feature = "dtype-f16")]
+    Float16,
     Float32,
     Float64,
     /// Fixed point decimal type optional precision and non-negative scale.
@@ -248,6 +258,8 @@ impl DataType {
     pub fn to_physical(&self) -> DataType {
         use DataType::*;
         match self {
+            #[cfg(feature = "dtype-f16")]
+            Float16 => UInt16,
             Date => Int32,
             Datetime(_, _) => Int64,
             Duration(_) => Int64,
@@ -425,10 +437,12 @@ impl DataType {
 
     /// Check if this [`DataType`] is a basic floating point type (excludes Decimal).
     pub fn is_float(&self) -> bool {
-        matches!(
-            self,
-            DataType::Float32 | DataType::Float64 | DataType::Unknown(UnknownKind::Float)
-        )
+        match self {
+            DataType::Float32 | DataType::Float64 | DataType::Unknown(UnknownKind::Float) => true,
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => true,
+            _ => false,
+        }
     }
 
     /// Check if this [`DataType`] is an integer.
@@ -520,6 +534,36 @@ impl DataType {
         }
     }
 
+    /// Arrow field metadata that preserves the exact categorical type.
+    ///
+    /// Dictionary encoded formats only store the categories that occur in the data, in an
+    /// arbitrary order. This metadata records all categories in their physical order and the
+    /// ordering of the type, so that readers can restore it with
+    /// [`DataType::from_arrow_field`].
+    #[cfg(feature = "dtype-categorical")]
+    pub fn categorical_metadata(&self) -> Option<Metadata> {
+        let (rev_map, ordering) = match self {
+            DataType::Categorical(rev_map, ordering) | DataType::Enum(rev_map, ordering) => {
+                (rev_map, ordering)
+            },
+            _ => return None,
+        };
+        let mut metadata = Metadata::new();
+        if self.is_enum() {
+            metadata.insert(DTYPE_ENUM_KEY.into(), DTYPE_ENUM_VALUE.into());
+        }
+        if let Some(rev_map) = rev_map {
+            metadata.insert(
+                DTYPE_CATEGORIES_KEY.into(),
+                encode_categories(rev_map.get_categories()),
+            );
+        }
+        if *ordering == CategoricalOrdering::Lexical {
+            metadata.insert(DTYPE_CATEGORICAL_ORDERING_KEY.into(), "lexical".into());
+        }
+        Some(metadata)
+    }
+
     /// Convert to an Arrow data type.
     #[inline]
     pub fn to_arrow(&self, pl_flavor: bool) -> ArrowDataType {
@@ -539,6 +583,8 @@ impl DataType {
             Int16 => Ok(ArrowDataType::Int16),
             Int32 => Ok(ArrowDataType::Int32),
             Int64 => Ok(ArrowDataType::Int64),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Ok(ArrowDataType::Float16),
             Float32 => Ok(ArrowDataType::Float32),
             Float64 => Ok(ArrowDataType::Float64),
             #[cfg(feature = "dtype-decimal")]
@@ -680,6 +726,8 @@ impl Display for DataType {
             DataType::Int16 => "i16",
             DataType::Int32 => "i32",
             DataType::Int64 => "i64",
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => "f16",
             DataType::Float32 => "f32",
             DataType::Float64 => "f64",
             #[cfg(feature = "dtype-decimal")]
@@ -784,3 +832,106 @@ pub fn create_enum_data_type(categories: Utf8ViewArray) -> DataType {
     let rev_map = RevMapping::build_local(categories);
     DataType::Enum(Some(Arc::new(rev_map)), Default::default())
 }
+
+/// The [`DataType::Enum`] with the categories of `dtype`, followed by the categories of
+/// `new_categories` that are not categories of `dtype` yet.
+///
+/// The existing categories keep their position, so their physical codes are unchanged.
+#[cfg(feature = "dtype-categorical")]
+pub fn extend_enum_data_type(
+    dtype: &DataType,
+    new_categories: &Utf8ViewArray,
+) -> PolarsResult<DataType> {
+    let DataType::Enum(Some(rev_map), ordering) = dtype else {
+        polars_bail!(InvalidOperation: "can only add categories to an Enum, got {}", dtype)
+    };
+    polars_ensure!(
+        new_categories.null_count() == 0,
+        ComputeError: "Enum categories must not contain nulls"
+    );
+    let categories = rev_map.get_categories();
+    let mut seen = categories.values_iter().collect::<PlHashSet<_>>();
+    let mut extended =
+        MutableBinaryViewArray::<str>::with_capacity(categories.len() + new_categories.len());
+    extended.extend_values(categories.values_iter());
+    for category in new_categories.values_iter() {
+        if seen.insert(category) {
+            extended.push_value(category)
+        }
+    }
+    let rev_map = RevMapping::build_local(extended.into());
+    Ok(DataType::Enum(Some(Arc::new(rev_map)), *ordering))
+}
+
+/// The [`DataType::Enum`] with the categories of `dtype` in the order of `categories`.
+///
+/// `categories` must hold every category of `dtype` exactly once.
+#[cfg(feature = "dtype-categorical")]
+pub fn reorder_enum_data_type(
+    dtype: &DataType,
+    categories: &Utf8ViewArray,
+) -> PolarsResult<DataType> {
+    let DataType::Enum(Some(rev_map), ordering) = dtype else {
+        polars_bail!(InvalidOperation: "can only reorder the categories of an Enum, got {}", dtype)
+    };
+    let current = rev_map
+        .get_categories()
+        .values_iter()
+        .collect::<PlHashSet<_>>();
+    let mut seen = PlHashSet::with_capacity(categories.len());
+    let is_reordering = categories.len() == current.len()
+        && categories.null_count() == 0
+        && categories
+            .values_iter()
+            .all(|category| current.contains(category) && seen.insert(category));
+    polars_ensure!(
+        is_reordering,
+        InvalidOperation: "the new categories must hold every category of the Enum exactly once"
+    );
+    let rev_map = RevMapping::build_local(categories.clone());
+    Ok(DataType::Enum(Some(Arc::new(rev_map)), *ordering))
+}
+
+/// Encode categories as a concatenation of `{byte_len}:{category}` entries.
+#[cfg(feature = "dtype-categorical")]
+fn encode_categories(categories: &Utf8ViewArray) -> String {
+    let mut out = String::new();
+    for category in categories.values_iter() {
+        write!(out, "{}:{}", category.len(), category).unwrap();
+    }
+    out
+}
+
+#[cfg(feature = "dtype-categorical")]
+fn decode_categories(mut encoded: &str) -> PolarsResult<Utf8ViewArray> {
+    let mut categories = MutableBinaryViewArray::<str>::new();
+    while !encoded.is_empty() {
+        let category = encoded.split_once(':').and_then(|(len, rest)| {
+            let len = len.parse::<usize>().ok()?;
+            let category = rest.get(..len)?;
+            encoded = &rest[len..];
+            Some(category)
+        });
+        let Some(category) = category else {
+            polars_bail!(ComputeError: "invalid categories in '{}' field metadata", DTYPE_CATEGORIES_KEY)
+        };
+        categories.push_value(category);
+    }
+    Ok(categories.into())
+}
+
+/// The categories and ordering stored by [`DataType::categorical_metadata`].
+#[cfg(feature = "dtype-categorical")]
+pub(crate) fn categories_from_metadata(
+    metadata: &Metadata,
+) -> PolarsResult<(Option<Utf8ViewArray>, CategoricalOrdering)> {
+    let categories = metadata
+        .get(DTYPE_CATEGORIES_KEY)
+        .map(|encoded| decode_categories(encoded))
+        .transpose()?;
+    let ordering = match metadata.get(DTYPE_CATEGORICAL_ORDERING_KEY) {
+        Some(ordering) if ordering == "lexical" => CategoricalOrdering::Lexical,
+        _ => CategoricalOrdering::Physical,
+    };
+    Ok((categories, ordering))
+}
@@ -131,6 +131,30 @@ impl DataType {
         Box::new(self)
     }
 
+    /// Convert an Arrow field to a data type, restoring categorical types from the metadata
+    /// written by [`DataType::categorical_metadata`].
+    pub fn from_arrow_field(field: &ArrowField) -> DataType {
+        // Run-end encoded arrays are decoded into their values on import.
+        if let ArrowDataType::RunEndEncoded(_, values) = field.data_type() {
+            return Self::from_arrow_field(values);
+        }
+        #[cfg(feature = "dtype-categorical")]
+        if let ArrowDataType::Dictionary(_, _, _) = field.data_type() {
+            let (categories, ordering) =
+                categories_from_metadata(&field.metadata).unwrap_or_default();
+            let is_enum =
+                field.metadata.get(DTYPE_ENUM_KEY).map(|v| v.as_str()) == Some(DTYPE_ENUM_VALUE);
+            return match categories {
+                Some(categories) if is_enum => DataType::Enum(
+                    Some(Arc::new(RevMapping::build_local(categories))),
+                    ordering,
+                ),
+                _ => DataType::Categorical(None, ordering),
+            };
+        }
+        Self::from_arrow(field.data_type(), true)
+    }
+
     pub fn from_arrow(dt: &ArrowDataType, bin_to_view: bool) -> DataType {
         match dt {
             ArrowDataType::Null => DataType::Null,
@@ -143,6 +167,8 @@ impl DataType {
             ArrowDataType::Int32 => DataType::Int32,
             ArrowDataType::Int64 => DataType::Int64,
             ArrowDataType::Boolean => DataType::Boolean,
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => DataType::Float16,
             ArrowDataType::Float32 => DataType::Float32,
             ArrowDataType::Float64 => DataType::Float64,
             #[cfg(feature = "dtype-array")]
@@ -186,6 +212,7 @@ impl DataType {
                 }
             },
             ArrowDataType::FixedSizeBinary(_) => DataType::Binary,
+            ArrowDataType::RunEndEncoded(_, values) => DataType::from_arrow(values.data_type(), bin_to_view),
             dt => panic!("Arrow datatype {dt:?} not supported by Polars. You probably need to activate that data-type feature."),
         }
     }
@@ -199,6 +226,6 @@ impl From<&ArrowDataType> for DataType {
 
 impl From<&ArrowField> for Field {
     fn from(f: &ArrowField) -> Self {
-        Field::new(&f.name, f.data_type().into())
+        Field::new(&f.name, DataType::from_arrow_field(f))
     }
 }
@@ -188,6 +188,9 @@ impl_polars_binview_datatype!(BinaryType, Binary, BinaryViewArray, 'a, &'a [u8],
 impl_polars_datatype!(BinaryOffsetType, BinaryOffset, BinaryArray<i64>, 'a, &'a [u8], Option<&'a [u8]>, Box<[u8]>);
 impl_polars_datatype!(BooleanType, Boolean, BooleanArray, 'a, bool, bool, bool);
 
+#[cfg(feature = "dtype-f16")]
+impl_polars_datatype!(Float16Type, Float16, PrimitiveArray<u16>, 'a, u16, u16, u16);
+
 #[cfg(feature = "dtype-decimal")]
 impl_polars_datatype_pass_dtype!(DecimalType, DataType::Unknown(UnknownKind::Any), PrimitiveArray<i128>, 'a, i128, i128, i128, FalseT);
 impl_polars_datatype_pass_dtype!(DatetimeType, DataType::Unknown(UnknownKind::Any), PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
@@ -1,5 +1,7 @@
 #[cfg(feature = "temporal")]
 pub use chrono;
+#[cfg(feature = "timezones")]
+pub use chrono_tz;
 #[cfg(any(feature = "strings", feature = "temporal"))]
 pub use regex;
 #[cfg(feature = "serde")]
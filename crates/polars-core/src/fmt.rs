@@ -334,6 +334,10 @@ impl Debug for Series {
             DataType::Int64 => {
                 format_array!(f, self.i64().unwrap(), "i64", self.name(), "Series")
             },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                format_array!(f, self.f16().unwrap(), "f16", self.name(), "Series")
+            },
             DataType::Float32 => {
                 format_array!(f, self.f32().unwrap(), "f32", self.name(), "Series")
             },
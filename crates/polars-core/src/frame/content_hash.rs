@@ -0,0 +1,192 @@
+//! Fingerprints of the contents of a [`DataFrame`].
+use rayon::prelude::*;
+use xxhash_rust::xxh3::{xxh3_128, Xxh3};
+
+use crate::prelude::sort::arg_sort_multiple::_get_rows_encoded_unordered;
+use crate::prelude::*;
+use crate::POOL;
+
+/// The number of rows that are row-encoded at once.
+const BATCH_SIZE: usize = 1 << 16;
+
+/// Fingerprints of the schema and the rows of a [`DataFrame`].
+///
+/// Both fingerprints are deterministic: they don't depend on the chunking of the data, the
+/// number of threads or the process they are computed in, so they can be used as cache keys
+/// and for change detection. They are based on the row encoding of Polars and may change
+/// between Polars versions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContentHash {
+    /// Changes if the schema changes, or if any row changes or moves.
+    pub ordered: u128,
+    /// Changes if the schema changes, or if any row changes, but not if the rows are
+    /// reordered.
+    pub order_insensitive: u128,
+}
+
+/// Compute a [`ContentHash`] incrementally over consecutive batches of a frame.
+///
+/// Feeding a frame in batches gives the same [`ContentHash`] as
+/// [`DataFrame::content_hash`] on the whole frame, so frames that don't fit in memory can
+/// be fingerprinted while they are read.
+pub struct ContentHasher {
+    schema: Schema,
+    ordered: Xxh3,
+    order_insensitive: u128,
+    height: u64,
+}
+
+impl ContentHasher {
+    /// Create a hasher for frames with the given schema.
+    pub fn new(schema: Schema) -> Self {
+        Self {
+            schema,
+            ordered: Xxh3::new(),
+            order_insensitive: 0,
+            height: 0,
+        }
+    }
+
+    /// Hash the rows of `df`, which must have the schema of the hasher.
+    pub fn update(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let schema = df.schema();
+        polars_ensure!(
+            schema == self.schema,
+            SchemaMismatch: "cannot hash a batch with schema {:?} into a hasher with schema {:?}",
+            schema, self.schema
+        );
+        self.height += df.height() as u64;
+        if df.width() == 0 {
+            return Ok(());
+        }
+
+        for offset in (0..df.height()).step_by(BATCH_SIZE) {
+            let batch = df.slice(offset as i64, BATCH_SIZE);
+            let columns = batch
+                .get_columns()
+                .iter()
+                .map(stable_encoding_column)
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let rows = _get_rows_encoded_unordered(&columns)?;
+
+            for row in rows.iter() {
+                self.ordered.update(&(row.len() as u64).to_le_bytes());
+                self.ordered.update(row);
+            }
+            let row_hashes: Vec<&[u8]> = rows.iter().collect();
+            let sum = POOL.install(|| {
+                row_hashes
+                    .par_iter()
+                    .map(|row| xxh3_128(row))
+                    .reduce(|| 0, u128::wrapping_add)
+            });
+            self.order_insensitive = self.order_insensitive.wrapping_add(sum);
+        }
+        Ok(())
+    }
+
+    /// Get the [`ContentHash`] of all rows passed to [`ContentHasher::update`] so far.
+    pub fn finish(&self) -> ContentHash {
+        let mut header = Vec::new();
+        for (name, dtype) in self.schema.iter() {
+            let dtype = dtype.to_string();
+            for part in [name.as_bytes(), dtype.as_bytes()] {
+                header.extend_from_slice(&(part.len() as u64).to_le_bytes());
+                header.extend_from_slice(part);
+            }
+        }
+        header.extend_from_slice(&self.height.to_le_bytes());
+
+        let mut ordered = Xxh3::new();
+        ordered.update(&header);
+        ordered.update(&self.ordered.digest128().to_le_bytes());
+
+        header.extend_from_slice(&self.order_insensitive.to_le_bytes());
+        ContentHash {
+            ordered: ordered.digest128(),
+            order_insensitive: xxh3_128(&header),
+        }
+    }
+}
+
+/// Categorical physicals depend on the string cache, so encode their strings instead.
+fn stable_encoding_column(s: &Series) -> PolarsResult<Series> {
+    match s.dtype() {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => s.cast(&DataType::String),
+        _ => Ok(s.clone()),
+    }
+}
+
+impl DataFrame {
+    /// Compute fingerprints of the schema and the rows of the [`DataFrame`].
+    ///
+    /// See [`ContentHash`] for the stability guarantees, and [`ContentHasher`] to
+    /// fingerprint a frame batch by batch.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("a" => [1, 2, 3], "b" => ["x", "y", "z"])?;
+    /// let reversed = df.reverse();
+    ///
+    /// let hash = df.content_hash()?;
+    /// let reversed_hash = reversed.content_hash()?;
+    /// assert_ne!(hash.ordered, reversed_hash.ordered);
+    /// assert_eq!(hash.order_insensitive, reversed_hash.order_insensitive);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn content_hash(&self) -> PolarsResult<ContentHash> {
+        let mut hasher = ContentHasher::new(self.schema());
+        hasher.update(self)?;
+        Ok(hasher.finish())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_content_hash() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [Some(1i64), None, Some(3), Some(4)],
+            "b" => [Some("x"), Some("y"), None, Some("a long string value")],
+        )?;
+        let hash = df.content_hash()?;
+
+        // Chunking and batching don't matter.
+        let mut chunked = df.slice(0, 1);
+        chunked.vstack_mut(&df.slice(1, 3))?;
+        assert_eq!(chunked.content_hash()?, hash);
+        let mut hasher = ContentHasher::new(df.schema());
+        hasher.update(&df.slice(0, 3))?;
+        hasher.update(&df.slice(3, 1))?;
+        assert_eq!(hasher.finish(), hash);
+
+        let reversed = df.reverse().content_hash()?;
+        assert_ne!(reversed.ordered, hash.ordered);
+        assert_eq!(reversed.order_insensitive, hash.order_insensitive);
+
+        // Values, duplicates, names and dtypes all matter.
+        let others = [
+            df.slice(0, 3),
+            df.vstack(&df.slice(0, 1))?,
+            df.select(["b", "a"])?,
+            df.clone().rename("a", "c")?.clone(),
+            df.clone()
+                .with_column(df.column("a")?.cast(&DataType::Int32)?)?
+                .clone(),
+        ];
+        for other in others {
+            let other = other.content_hash()?;
+            assert_ne!(other.ordered, hash.ordered);
+            assert_ne!(other.order_insensitive, hash.order_insensitive);
+        }
+
+        let mut hasher = ContentHasher::new(df.schema());
+        assert!(hasher.update(&df.select(["a"])?).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,269 @@
+use crate::prelude::*;
+
+// The characters that separate the cells of a row, for the UTF8 and ASCII table presets.
+const CELL_SEPARATORS: [char; 3] = ['│', '┆', '|'];
+
+impl DataFrame {
+    /// Parse a [`DataFrame`] from its table representation, i.e. the output of `{:?}` or `{}`.
+    ///
+    /// This allows defining fixtures in tests and examples in the same way they are displayed:
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = DataFrame::from_repr(
+    ///     r#"
+    ///     shape: (2, 3)
+    ///     ┌─────┬──────┬───────┐
+    ///     │ a   ┆ b    ┆ c     │
+    ///     │ --- ┆ ---  ┆ ---   │
+    ///     │ i64 ┆ str  ┆ bool  │
+    ///     ╞═════╪══════╪═══════╡
+    ///     │ 1   ┆ foo  ┆ true  │
+    ///     │ 2   ┆ null ┆ false │
+    ///     └─────┴──────┴───────┘
+    ///     "#,
+    /// )?;
+    /// assert_eq!(df.shape(), (2, 3));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    ///
+    /// Both the UTF8 and the ASCII table presets are supported. The representation must include
+    /// the data types and may not be truncated. Values are parsed from their string
+    /// representation, so only numeric, boolean, string, temporal and categorical columns are
+    /// supported. The value `null` is always parsed as a missing value.
+    pub fn from_repr(repr: &str) -> PolarsResult<DataFrame> {
+        let mut shape = None;
+        let mut header: Vec<Vec<&str>> = vec![];
+        let mut rows: Vec<Vec<&str>> = vec![];
+        // Rows before the double line are part of the header.
+        let mut in_body = false;
+
+        for line in repr.lines().map(str::trim) {
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(s) = line.strip_prefix("shape:") {
+                shape = Some(parse_shape(s.trim())?);
+                continue;
+            }
+            match line.strip_prefix(CELL_SEPARATORS) {
+                Some(line) => {
+                    let line = line.strip_suffix(CELL_SEPARATORS).unwrap_or(line);
+                    let cells = line.split(CELL_SEPARATORS).map(str::trim).collect();
+                    if in_body {
+                        rows.push(cells)
+                    } else {
+                        header.push(cells)
+                    }
+                },
+                None if line.contains(['═', '=']) => in_body = true,
+                None => {},
+            }
+        }
+        polars_ensure!(
+            !header.is_empty(),
+            ComputeError: "could not find the header of the table representation"
+        );
+        let width = header[0].len();
+        polars_ensure!(
+            header.iter().chain(rows.iter()).all(|cells| cells.len() == width),
+            ComputeError: "all rows of the table representation should have {} cells", width
+        );
+        polars_ensure!(
+            !rows.iter().any(|cells| cells.iter().all(|c| *c == "…")),
+            ComputeError: "cannot parse a table representation with hidden rows"
+        );
+
+        let columns = (0..width)
+            .map(|i| {
+                let cells = header.iter().map(|cells| cells[i]).collect::<Vec<_>>();
+                polars_ensure!(
+                    cells[0] != "…",
+                    ComputeError: "cannot parse a table representation with hidden columns"
+                );
+                let Some(sep) = cells.iter().position(|c| *c == "---") else {
+                    polars_bail!(ComputeError: "the table representation should include the data types")
+                };
+                let name = cells[..sep].join("");
+                let dtype = cells[sep + 1..].join("");
+                let Some(dtype) = parse_dtype(&dtype) else {
+                    polars_bail!(ComputeError: "cannot parse column '{}' of data type '{}' from its representation", name, dtype)
+                };
+                let values = rows.iter().map(|cells| cells[i]);
+                parse_column(&name, values, &dtype)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let df = DataFrame::new(columns)?;
+        if let Some(shape) = shape {
+            polars_ensure!(
+                df.shape() == shape,
+                ShapeMismatch: "the parsed shape {:?} does not match the shape {:?} of the table representation",
+                df.shape(), shape
+            );
+        }
+        Ok(df)
+    }
+}
+
+fn parse_shape(s: &str) -> PolarsResult<(usize, usize)> {
+    let parse = || {
+        let (height, width) = s.strip_prefix('(')?.strip_suffix(')')?.split_once(',')?;
+        let parse_len = |v: &str| v.trim().replace('_', "").parse::<usize>().ok();
+        Some((parse_len(height)?, parse_len(width)?))
+    };
+    parse().ok_or_else(|| polars_err!(ComputeError: "could not parse shape '{}'", s))
+}
+
+fn parse_time_unit(s: &str) -> Option<TimeUnit> {
+    match s {
+        "ns" => Some(TimeUnit::Nanoseconds),
+        "μs" | "us" => Some(TimeUnit::Microseconds),
+        "ms" => Some(TimeUnit::Milliseconds),
+        _ => None,
+    }
+}
+
+fn parse_dtype(s: &str) -> Option<DataType> {
+    let dtype = match s {
+        "null" => DataType::Null,
+        "bool" => DataType::Boolean,
+        "u8" => DataType::UInt8,
+        "u16" => DataType::UInt16,
+        "u32" => DataType::UInt32,
+        "u64" => DataType::UInt64,
+        "i8" => DataType::Int8,
+        "i16" => DataType::Int16,
+        "i32" => DataType::Int32,
+        "i64" => DataType::Int64,
+        "f32" => DataType::Float32,
+        "f64" => DataType::Float64,
+        "str" => DataType::String,
+        "date" => DataType::Date,
+        "time" => DataType::Time,
+        #[cfg(feature = "dtype-categorical")]
+        "cat" => DataType::Categorical(None, Default::default()),
+        _ => {
+            let inner = s.strip_prefix("datetime[")?.strip_suffix(']')?;
+            // The values of a datetime with a time zone are displayed in that time zone.
+            DataType::Datetime(parse_time_unit(inner)?, None)
+        },
+    };
+    Some(dtype)
+}
+
+fn parse_column<'a>(
+    name: &str,
+    values: impl Iterator<Item = &'a str>,
+    dtype: &DataType,
+) -> PolarsResult<Series> {
+    let values = values.map(|v| (v != "null").then_some(v));
+    if dtype == &DataType::Boolean {
+        let values = values
+            .map(|v| match v {
+                None => Ok(None),
+                Some("true") => Ok(Some(true)),
+                Some("false") => Ok(Some(false)),
+                Some(v) => polars_bail!(ComputeError: "could not parse '{}' as bool", v),
+            })
+            .collect::<PolarsResult<BooleanChunked>>()?;
+        return Ok(values.with_name(name).into_series());
+    }
+    match dtype {
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(tu, None) => {
+            let to_timestamp = match tu {
+                TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
+                TimeUnit::Microseconds => datetime_to_timestamp_us,
+                TimeUnit::Milliseconds => datetime_to_timestamp_ms,
+            };
+            let ca = parse_values(values, dtype, |v| {
+                chrono::NaiveDateTime::parse_from_str(v, "%Y-%m-%d %H:%M:%S%.f")
+                    .ok()
+                    .map(to_timestamp)
+            })?;
+            Ok(ca.with_name(name).into_datetime(*tu, None).into_series())
+        },
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => {
+            let ca = parse_values(values, dtype, |v| {
+                chrono::NaiveTime::parse_from_str(v, "%H:%M:%S%.f")
+                    .ok()
+                    .map(|t| crate::chunked_array::temporal::time_to_time64ns(&t))
+            })?;
+            Ok(ca.with_name(name).into_time().into_series())
+        },
+        _ => {
+            let s = StringChunked::from_iter_options(name, values).into_series();
+            s.strict_cast(dtype)
+        },
+    }
+}
+
+#[cfg(any(feature = "dtype-datetime", feature = "dtype-time"))]
+fn parse_values<'a>(
+    values: impl Iterator<Item = Option<&'a str>>,
+    dtype: &DataType,
+    parse: impl Fn(&str) -> Option<i64>,
+) -> PolarsResult<Int64Chunked> {
+    values
+        .map(|v| {
+            v.map(|v| {
+                parse(v).ok_or_else(
+                    || polars_err!(ComputeError: "could not parse '{}' as {}", v, dtype),
+                )
+            })
+            .transpose()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::values;
+
+    #[test]
+    #[cfg(feature = "fmt")]
+    fn test_from_repr_roundtrip() -> PolarsResult<()> {
+        let df = values! {
+            ["int", "float": Float32, "str", "bool", "date": Date, "datetime": Datetime(TimeUnit::Milliseconds, None), "time": Time];
+            [1, 1.5, "foo", true, 19000, 1_000_000_000_123i64, 3_600_000_000_123i64],
+            [-2, null, "null str", null, null, null, null],
+            [null, 3.0, null, false, 0, 0i64, 0i64],
+        }?;
+        assert_eq!(df.column("int")?.dtype(), &DataType::Int32);
+
+        let out = DataFrame::from_repr(&format!("{df:?}"))?;
+        assert!(out.equals_missing(&df));
+        assert!(DataFrame::from_repr(&format!("{:?}", df.head(Some(0))))?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_repr_errors() {
+        let repr = r#"
+            ┌─────┬─────┐
+            │ a   ┆ b   │
+            │ --- ┆ --- │
+            │ i64 ┆ str │
+            ╞═════╪═════╡
+            │ x   ┆ y   │
+            └─────┴─────┘
+        "#;
+        assert!(DataFrame::from_repr(repr).is_err());
+
+        let repr = r#"
+            +-----+-----+
+            | a   | b   |
+            | --- | --- |
+            | i64 | str |
+            +=====+=====+
+            | 1   | y   |
+            | …   | …   |
+            +-----+-----+
+        "#;
+        assert!(DataFrame::from_repr(repr).is_err());
+        assert!(values! { ["a", "b"]; [1, 2], [3] }.is_err());
+    }
+}
@@ -0,0 +1,51 @@
+use super::*;
+
+impl CategoricalChunked {
+    /// Aggregate every group to the category that sorts first (`keep` is `Ordering::Less`) or
+    /// last, in the ordering of the categorical. The output shares the rev map of `self`.
+    unsafe fn agg_min_max(&self, groups: &GroupsProxy, keep: Ordering) -> Series {
+        let cats = if self.uses_lexical_ordering() {
+            let rev_map = self.get_rev_map();
+            let cats = self.physical().rechunk();
+            let pick = |acc: Option<u32>, i: usize| match (acc, cats.get_unchecked(i)) {
+                (Some(acc), Some(cat))
+                    if rev_map.get_unchecked(cat).cmp(rev_map.get_unchecked(acc)) == keep =>
+                {
+                    Some(cat)
+                },
+                (None, cat) => cat,
+                (acc, _) => acc,
+            };
+            match groups {
+                GroupsProxy::Idx(groups) => _agg_helper_idx::<UInt32Type, _>(groups, |(_, idx)| {
+                    idx.iter().fold(None, |acc, i| pick(acc, *i as usize))
+                }),
+                GroupsProxy::Slice { groups, .. } => {
+                    _agg_helper_slice::<UInt32Type, _>(groups, |[first, len]| {
+                        (first..first + len).fold(None, |acc, i| pick(acc, i as usize))
+                    })
+                },
+            }
+        } else if keep == Ordering::Less {
+            self.physical().agg_min(groups)
+        } else {
+            self.physical().agg_max(groups)
+        };
+        let cats = cats.u32().unwrap().clone().with_name(self.name());
+        CategoricalChunked::from_cats_and_rev_map_unchecked(
+            cats,
+            self.get_rev_map().clone(),
+            self.is_enum(),
+            self.get_ordering(),
+        )
+        .into_series()
+    }
+
+    pub(crate) unsafe fn agg_min(&self, groups: &GroupsProxy) -> Series {
+        self.agg_min_max(groups, Ordering::Less)
+    }
+
+    pub(crate) unsafe fn agg_max(&self, groups: &GroupsProxy) -> Series {
+        self.agg_min_max(groups, Ordering::Greater)
+    }
+}
@@ -92,6 +92,43 @@ impl Series {
         s.restore_logical(out)
     }
 
+    #[doc(hidden)]
+    pub unsafe fn agg_first_non_null(&self, groups: &GroupsProxy) -> Series {
+        if self.null_count() == 0 {
+            return self.agg_first(groups);
+        }
+        let s = self.rechunk();
+        let valid = s.is_not_null();
+        let valid = valid.downcast_iter().next().unwrap();
+
+        let out = match groups {
+            GroupsProxy::Idx(groups) => {
+                let indices = groups
+                    .all()
+                    .iter()
+                    .map(|idx| {
+                        idx.iter()
+                            .copied()
+                            .find(|&i| valid.value_unchecked(i as usize))
+                    })
+                    .collect_ca("");
+                // SAFETY: groups are always in bounds.
+                s.take_unchecked(&indices)
+            },
+            GroupsProxy::Slice { groups, .. } => {
+                let indices = groups
+                    .iter()
+                    .map(|&[first, len]| {
+                        (first..first + len).find(|&i| valid.value_unchecked(i as usize))
+                    })
+                    .collect_ca("");
+                // SAFETY: groups are always in bounds.
+                s.take_unchecked(&indices)
+            },
+        };
+        s.restore_logical(out)
+    }
+
     #[doc(hidden)]
     pub unsafe fn agg_n_unique(&self, groups: &GroupsProxy) -> Series {
         // Prevent a rechunk for every individual group.
@@ -139,6 +176,8 @@ impl Series {
         use DataType::*;
         match s.dtype() {
             Boolean => s.cast(&Float64).unwrap().agg_mean(groups),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s.cast(&Float32).unwrap().agg_mean(groups),
             Float32 => SeriesWrap(s.f32().unwrap().clone()).agg_mean(groups),
             Float64 => SeriesWrap(s.f64().unwrap().clone()).agg_mean(groups),
             dt if dt.is_numeric() => apply_method_physical_integer!(s, agg_mean, groups),
@@ -191,6 +230,8 @@ impl Series {
         use DataType::*;
         match s.dtype() {
             Boolean => s.cast(&Float64).unwrap().agg_median(groups),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s.cast(&Float32).unwrap().agg_median(groups),
             Float32 => SeriesWrap(s.f32().unwrap().clone()).agg_median(groups),
             Float64 => SeriesWrap(s.f64().unwrap().clone()).agg_median(groups),
             dt if dt.is_numeric() => apply_method_physical_integer!(s, agg_median, groups),
@@ -247,6 +288,11 @@ impl Series {
 
         use DataType::*;
         match s.dtype() {
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s
+                .cast(&Float32)
+                .unwrap()
+                .agg_quantile(groups, quantile, interpol),
             Float32 => s.f32().unwrap().agg_quantile(groups, quantile, interpol),
             Float64 => s.f64().unwrap().agg_quantile(groups, quantile, interpol),
             dt if dt.is_numeric() || dt.is_temporal() => {
@@ -306,4 +352,44 @@ impl Series {
         };
         s.restore_logical(out)
     }
+
+    #[doc(hidden)]
+    pub unsafe fn agg_last_non_null(&self, groups: &GroupsProxy) -> Series {
+        if self.null_count() == 0 {
+            return self.agg_last(groups);
+        }
+        let s = self.rechunk();
+        let valid = s.is_not_null();
+        let valid = valid.downcast_iter().next().unwrap();
+
+        let out = match groups {
+            GroupsProxy::Idx(groups) => {
+                let indices = groups
+                    .all()
+                    .iter()
+                    .map(|idx| {
+                        idx.iter()
+                            .rev()
+                            .copied()
+                            .find(|&i| valid.value_unchecked(i as usize))
+                    })
+                    .collect_ca("");
+                // SAFETY: groups are always in bounds.
+                s.take_unchecked(&indices)
+            },
+            GroupsProxy::Slice { groups, .. } => {
+                let indices = groups
+                    .iter()
+                    .map(|&[first, len]| {
+                        (first..first + len)
+                            .rev()
+                            .find(|&i| valid.value_unchecked(i as usize))
+                    })
+                    .collect_ca("");
+                // SAFETY: groups are always in bounds.
+                s.take_unchecked(&indices)
+            },
+        };
+        s.restore_logical(out)
+    }
 }
@@ -1,5 +1,7 @@
 mod agg_list;
 mod boolean;
+#[cfg(feature = "dtype-categorical")]
+mod categorical;
 mod dispatch;
 mod string;
 
@@ -856,7 +856,9 @@ pub enum GroupByMethod {
     Median,
     Mean,
     First,
+    FirstNonNull,
     Last,
+    LastNonNull,
     Sum,
     Groups,
     NUnique,
@@ -878,7 +880,9 @@ impl Display for GroupByMethod {
             Median => "median",
             Mean => "mean",
             First => "first",
+            FirstNonNull => "first_non_null",
             Last => "last",
+            LastNonNull => "last_non_null",
             Sum => "sum",
             Groups => "groups",
             NUnique => "n_unique",
@@ -903,7 +907,9 @@ pub fn fmt_group_by_column(name: &str, method: GroupByMethod) -> String {
         Median => format!("{name}_median"),
         Mean => format!("{name}_mean"),
         First => format!("{name}_first"),
+        FirstNonNull => format!("{name}_first_non_null"),
         Last => format!("{name}_last"),
+        LastNonNull => format!("{name}_last_non_null"),
         Sum => format!("{name}_sum"),
         Groups => "groups".to_string(),
         NUnique => format!("{name}_n_unique"),
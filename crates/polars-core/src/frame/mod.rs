@@ -1,5 +1,4 @@
 //! DataFrame module.
-#[cfg(feature = "zip_with")]
 use std::borrow::Cow;
 use std::{mem, ops};
 
@@ -16,8 +15,11 @@ use crate::utils::{slice_offsets, try_get_supertype, NoNull};
 #[cfg(feature = "dataframe_arithmetic")]
 mod arithmetic;
 mod chunks;
+#[cfg(feature = "row_hash")]
+pub mod content_hash;
 pub mod explode;
 mod from;
+mod from_repr;
 #[cfg(feature = "algorithm_group_by")]
 pub mod group_by;
 #[cfg(any(feature = "rows", feature = "object"))]
@@ -25,6 +27,7 @@ pub mod row;
 mod top_k;
 mod upstream_traits;
 
+use arrow::compute::aggregate::BufferSizes;
 use arrow::record_batch::RecordBatch;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -35,7 +38,9 @@ use crate::chunked_array::cast::CastOptions;
 use crate::hashing::_df_rows_to_hashes_threaded_vertical;
 #[cfg(feature = "zip_with")]
 use crate::prelude::min_max_binary::min_max_binary_series;
-use crate::prelude::sort::{argsort_multiple_row_fmt, prepare_arg_sort};
+use crate::prelude::sort::{
+    argsort_multiple_row_fmt, is_sorted_by_flag, nan_ordered_sort_keys, prepare_arg_sort,
+};
 use crate::series::IsSorted;
 use crate::POOL;
 
@@ -60,6 +65,20 @@ pub enum UniqueKeepStrategy {
     Any,
 }
 
+/// How [`DataFrame::vstack_relaxed`] and [`DataFrame::extend_relaxed`] deal with columns whose
+/// data types differ.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DtypeMergePolicy {
+    /// Raise an error on mismatching data types.
+    #[default]
+    Error,
+    /// Cast both columns to their common supertype.
+    Supertype,
+    /// Strictly cast the appended column to the data type of the left column.
+    CastToLeft,
+}
+
 /// A contiguous growable collection of `Series` that have the same length.
 ///
 /// ## Use declarations
@@ -156,6 +175,30 @@ impl DataFrame {
         self.columns.iter().map(|s| s.estimated_size()).sum()
     }
 
+    /// Returns a [`DataFrame`] with the estimated size in bytes of the buffers of every column,
+    /// split into the data, validity, offsets and string view heap buffers.
+    ///
+    /// See [`Series::estimated_buffer_sizes`] for the meaning of `deep`.
+    pub fn memory_usage(&self, deep: bool) -> PolarsResult<DataFrame> {
+        let sizes = self
+            .columns
+            .iter()
+            .map(|s| s.estimated_buffer_sizes(deep))
+            .collect::<Vec<_>>();
+        let column = |name: &str, f: fn(&BufferSizes) -> usize| {
+            Series::new(name, sizes.iter().map(|s| f(s) as u64).collect::<Vec<_>>())
+        };
+
+        DataFrame::new(vec![
+            Series::new("column", self.get_column_names()),
+            column("data", |s| s.data),
+            column("validity", |s| s.validity),
+            column("offsets", |s| s.offsets),
+            column("heap", |s| s.heap),
+            column("total", BufferSizes::total),
+        ])
+    }
+
     // Reduce monomorphization.
     pub fn _apply_columns(&self, func: &(dyn Fn(&Series) -> Series)) -> Vec<Series> {
         self.columns.iter().map(func).collect()
@@ -951,6 +994,71 @@ impl DataFrame {
         Ok(self)
     }
 
+    /// Concatenate a [`DataFrame`] to this [`DataFrame`], resolving mismatching column data types
+    /// according to `policy`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df1: DataFrame = df!("a" => &[1i64, 2])?;
+    /// let df2: DataFrame = df!("a" => &[3i32])?;
+    ///
+    /// let out = df1.vstack_relaxed(&df2, DtypeMergePolicy::Supertype)?;
+    /// assert_eq!(out.column("a")?.dtype(), &DataType::Int64);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn vstack_relaxed(
+        &self,
+        other: &DataFrame,
+        policy: DtypeMergePolicy,
+    ) -> PolarsResult<Self> {
+        let mut df = self.clone();
+        df.vstack_mut_relaxed(other, policy)?;
+        Ok(df)
+    }
+
+    /// Concatenate a [`DataFrame`] to this [`DataFrame`] in place, resolving mismatching column
+    /// data types according to `policy`.
+    ///
+    /// With [`DtypeMergePolicy::Supertype`] the columns of this [`DataFrame`] may be cast as well.
+    pub fn vstack_mut_relaxed(
+        &mut self,
+        other: &DataFrame,
+        policy: DtypeMergePolicy,
+    ) -> PolarsResult<&mut Self> {
+        if self.width() != other.width() || policy == DtypeMergePolicy::Error {
+            return self.vstack_mut(other);
+        }
+
+        // Cast all columns before anything is appended, so that an error leaves `self` untouched.
+        let merged = self.merge_dtypes(other, policy)?;
+        for (left, (new_left, right)) in self.columns.iter_mut().zip(merged) {
+            if let Some(new_left) = new_left {
+                *left = new_left;
+            }
+            left.append(&right)?;
+        }
+        Ok(self)
+    }
+
+    /// Cast the columns of `self` and `other` to the data types they are merged into under
+    /// `policy`. Returns the cast column of `self`, if it changed, and the column of `other`.
+    fn merge_dtypes<'a>(
+        &self,
+        other: &'a DataFrame,
+        policy: DtypeMergePolicy,
+    ) -> PolarsResult<Vec<(Option<Series>, Cow<'a, Series>)>> {
+        self.columns
+            .iter()
+            .zip(other.columns.iter())
+            .map(|(left, right)| {
+                ensure_can_extend(left, right)?;
+                merge_dtypes(left, right, policy)
+            })
+            .collect()
+    }
+
     /// Does not check if schema is correct
     pub(crate) fn vstack_mut_unchecked(&mut self, other: &DataFrame) {
         self.columns
@@ -992,6 +1100,32 @@ impl DataFrame {
             })
     }
 
+    /// Extend the memory backed by this [`DataFrame`] with the values from `other`, resolving
+    /// mismatching column data types according to `policy`.
+    ///
+    /// See [`extend`](Self::extend) for when to prefer this over
+    /// [`vstack_mut_relaxed`](Self::vstack_mut_relaxed).
+    pub fn extend_relaxed(
+        &mut self,
+        other: &DataFrame,
+        policy: DtypeMergePolicy,
+    ) -> PolarsResult<()> {
+        polars_ensure!(
+            self.width() == other.width(),
+            ShapeMismatch:
+            "unable to extend a DataFrame of width {} with a DataFrame of width {}",
+            self.width(), other.width(),
+        );
+        let merged = self.merge_dtypes(other, policy)?;
+        for (left, (new_left, right)) in self.columns.iter_mut().zip(merged) {
+            if let Some(new_left) = new_left {
+                *left = new_left;
+            }
+            left.extend(&right)?;
+        }
+        Ok(())
+    }
+
     /// Remove a column by name and return the column removed.
     ///
     /// # Example
@@ -1757,8 +1891,15 @@ impl DataFrame {
         // as expressions are not present (they are renamed to _POLARS_SORT_COLUMN_i.
         let first_descending = sort_options.descending[0];
         let first_by_column = by_column[0].name().to_string();
+        // The sorted flag of a float column implies that `NaN`s are the largest values.
+        let first_keeps_flag =
+            !by_column[0].dtype().is_float() || sort_options.nan_ordering == NanOrdering::Largest;
+        let by_column = nan_ordered_sort_keys(by_column, sort_options.nan_ordering);
 
         let set_sorted = |df: &mut DataFrame| {
+            if !first_keeps_flag {
+                return;
+            }
             // Mark the first sort column as sorted; if the column does not exist it
             // is ok, because we sorted by an expression not present in the dataframe
             let _ = df.apply(&first_by_column, |s| {
@@ -1776,6 +1917,18 @@ impl DataFrame {
             set_sorted(&mut out);
             return Ok(out);
         }
+        // The rows already are in order if the flag of the only sort column says so.
+        if by_column.len() == 1
+            && first_keeps_flag
+            && is_sorted_by_flag(&by_column[0], first_descending, sort_options.nulls_last[0])
+        {
+            let mut out = match slice {
+                Some((offset, len)) => self.slice(offset, len),
+                None => self.clone(),
+            };
+            set_sorted(&mut out);
+            return Ok(out);
+        }
         if let Some((0, k)) = slice {
             return self.bottom_k_impl(k, by_column, sort_options);
         }
@@ -1800,11 +1953,12 @@ impl DataFrame {
                     nulls_last: sort_options.nulls_last[0],
                     multithreaded: sort_options.multithreaded,
                     maintain_order: sort_options.maintain_order,
+                    nan_ordering: sort_options.nan_ordering,
                 };
                 // fast path for a frame with a single series
                 // no need to compute the sort indices and then take by these indices
                 // simply sort and return as frame
-                if df.width() == 1 && df.check_name_to_idx(s.name()).is_ok() {
+                if df.width() == 1 && first_keeps_flag && df.check_name_to_idx(s.name()).is_ok() {
                     let mut out = s.sort_with(options)?;
                     if let Some((offset, len)) = slice {
                         out = out.slice(offset, len);
@@ -1822,6 +1976,7 @@ impl DataFrame {
                         &by_column,
                         sort_options.descending,
                         sort_options.nulls_last,
+                        sort_options.nan_ordering,
                         sort_options.multithreaded,
                     )?
                 } else {
@@ -2188,6 +2343,41 @@ impl DataFrame {
         self.try_apply_at_idx(idx, f)
     }
 
+    /// Mutate the values of a numeric column with a closure that is called for every chunk.
+    ///
+    /// The values are mutated in place if their buffers are uniquely owned. If they are shared,
+    /// e.g. with a clone of this [`DataFrame`], they are copied first. The returned [`Mutation`]
+    /// reports which of the two happened. This is the safe alternative to mutating the chunks of
+    /// a column directly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let mut df = df!("a" => [1, 2, 3])?;
+    /// let mutation = df.apply_at::<Int32Type, _>("a", |values| {
+    ///     values.iter_mut().for_each(|v| *v *= 10)
+    /// })?;
+    /// assert_eq!(mutation, Mutation::InPlace);
+    /// assert_eq!(Vec::from(df.column("a")?.i32()?), &[Some(10), Some(20), Some(30)]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn apply_at<T, F>(&mut self, name: &str, f: F) -> PolarsResult<Mutation>
+    where
+        T: PolarsNumericType,
+        F: FnMut(&mut [T::Native]),
+    {
+        let idx = self.check_name_to_idx(name)?;
+        let col = &mut self.columns[idx];
+        polars_ensure!(
+            col.dtype() == &T::get_dtype(),
+            SchemaMismatch: "cannot mutate column '{}' of dtype {} as {}",
+            name, col.dtype(), T::get_dtype()
+        );
+        let ca: &mut ChunkedArray<T> = col._get_inner_mut().as_mut();
+        Ok(ca.apply_slices_mut(f))
+    }
+
     /// Slice the [`DataFrame`] along the rows.
     ///
     /// # Example
@@ -3051,6 +3241,35 @@ fn ensure_can_extend(left: &Series, right: &Series) -> PolarsResult<()> {
     Ok(())
 }
 
+// Cast `left` and/or `right` so that they can be appended under `policy`.
+fn merge_dtypes<'a>(
+    left: &Series,
+    right: &'a Series,
+    policy: DtypeMergePolicy,
+) -> PolarsResult<(Option<Series>, Cow<'a, Series>)> {
+    if left.dtype() == right.dtype() {
+        return Ok((None, Cow::Borrowed(right)));
+    }
+    match policy {
+        DtypeMergePolicy::Error => Ok((None, Cow::Borrowed(right))),
+        DtypeMergePolicy::Supertype => {
+            let st = try_get_supertype(left.dtype(), right.dtype())?;
+            let left = if left.dtype() != &st {
+                Some(left.cast(&st)?)
+            } else {
+                None
+            };
+            let right = if right.dtype() != &st {
+                Cow::Owned(right.cast(&st)?)
+            } else {
+                Cow::Borrowed(right)
+            };
+            Ok((left, right))
+        },
+        DtypeMergePolicy::CastToLeft => Ok((None, Cow::Owned(right.strict_cast(left.dtype())?))),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -3262,4 +3481,97 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_apply_at_copy_on_write() -> PolarsResult<()> {
+        let mut df = df!("a" => [Some(1), None, Some(3)], "b" => ["x", "y", "z"])?;
+        let double = |values: &mut [i32]| values.iter_mut().for_each(|v| *v *= 2);
+
+        assert_eq!(df.apply_at::<Int32Type, _>("a", double)?, Mutation::InPlace);
+        let shared = df.clone();
+        assert_eq!(df.apply_at::<Int32Type, _>("a", double)?, Mutation::Copied);
+        // The copy is uniquely owned again.
+        assert_eq!(df.apply_at::<Int32Type, _>("a", double)?, Mutation::InPlace);
+
+        assert_eq!(
+            Vec::from(df.column("a")?.i32()?),
+            &[Some(8), None, Some(24)]
+        );
+        assert_eq!(
+            Vec::from(shared.column("a")?.i32()?),
+            &[Some(2), None, Some(6)]
+        );
+
+        assert!(df.apply_at::<Int64Type, _>("a", |_| {}).is_err());
+        assert!(df.apply_at::<Int32Type, _>("c", |_| {}).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_vstack_relaxed() -> PolarsResult<()> {
+        let df = df!("a" => [1i64, 2], "b" => ["x", "y"])?;
+        let other = df!("a" => [3i32], "b" => ["z"])?;
+
+        assert!(df.vstack(&other).is_err());
+        assert!(df.vstack_relaxed(&other, DtypeMergePolicy::Error).is_err());
+
+        let out = df.vstack_relaxed(&other, DtypeMergePolicy::Supertype)?;
+        assert_eq!(out.column("a")?.dtype(), &DataType::Int64);
+        assert_eq!(
+            Vec::from(out.column("a")?.i64()?),
+            &[Some(1), Some(2), Some(3)]
+        );
+
+        // The left frame is promoted as well.
+        let out = other.vstack_relaxed(&df, DtypeMergePolicy::Supertype)?;
+        assert_eq!(out.column("a")?.dtype(), &DataType::Int64);
+
+        let out = other.vstack_relaxed(&df, DtypeMergePolicy::CastToLeft)?;
+        assert_eq!(out.column("a")?.dtype(), &DataType::Int32);
+        let too_large = df!("a" => [i64::MAX], "b" => ["z"])?;
+        assert!(other
+            .vstack_relaxed(&too_large, DtypeMergePolicy::CastToLeft)
+            .is_err());
+
+        let mut extended = df.clone();
+        extended.extend_relaxed(&other, DtypeMergePolicy::Supertype)?;
+        assert_eq!(
+            Vec::from(extended.column("a")?.i64()?),
+            &[Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(extended.n_chunks(), 1);
+
+        // A failing column leaves the frame untouched, also when earlier columns were merged.
+        let mut df = df!("a" => [1i32], "b" => [1i64])?;
+        let other = df!("a" => [2i64], "b" => [i64::MAX])?;
+        let mut narrow = df!("a" => [1i32], "b" => [1i32])?;
+        assert!(narrow
+            .vstack_mut_relaxed(&other, DtypeMergePolicy::CastToLeft)
+            .is_err());
+        assert_eq!(narrow, df!("a" => [1i32], "b" => [1i32])?);
+        let bad = df!("a" => [2i64], "c" => [2i64])?;
+        assert!(df
+            .vstack_mut_relaxed(&bad, DtypeMergePolicy::Supertype)
+            .is_err());
+        assert_eq!(df, df!("a" => [1i32], "b" => [1i64])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_df_relaxed() -> PolarsResult<()> {
+        use crate::utils::concat_df_relaxed;
+
+        let int8 = df!("a" => [1i8])?;
+        let uint8 = df!("a" => [2u8])?;
+        let int16 = df!("a" => [3i16])?;
+
+        // The supertype is resolved over all frames, regardless of their order.
+        for frames in [[&int8, &uint8, &int16], [&int16, &uint8, &int8]] {
+            let out = concat_df_relaxed(frames, DtypeMergePolicy::Supertype)?;
+            assert_eq!(out.column("a")?.dtype(), &DataType::Int16);
+            assert_eq!(out.height(), 3);
+        }
+        assert!(concat_df_relaxed([&int8, &uint8], DtypeMergePolicy::Error).is_err());
+        Ok(())
+    }
 }
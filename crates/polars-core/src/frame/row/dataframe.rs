@@ -1,5 +1,66 @@
+use arrow::array::{BooleanArray, MutableBinaryViewArray, PrimitiveArray};
+
 use super::*;
 
+/// Collect `values` into an array of `data_type`, or return `None` if a value doesn't fit the
+/// data type.
+///
+/// The values are converted like the `AnyValueBuffer`s do.
+fn array_from_any_values<'a>(
+    values: impl Iterator<Item = &'a AnyValue<'a>>,
+    data_type: &ArrowDataType,
+) -> Option<ArrayRef> {
+    use ArrowDataType as D;
+    macro_rules! collect {
+        ($array:ty, |$v:ident| $map:expr) => {
+            values
+                .map(|value| match value {
+                    AnyValue::Null => Some(None),
+                    $v => $map.map(Some),
+                })
+                .collect::<Option<$array>>()?
+        };
+    }
+    macro_rules! collect_primitive {
+        ($T:ty) => {
+            collect!(PrimitiveArray<$T>, |v| v.extract::<$T>()).boxed()
+        };
+    }
+    let array = match data_type {
+        D::Boolean => collect!(BooleanArray, |v| match v {
+            AnyValue::Boolean(v) => Some(*v),
+            v => v.extract::<u8>().map(|v| v == 1),
+        })
+        .boxed(),
+        D::Int8 => collect_primitive!(i8),
+        D::Int16 => collect_primitive!(i16),
+        D::Int32 => collect_primitive!(i32),
+        D::Int64 => collect_primitive!(i64),
+        D::UInt8 => collect_primitive!(u8),
+        D::UInt16 => collect_primitive!(u16),
+        D::UInt32 => collect_primitive!(u32),
+        D::UInt64 => collect_primitive!(u64),
+        D::Float32 => collect_primitive!(f32),
+        D::Float64 => collect_primitive!(f64),
+        D::Utf8View => collect!(MutableBinaryViewArray<str>, |v| match v {
+            AnyValue::String(v) => Some(*v),
+            AnyValue::StringOwned(v) => Some(v.as_str()),
+            _ => None,
+        })
+        .freeze()
+        .boxed(),
+        D::BinaryView => collect!(MutableBinaryViewArray<[u8]>, |v| match v {
+            AnyValue::Binary(v) => Some(*v),
+            AnyValue::BinaryOwned(v) => Some(v.as_slice()),
+            _ => None,
+        })
+        .freeze()
+        .boxed(),
+        _ => return None,
+    };
+    Some(array)
+}
+
 impl DataFrame {
     /// Get a row from a [`DataFrame`]. Use of this is discouraged as it will likely be slow.
     pub fn get_row(&self, idx: usize) -> PolarsResult<Row> {
@@ -42,9 +103,35 @@ impl DataFrame {
     /// This should only be used when you have row wise data, as this is a lot slower
     /// than creating the [`Series`] in a columnar fashion
     pub fn from_rows_and_schema(rows: &[Row], schema: &Schema) -> PolarsResult<Self> {
+        if let Some(df) = Self::from_rows_direct(rows, schema) {
+            return Ok(df);
+        }
         Self::from_rows_iter_and_schema(rows.iter(), schema)
     }
 
+    /// Create the columns straight from the values of the rows, which skips dispatching every
+    /// value to the builder of its column.
+    ///
+    /// Returns `None` if not all rows fit the schema, or if the schema has data types that
+    /// can't be built this way. The caller then has to fall back to the builders, which also
+    /// report the values that don't fit.
+    fn from_rows_direct(rows: &[Row], schema: &Schema) -> Option<Self> {
+        let width = schema.len();
+        if rows.is_empty() || width == 0 || rows.iter().any(|row| row.0.len() != width) {
+            return None;
+        }
+        let columns = schema
+            .iter()
+            .enumerate()
+            .map(|(i, (name, dtype))| {
+                let values = rows.iter().map(|row| &row.0[i]);
+                let array = array_from_any_values(values, &dtype.to_arrow(true))?;
+                Series::try_from((name.as_str(), array)).ok()
+            })
+            .collect::<Option<Vec<_>>>()?;
+        DataFrame::new(columns).ok()
+    }
+
     /// Create a new [`DataFrame`] from an iterator over rows.
     ///
     /// This should only be used when you have row wise data, as this is a lot slower
@@ -143,4 +230,104 @@ impl DataFrame {
         );
         Self::from_rows_and_schema(rows, &schema)
     }
+
+    /// Create a new [`DataFrame`] from rows of values with the given column names.
+    ///
+    /// The data type of a column is the supertype of its values. If a data type is set in
+    /// `dtypes`, the values are strictly cast to it.
+    /// This is what the [`values!`](crate::values) macro expands to.
+    pub fn from_row_values(
+        names: &[&str],
+        dtypes: &[Option<DataType>],
+        rows: &[Vec<AnyValue>],
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            names.len() == dtypes.len(),
+            ShapeMismatch: "got {} column names but {} data types", names.len(), dtypes.len()
+        );
+        for (i, row) in rows.iter().enumerate() {
+            polars_ensure!(
+                row.len() == names.len(),
+                ShapeMismatch: "row {} has {} values, expected {}", i, row.len(), names.len()
+            );
+        }
+        let columns = names
+            .iter()
+            .zip(dtypes)
+            .enumerate()
+            .map(|(i, (name, dtype))| {
+                let values = rows.iter().map(|row| row[i].clone()).collect::<Vec<_>>();
+                let s = Series::from_any_values(name, &values, false)?;
+                match dtype {
+                    Some(dtype) => s.strict_cast(dtype),
+                    None => Ok(s),
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_rows_direct() -> PolarsResult<()> {
+        let rows = [
+            Row::new(vec![
+                AnyValue::Int64(1),
+                AnyValue::String("a"),
+                AnyValue::Boolean(true),
+            ]),
+            Row::new(vec![AnyValue::Int64(-2), AnyValue::Null, AnyValue::Null]),
+            Row::new(vec![
+                AnyValue::Null,
+                AnyValue::StringOwned("a string that is longer than a block".into()),
+                AnyValue::Int32(0),
+            ]),
+        ];
+        let schema = Schema::from_iter([
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::String),
+            Field::new("c", DataType::Boolean),
+        ]);
+        let df = DataFrame::from_rows_direct(&rows, &schema).unwrap();
+        let expected = DataFrame::from_rows_iter_and_schema(rows.iter(), &schema)?;
+        assert!(df.equals_missing(&expected));
+        assert_eq!(df.schema(), schema);
+
+        // Values that don't match the schema fall back to the builders.
+        let schema = Schema::from_iter([
+            Field::new("a", DataType::String),
+            Field::new("b", DataType::String),
+            Field::new("c", DataType::Boolean),
+        ]);
+        assert!(DataFrame::from_rows_direct(&rows, &schema).is_none());
+        let df = DataFrame::from_rows_and_schema(&rows, &schema)?;
+        let a = df.column("a")?.str()?.into_iter().collect::<Vec<_>>();
+        assert_eq!(a, [Some("1"), Some("-2"), None]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_rows_keeps_float_bits() -> PolarsResult<()> {
+        let nan = f64::from_bits(0x7ff8_0000_dead_beef);
+        let rows = [
+            Row::new(vec![AnyValue::Float64(-0.0), AnyValue::Float32(-0.0)]),
+            Row::new(vec![AnyValue::Float64(nan), AnyValue::Float64(nan)]),
+        ];
+        let schema = Schema::from_iter([
+            Field::new("a", DataType::Float64),
+            Field::new("b", DataType::Float32),
+        ]);
+        let df = DataFrame::from_rows_and_schema(&rows, &schema)?;
+        let a = df.column("a")?.f64()?.into_no_null_iter();
+        let a = a.map(f64::to_bits).collect::<Vec<_>>();
+        assert_eq!(a, [(-0.0f64).to_bits(), nan.to_bits()]);
+        let b = df.column("b")?.f32()?.into_no_null_iter();
+        let b = b.map(f32::to_bits).collect::<Vec<_>>();
+        assert_eq!(b, [(-0.0f32).to_bits(), (nan as f32).to_bits()]);
+        Ok(())
+    }
 }
@@ -30,9 +30,15 @@ mod tests;
 use std::sync::Mutex;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+pub use arrow::array::{
+    disable_string_pool, enable_string_pool, release_string_pool, set_string_pool_capacity,
+    string_pool_stats, using_string_pool, StringPoolStats,
+};
 pub use hashing::IdBuildHasher;
 use once_cell::sync::Lazy;
-use rayon::{ThreadPool, ThreadPoolBuilder};
+#[cfg(not(target_family = "wasm"))]
+use polars_utils::pool::PolarsThreadPool;
+use rayon::ThreadPoolBuilder;
 
 #[cfg(feature = "dtype-categorical")]
 pub use crate::chunked_array::logical::categorical::string_cache::*;
@@ -46,7 +52,7 @@ pub static PROCESS_ID: Lazy<u128> = Lazy::new(|| {
 
 // this is re-exported in utils for polars child crates
 #[cfg(not(target_family = "wasm"))] // only use this on non wasm targets
-pub static POOL: Lazy<ThreadPool> = Lazy::new(|| {
+pub static POOL: PolarsThreadPool = PolarsThreadPool::new(|| {
     let thread_name = std::env::var("POLARS_THREAD_NAME").unwrap_or_else(|_| "polars".to_string());
     ThreadPoolBuilder::new()
         .num_threads(
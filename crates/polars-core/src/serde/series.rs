@@ -45,6 +45,11 @@ impl Serialize for Series {
                 let ca = self.struct_().unwrap();
                 ca.serialize(serializer)
             },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                let ca = self.f16().unwrap();
+                ca.serialize(serializer)
+            },
             #[cfg(feature = "dtype-date")]
             DataType::Date => {
                 let ca = self.date().unwrap();
@@ -198,6 +203,13 @@ impl<'de> Deserialize<'de> for Series {
                         let values: Vec<Option<bool>> = map.next_value()?;
                         Ok(Series::new(&name, values))
                     },
+                    #[cfg(feature = "dtype-f16")]
+                    DataType::Float16 => {
+                        let values: Vec<Option<u16>> = map.next_value()?;
+                        Ok(UInt16Chunked::new(&name, values)
+                            .into_float16()
+                            .into_series())
+                    },
                     DataType::Float32 => {
                         let values: Vec<Option<f32>> = map.next_value()?;
                         Ok(Series::new(&name, values))
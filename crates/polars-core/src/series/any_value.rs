@@ -120,6 +120,10 @@ impl Series {
             DataType::UInt64 => any_values_to_integer::<UInt64Type>(values, strict)?
                 .with_cheap_metadata()
                 .into_series(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => any_values_to_f32(values, strict)?
+                .to_float16()
+                .into_series(),
             DataType::Float32 => any_values_to_f32(values, strict)?
                 .with_cheap_metadata()
                 .into_series(),
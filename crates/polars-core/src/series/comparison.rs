@@ -41,6 +41,15 @@ macro_rules! impl_compare {
 
         let (lhs, rhs) = coerce_lhs_rhs(lhs, rhs).map_err(|_| polars_err!(SchemaMismatch: "could not evalulate comparison between series '{}' of dtype: {} and series '{}' of dtype: {}",
         lhs.name(), lhs.dtype(), rhs.name(), rhs.dtype()))?;
+        // The physical bits of `f16` values don't order like the values.
+        #[cfg(feature = "dtype-f16")]
+        let (lhs, rhs) = match lhs.dtype() {
+            Float16 => (
+                std::borrow::Cow::Owned(lhs.cast(&Float32)?),
+                std::borrow::Cow::Owned(rhs.cast(&Float32)?),
+            ),
+            _ => (lhs, rhs),
+        };
         let lhs = lhs.to_physical_repr();
         let rhs = rhs.to_physical_repr();
         let mut out = match lhs.dtype() {
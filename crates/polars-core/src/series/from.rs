@@ -9,6 +9,8 @@ use arrow::legacy::kernels::concatenate::concatenate_owned_unchecked;
     feature = "dtype-duration"
 ))]
 use arrow::temporal_conversions::*;
+#[cfg(feature = "dtype-f16")]
+use arrow::types::f16;
 use polars_error::feature_gated;
 
 use crate::chunked_array::cast::{cast_chunks, CastOptions};
@@ -98,6 +100,10 @@ impl Series {
                 ca.into_series()
             },
             Boolean => BooleanChunked::from_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => UInt16Chunked::from_chunks(name, chunks)
+                .into_float16()
+                .into_series(),
             Float32 => Float32Chunked::from_chunks(name, chunks).into_series(),
             Float64 => Float64Chunked::from_chunks(name, chunks).into_series(),
             BinaryOffset => BinaryOffsetChunked::from_chunks(name, chunks).into_series(),
@@ -207,6 +213,20 @@ impl Series {
             ArrowDataType::Int16 => Ok(Int16Chunked::from_chunks(name, chunks).into_series()),
             ArrowDataType::Int32 => Ok(Int32Chunked::from_chunks(name, chunks).into_series()),
             ArrowDataType::Int64 => Ok(Int64Chunked::from_chunks(name, chunks).into_series()),
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| {
+                        let arr = arr.as_any().downcast_ref::<PrimitiveArray<f16>>().unwrap();
+                        arr.clone().transmute::<u16>().boxed()
+                    })
+                    .collect();
+                Ok(UInt16Chunked::from_chunks(name, chunks)
+                    .into_float16()
+                    .into_series())
+            },
+            #[cfg(not(feature = "dtype-f16"))]
             ArrowDataType::Float16 => {
                 let chunks =
                     cast_chunks(&chunks, &DataType::Float32, CastOptions::NonStrict).unwrap();
@@ -352,29 +372,34 @@ impl Series {
                 let keys = keys.as_any().downcast_ref::<PrimitiveArray<u32>>().unwrap();
                 let values = values.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
 
-                if let Some(metadata) = md {
-                    if metadata.get(DTYPE_ENUM_KEY) == Some(&DTYPE_ENUM_VALUE.into()) {
-                        // SAFETY:
-                        // the invariants of an Arrow Dictionary guarantee the keys are in bounds
-                        return Ok(CategoricalChunked::from_cats_and_rev_map_unchecked(
-                            UInt32Chunked::with_chunk(name, keys.clone()),
-                            Arc::new(RevMapping::build_local(values.clone())),
-                            true,
-                            Default::default(),
-                        )
-                        .into_series());
-                    }
+                let is_enum = md.map_or(false, |metadata| {
+                    metadata.get(DTYPE_ENUM_KEY) == Some(&DTYPE_ENUM_VALUE.into())
+                });
+                let (categories, ordering) = match md {
+                    Some(metadata) => categories_from_metadata(metadata)?,
+                    None => Default::default(),
+                };
+                let (keys, values) = match categories {
+                    Some(categories) => remap_to_categories(keys, values, categories, is_enum)?,
+                    None => (keys.clone(), values.clone()),
+                };
+
+                if is_enum {
+                    // SAFETY:
+                    // the invariants of an Arrow Dictionary guarantee the keys are in bounds
+                    return Ok(CategoricalChunked::from_cats_and_rev_map_unchecked(
+                        UInt32Chunked::with_chunk(name, keys),
+                        Arc::new(RevMapping::build_local(values)),
+                        true,
+                        ordering,
+                    )
+                    .into_series());
                 }
                 // SAFETY:
                 // the invariants of an Arrow Dictionary guarantee the keys are in bounds
                 Ok(
-                    CategoricalChunked::from_keys_and_values(
-                        name,
-                        keys,
-                        values,
-                        Default::default(),
-                    )
-                    .into_series(),
+                    CategoricalChunked::from_keys_and_values(name, &keys, &values, ordering)
+                        .into_series(),
                 )
             },
             #[cfg(feature = "object")]
@@ -660,6 +685,7 @@ unsafe fn to_physical_and_dtype(
         | ArrowDataType::Time64(_)
         | ArrowDataType::Timestamp(_, _)
         | ArrowDataType::Date32
+        | ArrowDataType::Float16
         | ArrowDataType::Decimal(_, _)
         | ArrowDataType::Date64) => {
             let dt = dt.clone();
@@ -692,6 +718,73 @@ fn check_types(chunks: &[ArrayRef]) -> PolarsResult<ArrowDataType> {
     Ok(data_type)
 }
 
+/// Remap dictionary `keys` from `values` to the `categories` stored in the field metadata.
+///
+/// Values that are missing from the categories are an error for enums. For categoricals they
+/// are appended, as the stored categories may predate the data.
+#[cfg(feature = "dtype-categorical")]
+fn remap_to_categories(
+    keys: &PrimitiveArray<u32>,
+    values: &Utf8ViewArray,
+    categories: Utf8ViewArray,
+    is_enum: bool,
+) -> PolarsResult<(PrimitiveArray<u32>, Utf8ViewArray)> {
+    if values.len() <= categories.len()
+        && values
+            .values_iter()
+            .zip(categories.values_iter())
+            .all(|(v, c)| v == c)
+    {
+        return Ok((keys.clone(), categories));
+    }
+
+    let mut appended = MutableBinaryViewArray::<str>::new();
+    let mapping = {
+        let mut positions: PlHashMap<&str, u32> = categories
+            .values_iter()
+            .enumerate()
+            .map(|(i, c)| (c, i as u32))
+            .collect();
+        values
+            .values_iter()
+            .map(|value| {
+                if let Some(idx) = positions.get(value) {
+                    return Ok(*idx);
+                }
+                polars_ensure!(
+                    !is_enum,
+                    ComputeError: "value '{}' is not one of the categories of the enum", value
+                );
+                let idx = (categories.len() + appended.len()) as u32;
+                appended.push_value(value);
+                positions.insert(value, idx);
+                Ok(idx)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?
+    };
+
+    let new_keys = keys
+        .values_iter()
+        .map(|k| mapping.get(*k as usize).copied().unwrap_or(0))
+        .collect::<Vec<_>>();
+    let keys = PrimitiveArray::new(
+        ArrowDataType::UInt32,
+        new_keys.into(),
+        keys.validity().cloned(),
+    );
+    let categories = if appended.is_empty() {
+        categories
+    } else {
+        let appended: Utf8ViewArray = appended.into();
+        let out = concatenate_owned_unchecked(&[categories.boxed(), appended.boxed()])?;
+        out.as_any()
+            .downcast_ref::<Utf8ViewArray>()
+            .unwrap()
+            .clone()
+    };
+    Ok((keys, categories))
+}
+
 impl TryFrom<(&str, Vec<ArrayRef>)> for Series {
     type Error = PolarsError;
 
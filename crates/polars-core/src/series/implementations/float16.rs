@@ -0,0 +1,384 @@
+//! `Float16` is backed by the bits of the `f16` values in a `UInt16` array.
+//!
+//! Data movement is done on the physical bits. Everything that looks at the values, like
+//! comparisons, aggregations and arithmetic, is computed in `f32`.
+use arrow::types::f16;
+
+use super::*;
+#[cfg(feature = "algorithm_group_by")]
+use crate::frame::group_by::*;
+use crate::prelude::*;
+
+unsafe impl IntoSeries for Float16Chunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl SeriesWrap<Float16Chunked> {
+    fn to_float32(&self) -> Series {
+        self.0.to_float32().into_series()
+    }
+
+    fn float32_to_float16(s: Series) -> Series {
+        s.f32().unwrap().to_float16().into_series()
+    }
+
+    fn float32_scalar_to_float16(&self, sc: Scalar) -> Scalar {
+        // The values of `Float16` are represented as `AnyValue::Float32`, so round the value to
+        // `f16` precision and keep that representation.
+        let value = match sc.value() {
+            AnyValue::Float32(v) => AnyValue::Float32(f16::from_f32(*v).to_f32()),
+            av => av.clone(),
+        };
+        Scalar::new(self.dtype().clone(), value)
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<Float16Chunked> {
+    fn compute_len(&mut self) {
+        self.0.compute_len()
+    }
+
+    fn _field(&self) -> Cow<Field> {
+        Cow::Owned(self.0.field())
+    }
+
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+
+    fn _get_flags(&self) -> MetadataFlags {
+        self.0.get_flags()
+    }
+
+    fn _set_flags(&mut self, flags: MetadataFlags) {
+        self.0.set_flags(flags)
+    }
+
+    fn explode_by_offsets(&self, offsets: &[i64]) -> Series {
+        self.0
+            .explode_by_offsets(offsets)
+            .u16()
+            .unwrap()
+            .clone()
+            .into_float16()
+            .into_series()
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.to_physical_repr().into_owned();
+        self.0
+            .zip_with(mask, other.as_ref().as_ref())
+            .map(|ca| ca.into_float16().into_series())
+    }
+
+    fn vec_hash(&self, random_state: RandomState, buf: &mut Vec<u64>) -> PolarsResult<()> {
+        self.to_float32().vec_hash(random_state, buf)?;
+        Ok(())
+    }
+
+    fn vec_hash_combine(&self, build_hasher: RandomState, hashes: &mut [u64]) -> PolarsResult<()> {
+        self.to_float32().vec_hash_combine(build_hasher, hashes)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_min(&self, groups: &GroupsProxy) -> Series {
+        Self::float32_to_float16(self.to_float32().agg_min(groups))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_max(&self, groups: &GroupsProxy) -> Series {
+        Self::float32_to_float16(self.to_float32().agg_max(groups))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_sum(&self, groups: &GroupsProxy) -> Series {
+        self.to_float32().agg_sum(groups)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_std(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        self.to_float32().agg_std(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_var(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        self.to_float32().agg_var(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsProxy) -> Series {
+        // we cannot cast and dispatch as the inner type of the list would be incorrect
+        self.0
+            .agg_list(groups)
+            .cast(&DataType::List(Box::new(self.dtype().clone())))
+            .unwrap()
+    }
+
+    fn subtract(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.to_float32().try_sub(&rhs.cast(&DataType::Float32)?)
+    }
+
+    fn add_to(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.to_float32().try_add(&rhs.cast(&DataType::Float32)?)
+    }
+
+    fn multiply(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.to_float32().try_mul(&rhs.cast(&DataType::Float32)?)
+    }
+
+    fn divide(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.to_float32().try_div(&rhs.cast(&DataType::Float32)?)
+    }
+
+    fn remainder(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.to_float32().try_rem(&rhs.cast(&DataType::Float32)?)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsProxy> {
+        self.to_float32().group_tuples(multithreaded, sorted)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Series],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        self.0.to_float32().arg_sort_multiple(by, options)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<Float16Chunked> {
+    fn rename(&mut self, name: &str) {
+        self.0.rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter {
+        self.0.chunk_lengths()
+    }
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.chunks_mut()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0.slice(offset, length).into_float16().into_series()
+    }
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.split_at(offset);
+        (
+            a.into_float16().into_series(),
+            b.into_float16().into_series(),
+        )
+    }
+
+    fn mean(&self) -> Option<f64> {
+        self.to_float32().mean()
+    }
+
+    fn median(&self) -> Option<f64> {
+        self.to_float32().median()
+    }
+
+    fn std(&self, ddof: u8) -> Option<f64> {
+        self.to_float32().std(ddof)
+    }
+
+    fn var(&self, ddof: u8) -> Option<f64> {
+        self.to_float32().var(ddof)
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let other = other.to_physical_repr();
+        self.0.append(other.as_ref().as_ref().as_ref());
+        Ok(())
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        let other = other.to_physical_repr();
+        self.0.extend(other.as_ref().as_ref().as_ref());
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        self.0
+            .filter(filter)
+            .map(|ca| ca.into_float16().into_series())
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        Ok(self.0.take(indices)?.into_float16().into_series())
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.0.take_unchecked(indices).into_float16().into_series()
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        Ok(self.0.take(indices)?.into_float16().into_series())
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.0.take_unchecked(indices).into_float16().into_series()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.0.rechunk().into_float16().into_series()
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.0
+            .new_from_index(index, length)
+            .into_float16()
+            .into_series()
+    }
+
+    fn cast(&self, data_type: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(data_type, cast_options)
+    }
+
+    fn get(&self, index: usize) -> PolarsResult<AnyValue> {
+        self.0.get_any_value(index)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        let idx = self.arg_sort(options);
+        // SAFETY: the sort indices are in bounds.
+        let mut out = unsafe { self.take_unchecked(&idx) };
+        out.set_sorted_flag(if options.descending {
+            IsSorted::Descending
+        } else {
+            IsSorted::Ascending
+        });
+        Ok(out)
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.0.to_float32().arg_sort(options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_validity(&self) -> bool {
+        self.0.has_validity()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        self.to_float32().unique().map(Self::float32_to_float16)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.to_float32().n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.to_float32().arg_unique()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.0.reverse().into_float16().into_series()
+    }
+
+    fn as_single_ptr(&mut self) -> PolarsResult<usize> {
+        self.0.as_single_ptr()
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.0.shift(periods).into_float16().into_series()
+    }
+
+    fn sum_reduce(&self) -> PolarsResult<Scalar> {
+        self.to_float32().sum_reduce()
+    }
+
+    fn max_reduce(&self) -> PolarsResult<Scalar> {
+        let sc = self.to_float32().max_reduce()?;
+        Ok(self.float32_scalar_to_float16(sc))
+    }
+
+    fn min_reduce(&self) -> PolarsResult<Scalar> {
+        let sc = self.to_float32().min_reduce()?;
+        Ok(self.float32_scalar_to_float16(sc))
+    }
+
+    fn median_reduce(&self) -> PolarsResult<Scalar> {
+        self.to_float32().median_reduce()
+    }
+
+    fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.to_float32().var_reduce(ddof)
+    }
+
+    fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.to_float32().std_reduce(ddof)
+    }
+
+    fn quantile_reduce(
+        &self,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    ) -> PolarsResult<Scalar> {
+        self.to_float32().quantile_reduce(quantile, interpol)
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<Float16Chunked> {
+    fn bit_repr_is_large(&self) -> bool {
+        false
+    }
+
+    // Equal values need equal bits, as for the other float types.
+    fn bit_repr_large(&self) -> UInt64Chunked {
+        self.0.to_canonical().bit_repr_large()
+    }
+
+    fn bit_repr_small(&self) -> UInt32Chunked {
+        self.0.to_canonical().bit_repr_small()
+    }
+}
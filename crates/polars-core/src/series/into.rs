@@ -6,6 +6,8 @@
 ))]
 use arrow::compute::cast::cast_default as cast;
 use arrow::compute::cast::cast_unchecked;
+#[cfg(feature = "dtype-f16")]
+use arrow::types::f16;
 
 use crate::prelude::*;
 
@@ -76,6 +78,14 @@ impl Series {
 
                 new.to_arrow(pl_flavor, false)
             },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                let arr = self.chunks()[chunk_idx]
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<u16>>()
+                    .unwrap();
+                arr.clone().transmute::<f16>().boxed()
+            },
             #[cfg(feature = "dtype-date")]
             DataType::Date => cast(
                 &*self.chunks()[chunk_idx],
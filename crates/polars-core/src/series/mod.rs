@@ -18,7 +18,7 @@ use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 
 use ahash::RandomState;
-use arrow::compute::aggregate::estimated_bytes_size;
+use arrow::compute::aggregate::{estimated_buffer_sizes, estimated_bytes_size, BufferSizes};
 use arrow::offset::Offsets;
 pub use from::*;
 pub use iterator::{SeriesIter, SeriesPhysIter};
@@ -465,6 +465,8 @@ impl Series {
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(_) => self.struct_().unwrap().cast_unchecked(dtype),
             DataType::List(_) => self.list().unwrap().cast_unchecked(dtype),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => self.cast_with_options(dtype, CastOptions::Overflowing),
             dt if dt.is_numeric() => {
                 with_match_physical_numeric_polars_type!(dt, |$T| {
                     let ca: &ChunkedArray<$T> = self.as_ref().as_ref().as_ref();
@@ -534,6 +536,8 @@ impl Series {
     /// Check if float value is NaN (note this is different than missing/ null)
     pub fn is_nan(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => self.cast(&DataType::Float32)?.is_nan(),
             DataType::Float32 => Ok(self.f32().unwrap().is_nan()),
             DataType::Float64 => Ok(self.f64().unwrap().is_nan()),
             dt if dt.is_numeric() => Ok(BooleanChunked::full(self.name(), false, self.len())),
@@ -544,6 +548,8 @@ impl Series {
     /// Check if float value is NaN (note this is different than missing/ null)
     pub fn is_not_nan(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => self.cast(&DataType::Float32)?.is_not_nan(),
             DataType::Float32 => Ok(self.f32().unwrap().is_not_nan()),
             DataType::Float64 => Ok(self.f64().unwrap().is_not_nan()),
             dt if dt.is_numeric() => Ok(BooleanChunked::full(self.name(), true, self.len())),
@@ -554,6 +560,8 @@ impl Series {
     /// Check if numeric value is finite
     pub fn is_finite(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => self.cast(&DataType::Float32)?.is_finite(),
             DataType::Float32 => Ok(self.f32().unwrap().is_finite()),
             DataType::Float64 => Ok(self.f64().unwrap().is_finite()),
             dt if dt.is_numeric() => Ok(BooleanChunked::full(self.name(), true, self.len())),
@@ -564,6 +572,8 @@ impl Series {
     /// Check if float value is infinite
     pub fn is_infinite(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => self.cast(&DataType::Float32)?.is_infinite(),
             DataType::Float32 => Ok(self.f32().unwrap().is_infinite()),
             DataType::Float64 => Ok(self.f64().unwrap().is_infinite()),
             dt if dt.is_numeric() => Ok(BooleanChunked::full(self.name(), false, self.len())),
@@ -594,6 +604,9 @@ impl Series {
         match self.dtype() {
             Date => Cow::Owned(self.cast(&Int32).unwrap()),
             Datetime(_, _) | Duration(_) | Time => Cow::Owned(self.cast(&Int64).unwrap()),
+            // A cast to `UInt16` converts the values, not the bits.
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Cow::Owned(self.f16().unwrap().0.clone().into_series()),
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => {
                 let ca = self.categorical().unwrap();
@@ -684,6 +697,16 @@ impl Series {
         self.cast_with_options(dtype, CastOptions::Strict)
     }
 
+    /// Cast an Enum to the Enum with `new_categories` appended to its categories.
+    ///
+    /// Categories that already exist are skipped. The physical codes are unchanged.
+    #[cfg(feature = "dtype-categorical")]
+    pub fn cast_enum_extend(&self, new_categories: &Utf8ViewArray) -> PolarsResult<Series> {
+        self.categorical()?
+            .extend_enum_categories(new_categories)
+            .map(|ca| ca.into_series())
+    }
+
     #[cfg(feature = "dtype-time")]
     pub(crate) fn into_time(self) -> Series {
         #[cfg(not(feature = "dtype-time"))]
@@ -813,6 +836,11 @@ impl Series {
                 let val = self.mean().map(|m| m as f32);
                 Scalar::new(self.dtype().clone(), val.into())
             },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                let val = self.mean().map(|m| m as f32);
+                Scalar::new(DataType::Float32, val.into())
+            },
             dt if dt.is_numeric() || matches!(dt, DataType::Boolean) => {
                 let val = self.mean();
                 Scalar::new(DataType::Float64, val.into())
@@ -898,6 +926,34 @@ impl Series {
         size
     }
 
+    /// Returns the estimated size of the buffers of the [`Series`] in bytes, split by the kind
+    /// of buffer.
+    ///
+    /// If `deep` is set, the heap of a string or binary [`Series`] counts all the buffers that it
+    /// keeps alive, rather than only the bytes that it refers to. See [`Series::estimated_size`]
+    /// for the caveats of the estimation.
+    pub fn estimated_buffer_sizes(&self, deep: bool) -> BufferSizes {
+        #[allow(unused_mut)]
+        let mut sizes = self
+            .chunks()
+            .iter()
+            .map(|arr| estimated_buffer_sizes(&**arr, deep))
+            .sum();
+        match self.dtype() {
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(Some(rv), _) | DataType::Enum(Some(rv), _) => match &**rv {
+                RevMapping::Local(arr, _) => sizes += estimated_buffer_sizes(arr, deep),
+                RevMapping::Global(map, arr, _) => {
+                    sizes.data += map.capacity() * std::mem::size_of::<u32>() * 2;
+                    sizes += estimated_buffer_sizes(arr, deep);
+                },
+            },
+            _ => {},
+        }
+
+        sizes
+    }
+
     /// Packs every element into a list.
     pub fn as_list(&self) -> ListChunked {
         let s = self.rechunk();
@@ -57,6 +57,12 @@ impl Series {
         unpack_chunked!(self, DataType::Int64 => Int64Chunked, "Int64")
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype `[DataType::Float16]`
+    #[cfg(feature = "dtype-f16")]
+    pub fn f16(&self) -> PolarsResult<&Float16Chunked> {
+        unpack_chunked!(self, DataType::Float16 => Float16Chunked, "Float16")
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype `[DataType::Float32]`
     pub fn f32(&self) -> PolarsResult<&Float32Chunked> {
         unpack_chunked!(self, DataType::Float32 => Float32Chunked, "Float32")
@@ -25,6 +25,10 @@ impl Series {
                 }
                 ca.into_series()
             },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => UInt16Chunked::full_null(name, size)
+                .into_float16()
+                .into_series(),
             #[cfg(feature = "dtype-date")]
             DataType::Date => Int32Chunked::full_null(name, size)
                 .into_date()
@@ -689,6 +689,48 @@ macro_rules! df {
     }
 }
 
+/// Create a [`DataFrame`](crate::prelude::DataFrame) from row literals.
+///
+/// The first list holds the column names, each optionally followed by a data type hint. The
+/// variants of [`DataType`](crate::prelude::DataType) can be used without qualifying them.
+/// Columns without a hint infer their data type from the values. A `null` value is a
+/// missing value.
+///
+/// ```rust
+/// # use polars_core::prelude::*;
+/// # use polars_core::values;
+/// let df = values! {
+///     ["name", "age": UInt8, "score"];
+///     ["foo", 28, 1.5],
+///     ["bar", null, 2.0],
+/// }?;
+/// assert_eq!(df.column("age")?.dtype(), &DataType::UInt8);
+/// # Ok::<(), PolarsError>(())
+/// ```
+#[macro_export]
+macro_rules! values {
+    (@dtype) => { None };
+    (@dtype $dtype:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::prelude::DataType::*;
+        Some($dtype)
+    }};
+    (@row [$($out:expr),*]) => { vec![$($out),*] };
+    (@row [$($out:expr),*] null $(, $($rest:tt)*)?) => {
+        $crate::values!(@row [$($out,)* $crate::prelude::AnyValue::Null] $($($rest)*)?)
+    };
+    (@row [$($out:expr),*] $value:expr $(, $($rest:tt)*)?) => {
+        $crate::values!(@row [$($out,)* $crate::prelude::AnyValue::from($value)] $($($rest)*)?)
+    };
+    ([$($name:literal $(: $dtype:expr)?),+ $(,)?] $(; $([$($row:tt)*]),* $(,)?)?) => {
+        $crate::prelude::DataFrame::from_row_values(
+            &[$($name),+],
+            &[$($crate::values!(@dtype $($dtype)?)),+],
+            &[$($($crate::values!(@row [] $($row)*)),*)?],
+        )
+    };
+}
+
 pub fn get_time_units(tu_l: &TimeUnit, tu_r: &TimeUnit) -> TimeUnit {
     use TimeUnit::*;
     match (tu_l, tu_r) {
@@ -760,6 +802,56 @@ where
     Ok(acc_df)
 }
 
+/// Concat the DataFrames to a single DataFrame, resolving mismatching column data types according
+/// to `policy`.
+///
+/// The data types are resolved over all DataFrames before any of them is appended, so the result
+/// doesn't depend on the order of the DataFrames.
+pub fn concat_df_relaxed<'a, I>(dfs: I, policy: DtypeMergePolicy) -> PolarsResult<DataFrame>
+where
+    I: IntoIterator<Item = &'a DataFrame>,
+{
+    let dfs = dfs.into_iter().collect::<Vec<_>>();
+    if policy == DtypeMergePolicy::Error {
+        return concat_df(dfs);
+    }
+    let first = dfs[0];
+    // Frames whose columns don't line up with the first one are left for `vstack` to raise on.
+    let lines_up = |df: &DataFrame| df.get_column_names() == first.get_column_names();
+
+    let mut dtypes = first.dtypes();
+    if policy == DtypeMergePolicy::Supertype {
+        for df in dfs[1..].iter().filter(|df| lines_up(df)) {
+            for (dtype, s) in dtypes.iter_mut().zip(df.get_columns()) {
+                *dtype = try_get_supertype(dtype, s.dtype())?;
+            }
+        }
+    }
+
+    let cast = |df: &DataFrame| -> PolarsResult<DataFrame> {
+        if !lines_up(df) {
+            return Ok(df.clone());
+        }
+        let columns = df
+            .get_columns()
+            .iter()
+            .zip(&dtypes)
+            .map(|(s, dtype)| match policy {
+                _ if s.dtype() == dtype => Ok(s.clone()),
+                DtypeMergePolicy::CastToLeft => s.strict_cast(dtype),
+                _ => s.cast(dtype),
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(unsafe { DataFrame::new_no_checks(columns) })
+    };
+    let dfs = POOL.install(|| {
+        dfs.into_par_iter()
+            .map(cast)
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+    accumulate_dataframes_vertical(dfs)
+}
+
 /// Concat the DataFrames to a single DataFrame.
 pub fn concat_df_unchecked<'a, I>(dfs: I) -> DataFrame
 where
@@ -140,6 +140,10 @@ pub fn get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
 
             (Float64, Float32) => Some(Float64),
 
+            // Computations on `Float16` are done in `Float32`.
+            #[cfg(feature = "dtype-f16")]
+            (Float16, dt) if dt.is_numeric() || dt.is_bool() => get_supertype(&Float32, dt),
+
             // Time related dtypes
             #[cfg(feature = "dtype-date")]
             (Date, UInt32) => Some(Int64),
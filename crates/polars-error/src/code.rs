@@ -0,0 +1,87 @@
+use std::fmt::{self, Display, Formatter};
+
+/// Stable code of a [`PolarsError`](crate::PolarsError).
+///
+/// Contrary to the error messages, the codes don't change between releases and can be
+/// used to handle errors programmatically.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    ColumnNotFound,
+    Compute,
+    Duplicate,
+    InvalidOperation,
+    Io,
+    NoData,
+    OutOfBounds,
+    SchemaFieldNotFound,
+    SchemaMismatch,
+    ShapeMismatch,
+    SqlInterface,
+    SqlSyntax,
+    StringCacheMismatch,
+    StructFieldNotFound,
+}
+
+impl ErrorCode {
+    pub fn as_str(&self) -> &'static str {
+        use ErrorCode::*;
+        match self {
+            ColumnNotFound => "column_not_found",
+            Compute => "compute",
+            Duplicate => "duplicate",
+            InvalidOperation => "invalid_operation",
+            Io => "io",
+            NoData => "no_data",
+            OutOfBounds => "out_of_bounds",
+            SchemaFieldNotFound => "schema_field_not_found",
+            SchemaMismatch => "schema_mismatch",
+            ShapeMismatch => "shape_mismatch",
+            SqlInterface => "sql_interface",
+            SqlSyntax => "sql_syntax",
+            StringCacheMismatch => "string_cache_mismatch",
+            StructFieldNotFound => "struct_field_not_found",
+        }
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// What the message of a [`PolarsError::Context`](crate::PolarsError::Context) refers to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContextKind {
+    /// The name of the operator that failed.
+    Operator,
+    /// The name of the column that was processed.
+    Column,
+    /// The path of the file that was processed.
+    File,
+    /// A free-form message.
+    Message,
+}
+
+impl ContextKind {
+    pub fn as_str(&self) -> &'static str {
+        use ContextKind::*;
+        match self {
+            Operator => "operator",
+            Column => "column",
+            File => "file",
+            Message => "message",
+        }
+    }
+
+    pub(crate) fn describe(&self, msg: &str) -> String {
+        use ContextKind::*;
+        match self {
+            Operator => format!("'{msg}' failed"),
+            Column => format!("in column '{msg}'"),
+            File => format!("in file '{msg}'"),
+            Message => msg.to_string(),
+        }
+    }
+}
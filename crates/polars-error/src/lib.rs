@@ -1,3 +1,4 @@
+mod code;
 pub mod constants;
 mod warning;
 
@@ -9,6 +10,7 @@ use std::ops::Deref;
 use std::sync::Arc;
 use std::{env, io};
 
+pub use code::*;
 pub use warning::*;
 
 #[derive(Debug)]
@@ -83,9 +85,10 @@ pub enum PolarsError {
     StringCacheMismatch(ErrString),
     #[error("field not found: {0}")]
     StructFieldNotFound(ErrString),
-    #[error("{error}: {msg}")]
+    #[error("{error}: {}", kind.describe(msg))]
     Context {
         error: Box<PolarsError>,
+        kind: ContextKind,
         msg: ErrString,
     },
 }
@@ -148,7 +151,7 @@ impl PolarsError {
     pub fn context_trace(self) -> Self {
         use PolarsError::*;
         match self {
-            Context { error, msg } => {
+            Context { error, kind, msg } => {
                 // If context is 1 level deep, just return error.
                 if !matches!(&*error, PolarsError::Context { .. }) {
                     return *error;
@@ -156,19 +159,19 @@ impl PolarsError {
                 let mut current_error = &*error;
                 let material_error = error.get_err();
 
-                let mut messages = vec![&msg];
+                let mut messages = vec![(kind, &msg)];
 
-                while let PolarsError::Context { msg, error } = current_error {
+                while let PolarsError::Context { msg, kind, error } = current_error {
                     current_error = error;
-                    messages.push(msg)
+                    messages.push((*kind, msg))
                 }
 
                 let mut bt = String::new();
 
                 let mut count = 0;
-                while let Some(msg) = messages.pop() {
+                while let Some((kind, msg)) = messages.pop() {
                     count += 1;
-                    writeln!(&mut bt, "\t[{count}] {}", msg).unwrap();
+                    writeln!(&mut bt, "\t[{count}] {}", kind.describe(msg)).unwrap();
                 }
                 material_error.wrap_msg(move |msg| {
                     format!("{msg}\n\nThis error occurred with the following context stack:\n{bt}")
@@ -217,11 +220,81 @@ impl PolarsError {
     }
 
     pub fn context(self, msg: ErrString) -> Self {
+        self.with_context(ContextKind::Message, msg)
+    }
+
+    /// Add context of the given kind, e.g. the column that was processed.
+    pub fn with_context(self, kind: ContextKind, msg: ErrString) -> Self {
         PolarsError::Context {
             msg,
+            kind,
             error: Box::new(self),
         }
     }
+
+    /// Add the name of the operator that failed as context.
+    pub fn with_operator(self, name: impl Into<ErrString>) -> Self {
+        self.with_context(ContextKind::Operator, name.into())
+    }
+
+    /// Add the name of the column that was processed as context.
+    pub fn with_column(self, name: impl Into<ErrString>) -> Self {
+        self.with_context(ContextKind::Column, name.into())
+    }
+
+    /// Add the path of the file that was processed as context.
+    pub fn with_file(self, path: impl Into<ErrString>) -> Self {
+        self.with_context(ContextKind::File, path.into())
+    }
+
+    /// The stable code of this error, see [`ErrorCode`].
+    pub fn code(&self) -> ErrorCode {
+        use PolarsError::*;
+        match self.get_err() {
+            ColumnNotFound(_) => ErrorCode::ColumnNotFound,
+            ComputeError(_) => ErrorCode::Compute,
+            Duplicate(_) => ErrorCode::Duplicate,
+            InvalidOperation(_) => ErrorCode::InvalidOperation,
+            IO { .. } => ErrorCode::Io,
+            NoData(_) => ErrorCode::NoData,
+            OutOfBounds(_) => ErrorCode::OutOfBounds,
+            SchemaFieldNotFound(_) => ErrorCode::SchemaFieldNotFound,
+            SchemaMismatch(_) => ErrorCode::SchemaMismatch,
+            ShapeMismatch(_) => ErrorCode::ShapeMismatch,
+            SQLInterface(_) => ErrorCode::SqlInterface,
+            SQLSyntax(_) => ErrorCode::SqlSyntax,
+            StringCacheMismatch(_) => ErrorCode::StringCacheMismatch,
+            StructFieldNotFound(_) => ErrorCode::StructFieldNotFound,
+            Context { .. } => unreachable!(),
+        }
+    }
+
+    /// The context that was added to this error, innermost first.
+    pub fn context_chain(&self) -> Vec<(ContextKind, &str)> {
+        let mut chain = vec![];
+        let mut current_error = self;
+        while let PolarsError::Context { error, kind, msg } = current_error {
+            chain.push((*kind, msg.as_ref()));
+            current_error = error;
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// Whether the operation that caused this error may succeed when it is retried.
+    pub fn is_retryable(&self) -> bool {
+        match self.get_err() {
+            PolarsError::IO { error, .. } => matches!(
+                error.kind(),
+                io::ErrorKind::Interrupted
+                    | io::ErrorKind::TimedOut
+                    | io::ErrorKind::WouldBlock
+                    | io::ErrorKind::ConnectionReset
+                    | io::ErrorKind::ConnectionAborted
+            ),
+            _ => false,
+        }
+    }
 }
 
 pub fn map_err<E: Error>(error: E) -> PolarsError {
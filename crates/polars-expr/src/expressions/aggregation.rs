@@ -11,6 +11,7 @@ use polars_core::utils::{NoNull, _split_offsets};
 use polars_core::POOL;
 #[cfg(feature = "propagate_nans")]
 use polars_ops::prelude::nan_propagating_aggregate;
+use polars_ops::prelude::{ApproxQuantileOptions, ModeKeep};
 use rayon::prelude::*;
 
 use super::*;
@@ -143,11 +144,27 @@ impl PhysicalExpr for AggregationExpr {
             } else {
                 s.head(Some(1))
             }),
+            GroupByMethod::FirstNonNull => {
+                let valid = s.drop_nulls();
+                Ok(if valid.is_empty() {
+                    Series::full_null(s.name(), 1, s.dtype())
+                } else {
+                    valid.head(Some(1))
+                })
+            },
             GroupByMethod::Last => Ok(if s.is_empty() {
                 Series::full_null(s.name(), 1, s.dtype())
             } else {
                 s.tail(Some(1))
             }),
+            GroupByMethod::LastNonNull => {
+                let valid = s.drop_nulls();
+                Ok(if valid.is_empty() {
+                    Series::full_null(s.name(), 1, s.dtype())
+                } else {
+                    valid.tail(Some(1))
+                })
+            },
             GroupByMethod::Sum => parallel_op_series(
                 |s| s.sum_reduce().map(|sc| sc.into_series(s.name())),
                 s,
@@ -350,11 +367,21 @@ impl PhysicalExpr for AggregationExpr {
                     let agg_s = s.agg_first(&groups);
                     AggregatedScalar(rename_series(agg_s, &keep_name))
                 },
+                GroupByMethod::FirstNonNull => {
+                    let (s, groups) = ac.get_final_aggregation();
+                    let agg_s = s.agg_first_non_null(&groups);
+                    AggregatedScalar(rename_series(agg_s, &keep_name))
+                },
                 GroupByMethod::Last => {
                     let (s, groups) = ac.get_final_aggregation();
                     let agg_s = s.agg_last(&groups);
                     AggregatedScalar(rename_series(agg_s, &keep_name))
                 },
+                GroupByMethod::LastNonNull => {
+                    let (s, groups) = ac.get_final_aggregation();
+                    let agg_s = s.agg_last_non_null(&groups);
+                    AggregatedScalar(rename_series(agg_s, &keep_name))
+                },
                 GroupByMethod::NUnique => {
                     let (s, groups) = ac.get_final_aggregation();
                     let agg_s = s.agg_n_unique(&groups);
@@ -509,11 +536,21 @@ impl PartitionedAggregation for AggregationExpr {
                     agg.rename(series.name());
                     Ok(agg)
                 },
+                GroupByMethod::FirstNonNull => {
+                    let mut agg = series.agg_first_non_null(groups);
+                    agg.rename(series.name());
+                    Ok(agg)
+                },
                 GroupByMethod::Last => {
                     let mut agg = series.agg_last(groups);
                     agg.rename(series.name());
                     Ok(agg)
                 },
+                GroupByMethod::LastNonNull => {
+                    let mut agg = series.agg_last_non_null(groups);
+                    agg.rename(series.name());
+                    Ok(agg)
+                },
                 GroupByMethod::Max => {
                     let mut agg = series.agg_max(groups);
                     agg.rename(series.name());
@@ -645,11 +682,21 @@ impl PartitionedAggregation for AggregationExpr {
                 agg.rename(partitioned.name());
                 Ok(agg)
             },
+            GroupByMethod::FirstNonNull => {
+                let mut agg = unsafe { partitioned.agg_first_non_null(groups) };
+                agg.rename(partitioned.name());
+                Ok(agg)
+            },
             GroupByMethod::Last => {
                 let mut agg = unsafe { partitioned.agg_last(groups) };
                 agg.rename(partitioned.name());
                 Ok(agg)
             },
+            GroupByMethod::LastNonNull => {
+                let mut agg = unsafe { partitioned.agg_last_non_null(groups) };
+                agg.rename(partitioned.name());
+                Ok(agg)
+            },
             GroupByMethod::Max => {
                 let mut agg = unsafe { partitioned.agg_max(groups) };
                 agg.rename(partitioned.name());
@@ -738,6 +785,130 @@ impl PhysicalExpr for AggQuantileExpr {
     }
 }
 
+pub struct AggApproxQuantileExpr {
+    pub(crate) input: Arc<dyn PhysicalExpr>,
+    pub(crate) quantile: Arc<dyn PhysicalExpr>,
+    pub(crate) options: ApproxQuantileOptions,
+}
+
+impl AggApproxQuantileExpr {
+    pub fn new(
+        input: Arc<dyn PhysicalExpr>,
+        quantile: Arc<dyn PhysicalExpr>,
+        options: ApproxQuantileOptions,
+    ) -> Self {
+        Self {
+            input,
+            quantile,
+            options,
+        }
+    }
+
+    fn get_quantile(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<f64> {
+        let quantile = self.quantile.evaluate(df, state)?;
+        polars_ensure!(quantile.len() <= 1, ComputeError:
+            "polars only supports computing a single quantile; \
+            make sure the 'quantile' expression input produces a single quantile"
+        );
+        quantile.get(0).unwrap().try_extract()
+    }
+}
+
+impl PhysicalExpr for AggApproxQuantileExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        None
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        let input = self.input.evaluate(df, state)?;
+        let quantile = self.get_quantile(df, state)?;
+        polars_ops::prelude::approx_quantile(&input, quantile, self.options)
+    }
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let mut ac = self.input.evaluate_on_groups(df, groups, state)?;
+        let quantile = self.get_quantile(df, state)?;
+
+        let input = ac.flat_naive().into_owned();
+        // SAFETY:
+        // groups are in bounds
+        let agg = unsafe {
+            polars_ops::prelude::approx_quantile_groups(
+                &input,
+                ac.groups(),
+                quantile,
+                self.options,
+            )?
+        };
+        Ok(AggregationContext::from_agg_state(
+            AggregatedScalar(agg),
+            Cow::Borrowed(groups),
+        ))
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        let mut field = self.input.to_field(input_schema)?;
+        if field.data_type() != &DataType::Float32 {
+            field.coerce(DataType::Float64);
+        }
+        Ok(field)
+    }
+}
+
+pub struct AggModeExpr {
+    pub(crate) input: Arc<dyn PhysicalExpr>,
+    pub(crate) keep: ModeKeep,
+}
+
+impl AggModeExpr {
+    pub fn new(input: Arc<dyn PhysicalExpr>, keep: ModeKeep) -> Self {
+        Self { input, keep }
+    }
+}
+
+impl PhysicalExpr for AggModeExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        None
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        let input = self.input.evaluate(df, state)?;
+        polars_ops::prelude::agg_mode(&input, self.keep)
+    }
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let mut ac = self.input.evaluate_on_groups(df, groups, state)?;
+
+        let input = ac.flat_naive().into_owned();
+        // SAFETY:
+        // groups are in bounds
+        let agg = unsafe { polars_ops::prelude::agg_mode_groups(&input, ac.groups(), self.keep)? };
+        // With `ModeKeep::All` every group has a list of modes, like the implode aggregation.
+        Ok(AggregationContext::from_agg_state(
+            AggregatedScalar(agg),
+            Cow::Borrowed(groups),
+        ))
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        let mut field = self.input.to_field(input_schema)?;
+        if self.keep == ModeKeep::All {
+            field.coerce(DataType::List(Box::new(field.data_type().clone())));
+        }
+        Ok(field)
+    }
+}
+
 /// Simple wrapper to parallelize functions that can be divided over threads aggregated and
 /// finally aggregated in the main thread. This can be done for sum, min, max, etc.
 fn parallel_op_series<F>(f: F, s: Series, allow_threading: bool) -> PolarsResult<Series>
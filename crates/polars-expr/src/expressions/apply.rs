@@ -200,6 +200,73 @@ impl ApplyExpr {
         self.finish_apply_groups(ac, ca.with_name(&name))
     }
 
+    /// Evaluate a [`GroupsUdf`] once on the flat inputs if they all share the same groups.
+    ///
+    /// Otherwise the inputs are aggregated and the function is called per group.
+    fn apply_groups_udf<'a>(
+        &self,
+        function: &dyn GroupsUdf,
+        mut acs: Vec<AggregationContext<'a>>,
+        df: &DataFrame,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let all_flat = acs
+            .iter()
+            .all(|ac| matches!(ac.agg_state(), AggState::NotAggregated(_)));
+        let first_groups = acs[0].groups().as_ref() as *const GroupsProxy;
+        let same_groups = acs[1..]
+            .iter_mut()
+            .all(|ac| std::ptr::eq(ac.groups().as_ref(), first_groups));
+        // A value per row requires every row to be in exactly one group, which isn't the
+        // case for e.g. rolling windows.
+        let flat_output = !function.returns_scalar();
+        let partitions_rows = |ac: &mut AggregationContext| {
+            let len = ac.flat_naive().len();
+            let groups = ac.groups().as_ref();
+            !matches!(groups, GroupsProxy::Slice { rolling: true, .. })
+                && groups.iter().map(|g| g.len()).sum::<usize>() == len
+        };
+        if !(all_flat && same_groups && (!flat_output || partitions_rows(&mut acs[0]))) {
+            return if acs.len() == 1 {
+                self.apply_single_group_aware(acs.pop().unwrap())
+            } else {
+                self.apply_multiple_group_aware(acs, df)
+            };
+        }
+
+        let mut inputs = acs
+            .iter()
+            .map(|ac| ac.flat_naive().into_owned())
+            .collect::<Vec<_>>();
+        let name = inputs[0].name().to_string();
+        let mut ac = acs.swap_remove(0);
+        let groups = ac.groups();
+        let mut out = function.call_udf_groups(&mut inputs, groups)?;
+        if flat_output {
+            polars_ensure!(
+                out.len() == inputs[0].len(),
+                expr = self.expr,
+                ComputeError: "grouped function returned {} values for {} rows",
+                out.len(), inputs[0].len()
+            );
+        } else {
+            polars_ensure!(
+                out.len() == groups.len(),
+                expr = self.expr,
+                ComputeError: "grouped function returned {} values for {} groups",
+                out.len(), groups.len()
+            );
+        }
+        out.rename(&name);
+
+        if flat_output {
+            ac.with_series(out, false, Some(&self.expr))?;
+        } else {
+            ac.with_agg_state(AggState::AggregatedScalar(out));
+        }
+        ac.with_update_groups(UpdateGroups::No);
+        Ok(ac)
+    }
+
     /// Apply elementwise e.g. ignore the group/list indices.
     fn apply_single_elementwise<'a>(
         &self,
@@ -328,11 +395,19 @@ impl PhysicalExpr for ApplyExpr {
             self.inputs.iter().map(f).collect::<PolarsResult<Vec<_>>>()
         }?;
 
-        if self.allow_rename {
-            self.eval_and_flatten(&mut inputs)
+        let in_name = (!self.allow_rename).then(|| inputs[0].name().to_string());
+        // Time UDFs apart from the expression that calls them.
+        let out = if matches!(self.expr, Expr::AnonymousFunction { .. }) {
+            state.record_expression(
+                || self.eval_and_flatten(&mut inputs),
+                || format!("udf({})", self.expr),
+            )?
         } else {
-            let in_name = inputs[0].name().to_string();
-            Ok(self.eval_and_flatten(&mut inputs)?.with_name(&in_name))
+            self.eval_and_flatten(&mut inputs)?
+        };
+        match in_name {
+            Some(in_name) => Ok(out.with_name(&in_name)),
+            None => Ok(out),
         }
     }
 
@@ -357,7 +432,10 @@ impl PhysicalExpr for ApplyExpr {
                     ac.with_series(s, true, Some(&self.expr))?;
                     Ok(ac)
                 },
-                ApplyOptions::GroupWise => self.apply_single_group_aware(ac),
+                ApplyOptions::GroupWise => match self.function.as_groups_udf() {
+                    Some(function) => self.apply_groups_udf(function, vec![ac], df),
+                    None => self.apply_single_group_aware(ac),
+                },
                 ApplyOptions::ElementWise => self.apply_single_elementwise(ac),
             }
         } else {
@@ -373,7 +451,10 @@ impl PhysicalExpr for ApplyExpr {
                     ac.with_series(s, true, Some(&self.expr))?;
                     Ok(ac)
                 },
-                ApplyOptions::GroupWise => self.apply_multiple_group_aware(acs, df),
+                ApplyOptions::GroupWise => match self.function.as_groups_udf() {
+                    Some(function) => self.apply_groups_udf(function, acs, df),
+                    None => self.apply_multiple_group_aware(acs, df),
+                },
                 ApplyOptions::ElementWise => {
                     let mut has_agg_list = false;
                     let mut has_agg_scalar = false;
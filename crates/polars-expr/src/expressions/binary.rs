@@ -63,6 +63,8 @@ pub fn apply_operator(left: &Series, right: &Series, op: Operator) -> PolarsResu
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => left.try_div(right),
             Duration(_) | Date | Datetime(_, _) | Float32 | Float64 => left.try_div(right),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => left.try_div(right),
             #[cfg(feature = "dtype-array")]
             dt @ Array(_, _) => {
                 let left_dt = dt.cast_leaf(Float64);
@@ -16,6 +16,7 @@ mod sort;
 mod sortby;
 mod ternary;
 mod window;
+mod window_frame;
 
 use std::borrow::Cow;
 use std::fmt::{Display, Formatter};
@@ -43,6 +44,7 @@ pub(crate) use sortby::*;
 pub(crate) use ternary::*;
 pub use window::window_function_format_order_by;
 pub(crate) use window::*;
+pub(crate) use window_frame::WindowFrameExpr;
 
 use crate::state::ExecutionState;
 
@@ -611,6 +613,10 @@ impl PhysicalIoExpr for PhysicalIoHelper {
         self.expr.evaluate(df, &state)
     }
 
+    fn live_variables(&self) -> Option<Vec<Arc<str>>> {
+        Some(expr_to_leaf_column_names(self.expr.as_expression()?))
+    }
+
     #[cfg(feature = "parquet")]
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         self.expr.as_stats_evaluator()
@@ -1,4 +1,5 @@
 use polars_core::chunked_array::from_iterator_par::ChunkedCollectParIterExt;
+use polars_core::prelude::sort::_nan_ordered_sort_key;
 use polars_core::prelude::*;
 use polars_core::POOL;
 use polars_utils::idx_vec::IdxVec;
@@ -71,13 +72,11 @@ fn sort_by_groups_single_by(
     sort_by_s: &Series,
     options: &SortOptions,
 ) -> PolarsResult<(IdxSize, IdxVec)> {
-    let options = SortOptions {
-        descending: options.descending,
-        nulls_last: options.nulls_last,
+    let options = SortOptions::default()
+        .with_order_descending(options.descending)
+        .with_nulls_last(options.nulls_last)
         // We are already in par iter.
-        multithreaded: false,
-        ..Default::default()
-    };
+        .with_multithreaded(false);
     let new_idx = match indicator {
         GroupsIndicator::Idx((_, idx)) => {
             // SAFETY: group tuples are always in bounds.
@@ -102,7 +101,7 @@ fn sort_by_groups_single_by(
 fn sort_by_groups_no_match_single<'a>(
     mut ac_in: AggregationContext<'a>,
     mut ac_by: AggregationContext<'a>,
-    descending: bool,
+    options: SortOptions,
     expr: &Expr,
 ) -> PolarsResult<AggregationContext<'a>> {
     let s_in = ac_in.aggregated();
@@ -117,12 +116,8 @@ fn sort_by_groups_no_match_single<'a>(
             .map(|(opt_s, s_sort_by)| match (opt_s, s_sort_by) {
                 (Some(s), Some(s_sort_by)) => {
                     polars_ensure!(s.len() == s_sort_by.len(), ComputeError: "series lengths don't match in 'sort_by' expression");
-                    let idx = s_sort_by.arg_sort(SortOptions {
-                        descending,
-                        // We are already in par iter.
-                        multithreaded: false,
-                        ..Default::default()
-                    });
+                    // We are already in par iter.
+                    let idx = s_sort_by.arg_sort(options.with_multithreaded(false));
                     Ok(Some(unsafe { s.take_unchecked(&idx) }))
                 },
                 _ => Ok(None),
@@ -149,12 +144,11 @@ fn sort_by_groups_multiple_by(
                 .map(|s| unsafe { s.take_slice_unchecked(idx) })
                 .collect::<Vec<_>>();
 
-            let options = SortMultipleOptions {
-                descending: descending.to_owned(),
-                nulls_last: vec![false; descending.len()],
-                multithreaded,
-                maintain_order,
-            };
+            let options = SortMultipleOptions::default()
+                .with_order_descending_multi(descending.to_owned())
+                .with_nulls_last_multi(vec![false; descending.len()])
+                .with_multithreaded(multithreaded)
+                .with_maintain_order(maintain_order);
 
             let sorted_idx = groups[0].arg_sort_multiple(&groups[1..], &options).unwrap();
             map_sorted_indices_to_group_idx(&sorted_idx, idx)
@@ -165,12 +159,11 @@ fn sort_by_groups_multiple_by(
                 .map(|s| s.slice(first as i64, len as usize))
                 .collect::<Vec<_>>();
 
-            let options = SortMultipleOptions {
-                descending: descending.to_owned(),
-                nulls_last: vec![false; descending.len()],
-                multithreaded,
-                maintain_order,
-            };
+            let options = SortMultipleOptions::default()
+                .with_order_descending_multi(descending.to_owned())
+                .with_nulls_last_multi(vec![false; descending.len()])
+                .with_multithreaded(multithreaded)
+                .with_maintain_order(maintain_order);
             let sorted_idx = groups[0].arg_sort_multiple(&groups[1..], &options).unwrap();
             map_sorted_indices_to_group_slice(&sorted_idx, first)
         },
@@ -206,7 +199,8 @@ impl PhysicalExpr for SortByExpr {
                         e.evaluate(df, state).map(|s| match s.dtype() {
                             #[cfg(feature = "dtype-categorical")]
                             DataType::Categorical(_, _) | DataType::Enum(_, _) => s,
-                            _ => s.to_physical_repr().into_owned(),
+                            _ => _nan_ordered_sort_key(&s, self.sort_options.nan_ordering)
+                                .unwrap_or_else(|| s.to_physical_repr().into_owned()),
                         })
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
@@ -255,7 +249,8 @@ impl PhysicalExpr for SortByExpr {
                 match s.dtype() {
                     #[cfg(feature = "dtype-categorical")]
                     DataType::Categorical(_, _) | DataType::Enum(_, _) => s.into_owned(),
-                    _ => s.to_physical_repr().into_owned(),
+                    _ => _nan_ordered_sort_key(&s, self.sort_options.nan_ordering)
+                        .unwrap_or_else(|| s.to_physical_repr().into_owned()),
                 }
             })
             .collect::<Vec<_>>();
@@ -282,7 +277,7 @@ impl PhysicalExpr for SortByExpr {
                 return sort_by_groups_no_match_single(
                     ac_in,
                     ac_sort_by,
-                    self.sort_options.descending[0],
+                    SortOptions::from(&self.sort_options),
                     &self.expr,
                 );
             };
@@ -296,10 +291,7 @@ impl PhysicalExpr for SortByExpr {
                     update_groups_sort_by(
                         groups,
                         &sort_by_s,
-                        &SortOptions {
-                            descending: descending[0],
-                            ..Default::default()
-                        },
+                        &SortOptions::default().with_order_descending(descending[0]),
                     )
                 },
             );
@@ -54,7 +54,7 @@ impl WindowExpr {
         cache_key: &str,
     ) -> PolarsResult<Series> {
         // idx (new-idx, original-idx)
-        let mut idx_mapping = Vec::with_capacity(out_column.len());
+        let mut idx_mapping = state.scratch().take_vec(out_column.len());
 
         // we already set this buffer so we can reuse the `original_idx` buffer
         // that saves an allocation
@@ -0,0 +1,114 @@
+use super::*;
+
+/// A window function that is evaluated for every row over a frame of rows around it, like
+/// `OVER (PARTITION BY .. ORDER BY .. ROWS BETWEEN ..)` in SQL.
+///
+/// The frames are passed to the function as overlapping slice groups of the partitions in
+/// window order, so no list is materialized per row.
+pub(crate) struct WindowFrameExpr {
+    pub(crate) group_by: Vec<Arc<dyn PhysicalExpr>>,
+    pub(crate) order_by: (Arc<dyn PhysicalExpr>, SortOptions),
+    pub(crate) frame: WindowFrame,
+    pub(crate) out_name: Option<Arc<str>>,
+    /// A function Expr. i.e. Mean, Median, Max, etc.
+    pub(crate) function: Expr,
+    pub(crate) phys_function: Arc<dyn PhysicalExpr>,
+    pub(crate) expr: Expr,
+}
+
+impl WindowFrameExpr {
+    /// The partitions of `df`, with the indices of every partition in window order.
+    fn partitions(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<GroupsProxy> {
+        let groups = if self.group_by.is_empty() {
+            GroupsProxy::Slice {
+                groups: vec![[0, df.height() as IdxSize]],
+                rolling: false,
+            }
+        } else {
+            let group_by_columns = self
+                .group_by
+                .iter()
+                .map(|e| e.evaluate(df, state))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            df.group_by_with_series(group_by_columns, true, false)?
+                .take_groups()
+        };
+
+        let (order_by, options) = &self.order_by;
+        let order_by = order_by.evaluate(df, state)?;
+        polars_ensure!(order_by.len() == df.height(), ShapeMismatch: "the order by expression evaluated to a length: {} that doesn't match the input DataFrame: {}", order_by.len(), df.height());
+        update_groups_sort_by(&groups, &order_by, options)
+    }
+}
+
+impl PhysicalExpr for WindowFrameExpr {
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        if df.is_empty() {
+            let field = self.phys_function.to_field(&df.schema())?;
+            return Ok(Series::full_null(field.name(), 0, field.data_type()));
+        }
+
+        let partitions = self.partitions(df, state)?;
+        let WindowFrame {
+            preceding,
+            following,
+        } = self.frame;
+
+        // Lay the partitions out one after the other in window order, so that every frame is a
+        // slice of that layout.
+        let mut take_idx = state.scratch().take_vec::<IdxSize>(df.height());
+        let mut frames = Vec::with_capacity(df.height());
+        for (_, idx) in partitions.unwrap_idx().iter() {
+            let offset = take_idx.len();
+            let len = idx.len();
+            take_idx.extend_from_slice(idx);
+            for i in 0..len {
+                let start = preceding.map_or(0, |p| i.saturating_sub(p));
+                let end = following.map_or(len, |f| i.saturating_add(f).saturating_add(1).min(len));
+                frames.push([(offset + start) as IdxSize, (end - start) as IdxSize]);
+            }
+        }
+        // SAFETY: the group indices are in bounds.
+        let ordered = unsafe { df._take_unchecked_slice(&take_idx, true) };
+        let groups = GroupsProxy::Slice {
+            groups: frames,
+            rolling: true,
+        };
+
+        let out = self
+            .phys_function
+            .evaluate_on_groups(&ordered, &groups, state)?
+            .finalize();
+        polars_ensure!(out.len() == groups.len(), agg_len = out.len(), groups.len());
+
+        // Map the results back to the rows they were computed for.
+        let mut positions = state.scratch().take_vec(take_idx.len());
+        positions.resize(take_idx.len(), 0 as IdxSize);
+        for (k, i) in take_idx.iter().enumerate() {
+            positions[*i as usize] = k as IdxSize;
+        }
+        // SAFETY: the positions are a permutation of the rows of `out`.
+        let mut out = unsafe { out.take_slice_unchecked(&positions) };
+        if let Some(name) = &self.out_name {
+            out.rename(name.as_ref());
+        }
+        Ok(out)
+    }
+
+    fn evaluate_on_groups<'a>(
+        &self,
+        _df: &DataFrame,
+        _groups: &'a GroupsProxy,
+        _state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        polars_bail!(InvalidOperation: "window expression with a frame not allowed in aggregation");
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        self.function.to_field(input_schema, Context::Default)
+    }
+
+    fn as_expression(&self) -> Option<&Expr> {
+        Some(&self.expr)
+    }
+}
@@ -303,6 +303,27 @@ fn create_physical_expr_inner(
                     options: options.clone(),
                     expr,
                 })),
+                WindowType::Frame(frame) => {
+                    let Some(order_by) = order_by else {
+                        polars_bail!(InvalidOperation: "a window frame requires an order by");
+                    };
+                    let group_by = create_physical_expressions_from_nodes(
+                        partition_by,
+                        Context::Default,
+                        expr_arena,
+                        schema,
+                        state,
+                    )?;
+                    Ok(Arc::new(WindowFrameExpr {
+                        group_by,
+                        order_by,
+                        frame: *frame,
+                        out_name,
+                        function: function_expr,
+                        phys_function,
+                        expr,
+                    }))
+                },
             }
         },
         Literal(value) => {
@@ -383,7 +404,14 @@ fn create_physical_expr_inner(
             let allow_threading = state.allow_threading;
 
             match ctxt {
-                Context::Default if !matches!(agg, IRAggExpr::Quantile { .. }) => {
+                Context::Default
+                    if !matches!(
+                        agg,
+                        IRAggExpr::Quantile { .. }
+                            | IRAggExpr::ApproxQuantile { .. }
+                            | IRAggExpr::Mode(..)
+                    ) =>
+                {
                     use {GroupByMethod as GBM, IRAggExpr as I};
 
                     let groupby = match agg {
@@ -394,10 +422,14 @@ fn create_physical_expr_inner(
                         I::Median(_) => GBM::Median,
                         I::NUnique(_) => GBM::NUnique,
                         I::First(_) => GBM::First,
+                        I::FirstNonNull(_) => GBM::FirstNonNull,
                         I::Last(_) => GBM::Last,
+                        I::LastNonNull(_) => GBM::LastNonNull,
                         I::Mean(_) => GBM::Mean,
                         I::Implode(_) => GBM::Implode,
-                        I::Quantile { .. } => unreachable!(),
+                        I::Quantile { .. } | I::ApproxQuantile { .. } | I::Mode(..) => {
+                            unreachable!()
+                        },
                         I::Sum(_) => GBM::Sum,
                         I::Count(_, include_nulls) => GBM::Count {
                             include_nulls: *include_nulls,
@@ -425,6 +457,19 @@ fn create_physical_expr_inner(
                             create_physical_expr_inner(*quantile, ctxt, expr_arena, schema, state)?;
                         return Ok(Arc::new(AggQuantileExpr::new(input, quantile, *interpol)));
                     }
+                    if let IRAggExpr::ApproxQuantile {
+                        quantile, options, ..
+                    } = agg
+                    {
+                        let quantile =
+                            create_physical_expr_inner(*quantile, ctxt, expr_arena, schema, state)?;
+                        return Ok(Arc::new(AggApproxQuantileExpr::new(
+                            input, quantile, *options,
+                        )));
+                    }
+                    if let IRAggExpr::Mode(_, keep) = agg {
+                        return Ok(Arc::new(AggModeExpr::new(input, *keep)));
+                    }
 
                     let field = schema
                         .map(|schema| {
@@ -8,7 +8,7 @@ use polars_core::config::verbose;
 use polars_core::prelude::*;
 use polars_ops::prelude::ChunkJoinOptIds;
 
-use super::NodeTimer;
+use super::{NodeTimer, ScratchSpace};
 
 pub type JoinTuplesCache = Arc<Mutex<PlHashMap<String, ChunkJoinOptIds>>>;
 pub type GroupsProxyCache = Arc<RwLock<PlHashMap<String, GroupsProxy>>>;
@@ -95,7 +95,12 @@ impl ExecutionState {
 
     /// Toggle this to measure execution times.
     pub fn time_nodes(&mut self) {
-        self.node_timer = Some(NodeTimer::new())
+        self.node_timer = Some(NodeTimer::new(false))
+    }
+    /// Toggle this to measure execution times, including those of the individual expressions
+    /// of projections and of the UDFs they call.
+    pub fn time_expressions(&mut self) {
+        self.node_timer = Some(NodeTimer::new(true))
     }
     pub fn has_node_timer(&self) -> bool {
         self.node_timer.is_some()
@@ -115,6 +120,16 @@ impl ExecutionState {
         self.stop.clone()
     }
 
+    /// Stop the query once `token` is set, instead of on its own token.
+    pub fn set_cancel_token(&mut self, token: Arc<AtomicBool>) {
+        self.stop = token;
+    }
+
+    /// Scratch space to borrow temporary buffers from, instead of allocating them per batch.
+    pub fn scratch(&self) -> &ScratchSpace {
+        &ScratchSpace
+    }
+
     pub fn record<T, F: FnOnce() -> T>(&self, func: F, name: Cow<'static, str>) -> T {
         match &self.node_timer {
             None => func(),
@@ -129,6 +144,26 @@ impl ExecutionState {
         }
     }
 
+    /// Like [`ExecutionState::record`], but only records if expressions are timed. The name is
+    /// only computed then.
+    pub fn record_expression<T, F, N>(&self, func: F, name: N) -> T
+    where
+        F: FnOnce() -> T,
+        N: FnOnce() -> String,
+    {
+        match &self.node_timer {
+            Some(timer) if timer.time_expressions => {
+                let start = std::time::Instant::now();
+                let out = func();
+                let end = std::time::Instant::now();
+
+                timer.store(start, end, name());
+                out
+            },
+            _ => func(),
+        }
+    }
+
     /// Partially clones and partially clears state
     /// This should be used when splitting a node, like a join or union
     pub fn split(&self) -> Self {
@@ -14,12 +14,15 @@ type Ticks = Vec<(StartInstant, EndInstant)>;
 pub(super) struct NodeTimer {
     query_start: Instant,
     data: Arc<Mutex<(Nodes, Ticks)>>,
+    // also time the expressions of projections
+    pub(super) time_expressions: bool,
 }
 
 impl NodeTimer {
-    pub(super) fn new() -> Self {
+    pub(super) fn new(time_expressions: bool) -> Self {
         Self {
             query_start: Instant::now(),
+            time_expressions,
             data: Arc::new(Mutex::new((Vec::with_capacity(16), Vec::with_capacity(16)))),
         }
     }
@@ -0,0 +1,155 @@
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::ops::{Deref, DerefMut};
+
+use polars_core::prelude::*;
+
+/// Buffers larger than `2^MAX_SIZE_CLASS` elements are not retained.
+const MAX_SIZE_CLASS: usize = 20;
+/// The number of buffers that are retained per size class, element type and thread.
+const BUFFERS_PER_CLASS: usize = 2;
+
+/// Free buffers of element type `T`, bucketed by the floor of the log2 of their capacity.
+struct SizeClasses<T> {
+    classes: Vec<Vec<Vec<T>>>,
+}
+
+impl<T> Default for SizeClasses<T> {
+    fn default() -> Self {
+        Self {
+            classes: (0..=MAX_SIZE_CLASS).map(|_| Vec::new()).collect(),
+        }
+    }
+}
+
+thread_local! {
+    static FREE_BUFFERS: RefCell<PlHashMap<TypeId, Box<dyn Any>>> = RefCell::default();
+}
+
+/// Run `f` on the free buffers of element type `T` of the current thread.
+///
+/// Returns `None` if the thread is shutting down and its buffers are already dropped.
+fn with_free_buffers<T: 'static, R>(f: impl FnOnce(&mut SizeClasses<T>) -> R) -> Option<R> {
+    FREE_BUFFERS
+        .try_with(|buffers| {
+            let mut buffers = buffers.borrow_mut();
+            let classes = buffers
+                .entry(TypeId::of::<T>())
+                .or_insert_with(|| Box::<SizeClasses<T>>::default())
+                .downcast_mut()
+                .unwrap();
+            f(classes)
+        })
+        .ok()
+}
+
+/// Scratch space that kernels can borrow temporary buffers from during expression evaluation.
+///
+/// The free buffers are kept per thread, so borrowing and returning a buffer never takes a lock.
+/// Buffers are handed out as a [`ScratchVec`] that returns its allocation to the thread that
+/// drops it, so that the next morsel of a streaming pipeline doesn't have to hit the allocator
+/// again.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ScratchSpace;
+
+fn size_class(n: usize) -> usize {
+    n.max(1).next_power_of_two().trailing_zeros() as usize
+}
+
+impl ScratchSpace {
+    /// Borrow an empty buffer with a capacity of at least `capacity` elements.
+    pub fn take_vec<T: 'static>(&self, capacity: usize) -> ScratchVec<T> {
+        let class = size_class(capacity);
+        let reused = (class <= MAX_SIZE_CLASS)
+            .then(|| with_free_buffers::<T, _>(|buffers| buffers.classes[class].pop()))
+            .flatten()
+            .flatten();
+        let vec = reused.unwrap_or_else(|| Vec::with_capacity((1 << class).max(capacity)));
+        debug_assert!(vec.is_empty() && vec.capacity() >= capacity);
+        ScratchVec { vec: Some(vec) }
+    }
+
+    fn give_back<T: 'static>(mut vec: Vec<T>) {
+        if vec.capacity() == 0 {
+            return;
+        }
+        // Buffers of class `k` hold at least `2^k` elements.
+        let class = (usize::BITS - 1 - vec.capacity().leading_zeros()) as usize;
+        if class > MAX_SIZE_CLASS {
+            return;
+        }
+        vec.clear();
+        with_free_buffers::<T, _>(|buffers| {
+            let buffers = &mut buffers.classes[class];
+            if buffers.len() < BUFFERS_PER_CLASS {
+                buffers.push(vec);
+            }
+        });
+    }
+}
+
+/// A buffer borrowed from a [`ScratchSpace`], returned to it on drop.
+pub struct ScratchVec<T: 'static> {
+    vec: Option<Vec<T>>,
+}
+
+impl<T: 'static> ScratchVec<T> {
+    /// Keep the buffer instead of returning it to the scratch space.
+    pub fn into_inner(mut self) -> Vec<T> {
+        self.vec.take().unwrap()
+    }
+}
+
+impl<T: 'static> Deref for ScratchVec<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.vec.as_ref().unwrap()
+    }
+}
+
+impl<T: 'static> DerefMut for ScratchVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.vec.as_mut().unwrap()
+    }
+}
+
+impl<T: 'static> Drop for ScratchVec<T> {
+    fn drop(&mut self) {
+        if let Some(vec) = self.vec.take() {
+            ScratchSpace::give_back(vec)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scratch_reuse() {
+        let scratch = ScratchSpace;
+        let ptr = {
+            let mut buf = scratch.take_vec::<u32>(100);
+            assert!(buf.capacity() >= 100);
+            buf.extend(0..100);
+            buf.as_ptr()
+        };
+
+        // Same size class reuses the allocation, cleared.
+        let buf = scratch.take_vec::<u32>(120);
+        assert!(buf.is_empty());
+        assert_eq!(buf.as_ptr(), ptr);
+        // A larger class and another element type get fresh buffers.
+        assert_ne!(scratch.take_vec::<u32>(1000).as_ptr(), ptr);
+        assert!(scratch.take_vec::<u64>(100).capacity() >= 100);
+
+        let kept = buf.into_inner();
+        assert_ne!(scratch.take_vec::<u32>(100).as_ptr(), kept.as_ptr());
+
+        // The free buffers are per thread.
+        let ptr = scratch.take_vec::<u32>(100).as_ptr() as usize;
+        let other = std::thread::spawn(move || scratch.take_vec::<u32>(100).as_ptr() as usize);
+        assert_ne!(other.join().unwrap(), ptr);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod version_0;
+pub mod version_1;
 
 use std::mem::ManuallyDrop;
 
@@ -0,0 +1,330 @@
+//! Version 1 of the plugin interface.
+//!
+//! Compared to version 0, the inputs of an expression are passed as a [`LazyColumnsExport`]:
+//! only the schema of the inputs is exported up front, and a column is exported when the
+//! plugin materializes it. Errors cross the boundary as an [`ErrorExport`], which keeps the
+//! kind of the [`PolarsError`].
+use std::ffi::{c_char, CStr, CString};
+
+use polars_core::error::{polars_err, PolarsError};
+use polars_core::prelude::Field;
+
+use super::*;
+pub use crate::version_0::{export_series, import_series, CallerContext, SeriesExport};
+
+pub const MAJOR: u16 = 1;
+pub const MINOR: u16 = 0;
+
+pub const fn get_version() -> (u16, u16) {
+    (MAJOR, MINOR)
+}
+
+/// An FFI exported schema: the fields of the inputs, without their data.
+#[repr(C)]
+pub struct SchemaExport {
+    fields: *mut ArrowSchema,
+    len: usize,
+    release: Option<unsafe extern "C" fn(arg1: *mut SchemaExport)>,
+    private_data: *mut std::os::raw::c_void,
+}
+
+impl SchemaExport {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Drop for SchemaExport {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            unsafe { release(self) }
+        }
+    }
+}
+
+// callback used to drop [SchemaExport] when it is exported.
+unsafe extern "C" fn c_release_schema_export(e: *mut SchemaExport) {
+    if e.is_null() {
+        return;
+    }
+    let e = &mut *e;
+    let _ = Box::from_raw(e.private_data as *mut Box<[ArrowSchema]>);
+
+    e.release = None;
+}
+
+pub fn export_schema(fields: &[Field]) -> SchemaExport {
+    let mut fields = fields
+        .iter()
+        .map(|field| ffi::export_field_to_c(&field.to_arrow(true)))
+        .collect::<Box<[_]>>();
+
+    SchemaExport {
+        fields: fields.as_mut_ptr(),
+        len: fields.len(),
+        release: Some(c_release_schema_export),
+        private_data: Box::into_raw(Box::new(fields)) as *mut std::os::raw::c_void,
+    }
+}
+
+/// # Safety
+/// `SchemaExport` must be valid
+pub unsafe fn import_schema(e: &SchemaExport) -> PolarsResult<Vec<Field>> {
+    std::slice::from_raw_parts(e.fields, e.len)
+        .iter()
+        .map(|schema| Ok(Field::from(&ffi::import_field_from_c(schema)?)))
+        .collect()
+}
+
+const ERROR_COLUMN_NOT_FOUND: u32 = 1;
+const ERROR_COMPUTE: u32 = 2;
+const ERROR_DUPLICATE: u32 = 3;
+const ERROR_INVALID_OPERATION: u32 = 4;
+const ERROR_NO_DATA: u32 = 5;
+const ERROR_OUT_OF_BOUNDS: u32 = 6;
+const ERROR_SCHEMA_FIELD_NOT_FOUND: u32 = 7;
+const ERROR_SCHEMA_MISMATCH: u32 = 8;
+const ERROR_SHAPE_MISMATCH: u32 = 9;
+const ERROR_STRING_CACHE_MISMATCH: u32 = 10;
+const ERROR_STRUCT_FIELD_NOT_FOUND: u32 = 11;
+
+/// An FFI exported [`PolarsError`].
+#[repr(C)]
+pub struct ErrorExport {
+    kind: u32,
+    message: *mut c_char,
+    release: Option<unsafe extern "C" fn(arg1: *mut ErrorExport)>,
+}
+
+impl ErrorExport {
+    pub fn empty() -> Self {
+        Self {
+            kind: 0,
+            message: std::ptr::null_mut(),
+            release: None,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.message.is_null()
+    }
+}
+
+impl Drop for ErrorExport {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            unsafe { release(self) }
+        }
+    }
+}
+
+// callback used to drop [ErrorExport] when it is exported.
+unsafe extern "C" fn c_release_error_export(e: *mut ErrorExport) {
+    if e.is_null() {
+        return;
+    }
+    let e = &mut *e;
+    let _ = CString::from_raw(e.message);
+
+    e.release = None;
+}
+
+pub fn export_error(err: &PolarsError) -> ErrorExport {
+    use PolarsError::*;
+    let (kind, msg) = match err {
+        ColumnNotFound(msg) => (ERROR_COLUMN_NOT_FOUND, msg.to_string()),
+        ComputeError(msg) => (ERROR_COMPUTE, msg.to_string()),
+        Duplicate(msg) => (ERROR_DUPLICATE, msg.to_string()),
+        InvalidOperation(msg) => (ERROR_INVALID_OPERATION, msg.to_string()),
+        NoData(msg) => (ERROR_NO_DATA, msg.to_string()),
+        OutOfBounds(msg) => (ERROR_OUT_OF_BOUNDS, msg.to_string()),
+        SchemaFieldNotFound(msg) => (ERROR_SCHEMA_FIELD_NOT_FOUND, msg.to_string()),
+        SchemaMismatch(msg) => (ERROR_SCHEMA_MISMATCH, msg.to_string()),
+        ShapeMismatch(msg) => (ERROR_SHAPE_MISMATCH, msg.to_string()),
+        StringCacheMismatch(msg) => (ERROR_STRING_CACHE_MISMATCH, msg.to_string()),
+        StructFieldNotFound(msg) => (ERROR_STRUCT_FIELD_NOT_FOUND, msg.to_string()),
+        // The other errors can't be rebuilt on the other side, so only their message is kept.
+        err => (ERROR_COMPUTE, err.to_string()),
+    };
+    // Interior nul bytes would truncate the message on the other side.
+    let message = CString::new(msg.replace('\0', "")).unwrap();
+
+    ErrorExport {
+        kind,
+        message: message.into_raw(),
+        release: Some(c_release_error_export),
+    }
+}
+
+/// # Safety
+/// `ErrorExport` must be valid
+pub unsafe fn import_error(e: ErrorExport) -> PolarsError {
+    use PolarsError::*;
+    let msg = CStr::from_ptr(e.message)
+        .to_string_lossy()
+        .into_owned()
+        .into();
+    match e.kind {
+        ERROR_COLUMN_NOT_FOUND => ColumnNotFound(msg),
+        ERROR_DUPLICATE => Duplicate(msg),
+        ERROR_INVALID_OPERATION => InvalidOperation(msg),
+        ERROR_NO_DATA => NoData(msg),
+        ERROR_OUT_OF_BOUNDS => OutOfBounds(msg),
+        ERROR_SCHEMA_FIELD_NOT_FOUND => SchemaFieldNotFound(msg),
+        ERROR_SCHEMA_MISMATCH => SchemaMismatch(msg),
+        ERROR_SHAPE_MISMATCH => ShapeMismatch(msg),
+        ERROR_STRING_CACHE_MISMATCH => StringCacheMismatch(msg),
+        ERROR_STRUCT_FIELD_NOT_FOUND => StructFieldNotFound(msg),
+        _ => ComputeError(msg),
+    }
+}
+
+/// The FFI exported inputs of an expression.
+///
+/// The schema is exported right away, the columns are only exported when they are
+/// materialized, so a plugin doesn't pay for the inputs that it doesn't touch.
+#[repr(C)]
+pub struct LazyColumnsExport {
+    schema: SchemaExport,
+    // Writes column `i` to the `SeriesExport`, or an `ErrorExport` if it returns `false`.
+    materialize: Option<
+        unsafe extern "C" fn(
+            arg1: *const LazyColumnsExport,
+            arg2: usize,
+            arg3: *mut SeriesExport,
+            arg4: *mut ErrorExport,
+        ) -> bool,
+    >,
+    release: Option<unsafe extern "C" fn(arg1: *mut LazyColumnsExport)>,
+    private_data: *mut std::os::raw::c_void,
+}
+
+impl LazyColumnsExport {
+    pub fn len(&self) -> usize {
+        self.schema.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.schema.is_empty()
+    }
+
+    /// The fields of the columns, without exporting any column.
+    ///
+    /// # Safety
+    /// `LazyColumnsExport` must be valid
+    pub unsafe fn schema(&self) -> PolarsResult<Vec<Field>> {
+        import_schema(&self.schema)
+    }
+
+    /// Export and import column `i`.
+    ///
+    /// # Safety
+    /// `LazyColumnsExport` must be valid
+    pub unsafe fn materialize(&self, i: usize) -> PolarsResult<Series> {
+        let materialize = self
+            .materialize
+            .ok_or_else(|| polars_err!(ComputeError: "the columns were already released"))?;
+        let mut out = SeriesExport::empty();
+        let mut error = ErrorExport::empty();
+        if materialize(self, i, &mut out, &mut error) {
+            import_series(out)
+        } else {
+            Err(import_error(error))
+        }
+    }
+}
+
+impl Drop for LazyColumnsExport {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            unsafe { release(self) }
+        }
+    }
+}
+
+// callback used to drop [LazyColumnsExport] when it is exported.
+unsafe extern "C" fn c_release_lazy_columns_export(e: *mut LazyColumnsExport) {
+    if e.is_null() {
+        return;
+    }
+    let e = &mut *e;
+    let _ = Box::from_raw(e.private_data as *mut Vec<Series>);
+
+    e.materialize = None;
+    e.release = None;
+}
+
+// callback used to export a column of [LazyColumnsExport].
+unsafe extern "C" fn c_materialize_column(
+    e: *const LazyColumnsExport,
+    i: usize,
+    out: *mut SeriesExport,
+    error: *mut ErrorExport,
+) -> bool {
+    let columns = &*((*e).private_data as *const Vec<Series>);
+    match columns.get(i) {
+        Some(s) => {
+            std::ptr::write(out, export_series(s));
+            true
+        },
+        None => {
+            let err = polars_err!(OutOfBounds: "column {} is out of bounds for {} columns", i, columns.len());
+            std::ptr::write(error, export_error(&err));
+            false
+        },
+    }
+}
+
+pub fn export_lazy_columns(columns: &[Series]) -> LazyColumnsExport {
+    let fields = columns
+        .iter()
+        .map(|s| s.field().into_owned())
+        .collect::<Vec<_>>();
+
+    LazyColumnsExport {
+        schema: export_schema(&fields),
+        materialize: Some(c_materialize_column),
+        release: Some(c_release_lazy_columns_export),
+        private_data: Box::into_raw(Box::new(columns.to_vec())) as *mut std::os::raw::c_void,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_ffi_lazy_columns() {
+        let a = Series::new("a", [1, 2]);
+        let b = Series::new("b", ["x", "y"]);
+        let e = export_lazy_columns(&[a.clone(), b.clone()]);
+
+        unsafe {
+            assert_eq!(
+                e.schema().unwrap(),
+                vec![a.field().into_owned(), b.field().into_owned()]
+            );
+            assert_eq!(e.materialize(1).unwrap(), b);
+            assert!(matches!(e.materialize(2), Err(PolarsError::OutOfBounds(_))));
+        }
+    }
+
+    #[test]
+    fn test_ffi_error() {
+        let err = polars_err!(InvalidOperation: "not supported");
+        let e = export_error(&err);
+
+        unsafe {
+            let err = import_error(e);
+            assert!(
+                matches!(&err, PolarsError::InvalidOperation(msg) if &**msg == "not supported")
+            );
+        }
+    }
+}
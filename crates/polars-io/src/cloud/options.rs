@@ -38,6 +38,8 @@ use url::Url;
 use crate::file_cache::get_env_file_cache_ttl;
 #[cfg(feature = "aws")]
 use crate::pl_async::with_concurrency_budget;
+#[cfg(feature = "cloud")]
+use crate::pl_async::RequestLimit;
 #[cfg(feature = "aws")]
 use crate::utils::resolve_homedir;
 
@@ -54,11 +56,20 @@ static BUCKET_REGION: Lazy<std::sync::Mutex<FastFixedCache<SmartString, SmartStr
 #[allow(dead_code)]
 type Configs<T> = Vec<(T, String)>;
 
+/// Configuration keys that are handled by Polars instead of the cloud provider.
+const MAX_CONCURRENT_REQUESTS_KEY: &str = "max_concurrent_requests";
+const RETRY_TIMEOUT_KEY: &str = "retry_timeout";
+
 #[derive(Clone, Debug, PartialEq, Hash, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 /// Options to connect to various cloud providers.
 pub struct CloudOptions {
     pub max_retries: usize,
+    /// The maximum time spent on retrying a single request. Requests don't share a retry
+    /// budget, every request retries on its own.
+    pub retry_timeout: std::time::Duration,
+    #[cfg(feature = "cloud")]
+    request_limit: Option<RequestLimit>,
     #[cfg(feature = "file_cache")]
     pub file_cache_ttl: u64,
     #[cfg(feature = "aws")]
@@ -73,6 +84,9 @@ impl Default for CloudOptions {
     fn default() -> Self {
         Self {
             max_retries: 2,
+            retry_timeout: std::time::Duration::from_secs(10),
+            #[cfg(feature = "cloud")]
+            request_limit: None,
             #[cfg(feature = "file_cache")]
             file_cache_ttl: get_env_file_cache_ttl(),
             #[cfg(feature = "aws")]
@@ -161,11 +175,11 @@ impl FromStr for CloudType {
     }
 }
 #[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
-fn get_retry_config(max_retries: usize) -> RetryConfig {
+fn get_retry_config(max_retries: usize, retry_timeout: std::time::Duration) -> RetryConfig {
     RetryConfig {
         backoff: BackoffConfig::default(),
         max_retries,
-        retry_timeout: std::time::Duration::from_secs(10),
+        retry_timeout,
     }
 }
 
@@ -215,6 +229,28 @@ fn read_config(
 }
 
 impl CloudOptions {
+    /// Limit the number of concurrent requests of the scan that uses these options, see
+    /// [`RequestLimit`].
+    #[cfg(feature = "cloud")]
+    pub fn with_max_concurrent_requests(
+        mut self,
+        max_concurrent_requests: u32,
+    ) -> PolarsResult<Self> {
+        self.request_limit = Some(RequestLimit::new(max_concurrent_requests)?);
+        Ok(self)
+    }
+
+    #[cfg(feature = "cloud")]
+    pub fn request_limit(&self) -> Option<&RequestLimit> {
+        self.request_limit.as_ref()
+    }
+
+    /// Set the maximum time spent on retrying a single request.
+    pub fn with_retry_timeout(mut self, retry_timeout: std::time::Duration) -> Self {
+        self.retry_timeout = retry_timeout;
+        self
+    }
+
     /// Set the configuration for AWS connections. This is the preferred API from rust.
     #[cfg(feature = "aws")]
     pub fn with_aws<I: IntoIterator<Item = (AmazonS3ConfigKey, impl Into<String>)>>(
@@ -313,7 +349,7 @@ impl CloudOptions {
 
         builder
             .with_client_options(get_client_options())
-            .with_retry(get_retry_config(self.max_retries))
+            .with_retry(get_retry_config(self.max_retries, self.retry_timeout))
             .build()
             .map_err(to_compute_err)
     }
@@ -347,7 +383,7 @@ impl CloudOptions {
         builder
             .with_client_options(get_client_options())
             .with_url(url)
-            .with_retry(get_retry_config(self.max_retries))
+            .with_retry(get_retry_config(self.max_retries, self.retry_timeout))
             .build()
             .map_err(to_compute_err)
     }
@@ -381,18 +417,33 @@ impl CloudOptions {
         builder
             .with_client_options(get_client_options())
             .with_url(url)
-            .with_retry(get_retry_config(self.max_retries))
+            .with_retry(get_retry_config(self.max_retries, self.retry_timeout))
             .build()
             .map_err(to_compute_err)
     }
 
     /// Parse a configuration from a Hashmap. This is the interface from Python.
+    ///
+    /// Besides the keys of the cloud provider, this accepts `max_concurrent_requests` and
+    /// `retry_timeout` (in seconds).
     #[allow(unused_variables)]
     pub fn from_untyped_config<I: IntoIterator<Item = (impl AsRef<str>, impl Into<String>)>>(
         url: &str,
         config: I,
     ) -> PolarsResult<Self> {
-        match CloudType::from_str(url)? {
+        let mut polars_config = vec![];
+        let config = config
+            .into_iter()
+            .filter_map(|(key, val)| match key.as_ref() {
+                MAX_CONCURRENT_REQUESTS_KEY | RETRY_TIMEOUT_KEY => {
+                    polars_config.push((key.as_ref().to_string(), val.into()));
+                    None
+                },
+                _ => Some((key, val)),
+            })
+            .collect::<Vec<_>>();
+
+        let options: PolarsResult<Self> = match CloudType::from_str(url)? {
             CloudType::Aws => {
                 #[cfg(feature = "aws")]
                 {
@@ -428,14 +479,44 @@ impl CloudOptions {
                     polars_bail!(ComputeError: "'gcp' feature is not enabled");
                 }
             },
+        };
+        options?.with_untyped_polars_config(polars_config)
+    }
+
+    fn with_untyped_polars_config(mut self, config: Vec<(String, String)>) -> PolarsResult<Self> {
+        for (key, val) in config {
+            match key.as_str() {
+                MAX_CONCURRENT_REQUESTS_KEY => {
+                    let limit = val.parse::<u32>().map_err(
+                        |_| polars_err!(ComputeError: "could not parse '{}' as a positive integer for '{}'", val, key),
+                    )?;
+                    #[cfg(feature = "cloud")]
+                    {
+                        self = self.with_max_concurrent_requests(limit)?;
+                    }
+                    #[cfg(not(feature = "cloud"))]
+                    let _ = limit;
+                },
+                RETRY_TIMEOUT_KEY => {
+                    self.retry_timeout = val
+                        .parse::<f64>()
+                        .ok()
+                        .and_then(|secs| std::time::Duration::try_from_secs_f64(secs).ok())
+                        .ok_or_else(
+                            || polars_err!(ComputeError: "could not parse '{}' as a number of seconds for '{}'", val, key),
+                        )?;
+                },
+                _ => unreachable!(),
+            }
         }
+        Ok(self)
     }
 }
 
 #[cfg(feature = "cloud")]
 #[cfg(test)]
 mod tests {
-    use super::parse_url;
+    use super::{parse_url, CloudOptions};
 
     #[test]
     fn test_parse_url() {
@@ -510,4 +591,38 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_untyped_polars_config() {
+        let options = CloudOptions::from_untyped_config(
+            "file:///data.parquet",
+            [("max_concurrent_requests", "4"), ("retry_timeout", "2.5")],
+        )
+        .unwrap();
+        assert_eq!(options.request_limit().unwrap().limit(), 4);
+        assert_eq!(
+            options.retry_timeout,
+            std::time::Duration::from_millis(2500)
+        );
+
+        for (key, val) in [
+            ("max_concurrent_requests", "0"),
+            ("max_concurrent_requests", "-1"),
+            ("retry_timeout", "-1"),
+        ] {
+            assert!(
+                CloudOptions::from_untyped_config("file:///data.parquet", [(key, val)]).is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_request_limit_eq() {
+        let config = [("max_concurrent_requests", "4")];
+        let a = CloudOptions::from_untyped_config("file:///data.parquet", config).unwrap();
+        let b = CloudOptions::from_untyped_config("file:///data.parquet", config).unwrap();
+        // Clones share the permits of the limit, separate limits don't.
+        assert_eq!(a.request_limit(), a.clone().request_limit());
+        assert_ne!(a.request_limit(), b.request_limit());
+    }
 }
@@ -7,22 +7,38 @@ use object_store::path::Path;
 use object_store::{ObjectMeta, ObjectStore};
 use polars_error::{to_compute_err, PolarsResult};
 use tokio::io::AsyncWriteExt;
+use tokio::sync::SemaphorePermit;
 
 use crate::pl_async::{
-    self, tune_with_concurrency_budget, with_concurrency_budget, MAX_BUDGET_PER_REQUEST,
+    self, tune_with_concurrency_budget, with_concurrency_budget, RequestLimit,
+    MAX_BUDGET_PER_REQUEST,
 };
 
 /// Polars specific wrapper for `Arc<dyn ObjectStore>` that limits the number of
-/// concurrent requests for the entire application.
+/// concurrent requests for the entire application, and optionally for a single scan.
 #[derive(Debug, Clone)]
-pub struct PolarsObjectStore(Arc<dyn ObjectStore>);
+pub struct PolarsObjectStore(Arc<dyn ObjectStore>, Option<RequestLimit>);
 
 impl PolarsObjectStore {
     pub fn new(store: Arc<dyn ObjectStore>) -> Self {
-        Self(store)
+        Self(store, None)
+    }
+
+    /// Share the request limit of a scan with this store, see [`RequestLimit`].
+    pub fn with_request_limit(mut self, request_limit: Option<RequestLimit>) -> Self {
+        self.1 = request_limit;
+        self
+    }
+
+    async fn acquire_request_limit(&self, requested_budget: u32) -> Option<SemaphorePermit<'_>> {
+        match &self.1 {
+            Some(limit) => Some(limit.acquire(requested_budget).await),
+            None => None,
+        }
     }
 
     pub async fn get(&self, path: &Path) -> PolarsResult<Bytes> {
+        let _permit = self.acquire_request_limit(1).await;
         tune_with_concurrency_budget(1, || async {
             self.0
                 .get(path)
@@ -36,6 +52,7 @@ impl PolarsObjectStore {
     }
 
     pub async fn get_range(&self, path: &Path, range: Range<usize>) -> PolarsResult<Bytes> {
+        let _permit = self.acquire_request_limit(1).await;
         tune_with_concurrency_budget(1, || self.0.get_range(path, range))
             .await
             .map_err(to_compute_err)
@@ -46,12 +63,11 @@ impl PolarsObjectStore {
         path: &Path,
         ranges: &[Range<usize>],
     ) -> PolarsResult<Vec<Bytes>> {
-        tune_with_concurrency_budget(
-            (ranges.len() as u32).clamp(0, MAX_BUDGET_PER_REQUEST as u32),
-            || self.0.get_ranges(path, ranges),
-        )
-        .await
-        .map_err(to_compute_err)
+        let budget = (ranges.len() as u32).clamp(0, MAX_BUDGET_PER_REQUEST as u32);
+        let _permit = self.acquire_request_limit(budget).await;
+        tune_with_concurrency_budget(budget, || self.0.get_ranges(path, ranges))
+            .await
+            .map_err(to_compute_err)
     }
 
     pub async fn download<F: tokio::io::AsyncWrite + std::marker::Unpin>(
@@ -59,6 +75,7 @@ impl PolarsObjectStore {
         path: &Path,
         file: &mut F,
     ) -> PolarsResult<()> {
+        let _permit = self.acquire_request_limit(1).await;
         tune_with_concurrency_budget(1, || async {
             let mut stream = self
                 .0
@@ -82,6 +99,7 @@ impl PolarsObjectStore {
 
     /// Fetch the metadata of the parquet file, do not memoize it.
     pub async fn head(&self, path: &Path) -> PolarsResult<ObjectMeta> {
+        let _permit = self.acquire_request_limit(1).await;
         with_concurrency_budget(1, || self.0.head(path))
             .await
             .map_err(to_compute_err)
@@ -0,0 +1,347 @@
+use std::collections::VecDeque;
+use std::io::{Cursor, Read};
+use std::sync::{Arc, Condvar, Mutex};
+
+use polars_core::prelude::*;
+use polars_core::POOL;
+
+use super::options::CsvReadOptions;
+use super::utils::is_compressed;
+use crate::shared::SerReader;
+
+/// The number of blocks that are read ahead of the parser.
+const READ_AHEAD: usize = 2;
+
+/// The blocks of a CSV file. Compressed files are decompressed.
+///
+/// Every block ends at a line boundary, so the blocks can be parsed independently while the
+/// next ones are being read. Only the first block contains the header.
+///
+/// The blocks are read ahead by jobs on the thread pool. A job stops once enough blocks are
+/// buffered, so it never waits for the parser. If the parser needs a block before the job
+/// that reads it has started, it reads the block itself.
+pub struct CsvBlocks {
+    shared: Arc<SharedBlocks>,
+}
+
+struct SharedBlocks {
+    state: Mutex<BlocksState>,
+    // Notified when a read finishes.
+    read: Condvar,
+}
+
+struct BlocksState {
+    // Taken by the read that is running.
+    reader: Option<BlockReader>,
+    blocks: VecDeque<PolarsResult<Vec<u8>>>,
+    read: ReadState,
+    // Set once the `CsvBlocks` is dropped, so that a queued job doesn't read anymore.
+    dropped: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ReadState {
+    Idle,
+    // A job is spawned, but didn't start yet.
+    Queued,
+    Running,
+}
+
+struct BlockReader {
+    reader: Box<dyn Read + Send>,
+    // The start of the next block, read after the last line end of the previous one.
+    remainder: Vec<u8>,
+    block_size: usize,
+    quote_char: Option<u8>,
+    eol_char: u8,
+    finished: bool,
+}
+
+impl BlockReader {
+    /// The next block, or `None` if the file is depleted.
+    fn next_block(&mut self) -> PolarsResult<Option<Vec<u8>>> {
+        if self.finished {
+            return Ok(None);
+        }
+        let mut block = std::mem::take(&mut self.remainder);
+        block.reserve(self.block_size.saturating_sub(block.len()));
+        loop {
+            let to_read = self.block_size.saturating_sub(block.len()).max(1 << 16);
+            let n = match (&mut self.reader)
+                .take(to_read as u64)
+                .read_to_end(&mut block)
+            {
+                Ok(n) => n,
+                Err(e) => {
+                    self.finished = true;
+                    return Err(e.into());
+                },
+            };
+            if n == 0 {
+                self.finished = true;
+                return Ok((!block.is_empty()).then_some(block));
+            }
+            // Lines that are longer than a block grow the block.
+            if let Some(end) = last_line_end(&block, self.quote_char, self.eol_char) {
+                self.remainder = block.split_off(end + 1);
+                return Ok(Some(block));
+            }
+        }
+    }
+}
+
+impl SharedBlocks {
+    /// Spawn a job that reads ahead, unless one is spawned already or enough blocks are
+    /// buffered.
+    fn schedule(self: &Arc<Self>, state: &mut BlocksState) {
+        let finished = state.reader.as_ref().map_or(true, |reader| reader.finished);
+        if state.read == ReadState::Idle && state.blocks.len() < READ_AHEAD && !finished {
+            state.read = ReadState::Queued;
+            let shared = self.clone();
+            POOL.spawn(move || shared.read_ahead());
+        }
+    }
+
+    fn read_ahead(&self) {
+        let mut state = self.state.lock().unwrap();
+        // The parser claimed the read, or the blocks are dropped.
+        if state.read != ReadState::Queued || state.dropped {
+            if state.read == ReadState::Queued {
+                state.read = ReadState::Idle;
+            }
+            return;
+        }
+        state.read = ReadState::Running;
+        let mut reader = state.reader.take().unwrap();
+        drop(state);
+
+        loop {
+            let block = reader.next_block();
+            let mut state = self.state.lock().unwrap();
+            match block {
+                Ok(Some(block)) => state.blocks.push_back(Ok(block)),
+                Ok(None) => {},
+                Err(e) => state.blocks.push_back(Err(e)),
+            }
+            if reader.finished || state.blocks.len() >= READ_AHEAD || state.dropped {
+                state.reader = Some(reader);
+                state.read = ReadState::Idle;
+                self.read.notify_all();
+                return;
+            }
+        }
+    }
+}
+
+impl CsvBlocks {
+    /// Start reading `reader` into blocks of about `block_size` bytes.
+    pub fn try_new<R: Read + Send + 'static>(
+        mut reader: R,
+        block_size: usize,
+        quote_char: Option<u8>,
+        eol_char: u8,
+    ) -> PolarsResult<Self> {
+        let mut magic = [0u8; 4];
+        let mut magic_len = 0;
+        while magic_len < magic.len() {
+            match reader.read(&mut magic[magic_len..])? {
+                0 => break,
+                n => magic_len += n,
+            }
+        }
+        let magic = &magic[..magic_len];
+        let reader = Cursor::new(magic.to_vec()).chain(reader);
+        let reader: Box<dyn Read + Send> = if is_compressed(magic) {
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            {
+                super::utils::decompressing_reader(magic, reader)?
+            }
+            #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
+            {
+                polars_bail!(
+                    ComputeError: "cannot read compressed CSV file; \
+                    compile with feature 'decompress' or 'decompress-fast'"
+                )
+            }
+        } else {
+            Box::new(reader)
+        };
+
+        let shared = Arc::new(SharedBlocks {
+            state: Mutex::new(BlocksState {
+                reader: Some(BlockReader {
+                    reader,
+                    remainder: vec![],
+                    block_size,
+                    quote_char,
+                    eol_char,
+                    finished: false,
+                }),
+                blocks: VecDeque::with_capacity(READ_AHEAD),
+                read: ReadState::Idle,
+                dropped: false,
+            }),
+            read: Condvar::new(),
+        });
+        shared.schedule(&mut shared.state.lock().unwrap());
+        Ok(Self { shared })
+    }
+
+    /// The next block, or `None` if the file is depleted.
+    pub fn next_block(&mut self) -> PolarsResult<Option<Vec<u8>>> {
+        let shared = &self.shared;
+        let mut state = shared.state.lock().unwrap();
+        loop {
+            if let Some(block) = state.blocks.pop_front() {
+                shared.schedule(&mut state);
+                return block.map(Some);
+            }
+            if state.read == ReadState::Running {
+                state = shared.read.wait(state).unwrap();
+                continue;
+            }
+            // Claim the read, so that a queued job doesn't start it.
+            state.read = ReadState::Running;
+            let mut reader = state.reader.take().unwrap();
+            drop(state);
+
+            let block = reader.next_block();
+
+            state = shared.state.lock().unwrap();
+            state.reader = Some(reader);
+            state.read = ReadState::Idle;
+            shared.read.notify_all();
+            shared.schedule(&mut state);
+            return block;
+        }
+    }
+}
+
+impl Drop for CsvBlocks {
+    fn drop(&mut self) {
+        self.shared.state.lock().unwrap().dropped = true;
+    }
+}
+
+/// Parses the blocks of a CSV file into `DataFrame`s, see [`CsvBlocks`].
+pub struct CsvBlockReader {
+    blocks: CsvBlocks,
+    options: CsvReadOptions,
+    first_block: bool,
+    rows_read: usize,
+}
+
+impl CsvBlockReader {
+    /// Parse `blocks` with `options`, which apply to the whole file.
+    pub fn new(blocks: CsvBlocks, options: CsvReadOptions) -> Self {
+        Self {
+            blocks,
+            options,
+            first_block: true,
+            rows_read: 0,
+        }
+    }
+
+    /// The `DataFrame` of the next block that has rows, or `None` if the file is depleted.
+    pub fn next_df(&mut self) -> PolarsResult<Option<DataFrame>> {
+        loop {
+            let n_rows = self
+                .options
+                .n_rows
+                .map(|n| n.saturating_sub(self.rows_read));
+            if n_rows == Some(0) {
+                return Ok(None);
+            }
+            let Some(block) = self.blocks.next_block()? else {
+                return Ok(None);
+            };
+
+            let row_index = self.options.row_index.clone().map(|mut ri| {
+                ri.offset += self.rows_read as IdxSize;
+                ri
+            });
+            let mut options = self
+                .options
+                .clone()
+                .with_n_rows(n_rows)
+                .with_row_index(row_index);
+            // Only the first block has the header and the rows to skip.
+            if !self.first_block {
+                options = options
+                    .with_has_header(false)
+                    .with_skip_rows(0)
+                    .with_skip_rows_after_header(0);
+            }
+            self.first_block = false;
+
+            let df = options
+                .into_reader_with_file_handle(Cursor::new(block))
+                .finish()?;
+            if df.height() == 0 {
+                continue;
+            }
+            self.rows_read += df.height();
+            return Ok(Some(df));
+        }
+    }
+}
+
+/// The position of the last line end in `bytes` that is not within quotes.
+///
+/// `bytes` must start at the start of a line.
+fn last_line_end(bytes: &[u8], quote_char: Option<u8>, eol_char: u8) -> Option<usize> {
+    let Some(quote_char) = quote_char else {
+        return memchr::memrchr(eol_char, bytes);
+    };
+    let mut in_quotes = false;
+    let mut end = None;
+    for (i, &b) in bytes.iter().enumerate() {
+        if b == quote_char {
+            // Escaped quotes toggle twice.
+            in_quotes = !in_quotes;
+        } else if b == eol_char && !in_quotes {
+            end = Some(i);
+        }
+    }
+    end
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_last_line_end() {
+        assert_eq!(last_line_end(b"a,b\nc,d\ne", Some(b'"'), b'\n'), Some(7));
+        assert_eq!(
+            last_line_end(b"a,b\n\"c\nd\",e", Some(b'"'), b'\n'),
+            Some(3)
+        );
+        assert_eq!(last_line_end(b"a,\"\"\"b\"\nc", Some(b'"'), b'\n'), Some(7));
+        assert_eq!(last_line_end(b"a,b", None, b'\n'), None);
+    }
+
+    #[test]
+    fn test_csv_blocks() {
+        let csv = b"a,b\n1,\"x\ny\"\n2,z\n3,w".to_vec();
+        let mut blocks =
+            CsvBlocks::try_new(Cursor::new(csv.clone()), 1, Some(b'"'), b'\n').unwrap();
+        let mut read = vec![];
+        while let Some(block) = blocks.next_block().unwrap() {
+            read.extend_from_slice(&block);
+        }
+        assert_eq!(read, csv);
+
+        // The blocks are read ahead while the previous ones are taken.
+        let csv = (0..1000).map(|i| format!("{i}\n")).collect::<String>();
+        let mut blocks =
+            CsvBlocks::try_new(Cursor::new(csv.clone().into_bytes()), 1, None, b'\n').unwrap();
+        let mut read = vec![];
+        while let Some(block) = blocks.next_block().unwrap() {
+            read.extend_from_slice(&block);
+        }
+        assert_eq!(read, csv.as_bytes());
+        // Dropping the blocks before they are read stops the reading.
+        drop(CsvBlocks::try_new(Cursor::new(csv.into_bytes()), 1, None, b'\n').unwrap());
+    }
+}
@@ -16,6 +16,7 @@
 //! }
 //! ```
 
+mod blocks;
 mod buffer;
 mod options;
 mod parser;
@@ -25,9 +26,10 @@ pub mod schema_inference;
 mod splitfields;
 mod utils;
 
+pub use blocks::{CsvBlockReader, CsvBlocks};
 pub use options::{CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues};
 pub use parser::count_rows;
 pub use read_impl::batched::{BatchedCsvReader, OwnedBatchedCsvReader};
 pub use reader::CsvReader;
 pub use schema_inference::infer_file_schema;
-pub use utils::is_compressed;
+pub use utils::{decompress_head, is_compressed};
@@ -1,3 +1,4 @@
+use std::io::{Read, Seek};
 use std::path::PathBuf;
 
 use memchr::memchr2_iter;
@@ -8,10 +9,11 @@ use polars_utils::index::Bounded;
 use polars_utils::slice::GetSaferUnchecked;
 use rayon::prelude::*;
 
+use super::blocks::CsvBlocks;
 use super::buffer::Buffer;
 use super::options::{CommentPrefix, NullValuesCompiled};
 use super::splitfields::SplitFields;
-use super::utils::get_file_chunks;
+use super::utils::{get_file_chunks, is_compressed};
 use crate::prelude::is_cloud_url;
 use crate::utils::get_reader_bytes;
 
@@ -41,13 +43,46 @@ pub fn count_rows(
     } else {
         polars_utils::open_file(path)?
     };
-    let reader_bytes = get_reader_bytes(&mut reader)?;
+
+    let mut magic_nr = [0u8; 4];
+    let res_len = reader.read(&mut magic_nr)?;
+    reader.rewind()?;
+    let n_rows = if is_compressed(&magic_nr[..res_len]) {
+        // Count the rows of the decompressed blocks, which end at line boundaries.
+        let mut blocks = CsvBlocks::try_new(reader, 1 << 24, quote_char, eol_char)?;
+        let mut n_rows = 0;
+        while let Some(block) = blocks.next_block()? {
+            n_rows +=
+                count_rows_from_slice(&block, separator, quote_char, comment_prefix, eol_char)?;
+        }
+        n_rows
+    } else {
+        let reader_bytes = get_reader_bytes(&mut reader)?;
+        count_rows_from_slice(
+            &reader_bytes,
+            separator,
+            quote_char,
+            comment_prefix,
+            eol_char,
+        )?
+    };
+    Ok(n_rows - (has_header as usize))
+}
+
+/// Read the number of rows of the CSV data in `reader_bytes` without parsing columns.
+fn count_rows_from_slice(
+    reader_bytes: &[u8],
+    separator: u8,
+    quote_char: Option<u8>,
+    comment_prefix: Option<&CommentPrefix>,
+    eol_char: u8,
+) -> PolarsResult<usize> {
     const MIN_ROWS_PER_THREAD: usize = 1024;
     let max_threads = POOL.current_num_threads();
 
     // Determine if parallelism is beneficial and how many threads
     let n_threads = get_line_stats(
-        &reader_bytes,
+        reader_bytes,
         MIN_ROWS_PER_THREAD,
         eol_char,
         None,
@@ -61,7 +96,7 @@ pub fn count_rows(
     .unwrap_or(1);
 
     let file_chunks: Vec<(usize, usize)> = get_file_chunks(
-        &reader_bytes,
+        reader_bytes,
         n_threads,
         None,
         separator,
@@ -81,12 +116,7 @@ pub fn count_rows(
         }
     });
 
-    let count_result: PolarsResult<usize> = POOL.install(|| iter.sum());
-
-    match count_result {
-        Ok(val) => Ok(val - (has_header as usize)),
-        Err(err) => Err(err),
-    }
+    POOL.install(|| iter.sum())
 }
 
 /// Skip the utf-8 Byte Order Mark.
@@ -100,7 +100,11 @@ impl CsvReadOptions {
 }
 
 /// Infer the data type of a record
-fn infer_field_schema(string: &str, try_parse_dates: bool, decimal_comma: bool) -> DataType {
+pub(crate) fn infer_field_schema(
+    string: &str,
+    try_parse_dates: bool,
+    decimal_comma: bool,
+) -> DataType {
     // when quoting is enabled in the reader, these quotes aren't escaped, we default to
     // String for them
     if string.starts_with('"') {
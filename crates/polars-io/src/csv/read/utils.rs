@@ -2,6 +2,11 @@
 use std::io::Read;
 use std::mem::MaybeUninit;
 
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+use polars_error::polars_err;
+use polars_error::{polars_bail, PolarsResult};
+
+use super::options::CsvParseOptions;
 use super::parser::next_line_position;
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
 use super::parser::next_line_position_naive;
@@ -156,6 +161,50 @@ pub(crate) fn decompress(
     }
 }
 
+/// Decompress the first `n_rows` rows of a compressed CSV file, or all rows if `None`.
+pub fn decompress_head(
+    bytes: &[u8],
+    n_rows: Option<usize>,
+    parse_options: &CsvParseOptions,
+) -> PolarsResult<Vec<u8>> {
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    {
+        decompress(
+            bytes,
+            n_rows,
+            parse_options.separator,
+            parse_options.quote_char,
+            parse_options.eol_char,
+        )
+        .ok_or_else(|| polars_err!(ComputeError: "could not decompress CSV file"))
+    }
+    #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
+    {
+        let _ = (bytes, n_rows, parse_options);
+        polars_bail!(
+            ComputeError: "cannot read compressed CSV file; \
+            compile with feature 'decompress' or 'decompress-fast'"
+        )
+    }
+}
+
+/// Wrap `reader` in a decoder for the compression format of the leading bytes `magic`.
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+pub(super) fn decompressing_reader<R: Read + Send + 'static>(
+    magic: &[u8],
+    reader: R,
+) -> PolarsResult<Box<dyn Read + Send>> {
+    Ok(if magic.starts_with(&GZIP) {
+        Box::new(flate2::read::MultiGzDecoder::new(reader))
+    } else if magic.starts_with(&ZLIB0) || magic.starts_with(&ZLIB1) || magic.starts_with(&ZLIB2) {
+        Box::new(flate2::read::ZlibDecoder::new(reader))
+    } else if magic.starts_with(&ZSTD) {
+        Box::new(zstd::Decoder::new(reader)?)
+    } else {
+        polars_bail!(ComputeError: "unknown compression format of CSV file")
+    })
+}
+
 /// replace double quotes by single ones
 ///
 /// This function assumes that bytes is wrapped in the quoting character.
@@ -51,6 +51,8 @@ pub struct SerializeOptions {
     pub line_terminator: String,
     /// When to insert quotes.
     pub quote_style: QuoteStyle,
+    /// Quote styles that override `quote_style` for the given columns.
+    pub column_quote_styles: Vec<(String, QuoteStyle)>,
 }
 
 impl Default for SerializeOptions {
@@ -65,10 +67,22 @@ impl Default for SerializeOptions {
             null: String::new(),
             line_terminator: "\n".into(),
             quote_style: Default::default(),
+            column_quote_styles: vec![],
         }
     }
 }
 
+impl SerializeOptions {
+    /// The quote style used for the column with the given name.
+    pub fn quote_style_for(&self, name: &str) -> QuoteStyle {
+        self.column_quote_styles
+            .iter()
+            .rev()
+            .find(|(column, _)| column == name)
+            .map_or(self.quote_style, |(_, quote_style)| *quote_style)
+    }
+}
+
 /// Quote style indicating when to insert quotes around a field.
 #[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
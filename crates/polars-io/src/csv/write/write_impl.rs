@@ -113,6 +113,19 @@ pub(crate) fn write<W: Write>(
         })
         .unzip();
 
+    // Columns with a quote style override get their own serialize options.
+    let column_options: Vec<Option<SerializeOptions>> = df
+        .get_columns()
+        .iter()
+        .map(|column| {
+            let quote_style = options.quote_style_for(column.name());
+            (quote_style != options.quote_style).then(|| SerializeOptions {
+                quote_style,
+                ..options.clone()
+            })
+        })
+        .collect();
+
     let len = df.height();
     let total_rows_per_pool_iter = n_threads * chunk_size;
     let serializer_pool = LowContentionPool::<Vec<_>>::new(n_threads);
@@ -155,7 +168,7 @@ pub(crate) fn write<W: Write>(
                     .map(|(i, col)| {
                         serializer_for(
                             &*col.chunks()[0],
-                            options,
+                            column_options[i].as_ref().unwrap_or(options),
                             col.dtype(),
                             datetime_formats[i],
                             time_zones[i],
@@ -218,14 +231,21 @@ pub(crate) fn write_header<W: Write>(
 
     // A hack, but it works for this case.
     let fake_arr = NullArray::new(ArrowDataType::Null, 0);
-    let mut names_serializer = string_serializer(
-        |iter: &mut std::slice::Iter<&str>| iter.next().copied(),
-        options,
-        |_| names.iter(),
-        &fake_arr,
-    );
-    for i in 0..names.len() {
-        names_serializer.serialize(&mut header, options);
+    for (i, name) in names.iter().enumerate() {
+        // Columns with a quote style override also quote their name with it.
+        let quote_style = options.quote_style_for(name);
+        let column_options = (quote_style != options.quote_style).then(|| SerializeOptions {
+            quote_style,
+            ..options.clone()
+        });
+        let options = column_options.as_ref().unwrap_or(options);
+        let mut name_serializer = string_serializer(
+            |iter: &mut std::slice::Iter<&str>| iter.next().copied(),
+            options,
+            |_| std::slice::from_ref(name).iter(),
+            &fake_arr,
+        );
+        name_serializer.serialize(&mut header, options);
         if i != names.len() - 1 {
             header.push(options.separator);
         }
@@ -148,6 +148,17 @@ where
         self
     }
 
+    /// Set the quoting behavior of individual columns, overriding [`with_quote_style`].
+    ///
+    /// [`with_quote_style`]: CsvWriter::with_quote_style
+    pub fn with_column_quote_styles(
+        mut self,
+        column_quote_styles: Vec<(String, QuoteStyle)>,
+    ) -> Self {
+        self.options.column_quote_styles = column_quote_styles;
+        self
+    }
+
     pub fn n_threads(mut self, n_threads: usize) -> Self {
         self.n_threads = n_threads;
         self
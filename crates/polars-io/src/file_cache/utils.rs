@@ -69,7 +69,8 @@ pub fn init_entries_from_uri_list<A: AsRef<[Arc<str>]>>(
     if is_cloud_url(first_uri) {
         let (_, object_store) = pl_async::get_runtime()
             .block_on_potential_spawn(build_object_store(first_uri, cloud_options))?;
-        let object_store = PolarsObjectStore::new(object_store);
+        let object_store = PolarsObjectStore::new(object_store)
+            .with_request_limit(cloud_options.and_then(|o| o.request_limit().cloned()));
 
         uri_list
             .iter()
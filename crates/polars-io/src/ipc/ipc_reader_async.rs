@@ -80,7 +80,8 @@ impl IpcReaderAsync {
         };
 
         Ok(Self {
-            store: PolarsObjectStore::new(store),
+            store: PolarsObjectStore::new(store)
+                .with_request_limit(cloud_options.and_then(|o| o.request_limit().cloned())),
             cache_entry,
             path,
         })
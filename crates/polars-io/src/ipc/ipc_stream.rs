@@ -208,6 +208,7 @@ pub struct IpcStreamWriter<W> {
     writer: W,
     compression: Option<IpcCompression>,
     pl_flavor: bool,
+    dictionary_deltas: bool,
 }
 
 use arrow::record_batch::RecordBatch;
@@ -225,6 +226,35 @@ impl<W> IpcStreamWriter<W> {
         self.pl_flavor = pl_flavor;
         self
     }
+
+    /// Write dictionaries that only grew since they were last written as delta dictionary
+    /// batches with only the new values, instead of writing them again in full. Defaults to
+    /// false.
+    pub fn with_dictionary_deltas(mut self, dictionary_deltas: bool) -> Self {
+        self.dictionary_deltas = dictionary_deltas;
+        self
+    }
+
+    fn write_options(&self) -> WriteOptions {
+        WriteOptions {
+            compression: self.compression.map(|c| c.into()),
+            emit_dictionary_deltas: self.dictionary_deltas,
+        }
+    }
+}
+
+impl<W: Write> IpcStreamWriter<W> {
+    /// Start a stream that [`DataFrame`]s with the given schema can be written to one by one.
+    pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedStreamWriter<W>> {
+        let options = self.write_options();
+        let mut writer = write::StreamWriter::new(self.writer, options);
+        writer.start(&schema.to_arrow(self.pl_flavor), None)?;
+
+        Ok(BatchedStreamWriter {
+            writer,
+            pl_flavor: self.pl_flavor,
+        })
+    }
 }
 
 impl<W> SerWriter<W> for IpcStreamWriter<W>
@@ -236,16 +266,13 @@ where
             writer,
             compression: None,
             pl_flavor: false,
+            dictionary_deltas: false,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let mut ipc_stream_writer = write::StreamWriter::new(
-            &mut self.writer,
-            WriteOptions {
-                compression: self.compression.map(|c| c.into()),
-            },
-        );
+        let options = self.write_options();
+        let mut ipc_stream_writer = write::StreamWriter::new(&mut self.writer, options);
 
         ipc_stream_writer.start(&df.schema().to_arrow(self.pl_flavor), None)?;
         let df = chunk_df_for_writing(df, 512 * 512)?;
@@ -259,6 +286,32 @@ where
     }
 }
 
+/// Writes [`DataFrame`]s to an Arrow IPC stream one by one.
+pub struct BatchedStreamWriter<W: Write> {
+    writer: write::StreamWriter<W>,
+    pl_flavor: bool,
+}
+
+impl<W: Write> BatchedStreamWriter<W> {
+    /// Write a batch to the stream.
+    ///
+    /// # Panics
+    /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let iter = df.iter_chunks(self.pl_flavor);
+        for batch in iter {
+            self.writer.write(&batch, None)?
+        }
+        Ok(())
+    }
+
+    /// Writes the end-of-stream marker.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        self.writer.finish()?;
+        Ok(())
+    }
+}
+
 pub struct IpcStreamWriterOption {
     compression: Option<IpcCompression>,
     extension: PathBuf,
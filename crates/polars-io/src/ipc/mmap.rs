@@ -1,14 +1,15 @@
+use arrow::array::new_empty_array;
 use arrow::io::ipc::read;
 use arrow::io::ipc::read::{Dictionaries, FileMetadata};
-use arrow::mmap::{mmap_dictionaries_unchecked, mmap_unchecked};
-use arrow::record_batch::RecordBatch;
+use arrow::mmap::{mmap_batch_len, mmap_dictionaries_unchecked, mmap_projected_unchecked};
 use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 
 use super::ipc_file::IpcReader;
 use crate::mmap::{MMapSemaphore, MmapBytesReader};
 use crate::predicates::PhysicalIoExpr;
-use crate::shared::{finish_reader, ArrowReader};
 use crate::utils::{apply_projection, columns_to_projection};
+use crate::RowIndex;
 
 impl<R: MmapBytesReader> IpcReader<R> {
     pub(super) fn finish_memmapped(
@@ -35,16 +36,13 @@ impl<R: MmapBytesReader> IpcReader<R> {
                     metadata.schema.clone()
                 };
 
-                let reader = MMapChunkIter::new(Arc::new(semaphore), metadata, &self.projection)?;
-
-                finish_reader(
-                    reader,
-                    // don't rechunk, that would trigger a read.
-                    false,
+                let batches = MMapBatches::new(Arc::new(semaphore), metadata)?;
+                batches.finish(
+                    self.projection.as_deref(),
+                    &schema.fields,
                     self.n_rows,
                     predicate,
-                    &schema,
-                    self.row_index.clone(),
+                    self.row_index.as_ref(),
                 )
             },
             None => polars_bail!(ComputeError: "cannot memory-map, you must provide a file"),
@@ -52,22 +50,18 @@ impl<R: MmapBytesReader> IpcReader<R> {
     }
 }
 
-struct MMapChunkIter<'a> {
+/// The record batches of a memory mapped IPC file.
+///
+/// The footer of the file locates every batch, so a batch is only mapped if its rows are needed,
+/// and then only the columns that are needed.
+struct MMapBatches {
     dictionaries: Dictionaries,
     metadata: FileMetadata,
     mmap: Arc<MMapSemaphore>,
-    idx: usize,
-    end: usize,
-    projection: &'a Option<Vec<usize>>,
 }
 
-impl<'a> MMapChunkIter<'a> {
-    fn new(
-        mmap: Arc<MMapSemaphore>,
-        metadata: FileMetadata,
-        projection: &'a Option<Vec<usize>>,
-    ) -> PolarsResult<Self> {
-        let end = metadata.blocks.len();
+impl MMapBatches {
+    fn new(mmap: Arc<MMapSemaphore>, metadata: FileMetadata) -> PolarsResult<Self> {
         // mmap the dictionaries
         let dictionaries = unsafe { mmap_dictionaries_unchecked(&metadata, mmap.clone())? };
 
@@ -75,36 +69,119 @@ impl<'a> MMapChunkIter<'a> {
             dictionaries,
             metadata,
             mmap,
-            idx: 0,
-            end,
-            projection,
         })
     }
-}
 
-impl ArrowReader for MMapChunkIter<'_> {
-    fn next_record_batch(&mut self) -> PolarsResult<Option<RecordBatch>> {
-        if self.idx < self.end {
-            let chunk = unsafe {
-                mmap_unchecked(
-                    &self.metadata,
-                    &self.dictionaries,
-                    self.mmap.clone(),
-                    self.idx,
-                )
-            }?;
-            self.idx += 1;
-            let chunk = match &self.projection {
-                None => chunk,
-                Some(proj) => {
-                    let cols = chunk.into_arrays();
-                    let arrays = proj.iter().map(|i| cols[*i].clone()).collect();
-                    RecordBatch::new(arrays)
+    /// Map the columns `projection` of the first `len` rows of batch `idx`.
+    fn batch_df(
+        &self,
+        idx: usize,
+        projection: Option<&[usize]>,
+        fields: &[ArrowField],
+        row_offset: usize,
+        len: usize,
+        row_index: Option<&RowIndex>,
+    ) -> PolarsResult<DataFrame> {
+        let batch = unsafe {
+            mmap_projected_unchecked(
+                &self.metadata,
+                &self.dictionaries,
+                self.mmap.clone(),
+                idx,
+                projection,
+            )
+        }?;
+        let mut df = DataFrame::try_from((batch, fields))?;
+        if len < df.height() {
+            df = df.slice(0, len);
+        }
+        if let Some(rc) = row_index {
+            df.with_row_index_mut(&rc.name, Some(row_offset as IdxSize + rc.offset));
+        }
+        Ok(df)
+    }
+
+    fn finish(
+        &self,
+        projection: Option<&[usize]>,
+        fields: &[ArrowField],
+        n_rows: Option<usize>,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        row_index: Option<&RowIndex>,
+    ) -> PolarsResult<DataFrame> {
+        // The columns that the predicate reads are mapped first, so that the other columns of
+        // batches without any matching row are never mapped.
+        let file_fields = &self.metadata.schema.fields;
+        let predicate_projection = predicate
+            .as_ref()
+            .and_then(|predicate| predicate.live_variables())
+            .and_then(|names| {
+                names
+                    .iter()
+                    .filter(|name| row_index.map_or(true, |rc| rc.name != *name))
+                    .map(|name| file_fields.iter().position(|f| f.name == name.as_ref()))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .filter(|projection| !projection.is_empty());
+        let predicate_fields = predicate_projection.as_ref().map(|projection| {
+            projection
+                .iter()
+                .map(|i| file_fields[*i].clone())
+                .collect::<Vec<_>>()
+        });
+
+        let limit = n_rows.unwrap_or(usize::MAX);
+        let mut row_offset = 0;
+        let mut dfs = vec![];
+        for idx in 0..self.metadata.blocks.len() {
+            if row_offset >= limit {
+                break;
+            }
+            // The row counts in the batch headers let us skip batches without mapping them, and
+            // keep the row index correct.
+            let len = mmap_batch_len(&self.metadata, &*self.mmap, idx)?.min(limit - row_offset);
+            let offset = row_offset;
+            row_offset += len;
+            if len == 0 {
+                continue;
+            }
+
+            let mask = match (&predicate, &predicate_projection, &predicate_fields) {
+                (Some(predicate), Some(projection), Some(fields)) => {
+                    let df =
+                        self.batch_df(idx, Some(projection), fields, offset, len, row_index)?;
+                    let s = predicate.evaluate_io(&df)?;
+                    let mask = s.bool().expect("filter predicates was not of type boolean");
+                    if !mask.any() {
+                        continue;
+                    }
+                    Some(mask.clone())
                 },
+                _ => None,
             };
-            Ok(Some(chunk))
+
+            let mut df = self.batch_df(idx, projection, fields, offset, len, row_index)?;
+            if let Some(mask) = mask {
+                df = df.filter(&mask)?;
+            } else if let Some(predicate) = &predicate {
+                let s = predicate.evaluate_io(&df)?;
+                let mask = s.bool().expect("filter predicates was not of type boolean");
+                df = df.filter(mask)?;
+            }
+            dfs.push(df);
+        }
+
+        if dfs.is_empty() {
+            // Create an empty dataframe with the correct data types
+            let empty_cols = fields
+                .iter()
+                .map(|fld| {
+                    Series::try_from((fld.name.as_str(), new_empty_array(fld.data_type.clone())))
+                })
+                .collect::<PolarsResult<_>>()?;
+            DataFrame::new(empty_cols)
         } else {
-            Ok(None)
+            Ok(accumulate_dataframes_vertical_unchecked(dfs))
         }
     }
 }
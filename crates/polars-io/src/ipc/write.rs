@@ -43,6 +43,7 @@ pub struct IpcWriter<W> {
     pub(super) compression: Option<IpcCompression>,
     /// Polars' flavor of arrow. This might be temporary.
     pub(super) pl_flavor: bool,
+    pub(super) dictionary_deltas: bool,
 }
 
 impl<W: Write> IpcWriter<W> {
@@ -57,6 +58,13 @@ impl<W: Write> IpcWriter<W> {
         self
     }
 
+    /// Write dictionaries that only grew since they were last written as delta dictionary
+    /// batches, instead of failing on the dictionary replacement. Defaults to false.
+    pub fn with_dictionary_deltas(mut self, dictionary_deltas: bool) -> Self {
+        self.dictionary_deltas = dictionary_deltas;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, self.pl_flavor, "ipc")?;
         let mut writer = write::FileWriter::new(
@@ -65,6 +73,7 @@ impl<W: Write> IpcWriter<W> {
             None,
             WriteOptions {
                 compression: self.compression.map(|c| c.into()),
+                emit_dictionary_deltas: self.dictionary_deltas,
             },
         );
         writer.start()?;
@@ -85,6 +94,7 @@ where
             writer,
             compression: None,
             pl_flavor: false,
+            dictionary_deltas: false,
         }
     }
 
@@ -96,6 +106,7 @@ where
             None,
             WriteOptions {
                 compression: self.compression.map(|c| c.into()),
+                emit_dictionary_deltas: self.dictionary_deltas,
             },
         )?;
         df.align_chunks();
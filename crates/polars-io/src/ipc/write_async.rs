@@ -11,6 +11,7 @@ impl<W: AsyncWrite + Unpin + Send> IpcWriter<W> {
             writer,
             compression: None,
             pl_flavor: false,
+            dictionary_deltas: false,
         }
     }
 
@@ -21,6 +22,7 @@ impl<W: AsyncWrite + Unpin + Send> IpcWriter<W> {
             None,
             WriteOptions {
                 compression: self.compression.map(|c| c.into()),
+                emit_dictionary_deltas: self.dictionary_deltas,
             },
         );
 
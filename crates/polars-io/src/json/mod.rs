@@ -71,7 +71,8 @@ use std::ops::Deref;
 use arrow::legacy::conversion::chunk_to_struct;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
-use polars_json::json::write::FallibleStreamingIterator;
+pub use polars_json::json::write::TemporalFormat;
+use polars_json::json::write::{FallibleStreamingIterator, SerializeOptions};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use simd_json::BorrowedValue;
@@ -84,6 +85,22 @@ use crate::prelude::*;
 pub struct JsonWriterOptions {
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// how dates, times, datetimes and durations are written
+    pub temporal_format: TemporalFormat,
+    /// write decimals as strings instead of numbers
+    pub decimal_as_string: bool,
+    /// leave out the fields of a row whose value is null
+    pub omit_nulls: bool,
+}
+
+impl JsonWriterOptions {
+    fn serialize_options(&self) -> SerializeOptions {
+        SerializeOptions {
+            temporal_format: self.temporal_format,
+            decimal_as_string: self.decimal_as_string,
+            omit_nulls: self.omit_nulls,
+        }
+    }
 }
 
 /// The format to use to write the DataFrame to JSON: `Json` (a JSON array) or `JsonLines` (each row output on a
@@ -110,13 +127,14 @@ pub enum JsonFormat {
 /// Under the hood, this uses [`arrow2::io::json`](https://docs.rs/arrow2/latest/arrow2/io/json/write/fn.write.html).
 /// `arrow2` generally serializes types that are not JSON primitives, such as Date and DateTime, as their
 /// `Display`-formatted versions. For instance, a (naive) DateTime column is formatted as the String `"yyyy-mm-dd
-/// HH:MM:SS"`. To control how non-primitive columns are serialized, convert them to String or another primitive type
-/// before serializing.
+/// HH:MM:SS"`. Use [`JsonWriter::with_temporal_format`] to write ISO 8601 strings or integers instead. To control
+/// how other non-primitive columns are serialized, convert them to String or another primitive type before serializing.
 #[must_use]
 pub struct JsonWriter<W: Write> {
     /// File or Stream handler
     buffer: W,
     json_format: JsonFormat,
+    options: SerializeOptions,
 }
 
 impl<W: Write> JsonWriter<W> {
@@ -124,6 +142,25 @@ impl<W: Write> JsonWriter<W> {
         self.json_format = format;
         self
     }
+
+    /// Set how dates, times, datetimes and durations are written.
+    pub fn with_temporal_format(mut self, format: TemporalFormat) -> Self {
+        self.options.temporal_format = format;
+        self
+    }
+
+    /// Write decimals as strings, so that readers that parse JSON numbers as floats don't lose
+    /// precision.
+    pub fn with_decimal_as_string(mut self, decimal_as_string: bool) -> Self {
+        self.options.decimal_as_string = decimal_as_string;
+        self
+    }
+
+    /// Leave the fields of a row out of its object when their value is null, also in structs.
+    pub fn with_omit_nulls(mut self, omit_nulls: bool) -> Self {
+        self.options.omit_nulls = omit_nulls;
+        self
+    }
 }
 
 impl<W> SerWriter<W> for JsonWriter<W>
@@ -136,6 +173,7 @@ where
         JsonWriter {
             buffer,
             json_format: JsonFormat::JsonLines,
+            options: SerializeOptions::default(),
         }
     }
 
@@ -155,13 +193,15 @@ where
 
         match self.json_format {
             JsonFormat::JsonLines => {
-                let serializer = polars_json::ndjson::write::Serializer::new(batches, vec![]);
+                let serializer = polars_json::ndjson::write::Serializer::new(batches, vec![])
+                    .with_options(self.options);
                 let writer =
                     polars_json::ndjson::write::FileWriter::new(&mut self.buffer, serializer);
                 writer.collect::<PolarsResult<()>>()?;
             },
             JsonFormat::Json => {
-                let serializer = polars_json::json::write::Serializer::new(batches, vec![]);
+                let serializer = polars_json::json::write::Serializer::new(batches, vec![])
+                    .with_options(self.options);
                 polars_json::json::write::write(&mut self.buffer, serializer)?;
             },
         }
@@ -172,6 +212,7 @@ where
 
 pub struct BatchedWriter<W: Write> {
     writer: W,
+    options: SerializeOptions,
 }
 
 impl<W> BatchedWriter<W>
@@ -179,8 +220,18 @@ where
     W: Write,
 {
     pub fn new(writer: W) -> Self {
-        BatchedWriter { writer }
+        BatchedWriter {
+            writer,
+            options: SerializeOptions::default(),
+        }
     }
+
+    /// Write the values as the [`JsonWriterOptions`] ask for.
+    pub fn with_options(mut self, options: &JsonWriterOptions) -> Self {
+        self.options = options.serialize_options();
+        self
+    }
+
     /// Write a batch to the json writer.
     ///
     /// # Panics
@@ -197,7 +248,8 @@ where
         let chunks = df.iter_chunks(true);
         let batches =
             chunks.map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
-        let mut serializer = polars_json::ndjson::write::Serializer::new(batches, vec![]);
+        let mut serializer =
+            polars_json::ndjson::write::Serializer::new(batches, vec![]).with_options(self.options);
         while let Some(block) = serializer.next()? {
             self.writer.write_all(block)?;
         }
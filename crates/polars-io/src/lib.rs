@@ -25,8 +25,12 @@ pub mod partition;
 pub mod pl_async;
 pub mod predicates;
 pub mod prelude;
+#[cfg(any(feature = "csv", feature = "json"))]
+pub mod schema_inference;
 mod shared;
 pub mod utils;
+#[cfg(feature = "xlsx")]
+pub mod xlsx;
 
 #[cfg(feature = "cloud")]
 pub use cloud::glob as async_glob;
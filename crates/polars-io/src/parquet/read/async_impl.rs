@@ -4,14 +4,14 @@ use std::ops::Range;
 use arrow::datatypes::ArrowSchemaRef;
 use bytes::Bytes;
 use object_store::path::Path as ObjectPath;
-use polars_core::config::{get_rg_prefetch_size, verbose};
+use polars_core::config::{get_column_prefetch_bytes, get_rg_prefetch_size, verbose};
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
 use polars_parquet::read::RowGroupMetaData;
 use polars_parquet::write::FileMetaData;
 use smartstring::alias::String as SmartString;
 use tokio::sync::mpsc::{channel, Receiver, Sender};
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore};
 
 use super::mmap::ColumnStore;
 use super::predicates::read_this_row_group;
@@ -21,9 +21,19 @@ use crate::parquet::metadata::FileMetaDataRef;
 use crate::pl_async::get_runtime;
 use crate::predicates::PhysicalIoExpr;
 
-type DownloadedRowGroup = Vec<(u64, Bytes)>;
 type QueuePayload = (usize, DownloadedRowGroup);
-type QueueSend = Arc<Sender<PolarsResult<QueuePayload>>>;
+type QueueSend = Sender<PolarsResult<QueuePayload>>;
+
+// The buffer pool counts the bytes of the column chunks in units of this size.
+const BUFFER_POOL_UNIT: usize = 1 << 10;
+
+/// The downloaded column chunks of a row group, keyed by their offset.
+#[derive(Default)]
+struct DownloadedRowGroup {
+    columns: Vec<(u64, Bytes)>,
+    // The slots of the buffer pool that the column chunks take.
+    slots: Option<OwnedSemaphorePermit>,
+}
 
 pub struct ParquetObjectStore {
     store: PolarsObjectStore,
@@ -51,7 +61,8 @@ impl ParquetObjectStore {
         let path = ObjectPath::from_url_path(prefix).map_err(to_compute_err)?;
 
         Ok(ParquetObjectStore {
-            store: PolarsObjectStore::new(store),
+            store: PolarsObjectStore::new(store)
+                .with_request_limit(options.and_then(|o| o.request_limit().cloned())),
             path,
             length: None,
             metadata,
@@ -170,93 +181,63 @@ pub async fn fetch_metadata(
     )?)
 }
 
-/// Download rowgroups for the column whose indexes are given in `projection`.
-/// We concurrently download the columns for each field.
-async fn download_projection(
-    fields: Arc<[SmartString]>,
-    row_group: RowGroupMetaData,
-    async_reader: Arc<ParquetObjectStore>,
-    sender: QueueSend,
-    rg_index: usize,
-) -> bool {
-    let async_reader = &async_reader;
-    let row_group = &row_group;
-    let fields = fields.as_ref();
-
-    let mut ranges = Vec::with_capacity(fields.len());
-    let mut offsets = Vec::with_capacity(fields.len());
-    fields.iter().for_each(|name| {
-        let columns = row_group.columns();
-
-        // A single column can have multiple matches (structs).
-        let iter = columns.iter().filter_map(|meta| {
-            if meta.descriptor().path_in_schema[0] == name.as_str() {
-                let (offset, len) = meta.byte_range();
-                Some((offset, offset as usize..(offset + len) as usize))
-            } else {
-                None
-            }
-        });
-
-        for (offset, range) in iter {
-            offsets.push(offset);
-            ranges.push(range);
-        }
-    });
-
-    let result = async_reader.get_ranges(&ranges).await.map(|bytes| {
-        (
-            rg_index,
-            bytes
-                .into_iter()
-                .zip(offsets)
-                .map(|(bytes, offset)| (offset, bytes))
-                .collect::<Vec<_>>(),
-        )
-    });
-    sender.send(result).await.is_ok()
+/// The byte ranges of the column chunks of `row_group` to download, keyed by their offset.
+///
+/// Without `fields`, all column chunks are downloaded.
+fn column_chunk_ranges(
+    row_group: &RowGroupMetaData,
+    fields: Option<&[SmartString]>,
+) -> Vec<(u64, Range<usize>)> {
+    row_group
+        .columns()
+        .iter()
+        .filter(|meta| match fields {
+            // A single field can have multiple column chunks (structs).
+            Some(fields) => fields
+                .iter()
+                .any(|name| meta.descriptor().path_in_schema[0] == name.as_str()),
+            None => true,
+        })
+        .map(|meta| {
+            let (offset, len) = meta.byte_range();
+            (offset, offset as usize..(offset + len) as usize)
+        })
+        .collect()
 }
 
+/// Download the column chunks of a row group.
+///
+/// The row group takes slots of the `buffer_pool` for the bytes of its column chunks before it
+/// is downloaded, at most all slots of the pool. The slots are released once the row group is
+/// handed over to the decoder, so the downloads of the next row groups start while the current
+/// ones are decoded. Adjacent column chunks are downloaded in a single request.
 async fn download_row_group(
-    rg: RowGroupMetaData,
+    column_chunks: Vec<(u64, Range<usize>)>,
     async_reader: Arc<ParquetObjectStore>,
+    buffer_pool: Arc<Semaphore>,
+    pool_size: u32,
     sender: QueueSend,
     rg_index: usize,
-) -> bool {
-    if rg.columns().is_empty() {
-        return true;
-    }
-    let offset = rg.columns().iter().map(|c| c.byte_range().0).min().unwrap();
-    let (max_offset, len) = rg
-        .columns()
+) {
+    let n_bytes = column_chunks
         .iter()
-        .map(|c| c.byte_range())
-        .max_by_key(|k| k.0)
-        .unwrap();
-
-    let result = async_reader
-        .get_range(offset as usize, (max_offset - offset + len) as usize)
-        .await
-        .map(|bytes| {
-            let base_offset = offset;
-            (
-                rg_index,
-                rg.columns()
-                    .iter()
-                    .map(|c| {
-                        let (offset, len) = c.byte_range();
-                        let slice_offset = offset - base_offset;
-
-                        (
-                            offset,
-                            bytes.slice(slice_offset as usize..(slice_offset + len) as usize),
-                        )
-                    })
-                    .collect::<DownloadedRowGroup>(),
-            )
+        .map(|(_, range)| range.len())
+        .sum::<usize>();
+    let n_slots = n_bytes.div_ceil(BUFFER_POOL_UNIT).min(pool_size as usize) as u32;
+    // The pool is never closed.
+    let slots = buffer_pool.acquire_many_owned(n_slots).await.unwrap();
+
+    tokio::spawn(async move {
+        let (offsets, ranges): (Vec<_>, Vec<_>) = column_chunks.into_iter().unzip();
+        let result = async_reader.get_ranges(&ranges).await.map(|bytes| {
+            let row_group = DownloadedRowGroup {
+                columns: offsets.into_iter().zip(bytes).collect(),
+                slots: Some(slots),
+            };
+            (rg_index, row_group)
         });
-
-    sender.send(result).await.is_ok()
+        let _ = sender.send(result).await;
+    });
 }
 
 pub struct FetchRowGroupsFromObjectStore {
@@ -273,7 +254,7 @@ impl FetchRowGroupsFromObjectStore {
         row_groups: &[RowGroupMetaData],
         limit: usize,
     ) -> PolarsResult<Self> {
-        let projected_fields: Option<Arc<[SmartString]>> = projection.map(|projection| {
+        let projected_fields: Option<Vec<SmartString>> = projection.map(|projection| {
             projection
                 .iter()
                 .map(|i| SmartString::from(schema.fields[*i].name.as_str()))
@@ -285,87 +266,61 @@ impl FetchRowGroupsFromObjectStore {
 
         let mut prefetched: PlHashMap<usize, DownloadedRowGroup> = PlHashMap::new();
 
-        let mut row_groups = if let Some(pred) = predicate.as_deref() {
-            row_groups
-                .iter()
-                .enumerate()
-                .filter(|(i, rg)| {
-                    let should_be_read =
-                        matches!(read_this_row_group(Some(pred), rg, &schema), Ok(true));
-
-                    // Already add the row groups that will be skipped to the prefetched data.
-                    if !should_be_read {
-                        prefetched.insert(*i, Default::default());
-                    }
-                    should_be_read
-                })
-                .map(|(i, rg)| (i, rg.clone()))
-                .collect::<Vec<_>>()
-        } else {
-            row_groups.iter().cloned().enumerate().collect()
-        };
+        let row_groups = row_groups
+            .iter()
+            .enumerate()
+            .filter(|(i, rg)| {
+                let should_be_read = matches!(
+                    read_this_row_group(predicate.as_deref(), rg, &schema),
+                    Ok(true)
+                );
+
+                // Already add the row groups that will be skipped to the prefetched data.
+                if !should_be_read {
+                    prefetched.insert(*i, Default::default());
+                }
+                should_be_read
+            })
+            .map(|(i, rg)| (i, column_chunk_ranges(rg, projected_fields.as_deref())))
+            .collect::<Vec<_>>();
         let reader = Arc::new(reader);
         let msg_limit = get_rg_prefetch_size();
+        let prefetch_bytes = get_column_prefetch_bytes();
+        let pool_size = prefetch_bytes
+            .div_ceil(BUFFER_POOL_UNIT)
+            .clamp(1, u32::MAX as usize);
 
         if verbose() {
-            eprintln!("POLARS ROW_GROUP PREFETCH_SIZE: {}", msg_limit)
+            eprintln!("POLARS ROW_GROUP PREFETCH_SIZE: {}", msg_limit);
+            eprintln!("POLARS COLUMN PREFETCH_BYTES: {}", prefetch_bytes)
         }
 
         let (snd, rcv) = channel(msg_limit);
-        let snd = Arc::new(snd);
+        let buffer_pool = Arc::new(Semaphore::new(pool_size));
 
+        // The row groups take their slots in order, so a row group never waits for the slots of
+        // a later one.
         get_runtime().spawn(async move {
-            let chunk_len = msg_limit;
-            let mut handles = Vec::with_capacity(chunk_len.clamp(0, row_groups.len()));
-            for chunk in row_groups.chunks_mut(chunk_len) {
-                // Start downloads concurrently
-                for (i, rg) in chunk {
-                    let rg = std::mem::take(rg);
-
-                    match &projected_fields {
-                        Some(projected_fields) => {
-                            let handle = tokio::spawn(download_projection(
-                                projected_fields.clone(),
-                                rg,
-                                reader.clone(),
-                                snd.clone(),
-                                *i,
-                            ));
-                            handles.push(handle)
-                        },
-                        None => {
-                            let handle = tokio::spawn(download_row_group(
-                                rg,
-                                reader.clone(),
-                                snd.clone(),
-                                *i,
-                            ));
-                            handles.push(handle)
-                        },
-                    }
-                }
-
-                // Wait n - 3 tasks, so we already start the next downloads earlier.
-                for task in handles.drain(..handles.len().saturating_sub(3)) {
-                    let succeeded = task.await.unwrap();
-                    if !succeeded {
-                        return;
-                    }
-                }
-            }
-
-            // Drain remaining tasks.
-            for task in handles.drain(..) {
-                let succeeded = task.await.unwrap();
-                if !succeeded {
+            for (i, column_chunks) in row_groups {
+                // The reader is dropped, so the remaining row groups are not needed.
+                if snd.is_closed() {
                     return;
                 }
+                download_row_group(
+                    column_chunks,
+                    reader.clone(),
+                    buffer_pool.clone(),
+                    pool_size as u32,
+                    snd.clone(),
+                    i,
+                )
+                .await
             }
         });
 
         Ok(FetchRowGroupsFromObjectStore {
             rg_q: Arc::new(Mutex::new(rcv)),
-            prefetched_rg: Default::default(),
+            prefetched_rg: prefetched,
         })
     }
 
@@ -382,14 +337,20 @@ impl FetchRowGroupsFromObjectStore {
             let Some(fetched) = guard.recv().await else {
                 break;
             };
-            let (rg_i, payload) = fetched?;
+            let (rg_i, mut payload) = fetched?;
+            // The requested row groups release their slots right away, as the downloads of
+            // the other requested row groups may be waiting for them. Row groups before the
+            // requested ones are never requested, so their slots are released as well.
+            if rg_i < row_groups.end {
+                payload.slots = None;
+            }
 
             self.prefetched_rg.insert(rg_i, payload);
         }
 
         let received = row_groups
             .flat_map(|i| self.prefetched_rg.remove(&i))
-            .flat_map(|rg| rg.into_iter())
+            .flat_map(|rg| rg.columns.into_iter())
             .collect::<PlHashMap<_, _>>();
 
         Ok(ColumnStore::Fetched(received))
@@ -4,6 +4,8 @@ use bytes::Bytes;
 #[cfg(feature = "async")]
 use polars_core::datatypes::PlHashMap;
 use polars_error::PolarsResult;
+use polars_parquet::parquet::read::{IndexedPageReader, PageMetaData};
+use polars_parquet::read::indexes::FilteredPage;
 use polars_parquet::read::{
     column_iter_to_arrays, get_field_columns, ArrayIter, BasicDecompressor, ColumnChunkMetaData,
     PageReader,
@@ -88,3 +90,38 @@ pub(super) fn to_deserializer<'a>(
 
     column_iter_to_arrays(columns, types, field, Some(chunk_size), num_rows)
 }
+
+/// Like [`to_deserializer`], but only decodes the pages that hold selected rows. The selected
+/// pages are decoded completely, `num_rows` is the sum of their rows.
+pub(super) fn to_filtered_deserializer<'a>(
+    columns: Vec<(&ColumnChunkMetaData, &'a [u8])>,
+    pages: Vec<Vec<FilteredPage>>,
+    field: Field,
+    num_rows: usize,
+) -> PolarsResult<ArrayIter<'a>> {
+    let (columns, types): (Vec<_>, Vec<_>) = columns
+        .into_iter()
+        .zip(pages)
+        .map(|((column_meta, chunk), mut pages)| {
+            // The page locations are offsets in the file, the chunk starts at the column.
+            let mut meta: PageMetaData = column_meta.into();
+            pages
+                .iter_mut()
+                .for_each(|page| page.start -= meta.column_start);
+            meta.column_start = 0;
+            let pages = IndexedPageReader::new_with_page_meta(
+                std::io::Cursor::new(chunk),
+                meta,
+                pages,
+                vec![],
+                vec![],
+            );
+            (
+                BasicDecompressor::new(pages, vec![]),
+                &column_meta.descriptor().descriptor.primitive_type,
+            )
+        })
+        .unzip();
+
+    column_iter_to_arrays(columns, types, field, Some(num_rows), num_rows)
+}
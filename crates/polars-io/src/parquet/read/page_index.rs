@@ -0,0 +1,195 @@
+use std::io::Cursor;
+
+use arrow::array::Array;
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_parquet::parquet::indexes::{select_pages, PageLocation};
+use polars_parquet::read::indexes::{
+    compute_page_row_intervals, has_indexes, read_columns_indexes, ColumnPageStatistics,
+    FieldPageStatistics, FilteredPage, Interval,
+};
+use polars_parquet::read::{
+    get_field_columns, get_field_pages, read_pages_locations, RowGroupMetaData,
+};
+
+use super::mmap::ColumnStore;
+use super::predicates::stats_should_read;
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+
+/// The rows of a row group that have to be read, as found with its page index.
+pub(super) struct RowSelection {
+    /// Sorted, non-overlapping intervals of row positions in the row group.
+    intervals: Vec<Interval>,
+    /// The page locations of every column chunk of the row group.
+    locations: Vec<Vec<PageLocation>>,
+}
+
+impl RowSelection {
+    pub(super) fn num_rows(&self) -> usize {
+        self.intervals.iter().map(|interval| interval.length).sum()
+    }
+
+    /// The pages of the column chunks of `field_name`, with the rows that are selected in each.
+    pub(super) fn pages(
+        &self,
+        md: &RowGroupMetaData,
+        field_name: &str,
+    ) -> PolarsResult<Vec<Vec<FilteredPage>>> {
+        get_field_pages(md.columns(), &self.locations, field_name)
+            .into_iter()
+            .map(|locations| Ok(select_pages(&self.intervals, locations, md.num_rows())?))
+            .collect()
+    }
+
+    /// The row index of the selected rows, for a row group that starts at `offset`.
+    pub(super) fn row_index(&self, name: &str, offset: IdxSize) -> Series {
+        let idx = self
+            .intervals
+            .iter()
+            .flat_map(|interval| interval.start..interval.start + interval.length)
+            .map(|i| i as IdxSize + offset)
+            .collect();
+        let mut ca = IdxCa::from_vec(name, idx);
+        ca.set_sorted_flag(IsSorted::Ascending);
+        ca.into_series()
+    }
+}
+
+/// Intersect two sorted sets of non-overlapping intervals, merging the adjacent ones.
+fn intersect(a: &[Interval], b: &[Interval]) -> Vec<Interval> {
+    let mut out: Vec<Interval> = Vec::with_capacity(a.len().max(b.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let (a_end, b_end) = (a[i].start + a[i].length, b[j].start + b[j].length);
+        let start = a[i].start.max(b[j].start);
+        let end = a_end.min(b_end);
+        if start < end {
+            match out.last_mut() {
+                Some(last) if last.start + last.length == start => last.length += end - start,
+                _ => out.push(Interval::new(start, end - start)),
+            }
+        }
+        if a_end < b_end {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    out
+}
+
+fn page_stats(field: &ArrowField, stats: &ColumnPageStatistics, page: usize) -> ColumnStats {
+    let to_series = |arr: &dyn Array| Series::try_from(("", arr.sliced(page, 1))).ok();
+    ColumnStats::new(
+        field.into(),
+        to_series(&stats.null_count),
+        to_series(stats.min.as_ref()),
+        to_series(stats.max.as_ref()),
+    )
+}
+
+/// Use the page index of a row group to find the rows among the first `limit` that can match
+/// the `predicate`.
+///
+/// The pages of a column only start at a row boundary if the column is not nested, so all
+/// projected columns have to be flat. Returns `None` if all rows have to be read, or if the
+/// page index can't be used.
+pub(super) fn select_rows(
+    predicate: Option<&dyn PhysicalIoExpr>,
+    md: &RowGroupMetaData,
+    store: &ColumnStore,
+    schema: &ArrowSchema,
+    projection: &[usize],
+    limit: usize,
+) -> PolarsResult<Option<RowSelection>> {
+    let num_rows = md.num_rows();
+    let evaluator = predicate.and_then(|pred| pred.as_stats_evaluator());
+    if evaluator.is_none() && limit >= num_rows {
+        return Ok(None);
+    }
+    let bytes = match store {
+        ColumnStore::Local(bytes) => *bytes,
+        // The page index of fetched row groups isn't downloaded.
+        #[cfg(feature = "async")]
+        ColumnStore::Fetched(_) => return Ok(None),
+    };
+    let columns = md.columns();
+    let fields = projection
+        .iter()
+        .map(|i| schema.fields[*i].clone())
+        .collect::<Vec<_>>();
+    let is_flat = |field: &ArrowField| {
+        let chunks = get_field_columns(columns, &field.name);
+        chunks.len() == 1 && chunks[0].descriptor().descriptor.max_rep_level == 0
+    };
+    if fields.is_empty()
+        || !fields.iter().all(is_flat)
+        || columns
+            .iter()
+            .any(|chunk| chunk.column_chunk().offset_index_offset.is_none())
+    {
+        return Ok(None);
+    }
+
+    let mut reader = Cursor::new(bytes);
+    let locations = read_pages_locations(&mut reader, columns)?;
+    let mut intervals = vec![Interval::new(0, limit.min(num_rows))];
+
+    if let Some(evaluator) = evaluator.filter(|_| has_indexes(md)) {
+        // Not every type has page statistics that can be read into arrow.
+        let Ok(field_stats) = read_columns_indexes(&mut reader, columns, &fields) else {
+            return Ok(None);
+        };
+        for (field, stats) in fields.iter().zip(field_stats) {
+            let FieldPageStatistics::Single(stats) = stats else {
+                continue;
+            };
+            let field_locations = get_field_pages(columns, &locations, &field.name);
+            let page_intervals = compute_page_row_intervals(field_locations[0], num_rows)?;
+            let schema = Arc::new(Schema::from_iter([Field::from(field)]));
+
+            let mut selected = Vec::with_capacity(page_intervals.len());
+            for (page, interval) in page_intervals.into_iter().enumerate() {
+                let stats = BatchStats::new(
+                    schema.clone(),
+                    vec![page_stats(field, &stats, page)],
+                    Some(interval.length),
+                );
+                if stats_should_read(evaluator, &stats)? {
+                    selected.push(interval);
+                }
+            }
+            intervals = intersect(&intervals, &selected);
+        }
+    }
+
+    if intervals == [Interval::new(0, num_rows)] {
+        return Ok(None);
+    }
+    Ok(Some(RowSelection {
+        intervals,
+        locations,
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_intersect() {
+        let a = [Interval::new(0, 10), Interval::new(20, 10)];
+        let b = [
+            Interval::new(5, 10),
+            Interval::new(15, 5),
+            Interval::new(25, 100),
+        ];
+        assert_eq!(
+            intersect(&a, &b),
+            [Interval::new(5, 5), Interval::new(25, 5)]
+        );
+        let b = [Interval::new(0, 5), Interval::new(5, 5)];
+        assert_eq!(intersect(&a, &b), [Interval::new(0, 10)]);
+        assert!(intersect(&a, &[]).is_empty());
+    }
+}
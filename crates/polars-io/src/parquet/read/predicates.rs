@@ -3,7 +3,7 @@ use polars_core::prelude::*;
 use polars_parquet::read::statistics::{deserialize, Statistics};
 use polars_parquet::read::RowGroupMetaData;
 
-use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr, StatsEvaluator};
 
 impl ColumnStats {
     fn from_arrow_stats(stats: Statistics, field: &ArrowField) -> Self {
@@ -39,6 +39,20 @@ pub(crate) fn collect_statistics(
     })
 }
 
+pub(super) fn stats_should_read(
+    pred: &dyn StatsEvaluator,
+    stats: &BatchStats,
+) -> PolarsResult<bool> {
+    let should_read = pred.should_read(stats);
+    // a parquet file may not have statistics of all columns
+    if matches!(should_read, Ok(false)) {
+        return Ok(false);
+    } else if !matches!(should_read, Err(PolarsError::ColumnNotFound(_))) {
+        let _ = should_read?;
+    }
+    Ok(true)
+}
+
 pub(super) fn read_this_row_group(
     predicate: Option<&dyn PhysicalIoExpr>,
     md: &RowGroupMetaData,
@@ -47,13 +61,7 @@ pub(super) fn read_this_row_group(
     if let Some(pred) = predicate {
         if let Some(pred) = pred.as_stats_evaluator() {
             if let Some(stats) = collect_statistics(md, schema)? {
-                let should_read = pred.should_read(&stats);
-                // a parquet file may not have statistics of all columns
-                if matches!(should_read, Ok(false)) {
-                    return Ok(false);
-                } else if !matches!(should_read, Err(PolarsError::ColumnNotFound(_))) {
-                    let _ = should_read?;
-                }
+                return stats_should_read(pred, &stats);
             }
         }
     }
@@ -2,17 +2,21 @@ use std::borrow::Cow;
 use std::collections::VecDeque;
 use std::ops::{Deref, Range};
 
-use arrow::array::new_empty_array;
+use arrow::array::{new_empty_array, BooleanArray};
+use arrow::bitmap::MutableBitmap;
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 use polars_core::utils::{accumulate_dataframes_vertical, split_df};
 use polars_core::POOL;
+use polars_parquet::read::indexes::Interval;
 use polars_parquet::read::{self, ArrayIter, FileMetaData, PhysicalType, RowGroupMetaData};
 use rayon::prelude::*;
 
 #[cfg(feature = "cloud")]
 use super::async_impl::FetchRowGroupsFromObjectStore;
 use super::mmap::{mmap_columns, ColumnStore};
+use super::page_index::{select_rows, RowSelection};
 use super::predicates::read_this_row_group;
 use super::to_metadata::ToMetadata;
 use super::utils::materialize_empty_df;
@@ -76,6 +80,7 @@ fn column_idx_to_series(
     // See if we can find some statistics for this series. If we cannot find anything just return
     // the series as is.
     let Some(Ok(stats)) = md.columns()[column_i].statistics() else {
+        set_sorted_from_metadata(&mut series, md);
         return Ok(series);
     };
 
@@ -113,10 +118,111 @@ fn column_idx_to_series(
         (D::String,  P::ByteArray) => (expect_as_binary,  StringType ),
         (D::Binary,  P::ByteArray) => (expect_as_binary,  BinaryType ),
     }
+    set_sorted_from_metadata(&mut series, md);
 
     Ok(series)
 }
 
+/// Deserialize the selected rows of a column.
+///
+/// Only the pages that hold selected rows are decoded, the other rows of those pages are
+/// filtered out afterwards. The statistics of the row group don't hold for the selected rows,
+/// so they aren't set as metadata.
+fn column_idx_to_selected_series(
+    column_i: usize,
+    md: &RowGroupMetaData,
+    file_schema: &ArrowSchema,
+    store: &mmap::ColumnStore,
+    selection: &RowSelection,
+) -> PolarsResult<Series> {
+    let field = &file_schema.fields[column_i];
+    let columns = mmap_columns(store, md.columns(), &field.name);
+    let mut pages = selection.pages(md, &field.name)?;
+
+    // The selection only holds flat columns, which consist of a single column chunk.
+    let decoded_pages = pages[0]
+        .iter_mut()
+        .filter(|page| !page.selected_rows.is_empty());
+    let mut num_decoded = 0;
+    let mut mask = MutableBitmap::new();
+    for page in decoded_pages {
+        let mut offset = 0;
+        for interval in &page.selected_rows {
+            mask.extend_constant(interval.start - offset, false);
+            mask.extend_constant(interval.length, true);
+            offset = interval.start + interval.length;
+        }
+        mask.extend_constant(page.num_rows - offset, false);
+        num_decoded += page.num_rows;
+        // Not every decoder skips the unselected rows of a page, so all decoders get whole
+        // pages and the rows are filtered afterwards.
+        page.selected_rows = vec![Interval::new(0, page.num_rows)];
+    }
+
+    let iter = mmap::to_filtered_deserializer(columns, pages, field.clone(), num_decoded)?;
+    let mut series = array_iter_to_series(iter, field, None)?;
+    if selection.num_rows() < num_decoded {
+        let mask = BooleanArray::from_data_default(mask.into(), None);
+        series = series.filter(&BooleanChunked::with_chunk("", mask))?;
+    }
+    set_sorted_from_metadata(&mut series, md);
+    Ok(series)
+}
+
+/// Set the sorted flag if the row group declares that it is sorted by `series`.
+fn set_sorted_from_metadata(series: &mut Series, md: &RowGroupMetaData) {
+    // The sorting columns are lexicographical, only the first one is sorted on its own.
+    let Some(sorting_column) = md.sorting_columns().and_then(|columns| columns.first()) else {
+        return;
+    };
+    // The order of the categories is not the order of the written strings.
+    if series.dtype().is_categorical() || series.dtype().is_enum() {
+        return;
+    }
+    let path = &md.columns()[sorting_column.column_idx]
+        .descriptor()
+        .path_in_schema;
+    if let [name] = path.as_slice() {
+        // Writers may declare an order that the values don't have, so it is verified first.
+        let (descending, nulls_first) = (sorting_column.descending, sorting_column.nulls_first);
+        if name == series.name() && is_in_order(series, descending, nulls_first).unwrap_or(false) {
+            series.set_sorted_flag(if descending {
+                IsSorted::Descending
+            } else {
+                IsSorted::Ascending
+            });
+        }
+    }
+}
+
+/// Whether the values of `series` are in the given order, with all nulls at its start or end.
+fn is_in_order(series: &Series, descending: bool, nulls_first: bool) -> PolarsResult<bool> {
+    let (len, null_count) = (series.len(), series.null_count());
+    let valid = len - null_count;
+    let (nulls, offset) = if nulls_first {
+        (series.slice(0, null_count), null_count as i64)
+    } else {
+        (series.slice(valid as i64, null_count), 0)
+    };
+    if nulls.null_count() != null_count {
+        return Ok(false);
+    }
+    if valid <= 1 {
+        return Ok(true);
+    }
+    // Compare every value with the next one.
+    let (current, next) = (
+        series.slice(offset, valid - 1),
+        series.slice(offset + 1, valid - 1),
+    );
+    let in_order = if descending {
+        current.gt_eq(&next)?
+    } else {
+        current.lt_eq(&next)?
+    };
+    Ok(in_order.all())
+}
+
 pub(super) fn array_iter_to_series(
     iter: ArrayIter,
     field: &ArrowField,
@@ -247,43 +353,50 @@ fn rg_to_dfs_optionally_par_over_columns(
             *previous_row_count += current_row_count;
             continue;
         }
+
+        let projection_height = (*remaining_rows).min(md.num_rows());
+        let selection = select_rows(
+            use_statistics.then_some(predicate).flatten(),
+            md,
+            store,
+            schema,
+            projection,
+            projection_height,
+        )?;
+        if selection.as_ref().map_or(false, |s| s.num_rows() == 0) {
+            *remaining_rows -= projection_height;
+            *previous_row_count += current_row_count;
+            if *remaining_rows == 0 {
+                break;
+            }
+            continue;
+        }
         // test we don't read the parquet file if this env var is set
         #[cfg(debug_assertions)]
         {
             assert!(std::env::var("POLARS_PANIC_IF_PARQUET_PARSED").is_err())
         }
 
-        let projection_height = (*remaining_rows).min(md.num_rows());
         let chunk_size = md.num_rows();
+        let to_series = |column_i: &usize| match &selection {
+            Some(selection) => {
+                column_idx_to_selected_series(*column_i, md, schema, store, selection)
+            },
+            None => {
+                column_idx_to_series(*column_i, md, projection_height, schema, store, chunk_size)
+            },
+        };
         let columns = if let ParallelStrategy::Columns = parallel {
             POOL.install(|| {
                 projection
                     .par_iter()
-                    .map(|column_i| {
-                        column_idx_to_series(
-                            *column_i,
-                            md,
-                            projection_height,
-                            schema,
-                            store,
-                            chunk_size,
-                        )
-                    })
+                    .map(to_series)
                     .collect::<PolarsResult<Vec<_>>>()
             })?
         } else {
             projection
                 .iter()
-                .map(|column_i| {
-                    column_idx_to_series(
-                        *column_i,
-                        md,
-                        projection_height,
-                        schema,
-                        store,
-                        chunk_size,
-                    )
-                })
+                .map(to_series)
                 .collect::<PolarsResult<Vec<_>>>()?
         };
 
@@ -291,10 +404,21 @@ fn rg_to_dfs_optionally_par_over_columns(
 
         let mut df = unsafe { DataFrame::new_no_checks(columns) };
         if let Some(rc) = &row_index {
-            df.with_row_index_mut(&rc.name, Some(*previous_row_count + rc.offset));
+            let offset = *previous_row_count + rc.offset;
+            match &selection {
+                Some(selection) => {
+                    df.insert_column(0, selection.row_index(&rc.name, offset))?;
+                },
+                None => {
+                    df.with_row_index_mut(&rc.name, Some(offset));
+                },
+            }
         }
 
-        materialize_hive_partitions(&mut df, hive_partition_columns, projection_height);
+        let height = selection
+            .as_ref()
+            .map_or(projection_height, |s| s.num_rows());
+        materialize_hive_partitions(&mut df, hive_partition_columns, height);
         apply_predicate(&mut df, predicate, true)?;
 
         *previous_row_count += current_row_count;
@@ -355,6 +479,17 @@ fn rg_to_dfs_par_over_rg(
                 {
                     return Ok(None);
                 }
+                let selection = select_rows(
+                    use_statistics.then_some(predicate).flatten(),
+                    md,
+                    store,
+                    schema,
+                    projection,
+                    projection_height,
+                )?;
+                if selection.as_ref().map_or(false, |s| s.num_rows() == 0) {
+                    return Ok(None);
+                }
                 // test we don't read the parquet file if this env var is set
                 #[cfg(debug_assertions)]
                 {
@@ -364,25 +499,39 @@ fn rg_to_dfs_par_over_rg(
                 let chunk_size = md.num_rows();
                 let columns = projection
                     .iter()
-                    .map(|column_i| {
-                        column_idx_to_series(
+                    .map(|column_i| match &selection {
+                        Some(selection) => {
+                            column_idx_to_selected_series(*column_i, md, schema, store, selection)
+                        },
+                        None => column_idx_to_series(
                             *column_i,
                             md,
                             projection_height,
                             schema,
                             store,
                             chunk_size,
-                        )
+                        ),
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
 
                 let mut df = unsafe { DataFrame::new_no_checks(columns) };
 
                 if let Some(rc) = &row_index {
-                    df.with_row_index_mut(&rc.name, Some(row_count_start as IdxSize + rc.offset));
+                    let offset = row_count_start as IdxSize + rc.offset;
+                    match &selection {
+                        Some(selection) => {
+                            df.insert_column(0, selection.row_index(&rc.name, offset))?;
+                        },
+                        None => {
+                            df.with_row_index_mut(&rc.name, Some(offset));
+                        },
+                    }
                 }
 
-                materialize_hive_partitions(&mut df, hive_partition_columns, projection_height);
+                let height = selection
+                    .as_ref()
+                    .map_or(projection_height, |s| s.num_rows());
+                materialize_hive_partitions(&mut df, hive_partition_columns, height);
                 apply_predicate(&mut df, predicate, false)?;
 
                 Ok(Some(df))
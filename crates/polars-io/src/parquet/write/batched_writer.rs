@@ -3,12 +3,13 @@ use std::sync::Mutex;
 
 use arrow::record_batch::RecordBatch;
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 use polars_core::POOL;
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
     array_to_columns, CompressedPage, Compressor, DynIter, DynStreamingIterator, Encoding,
     FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
-    SchemaDescriptor, WriteOptions,
+    SchemaDescriptor, SortingColumn, WriteOptions,
 };
 use rayon::prelude::*;
 
@@ -43,11 +44,43 @@ impl<W: Write> BatchedWriter<W> {
         })
     }
 
+    /// The columns of `df` that are flagged as sorted, as declared in the row group metadata.
+    ///
+    /// Only top-level columns that are not nested can be declared.
+    pub fn sorting_columns(&self, df: &DataFrame) -> Option<Vec<SortingColumn>> {
+        let leaves = self.parquet_schema.columns();
+        let sorting_columns = df
+            .get_columns()
+            .iter()
+            .filter_map(|s| {
+                let descending = match s.is_sorted_flag() {
+                    IsSorted::Ascending => false,
+                    IsSorted::Descending => true,
+                    IsSorted::Not => return None,
+                };
+                // The order of the categories is not the order of the written strings.
+                if s.dtype().is_categorical() || s.dtype().is_enum() {
+                    return None;
+                }
+                let column_idx = leaves.iter().position(
+                    |c| matches!(c.path_in_schema.as_slice(), [name] if name == s.name()),
+                )?;
+                Some(SortingColumn {
+                    column_idx,
+                    descending,
+                    nulls_first: s.null_count() > 0 && matches!(s.get(0), Ok(AnyValue::Null)),
+                })
+            })
+            .collect::<Vec<_>>();
+        (!sorting_columns.is_empty()).then_some(sorting_columns)
+    }
+
     /// Write a batch to the parquet writer.
     ///
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let sorting_columns = self.sorting_columns(df);
         let row_group_iter = prepare_rg_iter(
             df,
             &self.parquet_schema,
@@ -58,7 +91,7 @@ impl<W: Write> BatchedWriter<W> {
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
         for group in row_group_iter {
-            writer.write(group?)?;
+            writer.write_with_sorting_columns(group?, sorting_columns.as_deref())?;
         }
         Ok(())
     }
@@ -67,14 +100,21 @@ impl<W: Write> BatchedWriter<W> {
         &self.writer
     }
 
+    /// Write row groups that were created by [`encode_and_compress`].
+    ///
+    /// The `sorting_columns` are obtained from the same [`DataFrame`] with
+    /// [`sorting_columns`](Self::sorting_columns).
+    ///
+    /// [`encode_and_compress`]: Self::encode_and_compress
     pub fn write_row_groups(
         &self,
         rgs: Vec<RowGroupIterColumns<'static, PolarsError>>,
+        sorting_columns: Option<&[SortingColumn]>,
     ) -> PolarsResult<()> {
         // Lock before looping so that order is maintained.
         let mut writer = self.writer.lock().unwrap();
         for group in rgs {
-            writer.write(group)?;
+            writer.write_with_sorting_columns(group, sorting_columns)?;
         }
         Ok(())
     }
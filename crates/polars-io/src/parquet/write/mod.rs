@@ -6,5 +6,5 @@ mod writer;
 
 pub use batched_writer::BatchedWriter;
 pub use options::{BrotliLevel, GzipLevel, ParquetCompression, ParquetWriteOptions, ZstdLevel};
-pub use polars_parquet::write::{RowGroupIterColumns, StatisticsOptions};
+pub use polars_parquet::write::{RowGroupIterColumns, SortingColumn, StatisticsOptions};
 pub use writer::ParquetWriter;
@@ -1,4 +1,5 @@
 use std::io::Write;
+use std::path::PathBuf;
 use std::sync::Mutex;
 
 use arrow::datatypes::PhysicalType;
@@ -9,9 +10,9 @@ use polars_parquet::write::{
 };
 
 use super::batched_writer::BatchedWriter;
-use super::options::ParquetCompression;
+use super::options::{ParquetCompression, ParquetWriteOptions};
 use crate::prelude::chunk_df_for_writing;
-use crate::shared::schema_to_arrow_checked;
+use crate::shared::{schema_to_arrow_checked, SerWriter, WriterFactory};
 
 /// Write a DataFrame to Parquet format.
 #[must_use]
@@ -83,7 +84,16 @@ where
     }
 
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
-        let schema = schema_to_arrow_checked(schema, true, "parquet")?;
+        #[cfg_attr(not(feature = "dtype-categorical"), allow(unused_mut))]
+        let mut arrow_schema = schema_to_arrow_checked(schema, true, "parquet")?;
+        // Parquet dictionaries don't preserve the categories of a categorical type.
+        #[cfg(feature = "dtype-categorical")]
+        for (field, dtype) in arrow_schema.fields.iter_mut().zip(schema.iter_dtypes()) {
+            if let Some(metadata) = dtype.categorical_metadata() {
+                field.metadata.extend(metadata);
+            }
+        }
+        let schema = arrow_schema;
         let parquet_schema = to_parquet_schema(&schema)?;
         let encodings = get_encodings(&schema);
         let options = self.materialize_options();
@@ -116,6 +126,50 @@ where
     }
 }
 
+/// Writes a DataFrame to a single Parquet file for a [`WriterFactory`].
+struct ParquetFileWriter<W> {
+    writer: Option<W>,
+    options: ParquetWriteOptions,
+}
+
+impl<W: Write> SerWriter<W> for ParquetFileWriter<W> {
+    fn new(writer: W) -> Self {
+        Self {
+            writer: Some(writer),
+            options: ParquetWriteOptions::default(),
+        }
+    }
+
+    fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let writer = self
+            .writer
+            .take()
+            .ok_or_else(|| polars_err!(ComputeError: "parquet file is already written"))?;
+        ParquetWriter::new(writer)
+            .with_compression(self.options.compression)
+            .with_statistics(self.options.statistics)
+            .with_row_group_size(self.options.row_group_size)
+            .with_data_page_size(self.options.data_pagesize_limit)
+            // Files are already written in parallel by the callers of the factory.
+            .set_parallel(false)
+            .finish(df)?;
+        Ok(())
+    }
+}
+
+impl WriterFactory for ParquetWriteOptions {
+    fn create_writer<W: Write + 'static>(&self, writer: W) -> Box<dyn SerWriter<W>> {
+        Box::new(ParquetFileWriter {
+            writer: Some(writer),
+            options: *self,
+        })
+    }
+
+    fn extension(&self) -> PathBuf {
+        PathBuf::from("parquet")
+    }
+}
+
 fn get_encodings(schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
     schema
         .fields
@@ -1,19 +1,27 @@
 //! Functionality for writing a DataFrame partitioned into multiple files.
 
-use std::fs::File;
-use std::io::BufWriter;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, ErrorKind, Write};
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
 
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::POOL;
+use polars_utils::aliases::PlHashSet;
 use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::utils::resolve_homedir;
 use crate::WriterFactory;
 
 /// Write a DataFrame with disk partitioning
 ///
+/// The partitions are written to hive-style directories, e.g. `a=1/b=x`, in the root directory.
+///
 /// # Example
 /// ```
 /// use polars_core::prelude::*;
@@ -32,6 +40,49 @@ pub struct PartitionedWriter<F> {
     rootdir: PathBuf,
     by: Vec<String>,
     parallel: bool,
+    checkpoint: bool,
+    max_rows_per_file: Option<usize>,
+    existing_data: ExistingDataBehavior,
+    include_key: bool,
+    checkpoint_inputs: Vec<PathBuf>,
+}
+
+/// The file in the root directory that records the written files of a checkpointed write.
+pub const CHECKPOINT_MANIFEST: &str = "_polars_checkpoint";
+
+/// The directory name of null partition keys, as used by Hive.
+pub const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Characters that are percent-encoded in the partition keys of the directory names.
+const PARTITION_KEY_ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'\'')
+    .add(b'*')
+    .add(b'/')
+    .add(b':')
+    .add(b'=')
+    .add(b'?')
+    .add(b'\\')
+    .add(b'[')
+    .add(b']')
+    .add(b'^')
+    .add(b'{')
+    .add(b'}');
+
+/// What to do with the data that already exists in the root directory of a partitioned write.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ExistingDataBehavior {
+    /// Remove the existing files in the directories of the partitions that are written.
+    /// Partitions that are not in the written DataFrame are kept.
+    Overwrite,
+    /// Write the files next to the existing files.
+    Append,
+    /// Raise an error if the root directory already contains data.
+    #[default]
+    Error,
 }
 
 impl<F> PartitionedWriter<F>
@@ -49,6 +100,11 @@ where
             rootdir: rootdir.into(),
             by: by.into_iter().map(|s| s.as_ref().to_string()).collect(),
             parallel: true,
+            checkpoint: false,
+            max_rows_per_file: None,
+            existing_data: ExistingDataBehavior::default(),
+            include_key: true,
+            checkpoint_inputs: vec![],
         }
     }
 
@@ -58,28 +114,264 @@ where
         self
     }
 
-    fn write_partition_df(&self, partition_df: &mut DataFrame, i: usize) -> PolarsResult<()> {
-        let mut path = resolve_partition_dir(&self.rootdir, &self.by, partition_df);
-        std::fs::create_dir_all(&path)?;
+    /// Record the written files in a manifest in the root directory (default `false`).
+    ///
+    /// If writing fails halfway, finishing the same data again resumes where the previous
+    /// attempt stopped: the files that are recorded in the manifest are not written again.
+    /// Every file is written to a temporary path first and renamed once it is complete, so the
+    /// root directory never contains partially written files. The manifest is removed once all
+    /// partitions are written.
+    ///
+    /// The manifest records the schema, the number of rows and the options of the write, and
+    /// a write that doesn't match them refuses to resume. See
+    /// [`with_checkpoint_inputs`](Self::with_checkpoint_inputs) to also detect changed inputs.
+    pub fn with_checkpoint(mut self, checkpoint: bool) -> Self {
+        self.checkpoint = checkpoint;
+        self
+    }
 
-        path.push(format!(
-            "data-{:04}.{}",
-            i,
-            self.option.extension().display()
-        ));
+    /// The files the written data is computed from.
+    ///
+    /// Their sizes and modification times are recorded in the checkpoint manifest, and a
+    /// checkpoint isn't resumed if any of them changed since it was written.
+    pub fn with_checkpoint_inputs<I, P>(mut self, inputs: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        self.checkpoint_inputs = inputs.into_iter().map(Into::into).collect();
+        self
+    }
 
-        let file = std::fs::File::create(path)?;
-        let writer = BufWriter::new(file);
+    /// Split partitions into files of at most this many rows. Defaults to a single file per
+    /// partition.
+    pub fn with_max_rows_per_file(mut self, max_rows_per_file: Option<usize>) -> Self {
+        self.max_rows_per_file = max_rows_per_file;
+        self
+    }
 
-        self.option
-            .create_writer::<BufWriter<File>>(writer)
-            .finish(partition_df)
+    /// Write the partition columns to the files (default `true`).
+    ///
+    /// Hive readers take the partition columns from the directory names, and may not allow
+    /// them to be in the files as well.
+    pub fn with_include_key(mut self, include_key: bool) -> Self {
+        self.include_key = include_key;
+        self
+    }
+
+    /// Set what to do with data that already exists in the root directory. Defaults to
+    /// [`ExistingDataBehavior::Error`], so that no data is removed unless asked for.
+    pub fn with_existing_data(mut self, existing_data: ExistingDataBehavior) -> Self {
+        self.existing_data = existing_data;
+        self
+    }
+
+    /// The path of the `part`-th file of the `i`-th partition in the partition directory `dir`.
+    fn partition_file(
+        &self,
+        dir: &Path,
+        prefix: &str,
+        i: usize,
+        part: usize,
+        n_parts: usize,
+    ) -> PathBuf {
+        let extension = self.option.extension();
+        // Some writers report their extension with a leading dot.
+        let extension = extension.to_string_lossy();
+        let extension = extension.trim_start_matches('.');
+        let name = if n_parts == 1 {
+            format!("{prefix}-{i:04}.{extension}")
+        } else {
+            format!("{prefix}-{i:04}-{part:04}.{extension}")
+        };
+        dir.join(name)
+    }
+
+    fn write_partition_df(
+        &self,
+        rootdir: &Path,
+        file: &Path,
+        partition_df: &mut DataFrame,
+        checkpoint: Option<&Checkpoint>,
+    ) -> PolarsResult<()> {
+        let path = rootdir.join(file);
+        std::fs::create_dir_all(path.parent().unwrap())?;
+
+        let write = |path: &Path, partition_df: &mut DataFrame| {
+            let file = std::fs::File::create(path)?;
+            let writer = BufWriter::new(file);
+
+            self.option
+                .create_writer::<BufWriter<File>>(writer)
+                .finish(partition_df)
+        };
+
+        match checkpoint {
+            None => write(&path, partition_df),
+            Some(checkpoint) => {
+                let mut tmp_path = path.clone().into_os_string();
+                tmp_path.push(".tmp");
+                write(tmp_path.as_ref(), partition_df)?;
+                std::fs::rename(&tmp_path, &path)?;
+                checkpoint.commit(file)
+            },
+        }
     }
 
     pub fn finish(self, df: &DataFrame) -> PolarsResult<()> {
-        let groups = df.group_by(self.by.clone())?;
+        let fingerprint = self.fingerprint(&df.schema(), &format!("rows: {}", df.height()))?;
+        let state = self.start(fingerprint)?;
+        self.write_df(&state, &state.prefix, df)?;
+        state.finish()
+    }
+
+    /// Write the DataFrame in batches, e.g. the batches of a streaming query.
+    ///
+    /// Every batch is partitioned on its own, so a partition gets at least one file per batch
+    /// that contains it. A checkpointed write can only be resumed if the batches are the same,
+    /// i.e. the data is split into batches of `batch_size` rows in the same order. `query`
+    /// describes what produces the batches, e.g. the plan of a query, and is recorded in the
+    /// checkpoint, so that the batches of another query don't resume the write.
+    pub fn into_batched(
+        self,
+        schema: &Schema,
+        batch_size: usize,
+        query: &str,
+    ) -> PolarsResult<BatchedPartitionedWriter<F>> {
+        let mut fingerprint = self.fingerprint(schema, &format!("batch_size: {batch_size}"))?;
+        // Every line of the query is a line of the manifest header.
+        fingerprint.extend(query.lines().map(|line| format!("query: {line}")));
+        let state = self.start(fingerprint)?;
+        Ok(BatchedPartitionedWriter {
+            writer: self,
+            state,
+            n_batches: 0,
+        })
+    }
+
+    /// What the checkpoint of this write was written for: the schema, the size of the
+    /// data, the options and the inputs.
+    fn fingerprint(&self, schema: &Schema, size: &str) -> PolarsResult<Vec<String>> {
+        let mut fingerprint = vec![
+            format!(
+                "schema: {}",
+                schema
+                    .iter()
+                    .map(|(name, dtype)| format!("{name}: {dtype:?}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            size.to_string(),
+            format!("by: {}", self.by.join(", ")),
+            format!("max_rows_per_file: {:?}", self.max_rows_per_file),
+            format!("include_key: {}", self.include_key),
+        ];
+        for input in &self.checkpoint_inputs {
+            let metadata = std::fs::metadata(input)?;
+            let modified = metadata
+                .modified()?
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_nanos());
+            fingerprint.push(format!(
+                "input: {} {} {modified}",
+                input.display(),
+                metadata.len()
+            ));
+        }
+        Ok(fingerprint)
+    }
+
+    fn start(&self, fingerprint: Vec<String>) -> PolarsResult<WriteState> {
+        let rootdir = resolve_homedir(&self.rootdir);
+        polars_ensure!(
+            self.max_rows_per_file != Some(0),
+            InvalidOperation: "`max_rows_per_file` must be positive"
+        );
+        polars_ensure!(
+            !(self.checkpoint && self.existing_data == ExistingDataBehavior::Append),
+            InvalidOperation: "a checkpointed partitioned write can't append to existing data"
+        );
+        // A resumed checkpoint finds the files of its previous attempt in the root directory.
+        let resuming = self.checkpoint && rootdir.join(CHECKPOINT_MANIFEST).exists();
+        if self.existing_data == ExistingDataBehavior::Error && !resuming {
+            polars_ensure!(
+                !has_entries(&rootdir)?,
+                ComputeError: "the root directory '{}' of the partitioned write already contains data",
+                rootdir.display()
+            );
+        }
+        // Appended files get a unique prefix so that they don't replace existing files.
+        let prefix = match self.existing_data {
+            ExistingDataBehavior::Append => {
+                let nanos = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |d| d.as_nanos());
+                format!("data-{nanos:x}")
+            },
+            _ => "data".to_string(),
+        };
+        let checkpoint = if self.checkpoint {
+            Some(Checkpoint::open(&rootdir, &fingerprint)?)
+        } else {
+            None
+        };
+        Ok(WriteState {
+            rootdir,
+            prefix,
+            checkpoint,
+            cleared: Default::default(),
+        })
+    }
+
+    /// Remove the existing files of a partition directory before its first file is written,
+    /// if the existing data is overwritten.
+    fn clear_partition_dir(&self, state: &WriteState, dir: &Path) -> PolarsResult<()> {
+        if self.existing_data != ExistingDataBehavior::Overwrite {
+            return Ok(());
+        }
+        // The directory of a resumed checkpoint was cleared by the previous attempt.
+        if state
+            .checkpoint
+            .as_ref()
+            .is_some_and(|checkpoint| checkpoint.contains_dir(dir))
+        {
+            return Ok(());
+        }
+        if state.cleared.lock().unwrap().insert(dir.to_path_buf()) {
+            remove_files(&state.rootdir.join(dir))?;
+        }
+        Ok(())
+    }
+
+    fn write_df(&self, state: &WriteState, prefix: &str, df: &DataFrame) -> PolarsResult<()> {
+        let rootdir = &state.rootdir;
+        let checkpoint = state.checkpoint.as_ref();
+        // A checkpoint can only be resumed if the partitions are numbered in the same order.
+        let groups = if checkpoint.is_some() {
+            df.group_by_stable(self.by.clone())?
+        } else {
+            df.group_by(self.by.clone())?
+        };
         let groups = groups.get_groups();
 
+        let write_partition =
+            |i: usize, first: IdxSize, len: usize, take: &dyn Fn() -> DataFrame| {
+                let dir = resolve_partition_dir(&self.by, df, first as usize);
+                let n_parts = self
+                    .max_rows_per_file
+                    .map_or(1, |max_rows| (len + max_rows - 1) / max_rows);
+                let files = (0..n_parts)
+                    .map(|part| self.partition_file(&dir, prefix, i, part, n_parts))
+                    .collect::<Vec<_>>();
+                if checkpoint
+                    .is_some_and(|checkpoint| files.iter().all(|file| checkpoint.contains(file)))
+                {
+                    return Ok(());
+                }
+                self.clear_partition_dir(state, &dir)?;
+                self.write_partition_files(rootdir, &files, take(), checkpoint)
+            };
+
         // don't parallelize this
         // there is a lot of parallelization in take and this may easily SO
         POOL.install(|| {
@@ -87,13 +379,18 @@ where
                 GroupsProxy::Idx(idx) => {
                     idx.par_iter()
                         .enumerate()
-                        .map(|(i, (_, group))| {
-                            // groups are in bounds
-                            // and sorted
-                            let mut part_df = unsafe {
-                                df._take_unchecked_slice_sorted(group, false, IsSorted::Ascending)
-                            };
-                            self.write_partition_df(&mut part_df, i)
+                        .map(|(i, (first, group))| {
+                            write_partition(i, first, group.len(), &|| {
+                                // groups are in bounds
+                                // and sorted
+                                unsafe {
+                                    df._take_unchecked_slice_sorted(
+                                        group,
+                                        false,
+                                        IsSorted::Ascending,
+                                    )
+                                }
+                            })
                         })
                         .collect::<PolarsResult<Vec<_>>>()
                 },
@@ -101,8 +398,9 @@ where
                     .par_iter()
                     .enumerate()
                     .map(|(i, [first, len])| {
-                        let mut part_df = df.slice(*first as i64, *len as usize);
-                        self.write_partition_df(&mut part_df, i)
+                        write_partition(i, *first, *len as usize, &|| {
+                            df.slice(*first as i64, *len as usize)
+                        })
                     })
                     .collect::<PolarsResult<Vec<_>>>(),
             }
@@ -110,20 +408,324 @@ where
 
         Ok(())
     }
+
+    /// Write `partition_df` to `files`, in slices of at most `max_rows_per_file` rows.
+    fn write_partition_files(
+        &self,
+        rootdir: &Path,
+        files: &[PathBuf],
+        partition_df: DataFrame,
+        checkpoint: Option<&Checkpoint>,
+    ) -> PolarsResult<()> {
+        let partition_df = if self.include_key {
+            partition_df
+        } else {
+            partition_df.drop_many(&self.by)
+        };
+        let max_rows = self.max_rows_per_file.unwrap_or(partition_df.height());
+        for (part, file) in files.iter().enumerate() {
+            if checkpoint.is_some_and(|checkpoint| checkpoint.contains(file)) {
+                continue;
+            }
+            let mut part_df = partition_df.slice((part * max_rows) as i64, max_rows);
+            self.write_partition_df(rootdir, file, &mut part_df, checkpoint)?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether the directory exists and has any entries.
+fn has_entries(dir: &Path) -> PolarsResult<bool> {
+    match std::fs::read_dir(dir) {
+        Ok(mut entries) => Ok(entries.next().is_some()),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(false),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Remove the files, but not the subdirectories, of a directory if it exists.
+fn remove_files(dir: &Path) -> PolarsResult<()> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err.into()),
+    };
+    for entry in entries {
+        let entry = entry?;
+        if entry.file_type()?.is_file() {
+            std::fs::remove_file(entry.path())?;
+        }
+    }
+    Ok(())
 }
 
-/// `partition_df` must be created in the same way as `partition_by`.
-fn resolve_partition_dir<I, S>(rootdir: &Path, by: I, partition_df: &DataFrame) -> PathBuf
+/// A partitioned write of a DataFrame in batches, see [`PartitionedWriter::into_batched`].
+pub struct BatchedPartitionedWriter<F> {
+    writer: PartitionedWriter<F>,
+    state: WriteState,
+    n_batches: usize,
+}
+
+impl<F> BatchedPartitionedWriter<F>
+where
+    F: WriterFactory + Send + Sync,
+{
+    /// Write the next batch.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let prefix = format!("{}-{:05}", self.state.prefix, self.n_batches);
+        self.n_batches += 1;
+        self.writer.write_df(&self.state, &prefix, df)
+    }
+
+    /// Finish the write once all batches are written.
+    pub fn finish(self) -> PolarsResult<()> {
+        self.state.finish()
+    }
+}
+
+/// The state of a partitioned write that is in progress.
+struct WriteState {
+    rootdir: PathBuf,
+    prefix: String,
+    checkpoint: Option<Checkpoint>,
+    /// The partition directories whose existing files were removed by this write.
+    cleared: Mutex<PlHashSet<PathBuf>>,
+}
+
+impl WriteState {
+    fn finish(&self) -> PolarsResult<()> {
+        match &self.checkpoint {
+            Some(checkpoint) => checkpoint.finish(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The prefix of the lines of the manifest that describe what the checkpoint was written for.
+const CHECKPOINT_HEADER_PREFIX: &str = "# ";
+
+/// The files of a checkpointed write that are complete.
+///
+/// The manifest starts with the fingerprint of the write, followed by a line per written file.
+struct Checkpoint {
+    path: PathBuf,
+    written: PlHashSet<PathBuf>,
+    written_dirs: PlHashSet<PathBuf>,
+    manifest: Mutex<File>,
+}
+
+impl Checkpoint {
+    fn open(rootdir: &Path, fingerprint: &[String]) -> PolarsResult<Self> {
+        std::fs::create_dir_all(rootdir)?;
+        let path = rootdir.join(CHECKPOINT_MANIFEST);
+        let written: PlHashSet<PathBuf> = match std::fs::read_to_string(&path) {
+            Ok(manifest) => {
+                let (header, written): (Vec<_>, Vec<_>) = manifest
+                    .lines()
+                    .partition(|line| line.starts_with(CHECKPOINT_HEADER_PREFIX));
+                let header = header
+                    .iter()
+                    .map(|line| &line[CHECKPOINT_HEADER_PREFIX.len()..]);
+                polars_ensure!(
+                    header.eq(fingerprint.iter().map(|line| line.as_str())),
+                    ComputeError: "the checkpoint in '{}' was written for other data or options; \
+                    remove it to start the write over",
+                    path.display()
+                );
+                written.into_iter().map(PathBuf::from).collect()
+            },
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                let mut manifest = File::create(&path)?;
+                for line in fingerprint {
+                    writeln!(manifest, "{CHECKPOINT_HEADER_PREFIX}{line}")?;
+                }
+                manifest.sync_data()?;
+                PlHashSet::new()
+            },
+            Err(err) => return Err(err.into()),
+        };
+        let written_dirs = written
+            .iter()
+            .filter_map(|file| file.parent().map(Path::to_path_buf))
+            .collect();
+        let manifest = OpenOptions::new().append(true).open(&path)?;
+        Ok(Self {
+            path,
+            written,
+            written_dirs,
+            manifest: Mutex::new(manifest),
+        })
+    }
+
+    fn contains(&self, file: &Path) -> bool {
+        self.written.contains(file)
+    }
+
+    /// Whether any file of the partition directory `dir` was written.
+    fn contains_dir(&self, dir: &Path) -> bool {
+        self.written_dirs.contains(dir)
+    }
+
+    /// Record that `file` is completely written.
+    fn commit(&self, file: &Path) -> PolarsResult<()> {
+        let mut manifest = self.manifest.lock().unwrap();
+        writeln!(manifest, "{}", file.display())?;
+        manifest.sync_data()?;
+        Ok(())
+    }
+
+    fn finish(&self) -> PolarsResult<()> {
+        std::fs::remove_file(&self.path)?;
+        Ok(())
+    }
+}
+
+/// The directory of the partition that contains `row` of `df`, relative to the root directory.
+fn resolve_partition_dir<I, S>(by: I, df: &DataFrame, row: usize) -> PathBuf
 where
     I: IntoIterator<Item = S>,
     S: AsRef<str>,
 {
     let mut path = PathBuf::new();
-    path.push(resolve_homedir(rootdir));
 
     for key in by.into_iter() {
-        let value = partition_df[key.as_ref()].get(0).unwrap().to_string();
+        let value = df[key.as_ref()].get(row).unwrap();
+        let value = if value.is_null() {
+            HIVE_DEFAULT_PARTITION.to_string()
+        } else if let Some(s) = value.get_str() {
+            // Don't use the `Display` of strings, it quotes them.
+            utf8_percent_encode(s, PARTITION_KEY_ENCODE_SET).to_string()
+        } else {
+            utf8_percent_encode(&value.to_string(), PARTITION_KEY_ENCODE_SET).to_string()
+        };
         path.push(format!("{}={}", key.as_ref(), value))
     }
     path
 }
+
+#[cfg(test)]
+#[cfg(feature = "ipc")]
+mod test {
+    use super::*;
+    use crate::ipc::{IpcReader, IpcWriter, IpcWriterOption};
+    use crate::{SerReader, SerWriter};
+
+    #[test]
+    fn test_partitioned_writer_checkpoint() -> PolarsResult<()> {
+        let tmp_dir = tempfile::tempdir()?;
+        let rootdir = tmp_dir.path().join("partitioned");
+        let df = df!("a" => [1, 1, 2, 3], "b" => [2, 2, 3, 4], "c" => [2, 3, 4, 5])?;
+        let read = |file: &str| IpcReader::new(File::open(rootdir.join(file))?).finish();
+        let writer = || {
+            PartitionedWriter::new(IpcWriterOption::new(), &rootdir, ["a", "b"])
+                .with_checkpoint(true)
+        };
+
+        // Simulate a failed write in which only the first partition was completed.
+        let written = "a=1/b=2/data-0000.ipc";
+        let mut previous = df.head(Some(1));
+        let fingerprint = writer().fingerprint(&df.schema(), "rows: 4")?;
+        let checkpoint = Checkpoint::open(&rootdir, &fingerprint)?;
+        std::fs::create_dir_all(rootdir.join("a=1/b=2"))?;
+        IpcWriter::new(File::create(rootdir.join(written))?).finish(&mut previous)?;
+        checkpoint.commit(written.as_ref())?;
+
+        // A write of other data doesn't resume the checkpoint.
+        assert!(writer().finish(&df.head(Some(3))).is_err());
+        assert!(writer()
+            .with_max_rows_per_file(Some(1))
+            .finish(&df)
+            .is_err());
+
+        writer().finish(&df)?;
+
+        // The completed partition is not written again.
+        assert!(read(written)?.equals(&previous));
+        assert!(read("a=2/b=3/data-0001.ipc")?.equals(&df.slice(2, 1)));
+        assert!(read("a=3/b=4/data-0002.ipc")?.equals(&df.slice(3, 1)));
+        assert_eq!(std::fs::read_dir(rootdir.join("a=2/b=3"))?.count(), 1);
+        assert!(!rootdir.join(CHECKPOINT_MANIFEST).exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_partitioned_writer_checkpoint_inputs() -> PolarsResult<()> {
+        let tmp_dir = tempfile::tempdir()?;
+        let rootdir = tmp_dir.path().join("partitioned");
+        let input = tmp_dir.path().join("input.csv");
+        std::fs::write(&input, "a\n1\n")?;
+        let df = df!("a" => [1, 2], "b" => [3, 4])?;
+        let writer = || {
+            PartitionedWriter::new(IpcWriterOption::new(), &rootdir, ["a"])
+                .with_checkpoint(true)
+                .with_checkpoint_inputs([&input])
+        };
+
+        // Simulate a failed write of the first batch.
+        let mut batched = writer().into_batched(&df.schema(), 1, "query")?;
+        batched.write_batch(&df.head(Some(1)))?;
+        drop(batched);
+        assert!(rootdir.join("a=1/data-00000-0000.ipc").exists());
+        assert!(rootdir.join(CHECKPOINT_MANIFEST).exists());
+
+        // The input changed, so the written files may be outdated.
+        std::fs::write(&input, "a\n1\n2\n")?;
+        assert!(writer().into_batched(&df.schema(), 1, "query").is_err());
+
+        std::fs::remove_file(rootdir.join(CHECKPOINT_MANIFEST))?;
+        let mut batched = writer()
+            .with_existing_data(ExistingDataBehavior::Overwrite)
+            .into_batched(&df.schema(), 1, "query")?;
+        batched.write_batch(&df.head(Some(1)))?;
+        batched.write_batch(&df.tail(Some(1)))?;
+        batched.finish()?;
+        let read = |file: &str| IpcReader::new(File::open(rootdir.join(file))?).finish();
+        assert!(read("a=1/data-00000-0000.ipc")?.equals(&df.head(Some(1))));
+        assert!(read("a=2/data-00001-0000.ipc")?.equals(&df.tail(Some(1))));
+        assert!(!rootdir.join(CHECKPOINT_MANIFEST).exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_partitioned_writer_options() -> PolarsResult<()> {
+        let tmp_dir = tempfile::tempdir()?;
+        let rootdir = tmp_dir.path().join("partitioned");
+        let df = df!(
+            "a" => [Some("x/y"), Some("x/y"), Some("x/y"), None],
+            "b" => [1, 2, 3, 4],
+        )?;
+        let read_dir = |dir: &str| -> PolarsResult<Vec<String>> {
+            let mut names = std::fs::read_dir(rootdir.join(dir))?
+                .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            names.sort();
+            Ok(names)
+        };
+        let writer = || PartitionedWriter::new(IpcWriterOption::new(), &rootdir, ["a"]);
+
+        writer().with_max_rows_per_file(Some(2)).finish(&df)?;
+        assert_eq!(read_dir("")?, ["a=__HIVE_DEFAULT_PARTITION__", "a=x%2Fy"]);
+        let files = read_dir("a=x%2Fy")?;
+        assert_eq!(files.len(), 2);
+        let second = IpcReader::new(File::open(rootdir.join("a=x%2Fy").join(&files[1]))?);
+        assert!(second.finish()?.equals(&df.slice(2, 1)));
+
+        // By default, existing data is kept.
+        assert!(writer().finish(&df.head(Some(1))).is_err());
+        assert_eq!(read_dir("a=x%2Fy")?.len(), 2);
+        assert_eq!(read_dir("a=__HIVE_DEFAULT_PARTITION__")?.len(), 1);
+
+        writer()
+            .with_existing_data(ExistingDataBehavior::Append)
+            .finish(&df)?;
+        assert_eq!(read_dir("a=x%2Fy")?.len(), 3);
+        assert_eq!(read_dir("a=__HIVE_DEFAULT_PARTITION__")?.len(), 2);
+
+        writer()
+            .with_existing_data(ExistingDataBehavior::Overwrite)
+            .finish(&df.head(Some(1)))?;
+        assert_eq!(read_dir("a=x%2Fy")?.len(), 1);
+        assert_eq!(read_dir("a=__HIVE_DEFAULT_PARTITION__")?.len(), 2);
+        Ok(())
+    }
+}
@@ -1,13 +1,16 @@
 use std::error::Error;
 use std::future::Future;
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering};
+use std::sync::Arc;
 
 use once_cell::sync::Lazy;
 use polars_core::config::verbose;
 use polars_core::POOL;
+use polars_error::{polars_ensure, PolarsResult};
 use tokio::runtime::{Builder, Runtime};
-use tokio::sync::Semaphore;
+use tokio::sync::{Semaphore, SemaphorePermit};
 
 static CONCURRENCY_BUDGET: std::sync::OnceLock<(Semaphore, u32)> = std::sync::OnceLock::new();
 pub(super) const MAX_BUDGET_PER_REQUEST: usize = 10;
@@ -229,6 +232,79 @@ where
     callable().await
 }
 
+/// Limits the number of concurrent requests of a single scan.
+///
+/// This applies on top of the global concurrency budget, which is shared by all scans and can
+/// be set with the `POLARS_CONCURRENCY_BUDGET` environment variable. Clones share the same
+/// permits, so all files of a scan are limited together. A request first waits for the limit
+/// of its scan and only then for the global budget, so a scan that is at its limit doesn't hold
+/// on to global permits that other scans could use. Both wait in first-in-first-out order.
+///
+/// The global budget is fixed when it is first used, there is no per-query limit between the
+/// two.
+#[derive(Clone, Debug)]
+pub struct RequestLimit {
+    limit: u32,
+    semaphore: Arc<Semaphore>,
+}
+
+impl RequestLimit {
+    pub fn new(limit: u32) -> PolarsResult<Self> {
+        polars_ensure!(
+            limit > 0,
+            InvalidOperation: "the maximum number of concurrent requests must be positive"
+        );
+        Ok(Self {
+            limit,
+            semaphore: Arc::new(Semaphore::new(limit as usize)),
+        })
+    }
+
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+
+    /// Wait until `requested_budget` more requests may run concurrently.
+    pub(crate) async fn acquire(&self, requested_budget: u32) -> SemaphorePermit<'_> {
+        // Requesting more than the limit would never finish.
+        self.semaphore
+            .acquire_many(requested_budget.min(self.limit))
+            .await
+            .unwrap()
+    }
+}
+
+// Limits are only equal if they share their permits, otherwise two scans with their own limit
+// would be considered the same, e.g. when scans are cached.
+impl PartialEq for RequestLimit {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.semaphore, &other.semaphore)
+    }
+}
+
+impl Eq for RequestLimit {}
+
+impl Hash for RequestLimit {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.semaphore).hash(state)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RequestLimit {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.limit)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RequestLimit {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let limit = u32::deserialize(deserializer)?;
+        Self::new(limit).map_err(serde::de::Error::custom)
+    }
+}
+
 pub struct RuntimeManager {
     rt: Runtime,
 }
@@ -7,6 +7,11 @@ pub trait PhysicalIoExpr: Send + Sync {
     /// as a predicate mask
     fn evaluate_io(&self, df: &DataFrame) -> PolarsResult<Series>;
 
+    /// The names of the columns that the predicate reads, if they are known.
+    fn live_variables(&self) -> Option<Vec<Arc<str>>> {
+        None
+    }
+
     /// Can take &dyn Statistics and determine of a file should be
     /// read -> `true`
     /// or not -> `false`
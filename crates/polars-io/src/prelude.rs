@@ -11,3 +11,5 @@ pub use crate::ndjson::core::*;
 pub use crate::parquet::{metadata::*, read::*, write::*};
 pub use crate::shared::{SerReader, SerWriter};
 pub use crate::utils::*;
+#[cfg(feature = "xlsx")]
+pub use crate::xlsx::*;
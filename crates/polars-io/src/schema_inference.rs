@@ -0,0 +1,221 @@
+//! Infer the dtypes of the text columns of a sample, reporting every candidate dtype.
+//!
+//! The values are classified the way the CSV scanner infers the schema of a file, but instead
+//! of a single dtype per column, every dtype that parses some of the values is reported with
+//! the fraction of the values that it parses and examples of values that it doesn't.
+use polars_core::prelude::*;
+
+use crate::csv::read::schema_inference::infer_field_schema;
+
+/// The number of conflicting values that are reported per candidate.
+const N_CONFLICT_EXAMPLES: usize = 3;
+
+/// A dtype to try parsing text values as.
+///
+/// The order of the variants is their precedence: a column gets the first dtype that parses
+/// all of its values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InferenceStrategy {
+    Boolean,
+    Integer,
+    Float,
+    Date,
+    Datetime,
+}
+
+impl InferenceStrategy {
+    pub const ALL: [InferenceStrategy; 5] = [
+        InferenceStrategy::Boolean,
+        InferenceStrategy::Integer,
+        InferenceStrategy::Float,
+        InferenceStrategy::Date,
+        InferenceStrategy::Datetime,
+    ];
+
+    fn dtype(self, time_zone: Option<&str>) -> DataType {
+        match self {
+            InferenceStrategy::Boolean => DataType::Boolean,
+            InferenceStrategy::Integer => DataType::Int64,
+            InferenceStrategy::Float => DataType::Float64,
+            InferenceStrategy::Date => DataType::Date,
+            InferenceStrategy::Datetime => {
+                DataType::Datetime(TimeUnit::Microseconds, time_zone.map(|tz| tz.to_string()))
+            },
+        }
+    }
+
+    /// Whether a value that the scanner infers as `value_dtype` parses with this strategy.
+    fn parses(self, value_dtype: &DataType, time_zone: Option<&str>) -> bool {
+        match (self, value_dtype) {
+            (InferenceStrategy::Boolean, DataType::Boolean)
+            | (InferenceStrategy::Integer, DataType::Int64)
+            | (InferenceStrategy::Float, DataType::Int64 | DataType::Float64)
+            | (InferenceStrategy::Date, DataType::Date)
+            | (InferenceStrategy::Datetime, DataType::Date) => true,
+            (InferenceStrategy::Datetime, DataType::Datetime(_, tz)) => tz.as_deref() == time_zone,
+            _ => false,
+        }
+    }
+}
+
+/// A dtype that parses the values of a column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DtypeCandidate {
+    pub dtype: DataType,
+    /// The fraction of the non-null values that parse.
+    pub confidence: f64,
+    /// Up to three distinct values that don't parse, in the order they occur.
+    pub conflicts: Vec<String>,
+}
+
+/// The candidate dtypes of a column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnInference {
+    pub name: String,
+    /// The selected dtype comes first, followed by the others by decreasing confidence.
+    pub candidates: Vec<DtypeCandidate>,
+}
+
+impl ColumnInference {
+    pub fn selected(&self) -> &DataType {
+        &self.candidates[0].dtype
+    }
+}
+
+/// Infer the candidate dtypes of every column of `sample`, a frame that was read from a
+/// sample of a source.
+///
+/// Text columns are parsed with every strategy in `strategies` and get the first dtype that
+/// parses all of their non-null values, or `String` if there is none. Columns that the reader
+/// already parsed into another dtype keep that dtype.
+pub fn infer_sample_schema(
+    sample: &DataFrame,
+    strategies: &[InferenceStrategy],
+) -> PolarsResult<Vec<ColumnInference>> {
+    sample
+        .get_columns()
+        .iter()
+        .map(|s| {
+            let candidates = if s.dtype() == &DataType::String {
+                string_candidates(s.str()?, strategies)
+            } else {
+                vec![DtypeCandidate {
+                    dtype: s.dtype().clone(),
+                    confidence: 1.0,
+                    conflicts: vec![],
+                }]
+            };
+            Ok(ColumnInference {
+                name: s.name().to_string(),
+                candidates,
+            })
+        })
+        .collect()
+}
+
+fn string_candidates(ca: &StringChunked, strategies: &[InferenceStrategy]) -> Vec<DtypeCandidate> {
+    let try_parse_dates = cfg!(feature = "polars-time")
+        && strategies
+            .iter()
+            .any(|s| matches!(s, InferenceStrategy::Date | InferenceStrategy::Datetime));
+    let values = ca
+        .into_iter()
+        .flatten()
+        .map(|value| {
+            let value = value.trim();
+            // The scanner only sees quotes around values that it leaves unescaped.
+            let dtype = if value.starts_with('"') {
+                DataType::String
+            } else {
+                infer_field_schema(value, try_parse_dates, false)
+            };
+            (value, dtype)
+        })
+        .collect::<Vec<_>>();
+
+    // Datetimes with a time zone can't be parsed into the same column as those without.
+    let time_zone = values
+        .iter()
+        .find_map(|(_, dtype)| match dtype {
+            DataType::Datetime(_, tz) => Some(tz.as_deref()),
+            _ => None,
+        })
+        .flatten();
+
+    let mut candidates = vec![];
+    if !values.is_empty() {
+        for strategy in InferenceStrategy::ALL {
+            if !strategies.contains(&strategy) {
+                continue;
+            }
+            let mut n_parsed = 0;
+            let mut conflicts: Vec<String> = vec![];
+            for (value, dtype) in values.iter() {
+                if strategy.parses(dtype, time_zone) {
+                    n_parsed += 1;
+                } else if conflicts.len() < N_CONFLICT_EXAMPLES
+                    && !conflicts.iter().any(|c| c == value)
+                {
+                    conflicts.push(value.to_string());
+                }
+            }
+            if n_parsed > 0 {
+                candidates.push(DtypeCandidate {
+                    dtype: strategy.dtype(time_zone),
+                    confidence: n_parsed as f64 / values.len() as f64,
+                    conflicts,
+                });
+            }
+        }
+    }
+
+    // The candidates are in order of precedence, so the first that parses everything wins.
+    match candidates.iter().position(|c| c.confidence == 1.0) {
+        Some(idx) => {
+            let selected = candidates.remove(idx);
+            candidates.insert(0, selected);
+        },
+        None => candidates.insert(
+            0,
+            DtypeCandidate {
+                dtype: DataType::String,
+                confidence: 1.0,
+                conflicts: vec![],
+            },
+        ),
+    }
+    candidates[1..].sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_core::df;
+
+    use super::*;
+
+    #[test]
+    fn test_infer_sample_schema() -> PolarsResult<()> {
+        let df = df![
+            "id" => [Some("1"), Some("2"), None],
+            "score" => [Some("0.5"), Some("n/a"), Some("2")],
+            "n" => [1i64, 2, 3],
+        ]?;
+        let columns = infer_sample_schema(&df, &InferenceStrategy::ALL)?;
+
+        assert_eq!(columns[0].selected(), &DataType::Int64);
+        assert_eq!(columns[1].selected(), &DataType::String);
+        assert_eq!(columns[2].selected(), &DataType::Int64);
+
+        let score = &columns[1].candidates;
+        assert_eq!(score.len(), 3);
+        assert_eq!(score[1].dtype, DataType::Float64);
+        assert_eq!(score[1].conflicts, ["n/a"]);
+        assert_eq!(score[2].dtype, DataType::Int64);
+        assert_eq!(score[2].conflicts, ["0.5", "n/a"]);
+
+        let columns = infer_sample_schema(&df, &[InferenceStrategy::Float])?;
+        assert_eq!(columns[0].selected(), &DataType::Float64);
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+//! # Writing Excel workbooks
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//! use std::fs::File;
+//!
+//! fn example(sales: &DataFrame, costs: &DataFrame) -> PolarsResult<()> {
+//!     let file = File::create("report.xlsx")?;
+//!     let mut writer = XlsxWriter::new(file);
+//!     // The sheets are built in parallel and added in order.
+//!     writer.write_sheets(&[
+//!         (
+//!             sales,
+//!             XlsxSheetOptions::default()
+//!                 .with_name("sales")
+//!                 .with_column_format("revenue", "#,##0.00")
+//!                 .with_freeze_header(true)
+//!                 .with_autofilter(true),
+//!         ),
+//!         (costs, XlsxSheetOptions::default().with_name("costs")),
+//!     ])?;
+//!     writer.close()
+//! }
+//! ```
+mod write;
+
+pub use write::*;
@@ -0,0 +1,466 @@
+use std::io::Write;
+
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use polars_core::POOL;
+use polars_utils::aliases::PlHashMap;
+use rayon::prelude::*;
+use rust_xlsxwriter::{ColNum, Format, RowNum, Workbook, Worksheet};
+
+use crate::shared::SerWriter;
+
+/// The number of rows of an Excel worksheet.
+const MAX_ROWS: usize = 1_048_576;
+/// The number of columns of an Excel worksheet.
+const MAX_COLUMNS: usize = 16_384;
+/// Excel stores dates as days since 1899-12-30, which is this many days before 1970-01-01.
+const EXCEL_EPOCH_OFFSET: f64 = 25_569.0;
+const SECONDS_IN_DAY: f64 = 86_400.0;
+
+/// Options for a single worksheet written by [`XlsxWriter`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XlsxSheetOptions {
+    /// The name of the worksheet. Excel names sheets `Sheet1`, `Sheet2`, ... by default.
+    pub name: Option<String>,
+    /// Write the column names as a bold first row.
+    pub include_header: bool,
+    /// Adjust the column widths to their content.
+    pub autofit: bool,
+    /// Show floats with this many decimals, unless a column format is set.
+    pub float_precision: Option<usize>,
+    /// Excel number formats, such as `"0.00%"` or `"dd/mm/yyyy"`, by column name.
+    pub column_formats: PlHashMap<String, String>,
+    /// Keep the header row visible when scrolling.
+    pub freeze_header: bool,
+    /// Add filter buttons to the header row.
+    pub autofilter: bool,
+    /// Flush every row to a temporary file once it is written, so that the worksheet
+    /// doesn't have to be kept in memory. Can't be combined with `autofit`.
+    pub constant_memory: bool,
+}
+
+impl Default for XlsxSheetOptions {
+    fn default() -> Self {
+        Self {
+            name: None,
+            include_header: true,
+            autofit: false,
+            float_precision: None,
+            column_formats: PlHashMap::new(),
+            freeze_header: false,
+            autofilter: false,
+            constant_memory: false,
+        }
+    }
+}
+
+impl XlsxSheetOptions {
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn with_include_header(mut self, include_header: bool) -> Self {
+        self.include_header = include_header;
+        self
+    }
+
+    pub fn with_autofit(mut self, autofit: bool) -> Self {
+        self.autofit = autofit;
+        self
+    }
+
+    pub fn with_float_precision(mut self, float_precision: Option<usize>) -> Self {
+        self.float_precision = float_precision;
+        self
+    }
+
+    pub fn with_column_format(mut self, column: &str, num_format: &str) -> Self {
+        self.column_formats
+            .insert(column.to_string(), num_format.to_string());
+        self
+    }
+
+    pub fn with_freeze_header(mut self, freeze_header: bool) -> Self {
+        self.freeze_header = freeze_header;
+        self
+    }
+
+    pub fn with_autofilter(mut self, autofilter: bool) -> Self {
+        self.autofilter = autofilter;
+        self
+    }
+
+    pub fn with_constant_memory(mut self, constant_memory: bool) -> Self {
+        self.constant_memory = constant_memory;
+        self
+    }
+}
+
+/// Write [`DataFrame`]s as worksheets of an Excel workbook.
+///
+/// Use [`SerWriter::finish`] to write a workbook with a single sheet, or
+/// [`XlsxWriter::write_sheet`] or [`XlsxWriter::write_sheets`] followed by
+/// [`XlsxWriter::close`] to write several sheets.
+///
+/// Temporal columns are written as Excel dates and times, without their time zone.
+/// Null, `NaN` and infinite values are written as empty cells.
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::prelude::*;
+/// use std::fs::File;
+///
+/// fn example(df: &mut DataFrame) -> PolarsResult<()> {
+///     let file = File::create("example.xlsx")?;
+///     XlsxWriter::new(file)
+///         .with_sheet_name("data")
+///         .with_autofit(true)
+///         .finish(df)
+/// }
+/// ```
+#[must_use]
+pub struct XlsxWriter<W> {
+    writer: W,
+    workbook: Workbook,
+    options: XlsxSheetOptions,
+}
+
+impl<W> XlsxWriter<W>
+where
+    W: Write,
+{
+    /// Set the name of the sheet written by [`SerWriter::finish`].
+    pub fn with_sheet_name(mut self, name: &str) -> Self {
+        self.options.name = Some(name.to_string());
+        self
+    }
+
+    /// Write the column names as a bold first row. Defaults to `true`.
+    pub fn include_header(mut self, include_header: bool) -> Self {
+        self.options.include_header = include_header;
+        self
+    }
+
+    /// Adjust the column widths to their content. Defaults to `false`.
+    pub fn with_autofit(mut self, autofit: bool) -> Self {
+        self.options.autofit = autofit;
+        self
+    }
+
+    /// Show floats with this many decimals, unless a column format is set.
+    pub fn with_float_precision(mut self, float_precision: Option<usize>) -> Self {
+        self.options.float_precision = float_precision;
+        self
+    }
+
+    /// Set the Excel number format of a column, such as `"0.00%"`.
+    pub fn with_column_format(mut self, column: &str, num_format: &str) -> Self {
+        self.options = self.options.with_column_format(column, num_format);
+        self
+    }
+
+    /// Keep the header row visible when scrolling. Defaults to `false`.
+    pub fn with_freeze_header(mut self, freeze_header: bool) -> Self {
+        self.options.freeze_header = freeze_header;
+        self
+    }
+
+    /// Add filter buttons to the header row. Defaults to `false`.
+    pub fn with_autofilter(mut self, autofilter: bool) -> Self {
+        self.options.autofilter = autofilter;
+        self
+    }
+
+    /// Stream the rows to a temporary file instead of keeping the worksheet in memory.
+    /// Defaults to `false`.
+    pub fn with_constant_memory(mut self, constant_memory: bool) -> Self {
+        self.options.constant_memory = constant_memory;
+        self
+    }
+
+    /// Add `df` as a new worksheet. Nothing is written until [`XlsxWriter::close`] is called,
+    /// unless [`XlsxSheetOptions::constant_memory`] is set.
+    pub fn write_sheet(&mut self, df: &DataFrame, options: &XlsxSheetOptions) -> PolarsResult<()> {
+        validate(df, options)?;
+        let sheet = if options.constant_memory {
+            self.workbook.add_worksheet_with_constant_memory()
+        } else {
+            self.workbook.add_worksheet()
+        };
+        fill_sheet(sheet, df, options)
+    }
+
+    /// Add a worksheet per [`DataFrame`], in order. The worksheets are built in parallel,
+    /// unless any of them sets [`XlsxSheetOptions::constant_memory`].
+    pub fn write_sheets(&mut self, sheets: &[(&DataFrame, XlsxSheetOptions)]) -> PolarsResult<()> {
+        for (df, options) in sheets {
+            validate(df, options)?;
+        }
+        if sheets.iter().any(|(_, options)| options.constant_memory) {
+            for (df, options) in sheets {
+                self.write_sheet(df, options)?;
+            }
+            return Ok(());
+        }
+
+        let worksheets = POOL.install(|| {
+            sheets
+                .par_iter()
+                .map(|(df, options)| {
+                    let mut sheet = Worksheet::new();
+                    fill_sheet(&mut sheet, df, options)?;
+                    Ok(sheet)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        for sheet in worksheets {
+            self.workbook.push_worksheet(sheet);
+        }
+        Ok(())
+    }
+
+    /// Write the workbook with all sheets added by [`XlsxWriter::write_sheet`].
+    pub fn close(mut self) -> PolarsResult<()> {
+        self.save()
+    }
+
+    fn save(&mut self) -> PolarsResult<()> {
+        let buf = self.workbook.save_to_buffer().map_err(to_compute_err)?;
+        self.writer.write_all(&buf)?;
+        Ok(())
+    }
+}
+
+impl<W> SerWriter<W> for XlsxWriter<W>
+where
+    W: Write,
+{
+    fn new(writer: W) -> Self {
+        Self {
+            writer,
+            workbook: Workbook::new(),
+            options: XlsxSheetOptions::default(),
+        }
+    }
+
+    fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let options = self.options.clone();
+        self.write_sheet(df, &options)?;
+        self.save()
+    }
+}
+
+fn default_format(dtype: &DataType, float_precision: Option<usize>) -> Option<Format> {
+    let num_format = match dtype {
+        DataType::Float32 | DataType::Float64 => match float_precision? {
+            0 => "0".to_string(),
+            precision => format!("0.{}", "0".repeat(precision)),
+        },
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => "yyyy-mm-dd".to_string(),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(_, _) => "yyyy-mm-dd hh:mm:ss".to_string(),
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => "hh:mm:ss".to_string(),
+        #[cfg(feature = "dtype-duration")]
+        DataType::Duration(_) => "[h]:mm:ss".to_string(),
+        _ => return None,
+    };
+    Some(Format::new().set_num_format(num_format))
+}
+
+fn validate(df: &DataFrame, options: &XlsxSheetOptions) -> PolarsResult<()> {
+    let header_rows = options.include_header as usize;
+    polars_ensure!(
+        df.height() + header_rows <= MAX_ROWS && df.width() <= MAX_COLUMNS,
+        ComputeError: "a DataFrame of shape {:?} does not fit in an Excel worksheet", df.shape()
+    );
+    polars_ensure!(
+        !(options.constant_memory && options.autofit),
+        InvalidOperation: "`autofit` is not supported for constant memory worksheets"
+    );
+    for name in options.column_formats.keys() {
+        df.try_get_column_index(name)?;
+    }
+    Ok(())
+}
+
+fn fill_sheet(
+    sheet: &mut Worksheet,
+    df: &DataFrame,
+    options: &XlsxSheetOptions,
+) -> PolarsResult<()> {
+    if let Some(name) = &options.name {
+        sheet.set_name(name).map_err(to_compute_err)?;
+    }
+
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(to_cell_values)
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let cells = columns
+        .iter()
+        .map(|s| Cells::new(s))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let formats = df
+        .get_columns()
+        .iter()
+        .map(|s| match options.column_formats.get(s.name()) {
+            Some(num_format) => Some(Format::new().set_num_format(num_format)),
+            None => default_format(s.dtype(), options.float_precision),
+        })
+        .collect::<Vec<_>>();
+
+    // Rows are written in order, as required for constant memory worksheets.
+    let header_rows = options.include_header as usize;
+    if options.include_header {
+        let header_format = Format::new().set_bold();
+        for (col, s) in df.get_columns().iter().enumerate() {
+            sheet
+                .write_string_with_format(0, col as ColNum, s.name(), &header_format)
+                .map_err(to_compute_err)?;
+        }
+    }
+    for idx in 0..df.height() {
+        let row = (idx + header_rows) as RowNum;
+        for (col, (cells, format)) in cells.iter().zip(&formats).enumerate() {
+            cells.write(sheet, idx, row, col as ColNum, format.as_ref())?;
+        }
+    }
+
+    if options.include_header && options.freeze_header {
+        sheet.set_freeze_panes(1, 0).map_err(to_compute_err)?;
+    }
+    if options.autofilter && df.width() > 0 {
+        let last_row = (df.height() + header_rows).saturating_sub(1) as RowNum;
+        let last_col = (df.width() - 1) as ColNum;
+        sheet
+            .autofilter(0, 0, last_row, last_col)
+            .map_err(to_compute_err)?;
+    }
+    if options.autofit {
+        sheet.autofit();
+    }
+    Ok(())
+}
+
+#[cfg(any(
+    feature = "dtype-datetime",
+    feature = "dtype-time",
+    feature = "dtype-duration"
+))]
+fn units_in_day(tu: TimeUnit) -> f64 {
+    let units_in_second = match tu {
+        TimeUnit::Nanoseconds => 1e9,
+        TimeUnit::Microseconds => 1e6,
+        TimeUnit::Milliseconds => 1e3,
+    };
+    units_in_second * SECONDS_IN_DAY
+}
+
+/// Convert a column to the values of its cells: booleans, strings or numbers.
+fn to_cell_values(s: &Series) -> PolarsResult<Series> {
+    let out = match s.dtype() {
+        DataType::Boolean | DataType::String => s.clone(),
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => s.cast(&DataType::String)?,
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => {
+            let ca: Float64Chunked = s
+                .date()?
+                .apply_generic(|v| Some(v? as f64 + EXCEL_EPOCH_OFFSET));
+            ca.into_series()
+        },
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(tu, _) => {
+            let units_in_day = units_in_day(*tu);
+            let ca: Float64Chunked = s
+                .datetime()?
+                .apply_generic(|v| Some(v? as f64 / units_in_day + EXCEL_EPOCH_OFFSET));
+            ca.into_series()
+        },
+        #[cfg(feature = "dtype-duration")]
+        DataType::Duration(tu) => {
+            let units_in_day = units_in_day(*tu);
+            let ca: Float64Chunked = s
+                .duration()?
+                .apply_generic(|v| Some(v? as f64 / units_in_day));
+            ca.into_series()
+        },
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => {
+            let units_in_day = units_in_day(TimeUnit::Nanoseconds);
+            let ca: Float64Chunked = s.time()?.apply_generic(|v| Some(v? as f64 / units_in_day));
+            ca.into_series()
+        },
+        dt if dt.is_numeric() || dt.is_decimal() => s.cast(&DataType::Float64)?,
+        dt => polars_bail!(
+            ComputeError: "cannot write column '{}' of type {} to an Excel worksheet",
+            s.name(), dt
+        ),
+    };
+    Ok(out.rechunk())
+}
+
+/// The cells of a column converted by [`to_cell_values`].
+enum Cells<'a> {
+    Boolean(&'a BooleanChunked),
+    String(&'a StringChunked),
+    Number(&'a Float64Chunked),
+}
+
+impl<'a> Cells<'a> {
+    fn new(s: &'a Series) -> PolarsResult<Self> {
+        Ok(match s.dtype() {
+            DataType::Boolean => Self::Boolean(s.bool()?),
+            DataType::String => Self::String(s.str()?),
+            _ => Self::Number(s.f64()?),
+        })
+    }
+
+    /// Write the value at `idx`, leaving nulls and non-finite numbers empty.
+    fn write(
+        &self,
+        sheet: &mut Worksheet,
+        idx: usize,
+        row: RowNum,
+        col: ColNum,
+        format: Option<&Format>,
+    ) -> PolarsResult<()> {
+        match self {
+            Self::Boolean(ca) => {
+                let Some(v) = ca.get(idx) else {
+                    return Ok(());
+                };
+                match format {
+                    Some(format) => sheet.write_boolean_with_format(row, col, v, format),
+                    None => sheet.write_boolean(row, col, v),
+                }
+            },
+            Self::String(ca) => {
+                let Some(v) = ca.get(idx) else {
+                    return Ok(());
+                };
+                match format {
+                    Some(format) => sheet.write_string_with_format(row, col, v, format),
+                    None => sheet.write_string(row, col, v),
+                }
+            },
+            Self::Number(ca) => {
+                let Some(v) = ca.get(idx).filter(|v| v.is_finite()) else {
+                    return Ok(());
+                };
+                match format {
+                    Some(format) => sheet.write_number_with_format(row, col, v, format),
+                    None => sheet.write_number(row, col, v),
+                }
+            },
+        }
+        .map_err(to_compute_err)?;
+        Ok(())
+    }
+}
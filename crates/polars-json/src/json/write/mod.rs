@@ -10,10 +10,38 @@ use arrow::io::iterator::StreamingIterator;
 use arrow::record_batch::RecordBatchT;
 pub use fallible_streaming_iterator::*;
 use polars_error::{PolarsError, PolarsResult};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 pub(crate) use serialize::new_serializer;
 use serialize::serialize;
 pub use utf8::serialize_to_utf8;
 
+/// How dates, times, datetimes and durations are written.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TemporalFormat {
+    /// Strings in the format in which the values are displayed, e.g. `"2021-01-01 12:00:00"`.
+    #[default]
+    Display,
+    /// ISO 8601 strings, e.g. `"2021-01-01T12:00:00"`.
+    Iso8601,
+    /// Integers of the physical values: days since the epoch for dates, and a number of time
+    /// units for the other types, counted from the epoch or from midnight.
+    Epoch,
+}
+
+/// Options that control how values are written to JSON.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SerializeOptions {
+    pub temporal_format: TemporalFormat,
+    /// Write decimals as strings instead of numbers, so that readers that parse numbers as
+    /// floats don't lose precision.
+    pub decimal_as_string: bool,
+    /// Leave the fields of an object out when their value is null.
+    pub omit_nulls: bool,
+}
+
 /// [`FallibleStreamingIterator`] that serializes an [`Array`] to bytes of valid JSON
 /// # Implementation
 /// Advancing this iterator CPU-bounded
@@ -25,6 +53,7 @@ where
 {
     arrays: I,
     buffer: Vec<u8>,
+    options: SerializeOptions,
 }
 
 impl<A, I> Serializer<A, I>
@@ -34,7 +63,17 @@ where
 {
     /// Creates a new [`Serializer`].
     pub fn new(arrays: I, buffer: Vec<u8>) -> Self {
-        Self { arrays, buffer }
+        Self {
+            arrays,
+            buffer,
+            options: SerializeOptions::default(),
+        }
+    }
+
+    /// Set the options that control how values are written.
+    pub fn with_options(mut self, options: SerializeOptions) -> Self {
+        self.options = options;
+        self
     }
 }
 
@@ -51,7 +90,9 @@ where
         self.buffer.clear();
         self.arrays
             .next()
-            .map(|maybe_array| maybe_array.map(|array| serialize(array.as_ref(), &mut self.buffer)))
+            .map(|maybe_array| {
+                maybe_array.map(|array| serialize(array.as_ref(), self.options, &mut self.buffer))
+            })
             .transpose()?;
         Ok(())
     }
@@ -88,7 +129,7 @@ impl<'a> RecordSerializer<'a> {
         let iterators = chunk
             .arrays()
             .iter()
-            .map(|arr| new_serializer(arr.as_ref(), 0, usize::MAX))
+            .map(|arr| new_serializer(arr.as_ref(), 0, usize::MAX, SerializeOptions::default()))
             .collect();
 
         Self {
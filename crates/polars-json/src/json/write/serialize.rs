@@ -9,14 +9,15 @@ use arrow::offset::Offset;
 use arrow::temporal_conversions::parse_offset_tz;
 use arrow::temporal_conversions::{
     date32_to_date, duration_ms_to_duration, duration_ns_to_duration, duration_s_to_duration,
-    duration_us_to_duration, parse_offset, timestamp_ms_to_datetime, timestamp_ns_to_datetime,
-    timestamp_s_to_datetime, timestamp_to_datetime, timestamp_us_to_datetime,
+    duration_us_to_duration, parse_offset, time64ns_to_time, time64us_to_time,
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_s_to_datetime,
+    timestamp_to_datetime, timestamp_us_to_datetime,
 };
 use arrow::types::NativeType;
-use chrono::{Duration, NaiveDate, NaiveDateTime};
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
 use streaming_iterator::StreamingIterator;
 
-use super::utf8;
+use super::{utf8, SerializeOptions, TemporalFormat};
 
 fn write_integer<I: itoa::Integer>(buf: &mut Vec<u8>, val: I) {
     let mut buffer = itoa::Buffer::new();
@@ -162,6 +163,7 @@ fn struct_serializer<'a>(
     array: &'a StructArray,
     offset: usize,
     take: usize,
+    options: SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
     // {"a": [1, 2, 3], "b": [a, b, c], "c": {"a": [1, 2, 3]}}
     // [
@@ -174,7 +176,7 @@ fn struct_serializer<'a>(
         .values()
         .iter()
         .map(|x| x.as_ref())
-        .map(|arr| new_serializer(arr, offset, take))
+        .map(|arr| new_serializer(arr, offset, take, options))
         .collect::<Vec<_>>();
 
     Box::new(BufStreamingIterator::new(
@@ -190,6 +192,7 @@ fn struct_serializer<'a>(
                             .map(|serializer| serializer.next().unwrap()),
                     ),
                     true,
+                    options.omit_nulls,
                 );
             } else {
                 serializers.iter_mut().for_each(|iter| {
@@ -206,6 +209,7 @@ fn list_serializer<'a, O: Offset>(
     array: &'a ListArray<O>,
     offset: usize,
     take: usize,
+    options: SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
     // [[1, 2], [3]]
     // [
@@ -216,7 +220,7 @@ fn list_serializer<'a, O: Offset>(
     let offsets = array.offsets().as_slice();
     let start = offsets[0].to_usize();
     let end = offsets.last().unwrap().to_usize();
-    let mut serializer = new_serializer(array.values().as_ref(), start, end - start);
+    let mut serializer = new_serializer(array.values().as_ref(), start, end - start, options);
 
     let f = move |offset: Option<&[O]>, buf: &mut Vec<u8>| {
         if let Some(offset) = offset {
@@ -245,8 +249,9 @@ fn fixed_size_list_serializer<'a>(
     array: &'a FixedSizeListArray,
     offset: usize,
     take: usize,
+    options: SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
-    let mut serializer = new_serializer(array.values().as_ref(), offset, take);
+    let mut serializer = new_serializer(array.values().as_ref(), offset, take, options);
 
     Box::new(BufStreamingIterator::new(
         ZipValidity::new(0..array.len(), array.validity().map(|x| x.iter())),
@@ -293,6 +298,27 @@ where
     materialize_serializer(f, array.iter(), offset, take)
 }
 
+fn time_serializer<'a, F>(
+    array: &'a PrimitiveArray<i64>,
+    convert: F,
+    offset: usize,
+    take: usize,
+) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>
+where
+    F: Fn(i64) -> NaiveTime + 'static + Send + Sync,
+{
+    let f = move |x: Option<&i64>, buf: &mut Vec<u8>| {
+        if let Some(x) = x {
+            let nt = convert(*x);
+            write!(buf, "\"{nt}\"").unwrap();
+        } else {
+            buf.extend_from_slice(b"null")
+        }
+    };
+
+    materialize_serializer(f, array.iter(), offset, take)
+}
+
 fn duration_serializer<'a, T, F>(
     array: &'a PrimitiveArray<T>,
     convert: F,
@@ -318,6 +344,7 @@ where
 fn timestamp_serializer<'a, F>(
     array: &'a PrimitiveArray<i64>,
     convert: F,
+    format: TemporalFormat,
     offset: usize,
     take: usize,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>
@@ -327,7 +354,37 @@ where
     let f = move |x: Option<&i64>, buf: &mut Vec<u8>| {
         if let Some(x) = x {
             let ndt = convert(*x);
-            write!(buf, "\"{ndt}\"").unwrap();
+            match format {
+                TemporalFormat::Iso8601 => {
+                    write!(buf, "\"{}\"", ndt.format("%Y-%m-%dT%H:%M:%S%.f")).unwrap()
+                },
+                _ => write!(buf, "\"{ndt}\"").unwrap(),
+            }
+        } else {
+            buf.extend_from_slice(b"null")
+        }
+    };
+    materialize_serializer(f, array.iter(), offset, take)
+}
+
+#[cfg(feature = "dtype-decimal")]
+fn decimal_serializer<'a>(
+    array: &'a PrimitiveArray<i128>,
+    scale: usize,
+    as_string: bool,
+    offset: usize,
+    take: usize,
+) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+    let f = move |x: Option<&i128>, buf: &mut Vec<u8>| {
+        if let Some(x) = x {
+            let value = arrow::compute::decimal::format_decimal(*x, scale, false);
+            if as_string {
+                buf.push(b'"');
+                buf.extend_from_slice(value.as_str().as_bytes());
+                buf.push(b'"');
+            } else {
+                buf.extend_from_slice(value.as_str().as_bytes());
+            }
         } else {
             buf.extend_from_slice(b"null")
         }
@@ -384,7 +441,9 @@ pub(crate) fn new_serializer<'a>(
     array: &'a dyn Array,
     offset: usize,
     take: usize,
+    options: SerializeOptions,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+    let is_epoch = options.temporal_format == TemporalFormat::Epoch;
     match array.data_type().to_logical_type() {
         ArrowDataType::Boolean => {
             boolean_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
@@ -425,15 +484,32 @@ pub(crate) fn new_serializer<'a>(
         ArrowDataType::Utf8View => {
             utf8view_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
         },
-        ArrowDataType::Struct(_) => {
-            struct_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
-        ArrowDataType::FixedSizeList(_, _) => {
-            fixed_size_list_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
-        ArrowDataType::LargeList(_) => {
-            list_serializer::<i64>(array.as_any().downcast_ref().unwrap(), offset, take)
-        },
+        #[cfg(feature = "dtype-decimal")]
+        ArrowDataType::Decimal(_, scale) => decimal_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            *scale,
+            options.decimal_as_string,
+            offset,
+            take,
+        ),
+        ArrowDataType::Struct(_) => struct_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options,
+        ),
+        ArrowDataType::FixedSizeList(_, _) => fixed_size_list_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options,
+        ),
+        ArrowDataType::LargeList(_) => list_serializer::<i64>(
+            array.as_any().downcast_ref().unwrap(),
+            offset,
+            take,
+            options,
+        ),
         ArrowDataType::Dictionary(k, v, _) => match (k, &**v) {
             (IntegerType::UInt32, ArrowDataType::Utf8View) => {
                 let array = array
@@ -447,6 +523,15 @@ pub(crate) fn new_serializer<'a>(
                 unreachable!()
             },
         },
+        // Epoch values are the physical integers, whatever their unit or time zone.
+        ArrowDataType::Date32 if is_epoch => {
+            primitive_serializer::<i32>(array.as_any().downcast_ref().unwrap(), offset, take)
+        },
+        ArrowDataType::Timestamp(_, _) | ArrowDataType::Time64(_) | ArrowDataType::Duration(_)
+            if is_epoch =>
+        {
+            primitive_serializer::<i64>(array.as_any().downcast_ref().unwrap(), offset, take)
+        },
         ArrowDataType::Date32 => date_serializer(
             array.as_any().downcast_ref().unwrap(),
             date32_to_date,
@@ -463,6 +548,7 @@ pub(crate) fn new_serializer<'a>(
             timestamp_serializer(
                 array.as_any().downcast_ref().unwrap(),
                 convert,
+                options.temporal_format,
                 offset,
                 take,
             )
@@ -474,6 +560,20 @@ pub(crate) fn new_serializer<'a>(
             offset,
             take,
         ),
+        ArrowDataType::Time64(tu) => {
+            let convert = match tu {
+                TimeUnit::Nanosecond => time64ns_to_time,
+                TimeUnit::Microsecond => time64us_to_time,
+                // Not produced by polars
+                _ => unreachable!(),
+            };
+            time_serializer(
+                array.as_any().downcast_ref().unwrap(),
+                convert,
+                offset,
+                take,
+            )
+        },
         ArrowDataType::Duration(tu) => {
             let convert = match tu {
                 TimeUnit::Nanosecond => duration_ns_to_duration,
@@ -497,6 +597,7 @@ fn serialize_item<'a>(
     buffer: &mut Vec<u8>,
     record: impl Iterator<Item = (&'a str, &'a [u8])>,
     is_first_row: bool,
+    omit_nulls: bool,
 ) {
     if !is_first_row {
         buffer.push(b',');
@@ -504,6 +605,9 @@ fn serialize_item<'a>(
     buffer.push(b'{');
     let mut first_item = true;
     for (key, value) in record {
+        if omit_nulls && value == b"null" {
+            continue;
+        }
         if !first_item {
             buffer.push(b',');
         }
@@ -518,8 +622,8 @@ fn serialize_item<'a>(
 /// Serializes `array` to a valid JSON to `buffer`
 /// # Implementation
 /// This operation is CPU-bounded
-pub(crate) fn serialize(array: &dyn Array, buffer: &mut Vec<u8>) {
-    let mut serializer = new_serializer(array, 0, usize::MAX);
+pub(crate) fn serialize(array: &dyn Array, options: SerializeOptions, buffer: &mut Vec<u8>) {
+    let mut serializer = new_serializer(array, 0, usize::MAX, options);
 
     (0..array.len()).for_each(|i| {
         if i != 0 {
@@ -3,7 +3,7 @@ use std::io;
 
 use arrow::array::{Array, MutableBinaryViewArray, Utf8ViewArray};
 
-use crate::json::write::new_serializer;
+use crate::json::write::{new_serializer, SerializeOptions};
 
 pub fn write_str<W>(writer: &mut W, value: &str) -> io::Result<()>
 where
@@ -143,7 +143,7 @@ where
 
 pub fn serialize_to_utf8(array: &dyn Array) -> Utf8ViewArray {
     let mut values = MutableBinaryViewArray::with_capacity(array.len());
-    let mut serializer = new_serializer(array, 0, usize::MAX);
+    let mut serializer = new_serializer(array, 0, usize::MAX, SerializeOptions::default());
 
     while let Some(v) = serializer.next() {
         unsafe { values.push_value(std::str::from_utf8_unchecked(v)) }
@@ -6,9 +6,10 @@ pub use fallible_streaming_iterator::FallibleStreamingIterator;
 use polars_error::{PolarsError, PolarsResult};
 
 use super::super::json::write::new_serializer;
+pub use super::super::json::write::{SerializeOptions, TemporalFormat};
 
-fn serialize(array: &dyn Array, buffer: &mut Vec<u8>) {
-    let mut serializer = new_serializer(array, 0, usize::MAX);
+fn serialize(array: &dyn Array, options: SerializeOptions, buffer: &mut Vec<u8>) {
+    let mut serializer = new_serializer(array, 0, usize::MAX, options);
     (0..array.len()).for_each(|_| {
         buffer.extend_from_slice(serializer.next().unwrap());
         buffer.push(b'\n');
@@ -27,6 +28,7 @@ where
 {
     arrays: I,
     buffer: Vec<u8>,
+    options: SerializeOptions,
 }
 
 impl<A, I> Serializer<A, I>
@@ -36,7 +38,17 @@ where
 {
     /// Creates a new [`Serializer`].
     pub fn new(arrays: I, buffer: Vec<u8>) -> Self {
-        Self { arrays, buffer }
+        Self {
+            arrays,
+            buffer,
+            options: SerializeOptions::default(),
+        }
+    }
+
+    /// Set the options that control how the values of every line are written.
+    pub fn with_options(mut self, options: SerializeOptions) -> Self {
+        self.options = options;
+        self
     }
 }
 
@@ -53,7 +65,9 @@ where
         self.buffer.clear();
         self.arrays
             .next()
-            .map(|maybe_array| maybe_array.map(|array| serialize(array.as_ref(), &mut self.buffer)))
+            .map(|maybe_array| {
+                maybe_array.map(|array| serialize(array.as_ref(), self.options, &mut self.buffer))
+            })
             .transpose()?;
         Ok(())
     }
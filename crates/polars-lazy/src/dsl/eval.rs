@@ -42,6 +42,9 @@ pub trait ExprEvalExtension: IntoExpr + Sized {
     /// # Warning
     /// This can be really slow as it can have `O(n^2)` complexity. Don't use this for operations
     /// that visit all elements.
+    ///
+    /// Deprecated in favor of [`Expr::scan`] and [`Expr::cum_reduce`], which carry an explicit
+    /// state and run in linear time.
     fn cumulative_eval(self, expr: Expr, min_periods: usize, parallel: bool) -> Expr {
         let this = self.into_expr();
         let expr2 = expr.clone();
@@ -34,7 +34,11 @@ pub(crate) fn concat_impl<L: AsRef<[LazyFrame]>>(
         lps.push(lp)
     }
 
-    let lp = DslPlan::Union { inputs: lps, args };
+    let lp = DslPlan::Union {
+        inputs: lps,
+        args,
+        merge_sorted_by: None,
+    };
     Ok(LazyFrame::from_inner(lp, opt_state, cached_arenas))
 }
 
@@ -82,6 +86,24 @@ pub fn concat<L: AsRef<[LazyFrame]>>(inputs: L, args: UnionArgs) -> PolarsResult
     concat_impl(inputs, args)
 }
 
+/// Merge multiple [`LazyFrame`]s that are sorted by the column `by` into a single sorted
+/// [`LazyFrame`].
+#[cfg(feature = "merge_sorted")]
+pub fn concat_merge_sorted<L: AsRef<[LazyFrame]>>(
+    inputs: L,
+    by: &str,
+    args: UnionArgs,
+) -> PolarsResult<LazyFrame> {
+    let mut lf = concat_impl(inputs, args)?;
+    if let DslPlan::Union {
+        merge_sorted_by, ..
+    } = &mut lf.logical_plan
+    {
+        *merge_sorted_by = Some(Arc::from(by));
+    }
+    Ok(lf)
+}
+
 /// Collect all [`LazyFrame`] computations.
 pub fn collect_all<I>(lfs: I) -> PolarsResult<Vec<DataFrame>>
 where
@@ -8,6 +8,10 @@ mod err;
 mod exitable;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+#[cfg(feature = "dynamic_group_by")]
+pub mod resample;
+#[cfg(feature = "snapshot")]
+mod snapshot;
 
 #[cfg(any(
     feature = "parquet",
@@ -16,6 +20,7 @@ pub mod pivot;
     feature = "json"
 ))]
 use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
 use std::sync::{Arc, Mutex};
 
 pub use anonymous_scan::*;
@@ -32,9 +37,11 @@ pub use ndjson::*;
 pub use parquet::*;
 use polars_core::prelude::*;
 use polars_io::RowIndex;
-use polars_ops::frame::JoinCoalesce;
-pub use polars_plan::frame::{AllowedOptimizations, OptState};
+use polars_ops::frame::{JoinCoalesce, JoinHints};
+pub use polars_plan::frame::{AllowedOptimizations, OptState, QueryResources};
 use polars_plan::global::FETCH_ROWS;
+#[cfg(feature = "dynamic_group_by")]
+pub use resample::{PolarsResample, ResampleMethod, ResampleOptions};
 use smartstring::alias::String as SmartString;
 
 use crate::frame::cached_arenas::CachedArena;
@@ -134,6 +141,7 @@ impl LazyFrame {
 
     /// Turn off all optimizations.
     pub fn without_optimizations(self) -> Self {
+        let resources = self.opt_state.resources;
         self.with_optimizations(OptState {
             projection_pushdown: false,
             predicate_pushdown: false,
@@ -141,6 +149,7 @@ impl LazyFrame {
             type_coercion: true,
             simplify_expr: false,
             slice_pushdown: false,
+            semi_anti_join_pushdown: false,
             // will be toggled by a scan operation such as csv scan or parquet scan
             file_caching: false,
             #[cfg(feature = "cse")]
@@ -152,6 +161,7 @@ impl LazyFrame {
             fast_projection: false,
             row_estimate: false,
             new_streaming: false,
+            resources,
         })
     }
 
@@ -173,6 +183,12 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle pushing semi and anti joins below group-bys and other joins.
+    pub fn with_semi_anti_join_pushdown(mut self, toggle: bool) -> Self {
+        self.opt_state.semi_anti_join_pushdown = toggle;
+        self
+    }
+
     /// Toggle type coercion optimization.
     pub fn with_type_coercion(mut self, toggle: bool) -> Self {
         self.opt_state.type_coercion = toggle;
@@ -222,6 +238,23 @@ impl LazyFrame {
         self
     }
 
+    /// Limit the threads and memory that the query may use when it runs, see
+    /// [`QueryResources`].
+    pub fn with_resources(mut self, resources: QueryResources) -> Self {
+        self.opt_state.resources = resources;
+        self
+    }
+
+    /// Run `f`, which runs the query, within the resource limits of the query.
+    fn run_with_resources<F, R>(self, f: F) -> PolarsResult<R>
+    where
+        F: FnOnce(Self) -> PolarsResult<R> + Send,
+        R: Send,
+    {
+        let resources = self.opt_state.resources;
+        resources.install(move || f(self))?
+    }
+
     /// Run every node eagerly. This turns off multi-node optimizations.
     pub fn _with_eager(mut self, toggle: bool) -> Self {
         self.opt_state.eager = toggle;
@@ -654,10 +687,24 @@ impl LazyFrame {
 
     // post_opt: A function that is called after optimization. This can be used to modify the IR jit.
     pub fn _collect_post_opt<P>(self, post_opt: P) -> PolarsResult<DataFrame>
+    where
+        P: Fn(Node, &mut Arena<IR>, &mut Arena<AExpr>) -> PolarsResult<()>,
+    {
+        self.collect_post_opt_impl(post_opt, None)
+    }
+
+    fn collect_post_opt_impl<P>(
+        self,
+        post_opt: P,
+        cancel_token: Option<Arc<AtomicBool>>,
+    ) -> PolarsResult<DataFrame>
     where
         P: Fn(Node, &mut Arena<IR>, &mut Arena<AExpr>) -> PolarsResult<()>,
     {
         let (mut state, mut physical_plan, _) = self.prepare_collect_post_opt(false, post_opt)?;
+        if let Some(cancel_token) = cancel_token {
+            state.set_cancel_token(cancel_token);
+        }
         physical_plan.execute(&mut state)
     }
 
@@ -687,6 +734,18 @@ impl LazyFrame {
     /// }
     /// ```
     pub fn collect(self) -> PolarsResult<DataFrame> {
+        self.run_with_resources(|lf| lf.collect_impl(None))
+    }
+
+    /// Execute all the lazy operations and collect them into a [`DataFrame`], like
+    /// [`LazyFrame::collect`].
+    ///
+    /// Setting `cancel_token` stops the query at earliest convenience with an error.
+    pub fn collect_cancellable(self, cancel_token: Arc<AtomicBool>) -> PolarsResult<DataFrame> {
+        self.run_with_resources(|lf| lf.collect_impl(Some(cancel_token)))
+    }
+
+    fn collect_impl(self, cancel_token: Option<Arc<AtomicBool>>) -> PolarsResult<DataFrame> {
         #[cfg(feature = "new-streaming")]
         {
             if self.opt_state.new_streaming {
@@ -703,7 +762,7 @@ impl LazyFrame {
                 return polars_stream::run_query(lp_top, ir_arena, expr_arena);
             }
         }
-        self._collect_post_opt(|_, _, _| Ok(()))
+        self.collect_post_opt_impl(|_, _, _| Ok(()), cancel_token)
     }
 
     /// Profile a LazyFrame.
@@ -714,11 +773,29 @@ impl LazyFrame {
     ///
     /// The units of the timings are microseconds.
     pub fn profile(self) -> PolarsResult<(DataFrame, DataFrame)> {
-        let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
-        state.time_nodes();
-        let out = physical_plan.execute(&mut state)?;
-        let timer_df = state.finish_timer()?;
-        Ok((out, timer_df))
+        self.run_with_resources(|lf| {
+            let (mut state, mut physical_plan, _) = lf.prepare_collect(false)?;
+            state.time_nodes();
+            let out = physical_plan.execute(&mut state)?;
+            let timer_df = state.finish_timer()?;
+            Ok((out, timer_df))
+        })
+    }
+
+    /// Profile a LazyFrame, like [`LazyFrame::profile`], and also time the individual
+    /// expressions of the projections.
+    ///
+    /// Every evaluation of an expression of a `select` or `with_columns` gets a row named
+    /// `expr(<expression>)` and every call of a UDF a row named `udf(<expression>)`, so the
+    /// time of the UDF can be told apart from the time of the expression that calls it.
+    pub fn profile_expressions(self) -> PolarsResult<(DataFrame, DataFrame)> {
+        self.run_with_resources(|lf| {
+            let (mut state, mut physical_plan, _) = lf.prepare_collect(false)?;
+            state.time_expressions();
+            let out = physical_plan.execute(&mut state)?;
+            let timer_df = state.finish_timer()?;
+            Ok((out, timer_df))
+        })
     }
 
     /// Stream a query result into a parquet file. This is useful if the final result doesn't fit
@@ -777,28 +854,19 @@ impl LazyFrame {
     /// streaming fashion.
     #[cfg(all(feature = "cloud_write", feature = "ipc"))]
     pub fn sink_ipc_cloud(
-        mut self,
+        self,
         uri: String,
         cloud_options: Option<polars_io::cloud::CloudOptions>,
         ipc_options: IpcWriterOptions,
     ) -> PolarsResult<()> {
-        self.opt_state.streaming = true;
-        self.logical_plan = DslPlan::Sink {
-            input: Arc::new(self.logical_plan),
-            payload: SinkType::Cloud {
+        self.sink(
+            SinkType::Cloud {
                 uri: Arc::new(uri),
                 cloud_options,
                 file_type: FileType::Ipc(ipc_options),
             },
-        };
-        let (mut state, mut physical_plan, is_streaming) = self.prepare_collect(true)?;
-        polars_ensure!(
-            is_streaming,
-            ComputeError: "cannot run the whole query in a streaming order; \
-                           use `collect().write_ipc()` instead"
-        );
-        let _ = physical_plan.execute(&mut state)?;
-        Ok(())
+            "collect().write_ipc()",
+        )
     }
 
     /// Stream a query result into an csv file. This is useful if the final result doesn't fit
@@ -829,12 +897,105 @@ impl LazyFrame {
         )
     }
 
+    /// Stream a query result into a callback, which is called with batches of `chunk_size`
+    /// rows in the order of the result. Without a `chunk_size`, the callback receives the
+    /// morsels of the streaming engine as they are produced.
+    ///
+    /// The callback runs on a separate thread and slows down the query if it can't keep up,
+    /// so the result is never fully materialized. An error of the callback stops the query
+    /// and is returned. This methods will return an error if the query cannot be completely
+    /// done in a streaming fashion.
+    #[cfg(feature = "streaming")]
+    pub fn sink_batches<F>(self, callback: F, chunk_size: Option<usize>) -> PolarsResult<()>
+    where
+        F: Fn(DataFrame) -> PolarsResult<()> + Send + Sync + 'static,
+    {
+        self.sink(
+            SinkType::Batches {
+                callback: BatchCallback::new(callback),
+                chunk_size,
+            },
+            "collect()",
+        )
+    }
+
+    /// Stream a query result into hive-partitioned files, written by `writer`.
+    ///
+    /// The result is partitioned in batches of `batch_size` rows, in the order of the result,
+    /// so every partition gets a file per batch that contains it. If the writer is
+    /// checkpointed, a failed sink can be resumed by running the same query again: the files of
+    /// the batches that were completed are not written again. The plan of the query is recorded
+    /// in the checkpoint, and another query refuses to resume it. This methods will return an
+    /// error if the query cannot be completely done in a streaming fashion.
+    #[cfg(all(feature = "streaming", feature = "partition_by"))]
+    pub fn sink_partitioned<F>(
+        mut self,
+        writer: polars_io::partition::PartitionedWriter<F>,
+        batch_size: usize,
+    ) -> PolarsResult<()>
+    where
+        F: polars_io::WriterFactory + Send + Sync + 'static,
+    {
+        polars_ensure!(
+            batch_size > 0,
+            InvalidOperation: "`batch_size` of a partitioned sink must be positive"
+        );
+        let schema = self.schema()?;
+        let query = self.describe_plan()?;
+        let writer = writer.into_batched(&schema, batch_size, &query)?;
+        let writer = Arc::new(Mutex::new(Some(writer)));
+        let callback_writer = writer.clone();
+        self.sink_batches(
+            move |df| {
+                let mut writer = callback_writer.lock().unwrap();
+                writer.as_mut().unwrap().write_batch(&df)
+            },
+            Some(batch_size),
+        )?;
+        let writer = writer.lock().unwrap().take().unwrap();
+        writer.finish()
+    }
+
+    /// Compute the [`ContentHash`] of the query result on the streaming engine, so that results
+    /// that don't fit in memory can be fingerprinted. The hash is the same as the one of
+    /// [`DataFrame::content_hash`] on the collected result. This methods will return an error if
+    /// the query cannot be completely done in a streaming fashion.
+    #[cfg(all(feature = "streaming", feature = "row_hash"))]
+    pub fn content_hash(mut self) -> PolarsResult<ContentHash> {
+        let schema = self.schema()?;
+        let hasher = Arc::new(Mutex::new(ContentHasher::new(schema.as_ref().clone())));
+        let callback_hasher = hasher.clone();
+        // Sized batches are passed in the order of the result, which the ordered hash needs.
+        self.sink_batches(
+            move |df| callback_hasher.lock().unwrap().update(&df),
+            Some(1 << 16),
+        )?;
+        let hash = hasher.lock().unwrap().finish();
+        Ok(hash)
+    }
+
+    /// Stream a query result into multiple sinks, e.g. a parquet file and a csv file, in a
+    /// single pass. Every morsel is passed on to all sinks, so the query isn't recomputed for
+    /// every output.
+    ///
+    /// A memory sink can't be combined with other sinks. This methods will return an error if
+    /// the query cannot be completely done in a streaming fashion.
+    #[cfg(feature = "streaming")]
+    pub fn sink_multiple(self, sinks: Vec<SinkType>) -> PolarsResult<()> {
+        polars_ensure!(
+            !sinks.is_empty(),
+            InvalidOperation: "`sink_multiple` needs at least one sink"
+        );
+        self.sink(SinkType::Multiple(sinks), "collect()")
+    }
+
     #[cfg(any(
         feature = "ipc",
         feature = "parquet",
         feature = "cloud_write",
         feature = "csv",
         feature = "json",
+        feature = "streaming",
     ))]
     fn sink(mut self, payload: SinkType, msg_alternative: &str) -> Result<(), PolarsError> {
         self.opt_state.streaming = true;
@@ -842,14 +1003,16 @@ impl LazyFrame {
             input: Arc::new(self.logical_plan),
             payload,
         };
-        let (mut state, mut physical_plan, is_streaming) = self.prepare_collect(true)?;
-        polars_ensure!(
-            is_streaming,
-            ComputeError: format!("cannot run the whole query in a streaming order; \
-            use `{msg_alternative}` instead", msg_alternative=msg_alternative)
-        );
-        let _ = physical_plan.execute(&mut state)?;
-        Ok(())
+        self.run_with_resources(|lf| {
+            let (mut state, mut physical_plan, is_streaming) = lf.prepare_collect(true)?;
+            polars_ensure!(
+                is_streaming,
+                ComputeError: format!("cannot run the whole query in a streaming order; \
+                use `{msg_alternative}` instead", msg_alternative=msg_alternative)
+            );
+            let _ = physical_plan.execute(&mut state)?;
+            Ok(())
+        })
     }
 
     /// Filter by some predicate expression.
@@ -1233,6 +1396,89 @@ impl LazyFrame {
         )
     }
 
+    /// Keep the rows of this query that also occur in `other`.
+    ///
+    /// The columns of both queries are matched by position and null values are considered
+    /// equal. If `distinct` is set, duplicate rows are removed from the result; otherwise a
+    /// row that occurs `m` times in this query and `n` times in `other` occurs `min(m, n)`
+    /// times in the result, like SQL's `INTERSECT ALL`.
+    #[cfg(all(feature = "semi_anti_join", feature = "range"))]
+    pub fn intersect(self, other: LazyFrame, distinct: bool) -> PolarsResult<LazyFrame> {
+        self.set_operation(other, JoinType::Semi, distinct)
+    }
+
+    /// Keep the rows of this query that don't occur in `other`.
+    ///
+    /// The columns of both queries are matched by position and null values are considered
+    /// equal. If `distinct` is set, duplicate rows are removed from the result; otherwise a
+    /// row that occurs `m` times in this query and `n` times in `other` occurs `m - n`
+    /// times in the result, like SQL's `EXCEPT ALL`.
+    #[cfg(all(feature = "semi_anti_join", feature = "range"))]
+    pub fn except(self, other: LazyFrame, distinct: bool) -> PolarsResult<LazyFrame> {
+        self.set_operation(other, JoinType::Anti, distinct)
+    }
+
+    #[cfg(all(feature = "semi_anti_join", feature = "range"))]
+    fn set_operation(
+        mut self,
+        mut other: LazyFrame,
+        how: JoinType,
+        distinct: bool,
+    ) -> PolarsResult<LazyFrame> {
+        const OCCURRENCE: &str = "__POLARS_SET_OP_OCCURRENCE";
+
+        let left_on = self
+            .schema()?
+            .iter_names()
+            .map(|name| col(name))
+            .collect::<Vec<_>>();
+        let right_on = other
+            .schema()?
+            .iter_names()
+            .map(|name| col(name))
+            .collect::<Vec<_>>();
+        polars_ensure!(
+            left_on.len() == right_on.len(),
+            ShapeMismatch: "set operations require an equal number of columns, got {} and {}",
+            left_on.len(), right_on.len()
+        );
+
+        let join = |left: LazyFrame, right: LazyFrame, left_on: Vec<Expr>, right_on: Vec<Expr>| {
+            left.join_builder()
+                .with(right)
+                .left_on(left_on)
+                .right_on(right_on)
+                .how(how)
+                .join_nulls(true)
+                .finish()
+        };
+        if distinct {
+            let left = self.unique_stable(None, UniqueKeepStrategy::First);
+            return Ok(join(left, other, left_on, right_on));
+        }
+
+        // Number the duplicates of every row, so that the k-th occurrence of a row
+        // can only match the k-th occurrence of that row in `other`.
+        let with_occurrence = |lf: LazyFrame, on: &[Expr]| {
+            lf.with_column(
+                int_range(lit(0), len(), 1, IDX_DTYPE)
+                    .over(on)
+                    .alias(OCCURRENCE),
+            )
+        };
+        let left = with_occurrence(self, &left_on);
+        let right = with_occurrence(other, &right_on);
+        let left_on = left_on
+            .into_iter()
+            .chain([col(OCCURRENCE)])
+            .collect::<Vec<_>>();
+        let right_on = right_on
+            .into_iter()
+            .chain([col(OCCURRENCE)])
+            .collect::<Vec<_>>();
+        Ok(join(left, right, left_on, right_on).drop([OCCURRENCE]))
+    }
+
     /// Generic function to join two LazyFrames.
     ///
     /// `join` can join on multiple columns, given as two list of expressions, and with a
@@ -1523,6 +1769,38 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Drop non-unique rows, where the `order_by` columns decide which of the duplicates is
+    /// kept.
+    ///
+    /// With [`UniqueKeepStrategy::First`] the row with the smallest `order_by` values is kept,
+    /// with [`UniqueKeepStrategy::Last`] the row with the largest values, e.g. the latest
+    /// record per key. The other strategies don't depend on the order of the rows, so they
+    /// ignore `order_by`.
+    pub fn unique_order_by(
+        self,
+        subset: Option<Vec<String>>,
+        keep_strategy: UniqueKeepStrategy,
+        order_by: Vec<String>,
+        maintain_order: bool,
+    ) -> LazyFrame {
+        let opt_state = self.get_opt_state();
+        let order_by = match keep_strategy {
+            UniqueKeepStrategy::First | UniqueKeepStrategy::Last if !order_by.is_empty() => {
+                Some(Arc::new(order_by))
+            },
+            _ => None,
+        };
+        let options = DistinctOptions {
+            subset: subset.map(Arc::new),
+            maintain_order,
+            keep_strategy,
+            order_by,
+            ..Default::default()
+        };
+        let lp = self.get_plan_builder().distinct(options).build();
+        Self::from_logical_plan(lp, opt_state)
+    }
+
     /// Drop rows containing None.
     ///
     /// `subset` is an optional `Vec` of column names to consider for nulls; if None, all
@@ -1622,18 +1900,29 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Apply a Python function to the [`DataFrame`].
+    ///
+    /// If `required_columns` is set, only those input columns are materialized for the
+    /// function, regardless of the `projection_pushdown` optimization flag.
     #[cfg(feature = "python")]
     pub fn map_python(
         self,
         function: polars_plan::prelude::python_udf::PythonFunction,
         optimizations: AllowedOptimizations,
         schema: Option<SchemaRef>,
+        required_columns: Option<Arc<[Arc<str>]>>,
         validate_output: bool,
     ) -> LazyFrame {
         let opt_state = self.get_opt_state();
         let lp = self
             .get_plan_builder()
-            .map_python(function, optimizations, schema, validate_output)
+            .map_python(
+                function,
+                optimizations,
+                schema,
+                required_columns,
+                validate_output,
+            )
             .build();
         Self::from_logical_plan(lp, opt_state)
     }
@@ -1848,6 +2137,7 @@ pub struct JoinBuilder {
     validation: JoinValidation,
     coalesce: JoinCoalesce,
     join_nulls: bool,
+    hints: JoinHints,
 }
 impl JoinBuilder {
     /// Create the `JoinBuilder` with the provided `LazyFrame` as the left table.
@@ -1864,6 +2154,7 @@ impl JoinBuilder {
             suffix: None,
             validation: Default::default(),
             coalesce: Default::default(),
+            hints: Default::default(),
         }
     }
 
@@ -1941,6 +2232,12 @@ impl JoinBuilder {
         self
     }
 
+    /// Override the strategy, build side or parallelism the engine picks for this join.
+    pub fn hints(mut self, hints: JoinHints) -> Self {
+        self.hints = hints;
+        self
+    }
+
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
         let mut opt_state = self.lf.opt_state;
@@ -1956,6 +2253,7 @@ impl JoinBuilder {
             slice: None,
             join_nulls: self.join_nulls,
             coalesce: self.coalesce,
+            hints: self.hints,
         };
 
         let lp = self
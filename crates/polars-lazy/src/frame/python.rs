@@ -15,4 +15,18 @@ impl LazyFrame {
         }
         .into()
     }
+
+    /// Scan from a Python IO source that receives the projection, the predicate and the number
+    /// of rows to read, and reports which of the predicate and the slice it applied.
+    pub fn scan_from_io_plugin(schema: Schema, scan_fn: PyObject) -> Self {
+        DslPlan::PythonScan {
+            options: PythonOptions {
+                scan_fn: Some(scan_fn.into()),
+                schema: Arc::new(schema),
+                is_io_plugin: true,
+                ..Default::default()
+            },
+        }
+        .into()
+    }
 }
@@ -0,0 +1,135 @@
+//! Resampling changes the frequency of a [`DataFrame`] with a temporal (or integer) index.
+//!
+//! Downsampling aggregates the rows in every window with a dynamic group by, upsampling inserts
+//! the missing rows at a regular interval and optionally fills them. Both directions are exposed
+//! with the same interface, so that one doesn't have to know which of the two operations
+//! applies.
+
+use polars_core::prelude::*;
+use polars_time::prelude::*;
+
+use crate::prelude::*;
+
+/// How the rows of a resampled [`DataFrame`] are computed.
+#[derive(Clone, Debug)]
+pub enum ResampleMethod {
+    /// Downsample by aggregating the rows of every window with these expressions.
+    Aggregate(Vec<Expr>),
+    /// Upsample by inserting a row at every interval. The values of the inserted rows are
+    /// filled with the given strategy, or left null if there is none.
+    Fill(Option<FillNullStrategy>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResampleOptions {
+    /// Resample the rows of every group of these columns separately.
+    pub group_by: Vec<String>,
+    /// Which sides of a window are closed when downsampling.
+    pub closed_window: ClosedWindow,
+    /// Which boundary of a window labels the aggregated row when downsampling.
+    pub label: Label,
+}
+
+impl Default for ResampleOptions {
+    fn default() -> Self {
+        Self {
+            group_by: vec![],
+            closed_window: ClosedWindow::Left,
+            label: Label::Left,
+        }
+    }
+}
+
+/// The column that tells the rows of the input apart from the rows inserted by upsampling.
+const ORIGINAL_ROW: &str = "__POLARS_RESAMPLE_ORIGINAL_ROW";
+
+pub trait PolarsResample {
+    /// Resample to one row per `every` interval of the sorted `index_column`.
+    ///
+    /// When upsampling with a fill strategy, only the inserted rows are filled, and every
+    /// group of [`ResampleOptions::group_by`] is filled separately. The nulls of the input
+    /// are kept.
+    fn resample(
+        &self,
+        index_column: &str,
+        every: Duration,
+        method: ResampleMethod,
+        options: ResampleOptions,
+    ) -> PolarsResult<DataFrame>;
+}
+
+impl PolarsResample for DataFrame {
+    fn resample(
+        &self,
+        index_column: &str,
+        every: Duration,
+        method: ResampleMethod,
+        options: ResampleOptions,
+    ) -> PolarsResult<DataFrame> {
+        let group_by = options
+            .group_by
+            .iter()
+            .map(|name| col(name))
+            .collect::<Vec<_>>();
+        match method {
+            ResampleMethod::Aggregate(aggs) => self
+                .clone()
+                .lazy()
+                .group_by_dynamic(
+                    col(index_column),
+                    group_by,
+                    DynamicGroupOptions {
+                        every,
+                        period: every,
+                        offset: Duration::parse("0ns"),
+                        label: options.label,
+                        closed_window: options.closed_window,
+                        start_by: StartBy::WindowBound,
+                        ..Default::default()
+                    },
+                )
+                .agg(aggs)
+                .collect(),
+            ResampleMethod::Fill(strategy) => {
+                let mut df = self.clone();
+                df.with_column(
+                    BooleanChunked::full(ORIGINAL_ROW, true, df.height()).into_series(),
+                )?;
+                let out = df.upsample_stable(options.group_by.clone(), index_column, every)?;
+
+                // The rows of a group are contiguous and start with a row of the input, so the
+                // inserted rows belong to the group of the row before them.
+                let keys = group_by.iter().map(|key| key.clone().forward_fill(None));
+                let values = self
+                    .get_column_names()
+                    .into_iter()
+                    .filter(|name| {
+                        *name != index_column && !options.group_by.iter().any(|key| key == name)
+                    })
+                    .map(|name| {
+                        let filled = match strategy {
+                            Some(strategy) => {
+                                let filled = col(name).fill_null_with_strategy(strategy);
+                                if group_by.is_empty() {
+                                    filled
+                                } else {
+                                    filled.over(&group_by)
+                                }
+                            },
+                            None => col(name),
+                        };
+                        when(col(ORIGINAL_ROW).is_null())
+                            .then(filled)
+                            .otherwise(col(name))
+                            .alias(name)
+                    })
+                    .collect::<Vec<_>>();
+                out.lazy()
+                    .with_columns(keys.collect::<Vec<_>>())
+                    .with_columns(values)
+                    .drop([ORIGINAL_ROW])
+                    .collect()
+            },
+        }
+    }
+}
@@ -0,0 +1,462 @@
+//! Materialized views over append-only datasets.
+//!
+//! A snapshot stores the result of an aggregation over a file scan, together with the query and
+//! the files that were read and their sizes and modification times at that time. Sums, counts,
+//! minima and maxima of a dataset can be combined from the same aggregations over parts of it, so
+//! when files are only added to the dataset, a snapshot is refreshed by aggregating the new files
+//! and merging that result into the stored one.
+//!
+//! The directory of a snapshot holds a manifest, which names the file with the stored result.
+//! Every file is written under a temporary name and renamed into place, and the manifest is
+//! replaced last, so that a snapshot that was interrupted while being written is never read.
+
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use polars_core::prelude::*;
+use polars_io::ipc::{IpcReader, IpcWriter};
+use polars_io::utils::is_cloud_url;
+use polars_io::{SerReader, SerWriter};
+use polars_plan::prelude::{FileScan, GroupbyOptions};
+use polars_plan::utils::has_expr;
+
+use crate::prelude::*;
+
+const MANIFEST_FILE: &str = "manifest";
+
+/// How a column of the stored result is combined with the same column of a newer result.
+#[derive(Clone, Copy)]
+enum Merge {
+    Sum,
+    Min,
+    Max,
+}
+
+/// The aggregation at the root of a snapshotted query.
+struct Aggregation<'a> {
+    /// The plan that is aggregated, which reads the scanned files.
+    input: &'a DslPlan,
+    n_keys: usize,
+    /// How every aggregated column is merged, in the order of the output.
+    merges: Vec<Merge>,
+    maintain_order: bool,
+}
+
+impl<'a> Aggregation<'a> {
+    fn new(plan: &'a DslPlan) -> PolarsResult<Self> {
+        let (input, keys, aggs, maintain_order) = match plan {
+            DslPlan::GroupBy {
+                input,
+                keys,
+                aggs,
+                apply: None,
+                maintain_order,
+                options,
+            } if is_plain_group_by(options) => (input, keys.as_slice(), aggs, *maintain_order),
+            DslPlan::Select { expr, input, .. } => (input, &[][..], expr, false),
+            _ => polars_bail!(
+                InvalidOperation: "a snapshot must be taken of a group by or a select of aggregations"
+            ),
+        };
+        for key in keys {
+            polars_ensure!(
+                !expands(key),
+                InvalidOperation: "the group by key `{}` of a snapshot must be a single column", key
+            );
+        }
+        let merges = aggs.iter().map(merge_of).collect::<PolarsResult<_>>()?;
+        // Validate the input early, so that nothing is read for unsupported queries.
+        scan_paths(input)?;
+        Ok(Self {
+            input: input.as_ref(),
+            n_keys: keys.len(),
+            merges,
+            maintain_order,
+        })
+    }
+
+    fn paths(&self) -> &'a [PathBuf] {
+        scan_paths(self.input).unwrap()
+    }
+
+    /// Combine the result of a snapshot with the result of the same query over newer files.
+    fn merge(&self, stored: DataFrame, new: DataFrame) -> PolarsResult<DataFrame> {
+        let columns = stored
+            .get_columns()
+            .iter()
+            .map(|s| (s.name().to_string(), s.dtype().clone()))
+            .collect::<Vec<_>>();
+        let (keys, values) = columns.split_at(self.n_keys);
+        let keys = keys.iter().map(|(name, _)| col(name)).collect::<Vec<_>>();
+        let aggs = values
+            .iter()
+            .zip(&self.merges)
+            .map(|((name, dtype), merge)| {
+                let value = col(name);
+                let value = match merge {
+                    Merge::Sum => value.sum(),
+                    Merge::Min => value.min(),
+                    Merge::Max => value.max(),
+                };
+                // Sums of small integers are upcast, but the stored type is kept.
+                value.cast(dtype.clone())
+            })
+            .collect::<Vec<_>>();
+
+        let lf = concat([stored.lazy(), new.lazy()], UnionArgs::default())?;
+        let lf = if keys.is_empty() {
+            lf.select(aggs)
+        } else if self.maintain_order {
+            lf.group_by_stable(keys).agg(aggs)
+        } else {
+            lf.group_by(keys).agg(aggs)
+        };
+        lf.collect()
+    }
+}
+
+fn is_plain_group_by(options: &GroupbyOptions) -> bool {
+    #[cfg(feature = "dynamic_group_by")]
+    if options.dynamic.is_some() || options.rolling.is_some() {
+        return false;
+    }
+    options.slice.is_none()
+}
+
+/// Whether `e` can result in any other number of columns than one.
+fn expands(e: &Expr) -> bool {
+    has_expr(e, |e| match e {
+        Expr::Column(name) => name.starts_with('^') && name.ends_with('$'),
+        Expr::Wildcard
+        | Expr::Columns(_)
+        | Expr::DtypeColumn(_)
+        | Expr::IndexColumn(_)
+        | Expr::Exclude(..)
+        | Expr::Selector(_) => true,
+        _ => false,
+    })
+}
+
+fn merge_of(agg: &Expr) -> PolarsResult<Merge> {
+    let unaliased = match agg {
+        Expr::Alias(e, _) => e.as_ref(),
+        e => e,
+    };
+    let (merge, input) = match unaliased {
+        Expr::Len => return Ok(Merge::Sum),
+        Expr::Agg(AggExpr::Sum(input) | AggExpr::Count(input, _)) => (Merge::Sum, input),
+        Expr::Agg(AggExpr::Min { input, .. }) => (Merge::Min, input),
+        Expr::Agg(AggExpr::Max { input, .. }) => (Merge::Max, input),
+        _ => polars_bail!(
+            InvalidOperation: "cannot refresh the aggregation `{}` of a snapshot, only `sum`, `count`, `len`, `min` and `max` are supported", agg
+        ),
+    };
+    let is_row_wise = !has_expr(input, |e| {
+        matches!(e, Expr::Agg(_) | Expr::Len | Expr::Window { .. })
+    });
+    polars_ensure!(
+        is_row_wise && !expands(input),
+        InvalidOperation: "cannot refresh the aggregation `{}` of a snapshot, it must aggregate a single row-wise expression", agg
+    );
+    Ok(merge)
+}
+
+/// The files of the single scan that `plan` reads.
+fn scan_paths(plan: &DslPlan) -> PolarsResult<&[PathBuf]> {
+    match plan {
+        DslPlan::Scan {
+            paths,
+            file_options,
+            scan_type,
+            ..
+        } => {
+            polars_ensure!(
+                matches!(scan_type, FileScan::Parquet { .. }),
+                InvalidOperation: "snapshots are only supported for parquet scans"
+            );
+            polars_ensure!(
+                file_options.n_rows.is_none() && file_options.row_index.is_none(),
+                InvalidOperation: "snapshots are not supported for scans with a row limit or a row index"
+            );
+            Ok(paths)
+        },
+        DslPlan::Filter { input, .. }
+        | DslPlan::Select { input, .. }
+        | DslPlan::HStack { input, .. } => scan_paths(input),
+        _ => polars_bail!(
+            InvalidOperation: "a snapshot must aggregate filters and projections of a single file scan"
+        ),
+    }
+}
+
+/// Replace the files of the scan that `plan` reads, or aggregates.
+fn with_scan_paths(plan: &DslPlan, new_paths: Arc<[PathBuf]>) -> DslPlan {
+    let mut plan = plan.clone();
+    match &mut plan {
+        DslPlan::Scan {
+            paths, file_info, ..
+        } => {
+            *paths = new_paths;
+            // The file info describes the old files, so it is resolved again.
+            *file_info = None;
+        },
+        DslPlan::GroupBy { input, .. }
+        | DslPlan::Filter { input, .. }
+        | DslPlan::Select { input, .. }
+        | DslPlan::HStack { input, .. } => *input = Arc::new(with_scan_paths(input, new_paths)),
+        _ => unreachable!(),
+    }
+    plan
+}
+
+/// A description of the query of `plan` without the files that it scans, which is stored with a
+/// snapshot so that it isn't refreshed with the result of another query.
+fn fingerprint(plan: &DslPlan) -> String {
+    fn exprs(exprs: &[Expr]) -> String {
+        let exprs = exprs.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+        format!("[{}]", exprs.join(", "))
+    }
+    match plan {
+        DslPlan::Scan { file_options, .. } => format!(
+            "parquet(with_columns={:?}, hive={:?})",
+            file_options.with_columns, file_options.hive_options.enabled
+        ),
+        DslPlan::Filter { input, predicate } => {
+            format!("{}.filter({})", fingerprint(input), predicate)
+        },
+        DslPlan::Select { input, expr, .. } => {
+            format!("{}.select({})", fingerprint(input), exprs(expr))
+        },
+        DslPlan::HStack {
+            input, exprs: e, ..
+        } => {
+            format!("{}.with_columns({})", fingerprint(input), exprs(e))
+        },
+        DslPlan::GroupBy {
+            input,
+            keys,
+            aggs,
+            maintain_order,
+            ..
+        } => format!(
+            "{}.group_by({}, maintain_order={}).agg({})",
+            fingerprint(input),
+            exprs(keys),
+            maintain_order,
+            exprs(aggs)
+        ),
+        _ => unreachable!(),
+    }
+}
+
+/// A file that a snapshot read, with its size and modification time, which are compared to find
+/// out if files were only added.
+#[derive(PartialEq)]
+struct Source {
+    path: String,
+    size: u64,
+    modified: u128,
+}
+
+fn sources(paths: &[PathBuf]) -> PolarsResult<Vec<Source>> {
+    paths
+        .iter()
+        .map(|path| {
+            polars_ensure!(
+                !is_cloud_url(path),
+                InvalidOperation: "snapshots are only supported for local files"
+            );
+            let metadata = std::fs::metadata(path)?;
+            let modified = metadata
+                .modified()?
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_nanos());
+            Ok(Source {
+                // Escaped, so that every source fits on a line of the manifest.
+                path: format!("{:?}", path.to_string_lossy()),
+                size: metadata.len(),
+                modified,
+            })
+        })
+        .collect()
+}
+
+/// The contents of the manifest of a snapshot.
+struct Manifest {
+    fingerprint: String,
+    /// The name of the file with the stored result, in the directory of the snapshot.
+    data: String,
+    sources: Vec<Source>,
+}
+
+impl Manifest {
+    fn serialize(&self) -> String {
+        let mut out = String::new();
+        // Escaped, so that it fits on a line of the manifest.
+        writeln!(out, "{:?}", self.fingerprint).unwrap();
+        writeln!(out, "{}", self.data).unwrap();
+        for source in &self.sources {
+            writeln!(out, "{} {} {}", source.size, source.modified, source.path).unwrap();
+        }
+        out
+    }
+
+    fn deserialize(manifest: &str) -> Option<Self> {
+        let mut lines = manifest.lines();
+        let fingerprint = lines.next()?;
+        let fingerprint = fingerprint.strip_prefix('"')?.strip_suffix('"')?;
+        let data = lines.next()?.to_string();
+        let sources = lines
+            .map(|line| {
+                let mut parts = line.splitn(3, ' ');
+                Some(Source {
+                    size: parts.next()?.parse().ok()?,
+                    modified: parts.next()?.parse().ok()?,
+                    path: parts.next()?.to_string(),
+                })
+            })
+            .collect::<Option<_>>()?;
+        Some(Self {
+            // Compared to escaped fingerprints only, so it isn't unescaped.
+            fingerprint: fingerprint.to_string(),
+            data,
+            sources,
+        })
+    }
+}
+
+/// Write a file to `path` through a temporary file, which is renamed into place once it is
+/// complete.
+fn write_atomic(
+    path: &Path,
+    write: impl FnOnce(&mut File) -> PolarsResult<()>,
+) -> PolarsResult<()> {
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+    let mut file = File::create(&tmp_path)?;
+    write(&mut file)?;
+    file.sync_all()?;
+    std::fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+fn write_snapshot(
+    dir: &Path,
+    df: &mut DataFrame,
+    fingerprint: String,
+    sources: Vec<Source>,
+) -> PolarsResult<()> {
+    std::fs::create_dir_all(dir)?;
+    let previous = read_manifest(dir)?;
+    // Every result gets a new file, so that the result of the current manifest is kept until
+    // the new manifest replaces it.
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos());
+    let data = format!("data-{nanos}.ipc");
+    write_atomic(&dir.join(&data), |file| IpcWriter::new(file).finish(df))?;
+
+    let manifest = Manifest {
+        fingerprint: format!("{fingerprint:?}"),
+        data,
+        sources,
+    };
+    write_atomic(&dir.join(MANIFEST_FILE), |file| {
+        Ok(file.write_all(manifest.serialize().as_bytes())?)
+    })?;
+    if let Some(previous) = previous {
+        if previous.data != manifest.data {
+            let _ = std::fs::remove_file(dir.join(previous.data));
+        }
+    }
+    Ok(())
+}
+
+fn read_manifest(dir: &Path) -> PolarsResult<Option<Manifest>> {
+    let path = dir.join(MANIFEST_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let manifest = std::fs::read_to_string(&path)?;
+    let manifest = Manifest::deserialize(&manifest).ok_or_else(
+        || polars_err!(ComputeError: "the snapshot manifest {} is corrupt", path.display()),
+    )?;
+    Ok(Some(manifest))
+}
+
+impl LazyFrame {
+    /// Collect this query and store the result in the directory `path`, together with the query
+    /// and the files that it read.
+    ///
+    /// The query must be a group by, or a select, of `sum`, `count`, `len`, `min` and `max`
+    /// aggregations over filters and projections of a single parquet scan of local files. Use
+    /// [`LazyFrame::refresh`] to update the stored result when files are added to the dataset.
+    pub fn snapshot(self, path: impl AsRef<Path>) -> PolarsResult<DataFrame> {
+        let sources = sources(Aggregation::new(&self.logical_plan)?.paths())?;
+        let fingerprint = fingerprint(&self.logical_plan);
+        let mut df = self.collect()?;
+        write_snapshot(path.as_ref(), &mut df, fingerprint, sources)?;
+        Ok(df)
+    }
+
+    /// Update the snapshot in the directory `path` with the files that were added to the dataset
+    /// since, and return the updated result.
+    ///
+    /// This must be the query that the snapshot was taken of, created again so that its scan
+    /// finds the current files. Only the files that the snapshot doesn't know are read. If a
+    /// file of the snapshot was changed or removed, the dataset wasn't only appended to, and
+    /// the result is computed again from all files. Without a snapshot in `path`, one is taken
+    /// as with [`LazyFrame::snapshot`].
+    pub fn refresh(self, path: impl AsRef<Path>) -> PolarsResult<DataFrame> {
+        let path = path.as_ref();
+        let Some(manifest) = read_manifest(path)? else {
+            return self.snapshot(path);
+        };
+        let agg = Aggregation::new(&self.logical_plan)?;
+        let fingerprint = fingerprint(&self.logical_plan);
+        polars_ensure!(
+            format!("{fingerprint:?}") == manifest.fingerprint,
+            InvalidOperation: "the query doesn't match the snapshot in {}: the snapshot was taken of {}, got {:?}",
+            path.display(), manifest.fingerprint, fingerprint
+        );
+        let sources = sources(agg.paths())?;
+
+        let is_appended = manifest
+            .sources
+            .iter()
+            .all(|stored| sources.contains(stored));
+        if !is_appended {
+            return self.snapshot(path);
+        }
+        let new_paths = agg
+            .paths()
+            .iter()
+            .zip(&sources)
+            .filter(|(_, source)| {
+                !manifest
+                    .sources
+                    .iter()
+                    .any(|stored| stored.path == source.path)
+            })
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+        let stored = IpcReader::new(File::open(path.join(&manifest.data))?).finish()?;
+        if new_paths.is_empty() {
+            return Ok(stored);
+        }
+
+        let plan = with_scan_paths(&self.logical_plan, new_paths.into());
+        let new = LazyFrame::from_logical_plan(plan, self.opt_state).collect()?;
+        polars_ensure!(
+            new.schema() == stored.schema(),
+            SchemaMismatch: "the query doesn't match the snapshot in {}: expected schema {:?}, got {:?}",
+            path.display(), stored.schema(), new.schema()
+        );
+        let mut df = agg.merge(stored, new)?;
+        write_snapshot(path, &mut df, fingerprint, sources)?;
+        Ok(df)
+    }
+}
@@ -13,6 +13,21 @@ pub(super) fn profile_name(
     }
 }
 
+/// Evaluate an expression of a projection, timing it if the expressions are profiled.
+fn evaluate_expr(
+    expr: &dyn PhysicalExpr,
+    df: &DataFrame,
+    state: &ExecutionState,
+) -> PolarsResult<Series> {
+    state.record_expression(
+        || expr.evaluate(df, state),
+        || match expr.as_expression() {
+            Some(e) => format!("expr({e})"),
+            None => "expr".to_string(),
+        },
+    )
+}
+
 type IdAndExpression = (u32, Arc<dyn PhysicalExpr>);
 
 #[cfg(feature = "dynamic_group_by")]
@@ -40,7 +55,7 @@ fn rolling_evaluate(
                 }
                 partition
                     .par_iter()
-                    .map(|(idx, expr)| expr.evaluate(df, &state).map(|s| (*idx, s)))
+                    .map(|(idx, expr)| evaluate_expr(expr.as_ref(), df, &state).map(|s| (*idx, s)))
                     .collect::<PolarsResult<Vec<_>>>()
             })
             .collect()
@@ -86,7 +101,7 @@ fn window_evaluate(
                         state.remove_cache_window_flag();
                     }
 
-                    let s = e.evaluate(df, &state)?;
+                    let s = evaluate_expr(e.as_ref(), df, &state)?;
                     out.push((*index, s));
                 }
                 Ok(out)
@@ -143,6 +158,8 @@ fn execute_projection_cached_window_fns(
                     },
                     #[cfg(feature = "dynamic_group_by")]
                     WindowType::Rolling(options) => rolling.entry(options).or_insert_with(Vec::new),
+                    // Framed windows don't share a cache, so they run with the other expressions.
+                    WindowType::Frame(_) => break,
                 };
                 entry.push((index, phys.clone()));
                 is_window = true;
@@ -157,7 +174,7 @@ fn execute_projection_cached_window_fns(
     let mut selected_columns = POOL.install(|| {
         other
             .par_iter()
-            .map(|(idx, expr)| expr.evaluate(df, state).map(|s| (*idx, s)))
+            .map(|(idx, expr)| evaluate_expr(*expr, df, state).map(|s| (*idx, s)))
             .collect::<PolarsResult<Vec<_>>>()
     })?;
 
@@ -201,7 +218,7 @@ fn run_exprs_par(
     POOL.install(|| {
         exprs
             .par_iter()
-            .map(|expr| expr.evaluate(df, state))
+            .map(|expr| evaluate_expr(expr.as_ref(), df, state))
             .collect()
     })
 }
@@ -211,7 +228,10 @@ fn run_exprs_seq(
     exprs: &[Arc<dyn PhysicalExpr>],
     state: &ExecutionState,
 ) -> PolarsResult<Vec<Series>> {
-    exprs.iter().map(|expr| expr.evaluate(df, state)).collect()
+    exprs
+        .iter()
+        .map(|expr| evaluate_expr(expr.as_ref(), df, state))
+        .collect()
 }
 
 pub(super) fn evaluate_physical_expressions(
@@ -5,6 +5,25 @@ use super::*;
 
 pub(crate) struct PythonScanExec {
     pub(crate) options: PythonOptions,
+    /// The predicate that was pushed down into an IO plugin, applied after the scan if the
+    /// plugin didn't.
+    pub(crate) predicate: Option<Arc<dyn PhysicalExpr>>,
+    /// The predicate as JSON, to be deserialized into a Python `Expr` by the IO plugin.
+    pub(crate) serialized_predicate: Option<String>,
+}
+
+/// Serialize a predicate that is passed to an IO plugin, `None` if it cannot be serialized,
+/// e.g. because it holds a Python UDF. The plugin then doesn't get the predicate.
+pub(crate) fn serialize_python_predicate(predicate: &Expr) -> Option<String> {
+    #[cfg(feature = "serde")]
+    {
+        serde_json::to_string(predicate).ok()
+    }
+    #[cfg(not(feature = "serde"))]
+    {
+        let _ = predicate;
+        None
+    }
 }
 
 impl Executor for PythonScanExec {
@@ -17,37 +36,65 @@ impl Executor for PythonScanExec {
             }
         }
         let with_columns = self.options.with_columns.take();
-        let pyarrow_predicate = self.options.predicate.take();
+        let predicate = if self.options.is_io_plugin {
+            self.serialized_predicate.take()
+        } else {
+            self.options.predicate.take()
+        };
+        let predicate_sent = predicate.is_some();
         let n_rows = self.options.n_rows.take();
-        Python::with_gil(|py| {
+        let (mut df, predicate_applied, n_rows_applied) = Python::with_gil(|py| {
             let pl = PyModule::import_bound(py, "polars").unwrap();
             let utils = pl.getattr("_utils").unwrap();
             let callable = utils.getattr("_execute_from_rust").unwrap();
 
             let python_scan_function = self.options.scan_fn.take().unwrap().0;
 
-            let with_columns = with_columns.map(|cols| cols.iter().cloned().collect::<Vec<_>>());
+            let with_columns = with_columns
+                .as_ref()
+                .map(|cols| cols.iter().cloned().collect::<Vec<_>>());
 
             let out = callable
-                .call1((
-                    python_scan_function,
-                    with_columns,
-                    pyarrow_predicate,
-                    n_rows,
-                ))
+                .call1((python_scan_function, with_columns, predicate, n_rows))
                 .map_err(to_compute_err)?;
+            // IO plugins report whether they applied the predicate and the slice.
+            let (out, predicate_applied, n_rows_applied) = if self.options.is_io_plugin {
+                out.extract::<(Bound<PyAny>, bool, bool)>()
+                    .map_err(to_compute_err)?
+            } else {
+                (out, true, true)
+            };
             let pydf = out.getattr("_df").unwrap();
             let raw_parts = pydf.call_method0("into_raw_parts").unwrap();
             let raw_parts = raw_parts.extract::<(usize, usize, usize)>().unwrap();
 
             let (ptr, len, cap) = raw_parts;
-            unsafe {
-                Ok(DataFrame::new_no_checks(Vec::from_raw_parts(
-                    ptr as *mut Series,
-                    len,
-                    cap,
-                )))
+            let df = unsafe {
+                DataFrame::new_no_checks(Vec::from_raw_parts(ptr as *mut Series, len, cap))
+            };
+            PolarsResult::Ok((df, predicate_applied, n_rows_applied))
+        })?;
+
+        if !self.options.is_io_plugin {
+            return Ok(df);
+        }
+        // A plugin may return more columns than were projected.
+        if let Some(with_columns) = &with_columns {
+            if df.width() != with_columns.len() {
+                df = df.select(with_columns.iter().map(|s| s.as_str()))?;
             }
-        })
+        }
+        if let Some(predicate) = &self.predicate {
+            if !(predicate_sent && predicate_applied) {
+                let mask = predicate.evaluate(&df, state)?;
+                df = df.filter(mask.bool()?)?;
+            }
+        }
+        if let Some(n_rows) = n_rows {
+            if !n_rows_applied {
+                df = df.head(Some(n_rows));
+            }
+        }
+        Ok(df)
     }
 }
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use polars_core::config;
@@ -17,6 +17,45 @@ pub struct CsvExec {
 }
 
 impl CsvExec {
+    /// Add the projected hive partition columns of `path` to `df`, and apply the `predicate`
+    /// that may refer to them.
+    fn add_hive_partitions(
+        &self,
+        mut df: DataFrame,
+        path: &Path,
+        with_columns: Option<&[String]>,
+        placeholder_column: Option<&str>,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    ) -> PolarsResult<DataFrame> {
+        let mut file_info = self.file_info.clone();
+        file_info.update_hive_partitions(path)?;
+        let hive_columns = file_info
+            .hive_parts
+            .as_ref()
+            .unwrap()
+            .materialize_partition_columns();
+
+        let num_rows = df.height();
+        for s in hive_columns {
+            if with_columns.map_or(true, |columns| columns.iter().any(|c| c == s.name())) {
+                // SAFETY: the length matches and the names are not in the file schema.
+                unsafe { df.with_column_unchecked(s.new_from_index(0, num_rows)) };
+            }
+        }
+        if let Some(name) = placeholder_column {
+            df = df.drop(name)?;
+        }
+
+        match predicate {
+            Some(predicate) => {
+                let s = predicate.evaluate_io(&df)?;
+                let mask = s.bool().expect("filter predicates was not of type boolean");
+                df.filter(mask)
+            },
+            None => Ok(df),
+        }
+    }
+
     fn read(&self) -> PolarsResult<DataFrame> {
         let with_columns = self
             .file_options
@@ -27,13 +66,45 @@ impl CsvExec {
 
         let n_rows = _set_n_rows_for_scan(self.file_options.n_rows);
         let predicate = self.predicate.clone().map(phys_expr_to_io_expr);
+        let reader_schema = self.file_info.reader_schema.clone().unwrap().unwrap_right();
+
+        // The hive partition columns are not in the files, they are added after reading.
+        let has_hive_partitions = self.file_info.hive_parts.is_some();
+        // A predicate that refers to the hive partition columns is applied once they are added,
+        // other predicates are applied by the reader.
+        let is_hive_predicate = match (&self.file_info.hive_parts, &predicate) {
+            (Some(hive), Some(predicate)) => {
+                let hive_schema = hive.get_statistics().schema();
+                predicate.live_variables().map_or(true, |names| {
+                    names.iter().any(|name| hive_schema.contains(name))
+                })
+            },
+            _ => false,
+        };
+        let mut placeholder_column = None;
+        let reader_columns = match (&self.file_info.hive_parts, &with_columns) {
+            (Some(hive), Some(columns)) => {
+                let hive_schema = hive.get_statistics().schema();
+                let mut reader_columns = columns
+                    .iter()
+                    .filter(|name| !hive_schema.contains(name.as_str()))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                // A column has to be read for the number of rows.
+                if reader_columns.is_empty() {
+                    placeholder_column = reader_schema.iter_names().next().map(|n| n.to_string());
+                    reader_columns.extend(placeholder_column.clone());
+                }
+                Some(Arc::from(reader_columns))
+            },
+            _ => with_columns.clone(),
+        };
+
         let options_base = self
             .options
             .clone()
-            .with_schema(Some(
-                self.file_info.reader_schema.clone().unwrap().unwrap_right(),
-            ))
-            .with_columns(with_columns)
+            .with_schema(Some(reader_schema))
+            .with_columns(reader_columns)
             .with_rechunk(
                 // We rechunk at the end to avoid rechunking multiple times in the
                 // case of reading multiple files.
@@ -61,18 +132,20 @@ impl CsvExec {
 
         let finish_read =
             |i: usize, options: CsvReadOptions, predicate: Option<Arc<dyn PhysicalIoExpr>>| {
-                if run_async {
+                let path = self.paths.get(i).unwrap();
+                let read_predicate = predicate.clone().filter(|_| !is_hive_predicate);
+                let out = if run_async {
                     #[cfg(feature = "cloud")]
                     {
                         options
                             .into_reader_with_file_handle(
                                 polars_io::file_cache::FILE_CACHE
-                                    .get_entry(self.paths.get(i).unwrap().to_str().unwrap())
+                                    .get_entry(path.to_str().unwrap())
                                     // Safety: This was initialized by schema inference.
                                     .unwrap()
                                     .try_open_assume_latest()?,
                             )
-                            ._with_predicate(predicate.clone())
+                            ._with_predicate(read_predicate)
                             .finish()
                     }
                     #[cfg(not(feature = "cloud"))]
@@ -81,10 +154,22 @@ impl CsvExec {
                     }
                 } else {
                     options
-                        .try_into_reader_with_file_path(Some(self.paths.get(i).unwrap().clone()))
+                        .try_into_reader_with_file_path(Some(path.clone()))
                         .unwrap()
-                        ._with_predicate(predicate.clone())
+                        ._with_predicate(read_predicate)
                         .finish()
+                };
+                let df = out.map_err(|e| e.with_file(path.to_string_lossy().into_owned()))?;
+                if has_hive_partitions {
+                    self.add_hive_partitions(
+                        df,
+                        path,
+                        with_columns.as_deref(),
+                        placeholder_column.as_deref(),
+                        predicate.filter(|_| is_hive_predicate),
+                    )
+                } else {
+                    Ok(df)
                 }
             };
 
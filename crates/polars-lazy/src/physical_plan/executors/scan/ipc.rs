@@ -75,6 +75,18 @@ impl IpcExec {
 
         let row_limit = n_rows.unwrap_or(IdxSize::MAX);
 
+        // A memory mapped reader skips the batches without any row that matches the predicate.
+        // The row index and row limit count the rows of every file, so they need the unfiltered
+        // files.
+        let io_predicate =
+            if self.options.memory_map && self.file_options.row_index.is_none() && n_rows.is_none()
+            {
+                self.predicate.clone().map(phys_expr_to_io_expr)
+            } else {
+                None
+            };
+        let verbose = config::verbose();
+
         // Used to determine the next file to open. This guarantees the order.
         let path_index = AtomicUsize::new(0);
         let row_counter = RwLock::new(ConsecutiveCountState::new(self.paths.len()));
@@ -112,7 +124,7 @@ impl IpcExec {
                     .with_row_index(self.file_options.row_index.clone())
                     .with_projection(projection.clone())
                     .memory_mapped(memory_mapped)
-                    .finish()?;
+                    .finish_with_scan_ops(io_predicate.clone(), verbose)?;
 
                 row_counter
                     .write()
@@ -128,7 +140,7 @@ impl IpcExec {
             row_counter.into_inner().unwrap(),
             self.file_options.row_index.as_ref(),
             row_limit,
-            self.predicate.as_ref(),
+            self.predicate.as_ref().filter(|_| io_predicate.is_none()),
         )
     }
 
@@ -2,21 +2,62 @@ use std::num::NonZeroUsize;
 
 use super::*;
 
-impl AnonymousScan for LazyJsonLineReader {
-    fn as_any(&self) -> &dyn std::any::Any {
-        self
-    }
-    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
-        let schema = scan_opts.output_schema.unwrap_or(scan_opts.schema);
+impl LazyJsonLineReader {
+    fn read(&self, schema: SchemaRef, n_rows: Option<usize>) -> PolarsResult<DataFrame> {
         JsonLineReader::from_path(&self.path)?
             .with_schema(schema)
             .with_rechunk(self.rechunk)
             .with_chunk_size(self.batch_size)
             .low_memory(self.low_memory)
-            .with_n_rows(scan_opts.n_rows)
+            .with_n_rows(n_rows)
             .with_ignore_errors(self.ignore_errors)
             .finish()
     }
+}
+
+impl AnonymousScan for LazyJsonLineReader {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let schema = scan_opts.output_schema.unwrap_or(scan_opts.schema);
+        if !self.hive_options.enabled {
+            return self.read(schema, scan_opts.n_rows);
+        }
+
+        let file_schema = self.schema(None)?;
+        let file_info = self.file_info(file_schema.clone())?;
+        let Some(hive_parts) = &file_info.hive_parts else {
+            return self.read(schema, scan_opts.n_rows);
+        };
+        // The hive partition columns are not in the file, they are added after reading.
+        let hive_schema = hive_parts.get_statistics().schema();
+        let mut reader_schema = schema
+            .iter_fields()
+            .filter(|field| !hive_schema.contains(field.name()))
+            .collect::<Schema>();
+        // A column has to be read for the number of rows.
+        let mut placeholder_column = None;
+        if reader_schema.is_empty() {
+            if let Some(field) = file_schema.iter_fields().next() {
+                placeholder_column = Some(field.name().clone());
+                reader_schema.with_column(field.name().clone(), field.data_type().clone());
+            }
+        }
+
+        let mut df = self.read(Arc::new(reader_schema), scan_opts.n_rows)?;
+        let num_rows = df.height();
+        for s in hive_parts.materialize_partition_columns() {
+            if schema.contains(s.name()) {
+                // SAFETY: the length matches and the names are not in the file schema.
+                unsafe { df.with_column_unchecked(s.new_from_index(0, num_rows)) };
+            }
+        }
+        if let Some(name) = placeholder_column {
+            df = df.drop(&name)?;
+        }
+        df.select(schema.iter_names())
+    }
 
     fn schema(&self, infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
         polars_ensure!(infer_schema_length != Some(0), InvalidOperation: "JSON requires positive 'infer_schema_length'");
@@ -1,4 +1,6 @@
 use polars_core::utils::concat_df;
+#[cfg(feature = "merge_sorted")]
+use polars_ops::prelude::_merge_sorted_dfs_by;
 use polars_plan::global::_is_fetch_query;
 
 use super::*;
@@ -6,6 +8,19 @@ use super::*;
 pub(crate) struct UnionExec {
     pub(crate) inputs: Vec<Box<dyn Executor>>,
     pub(crate) options: UnionOptions,
+    pub(crate) merge_sorted_by: Option<Arc<str>>,
+}
+
+impl UnionExec {
+    fn concat(&self, dfs: &[DataFrame]) -> PolarsResult<DataFrame> {
+        match &self.merge_sorted_by {
+            None => concat_df(dfs),
+            #[cfg(feature = "merge_sorted")]
+            Some(by) => _merge_sorted_dfs_by(dfs, by),
+            #[cfg(not(feature = "merge_sorted"))]
+            Some(_) => panic!("activate feature 'merge_sorted'"),
+        }
+    }
 }
 
 impl Executor for UnionExec {
@@ -24,7 +39,8 @@ impl Executor for UnionExec {
         let mut inputs = std::mem::take(&mut self.inputs);
 
         let sliced_path = if let Some((offset, _)) = self.options.slice {
-            offset >= 0
+            // Merged inputs can only be sliced after merging.
+            offset >= 0 && self.merge_sorted_by.is_none()
         } else {
             false
         };
@@ -77,7 +93,10 @@ impl Executor for UnionExec {
                 }
             }
 
-            concat_df(&dfs)
+            self.concat(&dfs).map(|df| match self.options.slice {
+                Some((offset, len)) if !sliced_path => df.slice(offset, len),
+                _ => df,
+            })
         } else {
             if state.verbose() {
                 eprintln!("UNION: union is run in parallel")
@@ -104,7 +123,8 @@ impl Executor for UnionExec {
                     .collect::<PolarsResult<Vec<_>>>()
             });
 
-            concat_df(out?.iter().flat_map(|dfs| dfs.iter())).map(|df| {
+            let dfs = out?.into_iter().flatten().collect::<Vec<_>>();
+            self.concat(&dfs).map(|df| {
                 if let Some((offset, len)) = self.options.slice {
                     df.slice(offset, len)
                 } else {
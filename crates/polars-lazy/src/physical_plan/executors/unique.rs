@@ -1,3 +1,5 @@
+use polars_ops::prelude::DataFrameOps;
+
 use super::*;
 
 pub(crate) struct UniqueExec {
@@ -24,6 +26,15 @@ impl Executor for UniqueExec {
                     return Ok(df);
                 }
 
+                if let Some(order_by) = self.options.order_by.as_ref() {
+                    return df.unique_order_by(
+                        subset,
+                        keep,
+                        order_by,
+                        self.options.maintain_order,
+                        self.options.slice,
+                    );
+                }
                 match self.options.maintain_order {
                     true => df.unique_stable(subset, keep, self.options.slice),
                     false => df.unique(subset, keep, self.options.slice),
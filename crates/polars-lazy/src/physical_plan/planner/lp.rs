@@ -76,6 +76,8 @@ fn partitionable_gb(
                                                 | IRAggExpr::Sum(_)
                                                 | IRAggExpr::Last(_)
                                                 | IRAggExpr::First(_)
+                                                | IRAggExpr::FirstNonNull(_)
+                                                | IRAggExpr::LastNonNull(_)
                                                 | IRAggExpr::Count(_, true)
                                         )
                         },
@@ -158,7 +160,32 @@ fn create_physical_plan_impl(
     let logical_plan = lp_arena.take(root);
     match logical_plan {
         #[cfg(feature = "python")]
-        PythonScan { options, .. } => Ok(Box::new(executors::PythonScanExec { options })),
+        PythonScan { options, predicate } => {
+            // Only IO plugins take a polars predicate, the predicate of a pyarrow scan is
+            // already translated into the options.
+            let predicate = predicate.filter(|_| options.is_io_plugin);
+            let serialized_predicate = predicate
+                .as_ref()
+                .and_then(|pred| executors::serialize_python_predicate(&pred.to_expr(expr_arena)));
+            let mut state = ExpressionConversionState::new(true, state.expr_depth);
+            let schema = options.output_schema.as_ref().unwrap_or(&options.schema);
+            let predicate = predicate
+                .map(|pred| {
+                    create_physical_expr(
+                        &pred,
+                        Context::Default,
+                        expr_arena,
+                        Some(schema),
+                        &mut state,
+                    )
+                })
+                .map_or(Ok(None), |v| v.map(Some))?;
+            Ok(Box::new(executors::PythonScanExec {
+                options,
+                predicate,
+                serialized_predicate,
+            }))
+        },
         Sink { payload, .. } => match payload {
             SinkType::Memory => {
                 polars_bail!(InvalidOperation: "memory sink not supported in the standard engine")
@@ -172,13 +199,27 @@ fn create_physical_plan_impl(
             SinkType::Cloud { .. } => {
                 polars_bail!(InvalidOperation: "cloud sink not supported in standard engine.")
             },
+            SinkType::Batches { .. } => {
+                polars_bail!(InvalidOperation: "batches sink not supported in standard engine.")
+            },
+            SinkType::Multiple(_) => {
+                polars_bail!(InvalidOperation: "multiple sinks not supported in standard engine.")
+            },
         },
-        Union { inputs, options } => {
+        Union {
+            inputs,
+            options,
+            merge_sorted_by,
+        } => {
             let inputs = inputs
                 .into_iter()
                 .map(|node| create_physical_plan_impl(node, lp_arena, expr_arena, state))
                 .collect::<PolarsResult<Vec<_>>>()?;
-            Ok(Box::new(executors::UnionExec { inputs, options }))
+            Ok(Box::new(executors::UnionExec {
+                inputs,
+                options,
+                merge_sorted_by,
+            }))
         },
         HConcat {
             inputs, options, ..
@@ -519,7 +560,9 @@ fn create_physical_plan_impl(
             options,
             ..
         } => {
-            let parallel = if options.force_parallel {
+            let parallel = if let Some(parallel) = options.args.hints.parallel {
+                parallel
+            } else if options.force_parallel {
                 true
             } else if options.allow_parallel {
                 // check if two DataFrames come from a separate source.
@@ -1,4 +1,4 @@
-use polars_core::chunked_array::ops::SortMultipleOptions;
+use polars_core::chunked_array::ops::{NanOrdering, SortMultipleOptions};
 use polars_ops::prelude::*;
 use polars_plan::logical_plan::expr_ir::ExprIR;
 use polars_plan::prelude::*;
@@ -8,7 +8,8 @@ pub(super) fn is_streamable_sort(
     sort_options: &SortMultipleOptions,
 ) -> bool {
     // check if slice is positive or maintain order is true
-    if sort_options.maintain_order {
+    // the sort sinks merge with the default `NaN` ordering
+    if sort_options.maintain_order || sort_options.nan_ordering != NanOrdering::Largest {
         false
     } else if let Some((offset, _)) = slice {
         *offset >= 0
@@ -39,5 +40,5 @@ pub(super) fn streamable_join(args: &JoinArgs) -> bool {
         JoinType::Full { .. } => true,
         _ => false,
     };
-    supported && !args.validation.needs_checks()
+    supported && !args.validation.needs_checks() && args.hints.strategy != JoinStrategy::SortMerge
 }
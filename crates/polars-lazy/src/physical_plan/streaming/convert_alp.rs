@@ -69,6 +69,16 @@ fn process_non_streamable_node(
     state.streamable = false;
 }
 
+#[cfg_attr(not(feature = "csv"), allow(unused_variables))]
+fn is_streamable_scan(scan_type: &FileScan, file_info: &FileInfo) -> bool {
+    match scan_type {
+        // The streaming CSV source doesn't add the hive partition columns.
+        #[cfg(feature = "csv")]
+        FileScan::Csv { .. } if file_info.hive_parts.is_some() => false,
+        _ => scan_type.streamable(),
+    }
+}
+
 fn insert_file_sink(mut root: Node, lp_arena: &mut Arena<IR>) -> Node {
     // The pipelines need a final sink, we insert that here.
     // this allows us to split at joins/unions and share a sink
@@ -234,7 +244,11 @@ pub(crate) fn insert_streaming_nodes(
                     )
                 }
             },
-            Scan { scan_type, .. } if scan_type.streamable() => {
+            Scan {
+                scan_type,
+                file_info,
+                ..
+            } if is_streamable_scan(scan_type, file_info) => {
                 if state.streamable {
                     state.sources.push(root);
                     pipeline_trees[current_idx].push(state)
@@ -284,21 +298,30 @@ pub(crate) fn insert_streaming_nodes(
             },
             // add globbing patterns
             #[cfg(any(feature = "csv", feature = "parquet"))]
-            Union { inputs, options }
-                if options.slice.is_none()
-                    && inputs.iter().all(|node| match lp_arena.get(*node) {
-                        Scan { .. } => true,
-                        MapFunction {
-                            input,
-                            function: FunctionNode::Rechunk,
-                        } => matches!(lp_arena.get(*input), Scan { .. }),
-                        _ => false,
-                    }) =>
+            Union {
+                inputs,
+                options,
+                merge_sorted_by,
+            } if options.slice.is_none()
+                && (merge_sorted_by.is_none() || cfg!(feature = "merge_sorted"))
+                && inputs.iter().all(|node| match lp_arena.get(*node) {
+                    Scan { .. } => true,
+                    MapFunction {
+                        input,
+                        function: FunctionNode::Rechunk,
+                    } => matches!(lp_arena.get(*input), Scan { .. }),
+                    _ => false,
+                }) =>
             {
                 state.sources.push(root);
                 pipeline_trees[current_idx].push(state);
             },
-            Union { inputs, .. } => {
+            // Unions of pipelines don't keep the order of the inputs, so they can't merge.
+            Union {
+                inputs,
+                merge_sorted_by: None,
+                ..
+            } => {
                 {
                     state.streamable = true;
                     for (i, input) in inputs.iter().enumerate() {
@@ -319,6 +342,7 @@ pub(crate) fn insert_streaming_nodes(
             },
             Distinct { input, options }
                 if !options.maintain_order
+                    && options.order_by.is_none()
                     && !matches!(options.keep_strategy, UniqueKeepStrategy::None) =>
             {
                 state.streamable = true;
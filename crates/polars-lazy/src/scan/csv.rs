@@ -6,7 +6,7 @@ use polars_io::csv::read::{
     infer_file_schema, CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues,
 };
 use polars_io::utils::get_reader_bytes;
-use polars_io::RowIndex;
+use polars_io::{HiveOptions, RowIndex};
 
 use crate::prelude::*;
 
@@ -19,6 +19,7 @@ pub struct LazyCsvReader {
     cache: bool,
     read_options: CsvReadOptions,
     cloud_options: Option<CloudOptions>,
+    hive_options: HiveOptions,
 }
 
 #[cfg(feature = "csv")]
@@ -41,6 +42,10 @@ impl LazyCsvReader {
             cache: true,
             read_options: Default::default(),
             cloud_options: Default::default(),
+            hive_options: HiveOptions {
+                enabled: false,
+                ..Default::default()
+            },
         }
     }
 
@@ -211,6 +216,14 @@ impl LazyCsvReader {
         self
     }
 
+    /// Parse the hive partitions in the paths of the files into columns. This is disabled by
+    /// default.
+    #[must_use]
+    pub fn with_hive_options(mut self, hive_options: HiveOptions) -> Self {
+        self.hive_options = hive_options;
+        self
+    }
+
     /// Modify a schema before we run the lazy scanning.
     ///
     /// Important! Run this function latest in the builder!
@@ -284,10 +297,15 @@ impl LazyFileListReader for LazyCsvReader {
             self.paths
         };
 
-        let mut lf: LazyFrame =
-            DslBuilder::scan_csv(paths, self.read_options, self.cache, self.cloud_options)?
-                .build()
-                .into();
+        let mut lf: LazyFrame = DslBuilder::scan_csv(
+            paths,
+            self.read_options,
+            self.cache,
+            self.cloud_options,
+            self.hive_options,
+        )?
+        .build()
+        .into();
         lf.opt_state.file_caching = true;
         Ok(lf)
     }
@@ -3,10 +3,10 @@ use std::path::{Path, PathBuf};
 use std::sync::RwLock;
 
 use polars_core::prelude::*;
-use polars_io::RowIndex;
+use polars_io::{HiveOptions, RowIndex};
 
 use super::*;
-use crate::prelude::{LazyFrame, ScanArgsAnonymous};
+use crate::prelude::{DslPlan, FileInfo, LazyFrame, ScanArgsAnonymous};
 
 #[derive(Clone)]
 pub struct LazyJsonLineReader {
@@ -20,6 +20,7 @@ pub struct LazyJsonLineReader {
     pub(crate) infer_schema_length: Option<usize>,
     pub(crate) n_rows: Option<usize>,
     pub(crate) ignore_errors: bool,
+    pub(crate) hive_options: HiveOptions,
 }
 
 impl LazyJsonLineReader {
@@ -39,6 +40,10 @@ impl LazyJsonLineReader {
             infer_schema_length: Some(100),
             ignore_errors: false,
             n_rows: None,
+            hive_options: HiveOptions {
+                enabled: false,
+                ..Default::default()
+            },
         }
     }
     /// Add a row index column.
@@ -89,6 +94,22 @@ impl LazyJsonLineReader {
         self.batch_size = batch_size;
         self
     }
+
+    /// Add the hive partitions in the path as columns, disabled by default.
+    #[must_use]
+    pub fn with_hive_options(mut self, hive_options: HiveOptions) -> Self {
+        self.hive_options = hive_options;
+        self
+    }
+
+    /// The [`FileInfo`] of the file, with the hive partitions of its path if they are enabled.
+    pub(crate) fn file_info(&self, file_schema: SchemaRef) -> PolarsResult<FileInfo> {
+        let mut file_info = FileInfo::new(file_schema, None, (None, usize::MAX));
+        if self.hive_options.enabled {
+            file_info.init_hive_partitions(&self.path, self.hive_options.schema.clone())?;
+        }
+        Ok(file_info)
+    }
 }
 
 impl LazyFileListReader for LazyJsonLineReader {
@@ -101,8 +122,26 @@ impl LazyFileListReader for LazyJsonLineReader {
             schema: self.schema.read().unwrap().clone(),
             ..ScanArgsAnonymous::default()
         };
-
-        LazyFrame::anonymous_scan(std::sync::Arc::new(self), options)
+        let hive_options = self.hive_options.clone();
+        let path = self.path.clone();
+
+        let mut lf = LazyFrame::anonymous_scan(std::sync::Arc::new(self), options)?;
+        if hive_options.enabled {
+            // Register the path and its partitions on the scan, so that the predicate pushdown
+            // can skip the file if its partitions are filtered out.
+            if let DslPlan::Scan {
+                paths,
+                file_info: Some(file_info),
+                file_options,
+                ..
+            } = &mut lf.logical_plan
+            {
+                file_info.init_hive_partitions(&path, hive_options.schema.clone())?;
+                file_options.hive_options = hive_options;
+                *paths = Arc::from([path]);
+            }
+        }
+        Ok(lf)
     }
 
     fn path(&self) -> &Path {
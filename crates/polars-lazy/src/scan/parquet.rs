@@ -20,6 +20,10 @@ pub struct ScanArgsParquet {
     pub cache: bool,
     /// Expand path given via globbing rules.
     pub glob: bool,
+    /// Only keep the columns of the files whose name contains a match of this regex.
+    pub columns_matching: Option<String>,
+    /// Only keep the columns of the files that have one of these data types.
+    pub dtypes: Option<Vec<DataType>>,
 }
 
 impl Default for ScanArgsParquet {
@@ -35,6 +39,8 @@ impl Default for ScanArgsParquet {
             low_memory: false,
             cache: true,
             glob: true,
+            columns_matching: None,
+            dtypes: None,
         }
     }
 }
@@ -73,6 +79,11 @@ impl LazyFileListReader for LazyParquetReader {
 
     fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
         let row_index = self.args.row_index;
+        let column_selection = (self.args.columns_matching.is_some() || self.args.dtypes.is_some())
+            .then(|| ColumnSelection {
+                columns_matching: self.args.columns_matching,
+                dtypes: self.args.dtypes,
+            });
 
         let paths = if self.paths.is_empty() {
             Arc::new([self.path]) as Arc<[PathBuf]>
@@ -90,6 +101,7 @@ impl LazyFileListReader for LazyParquetReader {
             self.args.cloud_options,
             self.args.use_statistics,
             self.args.hive_options,
+            column_selection,
         )?
         .build()
         .into();
@@ -450,6 +450,7 @@ fn take_aggregations() -> PolarsResult<()> {
                             nulls_last: false,
                             multithreaded: true,
                             maintain_order: false,
+                            ..Default::default()
                         })
                         .head(Some(2)),
                 )
@@ -489,6 +490,7 @@ fn test_take_consistency() -> PolarsResult<()> {
                 nulls_last: false,
                 multithreaded: true,
                 maintain_order: false,
+                ..Default::default()
             })
             .get(lit(0))])
         .collect()?;
@@ -507,6 +509,7 @@ fn test_take_consistency() -> PolarsResult<()> {
                 nulls_last: false,
                 multithreaded: true,
                 maintain_order: false,
+                ..Default::default()
             })
             .get(lit(0))])
         .collect()?;
@@ -526,6 +529,7 @@ fn test_take_consistency() -> PolarsResult<()> {
                     nulls_last: false,
                     multithreaded: true,
                     maintain_order: false,
+                    ..Default::default()
                 })
                 .get(lit(0))
                 .alias("1"),
@@ -537,6 +541,7 @@ fn test_take_consistency() -> PolarsResult<()> {
                             nulls_last: false,
                             multithreaded: true,
                             maintain_order: false,
+                            ..Default::default()
                         })
                         .get(lit(0)),
                 )
@@ -571,3 +576,57 @@ fn test_take_in_groups() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "hist")]
+fn test_hist_group_by() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b", "b", "b"],
+        "x" => [1, 3, 8, 8, 2],
+        "e" => [0.0, 2.0, 5.0, 6.0, 7.0],
+    ]?;
+    let edges = Series::new("", [Some(0.0), Some(2.0)]);
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            col("x")
+                .hist(Some(lit(edges)), None, false, false)
+                .alias("lit"),
+            // Every group has its own edges.
+            col("x")
+                .hist(Some(col("e")), None, false, false)
+                .alias("col"),
+            col("x")
+                .hist(Some(col("e").implode()), None, false, true)
+                .alias("list"),
+        ])
+        .collect()?;
+
+    let counts = |name: &str| -> PolarsResult<Vec<Vec<IdxSize>>> {
+        Ok(out
+            .column(name)?
+            .list()?
+            .into_no_null_iter()
+            .map(|s| s.idx().unwrap().into_no_null_iter().collect())
+            .collect())
+    };
+    assert_eq!(counts("lit")?, [vec![0, 1, 1], vec![0, 1, 2]]);
+    assert_eq!(counts("col")?, [vec![0, 1, 1], vec![1, 0, 0, 2]]);
+
+    let list = out.column("list")?.explode()?;
+    let list = list.struct_()?;
+    assert_eq!(
+        list.field_by_name("breakpoint")?.f64()?.to_vec(),
+        [0.0, 2.0, f64::INFINITY, 5.0, 6.0, 7.0, f64::INFINITY].map(Some)
+    );
+    assert_eq!(
+        list.field_by_name("count")?
+            .idx()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [0, 1, 1, 1, 0, 0, 2]
+    );
+    Ok(())
+}
@@ -355,6 +355,57 @@ fn test_parquet_statistics() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_page_index() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => (0..10_000i64).collect::<Vec<_>>(),
+        "b" => (0..10_000).map(|i| format!("{}", i % 7)).collect::<Vec<_>>(),
+    ]?;
+    let path = std::env::temp_dir().join("polars_test_parquet_page_index.parquet");
+    ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_statistics(StatisticsOptions::full())
+        .with_data_page_size(Some(1024))
+        .finish(&mut df)?;
+
+    for parallel in [ParallelStrategy::None, ParallelStrategy::Columns] {
+        let args = ScanArgsParquet {
+            parallel,
+            row_index: Some(RowIndex {
+                name: Arc::from("index"),
+                offset: 10,
+            }),
+            ..Default::default()
+        };
+        let predicate = col("a")
+            .gt_eq(lit(5_000i64))
+            .and(col("a").lt(lit(5_010i64)));
+        let out = LazyFrame::scan_parquet(&path, args.clone())?
+            .filter(predicate.clone())
+            .collect()?;
+        let expected = df
+            .clone()
+            .lazy()
+            .with_row_index("index", Some(10))
+            .filter(predicate)
+            .collect()?;
+        assert!(out.equals(&expected));
+
+        // Rows past `n_rows` are not read.
+        let args = ScanArgsParquet {
+            n_rows: Some(3_000),
+            ..args
+        };
+        let out = LazyFrame::scan_parquet(&path, args)?
+            .filter(col("a").gt(lit(2_990i64)))
+            .collect()?;
+        assert_eq!(out.column("a")?.len(), 9);
+        assert_eq!(out.column("index")?.idx()?.get(0), Some(3_001));
+    }
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
 #[test]
 #[cfg(not(target_os = "windows"))]
 fn test_parquet_globbing() -> PolarsResult<()> {
@@ -380,6 +431,45 @@ fn test_parquet_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "parquet", feature = "regex"))]
+fn test_scan_parquet_column_selection() -> PolarsResult<()> {
+    init_files();
+    let _guard = SINGLE_LOCK.lock().unwrap();
+
+    let args = ScanArgsParquet {
+        columns_matching: Some("^(cal|fat)".into()),
+        ..Default::default()
+    };
+    let lf = LazyFrame::scan_parquet(FOODS_PARQUET, args)?;
+    let df = lf.clone().collect()?;
+    assert_eq!(df.get_column_names(), &["calories", "fats_g"]);
+    assert_eq!(df.height(), 27);
+    let df = lf.select([col("fats_g")]).collect()?;
+    assert_eq!(df.get_column_names(), &["fats_g"]);
+
+    let args = ScanArgsParquet {
+        dtypes: Some(vec![DataType::String]),
+        row_index: Some(RowIndex {
+            name: Arc::from("index"),
+            offset: 0,
+        }),
+        ..Default::default()
+    };
+    let df = LazyFrame::scan_parquet(FOODS_PARQUET, args)?.collect()?;
+    assert_eq!(df.get_column_names(), &["index", "category"]);
+
+    // Columns that are pruned can't be selected.
+    let args = ScanArgsParquet {
+        columns_matching: Some("^cal".into()),
+        ..Default::default()
+    };
+    let lf = LazyFrame::scan_parquet(FOODS_PARQUET, args)?;
+    assert!(lf.select([col("fats_g")]).collect().is_err());
+
+    Ok(())
+}
+
 #[test]
 fn test_scan_parquet_limit_9001() {
     init_files();
@@ -582,6 +672,46 @@ fn skip_rows_and_slice() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_count_star_row_preserving_nodes() -> PolarsResult<()> {
+    let scan = || LazyCsvReader::new(FOODS_CSV).finish();
+    let is_fast_count = |lf: LazyFrame| -> PolarsResult<bool> {
+        Ok(lf.describe_optimized_plan()?.contains("FAST COUNT(*)"))
+    };
+
+    let lf = scan()?
+        .with_column((col("calories") * lit(2)).alias("double"))
+        .sort(["fats_g"], Default::default())
+        .rename(["category"], ["cat"])
+        .select([len()]);
+    assert!(is_fast_count(lf.clone())?);
+    assert_eq!(lf.collect()?.column("len")?.get(0)?, AnyValue::UInt32(27));
+
+    // Skipped rows and a limit are not reflected in the number of lines of the file.
+    let lf = LazyCsvReader::new(FOODS_CSV)
+        .with_skip_rows(4)
+        .finish()?
+        .select([len()]);
+    assert!(!is_fast_count(lf.clone())?);
+    assert_eq!(lf.collect()?.column("len")?.get(0)?, AnyValue::UInt32(23));
+    let lf = LazyCsvReader::new(FOODS_CSV)
+        .with_n_rows(Some(5))
+        .finish()?
+        .select([len()]);
+    assert!(!is_fast_count(lf.clone())?);
+    assert_eq!(lf.collect()?.column("len")?.get(0)?, AnyValue::UInt32(5));
+
+    // A predicate on the data or a slice changes the number of rows.
+    let lf = scan()?.filter(col("calories").gt(lit(100))).select([len()]);
+    assert!(!is_fast_count(lf)?);
+    let lf = scan()?
+        .sort(["fats_g"], Default::default())
+        .limit(3)
+        .select([len()]);
+    assert!(!is_fast_count(lf)?);
+    Ok(())
+}
+
 #[test]
 fn test_row_index_on_files() -> PolarsResult<()> {
     let _guard = SINGLE_LOCK.lock().unwrap();
@@ -686,3 +816,84 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "snapshot")]
+fn test_snapshot_refresh() -> PolarsResult<()> {
+    let tmp_dir = tempfile::tempdir()?;
+    let dir = tmp_dir.path();
+    let snapshot = dir.join("snapshot");
+    let write = |name: &str, mut df: DataFrame| -> PolarsResult<()> {
+        ParquetWriter::new(std::fs::File::create(dir.join(name))?).finish(&mut df)?;
+        Ok(())
+    };
+    let query = || -> PolarsResult<LazyFrame> {
+        let glob = dir.join("*.parquet");
+        Ok(
+            LazyFrame::scan_parquet(glob.to_str().unwrap(), Default::default())?
+                .filter(col("b").gt(lit(0)))
+                .group_by_stable([col("a")])
+                .agg([
+                    col("b").sum().alias("sum"),
+                    col("b").min().alias("min"),
+                    col("b").max().alias("max"),
+                    len(),
+                ]),
+        )
+    };
+
+    write(
+        "0.parquet",
+        df!["a" => ["x", "y", "x"], "b" => [1i32, 2, 3]]?,
+    )?;
+    write("1.parquet", df!["a" => ["y", "y"], "b" => [4i32, 0]]?)?;
+    let out = query()?.snapshot(&snapshot)?;
+    assert!(out.equals(&query()?.collect()?));
+
+    // Only the new file is read, and merged into the stored result.
+    write("2.parquet", df!["a" => ["z", "x"], "b" => [5i32, 10]]?)?;
+    let out = query()?.refresh(&snapshot)?;
+    let expected = df![
+        "a" => ["x", "y", "z"],
+        "sum" => [14i32, 6, 5],
+        "min" => [1i32, 2, 5],
+        "max" => [10i32, 4, 5],
+        "len" => [3 as IdxSize, 2, 1],
+    ]?;
+    assert!(out.equals(&expected));
+    assert!(out.equals(&query()?.collect()?));
+
+    // A changed file invalidates the snapshot, so the result is computed from all files. A
+    // rewrite with the same number of values can keep the size of the file, so files are also
+    // compared by their modification times.
+    write(
+        "0.parquet",
+        df!["a" => ["x", "y", "x"], "b" => [1i32, 2, 30]]?,
+    )?;
+    let out = query()?.refresh(&snapshot)?;
+    assert!(out.equals(&query()?.collect()?));
+
+    // The snapshot can't be refreshed with another query.
+    let other =
+        LazyFrame::scan_parquet(dir.join("*.parquet").to_str().unwrap(), Default::default())?
+            .group_by_stable([col("a")])
+            .agg([col("b").sum().alias("sum")]);
+    assert!(matches!(
+        other.refresh(&snapshot),
+        Err(PolarsError::InvalidOperation(_))
+    ));
+    let mean = query()?.group_by([col("a")]).agg([col("sum").mean()]);
+    assert!(mean.refresh(&snapshot).is_err());
+    // Only parquet scans are supported.
+    let ipc = LazyFrame::scan_ipc(FOODS_IPC, Default::default())?.select([len()]);
+    assert!(ipc.snapshot(dir.join("ipc_snapshot")).is_err());
+
+    // The manifest and the stored result are the only files of the snapshot.
+    let mut files = std::fs::read_dir(&snapshot)?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    files.sort();
+    assert_eq!(files.len(), 2);
+    assert_eq!(files[1], "manifest");
+    Ok(())
+}
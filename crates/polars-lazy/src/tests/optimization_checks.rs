@@ -548,6 +548,46 @@ fn test_flatten_unions() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_semi_anti_join_pushdown() -> PolarsResult<()> {
+    let df = df! {
+        "a" => [1, 1, 2, 3, 3],
+        "b" => [1, 2, 3, 4, 5],
+    }?;
+    let keys = df! {
+        "a" => [1, 3],
+    }?
+    .lazy();
+
+    let q = df
+        .lazy()
+        .group_by_stable([col("a")])
+        .agg([col("b").sum()])
+        .semi_join(keys, col("a"), col("a"));
+
+    // The semi join filters the rows before they are aggregated.
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    match lp_arena.get(root) {
+        IR::GroupBy { input, .. } => {
+            assert!(matches!(lp_arena.get(*input), IR::Join { .. }))
+        },
+        _ => panic!(),
+    }
+
+    let expected = df! {
+        "a" => [1, 3],
+        "b" => [3, 9],
+    }?;
+    assert!(q.clone().collect()?.equals(&expected));
+    assert!(q
+        .with_semi_anti_join_pushdown(false)
+        .collect()?
+        .equals(&expected));
+    Ok(())
+}
+
 fn num_occurrences(s: &str, needle: &str) -> usize {
     let mut i = 0;
     let mut num = 0;
@@ -170,3 +170,42 @@ fn test_coalesce_toggle_projection_pushdown() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "regex")]
+fn test_selector_algebra() -> PolarsResult<()> {
+    let df = df![
+        "id_a" => [1i64, 2],
+        "id_b" => [3i64, 4],
+        "value" => [1.0, 2.0],
+        "name" => ["x", "y"],
+    ]?;
+
+    let numeric = Selector::by_dtype([DataType::Int64, DataType::Float64]);
+    let out = df
+        .clone()
+        .lazy()
+        .select([numeric.clone().difference(Selector::matches("^id")).into()])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["value"]);
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([(!numeric.clone()).union(Selector::by_name(["id_b"])).into()])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["id_b", "name"]);
+
+    let out = df
+        .lazy()
+        .with_columns([Expr::from(numeric & Selector::matches("_a")) * lit(10)])
+        .collect()?;
+    assert_eq!(
+        out.column("id_a")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[10, 20]
+    );
+    Ok(())
+}
@@ -707,6 +707,48 @@ fn test_lazy_group_by_apply() {
         .unwrap();
 }
 
+#[test]
+fn test_lazy_group_by_apply_groups() -> PolarsResult<()> {
+    let df = fruits_cars();
+
+    // Sum of `A * B` per group, without materializing the groups.
+    let dot = |s: &mut [Series], groups: &GroupsProxy| {
+        let (a, b) = (s[0].i32()?, s[1].i32()?);
+        groups
+            .iter()
+            .map(|g| {
+                let (a, b) = match g {
+                    GroupsIndicator::Idx((_, idx)) => {
+                        (a.take(idx.as_slice())?, b.take(idx.as_slice())?)
+                    },
+                    GroupsIndicator::Slice([first, len]) => (
+                        a.slice(first as i64, len as usize),
+                        b.slice(first as i64, len as usize),
+                    ),
+                };
+                Ok((&a * &b).sum())
+            })
+            .collect::<PolarsResult<Int32Chunked>>()
+            .map(|ca| ca.into_series())
+    };
+    let expr = col("A").apply_groups(dot, &[col("B")], GetOutput::same_type());
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("fruits")])
+        .agg([expr.clone()])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("A")?.i32()?),
+        &[Some(5 + 8 + 5), Some(9 + 8)]
+    );
+
+    let out = df.lazy().select([expr]).collect()?;
+    assert_eq!(Vec::from(out.column("A")?.i32()?), &[Some(35)]);
+    Ok(())
+}
+
 #[test]
 fn test_lazy_shift_and_fill() {
     let df = df! {
@@ -1178,6 +1220,61 @@ fn test_cross_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[cfg(all(feature = "semi_anti_join", feature = "range"))]
+#[test]
+fn test_intersect_except() -> PolarsResult<()> {
+    let df1 = df![
+        "a" => [Some(1), Some(1), Some(1), Some(2), None, None],
+        "b" => ["x", "x", "x", "y", "z", "z"],
+    ]?;
+    // Columns are matched by position.
+    let df2 = df![
+        "c" => [Some(1), Some(2), Some(2), None],
+        "d" => ["x", "y", "y", "z"],
+    ]?;
+
+    let out = df1
+        .clone()
+        .lazy()
+        .intersect(df2.clone().lazy(), true)?
+        .collect()?;
+    assert!(out.equals_missing(&df![
+        "a" => [Some(1), Some(2), None],
+        "b" => ["x", "y", "z"],
+    ]?));
+    let out = df1
+        .clone()
+        .lazy()
+        .intersect(df2.clone().lazy(), false)?
+        .collect()?;
+    assert!(out.equals_missing(&df![
+        "a" => [Some(1), Some(2), None],
+        "b" => ["x", "y", "z"],
+    ]?));
+
+    let out = df1
+        .clone()
+        .lazy()
+        .except(df2.clone().lazy(), true)?
+        .collect()?;
+    assert!(out.is_empty());
+    let out = df1
+        .clone()
+        .lazy()
+        .except(df2.clone().lazy(), false)?
+        .collect()?;
+    assert!(out.equals_missing(&df![
+        "a" => [Some(1), Some(1), None],
+        "b" => ["x", "x", "z"],
+    ]?));
+
+    assert!(df1
+        .lazy()
+        .except(df2.lazy().select([col("c")]), true)
+        .is_err());
+    Ok(())
+}
+
 #[test]
 fn test_select_empty_df() -> PolarsResult<()> {
     // https://github.com/pola-rs/polars/issues/1056
@@ -1638,6 +1735,7 @@ fn test_single_group_result() -> PolarsResult<()> {
                 nulls_last: false,
                 multithreaded: true,
                 maintain_order: false,
+                ..Default::default()
             })
             .over([col("a")])])
         .collect()?;
@@ -1919,3 +2017,94 @@ fn test_sort_maintain_order_true() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "is_unique")]
+fn test_streaming_is_duplicated() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1), Some(2), Some(1), None, None, Some(3)],
+        "b" => ["x", "y", "z", "x", "x", "y"],
+    ]?;
+    let q = df
+        .clone()
+        .lazy()
+        .with_comm_subplan_elim(false)
+        .with_columns([
+            col("a").is_duplicated().alias("dup"),
+            (col("b") + lit("_")).is_unique(),
+        ]);
+
+    let plan = q.clone().with_streaming(true).explain(true)?;
+    assert!(plan.starts_with("STREAMING"), "{plan}");
+    // Both passes read the input from the same cache.
+    assert!(plan.contains("CACHE[id: 0, cache_hits: 2]"), "{plan}");
+
+    let expected = q.clone().collect()?;
+    let out = q.with_streaming(true).collect()?;
+    assert!(out.equals_missing(&expected));
+    assert_eq!(out.get_column_names(), &["a", "b", "dup"]);
+
+    // Windows apply the functions per group.
+    let q = df
+        .clone()
+        .lazy()
+        .with_column(col("a").is_unique().over([col("b")]).alias("unique"));
+    let plan = q.clone().with_streaming(true).explain(true)?;
+    assert!(!plan.contains("CACHE"), "{plan}");
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "unique_counts")]
+fn test_streaming_unique_counts() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(3), Some(1), Some(3), None, Some(1), Some(3), None],
+    ]?;
+    let q = df.lazy().select([col("a").unique_counts().alias("counts")]);
+
+    let expected = q.clone().collect()?;
+    let out = q.with_streaming(true).collect()?;
+    assert!(out.equals(&expected));
+    assert_eq!(
+        out.column("counts")?
+            .idx()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [3, 2, 2]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_query_resources() -> PolarsResult<()> {
+    let df = df![
+        "a" => [3, 1, 2]
+    ]?;
+    let pool_size = || {
+        Ok(Some(Series::new(
+            "pool_size",
+            [polars_core::POOL.current_num_threads() as u32],
+        )))
+    };
+    let out = df
+        .clone()
+        .lazy()
+        .with_resources(QueryResources {
+            max_threads: Some(1),
+            memory_soft_limit: Some(1 << 20),
+        })
+        .select([col("a").map(move |_| pool_size(), GetOutput::from_type(DataType::UInt32))])
+        .collect()?;
+    assert_eq!(out.column("a")?.u32()?.get(0), Some(1));
+
+    // The limits are scoped to the query.
+    let out = df
+        .lazy()
+        .select([col("a").map(move |_| pool_size(), GetOutput::from_type(DataType::UInt32))])
+        .collect()?;
+    assert_eq!(
+        out.column("a")?.u32()?.get(0),
+        Some(polars_core::POOL.current_num_threads() as u32)
+    );
+    Ok(())
+}
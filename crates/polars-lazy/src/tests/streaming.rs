@@ -1,3 +1,6 @@
+use std::sync::Mutex;
+
+use polars_core::utils::accumulate_dataframes_vertical;
 use polars_ops::frame::JoinCoalesce;
 
 use super::*;
@@ -128,6 +131,75 @@ fn test_streaming_first_sum() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_streaming_approx_quantile() -> PolarsResult<()> {
+    let q = get_csv_file();
+
+    let q = q
+        .group_by([col("sugars_g")])
+        .agg([
+            col("calories").approx_quantile(lit(0.5), 0.01),
+            col("fats_g")
+                .approx_quantile(lit(0.9), 0.01)
+                .alias("fats_g_q90"),
+        ])
+        .sort(["sugars_g"], Default::default());
+
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_mode() -> PolarsResult<()> {
+    let q = get_csv_file();
+
+    let q = q
+        .group_by([col("sugars_g")])
+        .agg([
+            col("calories").agg_mode(ModeKeep::All),
+            col("fats_g")
+                .agg_mode(ModeKeep::First)
+                .alias("fats_g_first"),
+            col("fats_g").agg_mode(ModeKeep::Last).alias("fats_g_last"),
+        ])
+        .sort(["sugars_g"], Default::default());
+
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_mode_canonical_floats() -> PolarsResult<()> {
+    // -0.0 and 0.0, and NaNs with different payloads, are the same value.
+    let nan = f64::from_bits(0x7ff8000000000001);
+    let df = df![
+        "g" => [1, 1, 1, 2, 2, 2],
+        "v" => [-0.0, 1.0, 0.0, nan, 2.0, f64::NAN],
+    ]?;
+    let q = df
+        .lazy()
+        .group_by([col("g")])
+        .agg([col("v").agg_mode(ModeKeep::All)])
+        .sort(["g"], Default::default());
+
+    assert!(optimization_checks::is_pipeline(
+        q.clone().with_streaming(true)
+    ));
+    let bits = |q: LazyFrame| -> PolarsResult<Vec<Option<u64>>> {
+        let out = q.collect()?.explode(["v"])?;
+        Ok(out
+            .column("v")?
+            .f64()?
+            .into_iter()
+            .map(|v| v.map(f64::to_bits))
+            .collect())
+    };
+    let expected = bits(q.clone().with_streaming(false))?;
+    assert_eq!(expected, [Some((-0.0f64).to_bits()), Some(nan.to_bits())]);
+    assert_eq!(bits(q.with_streaming(true))?, expected);
+    Ok(())
+}
+
 #[test]
 fn test_streaming_unique() -> PolarsResult<()> {
     let q = get_csv_file();
@@ -428,3 +500,201 @@ fn test_streaming_full_outer_join() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_streaming_sink_batches() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..1000).collect::<Vec<i32>>(),
+    ]?;
+    let q = df
+        .clone()
+        .lazy()
+        .filter(col("a").gt(lit(10)))
+        .with_column((col("a") * lit(2)).alias("b"));
+    let expected = q.clone().collect()?;
+
+    let batches = Arc::new(Mutex::new(Vec::new()));
+    let sink = batches.clone();
+    q.clone().sink_batches(
+        move |df| {
+            sink.lock().unwrap().push(df);
+            Ok(())
+        },
+        Some(100),
+    )?;
+    let batches = std::mem::take(&mut *batches.lock().unwrap());
+    assert_eq!(batches.len(), 10);
+    assert!(batches[..9].iter().all(|df| df.height() == 100));
+    assert_eq!(batches[9].height(), 89);
+    assert!(accumulate_dataframes_vertical(batches)?.equals(&expected));
+
+    // Errors of the callback are returned.
+    let err = q
+        .sink_batches(|_| polars_bail!(ComputeError: "callback failed"), None)
+        .unwrap_err();
+    assert!(err.to_string().contains("callback failed"));
+    Ok(())
+}
+
+#[test]
+fn test_streaming_sink_batches_order() -> PolarsResult<()> {
+    // The filter removes whole morsels, so the pipeline threads sink empty chunks and some
+    // threads sink nothing at all.
+    let df = df![
+        "a" => (0..200_000).collect::<Vec<i32>>(),
+    ]?;
+    let q = df
+        .lazy()
+        .filter((col("a") / lit(5_000) % lit(3)).eq(lit(0)));
+    let expected = q.clone().collect()?;
+
+    let batches = Arc::new(Mutex::new(Vec::new()));
+    let sink = batches.clone();
+    q.sink_batches(
+        move |df| {
+            sink.lock().unwrap().push(df);
+            Ok(())
+        },
+        Some(1_000),
+    )?;
+    let batches = std::mem::take(&mut *batches.lock().unwrap());
+    assert!(accumulate_dataframes_vertical(batches)?.equals(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "row_hash")]
+fn test_streaming_content_hash() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..100_000).collect::<Vec<i32>>(),
+        "b" => (0..100_000).map(|i| format!("value {}", i % 7)).collect::<Vec<_>>(),
+    ]?;
+    let q = df.lazy().filter(col("a").gt(lit(10)));
+
+    let hash = q.clone().content_hash()?;
+    assert_eq!(hash, q.clone().collect()?.content_hash()?);
+    let reversed = q.reverse().collect()?.content_hash()?;
+    assert_ne!(hash.ordered, reversed.ordered);
+    assert_eq!(hash.order_insensitive, reversed.order_insensitive);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "partition_by", feature = "ipc"))]
+fn test_streaming_sink_partitioned_checkpoint() -> PolarsResult<()> {
+    use polars_io::ipc::{IpcReader, IpcWriter, IpcWriterOption};
+    use polars_io::partition::{PartitionedWriter, CHECKPOINT_MANIFEST};
+    use polars_io::{SerReader, SerWriter};
+
+    let tmp_dir = tempfile::tempdir()?;
+    let rootdir = tmp_dir.path().join("partitioned");
+    let df = df![
+        "a" => (0..1000).collect::<Vec<i32>>(),
+        "b" => (0..1000).map(|i| i % 2).collect::<Vec<i32>>(),
+    ]?;
+    let q = df.clone().lazy().filter(col("a").gt(lit(10)));
+    let writer =
+        || PartitionedWriter::new(IpcWriterOption::new(), &rootdir, ["b"]).with_checkpoint(true);
+    let read = |file: &str| IpcReader::new(std::fs::File::open(rootdir.join(file))?).finish();
+
+    // Simulate a failed sink in which only the first batch was written.
+    let first = q.clone().slice(0, 400).collect()?;
+    let mut batched = writer().into_batched(&first.schema(), 400, &q.describe_plan()?)?;
+    batched.write_batch(&first)?;
+    drop(batched);
+    // Mark a file of the first batch to tell whether it is written again.
+    let marked = rootdir.join("b=0/data-00000-0001.ipc");
+    IpcWriter::new(std::fs::File::create(&marked)?).finish(&mut first.head(Some(1)))?;
+
+    // Another query doesn't resume the checkpoint.
+    let other = q.clone().filter(col("a").gt(lit(20)));
+    assert!(other.sink_partitioned(writer(), 400).is_err());
+
+    q.clone().sink_partitioned(writer(), 400)?;
+    assert!(!rootdir.join(CHECKPOINT_MANIFEST).exists());
+    assert_eq!(read("b=0/data-00000-0001.ipc")?.height(), 1);
+
+    let mut files = std::fs::read_dir(rootdir.join("b=1"))?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    files.sort();
+    assert_eq!(
+        files,
+        [
+            "data-00000-0000.ipc",
+            "data-00001-0000.ipc",
+            "data-00002-0000.ipc"
+        ]
+    );
+    let parts = files
+        .iter()
+        .map(|file| read(&format!("b=1/{file}")))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let expected = q.filter(col("b").eq(lit(1))).collect()?;
+    assert!(accumulate_dataframes_vertical(parts)?.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_streaming_sink_multiple() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..1000).collect::<Vec<i32>>(),
+    ]?;
+    let q = df.lazy().filter(col("a").gt(lit(10)));
+    let expected = q.clone().collect()?;
+
+    let small = Arc::new(Mutex::new(Vec::new()));
+    let large = Arc::new(Mutex::new(Vec::new()));
+    let batches_sink = |batches: &Arc<Mutex<Vec<DataFrame>>>, chunk_size| {
+        let batches = batches.clone();
+        SinkType::Batches {
+            callback: BatchCallback::new(move |df| {
+                batches.lock().unwrap().push(df);
+                Ok(())
+            }),
+            chunk_size: Some(chunk_size),
+        }
+    };
+    q.clone()
+        .sink_multiple(vec![batches_sink(&small, 100), batches_sink(&large, 500)])?;
+
+    // Both sinks receive the whole result.
+    let small_batches = std::mem::take(&mut *small.lock().unwrap());
+    let large_batches = std::mem::take(&mut *large.lock().unwrap());
+    assert_eq!(small_batches.len(), 10);
+    assert_eq!(large_batches.len(), 2);
+    assert!(accumulate_dataframes_vertical(small_batches)?.equals(&expected));
+    assert!(accumulate_dataframes_vertical(large_batches)?.equals(&expected));
+
+    // The result of a memory sink would be lost.
+    let err = q
+        .sink_multiple(vec![SinkType::Memory, batches_sink(&small, 100)])
+        .unwrap_err();
+    assert!(err.to_string().contains("memory sink"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cum_agg")]
+fn test_streaming_scan_falls_back_to_in_memory() -> PolarsResult<()> {
+    let add = |acc: &AnyValue<'static>, v: AnyValue<'_>| {
+        Ok(AnyValue::Int64(
+            acc.extract::<i64>().unwrap() + v.extract::<i64>().unwrap(),
+        ))
+    };
+    let q = get_csv_file().select([
+        col("category"),
+        col("calories").scan(lit(0i64), add).alias("total_calories"),
+    ]);
+
+    // The state of the scan can't be carried between batches, so the projection isn't streamed.
+    let q_streaming = q.clone().with_streaming(true);
+    assert!(!optimization_checks::is_pipeline(q_streaming.clone()));
+
+    let out = q_streaming.collect()?;
+    assert_eq!(out, q.collect()?);
+    let total = out.column("total_calories")?.i64()?;
+    let calories = get_csv_file().collect()?.column("calories")?.sum::<i64>()?;
+    assert_eq!(total.get(total.len() - 1), Some(calories));
+    Ok(())
+}
@@ -1,3 +1,6 @@
+use arrow::legacy::kernels::fixed_size_list::sub_fixed_size_list_slice;
+use polars_core::utils::slice_offsets;
+
 use super::min_max::AggType;
 use super::*;
 #[cfg(feature = "array_count")]
@@ -170,6 +173,33 @@ pub trait ArrayNameSpace: AsArray {
         };
         Ok(out.into_series())
     }
+
+    /// Slice every sub-array. The result is again an array, of the sliced width.
+    fn array_slice(&self, offset: i64, length: usize) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        let (start, width) = slice_offsets(offset, length, ca.width());
+        polars_ensure!(
+            width > 0,
+            ComputeError: "`arr.slice` would result in arrays of width 0, which are not supported"
+        );
+        let chunks = ca
+            .downcast_iter()
+            .map(|arr| sub_fixed_size_list_slice(arr, start, width));
+        let mut out = ArrayChunked::from_chunk_iter(ca.name(), chunks);
+        // SAFETY: The physical inner values are not changed.
+        unsafe { out.to_logical(ca.inner_dtype().clone()) };
+        Ok(out)
+    }
+
+    /// Take the first `n` elements of every sub-array.
+    fn array_head(&self, n: usize) -> PolarsResult<ArrayChunked> {
+        self.array_slice(0, n)
+    }
+
+    /// Take the last `n` elements of every sub-array.
+    fn array_tail(&self, n: usize) -> PolarsResult<ArrayChunked> {
+        self.array_slice(-(n as i64), n)
+    }
 }
 
 impl ArrayNameSpace for ArrayChunked {}
@@ -158,10 +158,23 @@ pub fn hist_series(
     include_category: bool,
     include_breakpoint: bool,
 ) -> PolarsResult<Series> {
+    #[cfg(feature = "dtype-f16")]
+    if s.dtype() == &DataType::Float16 {
+        let s = s.cast(&DataType::Float32)?;
+        return hist_series(&s, bin_count, bins, include_category, include_breakpoint);
+    }
     let mut bins_arg = None;
 
     let owned_bins;
-    if let Some(bins) = bins {
+    if let Some(mut bins) = bins {
+        // A single list holds the bins, e.g. if every group has its own bins.
+        if bins.dtype().is_list() || bins.dtype().is_array() {
+            polars_ensure!(
+                bins.len() == 1,
+                ShapeMismatch: "'bins' must be a single list, got {} lists", bins.len()
+            );
+            bins = bins.explode()?;
+        }
         polars_ensure!(bins.null_count() == 0, InvalidOperation: "nulls not supported in 'bins' argument");
         let bins = bins.cast(&DataType::Float64)?;
         let bins_s = bins.rechunk();
@@ -0,0 +1,101 @@
+use arrow::offset::{Offsets, OffsetsBuffer};
+
+use super::*;
+
+/// Pair every element of the lists with its position in its list.
+///
+/// Every row becomes a list of structs with an `index` and a `value` field. The values are
+/// reused as is, only the indices are computed from the offsets.
+pub fn list_enumerate(ca: &ListChunked) -> PolarsResult<ListChunked> {
+    let name = ca.name();
+    let fields = vec![
+        Field::new("index", IDX_DTYPE),
+        Field::new("value", ca.inner_dtype().clone()),
+    ];
+    if ca.is_empty() {
+        return Ok(ListChunked::full_null_with_dtype(
+            name,
+            0,
+            &DataType::Struct(fields),
+        ));
+    }
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let offsets = arr.offsets();
+    let first = *offsets.first();
+
+    let n_values = (offsets.last() - first) as usize;
+
+    let mut index = Vec::with_capacity(n_values);
+    for i in 0..arr.len() {
+        index.extend(0..offsets.length_at(i) as IdxSize);
+    }
+    let index = IdxCa::from_vec("index", index).into_series();
+    let values = ca.get_inner().slice(first, n_values).with_name("value");
+
+    let new_offsets = offsets.iter().map(|o| o - first).collect::<Vec<_>>();
+    // SAFETY: offsets are rebased from valid offsets.
+    let new_offsets: OffsetsBuffer<i64> = unsafe { Offsets::new_unchecked(new_offsets) }.into();
+
+    let values = StructChunked::new(name, &[index, values])?
+        .into_series()
+        .rechunk();
+    let values_arr = values.chunks()[0].clone();
+    let arr = LargeListArray::new(
+        LargeListArray::default_datatype(values_arr.data_type().clone()),
+        new_offsets,
+        values_arr,
+        arr.validity().cloned(),
+    );
+
+    // SAFETY: the inner array is the physical representation of the struct dtype.
+    Ok(unsafe {
+        ListChunked::from_chunks_and_dtype(
+            name,
+            vec![arr.boxed()],
+            DataType::List(Box::new(values.dtype().clone())),
+        )
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_list_enumerate() -> PolarsResult<()> {
+        let values = [
+            Some(Series::new("", [1])),
+            Some(Series::new("", [2, 3])),
+            None,
+            Some(Series::new("", [4, 5])),
+        ];
+        // Slice off the first list, so the values don't start at the first offset.
+        let s = Series::new("a", values).slice(1, 3);
+
+        let out = list_enumerate(s.list()?)?;
+        assert_eq!(out.null_count(), 1);
+        let rows = out
+            .into_iter()
+            .map(|row| {
+                row.map(|row| {
+                    let row = row.struct_().unwrap();
+                    let index = row.field_by_name("index").unwrap();
+                    let value = row.field_by_name("value").unwrap();
+                    let index = index.idx().unwrap().into_no_null_iter().collect::<Vec<_>>();
+                    let value = value.i32().unwrap().into_no_null_iter().collect::<Vec<_>>();
+                    (index, value)
+                })
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            [
+                Some((vec![0, 1], vec![2, 3])),
+                None,
+                Some((vec![0, 1], vec![4, 5]))
+            ]
+        );
+        Ok(())
+    }
+}
@@ -54,6 +54,15 @@ pub(crate) fn hash(ca: &mut ListChunked, build_hasher: ahash::RandomState) -> UI
     // just some large prime
     let null_hash = 1969099309u64;
 
+    // Equal `f16` values may have different bits, so they are hashed as `f32`.
+    #[cfg(feature = "dtype-f16")]
+    if matches!(ca.inner_dtype(), DataType::Float16) {
+        let s = ca
+            .cast(&DataType::List(Box::new(DataType::Float32)))
+            .unwrap();
+        *ca = s.list().unwrap().clone();
+    }
+
     ca.set_inner_dtype(ca.inner_dtype().to_physical());
 
     let out: NoNull<UInt64Chunked> = POOL.install(|| {
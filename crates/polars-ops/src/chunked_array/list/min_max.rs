@@ -76,6 +76,13 @@ fn min_list_numerical(ca: &ListChunked, inner_type: &DataType) -> Series {
 }
 
 pub(super) fn list_min_function(ca: &ListChunked) -> PolarsResult<Series> {
+    // The min of `f16` values is an `f16` value, so it can be computed in `f32`.
+    #[cfg(feature = "dtype-f16")]
+    if matches!(ca.inner_dtype(), DataType::Float16) {
+        let s = ca.cast(&DataType::List(Box::new(DataType::Float32)))?;
+        return list_min_function(s.list()?)?.cast(&DataType::Float16);
+    }
+
     fn inner(ca: &ListChunked) -> PolarsResult<Series> {
         match ca.inner_dtype() {
             DataType::Boolean => {
@@ -185,6 +192,13 @@ fn max_list_numerical(ca: &ListChunked, inner_type: &DataType) -> Series {
 }
 
 pub(super) fn list_max_function(ca: &ListChunked) -> PolarsResult<Series> {
+    // The max of `f16` values is an `f16` value, so it can be computed in `f32`.
+    #[cfg(feature = "dtype-f16")]
+    if matches!(ca.inner_dtype(), DataType::Float16) {
+        let s = ca.cast(&DataType::List(Box::new(DataType::Float32)))?;
+        return list_max_function(s.list()?)?.cast(&DataType::Float16);
+    }
+
     fn inner(ca: &ListChunked) -> PolarsResult<Series> {
         match ca.inner_dtype() {
             DataType::Boolean => {
@@ -4,25 +4,37 @@ use polars_core::prelude::*;
 mod any_all;
 mod count;
 mod dispersion;
+#[cfg(feature = "list_enumerate")]
+mod enumerate;
 #[cfg(feature = "hash")]
 pub(crate) mod hash;
 mod min_max;
 mod namespace;
+#[cfg(feature = "list_rolling")]
+mod rolling;
 #[cfg(feature = "list_sets")]
 mod sets;
 mod sum_mean;
 #[cfg(feature = "list_to_struct")]
 mod to_struct;
+#[cfg(feature = "list_zip")]
+mod zip;
 
 #[cfg(feature = "list_count")]
 pub use count::*;
 #[cfg(not(feature = "list_count"))]
 use count::*;
+#[cfg(feature = "list_enumerate")]
+pub use enumerate::*;
 pub use namespace::*;
+#[cfg(feature = "list_rolling")]
+pub use rolling::*;
 #[cfg(feature = "list_sets")]
 pub use sets::*;
 #[cfg(feature = "list_to_struct")]
 pub use to_struct::*;
+#[cfg(feature = "list_zip")]
+pub use zip::*;
 
 pub trait AsList {
     fn as_list(&self) -> &ListChunked;
@@ -0,0 +1,163 @@
+use arrow::array::{Array, LargeListArray, PrimitiveArray};
+use arrow::offset::{Offsets, OffsetsBuffer};
+use num_traits::{AsPrimitive, Zero};
+use polars_utils::float::IsFloat;
+
+use super::*;
+
+/// The data type of the values of [`list_rolling_sum`] for lists of `inner` values.
+pub fn list_rolling_sum_dtype(inner: &DataType) -> DataType {
+    match inner {
+        DataType::Int8 | DataType::Int16 | DataType::UInt8 | DataType::UInt16 => DataType::Int64,
+        dt => dt.clone(),
+    }
+}
+
+/// The data type of the values of [`list_rolling_mean`] for lists of `inner` values.
+pub fn list_rolling_mean_dtype(inner: &DataType) -> DataType {
+    match inner {
+        DataType::Float32 => DataType::Float32,
+        _ => DataType::Float64,
+    }
+}
+
+/// Apply a rolling aggregation over the values within each list.
+///
+/// The windows don't cross the boundaries of the lists, so every list is aggregated
+/// independently. The values are summed as `T`, and `agg` receives the sum and the number of
+/// non-null values in a window; windows with fewer than `min_periods` non-null values are null.
+fn rolling_within_lists<T, O, F>(
+    ca: &ListChunked,
+    window_size: usize,
+    min_periods: usize,
+    agg: F,
+) -> PolarsResult<ListChunked>
+where
+    T: PolarsNumericType,
+    O: PolarsNumericType,
+    F: Fn(T::Native, usize) -> O::Native,
+{
+    polars_ensure!(
+        window_size > 0,
+        InvalidOperation: "`window_size` must be positive"
+    );
+    polars_ensure!(
+        min_periods <= window_size,
+        InvalidOperation: "`min_periods` should be <= `window_size`"
+    );
+    let inner_dtype = ca.inner_dtype();
+    polars_ensure!(
+        inner_dtype.is_numeric(),
+        InvalidOperation: "rolling aggregations within lists are not supported for dtype {}",
+        ca.dtype()
+    );
+
+    let ca = ca.rechunk();
+    let name = ca.name();
+    let Some(arr) = ca.downcast_iter().next() else {
+        return Ok(ListChunked::full_null_with_dtype(name, 0, &O::get_dtype()));
+    };
+    let values = ca.get_inner().cast(&T::get_dtype())?;
+    let values = values.unpack::<T>()?.rechunk();
+    let values = values.downcast_iter().next().unwrap();
+    let get = |i: usize| values.is_valid(i).then(|| values.value(i));
+
+    let offsets = arr.offsets();
+    let first = *offsets.first() as usize;
+    let mut out = Vec::with_capacity(*offsets.last() as usize - first);
+    for row in 0..arr.len() {
+        let (start, end) = offsets.start_end(row);
+        let mut sum = T::Native::zero();
+        let mut count = 0;
+        for i in start..end {
+            if let Some(v) = get(i) {
+                sum += v;
+                count += 1;
+            }
+            // Drop the value that leaves the window.
+            if i >= start + window_size {
+                if let Some(v) = get(i - window_size) {
+                    count -= 1;
+                    if T::Native::is_float() && !v.is_finite() {
+                        // Subtracting an infinite or NaN value doesn't undo adding it, so
+                        // recompute the sum of the window.
+                        sum = (i + 1 - window_size..=i).filter_map(get).sum();
+                    } else {
+                        sum -= v;
+                    }
+                }
+            }
+            if count > 0 && count >= min_periods {
+                out.push(Some(agg(sum, count)));
+            } else {
+                out.push(None);
+            }
+        }
+    }
+
+    let out_values = PrimitiveArray::<O::Native>::from(out);
+    let out_offsets = offsets.iter().map(|o| o - first as i64).collect::<Vec<_>>();
+    // SAFETY: offsets are rebased from valid offsets.
+    let out_offsets: OffsetsBuffer<i64> = unsafe { Offsets::new_unchecked(out_offsets) }.into();
+    let arr = LargeListArray::new(
+        LargeListArray::default_datatype(out_values.data_type().clone()),
+        out_offsets,
+        out_values.boxed(),
+        arr.validity().cloned(),
+    );
+    // SAFETY: the values are of dtype `O`.
+    Ok(unsafe {
+        ListChunked::from_chunks_and_dtype(
+            name,
+            vec![arr.boxed()],
+            DataType::List(Box::new(O::get_dtype())),
+        )
+    })
+}
+
+/// Sum the values of every window of `window_size` values within each list.
+///
+/// The result has a value for every value of the input. Windows with fewer than `min_periods`
+/// non-null values are null.
+pub fn list_rolling_sum(
+    ca: &ListChunked,
+    window_size: usize,
+    min_periods: usize,
+) -> PolarsResult<Series> {
+    let inner_dtype = ca.inner_dtype();
+    let dtype = DataType::List(Box::new(list_rolling_sum_dtype(inner_dtype)));
+    // Integers are summed as integers, so that large values don't lose precision.
+    let out = if inner_dtype.is_float() {
+        rolling_within_lists::<Float64Type, Float64Type, _>(ca, window_size, min_periods, |s, _| s)?
+    } else if inner_dtype.is_unsigned_integer() {
+        rolling_within_lists::<UInt64Type, UInt64Type, _>(ca, window_size, min_periods, |s, _| s)?
+    } else {
+        rolling_within_lists::<Int64Type, Int64Type, _>(ca, window_size, min_periods, |s, _| s)?
+    };
+    out.into_series().cast(&dtype)
+}
+
+/// Average the values of every window of `window_size` values within each list.
+///
+/// The result has a value for every value of the input. Windows with fewer than `min_periods`
+/// non-null values are null.
+pub fn list_rolling_mean(
+    ca: &ListChunked,
+    window_size: usize,
+    min_periods: usize,
+) -> PolarsResult<Series> {
+    let inner_dtype = ca.inner_dtype();
+    let dtype = DataType::List(Box::new(list_rolling_mean_dtype(inner_dtype)));
+    let out = if inner_dtype.is_float() {
+        rolling_within_lists::<Float64Type, Float64Type, _>(ca, window_size, min_periods, mean)?
+    } else if inner_dtype.is_unsigned_integer() {
+        rolling_within_lists::<UInt64Type, Float64Type, _>(ca, window_size, min_periods, mean)?
+    } else {
+        rolling_within_lists::<Int64Type, Float64Type, _>(ca, window_size, min_periods, mean)?
+    };
+    out.into_series().cast(&dtype)
+}
+
+fn mean<T: AsPrimitive<f64>>(sum: T, count: usize) -> f64 {
+    sum.as_() / count as f64
+}
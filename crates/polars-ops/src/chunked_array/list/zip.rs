@@ -0,0 +1,263 @@
+use std::fmt::{Display, Formatter};
+
+use arrow::bitmap::Bitmap;
+use arrow::compute::utils::combine_validities_and;
+use arrow::offset::{Offsets, OffsetsBuffer};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// How [`list_zip`] deals with rows whose lists have different lengths.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ListZipLength {
+    /// Raise an error.
+    #[default]
+    Strict,
+    /// Stop at the end of the shortest list.
+    Truncate,
+    /// Continue to the end of the longest list, padding the shorter lists with nulls.
+    PadNull,
+}
+
+impl Display for ListZipLength {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ListZipLength::Strict => "strict",
+            ListZipLength::Truncate => "truncate",
+            ListZipLength::PadNull => "pad",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Zip the lists of every row into a single list of structs.
+///
+/// The `i`-th struct of a row holds the `i`-th element of every input list, in fields that are
+/// named after the inputs. A row is null if it is null in any of the inputs. Inputs of length
+/// one are broadcast.
+pub fn list_zip(lists: &[Series], length: ListZipLength) -> PolarsResult<ListChunked> {
+    polars_ensure!(!lists.is_empty(), ComputeError: "cannot zip zero lists");
+    let len = lists
+        .iter()
+        .map(|s| s.len())
+        .find(|len| *len != 1)
+        .unwrap_or(1);
+    let mut names = PlHashSet::with_capacity(lists.len());
+
+    let lists = lists
+        .iter()
+        .map(|s| {
+            polars_ensure!(
+                names.insert(s.name()),
+                Duplicate: "cannot zip lists with duplicate name '{}', consider renaming them",
+                s.name()
+            );
+            polars_ensure!(
+                s.len() == len || s.len() == 1,
+                ShapeMismatch: "cannot zip lists of length {} and {}", s.len(), len
+            );
+            let s = if s.len() == len {
+                s.rechunk()
+            } else {
+                s.new_from_index(0, len)
+            };
+            Ok(s.list()?.clone())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    if len == 0 {
+        let fields = lists
+            .iter()
+            .map(|ca| Field::new(ca.name(), ca.inner_dtype().clone()))
+            .collect();
+        return Ok(ListChunked::full_null_with_dtype(
+            lists[0].name(),
+            0,
+            &DataType::Struct(fields),
+        ));
+    }
+    let arrays = lists
+        .iter()
+        .map(|ca| ca.downcast_iter().next().unwrap())
+        .collect::<Vec<_>>();
+
+    let validity = arrays.iter().fold(None, |acc: Option<Bitmap>, arr| {
+        combine_validities_and(acc.as_ref(), arr.validity())
+    });
+    let is_valid = |i: usize| validity.as_ref().map_or(true, |v| v.get_bit(i));
+
+    // Fast path: all lists share their lengths, so the values can be used as is.
+    let first_offsets = arrays[0].offsets();
+    let same_lengths = arrays[1..].iter().all(|arr| {
+        let offsets = arr.offsets();
+        let (start, first_start) = (offsets.first(), first_offsets.first());
+        offsets
+            .iter()
+            .zip(first_offsets.iter())
+            .all(|(o, f)| o - start == f - first_start)
+    });
+
+    let (offsets, fields) = if same_lengths {
+        let offsets = first_offsets
+            .iter()
+            .map(|o| o - first_offsets.first())
+            .collect::<Vec<_>>();
+        // SAFETY: offsets are rebased from valid offsets.
+        let offsets: OffsetsBuffer<i64> = unsafe { Offsets::new_unchecked(offsets) }.into();
+        let fields = lists
+            .iter()
+            .zip(&arrays)
+            .map(|(ca, arr)| {
+                let offsets = arr.offsets();
+                let values = ca.get_inner();
+                values
+                    .slice(
+                        *offsets.first(),
+                        (offsets.last() - offsets.first()) as usize,
+                    )
+                    .with_name(ca.name())
+            })
+            .collect::<Vec<_>>();
+        (offsets, fields)
+    } else {
+        let mut offsets = Vec::with_capacity(len + 1);
+        offsets.push(0i64);
+        let mut total = 0i64;
+        for i in 0..len {
+            if is_valid(i) {
+                let mut lengths = arrays.iter().map(|arr| arr.offsets().length_at(i));
+                let first = lengths.next().unwrap();
+                let (min, max) =
+                    lengths.fold((first, first), |(min, max), l| (min.min(l), max.max(l)));
+                let row_len = match length {
+                    ListZipLength::Strict => {
+                        polars_ensure!(
+                            min == max,
+                            ShapeMismatch: "cannot zip lists of different lengths in row {}; \
+                            use `length='truncate'` or `length='pad'`", i
+                        );
+                        min
+                    },
+                    ListZipLength::Truncate => min,
+                    ListZipLength::PadNull => max,
+                };
+                total += row_len as i64;
+            }
+            offsets.push(total);
+        }
+        // SAFETY: offsets are monotonically increasing.
+        let offsets: OffsetsBuffer<i64> = unsafe { Offsets::new_unchecked(offsets) }.into();
+
+        let fields = lists
+            .iter()
+            .zip(&arrays)
+            .map(|(ca, arr)| {
+                let mut idx: IdxCa = (0..len)
+                    .flat_map(|i| {
+                        let (start, end) = arr.offsets().start_end(i);
+                        let row_len = offsets.length_at(i);
+                        (0..row_len).map(move |j| {
+                            let j = start + j;
+                            (j < end).then_some(j as IdxSize)
+                        })
+                    })
+                    .collect();
+                idx.rename(ca.name());
+                let values = ca.get_inner();
+                // SAFETY: the indices are within the bounds of the list.
+                unsafe { values.take_unchecked(&idx) }.with_name(ca.name())
+            })
+            .collect::<Vec<_>>();
+        (offsets, fields)
+    };
+
+    let name = lists[0].name();
+    let values = StructChunked::new(name, &fields)?.into_series().rechunk();
+    let values_arr = values.chunks()[0].clone();
+    let arr = LargeListArray::new(
+        LargeListArray::default_datatype(values_arr.data_type().clone()),
+        offsets,
+        values_arr,
+        validity,
+    );
+
+    // SAFETY: the inner array is the physical representation of the struct dtype.
+    Ok(unsafe {
+        ListChunked::from_chunks_and_dtype(
+            name,
+            vec![arr.boxed()],
+            DataType::List(Box::new(values.dtype().clone())),
+        )
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn list(name: &str, values: &[Option<&[i32]>]) -> Series {
+        let values = values
+            .iter()
+            .map(|v| v.map(|v| Series::new("", v)))
+            .collect::<Vec<_>>();
+        Series::new(name, values)
+    }
+
+    fn field(ca: &ListChunked, name: &str) -> Vec<Option<Vec<Option<i32>>>> {
+        ca.into_iter()
+            .map(|row| {
+                row.map(|row| {
+                    let s = row.struct_().unwrap().field_by_name(name).unwrap();
+                    s.i32().unwrap().into_iter().collect()
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_list_zip() -> PolarsResult<()> {
+        let a = list("a", &[Some(&[1, 2]), None, Some(&[3])]);
+        let b = list("b", &[Some(&[4]), Some(&[5]), Some(&[6, 7])]);
+
+        assert!(list_zip(&[a.clone(), b.clone()], ListZipLength::Strict).is_err());
+
+        let out = list_zip(&[a.clone(), b.clone()], ListZipLength::Truncate)?;
+        assert_eq!(out.null_count(), 1);
+        assert_eq!(
+            field(&out, "a"),
+            [Some(vec![Some(1)]), None, Some(vec![Some(3)])]
+        );
+        assert_eq!(
+            field(&out, "b"),
+            [Some(vec![Some(4)]), None, Some(vec![Some(6)])]
+        );
+
+        let out = list_zip(&[a, b], ListZipLength::PadNull)?;
+        assert_eq!(
+            field(&out, "a"),
+            [
+                Some(vec![Some(1), Some(2)]),
+                None,
+                Some(vec![Some(3), None])
+            ]
+        );
+        assert_eq!(
+            field(&out, "b"),
+            [
+                Some(vec![Some(4), None]),
+                None,
+                Some(vec![Some(6), Some(7)])
+            ]
+        );
+
+        // Unit-length inputs are broadcast.
+        let a = list("a", &[Some(&[1]), Some(&[2])]);
+        let b = list("b", &[Some(&[3])]);
+        let out = list_zip(&[a.clone(), b], ListZipLength::Strict)?;
+        assert_eq!(field(&out, "b"), [Some(vec![Some(3)]), Some(vec![Some(3)])]);
+
+        assert!(list_zip(&[a.clone(), a], ListZipLength::Strict).is_err());
+        Ok(())
+    }
+}
@@ -40,6 +40,10 @@ pub fn nan_min_s(s: &Series, name: &str) -> Series {
             let ca = s.f64().unwrap();
             Series::new(name, [ca_nan_agg(ca, MinMax::min_propagate_nan)])
         },
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => nan_min_s(&s.cast(&DataType::Float32).unwrap(), name)
+            .cast(&DataType::Float16)
+            .unwrap(),
         _ => panic!("expected float"),
     }
 }
@@ -54,6 +58,10 @@ pub fn nan_max_s(s: &Series, name: &str) -> Series {
             let ca = s.f64().unwrap();
             Series::new(name, [ca_nan_agg(ca, MinMax::max_propagate_nan)])
         },
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => nan_max_s(&s.cast(&DataType::Float32).unwrap(), name)
+            .cast(&DataType::Float16)
+            .unwrap(),
         _ => panic!("expected float"),
     }
 }
@@ -208,6 +216,10 @@ pub unsafe fn group_agg_nan_min_s(s: &Series, groups: &GroupsProxy) -> Series {
             let ca = s.f64().unwrap();
             group_nan_min(ca, groups)
         },
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => group_agg_nan_min_s(&s.cast(&DataType::Float32).unwrap(), groups)
+            .cast(&DataType::Float16)
+            .unwrap(),
         _ => panic!("expected float"),
     }
 }
@@ -224,6 +236,10 @@ pub unsafe fn group_agg_nan_max_s(s: &Series, groups: &GroupsProxy) -> Series {
             let ca = s.f64().unwrap();
             group_nan_max(ca, groups)
         },
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => group_agg_nan_max_s(&s.cast(&DataType::Float32).unwrap(), groups)
+            .cast(&DataType::Float16)
+            .unwrap(),
         _ => panic!("expected float"),
     }
 }
@@ -1,5 +1,6 @@
-use num_traits::Zero;
+use num_traits::{ToPrimitive, Zero};
 use polars_core::prelude::*;
+use polars_utils::total_ord::TotalOrd;
 
 /// Get a boolean mask of the local maximum peaks.
 pub fn peak_max<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> BooleanChunked
@@ -20,3 +21,110 @@ where
     let shift_right = ca.shift_and_fill(-1, Some(Zero::zero()));
     ChunkedArray::gt(&shift_left, ca) & ChunkedArray::gt(&shift_right, ca)
 }
+
+/// The lowest of `values` before the first value that is higher than `top` or null.
+fn lowest_until_higher<N: NumericNative>(values: impl Iterator<Item = Option<N>>, top: N) -> N {
+    let mut lowest = top;
+    for v in values {
+        match v {
+            Some(v) if v <= top => {
+                if v < lowest {
+                    lowest = v
+                }
+            },
+            _ => break,
+        }
+    }
+    lowest
+}
+
+/// Get a boolean mask of the peaks: the local maxima that are higher than their neighbours.
+///
+/// A peak is never at the first or last value, or next to a null, and the middle value (rounded
+/// down) of a flat peak is taken. Like `scipy.signal.find_peaks`, the peaks can be filtered by
+/// their `prominence`: the height of the peak above the higher of the lowest values on either
+/// side of it, up to a higher value. With `distance`, only the highest of the peaks that are less
+/// than `distance` values apart is kept.
+pub fn find_peaks<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    distance: Option<usize>,
+    prominence: Option<f64>,
+) -> PolarsResult<BooleanChunked> {
+    polars_ensure!(
+        distance.map_or(true, |d| d >= 1),
+        InvalidOperation: "`distance` must be at least 1 in `find_peaks`"
+    );
+    let values = ca.iter().collect::<Vec<_>>();
+    let lt =
+        |a: Option<T::Native>, b: Option<T::Native>| matches!((a, b), (Some(a), Some(b)) if a < b);
+    let eq =
+        |a: Option<T::Native>, b: Option<T::Native>| matches!((a, b), (Some(a), Some(b)) if a == b);
+
+    // Single pass over the values for the local maxima.
+    let mut peaks = Vec::new();
+    let last = values.len().saturating_sub(1);
+    let mut i = 1;
+    while i < last {
+        if lt(values[i - 1], values[i]) {
+            let mut ahead = i + 1;
+            while ahead < last && eq(values[ahead], values[i]) {
+                ahead += 1;
+            }
+            if lt(values[ahead], values[i]) {
+                peaks.push((i + ahead - 1) / 2);
+                i = ahead;
+            }
+        }
+        i += 1;
+    }
+    // Peaks are never null.
+    let height = |peak: usize| values[peak].unwrap();
+
+    if let Some(distance) = distance {
+        // Visit the highest peaks first, and the last of equally high peaks first.
+        let mut by_height = (0..peaks.len()).collect::<Vec<_>>();
+        by_height.sort_by(|&a, &b| height(peaks[a]).tot_cmp(&height(peaks[b])).then(a.cmp(&b)));
+        let mut keep = vec![true; peaks.len()];
+        for &idx in by_height.iter().rev() {
+            if !keep[idx] {
+                continue;
+            }
+            let peak = peaks[idx];
+            for j in (0..idx).rev() {
+                if peak - peaks[j] >= distance {
+                    break;
+                }
+                keep[j] = false;
+            }
+            for j in idx + 1..peaks.len() {
+                if peaks[j] - peak >= distance {
+                    break;
+                }
+                keep[j] = false;
+            }
+        }
+        let mut keep = keep.into_iter();
+        peaks.retain(|_| keep.next().unwrap());
+    }
+
+    if let Some(prominence) = prominence {
+        peaks.retain(|&peak| {
+            let top = height(peak);
+            let left = lowest_until_higher(values[..peak].iter().rev().copied(), top);
+            let right = lowest_until_higher(values[peak + 1..].iter().copied(), top);
+            let base = if left > right { left } else { right };
+            top.to_f64().unwrap() - base.to_f64().unwrap() >= prominence
+        });
+    }
+
+    let mut mask = vec![false; values.len()];
+    for peak in peaks {
+        mask[peak] = true;
+    }
+    let out: BooleanChunked = values
+        .iter()
+        .zip(mask)
+        .map(|(v, is_peak)| v.map(|_| is_peak))
+        .collect_ca(ca.name());
+    Ok(out)
+}
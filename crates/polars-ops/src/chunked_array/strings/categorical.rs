@@ -0,0 +1,115 @@
+use arrow::array::BooleanArray;
+use polars_core::prelude::*;
+
+use super::StringNameSpaceImpl;
+use crate::chunked_array::binary::BinaryNameSpaceImpl;
+
+/// Evaluate a string predicate once per category and broadcast the result to the rows through
+/// their codes.
+///
+/// `f` receives the categories as a [`StringChunked`] and must return a mask of the same length.
+/// Rows that are null, or whose category evaluates to null, are null in the output.
+pub fn cat_apply_predicate<F>(ca: &CategoricalChunked, f: F) -> PolarsResult<BooleanChunked>
+where
+    F: FnOnce(&StringChunked) -> PolarsResult<BooleanChunked>,
+{
+    let rev_map = ca.get_rev_map();
+    let categories = StringChunked::with_chunk("", rev_map.get_categories().clone());
+    let mask = f(&categories)?;
+    polars_ensure!(
+        mask.len() == categories.len(),
+        ShapeMismatch: "predicate returned {} values for {} categories", mask.len(), categories.len()
+    );
+    let mask = mask.rechunk();
+    let mask: &BooleanArray = mask.downcast_iter().next().unwrap();
+
+    let physical = ca.physical();
+    let mut out: BooleanChunked = match &**rev_map {
+        RevMapping::Local(_, _) => physical.apply_generic(|code| mask.get(code? as usize)),
+        RevMapping::Global(map, _, _) => {
+            // Resolve the global codes to the mask once, so that the rows index into a slice
+            // instead of hashing their code.
+            let min = map.keys().copied().min().unwrap_or(0);
+            let max = map.keys().copied().max().unwrap_or(0);
+            let mut global_mask = vec![None; (max - min) as usize + !map.is_empty() as usize];
+            for (&global, &local) in map.iter() {
+                global_mask[(global - min) as usize] = mask.get(local as usize);
+            }
+            physical.apply_generic(|code| *global_mask.get(code?.checked_sub(min)? as usize)?)
+        },
+    };
+    out.rename(ca.name());
+    Ok(out)
+}
+
+/// Check if the categories of `ca` match a regex pattern, or contain `pat` if `literal` is set.
+pub fn cat_contains(
+    ca: &CategoricalChunked,
+    pat: &str,
+    literal: bool,
+    strict: bool,
+) -> PolarsResult<BooleanChunked> {
+    cat_apply_predicate(ca, |categories| {
+        if literal {
+            categories.contains_literal(pat)
+        } else {
+            categories.contains(pat, strict)
+        }
+    })
+}
+
+/// Check if the categories of `ca` start with `prefix`.
+pub fn cat_starts_with(ca: &CategoricalChunked, prefix: &str) -> PolarsResult<BooleanChunked> {
+    cat_apply_predicate(ca, |categories| {
+        Ok(categories.as_binary().starts_with(prefix.as_bytes()))
+    })
+}
+
+/// Check if the categories of `ca` end with `suffix`.
+pub fn cat_ends_with(ca: &CategoricalChunked, suffix: &str) -> PolarsResult<BooleanChunked> {
+    cat_apply_predicate(ca, |categories| {
+        Ok(categories.as_binary().ends_with(suffix.as_bytes()))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cat_predicates() -> PolarsResult<()> {
+        for global in [false, true] {
+            let _lock = polars_core::SINGLE_LOCK.lock().unwrap();
+            let _guard = global.then(StringCacheHolder::hold);
+            let s = Series::new("a", &[Some("apple"), None, Some("banana"), Some("apple")])
+                .cast(&DataType::Categorical(None, Default::default()))?;
+            let ca = s.categorical()?;
+
+            let out = cat_starts_with(ca, "ap")?;
+            assert_eq!(out.name(), "a");
+            assert_eq!(
+                Vec::from(&out),
+                &[Some(true), None, Some(false), Some(true)]
+            );
+            let out = cat_ends_with(ca, "na")?;
+            assert_eq!(
+                Vec::from(&out),
+                &[Some(false), None, Some(true), Some(false)]
+            );
+            let out = cat_contains(ca, "a.a", false, true)?;
+            assert_eq!(
+                Vec::from(&out),
+                &[Some(false), None, Some(true), Some(false)]
+            );
+            let out = cat_contains(ca, "a.a", true, true)?;
+            assert_eq!(
+                Vec::from(&out),
+                &[Some(false), None, Some(false), Some(false)]
+            );
+            assert!(cat_contains(ca, "(", false, true).is_err());
+            let out = cat_contains(ca, "(", false, false)?;
+            assert_eq!(out.null_count(), 4);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The check digit scheme used to validate identifiers.
+///
+/// Spaces and hyphens are ignored, so that formatted identifiers such as
+/// `"4111 1111 1111 1111"` validate. Any other character that is not part of the
+/// scheme makes the value invalid.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ChecksumAlgorithm {
+    /// The Luhn (mod 10) algorithm of credit card numbers and IMEIs.
+    Luhn,
+    /// ISO 7064 MOD 97-10, where letters count as 10 to 35.
+    Mod97,
+    /// An IBAN: the country code and check digits are moved to the end before MOD 97-10.
+    Iban,
+    /// The digits multiplied by `weights`, repeated from the left, must sum to a multiple
+    /// of `modulus`.
+    Weighted { weights: Vec<u32>, modulus: u32 },
+}
+
+fn is_separator(b: u8) -> bool {
+    b == b' ' || b == b'-'
+}
+
+fn luhn(s: &str) -> bool {
+    let mut sum = 0u32;
+    let mut n_digits = 0usize;
+    for b in s.bytes().rev().filter(|b| !is_separator(*b)) {
+        if !b.is_ascii_digit() {
+            return false;
+        }
+        let mut d = (b - b'0') as u32;
+        if n_digits % 2 == 1 {
+            d *= 2;
+            if d > 9 {
+                d -= 9;
+            }
+        }
+        sum += d;
+        n_digits += 1;
+    }
+    n_digits > 1 && sum % 10 == 0
+}
+
+/// Feed the alphanumeric characters of `bytes` to a running MOD 97 remainder.
+fn mod97_update(mut rem: u32, bytes: impl Iterator<Item = u8>) -> Option<u32> {
+    for b in bytes {
+        rem = match b {
+            b'0'..=b'9' => (rem * 10 + (b - b'0') as u32) % 97,
+            b'A'..=b'Z' | b'a'..=b'z' => {
+                let v = (b.to_ascii_uppercase() - b'A') as u32 + 10;
+                (rem * 100 + v) % 97
+            },
+            _ => return None,
+        };
+    }
+    Some(rem)
+}
+
+fn mod97(s: &str) -> bool {
+    let mut bytes = s.bytes().filter(|b| !is_separator(*b)).peekable();
+    bytes.peek().is_some() && mod97_update(0, bytes) == Some(1)
+}
+
+fn iban(s: &str) -> bool {
+    let bytes: Vec<u8> = s.bytes().filter(|b| *b != b' ').collect();
+    let valid_format = (15..=34).contains(&bytes.len())
+        && bytes[..2].iter().all(|b| b.is_ascii_alphabetic())
+        && bytes[2..4].iter().all(|b| b.is_ascii_digit());
+    if !valid_format {
+        return false;
+    }
+    let (head, tail) = bytes.split_at(4);
+    mod97_update(0, tail.iter().chain(head).copied()) == Some(1)
+}
+
+fn weighted(s: &str, weights: &[u32], modulus: u32) -> bool {
+    let mut sum = 0u64;
+    let mut weights = weights.iter().cycle();
+    let mut any = false;
+    for b in s.bytes().filter(|b| !is_separator(*b)) {
+        if !b.is_ascii_digit() {
+            return false;
+        }
+        sum += (b - b'0') as u64 * *weights.next().unwrap() as u64;
+        any = true;
+    }
+    any && sum % modulus as u64 == 0
+}
+
+/// Check the identifiers in `ca` with `algorithm`. Null values remain null.
+pub fn checksum(ca: &StringChunked, algorithm: &ChecksumAlgorithm) -> PolarsResult<BooleanChunked> {
+    let out = match algorithm {
+        ChecksumAlgorithm::Luhn => ca.apply_values_generic(luhn),
+        ChecksumAlgorithm::Mod97 => ca.apply_values_generic(mod97),
+        ChecksumAlgorithm::Iban => ca.apply_values_generic(iban),
+        ChecksumAlgorithm::Weighted { weights, modulus } => {
+            polars_ensure!(
+                !weights.is_empty() && *modulus > 0,
+                ComputeError: "weighted checksum requires at least one weight and a positive modulus"
+            );
+            ca.apply_values_generic(|s| weighted(s, weights, *modulus))
+        },
+    };
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_checksum() {
+        assert!(luhn("4111 1111 1111 1111"));
+        assert!(luhn("79927398713"));
+        assert!(!luhn("79927398710"));
+        assert!(!luhn("0"));
+        assert!(!luhn("7992739871a"));
+
+        assert!(iban("GB82 WEST 1234 5698 7654 32"));
+        assert!(iban("DE89370400440532013000"));
+        assert!(!iban("GB82 WEST 1234 5698 7654 33"));
+        assert!(!iban("GB82"));
+        assert!(mod97("3214282912345698765432161182"));
+        assert!(!mod97(""));
+
+        // ISBN-10 style weights.
+        let isbn_weights = [10, 9, 8, 7, 6, 5, 4, 3, 2, 1];
+        assert!(weighted("0-306-40615-2", &isbn_weights, 11));
+        assert!(!weighted("0-306-40615-3", &isbn_weights, 11));
+        assert!(!weighted("", &isbn_weights, 11));
+    }
+}
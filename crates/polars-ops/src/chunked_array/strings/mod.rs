@@ -1,5 +1,9 @@
 #[cfg(feature = "strings")]
 mod case;
+#[cfg(all(feature = "strings", feature = "dtype-categorical"))]
+mod categorical;
+#[cfg(feature = "string_checksum")]
+mod checksum;
 #[cfg(feature = "strings")]
 mod concat;
 #[cfg(feature = "strings")]
@@ -10,6 +14,8 @@ mod find_many;
 mod json_path;
 #[cfg(feature = "strings")]
 mod namespace;
+#[cfg(feature = "string_normalize")]
+mod normalize;
 #[cfg(feature = "string_pad")]
 mod pad;
 #[cfg(feature = "string_reverse")]
@@ -24,6 +30,10 @@ mod substring;
 #[cfg(all(not(feature = "nightly"), feature = "strings"))]
 mod unicode_internals;
 
+#[cfg(all(feature = "strings", feature = "dtype-categorical"))]
+pub use categorical::*;
+#[cfg(feature = "string_checksum")]
+pub use checksum::{checksum, ChecksumAlgorithm};
 #[cfg(feature = "strings")]
 pub use concat::*;
 #[cfg(feature = "find_many")]
@@ -32,6 +42,8 @@ pub use find_many::*;
 pub use json_path::*;
 #[cfg(feature = "strings")]
 pub use namespace::*;
+#[cfg(feature = "string_normalize")]
+pub use normalize::UnicodeForm;
 use polars_core::prelude::*;
 #[cfg(feature = "strings")]
 pub use split::*;
@@ -457,21 +457,33 @@ pub trait StringNameSpaceImpl: AsString {
     fn split_exact(&self, by: &StringChunked, n: usize) -> PolarsResult<StructChunked> {
         let ca = self.as_string();
 
-        split_to_struct(ca, by, n + 1, str::split, false)
+        split_to_struct(ca, by, n + 1, str::split, false, SplitFill::Null)
     }
 
     #[cfg(feature = "dtype-struct")]
     fn split_exact_inclusive(&self, by: &StringChunked, n: usize) -> PolarsResult<StructChunked> {
         let ca = self.as_string();
 
-        split_to_struct(ca, by, n + 1, str::split_inclusive, false)
+        split_to_struct(ca, by, n + 1, str::split_inclusive, false, SplitFill::Null)
     }
 
     #[cfg(feature = "dtype-struct")]
     fn splitn(&self, by: &StringChunked, n: usize) -> PolarsResult<StructChunked> {
+        self.splitn_with_fill(by, n, SplitFill::Null)
+    }
+
+    /// Split into at most `n` parts, like [`StringNameSpaceImpl::splitn`], and fill the fields
+    /// of strings with fewer parts according to `fill`.
+    #[cfg(feature = "dtype-struct")]
+    fn splitn_with_fill(
+        &self,
+        by: &StringChunked,
+        n: usize,
+        fill: SplitFill,
+    ) -> PolarsResult<StructChunked> {
         let ca = self.as_string();
 
-        split_to_struct(ca, by, n, |s, by| s.splitn(n, by), true)
+        split_to_struct(ca, by, n, |s, by| s.splitn(n, by), true, fill)
     }
 
     fn split(&self, by: &StringChunked) -> ListChunked {
@@ -592,6 +604,29 @@ pub trait StringNameSpaceImpl: AsString {
         ca + other
     }
 
+    /// Validate identifiers such as credit card numbers or IBANs with their check digits.
+    #[cfg(feature = "string_checksum")]
+    fn checksum(&self, algorithm: &ChecksumAlgorithm) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_string();
+        checksum::checksum(ca, algorithm)
+    }
+
+    /// Apply one of the Unicode normalization forms.
+    #[must_use]
+    #[cfg(feature = "string_normalize")]
+    fn str_normalize(&self, form: UnicodeForm) -> StringChunked {
+        let ca = self.as_string();
+        normalize::normalize(ca, form)
+    }
+
+    /// Apply full Unicode case folding, for case-insensitive comparisons.
+    #[must_use]
+    #[cfg(feature = "string_normalize")]
+    fn str_casefold(&self) -> StringChunked {
+        let ca = self.as_string();
+        normalize::casefold(ca)
+    }
+
     /// Reverses the string values
     #[must_use]
     #[cfg(feature = "string_reverse")]
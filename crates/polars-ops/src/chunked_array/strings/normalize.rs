@@ -0,0 +1,104 @@
+use caseless::Caseless;
+use polars_core::prelude::StringChunked;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use unicode_normalization::{
+    is_nfc_quick, is_nfd_quick, is_nfkc_quick, is_nfkd_quick, IsNormalized, UnicodeNormalization,
+};
+
+/// The Unicode normalization forms of [UAX #15](https://www.unicode.org/reports/tr15/).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UnicodeForm {
+    /// Canonical decomposition, followed by canonical composition.
+    NFC,
+    /// Canonical decomposition.
+    NFD,
+    /// Compatibility decomposition, followed by canonical composition.
+    NFKC,
+    /// Compatibility decomposition.
+    NFKD,
+}
+
+fn is_normalized(s: &str, form: UnicodeForm) -> bool {
+    let chars = s.chars();
+    let quick = match form {
+        UnicodeForm::NFC => is_nfc_quick(chars),
+        UnicodeForm::NFD => is_nfd_quick(chars),
+        UnicodeForm::NFKC => is_nfkc_quick(chars),
+        UnicodeForm::NFKD => is_nfkd_quick(chars),
+    };
+    // `Maybe` needs the full normalization to decide.
+    quick == IsNormalized::Yes
+}
+
+fn normalize_helper(s: &str, form: UnicodeForm, buf: &mut String) {
+    // ASCII and most other text is already normalized, don't rebuild it.
+    if s.is_ascii() || is_normalized(s, form) {
+        buf.push_str(s);
+        return;
+    }
+    match form {
+        UnicodeForm::NFC => buf.extend(s.nfc()),
+        UnicodeForm::NFD => buf.extend(s.nfd()),
+        UnicodeForm::NFKC => buf.extend(s.nfkc()),
+        UnicodeForm::NFKD => buf.extend(s.nfkd()),
+    }
+}
+
+pub fn normalize(ca: &StringChunked, form: UnicodeForm) -> StringChunked {
+    ca.apply_to_buffer(|s, buf| normalize_helper(s, form, buf))
+}
+
+fn casefold_helper(s: &str, buf: &mut String) {
+    if s.is_ascii() {
+        // Case folding of ASCII is lowercasing.
+        buf.extend(s.bytes().map(|b| b.to_ascii_lowercase() as char));
+    } else {
+        buf.extend(s.chars().default_case_fold());
+    }
+}
+
+/// Apply full Unicode case folding, e.g. `"Straße"` becomes `"strasse"`.
+///
+/// Unlike lowercasing, case folding maps all case variants of a string to the same
+/// string, which makes it suitable for case-insensitive comparisons.
+pub fn casefold(ca: &StringChunked) -> StringChunked {
+    ca.apply_to_buffer(casefold_helper)
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_normalize_and_casefold() {
+        // "é" precomposed and as "e" followed by a combining acute accent.
+        let ca = StringChunked::new("a", &[Some("caf\u{e9}"), Some("cafe\u{301}"), None]);
+        let nfc = normalize(&ca, UnicodeForm::NFC);
+        assert_eq!(
+            Vec::from(&nfc),
+            &[Some("caf\u{e9}"), Some("caf\u{e9}"), None]
+        );
+        let nfd = normalize(&ca, UnicodeForm::NFD);
+        assert_eq!(
+            Vec::from(&nfd),
+            &[Some("cafe\u{301}"), Some("cafe\u{301}"), None]
+        );
+
+        let ca = StringChunked::new("a", &["\u{fb01}le", "x\u{b2}"]);
+        let nfkc = normalize(&ca, UnicodeForm::NFKC);
+        assert_eq!(Vec::from(&nfkc), &[Some("file"), Some("x2")]);
+        let nfc = normalize(&ca, UnicodeForm::NFC);
+        assert_eq!(Vec::from(&nfc), &[Some("\u{fb01}le"), Some("x\u{b2}")]);
+
+        let ca = StringChunked::new("a", &[Some("Straße"), Some("STRASSE"), Some("ΣΑΣ"), None]);
+        let folded = casefold(&ca);
+        assert_eq!(
+            Vec::from(&folded),
+            &[Some("strasse"), Some("strasse"), Some("σασ"), None]
+        );
+    }
+}
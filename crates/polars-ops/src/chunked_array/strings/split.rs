@@ -2,6 +2,8 @@ use arrow::array::ValueSize;
 #[cfg(feature = "dtype-struct")]
 use arrow::array::{MutableArray, MutableUtf8Array};
 use polars_core::chunked_array::ops::arity::binary_elementwise_for_each;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use super::*;
 
@@ -53,6 +55,42 @@ fn split_chars(s: &str) -> SplitNChars<'_> {
     }
 }
 
+/// How to fill the fields of a split into a struct for which a string has no part.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SplitFill {
+    /// Fill the missing parts with null.
+    #[default]
+    Null,
+    /// Fill the missing parts with an empty string.
+    Empty,
+    /// Raise an error if a string has missing parts.
+    Error,
+}
+
+/// Push the parts of a string to the fields. Returns whether the string had too few parts.
+#[cfg(feature = "dtype-struct")]
+fn push_parts<'a>(
+    arrs: &mut [MutableUtf8Array<i64>],
+    parts: impl Iterator<Item = &'a str>,
+    fill: SplitFill,
+) -> bool {
+    let mut arr_iter = arrs.iter_mut();
+    parts
+        .zip(&mut arr_iter)
+        .for_each(|(splitted, arr)| arr.push(Some(splitted)));
+
+    let mut missing = false;
+    for arr in arr_iter {
+        missing = true;
+        match fill {
+            SplitFill::Empty => arr.push(Some("")),
+            SplitFill::Null | SplitFill::Error => arr.push_null(),
+        }
+    }
+    missing
+}
+
 #[cfg(feature = "dtype-struct")]
 pub fn split_to_struct<'a, F, I>(
     ca: &'a StringChunked,
@@ -60,6 +98,7 @@ pub fn split_to_struct<'a, F, I>(
     n: usize,
     op: F,
     keep_remainder: bool,
+    fill: SplitFill,
 ) -> PolarsResult<StructChunked>
 where
     F: Fn(&'a str, &'a str) -> I,
@@ -68,6 +107,7 @@ where
     let mut arrs = (0..n)
         .map(|_| MutableUtf8Array::<i64>::with_capacity(ca.len()))
         .collect::<Vec<_>>();
+    let mut missing = false;
 
     if by.len() == 1 {
         if let Some(by) = by.get(0) {
@@ -79,14 +119,7 @@ where
                         }
                     },
                     Some(s) => {
-                        let mut arr_iter = arrs.iter_mut();
-                        splitn_chars(s, n, keep_remainder)
-                            .zip(&mut arr_iter)
-                            .for_each(|(splitted, arr)| arr.push(Some(splitted)));
-                        // fill the remaining with null
-                        for arr in arr_iter {
-                            arr.push_null()
-                        }
+                        missing |= push_parts(&mut arrs, splitn_chars(s, n, keep_remainder), fill)
                     },
                 });
             } else {
@@ -96,16 +129,7 @@ where
                             arr.push_null()
                         }
                     },
-                    Some(s) => {
-                        let mut arr_iter = arrs.iter_mut();
-                        op(s, by)
-                            .zip(&mut arr_iter)
-                            .for_each(|(splitted, arr)| arr.push(Some(splitted)));
-                        // fill the remaining with null
-                        for arr in arr_iter {
-                            arr.push_null()
-                        }
-                    },
+                    Some(s) => missing |= push_parts(&mut arrs, op(s, by), fill),
                 });
             }
         } else {
@@ -116,20 +140,11 @@ where
     } else {
         binary_elementwise_for_each(ca, by, |opt_s, opt_by| match (opt_s, opt_by) {
             (Some(s), Some(by)) => {
-                let mut arr_iter = arrs.iter_mut();
-                if by.is_empty() {
-                    splitn_chars(s, n, keep_remainder)
-                        .zip(&mut arr_iter)
-                        .for_each(|(splitted, arr)| arr.push(Some(splitted)));
+                missing |= if by.is_empty() {
+                    push_parts(&mut arrs, splitn_chars(s, n, keep_remainder), fill)
                 } else {
-                    op(s, by)
-                        .zip(&mut arr_iter)
-                        .for_each(|(splitted, arr)| arr.push(Some(splitted)));
+                    push_parts(&mut arrs, op(s, by), fill)
                 };
-                // fill the remaining with null
-                for arr in arr_iter {
-                    arr.push_null()
-                }
             },
             _ => {
                 for arr in &mut arrs {
@@ -139,6 +154,11 @@ where
         })
     }
 
+    polars_ensure!(
+        !(missing && fill == SplitFill::Error),
+        ComputeError: "not all strings could be split into {} parts", n
+    );
+
     let fields = arrs
         .into_iter()
         .enumerate()
@@ -283,6 +283,7 @@ fn top_k_by_impl(
         nulls_last: vec![true; by.len()],
         multithreaded,
         maintain_order: false,
+        ..Default::default()
     };
 
     let idx = _arg_bottom_k(k, by, &mut sort_options)?;
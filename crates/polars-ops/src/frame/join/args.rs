@@ -27,6 +27,7 @@ pub struct JoinArgs {
     pub slice: Option<(i64, usize)>,
     pub join_nulls: bool,
     pub coalesce: JoinCoalesce,
+    pub hints: JoinHints,
 }
 
 impl JoinArgs {
@@ -73,6 +74,7 @@ impl Default for JoinArgs {
             slice: None,
             join_nulls: false,
             coalesce: Default::default(),
+            hints: Default::default(),
         }
     }
 }
@@ -86,6 +88,7 @@ impl JoinArgs {
             slice: None,
             join_nulls: false,
             coalesce: Default::default(),
+            hints: Default::default(),
         }
     }
 
@@ -99,11 +102,136 @@ impl JoinArgs {
         self
     }
 
+    pub fn with_hints(mut self, hints: JoinHints) -> Self {
+        self.hints = hints;
+        self
+    }
+
     pub fn suffix(&self) -> &str {
         self.suffix.as_deref().unwrap_or("_right")
     }
 }
 
+/// The algorithm used to execute a join.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoinStrategy {
+    /// Let the engine decide based on the sortedness and sizes of the inputs.
+    #[default]
+    Auto,
+    /// Always use a hash join, even if the keys are sorted.
+    Hash,
+    /// Merge the sorted keys, sorting them first if needed. Only supported for inner and left
+    /// joins on a single numeric key without nulls.
+    SortMerge,
+    /// Hash join that builds the hash table on the left table, which is expected to be small,
+    /// and probes it with the right table regardless of the estimated sizes. Only supported for
+    /// inner joins.
+    BroadcastLeft,
+    /// Hash join that builds the hash table on the right table, which is expected to be small,
+    /// and probes it with the left table regardless of the estimated sizes.
+    BroadcastRight,
+}
+
+/// The input of a hash join that is collected into the hash table.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoinBuildSide {
+    Left,
+    Right,
+}
+
+/// Hints that override the heuristics the engines use to execute a single join.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JoinHints {
+    pub strategy: JoinStrategy,
+    /// The input to build the hash table on. By default the smaller input of an inner join and
+    /// the right input of other joins is used.
+    pub build_side: Option<JoinBuildSide>,
+    /// Whether the inputs of the join are computed in parallel. By default the optimizer decides.
+    pub parallel: Option<bool>,
+}
+
+impl JoinHints {
+    pub fn with_strategy(mut self, strategy: JoinStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    pub fn with_build_side(mut self, build_side: JoinBuildSide) -> Self {
+        self.build_side = Some(build_side);
+        self
+    }
+
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = Some(parallel);
+        self
+    }
+
+    /// The build side the hints force on a hash join, if any.
+    pub fn forced_build_side(&self) -> Option<JoinBuildSide> {
+        match self.strategy {
+            JoinStrategy::BroadcastLeft => Some(JoinBuildSide::Left),
+            JoinStrategy::BroadcastRight => Some(JoinBuildSide::Right),
+            _ => self.build_side,
+        }
+    }
+
+    /// Check that the hints can be honored for a join of type `how` on `n_keys` key columns.
+    ///
+    /// Semi and anti joins always build the hash table on the right input.
+    pub fn validate(&self, how: &JoinType, n_keys: usize) -> PolarsResult<()> {
+        use JoinStrategy::*;
+        match self.strategy {
+            Auto => {},
+            Hash => polars_ensure!(
+                !matches!(how, JoinType::Cross) && !how.is_asof(),
+                InvalidOperation: "hash strategy is not supported for a {} join", how
+            ),
+            SortMerge => {
+                polars_ensure!(
+                    matches!(how, JoinType::Inner | JoinType::Left),
+                    InvalidOperation: "sort-merge strategy is not supported for a {} join", how
+                );
+                polars_ensure!(
+                    n_keys == 1,
+                    InvalidOperation: "sort-merge strategy is not supported for a join on multiple keys"
+                );
+            },
+            BroadcastLeft => polars_ensure!(
+                matches!(how, JoinType::Inner),
+                InvalidOperation: "left broadcast strategy is not supported for a {} join", how
+            ),
+            BroadcastRight => polars_ensure!(
+                !matches!(how, JoinType::Cross | JoinType::Full) && !how.is_asof(),
+                InvalidOperation: "right broadcast strategy is not supported for a {} join", how
+            ),
+        }
+        match self.build_side {
+            None => {},
+            Some(_) if matches!(self.strategy, SortMerge) => polars_bail!(
+                InvalidOperation: "a build side can't be set for a sort-merge join"
+            ),
+            Some(build_side) if matches!(self.strategy, BroadcastLeft | BroadcastRight) => {
+                polars_ensure!(
+                    self.forced_build_side() == Some(build_side),
+                    InvalidOperation: "the build side contradicts the broadcast strategy"
+                )
+            },
+            Some(JoinBuildSide::Left) => polars_ensure!(
+                matches!(how, JoinType::Inner),
+                InvalidOperation: "the left input can't be the build side of a {} join", how
+            ),
+            Some(JoinBuildSide::Right) => polars_ensure!(
+                !matches!(how, JoinType::Cross | JoinType::Full) && !how.is_asof(),
+                InvalidOperation: "the right input can't be the build side of a {} join", how
+            ),
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum JoinType {
@@ -177,6 +305,9 @@ pub enum JoinValidation {
     OneToOne,
 }
 
+/// The start of the error that a failed [`JoinValidation`] raises.
+pub(super) const VALIDATION_FAILED: &str = "the join keys did not fulfil";
+
 impl JoinValidation {
     pub fn needs_checks(&self) -> bool {
         !matches!(self, JoinValidation::ManyToMany)
@@ -209,16 +340,14 @@ impl JoinValidation {
         &self,
         s_left: &Series,
         s_right: &Series,
-        build_shortest_table: bool,
+        should_swap: bool,
     ) -> PolarsResult<()> {
         // In default, probe is the left series.
         //
-        // In inner join and outer join, the shortest relation will be used to create a hash table.
-        // In left join, always use the right side to create.
+        // In inner join and outer join, the shortest relation will be used to create a hash table
+        // unless a build side is forced. In left join, always use the right side to create.
         //
-        // If `build_shortest_table` and left is shorter, swap. Then rhs will be the probe.
-        // If left == right, swap too. (apply the same logic as `det_hash_prone_order`)
-        let should_swap = build_shortest_table && s_left.len() <= s_right.len();
+        // If `should_swap`, the lhs is the build side. Then rhs will be the probe.
         let probe = if should_swap { s_right } else { s_left };
 
         use JoinValidation::*;
@@ -228,7 +357,7 @@ impl JoinValidation {
             ManyToMany | ManyToOne => true,
             OneToMany | OneToOne => probe.n_unique()? == probe.len(),
         };
-        polars_ensure!(valid, ComputeError: "the join keys did not fulfil {} validation", self);
+        polars_ensure!(valid, ComputeError: "{} {} validation", VALIDATION_FAILED, self);
         Ok(())
     }
 
@@ -247,7 +376,7 @@ impl JoinValidation {
             ManyToMany | OneToMany => true,
             ManyToOne | OneToOne => build_size == expected_size,
         };
-        polars_ensure!(valid, ComputeError: "the join keys did not fulfil {} validation", self);
+        polars_ensure!(valid, ComputeError: "{} {} validation", VALIDATION_FAILED, self);
         Ok(())
     }
 }
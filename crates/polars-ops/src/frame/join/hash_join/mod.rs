@@ -13,6 +13,7 @@ use polars_utils::index::ChunkId;
 pub(super) use single_keys::*;
 #[cfg(feature = "asof_join")]
 pub(super) use single_keys_dispatch::prepare_bytes;
+pub(crate) use single_keys_dispatch::to_physical_key;
 pub use single_keys_dispatch::SeriesJoin;
 use single_keys_inner::*;
 use single_keys_left::*;
@@ -199,7 +200,14 @@ pub trait JoinDispatch: IntoDf {
             s_right = s_right.rechunk();
         }
 
-        let ids = sort_or_hash_left(&s_left, &s_right, verbose, args.validation, args.join_nulls)?;
+        let ids = sort_or_hash_left(
+            &s_left,
+            &s_right,
+            verbose,
+            args.validation,
+            args.join_nulls,
+            &args.hints,
+        )?;
         let right = if let Some(drop_names) = drop_names {
             right.drop_many(drop_names)
         } else {
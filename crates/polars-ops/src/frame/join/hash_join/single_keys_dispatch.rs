@@ -8,6 +8,15 @@ use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
 use super::*;
 use crate::series::SeriesSealed;
 
+/// The physical representation of a join key, in which equal values have equal bits.
+pub(crate) fn to_physical_key(s: &Series) -> Cow<'_, Series> {
+    match s.dtype() {
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => Cow::Owned(s.f16().unwrap().to_canonical().0.into_series()),
+        _ => s.to_physical_repr(),
+    }
+}
+
 pub trait SeriesJoin: SeriesSealed + Sized {
     #[doc(hidden)]
     fn hash_join_left(
@@ -17,7 +26,7 @@ pub trait SeriesJoin: SeriesSealed + Sized {
         join_nulls: bool,
     ) -> PolarsResult<LeftJoinIds> {
         let s_self = self.as_series();
-        let (lhs, rhs) = (s_self.to_physical_repr(), other.to_physical_repr());
+        let (lhs, rhs) = (to_physical_key(s_self), to_physical_key(other));
         validate.validate_probe(&lhs, &rhs, false)?;
 
         use DataType::*;
@@ -65,7 +74,7 @@ pub trait SeriesJoin: SeriesSealed + Sized {
     #[cfg(feature = "semi_anti_join")]
     fn hash_join_semi_anti(&self, other: &Series, anti: bool, join_nulls: bool) -> Vec<IdxSize> {
         let s_self = self.as_series();
-        let (lhs, rhs) = (s_self.to_physical_repr(), other.to_physical_repr());
+        let (lhs, rhs) = (to_physical_key(s_self), to_physical_key(other));
 
         use DataType::*;
 
@@ -124,10 +133,17 @@ pub trait SeriesJoin: SeriesSealed + Sized {
         other: &Series,
         validate: JoinValidation,
         join_nulls: bool,
+        build_side: Option<JoinBuildSide>,
     ) -> PolarsResult<(InnerJoinIds, bool)> {
         let s_self = self.as_series();
-        let (lhs, rhs) = (s_self.to_physical_repr(), other.to_physical_repr());
-        validate.validate_probe(&lhs, &rhs, true)?;
+        let (lhs, rhs) = (to_physical_key(s_self), to_physical_key(other));
+        // By default the shortest relation is used to create the hash table.
+        let swap = match build_side {
+            Some(JoinBuildSide::Left) => true,
+            Some(JoinBuildSide::Right) => false,
+            None => lhs.len() <= rhs.len(),
+        };
+        validate.validate_probe(&lhs, &rhs, swap)?;
 
         use DataType::*;
         match lhs.dtype() {
@@ -136,7 +152,7 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = rhs.cast(&Binary).unwrap();
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                let (lhs, rhs, swapped, _) = prepare_binary::<BinaryType>(lhs, rhs, true);
+                let (lhs, rhs, swapped, _) = prepare_binary::<BinaryType>(lhs, rhs, swap);
                 // Take slices so that vecs are not copied
                 let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
                 let rhs = rhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
@@ -148,7 +164,7 @@ pub trait SeriesJoin: SeriesSealed + Sized {
             BinaryOffset => {
                 let lhs = lhs.binary_offset().unwrap();
                 let rhs = rhs.binary_offset()?;
-                let (lhs, rhs, swapped, _) = prepare_binary::<BinaryOffsetType>(lhs, rhs, true);
+                let (lhs, rhs, swapped, _) = prepare_binary::<BinaryOffsetType>(lhs, rhs, swap);
                 // Take slices so that vecs are not copied
                 let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
                 let rhs = rhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
@@ -162,16 +178,16 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                     with_match_physical_float_polars_type!(lhs.dtype(), |$T| {
                         let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref().as_ref();
                         let rhs: &ChunkedArray<$T> = rhs.as_ref().as_ref().as_ref();
-                        group_join_inner::<$T>(lhs, rhs, validate, join_nulls)
+                        group_join_inner::<$T>(lhs, rhs, validate, join_nulls, swap)
                     })
                 } else if s_self.bit_repr_is_large() {
                     let lhs = s_self.bit_repr_large();
                     let rhs = other.bit_repr_large();
-                    group_join_inner::<UInt64Type>(&lhs, &rhs, validate, join_nulls)
+                    group_join_inner::<UInt64Type>(&lhs, &rhs, validate, join_nulls, swap)
                 } else {
                     let lhs = s_self.bit_repr_small();
                     let rhs = other.bit_repr_small();
-                    group_join_inner::<UInt32Type>(&lhs, &rhs, validate, join_nulls)
+                    group_join_inner::<UInt32Type>(&lhs, &rhs, validate, join_nulls, swap)
                 }
             },
         }
@@ -184,8 +200,9 @@ pub trait SeriesJoin: SeriesSealed + Sized {
         join_nulls: bool,
     ) -> PolarsResult<(PrimitiveArray<IdxSize>, PrimitiveArray<IdxSize>)> {
         let s_self = self.as_series();
-        let (lhs, rhs) = (s_self.to_physical_repr(), other.to_physical_repr());
-        validate.validate_probe(&lhs, &rhs, true)?;
+        let (lhs, rhs) = (to_physical_key(s_self), to_physical_key(other));
+        let swap = lhs.len() <= rhs.len();
+        validate.validate_probe(&lhs, &rhs, swap)?;
 
         use DataType::*;
         match lhs.dtype() {
@@ -194,7 +211,7 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = rhs.cast(&Binary).unwrap();
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                let (lhs, rhs, swapped, _) = prepare_binary::<BinaryType>(lhs, rhs, true);
+                let (lhs, rhs, swapped, _) = prepare_binary::<BinaryType>(lhs, rhs, swap);
                 // Take slices so that vecs are not copied
                 let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
                 let rhs = rhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
@@ -203,7 +220,7 @@ pub trait SeriesJoin: SeriesSealed + Sized {
             BinaryOffset => {
                 let lhs = lhs.binary_offset().unwrap();
                 let rhs = rhs.binary_offset()?;
-                let (lhs, rhs, swapped, _) = prepare_binary::<BinaryOffsetType>(lhs, rhs, true);
+                let (lhs, rhs, swapped, _) = prepare_binary::<BinaryOffsetType>(lhs, rhs, swap);
                 // Take slices so that vecs are not copied
                 let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
                 let rhs = rhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
@@ -251,6 +268,7 @@ fn group_join_inner<T>(
     right: &ChunkedArray<T>,
     validate: JoinValidation,
     join_nulls: bool,
+    swap: bool,
 ) -> PolarsResult<(InnerJoinIds, bool)>
 where
     T: PolarsDataType,
@@ -261,7 +279,11 @@ where
         Send + Sync + Copy + Hash + Eq + DirtyHash + IsNull,
 {
     let n_threads = POOL.current_num_threads();
-    let (a, b, swapped) = det_hash_prone_order!(left, right);
+    let (a, b, swapped) = if swap {
+        (right, left, true)
+    } else {
+        (left, right, false)
+    };
     let splitted_a = split(a, n_threads);
     let splitted_b = split(b, n_threads);
     let splitted_a = get_arrays(&splitted_a);
@@ -458,9 +480,8 @@ pub fn prepare_bytes<'a>(
 fn prepare_binary<'a, T>(
     ca: &'a ChunkedArray<T>,
     other: &'a ChunkedArray<T>,
-    // In inner join and outer join, the shortest relation will be used to create a hash table.
-    // In left join, always use the right side to create.
-    build_shortest_table: bool,
+    // Whether the lhs is the build side. Otherwise the rhs is used to create the hash table.
+    swap: bool,
 ) -> (
     Vec<Vec<BytesHash<'a>>>,
     Vec<Vec<BytesHash<'a>>>,
@@ -471,8 +492,8 @@ where
     T: PolarsDataType,
     for<'b> <T::Array as StaticArray>::ValueT<'b>: AsRef<[u8]>,
 {
-    let (a, b, swapped) = if build_shortest_table {
-        det_hash_prone_order!(ca, other)
+    let (a, b, swapped) = if swap {
+        (other, ca, true)
     } else {
         (ca, other, false)
     };
@@ -6,6 +6,8 @@ use polars_core::utils::_split_offsets;
 use polars_core::utils::flatten::flatten_par;
 
 use super::*;
+#[cfg(feature = "performant")]
+use crate::series::SeriesMethods;
 
 #[cfg(feature = "performant")]
 fn par_sorted_merge_left_impl<T>(
@@ -178,8 +180,29 @@ pub(crate) fn _sort_or_hash_inner(
     _verbose: bool,
     validate: JoinValidation,
     join_nulls: bool,
+    hints: &JoinHints,
 ) -> PolarsResult<(InnerJoinIds, bool)> {
-    s_left.hash_join_inner(s_right, validate, join_nulls)
+    polars_ensure!(
+        hints.strategy != JoinStrategy::SortMerge,
+        InvalidOperation: "sort-merge joins require the 'performant' feature"
+    );
+    s_left.hash_join_inner(s_right, validate, join_nulls, hints.forced_build_side())
+}
+
+#[cfg(feature = "performant")]
+fn ensure_sort_merge_possible(
+    s_left: &Series,
+    s_right: &Series,
+    validate: JoinValidation,
+) -> PolarsResult<()> {
+    polars_ensure!(
+        s_left.dtype().to_physical().is_numeric()
+            && s_left.null_count() == 0
+            && s_right.null_count() == 0
+            && !validate.needs_checks(),
+        InvalidOperation: "a sort-merge join requires a single numeric join key without nulls and no validation"
+    );
+    Ok(())
 }
 
 #[cfg(feature = "performant")]
@@ -189,22 +212,32 @@ pub(crate) fn _sort_or_hash_inner(
     verbose: bool,
     validate: JoinValidation,
     join_nulls: bool,
+    hints: &JoinHints,
 ) -> PolarsResult<(InnerJoinIds, bool)> {
+    let force_sort_merge = hints.strategy == JoinStrategy::SortMerge;
+    if force_sort_merge {
+        ensure_sort_merge_possible(s_left, s_right, validate)?;
+    }
+    let build_side = hints.forced_build_side();
+    if validate.needs_checks() || hints.strategy == JoinStrategy::Hash || build_side.is_some() {
+        return s_left.hash_join_inner(s_right, validate, join_nulls, build_side);
+    }
+
     // We check if keys are sorted.
     // - If they are we can do a sorted merge join
     // If one of the keys is not, it can still be faster to sort that key and use
     // the `arg_sort` indices to revert the sort once the join keys are determined.
     let size_factor_rhs = s_right.len() as f32 / s_left.len() as f32;
     let size_factor_lhs = s_left.len() as f32 / s_right.len() as f32;
-    let size_factor_acceptable = std::env::var("POLARS_JOIN_SORT_FACTOR")
-        .map(|s| s.parse::<f32>().unwrap())
-        .unwrap_or(1.0);
+    let size_factor_acceptable = if force_sort_merge {
+        f32::INFINITY
+    } else {
+        std::env::var("POLARS_JOIN_SORT_FACTOR")
+            .map(|s| s.parse::<f32>().unwrap())
+            .unwrap_or(1.0)
+    };
     let is_numeric = s_left.dtype().to_physical().is_numeric();
 
-    if validate.needs_checks() {
-        return s_left.hash_join_inner(s_right, validate, join_nulls);
-    }
-
     let no_nulls = s_left.null_count() == 0 && s_right.null_count() == 0;
     match (s_left.is_sorted_flag(), s_right.is_sorted_flag(), no_nulls) {
         (IsSorted::Ascending, IsSorted::Ascending, true) if is_numeric => {
@@ -225,6 +258,7 @@ pub(crate) fn _sort_or_hash_inner(
                 nulls_last: false,
                 multithreaded: true,
                 maintain_order: false,
+                ..Default::default()
             });
             let s_right = unsafe { s_right.take_unchecked(&sort_idx) };
             let ids = par_sorted_merge_inner_no_nulls(s_left, &s_right);
@@ -252,6 +286,7 @@ pub(crate) fn _sort_or_hash_inner(
                 nulls_last: false,
                 multithreaded: true,
                 maintain_order: false,
+                ..Default::default()
             });
             let s_left = unsafe { s_left.take_unchecked(&sort_idx) };
             let ids = par_sorted_merge_inner_no_nulls(&s_left, s_right);
@@ -268,7 +303,34 @@ pub(crate) fn _sort_or_hash_inner(
             // set sorted to `false` as we descending sorted the left key.
             Ok(((left, right), false))
         },
-        _ => s_left.hash_join_inner(s_right, validate, join_nulls),
+        _ if force_sort_merge => {
+            if verbose {
+                eprintln!("left and right key will be sorted in inner join operation.")
+            }
+
+            // Sort the left key, the right key is then sorted by the branch above.
+            let sort_idx = s_left.arg_sort(SortOptions {
+                descending: false,
+                nulls_last: false,
+                multithreaded: true,
+                maintain_order: false,
+                ..Default::default()
+            });
+            let mut s_left = unsafe { s_left.take_unchecked(&sort_idx) };
+            s_left.set_sorted_flag(IsSorted::Ascending);
+            let ((mut left, right), _) =
+                _sort_or_hash_inner(&s_left, s_right, verbose, validate, join_nulls, hints)?;
+            let reverse_idx_map = create_reverse_map_from_arg_sort(sort_idx);
+
+            POOL.install(|| {
+                left.par_iter_mut().for_each(|idx| {
+                    *idx = unsafe { *reverse_idx_map.get_unchecked(*idx as usize) };
+                });
+            });
+
+            Ok(((left, right), false))
+        },
+        _ => s_left.hash_join_inner(s_right, validate, join_nulls, None),
     }
 }
 
@@ -279,7 +341,12 @@ pub(crate) fn sort_or_hash_left(
     _verbose: bool,
     validate: JoinValidation,
     join_nulls: bool,
+    hints: &JoinHints,
 ) -> PolarsResult<LeftJoinIds> {
+    polars_ensure!(
+        hints.strategy != JoinStrategy::SortMerge,
+        InvalidOperation: "sort-merge joins require the 'performant' feature"
+    );
     s_left.hash_join_left(s_right, validate, join_nulls)
 }
 
@@ -290,15 +357,40 @@ pub(crate) fn sort_or_hash_left(
     verbose: bool,
     validate: JoinValidation,
     join_nulls: bool,
+    hints: &JoinHints,
 ) -> PolarsResult<LeftJoinIds> {
-    if validate.needs_checks() {
+    let force_sort_merge = hints.strategy == JoinStrategy::SortMerge;
+    let sorted_left;
+    let mut s_left = s_left;
+    if force_sort_merge {
+        ensure_sort_merge_possible(s_left, s_right, validate)?;
+        if s_left.is_sorted_flag() != IsSorted::Ascending {
+            // The output order follows the left keys, so we can't sort those.
+            polars_ensure!(
+                s_left.is_sorted(Default::default())?,
+                InvalidOperation: "a sort-merge left join requires the left join keys to be sorted"
+            );
+            let mut s = s_left.clone();
+            s.set_sorted_flag(IsSorted::Ascending);
+            sorted_left = s;
+            s_left = &sorted_left;
+        }
+    }
+    if validate.needs_checks()
+        || hints.strategy == JoinStrategy::Hash
+        || hints.forced_build_side().is_some()
+    {
         return s_left.hash_join_left(s_right, validate, join_nulls);
     }
 
     let size_factor_rhs = s_right.len() as f32 / s_left.len() as f32;
-    let size_factor_acceptable = std::env::var("POLARS_JOIN_SORT_FACTOR")
-        .map(|s| s.parse::<f32>().unwrap())
-        .unwrap_or(1.0);
+    let size_factor_acceptable = if force_sort_merge {
+        f32::INFINITY
+    } else {
+        std::env::var("POLARS_JOIN_SORT_FACTOR")
+            .map(|s| s.parse::<f32>().unwrap())
+            .unwrap_or(1.0)
+    };
     let is_numeric = s_left.dtype().to_physical().is_numeric();
 
     let no_nulls = s_left.null_count() == 0 && s_right.null_count() == 0;
@@ -323,6 +415,7 @@ pub(crate) fn sort_or_hash_left(
                 nulls_last: false,
                 multithreaded: true,
                 maintain_order: false,
+                ..Default::default()
             });
             let s_right = unsafe { s_right.take_unchecked(&sort_idx) };
 
@@ -1,6 +1,9 @@
 use arrow::legacy::utils::{CustomIterTools, FromTrustedLenIterator};
 use polars_core::prelude::*;
-use polars_core::with_match_physical_numeric_polars_type;
+use polars_core::{with_match_physical_numeric_polars_type, POOL};
+use rayon::prelude::*;
+
+use crate::series::SeriesMethods;
 
 pub fn _merge_sorted_dfs(
     left: &DataFrame,
@@ -46,6 +49,71 @@ pub fn _merge_sorted_dfs(
     Ok(unsafe { DataFrame::new_no_checks(new_columns) })
 }
 
+/// Merge any number of frames that are sorted by the column `by` into a single sorted
+/// frame.
+///
+/// The frames must be sorted in ascending order, with their nulls either all first or all
+/// last; the output places its nulls in the same way.
+pub fn _merge_sorted_dfs_by(dfs: &[DataFrame], by: &str) -> PolarsResult<DataFrame> {
+    polars_ensure!(!dfs.is_empty(), ComputeError: "cannot merge an empty list of frames");
+
+    let mut nulls_last = None;
+    for df in dfs {
+        let s = df.column(by)?;
+        let null_count = s.null_count();
+        // A frame without nulls or with only nulls fits both placements.
+        let df_nulls_last = (null_count > 0 && null_count < s.len())
+            .then(|| !s.get(0).is_ok_and(|av| av.is_null()));
+        if df_nulls_last.is_some() {
+            polars_ensure!(
+                nulls_last.is_none() || nulls_last == df_nulls_last,
+                InvalidOperation: "the frames to merge place the nulls of '{}' differently", by
+            );
+            nulls_last = df_nulls_last;
+        }
+        polars_ensure!(
+            s.is_sorted(SortOptions::default().with_nulls_last(df_nulls_last.unwrap_or(false)))?,
+            InvalidOperation: "the frames to merge must be sorted by '{}' in ascending order", by
+        );
+    }
+
+    if nulls_last != Some(true) {
+        return merge_sorted_dfs_by(dfs.to_vec(), by);
+    }
+    // The merge places nulls first, so the trailing nulls are appended after it.
+    let mut heads = Vec::with_capacity(dfs.len());
+    let mut tails = Vec::with_capacity(dfs.len());
+    for df in dfs {
+        let valid = df.height() - df.column(by)?.null_count();
+        let (head, tail) = df.split_at(valid as i64);
+        heads.push(head);
+        tails.push(tail);
+    }
+    let mut out = merge_sorted_dfs_by(heads, by)?;
+    for tail in &tails {
+        out.vstack_mut(tail)?;
+    }
+    Ok(out)
+}
+
+fn merge_sorted_dfs_by(mut dfs: Vec<DataFrame>, by: &str) -> PolarsResult<DataFrame> {
+    // Merge pairwise, so that every row is only moved a logarithmic number of times.
+    while dfs.len() > 1 {
+        dfs = POOL.install(|| {
+            dfs.par_chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => {
+                        _merge_sorted_dfs(left, right, left.column(by)?, right.column(by)?, true)
+                    },
+                    [df] => Ok(df.clone()),
+                    _ => unreachable!(),
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+    }
+    Ok(dfs.pop().unwrap())
+}
+
 fn merge_series(lhs: &Series, rhs: &Series, merge_indicator: &[bool]) -> Series {
     use DataType::*;
     match lhs.dtype() {
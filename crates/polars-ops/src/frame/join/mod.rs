@@ -8,6 +8,7 @@ mod general;
 mod hash_join;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
+mod report;
 
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
@@ -29,7 +30,7 @@ pub use general::{_coalesce_full_join, _finish_join, _join_suffix_name};
 pub use hash_join::*;
 use hashbrown::hash_map::{Entry, RawEntryMut};
 #[cfg(feature = "merge_sorted")]
-pub use merge_sorted::_merge_sorted_dfs;
+pub use merge_sorted::{_merge_sorted_dfs, _merge_sorted_dfs_by};
 use polars_core::hashing::_HASHMAP_INIT_SIZE;
 #[allow(unused_imports)]
 use polars_core::prelude::sort::arg_sort_multiple::{
@@ -43,6 +44,7 @@ use polars_core::utils::slice_slice;
 use polars_core::POOL;
 use polars_utils::hashing::BytesHash;
 use rayon::prelude::*;
+pub use report::JoinValidationReport;
 
 use super::IntoDf;
 
@@ -102,10 +104,69 @@ pub trait DataFrameJoinOps: IntoDf {
         self._join_impl(other, selected_left, selected_right, args, true, false)
     }
 
+    /// Find the join keys that violate `validation`, such as duplicated keys in `other` for a
+    /// many-to-one join.
+    ///
+    /// At most `limit` duplicated keys are reported per side.
+    fn join_report<I, S>(
+        &self,
+        other: &DataFrame,
+        left_on: I,
+        right_on: I,
+        validation: JoinValidation,
+        limit: usize,
+    ) -> PolarsResult<JoinValidationReport>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let selected_left = self.to_df().select_series(left_on)?;
+        let selected_right = other.select_series(right_on)?;
+        polars_ensure!(
+            selected_left.len() == selected_right.len(),
+            ComputeError: "the number of columns given as join key should be equal"
+        );
+        JoinValidationReport::new(&selected_left, &selected_right, validation, limit)
+    }
+
+    #[doc(hidden)]
+    fn _join_impl(
+        &self,
+        other: &DataFrame,
+        selected_left: Vec<Series>,
+        selected_right: Vec<Series>,
+        args: JoinArgs,
+        _check_rechunk: bool,
+        _verbose: bool,
+    ) -> PolarsResult<DataFrame> {
+        if !args.validation.needs_checks() {
+            return self._join_impl_inner(
+                other,
+                selected_left,
+                selected_right,
+                args,
+                _check_rechunk,
+                _verbose,
+            );
+        }
+        // Keep the keys around to report the offending ones if the validation fails.
+        let validation = args.validation;
+        let (left_keys, right_keys) = (selected_left.clone(), selected_right.clone());
+        self._join_impl_inner(
+            other,
+            selected_left,
+            selected_right,
+            args,
+            _check_rechunk,
+            _verbose,
+        )
+        .map_err(|err| JoinValidationReport::explain(err, &left_keys, &right_keys, validation))
+    }
+
     #[doc(hidden)]
     #[allow(clippy::too_many_arguments)]
     #[allow(unused_mut)]
-    fn _join_impl(
+    fn _join_impl_inner(
         &self,
         other: &DataFrame,
         mut selected_left: Vec<Series>,
@@ -115,6 +176,7 @@ pub trait DataFrameJoinOps: IntoDf {
         _verbose: bool,
     ) -> PolarsResult<DataFrame> {
         let left_df = self.to_df();
+        args.hints.validate(&args.how, selected_left.len())?;
         let should_coalesce = args.coalesce.coalesce(&args.how);
         assert_eq!(selected_left.len(), selected_right.len());
 
@@ -151,7 +213,7 @@ pub trait DataFrameJoinOps: IntoDf {
                     tmp_right.as_single_chunk_par();
                     right = Cow::Owned(tmp_right);
                 }
-                return left._join_impl(
+                return left._join_impl_inner(
                     &right,
                     selected_left,
                     selected_right,
@@ -189,8 +251,13 @@ pub trait DataFrameJoinOps: IntoDf {
             }
         }
 
+        // List and Array keys are hashed and compared on their row encoding, like multiple keys.
+        let row_encode_single_key = selected_left.len() == 1
+            && !args.how.is_asof()
+            && (selected_left[0].dtype().is_list() || selected_left[0].dtype().is_array());
+
         // Single keys.
-        if selected_left.len() == 1 {
+        if selected_left.len() == 1 && !row_encode_single_key {
             let s_left = &selected_left[0];
             let s_right = &selected_right[0];
             let drop_names: Option<&[&str]> = if should_coalesce { None } else { Some(&[]) };
@@ -416,8 +483,14 @@ trait DataFrameJoinOpsPrivate: IntoDf {
         let left_df = self.to_df();
         #[cfg(feature = "dtype-categorical")]
         _check_categorical_src(s_left.dtype(), s_right.dtype())?;
-        let ((join_tuples_left, join_tuples_right), sorted) =
-            _sort_or_hash_inner(s_left, s_right, verbose, args.validation, args.join_nulls)?;
+        let ((join_tuples_left, join_tuples_right), sorted) = _sort_or_hash_inner(
+            s_left,
+            s_right,
+            verbose,
+            args.validation,
+            args.join_nulls,
+            &args.hints,
+        )?;
 
         let mut join_tuples_left = &*join_tuples_left;
         let mut join_tuples_right = &*join_tuples_right;
@@ -450,14 +523,17 @@ fn prepare_keys_multiple(s: &[Series], join_nulls: bool) -> PolarsResult<BinaryO
     let keys = s
         .iter()
         .map(|s| {
-            let phys = s.to_physical_repr();
-            match phys.dtype() {
+            let phys = to_physical_key(s);
+            Ok(match phys.dtype() {
                 DataType::Float32 => phys.f32().unwrap().to_canonical().into_series(),
                 DataType::Float64 => phys.f64().unwrap().to_canonical().into_series(),
+                // The row encoding supports variable length lists only.
+                #[cfg(feature = "dtype-array")]
+                DataType::Array(inner, _) => phys.cast(&DataType::List(inner.clone()))?,
                 _ => phys.into_owned(),
-            }
+            })
         })
-        .collect::<Vec<_>>();
+        .collect::<PolarsResult<Vec<_>>>()?;
 
     if join_nulls {
         encode_rows_vertical_par_unordered(&keys)
@@ -472,5 +548,12 @@ pub fn private_left_join_multiple_keys(
 ) -> PolarsResult<LeftJoinIds> {
     let a = prepare_keys_multiple(a.get_columns(), join_nulls)?.into_series();
     let b = prepare_keys_multiple(b.get_columns(), join_nulls)?.into_series();
-    sort_or_hash_left(&a, &b, false, JoinValidation::ManyToMany, join_nulls)
+    sort_or_hash_left(
+        &a,
+        &b,
+        false,
+        JoinValidation::ManyToMany,
+        join_nulls,
+        &JoinHints::default(),
+    )
 }
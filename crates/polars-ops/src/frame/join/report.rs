@@ -0,0 +1,127 @@
+use super::args::VALIDATION_FAILED;
+use super::*;
+
+/// The number of offending keys that are listed in a validation error.
+const MAX_KEYS_IN_ERROR: usize = 5;
+
+/// The join keys that violate a [`JoinValidation`].
+///
+/// Both frames hold the duplicated key values in order of first occurrence, followed by a
+/// column with the number of rows that have that key. That column is named `count`, prefixed
+/// with as many underscores as needed to not collide with the name of a key.
+#[derive(Clone, Debug, Default)]
+pub struct JoinValidationReport {
+    /// Duplicated keys of the left table, if the validation requires them to be unique.
+    pub left: Option<DataFrame>,
+    /// Duplicated keys of the right table, if the validation requires them to be unique.
+    pub right: Option<DataFrame>,
+}
+
+impl JoinValidationReport {
+    /// Find at most `limit` duplicated keys on every side that `validation` requires to be unique.
+    pub fn new(
+        left_keys: &[Series],
+        right_keys: &[Series],
+        validation: JoinValidation,
+        limit: usize,
+    ) -> PolarsResult<Self> {
+        use JoinValidation::*;
+        let (check_left, check_right) = match validation {
+            ManyToMany => (false, false),
+            ManyToOne => (false, true),
+            OneToMany => (true, false),
+            OneToOne => (true, true),
+        };
+        Ok(Self {
+            left: check_left
+                .then(|| duplicated_keys(left_keys, limit))
+                .transpose()?,
+            right: check_right
+                .then(|| duplicated_keys(right_keys, limit))
+                .transpose()?,
+        })
+    }
+
+    /// Whether all keys fulfil the validation.
+    pub fn is_valid(&self) -> bool {
+        [&self.left, &self.right]
+            .into_iter()
+            .flatten()
+            .all(|df| df.is_empty())
+    }
+
+    fn describe(&self) -> String {
+        [("left", &self.left), ("right", &self.right)]
+            .into_iter()
+            .filter_map(|(side, df)| {
+                let df = df.as_ref().filter(|df| !df.is_empty())?;
+                let keys = (0..df.height().min(MAX_KEYS_IN_ERROR))
+                    .map(|i| {
+                        let row = df.get(i).unwrap();
+                        let (count, key) = row.split_last().unwrap();
+                        let key = key.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+                        format!("[{}] ({count} rows)", key.join(", "))
+                    })
+                    .collect::<Vec<_>>();
+                let more = if df.height() > MAX_KEYS_IN_ERROR {
+                    ", ..."
+                } else {
+                    ""
+                };
+                Some(format!("duplicated {side} keys: {}{more}", keys.join(", ")))
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
+    /// Replace a failed validation error by one that lists the offending keys.
+    pub(super) fn explain(
+        err: PolarsError,
+        left_keys: &[Series],
+        right_keys: &[Series],
+        validation: JoinValidation,
+    ) -> PolarsError {
+        let is_validation_error = matches!(
+            &err,
+            PolarsError::ComputeError(msg) if msg.starts_with(VALIDATION_FAILED)
+        );
+        if !is_validation_error {
+            return err;
+        }
+        match Self::new(left_keys, right_keys, validation, MAX_KEYS_IN_ERROR + 1) {
+            Ok(report) if !report.is_valid() => polars_err!(
+                ComputeError: "{} {} validation; {}",
+                VALIDATION_FAILED, validation, report.describe()
+            ),
+            _ => err,
+        }
+    }
+}
+
+fn duplicated_keys(keys: &[Series], limit: usize) -> PolarsResult<DataFrame> {
+    let keys = DataFrame::new(keys.to_vec())?;
+    let groups = keys.group_by_with_series(keys.get_columns().to_vec(), true, true)?;
+
+    let mut first = Vec::new();
+    let mut counts = Vec::new();
+    for group in groups.get_groups().iter() {
+        if first.len() == limit {
+            break;
+        }
+        if group.len() > 1 {
+            first.push(group.first());
+            counts.push(group.len() as IdxSize);
+        }
+    }
+
+    let mut count_name = "count".to_string();
+    while keys.get_column_index(&count_name).is_some() {
+        count_name.insert(0, '_');
+    }
+
+    let first = IdxCa::from_vec("", first);
+    // SAFETY: the first indices of the groups are in bounds.
+    let mut out = unsafe { keys.take_unchecked(&first) };
+    out.hstack_mut(&[IdxCa::from_vec(&count_name, counts).into_series()])?;
+    Ok(out)
+}
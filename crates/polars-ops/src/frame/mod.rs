@@ -1,6 +1,11 @@
 pub mod join;
+#[cfg(feature = "frame_patch")]
+pub mod patch;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+#[cfg(feature = "random")]
+mod sample;
+mod unique;
 
 pub use join::*;
 #[cfg(feature = "to_dummies")]
@@ -10,6 +15,8 @@ use polars_core::prelude::*;
 use polars_core::utils::accumulate_dataframes_horizontal;
 #[cfg(feature = "to_dummies")]
 use polars_core::POOL;
+#[cfg(feature = "random")]
+pub use sample::SampleSize;
 
 #[allow(unused_imports)]
 use crate::prelude::*;
@@ -111,4 +118,43 @@ pub trait DataFrameOps: IntoDf {
 
         accumulate_dataframes_horizontal(cols)
     }
+
+    /// Sample rows within every group defined by the key columns `by`.
+    ///
+    /// All groups are sampled in a single pass over the group indices followed by one gather,
+    /// instead of partitioning the frame and sampling every partition separately.
+    /// Unless `shuffle` is set, the sampled rows keep their original order.
+    #[cfg(feature = "random")]
+    fn sample_stratified<I, S>(
+        &self,
+        by: I,
+        size: SampleSize,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let df = self.to_df();
+        let by = df.select_series(by)?;
+        sample::sample_stratified(df, by, size, with_replacement, shuffle, seed)
+    }
+
+    /// Drop duplicate rows, where the `order_by` columns decide which of the duplicates is kept.
+    ///
+    /// `First` keeps the row with the smallest `order_by` values and `Last` the row with the
+    /// largest, e.g. the row with the latest timestamp. The rows are picked in a single pass
+    /// over the groups, instead of sorting the frame first. `Any` and `None` ignore `order_by`.
+    fn unique_order_by(
+        &self,
+        subset: Option<&[String]>,
+        keep: UniqueKeepStrategy,
+        order_by: &[String],
+        maintain_order: bool,
+        slice: Option<(i64, usize)>,
+    ) -> PolarsResult<DataFrame> {
+        unique::unique_order_by(self.to_df(), subset, keep, order_by, maintain_order, slice)
+    }
 }
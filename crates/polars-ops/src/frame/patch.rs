@@ -0,0 +1,295 @@
+//! Patches between two versions of a frame that share a key.
+//!
+//! A patch is a [`DataFrame`] itself, so it can be stored and sent in any format that holds a
+//! frame, e.g. IPC. It has a row for every inserted, updated and deleted key, with:
+//!
+//! * the key columns,
+//! * a [`PATCH_OP_COLUMN`] column with the operation of the row, one of [`PATCH_INSERT`],
+//!   [`PATCH_UPDATE`] and [`PATCH_DELETE`],
+//! * for every other column that has a changed value in any row, the new values and a
+//!   boolean column, named with the [`PATCH_CHANGED_SUFFIX`], that marks the rows in which the
+//!   value changed. The values are null in the rows in which they didn't change.
+use polars_core::prelude::*;
+
+use crate::frame::join::*;
+
+/// The name of the column of a patch that holds the operation of every row.
+pub const PATCH_OP_COLUMN: &str = "__op";
+/// The suffix of the columns of a patch that mark the rows in which a column changed.
+pub const PATCH_CHANGED_SUFFIX: &str = "__changed";
+pub const PATCH_INSERT: &str = "insert";
+pub const PATCH_UPDATE: &str = "update";
+pub const PATCH_DELETE: &str = "delete";
+
+const OLD_INDEX: &str = "__patch_old_index";
+const NEW_INDEX: &str = "__patch_new_index";
+
+fn check_keys(df: &DataFrame, on: &[&str], frame: &str) -> PolarsResult<()> {
+    polars_ensure!(!on.is_empty(), InvalidOperation: "a patch needs at least one key column");
+    let keys = df.select(on.iter().copied())?;
+    polars_ensure!(
+        !keys.is_duplicated()?.any(),
+        Duplicate: "the keys of the {} frame must be unique", frame
+    );
+    Ok(())
+}
+
+fn changed_name(name: &str) -> String {
+    format!("{name}{PATCH_CHANGED_SUFFIX}")
+}
+
+/// Match the rows of `left` and `right` by their keys, returning the row indices of a full join.
+fn match_rows(left: &DataFrame, right: &DataFrame, on: &[&str]) -> PolarsResult<(IdxCa, IdxCa)> {
+    let left = left
+        .select(on.iter().copied())?
+        .with_row_index(OLD_INDEX, None)?;
+    let right = right
+        .select(on.iter().copied())?
+        .with_row_index(NEW_INDEX, None)?;
+    let mut args = JoinArgs::new(JoinType::Full).with_coalesce(JoinCoalesce::CoalesceColumns);
+    args.join_nulls = true;
+    let joined = left.join(&right, on, on, args)?;
+    Ok((
+        joined.column(OLD_INDEX)?.idx()?.clone(),
+        joined.column(NEW_INDEX)?.idx()?.clone(),
+    ))
+}
+
+/// Compute the patch that turns `old` into `new`, where both frames are keyed by the columns
+/// `on`.
+///
+/// The updated and inserted rows of the patch are in the order of `new`, followed by the
+/// deleted rows in the order of `old`.
+pub fn compute_patch(old: &DataFrame, new: &DataFrame, on: &[&str]) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        old.schema() == new.schema(),
+        SchemaMismatch: "cannot compute a patch between frames with different schemas"
+    );
+    check_keys(old, on, "old")?;
+    check_keys(new, on, "new")?;
+
+    let (old_idx, new_idx) = match_rows(old, new, on)?;
+    let mut matched = vec![];
+    let mut inserted = vec![];
+    let mut deleted = vec![];
+    for (o, n) in old_idx.into_iter().zip(new_idx.into_iter()) {
+        match (o, n) {
+            (Some(o), Some(n)) => matched.push((n, o)),
+            (None, Some(n)) => inserted.push(n),
+            (Some(o), None) => deleted.push(o),
+            (None, None) => unreachable!(),
+        }
+    }
+    matched.sort_unstable();
+    inserted.sort_unstable();
+    deleted.sort_unstable();
+
+    let value_columns = new
+        .get_column_names()
+        .into_iter()
+        .filter(|name| !on.contains(name))
+        .collect::<Vec<_>>();
+
+    // Find the matched rows in which any value changed.
+    let matched_new = IdxCa::from_vec("", matched.iter().map(|(n, _)| *n).collect());
+    let matched_old = IdxCa::from_vec("", matched.iter().map(|(_, o)| *o).collect());
+    let mut changed = Vec::with_capacity(value_columns.len());
+    for name in &value_columns {
+        let before = old.column(name)?.take(&matched_old)?;
+        let after = new.column(name)?.take(&matched_new)?;
+        changed.push(before.not_equal_missing(&after)?);
+    }
+    let is_updated = changed
+        .iter()
+        .fold(BooleanChunked::full("", false, matched.len()), |acc, c| {
+            &acc | c
+        });
+    let updated = matched_new.filter(&is_updated)?;
+    let changed = changed
+        .iter()
+        .map(|c| c.filter(&is_updated))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let (n_updated, n_inserted, n_deleted) = (updated.len(), inserted.len(), deleted.len());
+    let height = n_updated + n_inserted + n_deleted;
+
+    // The rows of `new` that are updated or inserted, null for the deleted rows.
+    let new_rows: IdxCa = updated
+        .into_iter()
+        .chain(inserted.iter().map(|n| Some(*n)))
+        .chain(std::iter::repeat(None).take(n_deleted))
+        .collect();
+    let old_rows: IdxCa = std::iter::repeat(None)
+        .take(n_updated + n_inserted)
+        .chain(deleted.iter().map(|o| Some(*o)))
+        .collect();
+    let is_deleted: BooleanChunked = (0..height).map(|i| i >= n_updated + n_inserted).collect();
+
+    let mut columns = Vec::with_capacity(on.len() + 1 + 2 * value_columns.len());
+    for name in on {
+        let from_new = new.column(name)?.take(&new_rows)?;
+        let from_old = old.column(name)?.take(&old_rows)?;
+        columns.push(from_old.zip_with(&is_deleted, &from_new)?);
+    }
+    let ops = std::iter::repeat(PATCH_UPDATE)
+        .take(n_updated)
+        .chain(std::iter::repeat(PATCH_INSERT).take(n_inserted))
+        .chain(std::iter::repeat(PATCH_DELETE).take(n_deleted));
+    columns.push(StringChunked::from_iter_values(PATCH_OP_COLUMN, ops).into_series());
+
+    for (name, changed) in value_columns.iter().zip(changed) {
+        let mut is_changed: BooleanChunked = changed
+            .into_no_null_iter()
+            .chain(std::iter::repeat(true).take(n_inserted))
+            .chain(std::iter::repeat(false).take(n_deleted))
+            .collect();
+        if !is_changed.any() {
+            continue;
+        }
+        is_changed.rename(&changed_name(name));
+        let rows: IdxCa = new_rows
+            .iter()
+            .zip(is_changed.into_no_null_iter())
+            .map(|(row, is_changed)| row.filter(|_| is_changed))
+            .collect();
+        columns.push(new.column(name)?.take(&rows)?);
+        columns.push(is_changed.into_series());
+    }
+    DataFrame::new(columns)
+}
+
+/// Apply a `patch` that was computed with [`compute_patch`] to `df`, which is keyed by the
+/// columns `on`.
+///
+/// The updated rows keep their position and the inserted rows are appended. The patch must
+/// apply cleanly: the keys of the updated and deleted rows must be in `df` and the keys of the
+/// inserted rows must not.
+pub fn apply_patch(df: &DataFrame, patch: &DataFrame, on: &[&str]) -> PolarsResult<DataFrame> {
+    check_keys(df, on, "patched")?;
+    check_keys(patch, on, "patch")?;
+    let ops = patch.column(PATCH_OP_COLUMN)?.str()?;
+    if let Some(op) = ops.into_iter().find(|op| {
+        !matches!(
+            op,
+            Some(PATCH_INSERT) | Some(PATCH_UPDATE) | Some(PATCH_DELETE)
+        )
+    }) {
+        polars_bail!(ComputeError: "invalid patch operation: {:?}", op);
+    }
+    let schema = df.schema();
+    for name in patch.get_column_names() {
+        let is_known = name == PATCH_OP_COLUMN
+            || schema.contains(name)
+            || name
+                .strip_suffix(PATCH_CHANGED_SUFFIX)
+                .map_or(false, |name| schema.contains(name));
+        polars_ensure!(is_known, SchemaMismatch: "patch column '{}' is not in the frame", name);
+    }
+
+    let is_insert = ops.equal(PATCH_INSERT);
+    let is_update = ops.equal(PATCH_UPDATE);
+    let is_delete = ops.equal(PATCH_DELETE);
+    let (row_idx, patch_idx) = match_rows(df, patch, on)?;
+
+    // The row of the patch that changes every row of `df`.
+    let mut df_patch_rows = vec![None; df.height()];
+    for (row, patch_row) in row_idx.into_iter().zip(patch_idx.into_iter()) {
+        match (row, patch_row) {
+            (Some(row), Some(patch_row)) => {
+                polars_ensure!(
+                    !is_insert.get(patch_row as usize).unwrap(),
+                    Duplicate: "cannot insert row {} of the patch, its key is already in the frame",
+                    patch_row
+                );
+                df_patch_rows[row as usize] = Some(patch_row);
+            },
+            (None, Some(patch_row)) => polars_ensure!(
+                is_insert.get(patch_row as usize).unwrap(),
+                ComputeError: "cannot apply row {} of the patch, its key is not in the frame",
+                patch_row
+            ),
+            _ => {},
+        }
+    }
+    let df_patch_rows = IdxCa::from_iter_options("", df_patch_rows.into_iter());
+    // SAFETY: the rows of the join are in bounds of the patch.
+    let updated_by =
+        unsafe { is_update.take_unchecked(&df_patch_rows) }.fill_null_with_values(false)?;
+    let deleted_by =
+        unsafe { is_delete.take_unchecked(&df_patch_rows) }.fill_null_with_values(false)?;
+
+    let mut columns = Vec::with_capacity(df.width());
+    for s in df.get_columns() {
+        let name = s.name();
+        let (false, Ok(values), Ok(changed)) = (
+            on.contains(&name),
+            patch.column(name),
+            patch.column(&changed_name(name)).and_then(|s| s.bool()),
+        ) else {
+            columns.push(s.clone());
+            continue;
+        };
+        // SAFETY: the rows of the join are in bounds of the patch.
+        let is_changed =
+            unsafe { changed.take_unchecked(&df_patch_rows) }.fill_null_with_values(false)?;
+        let values = values.strict_cast(s.dtype())?.take(&df_patch_rows)?;
+        columns.push(values.zip_with(&(&is_changed & &updated_by), s)?);
+    }
+    let mut out = DataFrame::new(columns)?.filter(&!&deleted_by)?;
+
+    let inserts = patch.filter(&is_insert)?;
+    if inserts.height() > 0 {
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(|s| inserts.column(s.name())?.strict_cast(s.dtype()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        out.vstack_mut(&DataFrame::new(columns)?)?;
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_patch_round_trip() -> PolarsResult<()> {
+        let old = df![
+            "k" => [Some(1), Some(2), Some(3), None],
+            "a" => ["a", "b", "c", "d"],
+            "b" => [Some(1.0), Some(2.0), None, Some(4.0)],
+        ]?;
+        let new = df![
+            "k" => [None, Some(4), Some(3), Some(1)],
+            "a" => ["d", "e", "c", "x"],
+            "b" => [Some(4.0), Some(5.0), Some(3.0), Some(1.0)],
+        ]?;
+        let patch = compute_patch(&old, &new, &["k"])?;
+        assert_eq!(
+            patch.get_column_names(),
+            ["k", PATCH_OP_COLUMN, "a", "a__changed", "b", "b__changed"]
+        );
+        let ops = patch.column(PATCH_OP_COLUMN)?.str()?;
+        assert_eq!(
+            ops.into_no_null_iter().collect::<Vec<_>>(),
+            [PATCH_UPDATE, PATCH_UPDATE, PATCH_INSERT, PATCH_DELETE]
+        );
+        // Unchanged values are left out.
+        assert_eq!(patch.column("a")?.null_count(), 2);
+
+        let patched = apply_patch(&old, &patch, &["k"])?;
+        let expected = df![
+            "k" => [Some(1), Some(3), None, Some(4)],
+            "a" => ["x", "c", "d", "e"],
+            "b" => [Some(1.0), Some(3.0), Some(4.0), Some(5.0)],
+        ]?;
+        assert!(patched.equals_missing(&expected));
+
+        // The patch doesn't apply to the new version.
+        assert!(apply_patch(&new, &patch, &["k"]).is_err());
+        // An empty patch if nothing changed.
+        assert_eq!(compute_patch(&new, &new, &["k"])?.height(), 0);
+        Ok(())
+    }
+}
@@ -9,7 +9,14 @@ use polars_core::utils::_split_offsets;
 use polars_core::{downcast_as_macro_arg_physical, POOL};
 
 const HASHMAP_INIT_SIZE: usize = 512;
+/// The number of colliding keys that are listed in an error.
+const MAX_KEYS_IN_ERROR: usize = 5;
 
+/// How the values that land in the same (index, column) cell are combined.
+///
+/// Without an aggregation, such collisions raise an error that lists the offending keys.
+/// [`PivotAgg::First`] and [`PivotAgg::Last`] keep a single value and [`PivotAgg::List`]
+/// keeps all of them.
 #[derive(Clone)]
 pub enum PivotAgg {
     First,
@@ -20,6 +27,7 @@ pub enum PivotAgg {
     Median,
     Count,
     Last,
+    List,
     Expr(Arc<dyn PhysicalAggExpr + Send + Sync>),
 }
 
@@ -261,6 +269,9 @@ fn pivot_impl_single_column(
         group_by.push(column.to_string());
 
         let groups = pivot_df.group_by_stable(group_by)?.take_groups();
+        if agg_fn.is_none() && pivot_df.height() > groups.len() {
+            return Err(collision_error(pivot_df, index, column, &groups));
+        }
 
         let (col, row) = POOL.join(
             || positioning::compute_col_idx(pivot_df, column, &groups),
@@ -275,10 +286,7 @@ fn pivot_impl_single_column(
             use PivotAgg::*;
             let value_agg = unsafe {
                 match &agg_fn {
-                    None => match value_col.len() > groups.len() {
-                        true => polars_bail!(ComputeError: "found multiple elements in the same group, please specify an aggregation function"),
-                        false => value_col.agg_first(&groups),
-                    }
+                    None => value_col.agg_first(&groups),
                     Some(agg_fn) => match agg_fn {
                         Sum => value_col.agg_sum(&groups),
                         Min => value_col.agg_min(&groups),
@@ -287,6 +295,7 @@ fn pivot_impl_single_column(
                         First => value_col.agg_first(&groups),
                         Mean => value_col.agg_mean(&groups),
                         Median => value_col.agg_median(&groups),
+                        List => value_col.agg_list(&groups),
                         Count => groups.group_count().into_series(),
                         Expr(ref expr) => {
                             let name = expr.root_name()?;
@@ -296,7 +305,7 @@ fn pivot_impl_single_column(
                             let mut aggregated = expr.evaluate(&tmp_df, &groups)?;
                             aggregated.rename(value_col_name);
                             aggregated
-                        }
+                        },
                     },
                 }
             };
@@ -362,3 +371,45 @@ fn pivot_impl_single_column(
     // SAFETY: length has already been checked.
     unsafe { DataFrame::new_no_length_checks(final_cols) }
 }
+
+/// Build the error for (index, column) pairs that occur more than once, listing the first few.
+fn collision_error(
+    pivot_df: &DataFrame,
+    index: &[String],
+    column: &str,
+    groups: &GroupsProxy,
+) -> PolarsError {
+    let mut first = Vec::new();
+    let mut counts = Vec::new();
+    let mut n_collisions = 0;
+    for group in groups.iter().filter(|g| g.len() > 1) {
+        if first.len() < MAX_KEYS_IN_ERROR {
+            first.push(group.first());
+            counts.push(group.len());
+        }
+        n_collisions += 1;
+    }
+
+    let key_names = index.iter().map(|s| s.as_str()).chain([column]);
+    let keys = match pivot_df.select(key_names) {
+        Ok(keys) => keys,
+        Err(err) => return err,
+    };
+    // SAFETY: the first indices of the groups are in bounds.
+    let keys = unsafe { keys.take_unchecked(&IdxCa::from_vec("", first)) };
+    let mut described = (0..keys.height())
+        .map(|i| {
+            let row = keys.get(i).unwrap();
+            let row = row.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+            format!("[{}] ({} rows)", row.join(", "), counts[i])
+        })
+        .collect::<Vec<_>>();
+    if n_collisions > MAX_KEYS_IN_ERROR {
+        described.push(format!("... and {} more", n_collisions - MAX_KEYS_IN_ERROR));
+    }
+    polars_err!(
+        ComputeError: "found multiple elements in the same group, please specify an aggregation \
+        function such as 'first', 'last' or 'list'; colliding ({}, {}) keys: {}",
+        index.join(", "), column, described.join(", ")
+    )
+}
@@ -0,0 +1,99 @@
+use polars_core::prelude::*;
+use polars_core::random::get_global_random_u64;
+use rand::prelude::*;
+use rand::seq::index::IndexVec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The number of rows to sample per group.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SampleSize {
+    /// Sample a fixed number of rows from every group.
+    N(usize),
+    /// Sample a fraction between 0.0-1.0 of the rows of every group.
+    Frac(f64),
+}
+
+impl SampleSize {
+    fn for_group(&self, group_len: usize) -> usize {
+        match self {
+            SampleSize::N(n) => *n,
+            SampleSize::Frac(frac) => (group_len as f64 * frac) as usize,
+        }
+    }
+}
+
+pub(super) fn sample_stratified(
+    df: &DataFrame,
+    by: Vec<Series>,
+    size: SampleSize,
+    with_replacement: bool,
+    shuffle: bool,
+    seed: Option<u64>,
+) -> PolarsResult<DataFrame> {
+    if let SampleSize::Frac(frac) = size {
+        polars_ensure!(
+            frac >= 0.0 && (with_replacement || frac <= 1.0),
+            ComputeError: "sample fraction must be between 0.0 and 1.0 when `with_replacement=false`, got {}", frac
+        );
+    }
+
+    let gb = df.group_by_with_series(by, true, true)?;
+    let groups = gb.get_groups();
+
+    let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
+    let mut idx: Vec<IdxSize> = Vec::with_capacity(match size {
+        SampleSize::N(n) => n.saturating_mul(groups.len()).min(df.height()),
+        SampleSize::Frac(frac) => (df.height() as f64 * frac) as usize,
+    });
+
+    for group in groups.iter() {
+        let len = group.len();
+        let n = size.for_group(len);
+        polars_ensure!(
+            with_replacement || n <= len,
+            ShapeMismatch:
+            "cannot take a larger sample than the group size ({}) when `with_replacement=false`", len
+        );
+        if n == 0 {
+            continue;
+        }
+
+        // Positions within the group, translated to row indices below.
+        let offset = idx.len();
+        if with_replacement {
+            idx.extend((0..n).map(|_| rng.gen_range(0..len) as IdxSize));
+        } else {
+            match rand::seq::index::sample(&mut rng, len, n) {
+                IndexVec::U32(v) => idx.extend(v.into_iter().map(|x| x as IdxSize)),
+                IndexVec::USize(v) => idx.extend(v.into_iter().map(|x| x as IdxSize)),
+            }
+        }
+
+        let positions = &mut idx[offset..];
+        match group {
+            GroupsIndicator::Idx((_, group_idx)) => {
+                for p in positions.iter_mut() {
+                    *p = group_idx[*p as usize];
+                }
+            },
+            GroupsIndicator::Slice([first, _]) => {
+                for p in positions.iter_mut() {
+                    *p += first;
+                }
+            },
+        }
+    }
+
+    if shuffle {
+        idx.shuffle(&mut rng);
+    } else {
+        // Keep the original row order.
+        idx.sort_unstable();
+    }
+
+    let idx = IdxCa::from_vec("", idx);
+    // SAFETY: all indices originate from the groups of `df`.
+    Ok(unsafe { df.take_unchecked(&idx) })
+}
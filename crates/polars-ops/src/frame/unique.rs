@@ -0,0 +1,81 @@
+use polars_core::export::rayon::prelude::*;
+use polars_core::frame::group_by::GroupsIndicator;
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca;
+use polars_core::prelude::*;
+use polars_core::utils::slice_offsets;
+use polars_core::POOL;
+
+/// The row of a group with the smallest encoded `order_by` values, or the largest if
+/// `keep_last` is set.
+///
+/// The indices are ascending, so ties go to the first row, or to the last row if `keep_last`
+/// is set, like a stable sort followed by `unique` would.
+fn pick_row(
+    mut indices: impl Iterator<Item = IdxSize>,
+    rows: &LargeBinaryArray,
+    keep_last: bool,
+) -> IdxSize {
+    let mut best = indices.next().unwrap();
+    // SAFETY: the group indices are in bounds.
+    let mut best_row = unsafe { rows.value_unchecked(best as usize) };
+    for i in indices {
+        let row = unsafe { rows.value_unchecked(i as usize) };
+        if (keep_last && row >= best_row) || (!keep_last && row < best_row) {
+            best = i;
+            best_row = row;
+        }
+    }
+    best
+}
+
+pub(super) fn unique_order_by(
+    df: &DataFrame,
+    subset: Option<&[String]>,
+    keep: UniqueKeepStrategy,
+    order_by: &[String],
+    maintain_order: bool,
+    slice: Option<(i64, usize)>,
+) -> PolarsResult<DataFrame> {
+    // Any row may be kept and duplicates are dropped altogether, so the order doesn't matter.
+    if order_by.is_empty() || matches!(keep, UniqueKeepStrategy::Any | UniqueKeepStrategy::None) {
+        return df.unique_impl(maintain_order, subset, keep, slice);
+    }
+    let keep_last = matches!(keep, UniqueKeepStrategy::Last);
+
+    // The encoded rows compare like the `order_by` columns sort, with nulls first.
+    let by = df.select_series(order_by)?;
+    let no_flags = vec![false; by.len()];
+    let rows = _get_rows_encoded_ca("", &by, &no_flags, &no_flags)?;
+    let rows = rows.downcast_iter().next().unwrap();
+
+    let names = match subset {
+        Some(subset) => subset.iter().map(|s| s.as_str()).collect(),
+        None => df.get_column_names(),
+    };
+    let gb = df.group_by(names)?;
+    let groups = gb.get_groups();
+
+    let pick = |g: GroupsIndicator| match g {
+        GroupsIndicator::Idx((_, idx)) => pick_row(idx.iter().copied(), rows, keep_last),
+        GroupsIndicator::Slice([first, len]) => pick_row(first..first + len, rows, keep_last),
+    };
+    let mut idx: Vec<IdxSize> = POOL.install(|| groups.par_iter().map(pick).collect());
+
+    if maintain_order {
+        idx.sort_unstable();
+    }
+    let idx = match slice {
+        Some((offset, len)) => {
+            let (offset, len) = slice_offsets(offset, len, idx.len());
+            &idx[offset..offset + len]
+        },
+        None => &idx[..],
+    };
+    let sorted = if maintain_order {
+        IsSorted::Ascending
+    } else {
+        IsSorted::Not
+    };
+    // SAFETY: the indices are in bounds.
+    Ok(unsafe { df._take_unchecked_slice_sorted(idx, true, sorted) })
+}
@@ -2,8 +2,10 @@
 pub(crate) use {crate::series::*, polars_core::export::rayon::prelude::*};
 
 pub use crate::chunked_array::*;
-#[cfg(feature = "merge_sorted")]
-pub use crate::frame::_merge_sorted_dfs;
 pub use crate::frame::join::*;
+#[cfg(feature = "random")]
+pub use crate::frame::SampleSize;
 pub use crate::frame::{DataFrameJoinOps, DataFrameOps};
+#[cfg(feature = "merge_sorted")]
+pub use crate::frame::{_merge_sorted_dfs, _merge_sorted_dfs_by};
 pub use crate::series::*;
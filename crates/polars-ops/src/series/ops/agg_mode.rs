@@ -0,0 +1,160 @@
+use polars_core::prelude::*;
+use polars_core::POOL;
+use polars_utils::idx_vec::IdxVec;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Which of the most frequent values of a group a mode aggregation keeps.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ModeKeep {
+    /// Keep all of them as a list, in the order in which they first occur.
+    #[default]
+    All,
+    /// Keep the one that occurs first.
+    First,
+    /// Keep the one that occurs last, i.e. the first when the group is read backwards.
+    Last,
+}
+
+/// Number the distinct values of `s`, so that rows can be compared by their number.
+fn value_ids(s: &Series) -> PolarsResult<Vec<IdxSize>> {
+    let mut ids = vec![0; s.len()];
+    match s.group_tuples(true, false)? {
+        GroupsProxy::Idx(groups) => {
+            for (id, idx) in groups.all().iter().enumerate() {
+                for i in idx.iter() {
+                    ids[*i as usize] = id as IdxSize;
+                }
+            }
+        },
+        GroupsProxy::Slice { groups, .. } => {
+            for (id, [first, len]) in groups.iter().enumerate() {
+                ids[*first as usize..(*first + *len) as usize].fill(id as IdxSize);
+            }
+        },
+    }
+    Ok(ids)
+}
+
+struct ValueCount {
+    count: IdxSize,
+    /// The first row of the value, which stands in for all of its rows.
+    row: IdxSize,
+    /// The position of the last occurrence of the value in the group.
+    last: usize,
+}
+
+/// The rows of the modes of the group `rows` that `keep` asks for.
+fn group_modes(ids: &[IdxSize], rows: impl Iterator<Item = IdxSize>, keep: ModeKeep) -> IdxVec {
+    // The counts are kept in the order in which the values first occur.
+    let mut counts: Vec<ValueCount> = vec![];
+    let mut positions = PlHashMap::new();
+    for (pos, row) in rows.enumerate() {
+        let i = *positions.entry(ids[row as usize]).or_insert_with(|| {
+            counts.push(ValueCount {
+                count: 0,
+                row,
+                last: pos,
+            });
+            counts.len() - 1
+        });
+        let value = &mut counts[i];
+        value.count += 1;
+        value.last = pos;
+    }
+
+    let max = counts.iter().map(|v| v.count).max();
+    let mut modes = counts.iter().filter(|v| Some(v.count) == max);
+    match keep {
+        ModeKeep::All => modes.map(|v| v.row).collect(),
+        ModeKeep::First => modes.next().map(|v| v.row).into_iter().collect(),
+        ModeKeep::Last => modes
+            .max_by_key(|v| v.last)
+            .map(|v| v.row)
+            .into_iter()
+            .collect(),
+    }
+}
+
+/// Gather the modes of every group, given as the groups of their rows in `s`.
+///
+/// # Safety
+/// The rows must be in bounds of `s`.
+unsafe fn gather_modes(s: &Series, modes: GroupsIdx, keep: ModeKeep) -> Series {
+    let modes = GroupsProxy::Idx(modes);
+    let mut out = match keep {
+        ModeKeep::All => s.agg_list(&modes),
+        ModeKeep::First | ModeKeep::Last => s.agg_first(&modes),
+    };
+    out.rename(s.name());
+    out
+}
+
+/// The most frequent values of `s`, where `keep` decides what to do with ties.
+///
+/// Nulls are counted like any other value. The result has length one: a list of all modes with
+/// [`ModeKeep::All`], or else a single mode, which is null if `s` is empty.
+pub fn agg_mode(s: &Series, keep: ModeKeep) -> PolarsResult<Series> {
+    let ids = value_ids(s)?;
+    let modes = group_modes(&ids, 0..s.len() as IdxSize, keep);
+    let modes = GroupsIdx::from(vec![(modes.first().copied().unwrap_or(0), modes)]);
+    // SAFETY: the modes are rows of `s`.
+    Ok(unsafe { gather_modes(s, modes, keep) })
+}
+
+/// The most frequent values of every group of `s`, where `keep` decides what to do with ties.
+///
+/// # Safety
+/// The groups must be in bounds of `s`.
+pub unsafe fn agg_mode_groups(
+    s: &Series,
+    groups: &GroupsProxy,
+    keep: ModeKeep,
+) -> PolarsResult<Series> {
+    let ids = value_ids(s)?;
+    let to_item = |modes: IdxVec| (modes.first().copied().unwrap_or(0), modes);
+    let modes: GroupsIdx = POOL.install(|| match groups {
+        GroupsProxy::Idx(groups) => groups
+            .into_par_iter()
+            .map(|(_, idx)| to_item(group_modes(&ids, idx.iter().copied(), keep)))
+            .collect(),
+        GroupsProxy::Slice { groups, .. } => groups
+            .par_iter()
+            .map(|[first, len]| to_item(group_modes(&ids, *first..*first + *len, keep)))
+            .collect(),
+    });
+    Ok(gather_modes(s, modes, keep))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_agg_mode_groups() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            [Some(1), Some(2), None, Some(1), Some(2), None, Some(3)],
+        );
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, 5], [5, 2], [7, 0]],
+            rolling: false,
+        };
+
+        let first = unsafe { agg_mode_groups(&s, &groups, ModeKeep::First)? };
+        assert_eq!(Vec::from(first.i32()?), [Some(1), None, None]);
+        let last = unsafe { agg_mode_groups(&s, &groups, ModeKeep::Last)? };
+        assert_eq!(Vec::from(last.i32()?), [Some(2), Some(3), None]);
+
+        let all = unsafe { agg_mode_groups(&s, &groups, ModeKeep::All)? };
+        let all = all
+            .list()?
+            .into_iter()
+            .map(|modes| Vec::from(modes.unwrap().i32().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(all, [vec![Some(1), Some(2)], vec![None, Some(3)], vec![]]);
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use std::hash::{Hash, Hasher};
+
+use arrow::array::{Array, PrimitiveArray};
+use arrow::legacy::kernels::rolling::quantile_sketch::QuantileSketch;
+use polars_core::frame::group_by::aggregations::{_agg_helper_idx, _agg_helper_slice};
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ApproxQuantileOptions {
+    /// The maximum relative error of the estimated quantile, i.e.
+    /// `|estimate - exact| <= relative_accuracy * |exact|`.
+    pub relative_accuracy: f64,
+}
+
+impl Default for ApproxQuantileOptions {
+    fn default() -> Self {
+        Self {
+            relative_accuracy: 0.01,
+        }
+    }
+}
+
+impl Hash for ApproxQuantileOptions {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.relative_accuracy.to_bits().hash(state);
+    }
+}
+
+impl ApproxQuantileOptions {
+    pub fn is_valid(&self) -> bool {
+        self.relative_accuracy > 0.0 && self.relative_accuracy < 1.0
+    }
+}
+
+fn check_args(quantile: f64, options: ApproxQuantileOptions) -> PolarsResult<()> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "`quantile` should be between 0.0 and 1.0",
+    );
+    polars_ensure!(
+        options.is_valid(),
+        ComputeError: "`relative_accuracy` should be between 0.0 and 1.0 (exclusive)"
+    );
+    Ok(())
+}
+
+fn to_float(s: &Series) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(
+        s.dtype().is_numeric() || s.dtype().is_bool(),
+        opq = approx_quantile,
+        s.dtype()
+    );
+    Ok(s.cast(&DataType::Float64)?.f64()?.rechunk())
+}
+
+/// Restore the output type of the exact quantile, which keeps `Float32`.
+fn finish(out: Series, input: &Series) -> PolarsResult<Series> {
+    let mut out = match input.dtype() {
+        DataType::Float32 => out.cast(&DataType::Float32)?,
+        _ => out,
+    };
+    out.rename(input.name());
+    Ok(out)
+}
+
+/// Add the values of `arr` to `sketch`, skipping nulls and NaNs.
+pub fn quantile_sketch_extend(sketch: &mut QuantileSketch, arr: &PrimitiveArray<f64>) {
+    let mut insert = |v: f64| {
+        if !v.is_nan() {
+            sketch.insert(v)
+        }
+    };
+    if arr.null_count() == 0 {
+        arr.values_iter().for_each(|v| insert(*v));
+    } else {
+        arr.iter().flatten().for_each(|v| insert(*v));
+    }
+}
+
+/// Estimate the `quantile` of `s` with a [`QuantileSketch`].
+///
+/// Nulls and NaNs are ignored. The result is a `Series` of length one.
+pub fn approx_quantile(
+    s: &Series,
+    quantile: f64,
+    options: ApproxQuantileOptions,
+) -> PolarsResult<Series> {
+    check_args(quantile, options)?;
+    let ca = to_float(s)?;
+    let mut sketch = QuantileSketch::new(options.relative_accuracy);
+    ca.downcast_iter()
+        .for_each(|arr| quantile_sketch_extend(&mut sketch, arr));
+    let out = Float64Chunked::from_slice_options("", &[sketch.quantile(quantile)]);
+    finish(out.into_series(), s)
+}
+
+/// Estimate the `quantile` of every group of `s` with a [`QuantileSketch`].
+///
+/// # Safety
+/// The groups must be in bounds of `s`.
+pub unsafe fn approx_quantile_groups(
+    s: &Series,
+    groups: &GroupsProxy,
+    quantile: f64,
+    options: ApproxQuantileOptions,
+) -> PolarsResult<Series> {
+    check_args(quantile, options)?;
+    let ca = to_float(s)?;
+    let arr = ca.downcast_iter().next().unwrap();
+    let out = match groups {
+        GroupsProxy::Idx(groups) => _agg_helper_idx::<Float64Type, _>(groups, |(_, idx)| {
+            let mut sketch = QuantileSketch::new(options.relative_accuracy);
+            for i in idx.iter() {
+                match arr.get_unchecked(*i as usize) {
+                    Some(v) if !v.is_nan() => sketch.insert(v),
+                    _ => {},
+                }
+            }
+            sketch.quantile(quantile)
+        }),
+        GroupsProxy::Slice { groups, .. } => {
+            _agg_helper_slice::<Float64Type, _>(groups, |[first, len]| {
+                let mut sketch = QuantileSketch::new(options.relative_accuracy);
+                quantile_sketch_extend(
+                    &mut sketch,
+                    &arr.clone().sliced_unchecked(first as usize, len as usize),
+                );
+                sketch.quantile(quantile)
+            })
+        },
+    };
+    finish(out, s)
+}
@@ -55,6 +55,8 @@ impl ArgAgg for Series {
                 let ca = s.i64().unwrap();
                 arg_min_numeric_dispatch(ca)
             },
+            #[cfg(feature = "dtype-f16")]
+            Float16 => self.cast(&Float32).unwrap().arg_min(),
             dt if dt.is_numeric() => {
                 with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
                     let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
@@ -101,6 +103,8 @@ impl ArgAgg for Series {
                 let ca = s.i64().unwrap();
                 arg_max_numeric_dispatch(ca)
             },
+            #[cfg(feature = "dtype-f16")]
+            Float16 => self.cast(&Float32).unwrap().arg_max(),
             dt if dt.is_numeric() => {
                 with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
                     let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
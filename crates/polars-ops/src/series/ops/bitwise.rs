@@ -0,0 +1,76 @@
+use polars_compute::bitwise::BitwiseKernel;
+use polars_core::chunked_array::ops::arity::unary_kernel;
+use polars_core::prelude::*;
+use polars_core::with_match_physical_integer_polars_type;
+
+fn bitwise_unary<F>(s: &Series, op: &str, f: F) -> PolarsResult<Series>
+where
+    F: Fn(&Series) -> Series,
+{
+    polars_ensure!(
+        s.dtype().is_integer(),
+        InvalidOperation: "`{}` operation not supported for dtype `{}`", op, s.dtype()
+    );
+    Ok(f(s))
+}
+
+/// Flip all bits of an integer [`Series`].
+pub fn bitwise_not(s: &Series) -> PolarsResult<Series> {
+    bitwise_unary(s, "bitwise_not", |s| {
+        with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            unary_kernel(ca, |arr| arr.clone().bitwise_not()).into_series()
+        })
+    })
+}
+
+/// Shift the bits of an integer [`Series`] `n` places to the left.
+pub fn bitwise_shift_left(s: &Series, n: u32) -> PolarsResult<Series> {
+    bitwise_unary(s, "bitwise_shift_left", |s| {
+        with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            unary_kernel(ca, |arr| arr.clone().shift_left(n)).into_series()
+        })
+    })
+}
+
+/// Shift the bits of an integer [`Series`] `n` places to the right, keeping the sign of signed
+/// integers.
+pub fn bitwise_shift_right(s: &Series, n: u32) -> PolarsResult<Series> {
+    bitwise_unary(s, "bitwise_shift_right", |s| {
+        with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            unary_kernel(ca, |arr| arr.clone().shift_right(n)).into_series()
+        })
+    })
+}
+
+/// The bitwise and of the non-null values of an integer [`Series`], as a unit length
+/// [`Series`] that is null if there are no such values.
+pub fn bitwise_and_reduce(s: &Series) -> PolarsResult<Series> {
+    bitwise_unary(s, "bitwise_and_agg", |s| {
+        with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            let out = ca
+                .downcast_iter()
+                .filter_map(|arr| arr.reduce_and())
+                .reduce(|a, b| a & b);
+            ChunkedArray::<$T>::from_slice_options(s.name(), &[out]).into_series()
+        })
+    })
+}
+
+/// The bitwise or of the non-null values of an integer [`Series`], as a unit length [`Series`]
+/// that is null if there are no such values.
+pub fn bitwise_or_reduce(s: &Series) -> PolarsResult<Series> {
+    bitwise_unary(s, "bitwise_or_agg", |s| {
+        with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            let out = ca
+                .downcast_iter()
+                .filter_map(|arr| arr.reduce_or())
+                .reduce(|a, b| a | b);
+            ChunkedArray::<$T>::from_slice_options(s.name(), &[out]).into_series()
+        })
+    })
+}
@@ -1,11 +1,14 @@
 use std::ops::{Add, AddAssign, Mul};
 
+use arrow::legacy::kernels::rolling::quantile_sketch::QuantileSketch;
 use num_traits::{Bounded, One, Zero};
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::utils::{CustomIterTools, NoNull};
 use polars_core::with_match_physical_numeric_polars_type;
 
+use super::ApproxQuantileOptions;
+
 fn det_max<T>(state: &mut T, v: Option<T>) -> Option<Option<T>>
 where
     T: Copy + PartialOrd + AddAssign + Add<Output = T>,
@@ -207,6 +210,98 @@ pub fn cum_max(s: &Series, reverse: bool) -> PolarsResult<Series> {
     }
 }
 
+/// Running mean and sum of squared deviations, updated with Welford's algorithm.
+#[derive(Default)]
+struct WelfordState {
+    count: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl WelfordState {
+    fn push(&mut self, v: f64) {
+        self.count += 1.0;
+        let delta = v - self.mean;
+        self.mean += delta / self.count;
+        self.m2 += delta * (v - self.mean);
+    }
+
+    fn std(&self, ddof: u8) -> Option<f64> {
+        let denom = self.count - ddof as f64;
+        (denom > 0.0).then(|| (self.m2 / denom).sqrt())
+    }
+}
+
+/// Compute a cumulative statistic on `f64` values, casting the result back to `Float32` for
+/// `Float32` input.
+fn cum_float_stat<S>(
+    s: &Series,
+    reverse: bool,
+    mut state: S,
+    mut update: impl FnMut(&mut S, f64) -> Option<f64>,
+) -> PolarsResult<Series> {
+    let float_s = s.cast(&DataType::Float64)?;
+    let ca = float_s.f64()?;
+    let mut step = |v: Option<f64>| v.and_then(|v| update(&mut state, v));
+    let out: Float64Chunked = match reverse {
+        false => ca.iter().map(&mut step).collect_trusted(),
+        true => ca.iter().rev().map(&mut step).collect_reversed(),
+    };
+    let out = out.with_name(s.name()).into_series();
+    match s.dtype() {
+        DataType::Float32 => out.cast(&DataType::Float32),
+        _ => Ok(out),
+    }
+}
+
+fn is_cum_float_input(dtype: &DataType) -> bool {
+    dtype.is_numeric() || dtype.is_bool()
+}
+
+/// Get an array with the cumulative mean computed at every element.
+///
+/// The mean is updated incrementally, which is numerically stable, unlike dividing the
+/// cumulative sum by the cumulative count. Null values are skipped.
+pub fn cum_mean(s: &Series, reverse: bool) -> PolarsResult<Series> {
+    polars_ensure!(is_cum_float_input(s.dtype()), opq = cum_mean, s.dtype());
+    cum_float_stat(s, reverse, WelfordState::default(), |state, v| {
+        state.push(v);
+        Some(state.mean)
+    })
+}
+
+/// Get an array with the cumulative standard deviation computed at every element.
+///
+/// Uses Welford's algorithm. The result is null as long as there are no more than `ddof`
+/// non-null values.
+pub fn cum_std(s: &Series, reverse: bool, ddof: u8) -> PolarsResult<Series> {
+    polars_ensure!(is_cum_float_input(s.dtype()), opq = cum_std, s.dtype());
+    cum_float_stat(s, reverse, WelfordState::default(), |state, v| {
+        state.push(v);
+        state.std(ddof)
+    })
+}
+
+/// Get an array with an approximation of the cumulative `quantile` computed at every element.
+///
+/// The values seen so far are counted in a [`QuantileSketch`], so the memory doesn't grow with
+/// the length of the input and every estimate is within the default relative accuracy of
+/// [`ApproxQuantileOptions`] of the exact value. Null and NaN values are skipped.
+pub fn cum_quantile(s: &Series, quantile: f64, reverse: bool) -> PolarsResult<Series> {
+    polars_ensure!(is_cum_float_input(s.dtype()), opq = cum_quantile, s.dtype());
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "`quantile` should be between 0.0 and 1.0"
+    );
+    let sketch = QuantileSketch::new(ApproxQuantileOptions::default().relative_accuracy);
+    cum_float_stat(s, reverse, sketch, |sketch, v| {
+        if !v.is_nan() {
+            sketch.insert(v);
+        }
+        sketch.quantile(quantile)
+    })
+}
+
 pub fn cum_count(s: &Series, reverse: bool) -> PolarsResult<Series> {
     let mut out = if s.null_count() == 0 {
         // Fast paths for no nulls
@@ -253,3 +348,75 @@ fn cum_count_no_nulls(name: &str, len: usize, reverse: bool) -> Series {
     ca.rename(name);
     ca.into_series()
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cum_mean_std() -> PolarsResult<()> {
+        let s = Series::new("a", [Some(1i32), None, Some(3), Some(5)]);
+        let out = cum_mean(&s, false)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(1.0), None, Some(2.0), Some(3.0)]
+        );
+        let out = cum_mean(&s, true)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(3.0), None, Some(4.0), Some(5.0)]
+        );
+
+        let out = cum_std(&s, false, 1)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[None, None, Some(2.0f64.sqrt()), Some(2.0)]
+        );
+
+        // A large offset doesn't lose precision.
+        let s = Series::new("a", [1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0]);
+        let out = cum_std(&s, false, 0)?;
+        assert_eq!(out.f64()?.get(3), Some(22.5f64.sqrt()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_cum_quantile() -> PolarsResult<()> {
+        // Leave some room for rounding at the edges of the buckets.
+        let accuracy = ApproxQuantileOptions::default().relative_accuracy * 1.001;
+        let assert_close = |out: &Series, expected: &[Option<f64>]| {
+            let out = out.cast(&DataType::Float64).unwrap();
+            for (v, e) in out.f64().unwrap().iter().zip(expected) {
+                match (v, e) {
+                    (Some(v), Some(e)) => assert!((v - e).abs() <= accuracy * e.abs(), "{v}"),
+                    (v, e) => assert_eq!(v, *e),
+                }
+            }
+        };
+
+        let s = Series::new("a", [Some(4.0f32), Some(1.0), None, Some(3.0)]);
+        let out = cum_quantile(&s, 0.5, false)?;
+        assert_eq!(out.dtype(), &DataType::Float32);
+        assert_close(&out, &[Some(4.0), Some(1.0), None, Some(3.0)]);
+
+        // NaN values are skipped.
+        let s = Series::new("a", [f64::NAN, 2.0, f64::NAN, 4.0]);
+        let out = cum_quantile(&s, 1.0, false)?;
+        assert_close(&out, &[None, Some(2.0), Some(2.0), Some(4.0)]);
+
+        let n = 10_000;
+        let values: Vec<f64> = (1..=n).map(|i| ((i * 7919) % n + 1) as f64).collect();
+        let s = Series::new("a", values);
+        for q in [0.0, 0.1, 0.5, 0.9, 1.0] {
+            let out = cum_quantile(&s, q, false)?;
+            let estimate = out.f64()?.get(n - 1).unwrap();
+            let exact = (q * (n - 1) as f64).floor() + 1.0;
+            assert!(
+                (estimate - exact).abs() <= accuracy * exact,
+                "{q}: {estimate}"
+            );
+        }
+        assert!(cum_quantile(&s, 1.5, false).is_err());
+        Ok(())
+    }
+}
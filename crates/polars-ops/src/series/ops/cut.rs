@@ -117,6 +117,27 @@ pub fn cut(
     map_cats(s, &cut_labels, &breaks, left_closed, include_breaks)
 }
 
+/// The sorted quantile breaks of `sorted_ca`, which must contain a non-null value.
+fn quantile_breaks(
+    sorted_ca: &Float64Chunked,
+    probs: &[f64],
+    allow_duplicates: bool,
+) -> PolarsResult<Vec<f64>> {
+    let f = |&p| {
+        sorted_ca
+            .quantile(p, QuantileInterpolOptions::Linear)
+            .unwrap()
+            .unwrap()
+    };
+    let mut qbreaks: Vec<_> = probs.iter().map(f).collect();
+    qbreaks.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+
+    if !allow_duplicates {
+        polars_ensure!(qbreaks.windows(2).all(|x| x[0] != x[1]), Duplicate: "quantiles are not unique while allow_duplicates=False");
+    }
+    Ok(qbreaks)
+}
+
 pub fn qcut(
     s: &Series,
     probs: Vec<f64>,
@@ -136,17 +157,7 @@ pub fn qcut(
         return cut(&s, vec![], labels, left_closed, include_breaks);
     }
 
-    let f = |&p| {
-        ca.quantile(p, QuantileInterpolOptions::Linear)
-            .unwrap()
-            .unwrap()
-    };
-    let mut qbreaks: Vec<_> = probs.iter().map(f).collect();
-    qbreaks.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
-
-    if !allow_duplicates {
-        polars_ensure!(qbreaks.windows(2).all(|x| x[0] != x[1]), Duplicate: "quantiles are not unique while allow_duplicates=False");
-    }
+    let qbreaks = quantile_breaks(ca, &probs, allow_duplicates)?;
 
     let cut_labels = if let Some(l) = labels {
         polars_ensure!(l.len() == qbreaks.len() + 1, ShapeMismatch: "provide len(quantiles) + 1 labels");
@@ -158,6 +169,112 @@ pub fn qcut(
     map_cats(&s, &cut_labels, &qbreaks, left_closed, include_breaks)
 }
 
+/// Bin the values of every group of `s` by the quantiles of that group, see [`qcut`].
+///
+/// Unlike calling [`qcut`] per group, the groups share a single categorical, so the
+/// results of the groups can be combined without remapping their categories. The output
+/// has a value per row of `s`, in the order of `s`. With `include_breaks`, the
+/// breakpoints are those of the group of the row.
+pub fn qcut_groups(
+    s: &Series,
+    groups: &GroupsProxy,
+    probs: Vec<f64>,
+    labels: Option<Vec<String>>,
+    left_closed: bool,
+    allow_duplicates: bool,
+    include_breaks: bool,
+) -> PolarsResult<Series> {
+    polars_ensure!(!probs.iter().any(|x| x.is_nan()), ComputeError: "quantiles cannot be NaN");
+    if let Some(l) = &labels {
+        polars_ensure!(l.len() == probs.len() + 1, ShapeMismatch: "provide len(quantiles) + 1 labels");
+    }
+    let out_name = "category";
+
+    let s = s.cast(&DataType::Float64)?.rechunk();
+    let ca = s.f64()?;
+
+    let op = if left_closed {
+        PartialOrd::ge
+    } else {
+        PartialOrd::gt
+    };
+
+    // The group and the bin within that group of every row.
+    let mut bins: Vec<Option<(usize, usize)>> = vec![None; ca.len()];
+    let mut group_breaks = Vec::with_capacity(groups.len());
+    let mut group_labels = Vec::with_capacity(if labels.is_some() { 0 } else { groups.len() });
+    for (g, group) in groups.iter().enumerate() {
+        let values = match group {
+            GroupsIndicator::Idx((_, idx)) => unsafe { ca.take_unchecked(idx.as_slice()) },
+            GroupsIndicator::Slice([first, len]) => ca.slice(first as i64, len as usize),
+        };
+        let sorted = values.sort(false);
+        // Groups with only nulls don't have any breakpoints, nor rows to bin.
+        let qbreaks = if sorted.null_count() == sorted.len() {
+            vec![]
+        } else {
+            quantile_breaks(&sorted, &probs, allow_duplicates)?
+        };
+
+        let group_bins = values.into_iter().map(|opt| {
+            opt.filter(|x| !x.is_nan())
+                .map(|x| (g, qbreaks.partition_point(|v| op(&x, v))))
+        });
+        match group {
+            GroupsIndicator::Idx((_, idx)) => {
+                for (&row, bin) in idx.iter().zip(group_bins) {
+                    bins[row as usize] = bin;
+                }
+            },
+            GroupsIndicator::Slice([first, len]) => {
+                for (row, bin) in (first..first + len).zip(group_bins) {
+                    bins[row as usize] = bin;
+                }
+            },
+        }
+
+        if labels.is_none() {
+            group_labels.push(compute_labels(&qbreaks, left_closed)?);
+        }
+        group_breaks.push(qbreaks);
+    }
+    let labels_of = |g: usize| labels.as_ref().unwrap_or_else(|| &group_labels[g]);
+
+    // Register the labels in the order of the groups for consistent categorical indexes.
+    let mut bld = CategoricalChunkedBuilder::new(out_name, ca.len(), Default::default());
+    for g in 0..groups.len() {
+        for label in labels_of(g) {
+            bld.register_value(label);
+        }
+    }
+
+    if include_breaks {
+        let mut brk_vals = PrimitiveChunkedBuilder::<Float64Type>::new("breakpoint", ca.len());
+        for bin in &bins {
+            match *bin {
+                None => {
+                    bld.append_null();
+                    brk_vals.append_null();
+                },
+                Some((g, idx)) => {
+                    bld.append_value(&labels_of(g)[idx]);
+                    let breaks = &group_breaks[g];
+                    brk_vals.append_value(breaks.get(idx).copied().unwrap_or(f64::INFINITY));
+                },
+            }
+        }
+        let outvals = vec![brk_vals.finish().into_series(), bld.finish().into_series()];
+        Ok(StructChunked::new(out_name, &outvals)?.into_series())
+    } else {
+        Ok(bld
+            .drain_iter_and_finish(
+                bins.iter()
+                    .map(|bin| bin.map(|(g, idx)| labels_of(g)[idx].as_str())),
+            )
+            .into_series())
+    }
+}
+
 mod test {
     #[test]
     fn test_map_cats_fast_unique() {
@@ -1,29 +1,96 @@
+use std::borrow::Cow;
+
 use bytemuck::allocation::zeroed_vec;
 use num_traits::{Float, FromPrimitive, One, Zero};
 use polars_core::prelude::*;
 use polars_core::utils::binary_concatenate_validities;
 
+/// The statistic computed by [`ewm_by`].
+#[derive(Clone, Copy, Debug)]
+enum EwmByStatistic {
+    Mean,
+    Var { bias: bool },
+    Std { bias: bool },
+}
+
 pub fn ewm_mean_by(
     s: &Series,
     times: &Series,
     half_life: i64,
     times_is_sorted: bool,
+) -> PolarsResult<Series> {
+    ewm_by(s, times, half_life, times_is_sorted, EwmByStatistic::Mean)
+}
+
+/// Exponentially weighted moving variance, where the weight of an observation halves every
+/// `half_life` units of `times`.
+///
+/// If `bias` is `false`, the variance is corrected with the effective number of observations
+/// given by the weights.
+pub fn ewm_var_by(
+    s: &Series,
+    times: &Series,
+    half_life: i64,
+    bias: bool,
+    times_is_sorted: bool,
+) -> PolarsResult<Series> {
+    ewm_by(
+        s,
+        times,
+        half_life,
+        times_is_sorted,
+        EwmByStatistic::Var { bias },
+    )
+}
+
+/// Exponentially weighted moving standard deviation, see [`ewm_var_by`].
+pub fn ewm_std_by(
+    s: &Series,
+    times: &Series,
+    half_life: i64,
+    bias: bool,
+    times_is_sorted: bool,
+) -> PolarsResult<Series> {
+    ewm_by(
+        s,
+        times,
+        half_life,
+        times_is_sorted,
+        EwmByStatistic::Std { bias },
+    )
+}
+
+fn ewm_by(
+    s: &Series,
+    times: &Series,
+    half_life: i64,
+    times_is_sorted: bool,
+    statistic: EwmByStatistic,
 ) -> PolarsResult<Series> {
     fn func<T>(
         values: &ChunkedArray<T>,
         times: &Int64Chunked,
         half_life: i64,
         times_is_sorted: bool,
+        statistic: EwmByStatistic,
     ) -> PolarsResult<Series>
     where
         T: PolarsFloatType,
         T::Native: Float + Zero + One,
         ChunkedArray<T>: IntoSeries,
     {
-        if times_is_sorted {
-            Ok(ewm_mean_by_impl_sorted(values, times, half_life).into_series())
-        } else {
-            Ok(ewm_mean_by_impl(values, times, half_life).into_series())
+        match statistic {
+            EwmByStatistic::Mean if times_is_sorted => {
+                Ok(ewm_mean_by_impl_sorted(values, times, half_life).into_series())
+            },
+            EwmByStatistic::Mean => Ok(ewm_mean_by_impl(values, times, half_life).into_series()),
+            EwmByStatistic::Var { bias } => {
+                Ok(ewm_var_by_impl(values, times, half_life, bias, times_is_sorted).into_series())
+            },
+            EwmByStatistic::Std { bias } => {
+                let var = ewm_var_by_impl(values, times, half_life, bias, times_is_sorted);
+                Ok(var.apply_values(|v| v.sqrt()).into_series())
+            },
         }
     }
 
@@ -33,44 +100,48 @@ pub fn ewm_mean_by(
             times.i64().unwrap(),
             half_life,
             times_is_sorted,
+            statistic,
         ),
         (DataType::Float32, DataType::Int64) => func(
             s.f32().unwrap(),
             times.i64().unwrap(),
             half_life,
             times_is_sorted,
+            statistic,
         ),
         #[cfg(feature = "dtype-datetime")]
         (_, DataType::Datetime(time_unit, _)) => {
             let half_life = adjust_half_life_to_time_unit(half_life, time_unit);
-            ewm_mean_by(
+            ewm_by(
                 s,
                 &times.cast(&DataType::Int64)?,
                 half_life,
                 times_is_sorted,
+                statistic,
             )
         },
         #[cfg(feature = "dtype-date")]
-        (_, DataType::Date) => ewm_mean_by(
+        (_, DataType::Date) => ewm_by(
             s,
             &times.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
             half_life,
             times_is_sorted,
+            statistic,
         ),
-        (_, DataType::UInt64 | DataType::UInt32 | DataType::Int32) => ewm_mean_by(
+        (_, DataType::UInt64 | DataType::UInt32 | DataType::Int32) => ewm_by(
             s,
             &times.cast(&DataType::Int64)?,
             half_life,
             times_is_sorted,
+            statistic,
+        ),
+        (DataType::UInt64 | DataType::UInt32 | DataType::Int64 | DataType::Int32, _) => ewm_by(
+            &s.cast(&DataType::Float64)?,
+            times,
+            half_life,
+            times_is_sorted,
+            statistic,
         ),
-        (DataType::UInt64 | DataType::UInt32 | DataType::Int64 | DataType::Int32, _) => {
-            ewm_mean_by(
-                &s.cast(&DataType::Float64)?,
-                times,
-                half_life,
-                times_is_sorted,
-            )
-        },
         _ => {
             polars_bail!(InvalidOperation: "expected series to be Float64, Float32, \
                 Int64, Int32, UInt64, UInt32, and `by` to be Date, Datetime, Int64, Int32, \
@@ -187,6 +258,84 @@ where
     ChunkedArray::with_chunk(values.name(), arr)
 }
 
+/// Welford-style update of the weighted mean and variance, where the new observation gets
+/// weight `alpha` and the previous weights decay by `1 - alpha`.
+///
+/// The sum of the squared normalized weights is tracked for the bias correction.
+fn ewm_var_by_impl<T>(
+    values: &ChunkedArray<T>,
+    times: &Int64Chunked,
+    half_life: i64,
+    bias: bool,
+    times_is_sorted: bool,
+) -> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+    T::Native: Float + Zero + One,
+    ChunkedArray<T>: ChunkTakeUnchecked<IdxCa>,
+{
+    let (sorted_values, sorted_times, sorting_indices) = if times_is_sorted {
+        (Cow::Borrowed(values), Cow::Borrowed(times), None)
+    } else {
+        let sorting_indices = times.arg_sort(Default::default());
+        let sorted_values = unsafe { values.take_unchecked(&sorting_indices) };
+        let sorted_times = unsafe { times.take_unchecked(&sorting_indices) };
+        (
+            Cow::Owned(sorted_values),
+            Cow::Owned(sorted_times),
+            Some(sorting_indices),
+        )
+    };
+    let sorting_indices = sorting_indices.as_ref().map(|idx| {
+        idx.cont_slice()
+            .expect("`arg_sort` should have returned a single chunk")
+    });
+
+    let mut out: Vec<_> = zeroed_vec(times.len());
+
+    // The time of the previous observation, and the mean, variance and sum of squared weights
+    // of the observations seen so far.
+    let mut prev_time: Option<i64> = None;
+    let mut mean = T::Native::zero();
+    let mut var = T::Native::zero();
+    let mut sum_wt2 = T::Native::one();
+    for (idx, (value, time)) in sorted_values.iter().zip(sorted_times.iter()).enumerate() {
+        let (Some(time), Some(value)) = (time, value) else {
+            continue;
+        };
+        match prev_time {
+            None => mean = value,
+            Some(prev_time) => {
+                let one_minus_alpha = decay_factor(time, prev_time, half_life);
+                let alpha = T::Native::one() - one_minus_alpha;
+                let diff = value - mean;
+                let incr = alpha * diff;
+                mean += incr;
+                var = one_minus_alpha * (var + diff * incr);
+                sum_wt2 = one_minus_alpha * one_minus_alpha * sum_wt2 + alpha * alpha;
+            },
+        }
+        prev_time = Some(time);
+
+        // A single effective observation has no unbiased variance; report the biased one
+        // like `ewm_var` does for the first value.
+        let result = if bias || sum_wt2 >= T::Native::one() {
+            var
+        } else {
+            var / (T::Native::one() - sum_wt2)
+        };
+        let out_idx = sorting_indices.map_or(idx, |idx_slice| idx_slice[idx] as usize);
+        out[out_idx] = result;
+    }
+
+    let mut arr = T::Array::from_zeroable_vec(out, values.dtype().to_arrow(true));
+    if (times.null_count() > 0) || (values.null_count() > 0) {
+        let validity = binary_concatenate_validities(times, values);
+        arr = arr.with_validity_typed(validity);
+    }
+    ChunkedArray::with_chunk(values.name(), arr)
+}
+
 fn adjust_half_life_to_time_unit(half_life: i64, time_unit: &TimeUnit) -> i64 {
     match time_unit {
         TimeUnit::Milliseconds => half_life / 1_000_000,
@@ -200,14 +349,73 @@ where
     T: Float + Zero + One + FromPrimitive,
 {
     if value != prev_result {
-        let delta_time = time - prev_time;
-        // equivalent to: alpha = 1 - exp(-delta_time*ln(2) / half_life)
-        let one_minus_alpha = T::from_f64(0.5)
-            .unwrap()
-            .powf(T::from_i64(delta_time).unwrap() / T::from_i64(half_life).unwrap());
+        let one_minus_alpha: T = decay_factor(time, prev_time, half_life);
         let alpha = T::one() - one_minus_alpha;
         alpha * value + one_minus_alpha * prev_result
     } else {
         value
     }
 }
+
+/// The factor `1 - alpha` by which the weights of earlier observations decay.
+fn decay_factor<T>(time: i64, prev_time: i64, half_life: i64) -> T
+where
+    T: Float + FromPrimitive,
+{
+    let delta_time = time - prev_time;
+    // equivalent to: alpha = 1 - exp(-delta_time*ln(2) / half_life)
+    T::from_f64(0.5)
+        .unwrap()
+        .powf(T::from_i64(delta_time).unwrap() / T::from_i64(half_life).unwrap())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ewm_var_by() -> PolarsResult<()> {
+        let values = Series::new(
+            "values",
+            &[Some(1.0), Some(0.0), None, Some(4.0), Some(2.0)],
+        );
+        let times = Series::new(
+            "times",
+            &[Some(3i64), Some(1), Some(15), Some(17), Some(10)],
+        );
+        let to_vec = |s: Series| {
+            s.f64()
+                .unwrap()
+                .iter()
+                .map(|v| v.map(|v| (v * 1e6).round() / 1e6))
+                .collect::<Vec<_>>()
+        };
+
+        let out = ewm_var_by(&values, &times, 4, false, false)?;
+        assert_eq!(
+            to_vec(out),
+            &[Some(0.5), Some(0.0), None, Some(3.303299), Some(1.475209)]
+        );
+        let out = ewm_std_by(&values, &times, 4, true, false)?;
+        assert_eq!(
+            to_vec(out),
+            &[
+                Some(0.45509),
+                Some(0.0),
+                None,
+                Some(1.229996),
+                Some(0.818774)
+            ]
+        );
+
+        // Sorted fast path gives the same results.
+        let values = Series::new("values", &[0.0, 1.0, 2.0, 4.0]);
+        let times = Series::new("times", &[1i64, 3, 10, 17]);
+        let out = ewm_var_by(&values, &times, 4, false, true)?;
+        assert_eq!(
+            to_vec(out),
+            &[Some(0.0), Some(0.5), Some(1.475209), Some(3.303299)]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use num_traits::{Float, NumCast};
+use polars_core::prelude::arity::broadcast_binary_elementwise_values;
+use polars_core::prelude::*;
+
+fn is_close_values<T: Float>(a: T, b: T, abs_tol: T, rel_tol: T, nans_equal: bool) -> bool {
+    if a.is_nan() || b.is_nan() {
+        return nans_equal && a.is_nan() && b.is_nan();
+    }
+    // Also covers infinities, which are only close to the same infinity.
+    if a == b {
+        return true;
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return false;
+    }
+    let diff = (a - b).abs();
+    diff <= abs_tol || diff <= rel_tol * a.abs().max(b.abs())
+}
+
+fn is_close_ca<T>(
+    a: &ChunkedArray<T>,
+    b: &ChunkedArray<T>,
+    abs_tol: f64,
+    rel_tol: f64,
+    nans_equal: bool,
+) -> BooleanChunked
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+{
+    let abs_tol = <T::Native as NumCast>::from(abs_tol).unwrap();
+    let rel_tol = <T::Native as NumCast>::from(rel_tol).unwrap();
+    broadcast_binary_elementwise_values(a, b, |a, b| {
+        is_close_values(a, b, abs_tol, rel_tol, nans_equal)
+    })
+}
+
+/// Check if the values of `a` and `b` are close: their difference is at most `abs_tol` or
+/// at most `rel_tol` times the larger of their absolute values.
+///
+/// Infinities are only close to the same infinity. NaN values are not close to anything,
+/// unless `nans_equal` is set, in which case they are close to each other.
+pub fn is_close(
+    a: &Series,
+    b: &Series,
+    abs_tol: f64,
+    rel_tol: f64,
+    nans_equal: bool,
+) -> PolarsResult<BooleanChunked> {
+    polars_ensure!(
+        abs_tol >= 0.0 && rel_tol >= 0.0,
+        InvalidOperation: "`abs_tol` and `rel_tol` must be non-negative"
+    );
+    polars_ensure!(
+        a.len() == b.len() || a.len() == 1 || b.len() == 1,
+        ShapeMismatch: "cannot compare series of lengths {} and {}", a.len(), b.len()
+    );
+    polars_ensure!(
+        a.dtype().is_numeric() && b.dtype().is_numeric(),
+        InvalidOperation: "`is_close` is not supported for dtypes {} and {}", a.dtype(), b.dtype()
+    );
+    let out = match (a.dtype(), b.dtype()) {
+        (DataType::Float32, DataType::Float32) => {
+            is_close_ca(a.f32()?, b.f32()?, abs_tol, rel_tol, nans_equal)
+        },
+        _ => {
+            let a = a.cast(&DataType::Float64)?;
+            let b = b.cast(&DataType::Float64)?;
+            is_close_ca(a.f64()?, b.f64()?, abs_tol, rel_tol, nans_equal)
+        },
+    };
+    Ok(out.with_name(a.name()))
+}
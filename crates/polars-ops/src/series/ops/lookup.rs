@@ -0,0 +1,187 @@
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// What [`LookupTable::lookup`] returns for keys that are not in the table.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LookupMissing {
+    /// Return null.
+    #[default]
+    Null,
+    /// Raise an error.
+    Raise,
+}
+
+struct LookupTableInner {
+    keys: Series,
+    values: Series,
+    /// The dtype the keys are compared in.
+    key_dtype: DataType,
+    /// Row-encoded key to its index in `values`.
+    index: PlHashMap<Vec<u8>, IdxSize>,
+}
+
+/// A mapping from unique keys to values.
+///
+/// The hash table is built once on construction. Clones share it, so a single table can be
+/// probed by many threads without being rebuilt for every batch.
+#[derive(Clone)]
+pub struct LookupTable(Arc<LookupTableInner>);
+
+/// Categoricals are compared by their string values, as the physical codes of two local
+/// categoricals don't correspond.
+fn to_key_repr(s: &Series) -> PolarsResult<Series> {
+    match s.dtype() {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => s.cast(&DataType::String),
+        _ => Ok(s.clone()),
+    }
+}
+
+fn encode(s: &Series) -> PolarsResult<BinaryOffsetChunked> {
+    _get_rows_encoded_ca(s.name(), &[s.clone()], &[false], &[false])
+}
+
+impl LookupTable {
+    /// Create a table that maps every key to the value at the same position.
+    pub fn new(keys: Series, values: Series) -> PolarsResult<Self> {
+        polars_ensure!(
+            keys.len() == values.len(),
+            ShapeMismatch: "lookup keys and values must have the same length, got {} and {}",
+            keys.len(), values.len()
+        );
+        let keys = keys.rechunk();
+        let values = values.rechunk();
+        let key_repr = to_key_repr(&keys)?;
+
+        let mut index = PlHashMap::with_capacity(keys.len());
+        for (idx, key) in encode(&key_repr)?.into_no_null_iter().enumerate() {
+            polars_ensure!(
+                index.insert(key.to_vec(), idx as IdxSize).is_none(),
+                Duplicate: "lookup keys must be unique, found duplicate key {}",
+                keys.get(idx)?
+            );
+        }
+
+        Ok(LookupTable(Arc::new(LookupTableInner {
+            key_dtype: key_repr.dtype().clone(),
+            keys,
+            values,
+            index,
+        })))
+    }
+
+    /// Create a table from the `key` and `value` columns of a frame.
+    pub fn from_frame(df: &DataFrame, key: &str, value: &str) -> PolarsResult<Self> {
+        Self::new(df.column(key)?.clone(), df.column(value)?.clone())
+    }
+
+    pub fn keys(&self) -> &Series {
+        &self.0.keys
+    }
+
+    pub fn values(&self) -> &Series {
+        &self.0.values
+    }
+
+    /// Replace every element of `s` by the value of its key.
+    ///
+    /// A null in `s` matches a null key. The output keeps the name of `s`.
+    pub fn lookup(&self, s: &Series, missing: LookupMissing) -> PolarsResult<Series> {
+        let inner = &self.0;
+        let mut probe = to_key_repr(s)?;
+        if probe.dtype() != &inner.key_dtype {
+            probe = probe.strict_cast(&inner.key_dtype)?;
+        }
+        let mut idx: IdxCa = encode(&probe)?
+            .into_no_null_iter()
+            .map(|key| inner.index.get(key).copied())
+            .collect();
+        idx.rename(s.name());
+
+        if missing == LookupMissing::Raise && idx.null_count() > 0 {
+            // Nulls without a null key are not considered missing.
+            let first_missing = idx
+                .iter()
+                .zip(s.is_not_null().into_no_null_iter())
+                .position(|(idx, is_valid)| idx.is_none() && is_valid);
+            if let Some(i) = first_missing {
+                polars_bail!(
+                    ComputeError: "key {} was not found in the lookup table", s.get(i)?
+                );
+            }
+        }
+
+        // SAFETY: the indices of the hash table are within the bounds of the values.
+        let out = unsafe { inner.values.take_unchecked(&idx) };
+        Ok(out.with_name(s.name()))
+    }
+}
+
+impl Debug for LookupTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LookupTable")
+            .field("keys", self.keys())
+            .field("values", self.values())
+            .finish()
+    }
+}
+
+impl PartialEq for LookupTable {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+            || (self.keys().equals_missing(other.keys())
+                && self.values().equals_missing(other.values()))
+    }
+}
+
+impl Hash for LookupTable {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.keys().len().hash(state);
+        self.keys().dtype().hash(state);
+        self.values().dtype().hash(state);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for LookupTable {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.keys(), self.values()).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for LookupTable {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (keys, values) = <(Series, Series)>::deserialize(deserializer)?;
+        LookupTable::new(keys, values).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lookup() -> PolarsResult<()> {
+        let table = LookupTable::new(
+            Series::new("k", [Some(1i64), Some(3), None]),
+            Series::new("v", ["one", "three", "null"]),
+        )?;
+        let s = Series::new("s", [Some(3i32), Some(2), None, Some(1)]);
+
+        let out = table.lookup(&s, LookupMissing::Null)?;
+        assert_eq!(out.name(), "s");
+        let expected = Series::new("s", [Some("three"), None, Some("null"), Some("one")]);
+        assert!(out.equals_missing(&expected));
+        assert!(table.lookup(&s, LookupMissing::Raise).is_err());
+
+        assert!(LookupTable::new(Series::new("k", [1, 1]), Series::new("v", [1, 2])).is_err());
+        Ok(())
+    }
+}
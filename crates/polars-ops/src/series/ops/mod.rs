@@ -1,10 +1,14 @@
 #[cfg(feature = "abs")]
 mod abs;
+mod agg_mode;
 #[cfg(feature = "approx_unique")]
 mod approx_algo;
+mod approx_quantile;
 #[cfg(feature = "approx_unique")]
 mod approx_unique;
 mod arg_min_max;
+#[cfg(feature = "bitwise")]
+mod bitwise;
 #[cfg(feature = "business")]
 mod business;
 mod clip;
@@ -29,6 +33,8 @@ mod int_range;
 mod interpolation;
 #[cfg(feature = "is_between")]
 mod is_between;
+#[cfg(feature = "is_close")]
+mod is_close;
 #[cfg(feature = "is_first_distinct")]
 mod is_first_distinct;
 #[cfg(feature = "is_in")]
@@ -39,6 +45,8 @@ mod is_last_distinct;
 mod is_unique;
 #[cfg(feature = "log")]
 mod log;
+#[cfg(feature = "lookup")]
+mod lookup;
 #[cfg(feature = "moment")]
 mod moment;
 mod negate;
@@ -66,11 +74,15 @@ mod various;
 
 #[cfg(feature = "abs")]
 pub use abs::*;
+pub use agg_mode::*;
 #[cfg(feature = "approx_unique")]
 pub use approx_algo::*;
+pub use approx_quantile::*;
 #[cfg(feature = "approx_unique")]
 pub use approx_unique::*;
 pub use arg_min_max::ArgAgg;
+#[cfg(feature = "bitwise")]
+pub use bitwise::*;
 #[cfg(feature = "business")]
 pub use business::*;
 pub use clip::*;
@@ -99,6 +111,8 @@ pub use interpolation::interpolate_by::*;
 pub use interpolation::*;
 #[cfg(feature = "is_between")]
 pub use is_between::*;
+#[cfg(feature = "is_close")]
+pub use is_close::*;
 #[cfg(feature = "is_first_distinct")]
 pub use is_first_distinct::*;
 #[cfg(feature = "is_in")]
@@ -109,6 +123,8 @@ pub use is_last_distinct::*;
 pub use is_unique::*;
 #[cfg(feature = "log")]
 pub use log::*;
+#[cfg(feature = "lookup")]
+pub use lookup::*;
 #[cfg(feature = "moment")]
 pub use moment::*;
 pub use negate::*;
@@ -1,7 +1,11 @@
-use arrow::array::BooleanArray;
+use arrow::bitmap::Bitmap;
 use arrow::compute::concatenate::concatenate_validities;
+use polars_core::prelude::sort::_nan_ordered_sort_key;
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca;
 use polars_core::prelude::*;
+use polars_core::POOL;
 use rand::prelude::*;
+use rayon::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -25,6 +29,11 @@ pub enum RankMethod {
 pub struct RankOptions {
     pub method: RankMethod,
     pub descending: bool,
+    /// Where `NaN` values of float columns are ranked.
+    pub nan_ordering: NanOrdering,
+    /// Return the ranks as a fraction of the number of values that are not null, or of the
+    /// number of distinct values for [`RankMethod::Dense`].
+    pub pct: bool,
 }
 
 impl Default for RankOptions {
@@ -32,6 +41,8 @@ impl Default for RankOptions {
         Self {
             method: RankMethod::Dense,
             descending: false,
+            nan_ordering: NanOrdering::Largest,
+            pct: false,
         }
     }
 }
@@ -43,49 +54,193 @@ fn get_random_seed() -> u64 {
     rng.next_u64()
 }
 
-unsafe fn rank_impl<F: FnMut(&mut [IdxSize])>(idxs: &IdxCa, neq: &BooleanArray, mut flush_ties: F) {
-    let mut ties_indices = Vec::with_capacity(128);
-    let mut idx_it = idxs.downcast_iter().flat_map(|arr| arr.values_iter());
-    let Some(first_idx) = idx_it.next() else {
-        return;
-    };
-    ties_indices.push(*first_idx);
-
-    for (eq_idx, idx) in idx_it.enumerate() {
-        if neq.value_unchecked(eq_idx) {
-            flush_ties(&mut ties_indices);
-            ties_indices.clear()
+trait RankValue: Copy {
+    fn from_rank(rank: IdxSize) -> Self;
+
+    fn average(first: IdxSize, last: IdxSize) -> Self;
+}
+
+impl RankValue for IdxSize {
+    fn from_rank(rank: IdxSize) -> Self {
+        rank
+    }
+
+    fn average(_first: IdxSize, _last: IdxSize) -> Self {
+        unreachable!("average ranks are floats")
+    }
+}
+
+impl RankValue for f64 {
+    fn from_rank(rank: IdxSize) -> Self {
+        rank as f64
+    }
+
+    fn average(first: IdxSize, last: IdxSize) -> Self {
+        0.5 * (first as f64 + last as f64)
+    }
+}
+
+/// Calls `flush_ties` with every run of ties in `idx`, where `neq(i)` tells whether the rows
+/// `idx[i]` and `idx[i + 1]` differ.
+fn for_each_ties<N, F>(idx: &mut [IdxSize], neq: N, mut flush_ties: F)
+where
+    N: Fn(usize) -> bool,
+    F: FnMut(&mut [IdxSize]),
+{
+    let mut start = 0;
+    for i in 0..idx.len().saturating_sub(1) {
+        if neq(i) {
+            flush_ties(&mut idx[start..=i]);
+            start = i + 1;
         }
+    }
+    if start < idx.len() {
+        flush_ties(&mut idx[start..])
+    }
+}
+
+struct Ranker {
+    method: RankMethod,
+    #[cfg(feature = "random")]
+    rng: Option<SmallRng>,
+}
 
-        ties_indices.push(*idx);
+impl Ranker {
+    fn new(method: RankMethod, _seed: Option<u64>) -> Self {
+        Self {
+            method,
+            #[cfg(feature = "random")]
+            rng: matches!(method, RankMethod::Random)
+                .then(|| SmallRng::seed_from_u64(_seed.unwrap_or_else(get_random_seed))),
+        }
+    }
+
+    /// Writes the ranks of the rows `idx`, which are sorted and not null, to `out` and returns
+    /// the largest rank.
+    ///
+    /// # Safety
+    /// The rows must be in bounds of `out`.
+    unsafe fn rank_sorted<T: RankValue, N: Fn(usize) -> bool>(
+        &mut self,
+        idx: &mut [IdxSize],
+        neq: N,
+        out: &mut [T],
+    ) -> IdxSize {
+        use RankMethod::*;
+        let mut rank = 1;
+        match self.method {
+            // The sort is stable, so the ties are in the order in which they appear.
+            Ordinal => {
+                for i in idx.iter() {
+                    *out.get_unchecked_mut(*i as usize) = T::from_rank(rank);
+                    rank += 1;
+                }
+            },
+            #[cfg(feature = "random")]
+            Random => {
+                let rng = self.rng.as_mut().unwrap();
+                for_each_ties(idx, neq, |ties| {
+                    ties.shuffle(&mut *rng);
+                    for i in ties.iter() {
+                        *out.get_unchecked_mut(*i as usize) = T::from_rank(rank);
+                        rank += 1;
+                    }
+                })
+            },
+            Average => for_each_ties(idx, neq, |ties| {
+                let first = rank;
+                rank += ties.len() as IdxSize;
+                let avg = T::average(first, rank - 1);
+                for i in ties.iter() {
+                    *out.get_unchecked_mut(*i as usize) = avg;
+                }
+            }),
+            Min => for_each_ties(idx, neq, |ties| {
+                for i in ties.iter() {
+                    *out.get_unchecked_mut(*i as usize) = T::from_rank(rank);
+                }
+                rank += ties.len() as IdxSize;
+            }),
+            Max => for_each_ties(idx, neq, |ties| {
+                rank += ties.len() as IdxSize;
+                for i in ties.iter() {
+                    *out.get_unchecked_mut(*i as usize) = T::from_rank(rank - 1);
+                }
+            }),
+            Dense => for_each_ties(idx, neq, |ties| {
+                for i in ties.iter() {
+                    *out.get_unchecked_mut(*i as usize) = T::from_rank(rank);
+                }
+                rank += 1;
+            }),
+        }
+        rank - 1
     }
-    flush_ties(&mut ties_indices);
 }
 
-fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) -> Series {
-    let len = s.len();
-    let null_count = s.null_count();
-    match len {
-        1 => {
-            return match method {
-                Average => Series::new(s.name(), &[1.0f64]),
-                _ => Series::new(s.name(), &[1 as IdxSize]),
-            };
-        },
-        0 => {
-            return match method {
-                Average => Float64Chunked::from_slice(s.name(), &[]).into_series(),
-                _ => IdxCa::from_slice(s.name(), &[]).into_series(),
-            };
-        },
-        _ => {},
+/// Ranks the rows of every partition of `idx`, given as `(start, end)` positions of its rows
+/// that are not null.
+///
+/// The rows of a partition are sorted and `neq(i)` tells whether the rows `idx[i]` and
+/// `idx[i + 1]` differ.
+#[allow(clippy::too_many_arguments)]
+fn rank_partitions<N: Fn(usize) -> bool>(
+    name: &str,
+    len: usize,
+    validity: Option<Bitmap>,
+    idx: &mut [IdxSize],
+    partitions: &[(usize, usize)],
+    neq: N,
+    method: RankMethod,
+    pct: bool,
+    seed: Option<u64>,
+) -> Series {
+    let mut ranker = Ranker::new(method, seed);
+    let neq = &neq;
+    let neq_from = |offset: usize| move |i: usize| neq(offset + i);
+
+    if pct || matches!(method, RankMethod::Average) {
+        let mut out = vec![0.0; len];
+        for &(start, end) in partitions {
+            let idx = &mut idx[start..end];
+            // SAFETY: the rows are in bounds.
+            let max_rank = unsafe { ranker.rank_sorted(idx, neq_from(start), &mut out) };
+            if pct {
+                // The share of the rows, or of the distinct values for dense ranks, that rank
+                // lower or equal.
+                let n = match method {
+                    RankMethod::Dense => max_rank as f64,
+                    _ => idx.len() as f64,
+                };
+                for i in idx.iter() {
+                    out[*i as usize] /= n;
+                }
+            }
+        }
+        Float64Chunked::from_vec_validity(name, out, validity).into_series()
+    } else {
+        let mut out = vec![0 as IdxSize; len];
+        for &(start, end) in partitions {
+            // SAFETY: the rows are in bounds.
+            unsafe { ranker.rank_sorted(&mut idx[start..end], neq_from(start), &mut out) };
+        }
+        IdxCa::from_vec_validity(name, out, validity).into_series()
     }
+}
 
+fn output_dtype(method: RankMethod, pct: bool) -> DataType {
+    match method {
+        RankMethod::Average => DataType::Float64,
+        _ if pct => DataType::Float64,
+        _ => IDX_DTYPE,
+    }
+}
+
+fn rank(s: &Series, method: RankMethod, descending: bool, pct: bool, seed: Option<u64>) -> Series {
+    let len = s.len();
+    let null_count = s.null_count();
     if null_count == len {
-        return match method {
-            Average => Float64Chunked::full_null(s.name(), len).into_series(),
-            _ => IdxCa::full_null(s.name(), len).into_series(),
-        };
+        return Series::full_null(s.name(), len, &output_dtype(method, pct));
     }
 
     let sort_idx_ca = s
@@ -95,96 +250,156 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
             ..Default::default()
         })
         .slice(0, len - null_count);
+    let mut idx = sort_idx_ca.into_no_null_iter().collect::<Vec<_>>();
 
     let chunk_refs: Vec<_> = s.chunks().iter().map(|c| &**c).collect();
     let validity = concatenate_validities(&chunk_refs);
 
-    use RankMethod::*;
-    if let Ordinal = method {
-        let mut out = vec![0 as IdxSize; s.len()];
-        let mut rank = 0;
-        for arr in sort_idx_ca.downcast_iter() {
-            for i in arr.values_iter() {
-                out[*i as usize] = rank + 1;
-                rank += 1;
-            }
-        }
-        IdxCa::from_vec_validity(s.name(), out, validity).into_series()
-    } else {
-        let sorted_values = unsafe { s.take_unchecked(&sort_idx_ca) };
-        let not_consecutive_same = sorted_values
-            .slice(1, sorted_values.len() - 1)
-            .not_equal(&sorted_values.slice(0, sorted_values.len() - 1))
-            .unwrap()
-            .rechunk();
-        let neq = not_consecutive_same.downcast_iter().next().unwrap();
+    let sorted_values = unsafe { s.take_slice_unchecked(&idx) };
+    let not_consecutive_same = sorted_values
+        .slice(1, sorted_values.len() - 1)
+        .not_equal(&sorted_values.slice(0, sorted_values.len() - 1))
+        .unwrap()
+        .rechunk();
+    let neq = not_consecutive_same.downcast_iter().next().unwrap();
+
+    let n = idx.len();
+    rank_partitions(
+        s.name(),
+        len,
+        validity,
+        &mut idx,
+        &[(0, n)],
+        // SAFETY: `i` is smaller than the number of rows that are not null minus one.
+        |i| unsafe { neq.value_unchecked(i) },
+        method,
+        pct,
+        seed,
+    )
+}
 
-        let mut rank = 1;
-        match method {
-            #[cfg(feature = "random")]
-            Random => unsafe {
-                let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_random_seed));
-                let mut out = vec![0 as IdxSize; s.len()];
-                rank_impl(&sort_idx_ca, neq, |ties| {
-                    ties.shuffle(&mut rng);
-                    for i in ties {
-                        *out.get_unchecked_mut(*i as usize) = rank;
-                        rank += 1;
-                    }
-                });
-                IdxCa::from_vec_validity(s.name(), out, validity).into_series()
-            },
-            Average => unsafe {
-                let mut out = vec![0.0; s.len()];
-                rank_impl(&sort_idx_ca, neq, |ties| {
-                    let first = rank;
-                    rank += ties.len() as IdxSize;
-                    let last = rank - 1;
-                    let avg = 0.5 * (first as f64 + last as f64);
-                    for i in ties {
-                        *out.get_unchecked_mut(*i as usize) = avg;
-                    }
-                });
-                Float64Chunked::from_vec_validity(s.name(), out, validity).into_series()
-            },
-            Min => unsafe {
-                let mut out = vec![0 as IdxSize; s.len()];
-                rank_impl(&sort_idx_ca, neq, |ties| {
-                    for i in ties.iter() {
-                        *out.get_unchecked_mut(*i as usize) = rank;
-                    }
-                    rank += ties.len() as IdxSize;
-                });
-                IdxCa::from_vec_validity(s.name(), out, validity).into_series()
-            },
-            Max => unsafe {
-                let mut out = vec![0 as IdxSize; s.len()];
-                rank_impl(&sort_idx_ca, neq, |ties| {
-                    rank += ties.len() as IdxSize;
-                    for i in ties {
-                        *out.get_unchecked_mut(*i as usize) = rank - 1;
-                    }
-                });
-                IdxCa::from_vec_validity(s.name(), out, validity).into_series()
-            },
-            Dense => unsafe {
-                let mut out = vec![0 as IdxSize; s.len()];
-                rank_impl(&sort_idx_ca, neq, |ties| {
-                    for i in ties {
-                        *out.get_unchecked_mut(*i as usize) = rank;
-                    }
-                    rank += 1;
-                });
-                IdxCa::from_vec_validity(s.name(), out, validity).into_series()
-            },
-            Ordinal => unreachable!(),
+/// Ranks the values of `s` within the partitions of rows that have the same values in `by`.
+fn rank_by(
+    s: &Series,
+    by: &[Series],
+    method: RankMethod,
+    descending: bool,
+    pct: bool,
+    seed: Option<u64>,
+) -> PolarsResult<Series> {
+    let len = s.len();
+    for b in by {
+        polars_ensure!(
+            b.len() == len,
+            ShapeMismatch: "`rank_by` expected keys of length {}, got {}", len, b.len()
+        );
+    }
+    if by.is_empty() {
+        return Ok(rank(s, method, descending, pct, seed));
+    }
+
+    // One sort by the keys and then the values gives the partitions and the order within them.
+    let keys = _get_rows_encoded_ca("", by, &vec![false; by.len()], &vec![false; by.len()])?;
+    let mut columns = by.to_vec();
+    columns.push(s.clone());
+    let mut descending_flags = vec![false; by.len()];
+    descending_flags.push(descending);
+    let mut nulls_last = vec![false; by.len()];
+    nulls_last.push(true);
+    let rows = _get_rows_encoded_ca("", &columns, &descending_flags, &nulls_last)?;
+    let keys = keys.downcast_iter().next().unwrap();
+    let rows = rows.downcast_iter().next().unwrap();
+
+    let mut idx = (0..len as IdxSize).collect::<Vec<_>>();
+    // SAFETY: the indices are in bounds.
+    POOL.install(|| {
+        idx.par_sort_by(|a, b| unsafe {
+            rows.value_unchecked(*a as usize)
+                .cmp(rows.value_unchecked(*b as usize))
+        })
+    });
+
+    let chunk_refs: Vec<_> = s.chunks().iter().map(|c| &**c).collect();
+    let validity = concatenate_validities(&chunk_refs);
+    let is_valid = |i: IdxSize| validity.as_ref().map_or(true, |v| v.get_bit(i as usize));
+
+    let mut neq = Vec::with_capacity(len.saturating_sub(1));
+    let mut partitions = vec![];
+    let mut start = 0;
+    let mut end = 0;
+    for (i, w) in idx.windows(2).enumerate() {
+        // SAFETY: the indices are in bounds.
+        let (new_partition, new_value) = unsafe {
+            (
+                keys.value_unchecked(w[0] as usize) != keys.value_unchecked(w[1] as usize),
+                rows.value_unchecked(w[0] as usize) != rows.value_unchecked(w[1] as usize),
+            )
+        };
+        neq.push(new_value);
+        if is_valid(w[0]) {
+            end = i + 1;
+        }
+        if new_partition {
+            partitions.push((start, end));
+            start = i + 1;
+            end = start;
         }
     }
+    if idx.last().is_some_and(|i| is_valid(*i)) {
+        end = len;
+    }
+    if len > 0 {
+        partitions.push((start, end));
+    }
+
+    Ok(rank_partitions(
+        s.name(),
+        len,
+        validity,
+        &mut idx,
+        &partitions,
+        |i| neq[i],
+        method,
+        pct,
+        seed,
+    ))
 }
 
 pub trait SeriesRank: SeriesSealed {
     fn rank(&self, options: RankOptions, seed: Option<u64>) -> Series {
-        rank(self.as_series(), options.method, options.descending, seed)
+        let s = self.as_series();
+        let RankOptions {
+            method,
+            descending,
+            pct,
+            ..
+        } = options;
+        match _nan_ordered_sort_key(s, options.nan_ordering) {
+            Some(key) => rank(&key, method, descending, pct, seed),
+            None => rank(s, method, descending, pct, seed),
+        }
+    }
+
+    /// Rank the values within the partitions of rows that have the same values in `by`, like
+    /// `rank().over(by)` but in a single sort.
+    fn rank_by(
+        &self,
+        by: &[Series],
+        options: RankOptions,
+        seed: Option<u64>,
+    ) -> PolarsResult<Series> {
+        let s = self.as_series();
+        let RankOptions {
+            method,
+            descending,
+            pct,
+            ..
+        } = options;
+        match _nan_ordered_sort_key(s, options.nan_ordering) {
+            Some(key) => rank_by(&key, by, method, descending, pct, seed),
+            None => rank_by(s, by, method, descending, pct, seed),
+        }
+        .map(|out| out.with_name(s.name()))
     }
 }
 
@@ -194,11 +409,33 @@ impl SeriesRank for Series {}
 mod test {
     use super::*;
 
+    #[test]
+    fn test_rank_nan_ordering() -> PolarsResult<()> {
+        let s = Series::new("a", &[1.0, f64::NAN, 0.0, f64::NAN]);
+        let ranks = |nan_ordering| {
+            let options = RankOptions {
+                nan_ordering,
+                ..Default::default()
+            };
+            Vec::from(s.rank(options, None).idx().unwrap())
+        };
+        assert_eq!(
+            ranks(NanOrdering::Largest),
+            &[Some(2), Some(3), Some(1), Some(3)]
+        );
+        assert_eq!(
+            ranks(NanOrdering::Smallest),
+            &[Some(3), Some(1), Some(2), Some(1)]
+        );
+        assert_eq!(ranks(NanOrdering::Null), &[Some(2), None, Some(1), None]);
+        Ok(())
+    }
+
     #[test]
     fn test_rank() -> PolarsResult<()> {
         let s = Series::new("a", &[1, 2, 3, 2, 2, 3, 0]);
 
-        let out = rank(&s, RankMethod::Ordinal, false, None)
+        let out = rank(&s, RankMethod::Ordinal, false, false, None)
             .idx()?
             .into_no_null_iter()
             .collect::<Vec<_>>();
@@ -206,7 +443,7 @@ mod test {
 
         #[cfg(feature = "random")]
         {
-            let out = rank(&s, RankMethod::Random, false, None)
+            let out = rank(&s, RankMethod::Random, false, false, None)
                 .idx()?
                 .into_no_null_iter()
                 .collect::<Vec<_>>();
@@ -219,25 +456,25 @@ mod test {
             assert_ne!(out[3], out[4]);
         }
 
-        let out = rank(&s, RankMethod::Dense, false, None)
+        let out = rank(&s, RankMethod::Dense, false, false, None)
             .idx()?
             .into_no_null_iter()
             .collect::<Vec<_>>();
         assert_eq!(out, &[2, 3, 4, 3, 3, 4, 1]);
 
-        let out = rank(&s, RankMethod::Max, false, None)
+        let out = rank(&s, RankMethod::Max, false, false, None)
             .idx()?
             .into_no_null_iter()
             .collect::<Vec<_>>();
         assert_eq!(out, &[2, 5, 7, 5, 5, 7, 1]);
 
-        let out = rank(&s, RankMethod::Min, false, None)
+        let out = rank(&s, RankMethod::Min, false, false, None)
             .idx()?
             .into_no_null_iter()
             .collect::<Vec<_>>();
         assert_eq!(out, &[2, 3, 6, 3, 3, 6, 1]);
 
-        let out = rank(&s, RankMethod::Average, false, None)
+        let out = rank(&s, RankMethod::Average, false, false, None)
             .f64()?
             .into_no_null_iter()
             .collect::<Vec<_>>();
@@ -248,7 +485,7 @@ mod test {
             &[Some(1), Some(2), Some(3), Some(2), None, None, Some(0)],
         );
 
-        let out = rank(&s, RankMethod::Average, false, None)
+        let out = rank(&s, RankMethod::Average, false, false, None)
             .f64()?
             .into_iter()
             .collect::<Vec<_>>();
@@ -278,7 +515,7 @@ mod test {
                 Some(8),
             ],
         );
-        let out = rank(&s, RankMethod::Max, false, None)
+        let out = rank(&s, RankMethod::Max, false, false, None)
             .idx()?
             .into_iter()
             .collect::<Vec<_>>();
@@ -302,12 +539,12 @@ mod test {
     #[test]
     fn test_rank_all_null() -> PolarsResult<()> {
         let s = UInt32Chunked::new("", &[None, None, None]).into_series();
-        let out = rank(&s, RankMethod::Average, false, None)
+        let out = rank(&s, RankMethod::Average, false, false, None)
             .f64()?
             .into_iter()
             .collect::<Vec<_>>();
         assert_eq!(out, &[None, None, None]);
-        let out = rank(&s, RankMethod::Dense, false, None)
+        let out = rank(&s, RankMethod::Dense, false, false, None)
             .idx()?
             .into_iter()
             .collect::<Vec<_>>();
@@ -318,16 +555,45 @@ mod test {
     #[test]
     fn test_rank_empty() {
         let s = UInt32Chunked::from_slice("", &[]).into_series();
-        let out = rank(&s, RankMethod::Average, false, None);
+        let out = rank(&s, RankMethod::Average, false, false, None);
         assert_eq!(out.dtype(), &DataType::Float64);
-        let out = rank(&s, RankMethod::Max, false, None);
+        let out = rank(&s, RankMethod::Max, false, false, None);
         assert_eq!(out.dtype(), &IDX_DTYPE);
     }
 
+    #[test]
+    fn test_rank_by() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(3), Some(1), None, Some(2), Some(2), Some(1)]);
+        let by = Series::new("g", &[1, 2, 1, 1, 2, 1]);
+
+        let out = rank_by(&s, &[by.clone()], RankMethod::Min, false, false, None)?
+            .idx()?
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(out, &[Some(3), Some(1), None, Some(2), Some(2), Some(1)]);
+
+        let out = rank_by(&s, &[by], RankMethod::Max, true, true, None)?
+            .f64()?
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[
+                Some(1.0 / 3.0),
+                Some(1.0),
+                None,
+                Some(2.0 / 3.0),
+                Some(0.5),
+                Some(1.0)
+            ]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_rank_reverse() -> PolarsResult<()> {
         let s = Series::new("", &[None, Some(1), Some(1), Some(5), None]);
-        let out = rank(&s, RankMethod::Dense, true, None)
+        let out = rank(&s, RankMethod::Dense, true, false, None)
             .idx()?
             .into_iter()
             .collect::<Vec<_>>();
@@ -9,6 +9,14 @@ pub trait RoundSeries: SeriesSealed {
     fn round(&self, decimals: u32) -> PolarsResult<Series> {
         let s = self.as_series();
 
+        #[cfg(feature = "dtype-f16")]
+        if s.dtype() == &DataType::Float16 {
+            return s
+                .cast(&DataType::Float32)?
+                .round(decimals)?
+                .cast(&DataType::Float16);
+        }
+
         if let Ok(ca) = s.f32() {
             return if decimals == 0 {
                 let s = ca.apply_values(|val| val.round()).into_series();
@@ -41,6 +49,13 @@ pub trait RoundSeries: SeriesSealed {
     fn round_sig_figs(&self, digits: i32) -> PolarsResult<Series> {
         let s = self.as_series();
         polars_ensure!(digits >= 1, InvalidOperation: "digits must be an integer >= 1");
+        #[cfg(feature = "dtype-f16")]
+        if s.dtype() == &DataType::Float16 {
+            return s
+                .cast(&DataType::Float32)?
+                .round_sig_figs(digits)?
+                .cast(&DataType::Float16);
+        }
         polars_ensure!(s.dtype().is_numeric(), InvalidOperation: "round_sig_figs can only be used on numeric types" );
         with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
             let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
@@ -74,6 +74,10 @@ pub trait SeriesMethods: SeriesSealed {
     /// Checks if a [`Series`] is sorted. Tries to fail fast.
     fn is_sorted(&self, options: SortOptions) -> PolarsResult<bool> {
         let s = self.as_series();
+        #[cfg(feature = "dtype-f16")]
+        if s.dtype() == &DataType::Float16 {
+            return s.cast(&DataType::Float32)?.is_sorted(options);
+        }
         let null_count = s.null_count();
 
         // fast paths
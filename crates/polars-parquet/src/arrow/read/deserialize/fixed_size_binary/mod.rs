@@ -3,6 +3,23 @@ mod dictionary;
 mod nested;
 mod utils;
 
+use arrow::array::{Array, FixedSizeBinaryArray, PrimitiveArray};
+use arrow::datatypes::ArrowDataType;
+use arrow::types::f16;
 pub use basic::Iter;
 pub use dictionary::{DictIter, NestedDictIter};
 pub use nested::NestedIter;
+
+/// Read `f16` values that are stored as their 2 little-endian bytes.
+pub(super) fn to_float16(array: &FixedSizeBinaryArray) -> PrimitiveArray<f16> {
+    let values = array
+        .values()
+        .chunks_exact(2)
+        .map(|v| f16::from_bits(u16::from_le_bytes([v[0], v[1]])))
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(
+        ArrowDataType::Float16,
+        values.into(),
+        array.validity().cloned(),
+    )
+}
@@ -183,6 +183,22 @@ where
                 |x: i64| x as u64,
             ))
         },
+        Primitive(Float16) => {
+            init.push(InitNested::Primitive(field.is_nullable));
+            types.pop();
+            let iter = fixed_size_binary::NestedIter::new(
+                columns.pop().unwrap(),
+                init,
+                ArrowDataType::FixedSizeBinary(2),
+                num_rows,
+                chunk_size,
+            );
+            primitive(
+                iter.map(|x| {
+                    x.map(|(nested, array)| (nested, fixed_size_binary::to_float16(&array)))
+                }),
+            )
+        },
         Primitive(Float32) => {
             init.push(InitNested::Primitive(field.is_nullable));
             types.pop();
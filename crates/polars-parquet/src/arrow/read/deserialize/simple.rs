@@ -127,6 +127,16 @@ pub fn page_iter_to_arrays<'a, I: PagesIter + 'a>(
         (PhysicalType::FixedLenByteArray(_), FixedSizeBinary(_)) => dyn_iter(
             fixed_size_binary::Iter::new(pages, data_type, num_rows, chunk_size),
         ),
+        (PhysicalType::FixedLenByteArray(2), Float16) => {
+            let pages = fixed_size_binary::Iter::new(
+                pages,
+                ArrowDataType::FixedSizeBinary(2),
+                num_rows,
+                chunk_size,
+            );
+            let arrays = pages.map(|x| x.map(|x| fixed_size_binary::to_float16(&x).boxed()));
+            Box::new(arrays) as _
+        },
         (PhysicalType::FixedLenByteArray(12), Interval(IntervalUnit::YearMonth)) => {
             let n = 12;
             let pages = fixed_size_binary::Iter::new(
@@ -1,5 +1,5 @@
 use arrow::array::*;
-use arrow::types::{days_ms, i256};
+use arrow::types::{days_ms, f16, i256};
 use ethnum::I256;
 use polars_error::PolarsResult;
 
@@ -140,3 +140,24 @@ pub(super) fn push_days_ms(
 
     Ok(())
 }
+
+pub(super) fn push_f16(
+    from: Option<&FixedLenStatistics>,
+    min: &mut dyn MutableArray,
+    max: &mut dyn MutableArray,
+) -> PolarsResult<()> {
+    let min = min
+        .as_mut_any()
+        .downcast_mut::<MutablePrimitiveArray<f16>>()
+        .unwrap();
+    let max = max
+        .as_mut_any()
+        .downcast_mut::<MutablePrimitiveArray<f16>>()
+        .unwrap();
+
+    let convert = |x: &[u8]| f16::from_bits(u16::from_le_bytes(x.try_into().unwrap()));
+    min.push(from.and_then(|s| s.min_value.as_deref().map(convert)));
+    max.push(from.and_then(|s| s.max_value.as_deref().map(convert)));
+
+    Ok(())
+}
@@ -489,6 +489,7 @@ fn push(
                 })
             }
         },
+        Float16 => fixlen::push_f16(rmap!(from, expect_as_fixedlen), min, max),
         Float32 => primitive::push::<f32, f32, _>(rmap!(from, expect_as_float), min, max, Ok),
         Float64 => primitive::push::<f64, f64, _>(rmap!(from, expect_as_double), min, max, Ok),
         Decimal(_, _) => match physical_type {
@@ -5,20 +5,17 @@ use polars_error::{PolarsError, PolarsResult};
 
 use super::schema::schema_to_metadata_key;
 use super::{to_parquet_schema, ThriftFileMetaData, WriteOptions};
-use crate::parquet::metadata::{KeyValue, SchemaDescriptor};
+use crate::parquet::metadata::{KeyValue, SchemaDescriptor, SortingColumn};
 use crate::parquet::write::{RowGroupIterColumns, WriteOptions as FileWriteOptions};
 
 /// Attaches [`ArrowSchema`] to `key_value_metadata`
 pub fn add_arrow_schema(
     schema: &ArrowSchema,
     key_value_metadata: Option<Vec<KeyValue>>,
-) -> Option<Vec<KeyValue>> {
-    key_value_metadata
-        .map(|mut x| {
-            x.push(schema_to_metadata_key(schema));
-            x
-        })
-        .or_else(|| Some(vec![schema_to_metadata_key(schema)]))
+) -> PolarsResult<Option<Vec<KeyValue>>> {
+    let mut key_value_metadata = key_value_metadata.unwrap_or_default();
+    key_value_metadata.push(schema_to_metadata_key(schema)?);
+    Ok(Some(key_value_metadata))
 }
 
 /// An interface to write a parquet to a [`Write`]
@@ -75,9 +72,20 @@ impl<W: Write> FileWriter<W> {
         Ok(self.writer.write(row_group)?)
     }
 
+    /// Writes a row group to the file and declares the columns by which its rows are sorted.
+    pub fn write_with_sorting_columns(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, PolarsError>,
+        sorting_columns: Option<&[SortingColumn]>,
+    ) -> PolarsResult<()> {
+        Ok(self
+            .writer
+            .write_with_sorting_columns(row_group, sorting_columns)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> PolarsResult<u64> {
-        let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata);
+        let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata)?;
         Ok(self.writer.end(key_value_metadata)?)
     }
 
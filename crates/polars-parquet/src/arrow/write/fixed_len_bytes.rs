@@ -1,9 +1,10 @@
 use arrow::array::{Array, FixedSizeBinaryArray, PrimitiveArray};
-use arrow::types::i256;
+use arrow::datatypes::ArrowDataType;
+use arrow::types::{f16, i256};
 use polars_error::PolarsResult;
 
 use super::binary::ord_binary;
-use super::{utils, StatisticsOptions, WriteOptions};
+use super::{nested, utils, Nested, StatisticsOptions, WriteOptions};
 use crate::arrow::read::schema::is_nullable;
 use crate::parquet::encoding::Encoding;
 use crate::parquet::page::DataPage;
@@ -59,6 +60,49 @@ pub fn array_to_page(
     )
 }
 
+pub fn nested_array_to_page(
+    array: &FixedSizeBinaryArray,
+    options: WriteOptions,
+    type_: PrimitiveType,
+    nested: &[Nested],
+    statistics: Option<FixedLenStatistics>,
+) -> PolarsResult<DataPage> {
+    let is_optional = is_nullable(&type_.field_info);
+
+    let mut buffer = vec![];
+    let (repetition_levels_byte_length, definition_levels_byte_length) =
+        nested::write_rep_and_def(options.version, nested, &mut buffer)?;
+
+    encode_plain(array, is_optional, &mut buffer);
+
+    utils::build_plain_page(
+        buffer,
+        nested::num_values(nested),
+        nested[0].len(),
+        array.null_count(),
+        repetition_levels_byte_length,
+        definition_levels_byte_length,
+        statistics.map(|x| x.serialize()),
+        type_,
+        options,
+        Encoding::Plain,
+    )
+}
+
+/// Store `f16` values as their 2 little-endian bytes.
+pub(super) fn float16_to_fixed_size_binary(array: &PrimitiveArray<f16>) -> FixedSizeBinaryArray {
+    let mut values = Vec::<u8>::with_capacity(2 * array.len());
+    array
+        .values()
+        .iter()
+        .for_each(|x| values.extend_from_slice(&x.to_le_bytes()));
+    FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(2),
+        values.into(),
+        array.validity().cloned(),
+    )
+}
+
 pub(super) fn build_statistics(
     array: &FixedSizeBinaryArray,
     primitive_type: PrimitiveType,
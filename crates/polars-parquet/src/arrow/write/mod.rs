@@ -37,7 +37,7 @@ pub use utils::write_def_levels;
 pub use crate::parquet::compression::{BrotliLevel, CompressionOptions, GzipLevel, ZstdLevel};
 pub use crate::parquet::encoding::Encoding;
 pub use crate::parquet::metadata::{
-    Descriptor, FileMetaData, KeyValue, SchemaDescriptor, ThriftFileMetaData,
+    Descriptor, FileMetaData, KeyValue, SchemaDescriptor, SortingColumn, ThriftFileMetaData,
 };
 pub use crate::parquet::page::{CompressedDataPage, CompressedPage, Page};
 use crate::parquet::schema::types::PrimitiveType as ParquetPrimitiveType;
@@ -539,6 +539,12 @@ pub fn array_to_page_simple(
             };
             fixed_len_bytes::array_to_page(&array, options, type_, statistics)
         },
+        ArrowDataType::Float16 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let array = fixed_len_bytes::float16_to_fixed_size_binary(array);
+            // The bytes don't order like the values, so min and max would be misleading.
+            fixed_len_bytes::array_to_page(&array, options, type_, None)
+        },
         ArrowDataType::FixedSizeBinary(_) => {
             let array = array.as_any().downcast_ref().unwrap();
             let statistics = if options.has_statistics() {
@@ -786,6 +792,11 @@ fn array_to_page_nested(
             let array = array.as_any().downcast_ref().unwrap();
             primitive::nested_array_to_page::<i64, i64>(array, options, type_, nested)
         },
+        Float16 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let array = fixed_len_bytes::float16_to_fixed_size_binary(array);
+            fixed_len_bytes::nested_array_to_page(&array, options, type_, nested, None)
+        },
         Float32 => {
             let array = array.as_any().downcast_ref().unwrap();
             primitive::nested_array_to_page::<f32, f32>(array, options, type_, nested)
@@ -46,7 +46,7 @@ fn convert_data_type(data_type: ArrowDataType) -> ArrowDataType {
     }
 }
 
-pub fn schema_to_metadata_key(schema: &ArrowSchema) -> KeyValue {
+pub fn schema_to_metadata_key(schema: &ArrowSchema) -> PolarsResult<KeyValue> {
     // Convert schema until more arrow readers are aware of binview
     let serialized_schema = if schema.fields.iter().any(|field| field.data_type.is_view()) {
         let fields = schema
@@ -55,9 +55,9 @@ pub fn schema_to_metadata_key(schema: &ArrowSchema) -> KeyValue {
             .map(|field| convert_field(field.clone()))
             .collect::<Vec<_>>();
         let schema = ArrowSchema::from(fields);
-        schema_to_bytes(&schema, &default_ipc_fields(&schema.fields))
+        schema_to_bytes(&schema, &default_ipc_fields(&schema.fields))?
     } else {
-        schema_to_bytes(schema, &default_ipc_fields(&schema.fields))
+        schema_to_bytes(schema, &default_ipc_fields(&schema.fields))?
     };
 
     // manually prepending the length to the schema as arrow uses the legacy IPC format
@@ -70,10 +70,10 @@ pub fn schema_to_metadata_key(schema: &ArrowSchema) -> KeyValue {
 
     let encoded = general_purpose::STANDARD.encode(&len_prefix_schema);
 
-    KeyValue {
+    Ok(KeyValue {
         key: ARROW_SCHEMA_META_KEY.to_string(),
         value: Some(encoded),
-    }
+    })
 }
 
 /// Creates a [`ParquetType`] from a [`Field`].
@@ -306,6 +306,15 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             let dict_field = Field::new(name.as_str(), value.as_ref().clone(), field.is_nullable);
             to_parquet_type(&dict_field)
         },
+        // Parquet has no logical type for `f16` here, the arrow schema restores it on read.
+        ArrowDataType::Float16 => Ok(ParquetType::try_from_primitive(
+            name,
+            PhysicalType::FixedLenByteArray(2),
+            repetition,
+            None,
+            None,
+            None,
+        )?),
         ArrowDataType::FixedSizeBinary(size) => Ok(ParquetType::try_from_primitive(
             name,
             PhysicalType::FixedLenByteArray(*size),
@@ -180,7 +180,7 @@ where
                                 .collect::<Vec<_>>(),
                         )
                     };
-                    let kv_meta = add_arrow_schema(&this.schema, metadata);
+                    let kv_meta = add_arrow_schema(&this.schema, metadata)?;
 
                     this.task = Some(Box::pin(async move {
                         writer.end(kv_meta).map_err(to_compute_err).await?;
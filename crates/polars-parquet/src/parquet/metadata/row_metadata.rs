@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use super::column_chunk_metadata::ColumnChunkMetaData;
 use super::schema_descriptor::SchemaDescriptor;
+use super::sort::SortingColumn;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::write::ColumnOffsetsMetadata;
 
@@ -14,6 +15,7 @@ pub struct RowGroupMetaData {
     columns: Vec<ColumnChunkMetaData>,
     num_rows: usize,
     total_byte_size: usize,
+    sorting_columns: Option<Vec<SortingColumn>>,
 }
 
 impl RowGroupMetaData {
@@ -27,6 +29,7 @@ impl RowGroupMetaData {
             columns,
             num_rows,
             total_byte_size,
+            sorting_columns: None,
         }
     }
 
@@ -45,6 +48,11 @@ impl RowGroupMetaData {
         self.total_byte_size
     }
 
+    /// The columns by which the rows of this row group are sorted, if known.
+    pub fn sorting_columns(&self) -> Option<&[SortingColumn]> {
+        self.sorting_columns.as_deref()
+    }
+
     /// Total size of all compressed column data in this row group.
     pub fn compressed_size(&self) -> usize {
         self.columns
@@ -71,11 +79,19 @@ impl RowGroupMetaData {
                 ColumnChunkMetaData::try_from_thrift(descriptor.clone(), column_chunk)
             })
             .collect::<ParquetResult<Vec<_>>>()?;
+        // Invalid sorting columns are ignored as a whole, the order is lexicographical.
+        let sorting_columns = rg.sorting_columns.and_then(|sorting_columns| {
+            sorting_columns
+                .iter()
+                .map(|c| SortingColumn::try_from_thrift(c).filter(|c| c.column_idx < columns.len()))
+                .collect::<Option<Vec<_>>>()
+        });
 
         Ok(RowGroupMetaData {
             columns,
             num_rows,
             total_byte_size,
+            sorting_columns,
         })
     }
 
@@ -94,7 +110,9 @@ impl RowGroupMetaData {
             columns: self.columns.into_iter().map(|v| v.into_thrift()).collect(),
             total_byte_size: self.total_byte_size as i64,
             num_rows: self.num_rows as i64,
-            sorting_columns: None,
+            sorting_columns: self
+                .sorting_columns
+                .map(|cols| cols.into_iter().map(SortingColumn::to_thrift).collect()),
             file_offset,
             total_compressed_size,
             ordinal: None,
@@ -24,6 +24,37 @@ pub enum SortOrder {
     Undefined,
 }
 
+/// A leaf column by which the rows of a row group are sorted.
+///
+/// The sorting columns of a row group define a lexicographical order: only the first one is
+/// sorted on its own, the next ones are sorted within equal values of the preceding ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_types", derive(Deserialize, Serialize))]
+pub struct SortingColumn {
+    /// The index of the leaf column in the row group.
+    pub column_idx: usize,
+    pub descending: bool,
+    pub nulls_first: bool,
+}
+
+impl SortingColumn {
+    pub(crate) fn try_from_thrift(column: &parquet_format_safe::SortingColumn) -> Option<Self> {
+        Some(Self {
+            column_idx: column.column_idx.try_into().ok()?,
+            descending: column.descending,
+            nulls_first: column.nulls_first,
+        })
+    }
+
+    pub(crate) fn to_thrift(self) -> parquet_format_safe::SortingColumn {
+        parquet_format_safe::SortingColumn {
+            column_idx: self.column_idx as i32,
+            descending: self.descending,
+            nulls_first: self.nulls_first,
+        }
+    }
+}
+
 /// Returns sort order for a physical/logical type.
 pub fn get_sort_order(
     logical_type: &Option<PrimitiveLogicalType>,
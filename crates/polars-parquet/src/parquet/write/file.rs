@@ -9,7 +9,7 @@ use super::row_group::write_row_group;
 use super::{RowGroupIterColumns, WriteOptions};
 use crate::parquet::error::{ParquetError, ParquetResult};
 pub use crate::parquet::metadata::KeyValue;
-use crate::parquet::metadata::{SchemaDescriptor, ThriftFileMetaData};
+use crate::parquet::metadata::{SchemaDescriptor, SortingColumn, ThriftFileMetaData};
 use crate::parquet::write::State;
 use crate::parquet::{FOOTER_SIZE, PARQUET_MAGIC};
 
@@ -150,6 +150,21 @@ impl<W: Write> FileWriter<W> {
     ///
     /// This call is IO-bounded
     pub fn write<E>(&mut self, row_group: RowGroupIterColumns<'_, E>) -> ParquetResult<()>
+    where
+        ParquetError: From<E>,
+        E: std::error::Error,
+    {
+        self.write_with_sorting_columns(row_group, None)
+    }
+
+    /// Writes a row group to the file and declares the columns by which its rows are sorted.
+    ///
+    /// This call is IO-bounded
+    pub fn write_with_sorting_columns<E>(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, E>,
+        sorting_columns: Option<&[SortingColumn]>,
+    ) -> ParquetResult<()>
     where
         ParquetError: From<E>,
         E: std::error::Error,
@@ -158,13 +173,15 @@ impl<W: Write> FileWriter<W> {
             self.start()?;
         }
         let ordinal = self.row_groups.len();
-        let (group, specs, size) = write_row_group(
+        let (mut group, specs, size) = write_row_group(
             &mut self.writer,
             self.offset,
             self.schema.columns(),
             row_group,
             ordinal,
         )?;
+        group.sorting_columns =
+            sorting_columns.map(|columns| columns.iter().map(|c| c.to_thrift()).collect());
         self.offset += size;
         self.row_groups.push(group);
         self.page_specs.push(specs);
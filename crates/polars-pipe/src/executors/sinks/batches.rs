@@ -0,0 +1,192 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::thread::JoinHandle;
+
+use crossbeam_channel::{bounded, Receiver, Sender};
+use polars_core::prelude::*;
+use polars_plan::prelude::BatchCallback;
+
+use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+use crate::pipeline::morsels_per_sink;
+
+/// Splits the incoming frames into batches of `chunk_size` rows before they are handed to the
+/// callback.
+struct Batcher {
+    callback: BatchCallback,
+    chunk_size: Option<usize>,
+    pending: Option<DataFrame>,
+}
+
+impl Batcher {
+    fn emit(&self, mut df: DataFrame) -> PolarsResult<()> {
+        if df.n_chunks() > 1 {
+            df.as_single_chunk();
+        }
+        self.callback.call(df)
+    }
+
+    fn push(&mut self, df: DataFrame) -> PolarsResult<()> {
+        let Some(chunk_size) = self.chunk_size else {
+            return self.emit(df);
+        };
+        let mut pending = match self.pending.take() {
+            Some(mut pending) => {
+                pending.vstack_mut(&df)?;
+                pending
+            },
+            None => df,
+        };
+        while pending.height() >= chunk_size {
+            self.emit(pending.slice(0, chunk_size))?;
+            pending = pending.slice(chunk_size as i64, usize::MAX);
+        }
+        if pending.height() > 0 {
+            self.pending = Some(pending);
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> PolarsResult<()> {
+        match self.pending.take() {
+            Some(pending) => self.emit(pending),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A chunk and the pipeline thread that sank it.
+type Message = Option<(usize, DataChunk)>;
+
+fn init_callback_thread(
+    receiver: Receiver<Message>,
+    mut batcher: Batcher,
+    failed: Arc<AtomicBool>,
+) -> JoinHandle<PolarsResult<()>> {
+    std::thread::spawn(move || {
+        // The chunks that may still be preceded by a chunk that hasn't arrived, by chunk index
+        // and then by arrival, as a thread sinks the chunks of a morsel in order.
+        let mut pending = BTreeMap::new();
+        let mut n_received = 0usize;
+        let mut result = Ok(());
+        let push = |result: &mut PolarsResult<()>,
+                    batcher: &mut Batcher,
+                    chunks: BTreeMap<(IdxSize, usize), DataFrame>| {
+            for df in chunks.into_values() {
+                // After a failure the remaining chunks are only drained, so that the pipeline
+                // threads don't block on a full channel.
+                if result.is_ok() {
+                    *result = batcher.push(df);
+                    if result.is_err() {
+                        failed.store(true, Ordering::Relaxed);
+                    }
+                }
+            }
+        };
+
+        while let Ok(message) = receiver.recv() {
+            let Some((thread_no, chunk)) = message else {
+                push(&mut result, &mut batcher, std::mem::take(&mut pending));
+                return result.and_then(|_| batcher.finish());
+            };
+            let chunk_index = chunk.chunk_index;
+            if chunk.data.height() > 0 {
+                pending.insert((chunk_index, n_received), chunk.data);
+                n_received += 1;
+            }
+            // The morsels of a batch of the source are numbered in order, and the first one is
+            // sunk by the first thread. A batch is only taken from the source once the previous
+            // batch is sunk, so once the first thread sinks a chunk, all chunks with a lower
+            // index have arrived and are in their final order.
+            if thread_no == 0 {
+                let later = pending.split_off(&(chunk_index, 0));
+                push(
+                    &mut result,
+                    &mut batcher,
+                    std::mem::replace(&mut pending, later),
+                );
+            }
+        }
+        result
+    })
+}
+
+/// Hands the output of a streaming query in order to a [`BatchCallback`].
+///
+/// The callback is called from a single thread. The channel to that thread is bounded, so a
+/// slow callback stalls the pipeline instead of buffering the whole result.
+#[derive(Clone)]
+pub struct BatchSink {
+    thread_no: usize,
+    sender: Sender<Message>,
+    io_thread_handle: Arc<Mutex<Option<JoinHandle<PolarsResult<()>>>>>,
+    failed: Arc<AtomicBool>,
+}
+
+impl BatchSink {
+    pub fn new(callback: BatchCallback, chunk_size: Option<usize>) -> PolarsResult<Self> {
+        polars_ensure!(
+            chunk_size != Some(0),
+            InvalidOperation: "`chunk_size` of a batches sink must be positive"
+        );
+        let batcher = Batcher {
+            callback,
+            chunk_size,
+            pending: None,
+        };
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+        let failed = Arc::new(AtomicBool::new(false));
+        let handle = init_callback_thread(receiver, batcher, failed.clone());
+
+        Ok(BatchSink {
+            thread_no: 0,
+            sender,
+            io_thread_handle: Arc::new(Mutex::new(Some(handle))),
+            failed,
+        })
+    }
+}
+
+impl Sink for BatchSink {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        if self.failed.load(Ordering::Relaxed) {
+            return Ok(SinkResult::Finished);
+        }
+        // Empty chunks are sent as well, as they tell the callback thread how far the pipeline
+        // has come.
+        self.sender.send(Some((self.thread_no, chunk))).unwrap();
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, _other: &mut dyn Sink) {
+        // already synchronized
+    }
+
+    fn split(&self, thread_no: usize) -> Box<dyn Sink> {
+        let mut new = self.clone();
+        new.thread_no = thread_no;
+        Box::new(new)
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        // `None` indicates that we can flush all remaining chunks.
+        self.sender.send(None).unwrap();
+
+        let handle = self.io_thread_handle.lock().unwrap().take().unwrap();
+        handle.join().unwrap()?;
+
+        // return a dummy dataframe;
+        Ok(FinalizedSink::Finished(Default::default()))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "batch_sink"
+    }
+}
@@ -0,0 +1,99 @@
+use std::any::Any;
+
+use arrow::array::PrimitiveArray;
+use arrow::legacy::kernels::rolling::quantile_sketch::QuantileSketch;
+use polars_core::export::num::NumCast;
+use polars_core::prelude::*;
+use polars_ops::prelude::{quantile_sketch_extend, ApproxQuantileOptions};
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+
+/// Estimates a quantile with a [`QuantileSketch`], so that the values of a group don't have to be
+/// buffered.
+pub struct ApproxQuantileAgg {
+    sketch: QuantileSketch,
+    quantile: f64,
+    /// Either `Float32` or `Float64`, like the exact quantile.
+    dtype: DataType,
+}
+
+impl ApproxQuantileAgg {
+    pub(crate) fn new(quantile: f64, options: ApproxQuantileOptions, dtype: DataType) -> Self {
+        Self {
+            sketch: QuantileSketch::new(options.relative_accuracy),
+            quantile,
+            dtype,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        let options = ApproxQuantileOptions {
+            relative_accuracy: self.sketch.relative_accuracy(),
+        };
+        Self::new(self.quantile, options, self.dtype.clone())
+    }
+}
+
+impl AggregateFn for ApproxQuantileAgg {
+    fn has_physical_agg(&self) -> bool {
+        true
+    }
+
+    fn pre_agg_primitive<T: NumCast>(&mut self, _chunk_idx: IdxSize, item: Option<T>) {
+        match item.and_then(|v| v.to_f64()) {
+            Some(v) if !v.is_nan() => self.sketch.insert(v),
+            _ => {},
+        }
+    }
+
+    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        match item.extract::<f64>() {
+            Some(v) if !v.is_nan() => self.sketch.insert(v),
+            _ => {},
+        }
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        let arr = unsafe {
+            let arr = values.chunks().get_unchecked(0);
+            arr.sliced_unchecked(offset as usize, length as usize)
+        };
+        let arr =
+            arrow::compute::cast::cast_unchecked(arr.as_ref(), &ArrowDataType::Float64).unwrap();
+        let arr = unsafe {
+            arr.as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap_unchecked_release()
+        };
+        quantile_sketch_extend(&mut self.sketch, arr);
+    }
+
+    fn dtype(&self) -> DataType {
+        self.dtype.clone()
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        self.sketch.merge(&other.sketch);
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        match (self.sketch.quantile(self.quantile), &self.dtype) {
+            (Some(v), DataType::Float32) => AnyValue::Float32(v as f32),
+            (Some(v), _) => AnyValue::Float64(v),
+            (None, _) => AnyValue::Null,
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -14,11 +14,13 @@ use polars_plan::prelude::{AExpr, IRAggExpr};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::IdxSize;
 
+use crate::executors::sinks::group_by::aggregates::approx_quantile::ApproxQuantileAgg;
 use crate::executors::sinks::group_by::aggregates::count::CountAgg;
 use crate::executors::sinks::group_by::aggregates::first::FirstAgg;
 use crate::executors::sinks::group_by::aggregates::last::LastAgg;
 use crate::executors::sinks::group_by::aggregates::mean::MeanAgg;
 use crate::executors::sinks::group_by::aggregates::min_max::{new_max, new_min};
+use crate::executors::sinks::group_by::aggregates::mode::ModeAgg;
 use crate::executors::sinks::group_by::aggregates::null::NullAgg;
 use crate::executors::sinks::group_by::aggregates::{AggregateFunction, SumAgg};
 use crate::expressions::PhysicalPipedExpr;
@@ -82,12 +84,36 @@ pub fn can_convert_to_hash_agg(
         }
         match expr_arena.get(node) {
             AExpr::Len => true,
+            AExpr::Agg(IRAggExpr::ApproxQuantile {
+                expr,
+                quantile,
+                options,
+            }) => {
+                options.is_valid()
+                    && literal_quantile(*quantile, expr_arena).is_some()
+                    && expr_arena
+                        .get(*expr)
+                        .to_field(input_schema, Context::Default, expr_arena)
+                        .map_or(false, |field| field.dtype.is_numeric())
+            },
+            AExpr::Agg(IRAggExpr::Mode(input, _)) => expr_arena
+                .get(*input)
+                .to_field(input_schema, Context::Default, expr_arena)
+                .map_or(false, |field| {
+                    // The physical values are counted, which can't be cast back to categoricals
+                    // inside of the lists of all modes.
+                    field.dtype.is_numeric()
+                        || field.dtype.is_temporal()
+                        || matches!(field.dtype, DataType::Boolean | DataType::String)
+                }),
             ae @ AExpr::Agg(agg_fn) => {
                 matches!(
                     agg_fn,
                     IRAggExpr::Sum(_)
                         | IRAggExpr::First(_)
+                        | IRAggExpr::FirstNonNull(_)
                         | IRAggExpr::Last(_)
+                        | IRAggExpr::LastNonNull(_)
                         | IRAggExpr::Mean(_)
                         | IRAggExpr::Count(_, false)
                 ) || (matches!(
@@ -119,6 +145,17 @@ pub fn can_convert_to_hash_agg(
     }
 }
 
+/// The quantile of an approximate quantile aggregation, if it is a valid literal.
+fn literal_quantile(node: Node, expr_arena: &Arena<AExpr>) -> Option<f64> {
+    match expr_arena.get(node) {
+        AExpr::Literal(lv) => lv
+            .to_any_value()?
+            .extract::<f64>()
+            .filter(|q| (0.0..=1.0).contains(q)),
+        _ => None,
+    }
+}
+
 /// # Returns:
 ///  - input_dtype: dtype that goes into the agg expression
 ///  - physical expr: physical expression that produces the input of the aggregation
@@ -264,7 +301,7 @@ where
                 };
                 (logical_dtype, phys_expr, agg_fn)
             },
-            IRAggExpr::First(input) => {
+            IRAggExpr::First(input) | IRAggExpr::FirstNonNull(input) => {
                 let phys_expr = to_physical(
                     &ExprIR::from_node(*input, expr_arena),
                     expr_arena,
@@ -272,13 +309,29 @@ where
                 )
                 .unwrap();
                 let logical_dtype = phys_expr.field(schema).unwrap().dtype;
-                (
-                    logical_dtype.clone(),
-                    phys_expr,
-                    AggregateFunction::First(FirstAgg::new(logical_dtype.to_physical())),
+                let agg_fn = if matches!(agg, IRAggExpr::FirstNonNull(_)) {
+                    AggregateFunction::FirstNonNull(FirstAgg::new(logical_dtype.to_physical()))
+                } else {
+                    AggregateFunction::First(FirstAgg::new(logical_dtype.to_physical()))
+                };
+                (logical_dtype, phys_expr, agg_fn)
+            },
+            IRAggExpr::Last(input) | IRAggExpr::LastNonNull(input) => {
+                let phys_expr = to_physical(
+                    &ExprIR::from_node(*input, expr_arena),
+                    expr_arena,
+                    Some(schema),
                 )
+                .unwrap();
+                let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+                let agg_fn = if matches!(agg, IRAggExpr::LastNonNull(_)) {
+                    AggregateFunction::LastNonNull(LastAgg::new(logical_dtype.to_physical()))
+                } else {
+                    AggregateFunction::Last(LastAgg::new(logical_dtype.to_physical()))
+                };
+                (logical_dtype, phys_expr, agg_fn)
             },
-            IRAggExpr::Last(input) => {
+            IRAggExpr::Count(input, _) => {
                 let phys_expr = to_physical(
                     &ExprIR::from_node(*input, expr_arena),
                     expr_arena,
@@ -287,25 +340,48 @@ where
                 .unwrap();
                 let logical_dtype = phys_expr.field(schema).unwrap().dtype;
                 (
-                    logical_dtype.clone(),
+                    logical_dtype,
                     phys_expr,
-                    AggregateFunction::Last(LastAgg::new(logical_dtype.to_physical())),
+                    AggregateFunction::Count(CountAgg::new()),
                 )
             },
-            IRAggExpr::Count(input, _) => {
+            IRAggExpr::ApproxQuantile {
+                expr,
+                quantile,
+                options,
+            } => {
                 let phys_expr = to_physical(
-                    &ExprIR::from_node(*input, expr_arena),
+                    &ExprIR::from_node(*expr, expr_arena),
                     expr_arena,
                     Some(schema),
                 )
                 .unwrap();
                 let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+                let quantile = literal_quantile(*quantile, expr_arena).unwrap();
+                let out_dtype = match logical_dtype {
+                    DataType::Float32 => DataType::Float32,
+                    _ => DataType::Float64,
+                };
                 (
                     logical_dtype,
                     phys_expr,
-                    AggregateFunction::Count(CountAgg::new()),
+                    AggregateFunction::ApproxQuantile(ApproxQuantileAgg::new(
+                        quantile, *options, out_dtype,
+                    )),
                 )
             },
+            IRAggExpr::Mode(input, keep) => {
+                let phys_expr = to_physical(
+                    &ExprIR::from_node(*input, expr_arena),
+                    expr_arena,
+                    Some(schema),
+                )
+                .unwrap();
+                let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+                let agg_fn =
+                    AggregateFunction::Mode(ModeAgg::new(*keep, logical_dtype.to_physical()));
+                (logical_dtype, phys_expr, agg_fn)
+            },
             agg => panic!("{agg:?} not yet implemented."),
         },
         _ => todo!(),
@@ -7,13 +7,13 @@ use polars_utils::unwrap::UnwrapUncheckedRelease;
 use crate::executors::sinks::group_by::aggregates::AggregateFn;
 use crate::operators::IdxSize;
 
-pub(crate) struct FirstAgg {
+pub(crate) struct FirstAgg<const IGNORE_NULLS: bool> {
     chunk_idx: IdxSize,
     first: Option<AnyValue<'static>>,
     pub(crate) dtype: DataType,
 }
 
-impl FirstAgg {
+impl<const IGNORE_NULLS: bool> FirstAgg<IGNORE_NULLS> {
     pub(crate) fn new(dtype: DataType) -> Self {
         Self {
             chunk_idx: IdxSize::MAX,
@@ -23,10 +23,10 @@ impl FirstAgg {
     }
 }
 
-impl AggregateFn for FirstAgg {
+impl<const IGNORE_NULLS: bool> AggregateFn for FirstAgg<IGNORE_NULLS> {
     fn pre_agg(&mut self, chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
         let item = unsafe { item.next().unwrap_unchecked_release() };
-        if self.first.is_none() {
+        if self.first.is_none() && !(IGNORE_NULLS && item.is_null()) {
             self.chunk_idx = chunk_idx;
             self.first = Some(item.into_static().unwrap())
         }
@@ -35,16 +35,21 @@ impl AggregateFn for FirstAgg {
         &mut self,
         chunk_idx: IdxSize,
         offset: IdxSize,
-        _length: IdxSize,
+        length: IdxSize,
         values: &Series,
     ) {
         if self.first.is_none() {
-            self.chunk_idx = chunk_idx;
-            self.first = Some(
-                unsafe { values.get_unchecked(offset as usize) }
-                    .into_static()
-                    .unwrap(),
-            )
+            let first = if IGNORE_NULLS {
+                (offset..offset + length)
+                    .map(|i| unsafe { values.get_unchecked(i as usize) })
+                    .find(|av| !av.is_null())
+            } else {
+                Some(unsafe { values.get_unchecked(offset as usize) })
+            };
+            if let Some(first) = first {
+                self.chunk_idx = chunk_idx;
+                self.first = Some(first.into_static().unwrap())
+            }
         }
     }
 
@@ -5,11 +5,13 @@ use num_traits::NumCast;
 use polars_core::datatypes::DataType;
 use polars_core::prelude::{AnyValue, Series};
 
+use crate::executors::sinks::group_by::aggregates::approx_quantile::ApproxQuantileAgg;
 use crate::executors::sinks::group_by::aggregates::count::CountAgg;
 use crate::executors::sinks::group_by::aggregates::first::FirstAgg;
 use crate::executors::sinks::group_by::aggregates::last::LastAgg;
 use crate::executors::sinks::group_by::aggregates::mean::MeanAgg;
 use crate::executors::sinks::group_by::aggregates::min_max::MinMaxAgg;
+use crate::executors::sinks::group_by::aggregates::mode::ModeAgg;
 use crate::executors::sinks::group_by::aggregates::null::NullAgg;
 use crate::executors::sinks::group_by::aggregates::SumAgg;
 use crate::operators::IdxSize;
@@ -44,8 +46,10 @@ pub(crate) trait AggregateFn: Send + Sync {
 // as that saves an indirection
 #[enum_dispatch]
 pub(crate) enum AggregateFunction {
-    First(FirstAgg),
-    Last(LastAgg),
+    First(FirstAgg<false>),
+    FirstNonNull(FirstAgg<true>),
+    Last(LastAgg<false>),
+    LastNonNull(LastAgg<true>),
     Count(CountAgg<false>),
     Len(CountAgg<true>),
     SumF32(SumAgg<f32>),
@@ -67,6 +71,8 @@ pub(crate) enum AggregateFunction {
     MinMaxI16(MinMaxAgg<i16, fn(i16, i16) -> i16>),
     MinMaxI32(MinMaxAgg<i32, fn(i32, i32) -> i32>),
     MinMaxI64(MinMaxAgg<i64, fn(i64, i64) -> i64>),
+    ApproxQuantile(ApproxQuantileAgg),
+    Mode(ModeAgg),
 }
 
 impl AggregateFunction {
@@ -74,7 +80,9 @@ impl AggregateFunction {
         use AggregateFunction::*;
         match self {
             First(agg) => First(FirstAgg::new(agg.dtype.clone())),
+            FirstNonNull(agg) => FirstNonNull(FirstAgg::new(agg.dtype.clone())),
             Last(agg) => Last(LastAgg::new(agg.dtype.clone())),
+            LastNonNull(agg) => LastNonNull(LastAgg::new(agg.dtype.clone())),
             SumF32(_) => SumF32(SumAgg::new()),
             SumF64(_) => SumF64(SumAgg::new()),
             SumU32(_) => SumU32(SumAgg::new()),
@@ -96,6 +104,8 @@ impl AggregateFunction {
             MinMaxI16(inner) => MinMaxI16(inner.split()),
             MinMaxI32(inner) => MinMaxI32(inner.split()),
             MinMaxI64(inner) => MinMaxI64(inner.split()),
+            ApproxQuantile(inner) => ApproxQuantile(inner.split()),
+            Mode(inner) => Mode(inner.split()),
         }
     }
 }
@@ -7,13 +7,13 @@ use polars_utils::unwrap::UnwrapUncheckedRelease;
 use crate::executors::sinks::group_by::aggregates::AggregateFn;
 use crate::operators::IdxSize;
 
-pub(crate) struct LastAgg {
+pub(crate) struct LastAgg<const IGNORE_NULLS: bool> {
     chunk_idx: IdxSize,
     last: Option<AnyValue<'static>>,
     pub(crate) dtype: DataType,
 }
 
-impl LastAgg {
+impl<const IGNORE_NULLS: bool> LastAgg<IGNORE_NULLS> {
     pub(crate) fn new(dtype: DataType) -> Self {
         Self {
             chunk_idx: 0,
@@ -23,9 +23,12 @@ impl LastAgg {
     }
 }
 
-impl AggregateFn for LastAgg {
+impl<const IGNORE_NULLS: bool> AggregateFn for LastAgg<IGNORE_NULLS> {
     fn pre_agg(&mut self, chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
         let item = unsafe { item.next().unwrap_unchecked_release() };
+        if IGNORE_NULLS && item.is_null() {
+            return;
+        }
         self.chunk_idx = chunk_idx;
         self.last = Some(unsafe { item.into_static().unwrap_unchecked() });
     }
@@ -36,13 +39,18 @@ impl AggregateFn for LastAgg {
         length: IdxSize,
         values: &Series,
     ) {
-        self.chunk_idx = chunk_idx;
-        self.last = Some(unsafe {
-            values
-                .get_unchecked((offset + length - 1) as usize)
-                .into_static()
-                .unwrap_unchecked()
-        })
+        let last = if IGNORE_NULLS {
+            (offset..offset + length)
+                .rev()
+                .map(|i| unsafe { values.get_unchecked(i as usize) })
+                .find(|av| !av.is_null())
+        } else {
+            Some(unsafe { values.get_unchecked((offset + length - 1) as usize) })
+        };
+        if let Some(last) = last {
+            self.chunk_idx = chunk_idx;
+            self.last = Some(unsafe { last.into_static().unwrap_unchecked() })
+        }
     }
 
     fn dtype(&self) -> DataType {
@@ -1,3 +1,4 @@
+mod approx_quantile;
 mod convert;
 mod count;
 mod first;
@@ -5,6 +6,7 @@ mod interface;
 mod last;
 mod mean;
 mod min_max;
+mod mode;
 mod null;
 mod sum;
 
@@ -0,0 +1,144 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_ops::prelude::ModeKeep;
+use polars_utils::total_ord::{canonical_f32, canonical_f64};
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+
+/// Where a value occurs in the stream: the chunk and then the position in this aggregation.
+type Position = (IdxSize, IdxSize);
+
+struct ValueCount {
+    /// The value as it first occurs, which stands in for all of its occurrences.
+    value: AnyValue<'static>,
+    count: IdxSize,
+    first: Position,
+    last: Position,
+}
+
+/// Counts the distinct values of a group, so that its modes are known once all chunks are seen.
+pub struct ModeAgg {
+    counts: PlHashMap<AnyValue<'static>, ValueCount>,
+    /// The number of values this aggregation has seen, which orders the values of a chunk.
+    seen: IdxSize,
+    keep: ModeKeep,
+    /// The physical type of the values.
+    dtype: DataType,
+}
+
+impl ModeAgg {
+    pub(crate) fn new(keep: ModeKeep, dtype: DataType) -> Self {
+        Self {
+            counts: PlHashMap::new(),
+            seen: 0,
+            keep,
+            dtype,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.keep, self.dtype.clone())
+    }
+
+    fn insert(&mut self, chunk_idx: IdxSize, value: AnyValue) {
+        // Floats are counted by the bits of their canonical form, as NaN doesn't equal itself and
+        // -0.0 and 0.0, like all NaNs, are one value in the in-memory engine.
+        let key = match value {
+            AnyValue::Float32(v) => AnyValue::UInt32(canonical_f32(v).to_bits()),
+            AnyValue::Float64(v) => AnyValue::UInt64(canonical_f64(v).to_bits()),
+            ref value => value.clone().into_static().unwrap(),
+        };
+        let position = (chunk_idx, self.seen);
+        self.seen += 1;
+        let value = self.counts.entry(key).or_insert_with(|| ValueCount {
+            value: value.into_static().unwrap(),
+            count: 0,
+            first: position,
+            last: position,
+        });
+        value.count += 1;
+        value.last = position;
+    }
+}
+
+impl AggregateFn for ModeAgg {
+    fn pre_agg(&mut self, chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.insert(chunk_idx, item)
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        for i in offset..offset + length {
+            let value = unsafe { values.get_unchecked(i as usize) };
+            self.insert(chunk_idx, value)
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        match self.keep {
+            ModeKeep::All => DataType::List(Box::new(self.dtype.clone())),
+            ModeKeep::First | ModeKeep::Last => self.dtype.clone(),
+        }
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        for (key, other) in other.counts.iter() {
+            let value = self
+                .counts
+                .entry(key.clone())
+                .or_insert_with(|| ValueCount {
+                    value: other.value.clone(),
+                    count: 0,
+                    first: other.first,
+                    last: other.last,
+                });
+            value.count += other.count;
+            if other.first < value.first {
+                value.value = other.value.clone();
+                value.first = other.first;
+            }
+            value.last = value.last.max(other.last);
+        }
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        let counts = std::mem::take(&mut self.counts);
+        let max = counts.values().map(|v| v.count).max();
+        let mut modes = counts
+            .iter()
+            .filter(|(_, v)| Some(v.count) == max)
+            .collect::<Vec<_>>();
+        match self.keep {
+            ModeKeep::All => {
+                modes.sort_unstable_by_key(|(_, v)| v.first);
+                let values = modes
+                    .into_iter()
+                    .map(|(_, v)| v.value.clone())
+                    .collect::<Vec<_>>();
+                let s = Series::from_any_values_and_dtype("", &values, &self.dtype, true).unwrap();
+                AnyValue::List(s)
+            },
+            ModeKeep::First => modes
+                .into_iter()
+                .min_by_key(|(_, v)| v.first)
+                .map_or(AnyValue::Null, |(_, v)| v.value.clone()),
+            ModeKeep::Last => modes
+                .into_iter()
+                .max_by_key(|(_, v)| v.last)
+                .map_or(AnyValue::Null, |(_, v)| v.value.clone()),
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -64,6 +64,14 @@ impl OocState {
         Ok(())
     }
 
+    /// Count the size of a chunk that is aggregated by this sink towards the memory of the query.
+    /// Once the sink is out-of-core, the chunks are spilled and don't count.
+    pub(super) fn track_memory(&self, bytes: usize) {
+        if !self.ooc {
+            self.mem_track.fetch_add(bytes);
+        }
+    }
+
     pub(super) fn check_memory_usage(
         &mut self,
         spill_schema: &dyn Fn() -> Option<Schema>,
@@ -94,6 +102,8 @@ impl OocState {
     pub(super) fn dump(&self, partition_no: usize, df: DataFrame) {
         let iot = self.io_thread.lock().unwrap();
         let iot = iot.as_ref().unwrap();
+        // The spilled data no longer counts towards the memory of the query.
+        self.mem_track.fetch_sub(df.estimated_size());
         iot.dump_partition(partition_no as IdxSize, df)
     }
 }
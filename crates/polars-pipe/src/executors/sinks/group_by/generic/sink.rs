@@ -62,6 +62,7 @@ impl Sink for GenericGroupby2 {
         if chunk.is_empty() {
             return Ok(SinkResult::CanHaveMoreInput);
         }
+        self.ooc_state.track_memory(chunk.data.estimated_size());
         // load data and hashes
         unsafe {
             // SAFETY: we don't hold mutable refs
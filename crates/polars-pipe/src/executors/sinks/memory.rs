@@ -1,20 +1,38 @@
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
+use polars_utils::pool::{query_memory, QueryMemory};
 use polars_utils::sys::MEMINFO;
 
 use crate::pipeline::FORCE_OOC;
 
 const TO_MB: usize = 2 << 19;
 
+/// The memory that a node buffers, which counts towards the memory of its query until the node
+/// releases it or is dropped.
+struct NodeMemory {
+    used: AtomicUsize,
+    query_memory: Option<&'static QueryMemory>,
+}
+
+impl Drop for NodeMemory {
+    fn drop(&mut self) {
+        if let Some(memory) = self.query_memory {
+            memory.fetch_sub(*self.used.get_mut());
+        }
+    }
+}
+
 #[derive(Clone)]
 pub(super) struct MemTracker {
     // available memory at the start of this node
     available_mem: Arc<AtomicUsize>,
-    used_by_node: Arc<AtomicUsize>,
+    used_by_node: Arc<NodeMemory>,
     fetch_count: Arc<AtomicUsize>,
     thread_count: usize,
     available_at_start: usize,
+    // memory of the query this node belongs to, if it has a memory soft limit
+    query_memory: Option<&'static QueryMemory>,
     refresh_interval: usize,
 }
 
@@ -26,24 +44,38 @@ impl MemTracker {
             64
         };
 
+        let query_memory = query_memory();
         let mut out = Self {
             available_mem: Default::default(),
-            used_by_node: Default::default(),
+            used_by_node: Arc::new(NodeMemory {
+                used: AtomicUsize::new(0),
+                query_memory,
+            }),
             fetch_count: Arc::new(AtomicUsize::new(1)),
             thread_count,
             available_at_start: 0,
+            query_memory,
             refresh_interval,
         };
-        let available = MEMINFO.free() as usize;
+        let available = out.available(MEMINFO.free() as usize);
         out.available_mem.store(available, Ordering::Relaxed);
         out.available_at_start = available;
         out
     }
 
+    /// The free memory of the system, but no more than what is left of the memory soft limit of
+    /// the query. The memory that the nodes of the query buffer counts towards that limit.
+    fn available(&self, system_free: usize) -> usize {
+        match self.query_memory.and_then(|memory| memory.remaining()) {
+            Some(remaining) => system_free.min(remaining),
+            None => system_free,
+        }
+    }
+
     /// This shouldn't be called often as this is expensive.
     pub fn refresh_memory(&self) {
-        self.available_mem
-            .store(MEMINFO.free() as usize, Ordering::Relaxed);
+        let available = self.available(MEMINFO.free() as usize);
+        self.available_mem.store(available, Ordering::Relaxed);
     }
 
     /// Get available memory of the system measured on latest refresh.
@@ -73,6 +105,23 @@ impl MemTracker {
 
     /// Increment the used memory and return the previous value.
     pub(super) fn fetch_add(&self, add: usize) -> usize {
-        self.used_by_node.fetch_add(add, Ordering::Relaxed)
+        if let Some(memory) = self.query_memory {
+            memory.fetch_add(add);
+        }
+        self.used_by_node.used.fetch_add(add, Ordering::Relaxed)
+    }
+
+    /// Decrement the used memory, e.g. once buffered data is spilled to disk.
+    pub(super) fn fetch_sub(&self, sub: usize) {
+        let prev = self
+            .used_by_node
+            .used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                Some(used.saturating_sub(sub))
+            })
+            .unwrap();
+        if let Some(memory) = self.query_memory {
+            memory.fetch_sub(prev.min(sub));
+        }
     }
 }
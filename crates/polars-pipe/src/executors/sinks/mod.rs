@@ -1,3 +1,4 @@
+mod batches;
 pub(crate) mod group_by;
 mod io;
 mod joins;
@@ -7,10 +8,12 @@ mod output;
 mod reproject;
 mod slice;
 mod sort;
+mod tee;
 mod utils;
 
 use std::sync::OnceLock;
 
+pub(crate) use batches::*;
 pub(crate) use joins::*;
 pub(crate) use ordered::*;
 #[cfg(any(
@@ -23,6 +26,7 @@ pub(crate) use output::*;
 pub(crate) use reproject::*;
 pub(crate) use slice::*;
 pub(crate) use sort::*;
+pub(crate) use tee::*;
 
 // We must strike a balance between cache coherence and resizing costs.
 // Overallocation seems a lot more expensive than resizing so we start reasonable small.
@@ -26,6 +26,7 @@ impl CsvSink {
             .with_float_precision(options.serialize_options.float_precision)
             .with_null_value(options.serialize_options.null)
             .with_quote_style(options.serialize_options.quote_style)
+            .with_column_quote_styles(options.serialize_options.column_quote_styles)
             .n_threads(1)
             .batched(schema)?;
 
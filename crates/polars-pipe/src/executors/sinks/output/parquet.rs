@@ -5,14 +5,17 @@ use std::thread::JoinHandle;
 use crossbeam_channel::{bounded, Receiver, Sender};
 use polars_core::prelude::*;
 use polars_io::parquet::write::{
-    BatchedWriter, ParquetWriteOptions, ParquetWriter, RowGroupIterColumns,
+    BatchedWriter, ParquetWriteOptions, ParquetWriter, RowGroupIterColumns, SortingColumn,
 };
 
 use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
 use crate::pipeline::morsels_per_sink;
 
-type RowGroups = Vec<RowGroupIterColumns<'static, PolarsError>>;
+type RowGroups = (
+    Vec<RowGroupIterColumns<'static, PolarsError>>,
+    Option<Vec<SortingColumn>>,
+);
 
 pub(super) fn init_row_group_writer_thread(
     receiver: Receiver<Option<(IdxSize, RowGroups)>>,
@@ -39,8 +42,10 @@ pub(super) fn init_row_group_writer_thread(
             if batched.len() == morsels_per_sink || last_write {
                 batched.sort_by_key(|chunk| chunk.0);
 
-                for (_, rg) in batched.drain(0..) {
-                    writer.write_row_groups(rg).unwrap()
+                for (_, (rg, sorting_columns)) in batched.drain(0..) {
+                    writer
+                        .write_row_groups(rg, sorting_columns.as_deref())
+                        .unwrap()
                 }
             }
             if last_write {
@@ -98,9 +103,10 @@ impl Sink for ParquetSink {
             .writer
             .encode_and_compress(&chunk.data)
             .collect::<PolarsResult<Vec<_>>>()?;
+        let sorting_columns = self.writer.sorting_columns(&chunk.data);
         // Only then send the compressed pages to the writer.
         self.sender
-            .send(Some((chunk.chunk_index, row_groups)))
+            .send(Some((chunk.chunk_index, (row_groups, sorting_columns))))
             .unwrap();
         Ok(SinkResult::CanHaveMoreInput)
     }
@@ -92,8 +92,9 @@ impl SortSink {
 
     fn store_chunk(&mut self, chunk: DataChunk) -> PolarsResult<()> {
         let chunk_bytes = chunk.data.estimated_size();
+        // The chunks are counted until they are dumped to disk.
+        let used = self.mem_track.fetch_add(chunk_bytes);
         if !self.ooc {
-            let used = self.mem_track.fetch_add(chunk_bytes);
             let free = self.mem_track.get_available();
 
             // we need some free memory to be able to sort
@@ -133,6 +134,7 @@ impl SortSink {
                 iot.dump_chunk(df);
 
                 // reset sizes
+                self.mem_track.fetch_sub(self.current_chunks_size);
                 self.current_chunk_rows = 0;
                 self.current_chunks_size = 0;
             }
@@ -157,6 +159,8 @@ impl Sink for SortSink {
             self.ooc_start = Some(ooc_start);
         }
         self.chunks.extend(std::mem::take(&mut other.chunks));
+        self.current_chunk_rows += std::mem::take(&mut other.current_chunk_rows);
+        self.current_chunks_size += std::mem::take(&mut other.current_chunks_size);
         self.ooc |= other.ooc;
         self.dist_sample
             .extend(std::mem::take(&mut other.dist_sample));
@@ -0,0 +1,73 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+
+use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+
+/// Broadcasts every chunk to multiple sinks, so that the query only runs once for all of them.
+pub struct TeeSink {
+    sinks: Vec<Box<dyn Sink>>,
+}
+
+impl TeeSink {
+    pub fn new(sinks: Vec<Box<dyn Sink>>) -> Self {
+        TeeSink { sinks }
+    }
+}
+
+impl Sink for TeeSink {
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        let mut finished = true;
+        for sink in &mut self.sinks {
+            // Cloning a chunk only clones the `Arc`s of its columns.
+            if let SinkResult::CanHaveMoreInput = sink.sink(context, chunk.clone())? {
+                finished = false;
+            }
+        }
+        // Only stop early if no sink needs more input.
+        if finished {
+            Ok(SinkResult::Finished)
+        } else {
+            Ok(SinkResult::CanHaveMoreInput)
+        }
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<Self>().unwrap();
+        for (sink, other) in self.sinks.iter_mut().zip(other.sinks.iter_mut()) {
+            sink.combine(other.as_mut())
+        }
+    }
+
+    fn split(&self, thread_no: usize) -> Box<dyn Sink> {
+        Box::new(TeeSink {
+            sinks: self
+                .sinks
+                .iter()
+                .map(|sink| sink.split(thread_no))
+                .collect(),
+        })
+    }
+
+    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        // Finalize all sinks, even if one fails, so that every file is completed.
+        let mut result = Ok(());
+        for sink in &mut self.sinks {
+            if let Err(err) = sink.finalize(context) {
+                result = result.and(Err(err));
+            }
+        }
+        result?;
+
+        // return a dummy dataframe;
+        Ok(FinalizedSink::Finished(Default::default()))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "tee_sink"
+    }
+}
@@ -1,8 +1,13 @@
+use std::collections::VecDeque;
 use std::fs::File;
-use std::path::PathBuf;
+use std::io::{Read, Seek};
+use std::path::{Path, PathBuf};
 
+use polars_core::utils::split_df;
 use polars_core::{config, POOL};
-use polars_io::csv::read::{BatchedCsvReader, CsvReadOptions, CsvReader};
+use polars_io::csv::read::{
+    is_compressed, BatchedCsvReader, CsvBlockReader, CsvBlocks, CsvReadOptions, CsvReader,
+};
 use polars_io::utils::is_cloud_url;
 use polars_plan::global::_set_n_rows_for_scan;
 use polars_plan::prelude::FileScanOptions;
@@ -11,6 +16,12 @@ use polars_utils::iter::EnumerateIdxTrait;
 use super::*;
 use crate::pipeline::determine_chunk_size;
 
+// The size of the blocks that compressed files are decompressed into.
+const DECOMPRESSED_BLOCK_SIZE: usize = 1 << 24;
+// The number of compressed files that are decompressed ahead of the file that is read, so that
+// multiple files are decompressed in parallel.
+const MAX_FILES_READ_AHEAD: usize = 3;
+
 pub(crate) struct CsvSource {
     #[allow(dead_code)]
     // this exist because we need to keep ownership
@@ -19,6 +30,11 @@ pub(crate) struct CsvSource {
     // (so we have to order the `batched_reader` first in the struct fields)
     batched_reader: Option<BatchedCsvReader<'static>>,
     reader: Option<CsvReader<File>>,
+    // Set instead of `reader` if the current file is compressed.
+    compressed: Option<CsvBlockReader>,
+    // The compressed files after the current one that are being decompressed, by path index.
+    blocks_ahead: VecDeque<(usize, CsvBlocks)>,
+    next_read_ahead_idx: usize,
     n_threads: usize,
     paths: Arc<[PathBuf]>,
     options: Option<CsvReadOptions>,
@@ -44,7 +60,8 @@ impl CsvSource {
         {
             return Ok(());
         }
-        let path = &self.paths[self.current_path_idx];
+        let path_idx = self.current_path_idx;
+        let path = &self.paths[path_idx];
 
         let force_async = config::force_async();
         let run_async = force_async || is_cloud_url(path);
@@ -96,21 +113,20 @@ impl CsvSource {
             .with_rechunk(false)
             .with_row_index(row_index);
 
-        let reader: CsvReader<File> = if run_async {
-            #[cfg(feature = "cloud")]
-            {
-                options.into_reader_with_file_handle(
-                    polars_io::file_cache::FILE_CACHE
-                        .get_entry(path.to_str().unwrap())
-                        // Safety: This was initialized by schema inference.
-                        .unwrap()
-                        .try_open_assume_latest()?,
-                )
-            }
-            #[cfg(not(feature = "cloud"))]
-            {
-                panic!("required feature `cloud` is not enabled")
+        let blocks = match self.blocks_ahead.front() {
+            Some((idx, _)) if *idx == path_idx => self.blocks_ahead.pop_front().map(|(_, b)| b),
+            _ => self.try_read_compressed(path)?,
+        };
+        if let Some(blocks) = blocks {
+            self.compressed = Some(CsvBlockReader::new(blocks, options));
+            if file_options.n_rows.is_none() {
+                self.read_ahead()?;
             }
+            return Ok(());
+        }
+
+        let reader: CsvReader<File> = if run_async {
+            options.into_reader_with_file_handle(Self::open_file(path)?)
         } else {
             options
                 .with_path(Some(path))
@@ -127,6 +143,67 @@ impl CsvSource {
         Ok(())
     }
 
+    fn open_file(path: &Path) -> PolarsResult<File> {
+        if config::force_async() || is_cloud_url(path) {
+            #[cfg(feature = "cloud")]
+            {
+                polars_io::file_cache::FILE_CACHE
+                    .get_entry(path.to_str().unwrap())
+                    // Safety: This was initialized by schema inference.
+                    .unwrap()
+                    .try_open_assume_latest()
+            }
+            #[cfg(not(feature = "cloud"))]
+            {
+                panic!("required feature `cloud` is not enabled")
+            }
+        } else {
+            polars_utils::open_file(path)
+        }
+    }
+
+    /// Start decompressing the file at `path` if it is compressed.
+    fn try_read_compressed(&self, path: &Path) -> PolarsResult<Option<CsvBlocks>> {
+        let mut file = Self::open_file(path)?;
+        let mut magic_nr = [0u8; 4];
+        let res_len = file.read(&mut magic_nr)?;
+        if !is_compressed(&magic_nr[..res_len]) {
+            return Ok(None);
+        }
+        file.rewind()?;
+        let parse_options = self.options.as_ref().unwrap().get_parse_options();
+        CsvBlocks::try_new(
+            file,
+            DECOMPRESSED_BLOCK_SIZE,
+            parse_options.quote_char,
+            parse_options.eol_char,
+        )
+        .map(Some)
+    }
+
+    /// Start decompressing the compressed files that follow the current one.
+    fn read_ahead(&mut self) -> PolarsResult<()> {
+        self.next_read_ahead_idx = self.next_read_ahead_idx.max(self.current_path_idx);
+        while self.blocks_ahead.len() < MAX_FILES_READ_AHEAD
+            && self.next_read_ahead_idx < self.paths.len()
+        {
+            let idx = self.next_read_ahead_idx;
+            self.next_read_ahead_idx += 1;
+            if let Some(blocks) = self.try_read_compressed(&self.paths[idx])? {
+                self.blocks_ahead.push_back((idx, blocks));
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse the next decompressed block of the current file in parallel.
+    fn next_compressed_batches(&mut self) -> PolarsResult<Option<Vec<DataFrame>>> {
+        let compressed = self.compressed.as_mut().unwrap();
+        Ok(compressed
+            .next_df()?
+            .map(|mut df| split_df(&mut df, self.n_threads, false)))
+    }
+
     pub(crate) fn new(
         paths: Arc<[PathBuf]>,
         schema: SchemaRef,
@@ -137,6 +214,9 @@ impl CsvSource {
         Ok(CsvSource {
             schema,
             reader: None,
+            compressed: None,
+            blocks_ahead: VecDeque::new(),
+            next_read_ahead_idx: 0,
             batched_reader: None,
             n_threads: POOL.current_num_threads(),
             paths,
@@ -153,24 +233,33 @@ impl CsvSource {
 impl Source for CsvSource {
     fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
         loop {
-            let first_read_from_file = self.reader.is_none();
+            let first_read_from_file = self.reader.is_none() && self.compressed.is_none();
 
             if first_read_from_file {
                 self.init_next_reader()?;
             }
 
-            if self.reader.is_none() {
+            let batches = if self.compressed.is_some() {
+                let batches = self.next_compressed_batches()?;
+                if batches.is_none() {
+                    self.compressed = None;
+                }
+                batches
+            } else if self.reader.is_some() {
+                let batches = self
+                    .batched_reader
+                    .as_mut()
+                    .unwrap()
+                    .next_batches(self.n_threads)?;
+                if batches.is_none() {
+                    self.reader = None;
+                }
+                batches
+            } else {
                 // No more readers
                 return Ok(SourceResult::Finished);
-            }
-
-            let Some(batches) = self
-                .batched_reader
-                .as_mut()
-                .unwrap()
-                .next_batches(self.n_threads)?
-            else {
-                self.reader = None;
+            };
+            let Some(batches) = batches else {
                 continue;
             };
 
@@ -0,0 +1,266 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_core::utils::{accumulate_dataframes_vertical_unchecked, split_df};
+use polars_core::POOL;
+use polars_ops::prelude::{SeriesMethods, _merge_sorted_dfs_by};
+
+use crate::executors::sources::get_source_index;
+use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
+
+struct MergeInput {
+    source: Box<dyn Source>,
+    // Rows with a non-null key that are read, but not yet emitted.
+    buffer: Option<DataFrame>,
+    finished: bool,
+    // The last non-null key read, to check that the source continues its order.
+    last: Option<Series>,
+    // Whether null keys were read before or after the non-null keys.
+    leading_nulls: bool,
+    trailing_nulls: bool,
+}
+
+impl MergeInput {
+    /// Read from the source until rows are buffered or the source is finished.
+    ///
+    /// The rows with a null key are moved to `null_rows`.
+    fn fill(
+        &mut self,
+        context: &PExecutionContext,
+        by: &str,
+        null_rows: &mut Vec<DataFrame>,
+    ) -> PolarsResult<()> {
+        while !self.finished && self.buffer.is_none() {
+            match self.source.get_batches(context)? {
+                SourceResult::Finished => self.finished = true,
+                SourceResult::GotMoreData(mut chunks) => {
+                    chunks.sort_unstable_by_key(|chunk| chunk.chunk_index);
+                    let df = accumulate_dataframes_vertical_unchecked(
+                        chunks.into_iter().map(|chunk| chunk.data),
+                    );
+                    let df = self.split_off_nulls(df, by, null_rows)?;
+                    if df.height() > 0 {
+                        self.buffer = Some(self.check_sorted(df, by)?);
+                    }
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Move the rows with a null key to `null_rows` and return the other rows.
+    fn split_off_nulls(
+        &mut self,
+        df: DataFrame,
+        by: &str,
+        null_rows: &mut Vec<DataFrame>,
+    ) -> PolarsResult<DataFrame> {
+        let key = df.column(by)?;
+        let null_count = key.null_count();
+        let height = df.height();
+        let (nulls, df) = if null_count == 0 {
+            polars_ensure!(!self.trailing_nulls, InvalidOperation: "{}", not_sorted(by));
+            return Ok(df);
+        } else if null_count == height {
+            if self.last.is_some() {
+                self.trailing_nulls = true;
+            } else {
+                self.leading_nulls = true;
+            }
+            let empty = df.clear();
+            (df, empty)
+        } else if key.slice(0, null_count).null_count() == null_count {
+            polars_ensure!(
+                self.last.is_none() && !self.trailing_nulls,
+                InvalidOperation: "{}", not_sorted(by)
+            );
+            self.leading_nulls = true;
+            df.split_at(null_count as i64)
+        } else if key
+            .slice((height - null_count) as i64, null_count)
+            .null_count()
+            == null_count
+        {
+            polars_ensure!(!self.trailing_nulls, InvalidOperation: "{}", not_sorted(by));
+            self.trailing_nulls = true;
+            let (df, nulls) = df.split_at((height - null_count) as i64);
+            (nulls, df)
+        } else {
+            polars_bail!(InvalidOperation: "{}", not_sorted(by));
+        };
+        null_rows.push(nulls);
+        Ok(df)
+    }
+
+    /// Check that the non-null keys of `df` are sorted and continue the order of the source,
+    /// and flag them as sorted.
+    fn check_sorted(&mut self, mut df: DataFrame, by: &str) -> PolarsResult<DataFrame> {
+        let key = df.column(by)?;
+        let continues = match &self.last {
+            Some(last) => !sorts_before(&key.head(Some(1)), last)?,
+            None => true,
+        };
+        polars_ensure!(
+            continues && key.is_sorted(SortOptions::default())?,
+            InvalidOperation: "{}", not_sorted(by)
+        );
+        self.last = Some(key.tail(Some(1)));
+        df.apply(by, |s| {
+            let mut s = s.clone();
+            s.set_sorted_flag(IsSorted::Ascending);
+            s
+        })?;
+        Ok(df)
+    }
+}
+
+fn not_sorted(by: &str) -> String {
+    format!(
+        "the inputs of merge_sorted must be sorted by '{}' in ascending order, with their nulls \
+        either all first or all last",
+        by
+    )
+}
+
+/// Merges sources that are sorted by a key column into a single sorted source.
+///
+/// Rows are emitted up to the watermark: the smallest of the last keys read from the
+/// sources that are not finished. These sources can't produce smaller keys anymore, so
+/// only a batch per source has to be buffered.
+///
+/// Rows with a null key are kept apart. Every source is read until it has a non-null key
+/// before the first rows are emitted, so by then the leading nulls of all sources are
+/// known; they are emitted first. Trailing nulls are emitted after all other rows.
+pub struct MergeSortedSource {
+    inputs: Vec<MergeInput>,
+    by: Arc<str>,
+    n_threads: usize,
+    null_rows: Vec<DataFrame>,
+    // Decided when the first rows are emitted.
+    nulls_last: Option<bool>,
+}
+
+impl MergeSortedSource {
+    pub(crate) fn new(sources: Vec<Box<dyn Source>>, by: Arc<str>) -> Self {
+        let inputs = sources
+            .into_iter()
+            .map(|source| MergeInput {
+                source,
+                buffer: None,
+                finished: false,
+                last: None,
+                leading_nulls: false,
+                trailing_nulls: false,
+            })
+            .collect();
+        Self {
+            inputs,
+            by,
+            n_threads: POOL.current_num_threads(),
+            null_rows: vec![],
+            nulls_last: None,
+        }
+    }
+
+    fn watermark(&self) -> PolarsResult<Option<Series>> {
+        let mut watermark: Option<Series> = None;
+        for input in &self.inputs {
+            // Unfinished inputs always have buffered rows.
+            let Some(df) = input.buffer.as_ref().filter(|_| !input.finished) else {
+                continue;
+            };
+            let last = df.column(&self.by)?.tail(Some(1));
+            watermark = match watermark {
+                Some(wm) if !sorts_before(&last, &wm)? => Some(wm),
+                _ => Some(last),
+            };
+        }
+        Ok(watermark)
+    }
+
+    fn nulls_last(&mut self) -> PolarsResult<bool> {
+        // A source that only read nulls so far fits both placements.
+        let nulls_first = self
+            .inputs
+            .iter()
+            .any(|input| input.leading_nulls && input.last.is_some());
+        let nulls_last = *self.nulls_last.get_or_insert(!nulls_first);
+        let trailing_nulls = self.inputs.iter().any(|input| input.trailing_nulls);
+        polars_ensure!(
+            if nulls_last { !nulls_first } else { !trailing_nulls },
+            InvalidOperation: "the inputs of merge_sorted place the nulls of '{}' differently",
+            self.by
+        );
+        Ok(nulls_last)
+    }
+}
+
+/// Whether the single non-null value of `a` sorts before the one of `b`.
+fn sorts_before(a: &Series, b: &Series) -> PolarsResult<bool> {
+    Ok(a.lt(b)?.get(0) == Some(true))
+}
+
+/// The number of leading values of the sorted, non-null `key` that sort up to the watermark.
+fn n_up_to(key: &Series, watermark: &Series) -> PolarsResult<usize> {
+    Ok(key.lt_eq(watermark)?.sum().unwrap_or(0) as usize)
+}
+
+impl Source for MergeSortedSource {
+    fn get_batches(&mut self, context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        for input in &mut self.inputs {
+            input.fill(context, &self.by, &mut self.null_rows)?;
+        }
+        let nulls_last = self.nulls_last()?;
+        // If all inputs are finished, everything that is left can be emitted.
+        let watermark = self.watermark()?;
+
+        let mut ready = Vec::with_capacity(self.inputs.len());
+        for input in &mut self.inputs {
+            let Some(df) = input.buffer.take() else {
+                continue;
+            };
+            let n = match &watermark {
+                Some(watermark) => n_up_to(df.column(&self.by)?, watermark)?,
+                None => df.height(),
+            };
+            let (head, tail) = df.split_at(n as i64);
+            if tail.height() > 0 {
+                input.buffer = Some(tail);
+            }
+            if head.height() > 0 {
+                ready.push(head);
+            }
+        }
+
+        let mut out = Vec::with_capacity(self.null_rows.len() + 1);
+        if !nulls_last {
+            out.append(&mut self.null_rows);
+        }
+        if !ready.is_empty() {
+            out.push(_merge_sorted_dfs_by(&ready, &self.by)?);
+        } else {
+            // The input that sets the watermark is always emitted completely, so this
+            // only happens if all inputs are depleted.
+            out.append(&mut self.null_rows);
+        }
+        if out.is_empty() {
+            return Ok(SourceResult::Finished);
+        }
+
+        let mut df = accumulate_dataframes_vertical_unchecked(out);
+        df.as_single_chunk_par();
+        let dfs = split_df(&mut df, self.n_threads, false);
+        let idx_offset = get_source_index(dfs.len() as u32);
+        let chunks = dfs
+            .into_iter()
+            .enumerate()
+            .map(|(i, data)| DataChunk::new((idx_offset + i as u32) as IdxSize, data))
+            .collect();
+        Ok(SourceResult::GotMoreData(chunks))
+    }
+
+    fn fmt(&self) -> &str {
+        "merge_sorted"
+    }
+}
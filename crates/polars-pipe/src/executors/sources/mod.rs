@@ -2,6 +2,8 @@
 mod csv;
 mod frame;
 mod ipc_one_shot;
+#[cfg(feature = "merge_sorted")]
+mod merge_sorted;
 #[cfg(feature = "parquet")]
 mod parquet;
 mod reproject;
@@ -13,6 +15,8 @@ use std::sync::atomic::{AtomicU32, Ordering};
 pub(crate) use csv::CsvSource;
 pub(crate) use frame::*;
 pub(crate) use ipc_one_shot::*;
+#[cfg(feature = "merge_sorted")]
+pub(crate) use merge_sorted::*;
 #[cfg(feature = "parquet")]
 pub(crate) use parquet::*;
 pub(crate) use reproject::*;
@@ -6,7 +6,7 @@ use polars_core::prelude::*;
 use polars_core::with_match_physical_integer_polars_type;
 #[cfg(feature = "parquet")]
 use polars_io::predicates::{PhysicalIoExpr, StatsEvaluator};
-use polars_ops::prelude::JoinType;
+use polars_ops::prelude::{JoinBuildSide, JoinType};
 use polars_plan::prelude::expr_ir::{ExprIR, OutputName};
 use polars_plan::prelude::*;
 
@@ -156,6 +156,90 @@ where
     }
 }
 
+fn get_output_sink(
+    payload: &SinkType,
+    input_schema: &SchemaRef,
+) -> PolarsResult<Box<dyn SinkTrait>> {
+    let out = match payload {
+        SinkType::Memory => Box::new(OrderedSink::new(input_schema.clone())) as Box<dyn SinkTrait>,
+        SinkType::Batches {
+            callback,
+            chunk_size,
+        } => Box::new(BatchSink::new(callback.clone(), *chunk_size)?) as Box<dyn SinkTrait>,
+        #[allow(unused_variables)]
+        SinkType::File {
+            path, file_type, ..
+        } => {
+            let path = path.as_ref().as_path();
+            match &file_type {
+                #[cfg(feature = "parquet")]
+                FileType::Parquet(options) => {
+                    Box::new(ParquetSink::new(path, *options, input_schema.as_ref())?)
+                        as Box<dyn SinkTrait>
+                },
+                #[cfg(feature = "ipc")]
+                FileType::Ipc(options) => {
+                    Box::new(IpcSink::new(path, *options, input_schema.as_ref())?)
+                        as Box<dyn SinkTrait>
+                },
+                #[cfg(feature = "csv")]
+                FileType::Csv(options) => {
+                    Box::new(CsvSink::new(path, options.clone(), input_schema.as_ref())?)
+                        as Box<dyn SinkTrait>
+                },
+                #[cfg(feature = "json")]
+                FileType::Json(options) => {
+                    Box::new(JsonSink::new(path, *options, input_schema.as_ref())?)
+                        as Box<dyn SinkTrait>
+                },
+                #[allow(unreachable_patterns)]
+                _ => unreachable!(),
+            }
+        },
+        #[cfg(feature = "cloud")]
+        SinkType::Cloud {
+            #[cfg(any(feature = "parquet", feature = "ipc"))]
+            uri,
+            file_type,
+            #[cfg(any(feature = "parquet", feature = "ipc"))]
+            cloud_options,
+            ..
+        } => match &file_type {
+            #[cfg(feature = "parquet")]
+            FileType::Parquet(parquet_options) => Box::new(ParquetCloudSink::new(
+                uri.as_ref().as_str(),
+                cloud_options.as_ref(),
+                *parquet_options,
+                input_schema.as_ref(),
+            )?) as Box<dyn SinkTrait>,
+            #[cfg(feature = "ipc")]
+            FileType::Ipc(ipc_options) => Box::new(IpcCloudSink::new(
+                uri.as_ref().as_str(),
+                cloud_options.as_ref(),
+                *ipc_options,
+                input_schema.as_ref(),
+            )?) as Box<dyn SinkTrait>,
+            #[allow(unreachable_patterns)]
+            other_file_type => {
+                todo!("Cloud-sinking of the file type {other_file_type:?} is not (yet) supported.")
+            },
+        },
+        SinkType::Multiple(payloads) => {
+            // The result of a memory sink would be discarded.
+            polars_ensure!(
+                !payloads.iter().any(|p| matches!(p, SinkType::Memory)),
+                InvalidOperation: "a memory sink can't be combined with other sinks"
+            );
+            let sinks = payloads
+                .iter()
+                .map(|payload| get_output_sink(payload, input_schema))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            Box::new(TeeSink::new(sinks)) as Box<dyn SinkTrait>
+        },
+    };
+    Ok(out)
+}
+
 pub fn get_sink<F>(
     node: Node,
     lp_arena: &Arena<IR>,
@@ -170,71 +254,7 @@ where
     let out = match lp_arena.get(node) {
         Sink { input, payload } => {
             let input_schema = lp_arena.get(*input).schema(lp_arena);
-            match payload {
-                SinkType::Memory => {
-                    Box::new(OrderedSink::new(input_schema.into_owned())) as Box<dyn SinkTrait>
-                },
-                #[allow(unused_variables)]
-                SinkType::File {
-                    path, file_type, ..
-                } => {
-                    let path = path.as_ref().as_path();
-                    match &file_type {
-                        #[cfg(feature = "parquet")]
-                        FileType::Parquet(options) => {
-                            Box::new(ParquetSink::new(path, *options, input_schema.as_ref())?)
-                                as Box<dyn SinkTrait>
-                        },
-                        #[cfg(feature = "ipc")]
-                        FileType::Ipc(options) => {
-                            Box::new(IpcSink::new(path, *options, input_schema.as_ref())?)
-                                as Box<dyn SinkTrait>
-                        },
-                        #[cfg(feature = "csv")]
-                        FileType::Csv(options) => {
-                            Box::new(CsvSink::new(path, options.clone(), input_schema.as_ref())?)
-                                as Box<dyn SinkTrait>
-                        },
-                        #[cfg(feature = "json")]
-                        FileType::Json(options) => {
-                            Box::new(JsonSink::new(path, *options, input_schema.as_ref())?)
-                                as Box<dyn SinkTrait>
-                        },
-                        #[allow(unreachable_patterns)]
-                        _ => unreachable!(),
-                    }
-                },
-                #[cfg(feature = "cloud")]
-                SinkType::Cloud {
-                    #[cfg(any(feature = "parquet", feature = "ipc"))]
-                    uri,
-                    file_type,
-                    #[cfg(any(feature = "parquet", feature = "ipc"))]
-                    cloud_options,
-                    ..
-                } => {
-                    match &file_type {
-                        #[cfg(feature = "parquet")]
-                        FileType::Parquet(parquet_options) => Box::new(ParquetCloudSink::new(
-                            uri.as_ref().as_str(),
-                            cloud_options.as_ref(),
-                            *parquet_options,
-                            lp_arena.get(*input).schema(lp_arena).as_ref(),
-                        )?)
-                            as Box<dyn SinkTrait>,
-                        #[cfg(feature = "ipc")]
-                        FileType::Ipc(ipc_options) => Box::new(IpcCloudSink::new(
-                            uri.as_ref().as_str(),
-                            cloud_options.as_ref(),
-                            *ipc_options,
-                            lp_arena.get(*input).schema(lp_arena).as_ref(),
-                        )?)
-                            as Box<dyn SinkTrait>,
-                        #[allow(unreachable_patterns)]
-                        other_file_type => todo!("Cloud-sinking of the file type {other_file_type:?} is not (yet) supported."),
-                    }
-                },
-            }
+            get_output_sink(payload, &input_schema)?
         },
         Join {
             input_left,
@@ -679,7 +699,11 @@ where
                 true,
                 verbose,
             )?,
-            Union { inputs, .. } => {
+            Union {
+                inputs,
+                merge_sorted_by,
+                ..
+            } => {
                 let sources = inputs
                     .iter()
                     .enumerate()
@@ -696,7 +720,14 @@ where
                         )
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
-                Box::new(sources::UnionSource::new(sources)) as Box<dyn Source>
+                match merge_sorted_by {
+                    #[cfg(feature = "merge_sorted")]
+                    Some(by) => Box::new(sources::MergeSortedSource::new(sources, by.clone()))
+                        as Box<dyn Source>,
+                    #[cfg(not(feature = "merge_sorted"))]
+                    Some(_) => panic!("activate feature 'merge_sorted'"),
+                    None => Box::new(sources::UnionSource::new(sources)) as Box<dyn Source>,
+                }
             },
             lp => {
                 panic!("source {lp:?} not (yet) supported")
@@ -744,7 +775,13 @@ where
     ))
 }
 
+/// Whether the streaming join should build its hash table on the right input.
+///
+/// Unless the hints force a build side, the input with the fewest estimated rows is built on.
 pub fn swap_join_order(options: &JoinOptions) -> bool {
+    if let Some(build_side) = options.args.hints.forced_build_side() {
+        return build_side == JoinBuildSide::Right;
+    }
     matches!(options.args.how, JoinType::Left)
         || match (options.rows_left, options.rows_right) {
             ((Some(left), _), (Some(right), _)) => left > right,
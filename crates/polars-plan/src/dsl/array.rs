@@ -192,4 +192,24 @@ impl ArrayNameSpace {
             false,
         )
     }
+
+    /// Slice every sub-array.
+    ///
+    /// The bounds must be known up front, as they determine the width of the resulting array.
+    pub fn slice(self, offset: i64, length: usize) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Slice(
+                offset, length,
+            )))
+    }
+
+    /// Get the first `n` elements of every sub-array.
+    pub fn head(self, n: usize) -> Expr {
+        self.slice(0, n)
+    }
+
+    /// Get the last `n` elements of every sub-array.
+    pub fn tail(self, n: usize) -> Expr {
+        self.slice(-(n as i64), n)
+    }
 }
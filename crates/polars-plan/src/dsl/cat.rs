@@ -8,4 +8,47 @@ impl CategoricalNameSpace {
         self.0
             .apply_private(CategoricalFunction::GetCategories.into())
     }
+
+    /// Append the `categories` that are not categories yet to the categories of an Enum.
+    ///
+    /// The existing categories keep their position, so the physical codes are unchanged.
+    pub fn add_categories(self, categories: Vec<String>) -> Expr {
+        self.0
+            .map_private(CategoricalFunction::AddCategories(categories).into())
+    }
+
+    /// Reorder the categories of an Enum; `categories` must hold every category exactly once.
+    pub fn reorder_categories(self, categories: Vec<String>) -> Expr {
+        self.0
+            .map_private(CategoricalFunction::ReorderCategories(categories).into())
+    }
+
+    /// Check if the categories match a regex pattern, or contain `pat` if `literal` is set.
+    ///
+    /// The pattern is evaluated once per category rather than once per row.
+    #[cfg(feature = "strings")]
+    pub fn contains(self, pat: &str, literal: bool, strict: bool) -> Expr {
+        self.0.map_private(
+            CategoricalFunction::Contains {
+                pat: pat.to_string(),
+                literal,
+                strict,
+            }
+            .into(),
+        )
+    }
+
+    /// Check if the categories start with `prefix`.
+    #[cfg(feature = "strings")]
+    pub fn starts_with(self, prefix: &str) -> Expr {
+        self.0
+            .map_private(CategoricalFunction::StartsWith(prefix.to_string()).into())
+    }
+
+    /// Check if the categories end with `suffix`.
+    #[cfg(feature = "strings")]
+    pub fn ends_with(self, suffix: &str) -> Expr {
+        self.0
+            .map_private(CategoricalFunction::EndsWith(suffix.to_string()).into())
+    }
 }
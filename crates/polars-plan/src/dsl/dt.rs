@@ -130,6 +130,14 @@ impl DateLikeNameSpace {
             .map_private(FunctionExpr::TemporalExpr(TemporalFunction::WeekDay))
     }
 
+    /// Get the ISO year, ISO week and weekday of a Date/Datetime as a struct with the
+    /// fields `iso_year`, `iso_week` and `weekday`.
+    #[cfg(feature = "dtype-struct")]
+    pub fn iso_year_week_day(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::IsoYearWeekDay))
+    }
+
     /// Get the month of a Date/Datetime.
     pub fn day(self) -> Expr {
         self.0
@@ -212,6 +220,21 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Truncate the Datetime/Date range into buckets, localizing the results that are
+    /// ambiguous or non-existent in the time zone of the data with the policies of their row.
+    ///
+    /// `ambiguous` is one of `"infer"`, `"earliest"`, `"latest"`, `"null"` or `"raise"`, where
+    /// `"infer"` keeps the DST fold of the original datetime like [`DateLikeNameSpace::truncate`].
+    /// `non_existent` is `"null"` or `"raise"`.
+    pub fn truncate_localized(self, every: Expr, ambiguous: Expr, non_existent: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::TemporalExpr(TemporalFunction::Truncate),
+            &[every, ambiguous, non_existent],
+            false,
+            false,
+        )
+    }
+
     /// Roll backward to the first day of the month.
     #[cfg(feature = "month_start")]
     pub fn month_start(self) -> Expr {
@@ -250,6 +273,17 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Round the Datetime/Date range into buckets, localizing the results like
+    /// [`DateLikeNameSpace::truncate_localized`].
+    pub fn round_localized(self, every: Expr, ambiguous: Expr, non_existent: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::TemporalExpr(TemporalFunction::Round),
+            &[every, ambiguous, non_existent],
+            false,
+            false,
+        )
+    }
+
     /// Offset this `Date/Datetime` by a given offset [`Duration`].
     /// This will take leap years/ months into account.
     #[cfg(feature = "offset_by")]
@@ -331,4 +365,11 @@ impl DateLikeNameSpace {
             TemporalFunction::TotalNanoseconds,
         ))
     }
+
+    /// Format a Duration as an ISO 8601 duration like `P1DT2H30M`.
+    pub fn to_iso8601_duration(self) -> Expr {
+        self.0.map_private(FunctionExpr::TemporalExpr(
+            TemporalFunction::ToIso8601Duration,
+        ))
+    }
 }
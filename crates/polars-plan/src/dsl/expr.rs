@@ -23,7 +23,9 @@ pub enum AggExpr {
     Median(Arc<Expr>),
     NUnique(Arc<Expr>),
     First(Arc<Expr>),
+    FirstNonNull(Arc<Expr>),
     Last(Arc<Expr>),
+    LastNonNull(Arc<Expr>),
     Mean(Arc<Expr>),
     Implode(Arc<Expr>),
     // include_nulls
@@ -33,10 +35,16 @@ pub enum AggExpr {
         quantile: Arc<Expr>,
         interpol: QuantileInterpolOptions,
     },
+    ApproxQuantile {
+        expr: Arc<Expr>,
+        quantile: Arc<Expr>,
+        options: ApproxQuantileOptions,
+    },
     Sum(Arc<Expr>),
     AggGroups(Arc<Expr>),
     Std(Arc<Expr>, u8),
     Var(Arc<Expr>, u8),
+    Mode(Arc<Expr>, ModeKeep),
 }
 
 impl AsRef<Expr> for AggExpr {
@@ -48,15 +56,19 @@ impl AsRef<Expr> for AggExpr {
             Median(e) => e,
             NUnique(e) => e,
             First(e) => e,
+            FirstNonNull(e) => e,
             Last(e) => e,
+            LastNonNull(e) => e,
             Mean(e) => e,
             Implode(e) => e,
             Count(e, _) => e,
             Quantile { expr, .. } => expr,
+            ApproxQuantile { expr, .. } => expr,
             Sum(e) => e,
             AggGroups(e) => e,
             Std(e, _) => e,
             Var(e, _) => e,
+            Mode(e, _) => e,
         }
     }
 }
@@ -28,6 +28,58 @@ pub trait SeriesUdf: Send + Sync {
     fn get_output(&self) -> Option<GetOutput> {
         None
     }
+
+    /// The grouped implementation of this function, see [`GroupsUdf`].
+    fn as_groups_udf(&self) -> Option<&dyn GroupsUdf> {
+        None
+    }
+}
+
+/// A function that is evaluated once for all groups of a group-by aggregation.
+///
+/// Instead of being called with a materialized `Series` per group, the function receives
+/// the flat input columns together with the groups that index into them. Slice groups can
+/// be turned into zero-copy per group `Series` with [`Series::slice`].
+pub trait GroupsUdf: Send + Sync {
+    /// Evaluate the function on the `groups` of `s`. Must return one value per group, or
+    /// one value per row of `s` if [`GroupsUdf::returns_scalar`] is `false`.
+    fn call_udf_groups(&self, s: &mut [Series], groups: &GroupsProxy) -> PolarsResult<Series>;
+
+    /// Whether the function returns one value per group. Otherwise it returns one value
+    /// per row of the flat input, in the order of the input.
+    fn returns_scalar(&self) -> bool {
+        true
+    }
+}
+
+/// Wraps a grouped function, see [`Expr::apply_groups`].
+pub(crate) struct GroupsFunction<F>(pub(crate) F);
+
+impl<F> SeriesUdf for GroupsFunction<F>
+where
+    F: Fn(&mut [Series], &GroupsProxy) -> PolarsResult<Series> + Send + Sync,
+{
+    fn call_udf(&self, s: &mut [Series]) -> PolarsResult<Option<Series>> {
+        // Outside of a group-by context, all rows form a single group.
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, s[0].len() as IdxSize]],
+            rolling: false,
+        };
+        (self.0)(s, &groups).map(Some)
+    }
+
+    fn as_groups_udf(&self) -> Option<&dyn GroupsUdf> {
+        Some(self)
+    }
+}
+
+impl<F> GroupsUdf for GroupsFunction<F>
+where
+    F: Fn(&mut [Series], &GroupsProxy) -> PolarsResult<Series> + Send + Sync,
+{
+    fn call_udf_groups(&self, s: &mut [Series], groups: &GroupsProxy) -> PolarsResult<Series> {
+        (self.0)(s, groups)
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -1,3 +1,4 @@
+use polars_core::utils::slice_offsets;
 use polars_ops::chunked_array::array::*;
 
 use super::*;
@@ -30,6 +31,7 @@ pub enum ArrayFunction {
     #[cfg(feature = "array_count")]
     CountMatches,
     Shift,
+    Slice(i64, usize),
 }
 
 impl ArrayFunction {
@@ -56,6 +58,13 @@ impl ArrayFunction {
             #[cfg(feature = "array_count")]
             CountMatches => mapper.with_dtype(IDX_DTYPE),
             Shift => mapper.with_same_dtype(),
+            Slice(offset, length) => mapper.try_map_dtype(|dtype| match dtype {
+                DataType::Array(inner, width) => {
+                    let (_, width) = slice_offsets(*offset, *length, *width);
+                    Ok(DataType::Array(inner.clone(), width))
+                },
+                _ => polars_bail!(ComputeError: "expected array dtype"),
+            }),
         }
     }
 }
@@ -96,6 +105,7 @@ impl Display for ArrayFunction {
             #[cfg(feature = "array_count")]
             CountMatches => "count_matches",
             Shift => "shift",
+            Slice(_, _) => "slice",
         };
         write!(f, "arr.{name}")
     }
@@ -129,6 +139,7 @@ impl From<ArrayFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "array_count")]
             CountMatches => map_as_slice!(count_matches),
             Shift => map_as_slice!(shift),
+            Slice(offset, length) => map!(slice, offset, length),
         }
     }
 }
@@ -243,3 +254,7 @@ pub(super) fn shift(s: &[Series]) -> PolarsResult<Series> {
 
     ca.array_shift(n)
 }
+
+pub(super) fn slice(s: &Series, offset: i64, length: usize) -> PolarsResult<Series> {
+    Ok(s.array()?.array_slice(offset, length)?.into_series())
+}
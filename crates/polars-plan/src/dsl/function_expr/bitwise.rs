@@ -0,0 +1,52 @@
+use super::*;
+use crate::map;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum BitwiseFunction {
+    Not,
+    ShiftLeft(u32),
+    ShiftRight(u32),
+    // Aggregations
+    AndAgg,
+    OrAgg,
+}
+
+impl BitwiseFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        mapper.with_same_dtype()
+    }
+}
+
+impl Display for BitwiseFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use BitwiseFunction::*;
+        let s = match self {
+            Not => "not",
+            ShiftLeft(_) => "shift_left",
+            ShiftRight(_) => "shift_right",
+            AndAgg => "and_agg",
+            OrAgg => "or_agg",
+        };
+        write!(f, "bitwise_{s}")
+    }
+}
+
+impl From<BitwiseFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
+    fn from(func: BitwiseFunction) -> Self {
+        use BitwiseFunction::*;
+        match func {
+            Not => map!(polars_ops::series::bitwise_not),
+            ShiftLeft(n) => map!(polars_ops::series::bitwise_shift_left, n),
+            ShiftRight(n) => map!(polars_ops::series::bitwise_shift_right, n),
+            AndAgg => map!(polars_ops::series::bitwise_and_reduce),
+            OrAgg => map!(polars_ops::series::bitwise_or_reduce),
+        }
+    }
+}
+
+impl From<BitwiseFunction> for FunctionExpr {
+    fn from(func: BitwiseFunction) -> Self {
+        FunctionExpr::Bitwise(func)
+    }
+}
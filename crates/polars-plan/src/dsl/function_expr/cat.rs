@@ -5,6 +5,18 @@ use crate::map;
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum CategoricalFunction {
     GetCategories,
+    AddCategories(Vec<String>),
+    ReorderCategories(Vec<String>),
+    #[cfg(feature = "strings")]
+    Contains {
+        pat: String,
+        literal: bool,
+        strict: bool,
+    },
+    #[cfg(feature = "strings")]
+    StartsWith(String),
+    #[cfg(feature = "strings")]
+    EndsWith(String),
 }
 
 impl CategoricalFunction {
@@ -12,6 +24,14 @@ impl CategoricalFunction {
         use CategoricalFunction::*;
         match self {
             GetCategories => mapper.with_dtype(DataType::String),
+            AddCategories(categories) => mapper.try_map_dtype(|dt| {
+                extend_enum_data_type(dt, &Utf8ViewArray::from_slice_values(categories))
+            }),
+            ReorderCategories(categories) => mapper.try_map_dtype(|dt| {
+                reorder_enum_data_type(dt, &Utf8ViewArray::from_slice_values(categories))
+            }),
+            #[cfg(feature = "strings")]
+            Contains { .. } | StartsWith(_) | EndsWith(_) => mapper.with_dtype(DataType::Boolean),
         }
     }
 }
@@ -21,6 +41,14 @@ impl Display for CategoricalFunction {
         use CategoricalFunction::*;
         let s = match self {
             GetCategories => "get_categories",
+            AddCategories(_) => "add_categories",
+            ReorderCategories(_) => "reorder_categories",
+            #[cfg(feature = "strings")]
+            Contains { .. } => "contains",
+            #[cfg(feature = "strings")]
+            StartsWith(_) => "starts_with",
+            #[cfg(feature = "strings")]
+            EndsWith(_) => "ends_with",
         };
         write!(f, "cat.{s}")
     }
@@ -31,6 +59,18 @@ impl From<CategoricalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
         use CategoricalFunction::*;
         match func {
             GetCategories => map!(get_categories),
+            AddCategories(categories) => map!(add_categories, &categories),
+            ReorderCategories(categories) => map!(reorder_categories, &categories),
+            #[cfg(feature = "strings")]
+            Contains {
+                pat,
+                literal,
+                strict,
+            } => map!(contains, &pat, literal, strict),
+            #[cfg(feature = "strings")]
+            StartsWith(prefix) => map!(starts_with, &prefix),
+            #[cfg(feature = "strings")]
+            EndsWith(suffix) => map!(ends_with, &suffix),
         }
     }
 }
@@ -48,3 +88,31 @@ fn get_categories(s: &Series) -> PolarsResult<Series> {
     let arr = rev_map.get_categories().clone().boxed();
     Series::try_from((ca.name(), arr))
 }
+
+fn add_categories(s: &Series, categories: &[String]) -> PolarsResult<Series> {
+    s.cast_enum_extend(&Utf8ViewArray::from_slice_values(categories))
+}
+
+fn reorder_categories(s: &Series, categories: &[String]) -> PolarsResult<Series> {
+    let ca = s.categorical()?;
+    ca.reorder_enum_categories(&Utf8ViewArray::from_slice_values(categories))
+        .map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "strings")]
+fn contains(s: &Series, pat: &str, literal: bool, strict: bool) -> PolarsResult<Series> {
+    let ca = s.categorical()?;
+    polars_ops::prelude::cat_contains(ca, pat, literal, strict).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "strings")]
+fn starts_with(s: &Series, prefix: &str) -> PolarsResult<Series> {
+    let ca = s.categorical()?;
+    polars_ops::prelude::cat_starts_with(ca, prefix).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "strings")]
+fn ends_with(s: &Series, suffix: &str) -> PolarsResult<Series> {
+    let ca = s.categorical()?;
+    polars_ops::prelude::cat_ends_with(ca, suffix).map(|ca| ca.into_series())
+}
@@ -20,6 +20,18 @@ pub(super) fn cum_max(s: &Series, reverse: bool) -> PolarsResult<Series> {
     polars_ops::prelude::cum_max(s, reverse)
 }
 
+pub(super) fn cum_mean(s: &Series, reverse: bool) -> PolarsResult<Series> {
+    polars_ops::prelude::cum_mean(s, reverse)
+}
+
+pub(super) fn cum_std(s: &Series, reverse: bool, ddof: u8) -> PolarsResult<Series> {
+    polars_ops::prelude::cum_std(s, reverse, ddof)
+}
+
+pub(super) fn cum_quantile(s: &Series, quantile: f64, reverse: bool) -> PolarsResult<Series> {
+    polars_ops::prelude::cum_quantile(s, quantile, reverse)
+}
+
 pub(super) mod dtypes {
     use polars_core::utils::materialize_dyn_int;
     use DataType::*;
@@ -0,0 +1,46 @@
+use super::*;
+
+/// Bins by quantiles, which are computed per group in a group-by context.
+pub(super) struct QCutFunction {
+    pub(super) probs: Vec<f64>,
+    pub(super) labels: Option<Vec<String>>,
+    pub(super) left_closed: bool,
+    pub(super) allow_duplicates: bool,
+    pub(super) include_breaks: bool,
+}
+
+impl SeriesUdf for QCutFunction {
+    fn call_udf(&self, s: &mut [Series]) -> PolarsResult<Option<Series>> {
+        qcut(
+            &s[0],
+            self.probs.clone(),
+            self.labels.clone(),
+            self.left_closed,
+            self.allow_duplicates,
+            self.include_breaks,
+        )
+        .map(Some)
+    }
+
+    fn as_groups_udf(&self) -> Option<&dyn GroupsUdf> {
+        Some(self)
+    }
+}
+
+impl GroupsUdf for QCutFunction {
+    fn call_udf_groups(&self, s: &mut [Series], groups: &GroupsProxy) -> PolarsResult<Series> {
+        qcut_groups(
+            &s[0],
+            groups,
+            self.probs.clone(),
+            self.labels.clone(),
+            self.left_closed,
+            self.allow_duplicates,
+            self.include_breaks,
+        )
+    }
+
+    fn returns_scalar(&self) -> bool {
+        false
+    }
+}
@@ -3,6 +3,7 @@ use arrow::temporal_conversions::{MICROSECONDS, MILLISECONDS, NANOSECONDS, SECON
 use chrono_tz::Tz;
 #[cfg(feature = "timezones")]
 use polars_core::chunked_array::temporal::validate_time_zone;
+use polars_core::utils::handle_casting_failures;
 #[cfg(feature = "timezones")]
 use polars_time::base_utc_offset as base_utc_offset_fn;
 #[cfg(feature = "timezones")]
@@ -22,6 +23,8 @@ pub enum TemporalFunction {
     Year,
     IsLeapYear,
     IsoYear,
+    #[cfg(feature = "dtype-struct")]
+    IsoYearWeekDay,
     Quarter,
     Month,
     Week,
@@ -45,6 +48,7 @@ pub enum TemporalFunction {
     TotalMilliseconds,
     TotalMicroseconds,
     TotalNanoseconds,
+    ToIso8601Duration,
     ToString(String),
     CastTimeUnit(TimeUnit),
     WithTimeUnit(TimeUnit),
@@ -70,6 +74,12 @@ pub enum TemporalFunction {
         time_unit: TimeUnit,
         time_zone: Option<TimeZone>,
     },
+    #[cfg(feature = "dtype-date")]
+    DateFromIsoWeek,
+    DurationFromString {
+        time_unit: TimeUnit,
+        strict: bool,
+    },
 }
 
 impl TemporalFunction {
@@ -78,6 +88,12 @@ impl TemporalFunction {
         match self {
             Millennium | Century => mapper.with_dtype(DataType::Int8),
             Year | IsoYear => mapper.with_dtype(DataType::Int32),
+            #[cfg(feature = "dtype-struct")]
+            IsoYearWeekDay => mapper.with_dtype(DataType::Struct(vec![
+                Field::new("iso_year", DataType::Int32),
+                Field::new("iso_week", DataType::Int8),
+                Field::new("weekday", DataType::Int8),
+            ])),
             OrdinalDay => mapper.with_dtype(DataType::Int16),
             Month | Quarter | Week | WeekDay | Day | Hour | Minute | Second => {
                 mapper.with_dtype(DataType::Int8)
@@ -85,7 +101,7 @@ impl TemporalFunction {
             Millisecond | Microsecond | Nanosecond => mapper.with_dtype(DataType::Int32),
             TotalDays | TotalHours | TotalMinutes | TotalSeconds | TotalMilliseconds
             | TotalMicroseconds | TotalNanoseconds => mapper.with_dtype(DataType::Int64),
-            ToString(_) => mapper.with_dtype(DataType::String),
+            ToString(_) | ToIso8601Duration => mapper.with_dtype(DataType::String),
             WithTimeUnit(_) => mapper.with_same_dtype(),
             CastTimeUnit(tu) => mapper.try_map_dtype(|dt| match dt {
                 DataType::Duration(_) => Ok(DataType::Duration(*tu)),
@@ -127,6 +143,11 @@ impl TemporalFunction {
                 "datetime",
                 DataType::Datetime(*time_unit, time_zone.clone()),
             )),
+            #[cfg(feature = "dtype-date")]
+            DateFromIsoWeek => Ok(Field::new("date", DataType::Date)),
+            DurationFromString { time_unit, .. } => {
+                mapper.with_dtype(DataType::Duration(*time_unit))
+            },
             Combine(tu) => mapper.try_map_dtype(|dt| match dt {
                 DataType::Datetime(_, tz) => Ok(DataType::Datetime(*tu, tz.clone())),
                 DataType::Date => Ok(DataType::Datetime(*tu, None)),
@@ -147,6 +168,8 @@ impl Display for TemporalFunction {
             Year => "year",
             IsLeapYear => "is_leap_year",
             IsoYear => "iso_year",
+            #[cfg(feature = "dtype-struct")]
+            IsoYearWeekDay => "iso_year_week_day",
             Quarter => "quarter",
             Month => "month",
             Week => "week",
@@ -170,6 +193,7 @@ impl Display for TemporalFunction {
             TotalMilliseconds => "total_milliseconds",
             TotalMicroseconds => "total_microseconds",
             TotalNanoseconds => "total_nanoseconds",
+            ToIso8601Duration => "to_iso8601_duration",
             ToString(_) => "to_string",
             #[cfg(feature = "timezones")]
             ConvertTimeZone(_) => "convert_time_zone",
@@ -191,6 +215,9 @@ impl Display for TemporalFunction {
             #[cfg(feature = "timezones")]
             ReplaceTimeZone(_, _) => "replace_time_zone",
             DatetimeFunction { .. } => return write!(f, "dt.datetime"),
+            #[cfg(feature = "dtype-date")]
+            DateFromIsoWeek => return write!(f, "date_from_isoweek"),
+            DurationFromString { .. } => return write!(f, "duration_from_string"),
             Combine(_) => "combine",
         };
         write!(f, "dt.{s}")
@@ -212,6 +239,15 @@ pub(super) fn is_leap_year(s: &Series) -> PolarsResult<Series> {
 pub(super) fn iso_year(s: &Series) -> PolarsResult<Series> {
     s.iso_year().map(|ca| ca.into_series())
 }
+#[cfg(feature = "dtype-struct")]
+pub(super) fn iso_year_week_day(s: &Series) -> PolarsResult<Series> {
+    let fields = [
+        s.iso_year()?.with_name("iso_year").into_series(),
+        s.week()?.with_name("iso_week").into_series(),
+        s.weekday()?.with_name("weekday").into_series(),
+    ];
+    StructChunked::new(s.name(), &fields).map(|ca| ca.into_series())
+}
 pub(super) fn month(s: &Series) -> PolarsResult<Series> {
     s.month().map(|ca| ca.into_series())
 }
@@ -333,6 +369,17 @@ pub(super) fn total_microseconds(s: &Series) -> PolarsResult<Series> {
 pub(super) fn total_nanoseconds(s: &Series) -> PolarsResult<Series> {
     s.duration().map(|ca| ca.nanoseconds().into_series())
 }
+pub(super) fn to_iso8601_duration(s: &Series) -> PolarsResult<Series> {
+    s.duration().map(|ca| ca.to_iso8601().into_series())
+}
+pub(super) fn duration_from_string(s: &Series, tu: TimeUnit, strict: bool) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    let out = ca.as_duration_iso8601(tu).into_series();
+    if strict && ca.null_count() != out.null_count() {
+        handle_casting_failures(s, &out)?;
+    }
+    Ok(out)
+}
 pub(super) fn timestamp(s: &Series, tu: TimeUnit) -> PolarsResult<Series> {
     s.timestamp(tu).map(|ca| ca.into_series())
 }
@@ -386,15 +433,27 @@ pub(super) fn cast_time_unit(s: &Series, tu: TimeUnit) -> PolarsResult<Series> {
 pub(super) fn truncate(s: &[Series]) -> PolarsResult<Series> {
     let time_series = &s[0];
     let every = s[1].str()?;
+    // The localization policies are only given by `truncate_localized`.
+    let policies = match s.get(2..4) {
+        Some([ambiguous, non_existent]) => Some((ambiguous.str()?, non_existent.str()?)),
+        _ => None,
+    };
 
     let mut out = match time_series.dtype() {
-        DataType::Datetime(_, tz) => match tz {
-            #[cfg(feature = "timezones")]
-            Some(tz) => time_series
-                .datetime()?
-                .truncate(tz.parse::<Tz>().ok().as_ref(), every)?
-                .into_series(),
-            _ => time_series.datetime()?.truncate(None, every)?.into_series(),
+        DataType::Datetime(_, tz) => {
+            let ca = time_series.datetime()?;
+            let truncate = |tz: Option<&arrow::legacy::time_zone::Tz>| match policies {
+                Some((ambiguous, non_existent)) => {
+                    ca.truncate_localized(tz, every, ambiguous, non_existent)
+                },
+                None => ca.truncate(tz, every),
+            };
+            match tz {
+                #[cfg(feature = "timezones")]
+                Some(tz) => truncate(tz.parse::<Tz>().ok().as_ref())?,
+                _ => truncate(None)?,
+            }
+            .into_series()
         },
         DataType::Date => time_series.date()?.truncate(None, every)?.into_series(),
         dt => polars_bail!(opq = round, got = dt, expected = "date/datetime"),
@@ -478,20 +537,27 @@ pub(super) fn dst_offset(s: &Series) -> PolarsResult<Series> {
 pub(super) fn round(s: &[Series]) -> PolarsResult<Series> {
     let time_series = &s[0];
     let every = s[1].str()?;
+    // The localization policies are only given by `round_localized`.
+    let policies = match s.get(2..4) {
+        Some([ambiguous, non_existent]) => Some((ambiguous.str()?, non_existent.str()?)),
+        _ => None,
+    };
 
     Ok(match time_series.dtype() {
-        DataType::Datetime(_, tz) => match tz {
-            #[cfg(feature = "timezones")]
-            Some(tz) => time_series
-                .datetime()
-                .unwrap()
-                .round(every, tz.parse::<Tz>().ok().as_ref())?
-                .into_series(),
-            _ => time_series
-                .datetime()
-                .unwrap()
-                .round(every, None)?
-                .into_series(),
+        DataType::Datetime(_, tz) => {
+            let ca = time_series.datetime().unwrap();
+            let round = |tz: Option<&arrow::legacy::time_zone::Tz>| match policies {
+                Some((ambiguous, non_existent)) => {
+                    ca.round_localized(every, tz, ambiguous, non_existent)
+                },
+                None => ca.round(every, tz),
+            };
+            match tz {
+                #[cfg(feature = "timezones")]
+                Some(tz) => round(tz.parse::<Tz>().ok().as_ref())?,
+                _ => round(None)?,
+            }
+            .into_series()
         },
         DataType::Date => time_series
             .date()
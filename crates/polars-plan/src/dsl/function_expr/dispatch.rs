@@ -31,6 +31,17 @@ pub(super) fn interpolate_by(s: &[Series]) -> PolarsResult<Series> {
     polars_ops::prelude::interpolate_by(&s[0], by, by_is_sorted)
 }
 
+#[cfg(feature = "is_close")]
+pub(super) fn is_close(
+    s: &[Series],
+    abs_tol: f64,
+    rel_tol: f64,
+    nans_equal: bool,
+) -> PolarsResult<Series> {
+    polars_ops::prelude::is_close(&s[0], &s[1], abs_tol, rel_tol, nans_equal)
+        .map(|ca| ca.into_series())
+}
+
 pub(super) fn to_physical(s: &Series) -> PolarsResult<Series> {
     Ok(s.to_physical_repr().into_owned())
 }
@@ -139,6 +150,15 @@ pub(super) fn rank(s: &Series, options: RankOptions, seed: Option<u64>) -> Polar
     Ok(s.rank(options, seed))
 }
 
+#[cfg(feature = "rank")]
+pub(super) fn rank_by(
+    s: &[Series],
+    options: RankOptions,
+    seed: Option<u64>,
+) -> PolarsResult<Series> {
+    s[0].rank_by(&s[1..], options, seed)
+}
+
 #[cfg(feature = "hist")]
 pub(super) fn hist(
     s: &[Series],
@@ -161,6 +181,15 @@ pub(super) fn replace(s: &[Series], return_dtype: Option<DataType>) -> PolarsRes
     polars_ops::series::replace(&s[0], &s[1], &s[2], default, return_dtype)
 }
 
+#[cfg(feature = "lookup")]
+pub(super) fn lookup(
+    s: &Series,
+    table: &LookupTable,
+    on_missing: LookupMissing,
+) -> PolarsResult<Series> {
+    table.lookup(s, on_missing)
+}
+
 pub(super) fn fill_null_with_strategy(
     s: &Series,
     strategy: FillNullStrategy,
@@ -56,6 +56,20 @@ pub enum ListFunction {
     Join(bool),
     #[cfg(feature = "dtype-array")]
     ToArray(usize),
+    #[cfg(feature = "list_zip")]
+    ZipWith(ListZipLength),
+    #[cfg(feature = "list_enumerate")]
+    Enumerate,
+    #[cfg(feature = "list_rolling")]
+    RollingSum {
+        window_size: usize,
+        min_periods: usize,
+    },
+    #[cfg(feature = "list_rolling")]
+    RollingMean {
+        window_size: usize,
+        min_periods: usize,
+    },
 }
 
 impl ListFunction {
@@ -102,6 +116,43 @@ impl ListFunction {
             Join(_) => mapper.with_dtype(DataType::String),
             #[cfg(feature = "dtype-array")]
             ToArray(width) => mapper.try_map_dtype(|dt| map_list_dtype_to_array_dtype(dt, *width)),
+            #[cfg(feature = "list_zip")]
+            ZipWith(_) => {
+                let fields = mapper
+                    .args()
+                    .iter()
+                    .map(|field| match field.data_type() {
+                        DataType::List(inner) => Ok(Field::new(field.name(), *inner.clone())),
+                        dt => {
+                            polars_bail!(InvalidOperation: "expected List data type, got: {}", dt)
+                        },
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                mapper.with_dtype(DataType::List(Box::new(DataType::Struct(fields))))
+            },
+            #[cfg(feature = "list_enumerate")]
+            Enumerate => mapper.try_map_dtype(|dt| match dt {
+                DataType::List(inner) => {
+                    let fields = vec![
+                        Field::new("index", IDX_DTYPE),
+                        Field::new("value", *inner.clone()),
+                    ];
+                    Ok(DataType::List(Box::new(DataType::Struct(fields))))
+                },
+                dt => polars_bail!(InvalidOperation: "expected List data type, got: {}", dt),
+            }),
+            #[cfg(feature = "list_rolling")]
+            RollingSum { .. } => mapper.map_dtype(|dt| {
+                DataType::List(Box::new(list_rolling_sum_dtype(
+                    dt.inner_dtype().unwrap_or(dt),
+                )))
+            }),
+            #[cfg(feature = "list_rolling")]
+            RollingMean { .. } => mapper.map_dtype(|dt| {
+                DataType::List(Box::new(list_rolling_mean_dtype(
+                    dt.inner_dtype().unwrap_or(dt),
+                )))
+            }),
             NUnique => mapper.with_dtype(IDX_DTYPE),
         }
     }
@@ -174,6 +225,14 @@ impl Display for ListFunction {
             Join(_) => "join",
             #[cfg(feature = "dtype-array")]
             ToArray(_) => "to_array",
+            #[cfg(feature = "list_zip")]
+            ZipWith(_) => "zip_with",
+            #[cfg(feature = "list_enumerate")]
+            Enumerate => "enumerate",
+            #[cfg(feature = "list_rolling")]
+            RollingSum { .. } => "rolling_sum",
+            #[cfg(feature = "list_rolling")]
+            RollingMean { .. } => "rolling_mean",
         };
         write!(f, "list.{name}")
     }
@@ -234,6 +293,20 @@ impl From<ListFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Join(ignore_nulls) => map_as_slice!(join, ignore_nulls),
             #[cfg(feature = "dtype-array")]
             ToArray(width) => map!(to_array, width),
+            #[cfg(feature = "list_zip")]
+            ZipWith(length) => map_as_slice!(zip_with, length),
+            #[cfg(feature = "list_enumerate")]
+            Enumerate => map!(enumerate),
+            #[cfg(feature = "list_rolling")]
+            RollingSum {
+                window_size,
+                min_periods,
+            } => map!(rolling_sum, window_size, min_periods),
+            #[cfg(feature = "list_rolling")]
+            RollingMean {
+                window_size,
+                min_periods,
+            } => map!(rolling_mean, window_size, min_periods),
             NUnique => map!(n_unique),
         }
     }
@@ -590,6 +663,34 @@ pub(super) fn set_operation(s: &[Series], set_type: SetOperation) -> PolarsResul
     list_set_operation(s0.list()?, s1.list()?, set_type).map(|ca| ca.into_series())
 }
 
+#[cfg(feature = "list_zip")]
+pub(super) fn zip_with(s: &[Series], length: ListZipLength) -> PolarsResult<Series> {
+    list_zip(s, length).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "list_enumerate")]
+pub(super) fn enumerate(s: &Series) -> PolarsResult<Series> {
+    list_enumerate(s.list()?).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "list_rolling")]
+pub(super) fn rolling_sum(
+    s: &Series,
+    window_size: usize,
+    min_periods: usize,
+) -> PolarsResult<Series> {
+    list_rolling_sum(s.list()?, window_size, min_periods)
+}
+
+#[cfg(feature = "list_rolling")]
+pub(super) fn rolling_mean(
+    s: &Series,
+    window_size: usize,
+    min_periods: usize,
+) -> PolarsResult<Series> {
+    list_rolling_mean(s.list()?, window_size, min_periods)
+}
+
 #[cfg(feature = "list_any_all")]
 pub(super) fn lst_any(s: &Series) -> PolarsResult<Series> {
     s.list()?.lst_any()
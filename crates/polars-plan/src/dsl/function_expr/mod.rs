@@ -5,6 +5,8 @@ mod arg_where;
 #[cfg(feature = "dtype-array")]
 mod array;
 mod binary;
+#[cfg(feature = "bitwise")]
+mod bitwise;
 mod boolean;
 mod bounds;
 #[cfg(feature = "business")]
@@ -20,6 +22,8 @@ mod concat;
 mod correlation;
 #[cfg(feature = "cum_agg")]
 mod cum;
+#[cfg(feature = "cutqcut")]
+mod cut;
 #[cfg(feature = "temporal")]
 mod datetime;
 mod dispatch;
@@ -86,6 +90,8 @@ use schema::FieldsMapper;
 use serde::{Deserialize, Serialize};
 
 pub(crate) use self::binary::BinaryFunction;
+#[cfg(feature = "bitwise")]
+pub use self::bitwise::BitwiseFunction;
 pub use self::boolean::BooleanFunction;
 #[cfg(feature = "business")]
 pub(super) use self::business::BusinessFunction;
@@ -126,6 +132,8 @@ pub enum FunctionExpr {
     TemporalExpr(TemporalFunction),
 
     // Other expressions
+    #[cfg(feature = "bitwise")]
+    Bitwise(BitwiseFunction),
     Boolean(BooleanFunction),
     #[cfg(feature = "business")]
     Business(BusinessFunction),
@@ -179,6 +187,11 @@ pub enum FunctionExpr {
         options: RankOptions,
         seed: Option<u64>,
     },
+    #[cfg(feature = "rank")]
+    RankBy {
+        options: RankOptions,
+        seed: Option<u64>,
+    },
     #[cfg(feature = "round_series")]
     Clip {
         has_min: bool,
@@ -214,6 +227,20 @@ pub enum FunctionExpr {
     CumMax {
         reverse: bool,
     },
+    #[cfg(feature = "cum_agg")]
+    CumMean {
+        reverse: bool,
+    },
+    #[cfg(feature = "cum_agg")]
+    CumStd {
+        reverse: bool,
+        ddof: u8,
+    },
+    #[cfg(feature = "cum_agg")]
+    CumQuantile {
+        quantile: f64,
+        reverse: bool,
+    },
     Reverse,
     #[cfg(feature = "dtype-struct")]
     ValueCounts {
@@ -236,6 +263,12 @@ pub enum FunctionExpr {
     Interpolate(InterpolationMethod),
     #[cfg(feature = "interpolate_by")]
     InterpolateBy,
+    #[cfg(feature = "is_close")]
+    IsClose {
+        abs_tol: f64,
+        rel_tol: f64,
+        nans_equal: bool,
+    },
     #[cfg(feature = "log")]
     Entropy {
         base: f64,
@@ -276,6 +309,11 @@ pub enum FunctionExpr {
     PeakMin,
     #[cfg(feature = "peaks")]
     PeakMax,
+    #[cfg(feature = "peaks")]
+    FindPeaks {
+        distance: Option<usize>,
+        prominence: Option<f64>,
+    },
     #[cfg(feature = "cutqcut")]
     Cut {
         breaks: Vec<f64>,
@@ -331,6 +369,16 @@ pub enum FunctionExpr {
     EwmMeanBy {
         half_life: Duration,
     },
+    #[cfg(feature = "ewma_by")]
+    EwmStdBy {
+        half_life: Duration,
+        bias: bool,
+    },
+    #[cfg(feature = "ewma_by")]
+    EwmVarBy {
+        half_life: Duration,
+        bias: bool,
+    },
     #[cfg(feature = "ewma")]
     EwmStd {
         options: EWMOptions,
@@ -343,6 +391,11 @@ pub enum FunctionExpr {
     Replace {
         return_dtype: Option<DataType>,
     },
+    #[cfg(feature = "lookup")]
+    Lookup {
+        table: LookupTable,
+        on_missing: LookupMissing,
+    },
     GatherEvery {
         n: usize,
         offset: usize,
@@ -372,6 +425,8 @@ impl Hash for FunctionExpr {
             TemporalExpr(f) => f.hash(state),
 
             // Other expressions
+            #[cfg(feature = "bitwise")]
+            Bitwise(f) => f.hash(state),
             Boolean(f) => f.hash(state),
             #[cfg(feature = "business")]
             Business(f) => f.hash(state),
@@ -394,6 +449,16 @@ impl Hash for FunctionExpr {
             Interpolate(f) => f.hash(state),
             #[cfg(feature = "interpolate_by")]
             InterpolateBy => {},
+            #[cfg(feature = "is_close")]
+            IsClose {
+                abs_tol,
+                rel_tol,
+                nans_equal,
+            } => {
+                abs_tol.to_bits().hash(state);
+                rel_tol.to_bits().hash(state);
+                nans_equal.hash(state);
+            },
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 lib,
@@ -439,7 +504,7 @@ impl Hash for FunctionExpr {
                 b.hash(state);
             },
             #[cfg(feature = "rank")]
-            Rank { options, seed } => {
+            Rank { options, seed } | RankBy { options, seed } => {
                 options.hash(state);
                 seed.hash(state);
             },
@@ -460,6 +525,18 @@ impl Hash for FunctionExpr {
             CumMin { reverse } => reverse.hash(state),
             #[cfg(feature = "cum_agg")]
             CumMax { reverse } => reverse.hash(state),
+            #[cfg(feature = "cum_agg")]
+            CumMean { reverse } => reverse.hash(state),
+            #[cfg(feature = "cum_agg")]
+            CumStd { reverse, ddof } => {
+                reverse.hash(state);
+                ddof.hash(state);
+            },
+            #[cfg(feature = "cum_agg")]
+            CumQuantile { quantile, reverse } => {
+                quantile.to_bits().hash(state);
+                reverse.hash(state);
+            },
             #[cfg(feature = "dtype-struct")]
             ValueCounts {
                 sort,
@@ -507,6 +584,14 @@ impl Hash for FunctionExpr {
             PeakMin => {},
             #[cfg(feature = "peaks")]
             PeakMax => {},
+            #[cfg(feature = "peaks")]
+            FindPeaks {
+                distance,
+                prominence,
+            } => {
+                distance.hash(state);
+                prominence.map(f64::to_bits).hash(state);
+            },
             #[cfg(feature = "cutqcut")]
             Cut {
                 breaks,
@@ -552,6 +637,11 @@ impl Hash for FunctionExpr {
             EwmMean { options } => options.hash(state),
             #[cfg(feature = "ewma_by")]
             EwmMeanBy { half_life } => (half_life).hash(state),
+            #[cfg(feature = "ewma_by")]
+            EwmStdBy { half_life, bias } | EwmVarBy { half_life, bias } => {
+                half_life.hash(state);
+                bias.hash(state);
+            },
             #[cfg(feature = "ewma")]
             EwmStd { options } => options.hash(state),
             #[cfg(feature = "ewma")]
@@ -568,6 +658,11 @@ impl Hash for FunctionExpr {
             },
             #[cfg(feature = "replace")]
             Replace { return_dtype } => return_dtype.hash(state),
+            #[cfg(feature = "lookup")]
+            Lookup { table, on_missing } => {
+                table.hash(state);
+                on_missing.hash(state);
+            },
             FillNullWithStrategy(strategy) => strategy.hash(state),
             GatherEvery { n, offset } => (n, offset).hash(state),
             #[cfg(feature = "reinterpret")]
@@ -598,6 +693,8 @@ impl Display for FunctionExpr {
             TemporalExpr(func) => return write!(f, "{func}"),
 
             // Other expressions
+            #[cfg(feature = "bitwise")]
+            Bitwise(func) => return write!(f, "{func}"),
             Boolean(func) => return write!(f, "{func}"),
             #[cfg(feature = "business")]
             Business(func) => return write!(f, "{func}"),
@@ -637,6 +734,8 @@ impl Display for FunctionExpr {
             ArgUnique => "arg_unique",
             #[cfg(feature = "rank")]
             Rank { .. } => "rank",
+            #[cfg(feature = "rank")]
+            RankBy { .. } => "rank_by",
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => match (has_min, has_max) {
                 (true, true) => "clip",
@@ -667,6 +766,12 @@ impl Display for FunctionExpr {
             CumMin { .. } => "cum_min",
             #[cfg(feature = "cum_agg")]
             CumMax { .. } => "cum_max",
+            #[cfg(feature = "cum_agg")]
+            CumMean { .. } => "cum_mean",
+            #[cfg(feature = "cum_agg")]
+            CumStd { .. } => "cum_std",
+            #[cfg(feature = "cum_agg")]
+            CumQuantile { .. } => "cum_quantile",
             #[cfg(feature = "dtype-struct")]
             ValueCounts { .. } => "value_counts",
             #[cfg(feature = "unique_counts")]
@@ -684,6 +789,8 @@ impl Display for FunctionExpr {
             Interpolate(_) => "interpolate",
             #[cfg(feature = "interpolate_by")]
             InterpolateBy => "interpolate_by",
+            #[cfg(feature = "is_close")]
+            IsClose { .. } => "is_close",
             #[cfg(feature = "log")]
             Entropy { .. } => "entropy",
             #[cfg(feature = "log")]
@@ -718,6 +825,8 @@ impl Display for FunctionExpr {
             PeakMin => "peak_min",
             #[cfg(feature = "peaks")]
             PeakMax => "peak_max",
+            #[cfg(feature = "peaks")]
+            FindPeaks { .. } => "find_peaks",
             #[cfg(feature = "cutqcut")]
             Cut { .. } => "cut",
             #[cfg(feature = "cutqcut")]
@@ -745,6 +854,10 @@ impl Display for FunctionExpr {
             EwmMean { .. } => "ewm_mean",
             #[cfg(feature = "ewma_by")]
             EwmMeanBy { .. } => "ewm_mean_by",
+            #[cfg(feature = "ewma_by")]
+            EwmStdBy { .. } => "ewm_std_by",
+            #[cfg(feature = "ewma_by")]
+            EwmVarBy { .. } => "ewm_var_by",
             #[cfg(feature = "ewma")]
             EwmStd { .. } => "ewm_std",
             #[cfg(feature = "ewma")]
@@ -753,6 +866,8 @@ impl Display for FunctionExpr {
             Hist { .. } => "hist",
             #[cfg(feature = "replace")]
             Replace { .. } => "replace",
+            #[cfg(feature = "lookup")]
+            Lookup { .. } => "lookup",
             FillNullWithStrategy(_) => "fill_null_with_strategy",
             GatherEvery { .. } => "gather_every",
             #[cfg(feature = "reinterpret")]
@@ -864,6 +979,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             TemporalExpr(func) => func.into(),
 
             // Other expressions
+            #[cfg(feature = "bitwise")]
+            Bitwise(func) => func.into(),
             Boolean(func) => func.into(),
             #[cfg(feature = "business")]
             Business(func) => func.into(),
@@ -922,6 +1039,9 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                     Mean(options) => map!(rolling::rolling_mean, options.clone()),
                     Sum(options) => map!(rolling::rolling_sum, options.clone()),
                     Quantile(options) => map!(rolling::rolling_quantile, options.clone()),
+                    ApproxQuantile(options) => {
+                        map!(rolling::rolling_approx_quantile, options.clone())
+                    },
                     Var(options) => map!(rolling::rolling_var, options.clone()),
                     Std(options) => map!(rolling::rolling_std, options.clone()),
                     #[cfg(feature = "moment")]
@@ -974,6 +1094,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             ArgUnique => map!(dispatch::arg_unique),
             #[cfg(feature = "rank")]
             Rank { options, seed } => map!(dispatch::rank, options, seed),
+            #[cfg(feature = "rank")]
+            RankBy { options, seed } => map_as_slice!(dispatch::rank_by, options, seed),
             #[cfg(feature = "dtype-struct")]
             AsStruct => {
                 map_as_slice!(coerce::as_struct)
@@ -995,6 +1117,12 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             CumMin { reverse } => map!(cum::cum_min, reverse),
             #[cfg(feature = "cum_agg")]
             CumMax { reverse } => map!(cum::cum_max, reverse),
+            #[cfg(feature = "cum_agg")]
+            CumMean { reverse } => map!(cum::cum_mean, reverse),
+            #[cfg(feature = "cum_agg")]
+            CumStd { reverse, ddof } => map!(cum::cum_std, reverse, ddof),
+            #[cfg(feature = "cum_agg")]
+            CumQuantile { quantile, reverse } => map!(cum::cum_quantile, quantile, reverse),
             #[cfg(feature = "dtype-struct")]
             ValueCounts {
                 sort,
@@ -1027,6 +1155,12 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             InterpolateBy => {
                 map_as_slice!(dispatch::interpolate_by)
             },
+            #[cfg(feature = "is_close")]
+            IsClose {
+                abs_tol,
+                rel_tol,
+                nans_equal,
+            } => map_as_slice!(dispatch::is_close, abs_tol, rel_tol, nans_equal),
             #[cfg(feature = "log")]
             Entropy { base, normalize } => map!(log::entropy, base, normalize),
             #[cfg(feature = "log")]
@@ -1055,6 +1189,11 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             PeakMin => map!(peaks::peak_min),
             #[cfg(feature = "peaks")]
             PeakMax => map!(peaks::peak_max),
+            #[cfg(feature = "peaks")]
+            FindPeaks {
+                distance,
+                prominence,
+            } => map!(peaks::find_peaks, distance, prominence),
             #[cfg(feature = "repeat_by")]
             RepeatBy => map_as_slice!(dispatch::repeat_by),
             Reshape(dims, nested) => map!(dispatch::reshape, &dims, &nested),
@@ -1078,14 +1217,13 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                 left_closed,
                 allow_duplicates,
                 include_breaks,
-            } => map!(
-                qcut,
-                probs.clone(),
-                labels.clone(),
+            } => SpecialEq::new(Arc::new(cut::QCutFunction {
+                probs,
+                labels,
                 left_closed,
                 allow_duplicates,
-                include_breaks
-            ),
+                include_breaks,
+            })),
             #[cfg(feature = "rle")]
             RLE => map!(rle),
             #[cfg(feature = "rle")]
@@ -1133,6 +1271,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             EwmMean { options } => map!(ewm::ewm_mean, options),
             #[cfg(feature = "ewma_by")]
             EwmMeanBy { half_life } => map_as_slice!(ewm_by::ewm_mean_by, half_life),
+            #[cfg(feature = "ewma_by")]
+            EwmStdBy { half_life, bias } => map_as_slice!(ewm_by::ewm_std_by, half_life, bias),
+            #[cfg(feature = "ewma_by")]
+            EwmVarBy { half_life, bias } => map_as_slice!(ewm_by::ewm_var_by, half_life, bias),
             #[cfg(feature = "ewma")]
             EwmStd { options } => map!(ewm::ewm_std, options),
             #[cfg(feature = "ewma")]
@@ -1141,6 +1283,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Replace { return_dtype } => {
                 map_as_slice!(dispatch::replace, return_dtype.clone())
             },
+            #[cfg(feature = "lookup")]
+            Lookup { table, on_missing } => map!(dispatch::lookup, &table, on_missing),
             FillNullWithStrategy(strategy) => map!(dispatch::fill_null_with_strategy, strategy),
             GatherEvery { n, offset } => map!(dispatch::gather_every, n, offset),
             #[cfg(feature = "reinterpret")]
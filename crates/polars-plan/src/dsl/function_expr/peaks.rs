@@ -1,5 +1,5 @@
 use polars_core::with_match_physical_numeric_polars_type;
-use polars_ops::chunked_array::peaks::{peak_max as pmax, peak_min as pmin};
+use polars_ops::chunked_array::peaks::{find_peaks as fpeaks, peak_max as pmax, peak_min as pmin};
 
 use super::*;
 
@@ -34,3 +34,26 @@ pub(super) fn peak_max(s: &Series) -> PolarsResult<Series> {
     };
     Ok(s)
 }
+
+pub(super) fn find_peaks(
+    s: &Series,
+    distance: Option<usize>,
+    prominence: Option<f64>,
+) -> PolarsResult<Series> {
+    let s = s.to_physical_repr();
+    let s = match s.dtype() {
+        DataType::Boolean => polars_bail!(opq = find_peaks, DataType::Boolean),
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(_, _) => {
+            let s = s.cast(&DataType::Float64)?;
+            fpeaks(s.f64()?, distance, prominence)?.into_series()
+        },
+        dt => {
+            with_match_physical_numeric_polars_type!(dt, |$T| {
+                let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                fpeaks(ca, distance, prominence)?.into_series()
+            })
+        },
+    };
+    Ok(s)
+}
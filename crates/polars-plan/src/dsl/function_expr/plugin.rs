@@ -111,6 +111,47 @@ pub(super) unsafe fn call_plugin(
             check_panic(msg.as_ref())?;
             polars_bail!(ComputeError: "the plugin failed with message: {}", msg)
         }
+    } else if major == 1 {
+        use polars_ffi::version_1::*;
+        // *const LazyColumnsExport: the inputs, of which the plugin exports the columns it uses
+        // *const u8: pointer to &[u8]
+        // usize: length of the u8 slice
+        // *mut SeriesExport: pointer where return value should be written.
+        // *const CallerContext
+        // *mut ErrorExport: pointer where the error should be written if the plugin fails.
+        let symbol: libloading::Symbol<
+            unsafe extern "C" fn(
+                *const LazyColumnsExport,
+                *const u8,
+                usize,
+                *mut SeriesExport,
+                *const CallerContext,
+                *mut ErrorExport,
+            ),
+        > = lib
+            .get(format!("_polars_plugin_{}", symbol).as_bytes())
+            .unwrap();
+
+        // The columns are released when `input` is dropped.
+        let input = export_lazy_columns(s);
+
+        let mut return_value = SeriesExport::empty();
+        let mut error = ErrorExport::empty();
+        let context = CallerContext::default();
+        symbol(
+            &input,
+            kwargs.as_ptr(),
+            kwargs.len(),
+            &mut return_value,
+            &context,
+            &mut error,
+        );
+
+        if !return_value.is_null() {
+            import_series(return_value)
+        } else {
+            Err(import_plugin_error(error))
+        }
     } else {
         polars_bail!(ComputeError: "this polars engine doesn't support plugin version: {}", major)
     }
@@ -192,11 +233,59 @@ pub(super) unsafe fn plugin_field(
             check_panic(msg.as_ref())?;
             polars_bail!(ComputeError: "the plugin failed with message: {}", msg)
         }
+    } else if major == 1 {
+        use polars_ffi::version_1::*;
+        // *const SchemaExport: the fields of the inputs
+        // *const u8: pointer to &[u8] (kwargs)
+        // usize: length of the u8 slice
+        // *mut ArrowSchema: pointer where the return value can be written
+        // *mut ErrorExport: pointer where the error should be written if the plugin fails.
+        let symbol: libloading::Symbol<
+            unsafe extern "C" fn(
+                *const SchemaExport,
+                *const u8,
+                usize,
+                *mut ArrowSchema,
+                *mut ErrorExport,
+            ),
+        > = lib
+            .get((format!("_polars_plugin_field_{}", symbol)).as_bytes())
+            .unwrap();
+
+        let schema = export_schema(fields);
+        let mut error = ErrorExport::empty();
+        symbol(
+            &schema,
+            kwargs.as_ptr(),
+            kwargs.len(),
+            return_value_ptr,
+            &mut error,
+        );
+
+        if !return_value.is_null() {
+            let arrow_field = import_field_from_c(&return_value)?;
+            Ok(Field::from(&arrow_field))
+        } else {
+            Err(import_plugin_error(error))
+        }
     } else {
         polars_bail!(ComputeError: "this Polars engine doesn't support plugin version: {}", major)
     }
 }
 
+/// # Safety
+/// `ErrorExport` must be valid or empty
+unsafe fn import_plugin_error(error: polars_ffi::version_1::ErrorExport) -> PolarsError {
+    if error.is_null() {
+        return polars_err!(ComputeError: "the plugin failed without an error");
+    }
+    let err = polars_ffi::version_1::import_error(error);
+    match &err {
+        PolarsError::ComputeError(msg) => check_panic(msg).err().unwrap_or(err),
+        _ => err,
+    }
+}
+
 fn check_panic(msg: &str) -> PolarsResult<()> {
     polars_ensure!(msg != "PANIC", ComputeError: "the plugin panicked\n\nThe message is suppressed. Set POLARS_VERBOSE=1 to send the panic message to stderr.");
     Ok(())
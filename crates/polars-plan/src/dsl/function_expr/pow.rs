@@ -141,6 +141,16 @@ fn pow_on_series(base: &Series, exponent: &Series) -> PolarsResult<Option<Series
         InvalidOperation: "`pow` operation not supported for dtype `{}` as exponent", exponent_dtype
     );
 
+    // `f16` is raised to a power in `f32`.
+    #[cfg(feature = "dtype-f16")]
+    if matches!(base_dtype, Float16) || matches!(exponent_dtype, Float16) {
+        let to_float32 = |s: &Series| match s.dtype() {
+            Float16 => s.cast(&Float32),
+            _ => Ok(s.clone()),
+        };
+        return pow_on_series(&to_float32(base)?, &to_float32(exponent)?);
+    }
+
     // if false, dtype is float
     if base_dtype.is_integer() {
         with_match_physical_integer_type!(base_dtype, |$native_type| {
@@ -10,6 +10,7 @@ pub enum RollingFunction {
     Mean(RollingOptionsFixedWindow),
     Sum(RollingOptionsFixedWindow),
     Quantile(RollingOptionsFixedWindow),
+    ApproxQuantile(RollingOptionsFixedWindow),
     Var(RollingOptionsFixedWindow),
     Std(RollingOptionsFixedWindow),
     #[cfg(feature = "moment")]
@@ -26,6 +27,7 @@ impl Display for RollingFunction {
             Mean(_) => "rolling_mean",
             Sum(_) => "rolling_sum",
             Quantile(_) => "rolling_quantile",
+            ApproxQuantile(_) => "rolling_approx_quantile",
             Var(_) => "rolling_var",
             Std(_) => "rolling_std",
             #[cfg(feature = "moment")]
@@ -75,6 +77,13 @@ pub(super) fn rolling_quantile(
     s.rolling_quantile(options)
 }
 
+pub(super) fn rolling_approx_quantile(
+    s: &Series,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Series> {
+    s.rolling_approx_quantile(options)
+}
+
 pub(super) fn rolling_var(s: &Series, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
     s.rolling_var(options)
 }
@@ -28,6 +28,8 @@ impl FunctionExpr {
             TemporalExpr(fun) => fun.get_field(mapper),
 
             // Other expressions
+            #[cfg(feature = "bitwise")]
+            Bitwise(func) => func.get_field(mapper),
             Boolean(func) => func.get_field(mapper),
             #[cfg(feature = "business")]
             Business(func) => match func {
@@ -63,7 +65,9 @@ impl FunctionExpr {
                 use RollingFunction::*;
                 match rolling_func {
                     Min(_) | Max(_) | Sum(_) => mapper.with_same_dtype(),
-                    Mean(_) | Quantile(_) | Var(_) | Std(_) => mapper.map_to_float_dtype(),
+                    Mean(_) | Quantile(_) | ApproxQuantile(_) | Var(_) | Std(_) => {
+                        mapper.map_to_float_dtype()
+                    },
                     #[cfg(feature = "moment")]
                     Skew(..) => mapper.map_to_float_dtype(),
                 }
@@ -89,10 +93,13 @@ impl FunctionExpr {
             Kurtosis(..) => mapper.with_dtype(DataType::Float64),
             ArgUnique => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "rank")]
-            Rank { options, .. } => mapper.with_dtype(match options.method {
-                RankMethod::Average => DataType::Float64,
-                _ => IDX_DTYPE,
-            }),
+            Rank { options, .. } | RankBy { options, .. } => {
+                mapper.with_dtype(match options.method {
+                    RankMethod::Average => DataType::Float64,
+                    _ if options.pct => DataType::Float64,
+                    _ => IDX_DTYPE,
+                })
+            },
             #[cfg(feature = "dtype-struct")]
             AsStruct => Ok(Field::new(
                 fields[0].name(),
@@ -132,6 +139,8 @@ impl FunctionExpr {
             CumMin { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "cum_agg")]
             CumMax { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "cum_agg")]
+            CumMean { .. } | CumStd { .. } | CumQuantile { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "hist")]
@@ -182,6 +191,8 @@ impl FunctionExpr {
             },
             #[cfg(feature = "interpolate_by")]
             InterpolateBy => mapper.map_numeric_to_float_dtype(),
+            #[cfg(feature = "is_close")]
+            IsClose { .. } => mapper.with_dtype(DataType::Boolean),
             ShrinkType => {
                 // we return the smallest type this can return
                 // this might not be correct once the actual data
@@ -193,8 +204,10 @@ impl FunctionExpr {
                 // bit size this will likely not lead to issues
                 mapper.map_dtype(|dt| {
                     if dt.is_numeric() {
-                        if dt.is_float() {
+                        if dt == &DataType::Float64 {
                             DataType::Float32
+                        } else if dt.is_float() {
+                            dt.clone()
                         } else if dt.is_unsigned_integer() {
                             DataType::Int8
                         } else {
@@ -220,6 +233,8 @@ impl FunctionExpr {
             PeakMin => mapper.with_same_dtype(),
             #[cfg(feature = "peaks")]
             PeakMax => mapper.with_same_dtype(),
+            #[cfg(feature = "peaks")]
+            FindPeaks { .. } => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "cutqcut")]
             Cut {
                 include_breaks: false,
@@ -308,13 +323,15 @@ impl FunctionExpr {
             #[cfg(feature = "ewma")]
             EwmMean { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "ewma_by")]
-            EwmMeanBy { .. } => mapper.map_to_float_dtype(),
+            EwmMeanBy { .. } | EwmStdBy { .. } | EwmVarBy { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "ewma")]
             EwmStd { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "ewma")]
             EwmVar { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "replace")]
             Replace { return_dtype } => mapper.replace_dtype(return_dtype.clone()),
+            #[cfg(feature = "lookup")]
+            Lookup { table, .. } => mapper.with_dtype(table.values().dtype().clone()),
             FillNullWithStrategy(_) => mapper.with_same_dtype(),
             GatherEvery { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "reinterpret")]
@@ -511,14 +528,16 @@ impl<'a> FieldsMapper<'a> {
     pub(super) fn pow_dtype(&self) -> PolarsResult<Field> {
         let base_dtype = self.fields[0].data_type();
         let exponent_dtype = self.fields[1].data_type();
-        if base_dtype.is_integer() {
-            if exponent_dtype.is_float() {
-                Ok(Field::new(self.fields[0].name(), exponent_dtype.clone()))
-            } else {
-                Ok(Field::new(self.fields[0].name(), base_dtype.clone()))
-            }
+        let dtype = if base_dtype.is_integer() && exponent_dtype.is_float() {
+            exponent_dtype
         } else {
-            Ok(Field::new(self.fields[0].name(), base_dtype.clone()))
+            base_dtype
+        };
+        match dtype {
+            // `f16` is raised to a power in `f32`.
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Ok(Field::new(self.fields[0].name(), DataType::Float32)),
+            dt => Ok(Field::new(self.fields[0].name(), dt.clone())),
         }
     }
 
@@ -3,7 +3,11 @@ use super::*;
 pub(super) fn shrink(s: Series) -> PolarsResult<Series> {
     if s.dtype().is_numeric() {
         if s.dtype().is_float() {
-            s.cast(&DataType::Float32)
+            if s.dtype() == &DataType::Float64 {
+                s.cast(&DataType::Float32)
+            } else {
+                Ok(s)
+            }
         } else if s.dtype().is_unsigned_integer() {
             let max = s.max_reduce()?.value().extract::<u64>().unwrap_or(0_u64);
             if max <= u8::MAX as u64 {
@@ -72,6 +72,14 @@ pub enum StringFunction {
     },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_checksum")]
+    Checksum(ChecksumAlgorithm),
+    #[cfg(feature = "string_normalize")]
+    Normalize {
+        form: UnicodeForm,
+    },
+    #[cfg(feature = "string_normalize")]
+    CaseFold,
     #[cfg(feature = "string_pad")]
     PadStart {
         length: usize,
@@ -105,7 +113,10 @@ pub enum StringFunction {
         inclusive: bool,
     },
     #[cfg(feature = "dtype-struct")]
-    SplitN(usize),
+    SplitN {
+        n: usize,
+        fill: SplitFill,
+    },
     #[cfg(feature = "temporal")]
     Strptime(DataType, StrptimeOptions),
     Split(bool),
@@ -154,6 +165,10 @@ impl StringFunction {
             Replace { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "string_reverse")]
             Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "string_checksum")]
+            Checksum(_) => mapper.with_dtype(DataType::Boolean),
+            #[cfg(feature = "string_normalize")]
+            Normalize { .. } | CaseFold => mapper.with_same_dtype(),
             #[cfg(feature = "temporal")]
             Strptime(dtype, _) => mapper.with_dtype(dtype.clone()),
             Split(_) => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
@@ -180,7 +195,7 @@ impl StringFunction {
                     .collect(),
             )),
             #[cfg(feature = "dtype-struct")]
-            SplitN(n) => mapper.with_dtype(DataType::Struct(
+            SplitN { n, .. } => mapper.with_dtype(DataType::Struct(
                 (0..*n)
                     .map(|i| Field::from_owned(format_smartstring!("field_{i}"), DataType::String))
                     .collect(),
@@ -230,6 +245,12 @@ impl Display for StringFunction {
             Replace { .. } => "replace",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_checksum")]
+            Checksum(_) => "checksum",
+            #[cfg(feature = "string_normalize")]
+            Normalize { .. } => "normalize",
+            #[cfg(feature = "string_normalize")]
+            CaseFold => "casefold",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -254,7 +275,7 @@ impl Display for StringFunction {
                 }
             },
             #[cfg(feature = "dtype-struct")]
-            SplitN(_) => "splitn",
+            SplitN { .. } => "splitn",
             #[cfg(feature = "temporal")]
             Strptime(_, _) => "strptime",
             Split(inclusive) => {
@@ -325,7 +346,7 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "dtype-struct")]
             SplitExact { n, inclusive } => map_as_slice!(strings::split_exact, n, inclusive),
             #[cfg(feature = "dtype-struct")]
-            SplitN(n) => map_as_slice!(strings::splitn, n),
+            SplitN { n, fill } => map_as_slice!(strings::splitn, n, fill),
             #[cfg(feature = "concat_str")]
             ConcatVertical {
                 delimiter,
@@ -340,6 +361,12 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Replace { n, literal } => map_as_slice!(strings::replace, literal, n),
             #[cfg(feature = "string_reverse")]
             Reverse => map!(strings::reverse),
+            #[cfg(feature = "string_checksum")]
+            Checksum(algorithm) => map!(strings::checksum, &algorithm),
+            #[cfg(feature = "string_normalize")]
+            Normalize { form } => map!(strings::normalize, form),
+            #[cfg(feature = "string_normalize")]
+            CaseFold => map!(strings::casefold),
             Uppercase => map!(uppercase),
             Lowercase => map!(lowercase),
             #[cfg(feature = "nightly")]
@@ -601,11 +628,11 @@ pub(super) fn split_exact(s: &[Series], n: usize, inclusive: bool) -> PolarsResu
 }
 
 #[cfg(feature = "dtype-struct")]
-pub(super) fn splitn(s: &[Series], n: usize) -> PolarsResult<Series> {
+pub(super) fn splitn(s: &[Series], n: usize, fill: SplitFill) -> PolarsResult<Series> {
     let ca = s[0].str()?;
     let by = s[1].str()?;
 
-    ca.splitn(by, n).map(|ca| ca.into_series())
+    ca.splitn_with_fill(by, n, fill).map(|ca| ca.into_series())
 }
 
 pub(super) fn split(s: &[Series], inclusive: bool) -> PolarsResult<Series> {
@@ -621,6 +648,10 @@ pub(super) fn split(s: &[Series], inclusive: bool) -> PolarsResult<Series> {
 
 #[cfg(feature = "dtype-date")]
 fn to_date(s: &Series, options: &StrptimeOptions) -> PolarsResult<Series> {
+    polars_ensure!(
+        options.formats.is_none(),
+        InvalidOperation: "multiple formats are only supported for Datetime"
+    );
     let ca = s.str()?;
     let out = {
         if options.exact {
@@ -656,6 +687,9 @@ fn to_datetime(
     if let Some(time_zone) = time_zone {
         validate_time_zone(time_zone)?;
     }
+    if let Some(formats) = &options.formats {
+        return to_datetime_multiple_formats(s, formats, time_unit, time_zone, options);
+    }
     let out = if options.exact {
         datetime_strings
             .as_datetime(
@@ -685,8 +719,57 @@ fn to_datetime(
     Ok(out.into_series())
 }
 
+#[cfg(feature = "dtype-datetime")]
+fn to_datetime_multiple_formats(
+    s: &[Series],
+    formats: &[String],
+    time_unit: &TimeUnit,
+    time_zone: Option<&TimeZone>,
+    options: &StrptimeOptions,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        options.format.is_none(), InvalidOperation: "cannot set both a format and multiple formats"
+    );
+    polars_ensure!(
+        options.exact, InvalidOperation: "non-exact parsing is not supported for multiple formats"
+    );
+    let datetime_strings = &s[0].str()?;
+    let ambiguous = &s[1].str()?;
+    #[cfg(feature = "timezones")]
+    let tz_aware = {
+        let n_tz_aware = formats.iter().filter(|f| TZ_AWARE_RE.is_match(f)).count();
+        polars_ensure!(
+            n_tz_aware == 0 || n_tz_aware == formats.len(),
+            InvalidOperation: "either all or none of the formats must have a time zone directive"
+        );
+        n_tz_aware > 0
+    };
+    #[cfg(not(feature = "timezones"))]
+    let tz_aware = false;
+
+    let out = datetime_strings
+        .as_datetime_multiple_formats(
+            formats,
+            *time_unit,
+            options.cache,
+            tz_aware,
+            time_zone,
+            ambiguous,
+        )?
+        .into_series();
+
+    if options.strict && datetime_strings.null_count() != out.null_count() {
+        handle_casting_failures(&s[0], &out)?;
+    }
+    Ok(out)
+}
+
 #[cfg(feature = "dtype-time")]
 fn to_time(s: &Series, options: &StrptimeOptions) -> PolarsResult<Series> {
+    polars_ensure!(
+        options.formats.is_none(),
+        InvalidOperation: "multiple formats are only supported for Datetime"
+    );
     polars_ensure!(
         options.exact, ComputeError: "non-exact not implemented for Time data type"
     );
@@ -891,6 +974,24 @@ pub(super) fn reverse(s: &Series) -> PolarsResult<Series> {
     Ok(ca.str_reverse().into_series())
 }
 
+#[cfg(feature = "string_normalize")]
+pub(super) fn normalize(s: &Series, form: UnicodeForm) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(ca.str_normalize(form).into_series())
+}
+
+#[cfg(feature = "string_normalize")]
+pub(super) fn casefold(s: &Series) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    Ok(ca.str_casefold().into_series())
+}
+
+#[cfg(feature = "string_checksum")]
+pub(super) fn checksum(s: &Series, algorithm: &ChecksumAlgorithm) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    ca.checksum(algorithm).map(|ca| ca.into_series())
+}
+
 #[cfg(feature = "string_to_integer")]
 pub(super) fn to_integer(s: &[Series], strict: bool) -> PolarsResult<Series> {
     let ca = s[0].str()?;
@@ -15,6 +15,7 @@ pub enum StructFunction {
     JsonEncode,
     WithFields,
     MultipleFields(Arc<[ColumnName]>),
+    ExcludeFields(Arc<[ColumnName]>),
 }
 
 impl StructFunction {
@@ -119,7 +120,7 @@ impl StructFunction {
                     polars_bail!(op = "with_fields", got = dt, expected = "Struct")
                 }
             },
-            MultipleFields(_) => panic!("should be expanded"),
+            MultipleFields(_) | ExcludeFields(_) => panic!("should be expanded"),
         }
     }
 }
@@ -137,6 +138,7 @@ impl Display for StructFunction {
             JsonEncode => write!(f, "struct.to_json"),
             WithFields => write!(f, "with_fields"),
             MultipleFields(_) => write!(f, "multiple_fields"),
+            ExcludeFields(_) => write!(f, "exclude_fields"),
         }
     }
 }
@@ -153,7 +155,7 @@ impl From<StructFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "json")]
             JsonEncode => map!(to_json),
             WithFields => map_as_slice!(with_fields),
-            MultipleFields(_) => unimplemented!(),
+            MultipleFields(_) | ExcludeFields(_) => unimplemented!(),
         }
     }
 }
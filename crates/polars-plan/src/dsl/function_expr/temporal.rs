@@ -10,6 +10,8 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Year => map!(datetime::year),
             IsLeapYear => map!(datetime::is_leap_year),
             IsoYear => map!(datetime::iso_year),
+            #[cfg(feature = "dtype-struct")]
+            IsoYearWeekDay => map!(datetime::iso_year_week_day),
             Month => map!(datetime::month),
             Quarter => map!(datetime::quarter),
             Week => map!(datetime::week),
@@ -33,6 +35,7 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             TotalMilliseconds => map!(datetime::total_milliseconds),
             TotalMicroseconds => map!(datetime::total_microseconds),
             TotalNanoseconds => map!(datetime::total_nanoseconds),
+            ToIso8601Duration => map!(datetime::to_iso8601_duration),
             ToString(format) => map!(datetime::to_string, &format),
             TimeStamp(tu) => map!(datetime::timestamp, tu),
             #[cfg(feature = "timezones")]
@@ -66,6 +69,11 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             } => {
                 map_as_slice!(temporal::datetime, &time_unit, time_zone.as_deref())
             },
+            #[cfg(feature = "dtype-date")]
+            DateFromIsoWeek => map_as_slice!(temporal::date_from_isoweek),
+            DurationFromString { time_unit, strict } => {
+                map!(datetime::duration_from_string, time_unit, strict)
+            },
         }
     }
 }
@@ -182,6 +190,26 @@ pub(super) fn datetime(
     Ok(s)
 }
 
+#[cfg(feature = "dtype-date")]
+pub(super) fn date_from_isoweek(s: &[Series]) -> PolarsResult<Series> {
+    let max_len = s.iter().map(|s| s.len()).max().unwrap();
+    let broadcast = |s: &Series, dtype: &DataType| -> PolarsResult<Series> {
+        let s = s.cast(dtype)?;
+        Ok(if s.len() < max_len {
+            s.new_from_index(0, max_len)
+        } else {
+            s
+        })
+    };
+    let iso_year = broadcast(&s[0], &DataType::Int32)?;
+    let iso_week = broadcast(&s[1], &DataType::Int8)?;
+    let weekday = broadcast(&s[2], &DataType::Int8)?;
+
+    let ca =
+        DateChunked::from_iso_week_date("date", iso_year.i32()?, iso_week.i8()?, weekday.i8()?);
+    Ok(ca.into_series())
+}
+
 pub(super) fn combine(s: &[Series], tu: TimeUnit) -> PolarsResult<Series> {
     let date = &s[0];
     let time = &s[1];
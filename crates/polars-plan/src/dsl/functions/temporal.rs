@@ -150,6 +150,25 @@ pub fn datetime(args: DatetimeArgs) -> Expr {
     }
 }
 
+/// Construct a column of `Date` from ISO week dates.
+///
+/// Weeks range from 1 to 53 and weekdays from 1 (Monday) to 7 (Sunday). Week dates that
+/// don't exist result in null.
+#[cfg(feature = "dtype-date")]
+pub fn date_from_isoweek(iso_year: Expr, iso_week: Expr, weekday: Expr) -> Expr {
+    Expr::Function {
+        input: vec![iso_year, iso_week, weekday],
+        function: FunctionExpr::TemporalExpr(TemporalFunction::DateFromIsoWeek),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            allow_rename: true,
+            input_wildcard_expansion: true,
+            fmt_str: "date_from_isoweek",
+            ..Default::default()
+        },
+    }
+}
+
 /// Arguments used by `duration` in order to produce an [`Expr`] of [`Duration`]
 ///
 /// To construct a [`DurationArgs`], use struct literal syntax with `..Default::default()` to leave unspecified fields at
@@ -276,3 +295,14 @@ pub fn duration(args: DurationArgs) -> Expr {
         },
     }
 }
+
+/// Parse ISO 8601 durations like `P1DT2H30M` to a column of [`Duration`].
+///
+/// Years and months don't have a fixed length, so durations with them can't be parsed. If
+/// `strict` is set, values that can't be parsed raise an error instead of becoming null.
+#[cfg(feature = "temporal")]
+pub fn duration_from_string(s: Expr, time_unit: TimeUnit, strict: bool) -> Expr {
+    s.map_private(FunctionExpr::TemporalExpr(
+        TemporalFunction::DurationFromString { time_unit, strict },
+    ))
+}
@@ -339,6 +339,14 @@ impl ListNameSpace {
                 options
             })
     }
+
+    /// Pair every element of the lists with its position in its list, as a list of structs
+    /// with an `index` and a `value` field.
+    #[cfg(feature = "list_enumerate")]
+    pub fn enumerate(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ListExpr(ListFunction::Enumerate))
+    }
     #[cfg(feature = "list_count")]
     /// Count how often the value produced by ``element`` occurs.
     pub fn count_matches<E: Into<Expr>>(self, element: E) -> Expr {
@@ -399,4 +407,47 @@ impl ListNameSpace {
         let other = other.into();
         self.set_operation(other, SetOperation::SymmetricDifference)
     }
+
+    /// Zip this list with the lists in `others` elementwise into a list of structs.
+    ///
+    /// The struct fields are named after the inputs. `length` determines how rows whose lists
+    /// have different lengths are handled.
+    #[cfg(feature = "list_zip")]
+    pub fn zip_with<E: AsRef<[Expr]>>(self, others: E, length: ListZipLength) -> Expr {
+        self.0
+            .map_many_private(
+                FunctionExpr::ListExpr(ListFunction::ZipWith(length)),
+                others.as_ref(),
+                false,
+                false,
+            )
+            .with_function_options(|mut options| {
+                options.input_wildcard_expansion = true;
+                options
+            })
+    }
+
+    /// Compute the rolling sum over windows of `window_size` values within each list.
+    ///
+    /// Windows with fewer than `min_periods` non-null values are null.
+    #[cfg(feature = "list_rolling")]
+    pub fn rolling_sum(self, window_size: usize, min_periods: usize) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ListExpr(ListFunction::RollingSum {
+                window_size,
+                min_periods,
+            }))
+    }
+
+    /// Compute the rolling mean over windows of `window_size` values within each list.
+    ///
+    /// Windows with fewer than `min_periods` non-null values are null.
+    #[cfg(feature = "list_rolling")]
+    pub fn rolling_mean(self, window_size: usize, min_periods: usize) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ListExpr(ListFunction::RollingMean {
+                window_size,
+                min_periods,
+            }))
+    }
 }
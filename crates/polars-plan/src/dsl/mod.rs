@@ -32,6 +32,8 @@ mod options;
 pub mod python_udf;
 #[cfg(feature = "random")]
 mod random;
+#[cfg(feature = "cum_agg")]
+mod scan;
 mod selector;
 mod statistics;
 #[cfg(feature = "strings")]
@@ -63,7 +65,9 @@ use polars_core::prelude::*;
 use polars_core::series::ops::NullBehavior;
 use polars_core::series::IsSorted;
 use polars_core::utils::try_get_supertype;
-pub(crate) use selector::Selector;
+#[cfg(feature = "cum_agg")]
+pub use scan::*;
+pub use selector::Selector;
 #[cfg(feature = "dtype-struct")]
 pub use struct_::*;
 pub use udf::UserDefinedFunction;
@@ -72,6 +76,17 @@ use crate::constants::MAP_LIST_NAME;
 pub use crate::logical_plan::lit;
 use crate::prelude::*;
 
+fn window_order_by<IE: Into<Expr> + Clone>(e: &[IE]) -> Arc<Expr> {
+    if e.len() == 1 {
+        Arc::new(e[0].clone().into())
+    } else {
+        feature_gated!["dtype-struct", {
+            let e = e.iter().map(|e| e.clone().into()).collect::<Vec<_>>();
+            Arc::new(as_struct(e))
+        }]
+    }
+}
+
 impl Expr {
     /// Modify the Options passed to the `Function` node.
     pub(crate) fn with_function_options<F>(self, func: F) -> Expr
@@ -204,11 +219,21 @@ impl Expr {
         AggExpr::First(Arc::new(self)).into()
     }
 
+    /// Get the first non-null value in the group.
+    pub fn first_non_null(self) -> Self {
+        AggExpr::FirstNonNull(Arc::new(self)).into()
+    }
+
     /// Get the last value in the group.
     pub fn last(self) -> Self {
         AggExpr::Last(Arc::new(self)).into()
     }
 
+    /// Get the last non-null value in the group.
+    pub fn last_non_null(self) -> Self {
+        AggExpr::LastNonNull(Arc::new(self)).into()
+    }
+
     /// GroupBy the group to a Series.
     pub fn implode(self) -> Self {
         AggExpr::Implode(Arc::new(self)).into()
@@ -224,6 +249,29 @@ impl Expr {
         .into()
     }
 
+    /// Estimate the (lower) quantile per group with a quantile sketch.
+    ///
+    /// Unlike [`Expr::quantile`] this doesn't need to buffer the values of a group, which makes
+    /// it usable in the streaming engine. The estimate is within `relative_accuracy` of the
+    /// exact quantile, i.e. `|estimate - exact| <= relative_accuracy * |exact|`.
+    pub fn approx_quantile(self, quantile: Expr, relative_accuracy: f64) -> Self {
+        AggExpr::ApproxQuantile {
+            expr: Arc::new(self),
+            quantile: Arc::new(quantile),
+            options: ApproxQuantileOptions { relative_accuracy },
+        }
+        .into()
+    }
+
+    /// Aggregate to the most occurring value(s) per group, where `keep` decides how ties are
+    /// resolved.
+    ///
+    /// Unlike [`Expr::mode`], this is an aggregation with one row per group, which is either a
+    /// list of all modes or a single mode, and it runs in the streaming engine.
+    pub fn agg_mode(self, keep: ModeKeep) -> Self {
+        AggExpr::Mode(Arc::new(self), keep).into()
+    }
+
     /// Get the group indexes of the group by operation.
     pub fn agg_groups(self) -> Self {
         AggExpr::AggGroups(Arc::new(self)).into()
@@ -652,6 +700,32 @@ impl Expr {
         }
     }
 
+    /// Apply a function/closure once for all groups with many arguments. This should only be
+    /// used in a group_by aggregation.
+    ///
+    /// Contrary to [`Expr::apply_many`], the groups are not materialized. The function gets the
+    /// flat input columns and the [`GroupsProxy`] that indexes into them and must return one
+    /// value per group. Outside of a group_by context, all rows form a single group.
+    pub fn apply_groups<F>(self, function: F, arguments: &[Expr], output_type: GetOutput) -> Self
+    where
+        F: Fn(&mut [Series], &GroupsProxy) -> PolarsResult<Series> + 'static + Send + Sync,
+    {
+        let mut input = vec![self];
+        input.extend_from_slice(arguments);
+
+        Expr::AnonymousFunction {
+            input,
+            function: SpecialEq::new(Arc::new(GroupsFunction(function))),
+            output_type,
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::GroupWise,
+                returns_scalar: true,
+                fmt_str: "apply_groups",
+                ..Default::default()
+            },
+        }
+    }
+
     /// Apply a function/closure over the groups with many arguments. This should only be used in a group_by aggregation.
     ///
     /// See the [`Expr::apply`] function for the differences between [`map`](Expr::map) and [`apply`](Expr::apply).
@@ -787,6 +861,24 @@ impl Expr {
         self.apply_private(FunctionExpr::CumMax { reverse })
     }
 
+    /// Get an array with the cumulative mean computed at every element.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_mean(self, reverse: bool) -> Self {
+        self.apply_private(FunctionExpr::CumMean { reverse })
+    }
+
+    /// Get an array with the cumulative standard deviation computed at every element.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_std(self, reverse: bool, ddof: u8) -> Self {
+        self.apply_private(FunctionExpr::CumStd { reverse, ddof })
+    }
+
+    /// Get an array with an approximation of the cumulative quantile computed at every element.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_quantile(self, quantile: f64, reverse: bool) -> Self {
+        self.apply_private(FunctionExpr::CumQuantile { quantile, reverse })
+    }
+
     /// Get the product aggregation of an expression.
     pub fn product(self) -> Self {
         let options = FunctionOptions {
@@ -965,18 +1057,7 @@ impl Expr {
             .map(|e| e.clone().into())
             .collect();
 
-        let order_by = order_by.map(|(e, options)| {
-            let e = e.as_ref();
-            let e = if e.len() == 1 {
-                Arc::new(e[0].clone().into())
-            } else {
-                feature_gated!["dtype-struct", {
-                    let e = e.iter().map(|e| e.clone().into()).collect::<Vec<_>>();
-                    Arc::new(as_struct(e))
-                }]
-            };
-            (e, options)
-        });
+        let order_by = order_by.map(|(e, options)| (window_order_by(e.as_ref()), options));
 
         Expr::Window {
             function: Arc::new(self),
@@ -986,6 +1067,32 @@ impl Expr {
         }
     }
 
+    /// Evaluate `self` for every row over the rows of the `frame` around it, where the rows
+    /// of a partition are ordered by `order_by`. This is SQL's
+    /// `OVER (PARTITION BY .. ORDER BY .. ROWS BETWEEN ..)`.
+    ///
+    /// An empty `partition_by` puts all rows in the same partition.
+    pub fn over_frame<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        partition_by: E,
+        order_by: (E, SortOptions),
+        frame: WindowFrame,
+    ) -> Self {
+        let partition_by = partition_by
+            .as_ref()
+            .iter()
+            .map(|e| e.clone().into())
+            .collect();
+        let (order_by, options) = order_by;
+
+        Expr::Window {
+            function: Arc::new(self),
+            partition_by,
+            order_by: Some((window_order_by(order_by.as_ref()), options)),
+            options: WindowType::Frame(frame),
+        }
+    }
+
     #[cfg(feature = "dynamic_group_by")]
     pub fn rolling(self, options: RollingGroupOptions) -> Self {
         // We add the index column as `partition expr` so that the optimizer will
@@ -1060,6 +1167,29 @@ impl Expr {
         )
     }
 
+    /// Check if the values are close to the values of `other`: their difference is at most
+    /// `abs_tol` or at most `rel_tol` times the larger of their absolute values.
+    #[allow(clippy::wrong_self_convention)]
+    #[cfg(feature = "is_close")]
+    pub fn is_close<E: Into<Expr>>(
+        self,
+        other: E,
+        abs_tol: f64,
+        rel_tol: f64,
+        nans_equal: bool,
+    ) -> Self {
+        self.map_many_private(
+            FunctionExpr::IsClose {
+                abs_tol,
+                rel_tol,
+                nans_equal,
+            },
+            &[other.into()],
+            false,
+            false,
+        )
+    }
+
     /// Get a mask of unique values.
     #[allow(clippy::wrong_self_convention)]
     #[cfg(feature = "is_unique")]
@@ -1077,6 +1207,45 @@ impl Expr {
             })
     }
 
+    /// Flip all bits of an integer expression.
+    #[cfg(feature = "bitwise")]
+    pub fn bitwise_not(self) -> Self {
+        self.map_private(BitwiseFunction::Not.into())
+    }
+
+    /// Shift the bits of an integer expression `n` places to the left.
+    #[cfg(feature = "bitwise")]
+    pub fn bitwise_shift_left(self, n: u32) -> Self {
+        self.map_private(BitwiseFunction::ShiftLeft(n).into())
+    }
+
+    /// Shift the bits of an integer expression `n` places to the right, keeping the sign of
+    /// signed integers.
+    #[cfg(feature = "bitwise")]
+    pub fn bitwise_shift_right(self, n: u32) -> Self {
+        self.map_private(BitwiseFunction::ShiftRight(n).into())
+    }
+
+    /// Aggregate an integer expression to the bitwise and of its non-null values.
+    #[cfg(feature = "bitwise")]
+    pub fn bitwise_and_agg(self) -> Self {
+        self.apply_private(BitwiseFunction::AndAgg.into())
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
+    /// Aggregate an integer expression to the bitwise or of its non-null values.
+    #[cfg(feature = "bitwise")]
+    pub fn bitwise_or_agg(self) -> Self {
+        self.apply_private(BitwiseFunction::OrAgg.into())
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
     /// "and" operation.
     pub fn and<E: Into<Expr>>(self, expr: E) -> Self {
         binary_expr(self, Operator::And, expr.into())
@@ -1401,6 +1570,30 @@ impl Expr {
         self.finish_rolling(options, RollingFunction::Quantile)
     }
 
+    /// Apply an approximate rolling quantile.
+    ///
+    /// Contrary to [`Expr::rolling_quantile`], the cost per value doesn't grow with the window
+    /// size, which makes this suited for very large windows. Every estimate is within
+    /// `relative_accuracy` of the exact (lower) quantile of the window, i.e.
+    /// `|estimate - exact| <= relative_accuracy * |exact|`. Smaller values for
+    /// `relative_accuracy` give more accurate results at the cost of more memory.
+    ///
+    /// Weights are not supported.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_approx_quantile(
+        self,
+        quantile: f64,
+        relative_accuracy: f64,
+        mut options: RollingOptionsFixedWindow,
+    ) -> Expr {
+        options.fn_params = Some(Arc::new(RollingApproxQuantileParams {
+            prob: quantile,
+            relative_accuracy,
+        }) as Arc<dyn Any + Send + Sync>);
+
+        self.finish_rolling(options, RollingFunction::ApproxQuantile)
+    }
+
     /// Apply a rolling variance.
     #[cfg(feature = "rolling_window")]
     pub fn rolling_var(self, options: RollingOptionsFixedWindow) -> Expr {
@@ -1487,12 +1680,41 @@ impl Expr {
         self.apply_private(FunctionExpr::PeakMax)
     }
 
+    #[cfg(feature = "peaks")]
+    /// Get a boolean mask of the peaks, optionally only of those that are at least `distance`
+    /// values apart and stand out at least `prominence` above their surroundings.
+    pub fn find_peaks(self, distance: Option<usize>, prominence: Option<f64>) -> Expr {
+        self.apply_private(FunctionExpr::FindPeaks {
+            distance,
+            prominence,
+        })
+    }
+
     #[cfg(feature = "rank")]
     /// Assign ranks to data, dealing with ties appropriately.
     pub fn rank(self, options: RankOptions, seed: Option<u64>) -> Expr {
         self.apply_private(FunctionExpr::Rank { options, seed })
     }
 
+    #[cfg(feature = "rank")]
+    /// Assign ranks within the partitions of rows that have the same values in `by`.
+    ///
+    /// This gives the same result as `rank(..).over(by)`, but ranks all partitions with a single
+    /// sort.
+    pub fn rank_by<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        by: E,
+        options: RankOptions,
+        seed: Option<u64>,
+    ) -> Expr {
+        let by = by
+            .as_ref()
+            .iter()
+            .map(|e| e.clone().into())
+            .collect::<Vec<_>>();
+        self.apply_many_private(FunctionExpr::RankBy { options, seed }, &by, false, false)
+    }
+
     #[cfg(feature = "replace")]
     /// Replace the given values with other values.
     pub fn replace<E: Into<Expr>>(
@@ -1519,6 +1741,15 @@ impl Expr {
         }
     }
 
+    #[cfg(feature = "lookup")]
+    /// Replace every value by the value of its key in `table`.
+    ///
+    /// The hash table of `table` is built once and shared by every evaluation of the
+    /// expression, which makes this a cheap alternative to a join with a small mapping frame.
+    pub fn lookup(self, table: LookupTable, on_missing: LookupMissing) -> Expr {
+        self.map_private(FunctionExpr::Lookup { table, on_missing })
+    }
+
     #[cfg(feature = "cutqcut")]
     /// Bin continuous values into discrete categories.
     pub fn cut(
@@ -1542,6 +1773,8 @@ impl Expr {
 
     #[cfg(feature = "cutqcut")]
     /// Bin continuous values into discrete categories based on their quantiles.
+    ///
+    /// In a group-by context, the quantiles are computed per group.
     pub fn qcut(
         self,
         probs: Vec<f64>,
@@ -1677,6 +1910,28 @@ impl Expr {
         )
     }
 
+    #[cfg(feature = "ewma_by")]
+    /// Calculate the exponentially-weighted moving standard deviation by a time column.
+    pub fn ewm_std_by(self, times: Expr, half_life: Duration, bias: bool) -> Self {
+        self.apply_many_private(
+            FunctionExpr::EwmStdBy { half_life, bias },
+            &[times],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "ewma_by")]
+    /// Calculate the exponentially-weighted moving variance by a time column.
+    pub fn ewm_var_by(self, times: Expr, half_life: Duration, bias: bool) -> Self {
+        self.apply_many_private(
+            FunctionExpr::EwmVarBy { half_life, bias },
+            &[times],
+            false,
+            false,
+        )
+    }
+
     #[cfg(feature = "ewma")]
     /// Calculate the exponentially-weighted moving standard deviation.
     pub fn ewm_std(self, options: EWMOptions) -> Self {
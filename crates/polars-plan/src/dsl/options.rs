@@ -18,6 +18,10 @@ pub struct RollingCovOptions {
 pub struct StrptimeOptions {
     /// Formatting string
     pub format: Option<String>,
+    /// Formatting strings that are tried for every value, the first that matches is used.
+    /// Only supported for `Datetime` and exclusive with `format`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub formats: Option<Vec<String>>,
     /// If set then polars will return an error if any date parsing fails
     pub strict: bool,
     /// If polars may parse matches that not contain the whole string
@@ -31,6 +35,7 @@ impl Default for StrptimeOptions {
     fn default() -> Self {
         StrptimeOptions {
             format: None,
+            formats: None,
             strict: true,
             exact: true,
             cache: true,
@@ -70,6 +75,9 @@ pub enum WindowType {
     Over(WindowMapping),
     #[cfg(feature = "dynamic_group_by")]
     Rolling(RollingGroupOptions),
+    /// Evaluate the function for every row over a frame of rows around it, in the order of the
+    /// window.
+    Frame(WindowFrame),
 }
 
 impl From<WindowMapping> for WindowType {
@@ -98,6 +106,19 @@ pub enum WindowMapping {
     Join,
 }
 
+/// The rows of a window frame, relative to the current row.
+///
+/// The frame always contains the current row. An unset bound is unbounded, like
+/// `UNBOUNDED PRECEDING` or `UNBOUNDED FOLLOWING` in SQL.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WindowFrame {
+    /// The number of rows before the current row.
+    pub preceding: Option<usize>,
+    /// The number of rows after the current row.
+    pub following: Option<usize>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NestedType {
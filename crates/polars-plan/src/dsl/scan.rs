@@ -0,0 +1,160 @@
+use super::*;
+
+/// State of a cumulative scan over a column.
+///
+/// The state only consists of the current accumulator, so a column can be fed chunk by chunk
+/// and produces the same result as when it is fed at once. This allows a scan to be executed
+/// incrementally instead of recomputing over a growing window like `cumulative_eval` does.
+pub struct ScanState<F> {
+    acc: AnyValue<'static>,
+    dtype: DataType,
+    f: F,
+}
+
+impl<F> ScanState<F>
+where
+    F: Fn(&AnyValue<'static>, AnyValue<'_>) -> PolarsResult<AnyValue<'static>>,
+{
+    /// Create a new state. The accumulator is cast to `dtype`, which is also the output dtype.
+    pub fn new(acc_init: AnyValue<'_>, dtype: DataType, f: F) -> PolarsResult<Self> {
+        let acc = acc_init.try_strict_cast(&dtype)?.into_static()?;
+        Ok(Self { acc, dtype, f })
+    }
+
+    /// The current value of the accumulator.
+    pub fn accumulator(&self) -> &AnyValue<'static> {
+        &self.acc
+    }
+
+    /// The dtype of the accumulator and of the produced output.
+    pub fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    /// Feed the next chunk of values and return the accumulator after every value.
+    ///
+    /// Null values don't update the accumulator and produce a null in the output.
+    pub fn update(&mut self, s: &Series) -> PolarsResult<Series> {
+        let s = s.rechunk();
+        let mut out = Vec::with_capacity(s.len());
+        for av in s.iter() {
+            if av.is_null() {
+                out.push(AnyValue::Null);
+                continue;
+            }
+            let acc = (self.f)(&self.acc, av)?;
+            self.acc = acc.try_strict_cast(&self.dtype)?.into_static()?;
+            out.push(self.acc.clone());
+        }
+        Series::from_any_values_and_dtype(s.name(), &out, &self.dtype, true)
+    }
+}
+
+fn scan_impl<F>(
+    input: Expr,
+    acc_init: Expr,
+    f: F,
+    to_supertype: bool,
+    fmt_str: &'static str,
+) -> Expr
+where
+    F: 'static
+        + Fn(&AnyValue<'static>, AnyValue<'_>) -> PolarsResult<AnyValue<'static>>
+        + Send
+        + Sync
+        + Clone,
+{
+    let function = SpecialEq::new(Arc::new(move |series: &mut [Series]| {
+        let s = &series[0];
+        let init = &series[1];
+        polars_ensure!(
+            init.len() == 1,
+            ComputeError: "the initial accumulator of `{}` must be a scalar, got length {}",
+            fmt_str, init.len()
+        );
+        let dtype = if to_supertype {
+            try_get_supertype(s.dtype(), init.dtype())?
+        } else {
+            init.dtype().clone()
+        };
+
+        let mut state = ScanState::new(init.get(0)?, dtype, f.clone())?;
+        let mut chunks = Vec::with_capacity(s.chunks().len());
+        for chunk in s.chunks() {
+            let chunk = unsafe {
+                Series::from_chunks_and_dtype_unchecked(s.name(), vec![chunk.clone()], s.dtype())
+            };
+            chunks.push(state.update(&chunk)?);
+        }
+
+        let mut iter = chunks.into_iter();
+        let mut out = match iter.next() {
+            Some(out) => out,
+            None => Series::new_empty(s.name(), state.dtype()),
+        };
+        for chunk in iter {
+            out.append(&chunk)?;
+        }
+        Ok(Some(out))
+    }) as Arc<dyn SeriesUdf>);
+
+    let output_type = GetOutput::map_fields(move |fields| {
+        let dtype = if to_supertype {
+            try_get_supertype(fields[0].data_type(), fields[1].data_type())
+                .unwrap_or_else(|_| fields[0].data_type().clone())
+        } else {
+            fields[1].data_type().clone()
+        };
+        Field::new(fields[0].name(), dtype)
+    });
+
+    Expr::AnonymousFunction {
+        input: vec![input, acc_init],
+        function,
+        output_type,
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            fmt_str,
+            ..Default::default()
+        },
+    }
+}
+
+impl Expr {
+    /// Cumulatively fold the values of this expression into a state, starting from `acc_init`.
+    ///
+    /// The output has the same length as the input and holds the state after every value.
+    /// The output dtype is the dtype of `acc_init`; the result of `f` is cast to it.
+    ///
+    /// Contrary to `cumulative_eval`, this runs in linear time and only carries the state
+    /// between values, see [`ScanState`].
+    ///
+    /// The streaming engine evaluates expressions on its batches independently and in any order,
+    /// so it can't carry the state from one batch to the next. A projection that scans is
+    /// therefore executed by the in-memory engine, also when streaming is enabled.
+    pub fn scan<F>(self, acc_init: Expr, f: F) -> Expr
+    where
+        F: 'static
+            + Fn(&AnyValue<'static>, AnyValue<'_>) -> PolarsResult<AnyValue<'static>>
+            + Send
+            + Sync
+            + Clone,
+    {
+        scan_impl(self, acc_init, f, false, "scan")
+    }
+
+    /// Cumulatively reduce the values of this expression with `f`, starting from `acc_init`.
+    ///
+    /// This is a [`scan`](Expr::scan) where the output dtype is the supertype of this
+    /// expression and `acc_init`.
+    pub fn cum_reduce<F>(self, acc_init: Expr, f: F) -> Expr
+    where
+        F: 'static
+            + Fn(&AnyValue<'static>, AnyValue<'_>) -> PolarsResult<AnyValue<'static>>
+            + Send
+            + Sync
+            + Clone,
+    {
+        scan_impl(self, acc_init, f, true, "cum_reduce")
+    }
+}
@@ -1,10 +1,22 @@
-use std::ops::{Add, BitAnd, BitXor, Sub};
+use std::ops::{Add, BitAnd, BitXor, Not, Sub};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use super::*;
 
+/// A set of columns that is resolved against the schema when the query is planned.
+///
+/// Selectors can be combined with set operations: `+` (union), `-` (difference),
+/// `&` (intersection), `^` (symmetric difference) and `!` (complement).
+///
+/// # Example
+///
+/// ```ignore
+/// // all numeric columns except the ones starting with "id"
+/// let s = Selector::by_dtype([DataType::Int64, DataType::Float64]) - Selector::matches("^id");
+/// df.lazy().select([s.into()])
+/// ```
 #[derive(Clone, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Selector {
@@ -16,10 +28,72 @@ pub enum Selector {
 }
 
 impl Selector {
-    #[cfg(feature = "meta")]
     pub(crate) fn new(e: Expr) -> Self {
         Self::Root(Box::new(e))
     }
+
+    /// Select all columns.
+    pub fn all() -> Self {
+        Self::new(Expr::Wildcard)
+    }
+
+    /// Select the columns with the given names.
+    pub fn by_name<I: IntoVec<String>>(names: I) -> Self {
+        Self::new(cols(names))
+    }
+
+    /// Select the columns that have one of the given dtypes.
+    pub fn by_dtype<DT: AsRef<[DataType]>>(dtypes: DT) -> Self {
+        Self::new(dtype_cols(dtypes))
+    }
+
+    /// Select the columns at the given indices. Negative indices count from the end.
+    pub fn by_index<N: AsRef<[i64]>>(indices: N) -> Self {
+        Self::new(index_cols(indices))
+    }
+
+    /// Select the columns of which the name matches the regex `pattern`.
+    ///
+    /// The pattern is not anchored; use `^` and `$` to match the full name.
+    #[cfg(feature = "regex")]
+    pub fn matches(pattern: &str) -> Self {
+        if pattern == ".*" {
+            return Self::all();
+        }
+        let pattern = pattern
+            .strip_prefix(".*")
+            .or_else(|| pattern.strip_suffix(".*"))
+            .unwrap_or(pattern);
+        let prefix = if pattern.starts_with('^') { "" } else { "^.*" };
+        let suffix = if pattern.ends_with('$') { "" } else { ".*$" };
+        Self::new(col(&format!("{prefix}{pattern}{suffix}")))
+    }
+
+    /// Select the columns that are in either selector.
+    pub fn union(self, other: Selector) -> Self {
+        self + other
+    }
+
+    /// Select the columns that are in `self` but not in `other`.
+    pub fn difference(self, other: Selector) -> Self {
+        self - other
+    }
+
+    /// Select the columns that are in both selectors.
+    pub fn intersection(self, other: Selector) -> Self {
+        self & other
+    }
+
+    /// Select the columns that are in exactly one of the selectors.
+    pub fn symmetric_difference(self, other: Selector) -> Self {
+        self ^ other
+    }
+}
+
+impl From<Selector> for Expr {
+    fn from(s: Selector) -> Self {
+        Expr::Selector(s)
+    }
 }
 
 impl Add for Selector {
@@ -56,3 +130,11 @@ impl Sub for Selector {
         Selector::Sub(Box::new(self), Box::new(rhs))
     }
 }
+
+impl Not for Selector {
+    type Output = Selector;
+
+    fn not(self) -> Self::Output {
+        Selector::all() - self
+    }
+}
@@ -47,6 +47,24 @@ impl Expr {
         .into()
     }
 
+    /// Reduce groups to minimal value, where `NaN` values compare as `nan_ordering` dictates.
+    pub fn min_with_nan_ordering(self, nan_ordering: NanOrdering) -> Self {
+        match nan_ordering {
+            NanOrdering::Largest => self.min(),
+            NanOrdering::Smallest => self.nan_min(),
+            NanOrdering::Null => self.fill_nan(lit(Null {})).min(),
+        }
+    }
+
+    /// Reduce groups to maximum value, where `NaN` values compare as `nan_ordering` dictates.
+    pub fn max_with_nan_ordering(self, nan_ordering: NanOrdering) -> Self {
+        match nan_ordering {
+            NanOrdering::Largest => self.nan_max(),
+            NanOrdering::Smallest => self.max(),
+            NanOrdering::Null => self.fill_nan(lit(Null {})).max(),
+        }
+    }
+
     /// Reduce groups to the mean value.
     pub fn mean(self) -> Self {
         AggExpr::Mean(Arc::new(self)).into()
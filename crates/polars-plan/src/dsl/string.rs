@@ -268,19 +268,36 @@ impl StringNameSpace {
         options: StrptimeOptions,
         ambiguous: Expr,
     ) -> Expr {
-        // If time_unit is None, try to infer it from the format or set a default
-        let time_unit = match (&options.format, time_unit) {
-            (_, Some(time_unit)) => time_unit,
-            (Some(format), None) => {
-                if format.contains("%.9f") || format.contains("%9f") {
+        fn infer_time_unit(format: &str) -> TimeUnit {
+            if format.contains("%.9f") || format.contains("%9f") {
+                TimeUnit::Nanoseconds
+            } else if format.contains("%.3f") || format.contains("%3f") {
+                TimeUnit::Milliseconds
+            } else {
+                TimeUnit::Microseconds
+            }
+        }
+
+        // If time_unit is None, try to infer it from the format(s) or set a default
+        let time_unit = match (&options.format, &options.formats, time_unit) {
+            (_, _, Some(time_unit)) => time_unit,
+            (Some(format), _, None) => infer_time_unit(format),
+            // The unit must fit the most precise of the formats.
+            (None, Some(formats), None) => {
+                let units = formats
+                    .iter()
+                    .map(|f| infer_time_unit(f))
+                    .collect::<Vec<_>>();
+                if units.contains(&TimeUnit::Nanoseconds) {
                     TimeUnit::Nanoseconds
-                } else if format.contains("%.3f") || format.contains("%3f") {
+                } else if !units.is_empty() && units.iter().all(|tu| *tu == TimeUnit::Milliseconds)
+                {
                     TimeUnit::Milliseconds
                 } else {
                     TimeUnit::Microseconds
                 }
             },
-            (None, None) => TimeUnit::Microseconds,
+            (None, None, None) => TimeUnit::Microseconds,
         };
 
         self.strptime(DataType::Datetime(time_unit, time_zone), options, ambiguous)
@@ -363,8 +380,19 @@ impl StringNameSpace {
     /// Split by a given substring, returning exactly `n` items. If there are more possible splits,
     /// keeps the remainder of the string intact. The resulting dtype is [`DataType::Struct`].
     pub fn splitn(self, by: Expr, n: usize) -> Expr {
-        self.0
-            .map_many_private(StringFunction::SplitN(n).into(), &[by], false, false)
+        self.splitn_with_fill(by, n, SplitFill::Null)
+    }
+
+    #[cfg(feature = "dtype-struct")]
+    /// Split by a given substring, returning exactly `n` items, like [`Self::splitn`]. Strings
+    /// with fewer than `n` parts are filled according to `fill`.
+    pub fn splitn_with_fill(self, by: Expr, n: usize, fill: SplitFill) -> Expr {
+        self.0.map_many_private(
+            StringFunction::SplitN { n, fill }.into(),
+            &[by],
+            false,
+            false,
+        )
     }
 
     #[cfg(feature = "regex")]
@@ -400,6 +428,29 @@ impl StringNameSpace {
         )
     }
 
+    #[cfg(feature = "string_checksum")]
+    /// Validate identifiers, such as credit card numbers or IBANs, with their check digits.
+    pub fn checksum(self, algorithm: ChecksumAlgorithm) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::Checksum(
+                algorithm,
+            )))
+    }
+
+    #[cfg(feature = "string_normalize")]
+    /// Normalize each string to the given Unicode normalization form.
+    pub fn normalize(self, form: UnicodeForm) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::Normalize { form }))
+    }
+
+    #[cfg(feature = "string_normalize")]
+    /// Apply full Unicode case folding to each string, for case-insensitive comparisons.
+    pub fn casefold(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::CaseFold))
+    }
+
     #[cfg(feature = "string_reverse")]
     /// Reverse each string
     pub fn reverse(self) -> Expr {
@@ -38,6 +38,23 @@ impl StructNameSpace {
             })
     }
 
+    /// Retrieve all fields of this [`StructChunked`] except the given ones as new Series.
+    /// The names may also be regexes, which exclude all fields they match.
+    pub fn exclude_fields<S: AsRef<str>>(self, names: &[S]) -> Expr {
+        let names = names
+            .iter()
+            .map(|name| ColumnName::from(name.as_ref()))
+            .collect();
+        self.0
+            .map_private(FunctionExpr::StructExpr(StructFunction::ExcludeFields(
+                names,
+            )))
+            .with_function_options(|mut options| {
+                options.allow_rename = true;
+                options
+            })
+    }
+
     /// Retrieve one of the fields of this [`StructChunked`] as a new Series.
     /// This expression also supports wildcard "*" and regex expansion.
     pub fn field_by_name(self, name: &str) -> Expr {
@@ -1,3 +1,9 @@
+#[cfg(not(target_family = "wasm"))]
+use polars_core::error::polars_err;
+use polars_core::error::{polars_ensure, PolarsResult};
+#[cfg(not(target_family = "wasm"))]
+use polars_core::POOL;
+
 #[derive(Copy, Clone, Debug)]
 /// State of the allowed optimizations
 pub struct OptState {
@@ -15,6 +21,8 @@ pub struct OptState {
     pub file_caching: bool,
     /// Pushdown slices/limits.
     pub slice_pushdown: bool,
+    /// Push semi and anti joins below group-bys and other joins that pass their keys through.
+    pub semi_anti_join_pushdown: bool,
     #[cfg(feature = "cse")]
     /// Run common-subplan-elimination. This elides duplicate plans and caches their
     /// outputs.
@@ -32,6 +40,8 @@ pub struct OptState {
     /// Try to estimate the number of rows so that joins can determine which side to keep in memory.
     pub row_estimate: bool,
     pub new_streaming: bool,
+    /// Limit the threads and memory that the query may use.
+    pub resources: QueryResources,
 }
 
 impl Default for OptState {
@@ -43,6 +53,7 @@ impl Default for OptState {
             type_coercion: true,
             simplify_expr: true,
             slice_pushdown: true,
+            semi_anti_join_pushdown: true,
             // will be toggled by a scan operation such as csv scan or parquet scan
             file_caching: false,
             #[cfg(feature = "cse")]
@@ -54,9 +65,48 @@ impl Default for OptState {
             eager: false,
             row_estimate: true,
             new_streaming: false,
+            resources: QueryResources::default(),
         }
     }
 }
 
 /// AllowedOptimizations
 pub type AllowedOptimizations = OptState;
+
+/// Resource limits of a single query, so that queries that run concurrently don't compete for
+/// all threads and memory of the process.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct QueryResources {
+    /// The maximum number of threads the query runs on. Defaults to the size of the global
+    /// thread pool, see `POLARS_MAX_THREADS`.
+    pub max_threads: Option<usize>,
+    /// The number of bytes the query tries to stay below. Operators of the streaming engines
+    /// that can spill to disk do so earlier to respect it, other operators ignore it.
+    pub memory_soft_limit: Option<usize>,
+}
+
+impl QueryResources {
+    /// Run `op` within these limits.
+    ///
+    /// If any limit is set, `op` runs on a thread pool that no other query runs on at the same
+    /// time, and all work that it schedules on the [`POOL`] runs on that pool instead.
+    pub fn install<OP, R>(&self, op: OP) -> PolarsResult<R>
+    where
+        OP: FnOnce() -> R + Send,
+        R: Send,
+    {
+        polars_ensure!(
+            self.max_threads != Some(0),
+            InvalidOperation: "`max_threads` must be at least 1"
+        );
+        #[cfg(not(target_family = "wasm"))]
+        if *self != Self::default() {
+            let num_threads = self
+                .max_threads
+                .unwrap_or_else(|| POOL.current_num_threads());
+            return polars_utils::pool::install_scoped(num_threads, self.memory_soft_limit, op)
+                .map_err(|e| polars_err!(ComputeError: "could not spawn threads: {}", e));
+        }
+        Ok(op())
+    }
+}
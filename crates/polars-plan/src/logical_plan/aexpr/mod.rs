@@ -31,7 +31,9 @@ pub enum IRAggExpr {
     Median(Node),
     NUnique(Node),
     First(Node),
+    FirstNonNull(Node),
     Last(Node),
+    LastNonNull(Node),
     Mean(Node),
     Implode(Node),
     Quantile {
@@ -39,11 +41,17 @@ pub enum IRAggExpr {
         quantile: Node,
         interpol: QuantileInterpolOptions,
     },
+    ApproxQuantile {
+        expr: Node,
+        quantile: Node,
+        options: ApproxQuantileOptions,
+    },
     Sum(Node),
     Count(Node, bool),
     Std(Node, u8),
     Var(Node, u8),
     AggGroups(Node),
+    Mode(Node, ModeKeep),
 }
 
 impl Hash for IRAggExpr {
@@ -54,7 +62,9 @@ impl Hash for IRAggExpr {
                 propagate_nans.hash(state)
             },
             Self::Quantile { interpol, .. } => interpol.hash(state),
+            Self::ApproxQuantile { options, .. } => options.hash(state),
             Self::Std(_, v) | Self::Var(_, v) => v.hash(state),
+            Self::Mode(_, keep) => keep.hash(state),
             _ => {},
         }
     }
@@ -81,8 +91,10 @@ impl IRAggExpr {
                 },
             ) => l == r,
             (Quantile { interpol: l, .. }, Quantile { interpol: r, .. }) => l == r,
+            (ApproxQuantile { options: l, .. }, ApproxQuantile { options: r, .. }) => l == r,
             (Std(_, l), Std(_, r)) => l == r,
             (Var(_, l), Var(_, r)) => l == r,
+            (Mode(_, l), Mode(_, r)) => l == r,
             _ => std::mem::discriminant(self) == std::mem::discriminant(other),
         }
     }
@@ -109,7 +121,9 @@ impl From<IRAggExpr> for GroupByMethod {
             Median(_) => GroupByMethod::Median,
             NUnique(_) => GroupByMethod::NUnique,
             First(_) => GroupByMethod::First,
+            FirstNonNull(_) => GroupByMethod::FirstNonNull,
             Last(_) => GroupByMethod::Last,
+            LastNonNull(_) => GroupByMethod::LastNonNull,
             Mean(_) => GroupByMethod::Mean,
             Implode(_) => GroupByMethod::Implode,
             Sum(_) => GroupByMethod::Sum,
@@ -117,7 +131,7 @@ impl From<IRAggExpr> for GroupByMethod {
             Std(_, ddof) => GroupByMethod::Std(ddof),
             Var(_, ddof) => GroupByMethod::Var(ddof),
             AggGroups(_) => GroupByMethod::Groups,
-            Quantile { .. } => unreachable!(),
+            Quantile { .. } | ApproxQuantile { .. } | Mode(..) => unreachable!(),
         }
     }
 }
@@ -361,7 +375,8 @@ impl AExpr {
             },
             Agg(a) => {
                 match a {
-                    IRAggExpr::Quantile { expr, quantile, .. } => {
+                    IRAggExpr::Quantile { expr, quantile, .. }
+                    | IRAggExpr::ApproxQuantile { expr, quantile, .. } => {
                         *expr = inputs[0];
                         *quantile = inputs[1];
                     },
@@ -440,15 +455,20 @@ impl IRAggExpr {
             Median(input) => Single(*input),
             NUnique(input) => Single(*input),
             First(input) => Single(*input),
+            FirstNonNull(input) => Single(*input),
             Last(input) => Single(*input),
+            LastNonNull(input) => Single(*input),
             Mean(input) => Single(*input),
             Implode(input) => Single(*input),
-            Quantile { expr, quantile, .. } => Many(vec![*expr, *quantile]),
+            Quantile { expr, quantile, .. } | ApproxQuantile { expr, quantile, .. } => {
+                Many(vec![*expr, *quantile])
+            },
             Sum(input) => Single(*input),
             Count(input, _) => Single(*input),
             Std(input, _) => Single(*input),
             Var(input, _) => Single(*input),
             AggGroups(input) => Single(*input),
+            Mode(input, _) => Single(*input),
         }
     }
     pub fn set_input(&mut self, input: Node) {
@@ -459,15 +479,19 @@ impl IRAggExpr {
             Median(input) => input,
             NUnique(input) => input,
             First(input) => input,
+            FirstNonNull(input) => input,
             Last(input) => input,
+            LastNonNull(input) => input,
             Mean(input) => input,
             Implode(input) => input,
             Quantile { expr, .. } => expr,
+            ApproxQuantile { expr, .. } => expr,
             Sum(input) => input,
             Count(input, _) => input,
             Std(input, _) => input,
             Var(input, _) => input,
             AggGroups(input) => input,
+            Mode(input, _) => input,
         };
         *node = input;
     }
@@ -3,6 +3,10 @@ use recursive::recursive;
 use super::*;
 
 fn float_type(field: &mut Field) {
+    #[cfg(feature = "dtype-f16")]
+    if field.dtype == DataType::Float16 {
+        return field.coerce(DataType::Float32);
+    }
     if (field.dtype.is_numeric() || field.dtype == DataType::Boolean)
         && field.dtype != DataType::Float32
     {
@@ -128,7 +132,9 @@ impl AExpr {
                     Max { input: expr, .. }
                     | Min { input: expr, .. }
                     | First(expr)
-                    | Last(expr) => {
+                    | FirstNonNull(expr)
+                    | Last(expr)
+                    | LastNonNull(expr) => {
                         *nested = nested.saturating_sub(1);
                         arena.get(*expr).to_field_impl(schema, arena, nested)
                     },
@@ -138,6 +144,8 @@ impl AExpr {
                         let dt = match field.data_type() {
                             Boolean => Some(IDX_DTYPE),
                             UInt8 | Int8 | Int16 | UInt16 => Some(Int64),
+                            #[cfg(feature = "dtype-f16")]
+                            Float16 => Some(Float32),
                             _ => None,
                         };
                         if let Some(dt) = dt {
@@ -198,12 +206,20 @@ impl AExpr {
                         field.coerce(List(IDX_DTYPE.into()));
                         Ok(field)
                     },
-                    Quantile { expr, .. } => {
+                    Quantile { expr, .. } | ApproxQuantile { expr, .. } => {
                         *nested = nested.saturating_sub(1);
                         let mut field = arena.get(*expr).to_field_impl(schema, arena, nested)?;
                         float_type(&mut field);
                         Ok(field)
                     },
+                    Mode(expr, keep) => {
+                        *nested = nested.saturating_sub(1);
+                        let mut field = arena.get(*expr).to_field_impl(schema, arena, nested)?;
+                        if *keep == ModeKeep::All {
+                            field.coerce(List(field.data_type().clone().into()));
+                        }
+                        Ok(field)
+                    },
                 }
             },
             Cast {
@@ -427,6 +443,12 @@ fn get_arithmetic_field(
         },
     };
 
+    // Arithmetic on `Float16` is computed in `Float32`.
+    #[cfg(feature = "dtype-f16")]
+    let super_type = match super_type {
+        Float16 => Float32,
+        dt => dt,
+    };
     left_field.coerce(super_type);
     Ok(left_field)
 }
@@ -442,6 +464,8 @@ fn get_truediv_field(
     use DataType::*;
     let out_type = match left_field.data_type() {
         Float32 => Float32,
+        #[cfg(feature = "dtype-f16")]
+        Float16 => Float32,
         dt if dt.is_numeric() => Float64,
         #[cfg(feature = "dtype-duration")]
         Duration(_) => match arena
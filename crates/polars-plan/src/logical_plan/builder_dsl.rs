@@ -51,6 +51,7 @@ impl DslBuilder {
                 enabled: false,
                 ..Default::default()
             },
+            column_selection: None,
         };
 
         Ok(DslPlan::Scan {
@@ -82,6 +83,7 @@ impl DslBuilder {
         cloud_options: Option<CloudOptions>,
         use_statistics: bool,
         hive_options: HiveOptions,
+        column_selection: Option<ColumnSelection>,
     ) -> PolarsResult<Self> {
         let paths = paths.into();
 
@@ -93,6 +95,7 @@ impl DslBuilder {
             row_index,
             file_counter: Default::default(),
             hive_options,
+            column_selection,
         };
         Ok(DslPlan::Scan {
             paths,
@@ -139,6 +142,7 @@ impl DslBuilder {
                     enabled: false,
                     ..Default::default()
                 },
+                column_selection: None,
             },
             predicate: None,
             scan_type: FileScan::Ipc {
@@ -157,6 +161,7 @@ impl DslBuilder {
         read_options: CsvReadOptions,
         cache: bool,
         cloud_options: Option<CloudOptions>,
+        hive_options: HiveOptions,
     ) -> PolarsResult<Self> {
         let paths = paths.into();
 
@@ -170,11 +175,8 @@ impl DslBuilder {
             rechunk: read_options_clone.rechunk,
             row_index: read_options_clone.row_index,
             file_counter: Default::default(),
-            // TODO: Support Hive partitioning.
-            hive_options: HiveOptions {
-                enabled: false,
-                ..Default::default()
-            },
+            hive_options,
+            column_selection: None,
         };
         Ok(DslPlan::Scan {
             paths,
@@ -401,6 +403,7 @@ impl DslBuilder {
         function: PythonFunction,
         optimizations: AllowedOptimizations,
         schema: Option<SchemaRef>,
+        required_columns: Option<Arc<[Arc<str>]>>,
         validate_output: bool,
     ) -> Self {
         DslPlan::MapFunction {
@@ -410,6 +413,7 @@ impl DslBuilder {
                 schema,
                 predicate_pd: optimizations.predicate_pushdown,
                 projection_pd: optimizations.projection_pushdown,
+                required_columns,
                 streamable: optimizations.streaming,
                 validate_output,
             }),
@@ -36,7 +36,7 @@ macro_rules! failed_input_args {
 
 macro_rules! failed_here {
     ($($t:tt)*) => {
-        format!("'{}' failed", stringify!($($t)*)).into()
+        stringify!($($t)*)
     }
 }
 
@@ -73,7 +73,7 @@ pub fn to_alp_impl(
         let lp_node = lp_arena.add(lp);
         convert
             .coerce_types(expr_arena, lp_arena, lp_node)
-            .map_err(|e| e.context(format!("'{name}' failed").into()))?;
+            .map_err(|e| e.with_operator(name.to_string()))?;
 
         Ok(lp_node)
     }
@@ -84,7 +84,7 @@ pub fn to_alp_impl(
             paths,
             predicate,
             mut scan_type,
-            file_options,
+            mut file_options,
         } => {
             let mut file_info = if let Some(file_info) = file_info {
                 file_info
@@ -98,7 +98,7 @@ pub fn to_alp_impl(
                     } => {
                         let (file_info, md) =
                             scans::parquet_file_info(&paths, &file_options, cloud_options.as_ref())
-                                .map_err(|e| e.context(failed_here!(parquet scan)))?;
+                                .map_err(|e| e.with_operator(failed_here!(parquet scan)))?;
                         *metadata = md;
                         file_info
                     },
@@ -110,7 +110,7 @@ pub fn to_alp_impl(
                     } => {
                         let (file_info, md) =
                             scans::ipc_file_info(&paths, &file_options, cloud_options.as_ref())
-                                .map_err(|e| e.context(failed_here!(ipc scan)))?;
+                                .map_err(|e| e.with_operator(failed_here!(ipc scan)))?;
                         *metadata = Some(md);
                         file_info
                     },
@@ -120,7 +120,7 @@ pub fn to_alp_impl(
                         cloud_options,
                     } => {
                         scans::csv_file_info(&paths, &file_options, options, cloud_options.as_ref())
-                            .map_err(|e| e.context(failed_here!(csv scan)))?
+                            .map_err(|e| e.with_operator(failed_here!(csv scan)))?
                     },
                     // FileInfo should be set.
                     FileScan::Anonymous { .. } => unreachable!(),
@@ -134,10 +134,16 @@ pub fn to_alp_impl(
                     .unwrap();
             }
 
+            let output_schema = file_options
+                .column_selection
+                .clone()
+                .map(|selection| apply_column_selection(&file_info, &mut file_options, &selection))
+                .transpose()?;
+
             IR::Scan {
                 file_info,
                 paths,
-                output_schema: None,
+                output_schema,
                 predicate: predicate.map(|expr| to_expr_ir(expr, expr_arena)),
                 scan_type,
                 file_options,
@@ -148,7 +154,11 @@ pub fn to_alp_impl(
             options,
             predicate: None,
         },
-        DslPlan::Union { inputs, args } => {
+        DslPlan::Union {
+            inputs,
+            args,
+            merge_sorted_by,
+        } => {
             let mut inputs = inputs
                 .into_iter()
                 .map(|lp| to_alp_impl(lp, expr_arena, lp_arena, convert))
@@ -163,8 +173,19 @@ pub fn to_alp_impl(
                 convert_utils::convert_st_union(&mut inputs, lp_arena, expr_arena)
                     .map_err(|e| e.context(failed_input!(vertical concat)))?;
             }
+            if let Some(by) = &merge_sorted_by {
+                let schema = lp_arena.get(inputs[0]).schema(lp_arena);
+                polars_ensure!(
+                    schema.contains(by),
+                    ColumnNotFound: "cannot merge sorted inputs by '{}': column not found", by
+                );
+            }
             let options = args.into();
-            IR::Union { inputs, options }
+            IR::Union {
+                inputs,
+                options,
+                merge_sorted_by,
+            }
         },
         DslPlan::HConcat { inputs, options } => {
             let inputs = inputs
@@ -210,7 +231,7 @@ pub fn to_alp_impl(
 
             for predicate in predicates {
                 let predicate = expand_filter(predicate, input, lp_arena)
-                    .map_err(|e| e.context(failed_here!(filter)))?;
+                    .map_err(|e| e.with_operator(failed_here!(filter)))?;
                 let predicate = to_expr_ir(predicate, expr_arena);
                 convert.push_scratch(predicate.node(), expr_arena);
                 let lp = IR::Filter { input, predicate };
@@ -242,8 +263,8 @@ pub fn to_alp_impl(
             let input = to_alp_impl(owned(input), expr_arena, lp_arena, convert)
                 .map_err(|e| e.context(failed_input!(select)))?;
             let schema = lp_arena.get(input).schema(lp_arena);
-            let (exprs, schema) =
-                prepare_projection(expr, &schema).map_err(|e| e.context(failed_here!(select)))?;
+            let (exprs, schema) = prepare_projection(expr, &schema)
+                .map_err(|e| e.with_operator(failed_here!(select)))?;
 
             if exprs.is_empty() {
                 lp_arena.replace(input, empty_df());
@@ -271,7 +292,7 @@ pub fn to_alp_impl(
             let input = to_alp_impl(owned(input), expr_arena, lp_arena, convert)
                 .map_err(|e| e.context(failed_input!(sort)))?;
             let by_column = expand_expressions(input, by_column, lp_arena, expr_arena)
-                .map_err(|e| e.context(failed_here!(sort)))?;
+                .map_err(|e| e.with_operator(failed_here!(sort)))?;
 
             convert.fill_scratch(&by_column, expr_arena);
             let lp = IR::Sort {
@@ -309,7 +330,7 @@ pub fn to_alp_impl(
 
             let (keys, aggs, schema) =
                 resolve_group_by(input, keys, aggs, &options, lp_arena, expr_arena)
-                    .map_err(|e| e.context(failed_here!(group_by)))?;
+                    .map_err(|e| e.with_operator(failed_here!(group_by)))?;
 
             let (apply, schema) = if let Some((apply, schema)) = apply {
                 (Some(apply), schema)
@@ -349,6 +370,10 @@ pub fn to_alp_impl(
             }
 
             options.args.validation.is_valid_join(&options.args.how)?;
+            options
+                .args
+                .hints
+                .validate(&options.args.how, left_on.len())?;
 
             polars_ensure!(
                 left_on.len() == right_on.len(),
@@ -370,7 +395,7 @@ pub fn to_alp_impl(
 
             let schema =
                 det_join_schema(&schema_left, &schema_right, &left_on, &right_on, &options)
-                    .map_err(|e| e.context(failed_here!(join schema resolving)))?;
+                    .map_err(|e| e.with_operator(failed_here!(join schema resolving)))?;
 
             let left_on = to_expr_irs_ignore_alias(left_on, expr_arena);
             let right_on = to_expr_irs_ignore_alias(right_on, expr_arena);
@@ -406,7 +431,7 @@ pub fn to_alp_impl(
             let input = to_alp_impl(owned(input), expr_arena, lp_arena, convert)
                 .map_err(|e| e.context(failed_input!(with_columns)))?;
             let (exprs, schema) = resolve_with_columns(exprs, input, lp_arena, expr_arena)
-                .map_err(|e| e.context(failed_here!(with_columns)))?;
+                .map_err(|e| e.with_operator(failed_here!(with_columns)))?;
 
             convert.fill_scratch(&exprs, expr_arena);
             let lp = IR::HStack {
@@ -441,7 +466,7 @@ pub fn to_alp_impl(
                         .collect::<Vec<_>>();
 
                     let (exprs, schema) = resolve_with_columns(exprs, input, lp_arena, expr_arena)
-                        .map_err(|e| e.context(failed_here!(fill_nan)))?;
+                        .map_err(|e| e.with_operator(failed_here!(fill_nan)))?;
 
                     convert.fill_scratch(&exprs, expr_arena);
 
@@ -551,7 +576,7 @@ pub fn to_alp_impl(
                 .into_iter()
                 .map(|lp| to_alp_impl(lp, expr_arena, lp_arena, convert))
                 .collect::<PolarsResult<Vec<_>>>()
-                .map_err(|e| e.context(failed_here!(with_context)))?;
+                .map_err(|e| e.with_operator(failed_here!(with_context)))?;
 
             let mut schema = (**lp_arena.get(input).schema(lp_arena)).clone();
             for input in &contexts {
@@ -743,3 +768,62 @@ where
         })
         .collect()
 }
+
+/// Prune the columns that don't match `selection` from the schema of a scan.
+///
+/// The schema of the files is left as is, so that projections keep referring to the columns of
+/// the files. The selected columns are also set as the columns to read, so that the other
+/// columns are skipped even if no projection is pushed down to the scan.
+fn apply_column_selection(
+    file_info: &FileInfo,
+    file_options: &mut FileScanOptions,
+    selection: &ColumnSelection,
+) -> PolarsResult<SchemaRef> {
+    #[cfg(feature = "regex")]
+    let pattern = selection
+        .columns_matching
+        .as_deref()
+        .map(regex::Regex::new)
+        .transpose()
+        .map_err(|e| polars_err!(ComputeError: "invalid regex {}", e))?;
+    #[cfg(not(feature = "regex"))]
+    polars_ensure!(
+        selection.columns_matching.is_none(),
+        ComputeError: "selecting columns by pattern requires the 'regex' feature"
+    );
+
+    let row_index = file_options.row_index.as_ref().map(|ri| ri.name.as_ref());
+    let hive_schema = file_info.hive_parts.as_ref().map(|hive| hive.schema());
+    #[cfg_attr(not(feature = "regex"), allow(unused_variables))]
+    let is_selected = |name: &str, dtype: &DataType| {
+        #[cfg(feature = "regex")]
+        if let Some(pattern) = &pattern {
+            if !pattern.is_match(name) {
+                return false;
+            }
+        }
+        selection
+            .dtypes
+            .as_ref()
+            .map_or(true, |dtypes| dtypes.contains(dtype))
+    };
+
+    let schema = file_info
+        .schema
+        .iter()
+        .filter(|(name, dtype)| {
+            Some(name.as_str()) == row_index
+                || hive_schema.is_some_and(|schema| schema.contains(name))
+                || is_selected(name, dtype)
+        })
+        .map(|(name, dtype)| Field::new(name, dtype.clone()))
+        .collect::<Schema>();
+    file_options.with_columns = Some(
+        schema
+            .iter_names()
+            .filter(|name| Some(name.as_str()) != row_index)
+            .map(|name| name.to_string())
+            .collect(),
+    );
+    Ok(Arc::new(schema))
+}
@@ -326,7 +326,9 @@ fn replace_struct_multiple_fields_with_field(
         } => {
             if matches!(
                 function,
-                FunctionExpr::StructExpr(StructFunction::MultipleFields(_))
+                FunctionExpr::StructExpr(
+                    StructFunction::MultipleFields(_) | StructFunction::ExcludeFields(_)
+                )
             ) {
                 count += 1;
                 Expr::Function {
@@ -350,6 +352,50 @@ fn replace_struct_multiple_fields_with_field(
     Ok(out)
 }
 
+#[cfg(feature = "dtype-struct")]
+fn struct_input_fields(struct_expr: &Expr, schema: &Schema) -> PolarsResult<Vec<Field>> {
+    let Expr::Function { input, .. } = struct_expr else {
+        unreachable!()
+    };
+    let field = input[0].to_field(schema, Context::Default)?;
+    let DataType::Struct(fields) = field.data_type() else {
+        polars_bail!(InvalidOperation: "expected 'struct'")
+    };
+    Ok(fields.clone())
+}
+
+/// The names of the fields of the struct that are not matched by any of the `excluded` names or
+/// regexes.
+#[cfg(feature = "dtype-struct")]
+fn struct_fields_excluding(
+    struct_expr: &Expr,
+    schema: &Schema,
+    excluded: &[ColumnName],
+) -> PolarsResult<Vec<ColumnName>> {
+    let fields = struct_input_fields(struct_expr, schema)?;
+    #[cfg(feature = "regex")]
+    let regexes = excluded
+        .iter()
+        .filter(|name| is_regex_projection(name))
+        .map(|name| {
+            regex::Regex::new(name).map_err(|e| polars_err!(ComputeError: "invalid regex {}", e))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    Ok(fields
+        .iter()
+        .map(|field| field.name().as_str())
+        .filter(|name| {
+            #[cfg(feature = "regex")]
+            if regexes.iter().any(|re| re.is_match(name)) {
+                return false;
+            }
+            !excluded.iter().any(|excluded| excluded.as_ref() == *name)
+        })
+        .map(ColumnName::from)
+        .collect())
+}
+
 #[cfg(feature = "dtype-struct")]
 fn expand_struct_fields(
     struct_expr: &Expr,
@@ -359,15 +405,12 @@ fn expand_struct_fields(
     names: &[ColumnName],
     exclude: &PlHashSet<Arc<str>>,
 ) -> PolarsResult<()> {
-    let first_name = names[0].as_ref();
+    // A regex or exclusion may not leave any field to expand.
+    let Some(first_name) = names.first().map(|name| name.as_ref()) else {
+        return Ok(());
+    };
     if names.len() == 1 && first_name == "*" || is_regex_projection(first_name) {
-        let Expr::Function { input, .. } = struct_expr else {
-            unreachable!()
-        };
-        let field = input[0].to_field(schema, Context::Default)?;
-        let DataType::Struct(fields) = field.data_type() else {
-            polars_bail!(InvalidOperation: "expected 'struct'")
-        };
+        let fields = struct_input_fields(struct_expr, schema)?;
 
         // Wildcard.
         let names = if first_name == "*" {
@@ -606,7 +649,10 @@ fn find_flags(expr: &Expr) -> PolarsResult<ExpansionFlags> {
             },
             #[cfg(feature = "dtype-struct")]
             Expr::Function {
-                function: FunctionExpr::StructExpr(StructFunction::MultipleFields(_)),
+                function:
+                    FunctionExpr::StructExpr(
+                        StructFunction::MultipleFields(_) | StructFunction::ExcludeFields(_),
+                    ),
                 ..
             } => {
                 expands_fields = true;
@@ -685,7 +731,10 @@ fn replace_and_add_to_results(
             Expr::Columns(_) | Expr::DtypeColumn(_) | Expr::IndexColumn(_) => true,
             #[cfg(feature = "dtype-struct")]
             Expr::Function {
-                function: FunctionExpr::StructExpr(StructFunction::MultipleFields(_)),
+                function:
+                    FunctionExpr::StructExpr(
+                        StructFunction::MultipleFields(_) | StructFunction::ExcludeFields(_),
+                    ),
                 ..
             } => flags.expands_fields,
             _ => false,
@@ -706,8 +755,7 @@ fn replace_and_add_to_results(
                 },
                 #[cfg(feature = "dtype-struct")]
                 Expr::Function { function, .. } => {
-                    let FunctionExpr::StructExpr(StructFunction::MultipleFields(names)) = function
-                    else {
+                    let FunctionExpr::StructExpr(struct_function) = function else {
                         unreachable!()
                     };
                     let exclude = prepare_excluded(&expr, schema, keys, flags.has_exclude)?;
@@ -738,7 +786,16 @@ fn replace_and_add_to_results(
                     // has only field expansion
                     // col('a').struct.field('*')
                     else {
-                        expand_struct_fields(e, &expr, result, schema, names, &exclude)?
+                        match struct_function {
+                            StructFunction::MultipleFields(names) => {
+                                expand_struct_fields(e, &expr, result, schema, names, &exclude)?
+                            },
+                            StructFunction::ExcludeFields(excluded) => {
+                                let names = struct_fields_excluding(e, schema, excluded)?;
+                                expand_struct_fields(e, &expr, result, schema, &names, &exclude)?
+                            },
+                            _ => unreachable!(),
+                        }
                     }
                 },
                 _ => {},
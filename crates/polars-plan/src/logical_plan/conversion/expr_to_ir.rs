@@ -205,9 +205,15 @@ fn to_aexpr_impl(expr: Expr, arena: &mut Arena<AExpr>, state: &mut ConversionSta
                 AggExpr::First(expr) => {
                     IRAggExpr::First(to_aexpr_impl_materialized_lit(owned(expr), arena, state))
                 },
+                AggExpr::FirstNonNull(expr) => IRAggExpr::FirstNonNull(
+                    to_aexpr_impl_materialized_lit(owned(expr), arena, state),
+                ),
                 AggExpr::Last(expr) => {
                     IRAggExpr::Last(to_aexpr_impl_materialized_lit(owned(expr), arena, state))
                 },
+                AggExpr::LastNonNull(expr) => IRAggExpr::LastNonNull(
+                    to_aexpr_impl_materialized_lit(owned(expr), arena, state),
+                ),
                 AggExpr::Mean(expr) => {
                     IRAggExpr::Mean(to_aexpr_impl_materialized_lit(owned(expr), arena, state))
                 },
@@ -227,6 +233,15 @@ fn to_aexpr_impl(expr: Expr, arena: &mut Arena<AExpr>, state: &mut ConversionSta
                     quantile: to_aexpr_impl_materialized_lit(owned(quantile), arena, state),
                     interpol,
                 },
+                AggExpr::ApproxQuantile {
+                    expr,
+                    quantile,
+                    options,
+                } => IRAggExpr::ApproxQuantile {
+                    expr: to_aexpr_impl_materialized_lit(owned(expr), arena, state),
+                    quantile: to_aexpr_impl_materialized_lit(owned(quantile), arena, state),
+                    options,
+                },
                 AggExpr::Sum(expr) => {
                     IRAggExpr::Sum(to_aexpr_impl_materialized_lit(owned(expr), arena, state))
                 },
@@ -241,6 +256,10 @@ fn to_aexpr_impl(expr: Expr, arena: &mut Arena<AExpr>, state: &mut ConversionSta
                 AggExpr::AggGroups(expr) => {
                     IRAggExpr::AggGroups(to_aexpr_impl_materialized_lit(owned(expr), arena, state))
                 },
+                AggExpr::Mode(expr, keep) => IRAggExpr::Mode(
+                    to_aexpr_impl_materialized_lit(owned(expr), arena, state),
+                    keep,
+                ),
             };
             AExpr::Agg(a_agg)
         },
@@ -114,10 +114,18 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 let exp = node_to_expr(expr, expr_arena);
                 AggExpr::First(Arc::new(exp)).into()
             },
+            IRAggExpr::FirstNonNull(expr) => {
+                let exp = node_to_expr(expr, expr_arena);
+                AggExpr::FirstNonNull(Arc::new(exp)).into()
+            },
             IRAggExpr::Last(expr) => {
                 let exp = node_to_expr(expr, expr_arena);
                 AggExpr::Last(Arc::new(exp)).into()
             },
+            IRAggExpr::LastNonNull(expr) => {
+                let exp = node_to_expr(expr, expr_arena);
+                AggExpr::LastNonNull(Arc::new(exp)).into()
+            },
             IRAggExpr::Mean(expr) => {
                 let exp = node_to_expr(expr, expr_arena);
                 AggExpr::Mean(Arc::new(exp)).into()
@@ -140,6 +148,20 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 }
                 .into()
             },
+            IRAggExpr::ApproxQuantile {
+                expr,
+                quantile,
+                options,
+            } => {
+                let expr = node_to_expr(expr, expr_arena);
+                let quantile = node_to_expr(quantile, expr_arena);
+                AggExpr::ApproxQuantile {
+                    expr: Arc::new(expr),
+                    quantile: Arc::new(quantile),
+                    options,
+                }
+                .into()
+            },
             IRAggExpr::Sum(expr) => {
                 let exp = node_to_expr(expr, expr_arena);
                 AggExpr::Sum(Arc::new(exp)).into()
@@ -160,6 +182,10 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 let expr = node_to_expr(expr, expr_arena);
                 AggExpr::Count(Arc::new(expr), include_nulls).into()
             },
+            IRAggExpr::Mode(expr, keep) => {
+                let exp = node_to_expr(expr, expr_arena);
+                AggExpr::Mode(Arc::new(exp), keep).into()
+            },
         },
         AExpr::Ternary {
             predicate,
@@ -58,7 +58,11 @@ impl IR {
             },
             #[cfg(feature = "python")]
             IR::PythonScan { options, .. } => DslPlan::PythonScan { options },
-            IR::Union { inputs, .. } => {
+            IR::Union {
+                inputs,
+                merge_sorted_by,
+                ..
+            } => {
                 let inputs = inputs
                     .into_iter()
                     .map(|node| convert_to_lp(node, lp_arena))
@@ -66,6 +70,7 @@ impl IR {
                 DslPlan::Union {
                     inputs,
                     args: Default::default(),
+                    merge_sorted_by,
                 }
             },
             IR::HConcat {
@@ -127,8 +127,9 @@ pub(super) fn csv_file_info(
     use std::io::{Read, Seek};
 
     use polars_core::{config, POOL};
-    use polars_io::csv::read::is_compressed;
     use polars_io::csv::read::schema_inference::SchemaInferenceResult;
+    use polars_io::csv::read::{decompress_head, is_compressed};
+    use polars_io::mmap::ReaderBytes;
     use polars_io::utils::get_reader_bytes;
     use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
@@ -181,19 +182,26 @@ pub(super) fn csv_file_info(
 
         let mut magic_nr = [0u8; 4];
         let res_len = file.read(&mut magic_nr)?;
-        if res_len < 2 {
-            if csv_options.raise_if_empty {
-                polars_bail!(NoData: "empty CSV")
-            }
-        } else {
-            polars_ensure!(
-            !is_compressed(&magic_nr),
-            ComputeError: "cannot scan compressed csv; use `read_csv` for compressed data",
-            );
+        if res_len < 2 && csv_options.raise_if_empty {
+            polars_bail!(NoData: "empty CSV")
         }
 
         file.rewind()?;
-        let reader_bytes = get_reader_bytes(&mut file).expect("could not mmap file");
+        let mut reader_bytes = get_reader_bytes(&mut file).expect("could not mmap file");
+        if res_len >= 2 && is_compressed(&magic_nr) {
+            // Only decompress the rows that are needed for the inference.
+            let n_rows = csv_options.infer_schema_length.map(|n| {
+                csv_options.skip_rows
+                    + csv_options.has_header as usize
+                    + csv_options.skip_rows_after_header
+                    + n
+            });
+            reader_bytes = ReaderBytes::Owned(decompress_head(
+                &reader_bytes,
+                n_rows,
+                &csv_options.get_parse_options(),
+            )?);
+        }
 
         // this needs a way to estimated bytes/rows.
         let si_result =
@@ -258,9 +266,18 @@ pub(super) fn csv_file_info(
 
     let estimated_n_rows = si_result.get_estimated_n_rows();
 
-    Ok(FileInfo::new(
+    let mut file_info = FileInfo::new(
         schema,
         Some(Either::Right(reader_schema)),
         (None, estimated_n_rows),
-    ))
+    );
+
+    if file_options.hive_options.enabled {
+        file_info.init_hive_partitions(
+            first_path.as_path(),
+            file_options.hive_options.schema.clone(),
+        )?
+    }
+
+    Ok(file_info)
 }
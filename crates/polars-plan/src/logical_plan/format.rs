@@ -26,6 +26,13 @@ impl fmt::Debug for Expr {
                         function, options.index_column, options.offset, options.period
                     )
                 },
+                WindowType::Frame(frame) => {
+                    write!(f, "{function:?}.over(partition_by: {partition_by:?}")?;
+                    if let Some((order_by, _)) = order_by {
+                        write!(f, ", order_by: {order_by:?}")?;
+                    }
+                    write!(f, ", frame: {frame:?})")
+                },
                 _ => {
                     if let Some((order_by, _)) = order_by {
                         write!(f, "{function:?}.over(partition_by: {partition_by:?}, order_by: {order_by:?})")
@@ -111,7 +118,9 @@ impl fmt::Debug for Expr {
                     Median(expr) => write!(f, "{expr:?}.median()"),
                     Mean(expr) => write!(f, "{expr:?}.mean()"),
                     First(expr) => write!(f, "{expr:?}.first()"),
+                    FirstNonNull(expr) => write!(f, "{expr:?}.first_non_null()"),
                     Last(expr) => write!(f, "{expr:?}.last()"),
+                    LastNonNull(expr) => write!(f, "{expr:?}.last_non_null()"),
                     Implode(expr) => write!(f, "{expr:?}.list()"),
                     NUnique(expr) => write!(f, "{expr:?}.n_unique()"),
                     Sum(expr) => write!(f, "{expr:?}.sum()"),
@@ -120,6 +129,8 @@ impl fmt::Debug for Expr {
                     Var(expr, _) => write!(f, "{expr:?}.var()"),
                     Std(expr, _) => write!(f, "{expr:?}.std()"),
                     Quantile { expr, .. } => write!(f, "{expr:?}.quantile()"),
+                    ApproxQuantile { expr, .. } => write!(f, "{expr:?}.approx_quantile()"),
+                    Mode(expr, _) => write!(f, "{expr:?}.mode()"),
                 }
             },
             Cast {
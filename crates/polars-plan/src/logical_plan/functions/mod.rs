@@ -36,6 +36,9 @@ pub enum FunctionNode {
         predicate_pd: bool,
         ///  allow projection pushdown optimizations
         projection_pd: bool,
+        /// The input columns that the function reads. If set, only these columns are
+        /// materialized below the function.
+        required_columns: Option<Arc<[Arc<str>]>>,
         streamable: bool,
         validate_output: bool,
     },
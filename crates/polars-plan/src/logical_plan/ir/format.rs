@@ -174,12 +174,19 @@ impl<'a> IRDisplay<'a> {
                     options.n_rows,
                 )
             },
-            Union { inputs, options } => {
-                let name = if let Some(slice) = options.slice {
+            Union {
+                inputs,
+                options,
+                merge_sorted_by,
+            } => {
+                let mut name = if let Some(slice) = options.slice {
                     format!("SLICED UNION: {slice:?}")
                 } else {
                     "UNION".to_string()
                 };
+                if let Some(by) = merge_sorted_by {
+                    name = format!("{name} MERGE SORTED BY: {by}");
+                }
 
                 // 3 levels of indentation
                 // - 0 => UNION ... END UNION
@@ -361,8 +368,10 @@ impl<'a> IRDisplay<'a> {
                 let name = match payload {
                     SinkType::Memory => "SINK (memory)",
                     SinkType::File { .. } => "SINK (file)",
+                    SinkType::Batches { .. } => "SINK (batches)",
                     #[cfg(feature = "cloud")]
                     SinkType::Cloud { .. } => "SINK (cloud)",
+                    SinkType::Multiple(_) => "SINK (multiple)",
                 };
                 write!(f, "{:indent$}{name}", "")?;
                 self.with_root(*input)._format(f, sub_indent)
@@ -465,6 +474,14 @@ impl<'a> Display for ExprIRDisplay<'a> {
                             options.index_column, options.offset, options.period
                         )
                     },
+                    WindowType::Frame(frame) => {
+                        write!(f, "{function}.over(partition_by: {partition_by}")?;
+                        if let Some((order_by, _)) = order_by {
+                            let order_by = self.with_root(order_by);
+                            write!(f, ", order_by: {order_by}")?;
+                        }
+                        write!(f, ", frame: {frame:?})")
+                    },
                     _ => {
                         if let Some((order_by, _)) = order_by {
                             let order_by = self.with_root(order_by);
@@ -568,7 +585,11 @@ impl<'a> Display for ExprIRDisplay<'a> {
                     Median(expr) => write!(f, "{}.median()", self.with_root(expr)),
                     Mean(expr) => write!(f, "{}.mean()", self.with_root(expr)),
                     First(expr) => write!(f, "{}.first()", self.with_root(expr)),
+                    FirstNonNull(expr) => {
+                        write!(f, "{}.first_non_null()", self.with_root(expr))
+                    },
                     Last(expr) => write!(f, "{}.last()", self.with_root(expr)),
+                    LastNonNull(expr) => write!(f, "{}.last_non_null()", self.with_root(expr)),
                     Implode(expr) => write!(f, "{}.list()", self.with_root(expr)),
                     NUnique(expr) => write!(f, "{}.n_unique()", self.with_root(expr)),
                     Sum(expr) => write!(f, "{}.sum()", self.with_root(expr)),
@@ -577,6 +598,10 @@ impl<'a> Display for ExprIRDisplay<'a> {
                     Var(expr, _) => write!(f, "{}.var()", self.with_root(expr)),
                     Std(expr, _) => write!(f, "{}.std()", self.with_root(expr)),
                     Quantile { expr, .. } => write!(f, "{}.quantile()", self.with_root(expr)),
+                    ApproxQuantile { expr, .. } => {
+                        write!(f, "{}.approx_quantile()", self.with_root(expr))
+                    },
+                    Mode(expr, _) => write!(f, "{}.mode()", self.with_root(expr)),
                 }
             },
             Cast {
@@ -11,9 +11,14 @@ impl IR {
                 options: options.clone(),
                 predicate: predicate.clone(),
             },
-            Union { options, .. } => Union {
+            Union {
+                options,
+                merge_sorted_by,
+                ..
+            } => Union {
                 inputs,
                 options: *options,
+                merge_sorted_by: merge_sorted_by.clone(),
             },
             HConcat {
                 schema, options, ..
@@ -133,6 +133,8 @@ pub enum IR {
     Union {
         inputs: Vec<Node>,
         options: UnionOptions,
+        /// Merge the inputs, which are sorted by this column, instead of concatenating them.
+        merge_sorted_by: Option<Arc<str>>,
     },
     HConcat {
         inputs: Vec<Node>,
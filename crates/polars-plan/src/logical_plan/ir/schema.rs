@@ -37,8 +37,10 @@ impl IR {
             Sink { payload, .. } => match payload {
                 SinkType::Memory => "sink (memory)",
                 SinkType::File { .. } => "sink (file)",
+                SinkType::Batches { .. } => "sink (batches)",
                 #[cfg(feature = "cloud")]
                 SinkType::Cloud { .. } => "sink (cloud)",
+                SinkType::Multiple(_) => "sink (multiple)",
             },
             SimpleProjection { .. } => "simple_projection",
             Invalid => "invalid",
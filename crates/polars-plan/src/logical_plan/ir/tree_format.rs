@@ -360,8 +360,10 @@ impl<'a> TreeFmtNode<'a> {
                             match payload {
                                 SinkType::Memory => "SINK (memory)",
                                 SinkType::File { .. } => "SINK (file)",
+                                SinkType::Batches { .. } => "SINK (batches)",
                                 #[cfg(feature = "cloud")]
                                 SinkType::Cloud { .. } => "SINK (cloud)",
+                                SinkType::Multiple(_) => "SINK (multiple)",
                             },
                         ),
                         vec![self.lp_node(None, *input)],
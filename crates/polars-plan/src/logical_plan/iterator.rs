@@ -48,14 +48,18 @@ macro_rules! push_expr {
                     Median(e) => $push($c, e),
                     NUnique(e) => $push($c, e),
                     First(e) => $push($c, e),
+                    FirstNonNull(e) => $push($c, e),
                     Last(e) => $push($c, e),
+                    LastNonNull(e) => $push($c, e),
                     Implode(e) => $push($c, e),
                     Count(e, _) => $push($c, e),
                     Quantile { expr, .. } => $push($c, expr),
+                    ApproxQuantile { expr, .. } => $push($c, expr),
                     Sum(e) => $push($c, e),
                     AggGroups(e) => $push($c, e),
                     Std(e, _) => $push($c, e),
                     Var(e, _) => $push($c, e),
+                    Mode(e, _) => $push($c, e),
                 }
             },
             Ternary {
@@ -150,6 +150,9 @@ pub enum DslPlan {
     Union {
         inputs: Vec<DslPlan>,
         args: UnionArgs,
+        /// Merge the inputs, which must be sorted by this column, into a single sorted
+        /// output instead of concatenating them.
+        merge_sorted_by: Option<Arc<str>>,
     },
     /// Horizontal concatenation of multiple plans
     HConcat {
@@ -196,7 +199,7 @@ impl Clone for DslPlan {
             Self::Sort {input,by_column, slice, sort_options } => Self::Sort { input: input.clone(), by_column: by_column.clone(), slice: slice.clone(), sort_options: sort_options.clone() },
             Self::Slice { input, offset, len } => Self::Slice { input: input.clone(), offset: offset.clone(), len: len.clone() },
             Self::MapFunction { input, function } => Self::MapFunction { input: input.clone(), function: function.clone() },
-            Self::Union { inputs, args} => Self::Union { inputs: inputs.clone(), args: args.clone() },
+            Self::Union { inputs, args, merge_sorted_by } => Self::Union { inputs: inputs.clone(), args: args.clone(), merge_sorted_by: merge_sorted_by.clone() },
             Self::HConcat { inputs, options } => Self::HConcat { inputs: inputs.clone(), options: options.clone() },
             Self::ExtContext { input, contexts, } => Self::ExtContext { input: input.clone(), contexts: contexts.clone() },
             Self::Sink { input, payload } => Self::Sink { input: input.clone(), payload: payload.clone() },
@@ -1,5 +1,7 @@
 use std::collections::BTreeSet;
 
+use polars_core::prelude::sort::is_sorted_by_flag;
+
 use super::*;
 
 /// Projection in the physical plan is done by selecting an expression per thread.
@@ -16,13 +18,15 @@ pub(super) struct SimpleProjectionAndCollapse {
     /// can be expensive. Schema materialization can be for instance.
     processed: BTreeSet<Node>,
     eager: bool,
+    streaming: bool,
 }
 
 impl SimpleProjectionAndCollapse {
-    pub(super) fn new(eager: bool) -> Self {
+    pub(super) fn new(eager: bool, streaming: bool) -> Self {
         Self {
             processed: Default::default(),
             eager,
+            streaming,
         }
     }
 }
@@ -136,9 +140,76 @@ impl OptimizationRule for SimpleProjectionAndCollapse {
                     slice: *slice,
                     sort_options: sort_options.clone(),
                 }),
+                // Remove sorts of in-memory frames that are already in order
+                _ if is_in_order(
+                    *input,
+                    by_column,
+                    sort_options,
+                    lp_arena,
+                    expr_arena,
+                    self.streaming,
+                ) =>
+                {
+                    match slice {
+                        Some((offset, len)) => Some(Slice {
+                            input: *input,
+                            offset: *offset,
+                            len: *len as IdxSize,
+                        }),
+                        None => Some(lp_arena.get(*input).clone()),
+                    }
+                },
                 _ => None,
             },
             _ => None,
         }
     }
 }
+
+/// Whether the sorted flag of the only sort column shows that the rows produced by `node` are in
+/// order.
+///
+/// The flag is read from the in-memory frame the column comes from. Filters, slices and
+/// projections keep the order of their input, and so does an in-memory left join for the rows of
+/// its left input.
+fn is_in_order(
+    mut node: Node,
+    by_column: &[ExprIR],
+    sort_options: &SortMultipleOptions,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    streaming: bool,
+) -> bool {
+    let [by] = by_column else {
+        return false;
+    };
+    let AExpr::Column(name) = expr_arena.get(by.node()) else {
+        return false;
+    };
+    let df = loop {
+        match lp_arena.get(node) {
+            IR::Filter { input, .. }
+            | IR::Slice { input, .. }
+            | IR::SimpleProjection { input, .. } => node = *input,
+            // The streaming engine doesn't keep the order of the left rows.
+            IR::Join {
+                input_left,
+                options,
+                ..
+            } if !streaming
+                && matches!(options.args.how, JoinType::Left)
+                && lp_arena.get(*input_left).schema(lp_arena).contains(name) =>
+            {
+                node = *input_left
+            },
+            IR::DataFrameScan { df, .. } => break df,
+            _ => return false,
+        }
+    };
+    let Ok(s) = df.column(name) else {
+        return false;
+    };
+    // The sorted flag of a float column implies that `NaN`s are the largest values.
+    (!s.dtype().is_float() || sort_options.nan_ordering == NanOrdering::Largest)
+        && is_sorted_by_flag(s, sort_options.descending[0], sort_options.nulls_last[0])
+}
@@ -1,16 +1,21 @@
 use std::path::PathBuf;
 
 use super::*;
+use crate::logical_plan::hive::HivePartitions;
 
-pub(super) struct CountStar;
+pub(super) struct CountStar<'a> {
+    hive_partition_eval: HiveEval<'a>,
+}
 
-impl CountStar {
-    pub(super) fn new() -> Self {
-        Self
+impl<'a> CountStar<'a> {
+    pub(super) fn new(hive_partition_eval: HiveEval<'a>) -> Self {
+        Self {
+            hive_partition_eval,
+        }
     }
 }
 
-impl OptimizationRule for CountStar {
+impl OptimizationRule for CountStar<'_> {
     // Replace select count(*) from datasource with specialized map function.
     fn optimize_plan(
         &mut self,
@@ -18,30 +23,35 @@ impl OptimizationRule for CountStar {
         expr_arena: &mut Arena<AExpr>,
         node: Node,
     ) -> Option<IR> {
-        visit_logical_plan_for_scan_paths(node, lp_arena, expr_arena, false).map(
-            |count_star_expr| {
-                // MapFunction needs a leaf node, hence we create a dummy placeholder node
-                let placeholder = IR::DataFrameScan {
-                    df: Arc::new(Default::default()),
-                    schema: Arc::new(Default::default()),
-                    output_schema: None,
-                    filter: None,
-                };
-                let placeholder_node = lp_arena.add(placeholder);
+        visit_logical_plan_for_scan_paths(
+            node,
+            lp_arena,
+            expr_arena,
+            self.hive_partition_eval,
+            false,
+        )
+        .map(|count_star_expr| {
+            // MapFunction needs a leaf node, hence we create a dummy placeholder node
+            let placeholder = IR::DataFrameScan {
+                df: Arc::new(Default::default()),
+                schema: Arc::new(Default::default()),
+                output_schema: None,
+                filter: None,
+            };
+            let placeholder_node = lp_arena.add(placeholder);
 
-                let alp = IR::MapFunction {
-                    input: placeholder_node,
-                    function: FunctionNode::Count {
-                        paths: count_star_expr.paths,
-                        scan_type: count_star_expr.scan_type,
-                        alias: count_star_expr.alias,
-                    },
-                };
+            let alp = IR::MapFunction {
+                input: placeholder_node,
+                function: FunctionNode::Count {
+                    paths: count_star_expr.paths,
+                    scan_type: count_star_expr.scan_type,
+                    alias: count_star_expr.alias,
+                },
+            };
 
-                lp_arena.replace(count_star_expr.node, alp.clone());
-                alp
-            },
-        )
+            lp_arena.replace(count_star_expr.node, alp.clone());
+            alp
+        })
     }
 }
 
@@ -62,6 +72,7 @@ fn visit_logical_plan_for_scan_paths(
     node: Node,
     lp_arena: &Arena<IR>,
     expr_arena: &Arena<AExpr>,
+    hive_eval: HiveEval<'_>,
     inside_union: bool, // Inside union's we do not check for COUNT(*) expression
 ) -> Option<CountStarExpr> {
     match lp_arena.get(node) {
@@ -69,7 +80,9 @@ fn visit_logical_plan_for_scan_paths(
             let mut scan_type: Option<FileScan> = None;
             let mut paths = Vec::with_capacity(inputs.len());
             for input in inputs {
-                match visit_logical_plan_for_scan_paths(*input, lp_arena, expr_arena, true) {
+                match visit_logical_plan_for_scan_paths(
+                    *input, lp_arena, expr_arena, hive_eval, true,
+                ) {
                     Some(expr) => {
                         paths.extend(expr.paths.iter().cloned());
                         match &scan_type {
@@ -95,30 +108,68 @@ fn visit_logical_plan_for_scan_paths(
             })
         },
         IR::Scan {
-            scan_type, paths, ..
-        } if !matches!(scan_type, FileScan::Anonymous { .. }) => Some(CountStarExpr {
-            paths: paths.clone(),
-            scan_type: scan_type.clone(),
-            node,
-            alias: None,
-        }),
-        // A union can insert a simple projection to ensure all projections align.
-        // We can ignore that if we are inside a count star.
-        IR::SimpleProjection { input, .. } if inside_union => {
-            visit_logical_plan_for_scan_paths(*input, lp_arena, expr_arena, false)
+            scan_type,
+            paths,
+            file_info,
+            predicate,
+            file_options,
+            ..
+        } if !matches!(scan_type, FileScan::Anonymous { .. })
+            && file_options.n_rows.is_none()
+            && !skips_rows(scan_type) =>
+        {
+            let paths = match predicate {
+                None => paths.clone(),
+                // Only a predicate on the hive partitions can be evaluated per file.
+                Some(predicate) => {
+                    filter_hive_paths(paths, file_info, predicate, expr_arena, hive_eval)?
+                },
+            };
+            Some(CountStarExpr {
+                paths,
+                scan_type: scan_type.clone(),
+                node,
+                alias: None,
+            })
         },
+        // A union can insert a simple projection to ensure all projections align.
+        // We can ignore that, as well as other nodes that don't change the number of rows.
+        IR::SimpleProjection { input, .. }
+        | IR::HStack { input, .. }
+        | IR::Sort {
+            input, slice: None, ..
+        } => visit_logical_plan_for_scan_paths(*input, lp_arena, expr_arena, hive_eval, false),
+        // Note that `Explode` changes the number of rows, unlike `Unnest`.
+        IR::MapFunction {
+            input,
+            function:
+                FunctionNode::Rename { .. }
+                | FunctionNode::Unnest { .. }
+                | FunctionNode::RowIndex { .. },
+        } => visit_logical_plan_for_scan_paths(*input, lp_arena, expr_arena, hive_eval, false),
         IR::Select { input, expr, .. } => {
             if expr.len() == 1 {
                 let (valid, alias) = is_valid_count_expr(&expr[0], expr_arena);
                 if valid || inside_union {
-                    return visit_logical_plan_for_scan_paths(*input, lp_arena, expr_arena, false)
-                        .map(|mut expr| {
-                            expr.alias = alias;
-                            expr.node = node;
-                            expr
-                        });
+                    return visit_logical_plan_for_scan_paths(
+                        *input, lp_arena, expr_arena, hive_eval, false,
+                    )
+                    .map(|mut expr| {
+                        expr.alias = alias;
+                        expr.node = node;
+                        expr
+                    });
                 }
             }
+            // A selection of columns doesn't change the number of rows.
+            if expr
+                .iter()
+                .all(|e| matches!(expr_arena.get(e.node()), AExpr::Column(_)))
+            {
+                return visit_logical_plan_for_scan_paths(
+                    *input, lp_arena, expr_arena, hive_eval, false,
+                );
+            }
             None
         },
         _ => None,
@@ -131,3 +182,44 @@ fn is_valid_count_expr(e: &ExprIR, expr_arena: &Arena<AExpr>) -> (bool, Option<A
         _ => (false, None),
     }
 }
+
+/// Whether the scan skips rows that would be counted by the fast path.
+fn skips_rows(scan_type: &FileScan) -> bool {
+    match scan_type {
+        #[cfg(feature = "csv")]
+        FileScan::Csv { options, .. } => {
+            options.skip_rows > 0 || options.skip_rows_after_header > 0
+        },
+        _ => false,
+    }
+}
+
+/// Only keep the paths of which the hive partitions match `predicate`.
+///
+/// Returns `None` if the predicate refers to columns that are not hive partitions.
+fn filter_hive_paths(
+    paths: &Arc<[PathBuf]>,
+    file_info: &FileInfo,
+    predicate: &ExprIR,
+    expr_arena: &Arena<AExpr>,
+    hive_eval: HiveEval<'_>,
+) -> Option<Arc<[PathBuf]>> {
+    let hive_schema = file_info.hive_parts.as_ref()?.schema();
+    if !aexpr_to_leaf_names_iter(predicate.node(), expr_arena)
+        .all(|name| hive_schema.contains(name.as_ref()))
+    {
+        return None;
+    }
+    let io_expr = hive_eval?(predicate, expr_arena)?;
+
+    let mut filtered = Vec::with_capacity(paths.len());
+    for path in paths.iter() {
+        let hive_parts = HivePartitions::try_from_path(path, Some(hive_schema.clone())).ok()??;
+        let df = DataFrame::new(hive_parts.materialize_partition_columns()).ok()?;
+        let mask = io_expr.evaluate_io(&df).ok()?;
+        if mask.bool().ok()?.get(0) == Some(true) {
+            filtered.push(path.clone());
+        }
+    }
+    Some(filtered.into())
+}
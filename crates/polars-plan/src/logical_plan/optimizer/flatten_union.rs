@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use polars_utils::arena::{Arena, Node};
 use IR::*;
 
@@ -6,9 +8,18 @@ use crate::prelude::IR;
 
 pub struct FlattenUnionRule {}
 
-fn get_union_inputs(node: Node, lp_arena: &Arena<IR>) -> Option<&[Node]> {
+fn get_union_inputs<'a>(
+    node: Node,
+    merge_sorted_by: &Option<Arc<str>>,
+    lp_arena: &'a Arena<IR>,
+) -> Option<&'a [Node]> {
     match lp_arena.get(node) {
-        IR::Union { inputs, .. } => Some(inputs),
+        // Unions can only be flattened if they concatenate, or merge by the same key.
+        IR::Union {
+            inputs,
+            merge_sorted_by: input_merge_sorted_by,
+            ..
+        } if input_merge_sorted_by == merge_sorted_by => Some(inputs),
         _ => None,
     }
 }
@@ -25,16 +36,22 @@ impl OptimizationRule for FlattenUnionRule {
         match lp {
             Union {
                 inputs,
-                mut options,
+                options,
+                merge_sorted_by,
             } if inputs.iter().any(|node| match lp_arena.get(*node) {
-                Union { options, .. } => !options.flattened_by_opt,
+                Union {
+                    options,
+                    merge_sorted_by: input_merge_sorted_by,
+                    ..
+                } => !options.flattened_by_opt && input_merge_sorted_by == merge_sorted_by,
                 _ => false,
             }) =>
             {
+                let mut options = *options;
                 let mut new_inputs = Vec::with_capacity(inputs.len() * 2);
 
                 for node in inputs {
-                    match get_union_inputs(*node, lp_arena) {
+                    match get_union_inputs(*node, merge_sorted_by, lp_arena) {
                         Some(inp) => new_inputs.extend_from_slice(inp),
                         None => new_inputs.push(*node),
                     }
@@ -44,6 +61,7 @@ impl OptimizationRule for FlattenUnionRule {
                 Some(Union {
                     inputs: new_inputs,
                     options,
+                    merge_sorted_by: merge_sorted_by.clone(),
                 })
             },
             _ => None,
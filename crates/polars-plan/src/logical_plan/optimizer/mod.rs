@@ -17,11 +17,15 @@ mod fused;
 mod join_utils;
 mod predicate_pushdown;
 mod projection_pushdown;
+#[cfg(feature = "semi_anti_join")]
+mod semi_anti_join_pushdown;
 mod simplify_expr;
 mod simplify_functions;
 mod slice_pushdown_expr;
 mod slice_pushdown_lp;
 mod stack_opt;
+#[cfg(feature = "is_unique")]
+mod streaming_is_unique;
 
 use collapse_and_project::SimpleProjectionAndCollapse;
 use delay_rechunk::DelayRechunk;
@@ -76,6 +80,8 @@ pub fn optimize(
     let slice_pushdown = opt_state.slice_pushdown;
     let streaming = opt_state.streaming;
     let fast_projection = opt_state.fast_projection;
+    #[cfg(feature = "semi_anti_join")]
+    let semi_anti_join_pushdown = opt_state.semi_anti_join_pushdown;
     // Don't run optimizations that don't make sense on a single node.
     // This keeps eager execution more snappy.
     let eager = opt_state.eager;
@@ -138,6 +144,7 @@ pub fn optimize(
     #[cfg(not(feature = "cse"))]
     let _cse_plan_changed = false;
 
+    let mut is_count_star = false;
     // Should be run before predicate pushdown.
     if projection_pushdown {
         let mut projection_pushdown_opt = ProjectionPushDown::new();
@@ -145,10 +152,7 @@ pub fn optimize(
         let alp = projection_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
         lp_arena.replace(lp_top, alp);
 
-        if projection_pushdown_opt.is_count_star {
-            let mut count_star_opt = CountStar::new();
-            count_star_opt.optimize_plan(lp_arena, expr_arena, lp_top);
-        }
+        is_count_star = projection_pushdown_opt.is_count_star;
     }
 
     if predicate_pushdown {
@@ -158,13 +162,20 @@ pub fn optimize(
         lp_arena.replace(lp_top, alp);
     }
 
+    // Should be run after predicate pushdown, so that predicates on hive partitions
+    // can be resolved at the scan.
+    if is_count_star {
+        let mut count_star_opt = CountStar::new(hive_partition_eval);
+        count_star_opt.optimize_plan(lp_arena, expr_arena, lp_top);
+    }
+
     if cluster_with_columns {
         cluster_with_columns::optimize(lp_top, lp_arena, expr_arena)
     }
 
     // Make sure its before slice pushdown.
     if fast_projection {
-        rules.push(Box::new(SimpleProjectionAndCollapse::new(eager)));
+        rules.push(Box::new(SimpleProjectionAndCollapse::new(eager, streaming)));
     }
 
     if !eager {
@@ -191,8 +202,18 @@ pub fn optimize(
         rules.push(Box::new(FlattenUnionRule {}));
     }
 
+    #[cfg(feature = "semi_anti_join")]
+    if semi_anti_join_pushdown && !eager {
+        rules.push(Box::new(semi_anti_join_pushdown::SemiAntiJoinPushDown {}));
+    }
+
     lp_top = opt.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top)?;
 
+    #[cfg(feature = "is_unique")]
+    if streaming && !eager {
+        streaming_is_unique::optimize(lp_top, lp_arena, expr_arena);
+    }
+
     if members.has_joins_or_unions && members.has_cache && _cse_plan_changed {
         // We only want to run this on cse inserted caches
         cache_states::set_cache_states(
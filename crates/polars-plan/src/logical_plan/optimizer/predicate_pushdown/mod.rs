@@ -380,7 +380,7 @@ impl<'a> PredicatePushDown<'a> {
                                 }
                                 scan_type.remove_metadata();
                             }
-                            if paths.is_empty() {
+                            if new_paths.is_empty() {
                                 let schema = output_schema.as_ref().unwrap_or(&file_info.schema);
                                 let df = DataFrame::from(schema.as_ref());
 
@@ -696,6 +696,11 @@ impl<'a> PredicatePushDown<'a> {
                         }
                     }
                     Ok(PythonScan { options, predicate })
+                } else if options.is_io_plugin {
+                    // The source gets the predicate and tells whether it applied it, if not
+                    // the executor applies it after the scan.
+                    let predicate = predicate_at_scan(acc_predicates, predicate, expr_arena);
+                    Ok(PythonScan { options, predicate })
                 } else {
                     self.no_pushdown_restart_opt(
                         PythonScan { options, predicate },
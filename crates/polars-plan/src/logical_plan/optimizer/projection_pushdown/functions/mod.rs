@@ -1,6 +1,10 @@
 mod melt;
+#[cfg(feature = "python")]
+mod python_udf;
 
 use melt::process_melt;
+#[cfg(feature = "python")]
+use python_udf::process_python_udf;
 
 use super::*;
 
@@ -81,6 +85,27 @@ pub(super) fn process_functions(
                 expr_arena,
             )
         },
+        #[cfg(feature = "python")]
+        OpaquePython {
+            required_columns: Some(ref required_columns),
+            ref schema,
+            ..
+        } => {
+            let passes_input_through = schema.is_none();
+            let required_columns = required_columns.clone();
+            let lp = IR::MapFunction { input, function };
+            process_python_udf(
+                proj_pd,
+                lp,
+                input,
+                &required_columns,
+                passes_input_through,
+                acc_projections,
+                projections_seen,
+                lp_arena,
+                expr_arena,
+            )
+        },
         _ => {
             if function.allow_projection_pd() && !acc_projections.is_empty() {
                 let original_acc_projection_len = acc_projections.len();
@@ -0,0 +1,59 @@
+use super::*;
+
+/// Push down only the columns that a Python UDF declared to read.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn process_python_udf(
+    proj_pd: &mut ProjectionPushDown,
+    lp: IR,
+    input: Node,
+    required_columns: &[Arc<str>],
+    passes_input_through: bool,
+    acc_projections: Vec<ColumnNode>,
+    projections_seen: usize,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<IR> {
+    // Without an output schema the function returns its input columns, so all of them
+    // are needed if nothing is projected above the function.
+    if passes_input_through && acc_projections.is_empty() {
+        return proj_pd.no_pushdown_restart_opt(
+            lp,
+            acc_projections,
+            projections_seen,
+            lp_arena,
+            expr_arena,
+        );
+    }
+
+    let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
+    let mut pushdown = Vec::with_capacity(required_columns.len());
+    let mut pushdown_names = PlHashSet::with_capacity(required_columns.len());
+    for name in required_columns {
+        polars_ensure!(
+            input_schema.contains(name),
+            ColumnNotFound: "required column '{}' of the python udf is not in the input", name
+        );
+        let node = expr_arena.add(AExpr::Column(name.clone()));
+        add_expr_to_accumulated(node, &mut pushdown, &mut pushdown_names, expr_arena);
+    }
+    if passes_input_through {
+        for node in &acc_projections {
+            add_expr_to_accumulated(node.0, &mut pushdown, &mut pushdown_names, expr_arena);
+        }
+    }
+
+    proj_pd.pushdown_and_assign(
+        input,
+        pushdown,
+        pushdown_names,
+        projections_seen,
+        lp_arena,
+        expr_arena,
+    )?;
+
+    // The function may output more columns than are projected above it.
+    Ok(proj_pd.finish_node_simple_projection(
+        &acc_projections,
+        IRBuilder::from_lp(lp, expr_arena, lp_arena),
+    ))
+}
@@ -57,3 +57,49 @@ pub(super) fn process_generic(
 
     Ok(lp.with_exprs_and_input(exprs, new_inputs))
 }
+
+/// A union that merges its inputs needs the merge key, even if it isn't projected.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn process_union(
+    proj_pd: &mut ProjectionPushDown,
+    lp: IR,
+    acc_projections: Vec<ColumnNode>,
+    projected_names: PlHashSet<Arc<str>>,
+    projections_seen: usize,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<IR> {
+    let merge_sorted_by = match &lp {
+        IR::Union {
+            merge_sorted_by, ..
+        } => merge_sorted_by.clone(),
+        _ => unreachable!(),
+    };
+    match merge_sorted_by {
+        Some(by) if !acc_projections.is_empty() && !projected_names.contains(&by) => {
+            let mut pushdown = acc_projections.clone();
+            let mut pushdown_names = projected_names;
+            add_str_to_accumulated(&by, &mut pushdown, &mut pushdown_names, expr_arena);
+            let lp = process_generic(
+                proj_pd,
+                lp,
+                pushdown,
+                pushdown_names,
+                projections_seen,
+                lp_arena,
+                expr_arena,
+            )?;
+            let builder = IRBuilder::from_lp(lp, expr_arena, lp_arena);
+            Ok(proj_pd.finish_node_simple_projection(&acc_projections, builder))
+        },
+        _ => process_generic(
+            proj_pd,
+            lp,
+            acc_projections,
+            projected_names,
+            projections_seen,
+            lp_arena,
+            expr_arena,
+        ),
+    }
+}
@@ -16,7 +16,7 @@ use recursive::recursive;
 #[cfg(feature = "semi_anti_join")]
 use semi_anti_join::process_semi_anti_join;
 
-use crate::prelude::optimizer::projection_pushdown::generic::process_generic;
+use crate::prelude::optimizer::projection_pushdown::generic::{process_generic, process_union};
 use crate::prelude::optimizer::projection_pushdown::group_by::process_group_by;
 use crate::prelude::optimizer::projection_pushdown::hconcat::process_hconcat;
 use crate::prelude::optimizer::projection_pushdown::hstack::process_hstack;
@@ -407,15 +407,18 @@ impl ProjectionPushDown {
                 }
 
                 if do_optimization {
-                    file_options.with_columns = get_scan_columns(
+                    let with_columns = get_scan_columns(
                         &mut acc_projections,
                         expr_arena,
                         file_options.row_index.as_ref(),
                     );
 
-                    output_schema = if file_options.with_columns.is_none() {
-                        None
+                    // Without projections, keep the columns that were selected when the scan
+                    // was created.
+                    output_schema = if with_columns.is_none() {
+                        output_schema
                     } else {
+                        file_options.with_columns = with_columns;
                         let mut schema = update_scan_schema(
                             &acc_projections,
                             expr_arena,
@@ -504,6 +507,17 @@ impl ProjectionPushDown {
                             )
                         }
                     }
+                    // The columns that decide which duplicate is kept are needed as well.
+                    if let Some(order_by) = options.order_by.as_ref() {
+                        order_by.iter().for_each(|name| {
+                            add_str_to_accumulated(
+                                name,
+                                &mut acc_projections,
+                                &mut projected_names,
+                                expr_arena,
+                            )
+                        })
+                    }
                 }
 
                 self.pushdown_and_assign(
@@ -672,7 +686,7 @@ impl ProjectionPushDown {
                 lp_arena,
                 expr_arena,
             ),
-            lp @ Union { .. } => process_generic(
+            lp @ Union { .. } => process_union(
                 self,
                 lp,
                 acc_projections,
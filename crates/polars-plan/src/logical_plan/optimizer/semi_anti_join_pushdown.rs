@@ -0,0 +1,121 @@
+//! Push semi and anti joins below group-bys and other joins.
+//!
+//! A semi or anti join only filters the rows of its left input by the existence of their key in
+//! the right input. If the key columns are passed through unchanged from an input further down,
+//! filtering that input instead gives the same rows, and the operations in between run on fewer
+//! rows.
+use polars_ops::prelude::{JoinType, JoinValidation};
+use polars_utils::arena::{Arena, Node};
+
+use super::OptimizationRule;
+use crate::prelude::*;
+
+pub struct SemiAntiJoinPushDown {}
+
+/// The names of the columns if all `exprs` are plain columns.
+fn column_names<'a>(exprs: &[ExprIR], expr_arena: &'a Arena<AExpr>) -> Option<Vec<&'a str>> {
+    exprs
+        .iter()
+        .map(|e| match expr_arena.get(e.node()) {
+            AExpr::Column(name) if name.as_ref() == e.output_name() => Some(name.as_ref()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The position of the input of `node` that passes the columns `names` through unchanged, if
+/// any.
+fn pushdown_input(
+    node: Node,
+    names: &[&str],
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<usize> {
+    match lp_arena.get(node) {
+        // The rows of a group all have the same key, so filtering the groups by their key is the
+        // same as filtering the rows by their key.
+        IR::GroupBy {
+            keys,
+            apply: None,
+            options,
+            ..
+        } if options.slice.is_none() => {
+            // The windows of a dynamic group-by depend on the rows.
+            #[cfg(feature = "dynamic_group_by")]
+            if options.rolling.is_some() || options.dynamic.is_some() {
+                return None;
+            }
+            let keys = column_names(keys, expr_arena)?;
+            names.iter().all(|name| keys.contains(name)).then_some(0)
+        },
+        IR::Join {
+            input_left,
+            input_right,
+            schema,
+            options,
+            ..
+        } if options.args.slice.is_none()
+            && options.args.validation == JoinValidation::ManyToMany =>
+        {
+            let left_schema = lp_arena.get(*input_left).schema(lp_arena);
+            let right_schema = lp_arena.get(*input_right).schema(lp_arena);
+            // Columns of the left input keep their name in the output, and the columns of the
+            // right input too if they don't collide with a column of the left input.
+            let from_left = names.iter().all(|name| left_schema.contains(name));
+            let from_right = names
+                .iter()
+                .all(|name| !left_schema.contains(name) && right_schema.contains(name))
+                && names.iter().all(|name| schema.contains(name));
+            match options.args.how {
+                JoinType::Inner | JoinType::Left | JoinType::Cross if from_left => Some(0),
+                // Filtering the right input of a left join would turn matches into nulls.
+                JoinType::Inner | JoinType::Cross if from_right => Some(1),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+impl OptimizationRule for SemiAntiJoinPushDown {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<IR> {
+        let IR::Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options,
+            ..
+        } = lp_arena.get(node)
+        else {
+            return None;
+        };
+        if !matches!(options.args.how, JoinType::Semi | JoinType::Anti)
+            || options.args.slice.is_some()
+        {
+            return None;
+        }
+        let names = column_names(left_on, expr_arena)?;
+        let pos = pushdown_input(*input_left, &names, lp_arena, expr_arena)?;
+        let lp = lp_arena.get(*input_left).clone();
+        let mut inputs = lp.get_inputs_vec();
+        let below = inputs[pos];
+
+        // Join the input of the left input instead, and let the left input consume the result.
+        let join = IR::Join {
+            input_left: below,
+            input_right: *input_right,
+            schema: lp_arena.get(below).schema(lp_arena).into_owned(),
+            left_on: left_on.clone(),
+            right_on: right_on.clone(),
+            options: options.clone(),
+        };
+        inputs[pos] = lp_arena.add(join);
+        Some(lp.with_exprs_and_input(lp.get_exprs(), inputs))
+    }
+}
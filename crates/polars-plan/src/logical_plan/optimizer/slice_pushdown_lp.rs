@@ -204,7 +204,7 @@ impl SlicePushDown {
 
                 Ok(lp)
             }
-            (Union {mut inputs, mut options }, Some(state)) => {
+            (Union {mut inputs, mut options, merge_sorted_by }, Some(state)) => {
                 if state.offset == 0 {
                     for input in &mut inputs {
                         let input_lp = lp_arena.take(*input);
@@ -215,7 +215,7 @@ impl SlicePushDown {
                 // The in-memory union node is slice aware.
                 // We still set this information, but the streaming engine will ignore it.
                 options.slice = Some((state.offset, state.len as usize));
-                let lp = Union {inputs, options};
+                let lp = Union {inputs, options, merge_sorted_by};
 
                 if self.streaming {
                     // Ensure the slice node remains.
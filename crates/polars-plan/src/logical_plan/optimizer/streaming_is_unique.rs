@@ -0,0 +1,286 @@
+//! Rewrite `is_duplicated`, `is_unique` and `unique_counts` so that they can run on the
+//! streaming engine.
+//!
+//! These functions have to see the whole column before they can produce a single value. If
+//! their input is elementwise and the plan below them streams, a projection that uses
+//! `is_duplicated` or `is_unique` is rewritten in two passes over its input: a group-by counts
+//! the occurrences of every value and a left join adds these counts back onto the rows. Both
+//! passes read the same cached input, so it is only computed once. A `unique_counts`
+//! projection is rewritten into a group-by whose counts are ordered by the first occurrence of
+//! every value.
+use super::*;
+
+const KEY: &str = "__POLARS_UNIQUE_KEY";
+const COUNT: &str = "__POLARS_UNIQUE_COUNT";
+#[cfg(feature = "unique_counts")]
+const INDEX: &str = "__POLARS_UNIQUE_INDEX";
+
+pub(super) fn optimize(root: Node, lp_arena: &mut Arena<IR>, expr_arena: &mut Arena<AExpr>) {
+    let mut stack = vec![root];
+    let mut nodes = vec![];
+    let mut cache_id = 0;
+    while let Some(node) = stack.pop() {
+        let lp = lp_arena.get(node);
+        if let IR::Cache { id, .. } = lp {
+            cache_id = cache_id.max(*id + 1);
+        }
+        nodes.push(node);
+        lp.copy_inputs(&mut stack);
+    }
+    for node in nodes {
+        if !rewrite_unique_counts(node, lp_arena, expr_arena) {
+            rewrite_projection(node, lp_arena, expr_arena, &mut cache_id);
+        }
+    }
+}
+
+fn rewrite_projection(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    cache_id: &mut usize,
+) {
+    let (input, exprs) = match lp_arena.get(node) {
+        IR::Select { input, expr, .. } => (*input, expr),
+        IR::HStack { input, exprs, .. } => (*input, exprs),
+        _ => return,
+    };
+    if !exprs
+        .iter()
+        .any(|e| has_unique_function(e.node(), expr_arena))
+    {
+        return;
+    }
+    // Within a window the functions apply per group.
+    if exprs.iter().any(|e| has_aexpr_window(e.node(), expr_arena))
+        || !is_streamable_plan(input, lp_arena, expr_arena)
+    {
+        return;
+    }
+
+    // Replace the functions by a comparison of the counts that are joined onto the rows. The
+    // expressions are rebuilt, as their nodes may be shared with other parts of the plan.
+    let mut keys = vec![];
+    let new_exprs = exprs
+        .iter()
+        .map(|e| {
+            let expr = node_to_expr(e.node(), expr_arena).map_expr(|expr| match expr {
+                Expr::Function {
+                    input,
+                    function:
+                        FunctionExpr::Boolean(
+                            f @ (BooleanFunction::IsUnique | BooleanFunction::IsDuplicated),
+                        ),
+                    ..
+                } => {
+                    let count = col(&format!("{COUNT}_{}", keys.len()));
+                    keys.push(input[0].clone());
+                    if matches!(f, BooleanFunction::IsUnique) {
+                        count.eq(lit(1 as IdxSize))
+                    } else {
+                        count.gt(lit(1 as IdxSize))
+                    }
+                },
+                expr => expr,
+            });
+            let name = e.output_name_arc().clone();
+            ExprIR::new(to_aexpr(expr, expr_arena), OutputName::Alias(name))
+        })
+        .collect::<Vec<_>>();
+    // A function that is nested in another one can't be computed from the input.
+    let is_nested = |key: &Expr| {
+        key.into_iter()
+            .any(|e| matches!(e, Expr::Column(name) if name.starts_with(COUNT)))
+    };
+    if keys.is_empty() || keys.iter().any(is_nested) {
+        return;
+    }
+    let keys = keys
+        .into_iter()
+        .enumerate()
+        .map(|(i, key)| to_expr_ir(key.alias(&format!("{KEY}_{i}")), expr_arena))
+        .collect::<Vec<_>>();
+    // The rewrite only pays off if the projection can stream afterwards.
+    if !all_streamable(&keys, expr_arena, Context::Default)
+        || !all_streamable(&new_exprs, expr_arena, Context::Default)
+    {
+        return;
+    }
+
+    // All passes read the input from the same cache.
+    let id = *cache_id;
+    *cache_id += 1;
+    let cache = |input: Node, lp_arena: &mut Arena<IR>| {
+        lp_arena.add(IR::Cache {
+            input,
+            id,
+            cache_hits: keys.len() as u32,
+        })
+    };
+    let mut joined = cache(input, lp_arena);
+    joined = IRBuilder::new(joined, expr_arena, lp_arena)
+        .with_columns(keys.clone(), Default::default())
+        .node();
+    for (i, key) in keys.iter().enumerate() {
+        let input = copy_plan(input, lp_arena);
+        let input = cache(input, lp_arena);
+        let key_expr = vec![name_to_expr_ir(key.output_name(), expr_arena)];
+        let aggs = vec![to_expr_ir(len().alias(&format!("{COUNT}_{i}")), expr_arena)];
+        let counts = IRBuilder::new(input, expr_arena, lp_arena)
+            .with_columns(vec![key.clone()], Default::default())
+            .group_by(key_expr.clone(), aggs, None, false, Default::default())
+            .node();
+
+        let mut args = JoinArgs::new(JoinType::Left);
+        args.join_nulls = true;
+        let options = Arc::new(JoinOptions {
+            args,
+            ..Default::default()
+        });
+        joined = IRBuilder::new(joined, expr_arena, lp_arena)
+            .join(counts, key_expr.clone(), key_expr, options)
+            .node();
+    }
+
+    let schema = lp_arena.get(node).schema(lp_arena).into_owned();
+    let lp = match lp_arena.get(node) {
+        IR::Select { options, .. } => IR::Select {
+            input: joined,
+            expr: new_exprs,
+            schema,
+            options: *options,
+        },
+        IR::HStack { options, .. } => {
+            let options = *options;
+            let input = IRBuilder::new(joined, expr_arena, lp_arena)
+                .with_columns(new_exprs, options)
+                .node();
+            IR::SimpleProjection {
+                input,
+                columns: schema,
+            }
+        },
+        _ => unreachable!(),
+    };
+    lp_arena.replace(node, lp);
+}
+
+/// Rewrite a projection of a single `unique_counts` into a group-by, whose counts are sorted by
+/// the row index of the first occurrence of every value.
+#[cfg(feature = "unique_counts")]
+fn rewrite_unique_counts(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> bool {
+    let IR::Select { input, expr, .. } = lp_arena.get(node) else {
+        return false;
+    };
+    let [e] = expr.as_slice() else {
+        return false;
+    };
+    let (input, name) = (*input, e.output_name_arc().clone());
+    let key = match expr_arena.get(e.node()) {
+        AExpr::Function {
+            input: args,
+            function: FunctionExpr::UniqueCounts,
+            ..
+        } => args[0].node(),
+        _ => return false,
+    };
+    if !is_streamable(key, expr_arena, Context::Default)
+        || !is_streamable_plan(input, lp_arena, expr_arena)
+    {
+        return false;
+    }
+
+    let key = ExprIR::new(key, OutputName::Alias(ColumnName::from(KEY)));
+    let key_expr = vec![name_to_expr_ir(KEY, expr_arena)];
+    let aggs = vec![
+        to_expr_ir(len().alias(COUNT), expr_arena),
+        to_expr_ir(col(INDEX).first(), expr_arena),
+    ];
+    let counts = IRBuilder::new(input, expr_arena, lp_arena)
+        .row_index(ColumnName::from(INDEX), None)
+        .with_columns(vec![key], Default::default())
+        .group_by(key_expr, aggs, None, false, Default::default())
+        .node();
+    let sorted = lp_arena.add(IR::Sort {
+        input: counts,
+        by_column: vec![name_to_expr_ir(INDEX, expr_arena)],
+        slice: None,
+        sort_options: Default::default(),
+    });
+    let count = ExprIR::new(to_aexpr(col(COUNT), expr_arena), OutputName::Alias(name));
+    let lp = IRBuilder::new(sorted, expr_arena, lp_arena)
+        .project(vec![count], Default::default())
+        .build();
+    lp_arena.replace(node, lp);
+    true
+}
+
+#[cfg(not(feature = "unique_counts"))]
+fn rewrite_unique_counts(_: Node, _: &mut Arena<IR>, _: &mut Arena<AExpr>) -> bool {
+    false
+}
+
+fn has_unique_function(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    has_aexpr(node, expr_arena, |ae| {
+        matches!(
+            ae,
+            AExpr::Function {
+                function: FunctionExpr::Boolean(
+                    BooleanFunction::IsUnique | BooleanFunction::IsDuplicated
+                ),
+                ..
+            }
+        )
+    })
+}
+
+/// Whether the streaming engine can run the plan below `node` without falling back to
+/// in-memory execution.
+fn is_streamable_plan(node: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> bool {
+    let mut stack = vec![node];
+    while let Some(node) = stack.pop() {
+        let lp = lp_arena.get(node);
+        let streamable = match lp {
+            IR::Scan { scan_type, .. } => scan_type.streamable(),
+            IR::DataFrameScan { .. } | IR::SimpleProjection { .. } => true,
+            IR::Filter { predicate, .. } => {
+                is_streamable(predicate.node(), expr_arena, Context::Default)
+            },
+            IR::Select { expr, .. } | IR::HStack { exprs: expr, .. } => {
+                all_streamable(expr, expr_arena, Context::Default)
+            },
+            IR::Slice { offset, .. } => *offset >= 0,
+            IR::MapFunction { function, .. } => {
+                matches!(function, FunctionNode::Rechunk) || function.is_streamable()
+            },
+            IR::Union {
+                merge_sorted_by: None,
+                ..
+            } => true,
+            _ => false,
+        };
+        if !streamable {
+            return false;
+        }
+        lp.copy_inputs(&mut stack);
+    }
+    true
+}
+
+/// Copy the plan below `node`, so that it can be read by another cache.
+fn copy_plan(node: Node, lp_arena: &mut Arena<IR>) -> Node {
+    let lp = lp_arena.get(node);
+    let exprs = lp.get_exprs();
+    let mut inputs: Vec<Node> = vec![];
+    lp.copy_inputs(&mut inputs);
+    let inputs = inputs
+        .into_iter()
+        .map(|input| copy_plan(input, lp_arena))
+        .collect();
+    let lp = lp_arena.get(node).with_exprs_and_input(exprs, inputs);
+    lp_arena.add(lp)
+}
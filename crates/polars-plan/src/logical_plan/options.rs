@@ -1,3 +1,4 @@
+use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
 
 use polars_core::prelude::*;
@@ -31,6 +32,20 @@ pub struct FileScanOptions {
     pub rechunk: bool,
     pub file_counter: FileCount,
     pub hive_options: HiveOptions,
+    pub column_selection: Option<ColumnSelection>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// Restricts the columns of the files of a scan before any projection is applied.
+///
+/// A column is kept if it matches all of the given conditions. The row index and hive
+/// partition columns are always kept.
+pub struct ColumnSelection {
+    /// Keep the columns whose name contains a match of this regex.
+    pub columns_matching: Option<String>,
+    /// Keep the columns that have one of these data types.
+    pub dtypes: Option<Vec<DataType>>,
 }
 
 #[derive(Clone, Debug, Copy, Default, Eq, PartialEq, Hash)]
@@ -74,6 +89,9 @@ pub struct DistinctOptions {
     pub maintain_order: bool,
     /// Which rows to keep.
     pub keep_strategy: UniqueKeepStrategy,
+    /// Columns that decide which of the duplicate rows is kept: `First` keeps the row with
+    /// the smallest values and `Last` the row with the largest values.
+    pub order_by: Option<Arc<Vec<String>>>,
     /// Take only a slice of the result
     pub slice: Option<(i64, usize)>,
 }
@@ -206,6 +224,9 @@ pub struct PythonOptions {
     pub output_schema: Option<SchemaRef>,
     pub with_columns: Option<Arc<[String]>>,
     pub pyarrow: bool,
+    // a source registered with `register_io_source`, which receives the
+    // pushed-down polars predicate
+    pub is_io_plugin: bool,
     // a pyarrow predicate python expression
     // can be evaluated with python.eval
     pub predicate: Option<String>,
@@ -220,10 +241,56 @@ pub struct AnonymousScanOptions {
     pub fmt_str: &'static str,
 }
 
+/// A function that is called with every batch of a streaming query, see [`SinkType::Batches`].
+///
+/// Equality and hashing are by identity of the function.
+#[derive(Clone)]
+pub struct BatchCallback(pub Arc<dyn Fn(DataFrame) -> PolarsResult<()> + Send + Sync>);
+
+impl BatchCallback {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(DataFrame) -> PolarsResult<()> + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    pub fn call(&self, df: DataFrame) -> PolarsResult<()> {
+        (self.0)(df)
+    }
+}
+
+impl PartialEq for BatchCallback {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for BatchCallback {}
+
+impl Hash for BatchCallback {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).cast::<()>().hash(state)
+    }
+}
+
+impl std::fmt::Debug for BatchCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "batch_callback")
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum SinkType {
     Memory,
+    /// Call a function with batches of `chunk_size` rows, or with the morsels as they are
+    /// produced if no size is given.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Batches {
+        callback: BatchCallback,
+        chunk_size: Option<usize>,
+    },
     File {
         path: Arc<PathBuf>,
         file_type: FileType,
@@ -234,6 +301,8 @@ pub enum SinkType {
         file_type: FileType,
         cloud_options: Option<polars_io::cloud::CloudOptions>,
     },
+    /// Write the result to all of these sinks in a single pass of the query.
+    Multiple(Vec<SinkType>),
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -164,6 +164,7 @@ pub fn set_estimated_row_counts(
             if let Union {
                 inputs,
                 mut options,
+                merge_sorted_by,
             } = lp_arena.take(root)
             {
                 let mut sum_output = (None, 0usize);
@@ -178,7 +179,14 @@ pub fn set_estimated_row_counts(
                     sum_output.1 = sum_output.1.saturating_add(out.1);
                 }
                 options.rows = sum_output;
-                lp_arena.replace(root, Union { inputs, options });
+                lp_arena.replace(
+                    root,
+                    Union {
+                        inputs,
+                        options,
+                        merge_sorted_by,
+                    },
+                );
                 (sum_output.0, sum_output.1, 0)
             } else {
                 unreachable!()
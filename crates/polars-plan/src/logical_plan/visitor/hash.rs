@@ -168,7 +168,14 @@ impl Hash for HashableEqLP<'_> {
             IR::MapFunction { input: _, function } => {
                 function.hash(state);
             },
-            IR::Union { inputs: _, options } => options.hash(state),
+            IR::Union {
+                inputs: _,
+                options,
+                merge_sorted_by,
+            } => {
+                options.hash(state);
+                merge_sorted_by.hash(state);
+            },
             IR::HConcat {
                 inputs: _,
                 schema: _,
@@ -420,12 +427,14 @@ impl HashableEqLP<'_> {
                 IR::Union {
                     inputs: _,
                     options: l,
+                    merge_sorted_by: l_by,
                 },
                 IR::Union {
                     inputs: _,
                     options: r,
+                    merge_sorted_by: r_by,
                 },
-            ) => l == r,
+            ) => l == r && l_by == r_by,
             (
                 IR::HConcat {
                     inputs: _,
@@ -1,8 +1,13 @@
 use arrow::datatypes::ArrowDataType;
+use polars_error::{polars_bail, polars_ensure, polars_err, PolarsResult};
 
 use super::*;
-use crate::fixed::{decode_bool, decode_primitive};
-use crate::variable::{decode_binary, decode_binview};
+use crate::encode::encoded_size;
+use crate::fixed::{decode_bool, decode_primitive, get_null_sentinel};
+use crate::variable::{
+    decode_binary, decode_binview, BLOCK_CONTINUATION_TOKEN, BLOCK_SIZE, EMPTY_SENTINEL,
+    NON_EMPTY_SENTINEL,
+};
 
 /// Decode `rows` into a arrow format
 /// # Safety
@@ -38,6 +43,162 @@ pub unsafe fn decode_rows(
         .collect()
 }
 
+/// Decode `rows` into a arrow format, after validating that they are valid encodings.
+///
+/// Unlike [`decode_rows_from_binary`] this is safe to use on rows of untrusted origin, e.g.
+/// rows that are received from another process.
+pub fn decode_rows_from_binary_checked<'a>(
+    arr: &'a BinaryArray<i64>,
+    fields: &[EncodingField],
+    data_types: &[ArrowDataType],
+    rows: &mut Vec<&'a [u8]>,
+) -> PolarsResult<Vec<ArrayRef>> {
+    polars_ensure!(
+        arr.null_count() == 0,
+        ComputeError: "cannot decode rows that contain nulls"
+    );
+    rows.clear();
+    rows.extend(arr.values_iter());
+    decode_rows_checked(rows, fields, data_types)
+}
+
+/// Decode `rows` into a arrow format, after validating that they are valid encodings.
+///
+/// Every row must exactly contain the encodings of `data_types`: sentinels, block terminators,
+/// lengths and UTF-8 are all checked, so this is safe to use on rows of untrusted origin.
+pub fn decode_rows_checked(
+    // the rows will be updated while the data is decoded
+    rows: &mut [&[u8]],
+    fields: &[EncodingField],
+    data_types: &[ArrowDataType],
+) -> PolarsResult<Vec<ArrayRef>> {
+    polars_ensure!(
+        fields.len() == data_types.len(),
+        ComputeError: "expected as many encoding fields as data types, got {} and {}",
+        fields.len(), data_types.len()
+    );
+    for (data_type, field) in data_types.iter().zip(fields) {
+        check_data_type(data_type, field)?;
+    }
+
+    let mut scratch = vec![];
+    for (i, row) in rows.iter().enumerate() {
+        let mut row = *row;
+        for (data_type, field) in data_types.iter().zip(fields) {
+            validate(&mut row, field, data_type, &mut scratch).map_err(
+                |msg| polars_err!(ComputeError: "invalid encoding of row {}: {}", i, msg),
+            )?;
+        }
+        polars_ensure!(
+            row.is_empty(),
+            ComputeError: "invalid encoding of row {}: {} trailing bytes", i, row.len()
+        );
+    }
+    // SAFETY: we just validated the encodings.
+    Ok(unsafe { decode_rows(rows, fields, data_types) })
+}
+
+/// Check that rows of `data_type` can be decoded at all.
+fn check_data_type(data_type: &ArrowDataType, field: &EncodingField) -> PolarsResult<()> {
+    use ArrowDataType::*;
+    match data_type {
+        Null
+        | Boolean
+        | Int8
+        | Int16
+        | Int32
+        | Int64
+        | UInt8
+        | UInt16
+        | UInt32
+        | UInt64
+        | Float32
+        | Float64
+        | Decimal(_, _) => Ok(()),
+        BinaryView | LargeBinary | Utf8View | LargeUtf8 => {
+            // Without order the values are not terminated.
+            polars_ensure!(
+                !field.no_order,
+                ComputeError: "cannot decode variable length values that are encoded without order"
+            );
+            Ok(())
+        },
+        Struct(fields) => fields
+            .iter()
+            .try_for_each(|struct_fld| check_data_type(struct_fld.data_type(), field)),
+        dt => polars_bail!(ComputeError: "cannot decode rows of data type {:?}", dt),
+    }
+}
+
+/// Validate the encoding of a single value at the start of `row` and advance `row` past it.
+fn validate(
+    row: &mut &[u8],
+    field: &EncodingField,
+    data_type: &ArrowDataType,
+    scratch: &mut Vec<u8>,
+) -> Result<(), &'static str> {
+    use ArrowDataType::*;
+    let null_sentinel = get_null_sentinel(field);
+    match data_type {
+        Null => Ok(()),
+        BinaryView | LargeBinary => validate_variable(row, field, null_sentinel, scratch),
+        Utf8View | LargeUtf8 => {
+            validate_variable(row, field, null_sentinel, scratch)?;
+            std::str::from_utf8(scratch).map_err(|_| "string is not valid UTF-8")?;
+            Ok(())
+        },
+        Struct(fields) => fields
+            .iter()
+            .try_for_each(|struct_fld| validate(row, field, struct_fld.data_type(), scratch)),
+        dt => {
+            let len = encoded_size(dt);
+            let value = row.get(..len).ok_or("unexpected end of row")?;
+            if value[0] != 1 && value[0] != null_sentinel {
+                return Err("invalid null sentinel");
+            }
+            *row = &row[len..];
+            Ok(())
+        },
+    }
+}
+
+/// Validate a variable length value and write its (still encoded) bytes to `scratch`.
+fn validate_variable(
+    row: &mut &[u8],
+    field: &EncodingField,
+    null_sentinel: u8,
+    scratch: &mut Vec<u8>,
+) -> Result<(), &'static str> {
+    let invert = |byte: u8| if field.descending { !byte } else { byte };
+    scratch.clear();
+
+    let sentinel = *row.first().ok_or("unexpected end of row")?;
+    *row = &row[1..];
+    if sentinel == null_sentinel || sentinel == invert(EMPTY_SENTINEL) {
+        return Ok(());
+    }
+    if sentinel != invert(NON_EMPTY_SENTINEL) {
+        return Err("invalid sentinel");
+    }
+    loop {
+        let block = row
+            .get(..BLOCK_SIZE + 1)
+            .ok_or("unexpected end of variable length value")?;
+        *row = &row[BLOCK_SIZE + 1..];
+        let token = invert(block[BLOCK_SIZE]);
+        if token == BLOCK_CONTINUATION_TOKEN {
+            scratch.extend(block[..BLOCK_SIZE].iter().map(|&byte| invert(byte)));
+            continue;
+        }
+        let len = token as usize;
+        if len == 0 || len > BLOCK_SIZE {
+            return Err("invalid block length");
+        }
+        scratch.extend(block[..len].iter().map(|&byte| invert(byte)));
+        return Ok(());
+    }
+}
+
 unsafe fn decode(rows: &mut [&[u8]], field: &EncodingField, data_type: &ArrowDataType) -> ArrayRef {
     match data_type {
         ArrowDataType::Null => NullArray::new(ArrowDataType::Null, rows.len()).to_boxed(),
@@ -73,3 +234,56 @@ unsafe fn decode(rows: &mut [&[u8]], field: &EncodingField, data_type: &ArrowDat
         },
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::encode::convert_columns;
+
+    #[test]
+    fn test_decode_rows_checked() {
+        let a = Int32Array::from([Some(1), None, Some(-3)]);
+        let long = "a string that is longer than a single block of 32 bytes";
+        let b = Utf8ViewArray::from_slice([Some(long), Some(""), None]);
+        let dtypes = [ArrowDataType::Int32, ArrowDataType::Utf8View];
+
+        for (descending, nulls_last) in [(false, false), (true, false), (true, true)] {
+            let fields = [EncodingField::new_sorted(descending, nulls_last); 2];
+            let encoded = convert_columns(&[a.clone().boxed(), b.clone().boxed()], &fields);
+            let mut rows = encoded.iter().collect::<Vec<_>>();
+            let out = decode_rows_checked(&mut rows, &fields, &dtypes).unwrap();
+            assert_eq!(out[0].as_ref(), &a as &dyn Array);
+            assert_eq!(out[1].as_ref(), &b as &dyn Array);
+        }
+
+        let fields = [EncodingField::new_sorted(false, false); 2];
+        let encoded = convert_columns(&[a.clone().boxed(), b.boxed()], &fields);
+        let row = encoded.get(0);
+        let check = |row: &[u8], dtypes: &[ArrowDataType]| {
+            let fields = &fields[..dtypes.len()];
+            decode_rows_checked(&mut [row], fields, dtypes)
+        };
+        assert!(check(row, &dtypes).is_ok());
+        // Truncated, trailing and corrupted rows.
+        assert!(check(&row[..row.len() - 1], &dtypes).is_err());
+        assert!(check(&row[..3], &dtypes[..1]).is_err());
+        assert!(check(row, &dtypes[..1]).is_err());
+        let mut corrupted = row.to_vec();
+        corrupted[0] = 7;
+        assert!(check(&corrupted, &dtypes).is_err());
+        let mut corrupted = row.to_vec();
+        *corrupted.last_mut().unwrap() = BLOCK_SIZE as u8 + 1;
+        assert!(check(&corrupted, &dtypes).is_err());
+
+        // Binary values that are no valid UTF-8.
+        let invalid = BinaryViewArray::from_slice_values([[0xC3u8, 0x28]]);
+        let encoded = convert_columns(&[invalid.boxed()], &fields[..1]);
+        let binary = [ArrowDataType::BinaryView];
+        assert!(check(encoded.get(0), &binary).is_ok());
+        assert!(check(encoded.get(0), &[ArrowDataType::Utf8View]).is_err());
+
+        // Values without order are not terminated.
+        let unordered = [EncodingField::new_unsorted()];
+        assert!(decode_rows_checked(&mut [encoded.get(0)], &unordered, &binary).is_err());
+    }
+}
@@ -6,7 +6,7 @@ use polars_lazy::prelude::*;
 use polars_ops::frame::JoinCoalesce;
 use polars_plan::prelude::*;
 use sqlparser::ast::{
-    Distinct, ExcludeSelectItem, Expr as SQLExpr, FunctionArg, GroupByExpr, JoinConstraint,
+    Distinct, ExcludeSelectItem, Expr as SQLExpr, FunctionArg, GroupByExpr, Insert, JoinConstraint,
     JoinOperator, ObjectName, ObjectType, Offset, OrderByExpr, Query, Select, SelectItem, SetExpr,
     SetOperator, SetQuantifier, Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator,
     Value as SQLValue, Values, WildcardAdditionalOptions,
@@ -109,33 +109,64 @@ impl SQLContext {
     /// # }
     ///```
     pub fn execute(&mut self, query: &str) -> PolarsResult<LazyFrame> {
-        let mut parser = Parser::new(&GenericDialect);
-        parser = parser.with_options(ParserOptions {
-            trailing_commas: true,
-            ..Default::default()
-        });
+        let ast = parse_statements(query)?;
+        polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
+        self.execute_top_level_statement(ast.first().unwrap())
+    }
 
-        let ast = parser
-            .try_with_sql(query)
-            .map_err(to_sql_interface_err)?
-            .parse_statements()
-            .map_err(to_sql_interface_err)?;
+    /// Execute a script of `;`-separated SQL statements in order, returning the result of the
+    /// last one.
+    ///
+    /// Tables created or modified by a statement, e.g. with `CREATE TABLE ... AS` or
+    /// `INSERT INTO`, are visible to the statements that follow it.
+    ///
+    /// ```rust
+    /// # use polars_sql::SQLContext;
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn main() {
+    ///
+    /// let mut ctx = SQLContext::new();
+    /// ctx.register("t", df! { "a" => [1, 2] }.unwrap().lazy());
+    /// let sql_df = ctx
+    ///     .execute_many(
+    ///         "CREATE TABLE u AS SELECT a * 10 AS a FROM t;
+    ///          INSERT INTO u SELECT a FROM t;
+    ///          SELECT SUM(a) AS a FROM u;",
+    ///     )
+    ///     .unwrap()
+    ///     .collect()
+    ///     .unwrap();
+    /// assert_eq!(sql_df.column("a").unwrap().i32().unwrap().get(0), Some(33));
+    /// # }
+    ///```
+    pub fn execute_many(&mut self, queries: &str) -> PolarsResult<LazyFrame> {
+        let ast = parse_statements(queries)?;
+        polars_ensure!(!ast.is_empty(), SQLInterface: "no SQL statements to execute");
+        let mut res = None;
+        for stmt in &ast {
+            res = Some(self.execute_top_level_statement(stmt)?);
+        }
+        Ok(res.unwrap())
+    }
 
-        polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
-        let res = self.execute_statement(ast.first().unwrap())?;
+    fn execute_top_level_statement(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let res = self.execute_statement(stmt);
 
         // Ensure the result uses the proper arenas.
         // This will instantiate new arenas with a new version.
         let lp_arena = std::mem::take(&mut self.lp_arena);
         let expr_arena = std::mem::take(&mut self.expr_arena);
-        res.set_cached_arena(lp_arena, expr_arena);
+        if let Ok(res) = &res {
+            res.set_cached_arena(lp_arena, expr_arena);
+        }
 
         // Every execution should clear the statement-level maps.
         self.cte_map.borrow_mut().clear();
         self.table_aliases.borrow_mut().clear();
         self.joined_aliases.borrow_mut().clear();
 
-        Ok(res)
+        res
     }
 
     /// add a function registry to the SQLContext
@@ -163,6 +194,7 @@ impl SQLContext {
             Statement::Query(query) => self.execute_query(query)?,
             stmt @ Statement::ShowTables { .. } => self.execute_show_tables(stmt)?,
             stmt @ Statement::CreateTable { .. } => self.execute_create_table(stmt)?,
+            stmt @ Statement::Insert(_) => self.execute_insert_into(stmt)?,
             stmt @ Statement::Drop {
                 object_type: ObjectType::Table,
                 ..
@@ -328,33 +360,32 @@ impl SQLContext {
         };
         let mut lf = self.process_set_expr(left, query)?;
         let mut rf = self.process_set_expr(right, query)?;
-        let join = lf
-            .clone()
-            .join_builder()
-            .with(rf.clone())
-            .how(join_type)
-            .join_nulls(true);
 
         let lf_schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
-        let lf_cols: Vec<_> = lf_schema.iter_names().map(|nm| col(nm)).collect();
-        let joined_tbl = match quantifier {
-            SetQuantifier::ByName | SetQuantifier::AllByName => {
+        let distinct = match quantifier {
+            SetQuantifier::ByName | SetQuantifier::AllByName | SetQuantifier::DistinctByName => {
                 // note: 'BY NAME' is pending https://github.com/sqlparser-rs/sqlparser-rs/pull/1309
-                join.on(lf_cols).finish()
-            },
-            SetQuantifier::Distinct | SetQuantifier::None => {
-                let rf_schema = rf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
-                let rf_cols: Vec<_> = rf_schema.iter_names().map(|nm| col(nm)).collect();
-                if lf_cols.len() != rf_cols.len() {
-                    polars_bail!(SQLInterface: "{} requires equal number of columns in each table (use '{} BY NAME' to combine mismatched tables)", op_name, op_name)
-                }
-                join.left_on(lf_cols).right_on(rf_cols).finish()
-            },
-            _ => {
-                polars_bail!(SQLInterface: "'{} {}' is not supported", op_name, quantifier.to_string())
+                let lf_cols: Vec<_> = lf_schema.iter_names().map(|nm| col(nm)).collect();
+                let joined_tbl = lf
+                    .join_builder()
+                    .with(rf)
+                    .how(join_type)
+                    .join_nulls(true)
+                    .on(lf_cols)
+                    .finish();
+                return Ok(joined_tbl.unique(None, UniqueKeepStrategy::Any));
             },
+            SetQuantifier::Distinct | SetQuantifier::None => true,
+            SetQuantifier::All => false,
         };
-        Ok(joined_tbl.unique(None, UniqueKeepStrategy::Any))
+        let rf_schema = rf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+        if lf_schema.len() != rf_schema.len() {
+            polars_bail!(SQLInterface: "{} requires equal number of columns in each table (use '{} BY NAME' to combine mismatched tables)", op_name, op_name)
+        }
+        match join_type {
+            JoinType::Anti => lf.except(rf, distinct),
+            _ => lf.intersect(rf, distinct),
+        }
     }
 
     fn process_union(
@@ -864,6 +895,86 @@ impl SQLContext {
         }
     }
 
+    fn execute_insert_into(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Insert(Insert {
+            table_name,
+            columns,
+            source,
+            overwrite,
+            ..
+        }) = stmt
+        else {
+            unreachable!()
+        };
+        if *overwrite {
+            polars_bail!(SQLInterface: "INSERT OVERWRITE is not supported");
+        }
+        let tbl_name = table_name.to_string();
+        let Some(mut lf) = self.table_map.get(&tbl_name).cloned() else {
+            polars_bail!(SQLInterface: "relation '{}' does not exist", tbl_name);
+        };
+        let Some(query) = source else {
+            polars_bail!(SQLInterface: "INSERT INTO requires a query or VALUES");
+        };
+        let mut new = self.execute_query(query)?;
+
+        let schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+        let new_schema = new.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+        let target_cols: Vec<&str> = if columns.is_empty() {
+            schema.iter_names().map(|name| name.as_str()).collect()
+        } else {
+            columns.iter().map(|ident| ident.value.as_str()).collect()
+        };
+        polars_ensure!(
+            target_cols.len() == new_schema.len(),
+            SQLInterface: "INSERT INTO '{}' expected {} columns, got {}",
+            tbl_name, target_cols.len(), new_schema.len()
+        );
+        let mut seen = PlHashSet::with_capacity(target_cols.len());
+        for name in &target_cols {
+            polars_ensure!(
+                schema.contains(name),
+                ColumnNotFound: "column '{}' does not exist in relation '{}'", name, tbl_name
+            );
+            polars_ensure!(
+                seen.insert(*name),
+                SQLInterface: "column '{}' is specified more than once in INSERT INTO '{}'",
+                name, tbl_name
+            );
+        }
+
+        // The inserted columns are matched by position, the omitted ones are null.
+        let mut exprs = Vec::with_capacity(schema.len());
+        for (name, dtype) in schema.iter() {
+            let expr = match target_cols.iter().position(|col| *col == name.as_str()) {
+                Some(idx) => {
+                    let (new_name, new_dtype) = new_schema.get_at_index(idx).unwrap();
+                    // Integer columns only take integers, as casting other numbers would
+                    // truncate them. Values out of range fail the strict cast.
+                    polars_ensure!(
+                        new_dtype == dtype
+                            || new_dtype == &DataType::Null
+                            || (new_dtype.is_numeric()
+                                && dtype.is_numeric()
+                                && (new_dtype.is_integer() || !dtype.is_integer())),
+                        SchemaMismatch: "cannot insert {} values into column '{}' of type {}",
+                        new_dtype, name, dtype
+                    );
+                    col(new_name).strict_cast(dtype.clone())
+                },
+                None => lit(NULL).cast(dtype.clone()),
+            };
+            exprs.push(expr.alias(name));
+        }
+        lf = polars_lazy::dsl::concat(vec![lf, new.select(exprs)], UnionArgs::default())?;
+        self.table_map.insert(tbl_name, lf);
+        Ok(df! {
+            "Response" => ["Insert Into"]
+        }
+        .unwrap()
+        .lazy())
+    }
+
     fn get_table(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
         match relation {
             TableFactor::Table {
@@ -1228,3 +1339,16 @@ impl SQLContext {
         }
     }
 }
+
+fn parse_statements(query: &str) -> PolarsResult<Vec<Statement>> {
+    let mut parser = Parser::new(&GenericDialect);
+    parser = parser.with_options(ParserOptions {
+        trailing_commas: true,
+        ..Default::default()
+    });
+    parser
+        .try_with_sql(query)
+        .map_err(to_sql_interface_err)?
+        .parse_statements()
+        .map_err(to_sql_interface_err)
+}
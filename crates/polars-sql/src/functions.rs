@@ -1,9 +1,12 @@
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
-use polars_core::prelude::{polars_bail, polars_err, DataType, PolarsResult};
+use polars_core::prelude::{polars_bail, polars_ensure, polars_err, DataType, PolarsResult};
 use polars_lazy::dsl::Expr;
 #[cfg(feature = "list_eval")]
 use polars_lazy::dsl::ListNameSpaceExtension;
-use polars_plan::dsl::{coalesce, concat_str, len, max_horizontal, min_horizontal, when};
+use polars_plan::dsl::{
+    arg_sort_by, coalesce, concat_str, int_range, len, max_horizontal, min_horizontal, when,
+    WindowFrame,
+};
 use polars_plan::logical_plan::{typed_lit, LiteralValue};
 #[cfg(feature = "list_eval")]
 use polars_plan::prelude::col;
@@ -12,7 +15,7 @@ use polars_plan::prelude::{lit, StrptimeOptions};
 use sqlparser::ast::{
     DateTimeField, DuplicateTreatment, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
     FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments, Ident,
-    OrderByExpr, Value as SQLValue, WindowSpec, WindowType,
+    OrderByExpr, Value as SQLValue, WindowFrameBound, WindowFrameUnits, WindowType,
 };
 
 use crate::sql_expr::{parse_extract_date_part, parse_sql_expr};
@@ -496,6 +499,31 @@ pub(crate) enum PolarsSQLFunctions {
     /// ```
     Variance,
 
+    // ----
+    // Window functions
+    // ----
+    /// SQL 'lag' function
+    /// Returns the value of the row that is `offset` rows (default 1) before the current row
+    /// in the window, or `default` (default NULL) if there is no such row.
+    /// ```sql
+    /// SELECT LAG(column_1) OVER (PARTITION BY column_2 ORDER BY column_3) from df;
+    /// SELECT LAG(column_1, 2, 0) OVER (ORDER BY column_3) from df;
+    /// ```
+    Lag,
+    /// SQL 'lead' function
+    /// Returns the value of the row that is `offset` rows (default 1) after the current row
+    /// in the window, or `default` (default NULL) if there is no such row.
+    /// ```sql
+    /// SELECT LEAD(column_1) OVER (PARTITION BY column_2 ORDER BY column_3) from df;
+    /// ```
+    Lead,
+    /// SQL 'row_number' function
+    /// Returns the number of the current row in the window, starting at 1.
+    /// ```sql
+    /// SELECT ROW_NUMBER() OVER (PARTITION BY column_1 ORDER BY column_2) from df;
+    /// ```
+    RowNumber,
+
     // ----
     // Array functions
     // ----
@@ -623,7 +651,9 @@ impl PolarsSQLFunctions {
             "if",
             "ifnull",
             "initcap",
+            "lag",
             "last",
+            "lead",
             "least",
             "left",
             "length",
@@ -649,6 +679,7 @@ impl PolarsSQLFunctions {
             "reverse",
             "right",
             "round",
+            "row_number",
             "rtrim",
             "sign",
             "sin",
@@ -774,6 +805,13 @@ impl PolarsSQLFunctions {
             "sum" => Self::Sum,
             "var" | "variance" | "var_samp" => Self::Variance,
 
+            // ----
+            // Window functions
+            // ----
+            "lag" => Self::Lag,
+            "lead" => Self::Lead,
+            "row_number" => Self::RowNumber,
+
             // ----
             // Array functions
             // ----
@@ -817,6 +855,16 @@ impl SQLFunctionVisitor<'_> {
         if function.null_treatment.is_some() {
             polars_bail!(SQLInterface: "'IGNORE|RESPECT NULLS' is not currently supported")
         }
+        // Only the aggregations that evaluate over the rows of a frame accept one, the other
+        // functions would silently ignore it.
+        if let Some(WindowType::WindowSpec(spec)) = &function.over {
+            if spec.window_frame.is_some() && !matches!(function_name, Avg | Max | Min | Sum) {
+                polars_bail!(
+                    SQLInterface: "window frames are not supported for function '{}'",
+                    function.name
+                )
+            }
+        }
 
         match function_name {
             // ----
@@ -1132,7 +1180,10 @@ impl SQLFunctionVisitor<'_> {
             // ----
             // Aggregate functions
             // ----
-            Avg => self.visit_unary(Expr::mean),
+            Avg => self.visit_unary_with_opt_cumulative(Expr::mean, |e, reverse| {
+                e.clone().cum_sum(reverse).cast(DataType::Float64)
+                    / e.cum_count(reverse).cast(DataType::Float64)
+            }),
             Count => self.visit_count(),
             First => self.visit_unary(Expr::first),
             Last => self.visit_unary(Expr::last),
@@ -1143,6 +1194,20 @@ impl SQLFunctionVisitor<'_> {
             Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum),
             Variance => self.visit_unary(|e| e.var(1)),
 
+            // ----
+            // Window functions
+            // ----
+            Lag => self.visit_lag_lead(false),
+            Lead => self.visit_lag_lead(true),
+            RowNumber => {
+                let args = extract_args(function)?;
+                polars_ensure!(
+                    args.is_empty(),
+                    SQLSyntax: "ROW_NUMBER does not take arguments; found {}", args.len()
+                );
+                self.apply_ordered_window(|_| int_range(lit(0), len(), 1, DataType::Int64) + lit(1))
+            },
+
             // ----
             // Array functions
             // ----
@@ -1190,61 +1255,25 @@ impl SQLFunctionVisitor<'_> {
     /// e.g. SUM(a) OVER (ORDER BY b DESC) -> CUMSUM(a, false)
     /// visit_unary_with_cumulative_window will take in a function & a cumulative function
     /// if there is a cumulative window spec, it will apply the cumulative function,
-    /// otherwise it will apply the function
+    /// otherwise it will apply the function.
+    /// An explicit `ROWS BETWEEN` frame evaluates the function over the rows of the frame.
     fn visit_unary_with_opt_cumulative(
         &mut self,
         f: impl Fn(Expr) -> Expr,
         cumulative_f: impl Fn(Expr, bool) -> Expr,
     ) -> PolarsResult<Expr> {
+        if let Some(frame) = self.rows_window_frame()? {
+            let expr = self.visit_unary_no_window(f)?;
+            return self.apply_framed_window(expr, frame);
+        }
         match self.func.over.as_ref() {
-            Some(WindowType::WindowSpec(spec)) => {
-                self.apply_cumulative_window(f, cumulative_f, spec)
+            Some(WindowType::WindowSpec(spec)) if !spec.order_by.is_empty() => {
+                let expr = self.visit_unary_no_window(|e| e)?;
+                self.apply_ordered_window(|sort| cumulative_f(sort(expr), false))
             },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
-            ),
             _ => self.visit_unary(f),
         }
     }
-    /// Window specs without partition bys are essentially cumulative functions
-    /// e.g. SUM(a) OVER (ORDER BY b DESC) -> CUMSUM(a, false)
-    fn apply_cumulative_window(
-        &mut self,
-        f: impl Fn(Expr) -> Expr,
-        cumulative_f: impl Fn(Expr, bool) -> Expr,
-        WindowSpec {
-            partition_by,
-            order_by,
-            ..
-        }: &WindowSpec,
-    ) -> PolarsResult<Expr> {
-        if !order_by.is_empty() && partition_by.is_empty() {
-            let (order_by, desc): (Vec<Expr>, Vec<bool>) = order_by
-                .iter()
-                .map(|o| {
-                    let expr = parse_sql_expr(&o.expr, self.ctx, None)?;
-                    Ok(match o.asc {
-                        Some(b) => (expr, !b),
-                        None => (expr, false),
-                    })
-                })
-                .collect::<PolarsResult<Vec<_>>>()?
-                .into_iter()
-                .unzip();
-            self.visit_unary_no_window(|e| {
-                cumulative_f(
-                    e.sort_by(
-                        &order_by,
-                        SortMultipleOptions::default().with_order_descending_multi(desc.clone()),
-                    ),
-                    false,
-                )
-            })
-        } else {
-            self.visit_unary(f)
-        }
-    }
 
     fn visit_unary_no_window(&mut self, f: impl Fn(Expr) -> Expr) -> PolarsResult<Expr> {
         let args = extract_args(self.func)?;
@@ -1413,6 +1442,14 @@ impl SQLFunctionVisitor<'_> {
     }
 
     fn apply_order_by(&mut self, expr: Expr, order_by: &[OrderByExpr]) -> PolarsResult<Expr> {
+        let (by, options) = self.parse_order_by(order_by)?;
+        Ok(expr.sort_by(by, options))
+    }
+
+    fn parse_order_by(
+        &mut self,
+        order_by: &[OrderByExpr],
+    ) -> PolarsResult<(Vec<Expr>, SortMultipleOptions)> {
         let mut by = Vec::with_capacity(order_by.len());
         let mut descending = Vec::with_capacity(order_by.len());
         let mut nulls_last = Vec::with_capacity(order_by.len());
@@ -1425,13 +1462,132 @@ impl SQLFunctionVisitor<'_> {
             nulls_last.push(!ob.nulls_first.unwrap_or(desc_order));
             descending.push(desc_order);
         }
-        Ok(expr.sort_by(
-            by,
-            SortMultipleOptions::default()
-                .with_order_descending_multi(descending)
-                .with_nulls_last_multi(nulls_last)
-                .with_maintain_order(true),
-        ))
+        let options = SortMultipleOptions::default()
+            .with_order_descending_multi(descending)
+            .with_nulls_last_multi(nulls_last)
+            .with_maintain_order(true);
+        Ok((by, options))
+    }
+
+    /// Evaluate a window function on the rows of every partition in the order of the
+    /// `ORDER BY` clause of the window, and return the results in the original row order.
+    ///
+    /// `f` receives a function that sorts an expression in the order of the window,
+    /// e.g. `LAG(a) OVER (ORDER BY b)` shifts `a` after sorting it by `b`.
+    fn apply_ordered_window(
+        &mut self,
+        f: impl FnOnce(&dyn Fn(Expr) -> Expr) -> Expr,
+    ) -> PolarsResult<Expr> {
+        let func = self.func;
+        let (partition_by, order_by) = match &func.over {
+            Some(WindowType::WindowSpec(spec)) => {
+                (spec.partition_by.as_slice(), spec.order_by.as_slice())
+            },
+            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
+                SQLInterface: "Named windows are not currently supported; found {:?}",
+                named_window
+            ),
+            None => (&[][..], &[][..]),
+        };
+
+        let expr = if order_by.is_empty() {
+            f(&|e| e)
+        } else {
+            let (by, options) = self.parse_order_by(order_by)?;
+            // The position of every row in the order of the window.
+            let position = arg_sort_by(&by, options.clone()).arg_sort(SortOptions::default());
+            f(&|e| e.sort_by(&by, options.clone())).gather(position)
+        };
+        if partition_by.is_empty() {
+            Ok(expr)
+        } else {
+            let partition_by = partition_by
+                .iter()
+                .map(|p| parse_sql_expr(p, self.ctx, None))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            Ok(expr.over(partition_by))
+        }
+    }
+
+    /// The frame of the window if it is an explicit `ROWS BETWEEN` frame.
+    fn rows_window_frame(&mut self) -> PolarsResult<Option<WindowFrame>> {
+        let func = self.func;
+        let frame = match &func.over {
+            Some(WindowType::WindowSpec(spec)) => match &spec.window_frame {
+                Some(frame) => frame,
+                None => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+        polars_ensure!(
+            frame.units == WindowFrameUnits::Rows,
+            SQLInterface: "only ROWS window frames are supported; found {}",
+            frame.units
+        );
+        let preceding = parse_frame_rows(&frame.start_bound, true, self.ctx)?;
+        let following = match &frame.end_bound {
+            Some(bound) => parse_frame_rows(bound, false, self.ctx)?,
+            None => Some(0),
+        };
+        Ok(Some(WindowFrame {
+            preceding,
+            following,
+        }))
+    }
+
+    /// Evaluate an aggregation over the `frame` of every row, in the order of the `ORDER BY`
+    /// clause of the window.
+    fn apply_framed_window(&mut self, expr: Expr, frame: WindowFrame) -> PolarsResult<Expr> {
+        let func = self.func;
+        let Some(WindowType::WindowSpec(spec)) = &func.over else {
+            unreachable!()
+        };
+        polars_ensure!(
+            !spec.order_by.is_empty(),
+            SQLSyntax: "a ROWS window frame requires an ORDER BY clause"
+        );
+        let partition_by = spec
+            .partition_by
+            .iter()
+            .map(|p| parse_sql_expr(p, self.ctx, None))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let (mut by, options) = self.parse_order_by(&spec.order_by)?;
+        let (by, sort_options) = if by.len() == 1 {
+            let sort_options = SortOptions::default()
+                .with_order_descending(options.descending[0])
+                .with_nulls_last(options.nulls_last[0]);
+            (by.pop().unwrap(), sort_options)
+        } else {
+            // Order by the position of every row in the order of all the columns.
+            let position = arg_sort_by(&by, options).arg_sort(SortOptions::default());
+            (position, SortOptions::default())
+        };
+        Ok(expr.over_frame(partition_by, (vec![by], sort_options), frame))
+    }
+
+    fn visit_lag_lead(&mut self, lead: bool) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let (expr, offset, default) = match args.as_slice() {
+            [FunctionArgExpr::Expr(expr)] => (expr, 1, None),
+            [FunctionArgExpr::Expr(expr), FunctionArgExpr::Expr(offset)] => {
+                (expr, i64::from_sql_expr(offset, self.ctx)?, None)
+            },
+            [FunctionArgExpr::Expr(expr), FunctionArgExpr::Expr(offset), FunctionArgExpr::Expr(default)] =>
+            {
+                let offset = i64::from_sql_expr(offset, self.ctx)?;
+                (expr, offset, Some(parse_sql_expr(default, self.ctx, None)?))
+            },
+            _ => {
+                let name = if lead { "LEAD" } else { "LAG" };
+                polars_bail!(SQLSyntax: "invalid number of arguments for {} ({})", name, args.len())
+            },
+        };
+        let expr = parse_sql_expr(expr, self.ctx, None)?;
+        let n = lit(if lead { -offset } else { offset });
+        self.apply_ordered_window(|sort| match default {
+            Some(default) => sort(expr).shift_and_fill(n, default),
+            None => sort(expr).shift(n),
+        })
     }
 
     fn apply_window_spec(
@@ -1480,6 +1636,33 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
+/// The number of rows of a bound of a `ROWS` window frame, where `None` is unbounded.
+///
+/// The frame has to contain the current row, so the start can't follow it and the end can't
+/// precede it.
+fn parse_frame_rows(
+    bound: &WindowFrameBound,
+    start: bool,
+    ctx: &mut SQLContext,
+) -> PolarsResult<Option<usize>> {
+    let n = match bound {
+        WindowFrameBound::CurrentRow => return Ok(Some(0)),
+        WindowFrameBound::Preceding(n) if start => n,
+        WindowFrameBound::Following(n) if !start => n,
+        _ => polars_bail!(
+            SQLInterface: "window frames that don't contain the current row are not supported; found {}",
+            bound
+        ),
+    };
+    n.as_ref()
+        .map(|n| {
+            let n = i64::from_sql_expr(n, ctx)?;
+            polars_ensure!(n >= 0, SQLSyntax: "window frame offset must be non-negative; found {}", n);
+            Ok(n as usize)
+        })
+        .transpose()
+}
+
 fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
     let (args, _, _) = _extract_func_args(func, false, false)?;
     Ok(args)
@@ -1553,6 +1736,20 @@ impl FromSQLExpr for f64 {
     }
 }
 
+impl FromSQLExpr for i64 {
+    fn from_sql_expr(expr: &SQLExpr, _ctx: &mut SQLContext) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        match expr {
+            SQLExpr::Value(SQLValue::Number(s, _)) => s
+                .parse()
+                .map_err(|_| polars_err!(SQLInterface: "cannot parse integer literal {:?}", s)),
+            _ => polars_bail!(SQLInterface: "cannot parse integer literal {:?}", expr),
+        }
+    }
+}
+
 impl FromSQLExpr for bool {
     fn from_sql_expr(expr: &SQLExpr, _ctx: &mut SQLContext) -> PolarsResult<Self>
     where
@@ -1,42 +1,51 @@
 use std::str::FromStr;
 
+use polars_core::prelude::{polars_bail, PolarsError, PolarsResult};
 #[cfg(any(
     feature = "csv",
     feature = "parquet",
     feature = "ipc",
     feature = "json"
 ))]
-use polars_core::prelude::polars_ensure;
-use polars_core::prelude::{polars_bail, PolarsError, PolarsResult};
+use polars_core::prelude::{polars_ensure, polars_err};
+#[cfg(any(feature = "csv", feature = "json"))]
+use polars_io::HiveOptions;
 #[cfg(feature = "csv")]
 use polars_lazy::prelude::LazyCsvReader;
 use polars_lazy::prelude::LazyFrame;
-use sqlparser::ast::{FunctionArg, FunctionArgExpr};
+use sqlparser::ast::{Expr as SQLExpr, FunctionArg, FunctionArgExpr, Value as SQLValue};
 
 /// Table functions that are supported by Polars
+///
+/// Besides the path of the file, every function takes named options for the scan, like
+/// `n_rows => 100`.
 #[allow(clippy::enum_variant_names)]
 pub(crate) enum PolarsTableFunctions {
     /// SQL 'read_csv' function
     /// ```sql
     /// SELECT * FROM read_csv('path/to/file.csv')
+    /// SELECT * FROM read_csv('path/to/file.csv', has_header => false, separator => ';')
     /// ```
     #[cfg(feature = "csv")]
     ReadCsv,
     /// SQL 'read_parquet' function
     /// ```sql
     /// SELECT * FROM read_parquet('path/to/file.parquet')
+    /// SELECT * FROM read_parquet('s3://bucket/*.parquet', hive_partitioning => true)
     /// ```
     #[cfg(feature = "parquet")]
     ReadParquet,
     /// SQL 'read_ipc' function
     /// ```sql
     /// SELECT * FROM read_ipc('path/to/file.ipc')
+    /// SELECT * FROM read_ipc('path/to/file.ipc', memory_map => false)
     /// ```
     #[cfg(feature = "ipc")]
     ReadIpc,
     /// SQL 'read_json' function. *Only ndjson is currently supported.*
     /// ```sql
     /// SELECT * FROM read_json('path/to/file.json')
+    /// SELECT * FROM read_json('path/to/file.json', infer_schema_length => 1000)
     /// ```
     #[cfg(feature = "json")]
     ReadJson,
@@ -79,49 +88,139 @@ impl PolarsTableFunctions {
 
     #[cfg(feature = "csv")]
     fn read_csv(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_csv` expects a single file path; found {:?} arguments", args.len());
-
+        use polars_io::csv::read::NullValues;
         use polars_lazy::frame::LazyFileListReader;
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyCsvReader::new(&path)
+
+        let (path, options) = self.get_file_path_and_options("read_csv", args)?;
+        let mut reader = LazyCsvReader::new(&path)
             .with_try_parse_dates(true)
-            .with_missing_is_null(true)
-            .finish()?;
+            .with_missing_is_null(true);
+        for (name, value) in options {
+            reader = match name {
+                "has_header" => reader.with_has_header(bool_option(name, value)?),
+                "separator" => reader.with_separator(char_option(name, value)?),
+                "quote_char" => reader.with_quote_char(Some(char_option(name, value)?)),
+                "null_value" => reader.with_null_values(Some(NullValues::AllColumnsSingle(
+                    string_option(name, value)?,
+                ))),
+                "skip_rows" => reader.with_skip_rows(usize_option(name, value)?),
+                "n_rows" => reader.with_n_rows(Some(usize_option(name, value)?)),
+                "infer_schema_length" => {
+                    reader.with_infer_schema_length(Some(usize_option(name, value)?))
+                },
+                "ignore_errors" => reader.with_ignore_errors(bool_option(name, value)?),
+                "try_parse_dates" => reader.with_try_parse_dates(bool_option(name, value)?),
+                "glob" => reader.with_glob(bool_option(name, value)?),
+                "hive_partitioning" => reader.with_hive_options(HiveOptions {
+                    enabled: bool_option(name, value)?,
+                    schema: None,
+                }),
+                _ => return Err(unsupported_option("read_csv", name)),
+            };
+        }
+        let lf = reader.finish()?;
         Ok((path, lf))
     }
 
     #[cfg(feature = "parquet")]
     fn read_parquet(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_parquet` expects a single file path; found {:?} arguments", args.len());
+        use polars_lazy::prelude::ScanArgsParquet;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_parquet(&path, Default::default())?;
+        let (path, options) = self.get_file_path_and_options("read_parquet", args)?;
+        let mut scan_args = ScanArgsParquet::default();
+        for (name, value) in options {
+            match name {
+                "n_rows" => scan_args.n_rows = Some(usize_option(name, value)?),
+                "hive_partitioning" => scan_args.hive_options.enabled = bool_option(name, value)?,
+                "glob" => scan_args.glob = bool_option(name, value)?,
+                "use_statistics" => scan_args.use_statistics = bool_option(name, value)?,
+                "low_memory" => scan_args.low_memory = bool_option(name, value)?,
+                "rechunk" => scan_args.rechunk = bool_option(name, value)?,
+                _ => return Err(unsupported_option("read_parquet", name)),
+            }
+        }
+        let lf = LazyFrame::scan_parquet(&path, scan_args)?;
         Ok((path, lf))
     }
 
     #[cfg(feature = "ipc")]
     fn read_ipc(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_ipc` expects a single file path; found {:?} arguments", args.len());
+        use polars_lazy::prelude::ScanArgsIpc;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_ipc(&path, Default::default())?;
+        let (path, options) = self.get_file_path_and_options("read_ipc", args)?;
+        let mut scan_args = ScanArgsIpc::default();
+        for (name, value) in options {
+            match name {
+                "n_rows" => scan_args.n_rows = Some(usize_option(name, value)?),
+                "memory_map" => scan_args.memory_map = bool_option(name, value)?,
+                "rechunk" => scan_args.rechunk = bool_option(name, value)?,
+                _ => return Err(unsupported_option("read_ipc", name)),
+            }
+        }
+        let lf = LazyFrame::scan_ipc(&path, scan_args)?;
         Ok((path, lf))
     }
+
     #[cfg(feature = "json")]
     fn read_ndjson(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_ndjson` expects a single file path; found {:?} arguments", args.len());
-
         use polars_lazy::frame::LazyFileListReader;
         use polars_lazy::prelude::LazyJsonLineReader;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyJsonLineReader::new(path.clone()).finish()?;
+        let (path, options) = self.get_file_path_and_options("read_json", args)?;
+        let mut reader = LazyJsonLineReader::new(path.clone());
+        for (name, value) in options {
+            reader = match name {
+                "n_rows" => reader.with_n_rows(Some(usize_option(name, value)?)),
+                "infer_schema_length" => {
+                    reader.with_infer_schema_length(Some(usize_option(name, value)?))
+                },
+                "ignore_errors" => reader.with_ignore_errors(bool_option(name, value)?),
+                "hive_partitioning" => reader.with_hive_options(HiveOptions {
+                    enabled: bool_option(name, value)?,
+                    schema: None,
+                }),
+                _ => return Err(unsupported_option("read_json", name)),
+            };
+        }
+        let lf = reader.finish()?;
         Ok((path, lf))
     }
 
+    /// Split the arguments of the table function `name` into the file path, which must be the
+    /// only unnamed argument, and the named options, like `has_header => false`.
+    #[cfg(any(
+        feature = "csv",
+        feature = "parquet",
+        feature = "ipc",
+        feature = "json"
+    ))]
+    fn get_file_path_and_options<'a>(
+        &self,
+        name: &str,
+        args: &'a [FunctionArg],
+    ) -> PolarsResult<(String, Vec<(&'a str, &'a SQLExpr)>)> {
+        let (unnamed, named): (Vec<_>, Vec<_>) = args
+            .iter()
+            .partition(|arg| matches!(arg, FunctionArg::Unnamed(_)));
+        polars_ensure!(unnamed.len() == 1, SQLSyntax: "`{}` expects a single file path; found {:?} arguments", name, unnamed.len());
+
+        let path = self.get_file_path_from_arg(unnamed[0])?;
+        let options = named
+            .into_iter()
+            .map(|arg| match arg {
+                FunctionArg::Named {
+                    name: option,
+                    arg: FunctionArgExpr::Expr(value),
+                    ..
+                } => Ok((option.value.as_str(), value)),
+                _ => polars_bail!(SQLSyntax: "invalid option for `{}`: {}", name, arg),
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok((path, options))
+    }
+
     #[allow(dead_code)]
     fn get_file_path_from_arg(&self, arg: &FunctionArg) -> PolarsResult<String> {
-        use sqlparser::ast::{Expr as SQLExpr, Value as SQLValue};
         match arg {
             FunctionArg::Unnamed(FunctionArgExpr::Expr(SQLExpr::Value(
                 SQLValue::SingleQuotedString(s),
@@ -134,6 +233,62 @@ impl PolarsTableFunctions {
     }
 }
 
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json"
+))]
+fn unsupported_option(function: &str, option: &str) -> PolarsError {
+    polars_err!(SQLInterface: "`{}` does not support the `{}` option", function, option)
+}
+
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json"
+))]
+fn bool_option(name: &str, value: &SQLExpr) -> PolarsResult<bool> {
+    match value {
+        SQLExpr::Value(SQLValue::Boolean(b)) => Ok(*b),
+        _ => polars_bail!(SQLSyntax: "option `{}` expects a boolean; found: {}", name, value),
+    }
+}
+
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json"
+))]
+fn usize_option(name: &str, value: &SQLExpr) -> PolarsResult<usize> {
+    match value {
+        SQLExpr::Value(SQLValue::Number(n, _)) => n.parse::<usize>().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        polars_err!(SQLSyntax: "option `{}` expects a non-negative integer; found: {}", name, value)
+    })
+}
+
+#[cfg(feature = "csv")]
+fn string_option(name: &str, value: &SQLExpr) -> PolarsResult<String> {
+    match value {
+        SQLExpr::Value(SQLValue::SingleQuotedString(s)) => Ok(s.clone()),
+        _ => {
+            polars_bail!(SQLSyntax: "option `{}` expects a single-quoted string; found: {}", name, value)
+        },
+    }
+}
+
+#[cfg(feature = "csv")]
+fn char_option(name: &str, value: &SQLExpr) -> PolarsResult<u8> {
+    let s = string_option(name, value)?;
+    polars_ensure!(s.len() == 1, SQLSyntax: "option `{}` expects a single character; found: {}", name, value);
+    Ok(s.as_bytes()[0])
+}
+
 impl PolarsTableFunctions {
     // list sql names of all table functions
     pub(crate) fn keywords() -> &'static [&'static str] {
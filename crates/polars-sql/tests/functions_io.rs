@@ -1,8 +1,8 @@
-#[cfg(any(feature = "csv", feature = "ipc"))]
+#[cfg(any(feature = "csv", feature = "ipc", feature = "parquet"))]
 use polars_core::prelude::*;
-#[cfg(any(feature = "csv", feature = "ipc"))]
+#[cfg(any(feature = "csv", feature = "ipc", feature = "parquet"))]
 use polars_lazy::prelude::*;
-#[cfg(any(feature = "csv", feature = "ipc"))]
+#[cfg(any(feature = "csv", feature = "ipc", feature = "parquet"))]
 use polars_sql::*;
 
 #[test]
@@ -87,6 +87,47 @@ fn read_parquet_tbl() {
     assert_eq!(df_2.width(), 4);
 }
 
+#[test]
+#[cfg(feature = "csv")]
+fn read_csv_tbl_func_options() {
+    let mut context = SQLContext::new();
+    let sql = r#"
+            SELECT *
+            FROM read_csv('../../examples/datasets/foods1.csv', has_header => false, n_rows => 3)"#;
+    let df_sql = context.execute(sql).unwrap().collect().unwrap();
+
+    let expected = LazyCsvReader::new("../../examples/datasets/foods1.csv")
+        .with_has_header(false)
+        .with_n_rows(Some(3))
+        .finish()
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(df_sql.equals(&expected));
+    assert_eq!(
+        df_sql.get_column_names(),
+        ["column_1", "column_2", "column_3", "column_4"]
+    );
+
+    let sql = "SELECT * FROM read_csv('../../examples/datasets/foods1.csv', header => false)";
+    let err = context.execute(sql).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("`read_csv` does not support the `header` option"));
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn read_parquet_tbl_options() {
+    let mut context = SQLContext::new();
+    let sql = r#"
+            SELECT *
+            FROM read_parquet('../../examples/datasets/foods1.parquet', n_rows => 5, rechunk => true)"#;
+    let df_sql = context.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(df_sql.height(), 5);
+    assert_eq!(df_sql.width(), 4);
+}
+
 #[test]
 #[cfg(feature = "ipc")]
 fn read_ipc_tbl() {
@@ -0,0 +1,95 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+      "idx" => [0, 1, 2, 3, 4, 5],
+      "Country" => ["US", "UK", "US", "UK", "US", "UK"],
+      "Year" => [2020, 2019, 2018, 2018, 2019, 2020],
+      "Sales" => [1000, 2000, 3000, 4000, 5000, 6000]
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df);
+    ctx
+}
+
+fn execute(sql: &str) -> DataFrame {
+    create_ctx().execute(sql).unwrap().collect().unwrap()
+}
+
+#[test]
+fn test_row_number() {
+    let df = execute(
+        r#"
+        SELECT
+          ROW_NUMBER() OVER (PARTITION BY Country ORDER BY Year) AS rn,
+          ROW_NUMBER() OVER (ORDER BY Sales DESC) AS rn_all,
+          ROW_NUMBER() OVER () AS rn_none
+        FROM df
+        "#,
+    );
+    let expected = df! {
+        "rn" => [3i64, 2, 1, 1, 2, 3],
+        "rn_all" => [6i64, 5, 4, 3, 2, 1],
+        "rn_none" => [1i64, 2, 3, 4, 5, 6],
+    }
+    .unwrap();
+    assert!(df.equals(&expected));
+}
+
+#[test]
+fn test_lag_lead() {
+    let df = execute(
+        r#"
+        SELECT
+          LAG(Sales) OVER (PARTITION BY Country ORDER BY Year) AS lag,
+          LEAD(Sales, 1, 0) OVER (PARTITION BY Country ORDER BY Year) AS lead,
+          LAG(Sales, 2) OVER (ORDER BY idx) AS lag2
+        FROM df
+        "#,
+    );
+    let expected = df! {
+        "lag" => [Some(5000), Some(4000), None, None, Some(3000), Some(2000)],
+        "lead" => [0, 6000, 5000, 2000, 1000, 0],
+        "lag2" => [None, None, Some(1000), Some(2000), Some(3000), Some(4000)],
+    }
+    .unwrap();
+    assert!(df.equals_missing(&expected));
+}
+
+#[test]
+fn test_running_aggregates() {
+    let df = execute(
+        r#"
+        SELECT
+          SUM(Sales) OVER (PARTITION BY Country ORDER BY Year) AS sum,
+          MAX(Sales) OVER (PARTITION BY Country ORDER BY Year DESC) AS max,
+          AVG(Sales) OVER (ORDER BY idx) AS avg,
+          SUM(Sales) OVER (PARTITION BY Country) AS total
+        FROM df
+        "#,
+    );
+    let expected = df! {
+        "sum" => [9000, 6000, 3000, 4000, 8000, 12000],
+        "max" => [1000, 6000, 5000, 6000, 5000, 6000],
+        "avg" => [1000.0, 1500.0, 2000.0, 2500.0, 3000.0, 3500.0],
+        "total" => [9000, 12000, 9000, 12000, 9000, 12000],
+    }
+    .unwrap();
+    assert!(df.equals(&expected));
+}
+
+#[test]
+fn test_window_function_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "SELECT ROW_NUMBER(Sales) OVER (ORDER BY Year) FROM df",
+        "SELECT LAG(Sales, Year) OVER (ORDER BY Year) FROM df",
+        "SELECT LEAD() OVER (ORDER BY Year) FROM df",
+    ] {
+        assert!(ctx.execute(sql).is_err());
+    }
+}
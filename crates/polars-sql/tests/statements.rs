@@ -161,6 +161,43 @@ fn test_union_all() {
     assert!(actual.equals(&expected));
 }
 
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_except_intersect_all() {
+    let df1 = df![
+        "a" => [1, 1, 1, 2],
+        "b" => ["x", "x", "x", "y"]
+    ]
+    .unwrap();
+    let df2 = df![
+        "a" => [1, 2, 2],
+        "b" => ["x", "y", "y"]
+    ]
+    .unwrap();
+
+    let mut ctx = SQLContext::new();
+    ctx.register("test", df1.lazy());
+    ctx.register("test2", df2.lazy());
+
+    let sql = "SELECT * FROM test EXCEPT ALL SELECT * FROM test2";
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df![
+        "a" => [1, 1],
+        "b" => ["x", "x"]
+    ]
+    .unwrap();
+    assert!(actual.equals(&expected));
+
+    let sql = "SELECT * FROM test INTERSECT ALL SELECT * FROM test2";
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df![
+        "a" => [1, 2],
+        "b" => ["x", "y"]
+    ]
+    .unwrap();
+    assert!(actual.equals(&expected));
+}
+
 #[test]
 fn test_drop_table() {
     let mut ctx = create_ctx();
@@ -588,3 +625,48 @@ fn test_compound_invalid_3() {
     let sql = "SELECT * FROM df1 INNER JOIN df2 ON df1.a = df2.a AND b";
     ctx.execute(sql).unwrap().collect().unwrap();
 }
+
+#[test]
+fn test_insert_into() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        CREATE TABLE tbl AS SELECT a, b FROM df WHERE b < 3;
+        INSERT INTO tbl VALUES (10, 20);
+        INSERT INTO tbl (b) SELECT b FROM df WHERE b = 9;
+    "#;
+    ctx.execute_many(sql).unwrap();
+
+    let actual = ctx.execute("SELECT * FROM tbl").unwrap().collect().unwrap();
+    let expected = df! {
+        "a" => [Some(0i64), Some(0), Some(10), None],
+        "b" => [1i64, 2, 20, 9],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected));
+}
+
+#[test]
+fn test_insert_into_invalid() {
+    let mut ctx = create_ctx();
+    ctx.execute("CREATE TABLE tbl AS SELECT * FROM df").unwrap();
+    for sql in [
+        "INSERT INTO missing VALUES (1, 2)",
+        "INSERT INTO tbl VALUES (1)",
+        "INSERT INTO tbl (c) VALUES (1)",
+        "INSERT INTO tbl VALUES ('x', 1)",
+        "INSERT INTO tbl VALUES (1.5, 2)",
+        "INSERT INTO tbl (a, a) VALUES (1, 2)",
+        "INSERT INTO tbl (a, b) SELECT a, b + 0.5 FROM df",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+    // A failed statement leaves the table untouched.
+    let actual = ctx.execute("SELECT * FROM tbl").unwrap().collect().unwrap();
+    assert_eq!(actual.height(), 9);
+}
+
+#[test]
+fn test_execute_many_empty() {
+    let mut ctx = create_ctx();
+    assert!(ctx.execute_many("").is_err());
+}
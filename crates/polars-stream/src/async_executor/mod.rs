@@ -5,13 +5,17 @@ use std::cell::{Cell, UnsafeCell};
 use std::future::Future;
 use std::marker::PhantomData;
 use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, OnceLock, Weak};
+use std::task::{Context, Poll, Waker};
 
 use crossbeam_deque::{Injector, Steal, Stealer, Worker as WorkQueue};
 use crossbeam_utils::CachePadded;
 use park_group::ParkGroup;
 use parking_lot::Mutex;
+use pin_project_lite::pin_project;
+use polars_utils::pool::query_num_threads;
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
 use slotmap::SlotMap;
@@ -226,6 +230,77 @@ impl Executor {
     }
 }
 
+/// Limits how many tasks of a scope are polled at the same time.
+struct PollLimit {
+    max_running: usize,
+    state: Mutex<PollLimitState>,
+}
+
+#[derive(Default)]
+struct PollLimitState {
+    running: usize,
+    waiting: Vec<Waker>,
+}
+
+impl PollLimit {
+    fn try_acquire(&self, waker: &Waker) -> bool {
+        let mut state = self.state.lock();
+        if state.running < self.max_running {
+            state.running += 1;
+            true
+        } else {
+            state.waiting.push(waker.clone());
+            false
+        }
+    }
+
+    fn release(&self) {
+        let waker = {
+            let mut state = self.state.lock();
+            state.running -= 1;
+            state.waiting.pop()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// Releases the permit of a poll, also if it panics.
+struct PollPermit<'a>(&'a PollLimit);
+
+impl Drop for PollPermit<'_> {
+    fn drop(&mut self) {
+        self.0.release();
+    }
+}
+
+pin_project! {
+    struct Limited<F> {
+        #[pin]
+        fut: F,
+        limit: Option<Arc<PollLimit>>,
+    }
+}
+
+impl<F: Future> Future for Limited<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        let this = self.project();
+        let _permit = match this.limit {
+            Some(limit) => {
+                if !limit.try_acquire(cx.waker()) {
+                    return Poll::Pending;
+                }
+                Some(PollPermit(limit))
+            },
+            None => None,
+        };
+        this.fut.poll(cx)
+    }
+}
+
 pub struct TaskScope<'scope, 'env: 'scope> {
     // Keep track of in-progress tasks so we can forcibly cancel them
     // when the scope ends, to ensure the lifetimes are respected.
@@ -234,6 +309,10 @@ pub struct TaskScope<'scope, 'env: 'scope> {
     cancel_handles: Mutex<SlotMap<TaskKey, CancelHandle>>,
     completed_tasks: Arc<Mutex<Vec<TaskKey>>>,
 
+    // The executor threads are shared by all queries, so a query that is limited to a number
+    // of threads only has that many of its tasks running at the same time.
+    poll_limit: Option<Arc<PollLimit>>,
+
     // Copied from std::thread::scope. Necessary to prevent unsoundness.
     scope: PhantomData<&'scope mut &'scope ()>,
     env: PhantomData<&'env mut &'env ()>,
@@ -265,6 +344,10 @@ impl<'scope, 'env> TaskScope<'scope, 'env> {
     {
         self.clear_completed_tasks();
 
+        let fut = Limited {
+            fut,
+            limit: self.poll_limit.clone(),
+        };
         let mut runnable = None;
         let mut join_handle = None;
         self.cancel_handles.lock().insert_with_key(|task_key| {
@@ -302,6 +385,12 @@ where
     let scope = TaskScope {
         cancel_handles: Mutex::default(),
         completed_tasks: Arc::new(Mutex::default()),
+        poll_limit: query_num_threads().map(|max_running| {
+            Arc::new(PollLimit {
+                max_running,
+                state: Mutex::default(),
+            })
+        }),
         scope: PhantomData,
         env: PhantomData,
     };
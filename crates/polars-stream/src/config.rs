@@ -0,0 +1,29 @@
+use polars_error::{polars_err, PolarsResult};
+use polars_utils::pool::query_memory_soft_limit;
+use polars_utils::sys::MEMINFO;
+
+pub(crate) static MEMORY_LIMIT: &str = "POLARS_STREAMING_MEMORY_LIMIT";
+
+/// The number of bytes an operator may buffer in memory before it spills to disk.
+///
+/// This can be set with the `POLARS_STREAMING_MEMORY_LIMIT` environment variable and
+/// defaults to half of the memory that is free when the query starts. It never exceeds the
+/// memory soft limit of the query.
+pub fn get_memory_limit() -> PolarsResult<usize> {
+    let limit = if let Ok(val) = std::env::var(MEMORY_LIMIT) {
+        val.parse()
+            .map_err(|_| polars_err!(ComputeError: "could not parse '{}' env var", MEMORY_LIMIT))?
+    } else {
+        MEMINFO.free() as usize / 2
+    };
+    Ok(match query_memory_soft_limit() {
+        Some(soft_limit) => limit.min(soft_limit),
+        None => limit,
+    })
+}
+
+/// The directory that operators spill to.
+pub(crate) fn get_base_temp_dir() -> String {
+    std::env::var("POLARS_TEMP_DIR")
+        .unwrap_or_else(|_| std::env::temp_dir().to_string_lossy().into_owned())
+}
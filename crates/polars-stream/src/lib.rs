@@ -12,6 +12,7 @@ pub use skeleton::run_query;
 
 use crate::nodes::ComputeNode;
 
+mod config;
 mod execute;
 mod graph;
 mod morsel;
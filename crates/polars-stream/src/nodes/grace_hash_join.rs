@@ -0,0 +1,599 @@
+//! An equi-join that partitions both of its inputs on the hash of the join keys.
+//!
+//! Rows with equal keys always end up in the same partition, so every partition can be joined
+//! on its own. While the inputs are received, the largest partitions are spilled to disk as soon
+//! as the buffered data exceeds the memory limit. This way only a single partition of both sides
+//! has to fit in memory at once, which allows joins that are larger than the available memory.
+//!
+//! A partition that is still larger than the memory limit is split again with another hash, up
+//! to a maximum depth. Partitions that can't be split, e.g. because all of their rows have the
+//! same key, are joined in memory.
+use std::fs::File;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use ahash::RandomState;
+use parking_lot::Mutex;
+use polars_core::frame::DataFrame;
+use polars_core::hashing::_df_rows_to_hashes_threaded_vertical;
+use polars_core::prelude::{IdxSize, SchemaRef};
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_error::{polars_err, PolarsResult};
+use polars_expr::state::ExecutionState;
+use polars_io::ipc::{IpcReader, IpcWriter};
+use polars_io::{SerReader, SerWriter};
+use polars_ops::frame::{DataFrameJoinOps, JoinArgs};
+use polars_utils::hashing::hash_to_partition;
+
+use super::ComputeNode;
+use crate::async_executor::{JoinHandle, TaskScope};
+use crate::async_primitives::pipe::{Receiver, Sender};
+use crate::async_primitives::wait_group::{WaitGroup, WaitToken};
+use crate::morsel::{Morsel, MorselSeq, IDEAL_MORSEL_SIZE};
+
+const NUM_PARTITIONS: usize = 64;
+const MAX_RECURSION_DEPTH: usize = 3;
+
+static SPILL_DIR_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Default)]
+struct PartitionSide {
+    in_memory: Vec<DataFrame>,
+    spilled: Vec<PathBuf>,
+    /// The estimated size of the in-memory and the spilled frames.
+    size: usize,
+}
+
+impl PartitionSide {
+    fn is_empty(&self) -> bool {
+        self.in_memory.is_empty() && self.spilled.is_empty()
+    }
+
+    /// The frames of this side, reading the spilled frames back one at a time.
+    fn into_frames(self) -> impl Iterator<Item = PolarsResult<DataFrame>> {
+        self.in_memory
+            .into_iter()
+            .map(Ok)
+            .chain(self.spilled.into_iter().map(|path| {
+                let file = File::open(&path)?;
+                let df = IpcReader::new(file).set_rechunk(false).finish();
+                let _ = std::fs::remove_file(path);
+                df
+            }))
+    }
+}
+
+#[derive(Default)]
+struct Partition {
+    /// The left and right side of this partition.
+    sides: [PartitionSide; 2],
+    in_memory_size: usize,
+}
+
+impl Partition {
+    fn is_empty(&self) -> bool {
+        self.sides.iter().all(|side| side.is_empty())
+    }
+}
+
+struct Partitions {
+    partitions: Vec<Partition>,
+    in_memory_size: usize,
+}
+
+impl Default for Partitions {
+    fn default() -> Self {
+        Self {
+            partitions: (0..NUM_PARTITIONS).map(|_| Partition::default()).collect(),
+            in_memory_size: 0,
+        }
+    }
+}
+
+impl Partitions {
+    fn insert(&mut self, side: usize, parts: Vec<(usize, DataFrame)>) {
+        for (p, part) in parts {
+            let size = part.estimated_size();
+            let partition = &mut self.partitions[p];
+            partition.sides[side].in_memory.push(part);
+            partition.sides[side].size += size;
+            partition.in_memory_size += size;
+            self.in_memory_size += size;
+        }
+    }
+
+    /// Take the in-memory frames of the largest partition out, so that they can be spilled.
+    fn take_largest(&mut self) -> (usize, [Vec<DataFrame>; 2]) {
+        let (p, partition) = self
+            .partitions
+            .iter_mut()
+            .enumerate()
+            .max_by_key(|(_, partition)| partition.in_memory_size)
+            .unwrap();
+        self.in_memory_size -= std::mem::take(&mut partition.in_memory_size);
+        let frames = partition
+            .sides
+            .each_mut()
+            .map(|side| std::mem::take(&mut side.in_memory));
+        (p, frames)
+    }
+
+    fn add_spilled(&mut self, p: usize, paths: [Option<PathBuf>; 2]) {
+        for (side, path) in self.partitions[p].sides.iter_mut().zip(paths) {
+            side.spilled.extend(path);
+        }
+    }
+}
+
+pub struct GraceHashJoinNode {
+    left_on: Vec<Arc<str>>,
+    right_on: Vec<Arc<str>>,
+    args: JoinArgs,
+    schemas: [SchemaRef; 2],
+    memory_limit: usize,
+    /// The hash that assigns the partitions at every depth.
+    random_states: Vec<RandomState>,
+    partitions: Mutex<Partitions>,
+    spill_dir: Mutex<Option<PathBuf>>,
+    next_spill_file: AtomicUsize,
+    // Every pipeline waits on its own wait group, for which every pipeline holds a token
+    // until it has received all of its input.
+    wait_groups: Vec<WaitGroup>,
+    wait_tokens: Mutex<Vec<Vec<WaitToken>>>,
+    next_partition: AtomicUsize,
+}
+
+impl GraceHashJoinNode {
+    pub fn new(
+        left_on: Vec<Arc<str>>,
+        right_on: Vec<Arc<str>>,
+        args: JoinArgs,
+        left_schema: SchemaRef,
+        right_schema: SchemaRef,
+        memory_limit: usize,
+    ) -> Self {
+        Self {
+            left_on,
+            right_on,
+            args,
+            schemas: [left_schema, right_schema],
+            memory_limit,
+            random_states: (0..=MAX_RECURSION_DEPTH)
+                .map(|_| RandomState::new())
+                .collect(),
+            partitions: Mutex::default(),
+            spill_dir: Mutex::default(),
+            next_spill_file: AtomicUsize::new(0),
+            wait_groups: Vec::new(),
+            wait_tokens: Mutex::default(),
+            next_partition: AtomicUsize::new(0),
+        }
+    }
+
+    /// Split `df` on the hash of its keys into the partitions it belongs to.
+    fn split(
+        &self,
+        side: usize,
+        df: &DataFrame,
+        random_state: &RandomState,
+    ) -> PolarsResult<Vec<(usize, DataFrame)>> {
+        let keys = if side == 0 {
+            df.select(self.left_on.iter().map(|s| s.as_ref()))?
+        } else {
+            df.select(self.right_on.iter().map(|s| s.as_ref()))?
+        };
+        let (hashes, _) =
+            _df_rows_to_hashes_threaded_vertical(&[keys], Some(random_state.clone()))?;
+
+        let mut partition_idx = vec![Vec::new(); NUM_PARTITIONS];
+        for (i, h) in hashes[0].into_no_null_iter().enumerate() {
+            partition_idx[hash_to_partition(h, NUM_PARTITIONS)].push(i as IdxSize);
+        }
+        Ok(partition_idx
+            .iter()
+            .enumerate()
+            .filter(|(_, idx)| !idx.is_empty())
+            // SAFETY: the indices are in bounds of `df`.
+            .map(|(p, idx)| (p, unsafe { df._take_unchecked_slice(idx, false) }))
+            .collect())
+    }
+
+    /// Add the rows of `df` to the partitions of `side`, spilling the largest partitions to
+    /// disk until the buffered data fits the memory limit.
+    fn insert(&self, side: usize, df: DataFrame) -> PolarsResult<()> {
+        let parts = self.split(side, &df, &self.random_states[0])?;
+        let mut partitions = self.partitions.lock();
+        partitions.insert(side, parts);
+        while partitions.in_memory_size > self.memory_limit {
+            let (p, frames) = partitions.take_largest();
+            // Don't hold the lock while writing.
+            drop(partitions);
+            let paths = self.spill(frames)?;
+            partitions = self.partitions.lock();
+            partitions.add_spilled(p, paths);
+        }
+        Ok(())
+    }
+
+    /// Write the frames of both sides of a partition to disk.
+    fn spill(&self, frames: [Vec<DataFrame>; 2]) -> PolarsResult<[Option<PathBuf>; 2]> {
+        let spill_dir = self.spill_dir()?;
+        let [left, right] = frames.map(|frames| -> PolarsResult<Option<PathBuf>> {
+            if frames.is_empty() {
+                return Ok(None);
+            }
+            let file_idx = self.next_spill_file.fetch_add(1, Ordering::Relaxed);
+            let path = spill_dir.join(format!("{file_idx}.ipc"));
+            let mut df = accumulate_dataframes_vertical_unchecked(frames);
+            let file = File::create(&path)?;
+            IpcWriter::new(file).with_pl_flavor(true).finish(&mut df)?;
+            Ok(Some(path))
+        });
+        Ok([left?, right?])
+    }
+
+    fn spill_dir(&self) -> PolarsResult<PathBuf> {
+        let mut spill_dir = self.spill_dir.lock();
+        if let Some(dir) = &*spill_dir {
+            return Ok(dir.clone());
+        }
+        let dir = create_spill_dir()?;
+        *spill_dir = Some(dir.clone());
+        Ok(dir)
+    }
+
+    /// Join both sides of `partition` and add the result to `out`.
+    ///
+    /// A partition that doesn't fit the memory limit is split again with the hash of the next
+    /// depth and its parts are joined one at a time.
+    fn join_partition(
+        &self,
+        partition: Partition,
+        depth: usize,
+        out: &mut Vec<DataFrame>,
+    ) -> PolarsResult<()> {
+        if partition.is_empty() {
+            return Ok(());
+        }
+        let size = partition.sides.iter().map(|side| side.size).sum::<usize>();
+        if size <= self.memory_limit || depth >= MAX_RECURSION_DEPTH {
+            out.push(self.join_in_memory(partition)?);
+            return Ok(());
+        }
+
+        let mut partitions = Partitions::default();
+        for (side, frames) in partition.sides.into_iter().enumerate() {
+            for df in frames.into_frames() {
+                let parts = self.split(side, &df?, &self.random_states[depth + 1])?;
+                partitions.insert(side, parts);
+                while partitions.in_memory_size > self.memory_limit {
+                    let (p, frames) = partitions.take_largest();
+                    let paths = self.spill(frames)?;
+                    partitions.add_spilled(p, paths);
+                }
+            }
+        }
+
+        let parts = partitions
+            .partitions
+            .into_iter()
+            .filter(|partition| !partition.is_empty())
+            .collect::<Vec<_>>();
+        // If all rows ended up in the same part, they are likely to share their keys and
+        // splitting again doesn't help.
+        let depth = if parts.len() == 1 {
+            MAX_RECURSION_DEPTH
+        } else {
+            depth + 1
+        };
+        for part in parts {
+            self.join_partition(part, depth, out)?;
+        }
+        Ok(())
+    }
+
+    fn join_in_memory(&self, partition: Partition) -> PolarsResult<DataFrame> {
+        let [left, right] = partition
+            .sides
+            .map(|side| side.into_frames().collect::<PolarsResult<Vec<_>>>());
+        let [left, right] =
+            [(left?, &self.schemas[0]), (right?, &self.schemas[1])].map(|(frames, schema)| {
+                if frames.is_empty() {
+                    DataFrame::from(schema.as_ref())
+                } else {
+                    accumulate_dataframes_vertical_unchecked(frames)
+                }
+            });
+        left.join(
+            &right,
+            self.left_on.iter(),
+            self.right_on.iter(),
+            self.args.clone(),
+        )
+    }
+}
+
+fn create_spill_dir() -> PolarsResult<PathBuf> {
+    let count = SPILL_DIR_COUNT.fetch_add(1, Ordering::Relaxed);
+    let mut dir = PathBuf::from(crate::config::get_base_temp_dir());
+    dir.push(format!("polars/join/{}_{count}", std::process::id()));
+    std::fs::create_dir_all(&dir)
+        .map_err(|err| polars_err!(ComputeError: "failed to create spill directory: {}", err))?;
+    Ok(dir)
+}
+
+impl Drop for GraceHashJoinNode {
+    fn drop(&mut self) {
+        if let Some(dir) = self.spill_dir.get_mut() {
+            let _ = std::fs::remove_dir_all(dir);
+        }
+    }
+}
+
+impl ComputeNode for GraceHashJoinNode {
+    fn initialize(&mut self, num_pipelines: usize) {
+        *self.partitions.get_mut() = Partitions::default();
+        self.next_spill_file = AtomicUsize::new(0);
+
+        self.wait_groups = (0..num_pipelines).map(|_| WaitGroup::default()).collect();
+        *self.wait_tokens.get_mut() = (0..num_pipelines)
+            .map(|_| self.wait_groups.iter().map(|wg| wg.token()).collect())
+            .collect();
+        self.next_partition = AtomicUsize::new(0);
+    }
+
+    fn spawn<'env, 's>(
+        &'env self,
+        scope: &'s TaskScope<'s, 'env>,
+        pipeline: usize,
+        recv: Vec<Receiver<Morsel>>,
+        send: Vec<Sender<Morsel>>,
+        _state: &'s ExecutionState,
+    ) -> JoinHandle<PolarsResult<()>> {
+        let [recv_left, recv_right] = <[_; 2]>::try_from(recv).ok().unwrap();
+        let [mut send] = <[_; 1]>::try_from(send).ok().unwrap();
+        let wait_tokens = std::mem::take(&mut self.wait_tokens.lock()[pipeline]);
+
+        // Receive both inputs at the same time, they may depend on the same source.
+        let [left, right] = [(0, recv_left), (1, recv_right)].map(|(side, mut recv)| {
+            scope.spawn_task(true, async move {
+                while let Ok(morsel) = recv.recv().await {
+                    self.insert(side, morsel.into_df())?;
+                }
+                PolarsResult::Ok(())
+            })
+        });
+
+        scope.spawn_task(true, async move {
+            left.await?;
+            right.await?;
+
+            // Every partition is complete once all pipelines received their input.
+            drop(wait_tokens);
+            self.wait_groups[pipeline].wait().await;
+
+            loop {
+                let p = self.next_partition.fetch_add(1, Ordering::Relaxed);
+                if p >= NUM_PARTITIONS {
+                    break;
+                }
+                let partition = std::mem::take(&mut self.partitions.lock().partitions[p]);
+                let mut out = Vec::new();
+                self.join_partition(partition, 0, &mut out)?;
+                for df in out {
+                    let mut offset = 0;
+                    while offset < df.height() {
+                        let morsel = Morsel::new(
+                            df.slice(offset as i64, IDEAL_MORSEL_SIZE),
+                            MorselSeq::new(p as u64),
+                        );
+                        offset += IDEAL_MORSEL_SIZE;
+                        if send.send(morsel).await.is_err() {
+                            return Ok(());
+                        }
+                    }
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    fn finalize(&mut self) {
+        self.partitions.get_mut().partitions.clear();
+        if let Some(dir) = self.spill_dir.get_mut().take() {
+            let _ = std::fs::remove_dir_all(dir);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    use polars_core::df;
+    use polars_core::prelude::*;
+    use polars_ops::frame::JoinType;
+
+    use super::*;
+    use crate::async_executor::task_scope;
+    use crate::async_primitives::pipe::pipe;
+
+    struct ThreadWaker(std::thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark()
+        }
+    }
+
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        let mut fut = pin!(fut);
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match fut.as_mut().poll(&mut cx) {
+                Poll::Ready(out) => return out,
+                Poll::Pending => std::thread::park(),
+            }
+        }
+    }
+
+    /// Join `left` and `right` on their `key` column with the grace hash join node.
+    fn grace_hash_join(
+        left: &DataFrame,
+        right: &DataFrame,
+        how: JoinType,
+        memory_limit: usize,
+    ) -> PolarsResult<(DataFrame, bool)> {
+        let num_pipelines = 2;
+        let mut node = GraceHashJoinNode::new(
+            vec!["key".into()],
+            vec!["key".into()],
+            JoinArgs::new(how),
+            Arc::new(left.schema()),
+            Arc::new(right.schema()),
+            memory_limit,
+        );
+        node.initialize(num_pipelines);
+        let state = ExecutionState::new();
+
+        let out = task_scope(|scope| {
+            let mut handles = Vec::new();
+            let mut outputs = Vec::new();
+            for pipeline in 0..num_pipelines {
+                let recv = [left, right].map(|df| {
+                    // Every pipeline receives every other morsel of two rows.
+                    let morsels = (0..df.height())
+                        .step_by(2)
+                        .skip(pipeline)
+                        .step_by(num_pipelines)
+                        .map(|offset| df.slice(offset as i64, 2))
+                        .collect::<Vec<_>>();
+                    let (mut send, recv) = pipe();
+                    handles.push(scope.spawn_task(false, async move {
+                        for (seq, df) in morsels.into_iter().enumerate() {
+                            let morsel = Morsel::new(df, MorselSeq::new(seq as u64));
+                            if send.send(morsel).await.is_err() {
+                                break;
+                            }
+                        }
+                        PolarsResult::Ok(())
+                    }));
+                    recv
+                });
+                let (send, mut recv) = pipe();
+                handles.push(node.spawn(scope, pipeline, recv.into(), vec![send], &state));
+                outputs.push(scope.spawn_task(false, async move {
+                    let mut frames = Vec::new();
+                    while let Ok(morsel) = recv.recv().await {
+                        frames.push(morsel.into_df());
+                    }
+                    frames
+                }));
+            }
+            block_on(async {
+                for handle in handles {
+                    handle.await?;
+                }
+                let mut frames = Vec::new();
+                for output in outputs {
+                    frames.extend(output.await);
+                }
+                PolarsResult::Ok(frames)
+            })
+        })?;
+        let spilled = node.spill_dir.get_mut().is_some();
+        node.finalize();
+
+        let out = if out.is_empty() {
+            left.clear()
+                .join(&right.clear(), ["key"], ["key"], JoinArgs::new(how))?
+        } else {
+            accumulate_dataframes_vertical_unchecked(out)
+        };
+        Ok((out, spilled))
+    }
+
+    fn sorted(df: &DataFrame) -> DataFrame {
+        df.sort(df.get_column_names(), SortMultipleOptions::default())
+            .unwrap()
+    }
+
+    fn assert_join_eq(left: &DataFrame, right: &DataFrame, how: JoinType, memory_limit: usize) {
+        let (out, _) = grace_hash_join(left, right, how.clone(), memory_limit).unwrap();
+        let expected = left
+            .join(right, ["key"], ["key"], JoinArgs::new(how))
+            .unwrap();
+        assert!(sorted(&out).equals_missing(&sorted(&expected)));
+    }
+
+    fn join_types() -> [JoinType; 3] {
+        [JoinType::Inner, JoinType::Left, JoinType::Full]
+    }
+
+    fn inputs() -> (DataFrame, DataFrame) {
+        let left = df![
+            "key" => (0..200).map(|i| i % 50).collect::<Vec<i32>>(),
+            "a" => (0..200).collect::<Vec<i32>>(),
+        ]
+        .unwrap();
+        let right = df![
+            "key" => (0..100).map(|i| i % 70).collect::<Vec<i32>>(),
+            "b" => (0..100).map(|i| i.to_string()).collect::<Vec<_>>(),
+        ]
+        .unwrap();
+        (left, right)
+    }
+
+    #[test]
+    fn test_grace_hash_join_in_memory() {
+        let (left, right) = inputs();
+        for how in join_types() {
+            let (_, spilled) = grace_hash_join(&left, &right, how.clone(), usize::MAX).unwrap();
+            assert!(!spilled);
+            assert_join_eq(&left, &right, how, usize::MAX);
+        }
+    }
+
+    #[test]
+    fn test_grace_hash_join_spilled() {
+        let (left, right) = inputs();
+        for how in join_types() {
+            // Every partition is larger than the limit, so they are also split recursively.
+            let (_, spilled) = grace_hash_join(&left, &right, how.clone(), 64).unwrap();
+            assert!(spilled);
+            assert_join_eq(&left, &right, how, 64);
+        }
+    }
+
+    #[test]
+    fn test_grace_hash_join_skewed_keys() {
+        let left = df![
+            "key" => [1i32; 100],
+            "a" => (0..100).collect::<Vec<i32>>(),
+        ]
+        .unwrap();
+        let right = df![
+            "key" => [1i32, 1, 2],
+            "b" => [1i32, 2, 3],
+        ]
+        .unwrap();
+        for how in join_types() {
+            assert_join_eq(&left, &right, how, 64);
+        }
+    }
+
+    #[test]
+    fn test_grace_hash_join_empty_inputs() {
+        let (left, right) = inputs();
+        for how in join_types() {
+            assert_join_eq(&left.clear(), &right, how.clone(), 64);
+            assert_join_eq(&left, &right.clear(), how.clone(), 64);
+            assert_join_eq(&left.clear(), &right.clear(), how, 64);
+        }
+    }
+}
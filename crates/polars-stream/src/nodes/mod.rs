@@ -6,6 +6,7 @@ use crate::async_primitives::pipe::{Receiver, Sender};
 use crate::morsel::Morsel;
 
 pub mod filter;
+pub mod grace_hash_join;
 pub mod in_memory_sink;
 pub mod in_memory_source;
 pub mod simple_projection;
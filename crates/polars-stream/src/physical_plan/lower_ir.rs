@@ -1,5 +1,7 @@
 use polars_error::PolarsResult;
-use polars_plan::logical_plan::{AExpr, Context, IR};
+use polars_ops::frame::JoinType;
+use polars_plan::logical_plan::{AExpr, ColumnName, Context, IR};
+use polars_plan::prelude::expr_ir::ExprIR;
 use polars_utils::arena::{Arena, Node};
 use slotmap::SlotMap;
 
@@ -9,6 +11,19 @@ fn is_streamable(node: Node, arena: &Arena<AExpr>) -> bool {
     polars_plan::logical_plan::is_streamable(node, arena, Context::Default)
 }
 
+/// The names of the columns if all keys are plain columns.
+fn key_columns(keys: &[ExprIR], arena: &Arena<AExpr>) -> Option<Vec<ColumnName>> {
+    if keys.is_empty() {
+        return None;
+    }
+    keys.iter()
+        .map(|e| match arena.get(e.node()) {
+            AExpr::Column(name) if e.output_name() == name.as_ref() => Some(name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
 #[recursive::recursive]
 pub fn lower_ir(
     node: Node,
@@ -54,6 +69,44 @@ pub fn lower_ir(
             Ok(phys_node)
         },
 
+        IR::Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options,
+            ..
+        } => {
+            let (input_left, input_right) = (*input_left, *input_right);
+            let (Some(left_on), Some(right_on)) = (
+                key_columns(left_on, expr_arena),
+                key_columns(right_on, expr_arena),
+            ) else {
+                return Ok(phys_sm.insert(PhysNode::Fallback(node)));
+            };
+            // The partitions are joined independently, so the output order isn't maintained. Left
+            // joins keep the order of the left rows, so they aren't partitioned.
+            let args = &options.args;
+            if !matches!(args.how, JoinType::Inner | JoinType::Full) || args.slice.is_some() {
+                return Ok(phys_sm.insert(PhysNode::Fallback(node)));
+            }
+            let args = args.clone();
+            let left_schema = ir_arena.get(input_left).schema(ir_arena).into_owned();
+            let right_schema = ir_arena.get(input_right).schema(ir_arena).into_owned();
+
+            let input_left = lower_ir(input_left, ir_arena, expr_arena, phys_sm)?;
+            let input_right = lower_ir(input_right, ir_arena, expr_arena, phys_sm)?;
+            Ok(phys_sm.insert(PhysNode::EquiJoin {
+                input_left,
+                input_right,
+                left_schema,
+                right_schema,
+                left_on,
+                right_on,
+                args,
+            }))
+        },
+
         _ => Ok(phys_sm.insert(PhysNode::Fallback(node))),
     }
 }
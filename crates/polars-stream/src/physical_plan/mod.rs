@@ -2,6 +2,8 @@ use std::sync::Arc;
 
 use polars_core::frame::DataFrame;
 use polars_core::schema::SchemaRef;
+use polars_ops::frame::JoinArgs;
+use polars_plan::logical_plan::ColumnName;
 use polars_plan::prelude::expr_ir::ExprIR;
 use polars_utils::arena::Node;
 
@@ -29,6 +31,16 @@ pub enum PhysNode {
         input: PhysNodeKey,
         schema: SchemaRef,
     },
+    /// An equi-join on plain columns.
+    EquiJoin {
+        input_left: PhysNodeKey,
+        input_right: PhysNodeKey,
+        left_schema: SchemaRef,
+        right_schema: SchemaRef,
+        left_on: Vec<ColumnName>,
+        right_on: Vec<ColumnName>,
+        args: JoinArgs,
+    },
     // Fallback to the in-memory engine.
     Fallback(Node),
 }
@@ -6,6 +6,7 @@ use recursive::recursive;
 use slotmap::{SecondaryMap, SlotMap};
 
 use super::{PhysNode, PhysNodeKey};
+use crate::config::get_memory_limit;
 use crate::graph::{Graph, GraphNodeKey};
 use crate::nodes;
 
@@ -75,6 +76,30 @@ fn to_graph_rec<'a>(
             )
         },
 
+        EquiJoin {
+            input_left,
+            input_right,
+            left_schema,
+            right_schema,
+            left_on,
+            right_on,
+            args,
+        } => {
+            let input_left_key = to_graph_rec(*input_left, ctx)?;
+            let input_right_key = to_graph_rec(*input_right, ctx)?;
+            ctx.graph.add_node(
+                nodes::grace_hash_join::GraceHashJoinNode::new(
+                    left_on.clone(),
+                    right_on.clone(),
+                    args.clone(),
+                    left_schema.clone(),
+                    right_schema.clone(),
+                    get_memory_limit()?,
+                ),
+                [input_left_key, input_right_key],
+            )
+        },
+
         // Fallback to the in-memory engine.
         Fallback(node) => {
             todo!()
@@ -12,6 +12,42 @@ pub(crate) fn naive_datetime_to_date(v: NaiveDateTime) -> i32 {
     (datetime_to_timestamp_ms(v) / (MILLISECONDS * SECONDS_IN_DAY)) as i32
 }
 
+/// Days since the unix epoch of January 4th, which is always in the first ISO week of `year`.
+fn january_4th(year: i64) -> i64 {
+    // The days from civil algorithm of Howard Hinnant, with years starting in March, so
+    // January belongs to the previous year.
+    let y = year - 1;
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    // (153 * 10 + 2) / 5 days from March 1st to January 1st, plus 3.
+    let doy = 309;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Days since the unix epoch of the Monday of the first ISO week of `year`.
+fn iso_week_1_monday(year: i64) -> i64 {
+    let jan_4 = january_4th(year);
+    // The epoch is a Thursday.
+    let weekday_from_monday = (jan_4 + 3).rem_euclid(7);
+    jan_4 - weekday_from_monday
+}
+
+/// Days since the unix epoch of an ISO week date, or `None` if the week or the weekday are
+/// out of range for `year`.
+pub(crate) fn iso_week_date_to_date(year: i32, week: i8, weekday: i8) -> Option<i32> {
+    if week < 1 || !(1..=7).contains(&weekday) {
+        return None;
+    }
+    let year = year as i64;
+    let days = iso_week_1_monday(year) + (week as i64 - 1) * 7 + (weekday as i64 - 1);
+    // Years have 52 or 53 ISO weeks.
+    if days >= iso_week_1_monday(year + 1) {
+        return None;
+    }
+    days.try_into().ok()
+}
+
 pub trait DateMethods: AsDate {
     /// Extract month from underlying NaiveDate representation.
     /// Returns the year number in the calendar date.
@@ -81,6 +117,17 @@ pub trait DateMethods: AsDate {
     }
 
     fn parse_from_str_slice(name: &str, v: &[&str], fmt: &str) -> DateChunked;
+
+    /// Construct dates from ISO week dates: the ISO year, the ISO week starting from 1 and the
+    /// weekday where monday = 1 and sunday = 7.
+    ///
+    /// Week dates that don't exist, like week 53 of a year with 52 weeks, are null.
+    fn from_iso_week_date(
+        name: &str,
+        iso_year: &Int32Chunked,
+        iso_week: &Int8Chunked,
+        weekday: &Int8Chunked,
+    ) -> DateChunked;
 }
 
 impl DateMethods for DateChunked {
@@ -96,6 +143,26 @@ impl DateMethods for DateChunked {
         )
         .into()
     }
+
+    fn from_iso_week_date(
+        name: &str,
+        iso_year: &Int32Chunked,
+        iso_week: &Int8Chunked,
+        weekday: &Int8Chunked,
+    ) -> DateChunked {
+        let ca: Int32Chunked = iso_year
+            .into_iter()
+            .zip(iso_week)
+            .zip(weekday)
+            .map(|((year, week), weekday)| match (year, week, weekday) {
+                (Some(year), Some(week), Some(weekday)) => {
+                    iso_week_date_to_date(year, week, weekday)
+                },
+                _ => None,
+            })
+            .collect_trusted();
+        ca.with_name(name).into_date()
+    }
 }
 
 pub trait AsDate {
@@ -107,3 +174,40 @@ impl AsDate for DateChunked {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use chrono::{Datelike, Weekday};
+
+    use super::*;
+
+    #[test]
+    fn test_iso_week_date_to_date() {
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        for year in [
+            -401, -1, 0, 1, 1582, 1899, 1900, 1970, 2000, 2004, 2015, 2020, 2024,
+        ] {
+            for week in 0..=54i8 {
+                for weekday in 0..=8i8 {
+                    let expected = Weekday::try_from((weekday - 1) as u8)
+                        .ok()
+                        .and_then(|wd| NaiveDate::from_isoywd_opt(year, week as u32, wd))
+                        .map(|date| (date - epoch).num_days() as i32);
+                    assert_eq!(
+                        iso_week_date_to_date(year, week, weekday),
+                        expected,
+                        "{year}-W{week}-{weekday}"
+                    );
+                }
+            }
+        }
+
+        // The ISO year differs from the calendar year at its start and end.
+        let date = NaiveDate::from_ymd_opt(2019, 12, 30).unwrap();
+        assert_eq!(date.iso_week().year(), 2020);
+        assert_eq!(
+            iso_week_date_to_date(2020, 1, 1),
+            Some((date - epoch).num_days() as i32)
+        );
+    }
+}
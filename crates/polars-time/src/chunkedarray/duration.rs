@@ -1,3 +1,6 @@
+use std::fmt::Write;
+
+use arrow::array::{Array, MutablePlString};
 use arrow::temporal_conversions::{
     MICROSECONDS, MILLISECONDS, MILLISECONDS_IN_DAY, NANOSECONDS, SECONDS_IN_DAY,
 };
@@ -7,6 +10,138 @@ use super::*;
 const NANOSECONDS_IN_MILLISECOND: i64 = 1_000_000;
 const SECONDS_IN_HOUR: i64 = 3600;
 
+/// The number of `tu` in a second, and the number of fractional digits of a second in `tu`.
+fn units_per_second(tu: TimeUnit) -> (i64, usize) {
+    match tu {
+        TimeUnit::Nanoseconds => (NANOSECONDS, 9),
+        TimeUnit::Microseconds => (MICROSECONDS, 6),
+        TimeUnit::Milliseconds => (MILLISECONDS, 3),
+    }
+}
+
+/// Write `v`, a duration in `tu`, as an ISO 8601 duration like `P1DT2H30M`.
+///
+/// A day is always 24 hours, so no years, months or weeks are written, and negative durations
+/// get a leading minus sign.
+pub(crate) fn fmt_iso8601_duration(buf: &mut String, v: i64, tu: TimeUnit) {
+    let (per_second, mut width) = units_per_second(tu);
+    if v < 0 {
+        buf.push('-');
+    }
+    let v = v.unsigned_abs();
+    let (per_second, per_day) = (per_second as u64, SECONDS_IN_DAY as u64);
+    let (seconds, mut fraction) = (v / per_second, v % per_second);
+    let (days, seconds) = (seconds / per_day, seconds % per_day);
+    let (hours, seconds) = (seconds / 3600, seconds % 3600);
+    let (minutes, seconds) = (seconds / 60, seconds % 60);
+
+    buf.push('P');
+    if days > 0 {
+        write!(buf, "{days}D").unwrap();
+    }
+    if days > 0 && hours == 0 && minutes == 0 && seconds == 0 && fraction == 0 {
+        return;
+    }
+    buf.push('T');
+    if hours > 0 {
+        write!(buf, "{hours}H").unwrap();
+    }
+    if minutes > 0 {
+        write!(buf, "{minutes}M").unwrap();
+    }
+    if seconds > 0 || fraction > 0 || (days == 0 && hours == 0 && minutes == 0) {
+        write!(buf, "{seconds}").unwrap();
+        if fraction > 0 {
+            while fraction % 10 == 0 {
+                fraction /= 10;
+                width -= 1;
+            }
+            write!(buf, ".{fraction:0width$}").unwrap();
+        }
+        buf.push('S');
+    }
+}
+
+/// Parse an ISO 8601 duration like `P1DT2H30M` to a duration in `tu`.
+///
+/// Weeks, days, hours, minutes and seconds are supported. Years and months don't have a fixed
+/// length and aren't. The last component may have a fraction, separated by a dot or a comma,
+/// of which the digits beyond the precision of `tu` are truncated. A leading sign may negate
+/// the duration. Returns `None` if `s` isn't such a duration or if it overflows.
+pub(crate) fn parse_iso8601_duration(s: &str, tu: TimeUnit) -> Option<i64> {
+    let (per_second, _) = units_per_second(tu);
+    let (negative, s) = match s.as_bytes().first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let mut rest = s.strip_prefix('P')?;
+    if rest.is_empty() {
+        return None;
+    }
+
+    let mut total: i128 = 0;
+    let mut in_time = false;
+    // The components must be in order, and only the last one can have a fraction.
+    let mut last_rank = 0;
+    let mut had_fraction = false;
+    while !rest.is_empty() {
+        if let Some(r) = rest.strip_prefix('T') {
+            if in_time || r.is_empty() {
+                return None;
+            }
+            in_time = true;
+            rest = r;
+            continue;
+        }
+        if had_fraction {
+            return None;
+        }
+        let n_int = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let (int, r) = rest.split_at(n_int);
+        let (fraction, r) = match r.strip_prefix(['.', ',']) {
+            Some(r) => {
+                let n_fraction = r.bytes().take_while(u8::is_ascii_digit).count();
+                if n_fraction == 0 {
+                    return None;
+                }
+                r.split_at(n_fraction)
+            },
+            None => ("", r),
+        };
+        if int.is_empty() {
+            return None;
+        }
+        let (rank, seconds) = match (in_time, r.as_bytes().first()?) {
+            (false, b'W') => (1, 7 * SECONDS_IN_DAY),
+            (false, b'D') => (2, SECONDS_IN_DAY),
+            (true, b'H') => (3, SECONDS_IN_HOUR),
+            (true, b'M') => (4, 60),
+            (true, b'S') => (5, 1),
+            _ => return None,
+        };
+        if rank <= last_rank {
+            return None;
+        }
+        last_rank = rank;
+        rest = &r[1..];
+
+        let unit = (seconds * per_second) as i128;
+        total = total.checked_add(int.parse::<i128>().ok()?.checked_mul(unit)?)?;
+        if !fraction.is_empty() {
+            // More digits than this can't change the result for any time unit.
+            let fraction = &fraction[..fraction.len().min(18)];
+            let scale = 10i128.pow(fraction.len() as u32);
+            total += fraction.parse::<i128>().ok()? * unit / scale;
+            had_fraction = true;
+        }
+    }
+    if negative {
+        total = -total;
+    }
+    total.try_into().ok()
+}
+
 pub trait DurationMethods {
     /// Extract the hours from a `Duration`
     fn hours(&self) -> Int64Chunked;
@@ -28,6 +163,9 @@ pub trait DurationMethods {
 
     /// Extract the nanoseconds from a `Duration`
     fn nanoseconds(&self) -> Int64Chunked;
+
+    /// Format a `Duration` as an ISO 8601 duration like `P1DT2H30M`.
+    fn to_iso8601(&self) -> StringChunked;
 }
 
 impl DurationMethods for DurationChunked {
@@ -106,4 +244,90 @@ impl DurationMethods for DurationChunked {
             TimeUnit::Nanoseconds => self.0.clone(),
         }
     }
+
+    /// Format a `Duration` as an ISO 8601 duration like `P1DT2H30M`.
+    fn to_iso8601(&self) -> StringChunked {
+        let tu = self.time_unit();
+        let mut ca: StringChunked = self.0.apply_kernel_cast(&|arr| {
+            let mut buf = String::new();
+            let mut mutarr = MutablePlString::with_capacity(arr.len());
+
+            for opt in arr.into_iter() {
+                match opt {
+                    None => mutarr.push_null(),
+                    Some(v) => {
+                        buf.clear();
+                        fmt_iso8601_duration(&mut buf, *v, tu);
+                        mutarr.push_value(&buf)
+                    },
+                }
+            }
+
+            mutarr.freeze().boxed()
+        });
+        ca.rename(self.name());
+        ca
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_iso8601_duration() {
+        let ns = |d: i64, h: i64, m: i64, s: i64, ns: i64| {
+            (((d * 24 + h) * 60 + m) * 60 + s) * NANOSECONDS + ns
+        };
+        let cases = [
+            (ns(0, 0, 0, 0, 0), "PT0S"),
+            (ns(1, 2, 30, 0, 0), "P1DT2H30M"),
+            (ns(14, 0, 0, 0, 0), "P14D"),
+            (ns(0, 0, 0, 1, 500_000_000), "PT1.5S"),
+            (ns(0, 0, 0, 0, 1), "PT0.000000001S"),
+            (ns(3, 0, 5, 0, 0), "P3DT5M"),
+            (-ns(1, 0, 0, 7, 0), "-P1DT7S"),
+            (i64::MIN, "-P106751DT23H47M16.854775808S"),
+        ];
+        let mut buf = String::new();
+        for (v, expected) in cases {
+            buf.clear();
+            fmt_iso8601_duration(&mut buf, v, TimeUnit::Nanoseconds);
+            assert_eq!(buf, expected);
+            assert_eq!(
+                parse_iso8601_duration(expected, TimeUnit::Nanoseconds),
+                Some(v)
+            );
+        }
+
+        let us = |s: &str| parse_iso8601_duration(s, TimeUnit::Microseconds);
+        assert_eq!(us("P2W"), Some(14 * SECONDS_IN_DAY * MICROSECONDS));
+        assert_eq!(us("+PT1H"), Some(SECONDS_IN_HOUR * MICROSECONDS));
+        assert_eq!(us("PT1,25M"), Some(75 * MICROSECONDS));
+        assert_eq!(us("PT0.5H"), Some(1800 * MICROSECONDS));
+        // Digits beyond the time unit are truncated.
+        assert_eq!(us("PT0.0000019S"), Some(1));
+        for invalid in [
+            "",
+            "P",
+            "PT",
+            "P1DT",
+            "1D",
+            "P1Y",
+            "P1M",
+            "PT1D",
+            "P1H",
+            "PT1S1M",
+            "P1D1D",
+            "PT1.5M1S",
+            "PT.5S",
+            "PT1.S",
+            "P-1D",
+            "PT1",
+            "P1DX",
+            "P99999999999999D",
+        ] {
+            assert_eq!(us(invalid), None, "{invalid}");
+        }
+    }
 }
@@ -251,6 +251,35 @@ pub trait SeriesOpsTime: AsSeries {
         })
     }
 
+    /// Apply an approximate rolling quantile to a Series.
+    ///
+    /// The quantile of every window is estimated with a [`QuantileSketch`], which makes the
+    /// cost per element independent of the window size. The estimates are within the relative
+    /// accuracy set in the [`RollingApproxQuantileParams`] of the exact (lower) quantile.
+    ///
+    /// [`QuantileSketch`]: polars_arrow::legacy::kernels::rolling::quantile_sketch::QuantileSketch
+    #[cfg(feature = "rolling_window")]
+    fn rolling_approx_quantile(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        let params = options
+            .fn_params
+            .as_ref()
+            .and_then(|p| p.downcast_ref::<RollingApproxQuantileParams>());
+        polars_ensure!(
+            params.is_some_and(|p| p.relative_accuracy > 0.0 && p.relative_accuracy < 1.0),
+            InvalidOperation: "`relative_accuracy` of an approximate rolling quantile should be in (0, 1)"
+        );
+        let s = self.as_series().to_float()?;
+        with_match_physical_float_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+        rolling_agg(
+            ca,
+            options,
+            &rolling::no_nulls::rolling_approx_quantile,
+            &rolling::nulls::rolling_approx_quantile,
+        )
+        })
+    }
+
     /// Apply a rolling min to a Series based on another Series.
     #[cfg(feature = "rolling_window_by")]
     fn rolling_min_by(
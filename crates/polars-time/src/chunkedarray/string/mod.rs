@@ -11,6 +11,8 @@ use polars_utils::cache::FastCachedFunc;
 use super::*;
 #[cfg(feature = "dtype-date")]
 use crate::chunkedarray::date::naive_date_to_date;
+#[cfg(feature = "dtype-duration")]
+use crate::chunkedarray::duration::parse_iso8601_duration;
 use crate::prelude::string::strptime::StrpTimeState;
 
 #[cfg(feature = "dtype-time")]
@@ -121,6 +123,15 @@ pub trait StringMethods: AsString {
         Ok(ca.with_name(string_ca.name()).into())
     }
 
+    #[cfg(feature = "dtype-duration")]
+    /// Parse ISO 8601 durations like `P1DT2H30M` and return a [`DurationChunked`].
+    /// Values that aren't such durations, or that have years or months, are null.
+    fn as_duration_iso8601(&self, tu: TimeUnit) -> DurationChunked {
+        let string_ca = self.as_string();
+        let ca: Int64Chunked = string_ca.apply_generic(|opt_s| parse_iso8601_duration(opt_s?, tu));
+        ca.with_name(string_ca.name()).into_duration(tu)
+    }
+
     #[cfg(feature = "dtype-date")]
     /// Parsing string values and return a [`DateChunked`]
     /// Different from `as_date` this function allows matches that not contain the whole string
@@ -355,6 +366,89 @@ pub trait StringMethods: AsString {
             }
         }
     }
+
+    #[cfg(feature = "dtype-datetime")]
+    /// Parsing string values with the first of `fmts` that matches and return a
+    /// [`DatetimeChunked`].
+    ///
+    /// Consecutive values mostly share a format, so a value is first parsed with the format
+    /// that parsed the previous value, and then with the other formats in order.
+    fn as_datetime_multiple_formats(
+        &self,
+        fmts: &[String],
+        tu: TimeUnit,
+        use_cache: bool,
+        tz_aware: bool,
+        tz: Option<&TimeZone>,
+        ambiguous: &StringChunked,
+    ) -> PolarsResult<DatetimeChunked> {
+        let string_ca = self.as_string();
+        polars_ensure!(!fmts.is_empty(), ComputeError: "expected at least one format");
+        let fmts = fmts
+            .iter()
+            .map(|fmt| strptime::compile_fmt(fmt))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let fmt_lens = fmts
+            .iter()
+            .map(|fmt| strptime::fmt_len(fmt.as_bytes()))
+            .collect::<Vec<_>>();
+        let use_cache = use_cache && string_ca.len() > 50;
+
+        let func = match tu {
+            TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
+            TimeUnit::Microseconds => datetime_to_timestamp_us,
+            TimeUnit::Milliseconds => datetime_to_timestamp_ms,
+        };
+        let transform = match tu {
+            TimeUnit::Nanoseconds => infer::transform_datetime_ns,
+            TimeUnit::Microseconds => infer::transform_datetime_us,
+            TimeUnit::Milliseconds => infer::transform_datetime_ms,
+        };
+
+        let mut strptime_cache = StrpTimeState::default();
+        let mut parse = |s: &str, i: usize| {
+            let fmt = &fmts[i];
+            if tz_aware {
+                return DateTime::parse_from_str(s, fmt)
+                    .ok()
+                    .map(|dt| func(dt.naive_utc()));
+            }
+            match fmt_lens[i] {
+                // We can use the fast parser.
+                Some(fmt_len) => {
+                    // SAFETY: fmt_len is correct, it was computed with this `fmt` str.
+                    match unsafe { strptime_cache.parse(s.as_bytes(), fmt.as_bytes(), fmt_len) } {
+                        None => transform(s, fmt),
+                        Some(ndt) => Some(func(ndt)),
+                    }
+                },
+                None => transform(s, fmt),
+            }
+        };
+        // Every value is parsed with the first format that matches it.
+        let mut convert = FastCachedFunc::new(
+            |s: &str| (0..fmts.len()).find_map(|i| parse(s, i)),
+            (string_ca.len() as f64).sqrt() as usize,
+        );
+        let ca = string_ca
+            .apply_generic(|opt_s| convert.eval(opt_s?, use_cache))
+            .with_name(string_ca.name());
+
+        match (tz_aware, tz) {
+            #[cfg(feature = "timezones")]
+            (false, Some(tz)) => polars_ops::prelude::replace_time_zone(
+                &ca.into_datetime(tu, None),
+                Some(tz),
+                ambiguous,
+                NonExistent::Raise,
+            ),
+            #[cfg(feature = "timezones")]
+            (true, tz) => Ok(ca.into_datetime(tu, tz.cloned().or_else(|| Some("UTC".to_string())))),
+            #[cfg(not(feature = "timezones"))]
+            (true, _) => panic!("activate 'timezones' feature"),
+            _ => Ok(ca.into_datetime(tu, None)),
+        }
+    }
 }
 
 pub trait AsString {
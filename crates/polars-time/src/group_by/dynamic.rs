@@ -13,6 +13,7 @@ use serde::{Deserialize, Serialize};
 use smartstring::alias::String as SmartString;
 
 use crate::prelude::*;
+use crate::windows::bounds::Bounds;
 
 #[repr(transparent)]
 struct Wrap<T>(pub T);
@@ -60,6 +61,9 @@ pub struct RollingGroupOptions {
     pub period: Duration,
     pub offset: Duration,
     pub closed_window: ClosedWindow,
+    /// The index column is sorted in descending order.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub descending: bool,
 }
 
 impl Default for RollingGroupOptions {
@@ -69,15 +73,103 @@ impl Default for RollingGroupOptions {
             period: Duration::new(1),
             offset: Duration::new(1),
             closed_window: ClosedWindow::Left,
+            descending: false,
         }
     }
 }
 
-fn check_sortedness_slice(v: &[i64]) -> PolarsResult<()> {
+fn check_sortedness_slice<T: PartialOrd + Copy>(v: &[T]) -> PolarsResult<()> {
     polars_ensure!(v.is_sorted_ascending(), ComputeError: "input data is not sorted");
     Ok(())
 }
 
+fn check_sortedness_slice_descending<T: PartialOrd + Copy>(v: &[T]) -> PolarsResult<()> {
+    polars_ensure!(v.is_sorted_descending(), ComputeError: "input data is not sorted in descending order");
+    Ok(())
+}
+
+/// Compute the rolling windows of `time`, which is sorted in the order given by the options.
+fn rolling_group_by_values(
+    time: &[i64],
+    options: &RollingGroupOptions,
+    tu: TimeUnit,
+    tz: Option<Tz>,
+) -> PolarsResult<GroupsSlice> {
+    if !options.descending {
+        return group_by_values(
+            options.period,
+            options.offset,
+            time,
+            options.closed_window,
+            tu,
+            tz,
+        );
+    }
+    // The windows only depend on the time values, so we determine them on the ascending
+    // time values and map them back to the positions in the descending input.
+    let ascending = time.iter().rev().copied().collect::<Vec<_>>();
+    let groups = group_by_values(
+        options.period,
+        options.offset,
+        &ascending,
+        options.closed_window,
+        tu,
+        tz,
+    )?;
+    let len = time.len() as IdxSize;
+    Ok(groups
+        .iter()
+        .rev()
+        .map(|&[first, group_len]| [len - first - group_len, group_len])
+        .collect_trusted())
+}
+
+/// Compute the rolling windows of an integer index column, which is sorted in the order given
+/// by the options.
+///
+/// Integer windows don't depend on a calendar, so they are found on the values as they are,
+/// in either order, by two pointers that only move forward.
+fn rolling_int_groups<T: Copy + Into<i64>>(
+    time: &[T],
+    options: &RollingGroupOptions,
+) -> GroupsSlice {
+    let offset = if options.offset.negative() {
+        -options.offset.nanoseconds()
+    } else {
+        options.offset.nanoseconds()
+    };
+    let period = options.period.nanoseconds();
+    let closed = options.closed_window;
+    let value = |i: usize| -> i64 { time[i].into() };
+
+    let mut start = 0;
+    let mut end = 0;
+    time.iter()
+        .map(|&t| {
+            let lower = t.into() + offset;
+            let bounds = Bounds::new(lower, lower + period);
+            if options.descending {
+                while start < time.len() && !bounds.is_member_exit(value(start), closed) {
+                    start += 1;
+                }
+                end = end.max(start);
+                while end < time.len() && bounds.is_member_entry(value(end), closed) {
+                    end += 1;
+                }
+            } else {
+                while start < time.len() && !bounds.is_member_entry(value(start), closed) {
+                    start += 1;
+                }
+                end = end.max(start);
+                while end < time.len() && bounds.is_member_exit(value(end), closed) {
+                    end += 1;
+                }
+            }
+            [start as IdxSize, (end - start) as IdxSize]
+        })
+        .collect_trusted()
+}
+
 const LB_NAME: &str = "_lower_boundary";
 const UP_NAME: &str = "_upper_boundary";
 
@@ -128,7 +220,18 @@ impl Wrap<&DataFrame> {
         if group_by.is_empty() {
             // If by is given, the column must be sorted in the 'by' arg, which we can not check now
             // this will be checked when the groups are materialized.
-            time.ensure_sorted_arg("rolling")?;
+            if options.descending {
+                let sort_options = SortOptions {
+                    descending: true,
+                    ..Default::default()
+                };
+                polars_ensure!(
+                    time.is_sorted(sort_options)?,
+                    InvalidOperation: "argument in operation 'rolling' is not sorted in descending order, please sort the 'expr/series/column' first"
+                );
+            } else {
+                time.ensure_sorted_arg("rolling")?;
+            }
         }
         let time_type = time.dtype();
 
@@ -144,10 +247,36 @@ impl Wrap<&DataFrame> {
                 TimeUnit::Milliseconds,
                 None,
             ),
-            UInt32 | UInt64 | Int32 => {
+            // 32-bit indices are rolled over as they are.
+            Int32 => {
+                return self.impl_rolling::<Int32Type, _>(
+                    time.clone(),
+                    group_by,
+                    options,
+                    time_type,
+                    |ts| Ok(rolling_int_groups(ts, options)),
+                )
+            },
+            UInt32 => {
+                return self.impl_rolling::<UInt32Type, _>(
+                    time.clone(),
+                    group_by,
+                    options,
+                    time_type,
+                    |ts| Ok(rolling_int_groups(ts, options)),
+                )
+            },
+            UInt64 => {
                 let time_type_dt = Datetime(TimeUnit::Nanoseconds, None);
-                let dt = time.cast(&Int64).unwrap().cast(&time_type_dt).unwrap();
-                let (out, by, gt) = self.impl_rolling(
+                // Casting from `Int64` to `Datetime` only changes the logical type.
+                let dt = time
+                    .cast(&Int64)?
+                    .i64()
+                    .unwrap()
+                    .clone()
+                    .into_datetime(TimeUnit::Nanoseconds, None)
+                    .into_series();
+                let (out, by, gt) = self.impl_rolling_datetime(
                     dt,
                     group_by,
                     options,
@@ -155,13 +284,18 @@ impl Wrap<&DataFrame> {
                     None,
                     &time_type_dt,
                 )?;
-                let out = out.cast(&Int64).unwrap().cast(time_type).unwrap();
+                // Without `by` the index column is not reordered, so we don't have to cast back.
+                let out = if by.is_empty() {
+                    time.clone()
+                } else {
+                    out.cast(&Int64).unwrap().cast(time_type).unwrap()
+                };
                 return Ok((out, by, gt));
             },
             Int64 => {
                 let time_type = Datetime(TimeUnit::Nanoseconds, None);
                 let dt = time.cast(&time_type).unwrap();
-                let (out, by, gt) = self.impl_rolling(
+                let (out, by, gt) = self.impl_rolling_datetime(
                     dt,
                     group_by,
                     options,
@@ -180,10 +314,15 @@ impl Wrap<&DataFrame> {
         };
         match tz {
             #[cfg(feature = "timezones")]
-            Some(tz) => {
-                self.impl_rolling(dt, group_by, options, tu, tz.parse::<Tz>().ok(), time_type)
-            },
-            _ => self.impl_rolling(dt, group_by, options, tu, None, time_type),
+            Some(tz) => self.impl_rolling_datetime(
+                dt,
+                group_by,
+                options,
+                tu,
+                tz.parse::<Tz>().ok(),
+                time_type,
+            ),
+            _ => self.impl_rolling_datetime(dt, group_by, options, tu, None, time_type),
         }
     }
 
@@ -510,7 +649,7 @@ impl Wrap<&DataFrame> {
     }
 
     /// Returns: time_keys, keys, groupsproxy
-    fn impl_rolling(
+    fn impl_rolling_datetime(
         &self,
         dt: Series,
         group_by: Vec<Series>,
@@ -519,24 +658,40 @@ impl Wrap<&DataFrame> {
         tz: Option<Tz>,
         time_type: &DataType,
     ) -> PolarsResult<(Series, Vec<Series>, GroupsProxy)> {
+        self.impl_rolling::<Int64Type, _>(dt, group_by, options, time_type, |ts| {
+            rolling_group_by_values(ts, options, tu, tz)
+        })
+    }
+
+    /// Compute the rolling groups of the physical values of `dt`, where `rolling_groups` finds
+    /// the windows of a sorted slice of those values.
+    fn impl_rolling<T, F>(
+        &self,
+        dt: Series,
+        group_by: Vec<Series>,
+        options: &RollingGroupOptions,
+        time_type: &DataType,
+        rolling_groups: F,
+    ) -> PolarsResult<(Series, Vec<Series>, GroupsProxy)>
+    where
+        T: PolarsNumericType,
+        F: Fn(&[T::Native]) -> PolarsResult<GroupsSlice> + Sync,
+    {
         let mut dt = dt.rechunk();
 
         let groups = if group_by.is_empty() {
             // a requirement for the index
             // so we can set this such that downstream code has this info
-            dt.set_sorted_flag(IsSorted::Ascending);
-            let dt = dt.datetime().unwrap();
-            let vals = dt.downcast_iter().next().unwrap();
+            if options.descending {
+                dt.set_sorted_flag(IsSorted::Descending);
+            } else {
+                dt.set_sorted_flag(IsSorted::Ascending);
+            }
+            let phys = dt.to_physical_repr();
+            let vals = phys.unpack::<T>()?.downcast_iter().next().unwrap();
             let ts = vals.values().as_slice();
             PolarsResult::Ok(GroupsProxy::Slice {
-                groups: group_by_values(
-                    options.period,
-                    options.offset,
-                    ts,
-                    options.closed_window,
-                    tu,
-                    tz,
-                )?,
+                groups: rolling_groups(ts)?,
                 rolling: true,
             })
         } else {
@@ -546,7 +701,7 @@ impl Wrap<&DataFrame> {
                 .take_groups();
 
             // we keep a local copy, as we are reordering on next operation.
-            let dt_local = dt.datetime().unwrap().clone();
+            let dt_local = dt.to_physical_repr().unpack::<T>()?.clone();
 
             // make sure that the output order is correct
             dt = unsafe { dt.agg_list(&groups).explode().unwrap() };
@@ -561,18 +716,13 @@ impl Wrap<&DataFrame> {
                             let dt = unsafe { dt_local.take_unchecked(base_g.1) };
                             let vals = dt.downcast_iter().next().unwrap();
                             let ts = vals.values().as_slice();
-                            if !matches!(dt.is_sorted_flag(), IsSorted::Ascending) {
-                                check_sortedness_slice(ts)?
+                            match (options.descending, dt.is_sorted_flag()) {
+                                (false, IsSorted::Ascending) | (true, IsSorted::Descending) => {},
+                                (false, _) => check_sortedness_slice(ts)?,
+                                (true, _) => check_sortedness_slice_descending(ts)?,
                             }
 
-                            let sub_groups = group_by_values(
-                                options.period,
-                                options.offset,
-                                ts,
-                                options.closed_window,
-                                tu,
-                                tz,
-                            )?;
+                            let sub_groups = rolling_groups(ts)?;
                             Ok(update_subgroups_idx(&sub_groups, base_g))
                         })
                         .collect::<PolarsResult<Vec<_>>>()?;
@@ -586,14 +736,7 @@ impl Wrap<&DataFrame> {
                             let dt = dt_local.slice(base_g[0] as i64, base_g[1] as usize);
                             let vals = dt.downcast_iter().next().unwrap();
                             let ts = vals.values().as_slice();
-                            let sub_groups = group_by_values(
-                                options.period,
-                                options.offset,
-                                ts,
-                                options.closed_window,
-                                tu,
-                                tz,
-                            )?;
+                            let sub_groups = rolling_groups(ts)?;
                             Ok(update_subgroups_slice(&sub_groups, *base_g))
                         })
                         .collect::<PolarsResult<Vec<_>>>()?;
@@ -702,6 +845,7 @@ mod test {
                         period: Duration::parse("2d"),
                         offset: Duration::parse("-2d"),
                         closed_window: ClosedWindow::Right,
+                        descending: false,
                     },
                 )
                 .unwrap();
@@ -749,6 +893,7 @@ mod test {
                     period: Duration::parse("2d"),
                     offset: Duration::parse("-2d"),
                     closed_window: ClosedWindow::Right,
+                    descending: false,
                 },
             )
             .unwrap();
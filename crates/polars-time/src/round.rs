@@ -5,11 +5,24 @@ use polars_core::prelude::*;
 use polars_utils::cache::FastFixedCache;
 
 use crate::prelude::*;
+use crate::truncate::{is_default_policy, localize_elementwise};
 
 pub trait PolarsRound {
     fn round(&self, every: &StringChunked, tz: Option<&Tz>) -> PolarsResult<Self>
     where
         Self: Sized;
+
+    /// Round to multiples of `every`, localizing the results like
+    /// [`PolarsTruncate::truncate_localized`].
+    fn round_localized(
+        &self,
+        every: &StringChunked,
+        tz: Option<&Tz>,
+        ambiguous: &StringChunked,
+        non_existent: &StringChunked,
+    ) -> PolarsResult<Self>
+    where
+        Self: Sized;
 }
 
 impl PolarsRound for DatetimeChunked {
@@ -40,6 +53,34 @@ impl PolarsRound for DatetimeChunked {
         });
         Ok(out?.into_datetime(self.time_unit(), self.time_zone().clone()))
     }
+
+    fn round_localized(
+        &self,
+        every: &StringChunked,
+        tz: Option<&Tz>,
+        ambiguous: &StringChunked,
+        non_existent: &StringChunked,
+    ) -> PolarsResult<Self> {
+        if is_default_policy(ambiguous, non_existent) {
+            return self.round(every, tz);
+        }
+        let tu = self.time_unit();
+        let offset = Duration::new(0);
+        let out = localize_elementwise(
+            self,
+            every,
+            ambiguous,
+            non_existent,
+            |t, every, ambiguous, non_existent| {
+                if every.negative {
+                    polars_bail!(ComputeError: "Cannot round a Datetime to a negative duration")
+                }
+                let w = Window::new(every, every, offset);
+                w.round_localized(t, tu, tz, ambiguous, non_existent)
+            },
+        )?;
+        Ok(out.into_datetime(tu, self.time_zone().clone()))
+    }
 }
 
 impl PolarsRound for DateChunked {
@@ -66,4 +107,15 @@ impl PolarsRound for DateChunked {
         });
         Ok(out?.into_date())
     }
+
+    fn round_localized(
+        &self,
+        every: &StringChunked,
+        tz: Option<&Tz>,
+        _ambiguous: &StringChunked,
+        _non_existent: &StringChunked,
+    ) -> PolarsResult<Self> {
+        // Dates have no time zone, so there are no ambiguous or non-existent results.
+        self.round(every, tz)
+    }
 }
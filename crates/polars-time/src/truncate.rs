@@ -1,3 +1,6 @@
+use std::str::FromStr;
+
+use arrow::legacy::kernels::{Ambiguous, NonExistent};
 use arrow::legacy::time_zone::Tz;
 use arrow::temporal_conversions::MILLISECONDS_IN_DAY;
 use polars_core::prelude::arity::broadcast_try_binary_elementwise;
@@ -10,6 +13,22 @@ pub trait PolarsTruncate {
     fn truncate(&self, tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self>
     where
         Self: Sized;
+
+    /// Truncate to multiples of `every`, localizing the results that are ambiguous or
+    /// non-existent in the time zone `tz` with the policies of their row.
+    ///
+    /// The `ambiguous` values are one of `"infer"`, `"earliest"`, `"latest"`, `"null"` or
+    /// `"raise"`, where `"infer"` keeps the DST fold of the original datetime. The
+    /// `non_existent` values are `"null"` or `"raise"`.
+    fn truncate_localized(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        ambiguous: &StringChunked,
+        non_existent: &StringChunked,
+    ) -> PolarsResult<Self>
+    where
+        Self: Sized;
 }
 
 impl PolarsTruncate for DatetimeChunked {
@@ -89,6 +108,34 @@ impl PolarsTruncate for DatetimeChunked {
         });
         Ok(out?.into_datetime(self.time_unit(), self.time_zone().clone()))
     }
+
+    fn truncate_localized(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        ambiguous: &StringChunked,
+        non_existent: &StringChunked,
+    ) -> PolarsResult<Self> {
+        if is_default_policy(ambiguous, non_existent) {
+            return self.truncate(tz, every);
+        }
+        let tu = self.time_unit();
+        let offset = Duration::new(0);
+        let out = localize_elementwise(
+            self,
+            every,
+            ambiguous,
+            non_existent,
+            |t, every, ambiguous, non_existent| {
+                if every.negative {
+                    polars_bail!(ComputeError: "cannot truncate a Datetime to a negative duration")
+                }
+                let w = Window::new(every, every, offset);
+                w.truncate_localized(t, tu, tz, ambiguous, non_existent)
+            },
+        )?;
+        Ok(out.into_datetime(tu, self.time_zone().clone()))
+    }
 }
 
 impl PolarsTruncate for DateChunked {
@@ -134,4 +181,102 @@ impl PolarsTruncate for DateChunked {
         };
         Ok(out?.into_date())
     }
+
+    fn truncate_localized(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        _ambiguous: &StringChunked,
+        _non_existent: &StringChunked,
+    ) -> PolarsResult<Self> {
+        // Dates have no time zone, so there are no ambiguous or non-existent results.
+        self.truncate(tz, every)
+    }
+}
+
+/// Whether the policies keep the DST fold of the original datetime and raise on non-existent
+/// results, which allows for the faster paths.
+pub(crate) fn is_default_policy(ambiguous: &StringChunked, non_existent: &StringChunked) -> bool {
+    ambiguous.len() == 1
+        && ambiguous.get(0) == Some("infer")
+        && non_existent.len() == 1
+        && non_existent.get(0) == Some("raise")
+}
+
+fn parse_ambiguous(s: &str) -> PolarsResult<Option<Ambiguous>> {
+    match s {
+        "infer" => Ok(None),
+        s => Ambiguous::from_str(s).map(Some).map_err(|_| {
+            polars_err!(InvalidOperation:
+                "Invalid argument {}, expected one of: \"infer\", \"earliest\", \"latest\", \"null\", \"raise\"", s
+            )
+        }),
+    }
+}
+
+fn parse_non_existent(s: &str) -> PolarsResult<NonExistent> {
+    match s {
+        "null" => Ok(NonExistent::Null),
+        "raise" => Ok(NonExistent::Raise),
+        s => polars_bail!(InvalidOperation:
+            "Invalid argument {}, expected one of: \"null\", \"raise\"", s
+        ),
+    }
+}
+
+/// Apply `func` to every timestamp with the duration and the policies of its row.
+pub(crate) fn localize_elementwise<F>(
+    ca: &DatetimeChunked,
+    every: &StringChunked,
+    ambiguous: &StringChunked,
+    non_existent: &StringChunked,
+    mut func: F,
+) -> PolarsResult<Int64Chunked>
+where
+    F: FnMut(i64, Duration, Option<Ambiguous>, NonExistent) -> PolarsResult<Option<i64>>,
+{
+    let lengths = [ca.len(), every.len(), ambiguous.len(), non_existent.len()];
+    let len = lengths.into_iter().filter(|l| *l != 1).max().unwrap_or(1);
+    polars_ensure!(
+        lengths.iter().all(|l| *l == 1 || *l == len),
+        ShapeMismatch: "the lengths of the datetimes, `every`, `ambiguous` and `non_existent` don't match: {:?}", lengths
+    );
+    let broadcast = |s: &StringChunked| match s.len() == len {
+        true => s.clone(),
+        false => s.new_from_index(0, len),
+    };
+    let timestamps = match ca.len() == len {
+        true => ca.0.clone(),
+        false => ca.0.new_from_index(0, len),
+    };
+    let (every, ambiguous, non_existent) = (
+        broadcast(every),
+        broadcast(ambiguous),
+        broadcast(non_existent),
+    );
+
+    // A sqrt(n) cache is not too small, not too large.
+    let mut duration_cache = FastFixedCache::new((len as f64).sqrt() as usize);
+    let out: Int64Chunked = timestamps
+        .iter()
+        .zip(every.iter())
+        .zip(ambiguous.iter())
+        .zip(non_existent.iter())
+        .map(|(((opt_t, opt_every), opt_ambiguous), opt_non_existent)| {
+            match (opt_t, opt_every, opt_ambiguous, opt_non_existent) {
+                (Some(t), Some(every), Some(ambiguous), Some(non_existent)) => {
+                    let every =
+                        *duration_cache.get_or_insert_with(every, |every| Duration::parse(every));
+                    func(
+                        t,
+                        every,
+                        parse_ambiguous(ambiguous)?,
+                        parse_non_existent(non_existent)?,
+                    )
+                },
+                _ => Ok(None),
+            }
+        })
+        .collect::<PolarsResult<_>>()?;
+    Ok(out.with_name(ca.name()))
 }
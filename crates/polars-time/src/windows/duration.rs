@@ -2,7 +2,6 @@ use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::ops::{Mul, Neg};
 
-#[cfg(feature = "timezones")]
 use arrow::legacy::kernels::{Ambiguous, NonExistent};
 use arrow::legacy::time_zone::Tz;
 use arrow::temporal_conversions::{
@@ -14,7 +13,7 @@ use polars_core::datatypes::DataType;
 use polars_core::export::arrow::temporal_conversions::MICROSECONDS;
 use polars_core::prelude::{
     datetime_to_timestamp_ms, datetime_to_timestamp_ns, datetime_to_timestamp_us, polars_bail,
-    PolarsResult,
+    PolarsResult, TimeUnit,
 };
 use polars_error::polars_ensure;
 #[cfg(feature = "serde")]
@@ -531,6 +530,9 @@ impl Duration {
     ///   original datetime was 2022-11-06 01:30:00 CST, then this would
     ///   be 2022-11-06 07:30:00.
     /// * `result_dt_local` - result, without time zone.
+    /// * `ambiguous` - how to localize an ambiguous result, `None` keeps the DST fold of the
+    ///   original datetime.
+    /// * `non_existent` - how to localize a non-existent result.
     #[cfg(feature = "timezones")]
     fn localize_result(
         &self,
@@ -538,9 +540,15 @@ impl Duration {
         original_dt_utc: NaiveDateTime,
         result_dt_local: NaiveDateTime,
         tz: &Tz,
-    ) -> PolarsResult<NaiveDateTime> {
-        match localize_datetime_opt(result_dt_local, tz, Ambiguous::Raise) {
-            Some(dt) => Ok(dt.expect("we didn't use Ambiguous::Null")),
+        ambiguous: Option<Ambiguous>,
+        non_existent: NonExistent,
+    ) -> PolarsResult<Option<NaiveDateTime>> {
+        match localize_datetime_opt(result_dt_local, tz, ambiguous.unwrap_or(Ambiguous::Raise)) {
+            Some(dt) => Ok(dt),
+            // The result is non-existent.
+            None if ambiguous.is_some() => {
+                try_localize_datetime(result_dt_local, tz, Ambiguous::Raise, non_existent)
+            },
             None => {
                 if try_localize_datetime(
                     original_dt_local,
@@ -551,13 +559,7 @@ impl Duration {
                 .expect("we didn't use Ambiguous::Null or NonExistent::Null")
                     == original_dt_utc
                 {
-                    Ok(try_localize_datetime(
-                        result_dt_local,
-                        tz,
-                        Ambiguous::Earliest,
-                        NonExistent::Raise,
-                    )?
-                    .expect("we didn't use Ambiguous::Null or NonExistent::Null"))
+                    try_localize_datetime(result_dt_local, tz, Ambiguous::Earliest, non_existent)
                 } else if try_localize_datetime(
                     original_dt_local,
                     tz,
@@ -567,13 +569,7 @@ impl Duration {
                 .expect("we didn't use Ambiguous::Null or NonExistent::Null")
                     == original_dt_utc
                 {
-                    Ok(try_localize_datetime(
-                        result_dt_local,
-                        tz,
-                        Ambiguous::Latest,
-                        NonExistent::Raise,
-                    )?
-                    .expect("we didn't use Ambiguous::Null or NonExistent::Null"))
+                    try_localize_datetime(result_dt_local, tz, Ambiguous::Latest, non_existent)
                 } else {
                     unreachable!()
                 }
@@ -581,6 +577,7 @@ impl Duration {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn truncate_subweekly<G, J>(
         &self,
         t: i64,
@@ -588,7 +585,9 @@ impl Duration {
         duration: i64,
         _timestamp_to_datetime: G,
         _datetime_to_timestamp: J,
-    ) -> PolarsResult<i64>
+        _ambiguous: Option<Ambiguous>,
+        _non_existent: NonExistent,
+    ) -> PolarsResult<Option<i64>>
     where
         G: Fn(i64) -> NaiveDateTime,
         J: Fn(NaiveDateTime) -> i64,
@@ -606,20 +605,27 @@ impl Duration {
                 }
                 let result_timestamp = t - remainder;
                 let result_dt_local = _timestamp_to_datetime(result_timestamp);
-                let result_dt_utc =
-                    self.localize_result(original_dt_local, original_dt_utc, result_dt_local, tz)?;
-                Ok(_datetime_to_timestamp(result_dt_utc))
+                let result_dt_utc = self.localize_result(
+                    original_dt_local,
+                    original_dt_utc,
+                    result_dt_local,
+                    tz,
+                    _ambiguous,
+                    _non_existent,
+                )?;
+                Ok(result_dt_utc.map(_datetime_to_timestamp))
             },
             _ => {
                 let mut remainder = t % duration;
                 if remainder < 0 {
                     remainder += duration
                 }
-                Ok(t - remainder)
+                Ok(Some(t - remainder))
             },
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn truncate_weekly<G, J>(
         &self,
         t: i64,
@@ -627,7 +633,9 @@ impl Duration {
         _timestamp_to_datetime: G,
         _datetime_to_timestamp: J,
         daily_duration: i64,
-    ) -> PolarsResult<i64>
+        _ambiguous: Option<Ambiguous>,
+        _non_existent: NonExistent,
+    ) -> PolarsResult<Option<i64>>
     where
         G: Fn(i64) -> NaiveDateTime,
         J: Fn(NaiveDateTime) -> i64,
@@ -668,12 +676,15 @@ impl Duration {
                     _original_dt_utc.unwrap(),
                     result_dt_local,
                     tz,
+                    _ambiguous,
+                    _non_existent,
                 )?;
-                Ok(_datetime_to_timestamp(result_dt_utc))
+                Ok(result_dt_utc.map(_datetime_to_timestamp))
             },
-            _ => Ok(result_t_local),
+            _ => Ok(Some(result_t_local)),
         }
     }
+    #[allow(clippy::too_many_arguments)]
     fn truncate_monthly<G, J>(
         &self,
         t: i64,
@@ -681,7 +692,9 @@ impl Duration {
         timestamp_to_datetime: G,
         datetime_to_timestamp: J,
         daily_duration: i64,
-    ) -> PolarsResult<i64>
+        _ambiguous: Option<Ambiguous>,
+        _non_existent: NonExistent,
+    ) -> PolarsResult<Option<i64>>
     where
         G: Fn(i64) -> NaiveDateTime,
         J: Fn(NaiveDateTime) -> i64,
@@ -750,11 +763,17 @@ impl Duration {
             // for UTC, use fastpath below (same as naive)
             Some(tz) if tz != &chrono_tz::UTC => {
                 let result_dt_local = timestamp_to_datetime(t - remainder_days * daily_duration);
-                let result_dt_utc =
-                    self.localize_result(original_dt_local, original_dt_utc, result_dt_local, tz)?;
-                Ok(datetime_to_timestamp(result_dt_utc))
+                let result_dt_utc = self.localize_result(
+                    original_dt_local,
+                    original_dt_utc,
+                    result_dt_local,
+                    tz,
+                    _ambiguous,
+                    _non_existent,
+                )?;
+                Ok(result_dt_utc.map(datetime_to_timestamp))
             },
-            _ => Ok(t - remainder_days * daily_duration),
+            _ => Ok(Some(t - remainder_days * daily_duration)),
         }
     }
 
@@ -767,6 +786,36 @@ impl Duration {
         timestamp_to_datetime: G,
         datetime_to_timestamp: J,
     ) -> PolarsResult<i64>
+    where
+        F: Fn(i64) -> i64,
+        G: Fn(i64) -> NaiveDateTime,
+        J: Fn(NaiveDateTime) -> i64,
+    {
+        Ok(self
+            .truncate_localized_impl(
+                t,
+                tz,
+                None,
+                NonExistent::Raise,
+                nsecs_to_unit,
+                timestamp_to_datetime,
+                datetime_to_timestamp,
+            )?
+            .expect("we didn't use Ambiguous::Null or NonExistent::Null"))
+    }
+
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    fn truncate_localized_impl<F, G, J>(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        ambiguous: Option<Ambiguous>,
+        non_existent: NonExistent,
+        nsecs_to_unit: F,
+        timestamp_to_datetime: G,
+        datetime_to_timestamp: J,
+    ) -> PolarsResult<Option<i64>>
     where
         F: Fn(i64) -> i64,
         G: Fn(i64) -> NaiveDateTime,
@@ -783,6 +832,8 @@ impl Duration {
                     duration,
                     timestamp_to_datetime,
                     datetime_to_timestamp,
+                    ambiguous,
+                    non_existent,
                 )
             },
             // truncate by days
@@ -794,6 +845,8 @@ impl Duration {
                     duration,
                     timestamp_to_datetime,
                     datetime_to_timestamp,
+                    ambiguous,
+                    non_existent,
                 )
             },
             // truncate by weeks
@@ -805,6 +858,8 @@ impl Duration {
                     timestamp_to_datetime,
                     datetime_to_timestamp,
                     duration,
+                    ambiguous,
+                    non_existent,
                 )
             },
             // truncate by months
@@ -816,6 +871,8 @@ impl Duration {
                     timestamp_to_datetime,
                     datetime_to_timestamp,
                     duration,
+                    ambiguous,
+                    non_existent,
                 )
             },
             _ => {
@@ -860,6 +917,48 @@ impl Duration {
         )
     }
 
+    /// Truncate the given timestamp by the window boundary and localize the result with the
+    /// given policies for ambiguous and non-existent datetimes. If `ambiguous` is `None`,
+    /// ambiguous results keep the DST fold of `t`.
+    pub fn truncate_localized(
+        &self,
+        t: i64,
+        tu: TimeUnit,
+        tz: Option<&Tz>,
+        ambiguous: Option<Ambiguous>,
+        non_existent: NonExistent,
+    ) -> PolarsResult<Option<i64>> {
+        match tu {
+            TimeUnit::Nanoseconds => self.truncate_localized_impl(
+                t,
+                tz,
+                ambiguous,
+                non_existent,
+                |nsecs| nsecs,
+                timestamp_ns_to_datetime,
+                datetime_to_timestamp_ns,
+            ),
+            TimeUnit::Microseconds => self.truncate_localized_impl(
+                t,
+                tz,
+                ambiguous,
+                non_existent,
+                |nsecs| nsecs / 1000,
+                timestamp_us_to_datetime,
+                datetime_to_timestamp_us,
+            ),
+            TimeUnit::Milliseconds => self.truncate_localized_impl(
+                t,
+                tz,
+                ambiguous,
+                non_existent,
+                |nsecs| nsecs / 1_000_000,
+                timestamp_ms_to_datetime,
+                datetime_to_timestamp_ms,
+            ),
+        }
+    }
+
     fn add_impl_month_week_or_day<F, G, J>(
         &self,
         t: i64,
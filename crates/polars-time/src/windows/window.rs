@@ -1,3 +1,4 @@
+use arrow::legacy::kernels::{Ambiguous, NonExistent};
 use arrow::legacy::time_zone::Tz;
 use arrow::temporal_conversions::*;
 use chrono::NaiveDateTime;
@@ -95,6 +96,37 @@ impl Window {
         self.truncate_ms(t, tz)
     }
 
+    /// Truncate the given timestamp by the window boundary, see [`Duration::truncate_localized`].
+    pub fn truncate_localized(
+        &self,
+        t: i64,
+        tu: TimeUnit,
+        tz: Option<&Tz>,
+        ambiguous: Option<Ambiguous>,
+        non_existent: NonExistent,
+    ) -> PolarsResult<Option<i64>> {
+        self.every
+            .truncate_localized(t, tu, tz, ambiguous, non_existent)
+    }
+
+    /// Round the given timestamp by the window boundary, see [`Duration::truncate_localized`].
+    pub fn round_localized(
+        &self,
+        t: i64,
+        tu: TimeUnit,
+        tz: Option<&Tz>,
+        ambiguous: Option<Ambiguous>,
+        non_existent: NonExistent,
+    ) -> PolarsResult<Option<i64>> {
+        let scale = match tu {
+            TimeUnit::Nanoseconds => 1,
+            TimeUnit::Microseconds => 1_000,
+            TimeUnit::Milliseconds => 1_000_000,
+        };
+        let t = t + self.every.duration_ns() / (2 * scale);
+        self.truncate_localized(t, tu, tz, ambiguous, non_existent)
+    }
+
     /// returns the bounds for the earliest window bounds
     /// that contains the given time t.  For underlapping windows that
     /// do not contain time t, the window directly after time t will be returned.
@@ -40,6 +40,8 @@ pub mod io;
 pub mod nulls;
 pub mod ord;
 pub mod partitioned;
+#[cfg(not(target_family = "wasm"))]
+pub mod pool;
 
 pub use index::{IdxSize, NullableIdxSize};
 pub use io::*;
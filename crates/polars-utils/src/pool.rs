@@ -0,0 +1,308 @@
+use std::cell::{Cell, UnsafeCell};
+use std::ops::Deref;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Condvar, Mutex};
+
+use once_cell::sync::Lazy;
+use rayon::{ThreadPool, ThreadPoolBuildError, ThreadPoolBuilder};
+
+const NO_LIMIT: usize = usize::MAX;
+
+/// The memory use of the query that runs on a pool of [`install_scoped`].
+pub struct QueryMemory {
+    soft_limit: AtomicUsize,
+    used: AtomicUsize,
+}
+
+impl QueryMemory {
+    const fn new() -> Self {
+        Self {
+            soft_limit: AtomicUsize::new(NO_LIMIT),
+            used: AtomicUsize::new(0),
+        }
+    }
+
+    fn reset(&self, soft_limit: Option<usize>) {
+        self.soft_limit
+            .store(soft_limit.unwrap_or(NO_LIMIT), Ordering::Relaxed);
+        self.used.store(0, Ordering::Relaxed);
+    }
+
+    /// The number of bytes the query tries to stay below.
+    pub fn soft_limit(&self) -> Option<usize> {
+        match self.soft_limit.load(Ordering::Relaxed) {
+            NO_LIMIT => None,
+            limit => Some(limit),
+        }
+    }
+
+    /// The number of bytes the operators of the query buffer in memory.
+    pub fn used(&self) -> usize {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    /// The number of bytes that are left until the soft limit is reached.
+    pub fn remaining(&self) -> Option<usize> {
+        self.soft_limit()
+            .map(|limit| limit.saturating_sub(self.used()))
+    }
+
+    /// Count `bytes` more towards the memory of the query and return the previous value.
+    pub fn fetch_add(&self, bytes: usize) -> usize {
+        self.used.fetch_add(bytes, Ordering::Relaxed)
+    }
+
+    /// Count `bytes` less towards the memory of the query, once an operator freed or spilled
+    /// them, and return the previous value.
+    pub fn fetch_sub(&self, bytes: usize) -> usize {
+        self.used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                Some(used.saturating_sub(bytes))
+            })
+            .unwrap()
+    }
+}
+
+/// The most pools of [`install_scoped`] that exist at the same time. Once this many queries run
+/// on them, further queries wait until one is done, so that the pools don't keep more threads
+/// alive than that.
+const MAX_SCOPED_POOLS: usize = 16;
+
+struct ScopedPool {
+    /// Only replaced while the pool is checked out, before a query runs on it.
+    pool: UnsafeCell<Option<ThreadPool>>,
+    memory: QueryMemory,
+}
+
+// SAFETY: the pool is only replaced by the owner of its `Checkout`, while no query runs on it
+// and so no thread reads it.
+unsafe impl Sync for ScopedPool {}
+
+impl ScopedPool {
+    fn num_threads(&self) -> Option<usize> {
+        // SAFETY: see `Sync`.
+        unsafe { &*self.pool.get() }
+            .as_ref()
+            .map(|pool| pool.current_num_threads())
+    }
+
+    /// Replace the threads of this pool by `num_threads` new ones.
+    ///
+    /// # Safety
+    /// The caller must own the `Checkout` of this pool, and no query may run on it.
+    unsafe fn rebuild(&'static self, num_threads: usize) -> Result<(), ThreadPoolBuildError> {
+        // The threads of the old pool exit once it's dropped.
+        *self.pool.get() = None;
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .thread_name(|i| format!("polars-query-{}", i))
+            .start_handler(move |_| SCOPED_POOL.with(|s| s.set(Some(self))))
+            .build()?;
+        *self.pool.get() = Some(pool);
+        Ok(())
+    }
+
+    fn pool(&self) -> &ThreadPool {
+        // SAFETY: see `Sync`. The pool is built before any work is scheduled on it.
+        unsafe { &*self.pool.get() }.as_ref().unwrap()
+    }
+}
+
+thread_local! {
+    // Set on the threads of a pool created by `install_scoped`.
+    static SCOPED_POOL: Cell<Option<&'static ScopedPool>> = const { Cell::new(None) };
+}
+
+/// The pools of `install_scoped`. They are never dropped, but their threads are replaced when
+/// a query needs another number of threads than any idle pool has.
+struct ScopedPools {
+    /// The pools that no query runs on.
+    idle: Vec<&'static ScopedPool>,
+    n_pools: usize,
+}
+
+static SCOPED_POOLS: Mutex<ScopedPools> = Mutex::new(ScopedPools {
+    idle: Vec::new(),
+    n_pools: 0,
+});
+// Notified when a pool becomes idle.
+static SCOPED_POOL_IDLE: Condvar = Condvar::new();
+
+/// Puts the pool back into the idle pools once the query is done, also if it panics.
+struct Checkout(&'static ScopedPool);
+
+impl Checkout {
+    fn try_new(num_threads: usize) -> Result<Self, ThreadPoolBuildError> {
+        let mut pools = SCOPED_POOLS.lock().unwrap();
+        let scoped = loop {
+            if let Some(i) = pools
+                .idle
+                .iter()
+                .position(|scoped| scoped.num_threads() == Some(num_threads))
+            {
+                return Ok(Self(pools.idle.swap_remove(i)));
+            }
+            if pools.n_pools < MAX_SCOPED_POOLS {
+                pools.n_pools += 1;
+                let scoped: &'static ScopedPool = Box::leak(Box::new(ScopedPool {
+                    pool: UnsafeCell::new(None),
+                    memory: QueryMemory::new(),
+                }));
+                break scoped;
+            }
+            // Another number of threads is needed than any idle pool has, so the threads of
+            // an idle pool are replaced.
+            if let Some(scoped) = pools.idle.pop() {
+                break scoped;
+            }
+            pools = SCOPED_POOL_IDLE.wait(pools).unwrap();
+        };
+        drop(pools);
+        let checkout = Self(scoped);
+        // SAFETY: the pool is checked out, and no query runs on it.
+        unsafe { scoped.rebuild(num_threads)? };
+        Ok(checkout)
+    }
+}
+
+impl Drop for Checkout {
+    fn drop(&mut self) {
+        SCOPED_POOLS.lock().unwrap().idle.push(self.0);
+        SCOPED_POOL_IDLE.notify_one();
+    }
+}
+
+fn current_scoped_pool() -> Option<&'static ScopedPool> {
+    SCOPED_POOL.with(|scoped| scoped.get())
+}
+
+/// The number of threads of the query that runs on the current thread, if it runs within
+/// [`install_scoped`].
+pub fn query_num_threads() -> Option<usize> {
+    current_scoped_pool().and_then(|scoped| scoped.num_threads())
+}
+
+/// The memory of the query that runs on the current thread, if it runs within
+/// [`install_scoped`].
+pub fn query_memory() -> Option<&'static QueryMemory> {
+    current_scoped_pool().map(|scoped| &scoped.memory)
+}
+
+/// The memory soft limit of the query that runs on the current thread, if any.
+pub fn query_memory_soft_limit() -> Option<usize> {
+    query_memory().and_then(|memory| memory.soft_limit())
+}
+
+/// Run `op` on a pool of `num_threads` threads that no other query runs on.
+///
+/// Work that `op` schedules on a [`PolarsThreadPool`] stays on that pool, so a query that runs
+/// in `op` doesn't use more than `num_threads` threads. The memory that the query reports to
+/// [`query_memory`] is counted against `memory_soft_limit`.
+///
+/// The pool is reused by later calls with the same number of threads. At most 16 pools exist
+/// at the same time, and further calls wait until a pool is idle.
+pub fn install_scoped<OP, R>(
+    num_threads: usize,
+    memory_soft_limit: Option<usize>,
+    op: OP,
+) -> Result<R, ThreadPoolBuildError>
+where
+    OP: FnOnce() -> R + Send,
+    R: Send,
+{
+    let checkout = Checkout::try_new(num_threads)?;
+    checkout.0.memory.reset(memory_soft_limit);
+    Ok(checkout.0.pool().install(op))
+}
+
+/// A global thread pool that dereferences to the pool of [`install_scoped`] on the threads of
+/// that pool.
+pub struct PolarsThreadPool {
+    global: Lazy<ThreadPool>,
+}
+
+impl PolarsThreadPool {
+    pub const fn new(init: fn() -> ThreadPool) -> Self {
+        Self {
+            global: Lazy::new(init),
+        }
+    }
+}
+
+impl Deref for PolarsThreadPool {
+    type Target = ThreadPool;
+
+    fn deref(&self) -> &ThreadPool {
+        match current_scoped_pool() {
+            Some(scoped) => scoped.pool(),
+            None => &*self.global,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static POOL: PolarsThreadPool =
+        PolarsThreadPool::new(|| ThreadPoolBuilder::new().num_threads(4).build().unwrap());
+
+    #[test]
+    fn test_install_scoped() {
+        let global = &*POOL as *const ThreadPool as usize;
+
+        let (scoped, num_threads, pending) = install_scoped(2, None, || {
+            POOL.install(|| {
+                (
+                    &*POOL as *const ThreadPool as usize,
+                    POOL.current_num_threads(),
+                    POOL.current_thread_has_pending_tasks(),
+                )
+            })
+        })
+        .unwrap();
+        assert_ne!(scoped, global);
+        assert_eq!(num_threads, 2);
+        assert_eq!(pending, Some(false));
+
+        // The pool is reused.
+        let reused = install_scoped(2, None, || &*POOL as *const ThreadPool as usize).unwrap();
+        assert_eq!(reused, scoped);
+    }
+
+    #[test]
+    fn test_scoped_pools_capped() {
+        for num_threads in 1..=MAX_SCOPED_POOLS + 1 {
+            let n = install_scoped(num_threads, None, || {
+                assert_eq!(query_num_threads(), Some(num_threads));
+                POOL.current_num_threads()
+            })
+            .unwrap();
+            assert_eq!(n, num_threads);
+        }
+        assert!(SCOPED_POOLS.lock().unwrap().n_pools <= MAX_SCOPED_POOLS);
+    }
+
+    #[test]
+    fn test_query_memory() {
+        assert!(query_memory().is_none());
+
+        let (remaining, other) = install_scoped(3, Some(100), || {
+            query_memory().unwrap().fetch_add(40);
+            // A query that runs at the same time has its own memory.
+            let other = std::thread::scope(|s| {
+                s.spawn(|| install_scoped(3, Some(100), || query_memory().unwrap().remaining()))
+                    .join()
+                    .unwrap()
+            });
+            (query_memory().unwrap().remaining(), other.unwrap())
+        })
+        .unwrap();
+        assert_eq!(remaining, Some(60));
+        assert_eq!(other, Some(100));
+
+        // The memory is reset for the next query.
+        let used = install_scoped(3, None, || query_memory().unwrap().used()).unwrap();
+        assert_eq!(used, 0);
+    }
+}
@@ -188,6 +188,8 @@
 //! * `timezones` - Activate timezone support.
 //! * `strings` - Extra string utilities for [`StringChunked`] //!     - `string_pad` - `zfill`, `ljust`, `rjust`
 //!     - `string_to_integer` - `parse_int`
+//!     - `string_checksum` - Validate identifiers with Luhn, MOD 97 or weighted check digits
+//!     - `string_normalize` - Unicode normalization and full case folding
 //! * `object` - Support for generic ChunkedArrays called [`ObjectChunked<T>`] (generic over `T`).
 //!              These are downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
 //! * Performance related:
@@ -206,6 +208,9 @@
 //!     - `parquet` - Read Apache Parquet format
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `snapshot` - Store aggregations over file scans and refresh them incrementally when
+//!                    files are appended to the dataset.
+//!     - `xlsx` - Write Excel workbooks
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
 //!                      Supported compressions:
 //!                         * zip
@@ -228,15 +233,19 @@
 //!     - `row_hash` - Utility to hash [`DataFrame`] rows to [`UInt64Chunked`]
 //!     - `diagonal_concat` - Concat diagonally thereby combining different schemas.
 //!     - `dataframe_arithmetic` - Arithmetic on ([`Dataframe`] and [`DataFrame`]s) and ([`DataFrame`] on [`Series`])
-//!     - `partition_by` - Split into multiple [`DataFrame`]s partitioned by groups.
+//!     - `partition_by` - Split into multiple [`DataFrame`]s partitioned by groups, and write
+//!       hive-partitioned datasets.
+//!     - `frame_patch` - Compute and apply patches between two versions of a keyed [`DataFrame`].
 //! * [`Series`]/[`Expr`] operations:
 //!     - `is_in` - Check for membership in [`Series`].
+//!     - `lookup` - Look up values by key in a small mapping table, built once.
 //!     - `zip_with` - [Zip two Series/ ChunkedArrays](crate::chunked_array::ops::ChunkZip).
 //!     - `round_series` - round underlying float types of [`Series`].
 //!     - `repeat_by` - [Repeat element in an Array N times, where N is given by another array.
 //!     - `is_first_distinct` - Check if element is first unique value.
 //!     - `is_last_distinct` - Check if element is last unique value.
 //!     - `is_between` - Check if this expression is between the given lower and upper bounds.
+//!     - `is_close` - Check if the values of two expressions are close within a tolerance.
 //!     - `checked_arithmetic` - checked arithmetic/ returning [`None`] on invalid operations.
 //!     - `dot_product` - Dot/inner product on [`Series`] and [`Expr`].
 //!     - `concat_str` - Concat string data in linear time.
@@ -253,6 +262,7 @@
 //!     - `moment` - kurtosis and skew statistics
 //!     - `ewma` - Exponential moving average windows
 //!     - `abs` - Get absolute values of [`Series`].
+//!     - `bitwise` - Bitwise not, shifts and and/or aggregations of integer [`Series`].
 //!     - `arange` - Range operation on [`Series`].
 //!     - `product` - Compute the product of a [`Series`].
 //!     - `diff` - [`diff`] operation.
@@ -260,6 +270,9 @@
 //!     - `unique_counts` - Count unique values in expressions.
 //!     - `log` - Logarithms for [`Series`].
 //!     - `list_to_struct` - Convert [`List`] to [`Struct`] dtypes.
+//!     - `list_zip` - Zip multiple [`List`] columns elementwise into a [`List`] of [`Struct`].
+//!     - `list_enumerate` - Pair the elements of a [`List`] with their index in a [`Struct`].
+//!     - `list_rolling` - Rolling sums and means within the lists of [`List`] columns.
 //!     - `list_count` - Count elements in lists.
 //!     - `list_eval` - Apply expressions over list elements.
 //!     - `list_sets` - Compute UNION, INTERSECTION, and DIFFERENCE on list types.
@@ -306,6 +319,7 @@
 //! | Int16                   | dtype-i16         |
 //! | UInt8                   | dtype-u8          |
 //! | UInt16                  | dtype-u16         |
+//! | Float16                 | dtype-f16         |
 //! | Categorical             | dtype-categorical |
 //! | Struct                  | dtype-struct      |
 //!
@@ -420,8 +434,10 @@ pub mod prelude;
 pub mod sql;
 
 pub use polars_core::{
-    apply_method_all_arrow_series, chunked_array, datatypes, df, error, frame, functions, series,
-    testing,
+    apply_method_all_arrow_series, chunked_array, datatypes, df, disable_string_pool,
+    enable_string_pool, error, frame, functions, release_string_pool, series,
+    set_string_pool_capacity, string_pool_stats, testing, using_string_pool, values,
+    StringPoolStats,
 };
 #[cfg(feature = "dtype-categorical")]
 pub use polars_core::{enable_string_cache, using_string_cache};
@@ -33,3 +33,87 @@ fn split_at() {
     assert_eq!(rhs.value(1), "!!!");
     assert_eq!(rhs.value(2), "hello there");
 }
+
+#[test]
+fn intern_in_string_pool_shares_buffers() {
+    let values = [
+        Some("a long value that is not inlined"),
+        None,
+        Some("short"),
+        Some("another long value that is not inlined"),
+        Some("a long value that is not inlined"),
+    ];
+    // Built without the pool being enabled.
+    let a = MutablePlString::from(values).freeze();
+    let b = MutablePlString::from(values).freeze();
+
+    let before = string_pool_stats();
+    let a = intern_in_string_pool(&a);
+    let b = intern_in_string_pool(&b);
+    let after = string_pool_stats();
+
+    assert_eq!(a, b);
+    assert_eq!(a.iter().collect::<Vec<_>>(), values);
+    let pointers = |arr: &Utf8ViewArray| {
+        arr.data_buffers()
+            .iter()
+            .map(|buffer| buffer.as_ptr())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(pointers(&a), pointers(&b));
+    assert!(after.hits >= before.hits + 4);
+}
+
+#[test]
+fn intern_in_string_pool_from_many_threads() {
+    let values = (0..100)
+        .map(|i| Some(format!("value number {} that is not inlined", i % 10)))
+        .collect::<Vec<_>>();
+    let arr = MutablePlString::from(&values).freeze();
+
+    let interned = std::thread::scope(|s| {
+        let handles = (0..8)
+            .map(|_| s.spawn(|| intern_in_string_pool(&arr)))
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    let first = &interned[0];
+    assert_eq!(
+        first
+            .iter()
+            .map(|v| v.map(str::to_string))
+            .collect::<Vec<_>>(),
+        values
+    );
+    for arr in &interned[1..] {
+        assert_eq!(arr, first);
+        // Every thread points into the same pooled bytes.
+        for (a, b) in arr.views().iter().zip(first.views().iter()) {
+            let a = unsafe { a.get_slice_unchecked(arr.data_buffers()) };
+            let b = unsafe { b.get_slice_unchecked(first.data_buffers()) };
+            assert_eq!(a.as_ptr(), b.as_ptr());
+        }
+    }
+}
+
+#[test]
+fn intern_in_string_pool_keeps_buffers_of_new_values() {
+    let values = [
+        Some("a value that is only interned by this test"),
+        Some("another value that is only interned by this test"),
+    ];
+    let arr = MutablePlString::from(values).freeze();
+    let interned = intern_in_string_pool(&arr);
+
+    assert_eq!(interned.iter().collect::<Vec<_>>(), values);
+    // The values are not copied into a new buffer.
+    for (a, b) in arr.views().iter().zip(interned.views().iter()) {
+        let a = unsafe { a.get_slice_unchecked(arr.data_buffers()) };
+        let b = unsafe { b.get_slice_unchecked(interned.data_buffers()) };
+        assert_eq!(a.as_ptr(), b.as_ptr());
+    }
+}
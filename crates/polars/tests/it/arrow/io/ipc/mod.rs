@@ -2,8 +2,10 @@ use std::io::Cursor;
 use std::sync::Arc;
 
 use arrow::array::*;
-use arrow::datatypes::{ArrowSchema, ArrowSchemaRef, Field};
-use arrow::io::ipc::read::{read_file_metadata, FileReader};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, ArrowSchemaRef, Field, IntegerType};
+use arrow::io::ipc::read::{
+    read_file_metadata, read_stream_metadata, FileReader, StreamReader, StreamState,
+};
 use arrow::io::ipc::write::*;
 use arrow::io::ipc::IpcField;
 use arrow::record_batch::RecordBatchT;
@@ -16,7 +18,10 @@ pub(crate) fn write(
     compression: Option<Compression>,
 ) -> PolarsResult<Vec<u8>> {
     let result = vec![];
-    let options = WriteOptions { compression };
+    let options = WriteOptions {
+        compression,
+        ..Default::default()
+    };
     let mut writer = FileWriter::try_new(result, schema.clone(), ipc_fields.clone(), options)?;
     for batch in batches {
         writer.write(batch, ipc_fields.as_ref().map(|x| x.as_ref()))?;
@@ -78,3 +83,97 @@ fn write_binview() -> PolarsResult<()> {
     let columns = RecordBatchT::try_new(vec![array])?;
     round_trip(columns, schema, None, Some(Compression::ZSTD))
 }
+
+/// Batches of a dictionary column whose dictionary grows from batch to batch.
+fn growing_dictionary_batches() -> PolarsResult<(ArrowSchemaRef, Vec<RecordBatchT<Box<dyn Array>>>)>
+{
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]);
+    let batches = [(vec![0u32, 1, 0], 2), (vec![2, 1], 3), (vec![3, 0], 4)]
+        .into_iter()
+        .map(|(keys, n_values)| {
+            let array = DictionaryArray::try_from_keys(
+                PrimitiveArray::from_vec(keys),
+                values.clone().sliced(0, n_values).boxed(),
+            )?;
+            RecordBatchT::try_new(vec![array.boxed()])
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let schema = prep_schema(batches[0].arrays()[0].as_ref());
+    Ok((schema, batches))
+}
+
+fn dictionary_strings(batch: &RecordBatchT<Box<dyn Array>>) -> Vec<Option<String>> {
+    let array = batch.arrays()[0]
+        .as_any()
+        .downcast_ref::<DictionaryArray<u32>>()
+        .unwrap();
+    array
+        .iter_typed::<Utf8Array<i32>>()
+        .unwrap()
+        .map(|v| v.map(|v| v.to_string()))
+        .collect()
+}
+
+#[test]
+fn write_dictionary_deltas_stream() -> PolarsResult<()> {
+    let (schema, batches) = growing_dictionary_batches()?;
+    let options = WriteOptions {
+        emit_dictionary_deltas: true,
+        ..Default::default()
+    };
+    let mut writer = StreamWriter::new(vec![], options);
+    writer.start(&schema, None)?;
+    for batch in &batches {
+        writer.write(batch, None)?;
+    }
+    writer.finish()?;
+    let mut buf = Cursor::new(writer.into_inner());
+
+    let metadata = read_stream_metadata(&mut buf)?;
+    let reader = StreamReader::new(buf, metadata, None);
+    let read = reader
+        .map(|state| match state? {
+            StreamState::Some(batch) => Ok(batch),
+            StreamState::Waiting => unreachable!(),
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    assert_eq!(read, batches);
+    Ok(())
+}
+
+#[test]
+fn write_dictionary_deltas_file() -> PolarsResult<()> {
+    let (schema, batches) = growing_dictionary_batches()?;
+
+    // Files can't replace dictionaries, but they can extend them.
+    assert!(write(&batches, &schema, None, None).is_err());
+
+    let options = WriteOptions {
+        emit_dictionary_deltas: true,
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+    for batch in &batches {
+        writer.write(batch, None)?;
+    }
+    writer.finish()?;
+    let mut reader = Cursor::new(writer.into_inner());
+    let metadata = read_file_metadata(&mut reader)?;
+    let read = FileReader::new(reader, metadata, None, None).collect::<PolarsResult<Vec<_>>>()?;
+
+    // The dictionaries of a file are read upfront, so all batches get the full dictionary.
+    assert_eq!(read.len(), batches.len());
+    for (read, expected) in read.iter().zip(&batches) {
+        assert_eq!(dictionary_strings(read), dictionary_strings(expected));
+    }
+    Ok(())
+}
+
+#[test]
+fn write_run_end_encoded_schema_errors() {
+    let values = Field::new("values", ArrowDataType::Int32, true);
+    let data_type = ArrowDataType::RunEndEncoded(IntegerType::Int32, Box::new(values));
+    let schema = Arc::new(ArrowSchema::from(vec![Field::new("a", data_type, true)]));
+    let writer = FileWriter::try_new(vec![], schema, None, Default::default());
+    assert!(matches!(writer, Err(PolarsError::ComputeError(_))));
+}
@@ -94,3 +94,38 @@ fn test_sorted_group_by() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "random")]
+fn test_sample_stratified() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b", "a", "b", "a", "b", "a", "b", "a"],
+        "v" => [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    ]?;
+    let counts = |df: &DataFrame| -> PolarsResult<[IdxSize; 2]> {
+        let g = df.column("g")?.str()?;
+        Ok([g.equal("a").sum().unwrap(), g.equal("b").sum().unwrap()])
+    };
+
+    let out = df.sample_stratified(["g"], SampleSize::N(2), false, false, Some(0))?;
+    assert_eq!(counts(&out)?, [2, 2]);
+    // Rows keep their original order.
+    let v: Vec<_> = out.column("v")?.i32()?.into_no_null_iter().collect();
+    assert!(v.windows(2).all(|w| w[0] < w[1]));
+
+    let out = df.sample_stratified(["g"], SampleSize::Frac(0.5), false, false, Some(0))?;
+    assert_eq!(counts(&out)?, [3, 2]);
+
+    // Seeded samples are deterministic.
+    let a = df.sample_stratified(["g"], SampleSize::N(3), false, true, Some(7))?;
+    let b = df.sample_stratified(["g"], SampleSize::N(3), false, true, Some(7))?;
+    assert!(a.equals(&b));
+
+    // Without replacement no group can be sampled beyond its size.
+    assert!(df
+        .sample_stratified(["g"], SampleSize::N(5), false, false, Some(0))
+        .is_err());
+    let out = df.sample_stratified(["g"], SampleSize::N(5), true, false, Some(0))?;
+    assert_eq!(counts(&out)?, [5, 5]);
+    Ok(())
+}
@@ -630,3 +630,154 @@ fn test_4_threads_bit_offset() -> PolarsResult<()> {
     assert_eq!(out.shape(), (1, 2));
     Ok(())
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_hints() -> PolarsResult<()> {
+    let (temp, rain) = create_frames();
+    let sort = |df: DataFrame| df.sort(["days", "temp", "rain_right"], Default::default());
+    let expected = sort(temp.inner_join(&rain, ["days"], ["days"])?)?;
+
+    for hints in [
+        JoinHints::default().with_strategy(JoinStrategy::Hash),
+        JoinHints::default().with_strategy(JoinStrategy::SortMerge),
+        JoinHints::default().with_strategy(JoinStrategy::BroadcastLeft),
+        JoinHints::default().with_strategy(JoinStrategy::BroadcastRight),
+        JoinHints::default().with_build_side(JoinBuildSide::Left),
+        JoinHints::default().with_build_side(JoinBuildSide::Right),
+    ] {
+        let args = JoinArgs::new(JoinType::Inner).with_hints(hints);
+        let out = temp.join(&rain, ["days"], ["days"], args)?;
+        assert!(sort(out)?.equals(&expected), "{:?}", hints);
+    }
+
+    // A sort-merge left join needs sorted left keys.
+    let args = JoinArgs::new(JoinType::Left)
+        .with_hints(JoinHints::default().with_strategy(JoinStrategy::SortMerge));
+    assert!(rain.join(&temp, ["days"], ["days"], args.clone()).is_err());
+    let out = temp.join(&rain, ["days"], ["days"], args)?;
+    let expected = temp.left_join(&rain, ["days"], ["days"])?;
+    assert!(out.equals_missing(&expected));
+
+    // Hints that can't be honored are rejected.
+    let args = JoinArgs::new(JoinType::Left)
+        .with_hints(JoinHints::default().with_build_side(JoinBuildSide::Left));
+    assert!(temp.join(&rain, ["days"], ["days"], args).is_err());
+    let args = JoinArgs::new(JoinType::Full)
+        .with_hints(JoinHints::default().with_strategy(JoinStrategy::SortMerge));
+    assert!(temp.join(&rain, ["days"], ["days"], args).is_err());
+    let args = JoinArgs::new(JoinType::Left)
+        .with_hints(JoinHints::default().with_strategy(JoinStrategy::BroadcastLeft));
+    assert!(temp.join(&rain, ["days"], ["days"], args).is_err());
+    let hints = JoinHints::default()
+        .with_strategy(JoinStrategy::BroadcastRight)
+        .with_build_side(JoinBuildSide::Left);
+    let args = JoinArgs::new(JoinType::Inner).with_hints(hints);
+    assert!(temp.join(&rain, ["days"], ["days"], args).is_err());
+
+    // Joins on multiple keys honor the build side, but can't be merged.
+    let on = ["days", "rain"];
+    let expected = temp.inner_join(&rain, on, on)?;
+    for build_side in [JoinBuildSide::Left, JoinBuildSide::Right] {
+        let args = JoinArgs::new(JoinType::Inner)
+            .with_hints(JoinHints::default().with_build_side(build_side));
+        assert!(temp.join(&rain, on, on, args)?.equals(&expected));
+    }
+    let args = JoinArgs::new(JoinType::Inner)
+        .with_hints(JoinHints::default().with_strategy(JoinStrategy::SortMerge));
+    assert!(temp.join(&rain, on, on, args).is_err());
+
+    // Semi and anti joins always build on the right input.
+    #[cfg(feature = "semi_anti_join")]
+    for how in [JoinType::Semi, JoinType::Anti] {
+        for on in [&["days"][..], &on[..]] {
+            let expected = temp.join(&rain, on, on, JoinArgs::new(how.clone()))?;
+            for hints in [
+                JoinHints::default().with_strategy(JoinStrategy::Hash),
+                JoinHints::default().with_strategy(JoinStrategy::BroadcastRight),
+                JoinHints::default().with_build_side(JoinBuildSide::Right),
+            ] {
+                let args = JoinArgs::new(how.clone()).with_hints(hints);
+                assert!(temp.join(&rain, on, on, args)?.equals(&expected));
+            }
+            for hints in [
+                JoinHints::default().with_strategy(JoinStrategy::SortMerge),
+                JoinHints::default().with_strategy(JoinStrategy::BroadcastLeft),
+                JoinHints::default().with_build_side(JoinBuildSide::Left),
+            ] {
+                let args = JoinArgs::new(how.clone()).with_hints(hints);
+                assert!(temp.join(&rain, on, on, args).is_err());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_join_validation_report() -> PolarsResult<()> {
+    let left = df![
+        "a" => [1, 1, 2, 3],
+        "b" => ["x", "x", "y", "z"],
+    ]?;
+    let right = df![
+        "a" => [1, 2, 2, 3, 3, 3],
+        "b" => ["x", "y", "y", "z", "z", "w"],
+    ]?;
+
+    let args = JoinArgs {
+        validation: JoinValidation::ManyToOne,
+        ..JoinArgs::new(JoinType::Inner)
+    };
+    let err = left
+        .join(&right, ["a"], ["a"], args.clone())
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("duplicated right keys: [2] (2 rows), [3] (3 rows)"),
+        "{err}"
+    );
+    let err = left
+        .join(&right, ["a", "b"], ["a", "b"], args)
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains(r#"duplicated right keys: [2, "y"] (2 rows), [3, "z"] (2 rows)"#),
+        "{err}"
+    );
+
+    let report = left.join_report(&right, ["a"], ["a"], JoinValidation::OneToOne, 1)?;
+    let expected = df!["a" => [1], "count" => [2 as IdxSize]]?;
+    assert!(report.left.unwrap().equals(&expected));
+    let expected = df!["a" => [2], "count" => [2 as IdxSize]]?;
+    assert!(report.right.unwrap().equals(&expected));
+
+    let report = left.join_report(
+        &right,
+        ["a", "b"],
+        ["a", "b"],
+        JoinValidation::OneToMany,
+        10,
+    )?;
+    assert!(report.right.is_none());
+    assert_eq!(report.left.unwrap().shape(), (1, 3));
+
+    // The count column doesn't collide with a key named "count".
+    let left = df!["count" => [1, 1, 2]]?;
+    let report = left.join_report(&left, ["count"], ["count"], JoinValidation::OneToOne, 10)?;
+    let expected = df!["count" => [1], "_count" => [2 as IdxSize]]?;
+    assert!(report.left.unwrap().equals(&expected));
+
+    // Errors other than failed validations are kept.
+    let right = df!["count" => ["1"]]?;
+    let args = JoinArgs {
+        validation: JoinValidation::OneToOne,
+        ..JoinArgs::new(JoinType::Inner)
+    };
+    let err = left
+        .join(&right, ["count"], ["count"], args)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("datatypes of join keys don't match"), "{err}");
+    Ok(())
+}
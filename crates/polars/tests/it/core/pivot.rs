@@ -270,3 +270,154 @@ fn test_pivot_datetime() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_pivot_collisions() -> PolarsResult<()> {
+    let df = df![
+        "index" => ["a", "a", "a", "b"],
+        "columns" => ["x", "x", "y", "x"],
+        "values" => [1, 2, 3, 4],
+    ]?;
+
+    let err = pivot_stable(
+        &df,
+        ["index"],
+        ["columns"],
+        Some(["values"]),
+        false,
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("colliding (index, columns) keys: [\"a\", \"x\"] (2 rows)"));
+
+    let out = pivot_stable(
+        &df,
+        ["index"],
+        ["columns"],
+        Some(["values"]),
+        false,
+        Some(PivotAgg::List),
+        None,
+    )?;
+    let x = out.column("x")?.list()?;
+    assert_eq!(
+        x.get_as_series(0).unwrap().i32()?.to_vec(),
+        &[Some(1), Some(2)]
+    );
+    assert_eq!(x.get_as_series(1).unwrap().i32()?.to_vec(), &[Some(4)]);
+    assert!(out.column("y")?.list()?.get_as_series(1).is_none());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_pivot_categorical_values_dtype() -> PolarsResult<()> {
+    let enum_dtype = create_enum_data_type(::arrow::array::Utf8ViewArray::from_slice_values([
+        "z", "y", "x",
+    ]));
+    let values = Series::new("v", ["x", "y", "z", "x"]);
+    let df = DataFrame::new(vec![
+        Series::new("i", [1, 1, 2, 2]),
+        Series::new("c", ["a", "a", "a", "b"]),
+        values.cast(&enum_dtype)?.with_name("enum"),
+        values
+            .cast(&DataType::Categorical(None, CategoricalOrdering::Lexical))?
+            .with_name("cat"),
+        values
+            .cast(&DataType::Categorical(None, CategoricalOrdering::Physical))?
+            .with_name("phys"),
+    ])?;
+    let same_rev_map = |a: &DataType, b: &DataType| match (a, b) {
+        (DataType::Enum(Some(a), _), DataType::Enum(Some(b), _))
+        | (DataType::Categorical(Some(a), _), DataType::Categorical(Some(b), _)) => a.same_src(b),
+        _ => false,
+    };
+    let to_str = |s: &Series| -> PolarsResult<Vec<Option<String>>> {
+        Ok(s.cast(&DataType::String)?
+            .str()?
+            .into_iter()
+            .map(|v| v.map(str::to_string))
+            .collect())
+    };
+
+    // The expected values of the `a` column, in the order "enum", "cat", "phys".
+    let cases = [
+        (PivotAgg::First, ["x", "x", "x"]),
+        (PivotAgg::Last, ["y", "y", "y"]),
+        // Enum and physical categoricals are ordered by their categories, "z" < "y" < "x" and
+        // "x" < "y" < "z" respectively.
+        (PivotAgg::Min, ["y", "x", "x"]),
+        (PivotAgg::Max, ["x", "y", "y"]),
+    ];
+    for (agg, expected) in cases {
+        let out = pivot_stable(
+            &df,
+            ["i"],
+            ["c"],
+            Some(["enum", "cat", "phys"]),
+            false,
+            Some(agg),
+            None,
+        )?;
+        for (name, expected) in ["enum", "cat", "phys"].into_iter().zip(expected) {
+            let input = df.column(name)?;
+            let a = out.column(&format!("{name}_a"))?;
+            let b = out.column(&format!("{name}_b"))?;
+            assert_eq!(a.dtype(), input.dtype());
+            assert!(same_rev_map(a.dtype(), input.dtype()));
+            assert!(same_rev_map(b.dtype(), input.dtype()));
+            assert_eq!(to_str(a)?, [Some(expected.to_string()), Some("z".into())]);
+            assert_eq!(to_str(b)?, [None, Some("x".into())]);
+        }
+    }
+
+    let out = pivot_stable(
+        &df.slice(1, 3),
+        ["i"],
+        ["c"],
+        Some(["enum"]),
+        false,
+        None,
+        None,
+    )?;
+    let values = out.column("b")?;
+    assert!(same_rev_map(values.dtype(), &enum_dtype));
+
+    let out = pivot_stable(
+        &df,
+        ["i"],
+        ["c"],
+        Some(["enum"]),
+        false,
+        Some(PivotAgg::List),
+        None,
+    )?;
+    assert_eq!(
+        out.column("a")?.dtype(),
+        &DataType::List(Box::new(enum_dtype.clone()))
+    );
+
+    // A round-trip through melt restores the values and their dtype.
+    let out = pivot_stable(
+        &df,
+        ["i"],
+        ["c"],
+        Some(["enum"]),
+        false,
+        Some(PivotAgg::First),
+        None,
+    )?;
+    let melted = out
+        .melt(["i"], ["a", "b"])?
+        .drop_nulls::<String>(None)?
+        .sort(["i", "variable"], Default::default())?;
+    assert_eq!(melted.column("value")?.dtype(), &enum_dtype);
+    assert_eq!(
+        to_str(melted.column("value")?)?,
+        ["x", "z", "x"].map(|v| Some(v.to_string()))
+    );
+    Ok(())
+}
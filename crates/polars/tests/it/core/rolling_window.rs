@@ -1,3 +1,5 @@
+use std::any::Any;
+
 use super::*;
 
 #[test]
@@ -280,3 +282,56 @@ fn test_rolling_var() {
         out, exp_res
     );
 }
+
+#[test]
+fn test_rolling_approx_quantile() {
+    let values = (0..500)
+        .map(|i| (i % 7 != 3).then_some(((i * 37) % 101) as f64 - 20.0))
+        .collect::<Vec<_>>();
+    let s = Float64Chunked::new("foo", &values).into_series();
+
+    let relative_accuracy = 0.01;
+    for (prob, center) in [(0.5, false), (0.1, true), (0.9, false)] {
+        let options = RollingOptionsFixedWindow {
+            window_size: 50,
+            min_periods: 10,
+            center,
+            ..Default::default()
+        };
+        let approx = s
+            .rolling_approx_quantile(RollingOptionsFixedWindow {
+                fn_params: Some(Arc::new(RollingApproxQuantileParams {
+                    prob,
+                    relative_accuracy,
+                }) as Arc<dyn Any + Send + Sync>),
+                ..options.clone()
+            })
+            .unwrap();
+        let exact = s
+            .rolling_quantile(RollingOptionsFixedWindow {
+                fn_params: Some(Arc::new(RollingQuantileParams {
+                    prob,
+                    interpol: QuantileInterpolOptions::Lower,
+                }) as Arc<dyn Any + Send + Sync>),
+                ..options
+            })
+            .unwrap();
+
+        for (a, e) in approx.f64().unwrap().iter().zip(exact.f64().unwrap()) {
+            match (a, e) {
+                (Some(a), Some(e)) => assert!((a - e).abs() <= relative_accuracy * e.abs()),
+                (a, e) => assert_eq!(a, e),
+            }
+        }
+    }
+
+    let options = RollingOptionsFixedWindow {
+        window_size: 3,
+        fn_params: Some(Arc::new(RollingApproxQuantileParams {
+            prob: 0.5,
+            relative_accuracy: 1.5,
+        }) as Arc<dyn Any + Send + Sync>),
+        ..Default::default()
+    };
+    assert!(s.rolling_approx_quantile(options).is_err());
+}
@@ -40,6 +40,29 @@ fn write_csv() {
     assert_eq!("0,22.1\r\n1,19.9\r\n2,7.0\r\n3,2.0\r\n4,3.0\r\n", csv);
 }
 
+#[test]
+fn write_csv_column_quote_styles() {
+    let mut df = df![
+        "a" => ["x", "y"],
+        "b" => [1, 2],
+        "c" => ["z", "w"],
+    ]
+    .unwrap();
+
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .with_line_terminator("\r\n".into())
+        .with_quote_style(QuoteStyle::NonNumeric)
+        .with_column_quote_styles(vec![
+            ("b".into(), QuoteStyle::Always),
+            ("c".into(), QuoteStyle::Never),
+        ])
+        .finish(&mut df)
+        .expect("csv written");
+    let csv = std::str::from_utf8(&buf).unwrap();
+    assert_eq!("\"a\",\"b\",c\r\n\"x\",\"1\",z\r\n\"y\",\"2\",w\r\n", csv);
+}
+
 #[test]
 fn write_dates() {
     let s0 = Series::new("date", [chrono::NaiveDate::from_yo_opt(2024, 33), None]);
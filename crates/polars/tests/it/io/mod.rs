@@ -14,6 +14,9 @@ mod ipc;
 #[cfg(feature = "ipc_streaming")]
 mod ipc_stream;
 
+#[cfg(feature = "xlsx")]
+mod xlsx;
+
 use polars::prelude::*;
 
 pub(crate) fn create_df() -> DataFrame {
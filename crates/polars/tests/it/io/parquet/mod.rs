@@ -8,6 +8,7 @@ use std::io::Cursor;
 use std::path::PathBuf;
 
 use polars::prelude::*;
+use polars_core::series::IsSorted;
 
 // The dynamic representation of values in native Rust. This is not exhaustive.
 // todo: maybe refactor this into serde/json?
@@ -212,3 +213,74 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     assert!(stacked.equals(&read_df));
     Ok(())
 }
+
+#[test]
+fn test_sorted_flag_roundtrip() -> PolarsResult<()> {
+    let mut df = df! {
+        "asc" => [Some(1), Some(2), Some(2), Some(5)],
+        "desc" => [None, Some("c"), Some("b"), Some("a")],
+        "unsorted" => [3, 1, 2, 0],
+    }?;
+    for (name, sorted) in [("asc", IsSorted::Ascending), ("desc", IsSorted::Descending)] {
+        df.apply(name, |s| {
+            let mut s = s.clone();
+            s.set_sorted_flag(sorted);
+            s
+        })?;
+    }
+
+    // Write multiple row groups.
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(2))
+        .finish(&mut df)?;
+
+    let metadata = ParquetReader::new(buf.clone()).get_metadata()?.clone();
+    let sorting_columns = metadata.row_groups[0].sorting_columns().unwrap();
+    assert_eq!(sorting_columns.len(), 2);
+    assert!(!sorting_columns[0].descending);
+    assert!(sorting_columns[1].descending && sorting_columns[1].nulls_first);
+
+    // Only the first sorting column of a row group is sorted on its own.
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert!(df.equals_missing(&read_df));
+    assert_eq!(read_df.column("asc")?.is_sorted_flag(), IsSorted::Ascending);
+    assert_eq!(read_df.column("desc")?.is_sorted_flag(), IsSorted::Not);
+    assert_eq!(read_df.column("unsorted")?.is_sorted_flag(), IsSorted::Not);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_categorical_dtype_roundtrip() -> PolarsResult<()> {
+    let categories = ::arrow::array::Utf8ViewArray::from_slice_values(["c", "b", "a", "unused"]);
+    let enum_dtype = create_enum_data_type(categories);
+    let values = Series::new("", [Some("a"), None, Some("c"), Some("a"), Some("b")]);
+    let mut df = DataFrame::new(vec![
+        values.cast(&enum_dtype)?.with_name("enum"),
+        values
+            .cast(&DataType::Categorical(None, CategoricalOrdering::Lexical))?
+            .with_name("cat"),
+    ])?;
+
+    // Every row group has its own dictionary.
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(2))
+        .finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+
+    for name in ["enum", "cat"] {
+        let expected = df.column(name)?.cast(&DataType::String)?;
+        assert!(read_df
+            .column(name)?
+            .cast(&DataType::String)?
+            .equals_missing(&expected));
+    }
+    assert_eq!(read_df.column("enum")?.dtype(), &enum_dtype);
+    let cat = read_df.column("cat")?.categorical()?;
+    assert!(cat.uses_lexical_ordering());
+    let cats = cat.get_rev_map().get_categories();
+    assert_eq!(cats.values_iter().collect::<Vec<_>>(), ["a", "c", "b"]);
+    Ok(())
+}
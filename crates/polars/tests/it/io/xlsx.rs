@@ -0,0 +1,85 @@
+use super::*;
+
+fn contains(buf: &[u8], needle: &str) -> bool {
+    buf.windows(needle.len()).any(|w| w == needle.as_bytes())
+}
+
+#[test]
+fn write_xlsx() -> PolarsResult<()> {
+    let mut df = create_df();
+    let mut buf: Vec<u8> = Vec::new();
+    XlsxWriter::new(&mut buf)
+        .with_sheet_name("weather")
+        .with_float_precision(Some(1))
+        .with_autofit(true)
+        .finish(&mut df)?;
+    // An xlsx workbook is a zip archive with a part per worksheet.
+    assert!(buf.starts_with(b"PK"));
+    assert!(contains(&buf, "xl/worksheets/sheet1.xml"));
+    assert!(!contains(&buf, "xl/worksheets/sheet2.xml"));
+    Ok(())
+}
+
+#[test]
+fn write_xlsx_multiple_sheets() -> PolarsResult<()> {
+    let df = create_df();
+    let mut buf: Vec<u8> = Vec::new();
+    let mut writer = XlsxWriter::new(&mut buf);
+    writer.write_sheet(&df, &XlsxSheetOptions::default().with_name("a"))?;
+    writer.write_sheet(
+        &df,
+        &XlsxSheetOptions::default()
+            .with_name("b")
+            .with_include_header(false)
+            .with_column_format("temp", "0.00"),
+    )?;
+    writer.close()?;
+    assert!(contains(&buf, "xl/worksheets/sheet2.xml"));
+    Ok(())
+}
+
+#[test]
+fn write_xlsx_errors() -> PolarsResult<()> {
+    let df = create_df();
+    let mut writer = XlsxWriter::new(Vec::new());
+    let options = XlsxSheetOptions::default().with_column_format("unknown", "0.00");
+    assert!(writer.write_sheet(&df, &options).is_err());
+
+    let df = df!("a" => [Series::new("", [1, 2])])?;
+    assert!(writer.write_sheet(&df, &Default::default()).is_err());
+    Ok(())
+}
+
+#[test]
+fn write_xlsx_parallel_sheets() -> PolarsResult<()> {
+    let df = create_df();
+    let options = XlsxSheetOptions::default()
+        .with_freeze_header(true)
+        .with_autofilter(true);
+    let sheets = (0..4)
+        .map(|i| (&df, options.clone().with_name(&format!("sheet {i}"))))
+        .collect::<Vec<_>>();
+    let mut buf: Vec<u8> = Vec::new();
+    let mut writer = XlsxWriter::new(&mut buf);
+    writer.write_sheets(&sheets)?;
+    writer.close()?;
+    assert!(contains(&buf, "xl/worksheets/sheet4.xml"));
+    assert!(!contains(&buf, "xl/worksheets/sheet5.xml"));
+    Ok(())
+}
+
+#[test]
+fn write_xlsx_constant_memory() -> PolarsResult<()> {
+    let df = create_df();
+    let mut buf: Vec<u8> = Vec::new();
+    let mut writer = XlsxWriter::new(&mut buf);
+    let options = XlsxSheetOptions::default().with_constant_memory(true);
+    writer.write_sheets(&[(&df, options.clone()), (&df, options.clone())])?;
+    assert!(writer
+        .write_sheet(&df, &options.with_autofit(true))
+        .is_err());
+    writer.close()?;
+    assert!(contains(&buf, "xl/worksheets/sheet2.xml"));
+    assert!(!contains(&buf, "xl/worksheets/sheet3.xml"));
+    Ok(())
+}
@@ -65,3 +65,40 @@ fn test_apply_multiple_error() {
         .collect()
         .unwrap();
 }
+
+#[test]
+fn test_min_max_nan_ordering() -> PolarsResult<()> {
+    let df = df!(
+        "g" => [1, 1, 2, 2],
+        "x" => [1.0, f64::NAN, f64::NAN, f64::NAN]
+    )?;
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            col("x")
+                .min_with_nan_ordering(NanOrdering::Largest)
+                .alias("min_largest"),
+            col("x")
+                .max_with_nan_ordering(NanOrdering::Largest)
+                .alias("max_largest"),
+            col("x")
+                .min_with_nan_ordering(NanOrdering::Smallest)
+                .alias("min_smallest"),
+            col("x")
+                .max_with_nan_ordering(NanOrdering::Smallest)
+                .alias("max_smallest"),
+            col("x")
+                .max_with_nan_ordering(NanOrdering::Null)
+                .alias("max_null"),
+        ])
+        .collect()?;
+
+    let get = |name: &str| Vec::from(out.column(name).unwrap().f64().unwrap());
+    assert_eq!(get("min_largest")[0], Some(1.0));
+    assert!(get("max_largest")[0].unwrap().is_nan());
+    assert!(get("min_smallest")[0].unwrap().is_nan());
+    assert_eq!(get("max_smallest")[0], Some(1.0));
+    assert_eq!(get("max_null"), &[Some(1.0), None]);
+    Ok(())
+}
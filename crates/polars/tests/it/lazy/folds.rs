@@ -25,3 +25,38 @@ fn test_fold_wildcard() -> PolarsResult<()> {
         .collect()?;
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "cum_agg")]
+fn test_scan_and_cum_reduce() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1), Some(2), None, Some(3)]
+    ]?;
+
+    let add = |acc: &AnyValue<'static>, v: AnyValue<'_>| {
+        Ok(AnyValue::Float64(
+            acc.extract::<f64>().unwrap() + v.extract::<f64>().unwrap(),
+        ))
+    };
+    let out = df
+        .lazy()
+        .select([
+            col("a").scan(lit(0.5), add).alias("scan"),
+            col("a").cum_reduce(lit(10), add).alias("cum_reduce"),
+        ])
+        .collect()?;
+
+    let scan = out.column("scan")?;
+    assert_eq!(scan.dtype(), &DataType::Float64);
+    assert_eq!(
+        Vec::from(scan.f64()?),
+        &[Some(1.5), Some(3.5), None, Some(6.5)]
+    );
+    let cum_reduce = out.column("cum_reduce")?;
+    assert_eq!(cum_reduce.dtype(), &DataType::Int32);
+    assert_eq!(
+        Vec::from(cum_reduce.i32()?),
+        &[Some(11), Some(13), None, Some(16)]
+    );
+    Ok(())
+}
@@ -61,3 +61,47 @@ fn test_group_by_dynamic_week_bounds() -> PolarsResult<()> {
     assert_eq!(a.get(1)?, AnyValue::Int32(6));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dynamic_group_by")]
+fn test_resample_down_and_up() -> PolarsResult<()> {
+    let df = df![
+        "idx" => [0i64, 1, 2, 6, 7],
+        "a" => [1i32, 2, 3, 4, 5]
+    ]?;
+
+    let out = df.resample(
+        "idx",
+        Duration::parse("2i"),
+        ResampleMethod::Aggregate(vec![col("a").sum()]),
+        ResampleOptions::default(),
+    )?;
+    let expected = df![
+        "idx" => [0i64, 2, 6],
+        "a" => [3i32, 3, 9]
+    ]?;
+    assert!(out.equals(&expected));
+
+    // Only the inserted rows are filled, from the rows of their own group.
+    let df = df![
+        "idx" => [0i64, 2, 4, 0, 3],
+        "g" => ["x", "x", "x", "y", "y"],
+        "a" => [Some(1i32), None, Some(3), Some(10), Some(20)]
+    ]?;
+    let out = df.resample(
+        "idx",
+        Duration::parse("1i"),
+        ResampleMethod::Fill(Some(FillNullStrategy::Forward(None))),
+        ResampleOptions {
+            group_by: vec!["g".into()],
+            ..Default::default()
+        },
+    )?;
+    let expected = df![
+        "idx" => [0i64, 1, 2, 3, 4, 0, 1, 2, 3],
+        "g" => ["x", "x", "x", "x", "x", "y", "y", "y", "y"],
+        "a" => [Some(1i32), Some(1), None, Some(1), Some(3), Some(10), Some(10), Some(10), Some(20)]
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
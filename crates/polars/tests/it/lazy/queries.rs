@@ -272,3 +272,27 @@ fn test_group_by_on_lists() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_error_code_and_context() {
+    use polars_core::error::{ContextKind, ErrorCode};
+
+    let df = df![
+        "a" => ["1", "x"],
+    ]
+    .unwrap();
+
+    let err = df.clone().lazy().select([col("b")]).collect().unwrap_err();
+    assert_eq!(err.code(), ErrorCode::ColumnNotFound);
+    assert_eq!(err.context_chain(), [(ContextKind::Operator, "select")]);
+
+    let err = df
+        .lazy()
+        .select([col("a").strict_cast(DataType::Int64)])
+        .collect()
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidOperation);
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    assert!(err.to_string().contains("in column 'a'"));
+    assert!(!err.is_retryable());
+}
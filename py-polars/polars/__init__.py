@@ -58,6 +58,7 @@ from polars.datatypes import (
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -128,12 +129,14 @@ from polars.functions import (
     cum_sum,
     cum_sum_horizontal,
     date,
+    date_from_isoweek,
     date_range,
     date_ranges,
     datetime,
     datetime_range,
     datetime_ranges,
     duration,
+    duration_from_string,
     element,
     exclude,
     field,
@@ -151,6 +154,7 @@ from polars.functions import (
     lit,
     map_batches,
     map_groups,
+    map_groups_flat,
     max,
     max_horizontal,
     mean,
@@ -182,6 +186,7 @@ from polars.functions import (
     zeros,
 )
 from polars.io import (
+    infer_schema,
     read_avro,
     read_clipboard,
     read_csv,
@@ -273,6 +278,7 @@ __all__ = [
     "Duration",
     "Enum",
     "Field",
+    "Float16",
     "Float32",
     "Float64",
     "Int8",
@@ -302,6 +308,7 @@ __all__ = [
     # polars.type_aliases
     "PolarsDataType",
     # polars.io
+    "infer_schema",
     "read_avro",
     "read_clipboard",
     "read_csv",
@@ -384,8 +391,10 @@ __all__ = [
     "cumfold",
     "cumreduce",
     "date",  # named date_, see import above
+    "date_from_isoweek",
     "datetime",  # named datetime_, see import above
     "duration",
+    "duration_from_string",
     "exclude",
     "field",
     "first",
@@ -401,6 +410,7 @@ __all__ = [
     "lit",
     "map_batches",
     "map_groups",
+    "map_groups_flat",
     "mean",
     "median",
     "n_unique",
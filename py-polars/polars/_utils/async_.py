@@ -8,7 +8,7 @@ from polars.dependencies import _GEVENT_AVAILABLE
 if TYPE_CHECKING:
     from asyncio.futures import Future
 
-    from polars.polars import PyDataFrame
+    from polars.polars import PyCancelToken, PyDataFrame
 
 
 T = TypeVar("T")
@@ -70,31 +70,43 @@ class _GeventDataFrameResult(Generic[T]):
 
 
 class _AioDataFrameResult(Awaitable[T], Generic[T]):
-    __slots__ = ("loop", "result")
+    __slots__ = ("loop", "result", "_cancel_token")
 
     def __init__(self) -> None:
         from asyncio import get_event_loop
 
         self.loop = get_event_loop()
         self.result: Future[T] = self.loop.create_future()
+        self.result.add_done_callback(self._done_callback)
+        self._cancel_token: PyCancelToken | None = None
 
     def __await__(self) -> Generator[Any, None, T]:
         return self.result.__await__()
 
+    def _done_callback(self, result: Future[T]) -> None:
+        # stop the query if nobody waits for it anymore
+        if result.cancelled() and self._cancel_token is not None:
+            self._cancel_token.cancel()
+
+    def _set_result(self, value: Any) -> None:
+        # the future may have been cancelled in the meantime
+        if not self.result.done():
+            self.result.set_result(value)
+
+    def _set_exception(self, exc: Exception) -> None:
+        if not self.result.done():
+            self.result.set_exception(exc)
+
     def _callback(self, obj: PyDataFrame | Exception) -> None:
         if isinstance(obj, Exception):
-            self.loop.call_soon_threadsafe(self.result.set_exception, obj)
+            self.loop.call_soon_threadsafe(self._set_exception, obj)
         else:
-            self.loop.call_soon_threadsafe(
-                self.result.set_result,  # type: ignore[arg-type]
-                wrap_df(obj),
-            )
+            self.loop.call_soon_threadsafe(self._set_result, wrap_df(obj))
 
     def _callback_all(self, obj: list[PyDataFrame] | Exception) -> None:
         if isinstance(obj, Exception):
-            self.loop.call_soon_threadsafe(self.result.set_exception, obj)
+            self.loop.call_soon_threadsafe(self._set_exception, obj)
         else:
             self.loop.call_soon_threadsafe(
-                self.result.set_result,  # type: ignore[arg-type]
-                [wrap_df(pydf) for pydf in obj],
+                self._set_result, [wrap_df(pydf) for pydf in obj]
             )
@@ -1,4 +1,5 @@
 from polars._utils.construction.dataframe import (
+    arrow_stream_to_pydf,
     arrow_to_pydf,
     dataframe_to_pydf,
     dict_to_pydf,
@@ -13,6 +14,7 @@ from polars._utils.construction.other import (
     pandas_series_to_arrow,
 )
 from polars._utils.construction.series import (
+    arrow_stream_to_pyseries,
     arrow_to_pyseries,
     dataframe_to_pyseries,
     iterable_to_pyseries,
@@ -24,6 +26,7 @@ from polars._utils.construction.series import (
 
 __all__ = [
     # dataframe
+    "arrow_stream_to_pydf",
     "arrow_to_pydf",
     "dataframe_to_pydf",
     "dict_to_pydf",
@@ -33,6 +36,7 @@ __all__ = [
     "sequence_to_pydf",
     "series_to_pydf",
     # series
+    "arrow_stream_to_pyseries",
     "arrow_to_pyseries",
     "dataframe_to_pyseries",
     "iterable_to_pyseries",
@@ -1377,6 +1377,26 @@ def series_to_pydf(
     return PyDataFrame(data_series)
 
 
+def arrow_stream_to_pydf(
+    data: Any,
+    schema: SchemaDefinition | None = None,
+    *,
+    schema_overrides: SchemaDict | None = None,
+    strict: bool = True,
+) -> PyDataFrame:
+    """
+    Construct a PyDataFrame from an object that exports an Arrow C stream.
+
+    Every record batch of the stream becomes a chunk of the columns.
+    """
+    pydf = PyDataFrame.from_arrow_c_stream(data)
+    if schema is None and schema_overrides is None:
+        return pydf
+    return dataframe_to_pydf(
+        wrap_df(pydf), schema=schema, schema_overrides=schema_overrides, strict=strict
+    )
+
+
 def dataframe_to_pydf(
     data: DataFrame,
     schema: SchemaDefinition | None = None,
@@ -429,6 +429,26 @@ def arrow_to_pyseries(
     )
 
 
+def arrow_stream_to_pyseries(
+    name: str,
+    values: Any,
+    dtype: PolarsDataType | None = None,
+    *,
+    strict: bool = True,
+) -> PySeries:
+    """
+    Construct a PySeries from an object that exports an Arrow C stream.
+
+    The stream is consumed chunk by chunk and the chunks are not concatenated.
+    """
+    pys = PySeries.from_arrow_c_stream(name, values)
+    return (
+        pys.cast(dtype, strict=strict, wrap_numerical=False)
+        if dtype is not None
+        else pys
+    )
+
+
 def numpy_to_pyseries(
     name: str,
     values: np.ndarray[Any, Any],
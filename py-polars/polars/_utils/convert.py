@@ -1,6 +1,8 @@
 from __future__ import annotations
 
-from datetime import datetime, time, timedelta, timezone
+import contextlib
+import os
+from datetime import datetime, time, timedelta, timezone, tzinfo
 from decimal import Context
 from functools import lru_cache
 from typing import (
@@ -25,8 +27,11 @@ from polars._utils.constants import (
 )
 from polars.dependencies import _ZONEINFO_AVAILABLE, zoneinfo
 
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars.polars as plr
+
 if TYPE_CHECKING:
-    from datetime import date, tzinfo
+    from datetime import date
     from decimal import Decimal
 
     from polars.type_aliases import TimeUnit
@@ -164,6 +169,9 @@ def to_py_datetime(
     elif _ZONEINFO_AVAILABLE:
         dt = EPOCH_UTC + td
         return _localize_datetime(dt, time_zone)
+    elif (fallback := _tz_fallback()) is not None:
+        dt = EPOCH_UTC + td
+        return _localize_datetime_fallback(dt, time_zone, fallback)
     else:
         msg = "install polars[timezone] to handle datetimes with time zone information"
         raise ImportError(msg)
@@ -175,11 +183,87 @@ def _localize_datetime(dt: datetime, time_zone: str) -> datetime:
         tz = string_to_zoneinfo(time_zone)
     except zoneinfo.ZoneInfoNotFoundError:
         # try fixed offset, which is not supported by ZoneInfo
-        tz = _parse_fixed_tz_offset(time_zone)
+        try:
+            tz = _parse_fixed_tz_offset(time_zone)
+        except ValueError:
+            # the time zone database of the system may be missing
+            if (fallback := _tz_fallback()) is None:
+                raise
+            return _localize_datetime_fallback(dt, time_zone, fallback)
+
+    return dt.astimezone(tz)
+
 
+def _tz_fallback() -> str | None:
+    return os.environ.get("POLARS_TZ_FALLBACK")
+
+
+def _localize_datetime_fallback(
+    dt: datetime, time_zone: str, fallback: str
+) -> datetime:
+    """Localize a UTC datetime without the time zone data of the system."""
+    if fallback == "bundled":
+        tz: tzinfo = _bundled_time_zone(time_zone)
+    else:
+        # the fixed UTC offset of the time zone at that instant
+        tz = plr.utc_offset(time_zone, dt.replace(tzinfo=None))
     return dt.astimezone(tz)
 
 
+@lru_cache(16)
+def _bundled_time_zone(key: str) -> tzinfo:
+    return BundledTimeZone(key)
+
+
+class BundledTimeZone(tzinfo):
+    """
+    A time zone that is looked up in the time zone database bundled with polars.
+
+    This stands in for `zoneinfo.ZoneInfo` when the time zone data of the system is
+    missing. Like `ZoneInfo`, it resolves ambiguous wall times with `fold`.
+    """
+
+    def __init__(self, key: str) -> None:
+        # raise for unknown time zones right away
+        plr.utc_offset(key, datetime(2000, 1, 1))
+        self.key = key
+
+    def utcoffset(self, dt: datetime | None) -> timedelta | None:
+        if dt is None:
+            return None
+        local = dt.replace(tzinfo=None)
+        return plr.local_utc_offset(self.key, local, bool(dt.fold)).utcoffset(None)
+
+    def dst(self, dt: datetime | None) -> timedelta | None:
+        if dt is None:
+            return None
+        local = dt.replace(tzinfo=None)
+        return plr.local_dst_offset(self.key, local, bool(dt.fold))
+
+    def tzname(self, dt: datetime | None) -> str:
+        return self.key
+
+    def fromutc(self, dt: datetime) -> datetime:
+        offset = plr.utc_offset(self.key, dt.replace(tzinfo=None)).utcoffset(None)
+        local = dt + offset
+        # the second occurrence of an ambiguous wall time has `fold=1`
+        if local.utcoffset() != offset:
+            local = local.replace(fold=1)
+        return local
+
+    def __eq__(self, other: object) -> bool:
+        return isinstance(other, BundledTimeZone) and other.key == self.key
+
+    def __hash__(self) -> int:
+        return hash(self.key)
+
+    def __repr__(self) -> str:
+        return f"BundledTimeZone({self.key!r})"
+
+    def __str__(self) -> str:
+        return self.key
+
+
 @no_type_check
 @lru_cache(None)
 def string_to_zoneinfo(key: str) -> Any:
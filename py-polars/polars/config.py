@@ -61,7 +61,9 @@ _POLARS_CFG_ENV_VARS = {
     "POLARS_FMT_TABLE_INLINE_COLUMN_DATA_TYPE",
     "POLARS_FMT_TABLE_ROUNDED_CORNERS",
     "POLARS_STREAMING_CHUNK_SIZE",
+    "POLARS_STREAMING_MEMORY_LIMIT",
     "POLARS_TABLE_WIDTH",
+    "POLARS_TZ_FALLBACK",
     "POLARS_VERBOSE",
     "POLARS_MAX_EXPR_DEPTH",
 }
@@ -748,6 +750,31 @@ class Config(contextlib.ContextDecorator):
             os.environ["POLARS_STREAMING_CHUNK_SIZE"] = str(size)
         return cls
 
+    @classmethod
+    def set_streaming_memory_limit(cls, limit: int | None) -> type[Config]:
+        """
+        Set the memory limit of operators in the new streaming engine.
+
+        Operators that have to buffer their input, such as equi-joins, spill
+        partitions of it to disk once the buffered data exceeds this limit.
+        By default, the limit is half of the memory that is free when the
+        query starts.
+
+        Parameters
+        ----------
+        limit
+            Maximum number of bytes an operator may keep in memory.
+        """
+        if limit is None:
+            os.environ.pop("POLARS_STREAMING_MEMORY_LIMIT", None)
+        else:
+            if limit < 0:
+                msg = "memory limit must be >= 0"
+                raise ValueError(msg)
+
+            os.environ["POLARS_STREAMING_MEMORY_LIMIT"] = str(limit)
+        return cls
+
     @classmethod
     def set_tbl_cell_alignment(
         cls, format: Literal["LEFT", "CENTER", "RIGHT"] | None
@@ -1261,6 +1288,52 @@ class Config(contextlib.ContextDecorator):
         plr.set_trim_decimal_zeros(active)
         return cls
 
+    @classmethod
+    def set_tz_fallback(cls, mode: Literal["bundled", "offset"] | None) -> type[Config]:
+        """
+        Set how to convert datetimes to Python if the time zone data is missing.
+
+        Converting time zone-aware datetimes to Python objects uses the time zone
+        database of the system (or the `tzdata` package), which may be missing in
+        minimal environments. By default, this raises an error.
+
+        Parameters
+        ----------
+        mode : {'bundled', 'offset', None}
+            * "bundled": look the time zone up in the time zone database that is
+              bundled with polars. The datetimes keep the time zone, so arithmetic on
+              them follows its daylight saving time transitions.
+            * "offset": convert to a datetime with the fixed UTC offset that the time
+              zone has at that instant, as found in the bundled time zone database.
+            * None: raise an error. This is the behavior if no fallback is set.
+
+        Notes
+        -----
+        The computations of polars itself, such as parsing strings with a time zone,
+        never need the time zone data of the system, as the time zone database is
+        bundled with polars. The datetimes that the fallback returns can be passed
+        back to polars, for example to `pl.lit`.
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> s = pl.Series([datetime(2020, 7, 1)]).dt.replace_time_zone("Europe/Paris")
+        >>> with pl.Config(tz_fallback="offset"):  # doctest: +SKIP
+        ...     s.to_list()
+        [datetime.datetime(2020, 7, 1, 0, 0, tzinfo=datetime.timezone(datetime.timedelta(seconds=7200)))]
+        """  # noqa: W505
+        if mode is None:
+            os.environ.pop("POLARS_TZ_FALLBACK", None)
+        elif mode in ("bundled", "offset"):
+            os.environ["POLARS_TZ_FALLBACK"] = mode
+        else:
+            msg = (
+                f"invalid time zone fallback mode: {mode!r};"
+                " expected 'bundled', 'offset' or None"
+            )
+            raise ValueError(msg)
+        return cls
+
     @classmethod
     def set_verbose(cls, active: bool | None = True) -> type[Config]:
         """
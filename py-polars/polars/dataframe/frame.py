@@ -32,6 +32,7 @@ from typing import (
 import polars._reexport as pl
 from polars import functions as F
 from polars._utils.construction import (
+    arrow_stream_to_pydf,
     arrow_to_pydf,
     dataframe_to_pydf,
     dict_to_pydf,
@@ -48,7 +49,10 @@ from polars._utils.deprecation import (
     issue_deprecation_warning,
 )
 from polars._utils.getitem import get_df_item_by_key
-from polars._utils.parse import parse_into_expression
+from polars._utils.parse import (
+    parse_into_expression,
+    parse_into_list_of_expressions,
+)
 from polars._utils.unstable import issue_unstable_warning, unstable
 from polars._utils.various import (
     is_bool_sequence,
@@ -135,17 +139,21 @@ if TYPE_CHECKING:
         ConnectionOrCursor,
         CsvQuoteStyle,
         DbWriteEngine,
+        ExistingDataBehavior,
         FillNullStrategy,
         FrameInitTypes,
         IndexOrder,
         IntoExpr,
         IntoExprColumn,
         IpcCompression,
+        JoinExecutionStrategy,
         JoinStrategy,
         JoinValidation,
+        JsonTemporalFormat,
         Label,
         MultiColSelector,
         MultiIndexSelector,
+        NanOrdering,
         OneOrMoreDataTypes,
         Orientation,
         ParquetCompression,
@@ -396,6 +404,11 @@ class DataFrame:
                 data, schema=schema, schema_overrides=schema_overrides, strict=strict
             )
 
+        elif hasattr(data, "__arrow_c_stream__"):
+            self._df = arrow_stream_to_pydf(
+                data, schema=schema, schema_overrides=schema_overrides, strict=strict
+            )
+
         elif not isinstance(data, Sized) and isinstance(data, (Generator, Iterable)):
             self._df = iterable_to_pydf(
                 data,
@@ -2436,12 +2449,33 @@ class DataFrame:
             return None
 
     @overload
-    def write_ndjson(self, file: None = None) -> str: ...
+    def write_ndjson(
+        self,
+        file: None = None,
+        *,
+        temporal_format: JsonTemporalFormat = ...,
+        decimal_as_string: bool = ...,
+        omit_nulls: bool = ...,
+    ) -> str: ...
 
     @overload
-    def write_ndjson(self, file: IOBase | str | Path) -> None: ...
+    def write_ndjson(
+        self,
+        file: IOBase | str | Path,
+        *,
+        temporal_format: JsonTemporalFormat = ...,
+        decimal_as_string: bool = ...,
+        omit_nulls: bool = ...,
+    ) -> None: ...
 
-    def write_ndjson(self, file: IOBase | str | Path | None = None) -> str | None:
+    def write_ndjson(
+        self,
+        file: IOBase | str | Path | None = None,
+        *,
+        temporal_format: JsonTemporalFormat = "display",
+        decimal_as_string: bool = False,
+        omit_nulls: bool = False,
+    ) -> str | None:
         r"""
         Serialize to newline delimited JSON representation.
 
@@ -2450,6 +2484,23 @@ class DataFrame:
         file
             File path or writable file-like object to which the result will be written.
             If set to `None` (default), the output is returned as a string instead.
+        temporal_format : {'display', 'iso8601', 'epoch'}
+            How dates, times, datetimes and durations are written.
+
+            - display: strings in the format in which the values are displayed,
+              e.g. `"2024-01-01 12:00:00"`.
+            - iso8601: ISO 8601 strings, e.g. `"2024-01-01T12:00:00"`.
+            - epoch: integers of the underlying values, i.e. days since the epoch
+              for dates and a number of time units for the other types.
+
+            Datetimes with a time zone are written as RFC 3339 strings, unless
+            `"epoch"` is set. Many JSON consumers don't accept the integers, so set
+            `"iso8601"` if the output is read by other systems.
+        decimal_as_string
+            Write decimals as strings instead of numbers, so that readers that
+            parse numbers as floats don't lose precision.
+        omit_nulls
+            Leave fields out of a row when their value is null, also in structs.
 
         Examples
         --------
@@ -2461,11 +2512,26 @@ class DataFrame:
         ... )
         >>> df.write_ndjson()
         '{"foo":1,"bar":6}\n{"foo":2,"bar":7}\n{"foo":3,"bar":8}\n'
+
+        Write datetimes as ISO 8601 strings and leave out null values.
+
+        >>> from datetime import datetime
+        >>> df = pl.DataFrame({"ts": [datetime(2024, 1, 1, 12), None], "x": [1, 2]})
+        >>> df.write_ndjson(temporal_format="iso8601", omit_nulls=True)
+        '{"ts":"2024-01-01T12:00:00","x":1}\n{"x":2}\n'
         """
 
+        def write_ndjson(file: IOBase | str) -> None:
+            self._df.write_ndjson(
+                file,
+                temporal_format=temporal_format,
+                decimal_as_string=decimal_as_string,
+                omit_nulls=omit_nulls,
+            )
+
         def write_ndjson_to_string() -> str:
             with BytesIO() as buf:
-                self._df.write_ndjson(buf)
+                write_ndjson(buf)
                 ndjson_bytes = buf.getvalue()
             return ndjson_bytes.decode("utf8")
 
@@ -2477,10 +2543,10 @@ class DataFrame:
             return None
         elif isinstance(file, (str, Path)):
             file = normalize_filepath(file)
-            self._df.write_ndjson(file)
+            write_ndjson(file)
             return None
         else:
-            self._df.write_ndjson(file)
+            write_ndjson(file)
             return None
 
     @overload
@@ -2500,6 +2566,7 @@ class DataFrame:
         float_precision: int | None = ...,
         null_value: str | None = ...,
         quote_style: CsvQuoteStyle | None = ...,
+        column_quote_styles: dict[str, CsvQuoteStyle] | None = ...,
     ) -> str: ...
 
     @overload
@@ -2519,6 +2586,7 @@ class DataFrame:
         float_precision: int | None = ...,
         null_value: str | None = ...,
         quote_style: CsvQuoteStyle | None = ...,
+        column_quote_styles: dict[str, CsvQuoteStyle] | None = ...,
     ) -> None: ...
 
     def write_csv(
@@ -2537,6 +2605,7 @@ class DataFrame:
         float_precision: int | None = None,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        column_quote_styles: dict[str, CsvQuoteStyle] | None = None,
     ) -> str | None:
         """
         Write to comma-separated values (CSV) file.
@@ -2593,6 +2662,10 @@ class DataFrame:
               Namely, when writing a field that does not parse as a valid float
               or integer, then quotes will be used even if they aren`t strictly
               necessary.
+        column_quote_styles
+            Quoting strategies of individual columns, by column name, that override
+            `quote_style`. They also apply to the names of these columns in the
+            header.
 
         Examples
         --------
@@ -2638,6 +2711,7 @@ class DataFrame:
             float_precision,
             null_value,
             quote_style,
+            list((column_quote_styles or {}).items()),
         )
 
         if should_return_buffer:
@@ -2739,7 +2813,8 @@ class DataFrame:
             | tuple[int, int, int, int]
             | None
         ) = None,
-    ) -> Workbook:
+        engine: Literal["xlsxwriter", "rust"] = "xlsxwriter",
+    ) -> Workbook | None:
         """
         Write frame data to a table in an Excel workbook/worksheet.
 
@@ -2872,6 +2947,21 @@ class DataFrame:
               the `top_row` and `top_col`. Thus, to freeze only the top row and have the
               scrolling region begin at row 10, column D (5th col), supply (1, 0, 9, 4).
               Using cell notation for (row, col), supplying ("A2", 9, 4) is equivalent.
+        engine : {'xlsxwriter', 'rust'}
+            Library used to write the workbook.
+
+            * "xlsxwriter" supports all of the options above and returns the
+              `xlsxwriter.Workbook`.
+            * "rust" writes a plain worksheet natively, without requiring
+              `xlsxwriter`. Only `worksheet`, `float_precision`, `include_header`,
+              `autofit`, `autofilter`, string-valued `column_formats` keyed by column
+              name and a `freeze_panes` of `"A2"` or `(1, 0)`, which freezes the
+              header row, are supported; `workbook` must be a path or a file-like
+              object. Returns None.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Notes
         -----
@@ -3064,6 +3154,39 @@ class DataFrame:
         ...     sheet_zoom=125,
         ... )
         """  # noqa: W505
+        if engine == "rust":
+            return self._write_excel_native(
+                workbook,
+                worksheet,
+                column_formats=column_formats,
+                float_precision=float_precision,
+                include_header=include_header,
+                autofit=autofit,
+                autofilter=autofilter,
+                freeze_header=freeze_panes in ("A2", (1, 0)),
+                unsupported={
+                    "position": position != "A1",
+                    "table_style": table_style is not None,
+                    "table_name": table_name is not None,
+                    "dtype_formats": dtype_formats is not None,
+                    "conditional_formats": conditional_formats is not None,
+                    "header_format": header_format is not None,
+                    "column_totals": column_totals is not None,
+                    "column_widths": column_widths is not None,
+                    "row_totals": row_totals is not None,
+                    "row_heights": row_heights is not None,
+                    "sparklines": sparklines is not None,
+                    "formulas": formulas is not None,
+                    "hidden_columns": hidden_columns is not None,
+                    "hide_gridlines": hide_gridlines,
+                    "sheet_zoom": sheet_zoom is not None,
+                    "freeze_panes": freeze_panes not in (None, "A2", (1, 0)),
+                },
+            )
+        elif engine != "xlsxwriter":
+            msg = f"`engine` must be one of {{'xlsxwriter', 'rust'}}, got {engine!r}"
+            raise ValueError(msg)
+
         from polars.io.spreadsheet._write_utils import (
             _unpack_multi_column_dict,
             _xl_apply_conditional_formats,
@@ -3221,6 +3344,51 @@ class DataFrame:
             wb.close()
         return wb
 
+    def _write_excel_native(
+        self,
+        workbook: Any,
+        worksheet: str | None,
+        *,
+        column_formats: ColumnFormatDict | None,
+        float_precision: int,
+        include_header: bool,
+        autofit: bool,
+        autofilter: bool,
+        freeze_header: bool,
+        unsupported: dict[str, bool],
+    ) -> None:
+        """Write a single worksheet with the native Rust writer."""
+        if used := [name for name, is_set in unsupported.items() if is_set]:
+            msg = f"`engine='rust'` does not support {', '.join(map(repr, used))}"
+            raise ValueError(msg)
+
+        formats: dict[str, str] = {}
+        for column, fmt in (column_formats or {}).items():
+            if not isinstance(column, str) or not isinstance(fmt, str):
+                msg = (
+                    "`engine='rust'` only supports `column_formats` that map"
+                    " column names to format strings"
+                )
+                raise TypeError(msg)
+            formats[column] = fmt
+
+        if workbook is None or isinstance(workbook, (str, Path)):
+            file = Path("dataframe.xlsx" if workbook is None else workbook)
+            workbook = normalize_filepath(
+                file if file.suffix else file.with_suffix(".xlsx")
+            )
+
+        self._df.write_excel(
+            workbook,
+            worksheet,
+            include_header,
+            autofit,
+            autofilter,
+            freeze_header,
+            float_precision,
+            formats,
+        )
+
     @overload
     def write_ipc(
         self,
@@ -3366,6 +3534,9 @@ class DataFrame:
         data_page_size: int | None = None,
         use_pyarrow: bool = False,
         pyarrow_options: dict[str, Any] | None = None,
+        partition_by: str | Sequence[str] | None = None,
+        max_rows_per_file: int | None = None,
+        existing_data: ExistingDataBehavior = "error",
     ) -> None:
         """
         Write to Apache Parquet file.
@@ -3374,6 +3545,7 @@ class DataFrame:
         ----------
         file
             File path or writable file-like object to which the result will be written.
+            If `partition_by` is set, the path of the root directory of the dataset.
         compression : {'lz4', 'uncompressed', 'snappy', 'gzip', 'lzo', 'brotli', 'zstd'}
             Choose "zstd" for good compression performance.
             Choose "lz4" for fast compression/decompression.
@@ -3417,6 +3589,24 @@ class DataFrame:
             using `pyarrow.parquet.write_to_dataset`.
             The `partition_cols` parameter leads to write the dataset to a directory.
             Similar to Spark's partitioned datasets.
+        partition_by
+            Column(s) to partition the dataset by. Every partition is written to a
+            hive-style directory such as `file/a=1/b=x`, in which keys are
+            percent-encoded and null keys are written as `__HIVE_DEFAULT_PARTITION__`.
+            The partition columns are not written to the files; they are restored
+            when reading the dataset with `hive_partitioning=True`. Cannot be combined
+            with `use_pyarrow`.
+        max_rows_per_file
+            Split partitions into files of at most this many rows. Only used with
+            `partition_by`; by default every partition is written to a single file.
+        existing_data : {'overwrite', 'append', 'error'}
+            What to do with data that already exists in the directory. Only used with
+            `partition_by`.
+
+            - "error": raise an error if the directory already contains data.
+            - "overwrite": remove the existing files of the partitions that are
+              written; other partitions are kept.
+            - "append": write the new files next to the existing files.
 
         Examples
         --------
@@ -3444,11 +3634,29 @@ class DataFrame:
         ...     use_pyarrow=True,
         ...     pyarrow_options={"partition_cols": ["watermark"]},
         ... )
+
+        The same dataset can be written without pyarrow with `partition_by`, here
+        replacing the files written by pyarrow.
+
+        >>> df.write_parquet(path, partition_by="watermark", existing_data="overwrite")
         """
         if compression is None:
             compression = "uncompressed"
+        if partition_by is not None:
+            if use_pyarrow:
+                msg = "`partition_by` cannot be combined with `use_pyarrow`"
+                raise ValueError(msg)
+            if not isinstance(file, (str, Path)):
+                msg = "`file` must be a directory path if `partition_by` is set"
+                raise TypeError(msg)
+            if isinstance(partition_by, str):
+                partition_by = [partition_by]
+            else:
+                partition_by = list(partition_by)
         if isinstance(file, (str, Path)):
-            if pyarrow_options is not None and pyarrow_options.get("partition_cols"):
+            if partition_by is not None or (
+                pyarrow_options is not None and pyarrow_options.get("partition_cols")
+            ):
                 file = normalize_filepath(file, check_not_directory=False)
             else:
                 file = normalize_filepath(file)
@@ -3521,6 +3729,9 @@ class DataFrame:
                 statistics,
                 row_group_size,
                 data_page_size,
+                partition_by,
+                max_rows_per_file,
+                existing_data,
             )
 
     def write_database(
@@ -4001,6 +4212,43 @@ class DataFrame:
         sz = self._df.estimated_size()
         return scale_bytes(sz, unit)
 
+    def memory_usage(self, *, deep: bool = False) -> DataFrame:
+        """
+        Return the estimated size in bytes of the buffers of every column.
+
+        The size of a column is split into the size of its data, validity, offsets
+        and string view heap buffers. The sizes of nested columns include the
+        buffers of their children. See :meth:`estimated_size` for the caveats of
+        the estimation.
+
+        Parameters
+        ----------
+        deep
+            Count all the heap buffers that the string and binary columns keep alive,
+            instead of only the bytes that they refer to. A large difference points
+            to a column that holds on to the strings of rows that were filtered out.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "x": [1, 2, None],
+        ...         "s": ["a", "a much longer string value", None],
+        ...     }
+        ... )
+        >>> df.memory_usage()
+        shape: (2, 6)
+        ┌────────┬──────┬──────────┬─────────┬──────┬───────┐
+        │ column ┆ data ┆ validity ┆ offsets ┆ heap ┆ total │
+        │ ---    ┆ ---  ┆ ---      ┆ ---     ┆ ---  ┆ ---   │
+        │ str    ┆ u64  ┆ u64      ┆ u64     ┆ u64  ┆ u64   │
+        ╞════════╪══════╪══════════╪═════════╪══════╪═══════╡
+        │ x      ┆ 24   ┆ 1        ┆ 0       ┆ 0    ┆ 25    │
+        │ s      ┆ 48   ┆ 1        ┆ 0       ┆ 26   ┆ 75    │
+        └────────┴──────┴──────────┴─────────┴──────┴───────┘
+        """
+        return self._from_pydf(self._df.memory_usage(deep))
+
     def transpose(
         self,
         *,
@@ -4627,6 +4875,7 @@ class DataFrame:
         nulls_last: bool | Sequence[bool] = False,
         multithreaded: bool = True,
         maintain_order: bool = False,
+        nan_ordering: NanOrdering = "largest",
     ) -> DataFrame:
         """
         Sort the dataframe by the given columns.
@@ -4648,6 +4897,9 @@ class DataFrame:
             Sort using multiple threads.
         maintain_order
             Whether the order should be maintained if elements are equal.
+        nan_ordering : {'largest', 'smallest', 'null'}
+            Where to place `NaN` values of float columns: as the largest values, as the
+            smallest values, or as null values.
 
         Examples
         --------
@@ -4723,6 +4975,7 @@ class DataFrame:
                 nulls_last=nulls_last,
                 multithreaded=multithreaded,
                 maintain_order=maintain_order,
+                nan_ordering=nan_ordering,
             )
             .collect(_eager=True)
         )
@@ -5021,6 +5274,99 @@ class DataFrame:
         """
         return self._df.equals(other._df, null_equal=null_equal)
 
+    @unstable()
+    def compute_patch(self, other: DataFrame, on: str | Sequence[str]) -> DataFrame:
+        """
+        Compute the patch that turns this DataFrame into another version of it.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Both frames must have the same schema and unique keys. The patch is a
+        DataFrame with a row for every updated, inserted and deleted key: the key
+        columns, an `__op` column with the operation of the row and, for every
+        column with a changed value, the new values and a `<name>__changed` column
+        that marks the rows in which the value changed. Columns without any change
+        are left out, so the patch can be written to IPC and sent to another
+        service to be applied with :meth:`apply_patch`.
+
+        Parameters
+        ----------
+        other
+            The new version of the DataFrame.
+        on
+            Name(s) of the key columns.
+
+        See Also
+        --------
+        apply_patch
+
+        Examples
+        --------
+        >>> old = pl.DataFrame({"id": [1, 2, 3], "value": ["a", "b", "c"]})
+        >>> new = pl.DataFrame({"id": [1, 3, 4], "value": ["a", "x", "d"]})
+        >>> old.compute_patch(new, on="id")
+        shape: (3, 4)
+        ┌─────┬────────┬───────┬────────────────┐
+        │ id  ┆ __op   ┆ value ┆ value__changed │
+        │ --- ┆ ---    ┆ ---   ┆ ---            │
+        │ i64 ┆ str    ┆ str   ┆ bool           │
+        ╞═════╪════════╪═══════╪════════════════╡
+        │ 3   ┆ update ┆ x     ┆ true           │
+        │ 4   ┆ insert ┆ d     ┆ true           │
+        │ 2   ┆ delete ┆ null  ┆ false          │
+        └─────┴────────┴───────┴────────────────┘
+        """
+        if isinstance(on, str):
+            on = [on]
+        return self._from_pydf(self._df.compute_patch(other._df, list(on)))
+
+    @unstable()
+    def apply_patch(self, patch: DataFrame, on: str | Sequence[str]) -> DataFrame:
+        """
+        Apply a patch computed with :meth:`compute_patch`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Updated rows keep their position and inserted rows are appended. The patch
+        must apply cleanly: the keys of updated and deleted rows must be in this
+        DataFrame and the keys of inserted rows must not.
+
+        Parameters
+        ----------
+        patch
+            The patch to apply.
+        on
+            Name(s) of the key columns.
+
+        See Also
+        --------
+        compute_patch
+
+        Examples
+        --------
+        >>> old = pl.DataFrame({"id": [1, 2, 3], "value": ["a", "b", "c"]})
+        >>> new = pl.DataFrame({"id": [1, 3, 4], "value": ["a", "x", "d"]})
+        >>> patch = old.compute_patch(new, on="id")
+        >>> old.apply_patch(patch, on="id")
+        shape: (3, 2)
+        ┌─────┬───────┐
+        │ id  ┆ value │
+        │ --- ┆ ---   │
+        │ i64 ┆ str   │
+        ╞═════╪═══════╡
+        │ 1   ┆ a     │
+        │ 3   ┆ x     │
+        │ 4   ┆ d     │
+        └─────┴───────┘
+        """
+        if isinstance(on, str):
+            on = [on]
+        return self._from_pydf(self._df.apply_patch(patch._df, list(on)))
+
     def slice(self, offset: int, length: int | None = None) -> Self:
         """
         Get a slice of this DataFrame.
@@ -5639,6 +5985,7 @@ class DataFrame:
         offset: str | timedelta | None = None,
         closed: ClosedInterval = "right",
         group_by: IntoExpr | Iterable[IntoExpr] | None = None,
+        descending: bool = False,
     ) -> RollingGroupBy:
         """
         Create rolling groups based on a temporal or integer column.
@@ -5690,12 +6037,13 @@ class DataFrame:
         index_column
             Column used to group based on the time window.
             Often of type Date/Datetime.
-            This column must be sorted in ascending order (or, if `group_by` is
-            specified, then it must be sorted in ascending order within each group).
+            This column must be sorted in ascending order, or in descending order if
+            `descending` is set (if `group_by` is specified, then it must be sorted
+            within each group).
 
             In case of a rolling operation on indices, dtype needs to be one of
-            {UInt32, UInt64, Int32, Int64}. Note that the first three get temporarily
-            cast to Int64, so if performance matters use an Int64 column.
+            {UInt32, UInt64, Int32, Int64}. Note that UInt64 gets temporarily cast
+            to Int64, so if performance matters use another integer dtype.
         period
             Length of the window - must be non-negative.
         offset
@@ -5704,6 +6052,9 @@ class DataFrame:
             Define which sides of the temporal interval are closed (inclusive).
         group_by
             Also group by this column/these columns
+        descending
+            The `index_column` is sorted in descending order. The windows are
+            determined by the values in the same way as for an ascending column.
 
         Returns
         -------
@@ -5782,6 +6133,7 @@ class DataFrame:
             offset=offset,
             closed=closed,
             group_by=group_by,
+            descending=descending,
         )
 
     @deprecate_renamed_parameter("by", "group_by", version="0.20.14")
@@ -6201,6 +6553,104 @@ class DataFrame:
             self._df.upsample(group_by, time_column, every, maintain_order)
         )
 
+    @unstable()
+    def resample(
+        self,
+        time_column: str,
+        *,
+        every: str | timedelta,
+        aggs: IntoExpr | Iterable[IntoExpr] | None = None,
+        fill_strategy: FillNullStrategy | None = None,
+        limit: int | None = None,
+        group_by: str | Sequence[str] | None = None,
+        closed: ClosedInterval = "left",
+        label: Label = "left",
+    ) -> Self:
+        """
+        Resample a DataFrame to a regular frequency.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        With `aggs`, the DataFrame is downsampled: the rows of every window of size
+        `every` are aggregated, like :meth:`group_by_dynamic`. Otherwise it is
+        upsampled: a row is inserted at every interval, like :meth:`upsample`, and
+        the inserted rows are filled with `fill_strategy`. The nulls of the
+        DataFrame itself are not filled.
+
+        Parameters
+        ----------
+        time_column
+            Time column of the regular frequency. This column has to be sorted
+            (within each group).
+        every
+            Interval of the resampled rows, in the string language of
+            :meth:`upsample`.
+        aggs
+            Aggregations of the rows of every window. If not given, the DataFrame is
+            upsampled.
+        fill_strategy : {None, 'forward', 'backward', 'min', 'max', 'mean', 'zero', 'one'}
+            Strategy to fill the rows that are inserted when upsampling. They are
+            left null if not given.
+        limit
+            Number of consecutive rows to fill with the 'forward' or 'backward'
+            strategy.
+        group_by
+            Resample the rows of every group of these columns separately.
+        closed : {'left', 'right', 'both', 'none'}
+            Which sides of a window are closed when downsampling.
+        label : {'left', 'right', 'datapoint'}
+            Which boundary of a window labels its row when downsampling.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"idx": [0, 1, 2, 5], "value": [1, 2, 3, 4]})
+        >>> df.resample("idx", every="2i", aggs=pl.col("value").sum())
+        shape: (3, 2)
+        ┌─────┬───────┐
+        │ idx ┆ value │
+        │ --- ┆ ---   │
+        │ i64 ┆ i64   │
+        ╞═════╪═══════╡
+        │ 0   ┆ 3     │
+        │ 2   ┆ 3     │
+        │ 4   ┆ 4     │
+        └─────┴───────┘
+        >>> df.resample("idx", every="1i", fill_strategy="forward")
+        shape: (6, 2)
+        ┌─────┬───────┐
+        │ idx ┆ value │
+        │ --- ┆ ---   │
+        │ i64 ┆ i64   │
+        ╞═════╪═══════╡
+        │ 0   ┆ 1     │
+        │ 1   ┆ 2     │
+        │ 2   ┆ 3     │
+        │ 3   ┆ 3     │
+        │ 4   ┆ 3     │
+        │ 5   ┆ 4     │
+        └─────┴───────┘
+        """
+        if group_by is None:
+            group_by = []
+        if isinstance(group_by, str):
+            group_by = [group_by]
+        every = parse_as_duration_string(every)
+        pyexprs = None if aggs is None else parse_into_list_of_expressions(aggs)
+        return self._from_pydf(
+            self._df.resample(
+                time_column,
+                every,
+                pyexprs,
+                fill_strategy,
+                limit,
+                group_by,
+                closed,
+                label,
+            )
+        )
+
     def join_asof(
         self,
         other: DataFrame,
@@ -6525,6 +6975,7 @@ class DataFrame:
         validate: JoinValidation = "m:m",
         join_nulls: bool = False,
         coalesce: bool | None = None,
+        strategy: JoinExecutionStrategy = "auto",
     ) -> DataFrame:
         """
         Join in SQL-like fashion.
@@ -6583,6 +7034,19 @@ class DataFrame:
             - None: -> join specific.
             - True: -> Always coalesce join columns.
             - False: -> Never coalesce join columns.
+        strategy : {'auto', 'hash', 'sort_merge', 'broadcast_left', 'broadcast_right'}
+            Hint for how to execute the join.
+
+            - *auto*: let the engine decide. A hash join builds its hash table on
+              the input with the fewest rows, as estimated from the statistics of
+              the scans in a lazy query.
+            - *hash*: always use a hash join.
+            - *sort_merge*: merge the sorted join keys. Only supported for inner and
+              left joins on a single numeric key without nulls.
+            - *broadcast_left*: build the hash table on the left frame, which is
+              expected to be small. Only supported for inner joins.
+            - *broadcast_right*: build the hash table on the right frame, which is
+              expected to be small.
 
         Returns
         -------
@@ -6684,6 +7148,7 @@ class DataFrame:
                 validate=validate,
                 join_nulls=join_nulls,
                 coalesce=coalesce,
+                strategy=strategy,
             )
             .collect(_eager=True)
         )
@@ -7561,9 +8026,12 @@ class DataFrame:
         aggregate_function
             Choose from:
 
-            - None: no aggregation takes place, will raise error if multiple values are in group.
+            - None: no aggregation takes place, will raise an error listing the
+              offending keys if multiple values are in group.
             - A predefined aggregate function string, one of
-              {'min', 'max', 'first', 'last', 'sum', 'mean', 'median', 'len'}
+              {'min', 'max', 'first', 'last', 'sum', 'mean', 'median', 'len', 'list'}.
+              Use 'first' or 'last' to keep a single value per group, or 'list' to
+              collect all colliding values.
             - An expression to do the aggregation.
         maintain_order
             Sort the grouped keys so that the output order is predictable.
@@ -7717,6 +8185,8 @@ class DataFrame:
                 aggregate_expr = F.element().last()._pyexpr
             elif aggregate_function == "len":
                 aggregate_expr = F.len()._pyexpr
+            elif aggregate_function == "list":
+                aggregate_expr = F.element()._pyexpr
             elif aggregate_function == "count":
                 issue_deprecation_warning(
                     "`aggregate_function='count'` input for `pivot` is deprecated."
@@ -9150,6 +9620,7 @@ class DataFrame:
         *,
         keep: UniqueKeepStrategy = "any",
         maintain_order: bool = False,
+        order_by: ColumnNameOrSelector | Collection[ColumnNameOrSelector] | None = None,
     ) -> DataFrame:
         """
         Drop duplicate rows from this dataframe.
@@ -9172,6 +9643,12 @@ class DataFrame:
             compute.
             Settings this to `True` blocks the possibility
             to run on the streaming engine.
+        order_by
+            Column name(s) or selector(s) that decide which of the duplicate rows is
+            kept, instead of their position. With `keep='first'` the row with the
+            smallest values is kept and with `keep='last'` the row with the largest
+            values. Ties go to the first or last of the tied rows respectively.
+            Ignored for `keep='any'` and `keep='none'`.
 
         Returns
         -------
@@ -9226,7 +9703,12 @@ class DataFrame:
         """
         return (
             self.lazy()
-            .unique(subset=subset, keep=keep, maintain_order=maintain_order)
+            .unique(
+                subset=subset,
+                keep=keep,
+                maintain_order=maintain_order,
+                order_by=order_by,
+            )
             .collect(_eager=True)
         )
 
@@ -9436,6 +9918,84 @@ class DataFrame:
 
         return self._from_pydf(self._df.sample_n(n._s, with_replacement, shuffle, seed))
 
+    @unstable()
+    def sample_stratified(
+        self,
+        by: str | Sequence[str],
+        n: int | None = None,
+        *,
+        fraction: float | None = None,
+        with_replacement: bool = False,
+        shuffle: bool = False,
+        seed: int | None = None,
+    ) -> Self:
+        """
+        Sample rows within every group of the `by` columns.
+
+        All groups are sampled in a single pass, instead of sampling every partition
+        of the frame separately.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        by
+            Column name(s) that define the groups to sample from.
+        n
+            Number of rows to return per group. Cannot be used with `fraction`.
+            Defaults to 1 if `fraction` is None.
+        fraction
+            Fraction of the rows of every group to return. Cannot be used with `n`.
+        with_replacement
+            Allow rows to be sampled more than once.
+        shuffle
+            Shuffle the order of the sampled rows. If set to False (default), the
+            sampled rows keep their original order.
+        seed
+            Seed for the random number generator. If set to None (default), a
+            random seed is generated for each sample operation.
+
+        See Also
+        --------
+        sample
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "group": ["a", "a", "a", "b", "b"],
+        ...         "value": [1, 2, 3, 4, 5],
+        ...     }
+        ... )
+        >>> df.sample_stratified("group", n=1, seed=0)  # doctest: +IGNORE_RESULT
+        shape: (2, 2)
+        ┌───────┬───────┐
+        │ group ┆ value │
+        │ ---   ┆ ---   │
+        │ str   ┆ i64   │
+        ╞═══════╪═══════╡
+        │ a     ┆ 2     │
+        │ b     ┆ 5     │
+        └───────┴───────┘
+        """
+        if n is not None and fraction is not None:
+            msg = "cannot specify both `n` and `fraction`"
+            raise ValueError(msg)
+        if n is None and fraction is None:
+            n = 1
+        if seed is None:
+            seed = random.randint(0, 10000)
+        if isinstance(by, str):
+            by = [by]
+
+        return self._from_pydf(
+            self._df.sample_stratified(
+                list(by), n, fraction, with_replacement, shuffle, seed
+            )
+        )
+
     def fold(self, operation: Callable[[Series, Series], Series]) -> Series:
         """
         Apply a horizontal reduction on a DataFrame.
@@ -9960,14 +10520,14 @@ class DataFrame:
 
         # note: buffering rows results in a 2-4x speedup over individual calls
         # to ".row(i)", so it should only be disabled in extremely specific cases.
+        # the rows of a buffer are converted to tuples at once on the Rust side.
         if buffer_size and not has_object:
-            for offset in range(0, self.height, buffer_size):
-                zerocopy_slice = self.slice(offset, buffer_size)
+            for rows in self._df.row_iterator(buffer_size):
                 if named:
-                    for row in zerocopy_slice.rows(named=False):
+                    for row in rows:
                         yield dict_(zip_(columns, row))
                 else:
-                    yield from zerocopy_slice.rows(named=False)
+                    yield from rows
         elif named:
             for i in range(self.height):
                 yield dict_(zip_(columns, get_row(i)))
@@ -10184,6 +10744,41 @@ class DataFrame:
         k3 = seed_3 if seed_3 is not None else seed
         return wrap_s(self._df.hash_rows(k0, k1, k2, k3))
 
+    @unstable()
+    def content_hash(self) -> tuple[int, int]:
+        """
+        Compute fingerprints of the schema and the rows of this DataFrame.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Returns a tuple of two 128-bit integers. The first changes if the schema
+        changes, or if any row changes or moves. The second changes if the schema or
+        any row changes, but not if the rows are reordered.
+
+        Notes
+        -----
+        The fingerprints don't depend on the chunking of the data, the number of
+        threads or the process they are computed in, so they can be used as cache
+        keys and for change detection. They may change between Polars versions.
+
+        See Also
+        --------
+        LazyFrame.content_hash
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+        >>> ordered, order_insensitive = df.content_hash()
+        >>> reversed_ordered, reversed_order_insensitive = df.reverse().content_hash()
+        >>> reversed_ordered == ordered
+        False
+        >>> reversed_order_insensitive == order_insensitive
+        True
+        """
+        return self._df.content_hash()
+
     def interpolate(self) -> DataFrame:
         """
         Interpolate intermediate values. The interpolation method is linear.
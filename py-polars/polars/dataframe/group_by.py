@@ -771,6 +771,7 @@ class RollingGroupBy:
         offset: str | timedelta | None,
         closed: ClosedInterval,
         group_by: IntoExpr | Iterable[IntoExpr] | None,
+        descending: bool = False,
     ):
         period = parse_as_duration_string(period)
         offset = parse_as_duration_string(offset)
@@ -781,6 +782,7 @@ class RollingGroupBy:
         self.offset = offset
         self.closed = closed
         self.group_by = group_by
+        self.descending = descending
 
     def __iter__(self) -> Self:
         temp_col = "__POLARS_GB_GROUP_INDICES"
@@ -792,6 +794,7 @@ class RollingGroupBy:
                 offset=self.offset,
                 closed=self.closed,
                 group_by=self.group_by,
+                descending=self.descending,
             )
             .agg(F.first().agg_groups().alias(temp_col))
             .collect(no_optimization=True)
@@ -839,6 +842,7 @@ class RollingGroupBy:
                 offset=self.offset,
                 closed=self.closed,
                 group_by=self.group_by,
+                descending=self.descending,
             )
             .agg(*aggs, **named_aggs)
             .collect(no_optimization=True)
@@ -881,6 +885,7 @@ class RollingGroupBy:
                 offset=self.offset,
                 closed=self.closed,
                 group_by=self.group_by,
+                descending=self.descending,
             )
             .map_groups(function, schema)
             .collect(no_optimization=True)
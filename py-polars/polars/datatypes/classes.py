@@ -273,6 +273,15 @@ class UInt64(UnsignedIntegerType):
     """64-bit unsigned integer type."""
 
 
+class Float16(FloatType):
+    """
+    16-bit floating point type.
+
+    Values are stored as 16-bit floats, but computed on as 32-bit floats.
+    Arithmetic and most aggregations therefore return `Float32`.
+    """
+
+
 class Float32(FloatType):
     """32-bit floating point type."""
 
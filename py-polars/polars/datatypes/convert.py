@@ -29,6 +29,7 @@ from polars.datatypes import (
     Decimal,
     Duration,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -246,6 +247,7 @@ class _DataTypeMappings:
         return {
             Float64: float,
             Float32: float,
+            Float16: float,
             Int64: int,
             Int32: int,
             Int16: int,
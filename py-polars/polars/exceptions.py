@@ -24,7 +24,16 @@ except ImportError:
     # redefined for documentation purposes when there is no binary
 
     class PolarsError(Exception):  # type: ignore[no-redef]
-        """Base class for all Polars errors."""
+        """
+        Base class for all Polars errors.
+
+        Errors raised by Polars carry two additional attributes:
+
+        - `code`: a stable string identifying the kind of error, e.g.
+          `"schema_mismatch"`.
+        - `context`: a list of `(kind, value)` tuples, innermost first, where `kind`
+          is one of `"operator"`, `"column"`, `"file"` or `"message"`.
+        """
 
     class ColumnNotFoundError(PolarsError):  # type: ignore[no-redef, misc]
         """
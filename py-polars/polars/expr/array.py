@@ -779,3 +779,89 @@ class ExprArrayNameSpace:
         """
         n = parse_into_expression(n)
         return wrap_expr(self._pyexpr.arr_shift(n))
+
+    def slice(self, offset: int, length: int) -> Expr:
+        """
+        Slice every sub-array.
+
+        Contrary to `list.slice`, the result is again an array. Its width is
+        determined by `offset` and `length`, which therefore must be integers.
+
+        Parameters
+        ----------
+        offset
+            Start index. Negative indexing is supported.
+        length
+            Length of the slice.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3], [4, 5, 6]]}, schema={"a": pl.Array(pl.Int64, 3)}
+        ... )
+        >>> df.with_columns(slice=pl.col("a").arr.slice(1, 2))
+        shape: (2, 2)
+        ┌───────────────┬───────────────┐
+        │ a             ┆ slice         │
+        │ ---           ┆ ---           │
+        │ array[i64, 3] ┆ array[i64, 2] │
+        ╞═══════════════╪═══════════════╡
+        │ [1, 2, 3]     ┆ [2, 3]        │
+        │ [4, 5, 6]     ┆ [5, 6]        │
+        └───────────────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_slice(offset, length))
+
+    def head(self, n: int = 5) -> Expr:
+        """
+        Get the first `n` elements of every sub-array.
+
+        Parameters
+        ----------
+        n
+            Number of elements to return.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3], [4, 5, 6]]}, schema={"a": pl.Array(pl.Int64, 3)}
+        ... )
+        >>> df.with_columns(head=pl.col("a").arr.head(2))
+        shape: (2, 2)
+        ┌───────────────┬───────────────┐
+        │ a             ┆ head          │
+        │ ---           ┆ ---           │
+        │ array[i64, 3] ┆ array[i64, 2] │
+        ╞═══════════════╪═══════════════╡
+        │ [1, 2, 3]     ┆ [1, 2]        │
+        │ [4, 5, 6]     ┆ [4, 5]        │
+        └───────────────┴───────────────┘
+        """
+        return self.slice(0, n)
+
+    def tail(self, n: int = 5) -> Expr:
+        """
+        Get the last `n` elements of every sub-array.
+
+        Parameters
+        ----------
+        n
+            Number of elements to return.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3], [4, 5, 6]]}, schema={"a": pl.Array(pl.Int64, 3)}
+        ... )
+        >>> df.with_columns(tail=pl.col("a").arr.tail(2))
+        shape: (2, 2)
+        ┌───────────────┬───────────────┐
+        │ a             ┆ tail          │
+        │ ---           ┆ ---           │
+        │ array[i64, 3] ┆ array[i64, 2] │
+        ╞═══════════════╪═══════════════╡
+        │ [1, 2, 3]     ┆ [2, 3]        │
+        │ [4, 5, 6]     ┆ [5, 6]        │
+        └───────────────┴───────────────┘
+        """
+        return self.slice(-n, n)
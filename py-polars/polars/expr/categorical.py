@@ -5,6 +5,8 @@ from typing import TYPE_CHECKING
 from polars._utils.wrap import wrap_expr
 
 if TYPE_CHECKING:
+    from collections.abc import Sequence
+
     from polars import Expr
 
 
@@ -38,3 +40,186 @@ class ExprCatNameSpace:
         └──────┘
         """
         return wrap_expr(self._pyexpr.cat_get_categories())
+
+    def add_categories(self, categories: Sequence[str]) -> Expr:
+        """
+        Append categories to the categories of an Enum.
+
+        Categories that already exist are skipped. The existing categories keep
+        their position, so the physical representation of the values is unchanged.
+
+        Parameters
+        ----------
+        categories
+            The categories to append.
+
+        See Also
+        --------
+        reorder_categories : Reorder the categories of an Enum.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"size": ["S", "M", "S"]}, schema={"size": pl.Enum(["S", "M"])}
+        ... )
+        >>> df.select(pl.col("size").cat.add_categories(["M", "L"])).schema
+        Schema({'size': Enum(categories=['S', 'M', 'L'])})
+        """
+        return wrap_expr(self._pyexpr.cat_add_categories(list(categories)))
+
+    def reorder_categories(self, categories: Sequence[str]) -> Expr:
+        """
+        Reorder the categories of an Enum.
+
+        The values are unchanged, but they sort in the order of the new categories.
+
+        Parameters
+        ----------
+        categories
+            Every category of the Enum exactly once, in the new order.
+
+        See Also
+        --------
+        add_categories : Append categories to the categories of an Enum.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"size": ["S", "M", "L"]}, schema={"size": pl.Enum(["M", "S", "L"])}
+        ... )
+        >>> df.select(pl.col("size").cat.reorder_categories(["S", "M", "L"]).sort())
+        shape: (3, 1)
+        ┌──────┐
+        │ size │
+        │ ---  │
+        │ enum │
+        ╞══════╡
+        │ S    │
+        │ M    │
+        │ L    │
+        └──────┘
+        """
+        return wrap_expr(self._pyexpr.cat_reorder_categories(list(categories)))
+
+    def contains(
+        self, pattern: str, *, literal: bool = False, strict: bool = True
+    ) -> Expr:
+        """
+        Check if the categories contain a substring that matches a pattern.
+
+        The pattern is evaluated once per category instead of once per row, which
+        is much faster than `str.contains` on a column with few distinct values.
+
+        Parameters
+        ----------
+        pattern
+            A valid regular expression pattern, compatible with the `regex crate
+            <https://docs.rs/regex/latest/regex/>`_.
+        literal
+            Treat `pattern` as a literal string, not as a regular expression.
+        strict
+            Raise an error if the underlying pattern is not a valid regex,
+            otherwise mask out with a null value.
+
+        See Also
+        --------
+        starts_with : Check if the categories start with a substring.
+        ends_with : Check if the categories end with a substring.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"fruit": ["apple", "banana", None, "apricot", "apple"]},
+        ...     schema={"fruit": pl.Categorical},
+        ... )
+        >>> df.with_columns(pl.col("fruit").cat.contains("an+").alias("contains"))
+        shape: (5, 2)
+        ┌─────────┬──────────┐
+        │ fruit   ┆ contains │
+        │ ---     ┆ ---      │
+        │ cat     ┆ bool     │
+        ╞═════════╪══════════╡
+        │ apple   ┆ false    │
+        │ banana  ┆ true     │
+        │ null    ┆ null     │
+        │ apricot ┆ false    │
+        │ apple   ┆ false    │
+        └─────────┴──────────┘
+        """
+        return wrap_expr(self._pyexpr.cat_contains(pattern, literal, strict))
+
+    def starts_with(self, prefix: str) -> Expr:
+        """
+        Check if the categories start with a substring.
+
+        The prefix is checked once per category instead of once per row.
+
+        Parameters
+        ----------
+        prefix
+            Prefix substring.
+
+        See Also
+        --------
+        contains : Check if the categories contain a substring that matches a pattern.
+        ends_with : Check if the categories end with a substring.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"fruit": ["apple", "banana", None, "apricot", "apple"]},
+        ...     schema={"fruit": pl.Categorical},
+        ... )
+        >>> df.with_columns(pl.col("fruit").cat.starts_with("ap").alias("starts_with"))
+        shape: (5, 2)
+        ┌─────────┬─────────────┐
+        │ fruit   ┆ starts_with │
+        │ ---     ┆ ---         │
+        │ cat     ┆ bool        │
+        ╞═════════╪═════════════╡
+        │ apple   ┆ true        │
+        │ banana  ┆ false       │
+        │ null    ┆ null        │
+        │ apricot ┆ true        │
+        │ apple   ┆ true        │
+        └─────────┴─────────────┘
+        """
+        return wrap_expr(self._pyexpr.cat_starts_with(prefix))
+
+    def ends_with(self, suffix: str) -> Expr:
+        """
+        Check if the categories end with a substring.
+
+        The suffix is checked once per category instead of once per row.
+
+        Parameters
+        ----------
+        suffix
+            Suffix substring.
+
+        See Also
+        --------
+        contains : Check if the categories contain a substring that matches a pattern.
+        starts_with : Check if the categories start with a substring.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"fruit": ["apple", "banana", None, "apricot", "apple"]},
+        ...     schema={"fruit": pl.Categorical},
+        ... )
+        >>> df.with_columns(pl.col("fruit").cat.ends_with("e").alias("ends_with"))
+        shape: (5, 2)
+        ┌─────────┬───────────┐
+        │ fruit   ┆ ends_with │
+        │ ---     ┆ ---       │
+        │ cat     ┆ bool      │
+        ╞═════════╪═══════════╡
+        │ apple   ┆ true      │
+        │ banana  ┆ false     │
+        │ null    ┆ null      │
+        │ apricot ┆ false     │
+        │ apple   ┆ true      │
+        └─────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.cat_ends_with(suffix))
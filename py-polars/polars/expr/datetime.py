@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 import datetime as dt
-from typing import TYPE_CHECKING, Iterable
+from typing import TYPE_CHECKING, Iterable, Literal
 
 import polars._reexport as pl
 from polars import functions as F
@@ -150,21 +150,42 @@ class ExprDateTimeNameSpace:
             )
         )
 
-    def truncate(self, every: str | dt.timedelta | Expr) -> Expr:
+    def truncate(
+        self,
+        every: str | dt.timedelta | Expr,
+        *,
+        ambiguous: Ambiguous | Literal["infer"] | Expr = "infer",
+        non_existent: NonExistent | Expr = "raise",
+    ) -> Expr:
         """
         Divide the date/datetime range into buckets.
 
         Each date/datetime is mapped to the start of its bucket using the corresponding
         local datetime. Note that weekly buckets start on Monday.
-        Ambiguous results are localised using the DST offset of the original timestamp -
-        for example, truncating `'2022-11-06 01:30:00 CST'` by `'1h'` results in
-        `'2022-11-06 01:00:00 CST'`, whereas truncating `'2022-11-06 01:30:00 CDT'` by
-        `'1h'` results in `'2022-11-06 01:00:00 CDT'`.
+        By default, ambiguous results are localised using the DST offset of the
+        original timestamp - for example, truncating `'2022-11-06 01:30:00 CST'`
+        by `'1h'` results in `'2022-11-06 01:00:00 CST'`, whereas truncating
+        `'2022-11-06 01:30:00 CDT'` by `'1h'` results in
+        `'2022-11-06 01:00:00 CDT'`.
 
         Parameters
         ----------
         every
             Every interval start and period length
+        ambiguous
+            Determine how to deal with ambiguous results:
+
+            - `'infer'` (default): use the DST offset of the original datetime
+            - `'raise'`: raise
+            - `'earliest'`: use the earliest datetime
+            - `'latest'`: use the latest datetime
+            - `'null'`: set to null
+        non_existent
+            Determine how to deal with results that don't exist in the time zone,
+            either for all rows or per row with an expression:
+
+            - `'raise'` (default): raise
+            - `'null'`: set to null
 
         Notes
         -----
@@ -274,10 +295,22 @@ class ExprDateTimeNameSpace:
             every = parse_as_duration_string(every)
 
         every = parse_into_expression(every, str_as_lit=True)
-        return wrap_expr(self._pyexpr.dt_truncate(every))
+        if _is_default_policy(ambiguous, non_existent):
+            return wrap_expr(self._pyexpr.dt_truncate(every))
+        ambiguous = parse_into_expression(ambiguous, str_as_lit=True)
+        non_existent = parse_into_expression(non_existent, str_as_lit=True)
+        return wrap_expr(
+            self._pyexpr.dt_truncate_localized(every, ambiguous, non_existent)
+        )
 
     @unstable()
-    def round(self, every: str | dt.timedelta | IntoExprColumn) -> Expr:
+    def round(
+        self,
+        every: str | dt.timedelta | IntoExprColumn,
+        *,
+        ambiguous: Ambiguous | Literal["infer"] | Expr = "infer",
+        non_existent: NonExistent | Expr = "raise",
+    ) -> Expr:
         """
         Divide the date/datetime range into buckets.
 
@@ -289,15 +322,30 @@ class ExprDateTimeNameSpace:
         is mapped to the start of its bucket.
         Each date/datetime in the second half of the interval
         is mapped to the end of its bucket.
-        Ambiguous results are localised using the DST offset of the original timestamp -
-        for example, rounding `'2022-11-06 01:20:00 CST'` by `'1h'` results in
-        `'2022-11-06 01:00:00 CST'`, whereas rounding `'2022-11-06 01:20:00 CDT'` by
-        `'1h'` results in `'2022-11-06 01:00:00 CDT'`.
+        By default, ambiguous results are localised using the DST offset of the
+        original timestamp - for example, rounding `'2022-11-06 01:20:00 CST'`
+        by `'1h'` results in `'2022-11-06 01:00:00 CST'`, whereas rounding
+        `'2022-11-06 01:20:00 CDT'` by `'1h'` results in
+        `'2022-11-06 01:00:00 CDT'`.
 
         Parameters
         ----------
         every
             Every interval start and period length
+        ambiguous
+            Determine how to deal with ambiguous results:
+
+            - `'infer'` (default): use the DST offset of the original datetime
+            - `'raise'`: raise
+            - `'earliest'`: use the earliest datetime
+            - `'latest'`: use the latest datetime
+            - `'null'`: set to null
+        non_existent
+            Determine how to deal with results that don't exist in the time zone,
+            either for all rows or per row with an expression:
+
+            - `'raise'` (default): raise
+            - `'null'`: set to null
 
         Returns
         -------
@@ -382,7 +430,13 @@ class ExprDateTimeNameSpace:
         if isinstance(every, dt.timedelta):
             every = parse_as_duration_string(every)
         every = parse_into_expression(every, str_as_lit=True)
-        return wrap_expr(self._pyexpr.dt_round(every))
+        if _is_default_policy(ambiguous, non_existent):
+            return wrap_expr(self._pyexpr.dt_round(every))
+        ambiguous = parse_into_expression(ambiguous, str_as_lit=True)
+        non_existent = parse_into_expression(non_existent, str_as_lit=True)
+        return wrap_expr(
+            self._pyexpr.dt_round_localized(every, ambiguous, non_existent)
+        )
 
     def combine(self, time: dt.time | Expr, time_unit: TimeUnit = "us") -> Expr:
         """
@@ -915,6 +969,48 @@ class ExprDateTimeNameSpace:
         """
         return wrap_expr(self._pyexpr.dt_weekday())
 
+    def iso_year_week_day(self) -> Expr:
+        """
+        Extract the ISO year, ISO week and ISO weekday as a struct.
+
+        Applies to Date and Datetime columns.
+
+        The struct has the fields `iso_year`, `iso_week` and `weekday`, where the
+        weekday number ranges from monday = 1 to sunday = 7.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` with fields of data type
+            :class:`Int32`, :class:`Int8` and :class:`Int8`.
+
+        See Also
+        --------
+        iso_year
+        week
+        weekday
+        polars.date_from_isoweek
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2020, 12, 31), date(2021, 1, 1), date(2021, 1, 4)]}
+        ... )
+        >>> df.select(pl.col("date").dt.iso_year_week_day()).unnest("date")
+        shape: (3, 3)
+        ┌──────────┬──────────┬─────────┐
+        │ iso_year ┆ iso_week ┆ weekday │
+        │ ---      ┆ ---      ┆ ---     │
+        │ i32      ┆ i8       ┆ i8      │
+        ╞══════════╪══════════╪═════════╡
+        │ 2020     ┆ 53       ┆ 4       │
+        │ 2020     ┆ 53       ┆ 5       │
+        │ 2021     ┆ 1        ┆ 1       │
+        └──────────┴──────────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_iso_year_week_day())
+
     def day(self) -> Expr:
         """
         Extract day from underlying Date representation.
@@ -2014,6 +2110,48 @@ class ExprDateTimeNameSpace:
         """
         return wrap_expr(self._pyexpr.dt_total_nanoseconds())
 
+    def to_iso8601_duration(self) -> Expr:
+        """
+        Format a Duration as an ISO 8601 duration string.
+
+        Durations are written in days, hours, minutes and (fractional) seconds, like
+        `"P1DT2H30M"`. Use :func:`polars.duration_from_string` to parse them back.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        See Also
+        --------
+        polars.duration_from_string
+
+        Examples
+        --------
+        >>> from datetime import timedelta
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "duration": [
+        ...             timedelta(days=1, hours=2, minutes=30),
+        ...             timedelta(minutes=1, seconds=30.5),
+        ...             timedelta(weeks=2),
+        ...         ]
+        ...     }
+        ... )
+        >>> df.with_columns(iso=pl.col("duration").dt.to_iso8601_duration())
+        shape: (3, 2)
+        ┌──────────────┬───────────┐
+        │ duration     ┆ iso       │
+        │ ---          ┆ ---       │
+        │ duration[μs] ┆ str       │
+        ╞══════════════╪═══════════╡
+        │ 1d 2h 30m    ┆ P1DT2H30M │
+        │ 1m 30s 500ms ┆ PT1M30.5S │
+        │ 14d          ┆ P14D      │
+        └──────────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_to_iso8601_duration())
+
     def offset_by(self, by: str | Expr) -> Expr:
         """
         Offset this date by a relative time offset.
@@ -2274,3 +2412,15 @@ class ExprDateTimeNameSpace:
         └─────────────────────────────┴──────────────┘
         """
         return wrap_expr(self._pyexpr.dt_dst_offset())
+
+
+def _is_default_policy(
+    ambiguous: Ambiguous | Literal["infer"] | Expr, non_existent: NonExistent | Expr
+) -> bool:
+    """Whether truncating/rounding doesn't need the per-row localization."""
+    return (
+        isinstance(ambiguous, str)
+        and isinstance(non_existent, str)
+        and ambiguous == "infer"
+        and non_existent == "raise"
+    )
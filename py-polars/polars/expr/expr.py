@@ -85,7 +85,10 @@ if TYPE_CHECKING:
         InterpolationMethod,
         IntoExpr,
         IntoExprColumn,
+        LookupMissing,
         MapElementsStrategy,
+        ModeKeep,
+        NanOrdering,
         NullBehavior,
         NumericLiteral,
         PolarsDataType,
@@ -1547,6 +1550,106 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.cum_count(reverse))
 
+    def cum_mean(self, *, reverse: bool = False) -> Self:
+        """
+        Get an array with the cumulative mean computed at every element.
+
+        The mean is updated incrementally, which is more precise than dividing the
+        cumulative sum by the cumulative count.
+
+        Parameters
+        ----------
+        reverse
+            Reverse the operation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 3, 2, 6]})
+        >>> df.with_columns(
+        ...     pl.col("a").cum_mean().alias("cum_mean"),
+        ...     pl.col("a").cum_mean(reverse=True).alias("cum_mean_reverse"),
+        ... )
+        shape: (4, 3)
+        ┌─────┬──────────┬──────────────────┐
+        │ a   ┆ cum_mean ┆ cum_mean_reverse │
+        │ --- ┆ ---      ┆ ---              │
+        │ i64 ┆ f64      ┆ f64              │
+        ╞═════╪══════════╪══════════════════╡
+        │ 1   ┆ 1.0      ┆ 3.0              │
+        │ 3   ┆ 2.0      ┆ 3.666667         │
+        │ 2   ┆ 2.0      ┆ 4.0              │
+        │ 6   ┆ 3.0      ┆ 6.0              │
+        └─────┴──────────┴──────────────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.cum_mean(reverse))
+
+    def cum_std(self, *, ddof: int = 1, reverse: bool = False) -> Self:
+        """
+        Get an array with the cumulative standard deviation computed at every element.
+
+        The standard deviation is computed with Welford's algorithm, which is
+        numerically stable. The result is null until there are more than `ddof`
+        non-null values.
+
+        Parameters
+        ----------
+        ddof
+            "Delta Degrees of Freedom": the divisor used in the calculation is N - ddof,
+            where N represents the number of elements.
+        reverse
+            Reverse the operation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 3, 2, 6]})
+        >>> df.with_columns(pl.col("a").cum_std().alias("cum_std"))
+        shape: (4, 2)
+        ┌─────┬──────────┐
+        │ a   ┆ cum_std  │
+        │ --- ┆ ---      │
+        │ i64 ┆ f64      │
+        ╞═════╪══════════╡
+        │ 1   ┆ null     │
+        │ 3   ┆ 1.414214 │
+        │ 2   ┆ 1.0      │
+        │ 6   ┆ 2.160247 │
+        └─────┴──────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.cum_std(reverse, ddof))
+
+    def cum_quantile(self, quantile: float, *, reverse: bool = False) -> Self:
+        """
+        Get an array with an approximate cumulative quantile computed at every element.
+
+        The values are counted in a sketch with a relative accuracy of 1%, so the
+        memory doesn't grow with the length of the input. Null and NaN values are
+        skipped.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+        reverse
+            Reverse the operation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 3, 2, 6]})
+        >>> df.with_columns(pl.col("a").cum_quantile(0.5).alias("cum_median"))
+        shape: (4, 2)
+        ┌─────┬────────────┐
+        │ a   ┆ cum_median │
+        │ --- ┆ ---        │
+        │ i64 ┆ f64        │
+        ╞═════╪════════════╡
+        │ 1   ┆ 0.99       │
+        │ 3   ┆ 0.99       │
+        │ 2   ┆ 1.993662   │
+        │ 6   ┆ 1.993662   │
+        └─────┴────────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.cum_quantile(quantile, reverse))
+
     def floor(self) -> Self:
         """
         Rounds down to the nearest integer value.
@@ -1678,12 +1781,23 @@ class Expr:
         other = parse_into_expression(other)
         return self._from_pyexpr(self._pyexpr.dot(other))
 
-    def mode(self) -> Self:
+    def mode(self, *, keep: ModeKeep | None = None) -> Self:
         """
         Compute the most occurring value(s).
 
         Can return multiple Values.
 
+        Parameters
+        ----------
+        keep : {None, 'all', 'first', 'last'}
+            By default, every mode is a row of the result. Otherwise, the modes are
+            aggregated to a single value per group, which is also supported by the
+            streaming engine. `keep` decides which modes remain when values are tied:
+
+            - 'all': a list of all modes, in the order in which they first occur.
+            - 'first': the mode that occurs first.
+            - 'last': the mode that occurs last.
+
         Examples
         --------
         >>> df = pl.DataFrame(
@@ -1702,7 +1816,27 @@ class Expr:
         │ 1   ┆ 1   │
         │ 1   ┆ 2   │
         └─────┴─────┘
-        """
+
+        Aggregate the modes per group, and break ties.
+
+        >>> df = pl.DataFrame({"g": ["a", "a", "a", "a", "b"], "x": [2, 1, 2, 1, 3]})
+        >>> df.group_by("g", maintain_order=True).agg(
+        ...     pl.col("x").mode(keep="all").alias("all"),
+        ...     pl.col("x").mode(keep="first").alias("first"),
+        ...     pl.col("x").mode(keep="last").alias("last"),
+        ... )
+        shape: (2, 4)
+        ┌─────┬───────────┬───────┬──────┐
+        │ g   ┆ all       ┆ first ┆ last │
+        │ --- ┆ ---       ┆ ---   ┆ ---  │
+        │ str ┆ list[i64] ┆ i64   ┆ i64  │
+        ╞═════╪═══════════╪═══════╪══════╡
+        │ a   ┆ [2, 1]    ┆ 2     ┆ 1    │
+        │ b   ┆ [3]       ┆ 3     ┆ 3    │
+        └─────┴───────────┴───────┴──────┘
+        """
+        if keep is not None:
+            return self._from_pyexpr(self._pyexpr.agg_mode(keep))
         return self._from_pyexpr(self._pyexpr.mode())
 
     def cast(
@@ -1751,7 +1885,13 @@ class Expr:
         dtype = py_type_to_dtype(dtype)
         return self._from_pyexpr(self._pyexpr.cast(dtype, strict, wrap_numerical))
 
-    def sort(self, *, descending: bool = False, nulls_last: bool = False) -> Self:
+    def sort(
+        self,
+        *,
+        descending: bool = False,
+        nulls_last: bool = False,
+        nan_ordering: NanOrdering = "largest",
+    ) -> Self:
         """
         Sort this column.
 
@@ -1764,6 +1904,9 @@ class Expr:
             Sort in descending order.
         nulls_last
             Place null values last.
+        nan_ordering : {'largest', 'smallest', 'null'}
+            Where to place `NaN` values: as the largest values, as the smallest values,
+            or as null values.
 
         Examples
         --------
@@ -1828,7 +1971,9 @@ class Expr:
         │ one   ┆ [1, 2, 98] │
         └───────┴────────────┘
         """
-        return self._from_pyexpr(self._pyexpr.sort_with(descending, nulls_last))
+        return self._from_pyexpr(
+            self._pyexpr.sort_with(descending, nulls_last, nan_ordering)
+        )
 
     def top_k(self, k: int | IntoExprColumn = 5) -> Self:
         r"""
@@ -2174,7 +2319,13 @@ class Expr:
         reverse = extend_bool(reverse, len(by), "reverse", "by")
         return self._from_pyexpr(self._pyexpr.bottom_k_by(by, k=k, reverse=reverse))
 
-    def arg_sort(self, *, descending: bool = False, nulls_last: bool = False) -> Self:
+    def arg_sort(
+        self,
+        *,
+        descending: bool = False,
+        nulls_last: bool = False,
+        nan_ordering: NanOrdering = "largest",
+    ) -> Self:
         """
         Get the index values that would sort this column.
 
@@ -2184,6 +2335,9 @@ class Expr:
             Sort in descending (descending) order.
         nulls_last
             Place null values last instead of first.
+        nan_ordering : {'largest', 'smallest', 'null'}
+            Where to place `NaN` values: as the largest values, as the smallest values,
+            or as null values.
 
         Returns
         -------
@@ -2229,7 +2383,9 @@ class Expr:
         │ 3   │
         └─────┘
         """
-        return self._from_pyexpr(self._pyexpr.arg_sort(descending, nulls_last))
+        return self._from_pyexpr(
+            self._pyexpr.arg_sort(descending, nulls_last, nan_ordering)
+        )
 
     def arg_max(self) -> Self:
         """
@@ -2328,6 +2484,7 @@ class Expr:
         nulls_last: bool | Sequence[bool] = False,
         multithreaded: bool = True,
         maintain_order: bool = False,
+        nan_ordering: NanOrdering = "largest",
     ) -> Self:
         """
         Sort this column by the ordering of other columns.
@@ -2352,6 +2509,9 @@ class Expr:
             Sort using multiple threads.
         maintain_order
             Whether the order should be maintained if elements are equal.
+        nan_ordering : {'largest', 'smallest', 'null'}
+            Where to place `NaN` values of float columns: as the largest values, as the
+            smallest values, or as null values.
 
         Examples
         --------
@@ -2458,7 +2618,7 @@ class Expr:
         nulls_last = extend_bool(nulls_last, len(by), "nulls_last", "by")
         return self._from_pyexpr(
             self._pyexpr.sort_by(
-                by, descending, nulls_last, multithreaded, maintain_order
+                by, descending, nulls_last, multithreaded, maintain_order, nan_ordering
             )
         )
 
@@ -3323,6 +3483,28 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.first())
 
+    def first_non_null(self) -> Self:
+        """
+        Get the first non-null value.
+
+        Returns null if all values are null. Unlike `drop_nulls().first()`, this can
+        be computed by the streaming engine in a group by.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [None, 1, 2]})
+        >>> df.select(pl.col("a").first_non_null())
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 1   │
+        └─────┘
+        """
+        return self._from_pyexpr(self._pyexpr.first_non_null())
+
     def last(self) -> Self:
         """
         Get the last value.
@@ -3342,12 +3524,35 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.last())
 
+    def last_non_null(self) -> Self:
+        """
+        Get the last non-null value.
+
+        Returns null if all values are null. Unlike `drop_nulls().last()`, this can
+        be computed by the streaming engine in a group by.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, None]})
+        >>> df.select(pl.col("a").last_non_null())
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 2   │
+        └─────┘
+        """
+        return self._from_pyexpr(self._pyexpr.last_non_null())
+
     def over(
         self,
         partition_by: IntoExpr | Iterable[IntoExpr],
         *more_exprs: IntoExpr,
         order_by: IntoExpr | Iterable[IntoExpr] | None = None,
         mapping_strategy: WindowMappingStrategy = "group_to_rows",
+        frame: tuple[int | None, int | None] | None = None,
     ) -> Self:
         """
         Compute expressions over the given groups.
@@ -3383,6 +3588,16 @@ class Expr:
                 if the groups are not part of the window operation for the operation,
                 otherwise the result would not make sense. This operation changes the
                 number of rows.
+        frame
+            Evaluate the expression for every row over a frame of rows around it,
+            given as the number of rows `(preceding, following)` the current row in
+            the order of `order_by`. `None` leaves that side unbounded. This is
+            `ROWS BETWEEN preceding PRECEDING AND following FOLLOWING` in SQL, and
+            requires `order_by`. `mapping_strategy` does not apply to a frame.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Examples
         --------
@@ -3484,8 +3699,33 @@ class Expr:
         │ b   ┆ 3   ┆ 1   │
         └─────┴─────┴─────┘
 
+        Sum each value with the value before it in the order of `c` with `frame`.
+
+        >>> df.with_columns(
+        ...     pl.col("c").sum().over("a", order_by="c", frame=(1, 0)).alias("c_sum"),
+        ... )
+        shape: (5, 4)
+        ┌─────┬─────┬─────┬───────┐
+        │ a   ┆ b   ┆ c   ┆ c_sum │
+        │ --- ┆ --- ┆ --- ┆ ---   │
+        │ str ┆ i64 ┆ i64 ┆ i64   │
+        ╞═════╪═════╪═════╪═══════╡
+        │ a   ┆ 1   ┆ 5   ┆ 9     │
+        │ a   ┆ 2   ┆ 4   ┆ 4     │
+        │ b   ┆ 3   ┆ 3   ┆ 5     │
+        │ b   ┆ 5   ┆ 2   ┆ 3     │
+        │ b   ┆ 3   ┆ 1   ┆ 1     │
+        └─────┴─────┴─────┴───────┘
+
         """
         partition_by = parse_into_list_of_expressions(partition_by, *more_exprs)
+        if frame is not None:
+            issue_unstable_warning(
+                "The `frame` parameter of `over` is considered unstable."
+            )
+            if order_by is None:
+                msg = "`frame` requires `order_by` to be set"
+                raise ValueError(msg)
         if order_by is not None:
             order_by = parse_into_list_of_expressions(order_by)
         return self._from_pyexpr(
@@ -3495,6 +3735,7 @@ class Expr:
                 order_by_descending=False,  # does not work yet
                 order_by_nulls_last=False,  # does not work yet
                 mapping_strategy=mapping_strategy,
+                frame=frame,
             )
         )
 
@@ -3505,6 +3746,7 @@ class Expr:
         period: str | timedelta,
         offset: str | timedelta | None = None,
         closed: ClosedInterval = "right",
+        descending: bool = False,
     ) -> Self:
         """
         Create rolling groups based on a temporal or integer column.
@@ -3552,16 +3794,20 @@ class Expr:
         index_column
             Column used to group based on the time window.
             Often of type Date/Datetime.
-            This column must be sorted in ascending order.
+            This column must be sorted in ascending order, or in descending order if
+            `descending` is set.
             In case of a rolling group by on indices, dtype needs to be one of
-            {UInt32, UInt64, Int32, Int64}. Note that the first three get temporarily
-            cast to Int64, so if performance matters use an Int64 column.
+            {UInt32, UInt64, Int32, Int64}. Note that UInt64 gets temporarily cast
+            to Int64, so if performance matters use another integer dtype.
         period
             Length of the window - must be non-negative.
         offset
             Offset of the window. Default is `-period`.
         closed : {'right', 'left', 'both', 'none'}
             Define which sides of the temporal interval are closed (inclusive).
+        descending
+            The `index_column` is sorted in descending order. The windows are
+            determined by the values in the same way as for an ascending column.
 
         Examples
         --------
@@ -3602,7 +3848,7 @@ class Expr:
         offset = parse_as_duration_string(offset)
 
         return self._from_pyexpr(
-            self._pyexpr.rolling(index_column, period, offset, closed)
+            self._pyexpr.rolling(index_column, period, offset, closed, descending)
         )
 
     def is_unique(self) -> Self:
@@ -3731,6 +3977,59 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.peak_max())
 
+    def find_peaks(
+        self, *, distance: int | None = None, prominence: float | None = None
+    ) -> Self:
+        """
+        Get a boolean mask of the peaks.
+
+        A peak is a value that is higher than its neighbors, like in
+        `scipy.signal.find_peaks`. Unlike :func:`peak_max`, the first and last values
+        and the values next to a null are never peaks, and only the middle value of a
+        flat peak is taken.
+
+        Parameters
+        ----------
+        distance
+            Minimal number of values between neighboring peaks. Of the peaks that are
+            closer together, only the highest is kept.
+        prominence
+            Minimal prominence of the peaks: how far a peak stands out above the
+            higher of the lowest values to its left and right, up to a higher value.
+
+        See Also
+        --------
+        peak_max
+
+        Notes
+        -----
+        Use :func:`arg_true` on the result to get the indices of the peaks.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [0, 3, 1, 2, 1, 5, 5, 0]})
+        >>> df.with_columns(
+        ...     peaks=pl.col("a").find_peaks(),
+        ...     prominent_peaks=pl.col("a").find_peaks(prominence=2),
+        ... )
+        shape: (8, 3)
+        ┌─────┬───────┬─────────────────┐
+        │ a   ┆ peaks ┆ prominent_peaks │
+        │ --- ┆ ---   ┆ ---             │
+        │ i64 ┆ bool  ┆ bool            │
+        ╞═════╪═══════╪═════════════════╡
+        │ 0   ┆ false ┆ false           │
+        │ 3   ┆ true  ┆ true            │
+        │ 1   ┆ false ┆ false           │
+        │ 2   ┆ true  ┆ false           │
+        │ 1   ┆ false ┆ false           │
+        │ 5   ┆ true  ┆ true            │
+        │ 5   ┆ false ┆ false           │
+        │ 0   ┆ false ┆ false           │
+        └─────┴───────┴─────────────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.find_peaks(distance, prominence))
+
     def peak_min(self) -> Self:
         """
         Get a boolean mask of the local minimum peaks.
@@ -3821,6 +4120,59 @@ class Expr:
         quantile = parse_into_expression(quantile)
         return self._from_pyexpr(self._pyexpr.quantile(quantile, interpolation))
 
+    @unstable()
+    def approx_quantile(
+        self, quantile: float | Expr, *, relative_accuracy: float = 0.01
+    ) -> Self:
+        """
+        Get an approximate quantile value.
+
+        The quantile is estimated with a quantile sketch, which only counts the values
+        in logarithmically sized buckets. Unlike :meth:`quantile`, this does not need
+        to keep all values of a group in memory, so it can be computed by the
+        streaming engine. The result is within `relative_accuracy` of the exact
+        quantile, i.e. `abs(estimate - exact) <= relative_accuracy * abs(exact)`,
+        where the exact quantile is computed with the `'lower'` interpolation method.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+        relative_accuracy
+            Maximum relative error of the result, between 0.0 and 1.0 (exclusive).
+            Smaller values give more accurate results at the cost of more memory.
+
+        Notes
+        -----
+        Null and NaN values are ignored.
+
+        See Also
+        --------
+        quantile
+        rolling_approx_quantile
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [0, 1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").approx_quantile(0.5).round(1))
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 2.0 │
+        └─────┘
+        """
+        quantile = parse_into_expression(quantile)
+        return self._from_pyexpr(
+            self._pyexpr.approx_quantile(quantile, relative_accuracy)
+        )
+
     @unstable()
     def cut(
         self,
@@ -3917,6 +4269,9 @@ class Expr:
         """
         Bin continuous values into discrete categories based on their quantiles.
 
+        In a window (`over`) or group by context, the quantiles are computed per group.
+        The categories of all groups share a single :class:`Categorical`.
+
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
@@ -3938,7 +4293,8 @@ class Expr:
         include_breaks
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
-            :class:`Categorical` to a :class:`Struct`.
+            :class:`Categorical` to a :class:`Struct`. In a window or group by
+            context, these are the breakpoints of the group of the observation.
 
         Returns
         -------
@@ -4009,6 +4365,28 @@ class Expr:
         │ 1   ┆ 1.0        ┆ (-1, 1]    │
         │ 2   ┆ inf        ┆ (1, inf]   │
         └─────┴────────────┴────────────┘
+
+        Compute the quantiles and breakpoints per group.
+
+        >>> df = pl.DataFrame(
+        ...     {"g": ["a", "a", "a", "b", "b", "b"], "x": [1, 2, 3, 10, 20, 30]}
+        ... )
+        >>> df.with_columns(
+        ...     pl.col("x").qcut([0.5], include_breaks=True).over("g").alias("qcut")
+        ... ).unnest("qcut")
+        shape: (6, 4)
+        ┌─────┬─────┬────────────┬────────────┐
+        │ g   ┆ x   ┆ breakpoint ┆ category   │
+        │ --- ┆ --- ┆ ---        ┆ ---        │
+        │ str ┆ i64 ┆ f64        ┆ cat        │
+        ╞═════╪═════╪════════════╪════════════╡
+        │ a   ┆ 1   ┆ 2.0        ┆ (-inf, 2]  │
+        │ a   ┆ 2   ┆ 2.0        ┆ (-inf, 2]  │
+        │ a   ┆ 3   ┆ inf        ┆ (2, inf]   │
+        │ b   ┆ 10  ┆ 20.0       ┆ (-inf, 20] │
+        │ b   ┆ 20  ┆ 20.0       ┆ (-inf, 20] │
+        │ b   ┆ 30  ┆ inf        ┆ (20, inf]  │
+        └─────┴─────┴────────────┴────────────┘
         """
         if isinstance(quantiles, int):
             pyexpr = self._pyexpr.qcut_uniform(
@@ -4440,6 +4818,8 @@ class Expr:
             Dtype of the output Series.
             If not set, the dtype will be inferred based on the first non-null value
             that is returned by the function.
+            A `Struct` dtype lets the function return a dict or a tuple of the field
+            values per element.
         skip_nulls
             Don't map the function over values that contain nulls (this is faster).
         pass_name
@@ -5656,77 +6036,299 @@ class Expr:
         """
         return self.__xor__(other)
 
-    def is_in(self, other: Expr | Collection[Any] | Series) -> Self:
+    def bitwise_and(self, other: Any) -> Self:
         """
-        Check if elements of this expression are present in the other Series.
+        Compute the bitwise and of integer values with `other`.
 
         Parameters
         ----------
         other
-            Series or sequence of primitive type.
+            Integer value; accepts expression input.
 
-        Returns
-        -------
-        Expr
-            Expression of data type :class:`Boolean`.
+        See Also
+        --------
+        bitwise_and_agg
 
         Examples
         --------
-        >>> df = pl.DataFrame(
-        ...     {"sets": [[1, 2, 3], [1, 2], [9, 10]], "optional_members": [1, 2, 3]}
-        ... )
-        >>> df.with_columns(contains=pl.col("optional_members").is_in("sets"))
-        shape: (3, 3)
-        ┌───────────┬──────────────────┬──────────┐
-        │ sets      ┆ optional_members ┆ contains │
-        │ ---       ┆ ---              ┆ ---      │
-        │ list[i64] ┆ i64              ┆ bool     │
-        ╞═══════════╪══════════════════╪══════════╡
-        │ [1, 2, 3] ┆ 1                ┆ true     │
-        │ [1, 2]    ┆ 2                ┆ true     │
-        │ [9, 10]   ┆ 3                ┆ false    │
-        └───────────┴──────────────────┴──────────┘
+        >>> df = pl.DataFrame({"flags": [5, 3, 8]}, schema={"flags": pl.UInt8})
+        >>> df.with_columns(first_bit=pl.col("flags").bitwise_and(1))
+        shape: (3, 2)
+        ┌───────┬───────────┐
+        │ flags ┆ first_bit │
+        │ ---   ┆ ---       │
+        │ u8    ┆ u8        │
+        ╞═══════╪═══════════╡
+        │ 5     ┆ 1         │
+        │ 3     ┆ 1         │
+        │ 8     ┆ 0         │
+        └───────┴───────────┘
         """
-        if isinstance(other, Collection) and not isinstance(other, str):
-            if isinstance(other, (Set, FrozenSet)):
-                other = list(other)
-            other = F.lit(pl.Series(other))._pyexpr
-        else:
-            other = parse_into_expression(other)
-        return self._from_pyexpr(self._pyexpr.is_in(other))
+        return self.__and__(other)
 
-    def repeat_by(self, by: pl.Series | Expr | str | int) -> Self:
+    def bitwise_or(self, other: Any) -> Self:
         """
-        Repeat the elements in this Series as specified in the given expression.
-
-        The repeated elements are expanded into a `List`.
+        Compute the bitwise or of integer values with `other`.
 
         Parameters
         ----------
-        by
-            Numeric column that determines how often the values will be repeated.
-            The column will be coerced to UInt32. Give this dtype to make the coercion a
-            no-op.
+        other
+            Integer value; accepts expression input.
 
-        Returns
-        -------
-        Expr
-            Expression of data type :class:`List`, where the inner data type is equal
-            to the original data type.
+        See Also
+        --------
+        bitwise_or_agg
 
         Examples
         --------
-        >>> df = pl.DataFrame(
-        ...     {
-        ...         "a": ["x", "y", "z"],
-        ...         "n": [1, 2, 3],
-        ...     }
-        ... )
-        >>> df.select(pl.col("a").repeat_by("n"))
-        shape: (3, 1)
-        ┌─────────────────┐
-        │ a               │
-        │ ---             │
+        >>> df = pl.DataFrame({"flags": [5, 3, 8]}, schema={"flags": pl.UInt8})
+        >>> df.with_columns(set_first=pl.col("flags").bitwise_or(1))
+        shape: (3, 2)
+        ┌───────┬───────────┐
+        │ flags ┆ set_first │
+        │ ---   ┆ ---       │
+        │ u8    ┆ u8        │
+        ╞═══════╪═══════════╡
+        │ 5     ┆ 5         │
+        │ 3     ┆ 3         │
+        │ 8     ┆ 9         │
+        └───────┴───────────┘
+        """
+        return self.__or__(other)
+
+    def bitwise_xor(self, other: Any) -> Self:
+        """
+        Compute the bitwise exclusive or of integer values with `other`.
+
+        Parameters
+        ----------
+        other
+            Integer value; accepts expression input.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"flags": [5, 3, 8]}, schema={"flags": pl.UInt8})
+        >>> df.with_columns(flip_first=pl.col("flags").bitwise_xor(1))
+        shape: (3, 2)
+        ┌───────┬────────────┐
+        │ flags ┆ flip_first │
+        │ ---   ┆ ---        │
+        │ u8    ┆ u8         │
+        ╞═══════╪════════════╡
+        │ 5     ┆ 4          │
+        │ 3     ┆ 2          │
+        │ 8     ┆ 9          │
+        └───────┴────────────┘
+        """
+        return self.__xor__(other)
+
+    def bitwise_not(self) -> Self:
+        """
+        Flip all bits of integer values.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"flags": [5, 3, 8]}, schema={"flags": pl.UInt8})
+        >>> df.with_columns(flipped=pl.col("flags").bitwise_not())
+        shape: (3, 2)
+        ┌───────┬─────────┐
+        │ flags ┆ flipped │
+        │ ---   ┆ ---     │
+        │ u8    ┆ u8      │
+        ╞═══════╪═════════╡
+        │ 5     ┆ 250     │
+        │ 3     ┆ 252     │
+        │ 8     ┆ 247     │
+        └───────┴─────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.bitwise_not())
+
+    def bitwise_shift_left(self, n: int) -> Self:
+        """
+        Shift the bits of integer values `n` places to the left.
+
+        Bits shifted past the width of the data type are dropped, so shifting by
+        the bit width or more gives zero.
+
+        Parameters
+        ----------
+        n
+            Number of places to shift.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"flags": [5, 3, 8]}, schema={"flags": pl.UInt8})
+        >>> df.with_columns(shifted=pl.col("flags").bitwise_shift_left(1))
+        shape: (3, 2)
+        ┌───────┬─────────┐
+        │ flags ┆ shifted │
+        │ ---   ┆ ---     │
+        │ u8    ┆ u8      │
+        ╞═══════╪═════════╡
+        │ 5     ┆ 10      │
+        │ 3     ┆ 6       │
+        │ 8     ┆ 16      │
+        └───────┴─────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.bitwise_shift_left(n))
+
+    def bitwise_shift_right(self, n: int) -> Self:
+        """
+        Shift the bits of integer values `n` places to the right.
+
+        The shift is arithmetic: signed integers keep their sign, so shifting a
+        negative value by the bit width or more gives -1.
+
+        Parameters
+        ----------
+        n
+            Number of places to shift.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"flags": [5, 3, -8]}, schema={"flags": pl.Int8})
+        >>> df.with_columns(shifted=pl.col("flags").bitwise_shift_right(1))
+        shape: (3, 2)
+        ┌───────┬─────────┐
+        │ flags ┆ shifted │
+        │ ---   ┆ ---     │
+        │ i8    ┆ i8      │
+        ╞═══════╪═════════╡
+        │ 5     ┆ 2       │
+        │ 3     ┆ 1       │
+        │ -8    ┆ -4      │
+        └───────┴─────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.bitwise_shift_right(n))
+
+    def bitwise_and_agg(self) -> Self:
+        """
+        Aggregate integer values to the bitwise and of the non-null values.
+
+        The result is null if there are no non-null values.
+
+        See Also
+        --------
+        bitwise_and
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"group": ["a", "a", "b"], "flags": [5, 3, 8]},
+        ...     schema_overrides={"flags": pl.UInt8},
+        ... )
+        >>> df.group_by("group", maintain_order=True).agg(
+        ...     all_set=pl.col("flags").bitwise_and_agg(),
+        ...     any_set=pl.col("flags").bitwise_or_agg(),
+        ... )
+        shape: (2, 3)
+        ┌───────┬─────────┬─────────┐
+        │ group ┆ all_set ┆ any_set │
+        │ ---   ┆ ---     ┆ ---     │
+        │ str   ┆ u8      ┆ u8      │
+        ╞═══════╪═════════╪═════════╡
+        │ a     ┆ 1       ┆ 7       │
+        │ b     ┆ 8       ┆ 8       │
+        └───────┴─────────┴─────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.bitwise_and_agg())
+
+    def bitwise_or_agg(self) -> Self:
+        """
+        Aggregate integer values to the bitwise or of the non-null values.
+
+        The result is null if there are no non-null values.
+
+        See Also
+        --------
+        bitwise_or
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"flags": [5, 3, 8]}, schema={"flags": pl.UInt8})
+        >>> df.select(pl.col("flags").bitwise_or_agg())
+        shape: (1, 1)
+        ┌───────┐
+        │ flags │
+        │ ---   │
+        │ u8    │
+        ╞═══════╡
+        │ 15    │
+        └───────┘
+        """
+        return self._from_pyexpr(self._pyexpr.bitwise_or_agg())
+
+    def is_in(self, other: Expr | Collection[Any] | Series) -> Self:
+        """
+        Check if elements of this expression are present in the other Series.
+
+        Parameters
+        ----------
+        other
+            Series or sequence of primitive type.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"sets": [[1, 2, 3], [1, 2], [9, 10]], "optional_members": [1, 2, 3]}
+        ... )
+        >>> df.with_columns(contains=pl.col("optional_members").is_in("sets"))
+        shape: (3, 3)
+        ┌───────────┬──────────────────┬──────────┐
+        │ sets      ┆ optional_members ┆ contains │
+        │ ---       ┆ ---              ┆ ---      │
+        │ list[i64] ┆ i64              ┆ bool     │
+        ╞═══════════╪══════════════════╪══════════╡
+        │ [1, 2, 3] ┆ 1                ┆ true     │
+        │ [1, 2]    ┆ 2                ┆ true     │
+        │ [9, 10]   ┆ 3                ┆ false    │
+        └───────────┴──────────────────┴──────────┘
+        """
+        if isinstance(other, Collection) and not isinstance(other, str):
+            if isinstance(other, (Set, FrozenSet)):
+                other = list(other)
+            other = F.lit(pl.Series(other))._pyexpr
+        else:
+            other = parse_into_expression(other)
+        return self._from_pyexpr(self._pyexpr.is_in(other))
+
+    def repeat_by(self, by: pl.Series | Expr | str | int) -> Self:
+        """
+        Repeat the elements in this Series as specified in the given expression.
+
+        The repeated elements are expanded into a `List`.
+
+        Parameters
+        ----------
+        by
+            Numeric column that determines how often the values will be repeated.
+            The column will be coerced to UInt32. Give this dtype to make the coercion a
+            no-op.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`List`, where the inner data type is equal
+            to the original data type.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["x", "y", "z"],
+        ...         "n": [1, 2, 3],
+        ...     }
+        ... )
+        >>> df.select(pl.col("a").repeat_by("n"))
+        shape: (3, 1)
+        ┌─────────────────┐
+        │ a               │
+        │ ---             │
         │ list[str]       │
         ╞═════════════════╡
         │ ["x"]           │
@@ -5851,6 +6453,66 @@ class Expr:
             self._pyexpr.is_between(lower_bound, upper_bound, closed)
         )
 
+    def is_close(
+        self,
+        other: IntoExpr,
+        *,
+        abs_tol: float = 0.0,
+        rel_tol: float = 1e-09,
+        nans_equal: bool = False,
+    ) -> Self:
+        """
+        Check if the values are close to the values of another expression.
+
+        Two values are close if their difference is at most `abs_tol`, or at most
+        `rel_tol` times the larger of their absolute values. Infinities are only close
+        to the same infinity.
+
+        Parameters
+        ----------
+        other
+            The values to compare with. Accepts expression input. Strings are parsed
+            as column names, other non-expression inputs are parsed as literals.
+        abs_tol
+            The absolute tolerance, which must be non-negative.
+        rel_tol
+            The relative tolerance, which must be non-negative.
+        nans_equal
+            Consider NaN values to be close to each other. By default, NaN values are
+            not close to anything.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [1.0, 1.5, float("nan"), float("inf"), None],
+        ...         "b": [1.05, 1.0, float("nan"), float("inf"), 1.0],
+        ...     }
+        ... )
+        >>> df.with_columns(pl.col("a").is_close("b", abs_tol=0.1).alias("is_close"))
+        shape: (5, 3)
+        ┌──────┬──────┬──────────┐
+        │ a    ┆ b    ┆ is_close │
+        │ ---  ┆ ---  ┆ ---      │
+        │ f64  ┆ f64  ┆ bool     │
+        ╞══════╪══════╪══════════╡
+        │ 1.0  ┆ 1.05 ┆ true     │
+        │ 1.5  ┆ 1.0  ┆ false    │
+        │ NaN  ┆ NaN  ┆ false    │
+        │ inf  ┆ inf  ┆ true     │
+        │ null ┆ 1.0  ┆ null     │
+        └──────┴──────┴──────────┘
+        """
+        other = parse_into_expression(other)
+        return self._from_pyexpr(
+            self._pyexpr.is_close(other, abs_tol, rel_tol, nans_equal)
+        )
+
     def hash(
         self,
         seed: int = 0,
@@ -8158,6 +8820,79 @@ class Expr:
             )
         )
 
+    @unstable()
+    def rolling_approx_quantile(
+        self,
+        quantile: float,
+        window_size: int = 2,
+        *,
+        relative_accuracy: float = 0.01,
+        min_periods: int | None = None,
+        center: bool = False,
+    ) -> Self:
+        """
+        Compute an approximate rolling quantile.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Contrary to :meth:`rolling_quantile`, the cost per value does not grow with
+        the window size, which makes this suited for very large windows. Every result
+        is within `relative_accuracy` of the exact quantile of the window, i.e.
+        `abs(estimate - exact) <= relative_accuracy * abs(exact)`, where the exact
+        quantile is computed with the `'lower'` interpolation method.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+        window_size
+            The length of the window in number of elements.
+        relative_accuracy
+            Maximum relative error of the results, between 0.0 and 1.0 (exclusive).
+            Smaller values give more accurate results at the cost of more memory.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
+        >>> df.with_columns(
+        ...     rolling_median=pl.col("A")
+        ...     .rolling_approx_quantile(quantile=0.5, window_size=4)
+        ...     .round(1),
+        ... )
+        shape: (6, 2)
+        ┌─────┬────────────────┐
+        │ A   ┆ rolling_median │
+        │ --- ┆ ---            │
+        │ f64 ┆ f64            │
+        ╞═════╪════════════════╡
+        │ 1.0 ┆ null           │
+        │ 2.0 ┆ null           │
+        │ 3.0 ┆ null           │
+        │ 4.0 ┆ 2.0            │
+        │ 5.0 ┆ 3.0            │
+        │ 6.0 ┆ 4.0            │
+        └─────┴────────────────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_approx_quantile(
+                quantile,
+                relative_accuracy,
+                window_size,
+                min_periods,
+                center=center,
+            )
+        )
+
     @unstable()
     def rolling_skew(self, window_size: int, *, bias: bool = True) -> Self:
         """
@@ -8297,6 +9032,8 @@ class Expr:
         *,
         descending: bool = False,
         seed: int | None = None,
+        pct: bool = False,
+        nan_ordering: NanOrdering = "largest",
     ) -> Self:
         """
         Assign ranks to data, dealing with ties appropriately.
@@ -8325,6 +9062,13 @@ class Expr:
             Rank in descending order.
         seed
             If `method="random"`, use this as seed.
+        pct
+            Return the ranks as a fraction of the number of non-null values, or of
+            the number of distinct values for `method="dense"`. The result is always
+            of type Float64.
+        nan_ordering : {'largest', 'smallest', 'null'}
+            How to rank `NaN` values: as the largest values, as the smallest values,
+            or as null values, which are not ranked.
 
         Examples
         --------
@@ -8379,7 +9123,74 @@ class Expr:
         │ 2   ┆ 11  ┆ 2.0  │
         └─────┴─────┴──────┘
         """
-        return self._from_pyexpr(self._pyexpr.rank(method, descending, seed))
+        return self._from_pyexpr(
+            self._pyexpr.rank(method, descending, seed, pct, nan_ordering)
+        )
+
+    def rank_by(
+        self,
+        by: IntoExpr | Iterable[IntoExpr],
+        method: RankMethod = "average",
+        *,
+        descending: bool = False,
+        seed: int | None = None,
+        pct: bool = False,
+    ) -> Self:
+        """
+        Assign ranks within the groups of rows that have the same values in `by`.
+
+        This gives the same result as `rank(...).over(by)`, but ranks all groups
+        with a single sort.
+
+        Parameters
+        ----------
+        by
+            Column(s) that define the groups. Accepts expression input. Strings are
+            parsed as column names.
+        method : {'average', 'min', 'max', 'dense', 'ordinal', 'random'}
+            The method used to assign ranks to tied elements, see :meth:`rank`.
+        descending
+            Rank in descending order.
+        seed
+            If `method="random"`, use this as seed.
+        pct
+            Return the ranks as a fraction of the number of non-null values in the
+            group, or of the number of distinct values for `method="dense"`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 1, 2, 2, 2], "b": [6, 7, 5, 14, 11]})
+        >>> df.with_columns(pl.col("b").rank_by("a").alias("rank"))
+        shape: (5, 3)
+        ┌─────┬─────┬──────┐
+        │ a   ┆ b   ┆ rank │
+        │ --- ┆ --- ┆ ---  │
+        │ i64 ┆ i64 ┆ f64  │
+        ╞═════╪═════╪══════╡
+        │ 1   ┆ 6   ┆ 1.0  │
+        │ 1   ┆ 7   ┆ 2.0  │
+        │ 2   ┆ 5   ┆ 1.0  │
+        │ 2   ┆ 14  ┆ 3.0  │
+        │ 2   ┆ 11  ┆ 2.0  │
+        └─────┴─────┴──────┘
+        >>> df.with_columns(pl.col("b").rank_by("a", "max", pct=True).alias("pct"))
+        shape: (5, 3)
+        ┌─────┬─────┬──────────┐
+        │ a   ┆ b   ┆ pct      │
+        │ --- ┆ --- ┆ ---      │
+        │ i64 ┆ i64 ┆ f64      │
+        ╞═════╪═════╪══════════╡
+        │ 1   ┆ 6   ┆ 0.5      │
+        │ 1   ┆ 7   ┆ 1.0      │
+        │ 2   ┆ 5   ┆ 0.333333 │
+        │ 2   ┆ 14  ┆ 1.0      │
+        │ 2   ┆ 11  ┆ 0.666667 │
+        └─────┴─────┴──────────┘
+        """
+        by = parse_into_list_of_expressions(by)
+        return self._from_pyexpr(
+            self._pyexpr.rank_by(by, method, descending, seed, pct)
+        )
 
     def diff(self, n: int = 1, null_behavior: NullBehavior = "ignore") -> Self:
         """
@@ -9401,6 +10212,140 @@ class Expr:
         half_life = parse_as_duration_string(half_life)
         return self._from_pyexpr(self._pyexpr.ewm_mean_by(by, half_life))
 
+    def ewm_std_by(
+        self,
+        by: str | IntoExpr,
+        *,
+        half_life: str | timedelta,
+        bias: bool = False,
+    ) -> Self:
+        """
+        Calculate time-based exponentially weighted moving standard deviation.
+
+        The weights are the same as those of :meth:`ewm_mean_by`: the weight of an
+        observation halves every `half_life` between its time and the current one.
+        Null values are ignored and remain null in the output.
+
+        Parameters
+        ----------
+        by
+            Times to calculate the standard deviation by. Should be ``DateTime``, ``Date``,
+            ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        half_life
+            Unit over which observation decays to half its value, see
+            :meth:`ewm_mean_by` for the accepted strings. Calendar durations such
+            as months are not supported.
+        bias
+            If False, the calculations are corrected for statistical bias, using
+            the effective number of observations given by the weights.
+
+        Returns
+        -------
+        Expr
+            Float32 if input is Float32, otherwise Float64.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [0, 1, 2, None, 4],
+        ...         "times": [
+        ...             date(2020, 1, 1),
+        ...             date(2020, 1, 3),
+        ...             date(2020, 1, 10),
+        ...             date(2020, 1, 15),
+        ...             date(2020, 1, 17),
+        ...         ],
+        ...     }
+        ... ).sort("times")
+        >>> df.with_columns(
+        ...     result=pl.col("values").ewm_std_by("times", half_life="4d"),
+        ... )
+        shape: (5, 3)
+        ┌────────┬────────────┬──────────┐
+        │ values ┆ times      ┆ result   │
+        │ ---    ┆ ---        ┆ ---      │
+        │ i64    ┆ date       ┆ f64      │
+        ╞════════╪════════════╪══════════╡
+        │ 0      ┆ 2020-01-01 ┆ 0.0      │
+        │ 1      ┆ 2020-01-03 ┆ 0.707107 │
+        │ 2      ┆ 2020-01-10 ┆ 1.214582 │
+        │ null   ┆ 2020-01-15 ┆ null     │
+        │ 4      ┆ 2020-01-17 ┆ 1.817498 │
+        └────────┴────────────┴──────────┘
+        """
+        by = parse_into_expression(by)
+        half_life = parse_as_duration_string(half_life)
+        return self._from_pyexpr(self._pyexpr.ewm_std_by(by, half_life, bias))
+
+    def ewm_var_by(
+        self,
+        by: str | IntoExpr,
+        *,
+        half_life: str | timedelta,
+        bias: bool = False,
+    ) -> Self:
+        """
+        Calculate time-based exponentially weighted moving variance.
+
+        The weights are the same as those of :meth:`ewm_mean_by`: the weight of an
+        observation halves every `half_life` between its time and the current one.
+        Null values are ignored and remain null in the output.
+
+        Parameters
+        ----------
+        by
+            Times to calculate the variance by. Should be ``DateTime``, ``Date``,
+            ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        half_life
+            Unit over which observation decays to half its value, see
+            :meth:`ewm_mean_by` for the accepted strings. Calendar durations such
+            as months are not supported.
+        bias
+            If False, the calculations are corrected for statistical bias, using
+            the effective number of observations given by the weights.
+
+        Returns
+        -------
+        Expr
+            Float32 if input is Float32, otherwise Float64.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [0, 1, 2, None, 4],
+        ...         "times": [
+        ...             date(2020, 1, 1),
+        ...             date(2020, 1, 3),
+        ...             date(2020, 1, 10),
+        ...             date(2020, 1, 15),
+        ...             date(2020, 1, 17),
+        ...         ],
+        ...     }
+        ... ).sort("times")
+        >>> df.with_columns(
+        ...     result=pl.col("values").ewm_var_by("times", half_life="4d"),
+        ... )
+        shape: (5, 3)
+        ┌────────┬────────────┬──────────┐
+        │ values ┆ times      ┆ result   │
+        │ ---    ┆ ---        ┆ ---      │
+        │ i64    ┆ date       ┆ f64      │
+        ╞════════╪════════════╪══════════╡
+        │ 0      ┆ 2020-01-01 ┆ 0.0      │
+        │ 1      ┆ 2020-01-03 ┆ 0.5      │
+        │ 2      ┆ 2020-01-10 ┆ 1.475209 │
+        │ null   ┆ 2020-01-15 ┆ null     │
+        │ 4      ┆ 2020-01-17 ┆ 3.303299 │
+        └────────┴────────────┴──────────┘
+        """
+        by = parse_into_expression(by)
+        half_life = parse_as_duration_string(half_life)
+        return self._from_pyexpr(self._pyexpr.ewm_var_by(by, half_life, bias))
+
     def ewm_std(
         self,
         *,
@@ -9822,6 +10767,106 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.entropy(base, normalize))
 
+    @unstable()
+    def scan(self, acc_init: IntoExpr, function: Callable[[Any, Any], Any]) -> Self:
+        """
+        Cumulatively fold the values into an accumulator, starting from `acc_init`.
+
+        The output has the same length as the input and holds the accumulator after
+        every value. Null values don't update the accumulator and produce a null. The
+        output dtype is the dtype of `acc_init`, and the values that `function`
+        returns are cast to it.
+
+        Contrary to :meth:`cumulative_eval`, this runs in linear time.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        acc_init
+            Initial value of the accumulator. Accepts expression input. Non-expression
+            inputs are parsed as literals.
+        function
+            Function that takes the accumulator and the next value and returns the
+            new accumulator.
+
+        Warnings
+        --------
+        The function is called for every value, which is slow for large columns. The
+        expression is executed by the in-memory engine, also when streaming is enabled.
+
+        See Also
+        --------
+        cum_reduce
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"values": [1, 2, 3, 4]})
+        >>> df.select(pl.col("values").scan(0.5, lambda acc, x: acc * 2 + x))
+        shape: (4, 1)
+        ┌────────┐
+        │ values │
+        │ ---    │
+        │ f64    │
+        ╞════════╡
+        │ 2.0    │
+        │ 6.0    │
+        │ 15.0   │
+        │ 34.0   │
+        └────────┘
+        """
+        acc_init = parse_into_expression(acc_init)
+        return self._from_pyexpr(self._pyexpr.scan(acc_init, function))
+
+    @unstable()
+    def cum_reduce(
+        self, acc_init: IntoExpr, function: Callable[[Any, Any], Any]
+    ) -> Self:
+        """
+        Cumulatively reduce the values with `function`, starting from `acc_init`.
+
+        This is a :meth:`scan` where the output dtype is the supertype of this
+        expression and `acc_init`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        acc_init
+            Initial value of the accumulator. Accepts expression input. Non-expression
+            inputs are parsed as literals.
+        function
+            Function that takes the accumulator and the next value and returns the
+            new accumulator.
+
+        See Also
+        --------
+        scan
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"values": [1, 2, 3, 4]})
+        >>> df.select(pl.col("values").cum_reduce(1, lambda acc, x: acc * x))
+        shape: (4, 1)
+        ┌────────┐
+        │ values │
+        │ ---    │
+        │ i64    │
+        ╞════════╡
+        │ 1      │
+        │ 2      │
+        │ 6      │
+        │ 24     │
+        └────────┘
+        """
+        acc_init = parse_into_expression(acc_init)
+        return self._from_pyexpr(self._pyexpr.cum_reduce(acc_init, function))
+
+    @deprecate_function("Use `scan` or `cum_reduce` instead.", version="1.0.0")
     @unstable()
     def cumulative_eval(
         self, expr: Expr, *, min_periods: int = 1, parallel: bool = False
@@ -9829,6 +10874,9 @@ class Expr:
         """
         Run an expression over a sliding window that increases `1` slot every iteration.
 
+        .. deprecated:: 1.0.0
+            Use :meth:`scan` or :meth:`cum_reduce` instead, which run in linear time.
+
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
@@ -9962,6 +11010,9 @@ class Expr:
         bins
             Discretizations to make.
             If None given, we determine the boundaries based on the data.
+            Accepts expression input, which may evaluate to a single list. In a
+            `group_by` context, the bins are evaluated for every group, and every
+            group gets a list with its own histogram.
         bin_count
             If no bins provided, this will be used to determine
             the distance of the bins
@@ -10187,6 +11238,60 @@ class Expr:
 
         return self._from_pyexpr(self._pyexpr.replace(old, new, default, return_dtype))
 
+    def lookup(
+        self,
+        other: DataFrame,
+        key: str,
+        value: str,
+        *,
+        on_missing: LookupMissing = "null",
+    ) -> Self:
+        """
+        Replace every value by the value of its key in a mapping frame.
+
+        The hash table of the mapping is built once, when the expression is created,
+        and is shared by every evaluation of the expression. This makes it a cheap
+        alternative to a join with a small mapping table.
+
+        Parameters
+        ----------
+        other
+            Mapping frame.
+        key
+            Name of the column of `other` holding the keys. The keys must be unique.
+        value
+            Name of the column of `other` holding the values.
+        on_missing : {'null', 'raise'}
+            What to do with values that are not a key of the mapping.
+
+            - 'null': Return null.
+            - 'raise': Raise an error.
+
+        Notes
+        -----
+        A null matches a null key. Without a null key, nulls are never considered
+        missing.
+
+        Examples
+        --------
+        >>> mapping = pl.DataFrame(
+        ...     {"code": ["DE", "FR"], "country": ["Germany", "France"]}
+        ... )
+        >>> df = pl.DataFrame({"code": ["FR", "NL", "DE"]})
+        >>> df.with_columns(country=pl.col("code").lookup(mapping, "code", "country"))
+        shape: (3, 2)
+        ┌──────┬─────────┐
+        │ code ┆ country │
+        │ ---  ┆ ---     │
+        │ str  ┆ str     │
+        ╞══════╪═════════╡
+        │ FR   ┆ France  │
+        │ NL   ┆ null    │
+        │ DE   ┆ Germany │
+        └──────┴─────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.lookup(other._df, key, value, on_missing))
+
     @deprecate_function(
         "Use `polars.plugins.register_plugin_function` instead.", version="0.20.16"
     )
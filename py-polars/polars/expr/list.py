@@ -1,11 +1,14 @@
 from __future__ import annotations
 
 import copy
-from typing import TYPE_CHECKING, Any, Callable, Sequence
+from typing import TYPE_CHECKING, Any, Callable, Iterable, Sequence
 
 import polars._reexport as pl
 from polars import functions as F
-from polars._utils.parse import parse_into_expression
+from polars._utils.parse import (
+    parse_into_expression,
+    parse_into_list_of_expressions,
+)
 from polars._utils.wrap import wrap_expr
 
 if TYPE_CHECKING:
@@ -15,6 +18,7 @@ if TYPE_CHECKING:
     from polars.type_aliases import (
         IntoExpr,
         IntoExprColumn,
+        ListZipLength,
         NullBehavior,
         ToStructStrategy,
     )
@@ -1358,3 +1362,117 @@ class ExprListNameSpace:
         """  # noqa: W505.
         other = parse_into_expression(other, str_as_lit=False)
         return wrap_expr(self._pyexpr.list_set_operation(other, "symmetric_difference"))
+
+    def zip_with(
+        self, *others: IntoExpr | Iterable[IntoExpr], length: ListZipLength = "strict"
+    ) -> Expr:
+        """
+        Zip the lists of this column with the lists of other columns into structs.
+
+        The i-th struct of every row holds the i-th element of each of the lists, in
+        fields named after the input columns. A row is null if it is null in any of the
+        inputs.
+
+        Parameters
+        ----------
+        *others
+            Other list columns to zip with. Accepts expression input. Strings are
+            parsed as column names.
+        length : {'strict', 'truncate', 'pad'}
+            How to deal with rows whose lists have different lengths.
+
+            - 'strict': Raise an error.
+            - 'truncate': Stop at the end of the shortest list.
+            - 'pad': Continue to the end of the longest list, filling the missing
+              elements with nulls.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [3]], "b": [["x", "y"], ["z"]]})
+        >>> df.select(pl.col("a").list.zip_with("b")).to_series().to_list()
+        [[{'a': 1, 'b': 'x'}, {'a': 2, 'b': 'y'}], [{'a': 3, 'b': 'z'}]]
+
+        Lists of different lengths can be truncated or padded.
+
+        >>> df = pl.DataFrame({"a": [[1, 2], [3]], "b": [["x"], ["y", "z"]]})
+        >>> out = df.select(pl.col("a").list.zip_with("b", length="truncate"))
+        >>> out["a"].to_list()
+        [[{'a': 1, 'b': 'x'}], [{'a': 3, 'b': 'y'}]]
+        """
+        others_pyexpr = parse_into_list_of_expressions(*others)
+        return wrap_expr(self._pyexpr.list_zip_with(others_pyexpr, length))
+
+    def enumerate(self) -> Expr:
+        """
+        Pair every element of the lists with its position in its list.
+
+        Every list becomes a list of structs with an `index` and a `value` field,
+        so the position of the elements is kept when the lists are exploded.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [["x", "y"], ["z"]]})
+        >>> df.select(pl.col("a").list.enumerate().explode()).unnest("a")
+        shape: (3, 2)
+        ┌───────┬───────┐
+        │ index ┆ value │
+        │ ---   ┆ ---   │
+        │ u32   ┆ str   │
+        ╞═══════╪═══════╡
+        │ 0     ┆ x     │
+        │ 1     ┆ y     │
+        │ 0     ┆ z     │
+        └───────┴───────┘
+        """
+        return wrap_expr(self._pyexpr.list_enumerate())
+
+    def rolling_sum(self, window_size: int, *, min_periods: int | None = None) -> Expr:
+        """
+        Compute the rolling sum over the values within every list.
+
+        The windows don't cross the boundaries of the lists, so every list is
+        aggregated independently and the result has the same shape as the input.
+
+        Parameters
+        ----------
+        window_size
+            The number of consecutive values in a window.
+        min_periods
+            The number of non-null values a window must have for its result to be
+            non-null. Defaults to `window_size`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3, 4], [5, 6]]})
+        >>> df.select(pl.col("a").list.rolling_sum(2)).to_series().to_list()
+        [[None, 3, 5, 7], [None, 11]]
+        """
+        if min_periods is None:
+            min_periods = window_size
+        return wrap_expr(self._pyexpr.list_rolling_sum(window_size, min_periods))
+
+    def rolling_mean(self, window_size: int, *, min_periods: int | None = None) -> Expr:
+        """
+        Compute the rolling mean over the values within every list.
+
+        The windows don't cross the boundaries of the lists, so every list is
+        aggregated independently and the result has the same shape as the input.
+
+        Parameters
+        ----------
+        window_size
+            The number of consecutive values in a window.
+        min_periods
+            The number of non-null values a window must have for its result to be
+            non-null. Defaults to `window_size`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3, 4], [5, 6]]})
+        >>> out = df.select(pl.col("a").list.rolling_mean(2, min_periods=1))
+        >>> out.to_series().to_list()
+        [[1.0, 1.5, 2.5, 3.5], [5.0, 5.5]]
+        """
+        if min_periods is None:
+            min_periods = window_size
+        return wrap_expr(self._pyexpr.list_rolling_mean(window_size, min_periods))
@@ -17,15 +17,20 @@ from polars.datatypes.constants import N_INFER_DEFAULT
 from polars.exceptions import ChronoFormatWarning
 
 if TYPE_CHECKING:
+    from typing import Sequence
+
     from polars import Expr
     from polars.type_aliases import (
         Ambiguous,
+        ChecksumMethod,
         IntoExpr,
         IntoExprColumn,
         PolarsDataType,
         PolarsTemporalType,
+        SplitFill,
         TimeUnit,
         TransferEncoding,
+        UnicodeForm,
     )
 
 
@@ -92,6 +97,7 @@ class ExprStringNameSpace:
         exact: bool = True,
         cache: bool = True,
         ambiguous: Ambiguous | Expr = "raise",
+        formats: Sequence[str] | None = None,
     ) -> Expr:
         """
         Convert a String column into a Datetime column.
@@ -138,6 +144,12 @@ class ExprStringNameSpace:
             - `'earliest'`: use the earliest datetime
             - `'latest'`: use the latest datetime
             - `'null'`: set to null
+        formats
+            Formats to try for every value, instead of a single `format`. A value is
+            parsed with the first format that matches, starting with the format that
+            parsed the previous value, so the formats should not match the same
+            values. Either all or none of the formats must parse a time zone or
+            offset.
 
         Examples
         --------
@@ -149,8 +161,24 @@ class ExprStringNameSpace:
                 2020-01-01 01:00:00 UTC
                 2020-01-01 02:00:00 UTC
         ]
+
+        Parse values that come in different formats.
+
+        >>> s = pl.Series(["2020-01-01 01:00", "02/01/2020 03:00", "2020-01-03 04:00"])
+        >>> s.str.to_datetime(formats=["%Y-%m-%d %H:%M", "%d/%m/%Y %H:%M"])
+        shape: (3,)
+        Series: '' [datetime[μs]]
+        [
+                2020-01-01 01:00:00
+                2020-01-02 03:00:00
+                2020-01-03 04:00:00
+        ]
         """
         _validate_format_argument(format)
+        if formats is not None:
+            for f in formats:
+                _validate_format_argument(f)
+            formats = list(formats)
         if not isinstance(ambiguous, pl.Expr):
             ambiguous = F.lit(ambiguous)
         return wrap_expr(
@@ -162,6 +190,7 @@ class ExprStringNameSpace:
                 exact,
                 cache,
                 ambiguous._pyexpr,
+                formats,
             )
         )
 
@@ -1800,20 +1829,30 @@ class ExprStringNameSpace:
             return wrap_expr(self._pyexpr.str_split_exact_inclusive(by, n))
         return wrap_expr(self._pyexpr.str_split_exact(by, n))
 
-    def splitn(self, by: IntoExpr, n: int) -> Expr:
+    def splitn(
+        self, by: IntoExpr, n: int | Expr, *, fill: SplitFill = "null"
+    ) -> Expr:
         """
         Split the string by a substring, restricted to returning at most `n` items.
 
         If the number of possible splits is less than `n-1`, the remaining field
-        elements will be null. If the number of possible splits is `n-1` or greater,
-        the last (nth) substring will contain the remainder of the string.
+        elements are filled according to `fill`. If the number of possible splits is
+        `n-1` or greater, the last (nth) substring will contain the remainder of the
+        string.
 
         Parameters
         ----------
         by
             Substring to split by.
         n
-            Max number of items to return.
+            Max number of items to return. Can be a literal expression, as the number
+            of fields of the struct must be known up front.
+        fill : {'null', 'empty', 'error'}
+            How to fill the fields of strings that have fewer than `n` parts.
+
+            - 'null': fill with null.
+            - 'empty': fill with an empty string.
+            - 'error': raise an error.
 
         Returns
         -------
@@ -1859,7 +1898,8 @@ class ExprStringNameSpace:
         └─────────────┴────────────┴─────────────┘
         """
         by = parse_into_expression(by, str_as_lit=True)
-        return wrap_expr(self._pyexpr.str_splitn(by, n))
+        n = parse_into_expression(n)
+        return wrap_expr(self._pyexpr.str_splitn(by, n, fill))
 
     def replace(
         self,
@@ -2053,6 +2093,162 @@ class ExprStringNameSpace:
         value = parse_into_expression(value, str_as_lit=True)
         return wrap_expr(self._pyexpr.str_replace_all(pattern, value, literal))
 
+    def checksum(
+        self,
+        method: ChecksumMethod = "luhn",
+        *,
+        weights: Sequence[int] | None = None,
+        modulus: int | None = None,
+    ) -> Expr:
+        """
+        Validate identifiers such as credit card numbers or IBANs by their check digits.
+
+        Parameters
+        ----------
+        method : {'luhn', 'mod97', 'iban', 'weighted'}
+            The check digit scheme.
+
+            * 'luhn': the Luhn (mod 10) algorithm of credit card numbers and IMEIs.
+            * 'mod97': ISO 7064 MOD 97-10, where letters count as 10 to 35.
+            * 'iban': an IBAN; the first four characters are moved to the end before
+              MOD 97-10 is checked.
+            * 'weighted': the digits multiplied by `weights`, repeated from the left,
+              must sum to a multiple of `modulus`.
+        weights
+            Weights of the digits for the 'weighted' method.
+        modulus
+            Modulus of the weighted sum for the 'weighted' method.
+
+        Notes
+        -----
+        Spaces and hyphens are ignored. Any other character that is not part of the
+        scheme makes the value invalid.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"card": ["4111 1111 1111 1111", "4111 1111 1111 1112", None]}
+        ... )
+        >>> df.with_columns(valid=pl.col("card").str.checksum("luhn"))
+        shape: (3, 2)
+        ┌─────────────────────┬───────┐
+        │ card                ┆ valid │
+        │ ---                 ┆ ---   │
+        │ str                 ┆ bool  │
+        ╞═════════════════════╪═══════╡
+        │ 4111 1111 1111 1111 ┆ true  │
+        │ 4111 1111 1111 1112 ┆ false │
+        │ null                ┆ null  │
+        └─────────────────────┴───────┘
+
+        ISBN-10 numbers use weights 10 to 1 and a modulus of 11.
+
+        >>> df = pl.DataFrame({"isbn": ["0-306-40615-2", "0-306-40615-3"]})
+        >>> df.with_columns(
+        ...     valid=pl.col("isbn").str.checksum(
+        ...         "weighted", weights=range(10, 0, -1), modulus=11
+        ...     )
+        ... )
+        shape: (2, 2)
+        ┌───────────────┬───────┐
+        │ isbn          ┆ valid │
+        │ ---           ┆ ---   │
+        │ str           ┆ bool  │
+        ╞═══════════════╪═══════╡
+        │ 0-306-40615-2 ┆ true  │
+        │ 0-306-40615-3 ┆ false │
+        └───────────────┴───────┘
+        """
+        return wrap_expr(
+            self._pyexpr.str_checksum(
+                method, None if weights is None else list(weights), modulus
+            )
+        )
+
+    def normalize(self, form: UnicodeForm = "NFC") -> Expr:
+        """
+        Normalize the strings to the given Unicode normalization form.
+
+        Strings that render identically can be made up of different code points,
+        e.g. "é" is either a single code point or an "e" followed by a combining
+        accent. Normalizing makes such strings compare equal.
+
+        Parameters
+        ----------
+        form : {'NFC', 'NFD', 'NFKC', 'NFKD'}
+            The Unicode normalization form.
+
+            * 'NFC': canonical decomposition, followed by canonical composition.
+            * 'NFD': canonical decomposition.
+            * 'NFKC': compatibility decomposition, followed by canonical composition.
+            * 'NFKD': compatibility decomposition.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        See Also
+        --------
+        casefold
+
+        Examples
+        --------
+        The compatibility forms also replace ligatures and superscripts.
+
+        >>> df = pl.DataFrame({"text": ["\ufb01ne", "1\u00b2"]})
+        >>> df.with_columns(normalized=pl.col("text").str.normalize("NFKC"))
+        shape: (2, 2)
+        ┌──────┬────────────┐
+        │ text ┆ normalized │
+        │ ---  ┆ ---        │
+        │ str  ┆ str        │
+        ╞══════╪════════════╡
+        │ ﬁne  ┆ fine       │
+        │ 1²   ┆ 12         │
+        └──────┴────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_normalize(form))
+
+    def casefold(self) -> Expr:
+        """
+        Apply full Unicode case folding to the strings.
+
+        Case folding maps all case variants of a string to the same string, also
+        when they differ in length, which makes it suitable for case-insensitive
+        comparisons. Lowercasing isn't: "ß" is lowercase, but folds to "ss".
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        See Also
+        --------
+        to_lowercase
+        normalize
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["Straße", "STRASSE"]})
+        >>> df.with_columns(folded=pl.col("text").str.casefold())
+        shape: (2, 2)
+        ┌─────────┬─────────┐
+        │ text    ┆ folded  │
+        │ ---     ┆ ---     │
+        │ str     ┆ str     │
+        ╞═════════╪═════════╡
+        │ Straße  ┆ strasse │
+        │ STRASSE ┆ strasse │
+        └─────────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.str_casefold())
+
     def reverse(self) -> Expr:
         """
         Returns string values in reversed order.
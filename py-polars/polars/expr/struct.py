@@ -150,6 +150,59 @@ class ExprStructNameSpace:
 
         return wrap_expr(self._pyexpr.struct_field_by_name(name))
 
+    def exclude(self, name: str | list[str], *more_names: str) -> Expr:
+        """
+        Retrieve all `Struct` fields except the given ones as new Series.
+
+        Parameters
+        ----------
+        name
+            Name of the struct field to exclude. Regular expressions, which
+            should start with `^` and end with `$`, exclude all fields they match.
+        *more_names
+            Additional struct field names to exclude.
+
+        See Also
+        --------
+        field
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "id": [1, 2],
+        ...         "sensor_a": [0.5, 0.7],
+        ...         "sensor_b": [1.5, 1.1],
+        ...         "status": ["ok", "error"],
+        ...     }
+        ... ).select(pl.struct("id", "sensor_a", "sensor_b", "status").alias("s"))
+        >>> df.select(pl.col("s").struct.exclude("status"))
+        shape: (2, 3)
+        ┌─────┬──────────┬──────────┐
+        │ id  ┆ sensor_a ┆ sensor_b │
+        │ --- ┆ ---      ┆ ---      │
+        │ i64 ┆ f64      ┆ f64      │
+        ╞═════╪══════════╪══════════╡
+        │ 1   ┆ 0.5      ┆ 1.5      │
+        │ 2   ┆ 0.7      ┆ 1.1      │
+        └─────┴──────────┴──────────┘
+
+        Exclude fields by regex:
+
+        >>> df.select(pl.col("s").struct.exclude("^sensor_.*$"))
+        shape: (2, 2)
+        ┌─────┬────────┐
+        │ id  ┆ status │
+        │ --- ┆ ---    │
+        │ i64 ┆ str    │
+        ╞═════╪════════╡
+        │ 1   ┆ ok     │
+        │ 2   ┆ error  │
+        └─────┴────────┘
+        """
+        names = [name] if isinstance(name, str) else name
+        return wrap_expr(self._pyexpr.struct_exclude_fields([*names, *more_names]))
+
     def rename_fields(self, names: Sequence[str]) -> Expr:
         """
         Rename the fields of the struct.
@@ -16,7 +16,9 @@ from polars.functions.aggregation import (
 from polars.functions.as_datatype import (
     concat_list,
     concat_str,
+    date_from_isoweek,
     duration,
+    duration_from_string,
     format,
     struct,
 )
@@ -53,6 +55,7 @@ from polars.functions.lazy import (
     last,
     map_batches,
     map_groups,
+    map_groups_flat,
     mean,
     median,
     n_unique,
@@ -132,8 +135,10 @@ __all__ = [
     "cum_fold",
     "cum_reduce",
     "date",  # named date_, see import above
+    "date_from_isoweek",
     "datetime",  # named datetime_, see import above
     "duration",
+    "duration_from_string",
     "exclude",
     "field",
     "first",
@@ -149,6 +154,7 @@ __all__ = [
     "lit",
     "map_batches",
     "map_groups",
+    "map_groups_flat",
     "mean",
     "mean_horizontal",
     "median",
@@ -225,6 +225,60 @@ def date_(
     return datetime_(year, month, day).cast(Date).alias("date")
 
 
+def date_from_isoweek(
+    iso_year: int | IntoExpr,
+    iso_week: int | IntoExpr,
+    weekday: int | IntoExpr,
+) -> Expr:
+    """
+    Create a Polars expression of type Date from ISO week dates.
+
+    Parameters
+    ----------
+    iso_year
+        Column or literal of the ISO year.
+    iso_week
+        Column or literal of the ISO week, ranging from 1-53.
+    weekday
+        Column or literal of the ISO weekday, ranging from 1 (Monday) to 7 (Sunday).
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`Date`. ISO week dates that don't exist,
+        like week 53 of a year that only has 52 weeks, result in null.
+
+    See Also
+    --------
+    polars.Expr.dt.iso_year_week_day
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "iso_year": [2020, 2021, 2021],
+    ...         "iso_week": [53, 1, 53],
+    ...         "weekday": [5, 1, 1],
+    ...     }
+    ... )
+    >>> df.with_columns(pl.date_from_isoweek("iso_year", "iso_week", "weekday"))
+    shape: (3, 4)
+    ┌──────────┬──────────┬─────────┬────────────┐
+    │ iso_year ┆ iso_week ┆ weekday ┆ date       │
+    │ ---      ┆ ---      ┆ ---     ┆ ---        │
+    │ i64      ┆ i64      ┆ i64     ┆ date       │
+    ╞══════════╪══════════╪═════════╪════════════╡
+    │ 2020     ┆ 53       ┆ 5       ┆ 2021-01-01 │
+    │ 2021     ┆ 1        ┆ 1       ┆ 2021-01-04 │
+    │ 2021     ┆ 53       ┆ 1       ┆ null       │
+    └──────────┴──────────┴─────────┴────────────┘
+    """
+    iso_year_expr = parse_into_expression(iso_year)
+    iso_week_expr = parse_into_expression(iso_week)
+    weekday_expr = parse_into_expression(weekday)
+    return wrap_expr(plr.date_from_isoweek(iso_year_expr, iso_week_expr, weekday_expr))
+
+
 def time_(
     hour: Expr | str | int | None = None,
     minute: Expr | str | int | None = None,
@@ -429,6 +483,54 @@ def duration(
     )
 
 
+def duration_from_string(
+    expr: IntoExpr, time_unit: TimeUnit = "us", *, strict: bool = True
+) -> Expr:
+    """
+    Parse ISO 8601 duration strings into a polars `Duration`.
+
+    Strings such as `"P1DT2H30M"`, `"PT1.5S"` or `"-P2W"` are supported. Years and
+    months are rejected, as their length in time is not fixed.
+
+    Parameters
+    ----------
+    expr
+        Expression or column name of data type :class:`String`.
+    time_unit : {'us', 'ms', 'ns'}
+        Time unit of the resulting expression.
+    strict
+        Raise an error if a string cannot be parsed. If set to `False`, such
+        strings are converted to null instead.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`Duration`.
+
+    See Also
+    --------
+    polars.Expr.dt.to_iso8601_duration
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"iso": ["P1DT2H30M", "PT1M30.5S", "-P2W", None]})
+    >>> df.with_columns(duration=pl.duration_from_string("iso"))
+    shape: (4, 2)
+    ┌───────────┬──────────────┐
+    │ iso       ┆ duration     │
+    │ ---       ┆ ---          │
+    │ str       ┆ duration[μs] │
+    ╞═══════════╪══════════════╡
+    │ P1DT2H30M ┆ 1d 2h 30m    │
+    │ PT1M30.5S ┆ 1m 30s 500ms │
+    │ -P2W      ┆ -14d         │
+    │ null      ┆ null         │
+    └───────────┴──────────────┘
+    """
+    string_expr = parse_into_expression(expr)
+    return wrap_expr(plr.duration_from_string(string_expr, time_unit, strict))
+
+
 def concat_list(exprs: IntoExpr | Iterable[IntoExpr], *more_exprs: IntoExpr) -> Expr:
     """
     Horizontally concatenate columns into a single list column.
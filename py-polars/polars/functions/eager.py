@@ -26,6 +26,7 @@ def concat(
     how: ConcatMethod = "vertical",
     rechunk: bool = False,
     parallel: bool = True,
+    by: str | None = None,
 ) -> PolarsType:
     """
     Combine multiple DataFrames, LazyFrames, or Series into a single object.
@@ -34,7 +35,7 @@ def concat(
     ----------
     items
         DataFrames, LazyFrames, or Series to concatenate.
-    how : {'vertical', 'vertical_relaxed', 'diagonal', 'diagonal_relaxed', 'horizontal', 'align'}
+    how : {'vertical', 'vertical_relaxed', 'diagonal', 'diagonal_relaxed', 'horizontal', 'align', 'merge_sorted_by'}
         Series only support the `vertical` strategy.
 
         * vertical: Applies multiple `vstack` operations.
@@ -50,11 +51,17 @@ def concat(
           and aligning rows using the same logic as `align_frames`; this behaviour is
           patterned after a full outer join, but does not handle column-name collision.
           (If you need more control, you should use a suitable join method instead).
+        * merge_sorted_by: Merges frames that are sorted by the column `by` into a
+          single sorted frame, without sorting the result. On the streaming engine,
+          LazyFrames that scan files are merged while they are read.
     rechunk
         Make sure that the result data is in contiguous memory.
     parallel
         Only relevant for LazyFrames. This determines if the concatenated
         lazy computations may be executed in parallel.
+    by
+        The column the frames are sorted by, for the `merge_sorted_by` strategy. The
+        frames must be sorted in ascending order, with nulls first.
 
     Examples
     --------
@@ -124,6 +131,22 @@ def concat(
     │ 2   ┆ 4    ┆ 5    ┆ null │
     │ 3   ┆ null ┆ 6    ┆ 8    │
     └─────┴──────┴──────┴──────┘
+
+    >>> df_m1 = pl.DataFrame({"t": [1, 4], "x": ["a", "b"]})
+    >>> df_m2 = pl.DataFrame({"t": [2, 3, 5], "x": ["c", "d", "e"]})
+    >>> pl.concat([df_m1, df_m2], how="merge_sorted_by", by="t")
+    shape: (5, 2)
+    ┌─────┬─────┐
+    │ t   ┆ x   │
+    │ --- ┆ --- │
+    │ i64 ┆ str │
+    ╞═════╪═════╡
+    │ 1   ┆ a   │
+    │ 2   ┆ c   │
+    │ 3   ┆ d   │
+    │ 4   ┆ b   │
+    │ 5   ┆ e   │
+    └─────┴─────┘
     """  # noqa: W505
     # unpack/standardise (handles generator input)
     elems = list(items)
@@ -136,6 +159,27 @@ def concat(
     ):
         return elems[0]
 
+    if how == "merge_sorted_by":
+        if by is None:
+            msg = "'merge_sorted_by' strategy requires the `by` column"
+            raise ValueError(msg)
+        if not isinstance(elems[0], (pl.DataFrame, pl.LazyFrame)):
+            type_name = type(elems[0]).__name__
+            msg = f"'merge_sorted_by' strategy is not supported for {type_name!r}"
+            raise TypeError(msg)
+
+        merged = wrap_ldf(
+            plr.concat_lf(
+                [df.lazy() for df in elems],
+                rechunk=rechunk,
+                parallel=parallel,
+                to_supertypes=False,
+                merge_sorted_by=by,
+            )
+        )
+        eager = isinstance(elems[0], pl.DataFrame)
+        return merged.collect() if eager else merged  # type: ignore[return-value]
+
     if how == "align":
         if not isinstance(elems[0], (pl.DataFrame, pl.LazyFrame)):
             msg = f"'align' strategy is not supported for {type(elems[0]).__name__!r}"
@@ -181,15 +225,7 @@ def concat(
         if how == "vertical":
             out = wrap_df(plr.concat_df(elems))
         elif how == "vertical_relaxed":
-            out = wrap_ldf(
-                plr.concat_lf(
-                    [df.lazy() for df in elems],
-                    rechunk=rechunk,
-                    parallel=parallel,
-                    to_supertypes=True,
-                )
-            ).collect(no_optimization=True)
-
+            out = wrap_df(plr.concat_df(elems, to_supertypes=True))
         elif how == "diagonal":
             out = wrap_df(plr.concat_df_diagonal(elems))
         elif how == "diagonal_relaxed":
@@ -1046,6 +1046,70 @@ def map_groups(
     )
 
 
+def map_groups_flat(
+    exprs: Sequence[str | Expr],
+    function: Callable[[Sequence[Series], Series], Series | Any],
+    return_dtype: PolarsDataType | None = None,
+) -> Expr:
+    """
+    Apply a custom/user-defined function (UDF) once for all groups of a GroupBy.
+
+    Contrary to :func:`map_groups`, the function is not called for every group. It
+    is called once, with the flat input Series and a `List` Series that holds the
+    row indices of every group, and must return one value per group. This avoids
+    the overhead of calling into Python for every group.
+
+    Outside of a GroupBy context, all rows form a single group.
+
+    .. warning::
+        This method is much slower than the native expressions API.
+        Only use it if you cannot implement your logic otherwise.
+
+    Parameters
+    ----------
+    exprs
+        Expression(s) representing the input Series to the function.
+    function
+        Function to apply over the input; should be of type
+        Callable[[Sequence[Series], Series], Series].
+    return_dtype
+        dtype of the output Series.
+
+    Returns
+    -------
+    Expr
+        Expression with the data type given by `return_dtype`.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "group": [1, 1, 2],
+    ...         "a": [1, 3, 3],
+    ...     }
+    ... )
+    >>> def weighted_last(inputs: list[pl.Series], groups: pl.Series) -> pl.Series:
+    ...     (a,) = inputs
+    ...     return pl.Series([a[idx[-1]] * len(idx) for idx in groups])
+    >>> (
+    ...     df.group_by("group")
+    ...     .agg(pl.map_groups_flat("a", weighted_last).alias("weighted_last"))
+    ...     .sort("group")
+    ... )
+    shape: (2, 2)
+    ┌───────┬───────────────┐
+    │ group ┆ weighted_last │
+    │ ---   ┆ ---           │
+    │ i64   ┆ i64           │
+    ╞═══════╪═══════════════╡
+    │ 1     ┆ 6             │
+    │ 2     ┆ 3             │
+    └───────┴───────────────┘
+    """
+    exprs = parse_into_list_of_expressions(exprs)
+    return wrap_expr(plr.map_groups_flat(exprs, function, return_dtype))
+
+
 def fold(
     acc: IntoExpr,
     function: Callable[[Series, Series], Series],
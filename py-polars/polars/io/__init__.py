@@ -11,9 +11,11 @@ from polars.io.json import read_json
 from polars.io.ndjson import read_ndjson, scan_ndjson
 from polars.io.parquet import read_parquet, read_parquet_schema, scan_parquet
 from polars.io.pyarrow_dataset import scan_pyarrow_dataset
+from polars.io.schema_inference import infer_schema
 from polars.io.spreadsheet import read_excel, read_ods
 
 __all__ = [
+    "infer_schema",
     "read_avro",
     "read_clipboard",
     "read_csv",
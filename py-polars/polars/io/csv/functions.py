@@ -7,6 +7,7 @@ from typing import IO, TYPE_CHECKING, Any, Callable, Mapping, Sequence
 
 import polars._reexport as pl
 from polars._utils.deprecation import deprecate_renamed_parameter
+from polars._utils.unstable import issue_unstable_warning
 from polars._utils.various import (
     _process_null_values,
     is_str_sequence,
@@ -937,6 +938,8 @@ def scan_csv(
     storage_options: dict[str, Any] | None = None,
     retries: int = 0,
     file_cache_ttl: int | None = None,
+    hive_partitioning: bool = False,
+    hive_schema: SchemaDict | None = None,
 ) -> LazyFrame:
     r"""
     Lazily read from a CSV file or multiple files via glob patterns.
@@ -1047,12 +1050,31 @@ def scan_csv(
 
         If `storage_options` is not provided, Polars will try to infer the information
         from environment variables.
+
+        Polars additionally accepts the following keys:
+
+        * `max_concurrent_requests`: the maximum number of concurrent requests of
+          this scan. This applies on top of the global limit that can be set with
+          the `POLARS_CONCURRENCY_BUDGET` environment variable.
+        * `retry_timeout`: the maximum number of seconds spent on retrying a single
+          request. Every request retries on its own, there is no retry budget that
+          is shared by the requests of a scan.
     retries
         Number of retries if accessing a cloud instance fails.
     file_cache_ttl
         Amount of time to keep downloaded cloud files since their last access time,
         in seconds. Uses the `POLARS_FILE_CACHE_TTL` environment variable
         (which defaults to 1 hour) if not given.
+    hive_partitioning
+        Parse the Hive partitions in the paths of the files into columns, and use
+        them to skip the files that are filtered out.
+    hive_schema
+        The column names and data types of the columns by which the data is partitioned.
+        If set to `None` (default), the schema of the Hive partitions is inferred.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
     Returns
     -------
@@ -1140,6 +1162,10 @@ def scan_csv(
     _check_arg_is_1byte("separator", separator, can_be_empty=False)
     _check_arg_is_1byte("quote_char", quote_char, can_be_empty=True)
 
+    if hive_schema is not None:
+        msg = "The `hive_schema` parameter of `scan_csv` is considered unstable."
+        issue_unstable_warning(msg)
+
     if isinstance(source, (str, Path)):
         source = normalize_filepath(source)
     else:
@@ -1176,6 +1202,8 @@ def scan_csv(
         retries=retries,
         storage_options=storage_options,
         file_cache_ttl=file_cache_ttl,
+        hive_partitioning=hive_partitioning,
+        hive_schema=hive_schema,
     )
 
 
@@ -1211,6 +1239,8 @@ def _scan_csv_impl(
     storage_options: dict[str, Any] | None = None,
     retries: int = 0,
     file_cache_ttl: int | None = None,
+    hive_partitioning: bool = False,
+    hive_schema: SchemaDict | None = None,
 ) -> LazyFrame:
     dtype_list: list[tuple[str, PolarsDataType]] | None = None
     if schema_overrides is not None:
@@ -1262,5 +1292,7 @@ def _scan_csv_impl(
         retries=retries,
         cloud_options=storage_options,
         file_cache_ttl=file_cache_ttl,
+        hive_partitioning=hive_partitioning,
+        hive_schema=hive_schema,
     )
     return wrap_ldf(pylf)
@@ -27,7 +27,7 @@ with contextlib.suppress(ImportError):
 
 if TYPE_CHECKING:
     from polars import DataFrame, DataType, LazyFrame
-    from polars.type_aliases import ParallelStrategy, SchemaDict
+    from polars.type_aliases import ParallelStrategy, PolarsDataType, SchemaDict
 
 
 @deprecate_renamed_parameter("row_count_name", "row_index_name", version="0.20.4")
@@ -297,6 +297,8 @@ def scan_parquet(
     cache: bool = True,
     storage_options: dict[str, Any] | None = None,
     retries: int = 0,
+    columns_matching: str | None = None,
+    dtypes: Sequence[PolarsDataType] | None = None,
 ) -> LazyFrame:
     """
     Lazily read from a local or cloud-hosted parquet file (or files).
@@ -353,8 +355,25 @@ def scan_parquet(
 
         If `storage_options` is not provided, Polars will try to infer the information
         from environment variables.
+
+        Polars additionally accepts the following keys:
+
+        * `max_concurrent_requests`: the maximum number of concurrent requests of
+          this scan. This applies on top of the global limit that can be set with
+          the `POLARS_CONCURRENCY_BUDGET` environment variable.
+        * `retry_timeout`: the maximum number of seconds spent on retrying a single
+          request. Every request retries on its own, there is no retry budget that
+          is shared by the requests of a scan.
     retries
         Number of retries if accessing a cloud instance fails.
+    columns_matching
+        Only keep the columns of the files whose name contains a match of this regular
+        expression. The columns are pruned from the schema of the files before any
+        projection, which is cheaper than selecting them from a wide file afterwards.
+        The row index and hive partition columns are always kept.
+    dtypes
+        Only keep the columns of the files that have one of these data types. If
+        `columns_matching` is set as well, the columns have to match both.
 
     See Also
     --------
@@ -402,6 +421,8 @@ def scan_parquet(
         hive_schema=hive_schema,
         retries=retries,
         glob=glob,
+        columns_matching=columns_matching,
+        dtypes=dtypes,
     )
 
 
@@ -421,6 +442,8 @@ def _scan_parquet_impl(
     glob: bool = True,
     hive_schema: SchemaDict | None = None,
     retries: int = 0,
+    columns_matching: str | None = None,
+    dtypes: Sequence[PolarsDataType] | None = None,
 ) -> LazyFrame:
     if isinstance(source, list):
         sources = source
@@ -449,5 +472,7 @@ def _scan_parquet_impl(
         hive_schema=hive_schema,
         retries=retries,
         glob=glob,
+        columns_matching=columns_matching,
+        dtypes=dtypes,
     )
     return wrap_ldf(pylf)
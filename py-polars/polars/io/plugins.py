@@ -0,0 +1,103 @@
+from __future__ import annotations
+
+import contextlib
+from io import StringIO
+from typing import TYPE_CHECKING, Callable, Collection, Union
+
+import polars._reexport as pl
+from polars._utils.unstable import unstable
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    from polars.polars import PyLazyFrame
+
+if TYPE_CHECKING:
+    from polars import DataFrame, Expr, LazyFrame
+    from polars.type_aliases import SchemaDict
+
+    IOSourceResult = Union[DataFrame, "tuple[DataFrame, Collection[str]]"]
+
+__all__ = ["register_io_source"]
+
+_APPLIED_PARTS = frozenset({"predicate", "n_rows"})
+
+
+@unstable()
+def register_io_source(
+    io_source: Callable[[list[str] | None, Expr | None, int | None], IOSourceResult],
+    *,
+    schema: SchemaDict,
+) -> LazyFrame:
+    """
+    Register your IO plugin and initialize a LazyFrame.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    The source is called when the query runs, with the optimizations that the query
+    optimizer pushed down into the scan:
+
+    * `with_columns`: the names of the projected columns, or `None` for all columns.
+    * `predicate`: an :class:`Expr` that the returned rows should satisfy, or `None`.
+    * `n_rows`: the number of rows to read, or `None` for all rows.
+
+    All of them are hints. The source returns a :class:`DataFrame`, or a tuple of a
+    DataFrame and a collection with the names of the hints it applied,
+    `"predicate"` and/or `"n_rows"`. The hints that the source didn't apply are
+    applied by Polars on the returned DataFrame, so a source may e.g. apply only the
+    predicates it can evaluate natively and report that it didn't apply the
+    predicate. Extra columns that weren't projected are dropped.
+
+    Parameters
+    ----------
+    io_source
+        Function that reads the data, called with `with_columns`, `predicate` and
+        `n_rows`.
+    schema
+        Schema of the DataFrame that the source returns when no projection is
+        pushed down.
+
+    Examples
+    --------
+    >>> from polars.io.plugins import register_io_source
+    >>> def source(with_columns, predicate, n_rows):
+    ...     df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+    ...     if with_columns is not None:
+    ...         df = df.select(with_columns)
+    ...     if n_rows is not None:
+    ...         df = df.head(n_rows)
+    ...     # Leave the predicate to Polars.
+    ...     return df, ["n_rows"]
+    >>> lf = register_io_source(source, schema={"a": pl.Int64, "b": pl.String})
+    >>> lf.filter(pl.col("a") > 1).select("b").collect()
+    shape: (2, 1)
+    ┌─────┐
+    │ b   │
+    │ --- │
+    │ str │
+    ╞═════╡
+    │ y   │
+    │ z   │
+    └─────┘
+    """
+
+    def scan(
+        with_columns: list[str] | None, predicate: str | None, n_rows: int | None
+    ) -> tuple[DataFrame, bool, bool]:
+        expr = None if predicate is None else pl.Expr.deserialize(StringIO(predicate))
+        return _normalize_result(io_source(with_columns, expr, n_rows))
+
+    self = pl.LazyFrame.__new__(pl.LazyFrame)
+    self._ldf = PyLazyFrame.scan_from_io_plugin(list(schema.items()), scan)
+    return self
+
+
+def _normalize_result(result: IOSourceResult) -> tuple[DataFrame, bool, bool]:
+    if isinstance(result, pl.DataFrame):
+        return result, False, False
+    df, applied = result
+    applied_parts = set(applied)
+    if unknown := applied_parts - _APPLIED_PARTS:
+        msg = f"IO source reported unknown applied parts: {sorted(unknown)!r}"
+        raise ValueError(msg)
+    return df, "predicate" in applied_parts, "n_rows" in applied_parts
@@ -0,0 +1,191 @@
+from __future__ import annotations
+
+import contextlib
+from io import BytesIO
+from itertools import islice
+from pathlib import Path
+from typing import IO, TYPE_CHECKING, Any, Literal, NamedTuple, Sequence, get_args
+
+import polars._reexport as pl
+from polars._utils.unstable import unstable
+from polars._utils.various import normalize_filepath
+from polars.datatypes import Boolean, Float64, List, String
+from polars.io.csv import read_csv
+from polars.io.json import read_json
+from polars.io.ndjson import read_ndjson, scan_ndjson
+
+with contextlib.suppress(ImportError):
+    from polars.polars import infer_sample_schema as _infer_sample_schema
+
+if TYPE_CHECKING:
+    from polars import DataFrame
+    from polars.type_aliases import PolarsDataType
+
+__all__ = ["infer_schema"]
+
+InferenceFormat = Literal["csv", "json", "ndjson"]
+InferenceStrategy = Literal["boolean", "integer", "float", "date", "datetime"]
+
+
+class SchemaInference(NamedTuple):
+    """Result of :func:`infer_schema`."""
+
+    report: DataFrame
+    """
+    One row per column and candidate dtype that parses any of the sampled values,
+    with the fraction of the values that parse and up to three conflicting values.
+    """
+
+    schema_overrides: dict[str, PolarsDataType]
+    """The inferred dtype of every column, to pass as `schema_overrides`."""
+
+
+@unstable()
+def infer_schema(
+    source: str | Path | IO[bytes] | bytes,
+    *,
+    format: InferenceFormat | None = None,
+    sample_rows: int = 1000,
+    strategies: Sequence[InferenceStrategy] | None = None,
+    **kwargs: Any,
+) -> SchemaInference:
+    """
+    Infer the schema of a CSV, JSON or NDJSON source from a sample of its rows.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Only the first `sample_rows` rows of CSV and NDJSON sources are read; a JSON
+    document is parsed as a whole. Every text value of the sample is classified the
+    way the CSV reader infers a schema, and each of the `strategies` parses the
+    values of its dtype. A column gets the first dtype, in the order `Boolean`,
+    `Int64`, `Float64`, `Date` and `Datetime`, that parses all of its non-null
+    values, and `String` otherwise. Columns that the reader already parses into
+    another dtype (e.g. numbers in JSON) keep that dtype.
+
+    Parameters
+    ----------
+    source
+        Path to a file, a file-like object or the raw bytes.
+    format : {'csv', 'json', 'ndjson'}
+        Format of the source. Inferred from the file extension if not given.
+    sample_rows
+        Number of rows to sample.
+    strategies : {'boolean', 'integer', 'float', 'date', 'datetime'}
+        Dtypes to try parsing text columns as. Defaults to all of them.
+    **kwargs
+        Additional keyword arguments passed to the reader, e.g. `separator`
+        for CSV.
+
+    Returns
+    -------
+    SchemaInference
+        A named tuple with a `report` DataFrame, holding the candidate dtypes of
+        every column with their confidence and conflicting examples, and a
+        `schema_overrides` dict with the inferred dtype of every column.
+
+    Examples
+    --------
+    >>> source = b"id,flag,score\\n1,true,0.5\\n2,false,n/a\\n3,true,1.5\\n"
+    >>> inference = pl.infer_schema(source, format="csv")
+    >>> inference.schema_overrides
+    {'id': Int64, 'flag': Boolean, 'score': String}
+    >>> inference.report.filter(pl.col("column") == "score")
+    shape: (2, 5)
+    ┌────────┬─────────┬────────────┬──────────┬───────────┐
+    │ column ┆ dtype   ┆ confidence ┆ selected ┆ conflicts │
+    │ ---    ┆ ---     ┆ ---        ┆ ---      ┆ ---       │
+    │ str    ┆ str     ┆ f64        ┆ bool     ┆ list[str] │
+    ╞════════╪═════════╪════════════╪══════════╪═══════════╡
+    │ score  ┆ String  ┆ 1.0        ┆ true     ┆ []        │
+    │ score  ┆ Float64 ┆ 0.666667   ┆ false    ┆ ["n/a"]   │
+    └────────┴─────────┴────────────┴──────────┴───────────┘
+    """
+    if sample_rows < 1:
+        msg = f"`sample_rows` must be positive, got {sample_rows}"
+        raise ValueError(msg)
+    if strategies is None:
+        strategies = get_args(InferenceStrategy)
+    elif unknown := set(strategies) - set(get_args(InferenceStrategy)):
+        msg = f"unknown schema inference strategies: {sorted(unknown)!r}"
+        raise ValueError(msg)
+    if format is None:
+        format = _format_from_source(source)
+
+    sample = _read_sample(source, format, sample_rows, kwargs)
+    columns = _infer_sample_schema(sample._df, list(strategies))
+
+    rows: list[dict[str, Any]] = []
+    schema_overrides: dict[str, PolarsDataType] = {}
+    for name, candidates in columns:
+        # The selected dtype comes first, then the most likely alternatives.
+        schema_overrides[name] = candidates[0][0]
+        rows.extend(
+            {
+                "column": name,
+                "dtype": str(dtype),
+                "confidence": confidence,
+                "selected": i == 0,
+                "conflicts": conflicts,
+            }
+            for i, (dtype, confidence, conflicts) in enumerate(candidates)
+        )
+
+    report = pl.DataFrame(
+        rows,
+        schema={
+            "column": String,
+            "dtype": String,
+            "confidence": Float64,
+            "selected": Boolean,
+            "conflicts": List(String),
+        },
+    )
+    return SchemaInference(report, schema_overrides)
+
+
+def _format_from_source(source: str | Path | IO[bytes] | bytes) -> InferenceFormat:
+    if isinstance(source, (str, Path)):
+        suffix = Path(source).suffix.lower().lstrip(".")
+        if suffix in ("csv", "tsv"):
+            return "csv"
+        if suffix in ("json", "ndjson"):
+            return suffix  # type: ignore[return-value]
+        if suffix == "jsonl":
+            return "ndjson"
+    msg = "cannot infer the format of the source; pass `format` explicitly"
+    raise ValueError(msg)
+
+
+def _read_sample(
+    source: str | Path | IO[bytes] | bytes,
+    format: InferenceFormat,
+    sample_rows: int,
+    kwargs: dict[str, Any],
+) -> DataFrame:
+    if format == "csv":
+        # Read everything as text, the strategies decide on the dtypes.
+        return read_csv(source, infer_schema_length=0, n_rows=sample_rows, **kwargs)
+    elif format == "ndjson":
+        if isinstance(source, (str, Path)):
+            return scan_ndjson(
+                normalize_filepath(source),
+                infer_schema_length=sample_rows,
+                n_rows=sample_rows,
+                **kwargs,
+            ).collect()
+        if isinstance(source, bytes):
+            source = BytesIO(source)
+        lines = b"".join(islice(source, sample_rows))
+        return read_ndjson(lines, **kwargs)
+    elif format == "json":
+        # A JSON document is a single value, so it has to be parsed as a whole.
+        return read_json(
+            source,  # type: ignore[arg-type]
+            infer_schema_length=sample_rows,
+            **kwargs,
+        ).head(sample_rows)
+    else:
+        msg = f"`format` must be one of {{'csv', 'json', 'ndjson'}}, got {format!r}"
+        raise ValueError(msg)
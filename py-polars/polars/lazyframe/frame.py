@@ -104,9 +104,12 @@ if TYPE_CHECKING:
         FrameInitTypes,
         IntoExpr,
         IntoExprColumn,
+        JoinExecutionStrategy,
         JoinStrategy,
         JoinValidation,
+        JsonTemporalFormat,
         Label,
+        NanOrdering,
         Orientation,
         PolarsDataType,
         RollingInterpolationMethod,
@@ -1225,6 +1228,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         nulls_last: bool | Sequence[bool] = False,
         maintain_order: bool = False,
         multithreaded: bool = True,
+        nan_ordering: NanOrdering = "largest",
     ) -> Self:
         """
         Sort the LazyFrame by the given columns.
@@ -1248,6 +1252,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             worse since this requires a stable search.
         multithreaded
             Sort using multiple threads.
+        nan_ordering : {'largest', 'smallest', 'null'}
+            Where to place `NaN` values of float columns: as the largest values, as the
+            smallest values, or as null values.
 
         Examples
         --------
@@ -1318,7 +1325,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         if isinstance(by, str) and not more_by:
             return self._from_pyldf(
                 self._ldf.sort(
-                    by, descending, nulls_last, maintain_order, multithreaded
+                    by,
+                    descending,
+                    nulls_last,
+                    maintain_order,
+                    multithreaded,
+                    nan_ordering,
                 )
             )
 
@@ -1327,7 +1339,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         nulls_last = extend_bool(nulls_last, len(by), "nulls_last", "by")
         return self._from_pyldf(
             self._ldf.sort_by_exprs(
-                by, descending, nulls_last, maintain_order, multithreaded
+                by, descending, nulls_last, maintain_order, multithreaded, nan_ordering
             )
         )
 
@@ -1581,6 +1593,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         truncate_nodes: int = 0,
         figsize: tuple[int, int] = (18, 8),
         streaming: bool = False,
+        expressions: bool = False,
     ) -> tuple[DataFrame, DataFrame]:
         """
         Profile a LazyFrame.
@@ -1620,6 +1633,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             matplotlib figsize of the profiling plot
         streaming
             Run parts of the query in a streaming fashion (this is in an alpha state)
+        expressions
+            Also time the individual expressions of `select` and `with_columns`
+            nodes. Every evaluation of such an expression adds a row named
+            `expr(<expression>)`, and every call of a user-defined function, such as
+            :meth:`Expr.map_batches`, a row named `udf(<expression>)`. The rows overlap
+            with the row of the node that evaluates them.
 
         Examples
         --------
@@ -1674,7 +1693,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             _eager=False,
             new_streaming=False,
         )
-        df, timings = ldf.profile()
+        df, timings = ldf.profile(expressions)
         (df, timings) = wrap_df(df), wrap_df(timings)
 
         if show_plot:
@@ -2010,6 +2029,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         In case of error `set_exception` is used on
         `asyncio.Future`/`gevent.event.AsyncResult` and will be reraised by them.
 
+        Cancelling the awaitable (for example by cancelling the task that awaits it)
+        stops the query at the next operation or batch boundary. The wrapper of
+        `gevent=True` cannot be cancelled.
+
         Examples
         --------
         >>> import asyncio
@@ -2064,9 +2087,16 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             new_streaming=False,
         )
 
-        result = _GeventDataFrameResult() if gevent else _AioDataFrameResult()
-        ldf.collect_with_callback(result._callback)  # type: ignore[attr-defined]
-        return result  # type: ignore[return-value]
+        if gevent:
+            result = _GeventDataFrameResult()
+            ldf.collect_with_callback(result._callback)  # type: ignore[attr-defined]
+            return result  # type: ignore[return-value]
+
+        aio_result = _AioDataFrameResult()
+        aio_result._cancel_token = ldf.collect_with_callback(  # type: ignore[attr-defined]
+            aio_result._callback
+        )
+        return aio_result  # type: ignore[return-value]
 
     def collect_schema(self) -> Schema:
         """
@@ -2111,6 +2141,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         row_group_size: int | None = None,
         data_pagesize_limit: int | None = None,
         maintain_order: bool = True,
+        partition_by: str | Sequence[str] | None = None,
+        checkpoint: bool = False,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -2170,6 +2202,18 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will  be slightly faster.
+        partition_by
+            Column(s) to partition by. If given, `path` is the root directory of a
+            hive-partitioned dataset, e.g. `path/a=1/data-00000-0000.parquet`, and
+            the partition columns are not written to the files. The result is
+            partitioned in batches of 2**20 rows, so every partition gets a file per
+            batch that contains it.
+        checkpoint
+            Record the written files of a partitioned sink in a manifest in `path`.
+            If the sink fails, running the same query again resumes it, and the files
+            of the completed batches are not written again. Every file is written to a
+            temporary path and renamed once it is complete. The manifest is removed
+            once the sink is done.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -2191,6 +2235,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         --------
         >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
         >>> lf.sink_parquet("out.parquet")  # doctest: +SKIP
+        >>> lf.sink_parquet("out", partition_by="year")  # doctest: +SKIP
         """
         lf = self._set_sink_optimizations(
             type_coercion=type_coercion,
@@ -2218,14 +2263,24 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 "null_count": True,
             }
 
+        if isinstance(partition_by, str):
+            partition_by = [partition_by]
+        elif partition_by is not None:
+            partition_by = list(partition_by)
+        elif checkpoint:
+            msg = "`checkpoint` can only be set together with `partition_by`"
+            raise ValueError(msg)
+
         return lf.sink_parquet(
-            path=normalize_filepath(path),
+            path=normalize_filepath(path, check_not_directory=partition_by is None),
             compression=compression,
             compression_level=compression_level,
             statistics=statistics,
             row_group_size=row_group_size,
             data_pagesize_limit=data_pagesize_limit,
             maintain_order=maintain_order,
+            partition_by=partition_by,
+            checkpoint=checkpoint,
         )
 
     @unstable()
@@ -2235,6 +2290,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         *,
         compression: str | None = "zstd",
         maintain_order: bool = True,
+        partition_by: str | Sequence[str] | None = None,
+        checkpoint: bool = False,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -2261,6 +2318,18 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will  be slightly faster.
+        partition_by
+            Column(s) to partition by. If given, `path` is the root directory of a
+            hive-partitioned dataset, e.g. `path/a=1/data-00000-0000.ipc`, and
+            the partition columns are not written to the files. The result is
+            partitioned in batches of 2**20 rows, so every partition gets a file per
+            batch that contains it.
+        checkpoint
+            Record the written files of a partitioned sink in a manifest in `path`.
+            If the sink fails, running the same query again resumes it, and the files
+            of the completed batches are not written again. Every file is written to a
+            temporary path and renamed once it is complete. The manifest is removed
+            once the sink is done.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -2292,10 +2361,20 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             no_optimization=no_optimization,
         )
 
+        if isinstance(partition_by, str):
+            partition_by = [partition_by]
+        elif partition_by is not None:
+            partition_by = list(partition_by)
+        elif checkpoint:
+            msg = "`checkpoint` can only be set together with `partition_by`"
+            raise ValueError(msg)
+
         return lf.sink_ipc(
             path=path,
             compression=compression,
             maintain_order=maintain_order,
+            partition_by=partition_by,
+            checkpoint=checkpoint,
         )
 
     @unstable()
@@ -2315,6 +2394,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         float_precision: int | None = None,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        column_quote_styles: dict[str, CsvQuoteStyle] | None = None,
         maintain_order: bool = True,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
@@ -2384,6 +2464,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
               Namely, when writing a field that does not parse as a valid float
               or integer, then quotes will be used even if they aren`t strictly
               necessary.
+        column_quote_styles
+            Quoting strategies of individual columns, by column name, that override
+            `quote_style`. They also apply to the names of these columns in the
+            header.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will  be slightly faster.
@@ -2439,6 +2523,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             float_precision=float_precision,
             null_value=null_value,
             quote_style=quote_style,
+            column_quote_styles=list((column_quote_styles or {}).items()),
             maintain_order=maintain_order,
         )
 
@@ -2448,6 +2533,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         path: str | Path,
         *,
         maintain_order: bool = True,
+        temporal_format: JsonTemporalFormat = "display",
+        decimal_as_string: bool = False,
+        omit_nulls: bool = False,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -2471,6 +2559,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
+        temporal_format : {'display', 'iso8601', 'epoch'}
+            How dates, times, datetimes and durations are written: as strings in
+            their display format, as ISO 8601 strings, or as the integers of their
+            underlying values. See :meth:`DataFrame.write_ndjson`.
+        decimal_as_string
+            Write decimals as strings instead of numbers.
+        omit_nulls
+            Leave fields out of a row when their value is null.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -2492,6 +2588,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         --------
         >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
         >>> lf.sink_ndjson("out.ndjson")  # doctest: +SKIP
+
+        Write datetimes as ISO 8601 strings, which other systems can parse.
+
+        >>> lf.sink_ndjson("out.ndjson", temporal_format="iso8601")  # doctest: +SKIP
         """
         lf = self._set_sink_optimizations(
             type_coercion=type_coercion,
@@ -2502,7 +2602,255 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             no_optimization=no_optimization,
         )
 
-        return lf.sink_json(path=path, maintain_order=maintain_order)
+        return lf.sink_json(
+            path=path,
+            maintain_order=maintain_order,
+            temporal_format=temporal_format,
+            decimal_as_string=decimal_as_string,
+            omit_nulls=omit_nulls,
+        )
+
+    @unstable()
+    def sink_batches(
+        self,
+        function: Callable[[DataFrame], None],
+        *,
+        chunk_size: int | None = None,
+        type_coercion: bool = True,
+        predicate_pushdown: bool = True,
+        projection_pushdown: bool = True,
+        simplify_expression: bool = True,
+        slice_pushdown: bool = True,
+        no_optimization: bool = False,
+    ) -> None:
+        """
+        Evaluate the query in streaming mode and pass the result to a function in batches.
+
+        .. warning::
+            Streaming mode is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        This allows pushing results that are larger than RAM to another system, such
+        as a message queue, without collecting them. The function is called with the
+        batches in order, one at a time. The query waits for the function if it cannot
+        keep up, and stops if the function raises.
+
+        Parameters
+        ----------
+        function
+            Function that is called with every batch as a DataFrame.
+        chunk_size
+            Number of rows per batch; only the last batch may be smaller. By default,
+            the batches are passed on as the streaming engine produces them.
+        type_coercion
+            Do type coercion optimization.
+        predicate_pushdown
+            Do predicate pushdown optimization.
+        projection_pushdown
+            Do projection pushdown optimization.
+        simplify_expression
+            Run simplify expressions optimization.
+        slice_pushdown
+            Slice pushdown optimization.
+        no_optimization
+            Turn off (certain) optimizations.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": range(10)})
+        >>> heights = []
+        >>> lf.sink_batches(lambda df: heights.append(df.height), chunk_size=4)
+        >>> heights
+        [4, 4, 2]
+        """
+        if chunk_size is not None and chunk_size <= 0:
+            msg = f"`chunk_size` must be positive, got {chunk_size}"
+            raise ValueError(msg)
+
+        lf = self._set_sink_optimizations(
+            type_coercion=type_coercion,
+            predicate_pushdown=predicate_pushdown,
+            projection_pushdown=projection_pushdown,
+            simplify_expression=simplify_expression,
+            slice_pushdown=slice_pushdown,
+            no_optimization=no_optimization,
+        )
+
+        lf.sink_batches(function, chunk_size)
+
+    @unstable()
+    def content_hash(self) -> tuple[int, int]:
+        """
+        Evaluate the query in streaming mode and compute fingerprints of the result.
+
+        .. warning::
+            Streaming mode is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The fingerprints are the same as those of :meth:`DataFrame.content_hash` on
+        the collected result, but the result is hashed batch by batch, so results that
+        are larger than RAM can be fingerprinted.
+
+        See Also
+        --------
+        DataFrame.content_hash
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+        >>> lf.content_hash() == lf.collect().content_hash()
+        True
+        """
+        return self._set_sink_optimizations().content_hash()
+
+    @unstable()
+    def sink_multiple(
+        self,
+        paths: Sequence[str | Path],
+        *,
+        maintain_order: bool = True,
+        type_coercion: bool = True,
+        predicate_pushdown: bool = True,
+        projection_pushdown: bool = True,
+        simplify_expression: bool = True,
+        slice_pushdown: bool = True,
+        no_optimization: bool = False,
+    ) -> None:
+        """
+        Evaluate the query in streaming mode and write to multiple files in one pass.
+
+        .. warning::
+            Streaming mode is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The query is run once and every batch is written to all files, so a costly
+        query isn't recomputed for every output. The format of each file is derived
+        from its extension, and the files are written with the default options of
+        the respective `sink_*` method.
+
+        Parameters
+        ----------
+        paths
+            File paths to which the files should be written. Supported extensions
+            are `.parquet`, `.arrow`, `.ipc`, `.feather`, `.csv`, `.ndjson` and
+            `.jsonl`.
+        maintain_order
+            Maintain the order in which data is processed.
+            Setting this to `False` will  be slightly faster.
+        type_coercion
+            Do type coercion optimization.
+        predicate_pushdown
+            Do predicate pushdown optimization.
+        projection_pushdown
+            Do projection pushdown optimization.
+        simplify_expression
+            Run simplify expressions optimization.
+        slice_pushdown
+            Slice pushdown optimization.
+        no_optimization
+            Turn off (certain) optimizations.
+
+        Examples
+        --------
+        >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+        >>> lf.sink_multiple(["out.parquet", "out.csv"])  # doctest: +SKIP
+        """
+        formats = {
+            ".parquet": "parquet",
+            ".arrow": "ipc",
+            ".ipc": "ipc",
+            ".feather": "ipc",
+            ".csv": "csv",
+            ".ndjson": "json",
+            ".jsonl": "json",
+        }
+        sinks = []
+        for path in paths:
+            file = normalize_filepath(path)
+            suffix = Path(file).suffix.lower()
+            if suffix not in formats:
+                msg = f"cannot derive the file format of {file!r} from its extension"
+                raise ValueError(msg)
+            sinks.append((file, formats[suffix]))
+        if not sinks:
+            msg = "`paths` must contain at least one path"
+            raise ValueError(msg)
+
+        lf = self._set_sink_optimizations(
+            type_coercion=type_coercion,
+            predicate_pushdown=predicate_pushdown,
+            projection_pushdown=projection_pushdown,
+            simplify_expression=simplify_expression,
+            slice_pushdown=slice_pushdown,
+            no_optimization=no_optimization,
+        )
+
+        lf.sink_multiple(sinks, maintain_order)
+
+    @unstable()
+    def snapshot(self, path: str | Path) -> DataFrame:
+        """
+        Collect the query and store the result in a directory, with the files it read.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The query must be a group by, or a select, of `sum`, `count`, `len`, `min` and
+        `max` aggregations over filters and projections of a single parquet scan of
+        local files. Use :meth:`refresh` to update the stored result when files are
+        added to the dataset.
+
+        Parameters
+        ----------
+        path
+            The directory to store the snapshot in.
+
+        See Also
+        --------
+        refresh
+
+        Examples
+        --------
+        >>> lf = pl.scan_parquet("/path/to/dataset/*.parquet")  # doctest: +SKIP
+        >>> lf.group_by("a").agg(pl.col("b").sum()).snapshot(
+        ...     "/path/to/snapshot"
+        ... )  # doctest: +SKIP
+        """
+        return wrap_df(self._ldf.snapshot(normalize_filepath(path)))
+
+    @unstable()
+    def refresh(self, path: str | Path) -> DataFrame:
+        """
+        Update a snapshot with the files that were added to the dataset since.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        This must be the query that the snapshot was taken of, created again so that
+        its scan finds the current files. Only the files that the snapshot doesn't know
+        are read, and their result is merged into the stored one. If a file of the
+        snapshot was changed or removed, the result is computed again from all files.
+        Without a snapshot in `path`, one is taken as with :meth:`snapshot`.
+
+        Parameters
+        ----------
+        path
+            The directory of the snapshot.
+
+        See Also
+        --------
+        snapshot
+
+        Examples
+        --------
+        >>> lf = pl.scan_parquet("/path/to/dataset/*.parquet")  # doctest: +SKIP
+        >>> lf.group_by("a").agg(pl.col("b").sum()).refresh(
+        ...     "/path/to/snapshot"
+        ... )  # doctest: +SKIP
+        """
+        return wrap_df(self._ldf.refresh(normalize_filepath(path)))
 
     def _set_sink_optimizations(
         self,
@@ -2670,6 +3018,53 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         """
         return self._from_pyldf(self._ldf.cache())
 
+    @unstable()
+    def with_resources(
+        self,
+        *,
+        max_threads: int | None = None,
+        memory_soft_limit: int | None = None,
+    ) -> Self:
+        """
+        Limit the threads and memory that the query may use when it is executed.
+
+        Unlike `POLARS_MAX_THREADS`, which sets the size of the thread pool of the
+        process, these limits apply to this query only. This allows queries that run
+        concurrently to be isolated from each other.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        max_threads
+            The maximum number of threads the query runs on. Defaults to the size of
+            the thread pool, see :func:`polars.thread_pool_size`.
+        memory_soft_limit
+            The number of bytes the query tries to stay below. Streaming operators
+            that can spill to disk, like sorts and group-bys, do so earlier to respect
+            it. The query doesn't fail if it exceeds the limit.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [3, 1, 2]})
+        >>> lf.with_resources(max_threads=2).sort("a").collect()
+        shape: (3, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 1   │
+        │ 2   │
+        │ 3   │
+        └─────┘
+        """
+        return self._from_pyldf(
+            self._ldf.with_resources(max_threads, memory_soft_limit)
+        )
+
     def cast(
         self,
         dtypes: (
@@ -3256,6 +3651,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         offset: str | timedelta | None = None,
         closed: ClosedInterval = "right",
         group_by: IntoExpr | Iterable[IntoExpr] | None = None,
+        descending: bool = False,
     ) -> LazyGroupBy:
         """
         Create rolling groups based on a temporal or integer column.
@@ -3307,12 +3703,13 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         index_column
             Column used to group based on the time window.
             Often of type Date/Datetime.
-            This column must be sorted in ascending order (or, if `group_by` is
-            specified, then it must be sorted in ascending order within each group).
+            This column must be sorted in ascending order, or in descending order if
+            `descending` is set (if `group_by` is specified, then it must be sorted
+            within each group).
 
             In case of a rolling group by on indices, dtype needs to be one of
-            {UInt32, UInt64, Int32, Int64}. Note that the first three get temporarily
-            cast to Int64, so if performance matters use an Int64 column.
+            {UInt32, UInt64, Int32, Int64}. Note that UInt64 gets temporarily cast
+            to Int64, so if performance matters use another integer dtype.
         period
             Length of the window - must be non-negative.
         offset
@@ -3321,6 +3718,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             Define which sides of the temporal interval are closed (inclusive).
         group_by
             Also group by this column/these columns
+        descending
+            The `index_column` is sorted in descending order. The windows are
+            determined by the values in the same way as for an ascending column.
 
         Returns
         -------
@@ -3380,7 +3780,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         period = parse_as_duration_string(period)
         offset = parse_as_duration_string(offset)
 
-        lgb = self._ldf.rolling(index_column, period, offset, closed, pyexprs_by)
+        lgb = self._ldf.rolling(
+            index_column, period, offset, closed, pyexprs_by, descending
+        )
         return LazyGroupBy(lgb)
 
     @deprecate_renamed_parameter("by", "group_by", version="0.20.14")
@@ -3916,6 +4318,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         coalesce: bool | None = None,
         allow_parallel: bool = True,
         force_parallel: bool = False,
+        strategy: JoinExecutionStrategy = "auto",
     ) -> Self:
         """
         Add a join operation to the Logical Plan.
@@ -3981,6 +4384,19 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         force_parallel
             Force the physical plan to evaluate the computation of both DataFrames up to
             the join in parallel.
+        strategy : {'auto', 'hash', 'sort_merge', 'broadcast_left', 'broadcast_right'}
+            Hint for how to execute the join.
+
+            - *auto*: let the engine decide. A hash join builds its hash table on
+              the input with the fewest rows, as estimated from the statistics of
+              the scans in a lazy query.
+            - *hash*: always use a hash join.
+            - *sort_merge*: merge the sorted join keys. Only supported for inner and
+              left joins on a single numeric key without nulls.
+            - *broadcast_left*: build the hash table on the left frame, which is
+              expected to be small. Only supported for inner joins.
+            - *broadcast_right*: build the hash table on the right frame, which is
+              expected to be small.
 
         See Also
         --------
@@ -4084,6 +4500,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                     how,
                     suffix,
                     validate,
+                    coalesce,
+                    strategy,
                 )
             )
 
@@ -4110,9 +4528,118 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 suffix,
                 validate,
                 coalesce,
+                strategy,
             )
         )
 
+    @unstable()
+    def intersect(self, other: LazyFrame, *, distinct: bool = True) -> Self:
+        """
+        Keep the rows that also occur in `other`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Columns are matched by position and null values are considered equal.
+        This is the equivalent of SQL's `INTERSECT` (or `INTERSECT ALL`).
+
+        Parameters
+        ----------
+        other
+            Lazy DataFrame with the same number of columns.
+        distinct
+            Remove duplicate rows from the result. Otherwise a row that occurs `m`
+            times in this frame and `n` times in `other` occurs `min(m, n)` times.
+
+        See Also
+        --------
+        except_
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 1, 2, 3], "b": ["x", "x", "y", None]})
+        >>> other = pl.LazyFrame({"a": [1, 1, 3], "b": ["x", "x", None]})
+        >>> lf.intersect(other).collect()
+        shape: (2, 2)
+        ┌─────┬──────┐
+        │ a   ┆ b    │
+        │ --- ┆ ---  │
+        │ i64 ┆ str  │
+        ╞═════╪══════╡
+        │ 1   ┆ x    │
+        │ 3   ┆ null │
+        └─────┴──────┘
+        >>> lf.intersect(other, distinct=False).collect()
+        shape: (3, 2)
+        ┌─────┬──────┐
+        │ a   ┆ b    │
+        │ --- ┆ ---  │
+        │ i64 ┆ str  │
+        ╞═════╪══════╡
+        │ 1   ┆ x    │
+        │ 1   ┆ x    │
+        │ 3   ┆ null │
+        └─────┴──────┘
+        """
+        if not isinstance(other, LazyFrame):
+            msg = f"expected `other` to be a LazyFrame, not a {type(other).__name__!r}"
+            raise TypeError(msg)
+        return self._from_pyldf(self._ldf.intersect(other._ldf, distinct))
+
+    @unstable()
+    def except_(self, other: LazyFrame, *, distinct: bool = True) -> Self:
+        """
+        Keep the rows that don't occur in `other`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Columns are matched by position and null values are considered equal.
+        This is the equivalent of SQL's `EXCEPT` (or `EXCEPT ALL`).
+
+        Parameters
+        ----------
+        other
+            Lazy DataFrame with the same number of columns.
+        distinct
+            Remove duplicate rows from the result. Otherwise a row that occurs `m`
+            times in this frame and `n` times in `other` occurs `m - n` times.
+
+        See Also
+        --------
+        intersect
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 1, 2, 3], "b": ["x", "x", "y", None]})
+        >>> other = pl.LazyFrame({"a": [1, 3], "b": ["x", None]})
+        >>> lf.except_(other).collect()
+        shape: (1, 2)
+        ┌─────┬─────┐
+        │ a   ┆ b   │
+        │ --- ┆ --- │
+        │ i64 ┆ str │
+        ╞═════╪═════╡
+        │ 2   ┆ y   │
+        └─────┴─────┘
+        >>> lf.except_(other, distinct=False).collect()
+        shape: (2, 2)
+        ┌─────┬─────┐
+        │ a   ┆ b   │
+        │ --- ┆ --- │
+        │ i64 ┆ str │
+        ╞═════╪═════╡
+        │ 1   ┆ x   │
+        │ 2   ┆ y   │
+        └─────┴─────┘
+        """
+        if not isinstance(other, LazyFrame):
+            msg = f"expected `other` to be a LazyFrame, not a {type(other).__name__!r}"
+            raise TypeError(msg)
+        return self._from_pyldf(self._ldf.except_(other._ldf, distinct))
+
     def with_columns(
         self,
         *exprs: IntoExpr | Iterable[IntoExpr],
@@ -5509,6 +6036,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         *,
         keep: UniqueKeepStrategy = "any",
         maintain_order: bool = False,
+        order_by: ColumnNameOrSelector | Collection[ColumnNameOrSelector] | None = None,
     ) -> Self:
         """
         Drop duplicate rows from this DataFrame.
@@ -5531,6 +6059,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             compute.
             Settings this to `True` blocks the possibility
             to run on the streaming engine.
+        order_by
+            Column name(s) or selector(s) that decide which of the duplicate rows is
+            kept, instead of their position. With `keep='first'` the row with the
+            smallest values is kept and with `keep='last'` the row with the largest
+            values. Ties go to the first or last of the tied rows respectively.
+            Ignored for `keep='any'` and `keep='none'`.
 
         Returns
         -------
@@ -5582,10 +6116,34 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         │ 3   ┆ a   ┆ b   │
         │ 1   ┆ a   ┆ b   │
         └─────┴─────┴─────┘
+
+        Keep the latest row of each key by ordering the duplicates on another column.
+
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "key": ["a", "b", "a", "b"],
+        ...         "ts": [3, 1, 2, 4],
+        ...         "val": [10, 20, 30, 40],
+        ...     }
+        ... )
+        >>> lf.unique("key", keep="last", order_by="ts", maintain_order=True).collect()
+        shape: (2, 3)
+        ┌─────┬─────┬─────┐
+        │ key ┆ ts  ┆ val │
+        │ --- ┆ --- ┆ --- │
+        │ str ┆ i64 ┆ i64 │
+        ╞═════╪═════╪═════╡
+        │ a   ┆ 3   ┆ 10  │
+        │ b   ┆ 4   ┆ 40  │
+        └─────┴─────┴─────┘
         """
         if subset is not None:
             subset = _expand_selectors(self, subset)
-        return self._from_pyldf(self._ldf.unique(maintain_order, subset, keep))
+        if order_by is not None:
+            order_by = _expand_selectors(self, order_by)
+        return self._from_pyldf(
+            self._ldf.unique(maintain_order, subset, keep, order_by)
+        )
 
     def drop_nulls(
         self,
@@ -5760,6 +6318,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         slice_pushdown: bool = True,
         no_optimizations: bool = False,
         schema: None | SchemaDict = None,
+        required_columns: Sequence[str] | None = None,
         validate_output_schema: bool = True,
         streamable: bool = False,
     ) -> Self:
@@ -5783,6 +6342,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         schema
             Output schema of the function, if set to `None` we assume that the schema
             will remain unchanged by the applied function.
+        required_columns
+            Names of the input columns that the function reads. If set, only these
+            columns are materialized for the function, also if `projection_pushdown`
+            is turned off, and the columns that are selected after this node are
+            pruned from the output of the function. If `schema` is not set, the
+            function must return the columns it is given.
         validate_output_schema
             It is paramount that polars' schema is correct. This flag will ensure that
             the output schema of this function will be checked with the expected schema.
@@ -5845,6 +6410,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 slice_pushdown,
                 streamable=streamable,
                 schema=schema,
+                required_columns=(
+                    None if required_columns is None else list(required_columns)
+                ),
                 validate_output=validate_output_schema,
             )
         )
@@ -621,3 +621,71 @@ class ArrayNameSpace:
             [6, null, null]
         ]
         """
+
+    def slice(self, offset: int, length: int) -> Series:
+        """
+        Slice every sub-array.
+
+        Contrary to `list.slice`, the result is again an array. Its width is
+        determined by `offset` and `length`.
+
+        Parameters
+        ----------
+        offset
+            Start index. Negative indexing is supported.
+        length
+            Length of the slice.
+
+        Examples
+        --------
+        >>> s = pl.Series([[1, 2, 3], [4, 5, 6]], dtype=pl.Array(pl.Int64, 3))
+        >>> s.arr.slice(1, 2)
+        shape: (2,)
+        Series: '' [array[i64, 2]]
+        [
+            [2, 3]
+            [5, 6]
+        ]
+        """
+
+    def head(self, n: int = 5) -> Series:
+        """
+        Get the first `n` elements of every sub-array.
+
+        Parameters
+        ----------
+        n
+            Number of elements to return.
+
+        Examples
+        --------
+        >>> s = pl.Series([[1, 2, 3], [4, 5, 6]], dtype=pl.Array(pl.Int64, 3))
+        >>> s.arr.head(2)
+        shape: (2,)
+        Series: '' [array[i64, 2]]
+        [
+            [1, 2]
+            [4, 5]
+        ]
+        """
+
+    def tail(self, n: int = 5) -> Series:
+        """
+        Get the last `n` elements of every sub-array.
+
+        Parameters
+        ----------
+        n
+            Number of elements to return.
+
+        Examples
+        --------
+        >>> s = pl.Series([[1, 2, 3], [4, 5, 6]], dtype=pl.Array(pl.Int64, 3))
+        >>> s.arr.tail(2)
+        shape: (2,)
+        Series: '' [array[i64, 2]]
+        [
+            [2, 3]
+            [5, 6]
+        ]
+        """
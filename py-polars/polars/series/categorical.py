@@ -7,6 +7,8 @@ from polars._utils.wrap import wrap_s
 from polars.series.utils import expr_dispatch
 
 if TYPE_CHECKING:
+    from collections.abc import Sequence
+
     from polars import Series
     from polars.polars import PySeries
 
@@ -37,6 +39,128 @@ class CatNameSpace:
         ]
         """
 
+    def add_categories(self, categories: Sequence[str]) -> Series:
+        """
+        Append categories to the categories of an Enum.
+
+        Categories that already exist are skipped. The existing categories keep
+        their position, so the physical representation of the values is unchanged.
+
+        Parameters
+        ----------
+        categories
+            The categories to append.
+
+        Examples
+        --------
+        >>> s = pl.Series(["S", "M", "S"], dtype=pl.Enum(["S", "M"]))
+        >>> s.cat.add_categories(["M", "L"]).dtype
+        Enum(categories=['S', 'M', 'L'])
+        """
+
+    def reorder_categories(self, categories: Sequence[str]) -> Series:
+        """
+        Reorder the categories of an Enum.
+
+        The values are unchanged, but they sort in the order of the new categories.
+
+        Parameters
+        ----------
+        categories
+            Every category of the Enum exactly once, in the new order.
+
+        Examples
+        --------
+        >>> s = pl.Series(["S", "M", "L"], dtype=pl.Enum(["M", "S", "L"]))
+        >>> s.cat.reorder_categories(["S", "M", "L"]).sort()
+        shape: (3,)
+        Series: '' [enum]
+        [
+            "S"
+            "M"
+            "L"
+        ]
+        """
+
+    def contains(
+        self, pattern: str, *, literal: bool = False, strict: bool = True
+    ) -> Series:
+        """
+        Check if the categories contain a substring that matches a pattern.
+
+        The pattern is evaluated once per category instead of once per row.
+
+        Parameters
+        ----------
+        pattern
+            A valid regular expression pattern, compatible with the `regex crate
+            <https://docs.rs/regex/latest/regex/>`_.
+        literal
+            Treat `pattern` as a literal string, not as a regular expression.
+        strict
+            Raise an error if the underlying pattern is not a valid regex,
+            otherwise mask out with a null value.
+
+        Examples
+        --------
+        >>> s = pl.Series(["apple", "banana", None, "apricot"], dtype=pl.Categorical)
+        >>> s.cat.contains("an+")
+        shape: (4,)
+        Series: '' [bool]
+        [
+            false
+            true
+            null
+            false
+        ]
+        """
+
+    def starts_with(self, prefix: str) -> Series:
+        """
+        Check if the categories start with a substring.
+
+        Parameters
+        ----------
+        prefix
+            Prefix substring.
+
+        Examples
+        --------
+        >>> s = pl.Series(["apple", "banana", None, "apricot"], dtype=pl.Categorical)
+        >>> s.cat.starts_with("ap")
+        shape: (4,)
+        Series: '' [bool]
+        [
+            true
+            false
+            null
+            true
+        ]
+        """
+
+    def ends_with(self, suffix: str) -> Series:
+        """
+        Check if the categories end with a substring.
+
+        Parameters
+        ----------
+        suffix
+            Suffix substring.
+
+        Examples
+        --------
+        >>> s = pl.Series(["apple", "banana", None, "apricot"], dtype=pl.Categorical)
+        >>> s.cat.ends_with("e")
+        shape: (4,)
+        Series: '' [bool]
+        [
+            true
+            false
+            null
+            false
+        ]
+        """
+
     def is_local(self) -> bool:
         """
         Return whether or not the column is a local categorical.
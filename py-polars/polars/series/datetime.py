@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING, Iterable
+from typing import TYPE_CHECKING, Iterable, Literal
 
 from polars._utils.deprecation import deprecate_function
 from polars._utils.unstable import unstable
@@ -597,6 +597,34 @@ class DateTimeNameSpace:
         ]
         """
 
+    def iso_year_week_day(self) -> Series:
+        """
+        Extract the ISO year, ISO week and ISO weekday as a struct.
+
+        Applies to Date and Datetime columns.
+
+        The struct has the fields `iso_year`, `iso_week` and `weekday`, where the
+        weekday number ranges from monday = 1 to sunday = 7.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Struct` with fields of data type
+            :class:`Int32`, :class:`Int8` and :class:`Int8`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series("date", [date(2020, 12, 31), date(2021, 1, 4)])
+        >>> s.dt.iso_year_week_day()
+        shape: (2,)
+        Series: 'date' [struct[3]]
+        [
+                {2020,53,4}
+                {2021,1,1}
+        ]
+        """
+
     def day(self) -> Series:
         """
         Extract the day from the underlying date representation.
@@ -1566,6 +1594,35 @@ class DateTimeNameSpace:
         ]
         """
 
+    def to_iso8601_duration(self) -> Series:
+        """
+        Format a Duration as an ISO 8601 duration string.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        See Also
+        --------
+        polars.duration_from_string
+
+        Examples
+        --------
+        >>> from datetime import timedelta
+        >>> s = pl.Series(
+        ...     "duration",
+        ...     [timedelta(days=1, hours=2, minutes=30), timedelta(seconds=-1)],
+        ... )
+        >>> s.dt.to_iso8601_duration()
+        shape: (2,)
+        Series: 'duration' [str]
+        [
+                "P1DT2H30M"
+                "-PT1S"
+        ]
+        """
+
     def offset_by(self, by: str | Expr) -> Series:
         """
         Offset this date by a relative time offset.
@@ -1642,21 +1699,42 @@ class DateTimeNameSpace:
         ]
         """
 
-    def truncate(self, every: str | dt.timedelta | Expr) -> Series:
+    def truncate(
+        self,
+        every: str | dt.timedelta | Expr,
+        *,
+        ambiguous: Ambiguous | Literal["infer"] | Series = "infer",
+        non_existent: NonExistent | Series = "raise",
+    ) -> Series:
         """
         Divide the date/ datetime range into buckets.
 
         Each date/datetime is mapped to the start of its bucket using the corresponding
         local datetime. Note that weekly buckets start on Monday.
-        Ambiguous results are localised using the DST offset of the original timestamp -
-        for example, truncating `'2022-11-06 01:30:00 CST'` by `'1h'` results in
-        `'2022-11-06 01:00:00 CST'`, whereas truncating `'2022-11-06 01:30:00 CDT'` by
-        `'1h'` results in `'2022-11-06 01:00:00 CDT'`.
+        By default, ambiguous results are localised using the DST offset of the
+        original timestamp - for example, truncating `'2022-11-06 01:30:00 CST'`
+        by `'1h'` results in `'2022-11-06 01:00:00 CST'`, whereas truncating
+        `'2022-11-06 01:30:00 CDT'` by `'1h'` results in
+        `'2022-11-06 01:00:00 CDT'`.
 
         Parameters
         ----------
         every
             Every interval start and period length
+        ambiguous
+            Determine how to deal with ambiguous results:
+
+            - `'infer'` (default): use the DST offset of the original datetime
+            - `'raise'`: raise
+            - `'earliest'`: use the earliest datetime
+            - `'latest'`: use the latest datetime
+            - `'null'`: set to null
+        non_existent
+            Determine how to deal with results that don't exist in the time zone,
+            either for all rows or per row with a Series:
+
+            - `'raise'` (default): raise
+            - `'null'`: set to null
 
         Notes
         -----
@@ -1756,7 +1834,13 @@ class DateTimeNameSpace:
         """
 
     @unstable()
-    def round(self, every: str | dt.timedelta | IntoExprColumn) -> Series:
+    def round(
+        self,
+        every: str | dt.timedelta | IntoExprColumn,
+        *,
+        ambiguous: Ambiguous | Literal["infer"] | Series = "infer",
+        non_existent: NonExistent | Series = "raise",
+    ) -> Series:
         """
         Divide the date/ datetime range into buckets.
 
@@ -1768,15 +1852,30 @@ class DateTimeNameSpace:
         its bucket.
         Each date/datetime in the second half of the interval is mapped to the end of
         its bucket.
-        Ambiguous results are localized using the DST offset of the original timestamp -
-        for example, rounding `'2022-11-06 01:20:00 CST'` by `'1h'` results in
-        `'2022-11-06 01:00:00 CST'`, whereas rounding `'2022-11-06 01:20:00 CDT'` by
-        `'1h'` results in `'2022-11-06 01:00:00 CDT'`.
+        By default, ambiguous results are localized using the DST offset of the
+        original timestamp - for example, rounding `'2022-11-06 01:20:00 CST'`
+        by `'1h'` results in `'2022-11-06 01:00:00 CST'`, whereas rounding
+        `'2022-11-06 01:20:00 CDT'` by `'1h'` results in
+        `'2022-11-06 01:00:00 CDT'`.
 
         Parameters
         ----------
         every
             Every interval start and period length
+        ambiguous
+            Determine how to deal with ambiguous results:
+
+            - `'infer'` (default): use the DST offset of the original datetime
+            - `'raise'`: raise
+            - `'earliest'`: use the earliest datetime
+            - `'latest'`: use the latest datetime
+            - `'null'`: set to null
+        non_existent
+            Determine how to deal with results that don't exist in the time zone,
+            either for all rows or per row with a Series:
+
+            - `'raise'` (default): raise
+            - `'null'`: set to null
 
         Returns
         -------
@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING, Any, Callable, Sequence
+from typing import TYPE_CHECKING, Any, Callable, Iterable, Sequence
 
 from polars import functions as F
 from polars._utils.wrap import wrap_s
@@ -14,6 +14,7 @@ if TYPE_CHECKING:
     from polars.type_aliases import (
         IntoExpr,
         IntoExprColumn,
+        ListZipLength,
         NullBehavior,
         ToStructStrategy,
     )
@@ -1052,3 +1053,94 @@ class ListNameSpace:
             [5, 7, 8]
         ]
         """  # noqa: W505
+
+    def zip_with(
+        self, *others: IntoExpr | Iterable[IntoExpr], length: ListZipLength = "strict"
+    ) -> Series:
+        """
+        Zip the lists of this Series with the lists of other Series into structs.
+
+        The i-th struct of every row holds the i-th element of each of the lists, in
+        fields named after the input Series. A row is null if it is null in any of the
+        inputs.
+
+        Parameters
+        ----------
+        *others
+            Other list Series to zip with.
+        length : {'strict', 'truncate', 'pad'}
+            How to deal with rows whose lists have different lengths.
+
+            - 'strict': Raise an error.
+            - 'truncate': Stop at the end of the shortest list.
+            - 'pad': Continue to the end of the longest list, filling the missing
+              elements with nulls.
+
+        Examples
+        --------
+        >>> a = pl.Series("a", [[1, 2], [3]])
+        >>> b = pl.Series("b", [["x", "y"], ["z"]])
+        >>> a.list.zip_with(b).to_list()
+        [[{'a': 1, 'b': 'x'}, {'a': 2, 'b': 'y'}], [{'a': 3, 'b': 'z'}]]
+        """
+
+    def enumerate(self) -> Series:
+        """
+        Pair every element of the lists with its position in its list.
+
+        Every list becomes a list of structs with an `index` and a `value` field.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [["x", "y"], ["z"]])
+        >>> s.list.enumerate().to_list()
+        [[{'index': 0, 'value': 'x'}, {'index': 1, 'value': 'y'}], [{'index': 0, 'value': 'z'}]]
+        """  # noqa: W505
+
+    def rolling_sum(
+        self, window_size: int, *, min_periods: int | None = None
+    ) -> Series:
+        """
+        Compute the rolling sum over the values within every list.
+
+        The windows don't cross the boundaries of the lists, so every list is
+        aggregated independently and the result has the same shape as the input.
+
+        Parameters
+        ----------
+        window_size
+            The number of consecutive values in a window.
+        min_periods
+            The number of non-null values a window must have for its result to be
+            non-null. Defaults to `window_size`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3, 4], [5, 6]])
+        >>> s.list.rolling_sum(2).to_list()
+        [[None, 3, 5, 7], [None, 11]]
+        """
+
+    def rolling_mean(
+        self, window_size: int, *, min_periods: int | None = None
+    ) -> Series:
+        """
+        Compute the rolling mean over the values within every list.
+
+        The windows don't cross the boundaries of the lists, so every list is
+        aggregated independently and the result has the same shape as the input.
+
+        Parameters
+        ----------
+        window_size
+            The number of consecutive values in a window.
+        min_periods
+            The number of non-null values a window must have for its result to be
+            non-null. Defaults to `window_size`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3, 4], [5, 6]])
+        >>> s.list.rolling_mean(2, min_periods=1).to_list()
+        [[1.0, 1.5, 2.5, 3.5], [5.0, 5.5]]
+        """
@@ -25,6 +25,7 @@ from typing import (
 import polars._reexport as pl
 from polars import functions as F
 from polars._utils.construction import (
+    arrow_stream_to_pyseries,
     arrow_to_pyseries,
     dataframe_to_pyseries,
     iterable_to_pyseries,
@@ -65,6 +66,7 @@ from polars.datatypes import (
     Decimal,
     Duration,
     Enum,
+    Float16,
     Float32,
     Float64,
     Int32,
@@ -131,6 +133,7 @@ if TYPE_CHECKING:
         IntoExpr,
         IntoExprColumn,
         MultiIndexSelector,
+        NanOrdering,
         NonNestedLiteral,
         NullBehavior,
         NumericLiteral,
@@ -347,6 +350,11 @@ class Series:
                 original_name, values, dtype=dtype, strict=strict
             )
 
+        elif hasattr(values, "__arrow_c_stream__"):
+            self._s = arrow_stream_to_pyseries(
+                name, values, dtype=dtype, strict=strict
+            )
+
         else:
             msg = (
                 f"Series constructor called with unsupported type {type(values).__name__!r}"
@@ -683,6 +691,10 @@ class Series:
             assert f is not None
             return self._from_pyseries(f(d))
 
+        elif isinstance(other, (int, float)) and self.dtype == Float16:
+            # values are compared as the 32-bit floats they are computed on
+            self = self.cast(Float32)
+
         if isinstance(other, Sequence) and not isinstance(other, str):
             if self.dtype in (List, Array):
                 other = [other]
@@ -1284,6 +1296,16 @@ class Series:
 
         return arr
 
+    def __arrow_c_stream__(self, requested_schema: object | None = None) -> object:
+        """
+        Export the Series as an Arrow C stream.
+
+        This implements the Arrow PyCapsule interface, such that the Series can be
+        consumed by other libraries without copying. Every chunk of the Series is
+        exported as a separate array of the stream.
+        """
+        return self._s.__arrow_c_stream__(requested_schema)
+
     def __array_ufunc__(
         self, ufunc: np.ufunc, method: str, *inputs: Any, **kwargs: Any
     ) -> Series:
@@ -2529,6 +2551,10 @@ class Series:
         """
         Run an expression over a sliding window that increases `1` slot every iteration.
 
+        .. deprecated:: 1.0.0
+            Use :meth:`Expr.scan` or :meth:`Expr.cum_reduce` instead, which run in
+            linear time.
+
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
@@ -2779,6 +2805,84 @@ class Series:
         ]
         """
 
+    def cum_mean(self, *, reverse: bool = False) -> Series:
+        """
+        Get an array with the cumulative mean computed at every element.
+
+        Parameters
+        ----------
+        reverse
+            reverse the operation.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 3, 2, 6])
+        >>> s.cum_mean()
+        shape: (4,)
+        Series: 'a' [f64]
+        [
+                1.0
+                2.0
+                2.0
+                3.0
+        ]
+        """
+
+    def cum_std(self, *, ddof: int = 1, reverse: bool = False) -> Series:
+        """
+        Get an array with the cumulative standard deviation computed at every element.
+
+        Parameters
+        ----------
+        ddof
+            "Delta Degrees of Freedom": the divisor used in the calculation is N - ddof,
+            where N represents the number of elements.
+        reverse
+            reverse the operation.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 3, 2, 6])
+        >>> s.cum_std()
+        shape: (4,)
+        Series: 'a' [f64]
+        [
+                null
+                1.414214
+                1.0
+                2.160247
+        ]
+        """
+
+    def cum_quantile(self, quantile: float, *, reverse: bool = False) -> Series:
+        """
+        Get an array with an approximate cumulative quantile computed at every element.
+
+        The values are counted in a sketch with a relative accuracy of 1%, so the
+        memory doesn't grow with the length of the input. Null and NaN values are
+        skipped.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+        reverse
+            reverse the operation.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 3, 2, 6])
+        >>> s.cum_quantile(0.5)
+        shape: (4,)
+        Series: 'a' [f64]
+        [
+                0.99
+                0.99
+                1.993662
+                1.993662
+        ]
+        """
+
     def slice(self, offset: int, length: int | None = None) -> Series:
         """
         Get a slice of this Series.
@@ -3103,6 +3207,7 @@ class Series:
         nulls_last: bool = False,
         multithreaded: bool = True,
         in_place: bool = False,
+        nan_ordering: NanOrdering = "largest",
     ) -> Self:
         """
         Sort this Series.
@@ -3117,6 +3222,9 @@ class Series:
             Sort using multiple threads.
         in_place
             Sort in-place.
+        nan_ordering : {'largest', 'smallest', 'null'}
+            Where to place `NaN` values: as the largest values, as the smallest values,
+            or as null values.
 
         Examples
         --------
@@ -3141,11 +3249,11 @@ class Series:
         ]
         """
         if in_place:
-            self._s = self._s.sort(descending, nulls_last, multithreaded)
+            self._s = self._s.sort(descending, nulls_last, multithreaded, nan_ordering)
             return self
         else:
             return self._from_pyseries(
-                self._s.sort(descending, nulls_last, multithreaded)
+                self._s.sort(descending, nulls_last, multithreaded, nan_ordering)
             )
 
     def top_k(self, k: int = 5) -> Series:
@@ -4135,6 +4243,48 @@ class Series:
 
         return out
 
+    def is_close(
+        self,
+        other: IntoExpr,
+        *,
+        abs_tol: float = 0.0,
+        rel_tol: float = 1e-09,
+        nans_equal: bool = False,
+    ) -> Series:
+        """
+        Check if the values are close to the values of another Series.
+
+        Two values are close if their difference is at most `abs_tol`, or at most
+        `rel_tol` times the larger of their absolute values. Infinities are only close
+        to the same infinity.
+
+        Parameters
+        ----------
+        other
+            The values to compare with. Accepts expression input. Non-expression
+            inputs are parsed as literals.
+        abs_tol
+            The absolute tolerance, which must be non-negative.
+        rel_tol
+            The relative tolerance, which must be non-negative.
+        nans_equal
+            Consider NaN values to be close to each other. By default, NaN values are
+            not close to anything.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1.0, 1.5, float("nan"), None])
+        >>> s.is_close(pl.Series([1.05, 1.0, float("nan"), 1.0]), abs_tol=0.1)
+        shape: (4,)
+        Series: 'a' [bool]
+        [
+            true
+            false
+            false
+            null
+        ]
+        """
+
     def to_numpy(
         self,
         *,
@@ -4349,12 +4499,18 @@ class Series:
         # tensor.rename(self.name)
         return tensor
 
-    def to_arrow(self) -> pa.Array:
+    def to_arrow(self, *, run_end_encode: bool = False) -> pa.Array:
         """
         Return the underlying Arrow array.
 
         If the Series contains only a single chunk this operation is zero copy.
 
+        Parameters
+        ----------
+        run_end_encode
+            Return a run-end encoded array if the Series has at most half as many runs
+            of equal values as it has values. Nested data types are never encoded.
+
         Examples
         --------
         >>> s = pl.Series("a", [1, 2, 3])
@@ -4367,7 +4523,7 @@ class Series:
           3
         ]
         """
-        return self._s.to_arrow()
+        return self._s.to_arrow(run_end_encode)
 
     def to_pandas(
         self, *, use_pyarrow_extension_array: bool = False, **kwargs: Any
@@ -5222,6 +5378,8 @@ class Series:
             Output datatype.
             If not set, the dtype will be inferred based on the first non-null value
             that is returned by the function.
+            If this is a `Struct`, the function may return a dict or a tuple per
+            element, whose values are assigned to the fields by name or by position.
         skip_nulls
             Nulls will be skipped and not passed to the python function.
             This is faster because python can be skipped and because we call
@@ -5850,6 +6008,60 @@ class Series:
         ]
         """
 
+    @unstable()
+    def rolling_approx_quantile(
+        self,
+        quantile: float,
+        window_size: int = 2,
+        *,
+        relative_accuracy: float = 0.01,
+        min_periods: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Compute an approximate rolling quantile.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Every result is within `relative_accuracy` of the exact quantile of the window
+        (computed with the `'lower'` interpolation method), while the cost per value
+        does not grow with the window size.
+
+        The window at a given row will include the row itself and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+        window_size
+            The length of the window in number of elements.
+        relative_accuracy
+            Maximum relative error of the results, between 0.0 and 1.0 (exclusive).
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1.0, 2.0, 3.0, 4.0, 6.0, 8.0])
+        >>> s.rolling_approx_quantile(quantile=0.5, window_size=3).round(1)
+        shape: (6,)
+        Series: 'a' [f64]
+        [
+                null
+                null
+                2.0
+                3.0
+                4.0
+                6.0
+        ]
+        """
+
     @unstable()
     def rolling_skew(self, window_size: int, *, bias: bool = True) -> Series:
         """
@@ -5945,6 +6157,42 @@ class Series:
         ]
         """
 
+    def find_peaks(
+        self, *, distance: int | None = None, prominence: float | None = None
+    ) -> Self:
+        """
+        Get a boolean mask of the peaks.
+
+        A peak is a value that is higher than its neighbors, like in
+        `scipy.signal.find_peaks`. Unlike :func:`peak_max`, the first and last values
+        and the values next to a null are never peaks, and only the middle value of a
+        flat peak is taken.
+
+        Parameters
+        ----------
+        distance
+            Minimal number of values between neighboring peaks. Of the peaks that are
+            closer together, only the highest is kept.
+        prominence
+            Minimal prominence of the peaks: how far a peak stands out above the
+            higher of the lowest values to its left and right, up to a higher value.
+
+        See Also
+        --------
+        peak_max
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [0, 3, 1, 2, 1, 5, 5, 0])
+        >>> s.find_peaks(distance=3).arg_true()
+        shape: (2,)
+        Series: 'a' [u32]
+        [
+                1
+                5
+        ]
+        """
+
     def peak_min(self) -> Self:
         """
         Get a boolean mask of the local minimum peaks.
@@ -6141,6 +6389,8 @@ class Series:
         *,
         descending: bool = False,
         seed: int | None = None,
+        pct: bool = False,
+        nan_ordering: NanOrdering = "largest",
     ) -> Series:
         """
         Assign ranks to data, dealing with ties appropriately.
@@ -6169,6 +6419,13 @@ class Series:
             Rank in descending order.
         seed
             If `method="random"`, use this as seed.
+        pct
+            Return the ranks as a fraction of the number of non-null values, or of
+            the number of distinct values for `method="dense"`. The result is always
+            of type Float64.
+        nan_ordering : {'largest', 'smallest', 'null'}
+            How to rank `NaN` values: as the largest values, as the smallest values,
+            or as null values, which are not ranked.
 
         Examples
         --------
@@ -6201,6 +6458,50 @@ class Series:
         ]
         """
 
+    def rank_by(
+        self,
+        by: IntoExpr | Iterable[IntoExpr],
+        method: RankMethod = "average",
+        *,
+        descending: bool = False,
+        seed: int | None = None,
+        pct: bool = False,
+    ) -> Series:
+        """
+        Assign ranks within the groups of values that have the same values in `by`.
+
+        This gives the same result as `rank(...).over(by)`, but ranks all groups
+        with a single sort.
+
+        Parameters
+        ----------
+        by
+            Series that define the groups, of the same length as this Series.
+        method : {'average', 'min', 'max', 'dense', 'ordinal', 'random'}
+            The method used to assign ranks to tied elements, see :meth:`rank`.
+        descending
+            Rank in descending order.
+        seed
+            If `method="random"`, use this as seed.
+        pct
+            Return the ranks as a fraction of the number of non-null values in the
+            group, or of the number of distinct values for `method="dense"`.
+
+        Examples
+        --------
+        >>> s = pl.Series("b", [6, 7, 5, 14, 11])
+        >>> s.rank_by(pl.Series([1, 1, 2, 2, 2]))
+        shape: (5,)
+        Series: 'b' [f64]
+        [
+            1.0
+            2.0
+            1.0
+            3.0
+            2.0
+        ]
+        """
+
     def diff(self, n: int = 1, null_behavior: NullBehavior = "ignore") -> Series:
         """
         Calculate the first discrete difference between shifted items.
@@ -6848,6 +7149,124 @@ class Series:
         ]
         """
 
+    def ewm_std_by(
+        self,
+        by: IntoExpr,
+        *,
+        half_life: str | timedelta,
+        bias: bool = False,
+    ) -> Series:
+        """
+        Calculate time-based exponentially weighted moving standard deviation.
+
+        The weights are the same as those of :meth:`ewm_mean_by`: the weight of an
+        observation halves every `half_life` between its time and the current one.
+        Null values are ignored and remain null in the output.
+
+        Parameters
+        ----------
+        by
+            Times to calculate the standard deviation by. Should be ``DateTime``, ``Date``,
+            ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        half_life
+            Unit over which observation decays to half its value, see
+            :meth:`ewm_mean_by` for the accepted strings. Calendar durations such
+            as months are not supported.
+        bias
+            If False, the calculations are corrected for statistical bias, using
+            the effective number of observations given by the weights.
+
+        Returns
+        -------
+        Series
+            Float32 if input is Float32, otherwise Float64.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [0, 1, 2, None, 4],
+        ...         "times": [
+        ...             date(2020, 1, 1),
+        ...             date(2020, 1, 3),
+        ...             date(2020, 1, 10),
+        ...             date(2020, 1, 15),
+        ...             date(2020, 1, 17),
+        ...         ],
+        ...     }
+        ... ).sort("times")
+        >>> df["values"].ewm_std_by(df["times"], half_life="4d")
+        shape: (5,)
+        Series: 'values' [f64]
+        [
+                0.0
+                0.707107
+                1.214582
+                null
+                1.817498
+        ]
+        """
+
+    def ewm_var_by(
+        self,
+        by: IntoExpr,
+        *,
+        half_life: str | timedelta,
+        bias: bool = False,
+    ) -> Series:
+        """
+        Calculate time-based exponentially weighted moving variance.
+
+        The weights are the same as those of :meth:`ewm_mean_by`: the weight of an
+        observation halves every `half_life` between its time and the current one.
+        Null values are ignored and remain null in the output.
+
+        Parameters
+        ----------
+        by
+            Times to calculate the variance by. Should be ``DateTime``, ``Date``,
+            ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        half_life
+            Unit over which observation decays to half its value, see
+            :meth:`ewm_mean_by` for the accepted strings. Calendar durations such
+            as months are not supported.
+        bias
+            If False, the calculations are corrected for statistical bias, using
+            the effective number of observations given by the weights.
+
+        Returns
+        -------
+        Series
+            Float32 if input is Float32, otherwise Float64.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [0, 1, 2, None, 4],
+        ...         "times": [
+        ...             date(2020, 1, 1),
+        ...             date(2020, 1, 3),
+        ...             date(2020, 1, 10),
+        ...             date(2020, 1, 15),
+        ...             date(2020, 1, 17),
+        ...         ],
+        ...     }
+        ... ).sort("times")
+        >>> df["values"].ewm_var_by(df["times"], half_life="4d")
+        shape: (5,)
+        Series: 'values' [f64]
+        [
+                0.0
+                0.5
+                1.475209
+                null
+                3.303299
+        ]
+        """
+
     def ewm_std(
         self,
         *,
@@ -7,16 +7,21 @@ from polars.datatypes.constants import N_INFER_DEFAULT
 from polars.series.utils import expr_dispatch
 
 if TYPE_CHECKING:
+    from typing import Sequence
+
     from polars import Expr, Series
     from polars.polars import PySeries
     from polars.type_aliases import (
         Ambiguous,
+        ChecksumMethod,
         IntoExpr,
         IntoExprColumn,
         PolarsDataType,
         PolarsTemporalType,
+        SplitFill,
         TimeUnit,
         TransferEncoding,
+        UnicodeForm,
     )
 
 
@@ -82,6 +87,7 @@ class StringNameSpace:
         exact: bool = True,
         cache: bool = True,
         ambiguous: Ambiguous | Series = "raise",
+        formats: Sequence[str] | None = None,
     ) -> Series:
         """
         Convert a String column into a Datetime column.
@@ -128,6 +134,12 @@ class StringNameSpace:
             - `'earliest'`: use the earliest datetime
             - `'latest'`: use the latest datetime
             - `'null'`: set to null
+        formats
+            Formats to try for every value, instead of a single `format`. A value is
+            parsed with the first format that matches, starting with the format that
+            parsed the previous value, so the formats should not match the same
+            values. Either all or none of the formats must parse a time zone or
+            offset.
 
         Examples
         --------
@@ -139,6 +151,18 @@ class StringNameSpace:
                 2020-01-01 01:00:00 UTC
                 2020-01-01 02:00:00 UTC
         ]
+
+        Parse values that come in different formats.
+
+        >>> s = pl.Series(["2020-01-01 01:00", "02/01/2020 03:00", "2020-01-03 04:00"])
+        >>> s.str.to_datetime(formats=["%Y-%m-%d %H:%M", "%d/%m/%Y %H:%M"])
+        shape: (3,)
+        Series: '' [datetime[μs]]
+        [
+                2020-01-01 01:00:00
+                2020-01-02 03:00:00
+                2020-01-03 04:00:00
+        ]
         """
 
     def to_time(
@@ -1022,20 +1046,30 @@ class StringNameSpace:
             :class:`String`.
         """
 
-    def splitn(self, by: IntoExpr, n: int) -> Series:
+    def splitn(
+        self, by: IntoExpr, n: int | Expr, *, fill: SplitFill = "null"
+    ) -> Series:
         """
         Split the string by a substring, restricted to returning at most `n` items.
 
         If the number of possible splits is less than `n-1`, the remaining field
-        elements will be null. If the number of possible splits is `n-1` or greater,
-        the last (nth) substring will contain the remainder of the string.
+        elements are filled according to `fill`. If the number of possible splits is
+        `n-1` or greater, the last (nth) substring will contain the remainder of the
+        string.
 
         Parameters
         ----------
         by
             Substring to split by.
         n
-            Max number of items to return.
+            Max number of items to return. Can be a literal expression, as the number
+            of fields of the struct must be known up front.
+        fill : {'null', 'empty', 'error'}
+            How to fill the fields of strings that have fewer than `n` parts.
+
+            - 'null': fill with null.
+            - 'empty': fill with an empty string.
+            - 'error': raise an error.
 
         Examples
         --------
@@ -1526,6 +1560,122 @@ class StringNameSpace:
         ]
         """
 
+    def checksum(
+        self,
+        method: ChecksumMethod = "luhn",
+        *,
+        weights: Sequence[int] | None = None,
+        modulus: int | None = None,
+    ) -> Series:
+        """
+        Validate identifiers such as credit card numbers or IBANs by their check digits.
+
+        Parameters
+        ----------
+        method : {'luhn', 'mod97', 'iban', 'weighted'}
+            The check digit scheme.
+
+            * 'luhn': the Luhn (mod 10) algorithm of credit card numbers and IMEIs.
+            * 'mod97': ISO 7064 MOD 97-10, where letters count as 10 to 35.
+            * 'iban': an IBAN; the first four characters are moved to the end before
+              MOD 97-10 is checked.
+            * 'weighted': the digits multiplied by `weights`, repeated from the left,
+              must sum to a multiple of `modulus`.
+        weights
+            Weights of the digits for the 'weighted' method.
+        modulus
+            Modulus of the weighted sum for the 'weighted' method.
+
+        Notes
+        -----
+        Spaces and hyphens are ignored. Any other character that is not part of the
+        scheme makes the value invalid.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "iban", ["GB82 WEST 1234 5698 7654 32", "GB82 WEST 1234 5698 7654 33"]
+        ... )
+        >>> s.str.checksum("iban")
+        shape: (2,)
+        Series: 'iban' [bool]
+        [
+            true
+            false
+        ]
+        """
+
+    def normalize(self, form: UnicodeForm = "NFC") -> Series:
+        """
+        Normalize the strings to the given Unicode normalization form.
+
+        Strings that render identically can be made up of different code points,
+        e.g. "é" is either a single code point or an "e" followed by a combining
+        accent. Normalizing makes such strings compare equal.
+
+        Parameters
+        ----------
+        form : {'NFC', 'NFD', 'NFKC', 'NFKD'}
+            The Unicode normalization form.
+
+            * 'NFC': canonical decomposition, followed by canonical composition.
+            * 'NFD': canonical decomposition.
+            * 'NFKC': compatibility decomposition, followed by canonical composition.
+            * 'NFKD': compatibility decomposition.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        See Also
+        --------
+        casefold
+
+        Examples
+        --------
+        >>> s = pl.Series("text", ["caf\u00e9", "cafe\u0301"])
+        >>> s.str.len_chars().to_list()
+        [4, 5]
+        >>> s.str.normalize("NFC").str.len_chars().to_list()
+        [4, 4]
+        """
+
+    def casefold(self) -> Series:
+        """
+        Apply full Unicode case folding to the strings.
+
+        Case folding maps all case variants of a string to the same string, also
+        when they differ in length, which makes it suitable for case-insensitive
+        comparisons. Lowercasing isn't: "ß" is lowercase, but folds to "ss".
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        See Also
+        --------
+        to_lowercase
+        normalize
+
+        Examples
+        --------
+        >>> s = pl.Series("text", ["Straße", "STRASSE"])
+        >>> s.str.casefold()
+        shape: (2,)
+        Series: 'text' [str]
+        [
+            "strasse"
+            "strasse"
+        ]
+        """
+
     def reverse(self) -> Series:
         """
         Returns string values in reversed order.
@@ -435,6 +435,89 @@ class SQLContext(Generic[FrameType]):
         res = wrap_ldf(self._ctxt.execute(query))
         return res.collect() if (eager or self._eager_execution) else res
 
+    @overload
+    def execute_many(
+        self: SQLContext[DataFrame], query: str, *, eager: None = ...
+    ) -> DataFrame: ...
+
+    @overload
+    def execute_many(
+        self: SQLContext[DataFrame], query: str, *, eager: Literal[False]
+    ) -> LazyFrame: ...
+
+    @overload
+    def execute_many(
+        self: SQLContext[DataFrame], query: str, *, eager: Literal[True]
+    ) -> DataFrame: ...
+
+    @overload
+    def execute_many(
+        self: SQLContext[LazyFrame], query: str, *, eager: None = ...
+    ) -> LazyFrame: ...
+
+    @overload
+    def execute_many(
+        self: SQLContext[LazyFrame], query: str, *, eager: Literal[False]
+    ) -> LazyFrame: ...
+
+    @overload
+    def execute_many(
+        self: SQLContext[LazyFrame], query: str, *, eager: Literal[True]
+    ) -> DataFrame: ...
+
+    @overload
+    def execute_many(
+        self, query: str, *, eager: bool | None = ...
+    ) -> LazyFrame | DataFrame: ...
+
+    def execute_many(
+        self, query: str, *, eager: bool | None = None
+    ) -> LazyFrame | DataFrame:
+        """
+        Execute a script of `;`-separated SQL statements, returning the last result.
+
+        The statements run in order, so tables created or modified by a statement
+        (e.g. with `CREATE TABLE ... AS SELECT` or `INSERT INTO`) are visible to the
+        statements that follow it.
+
+        Parameters
+        ----------
+        query
+            One or more valid SQL statements, separated by `;`.
+        eager
+            Apply the last statement eagerly, returning `DataFrame` instead of
+            `LazyFrame`. If unset, the value of the init-time "eager" parameter
+            will be used.
+
+        See Also
+        --------
+        execute
+
+        Examples
+        --------
+        >>> ctx = pl.SQLContext(df=pl.DataFrame({"a": [1, 2, 3]}))
+        >>> ctx.execute_many(
+        ...     '''
+        ...     CREATE TABLE t AS SELECT a FROM df WHERE a > 1;
+        ...     INSERT INTO t VALUES (10);
+        ...     SELECT * FROM t;
+        ...     ''',
+        ...     eager=True,
+        ... )
+        shape: (3, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 2   │
+        │ 3   │
+        │ 10  │
+        └─────┘
+        """
+        res = wrap_ldf(self._ctxt.execute_many(query))
+        return res.collect() if (eager or self._eager_execution) else res
+
     def register(self, name: str, frame: CompatibleFrameType | None) -> Self:
         """
         Register a single frame as a table, using the given name.
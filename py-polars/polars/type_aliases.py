@@ -93,16 +93,18 @@ FillNullStrategy: TypeAlias = Literal[
 FloatFmt: TypeAlias = Literal["full", "mixed"]
 IndexOrder: TypeAlias = Literal["c", "fortran"]
 IpcCompression: TypeAlias = Literal["uncompressed", "lz4", "zstd"]
+JsonTemporalFormat: TypeAlias = Literal["display", "iso8601", "epoch"]
 JoinValidation: TypeAlias = Literal["m:m", "m:1", "1:m", "1:1"]
 Label: TypeAlias = Literal["left", "right", "datapoint"]
 NonExistent: TypeAlias = Literal["raise", "null"]
+NanOrdering: TypeAlias = Literal["largest", "smallest", "null"]
 NullBehavior: TypeAlias = Literal["ignore", "drop"]
 ParallelStrategy: TypeAlias = Literal["auto", "columns", "row_groups", "none"]
 ParquetCompression: TypeAlias = Literal[
     "lz4", "uncompressed", "snappy", "gzip", "lzo", "brotli", "zstd"
 ]
 PivotAgg: TypeAlias = Literal[
-    "min", "max", "first", "last", "sum", "mean", "median", "len"
+    "min", "max", "first", "last", "sum", "mean", "median", "len", "list"
 ]
 RankMethod: TypeAlias = Literal["average", "min", "max", "dense", "ordinal", "random"]
 Roll: TypeAlias = Literal["raise", "forward", "backward"]
@@ -118,6 +120,7 @@ SizeUnit: TypeAlias = Literal[
     "gigabytes",
     "terabytes",
 ]
+SplitFill: TypeAlias = Literal["null", "empty", "error"]
 StartBy: TypeAlias = Literal[
     "window",
     "datapoint",
@@ -130,6 +133,7 @@ StartBy: TypeAlias = Literal[
     "sunday",
 ]
 TimeUnit: TypeAlias = Literal["ns", "us", "ms"]
+UnicodeForm: TypeAlias = Literal["NFC", "NFD", "NFKC", "NFKD"]
 UniqueKeepStrategy: TypeAlias = Literal["first", "last", "any", "none"]
 UnstackDirection: TypeAlias = Literal["vertical", "horizontal"]
 MapElementsStrategy: TypeAlias = Literal["thread_local", "threading"]
@@ -141,12 +145,18 @@ InterpolationMethod: TypeAlias = Literal["linear", "nearest"]
 JoinStrategy: TypeAlias = Literal[
     "inner", "left", "full", "semi", "anti", "cross", "outer"
 ]  # JoinType
+JoinExecutionStrategy: TypeAlias = Literal[
+    "auto", "hash", "sort_merge", "broadcast_left", "broadcast_right"
+]  # JoinStrategy
 RollingInterpolationMethod: TypeAlias = Literal[
     "nearest", "higher", "lower", "midpoint", "linear"
 ]  # QuantileInterpolOptions
 ToStructStrategy: TypeAlias = Literal[
     "first_non_null", "max_width"
 ]  # ListToStructWidthStrategy
+ListZipLength: TypeAlias = Literal["strict", "truncate", "pad"]
+LookupMissing: TypeAlias = Literal["null", "raise"]
+ModeKeep: TypeAlias = Literal["all", "first", "last"]
 
 # The following have no equivalent on the Rust side
 ConcatMethod = Literal[
@@ -156,17 +166,20 @@ ConcatMethod = Literal[
     "diagonal_relaxed",
     "horizontal",
     "align",
+    "merge_sorted_by",
 ]
 CorrelationMethod: TypeAlias = Literal["pearson", "spearman"]
 DbReadEngine: TypeAlias = Literal["adbc", "connectorx"]
 DbWriteEngine: TypeAlias = Literal["sqlalchemy", "adbc"]
 DbWriteMode: TypeAlias = Literal["replace", "append", "fail"]
 EpochTimeUnit = Literal["ns", "us", "ms", "s", "d"]
+ExistingDataBehavior: TypeAlias = Literal["overwrite", "append", "error"]
 JaxExportType: TypeAlias = Literal["array", "dict"]
 Orientation: TypeAlias = Literal["col", "row"]
 SearchSortedSide: TypeAlias = Literal["any", "left", "right"]
 TorchExportType: TypeAlias = Literal["tensor", "dataset", "dict"]
 TransferEncoding: TypeAlias = Literal["hex", "base64"]
+ChecksumMethod: TypeAlias = Literal["luhn", "mod97", "iban", "weighted"]
 WindowMappingStrategy: TypeAlias = Literal["group_to_rows", "join", "explode"]
 ExplainFormat: TypeAlias = Literal["plain", "tree"]
 
@@ -12,6 +12,10 @@ use polars::frame::NullStrategy;
 use polars::io::avro::AvroCompression;
 #[cfg(feature = "cloud")]
 use polars::io::cloud::CloudOptions;
+#[cfg(feature = "parquet")]
+use polars::io::partition::ExistingDataBehavior;
+#[cfg(any(feature = "csv", feature = "json"))]
+use polars::io::schema_inference::InferenceStrategy;
 use polars::series::ops::NullBehavior;
 use polars_core::utils::arrow::array::Array;
 use polars_core::utils::arrow::types::NativeType;
@@ -204,6 +208,10 @@ impl ToPyObject for Wrap<DataType> {
                 let class = pl.getattr(intern!(py, "UInt64")).unwrap();
                 class.call0().unwrap().into()
             },
+            DataType::Float16 => {
+                let class = pl.getattr(intern!(py, "Float16")).unwrap();
+                class.call0().unwrap().into()
+            },
             DataType::Float32 => {
                 let class = pl.getattr(intern!(py, "Float32")).unwrap();
                 class.call0().unwrap().into()
@@ -340,6 +348,7 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "UInt16" => DataType::UInt16,
                     "UInt32" => DataType::UInt32,
                     "UInt64" => DataType::UInt64,
+                    "Float16" => DataType::Float16,
                     "Float32" => DataType::Float32,
                     "Float64" => DataType::Float64,
                     "Boolean" => DataType::Boolean,
@@ -374,6 +383,7 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
             "UInt16" => DataType::UInt16,
             "UInt32" => DataType::UInt32,
             "UInt64" => DataType::UInt64,
+            "Float16" => DataType::Float16,
             "Float32" => DataType::Float32,
             "Float64" => DataType::Float64,
             "Boolean" => DataType::Boolean,
@@ -753,6 +763,25 @@ impl<'py> FromPyObject<'py> for Wrap<CsvEncoding> {
     }
 }
 
+#[cfg(any(feature = "csv", feature = "json"))]
+impl<'py> FromPyObject<'py> for Wrap<InferenceStrategy> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "boolean" => InferenceStrategy::Boolean,
+            "integer" => InferenceStrategy::Integer,
+            "float" => InferenceStrategy::Float,
+            "date" => InferenceStrategy::Date,
+            "datetime" => InferenceStrategy::Datetime,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "unknown schema inference strategy: {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "ipc")]
 impl<'py> FromPyObject<'py> for Wrap<Option<IpcCompression>> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
@@ -821,6 +850,53 @@ impl<'py> FromPyObject<'py> for Wrap<ListToStructWidthStrategy> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<ListZipLength> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "strict" => ListZipLength::Strict,
+            "truncate" => ListZipLength::Truncate,
+            "pad" => ListZipLength::PadNull,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`length` must be one of {{'strict', 'truncate', 'pad'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<ModeKeep> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "all" => ModeKeep::All,
+            "first" => ModeKeep::First,
+            "last" => ModeKeep::Last,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`keep` must be one of {{'all', 'first', 'last'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<LookupMissing> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "null" => LookupMissing::Null,
+            "raise" => LookupMissing::Raise,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`on_missing` must be one of {{'null', 'raise'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<NonExistent> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -884,6 +960,23 @@ impl<'py> FromPyObject<'py> for Wrap<ParallelStrategy> {
     }
 }
 
+#[cfg(feature = "parquet")]
+impl<'py> FromPyObject<'py> for Wrap<ExistingDataBehavior> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "overwrite" => ExistingDataBehavior::Overwrite,
+            "append" => ExistingDataBehavior::Append,
+            "error" => ExistingDataBehavior::Error,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`existing_data` must be one of {{'overwrite', 'append', 'error'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<IndexOrder> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -936,6 +1029,22 @@ impl<'py> FromPyObject<'py> for Wrap<RankMethod> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<NanOrdering> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "largest" => NanOrdering::Largest,
+            "smallest" => NanOrdering::Smallest,
+            "null" => NanOrdering::Null,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`nan_ordering` must be one of {{'largest', 'smallest', 'null'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<Roll> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1068,6 +1177,24 @@ impl<'py> FromPyObject<'py> for Wrap<JoinValidation> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<JoinStrategy> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "auto" => JoinStrategy::Auto,
+            "hash" => JoinStrategy::Hash,
+            "sort_merge" => JoinStrategy::SortMerge,
+            "broadcast_left" => JoinStrategy::BroadcastLeft,
+            "broadcast_right" => JoinStrategy::BroadcastRight,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`strategy` must be one of {{'auto', 'hash', 'sort_merge', 'broadcast_left', 'broadcast_right'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "csv")]
 impl<'py> FromPyObject<'py> for Wrap<QuoteStyle> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
@@ -1086,6 +1213,23 @@ impl<'py> FromPyObject<'py> for Wrap<QuoteStyle> {
     }
 }
 
+#[cfg(feature = "json")]
+impl<'py> FromPyObject<'py> for Wrap<TemporalFormat> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "display" => TemporalFormat::Display,
+            "iso8601" => TemporalFormat::Iso8601,
+            "epoch" => TemporalFormat::Epoch,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`temporal_format` must be one of {{'display', 'iso8601', 'epoch'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "cloud")]
 pub(crate) fn parse_cloud_options(uri: &str, kv: Vec<(String, String)>) -> PyResult<CloudOptions> {
     let out = CloudOptions::from_untyped_config(uri, kv).map_err(PyPolarsErr::from)?;
@@ -1110,6 +1254,39 @@ impl<'py> FromPyObject<'py> for Wrap<SetOperation> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<SplitFill> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "null" => SplitFill::Null,
+            "empty" => SplitFill::Empty,
+            "error" => SplitFill::Error,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`fill` must be one of {{'null', 'empty', 'error'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<UnicodeForm> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "NFC" => UnicodeForm::NFC,
+            "NFD" => UnicodeForm::NFD,
+            "NFKC" => UnicodeForm::NFKC,
+            "NFKD" => UnicodeForm::NFKD,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`form` must be one of {{'NFC', 'NFD', 'NFKC', 'NFKD'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 pub(crate) fn parse_fill_null_strategy(
     strategy: &str,
     limit: FillNullLimit,
@@ -52,6 +52,14 @@ impl PyDataFrame {
         let df = interop::arrow::to_rust::to_rust_df(&rb)?;
         Ok(Self::from(df))
     }
+
+    /// Create a DataFrame from an object that exports an Arrow C stream of record batches.
+    /// Every record batch becomes a chunk of the columns.
+    #[staticmethod]
+    pub fn from_arrow_c_stream(data: &Bound<PyAny>) -> PyResult<Self> {
+        let df = interop::arrow::to_rust::stream_to_rust_df(data)?;
+        Ok(Self::from(df))
+    }
 }
 
 fn finish_from_rows(
@@ -62,6 +62,17 @@ impl PyDataFrame {
         })
     }
 
+    /// Iterate over the rows in blocks of `buffer_size` rows. Every block is converted to a
+    /// list of tuples at once.
+    #[cfg(feature = "object")]
+    pub fn row_iterator(&self, buffer_size: usize) -> PyRowIterator {
+        PyRowIterator {
+            df: self.df.clone(),
+            buffer_size: buffer_size.max(1),
+            offset: 0,
+        }
+    }
+
     #[allow(clippy::wrong_self_convention)]
     pub fn to_arrow(&mut self) -> PyResult<Vec<PyObject>> {
         self.df.align_chunks();
@@ -130,3 +141,62 @@ impl PyDataFrame {
         })
     }
 }
+
+#[cfg(feature = "object")]
+#[pyclass]
+pub struct PyRowIterator {
+    df: DataFrame,
+    buffer_size: usize,
+    offset: usize,
+}
+
+#[cfg(feature = "object")]
+#[pymethods]
+impl PyRowIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> Option<PyObject> {
+        if self.offset >= self.df.height() {
+            return None;
+        }
+        let block = self.df.slice(self.offset as i64, self.buffer_size);
+        self.offset += block.height();
+        Some(block_to_row_tuples(py, &block))
+    }
+}
+
+/// Convert the rows of `df` to a list of tuples, converting column by column.
+#[cfg(feature = "object")]
+fn block_to_row_tuples(py: Python, df: &DataFrame) -> PyObject {
+    let mut columns = df
+        .get_columns()
+        .iter()
+        .map(|s| {
+            let values: Vec<PyObject> = match s.dtype() {
+                DataType::Null => (0..s.len()).map(|_| py.None()).collect(),
+                DataType::Object(_, _) => (0..s.len())
+                    .map(|idx| {
+                        let obj: Option<&ObjectValue> = s.get_object(idx).map(|any| any.into());
+                        obj.to_object(py)
+                    })
+                    .collect(),
+                _ => {
+                    let s = s.rechunk();
+                    s.iter().map(|av| Wrap(av).into_py(py)).collect()
+                },
+            };
+            values.into_iter()
+        })
+        .collect::<Vec<_>>();
+
+    PyList::new_bound(
+        py,
+        (0..df.height()).map(|_| {
+            // Every column has a value for every row.
+            PyTuple::new_bound(py, columns.iter_mut().map(|col| col.next().unwrap()))
+        }),
+    )
+    .into_py(py)
+}
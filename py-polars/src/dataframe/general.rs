@@ -5,13 +5,14 @@ use polars::prelude::*;
 use polars_core::frame::*;
 #[cfg(feature = "pivot")]
 use polars_lazy::frame::pivot::{pivot, pivot_stable};
-use pyo3::exceptions::PyIndexError;
+use pyo3::exceptions::{PyIndexError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
 use pyo3::types::{PyBytes, PyList};
 
 use super::*;
-use crate::conversion::Wrap;
+use crate::conversion::{parse_fill_null_strategy, Wrap};
+use crate::expr::ToExprs;
 use crate::map::dataframe::{
     apply_lambda_unknown, apply_lambda_with_bool_out_type, apply_lambda_with_primitive_out_type,
     apply_lambda_with_string_out_type,
@@ -62,6 +63,11 @@ impl PyDataFrame {
         self.df.estimated_size()
     }
 
+    pub fn memory_usage(&self, deep: bool) -> PyResult<Self> {
+        let df = self.df.memory_usage(deep).map_err(PyPolarsErr::from)?;
+        Ok(df.into())
+    }
+
     pub fn dtype_strings(&self) -> Vec<String> {
         self.df
             .get_columns()
@@ -148,6 +154,34 @@ impl PyDataFrame {
         Ok(df.into())
     }
 
+    pub fn sample_stratified(
+        &self,
+        py: Python,
+        by: Vec<String>,
+        n: Option<usize>,
+        frac: Option<f64>,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
+        let size = match (n, frac) {
+            (Some(n), None) => SampleSize::N(n),
+            (None, Some(frac)) => SampleSize::Frac(frac),
+            _ => {
+                return Err(PyValueError::new_err(
+                    "exactly one of `n` and `frac` must be set",
+                ))
+            },
+        };
+        let df = py
+            .allow_threads(|| {
+                self.df
+                    .sample_stratified(by, size, with_replacement, shuffle, seed)
+            })
+            .map_err(PyPolarsErr::from)?;
+        Ok(df.into())
+    }
+
     pub fn rechunk(&self, py: Python) -> Self {
         let mut df = self.df.clone();
         py.allow_threads(|| df.as_single_chunk_par());
@@ -519,6 +553,20 @@ impl PyDataFrame {
         Ok(df.into())
     }
 
+    pub fn compute_patch(&self, other: &PyDataFrame, on: Vec<String>) -> PyResult<Self> {
+        let on = on.iter().map(|x| x as &str).collect::<Vec<_>>();
+        let df = polars_ops::frame::patch::compute_patch(&self.df, &other.df, &on)
+            .map_err(PyPolarsErr::from)?;
+        Ok(df.into())
+    }
+
+    pub fn apply_patch(&self, patch: &PyDataFrame, on: Vec<String>) -> PyResult<Self> {
+        let on = on.iter().map(|x| x as &str).collect::<Vec<_>>();
+        let df = polars_ops::frame::patch::apply_patch(&self.df, &patch.df, &on)
+            .map_err(PyPolarsErr::from)?;
+        Ok(df.into())
+    }
+
     pub fn null_count(&self) -> Self {
         let df = self.df.null_count();
         df.into()
@@ -566,6 +614,13 @@ impl PyDataFrame {
         Ok(hash.into_series().into())
     }
 
+    pub fn content_hash(&self, py: Python) -> PyResult<(u128, u128)> {
+        let hash = py
+            .allow_threads(|| self.df.content_hash())
+            .map_err(PyPolarsErr::from)?;
+        Ok((hash.ordered, hash.order_insensitive))
+    }
+
     #[pyo3(signature = (keep_names_as, column_names))]
     pub fn transpose(
         &mut self,
@@ -602,6 +657,38 @@ impl PyDataFrame {
         Ok(out.into())
     }
 
+    #[pyo3(signature = (index_column, every, aggs, fill_strategy, limit, by, closed, label))]
+    pub fn resample(
+        &self,
+        index_column: &str,
+        every: &str,
+        aggs: Option<Vec<PyExpr>>,
+        fill_strategy: Option<&str>,
+        limit: FillNullLimit,
+        by: Vec<String>,
+        closed: Wrap<ClosedWindow>,
+        label: Wrap<Label>,
+    ) -> PyResult<Self> {
+        let method = match aggs {
+            Some(aggs) => ResampleMethod::Aggregate(aggs.to_exprs()),
+            None => ResampleMethod::Fill(
+                fill_strategy
+                    .map(|strategy| parse_fill_null_strategy(strategy, limit))
+                    .transpose()?,
+            ),
+        };
+        let options = ResampleOptions {
+            group_by: by,
+            closed_window: closed.0,
+            label: label.0,
+        };
+        let out = self
+            .df
+            .resample(index_column, Duration::parse(every), method, options)
+            .map_err(PyPolarsErr::from)?;
+        Ok(out.into())
+    }
+
     pub fn to_struct(&self, name: &str) -> PySeries {
         let s = self.df.clone().into_struct(name);
         s.into_series().into()
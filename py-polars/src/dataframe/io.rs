@@ -1,3 +1,5 @@
+#[cfg(feature = "xlsx")]
+use std::collections::HashMap;
 use std::io::BufWriter;
 use std::num::NonZeroUsize;
 use std::ops::Deref;
@@ -5,6 +7,8 @@ use std::ops::Deref;
 #[cfg(feature = "avro")]
 use polars::io::avro::AvroCompression;
 use polars::io::mmap::{try_create_file, ReaderBytes};
+#[cfg(feature = "parquet")]
+use polars::io::partition::{ExistingDataBehavior, PartitionedWriter};
 use polars::io::RowIndex;
 #[cfg(feature = "parquet")]
 use polars_parquet::arrow::write::StatisticsOptions;
@@ -373,8 +377,13 @@ impl PyDataFrame {
         float_precision: Option<usize>,
         null_value: Option<String>,
         quote_style: Option<Wrap<QuoteStyle>>,
+        column_quote_styles: Vec<(String, Wrap<QuoteStyle>)>,
     ) -> PyResult<()> {
         let null = null_value.unwrap_or_default();
+        let column_quote_styles = column_quote_styles
+            .into_iter()
+            .map(|(name, wrap)| (name, wrap.0))
+            .collect::<Vec<_>>();
 
         if let Ok(s) = py_f.extract::<PyBackedStr>(py) {
             let f = std::fs::File::create(&*s)?;
@@ -393,6 +402,7 @@ impl PyDataFrame {
                     .with_float_precision(float_precision)
                     .with_null_value(null)
                     .with_quote_style(quote_style.map(|wrap| wrap.0).unwrap_or_default())
+                    .with_column_quote_styles(column_quote_styles)
                     .finish(&mut self.df)
                     .map_err(PyPolarsErr::from)
             })?;
@@ -411,6 +421,7 @@ impl PyDataFrame {
                 .with_float_precision(float_precision)
                 .with_null_value(null)
                 .with_quote_style(quote_style.map(|wrap| wrap.0).unwrap_or_default())
+                .with_column_quote_styles(column_quote_styles)
                 .finish(&mut self.df)
                 .map_err(PyPolarsErr::from)?;
         }
@@ -419,7 +430,10 @@ impl PyDataFrame {
     }
 
     #[cfg(feature = "parquet")]
-    #[pyo3(signature = (py_f, compression, compression_level, statistics, row_group_size, data_page_size))]
+    #[pyo3(signature = (
+        py_f, compression, compression_level, statistics, row_group_size, data_page_size,
+        partition_by, max_rows_per_file, existing_data,
+    ))]
     pub fn write_parquet(
         &mut self,
         py: Python,
@@ -429,10 +443,30 @@ impl PyDataFrame {
         statistics: Wrap<StatisticsOptions>,
         row_group_size: Option<usize>,
         data_page_size: Option<usize>,
+        partition_by: Option<Vec<String>>,
+        max_rows_per_file: Option<usize>,
+        existing_data: Wrap<ExistingDataBehavior>,
     ) -> PyResult<()> {
         let compression = parse_parquet_compression(compression, compression_level)?;
 
-        if let Ok(s) = py_f.extract::<PyBackedStr>(py) {
+        if let Some(partition_by) = partition_by {
+            let rootdir = py_f.extract::<PyBackedStr>(py)?;
+            let options = ParquetWriteOptions {
+                compression,
+                statistics: statistics.0,
+                row_group_size,
+                data_pagesize_limit: data_page_size,
+                maintain_order: true,
+            };
+            py.allow_threads(|| {
+                PartitionedWriter::new(options, &*rootdir, partition_by)
+                    .with_max_rows_per_file(max_rows_per_file)
+                    .with_existing_data(existing_data.0)
+                    .with_include_key(false)
+                    .finish(&self.df)
+                    .map_err(PyPolarsErr::from)
+            })?;
+        } else if let Ok(s) = py_f.extract::<PyBackedStr>(py) {
             let f = std::fs::File::create(&*s)?;
             py.allow_threads(|| {
                 ParquetWriter::new(f)
@@ -477,11 +511,20 @@ impl PyDataFrame {
     }
 
     #[cfg(feature = "json")]
-    pub fn write_ndjson(&mut self, py_f: PyObject) -> PyResult<()> {
+    pub fn write_ndjson(
+        &mut self,
+        py_f: PyObject,
+        temporal_format: Wrap<TemporalFormat>,
+        decimal_as_string: bool,
+        omit_nulls: bool,
+    ) -> PyResult<()> {
         let file = BufWriter::new(get_file_like(py_f, true)?);
 
         JsonWriter::new(file)
             .with_json_format(JsonFormat::JsonLines)
+            .with_temporal_format(temporal_format.0)
+            .with_decimal_as_string(decimal_as_string)
+            .with_omit_nulls(omit_nulls)
             .finish(&mut self.df)
             .map_err(PyPolarsErr::from)?;
 
@@ -574,4 +617,53 @@ impl PyDataFrame {
 
         Ok(())
     }
+
+    #[cfg(feature = "xlsx")]
+    #[pyo3(signature = (
+        py_f, worksheet, include_header, autofit, autofilter, freeze_header, float_precision,
+        column_formats
+    ))]
+    pub fn write_excel(
+        &mut self,
+        py: Python,
+        py_f: PyObject,
+        worksheet: Option<String>,
+        include_header: bool,
+        autofit: bool,
+        autofilter: bool,
+        freeze_header: bool,
+        float_precision: Option<usize>,
+        column_formats: Option<HashMap<String, String>>,
+    ) -> PyResult<()> {
+        use polars::io::xlsx::{XlsxSheetOptions, XlsxWriter};
+
+        let mut options = XlsxSheetOptions::default()
+            .with_include_header(include_header)
+            .with_autofit(autofit)
+            .with_autofilter(autofilter)
+            .with_freeze_header(freeze_header)
+            .with_float_precision(float_precision);
+        options.name = worksheet;
+        for (column, num_format) in column_formats.unwrap_or_default() {
+            options = options.with_column_format(&column, &num_format);
+        }
+
+        if let Ok(s) = py_f.extract::<PyBackedStr>(py) {
+            let f = std::fs::File::create(&*s)?;
+            let mut writer = XlsxWriter::new(f);
+            writer
+                .write_sheet(&self.df, &options)
+                .map_err(PyPolarsErr::from)?;
+            writer.close().map_err(PyPolarsErr::from)?;
+        } else {
+            let mut buf = get_file_like(py_f, true)?;
+            let mut writer = XlsxWriter::new(&mut buf);
+            writer
+                .write_sheet(&self.df, &options)
+                .map_err(PyPolarsErr::from)?;
+            writer.close().map_err(PyPolarsErr::from)?;
+        }
+
+        Ok(())
+    }
 }
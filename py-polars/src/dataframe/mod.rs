@@ -3,6 +3,8 @@ mod export;
 mod general;
 mod io;
 
+#[cfg(feature = "object")]
+pub use export::PyRowIterator;
 use polars::prelude::*;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
@@ -34,6 +34,7 @@ pub(crate) enum PyDataType {
     Decimal(Option<usize>, usize),
     Array(usize),
     Enum(Utf8ViewArray),
+    Float16,
 }
 
 impl From<&DataType> for PyDataType {
@@ -48,6 +49,7 @@ impl From<&DataType> for PyDataType {
             DataType::UInt16 => UInt16,
             DataType::UInt32 => UInt32,
             DataType::UInt64 => UInt64,
+            DataType::Float16 => Float16,
             DataType::Float32 => Float32,
             DataType::Float64 => Float64,
             DataType::Decimal(p, s) => Decimal(*p, s.expect("unexpected null decimal scale")),
@@ -90,6 +92,7 @@ impl From<PyDataType> for DataType {
             PyDataType::UInt16 => UInt16,
             PyDataType::UInt32 => UInt32,
             PyDataType::UInt64 => UInt64,
+            PyDataType::Float16 => Float16,
             PyDataType::Float32 => Float32,
             PyDataType::Float64 => Float64,
             PyDataType::Bool => Boolean,
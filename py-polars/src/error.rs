@@ -29,55 +29,65 @@ impl std::convert::From<std::io::Error> for PyPolarsErr {
 
 impl std::convert::From<PyPolarsErr> for PyErr {
     fn from(err: PyPolarsErr) -> PyErr {
-        let default = || PyRuntimeError::new_err(format!("{:?}", &err));
-
         use PyPolarsErr::*;
         match err {
-            Polars(err) => match err {
-                PolarsError::ColumnNotFound(name) => ColumnNotFoundError::new_err(name.to_string()),
-                PolarsError::ComputeError(err) => ComputeError::new_err(err.to_string()),
-                PolarsError::Duplicate(err) => DuplicateError::new_err(err.to_string()),
-                PolarsError::InvalidOperation(err) => {
-                    InvalidOperationError::new_err(err.to_string())
-                },
-                PolarsError::IO { error, msg } => {
-                    let msg = if let Some(msg) = msg {
-                        msg.to_string()
-                    } else {
-                        error.to_string()
-                    };
-                    match error.kind() {
-                        ErrorKind::NotFound => PyFileNotFoundError::new_err(msg),
-                        ErrorKind::PermissionDenied => PyPermissionError::new_err(msg),
-                        ErrorKind::AlreadyExists => PyFileExistsError::new_err(msg),
-                        _ => PyIOError::new_err(msg),
-                    }
-                },
-                PolarsError::NoData(err) => NoDataError::new_err(err.to_string()),
-                PolarsError::OutOfBounds(err) => OutOfBoundsError::new_err(err.to_string()),
-                PolarsError::SQLInterface(name) => SQLInterfaceError::new_err(name.to_string()),
-                PolarsError::SQLSyntax(name) => SQLSyntaxError::new_err(name.to_string()),
-                PolarsError::SchemaFieldNotFound(name) => {
-                    SchemaFieldNotFoundError::new_err(name.to_string())
-                },
-                PolarsError::SchemaMismatch(err) => SchemaError::new_err(err.to_string()),
-                PolarsError::ShapeMismatch(err) => ShapeError::new_err(err.to_string()),
-                PolarsError::StringCacheMismatch(err) => {
-                    StringCacheMismatchError::new_err(err.to_string())
-                },
-                PolarsError::StructFieldNotFound(name) => {
-                    StructFieldNotFoundError::new_err(name.to_string())
-                },
-                PolarsError::Context { .. } => {
-                    let tmp = PyPolarsErr::Polars(err.context_trace());
-                    PyErr::from(tmp)
-                },
+            Polars(err) => {
+                let code = err.code();
+                let context = err
+                    .context_chain()
+                    .into_iter()
+                    .map(|(kind, msg)| (kind.as_str(), msg.to_string()))
+                    .collect::<Vec<_>>();
+                let py_err = polars_err_to_py(err);
+                Python::with_gil(|py| {
+                    let value = py_err.value_bound(py);
+                    // Not all exception types accept new attributes, so ignore failures.
+                    let _ = value.setattr("code", code.as_str());
+                    let _ = value.setattr("context", context);
+                });
+                py_err
             },
-            _ => default(),
+            _ => PyRuntimeError::new_err(format!("{:?}", &err)),
         }
     }
 }
 
+fn polars_err_to_py(err: PolarsError) -> PyErr {
+    match err {
+        PolarsError::ColumnNotFound(name) => ColumnNotFoundError::new_err(name.to_string()),
+        PolarsError::ComputeError(err) => ComputeError::new_err(err.to_string()),
+        PolarsError::Duplicate(err) => DuplicateError::new_err(err.to_string()),
+        PolarsError::InvalidOperation(err) => InvalidOperationError::new_err(err.to_string()),
+        PolarsError::IO { error, msg } => {
+            let msg = if let Some(msg) = msg {
+                msg.to_string()
+            } else {
+                error.to_string()
+            };
+            match error.kind() {
+                ErrorKind::NotFound => PyFileNotFoundError::new_err(msg),
+                ErrorKind::PermissionDenied => PyPermissionError::new_err(msg),
+                ErrorKind::AlreadyExists => PyFileExistsError::new_err(msg),
+                _ => PyIOError::new_err(msg),
+            }
+        },
+        PolarsError::NoData(err) => NoDataError::new_err(err.to_string()),
+        PolarsError::OutOfBounds(err) => OutOfBoundsError::new_err(err.to_string()),
+        PolarsError::SQLInterface(name) => SQLInterfaceError::new_err(name.to_string()),
+        PolarsError::SQLSyntax(name) => SQLSyntaxError::new_err(name.to_string()),
+        PolarsError::SchemaFieldNotFound(name) => {
+            SchemaFieldNotFoundError::new_err(name.to_string())
+        },
+        PolarsError::SchemaMismatch(err) => SchemaError::new_err(err.to_string()),
+        PolarsError::ShapeMismatch(err) => ShapeError::new_err(err.to_string()),
+        PolarsError::StringCacheMismatch(err) => StringCacheMismatchError::new_err(err.to_string()),
+        PolarsError::StructFieldNotFound(name) => {
+            StructFieldNotFoundError::new_err(name.to_string())
+        },
+        PolarsError::Context { .. } => polars_err_to_py(err.context_trace()),
+    }
+}
+
 impl Debug for PyPolarsErr {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         use PyPolarsErr::*;
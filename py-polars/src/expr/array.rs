@@ -62,11 +62,11 @@ impl PyExpr {
         self.inner
             .clone()
             .arr()
-            .sort(SortOptions {
-                descending,
-                nulls_last,
-                ..Default::default()
-            })
+            .sort(
+                SortOptions::default()
+                    .with_order_descending(descending)
+                    .with_nulls_last(nulls_last),
+            )
             .into()
     }
 
@@ -126,4 +126,8 @@ impl PyExpr {
     fn arr_shift(&self, n: PyExpr) -> Self {
         self.inner.clone().arr().shift(n.inner).into()
     }
+
+    fn arr_slice(&self, offset: i64, length: usize) -> Self {
+        self.inner.clone().arr().slice(offset, length).into()
+    }
 }
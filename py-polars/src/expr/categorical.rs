@@ -7,4 +7,32 @@ impl PyExpr {
     fn cat_get_categories(&self) -> Self {
         self.inner.clone().cat().get_categories().into()
     }
+
+    fn cat_add_categories(&self, categories: Vec<String>) -> Self {
+        self.inner.clone().cat().add_categories(categories).into()
+    }
+
+    fn cat_reorder_categories(&self, categories: Vec<String>) -> Self {
+        self.inner
+            .clone()
+            .cat()
+            .reorder_categories(categories)
+            .into()
+    }
+
+    fn cat_contains(&self, pat: &str, literal: bool, strict: bool) -> Self {
+        self.inner
+            .clone()
+            .cat()
+            .contains(pat, literal, strict)
+            .into()
+    }
+
+    fn cat_starts_with(&self, prefix: &str) -> Self {
+        self.inner.clone().cat().starts_with(prefix).into()
+    }
+
+    fn cat_ends_with(&self, suffix: &str) -> Self {
+        self.inner.clone().cat().ends_with(suffix).into()
+    }
 }
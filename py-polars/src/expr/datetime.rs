@@ -73,6 +73,14 @@ impl PyExpr {
         self.inner.clone().dt().truncate(every.inner).into()
     }
 
+    fn dt_truncate_localized(&self, every: Self, ambiguous: Self, non_existent: Self) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .truncate_localized(every.inner, ambiguous.inner, non_existent.inner)
+            .into()
+    }
+
     fn dt_month_start(&self) -> Self {
         self.inner.clone().dt().month_start().into()
     }
@@ -94,6 +102,14 @@ impl PyExpr {
         self.inner.clone().dt().round(every.inner).into()
     }
 
+    fn dt_round_localized(&self, every: Self, ambiguous: Self, non_existent: Self) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .round_localized(every.inner, ambiguous.inner, non_existent.inner)
+            .into()
+    }
+
     fn dt_combine(&self, time: Self, time_unit: Wrap<TimeUnit>) -> Self {
         self.inner
             .clone()
@@ -128,6 +144,9 @@ impl PyExpr {
     fn dt_weekday(&self) -> Self {
         self.inner.clone().dt().weekday().into()
     }
+    fn dt_iso_year_week_day(&self) -> Self {
+        self.inner.clone().dt().iso_year_week_day().into()
+    }
     fn dt_day(&self) -> Self {
         self.inner.clone().dt().day().into()
     }
@@ -185,4 +204,7 @@ impl PyExpr {
     fn dt_total_nanoseconds(&self) -> Self {
         self.inner.clone().dt().total_nanoseconds().into()
     }
+    fn dt_to_iso8601_duration(&self) -> Self {
+        self.inner.clone().dt().to_iso8601_duration().into()
+    }
 }
@@ -7,13 +7,15 @@ use polars::series::ops::NullBehavior;
 use polars_core::chunked_array::cast::CastOptions;
 use polars_core::series::IsSorted;
 use pyo3::class::basic::CompareOp;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedBytes;
 use pyo3::types::PyBytes;
 
 use crate::conversion::{parse_fill_null_strategy, vec_extract_wrapped, Wrap};
+use crate::dataframe::PyDataFrame;
 use crate::error::PyPolarsErr;
-use crate::map::lazy::map_single;
+use crate::map::lazy::{map_single, scan};
 use crate::PyExpr;
 
 #[pymethods]
@@ -169,9 +171,15 @@ impl PyExpr {
     fn first(&self) -> Self {
         self.inner.clone().first().into()
     }
+    fn first_non_null(&self) -> Self {
+        self.inner.clone().first_non_null().into()
+    }
     fn last(&self) -> Self {
         self.inner.clone().last().into()
     }
+    fn last_non_null(&self) -> Self {
+        self.inner.clone().last_non_null().into()
+    }
     fn implode(&self) -> Self {
         self.inner.clone().implode().into()
     }
@@ -182,6 +190,13 @@ impl PyExpr {
             .into()
     }
 
+    fn approx_quantile(&self, quantile: Self, relative_accuracy: f64) -> Self {
+        self.inner
+            .clone()
+            .approx_quantile(quantile.inner, relative_accuracy)
+            .into()
+    }
+
     #[pyo3(signature = (breaks, labels, left_closed, include_breaks))]
     #[cfg(feature = "cutqcut")]
     fn cut(
@@ -277,27 +292,37 @@ impl PyExpr {
         let expr = self.inner.clone().cast_with_options(dt, options);
         expr.into()
     }
-    fn sort_with(&self, descending: bool, nulls_last: bool) -> Self {
+    fn sort_with(
+        &self,
+        descending: bool,
+        nulls_last: bool,
+        nan_ordering: Wrap<NanOrdering>,
+    ) -> Self {
         self.inner
             .clone()
-            .sort(SortOptions {
-                descending,
-                nulls_last,
-                multithreaded: true,
-                maintain_order: false,
-            })
+            .sort(
+                SortOptions::default()
+                    .with_order_descending(descending)
+                    .with_nulls_last(nulls_last)
+                    .with_nan_ordering(nan_ordering.0),
+            )
             .into()
     }
 
-    fn arg_sort(&self, descending: bool, nulls_last: bool) -> Self {
+    fn arg_sort(
+        &self,
+        descending: bool,
+        nulls_last: bool,
+        nan_ordering: Wrap<NanOrdering>,
+    ) -> Self {
         self.inner
             .clone()
-            .arg_sort(SortOptions {
-                descending,
-                nulls_last,
-                multithreaded: true,
-                maintain_order: false,
-            })
+            .arg_sort(
+                SortOptions::default()
+                    .with_order_descending(descending)
+                    .with_nulls_last(nulls_last)
+                    .with_nan_ordering(nan_ordering.0),
+            )
             .into()
     }
 
@@ -333,6 +358,12 @@ impl PyExpr {
         self.inner.clone().peak_max().into()
     }
 
+    #[cfg(feature = "peaks")]
+    #[pyo3(signature = (distance, prominence))]
+    fn find_peaks(&self, distance: Option<usize>, prominence: Option<f64>) -> Self {
+        self.inner.clone().find_peaks(distance, prominence).into()
+    }
+
     fn arg_max(&self) -> Self {
         self.inner.clone().arg_max().into()
     }
@@ -363,18 +394,19 @@ impl PyExpr {
         nulls_last: Vec<bool>,
         multithreaded: bool,
         maintain_order: bool,
+        nan_ordering: Wrap<NanOrdering>,
     ) -> Self {
         let by = by.into_iter().map(|e| e.inner).collect::<Vec<_>>();
         self.inner
             .clone()
             .sort_by(
                 by,
-                SortMultipleOptions {
-                    descending,
-                    nulls_last,
-                    multithreaded,
-                    maintain_order,
-                },
+                SortMultipleOptions::default()
+                    .with_order_descending_multi(descending)
+                    .with_nulls_last_multi(nulls_last)
+                    .with_multithreaded(multithreaded)
+                    .with_maintain_order(maintain_order)
+                    .with_nan_ordering(nan_ordering.0),
             )
             .into()
     }
@@ -444,10 +476,37 @@ impl PyExpr {
             .into()
     }
 
+    fn is_close(&self, other: Self, abs_tol: f64, rel_tol: f64, nans_equal: bool) -> Self {
+        self.inner
+            .clone()
+            .is_close(other.inner, abs_tol, rel_tol, nans_equal)
+            .into()
+    }
+
     fn approx_n_unique(&self) -> Self {
         self.inner.clone().approx_n_unique().into()
     }
 
+    fn bitwise_not(&self) -> Self {
+        self.inner.clone().bitwise_not().into()
+    }
+
+    fn bitwise_shift_left(&self, n: u32) -> Self {
+        self.inner.clone().bitwise_shift_left(n).into()
+    }
+
+    fn bitwise_shift_right(&self, n: u32) -> Self {
+        self.inner.clone().bitwise_shift_right(n).into()
+    }
+
+    fn bitwise_and_agg(&self) -> Self {
+        self.inner.clone().bitwise_and_agg().into()
+    }
+
+    fn bitwise_or_agg(&self) -> Self {
+        self.inner.clone().bitwise_or_agg().into()
+    }
+
     fn is_first_distinct(&self) -> Self {
         self.inner.clone().is_first_distinct().into()
     }
@@ -607,7 +666,7 @@ impl PyExpr {
         self.inner.clone().is_duplicated().into()
     }
 
-    #[pyo3(signature = (partition_by, order_by, order_by_descending, order_by_nulls_last, mapping_strategy))]
+    #[pyo3(signature = (partition_by, order_by, order_by_descending, order_by_nulls_last, mapping_strategy, frame=None))]
     fn over(
         &self,
         partition_by: Vec<Self>,
@@ -615,7 +674,8 @@ impl PyExpr {
         order_by_descending: bool,
         order_by_nulls_last: bool,
         mapping_strategy: Wrap<WindowMapping>,
-    ) -> Self {
+        frame: Option<(Option<usize>, Option<usize>)>,
+    ) -> PyResult<Self> {
         let partition_by = partition_by
             .into_iter()
             .map(|e| e.inner)
@@ -624,19 +684,27 @@ impl PyExpr {
         let order_by = order_by.map(|order_by| {
             (
                 order_by.into_iter().map(|e| e.inner).collect::<Vec<Expr>>(),
-                SortOptions {
-                    descending: order_by_descending,
-                    nulls_last: order_by_nulls_last,
-                    maintain_order: false,
-                    ..Default::default()
-                },
+                SortOptions::default()
+                    .with_order_descending(order_by_descending)
+                    .with_nulls_last(order_by_nulls_last),
             )
         });
 
-        self.inner
-            .clone()
-            .over_with_options(partition_by, order_by, mapping_strategy.0)
-            .into()
+        let expr = self.inner.clone();
+        let out = match (frame, order_by) {
+            (None, order_by) => expr.over_with_options(partition_by, order_by, mapping_strategy.0),
+            (Some((preceding, following)), Some(order_by)) => {
+                let frame = WindowFrame {
+                    preceding,
+                    following,
+                };
+                expr.over_frame(partition_by, order_by, frame)
+            },
+            (Some(_), None) => {
+                return Err(PyValueError::new_err("a window frame requires `order_by`"))
+            },
+        };
+        Ok(out.into())
     }
 
     fn rolling(
@@ -645,12 +713,14 @@ impl PyExpr {
         period: &str,
         offset: &str,
         closed: Wrap<ClosedWindow>,
+        descending: bool,
     ) -> Self {
         let options = RollingGroupOptions {
             index_column: index_column.into(),
             period: Duration::parse(period),
             offset: Duration::parse(offset),
             closed_window: closed.0,
+            descending,
         };
 
         self.inner.clone().rolling(options).into()
@@ -705,6 +775,15 @@ impl PyExpr {
     fn cum_count(&self, reverse: bool) -> Self {
         self.inner.clone().cum_count(reverse).into()
     }
+    fn cum_mean(&self, reverse: bool) -> Self {
+        self.inner.clone().cum_mean(reverse).into()
+    }
+    fn cum_std(&self, reverse: bool, ddof: u8) -> Self {
+        self.inner.clone().cum_std(reverse, ddof).into()
+    }
+    fn cum_quantile(&self, quantile: f64, reverse: bool) -> Self {
+        self.inner.clone().cum_quantile(quantile, reverse).into()
+    }
 
     fn cumulative_eval(&self, expr: Self, min_periods: usize, parallel: bool) -> Self {
         self.inner
@@ -740,6 +819,14 @@ impl PyExpr {
         )
     }
 
+    fn scan(&self, acc_init: Self, lambda: PyObject) -> Self {
+        scan(self, acc_init, lambda, false)
+    }
+
+    fn cum_reduce(&self, acc_init: Self, lambda: PyObject) -> Self {
+        scan(self, acc_init, lambda, true)
+    }
+
     fn dot(&self, other: Self) -> Self {
         self.inner.clone().dot(other.inner).into()
     }
@@ -750,6 +837,9 @@ impl PyExpr {
     fn mode(&self) -> Self {
         self.inner.clone().mode().into()
     }
+    fn agg_mode(&self, keep: Wrap<ModeKeep>) -> Self {
+        self.inner.clone().agg_mode(keep.0).into()
+    }
     fn exclude(&self, columns: Vec<String>) -> Self {
         self.inner.clone().exclude(columns).into()
     }
@@ -772,14 +862,41 @@ impl PyExpr {
         self.inner.clone().upper_bound().into()
     }
 
-    fn rank(&self, method: Wrap<RankMethod>, descending: bool, seed: Option<u64>) -> Self {
+    fn rank(
+        &self,
+        method: Wrap<RankMethod>,
+        descending: bool,
+        seed: Option<u64>,
+        pct: bool,
+        nan_ordering: Wrap<NanOrdering>,
+    ) -> Self {
         let options = RankOptions {
             method: method.0,
             descending,
+            nan_ordering: nan_ordering.0,
+            pct,
         };
         self.inner.clone().rank(options, seed).into()
     }
 
+    fn rank_by(
+        &self,
+        by: Vec<Self>,
+        method: Wrap<RankMethod>,
+        descending: bool,
+        seed: Option<u64>,
+        pct: bool,
+    ) -> Self {
+        let options = RankOptions {
+            method: method.0,
+            descending,
+            nan_ordering: Default::default(),
+            pct,
+        };
+        let by = by.into_iter().map(|e| e.inner).collect::<Vec<_>>();
+        self.inner.clone().rank_by(by, options, seed).into()
+    }
+
     fn diff(&self, n: i64, null_behavior: Wrap<NullBehavior>) -> Self {
         self.inner.clone().diff(n, null_behavior.0).into()
     }
@@ -848,6 +965,20 @@ impl PyExpr {
             .ewm_mean_by(times.inner, half_life)
             .into()
     }
+    fn ewm_std_by(&self, times: PyExpr, half_life: &str, bias: bool) -> Self {
+        let half_life = Duration::parse(half_life);
+        self.inner
+            .clone()
+            .ewm_std_by(times.inner, half_life, bias)
+            .into()
+    }
+    fn ewm_var_by(&self, times: PyExpr, half_life: &str, bias: bool) -> Self {
+        let half_life = Duration::parse(half_life);
+        self.inner
+            .clone()
+            .ewm_var_by(times.inner, half_life, bias)
+            .into()
+    }
 
     fn ewm_std(
         &self,
@@ -942,6 +1073,17 @@ impl PyExpr {
             .into()
     }
 
+    fn lookup(
+        &self,
+        other: &PyDataFrame,
+        key: &str,
+        value: &str,
+        on_missing: Wrap<LookupMissing>,
+    ) -> PyResult<Self> {
+        let table = LookupTable::from_frame(&other.df, key, value).map_err(PyPolarsErr::from)?;
+        Ok(self.inner.clone().lookup(table, on_missing.0).into())
+    }
+
     #[cfg(feature = "hist")]
     #[pyo3(signature = (bins, bin_count, include_category, include_breakpoint))]
     fn hist(
@@ -6,6 +6,7 @@ use pyo3::prelude::*;
 use smartstring::alias::String as SmartString;
 
 use crate::conversion::Wrap;
+use crate::expr::ToExprs;
 use crate::PyExpr;
 
 #[pymethods]
@@ -253,4 +254,32 @@ impl PyExpr {
         }
         .into()
     }
+
+    fn list_zip_with(&self, others: Vec<PyExpr>, length: Wrap<ListZipLength>) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .zip_with(others.to_exprs(), length.0)
+            .into()
+    }
+
+    fn list_enumerate(&self) -> Self {
+        self.inner.clone().list().enumerate().into()
+    }
+
+    fn list_rolling_sum(&self, window_size: usize, min_periods: usize) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .rolling_sum(window_size, min_periods)
+            .into()
+    }
+
+    fn list_rolling_mean(&self, window_size: usize, min_periods: usize) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .rolling_mean(window_size, min_periods)
+            .into()
+    }
 }
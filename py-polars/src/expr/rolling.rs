@@ -299,6 +299,30 @@ impl PyExpr {
             .into()
     }
 
+    #[pyo3(signature = (quantile, relative_accuracy, window_size, min_periods, center))]
+    fn rolling_approx_quantile(
+        &self,
+        quantile: f64,
+        relative_accuracy: f64,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            weights: None,
+            min_periods,
+            center,
+            fn_params: None,
+        };
+
+        self.inner
+            .clone()
+            .rolling_approx_quantile(quantile, relative_accuracy, options)
+            .into()
+    }
+
     #[pyo3(signature = (by, quantile, interpolation, window_size, min_periods, closed))]
     fn rolling_quantile_by(
         &self,
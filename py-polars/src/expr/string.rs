@@ -1,4 +1,5 @@
 use polars::prelude::*;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 use crate::conversion::Wrap;
@@ -19,6 +20,7 @@ impl PyExpr {
     fn str_to_date(&self, format: Option<String>, strict: bool, exact: bool, cache: bool) -> Self {
         let options = StrptimeOptions {
             format,
+            formats: None,
             strict,
             exact,
             cache,
@@ -26,7 +28,7 @@ impl PyExpr {
         self.inner.clone().str().to_date(options).into()
     }
 
-    #[pyo3(signature = (format, time_unit, time_zone, strict, exact, cache, ambiguous))]
+    #[pyo3(signature = (format, time_unit, time_zone, strict, exact, cache, ambiguous, formats=None))]
     fn str_to_datetime(
         &self,
         format: Option<String>,
@@ -36,9 +38,11 @@ impl PyExpr {
         exact: bool,
         cache: bool,
         ambiguous: Self,
+        formats: Option<Vec<String>>,
     ) -> Self {
         let options = StrptimeOptions {
             format,
+            formats,
             strict,
             exact,
             cache,
@@ -59,6 +63,7 @@ impl PyExpr {
     fn str_to_time(&self, format: Option<String>, strict: bool, cache: bool) -> Self {
         let options = StrptimeOptions {
             format,
+            formats: None,
             strict,
             cache,
             exact: true,
@@ -149,6 +154,47 @@ impl PyExpr {
             .into()
     }
 
+    #[pyo3(signature = (method, weights, modulus))]
+    fn str_checksum(
+        &self,
+        method: &str,
+        weights: Option<Vec<u32>>,
+        modulus: Option<u32>,
+    ) -> PyResult<Self> {
+        let algorithm = match (method, weights, modulus) {
+            ("luhn", None, None) => ChecksumAlgorithm::Luhn,
+            ("mod97", None, None) => ChecksumAlgorithm::Mod97,
+            ("iban", None, None) => ChecksumAlgorithm::Iban,
+            ("weighted", Some(weights), Some(modulus)) => {
+                ChecksumAlgorithm::Weighted { weights, modulus }
+            },
+            ("weighted", _, _) => {
+                return Err(PyValueError::new_err(
+                    "`weights` and `modulus` are required for the 'weighted' method",
+                ))
+            },
+            ("luhn" | "mod97" | "iban", _, _) => {
+                return Err(PyValueError::new_err(
+                    "`weights` and `modulus` can only be set for the 'weighted' method",
+                ))
+            },
+            (v, _, _) => {
+                return Err(PyValueError::new_err(format!(
+                    "`method` must be one of {{'luhn', 'mod97', 'iban', 'weighted'}}, got {v}",
+                )))
+            },
+        };
+        Ok(self.inner.clone().str().checksum(algorithm).into())
+    }
+
+    fn str_normalize(&self, form: Wrap<UnicodeForm>) -> Self {
+        self.inner.clone().str().normalize(form.0).into()
+    }
+
+    fn str_casefold(&self) -> Self {
+        self.inner.clone().str().casefold().into()
+    }
+
     fn str_reverse(&self) -> Self {
         self.inner.clone().str().reverse().into()
     }
@@ -288,8 +334,19 @@ impl PyExpr {
             .into()
     }
 
-    fn str_splitn(&self, by: Self, n: usize) -> Self {
-        self.inner.clone().str().splitn(by.inner, n).into()
+    fn str_splitn(&self, by: Self, n: Self, fill: Wrap<SplitFill>) -> PyResult<Self> {
+        // The number of fields of the struct has to be known up front.
+        let n = match &n.inner {
+            Expr::Literal(lv) => lv.to_any_value().and_then(|av| av.extract::<usize>()),
+            _ => None,
+        }
+        .ok_or_else(|| PyValueError::new_err("`n` must be a non-negative integer literal"))?;
+        Ok(self
+            .inner
+            .clone()
+            .str()
+            .splitn_with_fill(by.inner, n, fill.0)
+            .into())
     }
 
     fn str_to_decimal(&self, infer_len: usize) -> Self {
@@ -17,6 +17,10 @@ impl PyExpr {
         self.inner.clone().struct_().field_by_names(&names).into()
     }
 
+    fn struct_exclude_fields(&self, names: Vec<String>) -> Self {
+        self.inner.clone().struct_().exclude_fields(&names).into()
+    }
+
     fn struct_rename_fields(&self, names: Vec<String>) -> Self {
         self.inner.clone().struct_().rename_fields(names).into()
     }
@@ -7,7 +7,8 @@ use crate::error::PyPolarsErr;
 use crate::{PyDataFrame, PySeries};
 
 #[pyfunction]
-pub fn concat_df(dfs: &Bound<'_, PyAny>, py: Python) -> PyResult<PyDataFrame> {
+#[pyo3(signature = (dfs, to_supertypes=false))]
+pub fn concat_df(dfs: &Bound<'_, PyAny>, to_supertypes: bool, py: Python) -> PyResult<PyDataFrame> {
     use polars_core::error::PolarsResult;
     use polars_core::utils::rayon::prelude::*;
 
@@ -17,11 +18,20 @@ pub fn concat_df(dfs: &Bound<'_, PyAny>, py: Python) -> PyResult<PyDataFrame> {
     let first_rdf = get_df(&first)?;
     let identity_df = first_rdf.clear();
 
-    let mut rdfs: Vec<PolarsResult<DataFrame>> = vec![Ok(first_rdf)];
+    let mut rdfs: Vec<DataFrame> = vec![first_rdf];
 
     for item in iter {
         let rdf = get_df(&item?)?;
-        rdfs.push(Ok(rdf));
+        rdfs.push(rdf);
+    }
+
+    if to_supertypes {
+        let df = py
+            .allow_threads(|| {
+                polars_core::utils::concat_df_relaxed(&rdfs, DtypeMergePolicy::Supertype)
+            })
+            .map_err(PyPolarsErr::from)?;
+        return Ok(df.into());
     }
 
     let identity = || Ok(identity_df.clone());
@@ -32,7 +42,7 @@ pub fn concat_df(dfs: &Bound<'_, PyAny>, py: Python) -> PyResult<PyDataFrame> {
                 rdfs.into_par_iter()
                     .fold(identity, |acc: PolarsResult<DataFrame>, df| {
                         let mut acc = acc?;
-                        acc.vstack_mut(&df?)?;
+                        acc.vstack_mut(&df)?;
                         Ok(acc)
                     })
                     .reduce(identity, |acc, df| {
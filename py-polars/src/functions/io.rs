@@ -1,3 +1,7 @@
+#[cfg(any(feature = "csv", feature = "json"))]
+use polars::io::schema_inference::{
+    infer_sample_schema as infer_sample_schema_impl, InferenceStrategy,
+};
 use polars_core::datatypes::create_enum_data_type;
 use polars_core::export::arrow::array::Utf8ViewArray;
 use polars_core::export::arrow::datatypes::Field;
@@ -8,7 +12,7 @@ use pyo3::types::PyDict;
 use crate::conversion::Wrap;
 use crate::file::{get_either_file, EitherRustPythonFile};
 use crate::prelude::ArrowDataType;
-use crate::PyPolarsErr;
+use crate::{PyDataFrame, PyPolarsErr};
 
 #[cfg(feature = "ipc")]
 #[pyfunction]
@@ -42,6 +46,32 @@ pub fn read_parquet_schema(py: Python, py_f: PyObject) -> PyResult<PyObject> {
     Ok(dict.to_object(py))
 }
 
+#[cfg(any(feature = "csv", feature = "json"))]
+#[pyfunction]
+pub fn infer_sample_schema(
+    py: Python,
+    df: PyDataFrame,
+    strategies: Vec<Wrap<InferenceStrategy>>,
+) -> PyResult<PyObject> {
+    let strategies = strategies.into_iter().map(|s| s.0).collect::<Vec<_>>();
+    let columns = py
+        .allow_threads(|| infer_sample_schema_impl(&df.df, &strategies))
+        .map_err(PyPolarsErr::from)?;
+
+    let columns = columns
+        .into_iter()
+        .map(|column| {
+            let candidates = column
+                .candidates
+                .into_iter()
+                .map(|c| (Wrap(c.dtype), c.confidence, c.conflicts))
+                .collect::<Vec<_>>();
+            (column.name, candidates)
+        })
+        .collect::<Vec<_>>();
+    Ok(columns.to_object(py))
+}
+
 #[cfg(any(feature = "ipc", feature = "parquet"))]
 fn fields_to_pydict(fields: &Vec<Field>, dict: &Bound<'_, PyDict>, py: Python) -> PyResult<()> {
     for field in fields {
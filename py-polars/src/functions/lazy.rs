@@ -1,7 +1,7 @@
 use polars::lazy::dsl;
 use polars::prelude::*;
 use polars_plan::prelude::UnionArgs;
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::{PyBool, PyBytes, PyFloat, PyInt, PyString};
 
@@ -69,12 +69,11 @@ pub fn arg_sort_by(
     let by = by.into_iter().map(|e| e.inner).collect::<Vec<Expr>>();
     dsl::arg_sort_by(
         by,
-        SortMultipleOptions {
-            descending,
-            nulls_last,
-            multithreaded,
-            maintain_order,
-        },
+        SortMultipleOptions::default()
+            .with_order_descending_multi(descending)
+            .with_nulls_last_multi(nulls_last)
+            .with_multithreaded(multithreaded)
+            .with_maintain_order(maintain_order),
     )
     .into()
 }
@@ -164,11 +163,13 @@ pub fn cols(names: Vec<String>) -> PyExpr {
 }
 
 #[pyfunction]
+#[pyo3(signature = (seq, rechunk, parallel, to_supertypes, merge_sorted_by=None))]
 pub fn concat_lf(
     seq: &Bound<'_, PyAny>,
     rechunk: bool,
     parallel: bool,
     to_supertypes: bool,
+    merge_sorted_by: Option<&str>,
 ) -> PyResult<PyLazyFrame> {
     let len = seq.len()?;
     let mut lfs = Vec::with_capacity(len);
@@ -179,15 +180,19 @@ pub fn concat_lf(
         lfs.push(lf);
     }
 
-    let lf = dsl::concat(
-        lfs,
-        UnionArgs {
-            rechunk,
-            parallel,
-            to_supertypes,
-            ..Default::default()
-        },
-    )
+    let args = UnionArgs {
+        rechunk,
+        parallel,
+        to_supertypes,
+        ..Default::default()
+    };
+    let lf = match merge_sorted_by {
+        #[cfg(feature = "merge_sorted")]
+        Some(by) => dsl::concat_merge_sorted(lfs, by, args),
+        #[cfg(not(feature = "merge_sorted"))]
+        Some(_) => panic!("activate feature 'merge_sorted'"),
+        None => dsl::concat(lfs, args),
+    }
     .map_err(PyPolarsErr::from)?;
     Ok(lf.into())
 }
@@ -274,6 +279,16 @@ pub fn datetime(
     dsl::datetime(args).into()
 }
 
+#[pyfunction]
+pub fn date_from_isoweek(iso_year: PyExpr, iso_week: PyExpr, weekday: PyExpr) -> PyExpr {
+    dsl::date_from_isoweek(iso_year.inner, iso_week.inner, weekday.inner).into()
+}
+
+#[pyfunction]
+pub fn duration_from_string(s: PyExpr, time_unit: Wrap<TimeUnit>, strict: bool) -> PyExpr {
+    dsl::duration_from_string(s.inner, time_unit.0, strict).into()
+}
+
 #[pyfunction]
 pub fn concat_lf_diagonal(
     lfs: &Bound<'_, PyAny>,
@@ -460,6 +475,22 @@ pub fn map_mul(
     map::lazy::map_mul(&pyexpr, py, lambda, output_type, map_groups, returns_scalar)
 }
 
+#[pyfunction]
+#[pyo3(signature = (pyexpr, lambda, output_type))]
+pub fn map_groups_flat(
+    py: Python,
+    pyexpr: Vec<PyExpr>,
+    lambda: PyObject,
+    output_type: Option<Wrap<DataType>>,
+) -> PyResult<PyExpr> {
+    if pyexpr.is_empty() {
+        return Err(PyValueError::new_err(
+            "`map_groups_flat` needs at least one input expression",
+        ));
+    }
+    Ok(map::lazy::map_groups_flat(&pyexpr, py, lambda, output_type))
+}
+
 #[pyfunction]
 pub fn pearson_corr(a: PyExpr, b: PyExpr, ddof: u8) -> PyExpr {
     dsl::pearson_corr(a.inner, b.inner, ddof).into()
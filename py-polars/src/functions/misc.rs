@@ -1,9 +1,19 @@
 use std::sync::Arc;
 
+#[cfg(feature = "timezones")]
+use polars_core::chunked_array::temporal::parse_time_zone;
+#[cfg(feature = "timezones")]
+use polars_core::export::chrono::{
+    Duration, FixedOffset, LocalResult, NaiveDateTime, Offset, TimeZone,
+};
+#[cfg(feature = "timezones")]
+use polars_core::export::chrono_tz::{OffsetComponents, Tz};
 use polars_plan::prelude::*;
 use pyo3::prelude::*;
 
 use crate::conversion::Wrap;
+#[cfg(feature = "timezones")]
+use crate::error::PyPolarsErr;
 use crate::expr::ToExprs;
 use crate::prelude::DataType;
 use crate::PyExpr;
@@ -14,6 +24,65 @@ pub fn dtype_str_repr(dtype: Wrap<DataType>) -> PyResult<String> {
     Ok(dtype.to_string())
 }
 
+/// The UTC offset of `time_zone` at the instant `utc`, looked up in the time zone database
+/// that is compiled into polars. This doesn't need the time zone data of the system.
+#[cfg(feature = "timezones")]
+#[pyfunction]
+pub fn utc_offset(time_zone: &str, utc: NaiveDateTime) -> PyResult<FixedOffset> {
+    let tz = parse_time_zone(time_zone).map_err(PyPolarsErr::from)?;
+    Ok(tz.offset_from_utc_datetime(&utc).fix())
+}
+
+/// The UTC offset of `time_zone` at the wall time `local`, looked up in the time zone database
+/// that is compiled into polars. As in PEP 495, `fold` selects the later of two offsets when the
+/// wall time is ambiguous or skipped.
+#[cfg(feature = "timezones")]
+#[pyfunction]
+pub fn local_utc_offset(
+    time_zone: &str,
+    local: NaiveDateTime,
+    fold: bool,
+) -> PyResult<FixedOffset> {
+    Ok(local_offset(time_zone, local, fold)?.fix())
+}
+
+/// The daylight saving time adjustment of `time_zone` at the wall time `local`, which is zero
+/// outside of daylight saving time. `fold` is handled as in [`local_utc_offset`].
+#[cfg(feature = "timezones")]
+#[pyfunction]
+pub fn local_dst_offset(time_zone: &str, local: NaiveDateTime, fold: bool) -> PyResult<Duration> {
+    Ok(local_offset(time_zone, local, fold)?.dst_offset())
+}
+
+#[cfg(feature = "timezones")]
+fn local_offset(
+    time_zone: &str,
+    local: NaiveDateTime,
+    fold: bool,
+) -> PyResult<<Tz as TimeZone>::Offset> {
+    let tz = parse_time_zone(time_zone).map_err(PyPolarsErr::from)?;
+    let offset = match tz.offset_from_local_datetime(&local) {
+        LocalResult::Single(offset) => offset,
+        LocalResult::Ambiguous(earliest, latest) => {
+            if fold {
+                latest
+            } else {
+                earliest
+            }
+        },
+        // A skipped wall time takes the offset from before or after the transition.
+        LocalResult::None => {
+            let shift = if fold {
+                Duration::days(1)
+            } else {
+                -Duration::days(1)
+            };
+            tz.offset_from_utc_datetime(&(local + shift))
+        },
+    };
+    Ok(offset)
+}
+
 #[cfg(feature = "ffi_plugin")]
 #[pyfunction]
 pub fn register_plugin_function(
@@ -1,9 +1,14 @@
+use std::ffi::CString;
+
+use arrow::array::PrimitiveArray;
+use arrow::datatypes::{ArrowDataType, IntegerType};
 use arrow::ffi;
 use arrow::record_batch::RecordBatch;
-use polars::prelude::{ArrayRef, ArrowField};
+use polars::prelude::*;
 use polars_core::utils::arrow;
 use pyo3::ffi::Py_uintptr_t;
 use pyo3::prelude::*;
+use pyo3::types::PyCapsule;
 
 /// Arrow array to Python.
 pub(crate) fn to_py_array(
@@ -17,7 +22,32 @@ pub(crate) fn to_py_array(
         true,
     )));
     let array = Box::new(ffi::export_array_to_c(array));
+    import_py_array(array, schema, py, pyarrow)
+}
+
+/// Run-end encoded Arrow array of `run_ends` and `values` to Python.
+pub(crate) fn to_py_array_run_end_encoded(
+    run_ends: PrimitiveArray<i32>,
+    values: ArrayRef,
+    py: Python,
+    pyarrow: &Bound<PyModule>,
+) -> PyResult<PyObject> {
+    let values_field = ArrowField::new("values", values.data_type().clone(), true);
+    let schema = Box::new(ffi::export_field_to_c(&ArrowField::new(
+        "",
+        ArrowDataType::RunEndEncoded(IntegerType::Int32, Box::new(values_field)),
+        true,
+    )));
+    let array = Box::new(ffi::export_run_end_encoded_array_to_c(run_ends, values));
+    import_py_array(array, schema, py, pyarrow)
+}
 
+fn import_py_array(
+    array: Box<ffi::ArrowArray>,
+    schema: Box<ffi::ArrowSchema>,
+    py: Python,
+    pyarrow: &Bound<PyModule>,
+) -> PyResult<PyObject> {
     let schema_ptr: *const ffi::ArrowSchema = &*schema;
     let array_ptr: *const ffi::ArrowArray = &*array;
 
@@ -29,6 +59,48 @@ pub(crate) fn to_py_array(
     Ok(array.to_object(py))
 }
 
+/// The run ends and values of the run-end encoding of `series`, if it has at most half as many
+/// runs of equal values as it has values. Floats are only equal if their bits are equal, so
+/// that e.g. `-0.0` and `0.0` are kept apart.
+pub(crate) fn run_end_encode(series: &Series) -> Option<(PrimitiveArray<i32>, ArrayRef)> {
+    let len = series.len();
+    if len < 2 || i32::try_from(len).is_err() || series.dtype().is_nested() {
+        return None;
+    }
+    let bits = match series.dtype() {
+        DataType::Float32 => series.f32().unwrap().reinterpret_unsigned(),
+        DataType::Float64 => series.f64().unwrap().reinterpret_unsigned(),
+        _ => series.clone(),
+    };
+    let is_run_start = bits
+        .slice(1, len - 1)
+        .not_equal_missing(&bits.slice(0, len - 1))
+        .ok()?;
+    let n_runs = is_run_start.sum().unwrap_or(0) as usize + 1;
+    if 2 * n_runs > len {
+        return None;
+    }
+
+    let mut starts = Vec::with_capacity(n_runs);
+    starts.push(0);
+    starts.extend(
+        is_run_start
+            .into_no_null_iter()
+            .enumerate()
+            .filter_map(|(i, is_start)| is_start.then_some(i as IdxSize + 1)),
+    );
+    let run_ends = starts[1..]
+        .iter()
+        .map(|start| *start as i32)
+        .chain([len as i32])
+        .collect();
+    let values = series.take_slice(&starts).ok()?.rechunk();
+    Some((
+        PrimitiveArray::from_vec(run_ends),
+        values.to_arrow(0, false),
+    ))
+}
+
 /// RecordBatch to Python.
 pub(crate) fn to_py_rb(
     rb: &RecordBatch,
@@ -49,3 +121,26 @@ pub(crate) fn to_py_rb(
 
     Ok(record.to_object(py))
 }
+
+// The stream owns the chunks of a `Series`, which can be sent between threads.
+#[repr(transparent)]
+struct SeriesStream(ffi::ArrowArrayStream);
+
+unsafe impl Send for SeriesStream {}
+
+/// Export the chunks of a [`Series`] as an `ArrowArrayStream` in a PyCapsule, following the
+/// Arrow PyCapsule interface. The chunks are not concatenated.
+pub(crate) fn series_to_stream<'py>(
+    series: &Series,
+    py: Python<'py>,
+) -> PyResult<Bound<'py, PyCapsule>> {
+    let field = series.field().to_arrow(false);
+    let series = series.clone();
+    let iter =
+        Box::new((0..series.n_chunks()).map(move |i| PolarsResult::Ok(series.to_arrow(i, false))));
+    let stream = SeriesStream(ffi::export_iterator(iter, field));
+    // The consumer moves the stream out of the capsule and marks it as released. Otherwise it
+    // is released when the capsule is dropped.
+    let name = CString::new("arrow_array_stream").unwrap();
+    PyCapsule::new_bound(py, stream, Some(name))
+}
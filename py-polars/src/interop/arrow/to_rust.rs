@@ -1,11 +1,12 @@
 use polars_core::export::rayon::prelude::*;
 use polars_core::prelude::*;
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::utils::arrow::array::StructArray;
 use polars_core::utils::arrow::ffi;
 use polars_core::POOL;
 use pyo3::ffi::Py_uintptr_t;
 use pyo3::prelude::*;
-use pyo3::types::PyList;
+use pyo3::types::{PyCapsule, PyList};
 
 use crate::error::PyPolarsErr;
 
@@ -46,6 +47,96 @@ pub fn array_to_rust(obj: &Bound<PyAny>) -> PyResult<ArrayRef> {
     }
 }
 
+/// Import the chunks of an object that implements the Arrow PyCapsule interface by consuming the
+/// `ArrowArrayStream` that it exports through `__arrow_c_stream__`.
+pub fn stream_to_rust(obj: &Bound<PyAny>) -> PyResult<(ArrowField, Vec<ArrayRef>)> {
+    let capsule = obj.call_method0("__arrow_c_stream__")?;
+    let capsule = capsule.downcast::<PyCapsule>()?;
+    let name = capsule.name()?.map(|name| name.to_bytes());
+    if name != Some(b"arrow_array_stream".as_slice()) {
+        return Err(PyPolarsErr::Other(
+            "expected a PyCapsule with the name 'arrow_array_stream'".into(),
+        )
+        .into());
+    }
+
+    // Move the stream out of the capsule and mark the original as released, such that the
+    // capsule doesn't release it again when it is dropped.
+    let stream = unsafe {
+        std::ptr::replace(
+            capsule.pointer() as *mut ffi::ArrowArrayStream,
+            ffi::ArrowArrayStream::empty(),
+        )
+    };
+
+    unsafe {
+        let mut reader =
+            ffi::ArrowArrayStreamReader::try_new(Box::new(stream)).map_err(PyPolarsErr::from)?;
+        let field = reader.field().clone();
+        let mut chunks = vec![];
+        while let Some(array) = reader.next() {
+            chunks.push(array.map_err(PyPolarsErr::from)?);
+        }
+        Ok((field, chunks))
+    }
+}
+
+/// Create a [`Series`] from the chunks of an Arrow stream without concatenating them.
+pub fn stream_chunks_to_series(
+    name: &str,
+    field: &ArrowField,
+    chunks: Vec<ArrayRef>,
+) -> PyResult<Series> {
+    if chunks.is_empty() {
+        let dtype = DataType::from_arrow(&field.data_type, true);
+        return Ok(Series::new_empty(name, &dtype));
+    }
+    // SAFETY: every chunk of a stream is imported with the data type of the stream.
+    let s = unsafe {
+        Series::_try_from_arrow_unchecked_with_md(
+            name,
+            chunks,
+            &field.data_type,
+            Some(&field.metadata),
+        )
+    };
+    Ok(s.map_err(PyPolarsErr::from)?)
+}
+
+/// Import a [`DataFrame`] from a stream of struct arrays, such as the stream of record batches
+/// of a table. Every record batch becomes a chunk of the columns.
+pub fn stream_to_rust_df(obj: &Bound<PyAny>) -> PyResult<DataFrame> {
+    let (field, chunks) = stream_to_rust(obj)?;
+    let ArrowDataType::Struct(fields) = field.data_type() else {
+        return Err(PyPolarsErr::Other(format!(
+            "expected a stream of struct arrays to create a DataFrame, got {:?}",
+            field.data_type()
+        ))
+        .into());
+    };
+
+    let mut columns = vec![Vec::with_capacity(chunks.len()); fields.len()];
+    for chunk in chunks {
+        let chunk = chunk.as_any().downcast_ref::<StructArray>().unwrap();
+        if chunk.null_count() > 0 {
+            return Err(PyPolarsErr::Other(
+                "cannot create a DataFrame from a stream of struct arrays with nulls".into(),
+            )
+            .into());
+        }
+        for (column, values) in columns.iter_mut().zip(chunk.values()) {
+            column.push(values.clone());
+        }
+    }
+
+    let columns = fields
+        .iter()
+        .zip(columns)
+        .map(|(field, chunks)| stream_chunks_to_series(&field.name, field, chunks))
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(DataFrame::new(columns).map_err(PyPolarsErr::from)?)
+}
+
 pub fn to_rust_df(rb: &[Bound<PyAny>]) -> PyResult<DataFrame> {
     let schema = rb
         .first()
@@ -1,3 +1,5 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use super::*;
 
 #[pymethods]
@@ -34,3 +36,21 @@ impl PyInProcessQuery {
         Ok(out.into())
     }
 }
+
+/// Cancels the query of a `collect_with_callback` call.
+#[pyclass]
+#[derive(Clone)]
+pub struct PyCancelToken {
+    pub token: Arc<AtomicBool>,
+}
+
+#[pymethods]
+impl PyCancelToken {
+    pub fn cancel(&self) {
+        self.token.store(true, Ordering::Relaxed)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.token.load(Ordering::Relaxed)
+    }
+}
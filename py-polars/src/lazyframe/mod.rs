@@ -5,8 +5,9 @@ use std::collections::HashMap;
 use std::io::BufWriter;
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
 
-pub use exitable::PyInProcessQuery;
+pub use exitable::{PyCancelToken, PyInProcessQuery};
 use polars::io::cloud::CloudOptions;
 use polars::io::{HiveOptions, RowIndex};
 use polars::time::*;
@@ -99,7 +100,7 @@ impl PyLazyFrame {
     #[staticmethod]
     #[cfg(feature = "json")]
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (path, paths, infer_schema_length, schema, batch_size, n_rows, low_memory, rechunk, row_index, ignore_errors))]
+    #[pyo3(signature = (path, paths, infer_schema_length, schema, batch_size, n_rows, low_memory, rechunk, row_index, ignore_errors, hive_partitioning, hive_schema))]
     fn new_from_ndjson(
         path: Option<PathBuf>,
         paths: Vec<PathBuf>,
@@ -111,11 +112,17 @@ impl PyLazyFrame {
         rechunk: bool,
         row_index: Option<(String, IdxSize)>,
         ignore_errors: bool,
+        hive_partitioning: bool,
+        hive_schema: Option<Wrap<Schema>>,
     ) -> PyResult<Self> {
         let row_index = row_index.map(|(name, offset)| RowIndex {
             name: Arc::from(name.as_str()),
             offset,
         });
+        let hive_options = HiveOptions {
+            enabled: hive_partitioning,
+            schema: hive_schema.map(|s| Arc::new(s.0)),
+        };
 
         let r = if let Some(path) = &path {
             LazyJsonLineReader::new(path)
@@ -132,6 +139,7 @@ impl PyLazyFrame {
             .with_schema(schema.map(|schema| Arc::new(schema.0)))
             .with_row_index(row_index)
             .with_ignore_errors(ignore_errors)
+            .with_hive_options(hive_options)
             .finish()
             .map_err(PyPolarsErr::from)?;
 
@@ -144,7 +152,7 @@ impl PyLazyFrame {
         low_memory, comment_prefix, quote_char, null_values, missing_utf8_is_empty_string,
         infer_schema_length, with_schema_modify, rechunk, skip_rows_after_header,
         encoding, row_index, try_parse_dates, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, glob, schema,
-        cloud_options, retries, file_cache_ttl
+        cloud_options, retries, file_cache_ttl, hive_partitioning, hive_schema
     )
     )]
     fn new_from_csv(
@@ -178,6 +186,8 @@ impl PyLazyFrame {
         cloud_options: Option<Vec<(String, String)>>,
         retries: usize,
         file_cache_ttl: Option<u64>,
+        hive_partitioning: bool,
+        hive_schema: Option<Wrap<Schema>>,
     ) -> PyResult<Self> {
         let null_values = null_values.map(|w| w.0);
         let quote_char = quote_char.map(|s| s.as_bytes()[0]);
@@ -188,6 +198,11 @@ impl PyLazyFrame {
             offset,
         });
 
+        let hive_options = HiveOptions {
+            enabled: hive_partitioning,
+            schema: hive_schema.map(|s| Arc::new(s.0)),
+        };
+
         let overwrite_dtype = overwrite_dtype.map(|overwrite_dtype| {
             overwrite_dtype
                 .into_iter()
@@ -255,7 +270,8 @@ impl PyLazyFrame {
             .with_decimal_comma(decimal_comma)
             .with_glob(glob)
             .with_raise_if_empty(raise_if_empty)
-            .with_cloud_options(cloud_options);
+            .with_cloud_options(cloud_options)
+            .with_hive_options(hive_options);
 
         if let Some(lambda) = with_schema_modify {
             let f = |schema: Schema| {
@@ -286,7 +302,8 @@ impl PyLazyFrame {
     #[cfg(feature = "parquet")]
     #[staticmethod]
     #[pyo3(signature = (path, paths, n_rows, cache, parallel, rechunk, row_index,
-        low_memory, cloud_options, use_statistics, hive_partitioning, hive_schema, retries, glob)
+        low_memory, cloud_options, use_statistics, hive_partitioning, hive_schema, retries, glob,
+        columns_matching, dtypes)
     )]
     fn new_from_parquet(
         path: Option<PathBuf>,
@@ -303,6 +320,8 @@ impl PyLazyFrame {
         hive_schema: Option<Wrap<Schema>>,
         retries: usize,
         glob: bool,
+        columns_matching: Option<String>,
+        dtypes: Option<Vec<Wrap<DataType>>>,
     ) -> PyResult<Self> {
         let parallel = parallel.0;
         let hive_schema = hive_schema.map(|s| Arc::new(s.0));
@@ -348,6 +367,8 @@ impl PyLazyFrame {
             use_statistics,
             hive_options,
             glob,
+            columns_matching,
+            dtypes: dtypes.map(|dtypes| dtypes.into_iter().map(|dt| dt.0).collect()),
         };
 
         let lf = if path.is_some() {
@@ -373,6 +394,8 @@ impl PyLazyFrame {
         cloud_options: Option<Vec<(String, String)>>,
         retries: usize,
         file_cache_ttl: Option<u64>,
+        hive_partitioning: bool,
+        hive_schema: Option<Wrap<Schema>>,
     ) -> PyResult<Self> {
         let row_index = row_index.map(|(name, offset)| RowIndex {
             name: Arc::from(name.as_str()),
@@ -448,6 +471,16 @@ impl PyLazyFrame {
         Ok(LazyFrame::scan_from_python_function(schema, scan_fn, pyarrow).into())
     }
 
+    #[staticmethod]
+    fn scan_from_io_plugin(
+        schema: Vec<(PyBackedStr, Wrap<DataType>)>,
+        scan_fn: PyObject,
+    ) -> PyResult<Self> {
+        let schema =
+            Schema::from_iter(schema.into_iter().map(|(name, dt)| Field::new(&name, dt.0)));
+        Ok(LazyFrame::scan_from_io_plugin(schema, scan_fn).into())
+    }
+
     fn describe_plan(&self) -> PyResult<String> {
         self.ldf
             .describe_plan()
@@ -527,16 +560,17 @@ impl PyLazyFrame {
         nulls_last: bool,
         maintain_order: bool,
         multithreaded: bool,
+        nan_ordering: Wrap<NanOrdering>,
     ) -> Self {
         let ldf = self.ldf.clone();
         ldf.sort(
             [by_column],
-            SortMultipleOptions {
-                descending: vec![descending],
-                nulls_last: vec![nulls_last],
-                multithreaded,
-                maintain_order,
-            },
+            SortMultipleOptions::default()
+                .with_order_descending(descending)
+                .with_nulls_last(nulls_last)
+                .with_multithreaded(multithreaded)
+                .with_maintain_order(maintain_order)
+                .with_nan_ordering(nan_ordering.0),
         )
         .into()
     }
@@ -548,17 +582,18 @@ impl PyLazyFrame {
         nulls_last: Vec<bool>,
         maintain_order: bool,
         multithreaded: bool,
+        nan_ordering: Wrap<NanOrdering>,
     ) -> Self {
         let ldf = self.ldf.clone();
         let exprs = by.to_exprs();
         ldf.sort_by_exprs(
             exprs,
-            SortMultipleOptions {
-                descending,
-                nulls_last,
-                maintain_order,
-                multithreaded,
-            },
+            SortMultipleOptions::default()
+                .with_order_descending_multi(descending)
+                .with_nulls_last_multi(nulls_last)
+                .with_maintain_order(maintain_order)
+                .with_multithreaded(multithreaded)
+                .with_nan_ordering(nan_ordering.0),
         )
         .into()
     }
@@ -590,12 +625,27 @@ impl PyLazyFrame {
         ldf.cache().into()
     }
 
-    fn profile(&self, py: Python) -> PyResult<(PyDataFrame, PyDataFrame)> {
+    #[pyo3(signature = (max_threads, memory_soft_limit))]
+    fn with_resources(&self, max_threads: Option<usize>, memory_soft_limit: Option<usize>) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.with_resources(QueryResources {
+            max_threads,
+            memory_soft_limit,
+        })
+        .into()
+    }
+
+    fn profile(&self, py: Python, expressions: bool) -> PyResult<(PyDataFrame, PyDataFrame)> {
         // if we don't allow threads and we have udfs trying to acquire the gil from different
         // threads we deadlock.
         let (df, time_df) = py.allow_threads(|| {
             let ldf = self.ldf.clone();
-            ldf.profile().map_err(PyPolarsErr::from)
+            if expressions {
+                ldf.profile_expressions()
+            } else {
+                ldf.profile()
+            }
+            .map_err(PyPolarsErr::from)
         })?;
         Ok((df.into(), time_df.into()))
     }
@@ -641,12 +691,14 @@ impl PyLazyFrame {
     }
 
     #[pyo3(signature = (lambda,))]
-    fn collect_with_callback(&self, lambda: PyObject) {
+    fn collect_with_callback(&self, lambda: PyObject) -> PyCancelToken {
         let ldf = self.ldf.clone();
+        let token = Arc::new(AtomicBool::new(false));
+        let cancel_token = token.clone();
 
         polars_core::POOL.spawn(move || {
             let result = ldf
-                .collect()
+                .collect_cancellable(cancel_token)
                 .map(PyDataFrame::new)
                 .map_err(PyPolarsErr::from);
 
@@ -662,10 +714,11 @@ impl PyLazyFrame {
                 },
             });
         });
+        PyCancelToken { token }
     }
 
     #[cfg(all(feature = "streaming", feature = "parquet"))]
-    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_pagesize_limit, maintain_order))]
+    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_pagesize_limit, maintain_order, partition_by, checkpoint))]
     fn sink_parquet(
         &self,
         py: Python,
@@ -676,6 +729,8 @@ impl PyLazyFrame {
         row_group_size: Option<usize>,
         data_pagesize_limit: Option<usize>,
         maintain_order: bool,
+        partition_by: Option<Vec<String>>,
+        checkpoint: bool,
     ) -> PyResult<()> {
         let compression = parse_parquet_compression(compression, compression_level)?;
 
@@ -691,36 +746,52 @@ impl PyLazyFrame {
         // threads we deadlock.
         py.allow_threads(|| {
             let ldf = self.ldf.clone();
-            ldf.sink_parquet(path, options).map_err(PyPolarsErr::from)
+            match partition_by {
+                Some(by) => sink_partitioned(ldf, options, path, by, checkpoint),
+                None => ldf.sink_parquet(path, options),
+            }
+            .map_err(PyPolarsErr::from)
         })?;
         Ok(())
     }
 
     #[cfg(all(feature = "streaming", feature = "ipc"))]
-    #[pyo3(signature = (path, compression, maintain_order))]
+    #[pyo3(signature = (path, compression, maintain_order, partition_by, checkpoint))]
     fn sink_ipc(
         &self,
         py: Python,
         path: PathBuf,
         compression: Option<Wrap<IpcCompression>>,
         maintain_order: bool,
+        partition_by: Option<Vec<String>>,
+        checkpoint: bool,
     ) -> PyResult<()> {
-        let options = IpcWriterOptions {
-            compression: compression.map(|c| c.0),
-            maintain_order,
-        };
+        let compression = compression.map(|c| c.0);
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
         // threads we deadlock.
         py.allow_threads(|| {
             let ldf = self.ldf.clone();
-            ldf.sink_ipc(path, options).map_err(PyPolarsErr::from)
+            match partition_by {
+                Some(by) => {
+                    let options = IpcWriterOption::new().with_compression(compression);
+                    sink_partitioned(ldf, options, path, by, checkpoint)
+                },
+                None => {
+                    let options = IpcWriterOptions {
+                        compression,
+                        maintain_order,
+                    };
+                    ldf.sink_ipc(path, options)
+                },
+            }
+            .map_err(PyPolarsErr::from)
         })?;
         Ok(())
     }
 
     #[cfg(all(feature = "streaming", feature = "csv"))]
-    #[pyo3(signature = (path, include_bom, include_header, separator, line_terminator, quote_char, batch_size, datetime_format, date_format, time_format, float_precision, null_value, quote_style, maintain_order))]
+    #[pyo3(signature = (path, include_bom, include_header, separator, line_terminator, quote_char, batch_size, datetime_format, date_format, time_format, float_precision, null_value, quote_style, column_quote_styles, maintain_order))]
     fn sink_csv(
         &self,
         py: Python,
@@ -737,9 +808,14 @@ impl PyLazyFrame {
         float_precision: Option<usize>,
         null_value: Option<String>,
         quote_style: Option<Wrap<QuoteStyle>>,
+        column_quote_styles: Vec<(String, Wrap<QuoteStyle>)>,
         maintain_order: bool,
     ) -> PyResult<()> {
         let quote_style = quote_style.map_or(QuoteStyle::default(), |wrap| wrap.0);
+        let column_quote_styles = column_quote_styles
+            .into_iter()
+            .map(|(name, wrap)| (name, wrap.0))
+            .collect();
         let null_value = null_value.unwrap_or(SerializeOptions::default().null);
 
         let serialize_options = SerializeOptions {
@@ -752,6 +828,7 @@ impl PyLazyFrame {
             null: null_value,
             line_terminator,
             quote_style,
+            column_quote_styles,
         };
 
         let options = CsvWriterOptions {
@@ -773,9 +850,22 @@ impl PyLazyFrame {
 
     #[allow(clippy::too_many_arguments)]
     #[cfg(all(feature = "streaming", feature = "json"))]
-    #[pyo3(signature = (path, maintain_order))]
-    fn sink_json(&self, py: Python, path: PathBuf, maintain_order: bool) -> PyResult<()> {
-        let options = JsonWriterOptions { maintain_order };
+    #[pyo3(signature = (path, maintain_order, temporal_format, decimal_as_string, omit_nulls))]
+    fn sink_json(
+        &self,
+        py: Python,
+        path: PathBuf,
+        maintain_order: bool,
+        temporal_format: Wrap<TemporalFormat>,
+        decimal_as_string: bool,
+        omit_nulls: bool,
+    ) -> PyResult<()> {
+        let options = JsonWriterOptions {
+            maintain_order,
+            temporal_format: temporal_format.0,
+            decimal_as_string,
+            omit_nulls,
+        };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
         // threads we deadlock.
@@ -786,6 +876,120 @@ impl PyLazyFrame {
         Ok(())
     }
 
+    #[cfg(feature = "streaming")]
+    #[pyo3(signature = (function, chunk_size))]
+    fn sink_batches(
+        &self,
+        py: Python,
+        function: PyObject,
+        chunk_size: Option<usize>,
+    ) -> PyResult<()> {
+        let callback = move |df: DataFrame| {
+            Python::with_gil(|py| {
+                let pypolars = PyModule::import_bound(py, "polars").unwrap();
+                let pydf = PyDataFrame::new(df);
+                let python_df_wrapper =
+                    pypolars.getattr("wrap_df").unwrap().call1((pydf,)).unwrap();
+                function.call1(py, (python_df_wrapper,)).map_err(|e| {
+                    PolarsError::ComputeError(
+                        format!("User provided python function failed: {e}").into(),
+                    )
+                })?;
+                Ok(())
+            })
+        };
+
+        // the callback acquires the gil from the thread that receives the batches, so we
+        // must release it.
+        py.allow_threads(|| {
+            let ldf = self.ldf.clone();
+            ldf.sink_batches(callback, chunk_size)
+                .map_err(PyPolarsErr::from)
+        })?;
+        Ok(())
+    }
+
+    #[cfg(feature = "snapshot")]
+    fn snapshot(&self, py: Python, path: PathBuf) -> PyResult<PyDataFrame> {
+        let df = py.allow_threads(|| {
+            let ldf = self.ldf.clone();
+            ldf.snapshot(path).map_err(PyPolarsErr::from)
+        })?;
+        Ok(df.into())
+    }
+
+    #[cfg(feature = "snapshot")]
+    fn refresh(&self, py: Python, path: PathBuf) -> PyResult<PyDataFrame> {
+        let df = py.allow_threads(|| {
+            let ldf = self.ldf.clone();
+            ldf.refresh(path).map_err(PyPolarsErr::from)
+        })?;
+        Ok(df.into())
+    }
+
+    #[cfg(feature = "streaming")]
+    fn content_hash(&self, py: Python) -> PyResult<(u128, u128)> {
+        let hash = py.allow_threads(|| {
+            let ldf = self.ldf.clone();
+            ldf.content_hash().map_err(PyPolarsErr::from)
+        })?;
+        Ok((hash.ordered, hash.order_insensitive))
+    }
+
+    #[cfg(feature = "streaming")]
+    #[pyo3(signature = (sinks, maintain_order))]
+    fn sink_multiple(
+        &self,
+        py: Python,
+        sinks: Vec<(PathBuf, PyBackedStr)>,
+        maintain_order: bool,
+    ) -> PyResult<()> {
+        let sinks = sinks
+            .into_iter()
+            .map(|(path, format)| {
+                let file_type = match &*format {
+                    #[cfg(feature = "parquet")]
+                    "parquet" => FileType::Parquet(ParquetWriteOptions {
+                        maintain_order,
+                        ..Default::default()
+                    }),
+                    #[cfg(feature = "ipc")]
+                    "ipc" => FileType::Ipc(IpcWriterOptions {
+                        maintain_order,
+                        ..Default::default()
+                    }),
+                    #[cfg(feature = "csv")]
+                    "csv" => FileType::Csv(CsvWriterOptions {
+                        maintain_order,
+                        ..Default::default()
+                    }),
+                    #[cfg(feature = "json")]
+                    "json" => FileType::Json(JsonWriterOptions {
+                        maintain_order,
+                        ..Default::default()
+                    }),
+                    v => {
+                        return Err(PyValueError::new_err(format!(
+                            "cannot sink to a file of format {v:?}"
+                        )))
+                    },
+                };
+                Ok(SinkType::File {
+                    path: Arc::new(path),
+                    file_type,
+                })
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        // if we don't allow threads and we have udfs trying to acquire the gil from different
+        // threads we deadlock.
+        py.allow_threads(|| {
+            let ldf = self.ldf.clone();
+            ldf.sink_multiple(sinks).map_err(PyPolarsErr::from)
+        })?;
+        Ok(())
+    }
+
     fn fetch(&self, py: Python, n_rows: usize) -> PyResult<PyDataFrame> {
         let ldf = self.ldf.clone();
         let df = py.allow_threads(|| ldf.fetch(n_rows).map_err(PyPolarsErr::from))?;
@@ -828,6 +1032,7 @@ impl PyLazyFrame {
         offset: &str,
         closed: Wrap<ClosedWindow>,
         by: Vec<PyExpr>,
+        descending: bool,
     ) -> PyLazyGroupBy {
         let closed_window = closed.0;
         let ldf = self.ldf.clone();
@@ -843,6 +1048,7 @@ impl PyLazyFrame {
                 period: Duration::parse(period),
                 offset: Duration::parse(offset),
                 closed_window,
+                descending,
             },
         );
 
@@ -941,6 +1147,7 @@ impl PyLazyFrame {
         suffix: String,
         validate: Wrap<JoinValidation>,
         coalesce: Option<bool>,
+        strategy: Wrap<JoinStrategy>,
     ) -> PyResult<Self> {
         let coalesce = match coalesce {
             None => JoinCoalesce::JoinSpecific,
@@ -969,11 +1176,26 @@ impl PyLazyFrame {
             .how(how.0)
             .coalesce(coalesce)
             .validate(validate.0)
+            .hints(JoinHints::default().with_strategy(strategy.0))
             .suffix(suffix)
             .finish()
             .into())
     }
 
+    fn intersect(&self, other: Self, distinct: bool) -> PyResult<Self> {
+        let ldf = self.ldf.clone();
+        let out = ldf
+            .intersect(other.ldf, distinct)
+            .map_err(PyPolarsErr::from)?;
+        Ok(out.into())
+    }
+
+    fn except_(&self, other: Self, distinct: bool) -> PyResult<Self> {
+        let ldf = self.ldf.clone();
+        let out = ldf.except(other.ldf, distinct).map_err(PyPolarsErr::from)?;
+        Ok(out.into())
+    }
+
     fn with_column(&mut self, expr: PyExpr) -> Self {
         let ldf = self.ldf.clone();
         ldf.with_column(expr.inner).into()
@@ -1073,13 +1295,20 @@ impl PyLazyFrame {
     }
 
     #[pyo3(signature = (maintain_order, subset, keep))]
+    #[pyo3(signature = (maintain_order, subset, keep, order_by=None))]
     fn unique(
         &self,
         maintain_order: bool,
         subset: Option<Vec<String>>,
         keep: Wrap<UniqueKeepStrategy>,
+        order_by: Option<Vec<String>>,
     ) -> Self {
         let ldf = self.ldf.clone();
+        if let Some(order_by) = order_by {
+            return ldf
+                .unique_order_by(subset, keep.0, order_by, maintain_order)
+                .into();
+        }
         match maintain_order {
             true => ldf.unique_stable(subset, keep.0),
             false => ldf.unique(subset, keep.0),
@@ -1129,7 +1358,7 @@ impl PyLazyFrame {
         ldf.with_row_index(name, offset).into()
     }
 
-    #[pyo3(signature = (lambda, predicate_pushdown, projection_pushdown, slice_pushdown, streamable, schema, validate_output))]
+    #[pyo3(signature = (lambda, predicate_pushdown, projection_pushdown, slice_pushdown, streamable, schema, required_columns, validate_output))]
     fn map_batches(
         &self,
         lambda: PyObject,
@@ -1138,6 +1367,7 @@ impl PyLazyFrame {
         slice_pushdown: bool,
         streamable: bool,
         schema: Option<Wrap<Schema>>,
+        required_columns: Option<Vec<String>>,
         validate_output: bool,
     ) -> Self {
         let opt = AllowedOptimizations {
@@ -1154,6 +1384,7 @@ impl PyLazyFrame {
                 lambda.into(),
                 opt,
                 schema.map(|s| Arc::new(s.0)),
+                required_columns.map(|names| names.into_iter().map(Arc::from).collect()),
                 validate_output,
             )
             .into()
@@ -1209,3 +1440,26 @@ impl PyLazyFrame {
         Ok(out.into())
     }
 }
+
+/// The number of rows of the batches of a partitioned sink, so every partition gets a file per
+/// batch of this many rows that contains it.
+#[cfg(all(feature = "streaming", any(feature = "parquet", feature = "ipc")))]
+const PARTITIONED_SINK_BATCH_SIZE: usize = 1 << 20;
+
+/// Stream the result of `ldf` into the hive-partitioned directory `path`.
+#[cfg(all(feature = "streaming", any(feature = "parquet", feature = "ipc")))]
+fn sink_partitioned<F>(
+    ldf: LazyFrame,
+    options: F,
+    path: PathBuf,
+    by: Vec<String>,
+    checkpoint: bool,
+) -> PolarsResult<()>
+where
+    F: polars::io::WriterFactory + Send + Sync + 'static,
+{
+    let writer = polars::io::partition::PartitionedWriter::new(options, path, by)
+        .with_include_key(false)
+        .with_checkpoint(checkpoint);
+    ldf.sink_partitioned(writer, PARTITIONED_SINK_BATCH_SIZE)
+}
@@ -152,6 +152,7 @@ impl NodeTraverser {
                 output_schema: None,
                 with_columns: None,
                 pyarrow: false,
+                is_io_plugin: false,
                 predicate: None,
                 n_rows: None,
             },
@@ -1,7 +1,7 @@
 use polars::datatypes::TimeUnit;
 use polars_core::series::IsSorted;
 use polars_core::utils::arrow::legacy::kernels::NonExistent;
-use polars_ops::prelude::ClosedInterval;
+use polars_ops::prelude::{ClosedInterval, ModeKeep};
 use polars_plan::dsl::function_expr::rolling::RollingFunction;
 use polars_plan::dsl::function_expr::rolling_by::RollingFunctionBy;
 use polars_plan::dsl::function_expr::trigonometry::TrigonometricFunction;
@@ -156,6 +156,9 @@ pub enum PyStringFunction {
     ZFill,
     ContainsMany,
     ReplaceMany,
+    Checksum,
+    Normalize,
+    CaseFold,
 }
 
 #[pymethods]
@@ -225,6 +228,7 @@ pub enum PyTemporalFunction {
     TotalMilliseconds,
     TotalMicroseconds,
     TotalNanoseconds,
+    ToIso8601Duration,
     ToString,
     CastTimeUnit,
     WithTimeUnit,
@@ -240,6 +244,9 @@ pub enum PyTemporalFunction {
     ReplaceTimeZone,
     Combine,
     DatetimeFunction,
+    IsoYearWeekDay,
+    DateFromIsoWeek,
+    DurationFromString,
 }
 
 #[pymethods]
@@ -442,6 +449,11 @@ impl PyRollingGroupOptions {
         };
         Ok(result.into_py(py))
     }
+
+    #[getter]
+    fn descending(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(self.inner.descending.to_object(py))
+    }
 }
 
 #[pyclass(name = "GroupbyOptions")]
@@ -661,11 +673,21 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 arguments: n.0,
                 options: py.None(),
             },
+            IRAggExpr::FirstNonNull(n) => Agg {
+                name: "first_non_null".to_object(py),
+                arguments: n.0,
+                options: py.None(),
+            },
             IRAggExpr::Last(n) => Agg {
                 name: "last".to_object(py),
                 arguments: n.0,
                 options: py.None(),
             },
+            IRAggExpr::LastNonNull(n) => Agg {
+                name: "last_non_null".to_object(py),
+                arguments: n.0,
+                options: py.None(),
+            },
             IRAggExpr::Mean(n) => Agg {
                 name: "mean".to_object(py),
                 arguments: n.0,
@@ -673,6 +695,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
             },
             IRAggExpr::Implode(_) => return Err(PyNotImplementedError::new_err("implode")),
             IRAggExpr::Quantile { .. } => return Err(PyNotImplementedError::new_err("quantile")),
+            IRAggExpr::ApproxQuantile { .. } => {
+                return Err(PyNotImplementedError::new_err("approx quantile"))
+            },
             IRAggExpr::Sum(n) => Agg {
                 name: "sum".to_object(py),
                 arguments: n.0,
@@ -698,6 +723,16 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 arguments: n.0,
                 options: py.None(),
             },
+            IRAggExpr::Mode(n, keep) => Agg {
+                name: "mode".to_object(py),
+                arguments: n.0,
+                options: match keep {
+                    ModeKeep::All => "all",
+                    ModeKeep::First => "first",
+                    ModeKeep::Last => "last",
+                }
+                .to_object(py),
+            },
         }
         .into_py(py),
         AExpr::Ternary {
@@ -843,7 +878,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::SplitExact { n, inclusive } => {
                         (PyStringFunction::SplitExact.into_py(py), n, inclusive).to_object(py)
                     },
-                    StringFunction::SplitN(_) => {
+                    StringFunction::SplitN { .. } => {
                         (PyStringFunction::SplitN.into_py(py),).to_object(py)
                     },
                     StringFunction::Strptime(_, _) => {
@@ -876,6 +911,15 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                         ascii_case_insensitive,
                     )
                         .to_object(py),
+                    StringFunction::Checksum(_) => {
+                        (PyStringFunction::Checksum.into_py(py),).to_object(py)
+                    },
+                    StringFunction::Normalize { form } => {
+                        (PyStringFunction::Normalize.into_py(py), format!("{form:?}")).to_object(py)
+                    },
+                    StringFunction::CaseFold => {
+                        (PyStringFunction::CaseFold.into_py(py),).to_object(py)
+                    },
                 },
                 FunctionExpr::StructExpr(_) => {
                     return Err(PyNotImplementedError::new_err("struct expr"))
@@ -886,6 +930,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     TemporalFunction::Year => (PyTemporalFunction::Year,).into_py(py),
                     TemporalFunction::IsLeapYear => (PyTemporalFunction::IsLeapYear,).into_py(py),
                     TemporalFunction::IsoYear => (PyTemporalFunction::IsoYear,).into_py(py),
+                    TemporalFunction::IsoYearWeekDay => {
+                        (PyTemporalFunction::IsoYearWeekDay,).into_py(py)
+                    },
                     TemporalFunction::Quarter => (PyTemporalFunction::Quarter,).into_py(py),
                     TemporalFunction::Month => (PyTemporalFunction::Month,).into_py(py),
                     TemporalFunction::Week => (PyTemporalFunction::Week,).into_py(py),
@@ -921,6 +968,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     TemporalFunction::TotalNanoseconds => {
                         (PyTemporalFunction::TotalNanoseconds,).into_py(py)
                     },
+                    TemporalFunction::ToIso8601Duration => {
+                        (PyTemporalFunction::ToIso8601Duration,).into_py(py)
+                    },
                     TemporalFunction::ToString(format) => {
                         (PyTemporalFunction::ToString, format).into_py(py)
                     },
@@ -970,6 +1020,15 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                             .map_or_else(|| py.None(), |s| s.to_object(py)),
                     )
                         .into_py(py),
+                    TemporalFunction::DateFromIsoWeek => {
+                        (PyTemporalFunction::DateFromIsoWeek,).into_py(py)
+                    },
+                    TemporalFunction::DurationFromString { time_unit, strict } => (
+                        PyTemporalFunction::DurationFromString,
+                        Wrap(*time_unit),
+                        *strict,
+                    )
+                        .into_py(py),
                 },
                 FunctionExpr::Boolean(boolfun) => match boolfun {
                     BooleanFunction::Any { ignore_nulls } => {
@@ -1110,6 +1169,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     options: _,
                     seed: _,
                 } => return Err(PyNotImplementedError::new_err("rank")),
+                FunctionExpr::RankBy { .. } => {
+                    return Err(PyNotImplementedError::new_err("rank by"))
+                },
                 FunctionExpr::Clip {
                     has_min: _,
                     has_max: _,
@@ -1121,6 +1183,11 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::CumProd { reverse } => ("cumprod", reverse).to_object(py),
                 FunctionExpr::CumMin { reverse } => ("cummin", reverse).to_object(py),
                 FunctionExpr::CumMax { reverse } => ("cummax", reverse).to_object(py),
+                FunctionExpr::CumMean { reverse } => ("cummean", reverse).to_object(py),
+                FunctionExpr::CumStd { reverse, ddof } => ("cumstd", reverse, ddof).to_object(py),
+                FunctionExpr::CumQuantile { quantile, reverse } => {
+                    ("cumquantile", quantile, reverse).to_object(py)
+                },
                 FunctionExpr::Reverse => return Err(PyNotImplementedError::new_err("reverse")),
                 FunctionExpr::ValueCounts {
                     sort: _,
@@ -1146,6 +1213,11 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::InterpolateBy => {
                     return Err(PyNotImplementedError::new_err("interpolate_by"))
                 },
+                FunctionExpr::IsClose {
+                    abs_tol: _,
+                    rel_tol: _,
+                    nans_equal: _,
+                } => return Err(PyNotImplementedError::new_err("is_close")),
                 FunctionExpr::Entropy {
                     base: _,
                     normalize: _,
@@ -1169,6 +1241,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 },
                 FunctionExpr::PeakMin => return Err(PyNotImplementedError::new_err("peak min")),
                 FunctionExpr::PeakMax => return Err(PyNotImplementedError::new_err("peak max")),
+                FunctionExpr::FindPeaks { .. } => {
+                    return Err(PyNotImplementedError::new_err("find peaks"))
+                },
                 FunctionExpr::Cut { .. } => return Err(PyNotImplementedError::new_err("cut")),
                 FunctionExpr::QCut { .. } => return Err(PyNotImplementedError::new_err("qcut")),
                 FunctionExpr::RLE => return Err(PyNotImplementedError::new_err("rle")),
@@ -1221,6 +1296,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Replace { return_dtype: _ } => {
                     return Err(PyNotImplementedError::new_err("replace"))
                 },
+                FunctionExpr::Lookup { .. } => {
+                    return Err(PyNotImplementedError::new_err("lookup"))
+                },
                 FunctionExpr::Negate => return Err(PyNotImplementedError::new_err("negate")),
                 FunctionExpr::FillNullWithStrategy(_) => {
                     return Err(PyNotImplementedError::new_err("fill null with strategy"))
@@ -1237,12 +1315,19 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Business(_) => {
                     return Err(PyNotImplementedError::new_err("business"))
                 },
+                FunctionExpr::Bitwise(_) => return Err(PyNotImplementedError::new_err("bitwise")),
                 FunctionExpr::TopKBy { .. } => {
                     return Err(PyNotImplementedError::new_err("top_k_by"))
                 },
                 FunctionExpr::EwmMeanBy { half_life: _ } => {
                     return Err(PyNotImplementedError::new_err("ewm_mean_by"))
                 },
+                FunctionExpr::EwmStdBy { .. } => {
+                    return Err(PyNotImplementedError::new_err("ewm_std_by"))
+                },
+                FunctionExpr::EwmVarBy { .. } => {
+                    return Err(PyNotImplementedError::new_err("ewm_var_by"))
+                },
             },
             options: py.None(),
         }
@@ -1269,6 +1354,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     inner: options.clone(),
                 }
                 .into_py(py),
+                WindowType::Frame(_) => return Err(PyNotImplementedError::new_err("window frame")),
             };
             Window {
                 function,
@@ -476,6 +476,7 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     schema: _,
                     predicate_pd: _,
                     projection_pd: _,
+                    required_columns: _,
                     streamable: _,
                     validate_output: _,
                 } => return Err(PyNotImplementedError::new_err("opaque python mapfunction")),
@@ -546,7 +547,9 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
             },
         }
         .into_py(py),
-        IR::Union { inputs, options } => Union {
+        IR::Union {
+            inputs, options, ..
+        } => Union {
             inputs: inputs.iter().map(|n| n.0).collect(),
             // TODO: rest of options
             options: options.slice,
@@ -46,6 +46,8 @@ use pyo3::{wrap_pyfunction, wrap_pymodule};
 use crate::batched_csv::PyBatchedCsv;
 use crate::conversion::Wrap;
 use crate::dataframe::PyDataFrame;
+#[cfg(feature = "object")]
+use crate::dataframe::PyRowIterator;
 use crate::error::{
     CategoricalRemappingWarning, ColumnNotFoundError, ComputeError, DuplicateError,
     InvalidOperationError, MapWithoutReturnDtypeWarning, NoDataError, OutOfBoundsError,
@@ -54,7 +56,7 @@ use crate::error::{
 };
 use crate::expr::PyExpr;
 use crate::functions::PyStringCacheHolder;
-use crate::lazyframe::{PyInProcessQuery, PyLazyFrame};
+use crate::lazyframe::{PyCancelToken, PyInProcessQuery, PyLazyFrame};
 use crate::lazygroupby::PyLazyGroupBy;
 use crate::series::PySeries;
 #[cfg(feature = "sql")]
@@ -122,8 +124,11 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     // Classes
     m.add_class::<PySeries>().unwrap();
     m.add_class::<PyDataFrame>().unwrap();
+    #[cfg(feature = "object")]
+    m.add_class::<PyRowIterator>().unwrap();
     m.add_class::<PyLazyFrame>().unwrap();
     m.add_class::<PyInProcessQuery>().unwrap();
+    m.add_class::<PyCancelToken>().unwrap();
     m.add_class::<PyLazyGroupBy>().unwrap();
     m.add_class::<PyExpr>().unwrap();
     m.add_class::<PyStringCacheHolder>().unwrap();
@@ -217,6 +222,10 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(functions::arctan2)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::datetime))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::date_from_isoweek))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::duration_from_string))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::concat_expr))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::concat_lf_diagonal))
@@ -234,6 +243,8 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(functions::last)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::lit)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::map_mul)).unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::map_groups_flat))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::nth)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::pearson_corr))
         .unwrap();
@@ -258,6 +269,9 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     #[cfg(feature = "parquet")]
     m.add_wrapped(wrap_pyfunction!(functions::read_parquet_schema))
         .unwrap();
+    #[cfg(any(feature = "csv", feature = "json"))]
+    m.add_wrapped(wrap_pyfunction!(functions::infer_sample_schema))
+        .unwrap();
     #[cfg(feature = "clipboard")]
     m.add_wrapped(wrap_pyfunction!(functions::read_clipboard_string))
         .unwrap();
@@ -302,6 +316,15 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     // Functions - misc
     m.add_wrapped(wrap_pyfunction!(functions::dtype_str_repr))
         .unwrap();
+    #[cfg(feature = "timezones")]
+    m.add_wrapped(wrap_pyfunction!(functions::utc_offset))
+        .unwrap();
+    #[cfg(feature = "timezones")]
+    m.add_wrapped(wrap_pyfunction!(functions::local_utc_offset))
+        .unwrap();
+    #[cfg(feature = "timezones")]
+    m.add_wrapped(wrap_pyfunction!(functions::local_dst_offset))
+        .unwrap();
     #[cfg(feature = "object")]
     m.add_wrapped(wrap_pyfunction!(on_startup::__register_startup_deps))
         .unwrap();
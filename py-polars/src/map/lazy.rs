@@ -2,6 +2,7 @@ use polars::prelude::*;
 use pyo3::prelude::*;
 use pyo3::types::PyList;
 
+use crate::conversion::any_value::py_object_to_any_value;
 use crate::py_modules::POLARS;
 use crate::series::PySeries;
 use crate::{PyExpr, Wrap};
@@ -138,6 +139,35 @@ pub fn map_single(
     pyexpr.inner.clone().map_python(func, agg_list).into()
 }
 
+/// Cumulatively fold the values of `pyexpr` with a Python function that takes the accumulator
+/// and the next value.
+pub fn scan(pyexpr: &PyExpr, acc_init: PyExpr, lambda: PyObject, cum_reduce: bool) -> PyExpr {
+    let function = move |acc: &AnyValue<'static>, av: AnyValue<'_>| {
+        Python::with_gil(|py| {
+            let out = match lambda.call1(py, (Wrap(acc.clone()), Wrap(av))) {
+                Ok(out) => out,
+                Err(e) => polars_bail!(
+                    ComputeError: "custom python function failed: {}", e.value_bound(py),
+                ),
+            };
+            let av = py_object_to_any_value(out.bind(py), true).map_err(|e| {
+                polars_err!(
+                    ComputeError: "could not convert the output of the python function: {}",
+                    e.value_bound(py),
+                )
+            })?;
+            av.into_static()
+        })
+    };
+
+    let input = pyexpr.inner.clone();
+    if cum_reduce {
+        input.cum_reduce(acc_init.inner, function).into()
+    } else {
+        input.scan(acc_init.inner, function).into()
+    }
+}
+
 pub(crate) fn call_lambda_with_series_slice(
     py: Python,
     s: &[Series],
@@ -202,3 +232,75 @@ pub fn map_mul(
         polars::lazy::dsl::map_multiple(function, exprs, output_map).into()
     }
 }
+
+/// The row indices of every group, as a `List` column with one element per group.
+fn groups_to_series(groups: &GroupsProxy) -> Series {
+    let n_values = match groups {
+        GroupsProxy::Idx(groups) => groups.all().iter().map(|idx| idx.len()).sum(),
+        GroupsProxy::Slice { groups, .. } => groups.iter().map(|[_, len]| *len as usize).sum(),
+    };
+    let mut builder =
+        ListPrimitiveChunkedBuilder::<IdxType>::new("groups", groups.len(), n_values, IDX_DTYPE);
+    match groups {
+        GroupsProxy::Idx(groups) => {
+            for idx in groups.all() {
+                builder.append_slice(idx.as_slice());
+            }
+        },
+        GroupsProxy::Slice { groups, .. } => {
+            for &[first, len] in groups {
+                builder.append_iter_values(first..first + len);
+            }
+        },
+    }
+    builder.finish().into_series()
+}
+
+pub fn map_groups_flat(
+    pyexpr: &[PyExpr],
+    py: Python,
+    lambda: PyObject,
+    output_type: Option<Wrap<DataType>>,
+) -> PyExpr {
+    // do the import outside of the function to prevent import side effects in a hot loop.
+    let pypolars = PyModule::import_bound(py, "polars").unwrap().to_object(py);
+
+    let function = move |s: &mut [Series], groups: &GroupsProxy| {
+        Python::with_gil(|py| {
+            let polars_module = pypolars.downcast_bound::<PyModule>(py).unwrap();
+            let wrap_s = |s: Series| {
+                polars_module
+                    .getattr("wrap_s")
+                    .unwrap()
+                    .call1((PySeries::new(s),))
+                    .unwrap()
+            };
+            let wrapped_s = PyList::new_bound(py, s.iter().map(|s| wrap_s(s.clone())));
+            let wrapped_groups = wrap_s(groups_to_series(groups));
+
+            let out = match lambda.call1(py, (wrapped_s, wrapped_groups)) {
+                Ok(pyobj) => pyobj,
+                Err(e) => polars_bail!(
+                    ComputeError: "custom python function failed: {}", e.value_bound(py),
+                ),
+            };
+            let out = out.to_series(py, &pypolars, "")?;
+            polars_ensure!(
+                out.len() == groups.len(),
+                ComputeError: "the function passed to `map_groups_flat` must return one value per group: expected {} values, got {}",
+                groups.len(), out.len(),
+            );
+            Ok(out)
+        })
+    };
+
+    let mut exprs = pyexpr.iter().map(|pe| pe.clone().inner);
+    let first = exprs.next().unwrap();
+    let arguments = exprs.collect::<Vec<_>>();
+
+    let output_map = GetOutput::map_field(move |fld| match output_type {
+        Some(ref dt) => Field::new(fld.name(), dt.0.clone()),
+        None => fld.clone(),
+    });
+    first.apply_groups(function, &arguments, output_map).into()
+}
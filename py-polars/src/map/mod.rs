@@ -11,7 +11,7 @@ use polars_core::utils::CustomIterTools;
 use polars_core::POOL;
 use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList, PyTuple};
 use smartstring::alias::String as SmartString;
 
 use crate::prelude::ObjectValue;
@@ -127,6 +127,79 @@ fn iterator_to_struct<'a>(
         .into())
 }
 
+/// Build a struct with the given `fields` from the dicts or tuples returned by a lambda.
+///
+/// Dict values are matched to the fields by key and tuple values by position. As the dtypes
+/// are known, the values are collected into their fields directly without inferring a dtype.
+fn iterator_to_struct_with_fields<'a>(
+    it: impl Iterator<Item = Option<Bound<'a, PyAny>>>,
+    init_null_count: usize,
+    fields: &[Field],
+    name: &str,
+    capacity: usize,
+) -> PyResult<PySeries> {
+    let mut buffers = fields
+        .iter()
+        .map(|_| {
+            let mut buf = Vec::with_capacity(capacity);
+            buf.extend((0..init_null_count).map(|_| AnyValue::Null));
+            buf
+        })
+        .collect::<Vec<_>>();
+
+    for out in it {
+        match out {
+            Some(out) if out.is_instance_of::<PyDict>() => {
+                let dict = out.downcast::<PyDict>()?;
+                for (fld, buf) in fields.iter().zip(buffers.iter_mut()) {
+                    let item = match dict.get_item(fld.name().as_str())? {
+                        Some(val) => val.extract::<Wrap<AnyValue>>()?.0,
+                        None => AnyValue::Null,
+                    };
+                    buf.push(item);
+                }
+            },
+            Some(out) if out.is_instance_of::<PyTuple>() || out.is_instance_of::<PyList>() => {
+                if out.len()? != fields.len() {
+                    return Err(crate::error::ComputeError::new_err(format!(
+                        "expected {} values for the struct fields, got {}",
+                        fields.len(),
+                        out.len()?
+                    )));
+                }
+                for (i, buf) in buffers.iter_mut().enumerate() {
+                    buf.push(out.get_item(i)?.extract::<Wrap<AnyValue>>()?.0);
+                }
+            },
+            Some(out) if !out.is_none() => {
+                return Err(crate::error::ComputeError::new_err(format!(
+                    "expected a dict or tuple for the struct return dtype, got {}",
+                    out.get_type().qualname()?
+                )))
+            },
+            _ => {
+                for buf in buffers.iter_mut() {
+                    buf.push(AnyValue::Null);
+                }
+            },
+        }
+    }
+
+    let fields = fields
+        .iter()
+        .zip(buffers)
+        .map(|(fld, buf)| {
+            Series::from_any_values_and_dtype(fld.name(), &buf, fld.data_type(), false)
+        })
+        .collect::<PolarsResult<Vec<_>>>()
+        .map_err(PyPolarsErr::from)?;
+
+    Ok(StructChunked::new(name, &fields)
+        .map_err(PyPolarsErr::from)?
+        .into_series()
+        .into())
+}
+
 fn iterator_to_primitive<T>(
     it: impl Iterator<Item = Option<T::Native>>,
     init_null_count: usize,
@@ -137,6 +137,15 @@ pub trait ApplyLambda<'a> {
         first_value: AnyValue<'a>,
     ) -> PyResult<PySeries>;
 
+    /// Apply a lambda that returns dicts or tuples with a struct output type
+    fn apply_lambda_with_struct_out_type(
+        &'a self,
+        py: Python,
+        lambda: &Bound<'a, PyAny>,
+        init_null_count: usize,
+        fields: &[Field],
+    ) -> PyResult<PySeries>;
+
     /// Apply a lambda with a primitive output type
     fn apply_lambda_with_primitive_out_type<D>(
         &'a self,
@@ -277,6 +286,28 @@ impl<'a> ApplyLambda<'a> for BooleanChunked {
         }
     }
 
+    fn apply_lambda_with_struct_out_type(
+        &'a self,
+        py: Python,
+        lambda: &Bound<'a, PyAny>,
+        init_null_count: usize,
+        fields: &[Field],
+    ) -> PyResult<PySeries> {
+        if !self.has_validity() {
+            let it = self
+                .into_no_null_iter()
+                .skip(init_null_count)
+                .map(|val| call_lambda(py, lambda, val).ok());
+            iterator_to_struct_with_fields(it, init_null_count, fields, self.name(), self.len())
+        } else {
+            let it = self
+                .into_iter()
+                .skip(init_null_count)
+                .map(|opt_val| opt_val.and_then(|val| call_lambda(py, lambda, val).ok()));
+            iterator_to_struct_with_fields(it, init_null_count, fields, self.name(), self.len())
+        }
+    }
+
     fn apply_lambda_with_primitive_out_type<D>(
         &'a self,
         py: Python,
@@ -570,6 +601,28 @@ where
         }
     }
 
+    fn apply_lambda_with_struct_out_type(
+        &'a self,
+        py: Python,
+        lambda: &Bound<'a, PyAny>,
+        init_null_count: usize,
+        fields: &[Field],
+    ) -> PyResult<PySeries> {
+        if !self.has_validity() {
+            let it = self
+                .into_no_null_iter()
+                .skip(init_null_count)
+                .map(|val| call_lambda(py, lambda, val).ok());
+            iterator_to_struct_with_fields(it, init_null_count, fields, self.name(), self.len())
+        } else {
+            let it = self
+                .into_iter()
+                .skip(init_null_count)
+                .map(|opt_val| opt_val.and_then(|val| call_lambda(py, lambda, val).ok()));
+            iterator_to_struct_with_fields(it, init_null_count, fields, self.name(), self.len())
+        }
+    }
+
     fn apply_lambda_with_primitive_out_type<D>(
         &'a self,
         py: Python,
@@ -856,6 +909,28 @@ impl<'a> ApplyLambda<'a> for StringChunked {
         }
     }
 
+    fn apply_lambda_with_struct_out_type(
+        &'a self,
+        py: Python,
+        lambda: &Bound<'a, PyAny>,
+        init_null_count: usize,
+        fields: &[Field],
+    ) -> PyResult<PySeries> {
+        if !self.has_validity() {
+            let it = self
+                .into_no_null_iter()
+                .skip(init_null_count)
+                .map(|val| call_lambda(py, lambda, val).ok());
+            iterator_to_struct_with_fields(it, init_null_count, fields, self.name(), self.len())
+        } else {
+            let it = self
+                .into_iter()
+                .skip(init_null_count)
+                .map(|opt_val| opt_val.and_then(|val| call_lambda(py, lambda, val).ok()));
+            iterator_to_struct_with_fields(it, init_null_count, fields, self.name(), self.len())
+        }
+    }
+
     fn apply_lambda_with_primitive_out_type<D>(
         &'a self,
         py: Python,
@@ -1203,6 +1278,29 @@ impl<'a> ApplyLambda<'a> for ListChunked {
         }
     }
 
+    fn apply_lambda_with_struct_out_type(
+        &'a self,
+        py: Python,
+        lambda: &Bound<'a, PyAny>,
+        init_null_count: usize,
+        fields: &[Field],
+    ) -> PyResult<PySeries> {
+        // get the pypolars module
+        let pypolars = PyModule::import_bound(py, "polars")?;
+        let it = self.into_iter().skip(init_null_count).map(|opt_val| {
+            opt_val.and_then(|val| {
+                // Wrap the series in the python side Series wrapper
+                let python_series_wrapper = pypolars
+                    .getattr("wrap_s")
+                    .unwrap()
+                    .call1((PySeries::new(val),))
+                    .unwrap();
+                call_lambda(py, lambda, python_series_wrapper).ok()
+            })
+        });
+        iterator_to_struct_with_fields(it, init_null_count, fields, self.name(), self.len())
+    }
+
     fn apply_lambda_with_primitive_out_type<D>(
         &'a self,
         py: Python,
@@ -1618,6 +1716,29 @@ impl<'a> ApplyLambda<'a> for ArrayChunked {
         }
     }
 
+    fn apply_lambda_with_struct_out_type(
+        &'a self,
+        py: Python,
+        lambda: &Bound<'a, PyAny>,
+        init_null_count: usize,
+        fields: &[Field],
+    ) -> PyResult<PySeries> {
+        // get the pypolars module
+        let pypolars = PyModule::import_bound(py, "polars")?;
+        let it = self.into_iter().skip(init_null_count).map(|opt_val| {
+            opt_val.and_then(|val| {
+                // Wrap the series in the python side Series wrapper
+                let python_series_wrapper = pypolars
+                    .getattr("wrap_s")
+                    .unwrap()
+                    .call1((PySeries::new(val),))
+                    .unwrap();
+                call_lambda(py, lambda, python_series_wrapper).ok()
+            })
+        });
+        iterator_to_struct_with_fields(it, init_null_count, fields, self.name(), self.len())
+    }
+
     fn apply_lambda_with_primitive_out_type<D>(
         &'a self,
         py: Python,
@@ -1995,6 +2116,20 @@ impl<'a> ApplyLambda<'a> for ObjectChunked<ObjectValue> {
         iterator_to_struct(it, init_null_count, first_value, self.name(), self.len())
     }
 
+    fn apply_lambda_with_struct_out_type(
+        &'a self,
+        _py: Python,
+        lambda: &Bound<'a, PyAny>,
+        init_null_count: usize,
+        fields: &[Field],
+    ) -> PyResult<PySeries> {
+        let it = self
+            .into_iter()
+            .skip(init_null_count)
+            .map(|object_value| lambda.call1((object_value.map(|v| &v.inner),)).ok());
+        iterator_to_struct_with_fields(it, init_null_count, fields, self.name(), self.len())
+    }
+
     fn apply_lambda_with_primitive_out_type<D>(
         &'a self,
         py: Python,
@@ -2281,6 +2416,22 @@ impl<'a> ApplyLambda<'a> for StructChunked {
         iterator_to_struct(it, init_null_count, first_value, self.name(), self.len())
     }
 
+    fn apply_lambda_with_struct_out_type(
+        &'a self,
+        py: Python,
+        lambda: &Bound<'a, PyAny>,
+        init_null_count: usize,
+        fields: &[Field],
+    ) -> PyResult<PySeries> {
+        let names = self.fields().iter().map(|s| s.name()).collect::<Vec<_>>();
+
+        let it = self.into_iter().skip(init_null_count).map(|val| {
+            let arg = make_dict_arg(py, &names, val);
+            lambda.call1((arg,)).ok()
+        });
+        iterator_to_struct_with_fields(it, init_null_count, fields, self.name(), self.len())
+    }
+
     fn apply_lambda_with_primitive_out_type<D>(
         &'a self,
         py: Python,
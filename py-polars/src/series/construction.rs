@@ -13,7 +13,7 @@ use pyo3::prelude::*;
 use crate::conversion::any_value::py_object_to_any_value;
 use crate::conversion::{reinterpret_vec, Wrap};
 use crate::error::PyPolarsErr;
-use crate::interop::arrow::to_rust::array_to_rust;
+use crate::interop::arrow::to_rust::{array_to_rust, stream_chunks_to_series, stream_to_rust};
 use crate::prelude::ObjectValue;
 use crate::PySeries;
 
@@ -342,4 +342,12 @@ impl PySeries {
             },
         }
     }
+
+    /// Create a Series from an object that exports an Arrow C stream, keeping its chunks.
+    #[staticmethod]
+    fn from_arrow_c_stream(name: &str, data: &Bound<PyAny>) -> PyResult<Self> {
+        let (field, chunks) = stream_to_rust(data)?;
+        let s = stream_chunks_to_series(name, &field, chunks)?;
+        Ok(s.into())
+    }
 }
@@ -1,6 +1,6 @@
 use polars_core::prelude::*;
 use pyo3::prelude::*;
-use pyo3::types::PyList;
+use pyo3::types::{PyCapsule, PyList};
 
 use crate::prelude::*;
 use crate::{interop, PySeries};
@@ -24,6 +24,10 @@ impl PySeries {
                     DataType::Int16 => PyList::new_bound(py, series.i16().unwrap()),
                     DataType::Int32 => PyList::new_bound(py, series.i32().unwrap()),
                     DataType::Int64 => PyList::new_bound(py, series.i64().unwrap()),
+                    DataType::Float16 => {
+                        let ca = series.f16().unwrap().to_float32();
+                        PyList::new_bound(py, &ca)
+                    },
                     DataType::Float32 => PyList::new_bound(py, series.f32().unwrap()),
                     DataType::Float64 => PyList::new_bound(py, series.f64().unwrap()),
                     DataType::Categorical(_, _) | DataType::Enum(_, _) => {
@@ -145,12 +149,33 @@ impl PySeries {
 
     /// Return the underlying Arrow array.
     #[allow(clippy::wrong_self_convention)]
-    fn to_arrow(&mut self) -> PyResult<PyObject> {
+    #[pyo3(signature = (run_end_encode=false))]
+    fn to_arrow(&mut self, run_end_encode: bool) -> PyResult<PyObject> {
         self.rechunk(true);
         Python::with_gil(|py| {
             let pyarrow = py.import_bound("pyarrow")?;
 
+            if run_end_encode {
+                if let Some((run_ends, values)) =
+                    interop::arrow::to_py::run_end_encode(&self.series)
+                {
+                    return interop::arrow::to_py::to_py_array_run_end_encoded(
+                        run_ends, values, py, &pyarrow,
+                    );
+                }
+            }
             interop::arrow::to_py::to_py_array(self.series.to_arrow(0, false), py, &pyarrow)
         })
     }
+
+    /// Export the chunks of this Series as an Arrow C stream.
+    #[allow(unused_variables)]
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_stream__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<PyObject>,
+    ) -> PyResult<Bound<'py, PyCapsule>> {
+        interop::arrow::to_py::series_to_stream(&self.series, py)
+    }
 }
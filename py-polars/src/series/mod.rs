@@ -295,14 +295,21 @@ impl PySeries {
         }
     }
 
-    fn sort(&mut self, descending: bool, nulls_last: bool, multithreaded: bool) -> PyResult<Self> {
+    fn sort(
+        &mut self,
+        descending: bool,
+        nulls_last: bool,
+        multithreaded: bool,
+        nan_ordering: Wrap<NanOrdering>,
+    ) -> PyResult<Self> {
         Ok(self
             .series
             .sort(
                 SortOptions::default()
                     .with_order_descending(descending)
                     .with_nulls_last(nulls_last)
-                    .with_multithreaded(multithreaded),
+                    .with_multithreaded(multithreaded)
+                    .with_nan_ordering(nan_ordering.0),
             )
             .map_err(PyPolarsErr::from)?
             .into())
@@ -584,6 +591,16 @@ impl PySeries {
                     )?;
                     ca.into_series()
                 },
+                Some(DataType::Struct(fields)) => {
+                    return dispatch_apply!(
+                        series,
+                        apply_lambda_with_struct_out_type,
+                        py,
+                        lambda,
+                        0,
+                        &fields
+                    )
+                },
                 None => return dispatch_apply!(series, apply_lambda_unknown, py, lambda),
 
                 _ => return dispatch_apply!(series, apply_lambda_unknown, py, lambda),
@@ -734,12 +751,9 @@ impl PySeries {
     }
 
     fn is_sorted(&self, descending: bool, nulls_last: bool) -> PyResult<bool> {
-        let options = SortOptions {
-            descending,
-            nulls_last,
-            multithreaded: true,
-            maintain_order: false,
-        };
+        let options = SortOptions::default()
+            .with_order_descending(descending)
+            .with_nulls_last(nulls_last);
         Ok(self.series.is_sorted(options).map_err(PyPolarsErr::from)?)
     }
 
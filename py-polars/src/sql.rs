@@ -33,6 +33,14 @@ impl PySQLContext {
             .into())
     }
 
+    pub fn execute_many(&mut self, query: &str) -> PyResult<PyLazyFrame> {
+        Ok(self
+            .context
+            .execute_many(query)
+            .map_err(PyPolarsErr::from)?
+            .into())
+    }
+
     pub fn get_tables(&self) -> PyResult<Vec<String>> {
         Ok(self.context.get_tables())
     }
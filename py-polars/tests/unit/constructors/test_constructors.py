@@ -1189,6 +1189,13 @@ def test_from_rows_dtype() -> None:
     assert df.item() == dc
 
 
+def test_from_rows_keeps_float_bits() -> None:
+    df = pl.DataFrame([(-0.0, "a"), (float("nan"), None)], orient="row")
+    assert df.schema == {"column_0": pl.Float64, "column_1": pl.String}
+    assert np.signbit(df.item(0, 0))
+    assert np.isnan(df.item(1, 0))
+
+
 def test_from_dicts_schema() -> None:
     data = [{"a": 1, "b": 4}, {"a": 2, "b": 5}, {"a": 3, "b": 6}]
 
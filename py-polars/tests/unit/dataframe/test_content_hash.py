@@ -0,0 +1,29 @@
+from __future__ import annotations
+
+import polars as pl
+
+
+def test_content_hash() -> None:
+    df = pl.DataFrame({"a": [1, None, 3, 4], "b": ["x", "y", None, "z"]})
+    ordered, order_insensitive = df.content_hash()
+
+    # Chunking doesn't matter.
+    chunked = pl.concat([df.head(1), df.tail(3)], rechunk=False)
+    assert chunked.content_hash() == (ordered, order_insensitive)
+
+    reversed_ordered, reversed_order_insensitive = df.reverse().content_hash()
+    assert reversed_ordered != ordered
+    assert reversed_order_insensitive == order_insensitive
+
+    for other in [df.head(3), df.rename({"a": "c"}), df.cast({"a": pl.Int32})]:
+        other_ordered, other_order_insensitive = other.content_hash()
+        assert other_ordered != ordered
+        assert other_order_insensitive != order_insensitive
+
+
+def test_content_hash_streaming() -> None:
+    lf = pl.LazyFrame({"a": range(100_000)}).with_columns(b=pl.col("a") % 7)
+    assert lf.content_hash() == lf.collect().content_hash()
+    assert lf.filter(pl.col("b") == 0).content_hash() == (
+        lf.collect().filter(pl.col("b") == 0).content_hash()
+    )
@@ -2617,11 +2617,13 @@ def test_deadlocks_3409() -> None:
         .to_dict(as_series=False)
     ) == {"col1": [[1, 2, 3]]}
 
+    with pytest.deprecated_call():
+        cumulative_eval = pl.col("col1").cumulative_eval(
+            pl.element().map_batches(lambda x: 0)
+        )
     assert (
         pl.DataFrame({"col1": [1, 2, 3]})
-        .with_columns(
-            pl.col("col1").cumulative_eval(pl.element().map_batches(lambda x: 0))
-        )
+        .with_columns(cumulative_eval)
         .to_dict(as_series=False)
     ) == {"col1": [0, 0, 0]}
 
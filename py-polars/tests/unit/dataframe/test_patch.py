@@ -0,0 +1,72 @@
+from __future__ import annotations
+
+import io
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+def test_patch_round_trip() -> None:
+    old = pl.DataFrame(
+        {
+            "k1": [1, 1, 2, None],
+            "k2": ["a", "b", "a", "a"],
+            "x": [1.0, 2.0, None, 4.0],
+            "y": ["p", "q", "r", "s"],
+        }
+    )
+    new = pl.DataFrame(
+        {
+            "k1": [None, 2, 3, 1],
+            "k2": ["a", "a", "a", "a"],
+            "x": [4.0, 3.0, 5.0, 1.0],
+            "y": ["s", "r", "t", "p"],
+        }
+    )
+    patch = old.compute_patch(new, on=["k1", "k2"])
+    assert patch.columns == ["k1", "k2", "__op", "x", "x__changed", "y", "y__changed"]
+    assert patch["__op"].to_list() == ["update", "insert", "delete"]
+    assert patch["x__changed"].to_list() == [True, True, False]
+    assert patch["y__changed"].to_list() == [False, True, False]
+
+    # The patch survives a round trip through IPC.
+    f = io.BytesIO()
+    patch.write_ipc(f)
+    f.seek(0)
+    patch = pl.read_ipc(f)
+
+    expected = pl.DataFrame(
+        {
+            "k1": [1, 2, None, 3],
+            "k2": ["a", "a", "a", "a"],
+            "x": [1.0, 3.0, 4.0, 5.0],
+            "y": ["p", "r", "s", "t"],
+        }
+    )
+    result = old.apply_patch(patch, on=["k1", "k2"])
+    assert_frame_equal(result, expected)
+    assert_frame_equal(result.sort("k1"), new.sort("k1"))
+
+
+def test_patch_unchanged() -> None:
+    df = pl.DataFrame({"k": [1, 2], "v": ["a", "b"]})
+    patch = df.compute_patch(df, on="k")
+    assert patch.columns == ["k", "__op"]
+    assert patch.height == 0
+    assert_frame_equal(df.apply_patch(patch, on="k"), df)
+
+
+def test_patch_errors() -> None:
+    old = pl.DataFrame({"k": [1, 2], "v": ["a", "b"]})
+    new = pl.DataFrame({"k": [2, 3], "v": ["c", "d"]})
+    patch = old.compute_patch(new, on="k")
+
+    with pytest.raises(pl.DuplicateError):
+        old.compute_patch(pl.DataFrame({"k": [1, 1], "v": ["a", "b"]}), on="k")
+    with pytest.raises(pl.SchemaError):
+        old.compute_patch(new.rename({"v": "w"}), on="k")
+    # Inserting a key that is already present.
+    with pytest.raises(pl.DuplicateError):
+        pl.DataFrame({"k": [1, 2, 3], "v": ["a", "b", "c"]}).apply_patch(patch, on="k")
@@ -215,3 +215,32 @@ def test_upsample_index_invalid(
             every="1h",
             maintain_order=maintain_order,
         )
+
+
+def test_resample_down_and_up() -> None:
+    df = pl.DataFrame({"idx": [0, 1, 2, 6, 7], "a": [1, 2, 3, 4, 5]})
+    result = df.resample("idx", every="2i", aggs=pl.col("a").sum())
+    expected = pl.DataFrame({"idx": [0, 2, 6], "a": [3, 3, 9]})
+    assert_frame_equal(result, expected)
+
+
+def test_resample_fill_inserted_rows_per_group() -> None:
+    df = pl.DataFrame(
+        {
+            "idx": [0, 2, 4, 0, 3],
+            "g": ["x", "x", "x", "y", "y"],
+            "a": [1, None, 3, 10, 20],
+        }
+    )
+    result = df.resample("idx", every="1i", fill_strategy="forward", group_by="g")
+    expected = pl.DataFrame(
+        {
+            "idx": [0, 1, 2, 3, 4, 0, 1, 2, 3],
+            "g": ["x"] * 5 + ["y"] * 4,
+            "a": [1, 1, None, 1, 3, 10, 10, 10, 20],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.resample("idx", every="1i", group_by="g")
+    assert result["a"].to_list() == [1, None, None, None, 3, 10, None, None, 20]
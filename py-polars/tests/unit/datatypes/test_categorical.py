@@ -750,6 +750,46 @@ def test_categorical_vstack_with_local_different_rev_map() -> None:
     assert df3.get_column("a").cast(pl.UInt32).to_list() == [0, 1, 2, 3, 4, 5]
 
 
+def test_categorical_vstack_with_different_sources() -> None:
+    with pl.StringCache():
+        df1 = pl.DataFrame({"a": pl.Series(["x", "b", "c"], dtype=pl.Categorical)})
+    df2 = pl.DataFrame({"a": pl.Series(["c", "d", "x"], dtype=pl.Categorical)})
+    with pytest.warns(CategoricalRemappingWarning, match="different string caches"):
+        df3 = df1.vstack(df2)
+    assert df3.get_column("a").cat.get_categories().to_list() == ["x", "b", "c", "d"]
+    assert df3.get_column("a").cast(pl.UInt32).to_list() == [0, 1, 2, 2, 3, 0]
+    assert df3.get_column("a").to_list() == ["x", "b", "c", "c", "d", "x"]
+
+
+def test_categorical_concat_with_different_string_caches() -> None:
+    with pl.StringCache():
+        s1 = pl.Series(["a", "b"], dtype=pl.Categorical)
+    with pl.StringCache():
+        s2 = pl.Series(["c", "b"], dtype=pl.Categorical)
+    with pytest.warns(CategoricalRemappingWarning, match="different string caches"):
+        out = pl.concat([s1, s2])
+    assert out.to_list() == ["a", "b", "c", "b"]
+    assert out.cat.get_categories().to_list() == ["a", "b", "c"]
+
+
+def test_list_categorical_concat_with_different_string_caches() -> None:
+    with pl.StringCache():
+        s1 = pl.Series([["a", "b"], None], dtype=pl.List(pl.Categorical))
+    s2 = pl.Series([["c"], ["b", "a"]], dtype=pl.List(pl.Categorical))
+    with pytest.warns(CategoricalRemappingWarning, match="different string caches"):
+        out = pl.concat([s1, s2])
+    assert out.to_list() == [["a", "b"], None, ["c"], ["b", "a"]]
+    assert out.explode().cat.get_categories().to_list() == ["a", "b", "c"]
+
+    # nested lists are re-encoded as well
+    with pl.StringCache():
+        s1 = pl.Series([[["a"]], [["b", "c"]]], dtype=pl.List(pl.List(pl.Categorical)))
+    s2 = pl.Series([[["c", "d"]]], dtype=pl.List(pl.List(pl.Categorical)))
+    with pytest.warns(CategoricalRemappingWarning, match="different string caches"):
+        out = pl.concat([s1, s2])
+    assert out.to_list() == [[["a"]], [["b", "c"]], [["c", "d"]]]
+
+
 def test_shift_over_13041() -> None:
     df = pl.DataFrame(
         {
@@ -1,7 +1,15 @@
+from __future__ import annotations
+
+import math
+from typing import TYPE_CHECKING
+
 import pytest
 
 import polars as pl
-from polars.testing import assert_series_equal
+from polars.testing import assert_frame_equal, assert_series_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
 
 
 def test_nan_in_group_by_agg() -> None:
@@ -296,3 +304,80 @@ def test_first_last_distinct() -> None:
     assert_series_equal(
         pl.Series("x", [False, True, False, True, True, True]), s.is_last_distinct()
     )
+
+
+def test_float16_cast_roundtrip() -> None:
+    s = pl.Series("a", [1.5, -0.25, None, 65504.0]).cast(pl.Float16)
+    assert s.dtype == pl.Float16
+    assert s.to_list() == [1.5, -0.25, None, 65504.0]
+    assert s.cast(pl.Float64).to_list() == [1.5, -0.25, None, 65504.0]
+
+    # values are rounded to the nearest 16-bit float
+    assert pl.Series([0.1]).cast(pl.Float16).item() == pytest.approx(0.1, rel=1e-3)
+    assert pl.Series([1e6]).cast(pl.Float16).item() == float("inf")
+
+
+def test_float16_arithmetic_and_aggregations() -> None:
+    s = pl.Series("a", [1.0, 2.5, None, 0.5]).cast(pl.Float16)
+
+    assert (s + 1).dtype == pl.Float32
+    assert (s * s).to_list() == [1.0, 6.25, None, 0.25]
+    assert (s / 2).dtype == pl.Float32
+
+    assert s.sum() == 4.0
+    assert s.mean() == 4.0 / 3
+    assert s.min() == 0.5
+    assert s.max() == 2.5
+    assert s.sort().to_list() == [None, 0.5, 1.0, 2.5]
+    assert (s > 0.75).to_list() == [True, True, None, False]
+
+    df = pl.DataFrame({"g": [1, 1, 2, 2], "a": s})
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("a").min().alias("min"), pl.col("a").sum().alias("sum")
+    )
+    assert out.schema == {"g": pl.Int64, "min": pl.Float16, "sum": pl.Float32}
+    assert out.rows() == [(1, 1.0, 3.5), (2, 0.5, 0.5)]
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("format", ["parquet", "ipc"])
+def test_float16_io_roundtrip(format: str, tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "a": pl.Series([1.5, None, -2.0]).cast(pl.Float16),
+            "l": pl.Series([[0.5, None], None, [3.0]]).cast(pl.List(pl.Float16)),
+        }
+    )
+    path = tmp_path / f"float16.{format}"
+    if format == "parquet":
+        df.write_parquet(path)
+        out = pl.read_parquet(path)
+    else:
+        df.write_ipc(path)
+        out = pl.read_ipc(path)
+    assert_frame_equal(out, df)
+
+
+def test_float16_numeric_dispatch() -> None:
+    s = pl.Series("a", [1.0, float("nan"), 0.5]).cast(pl.Float16)
+
+    assert math.isnan(s.nan_max())
+    assert s.max() == 1.0
+    assert (s**2).dtype == pl.Float32
+    assert s.shrink_dtype().dtype == pl.Float16
+    assert s.round(0).dtype == pl.Float16
+
+    df = pl.DataFrame({"g": [1, 1, 2], "a": s})
+    out = df.group_by("g", maintain_order=True).agg(pl.col("a").nan_min())
+    assert out.schema == {"g": pl.Int64, "a": pl.Float16}
+    assert math.isnan(out["a"][0])
+    assert out["a"][1] == 0.5
+
+
+def test_float16_signed_zero_keys() -> None:
+    left = pl.DataFrame({"a": pl.Series([0.0, -0.0, 1.0]).cast(pl.Float16)})
+    right = pl.DataFrame({"a": pl.Series([-0.0, 1.0]).cast(pl.Float16), "b": [1, 2]})
+
+    out = left.join(right, on="a", how="left")
+    assert out["b"].to_list() == [1, 1, 2]
+    assert left["a"].n_unique() == 2
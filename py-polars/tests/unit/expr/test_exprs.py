@@ -381,6 +381,36 @@ def test_rank_string_null_11252() -> None:
     assert rank.to_list() == [None, 1.0, 3.0, None, 2.0]
 
 
+def test_rank_pct() -> None:
+    s = pl.Series([3, 6, 1, 1, None, 6])
+    assert s.rank(pct=True).to_list() == [0.6, 0.9, 0.3, 0.3, None, 0.9]
+    assert s.rank("min", pct=True).to_list() == [0.6, 0.8, 0.2, 0.2, None, 0.8]
+    assert s.rank("dense", pct=True).to_list() == [2 / 3, 1.0, 1 / 3, 1 / 3, None, 1.0]
+
+
+@pytest.mark.parametrize("method", ["average", "min", "max", "dense", "ordinal"])
+@pytest.mark.parametrize("descending", [False, True])
+@pytest.mark.parametrize("pct", [False, True])
+def test_rank_by(method: Any, descending: bool, pct: bool) -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 2, 1, None, 2, 1, None, 2],
+            "h": ["x", "x", "y", "x", "x", "x", "x", None],
+            "v": [3.0, 1.0, None, 2.0, 1.0, 3.0, 5.0, 0.5],
+        }
+    )
+    rank = {"method": method, "descending": descending, "pct": pct}
+    result = df.select(pl.col("v").rank_by(["g", "h"], **rank))
+    expected = df.select(pl.col("v").rank(**rank).over("g", "h"))
+    assert_frame_equal(result, expected)
+
+
+def test_rank_by_series() -> None:
+    s = pl.Series("b", [6, 7, 5, 14, 11])
+    result = s.rank_by(pl.Series([1, 1, 2, 2, 2]), "dense")
+    assert_series_equal(result, pl.Series("b", [1, 2, 1, 3, 2], dtype=pl.get_index_type()))
+
+
 def test_search_sorted() -> None:
     for seed in [1, 2, 3]:
         np.random.seed(seed)
@@ -1,6 +1,7 @@
 import pytest
 
 import polars as pl
+from polars.testing import assert_frame_equal
 
 
 @pytest.mark.slow()
@@ -59,3 +60,90 @@ def test_concat_vertically_relaxed() -> None:
         "a": [1.0, 0.2, 1.0, 2.0],
         "b": [None, 0.1, 2.0, 1.0],
     }
+
+
+def test_concat_vertically_relaxed_many() -> None:
+    frames = [
+        pl.DataFrame({"a": [i]}, schema={"a": dtype})
+        for i, dtype in enumerate([pl.Int8, pl.Int32, pl.Int16, pl.Int64] * 5)
+    ]
+    out = pl.concat(frames, how="vertical_relaxed")
+    assert out.schema == {"a": pl.Int64}
+    assert out["a"].to_list() == list(range(20))
+
+    with pytest.raises(pl.ShapeError, match="column names don't match"):
+        pl.concat([frames[0], frames[1].rename({"a": "b"})], how="vertical_relaxed")
+
+
+def test_concat_merge_sorted_by() -> None:
+    df1 = pl.DataFrame({"t": [None, 1, 4, 7], "x": ["n", "a", "b", "c"]})
+    df2 = pl.DataFrame({"t": [2, 3, 8], "x": ["d", "e", "f"]})
+    df3 = pl.DataFrame({"t": [0, 5], "x": ["g", "h"]})
+    expected = pl.DataFrame(
+        {
+            "t": [None, 0, 1, 2, 3, 4, 5, 7, 8],
+            "x": ["n", "g", "a", "d", "e", "b", "h", "c", "f"],
+        }
+    )
+
+    out = pl.concat([df1, df2, df3], how="merge_sorted_by", by="t")
+    assert_frame_equal(out, expected)
+
+    lfs = [df1.lazy(), df2.lazy(), df3.lazy()]
+    lf = pl.concat(lfs, how="merge_sorted_by", by="t")
+    assert_frame_equal(lf.collect(), expected)
+    # The merge key is kept when it isn't projected.
+    assert lf.select("x").collect().to_series().to_list() == expected["x"].to_list()
+    assert_frame_equal(lf.head(3).collect(), expected.head(3))
+
+    with pytest.raises(ValueError, match="requires the `by` column"):
+        pl.concat([df1, df2], how="merge_sorted_by")
+    with pytest.raises(pl.ColumnNotFoundError):
+        pl.concat([df1, df2], how="merge_sorted_by", by="y")
+
+
+def test_concat_merge_sorted_by_nulls_last() -> None:
+    lfs = [pl.LazyFrame({"t": [1, 4, None]}), pl.LazyFrame({"t": [2, 3, None]})]
+    lf = pl.concat(lfs, how="merge_sorted_by", by="t")
+    expected = [1, 2, 3, 4, None, None]
+    assert lf.collect()["t"].to_list() == expected
+    assert lf.collect(streaming=True)["t"].to_list() == expected
+
+
+def test_concat_merge_sorted_by_not_sorted() -> None:
+    lfs = [pl.LazyFrame({"t": [2, 1]}), pl.LazyFrame({"t": [1, 3]})]
+    lf = pl.concat(lfs, how="merge_sorted_by", by="t")
+    with pytest.raises(pl.InvalidOperationError, match="sorted by 't'"):
+        lf.collect()
+    with pytest.raises(pl.InvalidOperationError, match="sorted by 't'"):
+        lf.collect(streaming=True)
+
+    lfs = [pl.LazyFrame({"t": [None, 1]}), pl.LazyFrame({"t": [1, None]})]
+    lf = pl.concat(lfs, how="merge_sorted_by", by="t")
+    with pytest.raises(pl.InvalidOperationError, match="nulls of 't' differently"):
+        lf.collect()
+    with pytest.raises(pl.InvalidOperationError, match="'t'"):
+        lf.collect(streaming=True)
+
+
+def test_concat_vertically_relaxed_order_independent() -> None:
+    int8 = pl.DataFrame({"a": [1]}, schema={"a": pl.Int8})
+    uint8 = pl.DataFrame({"a": [2]}, schema={"a": pl.UInt8})
+    int16 = pl.DataFrame({"a": [3]}, schema={"a": pl.Int16})
+
+    for frames in ([int8, uint8, int16], [int16, uint8, int8]):
+        out = pl.concat(frames, how="vertical_relaxed")
+        assert out.schema == {"a": pl.Int16}
+
+
+def test_concat_vertically_relaxed_streaming() -> None:
+    frames = [
+        pl.LazyFrame({"a": [i], "b": ["x"]}, schema={"a": dtype, "b": pl.String})
+        for i, dtype in enumerate([pl.Int8, pl.UInt8, pl.Int32])
+    ]
+    q = pl.concat(frames, how="vertical_relaxed").select(pl.col("a").sum())
+    assert q.explain(streaming=True).startswith("STREAMING")
+
+    out = q.collect(streaming=True)
+    assert out.schema == {"a": pl.Int32}
+    assert out.item() == 3
@@ -1037,3 +1037,109 @@ def test_from_pandas_nan_to_null_16453(monkeypatch: pytest.MonkeyPatch) -> None:
         {"a": [None, 1.0, 2], "b": [1.0, 2.0, 3.0], "c": [4.0, 5.0, 6.0]}
     )
     assert_frame_equal(result, expected)
+
+
+class _ArrowStream:
+    # Only exposes the Arrow PyCapsule interface of the wrapped object.
+    def __init__(self, obj: Any) -> None:
+        self.obj = obj
+
+    def __arrow_c_stream__(self, requested_schema: object | None = None) -> object:
+        return self.obj.__arrow_c_stream__(requested_schema)
+
+
+def test_series_from_arrow_c_stream_keeps_chunks() -> None:
+    s = pl.concat([pl.Series("a", [1, 2]), pl.Series("a", [None, 4])], rechunk=False)
+    assert s.n_chunks() == 2
+
+    out = pl.Series("b", _ArrowStream(s))
+    assert out.n_chunks() == 2
+    assert_series_equal(out, s.alias("b"))
+
+    out = pl.Series("b", _ArrowStream(s), dtype=pl.Float64)
+    assert_series_equal(out, s.cast(pl.Float64).alias("b"))
+
+    empty = pl.Series("a", [], dtype=pl.String)
+    assert_series_equal(pl.Series("a", _ArrowStream(empty)), empty)
+
+
+@pytest.mark.skipif(
+    not hasattr(pa.ChunkedArray, "__arrow_c_stream__"),
+    reason="pyarrow does not implement the Arrow PyCapsule interface",
+)
+def test_series_from_pyarrow_c_stream() -> None:
+    arr = pa.chunked_array([["a", None], ["c"]])
+    s = pl.Series("a", _ArrowStream(arr))
+    assert s.n_chunks() == 2
+    assert s.to_list() == ["a", None, "c"]
+
+    out = pa.chunked_array(_ArrowStream(s))  # type: ignore[call-overload]
+    assert out.num_chunks == 2
+    assert out.to_pylist() == ["a", None, "c"]
+
+
+def test_dataframe_from_arrow_c_stream_keeps_chunks() -> None:
+    batches = [
+        pa.record_batch({"a": [1, 2], "b": ["x", None]}),
+        pa.record_batch({"a": [3], "b": ["z"]}),
+    ]
+    reader = pa.RecordBatchReader.from_batches(batches[0].schema, batches)
+
+    df = pl.DataFrame(_ArrowStream(reader))
+    assert df.n_chunks() == 2
+    assert_frame_equal(
+        df, pl.DataFrame({"a": [1, 2, 3], "b": ["x", None, "z"]}), check_exact=True
+    )
+
+    reader = pa.RecordBatchReader.from_batches(batches[0].schema, batches)
+    df = pl.DataFrame(_ArrowStream(reader), schema_overrides={"a": pl.Int32})
+    assert df.schema == {"a": pl.Int32, "b": pl.String}
+    assert df["a"].to_list() == [1, 2, 3]
+
+
+@pytest.mark.skipif(
+    not hasattr(pa, "RunEndEncodedArray"),
+    reason="pyarrow does not support run-end encoded arrays",
+)
+def test_from_arrow_run_end_encoded() -> None:
+    arr = pa.RunEndEncodedArray.from_arrays([2, 3, 6], ["a", None, "b"])
+    s = pl.from_arrow(arr)
+    assert isinstance(s, pl.Series)
+    assert s.dtype == pl.String
+    assert s.to_list() == ["a", "a", None, "b", "b", "b"]
+    # The offset of a sliced array applies to the runs.
+    assert pl.Series(arr.slice(1, 3)).to_list() == ["a", None, "b"]
+
+    run_ends = pa.array([4, 6], type=pa.int16())
+    tbl = pa.table(
+        {"a": arr, "b": pa.RunEndEncodedArray.from_arrays(run_ends, [1, 2])}
+    )
+    expected = pl.DataFrame({"a": s, "b": [1, 1, 1, 1, 2, 2]})
+    assert_frame_equal(cast(pl.DataFrame, pl.from_arrow(tbl)), expected)
+
+    reader = pa.RecordBatchReader.from_batches(tbl.schema, tbl.to_batches())
+    assert_frame_equal(pl.DataFrame(_ArrowStream(reader)), expected)
+
+
+@pytest.mark.skipif(
+    not hasattr(pa, "RunEndEncodedArray"),
+    reason="pyarrow does not support run-end encoded arrays",
+)
+def test_series_to_arrow_run_end_encoded() -> None:
+    s = pl.Series("a", ["x", "x", "x", None, None, "y"])
+    arr = s.to_arrow(run_end_encode=True)
+    assert isinstance(arr, pa.RunEndEncodedArray)
+    assert arr.run_ends.to_pylist() == [3, 5, 6]
+    assert arr.values.to_pylist() == ["x", None, "y"]
+    assert_series_equal(cast(pl.Series, pl.from_arrow(arr)), s, check_names=False)
+
+    # Floats are only in the same run if their bits are equal.
+    s = pl.Series([0.0, -0.0, -0.0, float("nan"), float("nan"), float("nan")])
+    arr = s.to_arrow(run_end_encode=True)
+    assert arr.run_ends.to_pylist() == [1, 3, 6]
+    assert str(arr.values[1]) == "-0.0"
+
+    # Series with too many runs are not encoded.
+    s = pl.Series([1, 2, 2, 3])
+    assert s.to_arrow(run_end_encode=True) == s.to_arrow()
+    assert not isinstance(s.to_arrow(run_end_encode=True), pa.RunEndEncodedArray)
@@ -102,3 +102,21 @@ def test_lazy_count_s3(s3: str) -> None:
     assert "FAST COUNT(*)" in lf.explain()
     expected = pl.DataFrame({"len": [54]}, schema={"len": pl.UInt32})
     assert_frame_equal(lf.collect(), expected)
+
+
+def test_scan_s3_request_limit(s3: str) -> None:
+    lf = pl.scan_parquet(
+        "s3://bucket/foods*.parquet",
+        storage_options={
+            "endpoint_url": s3,
+            "max_concurrent_requests": "1",
+            "retry_timeout": "5",
+        },
+    )
+    assert lf.collect().shape == (54, 4)
+
+    with pytest.raises(pl.ComputeError, match="max_concurrent_requests"):
+        pl.scan_parquet(
+            "s3://bucket/foods1.parquet",
+            storage_options={"endpoint_url": s3, "max_concurrent_requests": "many"},
+        ).collect()
@@ -24,7 +24,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
-    from polars.type_aliases import TimeUnit
+    from polars.type_aliases import CsvQuoteStyle, TimeUnit
     from tests.unit.conftest import MemoryUsage
 
 
@@ -564,11 +564,8 @@ def test_compressed_csv(io_files_path: Path) -> None:
 
     # zstd compressed file
     csv_file = io_files_path / "zstd_compressed.csv.zst"
-    with pytest.raises(
-        ComputeError,
-        match="cannot scan compressed csv; use `read_csv` for compressed data",
-    ):
-        pl.scan_csv(csv_file).collect()
+    out = pl.scan_csv(csv_file, truncate_ragged_lines=True).collect()
+    assert_frame_equal(out, expected)
     out = pl.read_csv(str(csv_file), truncate_ragged_lines=True)
     assert_frame_equal(out, expected)
 
@@ -1790,6 +1787,31 @@ def test_csv_quote_styles() -> None:
     )
 
 
+@pytest.mark.write_disk()
+def test_csv_column_quote_styles(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": ["x", "y"], "b": [1, 2], "c": ["z", "w"]})
+    expected = '"a","b",c\r\n"x","1",z\r\n"y","2",w\r\n'
+
+    column_quote_styles: dict[str, CsvQuoteStyle] = {"b": "always", "c": "never"}
+    assert (
+        df.write_csv(
+            line_terminator="\r\n",
+            quote_style="non_numeric",
+            column_quote_styles=column_quote_styles,
+        )
+        == expected
+    )
+
+    path = tmp_path / "out.csv"
+    df.lazy().sink_csv(
+        path,
+        line_terminator="\r\n",
+        quote_style="non_numeric",
+        column_quote_styles=column_quote_styles,
+    )
+    assert path.read_bytes() == expected.encode()
+
+
 def test_ignore_errors_casting_dtypes() -> None:
     csv = """inventory
 10
@@ -272,3 +272,134 @@ def test_read_parquet_hive_schema_with_pyarrow() -> None:
         match="cannot use `hive_partitions` with `use_pyarrow=True`",
     ):
         pl.read_parquet("test.parquet", hive_schema={"c": pl.Int32}, use_pyarrow=True)
+
+
+@pytest.mark.write_disk()
+def test_write_parquet_partitioned(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, 3, 4, 5],
+            "key": ["x/y", "x/y", "x/y", "z", None],
+        }
+    )
+    root = tmp_path / "dataset"
+
+    def read() -> pl.DataFrame:
+        return pl.read_parquet(
+            root / "**/*.parquet",
+            hive_partitioning=True,
+            hive_schema={"key": pl.String},
+        ).select(df.columns)
+
+    df.write_parquet(root, partition_by="key", max_rows_per_file=2)
+
+    assert sorted(p.name for p in root.iterdir()) == [
+        "key=__HIVE_DEFAULT_PARTITION__",
+        "key=x%2Fy",
+        "key=z",
+    ]
+    assert len(list((root / "key=x%2Fy").iterdir())) == 2
+
+    result = read()
+    assert_frame_equal(result, df, check_row_order=False)
+
+    # Existing data is kept by default.
+    with pytest.raises(pl.ComputeError, match="already contains data"):
+        df.head(1).write_parquet(root, partition_by="key")
+    assert_frame_equal(read(), df, check_row_order=False)
+
+    df.write_parquet(root, partition_by="key", existing_data="append")
+    result = read()
+    assert_frame_equal(result, pl.concat([df, df]), check_row_order=False)
+
+    # Only the written partitions are overwritten.
+    df.head(1).write_parquet(root, partition_by=["key"], existing_data="overwrite")
+    result = read()
+    assert_frame_equal(
+        result, pl.concat([df.head(1), df.tail(2), df.tail(2)]), check_row_order=False
+    )
+
+    with pytest.raises(ValueError, match="`existing_data` must be one of"):
+        df.write_parquet(root, partition_by="key", existing_data="ignore")  # type: ignore[arg-type]
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("file_type", ["csv", "ndjson"])
+def test_scan_hive_partitioned_text_files(
+    file_type: str, tmp_path: Path, monkeypatch: Any, capfd: Any
+) -> None:
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    for c, df in [
+        (1, pl.DataFrame({"a": [1, 2], "b": [11.0, 12.0]})),
+        (2, pl.DataFrame({"a": [3, 4], "b": [13.0, 14.0]})),
+        (3, pl.DataFrame({"a": [5], "b": [15.0]})),
+    ]:
+        part = tmp_path / f"c={c}"
+        part.mkdir()
+        if file_type == "csv":
+            df.write_csv(part / "data.csv")
+        else:
+            df.write_ndjson(part / "data.ndjson")
+
+    def scan(**kwargs: Any) -> pl.LazyFrame:
+        if file_type == "csv":
+            return pl.scan_csv(tmp_path / "**/*.csv", hive_partitioning=True, **kwargs)
+        return pl.scan_ndjson(
+            tmp_path / "**/*.ndjson", hive_partitioning=True, **kwargs
+        )
+
+    expected_schema = OrderedDict({"a": pl.Int64, "b": pl.Float64, "c": pl.Int64})
+    assert scan().collect_schema() == expected_schema
+
+    q = scan(hive_schema={"c": pl.Int8})
+    result = q.filter(pl.col("c") >= 2).sort("a").collect()
+    expected = pl.DataFrame(
+        {"a": [3, 4, 5], "b": [13.0, 14.0, 15.0], "c": [2, 2, 3]},
+        schema_overrides={"c": pl.Int8},
+    )
+    assert_frame_equal(result, expected)
+    assert "hive partitioning: skipped 1 files" in capfd.readouterr().err
+
+    result = q.select("c").sort("c").collect()
+    assert result.to_series().to_list() == [1, 1, 2, 2, 3]
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("file_type", ["csv", "ndjson"])
+def test_scan_hive_partitioned_text_files_skip_pruned(
+    file_type: str, tmp_path: Path
+) -> None:
+    df = pl.DataFrame({"a": [1, 2], "b": [11.0, 12.0]})
+    for c in [1, 2]:
+        part = tmp_path / f"c={c}"
+        part.mkdir()
+        if file_type == "csv":
+            df.write_csv(part / "data.csv")
+        else:
+            df.write_ndjson(part / "data.ndjson")
+    # The file of the pruned partition fails to parse if it is read.
+    part = tmp_path / "c=3"
+    part.mkdir()
+    (part / f"data.{file_type}").write_text("a,b\nx,y\n")
+
+    schema = {"a": pl.Int64, "b": pl.Float64}
+    if file_type == "csv":
+        lf = pl.scan_csv(tmp_path / "**/*.csv", hive_partitioning=True, schema=schema)
+    else:
+        lf = pl.scan_ndjson(
+            tmp_path / "**/*.ndjson", hive_partitioning=True, schema=schema
+        )
+
+    with pytest.raises(pl.PolarsError):
+        lf.collect()
+
+    result = lf.filter(pl.col("c") <= 2).sort("c", "a").collect()
+    expected = pl.DataFrame(
+        {"a": [1, 2, 1, 2], "b": [11.0, 12.0, 11.0, 12.0], "c": [1, 1, 2, 2]}
+    )
+    assert_frame_equal(result, expected)
+
+    # Predicates on both the columns of the files and the partitions prune as well.
+    result = lf.filter((pl.col("a") > 1) & (pl.col("c") < 3)).sort("c").collect()
+    expected = pl.DataFrame({"a": [2, 2], "b": [12.0, 12.0], "c": [1, 2]})
+    assert_frame_equal(result, expected)
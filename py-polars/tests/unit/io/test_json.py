@@ -4,6 +4,8 @@ import io
 import json
 import typing
 from collections import OrderedDict
+from datetime import date, datetime, time, timedelta
+from decimal import Decimal
 from io import BytesIO
 from typing import TYPE_CHECKING
 
@@ -134,6 +136,67 @@ def test_write_ndjson_with_trailing_newline() -> None:
     assert_frame_equal(df, expected)
 
 
+@pytest.mark.parametrize(
+    ("temporal_format", "expected"),
+    [
+        (
+            "display",
+            '{"date":"2024-01-02","dt":"2024-01-01 12:30:00",'
+            '"time":"01:02:03","dur":"PT90S"}\n',
+        ),
+        (
+            "iso8601",
+            '{"date":"2024-01-02","dt":"2024-01-01T12:30:00",'
+            '"time":"01:02:03","dur":"PT90S"}\n',
+        ),
+        (
+            "epoch",
+            '{"date":19724,"dt":1704112200000000,'
+            '"time":3723000000000,"dur":90000000}\n',
+        ),
+    ],
+)
+def test_write_ndjson_temporal_format(temporal_format: str, expected: str) -> None:
+    df = pl.DataFrame(
+        {
+            "date": [date(2024, 1, 2)],
+            "dt": [datetime(2024, 1, 1, 12, 30)],
+            "time": [time(1, 2, 3)],
+            "dur": [timedelta(seconds=90)],
+        }
+    )
+    out = df.write_ndjson(temporal_format=temporal_format)  # type: ignore[arg-type]
+    assert out == expected
+
+
+def test_write_ndjson_temporal_format_invalid() -> None:
+    df = pl.DataFrame({"a": [1]})
+    with pytest.raises(ValueError, match="temporal_format"):
+        df.write_ndjson(temporal_format="unix")  # type: ignore[arg-type]
+
+
+def test_write_ndjson_decimal_as_string() -> None:
+    df = pl.DataFrame(
+        {"a": [Decimal("1.50"), Decimal("-0.05"), None]},
+        schema={"a": pl.Decimal(10, 2)},
+    )
+    assert df.write_ndjson() == '{"a":1.50}\n{"a":-0.05}\n{"a":null}\n'
+    out = df.write_ndjson(decimal_as_string=True)
+    assert out == '{"a":"1.50"}\n{"a":"-0.05"}\n{"a":null}\n'
+
+
+def test_write_ndjson_omit_nulls() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, None],
+            "b": ["null", None],
+            "c": [{"x": None, "y": 1}, None],
+        }
+    )
+    out = df.write_ndjson(omit_nulls=True)
+    assert out == '{"a":1,"b":"null","c":{"y":1}}\n{}\n'
+
+
 def test_read_ndjson_empty_array() -> None:
     assert pl.read_ndjson(io.StringIO("""{"foo": {"bar": []}}""")).to_dict(
         as_series=False
@@ -69,3 +69,38 @@ def test_count_ipc(io_files_path: Path, path: str, n_rows: int) -> None:
     # Check if we are using our fast count star
     assert "FAST COUNT(*)" in lf.explain()
     assert_frame_equal(lf.collect(), expected)
+
+
+def test_count_row_preserving_transforms(io_files_path: Path) -> None:
+    lf = pl.scan_parquet(io_files_path / "foods1.parquet").with_columns(
+        pl.struct("category", "calories").alias("s"),
+        pl.concat_list("calories", "calories").alias("l"),
+    )
+    expected = pl.DataFrame(pl.Series("len", [27], dtype=pl.UInt32))
+
+    unnested = lf.sort("fats_g").unnest("s").select(pl.len())
+    assert "FAST COUNT(*)" in unnested.explain()
+    assert_frame_equal(unnested.collect(), expected)
+
+    exploded = lf.explode("l").select(pl.len())
+    assert "FAST COUNT(*)" not in exploded.explain()
+    assert_frame_equal(exploded.collect(), expected.with_columns(pl.col("len") * 2))
+
+
+@pytest.mark.write_disk()
+def test_count_hive_partition_predicate(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": [1, 1, 2, 3, 3, 3], "b": range(6)})
+    for (a,), part in df.group_by("a"):
+        (tmp_path / f"a={a}").mkdir()
+        part.drop("a").write_parquet(tmp_path / f"a={a}" / "data.parquet")
+
+    lf = pl.scan_parquet(tmp_path / "**/*.parquet", hive_partitioning=True)
+
+    q = lf.filter(pl.col("a") >= 2).select(pl.len())
+    assert "FAST COUNT(*)" in q.explain()
+    assert q.collect().item() == 4
+
+    # Predicates on the data can't be answered from the metadata.
+    q = lf.filter(pl.col("b") >= 2).select(pl.len())
+    assert "FAST COUNT(*)" not in q.explain()
+    assert q.collect().item() == 4
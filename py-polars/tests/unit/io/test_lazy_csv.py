@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import gzip
 import tempfile
 from collections import OrderedDict
 from pathlib import Path
@@ -35,6 +36,39 @@ def test_scan_empty_csv(io_files_path: Path) -> None:
     assert_frame_equal(lf, pl.LazyFrame())
 
 
+@pytest.mark.parametrize("file", ["gzipped.csv.gz", "zstd_compressed.csv.zst"])
+@pytest.mark.parametrize("streaming", [False, True])
+def test_scan_csv_compressed(io_files_path: Path, file: str, streaming: bool) -> None:
+    expected = pl.read_csv(io_files_path / file, truncate_ragged_lines=True)
+
+    lf = pl.scan_csv(io_files_path / file, truncate_ragged_lines=True)
+    assert_frame_equal(lf.collect(streaming=streaming), expected)
+    assert_frame_equal(lf.head(2).collect(streaming=streaming), expected.head(2))
+
+    lf = pl.scan_csv(
+        io_files_path / file, truncate_ragged_lines=True, row_index_name="idx"
+    )
+    assert_frame_equal(lf.collect(streaming=streaming), expected.with_row_index("idx"))
+
+    # The rows are counted in the decompressed data.
+    lf = pl.scan_csv(io_files_path / file, truncate_ragged_lines=True)
+    assert lf.select(pl.len()).collect().item() == expected.height
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("streaming", [False, True])
+def test_scan_csv_compressed_multiple_files(tmp_path: Path, streaming: bool) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    dfs = [pl.DataFrame({"a": range(i * 10, i * 10 + 10)}) for i in range(5)]
+    for i, df in enumerate(dfs):
+        with gzip.open(tmp_path / f"{i}.csv.gz", "wb") as f:
+            df.write_csv(f)
+
+    lf = pl.scan_csv(tmp_path / "*.csv.gz")
+    assert_frame_equal(lf.collect(streaming=streaming), pl.concat(dfs))
+    assert lf.select(pl.len()).collect().item() == 50
+
+
 @pytest.mark.write_disk()
 def test_invalid_utf8(tmp_path: Path) -> None:
     tmp_path.mkdir(exist_ok=True)
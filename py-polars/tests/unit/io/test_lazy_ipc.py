@@ -110,3 +110,39 @@ def test_scan_ipc_local_with_async(
 
     captured = capfd.readouterr().err
     assert "ASYNC READING FORCED" in captured
+
+
+@pytest.mark.write_disk()
+def test_scan_ipc_memory_mapped_batches(tmp_path: Path) -> None:
+    # Every chunk is written as its own record batch.
+    df = pl.concat(
+        [
+            pl.DataFrame({"a": [i, i + 1], "b": ["x", "y"], "c": [0.5, 1.5]})
+            for i in (0, 2, 4)
+        ],
+        rechunk=False,
+    )
+    path = tmp_path / "batches.ipc"
+    df.write_ipc(path, compression="uncompressed")
+
+    out = (
+        pl.scan_ipc(path, memory_map=True)
+        .filter(pl.col("a") >= 3)
+        .select("b")
+        .collect()
+    )
+    assert out["b"].to_list() == ["y", "x", "y"]
+
+    out = pl.scan_ipc(path, memory_map=True).filter(pl.col("a") > 10).collect()
+    assert out.schema == df.schema
+    assert out.is_empty()
+
+    out = (
+        pl.scan_ipc(path, memory_map=True, row_index_name="i")
+        .filter(pl.col("a") >= 3)
+        .collect()
+    )
+    assert out["i"].to_list() == [3, 4, 5]
+
+    out = pl.scan_ipc(path, memory_map=True).head(3).collect()
+    assert_frame_equal(out, df.head(3))
@@ -37,6 +37,26 @@ def test_scan_parquet_local_with_async(
     pl.scan_parquet(foods_parquet_path.relative_to(Path.cwd())).head(1).collect()
 
 
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("columns", [None, ["a", "c"]])
+def test_scan_parquet_async_prefetch_bytes_limit(
+    monkeypatch: Any, tmp_path: Path, columns: list[str] | None
+) -> None:
+    monkeypatch.setenv("POLARS_FORCE_ASYNC", "1")
+    # Every row group takes more bytes than the limit.
+    monkeypatch.setenv("POLARS_COLUMN_PREFETCH_BYTES", "1")
+    path = tmp_path / "data.parquet"
+
+    df = pl.DataFrame({"a": range(10_000), "b": ["x"] * 10_000, "c": [1.5] * 10_000})
+    df.write_parquet(path, row_group_size=100)
+
+    lf = pl.scan_parquet(path)
+    if columns is not None:
+        lf = lf.select(columns)
+        df = df.select(columns)
+    assert_frame_equal(lf.collect(), df)
+
+
 def test_row_index(foods_parquet_path: Path) -> None:
     df = pl.read_parquet(foods_parquet_path, row_index_name="row_index")
     assert df["row_index"].to_list() == list(range(27))
@@ -437,3 +457,54 @@ def test_scan_deadlock_rayon_spawn_from_async_15172(
     t.join(5)
 
     assert results[0].equals(df)
+
+
+@pytest.mark.write_disk()
+def test_scan_parquet_column_selection(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    df = pl.DataFrame(
+        {
+            "id": ["a", "b"],
+            "feature_1": [1, 2],
+            "feature_2": [1.0, 2.0],
+            "target": [0, 1],
+        }
+    )
+    path = tmp_path / "features.parquet"
+    df.write_parquet(path)
+
+    lf = pl.scan_parquet(path, columns_matching="^feature_")
+    assert lf.collect_schema().names() == ["feature_1", "feature_2"]
+    assert_frame_equal(lf.collect(), df.select("feature_1", "feature_2"))
+
+    lf = pl.scan_parquet(path, dtypes=[pl.Int64], row_index_name="index")
+    assert lf.collect().columns == ["index", "feature_1", "target"]
+
+    lf = pl.scan_parquet(path, columns_matching="^feature_", dtypes=[pl.Float64])
+    assert_frame_equal(lf.select(pl.all()).collect(), df.select("feature_2"))
+
+
+@pytest.mark.write_disk()
+def test_lazy_snapshot_refresh(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    snapshot = tmp_path / "snapshot"
+
+    def query() -> pl.LazyFrame:
+        return (
+            pl.scan_parquet(tmp_path / "*.parquet")
+            .group_by("a", maintain_order=True)
+            .agg(pl.col("b").sum(), pl.col("b").max().alias("max"))
+        )
+
+    pl.DataFrame({"a": ["x", "y", "x"], "b": [1, 2, 3]}).write_parquet(
+        tmp_path / "0.parquet"
+    )
+    assert_frame_equal(query().snapshot(snapshot), query().collect())
+
+    pl.DataFrame({"a": ["y", "z"], "b": [4, 5]}).write_parquet(tmp_path / "1.parquet")
+    expected = pl.DataFrame({"a": ["x", "y", "z"], "b": [4, 6, 5], "max": [3, 4, 5]})
+    assert_frame_equal(query().refresh(snapshot), expected)
+
+    other = pl.scan_parquet(tmp_path / "*.parquet").select(pl.col("b").sum())
+    with pytest.raises(pl.InvalidOperationError, match="doesn't match the snapshot"):
+        other.refresh(snapshot)
@@ -604,6 +604,26 @@ def test_enum_parquet(tmp_path: Path) -> None:
     assert_frame_equal(df, out)
 
 
+def test_parquet_categorical_dtype_roundtrip() -> None:
+    enum_dtype = pl.Enum(["c", "b", "a", "unused"])
+    values = ["a", None, "c", "a", "b"]
+    df = pl.DataFrame(
+        [
+            pl.Series("enum", values, dtype=enum_dtype),
+            pl.Series("cat", values, dtype=pl.Categorical(ordering="lexical")),
+        ]
+    )
+
+    f = io.BytesIO()
+    df.write_parquet(f, row_group_size=2)
+    f.seek(0)
+    out = pl.read_parquet(f)
+
+    assert out.schema == {"enum": enum_dtype, "cat": pl.Categorical("lexical")}
+    assert_frame_equal(df, out, categorical_as_str=True)
+    assert out["cat"].cat.get_categories().to_list() == ["a", "c", "b"]
+
+
 def test_parquet_rle_non_nullable_12814() -> None:
     column = (
         pl.select(x=pl.arange(0, 1025, dtype=pl.Int64) // 10).to_series().to_arrow()
@@ -1141,3 +1161,56 @@ def test_parquet_statistics_uint64_16683() -> None:
 
     assert statistics.min == 0
     assert statistics.max == u64_max
+
+
+def test_parquet_sorting_columns_roundtrip() -> None:
+    df = pl.DataFrame({"a": [3, 1, 2], "b": ["x", "y", "z"]}).sort("a")
+
+    f = io.BytesIO()
+    df.write_parquet(f)
+    f.seek(0)
+    sorting_columns = pq.read_metadata(f).row_group(0).sorting_columns
+    assert [c.column_index for c in sorting_columns] == [0]
+
+    f.seek(0)
+    out = pl.read_parquet(f)
+    assert_frame_equal(out, df)
+    assert out["a"].flags["SORTED_ASC"]
+    assert not out["b"].flags["SORTED_ASC"]
+
+
+def test_parquet_sorting_columns_not_trusted() -> None:
+    table = pa.table({"a": [3, 1, 2]})
+    f = io.BytesIO()
+    pq.write_table(table, f, sorting_columns=[pq.SortingColumn(0)])
+
+    f.seek(0)
+    out = pl.read_parquet(f)
+    assert out["a"].to_list() == [3, 1, 2]
+    assert not out["a"].flags["SORTED_ASC"]
+    assert out.sort("a")["a"].to_list() == [1, 2, 3]
+
+
+@pytest.mark.write_disk()
+def test_parquet_page_index_skipping(tmp_path: Path) -> None:
+    n = 10_000
+    df = pl.DataFrame(
+        {
+            "a": pl.arange(0, n, eager=True),
+            "b": pl.Series([None, "x", "yy"] * (n // 3) + [None]),
+            "c": pl.Series([1.5, None] * (n // 2)),
+        }
+    )
+    file_path = tmp_path / "polars.parquet"
+    df.write_parquet(file_path, statistics=True, data_page_size=1024)
+    pq_path = tmp_path / "pyarrow.parquet"
+    pq.write_table(df.to_arrow(), pq_path, data_page_size=1024, write_page_index=True)
+
+    for path in [file_path, pq_path]:
+        predicate = pl.col("a").is_between(4_990, 5_020)
+        result = pl.scan_parquet(path, row_index_name="idx").filter(predicate)
+        expected = df.with_row_index("idx").filter(predicate)
+        assert_frame_equal(result.collect(), expected)
+
+        result = pl.scan_parquet(path, n_rows=3_000).filter(pl.col("a") > 2_990)
+        assert_frame_equal(result.collect(), df.head(3_000).filter(pl.col("a") > 2_990))
@@ -0,0 +1,61 @@
+from __future__ import annotations
+
+from typing import Any
+
+import pytest
+
+import polars as pl
+from polars.io.plugins import register_io_source
+from polars.testing import assert_frame_equal
+
+SOURCE = pl.DataFrame({"a": [1, 2, 3, 4], "b": ["w", "x", "y", "z"], "c": [1.0] * 4})
+SCHEMA = dict(SOURCE.schema)
+
+
+def make_source(calls: list[tuple[Any, ...]], applied: list[str] | None) -> Any:
+    def source(
+        with_columns: list[str] | None, predicate: pl.Expr | None, n_rows: int | None
+    ) -> Any:
+        calls.append((with_columns, predicate, n_rows))
+        if applied is None:
+            return SOURCE
+        df = SOURCE
+        if "predicate" in applied and predicate is not None:
+            df = df.filter(predicate)
+        if "n_rows" in applied and n_rows is not None:
+            df = df.head(n_rows)
+        if with_columns is not None:
+            df = df.select(with_columns)
+        return df, applied
+
+    return source
+
+
+@pytest.mark.parametrize("applied", [None, [], ["predicate"], ["predicate", "n_rows"]])
+def test_io_source_predicate_pushdown(applied: list[str] | None) -> None:
+    calls: list[tuple[Any, ...]] = []
+    lf = register_io_source(make_source(calls, applied), schema=SCHEMA)
+
+    out = lf.filter(pl.col("a") > 1).select("b").collect()
+    assert_frame_equal(out, pl.DataFrame({"b": ["x", "y", "z"]}))
+
+    ((with_columns, predicate, n_rows),) = calls
+    assert sorted(with_columns) == ["a", "b"]
+    assert isinstance(predicate, pl.Expr)
+    assert_frame_equal(SOURCE.filter(predicate), SOURCE.filter(pl.col("a") > 1))
+    assert n_rows is None
+
+
+@pytest.mark.parametrize("applied", [None, ["n_rows"]])
+def test_io_source_slice_pushdown(applied: list[str] | None) -> None:
+    calls: list[tuple[Any, ...]] = []
+    lf = register_io_source(make_source(calls, applied), schema=SCHEMA)
+
+    assert_frame_equal(lf.head(2).collect(), SOURCE.head(2))
+    assert calls == [(None, None, 2)]
+
+
+def test_io_source_unknown_applied() -> None:
+    lf = register_io_source(lambda *_: (SOURCE, ["projection"]), schema=SCHEMA)
+    with pytest.raises(pl.ComputeError, match="unknown applied parts"):
+        lf.collect()
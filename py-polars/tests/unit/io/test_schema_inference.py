@@ -0,0 +1,98 @@
+from __future__ import annotations
+
+import io
+from datetime import date
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+CSV = b"""\
+id,flag,score,day,name
+1,true,0.5,2024-01-01,a
+2,FALSE,n/a,2024-01-02,b
+3,true,1.5,,c
+4,,2,2024-01-04,d
+"""
+
+
+def test_infer_schema_csv() -> None:
+    inference = pl.infer_schema(CSV, format="csv")
+    assert inference.schema_overrides == {
+        "id": pl.Int64,
+        "flag": pl.Boolean,
+        "score": pl.String,
+        "day": pl.Date,
+        "name": pl.String,
+    }
+
+    selected = inference.report.filter("selected")
+    assert selected["column"].to_list() == ["id", "flag", "score", "day", "name"]
+    assert (selected["confidence"] == 1.0).all()
+
+    score = inference.report.filter(pl.col("column") == "score", ~pl.col("selected"))
+    assert score.rows() == [
+        ("score", "Float64", 0.75, False, ["n/a"]),
+        ("score", "Int64", 0.25, False, ["0.5", "n/a", "1.5"]),
+    ]
+
+    # The overrides can be passed to the reader as is.
+    df = pl.read_csv(CSV, schema_overrides=inference.schema_overrides)
+    assert df["day"].to_list() == [
+        date(2024, 1, 1),
+        date(2024, 1, 2),
+        None,
+        date(2024, 1, 4),
+    ]
+
+
+def test_infer_schema_sample_rows_and_strategies() -> None:
+    inference = pl.infer_schema(CSV, format="csv", sample_rows=1)
+    assert inference.schema_overrides["score"] == pl.Float64
+
+    inference = pl.infer_schema(CSV, format="csv", strategies=["integer"])
+    assert inference.schema_overrides == {
+        "id": pl.Int64,
+        "flag": pl.String,
+        "score": pl.String,
+        "day": pl.String,
+        "name": pl.String,
+    }
+
+    with pytest.raises(ValueError, match="unknown schema inference strategies"):
+        pl.infer_schema(CSV, format="csv", strategies=["complex"])  # type: ignore[list-item]
+
+
+def test_infer_schema_ndjson(tmp_path: Path) -> None:
+    path = tmp_path / "data.ndjson"
+    path.write_text(
+        '{"a": 1, "b": "2024-01-01T10:00:00"}\n{"a": 2, "b": "2024-01-02T11:30:00"}\n'
+    )
+    inference = pl.infer_schema(path)
+    assert inference.schema_overrides == {"a": pl.Int64, "b": pl.Datetime("us")}
+
+    from_bytes = pl.infer_schema(io.BytesIO(path.read_bytes()), format="ndjson")
+    assert_frame_equal(from_bytes.report, inference.report)
+
+
+def test_infer_schema_reads_sample() -> None:
+    # The invalid line after the sample is never parsed.
+    source = b'{"a": "1"}\n{"a": "2"}\nnot json\n'
+    inference = pl.infer_schema(source, format="ndjson", sample_rows=2)
+    assert inference.schema_overrides == {"a": pl.Int64}
+
+
+def test_infer_schema_json() -> None:
+    source = b'[{"a": "x", "b": "1"}, {"a": "y", "b": null}]'
+    inference = pl.infer_schema(source, format="json")
+    assert inference.schema_overrides == {"a": pl.String, "b": pl.Int64}
+
+
+def test_infer_schema_unknown_format() -> None:
+    with pytest.raises(ValueError, match="cannot infer the format"):
+        pl.infer_schema(CSV)
@@ -650,6 +650,43 @@ def test_excel_round_trip(write_params: dict[str, Any]) -> None:
         assert_frame_equal(df, xldf)
 
 
+def test_excel_write_rust_engine() -> None:
+    df = pl.DataFrame(
+        {
+            "dtm": [date(2023, 1, 1), date(2023, 1, 2), None],
+            "str": ["xxx", None, "xxx"],
+            "val": [100.5, 55.0, -99.5],
+        }
+    )
+    xls = BytesIO()
+    assert (
+        df.write_excel(
+            xls,
+            worksheet="data",
+            column_formats={"val": "0.00"},
+            autofit=True,
+            engine="rust",
+        )
+        is None
+    )
+
+    xldf = pl.read_excel(xls, sheet_name="data", engine="calamine")
+    assert_frame_equal(df, xldf)
+
+    xls = BytesIO()
+    df.write_excel(xls, freeze_panes="A2", autofilter=False, engine="rust")
+    assert_frame_equal(df, pl.read_excel(xls, engine="calamine"))
+
+    with pytest.raises(ValueError, match="does not support 'freeze_panes'"):
+        df.write_excel(BytesIO(), freeze_panes=(2, 1), engine="rust")
+    with pytest.raises(ValueError, match="does not support 'table_style'"):
+        df.write_excel(BytesIO(), table_style="Table Style Light 2", engine="rust")
+    with pytest.raises(TypeError, match="column names to format strings"):
+        df.write_excel(BytesIO(), column_formats={("val",): "0.00"}, engine="rust")
+    with pytest.raises(pl.ColumnNotFoundError):
+        df.write_excel(BytesIO(), column_formats={"x": "0.00"}, engine="rust")
+
+
 @pytest.mark.parametrize("engine", ["xlsx2csv", "openpyxl", "calamine"])
 def test_excel_compound_types(
     engine: ExcelSpreadsheetEngine,
@@ -1390,3 +1390,28 @@ def test_lf_properties() -> None:
         assert lf.dtypes == [pl.Int64, pl.Float64, pl.String]
     with pytest.warns(PerformanceWarning):
         assert lf.width == 3
+
+
+def test_with_resources() -> None:
+    lf = pl.LazyFrame({"a": [3, 1, 2]})
+    pool_sizes = []
+
+    def record_pool_size(df: pl.DataFrame) -> pl.DataFrame:
+        pool_sizes.append(pl.thread_pool_size())
+        return df
+
+    # The limits carry over to the operations that follow.
+    out = (
+        lf.with_resources(max_threads=1, memory_soft_limit=1 << 20)
+        .map_batches(record_pool_size)
+        .sort("a")
+        .collect()
+    )
+    assert out["a"].to_list() == [1, 2, 3]
+    assert pool_sizes == [1]
+
+    out = lf.with_resources(memory_soft_limit=1).sort("a").collect(streaming=True)
+    assert out["a"].to_list() == [1, 2, 3]
+
+    with pytest.raises(pl.InvalidOperationError, match="max_threads"):
+        lf.with_resources(max_threads=0).collect()
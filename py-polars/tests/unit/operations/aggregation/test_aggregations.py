@@ -112,6 +112,106 @@ def test_quantile() -> None:
     assert s.quantile(0.5, "higher") == 2
 
 
+def test_approx_quantile() -> None:
+    df = pl.DataFrame({"a": [0, 1, None, 2, 3, float("nan"), 4, 5]})
+    assert df.select(pl.col("a").approx_quantile(0.5)).item() == pytest.approx(
+        2.0, rel=0.01
+    )
+    assert df.select(pl.col("a").approx_quantile(0.0)).item() == 0.0
+    assert df.select(pl.col("a").approx_quantile(1.0)).item() == pytest.approx(
+        5.0, rel=0.01
+    )
+
+    with pytest.raises(pl.ComputeError, match="should be between 0.0 and 1.0"):
+        df.select(pl.col("a").approx_quantile(1.5))
+    with pytest.raises(pl.ComputeError, match="`relative_accuracy`"):
+        df.select(pl.col("a").approx_quantile(0.5, relative_accuracy=0.0))
+
+
+@pytest.mark.parametrize("streaming", [False, True])
+def test_approx_quantile_group_by_error_bound(streaming: bool) -> None:
+    n = 20_000
+    relative_accuracy = 0.02
+    df = pl.DataFrame(
+        {
+            "g": np.arange(n) % 2,
+            "x": np.random.default_rng(0).permutation(n).astype(np.float32),
+        }
+    )
+    qs = [0.01, 0.5, 0.99]
+    out = (
+        df.lazy()
+        .group_by("g")
+        .agg(
+            [
+                pl.col("x")
+                .approx_quantile(q, relative_accuracy=relative_accuracy)
+                .alias(str(q))
+                for q in qs
+            ]
+        )
+        .sort("g")
+        .collect(streaming=streaming)
+    )
+    assert out.schema[str(qs[0])] == pl.Float32
+
+    for g, group in df.partition_by("g", as_dict=True).items():
+        for q in qs:
+            estimate = out.filter(pl.col("g") == g[0])[str(q)].item()
+            exact = group["x"].quantile(q, "lower")
+            assert abs(estimate - exact) <= relative_accuracy * abs(exact) + 1e-3
+
+
+@pytest.mark.parametrize("streaming", [False, True])
+@pytest.mark.parametrize(
+    ("values", "dtype"),
+    [
+        ([2, 1, None, 2, 1, None, 3, 4, 4], pl.Int64),
+        ([2.0, 1.0, None, 2.0, 1.0, None, 3.0, np.nan, np.nan], pl.Float64),
+        (["b", "a", None, "b", "a", None, "c", "d", "d"], pl.String),
+    ],
+)
+def test_mode_group_by_keep(
+    values: list[Any], dtype: PolarsDataType, streaming: bool
+) -> None:
+    df = pl.DataFrame(
+        {"g": [1, 1, 1, 1, 1, 2, 2, 3, 3], "x": pl.Series(values, dtype=dtype)}
+    )
+    out = (
+        df.lazy()
+        .group_by("g")
+        .agg(
+            pl.col("x").mode(keep="all").alias("all"),
+            pl.col("x").mode(keep="first").alias("first"),
+            pl.col("x").mode(keep="last").alias("last"),
+        )
+        .sort("g")
+        .collect(streaming=streaming)
+    )
+    # Ties are broken by the first occurrence, or by the last one.
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2, 3],
+            "all": pl.Series([values[:2], [None, values[6]], values[7:8]]),
+            "first": pl.Series([values[0], None, values[7]], dtype=dtype),
+            "last": pl.Series([values[1], values[6], values[7]], dtype=dtype),
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_mode_keep_select() -> None:
+    df = pl.DataFrame({"a": [3, 1, 1, 3, 2]})
+    assert df.select(pl.col("a").mode(keep="all")).item().to_list() == [3, 1]
+    assert df.select(pl.col("a").mode(keep="last")).item() == 1
+
+    empty = pl.DataFrame({"a": []}, schema={"a": pl.Int64})
+    assert empty.select(pl.col("a").mode(keep="first")).item() is None
+
+    with pytest.raises(ValueError, match="`keep` must be one of"):
+        pl.col("a").mode(keep="middle")  # type: ignore[arg-type]
+
+
 @pytest.mark.slow()
 @pytest.mark.parametrize("tp", [int, float])
 @pytest.mark.parametrize("n", [1, 2, 10, 100])
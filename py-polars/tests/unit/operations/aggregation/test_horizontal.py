@@ -106,6 +106,44 @@ def test_max_min_nulls_consistency() -> None:
     assert_series_equal(result, expected)
 
 
+def test_max_min_temporal_and_string() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [datetime.date(2020, 1, 1), None, datetime.date(2022, 1, 1), None],
+            "b": [datetime.date(2021, 1, 1), datetime.date(2019, 1, 1), None, None],
+            "c": ["foo", None, "bar", None],
+            "d": ["baz", "ham", None, None],
+            "e": [None, None, None, None],
+        }
+    )
+
+    result = df.select(
+        max_date=pl.max_horizontal("a", "b", "e"),
+        min_date=pl.min_horizontal("a", "b", "e"),
+        max_str=pl.max_horizontal("c", "d"),
+        min_str=pl.min_horizontal("e", "c", "d"),
+    )
+    expected = pl.DataFrame(
+        {
+            "max_date": [
+                datetime.date(2021, 1, 1),
+                datetime.date(2019, 1, 1),
+                datetime.date(2022, 1, 1),
+                None,
+            ],
+            "min_date": [
+                datetime.date(2020, 1, 1),
+                datetime.date(2019, 1, 1),
+                datetime.date(2022, 1, 1),
+                None,
+            ],
+            "max_str": ["foo", "ham", "bar", None],
+            "min_str": ["baz", "ham", "bar", None],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
 def test_nested_min_max() -> None:
     df = pl.DataFrame({"a": [1], "b": [2], "c": [3], "d": [4]})
 
@@ -64,6 +64,34 @@ def test_map_elements_struct() -> None:
     assert_frame_equal(out, expected)
 
 
+def test_map_elements_struct_return_dtype() -> None:
+    dtype = pl.Struct({"a": pl.Int32, "b": pl.String})
+    s = pl.Series("x", [1, None, 3])
+
+    out = s.map_elements(lambda x: {"b": str(x), "a": x * 2}, return_dtype=dtype)
+    expected = pl.Series(
+        "x", [{"a": 2, "b": "1"}, {"a": None, "b": None}, {"a": 6, "b": "3"}], dtype
+    )
+    assert_series_equal(out, expected)
+
+    # Tuples are matched to the fields by position and missing keys are null.
+    out = s.map_elements(lambda x: (x * 2, str(x)), return_dtype=dtype)
+    assert_series_equal(out, expected)
+    out = s.map_elements(lambda x: {"a": x}, return_dtype=dtype)
+    assert out.struct.field("b").to_list() == [None, None, None]
+
+    df = pl.DataFrame({"x": [1, 2]})
+    out = df.select(
+        pl.col("x").map_elements(lambda x: (x, str(x)), return_dtype=dtype)
+    ).unnest("x")
+    assert_frame_equal(
+        out, pl.DataFrame({"a": [1, 2], "b": ["1", "2"]}, schema=dtype.to_schema())
+    )
+
+    with pytest.raises(pl.ComputeError, match="expected 2 values"):
+        s.map_elements(lambda x: (x,), return_dtype=dtype)
+
+
 def test_map_elements_numpy_int_out() -> None:
     df = pl.DataFrame({"col1": [2, 4, 8, 16]})
     result = df.with_columns(
@@ -147,3 +147,64 @@ def test_map_groups_numpy_output_3057() -> None:
 
     expected = pl.DataFrame({"id": [0, 1], "result": [1.955, 13.0]})
     assert_frame_equal(result, expected)
+
+
+def test_map_groups_flat() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "b", "a", "b", "b", "c"],
+            "x": [1, 2, 3, 4, 5, 6],
+            "y": [6, 5, 4, 3, 2, 1],
+        }
+    )
+    n_calls = 0
+
+    def dot(inputs: Sequence[pl.Series], groups: pl.Series) -> pl.Series:
+        nonlocal n_calls
+        n_calls += 1
+        x, y = inputs
+        return pl.Series([(x.gather(idx) * y.gather(idx)).sum() for idx in groups])
+
+    result = df.group_by("g", maintain_order=True).agg(
+        pl.map_groups_flat(["x", "y"], dot).alias("dot")
+    )
+
+    expected = pl.DataFrame({"g": ["a", "b", "c"], "dot": [18, 32, 6]})
+    assert_frame_equal(result, expected)
+    assert n_calls == 1
+
+
+def test_map_groups_flat_slice_groups() -> None:
+    df = pl.DataFrame({"t": [0, 1, 2, 3, 4, 5], "x": [1, 2, 3, 4, 5, 6]})
+
+    def first_of_group(inputs: Sequence[pl.Series], groups: pl.Series) -> pl.Series:
+        return inputs[0].gather(groups.list.first())
+
+    result = (
+        df.set_sorted("t")
+        .group_by_dynamic("t", every="2i")
+        .agg(pl.map_groups_flat("x", first_of_group).alias("x"))
+    )
+
+    expected = pl.DataFrame({"t": [0, 2, 4], "x": [1, 3, 5]})
+    assert_frame_equal(result, expected)
+
+
+def test_map_groups_flat_select() -> None:
+    df = pl.DataFrame({"x": [1, 2, 3]})
+
+    result = df.select(
+        pl.map_groups_flat("x", lambda inputs, groups: pl.Series([len(groups[0])]))
+        .alias("n")
+    )
+
+    assert result.to_dict(as_series=False) == {"n": [3]}
+
+
+def test_map_groups_flat_must_return_value_per_group() -> None:
+    df = pl.DataFrame({"g": [1, 1, 2], "x": [1, 2, 3]})
+
+    with pytest.raises(pl.ComputeError, match="one value per group"):
+        df.group_by("g").agg(
+            pl.map_groups_flat("x", lambda inputs, groups: pl.Series([1, 2, 3]))
+        )
@@ -449,3 +449,31 @@ def test_array_n_unique() -> None:
         {"n_unique": [2, 1, 1, None]}, schema={"n_unique": pl.UInt32}
     )
     assert_frame_equal(out, expected)
+
+
+def test_array_slice() -> None:
+    df = pl.DataFrame(
+        {"a": [[1, 2, 3], None, [4, None, 6]]}, schema={"a": pl.Array(pl.Int64, 3)}
+    )
+
+    out = df.select(
+        slice=pl.col("a").arr.slice(1, 5),
+        head=pl.col("a").arr.head(2),
+        tail=pl.col("a").arr.tail(1),
+    )
+    expected = pl.DataFrame(
+        {
+            "slice": [[2, 3], None, [None, 6]],
+            "head": [[1, 2], None, [4, None]],
+            "tail": [[3], None, [6]],
+        },
+        schema={
+            "slice": pl.Array(pl.Int64, 2),
+            "head": pl.Array(pl.Int64, 2),
+            "tail": pl.Array(pl.Int64, 1),
+        },
+    )
+    assert_frame_equal(out, expected)
+
+    with pytest.raises(ComputeError, match="width 0"):
+        df.select(pl.col("a").arr.slice(3, 1))
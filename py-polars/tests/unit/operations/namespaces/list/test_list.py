@@ -913,3 +913,102 @@ def test_list_eval_type_cast_11188() -> None:
     assert df.select(
         pl.col("a").list.eval(pl.element().cast(pl.String)).alias("a_str")
     ).schema == {"a_str": pl.List(pl.String)}
+
+
+def test_list_zip_with() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 2], None, [3], []],
+            "b": [["x"], ["y"], ["z", "w"], []],
+        }
+    )
+
+    with pytest.raises(pl.ShapeError, match="different lengths"):
+        df.select(pl.col("a").list.zip_with("b"))
+
+    out = df.select(pl.col("a").list.zip_with("b", length="truncate"))
+    assert out.schema == {"a": pl.List(pl.Struct({"a": pl.Int64, "b": pl.String}))}
+    assert out["a"].to_list() == [[{"a": 1, "b": "x"}], None, [{"a": 3, "b": "z"}], []]
+
+    out = df.select(pl.col("a").list.zip_with(pl.col("b"), length="pad"))
+    assert out["a"].to_list() == [
+        [{"a": 1, "b": "x"}, {"a": 2, "b": None}],
+        None,
+        [{"a": 3, "b": "z"}, {"a": None, "b": "w"}],
+        [],
+    ]
+
+
+def test_list_zip_with_broadcast() -> None:
+    s = pl.Series("a", [[1, 2], [3, 4]])
+    out = s.list.zip_with(pl.Series("b", [[True, False]]))
+    assert out.to_list() == [
+        [{"a": 1, "b": True}, {"a": 2, "b": False}],
+        [{"a": 3, "b": True}, {"a": 4, "b": False}],
+    ]
+
+    with pytest.raises(pl.DuplicateError):
+        s.list.zip_with(s)
+
+
+def test_list_enumerate() -> None:
+    df = pl.DataFrame({"a": [["x", "y"], None, [], ["z"]]})
+
+    out = df.select(pl.col("a").list.enumerate())
+    assert out.schema == {
+        "a": pl.List(pl.Struct({"index": pl.UInt32, "value": pl.String}))
+    }
+    assert out["a"].to_list() == [
+        [{"index": 0, "value": "x"}, {"index": 1, "value": "y"}],
+        None,
+        [],
+        [{"index": 0, "value": "z"}],
+    ]
+
+    # The lists of a slice don't start at the first value.
+    out = df["a"].slice(3).list.enumerate()
+    assert out.to_list() == [[{"index": 0, "value": "z"}]]
+
+
+def test_list_rolling() -> None:
+    s = pl.Series("a", [[1, 2, None, 4], None, [], [5, 6, 7]], dtype=pl.List(pl.Int8))
+
+    out = s.list.rolling_sum(2)
+    expected = pl.Series(
+        "a", [[None, 3, None, None], None, [], [None, 11, 13]], dtype=pl.List(pl.Int64)
+    )
+    assert_series_equal(out, expected)
+
+    out = s.list.rolling_sum(2, min_periods=1)
+    expected = pl.Series(
+        "a", [[1, 3, 2, 4], None, [], [5, 11, 13]], dtype=pl.List(pl.Int64)
+    )
+    assert_series_equal(out, expected)
+
+    out = s.to_frame().select(pl.col("a").list.rolling_mean(3, min_periods=2))
+    expected_s = pl.Series("a", [[None, 1.5, 1.5, 3.0], None, [], [None, 5.5, 6.0]])
+    assert_series_equal(out.to_series(), expected_s)
+
+    # the result is the same for a sliced input
+    assert_series_equal(s[3:].list.rolling_sum(2), s.list.rolling_sum(2)[3:])
+
+    # non-finite values only affect the windows that contain them
+    s = pl.Series("a", [[1.0, float("inf"), 2.0, 3.0, float("nan"), 4.0, 5.0]])
+    out = s.list.rolling_sum(2)
+    expected = pl.Series(
+        "a", [[None, float("inf"), float("inf"), 5.0, float("nan"), float("nan"), 9.0]]
+    )
+    assert_series_equal(out, expected)
+
+    # integers are summed without a loss of precision
+    s = pl.Series("a", [[2**53, 1, 1]], dtype=pl.List(pl.Int64))
+    out = s.list.rolling_sum(2, min_periods=1)
+    expected = pl.Series("a", [[2**53, 2**53 + 1, 2]], dtype=pl.List(pl.Int64))
+    assert_series_equal(out, expected)
+    s = pl.Series("a", [[2**64 - 1, 0]], dtype=pl.List(pl.UInt64))
+    assert s.list.rolling_sum(1).to_list() == [[2**64 - 1, 0]]
+
+    with pytest.raises(pl.InvalidOperationError):
+        pl.Series([["a"]]).list.rolling_sum(2)
+    with pytest.raises(pl.InvalidOperationError):
+        s.list.rolling_sum(2, min_periods=3)
@@ -1395,6 +1395,30 @@ def test_splitn_expr() -> None:
     assert_frame_equal(out, expected)
 
 
+def test_splitn_fill() -> None:
+    df = pl.DataFrame({"x": ["a_a", None, "b", "c_c_c"]})
+
+    out = df.select(pl.col("x").str.splitn("_", pl.lit(2), fill="empty")).unnest("x")
+    expected = pl.DataFrame(
+        {
+            "field_0": ["a", None, "b", "c"],
+            "field_1": ["a", None, "", "c_c"],
+        }
+    )
+    assert_frame_equal(out, expected)
+    assert_frame_equal(
+        df["x"].str.splitn("_", 2, fill="empty").to_frame().unnest("x"), expected
+    )
+
+    with pytest.raises(pl.ComputeError, match="split into 2 parts"):
+        df.select(pl.col("x").str.splitn("_", 2, fill="error"))
+    assert df.select(pl.col("x").str.splitn("_", 1, fill="error")).height == 4
+
+    with pytest.raises(ValueError, match="integer literal"):
+        df.select(pl.col("x").str.splitn("_", pl.col("x")))
+    with pytest.raises(ValueError, match="`fill` must be one of"):
+        df.select(pl.col("x").str.splitn("_", 2, fill="zero"))  # type: ignore[arg-type]
+
 def test_titlecase() -> None:
     df = pl.DataFrame(
         {
@@ -1486,3 +1510,75 @@ def test_replace_lit_n_char_13385(
     res = s.str.replace("a", "b", literal=True)
     expected_s = pl.Series(expected_dat, dtype=pl.String)
     assert_series_equal(res, expected_s)
+
+
+def test_string_checksum() -> None:
+    s = pl.Series(
+        "id",
+        [
+            "4111 1111 1111 1111",
+            "4111-1111-1111-1112",
+            "GB82 WEST 1234 5698 7654 32",
+            None,
+        ],
+    )
+    assert s.str.checksum("luhn").to_list() == [True, False, False, None]
+    assert s.str.checksum("iban").to_list() == [False, False, True, None]
+    assert s.str.checksum("mod97").to_list() == [False, False, False, None]
+
+    isbn = pl.DataFrame({"isbn": ["0-306-40615-2", "0-306-40615-3", "0306406152x"]})
+    out = isbn.select(
+        pl.col("isbn").str.checksum("weighted", weights=range(10, 0, -1), modulus=11)
+    )
+    assert out.to_series().to_list() == [True, False, False]
+
+    with pytest.raises(ValueError, match="required for the 'weighted' method"):
+        s.str.checksum("weighted")
+    with pytest.raises(ValueError, match="only be set for the 'weighted' method"):
+        s.str.checksum("luhn", modulus=10)
+    with pytest.raises(ValueError, match="`method` must be one of"):
+        s.str.checksum("crc")  # type: ignore[arg-type]
+
+
+def test_string_normalize() -> None:
+    # Precomposed and decomposed "é", and the "fi" ligature.
+    s = pl.Series(["caf\u00e9", "cafe\u0301", "\ufb01ne", None])
+    nfc = ["caf\u00e9", "caf\u00e9", "\ufb01ne", None]
+    assert s.str.normalize().to_list() == nfc
+    assert s.str.normalize("NFC").to_list() == nfc
+    assert s.str.normalize("NFD").to_list() == [
+        "cafe\u0301",
+        "cafe\u0301",
+        "\ufb01ne",
+        None,
+    ]
+    nfkc = ["caf\u00e9", "caf\u00e9", "fine", None]
+    assert s.str.normalize("NFKC").to_list() == nfkc
+
+    df = pl.DataFrame({"a": s})
+    out = df.select(pl.col("a").str.normalize("NFKD").str.len_chars())
+    assert out.to_series().to_list() == [5, 5, 4, None]
+
+    with pytest.raises(ValueError, match="`form` must be one of"):
+        s.str.normalize("NFX")  # type: ignore[arg-type]
+
+
+def test_string_casefold() -> None:
+    s = pl.Series(
+        ["Stra\u00dfe", "STRASSE", "\u03a3\u0391\u03a3", "\ufb01le", None]
+    )
+    assert s.str.casefold().to_list() == [
+        "strasse",
+        "strasse",
+        "\u03c3\u03b1\u03c3",
+        "file",
+        None,
+    ]
+    # Lowercasing keeps the differences.
+    assert s.str.to_lowercase().to_list() == [
+        "stra\u00dfe",
+        "strasse",
+        "\u03c3\u03b1\u03c2",
+        "\ufb01le",
+        None,
+    ]
@@ -996,6 +996,89 @@ def test_weekday(time_unit: TimeUnit) -> None:
     assert friday.cast(pl.Date).dt.weekday()[0] == 5
 
 
+def test_iso_year_week_day_roundtrip() -> None:
+    dates = pl.Series(
+        "date",
+        [date(2020, 12, 31), date(2021, 1, 3), date(2021, 1, 4), None],
+    )
+    df = dates.to_frame().select(pl.col("date").dt.iso_year_week_day()).unnest("date")
+    expected = pl.DataFrame(
+        {
+            "iso_year": [2020, 2020, 2021, None],
+            "iso_week": [53, 53, 1, None],
+            "weekday": [4, 7, 1, None],
+        },
+        schema={"iso_year": pl.Int32, "iso_week": pl.Int8, "weekday": pl.Int8},
+    )
+    assert_frame_equal(df, expected)
+
+    result = df.select(pl.date_from_isoweek("iso_year", "iso_week", "weekday"))
+    assert_series_equal(result.to_series(), dates)
+
+    datetimes = pl.Series([datetime(2021, 1, 3, 23, 59)])
+    assert datetimes.dt.iso_year_week_day().to_list() == [
+        {"iso_year": 2020, "iso_week": 53, "weekday": 7}
+    ]
+
+
+def test_date_from_isoweek_invalid() -> None:
+    df = pl.DataFrame(
+        {
+            "iso_year": [2021, 2020, 2021, 2021],
+            "iso_week": [53, 53, 0, 1],
+            "weekday": [1, 1, 1, 8],
+        }
+    )
+    result = df.select(pl.date_from_isoweek("iso_year", "iso_week", "weekday"))
+    expected = pl.Series("date", [None, date(2020, 12, 28), None, None])
+    assert_series_equal(result.to_series(), expected)
+
+    # Literals are broadcast.
+    result = df.select(pl.date_from_isoweek(2021, "iso_week", 1))
+    assert result.to_series().to_list() == [None, None, None, date(2021, 1, 4)]
+
+
+@pytest.mark.parametrize("time_unit", ["ms", "us", "ns"])
+def test_iso8601_duration_roundtrip(time_unit: TimeUnit) -> None:
+    s = pl.Series(
+        "duration",
+        [
+            timedelta(0),
+            timedelta(days=1, hours=2, minutes=30),
+            timedelta(seconds=90, milliseconds=500),
+            timedelta(days=-1, seconds=-7),
+            None,
+        ],
+    ).dt.cast_time_unit(time_unit)
+
+    iso = s.dt.to_iso8601_duration()
+    assert iso.to_list() == ["PT0S", "P1DT2H30M", "PT1M30.5S", "-P1DT7S", None]
+
+    result = iso.to_frame().select(pl.duration_from_string("duration", time_unit))
+    assert_series_equal(result.to_series(), s)
+
+
+def test_duration_from_string() -> None:
+    df = pl.DataFrame({"iso": ["P2W", "PT1,25S", "+PT0.0019S", "P1Y", "PT"]})
+
+    with pytest.raises(InvalidOperationError):
+        df.select(pl.duration_from_string("iso"))
+
+    result = df.select(pl.duration_from_string("iso", "ms", strict=False))
+    expected = pl.Series(
+        "iso",
+        [
+            timedelta(weeks=2),
+            timedelta(seconds=1.25),
+            timedelta(milliseconds=1),
+            None,
+            None,
+        ],
+        dtype=pl.Duration("ms"),
+    )
+    assert_series_equal(result.to_series(), expected)
+
+
 @pytest.mark.parametrize(
     ("values", "expected_median"),
     [
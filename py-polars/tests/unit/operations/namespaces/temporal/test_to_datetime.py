@@ -186,3 +186,66 @@ def test_to_datetime_aware_values_aware_dtype() -> None:
         dtype=pl.Datetime("us", "Asia/Kathmandu"),
     )
     assert_series_equal(result, expected)
+
+
+def test_to_datetime_multiple_formats() -> None:
+    s = pl.Series(
+        ["2020-01-01 01:00", "02/01/2020 03:00", None, "2020-01-03 04:00", "03/01/2020"]
+    )
+    result = s.str.to_datetime(formats=["%Y-%m-%d %H:%M", "%d/%m/%Y %H:%M", "%d/%m/%Y"])
+    expected = pl.Series(
+        [
+            datetime(2020, 1, 1, 1),
+            datetime(2020, 1, 2, 3),
+            None,
+            datetime(2020, 1, 3, 4),
+            datetime(2020, 1, 3),
+        ]
+    )
+    assert_series_equal(result, expected)
+
+    # The most precise format decides the time unit.
+    result = pl.Series(["2020-01-01 01:00:00.123456789", "2020-01-02"]).str.to_datetime(
+        formats=["%Y-%m-%d %H:%M:%S%.9f", "%Y-%m-%d"]
+    )
+    assert result.dtype == pl.Datetime("ns")
+    assert result.to_list() == [
+        datetime(2020, 1, 1, 1, 0, 0, 123456),
+        datetime(2020, 1, 2),
+    ]
+
+
+def test_to_datetime_multiple_formats_order() -> None:
+    # Every value is parsed with the first format that matches, regardless of the
+    # formats that matched the previous values.
+    s = pl.Series(["01/02/2020", "13/02/2020", "01/02/2020"])
+    result = s.str.to_datetime(formats=["%m/%d/%Y", "%d/%m/%Y"], cache=False)
+    assert result.to_list() == [
+        datetime(2020, 1, 2),
+        datetime(2020, 2, 13),
+        datetime(2020, 1, 2),
+    ]
+
+
+def test_to_datetime_multiple_formats_aware() -> None:
+    s = pl.Series(["2020-01-01 01:00+01:00", "2020/01/02 01:00+00:00"])
+    result = s.str.to_datetime(formats=["%Y-%m-%d %H:%M%#z", "%Y/%m/%d %H:%M%#z"])
+    expected = pl.Series(
+        [datetime(2020, 1, 1, 0), datetime(2020, 1, 2, 1)]
+    ).dt.replace_time_zone("UTC")
+    assert_series_equal(result, expected)
+
+    with pytest.raises(pl.InvalidOperationError, match="time zone directive"):
+        s.str.to_datetime(formats=["%Y-%m-%d %H:%M%#z", "%Y/%m/%d %H:%M"])
+
+
+def test_to_datetime_multiple_formats_strict() -> None:
+    s = pl.Series(["2020-01-01", "not a date"])
+    with pytest.raises(pl.InvalidOperationError):
+        s.str.to_datetime(formats=["%Y-%m-%d", "%d/%m/%Y"])
+
+    result = s.str.to_datetime(formats=["%Y-%m-%d", "%d/%m/%Y"], strict=False)
+    assert result.to_list() == [datetime(2020, 1, 1), None]
+
+    with pytest.raises(pl.InvalidOperationError, match="cannot set both"):
+        s.str.to_datetime("%Y-%m-%d", formats=["%d/%m/%Y"])
@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from datetime import date, datetime
+from datetime import date, datetime, timezone
 from typing import TYPE_CHECKING
 
 import hypothesis.strategies as st
@@ -92,3 +92,93 @@ def test_truncate_datetime_w_expression(time_unit: TimeUnit) -> None:
     result = df.select(pl.col("a").dt.truncate(pl.col("b")))["a"]
     assert result[0] == datetime(2020, 1, 1)
     assert result[1] == datetime(2020, 1, 3)
+
+
+def test_truncate_non_existent() -> None:
+    # Midnight didn't exist in Sao Paulo on the day DST started.
+    s = pl.Series([datetime(2018, 11, 4, 12)]).dt.replace_time_zone(
+        "America/Sao_Paulo"
+    )
+    with pytest.raises(pl.ComputeError, match="is non-existent"):
+        s.dt.truncate("1d")
+    result = s.dt.truncate("1d", non_existent="null")
+    expected = pl.Series([None], dtype=pl.Datetime("us", "America/Sao_Paulo"))
+    assert_series_equal(result, expected)
+
+
+def test_truncate_non_existent_per_row() -> None:
+    # Midnight didn't exist in Sao Paulo on the day DST started.
+    df = pl.DataFrame(
+        {
+            "ts": [datetime(2018, 11, 4, 12), datetime(2018, 11, 5, 12)],
+            "non_existent": ["null", "raise"],
+        }
+    ).with_columns(pl.col("ts").dt.replace_time_zone("America/Sao_Paulo"))
+
+    result = df.select(
+        pl.col("ts").dt.truncate("1d", non_existent=pl.col("non_existent"))
+    )["ts"]
+    expected = pl.Series(
+        "ts", [None, datetime(2018, 11, 5)], dtype=pl.Datetime("us")
+    ).dt.replace_time_zone("America/Sao_Paulo")
+    assert_series_equal(result, expected)
+
+    with pytest.raises(pl.ComputeError, match="is non-existent"):
+        df.reverse().select(
+            pl.col("ts").dt.truncate("1d", non_existent=pl.col("non_existent"))
+        )
+
+
+def test_truncate_ambiguous() -> None:
+    # 01:30 CDT and 01:30 CST.
+    s = pl.Series(
+        [
+            datetime(2022, 11, 6, 6, 30),
+            datetime(2022, 11, 6, 7, 30),
+            datetime(2022, 11, 6, 6, 30),
+            datetime(2022, 11, 6, 7, 30),
+        ]
+    ).dt.replace_time_zone("UTC")
+    df = pl.DataFrame(
+        {
+            "ts": s.dt.convert_time_zone("America/Chicago"),
+            "ambiguous": ["latest", "earliest", "null", "infer"],
+        }
+    )
+
+    result = df.select(pl.col("ts").dt.truncate("1h", ambiguous="earliest"))["ts"]
+    expected = pl.Series("ts", [datetime(2022, 11, 6, 6)] * 4).dt.replace_time_zone(
+        "UTC"
+    )
+    assert_series_equal(result.dt.convert_time_zone("UTC"), expected)
+
+    result = df.select(
+        pl.col("ts").dt.truncate("1h", ambiguous=pl.col("ambiguous"))
+    )["ts"]
+    expected = pl.Series(
+        "ts",
+        [
+            datetime(2022, 11, 6, 7),
+            datetime(2022, 11, 6, 6),
+            None,
+            datetime(2022, 11, 6, 7),
+        ],
+    ).dt.replace_time_zone("UTC")
+    assert_series_equal(result.dt.convert_time_zone("UTC"), expected)
+
+    with pytest.raises(pl.ComputeError, match="is ambiguous"):
+        df.select(pl.col("ts").dt.truncate("1h", ambiguous="raise"))
+
+
+def test_round_ambiguous() -> None:
+    # 01:30 CDT is rounded up to 01:00 CST, which is ambiguous in local time.
+    s = (
+        pl.Series([datetime(2022, 11, 6, 6, 30)])
+        .dt.replace_time_zone("UTC")
+        .dt.convert_time_zone("America/Chicago")
+    )
+    result = s.dt.round("1h").dt.convert_time_zone("UTC").item()
+    assert result == datetime(2022, 11, 6, 7, tzinfo=timezone.utc)
+    result = s.dt.round("1h", ambiguous="earliest").dt.convert_time_zone("UTC").item()
+    assert result == datetime(2022, 11, 6, 6, tzinfo=timezone.utc)
+    assert s.dt.round("1h", ambiguous="null").item() is None
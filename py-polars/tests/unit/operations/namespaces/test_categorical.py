@@ -1,5 +1,9 @@
+from contextlib import nullcontext
+
+import pytest
+
 import polars as pl
-from polars.testing import assert_frame_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 
 
 def test_categorical_lexical_sort() -> None:
@@ -140,3 +144,55 @@ def test_cat_uses_lexical_ordering() -> None:
 
     s = s.cast(pl.Categorical("physical"))
     assert s.cat.uses_lexical_ordering() is False
+
+
+@pytest.mark.parametrize("use_string_cache", [False, True])
+def test_cat_string_predicates(use_string_cache: bool) -> None:
+    with pl.StringCache() if use_string_cache else nullcontext():
+        values = ["apple", "banana", None, "apricot", "ap.le"]
+        s = pl.Series("fruit", values, dtype=pl.Categorical)
+        s_str = s.cast(pl.String)
+
+        for pattern, literal in [("an+", False), ("ap.", False), ("ap.", True)]:
+            assert_series_equal(
+                s.cat.contains(pattern, literal=literal),
+                s_str.str.contains(pattern, literal=literal),
+            )
+        assert_series_equal(s.cat.starts_with("ap"), s_str.str.starts_with("ap"))
+        assert_series_equal(s.cat.ends_with("e"), s_str.str.ends_with("e"))
+
+        out = s.to_frame().select(pl.col("fruit").cat.starts_with("b"))
+        assert out.to_series().to_list() == [False, True, None, False, False]
+
+        with pytest.raises(pl.ComputeError):
+            s.cat.contains("(")
+        assert s.cat.contains("(", strict=False).null_count() == len(s)
+
+
+def test_cat_add_categories() -> None:
+    s = pl.Series("size", ["S", "M", None, "S"], dtype=pl.Enum(["S", "M"]))
+
+    out = s.cat.add_categories(["M", "L"])
+    assert out.dtype == pl.Enum(["S", "M", "L"])
+    assert out.to_list() == s.to_list()
+    assert_series_equal(out.to_physical(), s.to_physical())
+
+    df = s.to_frame().lazy().select(pl.col("size").cat.add_categories(["XL"]))
+    assert df.collect_schema()["size"] == pl.Enum(["S", "M", "XL"])
+    assert df.collect()["size"].dtype == pl.Enum(["S", "M", "XL"])
+
+    with pytest.raises(pl.InvalidOperationError):
+        pl.Series(["a"], dtype=pl.Categorical).cat.add_categories(["b"])
+
+
+def test_cat_reorder_categories() -> None:
+    s = pl.Series("size", ["S", "M", None, "L"], dtype=pl.Enum(["M", "S", "L"]))
+
+    out = s.cat.reorder_categories(["S", "M", "L"])
+    assert out.dtype == pl.Enum(["S", "M", "L"])
+    assert out.to_list() == s.to_list()
+    assert out.sort().to_list() == [None, "S", "M", "L"]
+
+    for categories in [["S", "M"], ["S", "M", "M"], ["S", "M", "XL"]]:
+        with pytest.raises(pl.InvalidOperationError):
+            s.cat.reorder_categories(categories)
@@ -5,3 +5,11 @@ def test_rolling_var_stability_12905() -> None:
     s1 = pl.Series("a", [36743.6 for _ in range(10)])
     assert s1.rolling_var(window_size=12, min_periods=2).sum() == 0.0
     assert s1.rolling_std(window_size=12, min_periods=2).sum() == 0.0
+
+
+def test_rolling_approx_quantile() -> None:
+    s = pl.Series("a", [float((i * 37) % 101) for i in range(200)])
+    exact = s.rolling_quantile(0.25, "lower", window_size=50)
+    approx = s.rolling_approx_quantile(0.25, window_size=50, relative_accuracy=0.01)
+    assert approx.null_count() == exact.null_count()
+    assert ((approx - exact).abs() <= 0.01 * exact.abs()).all()
@@ -1,6 +1,7 @@
 import pytest
 
 import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
 
 
 @pytest.mark.parametrize("op", ["and_", "or_"])
@@ -8,3 +9,93 @@ def test_bitwise_integral_schema(op: str) -> None:
     df = pl.LazyFrame({"a": [1, 2], "b": [3, 4]})
     q = df.select(getattr(pl.col("a"), op)(pl.col("b")))
     assert q.collect_schema()["a"] == df.collect_schema()["a"]
+
+
+def test_bitwise_elementwise() -> None:
+    df = pl.DataFrame({"a": [0b1100, 0b1010, None], "b": [0b1010, 0b0110, 1]})
+    out = df.select(
+        and_=pl.col("a").bitwise_and(pl.col("b")),
+        or_=pl.col("a").bitwise_or(pl.col("b")),
+        xor=pl.col("a").bitwise_xor(pl.col("b")),
+        not_=pl.col("a").bitwise_not(),
+    )
+    expected = pl.DataFrame(
+        {
+            "and_": [0b1000, 0b0010, None],
+            "or_": [0b1110, 0b1110, None],
+            "xor": [0b0110, 0b1100, None],
+            "not_": [~0b1100, ~0b1010, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+@pytest.mark.parametrize(
+    ("dtype", "values", "left", "right"),
+    [
+        (pl.UInt8, [1, 129, 255], [4, 4, 252], [0, 32, 63]),
+        (pl.Int16, [1, -3, -32768], [4, -12, 0], [0, -1, -8192]),
+        (pl.Int64, [5, -5, 1 << 60], [20, -20, 1 << 62], [1, -2, 1 << 58]),
+    ],
+)
+def test_bitwise_shift(
+    dtype: pl.PolarsDataType, values: list[int], left: list[int], right: list[int]
+) -> None:
+    s = pl.Series("a", values, dtype=dtype)
+    assert_series_equal(
+        s.to_frame().select(pl.col("a").bitwise_shift_left(2)).to_series(),
+        pl.Series("a", left, dtype=dtype),
+    )
+    assert_series_equal(
+        s.to_frame().select(pl.col("a").bitwise_shift_right(2)).to_series(),
+        pl.Series("a", right, dtype=dtype),
+    )
+
+
+def test_bitwise_shift_out_of_range() -> None:
+    df = pl.DataFrame({"a": [3, -3]}, schema={"a": pl.Int32})
+    out = df.select(
+        left=pl.col("a").bitwise_shift_left(40),
+        right=pl.col("a").bitwise_shift_right(40),
+    )
+    expected = pl.DataFrame(
+        {"left": [0, 0], "right": [0, -1]}, schema={"left": pl.Int32, "right": pl.Int32}
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_bitwise_agg() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "b", "c"],
+            "flags": [0b0111, 0b1101, None, 0b1000, None],
+        },
+        schema_overrides={"flags": pl.UInt16},
+    )
+    out = df.group_by("g", maintain_order=True).agg(
+        and_=pl.col("flags").bitwise_and_agg(),
+        or_=pl.col("flags").bitwise_or_agg(),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": ["a", "b", "c"],
+            "and_": [0b0101, 0b1000, None],
+            "or_": [0b1111, 0b1000, None],
+        },
+        schema_overrides={"and_": pl.UInt16, "or_": pl.UInt16},
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.select(
+        pl.col("flags").bitwise_and_agg().alias("and_"),
+        pl.col("flags").bitwise_or_agg().alias("or_"),
+    )
+    assert out.rows() == [(0, 0b1111)]
+
+
+def test_bitwise_non_integer() -> None:
+    df = pl.DataFrame({"a": [1.0, 2.0]})
+    with pytest.raises(pl.InvalidOperationError, match="bitwise_not"):
+        df.select(pl.col("a").bitwise_not())
+    with pytest.raises(pl.InvalidOperationError, match="bitwise_or_agg"):
+        df.select(pl.col("a").bitwise_or_agg())
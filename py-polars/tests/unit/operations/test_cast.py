@@ -689,3 +689,21 @@ def test_bool_numeric_supertype(dtype: pl.PolarsDataType) -> None:
     df = pl.DataFrame({"v": [1, 2, 3, 4, 5, 6]})
     result = df.select((pl.col("v") < 3).sum().cast(dtype) / pl.len())
     assert result.item() - 0.3333333 <= 0.00001
+
+
+def test_cast_binary_bytes_roundtrip() -> None:
+    s = pl.Series("a", [b"\x00\x01\x02", None, b"\xff\xfe\xfd"])
+
+    as_list = s.cast(pl.List(pl.UInt8))
+    assert as_list.to_list() == [[0, 1, 2], None, [255, 254, 253]]
+    assert_series_equal(as_list.cast(pl.Binary), s)
+
+    as_array = s.cast(pl.Array(pl.UInt8, 3))
+    assert as_array.dtype == pl.Array(pl.UInt8, 3)
+    assert_series_equal(as_array.cast(pl.Binary), s)
+    assert_series_equal(as_array.cast(pl.List(pl.UInt8)), as_list)
+
+    with pytest.raises(pl.ComputeError, match="specified width 2"):
+        s.cast(pl.Array(pl.UInt8, 2))
+    with pytest.raises(pl.InvalidOperationError):
+        pl.Series([[1, None]], dtype=pl.List(pl.UInt8)).cast(pl.Binary)
@@ -0,0 +1,89 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_cum_mean_std() -> None:
+    s = pl.Series("a", [1, None, 3, 5])
+    assert_series_equal(s.cum_mean(), pl.Series("a", [1.0, None, 2.0, 3.0]))
+    assert_series_equal(s.cum_mean(reverse=True), pl.Series("a", [3.0, None, 4.0, 5.0]))
+    assert_series_equal(s.cum_std(), pl.Series("a", [None, None, 2.0**0.5, 2.0]))
+    assert_series_equal(
+        s.cum_std(ddof=0), pl.Series("a", [0.0, None, 1.0, (8 / 3) ** 0.5])
+    )
+
+    s = pl.Series("a", [1.0, 2.0], dtype=pl.Float32)
+    assert s.cum_mean().dtype == pl.Float32
+    assert s.cum_std().dtype == pl.Float32
+
+
+def test_cum_mean_precision() -> None:
+    offset = 1e9
+    s = pl.Series("a", [offset + x for x in [4.0, 7.0, 13.0, 16.0]])
+    assert s.cum_mean()[-1] == offset + 10.0
+    assert s.cum_std(ddof=0)[-1] == 22.5**0.5
+
+
+def test_cum_quantile() -> None:
+    s = pl.Series("a", [4, 1, None, 3])
+    assert_series_equal(
+        s.cum_quantile(0.5), pl.Series("a", [4.0, 1.0, None, 3.0]), rtol=0.011
+    )
+
+    s = pl.Series("a", [float("nan"), 2.0, float("nan"), 4.0])
+    assert_series_equal(
+        s.cum_quantile(1.0), pl.Series("a", [None, 2.0, 2.0, 4.0]), rtol=0.011
+    )
+
+    n = 10_000
+    s = pl.Series("a", [(i * 7919) % n for i in range(n)])
+    for q in [0.1, 0.5, 0.9]:
+        assert abs(s.cum_quantile(q)[-1] - q * (n - 1)) < 0.01 * n
+
+    with pytest.raises(pl.ComputeError, match="should be between 0.0 and 1.0"):
+        s.cum_quantile(1.5)
+
+
+def test_cum_agg_float_over() -> None:
+    df = pl.DataFrame({"g": ["a", "b", "a", "b", "a"], "x": [1, 10, 3, 20, 5]})
+    out = df.select(
+        pl.col("x").cum_mean().over("g").alias("mean"),
+        pl.col("x").cum_std().over("g").alias("std"),
+        pl.col("x").cum_quantile(0.5).over("g").alias("median"),
+    )
+    expected = pl.DataFrame(
+        {
+            "mean": [1.0, 10.0, 2.0, 15.0, 3.0],
+            "std": [None, None, 2.0**0.5, 50**0.5, 2.0],
+            "median": [1.0, 10.0, 1.0, 10.0, 3.0],
+        }
+    )
+    assert_frame_equal(out, expected, rtol=0.011)
+
+
+def test_cum_agg_float_invalid_dtype() -> None:
+    with pytest.raises(pl.InvalidOperationError):
+        pl.Series(["a"]).cum_mean()
+
+
+def test_scan_cum_reduce() -> None:
+    df = pl.DataFrame({"x": [1, None, 3, 4]})
+    out = df.select(
+        pl.col("x").scan(0.5, lambda acc, x: acc * 2 + x).alias("scan"),
+        pl.col("x").cum_reduce(1, lambda acc, x: acc * x).alias("prod"),
+    )
+    expected = pl.DataFrame(
+        {
+            "scan": [2.0, None, 7.0, 18.0],
+            "prod": [1, None, 3, 12],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_scan_function_error() -> None:
+    with pytest.raises(pl.ComputeError, match="custom python function failed"):
+        pl.Series([1, 2]).to_frame().select(pl.first().scan(0, lambda acc, x: 1 / 0))
@@ -245,3 +245,49 @@ def test_ewma_by_multiple_chunks() -> None:
     values = pl.Series([1, 2]).append(pl.Series([None], dtype=pl.Int64))
     result = values.ewm_mean_by(times, half_life="2i")
     assert_series_equal(result, expected)
+
+
+@pytest.mark.parametrize("sort", [True, False])
+def test_ewm_std_var_by(sort: bool) -> None:
+    df = pl.DataFrame(
+        {
+            "values": [0.0, 1.0, 2.0, None, 4.0],
+            "times": [1, 3, 10, 15, 17],
+        }
+    )
+    if not sort:
+        df = df.reverse()
+    result = df.select(
+        var=pl.col("values").ewm_var_by("times", half_life="4i"),
+        var_biased=pl.col("values").ewm_var_by("times", half_life="4i", bias=True),
+        std=pl.col("values").ewm_std_by("times", half_life="4i"),
+    )
+    expected = pl.DataFrame(
+        {
+            "var": [0.0, 0.5, 1.4752085205033367, None, 3.3032985115516564],
+            "var_biased": [0.0, 0.2071067811865475, 0.6703915687184885, None, 1.51289],
+        }
+    ).with_columns(std=pl.col("var").sqrt())
+    if not sort:
+        expected = expected.reverse()
+    assert_frame_equal(result, expected, rtol=1e-5)
+
+
+def test_ewm_std_by_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "group": ["a", "b", "a", "b", "a"],
+            "values": [0.0, 5.0, 1.0, 5.0, 2.0],
+            "times": [1, 1, 3, 2, 10],
+        }
+    )
+    result = df.group_by("group", maintain_order=True).agg(
+        pl.col("values").ewm_std_by("times", half_life="4i")
+    )
+    expected = pl.DataFrame(
+        {
+            "group": ["a", "b"],
+            "values": [[0.0, 0.7071067811865476, 1.214581623647969], [0.0, 0.0]],
+        }
+    )
+    assert_frame_equal(result, expected)
@@ -1129,3 +1129,42 @@ def test_absence_off_null_prop_8224() -> None:
         pl.List(pl.Float64),
         pl.List(pl.Float64),
     ]
+
+
+def test_group_by_first_last_non_null() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 2, 3],
+            "a": [None, 2, 3, 4, None, None],
+            "b": ["x", None, None, None, "y", None],
+        }
+    )
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2, 3],
+            "a_first": [2, 4, None],
+            "a_last": [3, 4, None],
+            "b_first": ["x", "y", None],
+            "b_last": ["x", "y", None],
+        }
+    )
+    aggs = [
+        pl.col("a").first_non_null().alias("a_first"),
+        pl.col("a").last_non_null().alias("a_last"),
+        pl.col("b").first_non_null().alias("b_first"),
+        pl.col("b").last_non_null().alias("b_last"),
+    ]
+    out = df.group_by("g", maintain_order=True).agg(aggs)
+    assert_frame_equal(out, expected)
+
+    # Same result as dropping the nulls first.
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("a").drop_nulls().first().alias("a_first"),
+        pl.col("a").drop_nulls().last().alias("a_last"),
+        pl.col("b").drop_nulls().first().alias("b_first"),
+        pl.col("b").drop_nulls().last().alias("b_last"),
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.select(pl.col("a").first_non_null(), pl.col("b").last_non_null())
+    assert out.row(0) == (2, "y")
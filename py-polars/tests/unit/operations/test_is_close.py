@@ -0,0 +1,60 @@
+from __future__ import annotations
+
+import math
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_series_equal
+
+INF = float("inf")
+NAN = float("nan")
+
+
+def test_is_close() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1.0, 1.0, 100.0, INF, INF, -INF, NAN, NAN, None, 0.0],
+            "b": [1.0, 1.1, 101.0, INF, -INF, -INF, NAN, 1.0, 1.0, 1e-12],
+        }
+    )
+
+    out = df.select(pl.col("a").is_close("b", abs_tol=0.2)).to_series()
+    expected = [True, True, False, True, False, True, False, False, None, True]
+    assert out.to_list() == expected
+
+    out = df.select(pl.col("a").is_close("b", rel_tol=0.05)).to_series()
+    expected = [True, False, True, True, False, True, False, False, None, False]
+    assert out.to_list() == expected
+
+    out = df.select(pl.col("a").is_close("b", nans_equal=True)).to_series()
+    expected = [True, False, False, True, False, True, True, False, None, False]
+    assert out.to_list() == expected
+
+
+def test_is_close_matches_math_isclose() -> None:
+    a = [1.0, 1e10, 1e-10, -3.0, 0.0, 2.5]
+    b = [1.0 + 1e-10, 1.00001e10, 2e-10, -3.0000001, 1e-9, 2.4]
+    for abs_tol, rel_tol in [(0.0, 1e-9), (1e-9, 0.0), (0.0, 1e-5), (0.2, 0.0)]:
+        out = pl.Series(a).is_close(pl.Series(b), abs_tol=abs_tol, rel_tol=rel_tol)
+        expected = [
+            math.isclose(x, y, abs_tol=abs_tol, rel_tol=rel_tol) for x, y in zip(a, b)
+        ]
+        assert out.to_list() == expected
+
+
+def test_is_close_dtypes() -> None:
+    s = pl.Series("a", [1.0, 2.0], dtype=pl.Float32)
+    assert_series_equal(
+        s.is_close(pl.Series([1.0, 2.5], dtype=pl.Float32), abs_tol=0.1),
+        pl.Series("a", [True, False]),
+    )
+    assert pl.Series([1, 2, 3]).is_close(2, abs_tol=1).to_list() == [True, True, True]
+
+
+def test_is_close_invalid() -> None:
+    s = pl.Series([1.0, 2.0])
+    with pytest.raises(pl.InvalidOperationError):
+        s.is_close(s, abs_tol=-1.0)
+    with pytest.raises(pl.InvalidOperationError):
+        pl.Series(["a"]).is_close(pl.Series(["a"]))
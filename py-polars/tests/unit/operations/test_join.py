@@ -12,7 +12,7 @@ import polars as pl
 from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
-    from polars.type_aliases import JoinStrategy
+    from polars.type_aliases import JoinExecutionStrategy, JoinStrategy
 
 
 def test_semi_anti_join() -> None:
@@ -845,6 +845,40 @@ def test_join_list_non_numeric() -> None:
     }
 
 
+@pytest.mark.parametrize("dtype", [pl.List(pl.Int64), pl.Array(pl.Int64, 2)])
+def test_join_on_nested_keys(dtype: pl.DataType) -> None:
+    left = pl.DataFrame(
+        {"key": [[1, 2], [3, 4], [1, 2], None], "a": [1, 2, 3, 4]},
+        schema={"key": dtype, "a": pl.Int64},
+    )
+    right = pl.DataFrame(
+        {"key": [[1, 2], [5, 6], None], "b": ["x", "y", "z"]},
+        schema={"key": dtype, "b": pl.String},
+    )
+
+    out = left.join(right, on="key", how="inner")
+    expected = pl.DataFrame(
+        {"key": [[1, 2], [1, 2]], "a": [1, 3], "b": ["x", "x"]},
+        schema={"key": dtype, "a": pl.Int64, "b": pl.String},
+    )
+    assert_frame_equal(out, expected, check_row_order=False)
+
+    out = left.join(right, on="key", how="left")
+    expected = pl.DataFrame(
+        {
+            "key": [[1, 2], [3, 4], [1, 2], None],
+            "a": [1, 2, 3, 4],
+            "b": ["x", None, "x", None],
+        },
+        schema={"key": dtype, "a": pl.Int64, "b": pl.String},
+    )
+    assert_frame_equal(out, expected)
+
+    assert sorted(left.join(right, on="key", how="semi")["a"]) == [1, 3]
+    assert sorted(left.join(right, on="key", how="anti")["a"]) == [2, 4]
+    assert left.join(right, on="key", how="inner", join_nulls=True).height == 3
+
+
 @pytest.mark.slow()
 def test_join_4_columns_with_validity() -> None:
     # join on 4 columns so we trigger combine validities
@@ -967,3 +1001,59 @@ def test_join_lit_panic_11410() -> None:
     assert symbols.join(dates, left_on=pl.lit(1), right_on=pl.lit(1)).drop(
         "literal"
     ).collect().to_dict(as_series=False) == {"symbol": [4], "date": [1]}
+
+
+def test_intersect_except() -> None:
+    lf = pl.LazyFrame({"a": [1, 1, 1, 2, None], "b": ["x", "x", "x", "y", "z"]})
+    other = pl.LazyFrame({"c": [1, 2, 2, None], "d": ["x", "y", "y", "z"]})
+
+    assert lf.intersect(other).collect().rows() == [(1, "x"), (2, "y"), (None, "z")]
+    assert lf.intersect(other, distinct=False).collect().rows() == [
+        (1, "x"),
+        (2, "y"),
+        (None, "z"),
+    ]
+    assert lf.except_(other).collect().rows() == []
+    assert lf.except_(other, distinct=False).collect().rows() == [
+        (1, "x"),
+        (1, "x"),
+    ]
+
+    with pytest.raises(pl.ShapeError, match="equal number of columns"):
+        lf.intersect(other.select("c")).collect()
+    with pytest.raises(TypeError, match="expected `other` to be a LazyFrame"):
+        lf.except_(other.collect())  # type: ignore[arg-type]
+
+
+def test_join_validation_error_lists_keys() -> None:
+    left = pl.DataFrame({"a": [1, 2, 3]})
+    right = pl.DataFrame({"a": [1, 2, 2, 3, 3, 3]})
+    with pytest.raises(
+        pl.ComputeError, match=r"duplicated right keys: \[2\] \(2 rows\), \[3\] \(3 rows\)"
+    ):
+        left.join(right, on="a", validate="m:1")
+
+
+@pytest.mark.parametrize(
+    "strategy", ["auto", "hash", "sort_merge", "broadcast_left", "broadcast_right"]
+)
+def test_join_strategy_hints(strategy: JoinExecutionStrategy) -> None:
+    left = pl.DataFrame({"a": [1, 2, 3, 4], "b": ["w", "x", "y", "z"]})
+    right = pl.DataFrame({"a": [2, 3, 3, 5], "c": [1.0, 2.0, 3.0, 4.0]})
+    expected = left.join(right, on="a").sort("a", "c")
+
+    out = left.join(right, on="a", strategy=strategy)
+    assert_frame_equal(out.sort("a", "c"), expected)
+
+    lf = left.lazy().join(right.lazy(), on="a", strategy=strategy)
+    assert_frame_equal(lf.collect().sort("a", "c"), expected)
+    assert_frame_equal(lf.collect(streaming=True).sort("a", "c"), expected)
+
+
+def test_join_strategy_hints_invalid() -> None:
+    left = pl.DataFrame({"a": [1, 2, 3]})
+    right = pl.DataFrame({"a": [1, 2]})
+    with pytest.raises(pl.InvalidOperationError, match="left broadcast strategy"):
+        left.join(right, on="a", how="left", strategy="broadcast_left")
+    with pytest.raises(ValueError, match="`strategy` must be one of"):
+        left.join(right, on="a", strategy="merge")  # type: ignore[arg-type]
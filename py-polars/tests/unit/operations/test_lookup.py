@@ -0,0 +1,57 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+@pytest.fixture()
+def mapping() -> pl.DataFrame:
+    return pl.DataFrame({"key": [1, 2, 3], "value": ["a", "b", "c"]})
+
+
+def test_lookup(mapping: pl.DataFrame) -> None:
+    df = pl.DataFrame({"x": [3, None, 4, 1]}, schema={"x": pl.Int32})
+    result = df.select(pl.col("x").lookup(mapping, "key", "value"))
+    expected = pl.DataFrame({"x": ["c", None, None, "a"]})
+    assert_frame_equal(result, expected)
+
+    with pytest.raises(pl.ComputeError, match="key 4 was not found"):
+        df.select(pl.col("x").lookup(mapping, "key", "value", on_missing="raise"))
+
+    result = df.head(2).select(
+        pl.col("x").lookup(mapping, "key", "value", on_missing="raise")
+    )
+    assert_series_equal(result.to_series(), pl.Series("x", ["c", None]))
+
+
+def test_lookup_null_key() -> None:
+    mapping = pl.DataFrame({"key": ["a", None], "value": [1, 0]})
+    s = pl.Series("x", ["a", None, "b"])
+    result = s.to_frame().select(pl.col("x").lookup(mapping, "key", "value"))
+    assert result.to_series().to_list() == [1, 0, None]
+
+
+def test_lookup_categorical() -> None:
+    mapping = pl.DataFrame({"key": ["x", "y"], "value": [1, 2]})
+    df = pl.DataFrame({"a": ["y", "x", "z"]}, schema={"a": pl.Categorical})
+    result = df.select(pl.col("a").lookup(mapping, "key", "value"))
+    assert result.to_series().to_list() == [2, 1, None]
+
+
+def test_lookup_lazy_streaming(mapping: pl.DataFrame) -> None:
+    lf = pl.LazyFrame({"x": [1, 2, 3] * 100})
+    q = lf.select(pl.col("x").lookup(mapping, "key", "value"))
+    assert q.collect_schema() == {"x": pl.String}
+    assert q.collect(streaming=True).to_series().to_list() == ["a", "b", "c"] * 100
+
+
+def test_lookup_invalid(mapping: pl.DataFrame) -> None:
+    duplicated = pl.DataFrame({"key": [1, 1], "value": ["a", "b"]})
+    with pytest.raises(pl.DuplicateError):
+        pl.col("x").lookup(duplicated, "key", "value")
+    with pytest.raises(pl.ColumnNotFoundError):
+        pl.col("x").lookup(mapping, "key", "missing")
+    with pytest.raises(ValueError, match="on_missing"):
+        pl.col("x").lookup(mapping, "key", "value", on_missing="keep")  # type: ignore[arg-type]
@@ -511,3 +511,87 @@ def test_list_pivot() -> None:
         "x": [1, 2, None],
         "y": [4, None, 3],
     }
+
+
+def test_pivot_collision_policy() -> None:
+    df = pl.DataFrame(
+        {
+            "index": ["a", "a", "a", "b"],
+            "columns": ["x", "x", "y", "x"],
+            "values": [1, 2, 3, 4],
+        }
+    )
+    with pytest.raises(
+        ComputeError, match=r'colliding \(index, columns\) keys: \["a", "x"\] \(2 rows\)'
+    ):
+        df.pivot(index="index", columns="columns", values="values")
+
+    result = df.pivot(
+        index="index", columns="columns", values="values", aggregate_function="list"
+    )
+    expected = pl.DataFrame(
+        {"index": ["a", "b"], "x": [[1, 2], [4]], "y": [[3], None]},
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.pivot(
+        index="index", columns="columns", values="values", aggregate_function="last"
+    )
+    expected = pl.DataFrame({"index": ["a", "b"], "x": [2, 4], "y": [3, None]})
+    assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize(
+    ("aggregate_function", "expected"),
+    [
+        ("first", ["x", "z"]),
+        ("last", ["y", "z"]),
+        ("min", ["y", "z"]),
+        ("max", ["x", "z"]),
+    ],
+)
+def test_pivot_enum_values(aggregate_function: str, expected: list[str]) -> None:
+    dtype = pl.Enum(["z", "y", "x"])
+    df = pl.DataFrame(
+        {
+            "index": [1, 1, 2, 2],
+            "columns": ["a", "a", "a", "b"],
+            "values": pl.Series(["x", "y", "z", "x"], dtype=dtype),
+        }
+    )
+    result = df.pivot(
+        index="index",
+        columns="columns",
+        values="values",
+        aggregate_function=aggregate_function,  # type: ignore[arg-type]
+    )
+    expected_df = pl.DataFrame(
+        {
+            "index": [1, 2],
+            "a": pl.Series(expected, dtype=dtype),
+            "b": pl.Series([None, "x"], dtype=dtype),
+        }
+    )
+    assert_frame_equal(result, expected_df)
+
+    melted = result.melt(id_vars="index").drop_nulls()
+    assert melted.schema["value"] == dtype
+
+
+def test_pivot_categorical_values_min_max() -> None:
+    df = pl.DataFrame(
+        {
+            "index": [1, 1, 2],
+            "columns": ["a", "a", "a"],
+            "values": pl.Series(["b", "a", "c"], dtype=pl.Categorical("lexical")),
+        }
+    )
+    for aggregate_function, expected in [("min", ["a", "c"]), ("max", ["b", "c"])]:
+        result = df.pivot(
+            index="index",
+            columns="columns",
+            values="values",
+            aggregate_function=aggregate_function,  # type: ignore[arg-type]
+        )
+        assert result.schema["a"] == pl.Categorical("lexical")
+        assert result["a"].to_list() == expected
@@ -30,3 +30,24 @@ def test_profile_with_cse() -> None:
         .then(None)
         .otherwise(pl.when(y == 0).then(None).otherwise(x + y))
     ).profile(comm_subexpr_elim=True)[1].shape == (2, 3)
+
+
+def test_profile_expressions() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+    q = lf.with_columns(
+        b=pl.col("a") * 2,
+        c=pl.col("a").map_batches(lambda s: s + 1),
+    )
+
+    df, timings = q.profile(expressions=True)
+    assert df["c"].to_list() == [2, 3, 4]
+    nodes = timings["node"].to_list()
+    assert nodes[0] == "optimization"
+    assert len([n for n in nodes if n.startswith("expr(")]) == 2
+    (udf,) = [n for n in nodes if n.startswith("udf(")]
+    assert 'col("a")' in udf
+    assert (timings["end"] >= timings["start"]).all()
+
+    # Expressions are only timed on request.
+    _, timings = q.profile()
+    assert not timings["node"].str.contains(r"^(expr|udf)\(").any()
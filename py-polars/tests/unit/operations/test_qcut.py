@@ -131,3 +131,53 @@ def test_qcut_over() -> None:
         dtype=pl.Categorical,
     )
     assert_series_equal(out, expected, categorical_as_str=True)
+
+
+def test_qcut_per_group_breaks() -> None:
+    df = pl.DataFrame(
+        {
+            "group": ["a", "b", "a", "b", "a", "b"],
+            "value": [1, 10, 2, 20, 3, 30],
+        }
+    )
+
+    out = df.select(
+        pl.col("value").qcut([0.5], include_breaks=True).over("group")
+    ).unnest("value")
+
+    expected = pl.DataFrame(
+        {
+            "breakpoint": [2.0, 20.0, 2.0, 20.0, inf, inf],
+            "category": [
+                "(-inf, 2]",
+                "(-inf, 20]",
+                "(-inf, 2]",
+                "(-inf, 20]",
+                "(2, inf]",
+                "(20, inf]",
+            ],
+        },
+        schema_overrides={"category": pl.Categorical},
+    )
+    assert_frame_equal(out, expected, categorical_as_str=True)
+    # The labels of all groups are in a single categorical.
+    assert out["category"].cat.get_categories().sort().to_list() == [
+        "(-inf, 20]",
+        "(-inf, 2]",
+        "(2, inf]",
+        "(20, inf]",
+    ]
+
+    out = (
+        df.group_by("group", maintain_order=True)
+        .agg(pl.col("value").qcut(2, labels=["low", "high"]))
+        .explode("value")
+    )
+    assert out["value"].cast(pl.String).to_list() == [
+        "low",
+        "low",
+        "high",
+        "low",
+        "low",
+        "high",
+    ]
@@ -64,6 +64,33 @@ def test_sample_df() -> None:
         df.sample(n=2, fraction=0.4)
 
 
+def test_sample_stratified() -> None:
+    df = pl.DataFrame(
+        {
+            "group": ["a", "a", "a", "a", "b", "b", "c"],
+            "key": [1, 1, 2, 2, 1, 1, 1],
+            "val": [1, 2, 3, 4, 5, 6, 7],
+        }
+    )
+
+    out = df.sample_stratified("group", n=1, seed=0)
+    assert out["group"].to_list() == ["a", "b", "c"]
+    assert out.join(df, on=out.columns).height == 3
+
+    # Half of every group, rounded down, in the original order of the rows.
+    out = df.sample_stratified(["group", "key"], fraction=0.5, seed=0)
+    assert out.select("group", "key").rows() == [("a", 1), ("a", 2), ("b", 1)]
+    assert out["val"].is_sorted()
+
+    out = df.sample_stratified("group", n=3, with_replacement=True, seed=0)
+    assert out.group_by("group").len().sort("group")["len"].to_list() == [3, 3, 3]
+
+    with pytest.raises(pl.ShapeError, match="larger sample than the group size"):
+        df.sample_stratified("group", n=2)
+    with pytest.raises(ValueError, match="cannot specify both `n` and `fraction`"):
+        df.sample_stratified("group", n=1, fraction=0.5)
+
+
 def test_sample_n_expr() -> None:
     df = pl.DataFrame(
         {
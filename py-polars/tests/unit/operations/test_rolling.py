@@ -9,7 +9,7 @@ import polars as pl
 from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
-    from polars.type_aliases import ClosedInterval, PolarsIntegerType
+    from polars.type_aliases import ClosedInterval, PolarsDataType, PolarsIntegerType
 
 
 def test_rolling() -> None:
@@ -532,3 +532,81 @@ def test_rolling_by_ordering() -> None:
         ],
         "sum val": [2, 2, 1, 1, 2, 2, 1],
     }
+
+
+@pytest.mark.parametrize("dtype", [pl.UInt32, pl.UInt64, pl.Int32, pl.Int64, pl.Date])
+def test_rolling_descending(dtype: PolarsDataType) -> None:
+    df = pl.DataFrame(
+        {
+            "idx": [0, 1, 1, 3, 4, 7, 8],
+            "group": [1, 2, 1, 1, 2, 2, 1],
+            "a": [1, 2, 3, 4, 5, 6, 7],
+        },
+        schema_overrides={"idx": dtype},
+    )
+    period = "3d" if dtype == pl.Date else "3i"
+
+    aggs = [pl.col("a"), pl.sum("a").alias("sum_a")]
+    expected = df.rolling("idx", period=period).agg(aggs)
+    result = df.reverse().rolling("idx", period=period, descending=True).agg(aggs)
+    assert result.schema["idx"] == dtype
+    assert_frame_equal(
+        result.with_columns(pl.col("a").list.sort()),
+        expected.reverse().with_columns(pl.col("a").list.sort()),
+    )
+
+    expected = df.rolling("idx", period=period, group_by="group").agg(pl.sum("a"))
+    result = (
+        df.reverse()
+        .rolling("idx", period=period, group_by="group", descending=True)
+        .agg(pl.sum("a"))
+    )
+    assert_frame_equal(result.sort("group", "idx"), expected.sort("group", "idx"))
+
+    with pytest.raises(
+        pl.InvalidOperationError,
+        match="argument in operation 'rolling' is not sorted in descending order",
+    ):
+        df.rolling("idx", period=period, descending=True).agg(pl.sum("a"))
+    with pytest.raises(pl.ComputeError, match="not sorted in descending order"):
+        df.rolling("idx", period=period, group_by="group", descending=True).agg(
+            pl.sum("a")
+        )
+
+
+@pytest.mark.parametrize("dtype", [pl.UInt32, pl.Int32])
+@pytest.mark.parametrize("closed", ["left", "right", "both", "none"])
+@pytest.mark.parametrize("offset", ["-3i", "-5i", "0i", "1i"])
+def test_rolling_32_bit_index(
+    dtype: PolarsDataType, closed: ClosedInterval, offset: str
+) -> None:
+    df = pl.DataFrame({"idx": [0, 1, 1, 3, 4, 7, 8, 8, 12], "a": range(9)})
+    kwargs = {"period": "3i", "offset": offset, "closed": closed}
+
+    # 32-bit indices are rolled over without a cast, like 64-bit ones.
+    expected = df.rolling("idx", **kwargs).agg(pl.sum("a"))  # type: ignore[arg-type]
+    result = (
+        df.with_columns(pl.col("idx").cast(dtype))
+        .rolling("idx", **kwargs)  # type: ignore[arg-type]
+        .agg(pl.sum("a"))
+    )
+    assert result.schema["idx"] == dtype
+    assert_frame_equal(result, expected.with_columns(pl.col("idx").cast(dtype)))
+
+    result = (
+        df.with_columns(pl.col("idx").cast(dtype))
+        .reverse()
+        .rolling("idx", descending=True, **kwargs)  # type: ignore[arg-type]
+        .agg(pl.sum("a"))
+    )
+    assert_frame_equal(
+        result.reverse(), expected.with_columns(pl.col("idx").cast(dtype))
+    )
+
+
+def test_rolling_expr_descending() -> None:
+    df = pl.DataFrame({"idx": [8, 7, 4, 3, 1, 0], "a": [1, 2, 3, 4, 5, 6]})
+    result = df.select(
+        pl.sum("a").rolling(index_column="idx", period="3i", descending=True)
+    )
+    assert result["a"].to_list() == [3, 2, 7, 9, 11, 6]
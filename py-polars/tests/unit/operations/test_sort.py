@@ -193,6 +193,57 @@ def test_sort_nans_3740() -> None:
     assert df.sort("val")["key"].to_list() == [2, 4, 1, 5, 3]
 
 
+def test_sort_nan_ordering() -> None:
+    df = pl.DataFrame(
+        {
+            "group": [1, 1, 1, 1, 1],
+            "key": [1, 2, 3, 4, 5],
+            "val": [0.0, None, float("nan"), float("-inf"), float("inf")],
+        }
+    )
+    assert df.sort("val", nan_ordering="smallest")["key"].to_list() == [2, 3, 4, 1, 5]
+    assert df.sort("val", nan_ordering="null")["key"].to_list() == [2, 3, 4, 1, 5]
+    assert df.sort("val", nan_ordering="null", nulls_last=True)["key"].to_list() == [
+        4,
+        1,
+        5,
+        2,
+        3,
+    ]
+    assert df.sort("group", "val", nan_ordering="smallest")["key"].to_list() == [
+        2,
+        3,
+        4,
+        1,
+        5,
+    ]
+    assert df.sort(
+        "group", "val", nan_ordering="smallest", nulls_last=True
+    )["key"].to_list() == [3, 4, 1, 5, 2]
+
+    s = df["val"].sort(nan_ordering="smallest", descending=True)
+    assert s.is_nan().to_list()[-1]
+
+    out = df.select(
+        pl.col("val").arg_sort(nan_ordering="smallest").alias("arg_sort"),
+        pl.col("key").sort_by("val", nan_ordering="smallest").alias("sort_by"),
+        pl.col("val").rank("ordinal", nan_ordering="smallest").alias("rank"),
+    )
+    assert out.to_dict(as_series=False) == {
+        "arg_sort": [1, 2, 3, 0, 4],
+        "sort_by": [2, 3, 4, 1, 5],
+        "rank": [3, None, 1, 2, 4],
+    }
+
+    out = df.group_by("group").agg(
+        pl.col("key").head(5).sort_by(pl.col("val").head(5), nan_ordering="smallest")
+    )
+    assert out["key"].to_list() == [[2, 3, 4, 1, 5]]
+
+    with pytest.raises(ValueError, match="nan_ordering"):
+        df.sort("val", nan_ordering="first")  # type: ignore[arg-type]
+
+
 def test_sort_by_exps_nulls_last() -> None:
     df = pl.DataFrame({"a": [1, 3, -2, None, 1]}).with_row_index()
 
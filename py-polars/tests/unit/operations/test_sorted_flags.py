@@ -0,0 +1,116 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Callable
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from polars.type_aliases import PolarsDataType
+
+
+def assert_sorted_flags_hold(s: pl.Series) -> None:
+    """Assert that the values of `s` are in the order that its sorted flags claim."""
+    ascending, descending = s.flags["SORTED_ASC"], s.flags["SORTED_DESC"]
+    if not (ascending or descending):
+        return
+    values = s.drop_nulls().to_list()
+    assert values == sorted(values, reverse=descending)
+    # The nulls of a sorted series are all at its start or at its end.
+    is_null = s.is_null().to_list()
+    assert is_null in (sorted(is_null), sorted(is_null, reverse=True))
+
+
+@pytest.mark.parametrize(
+    "op",
+    [
+        lambda s: s.filter(s.is_not_null()),
+        lambda s: s.filter(pl.Series([True, False, True, True, False, True, True])),
+        lambda s: s.slice(2, 4),
+        lambda s: s.head(3),
+        lambda s: s.tail(3),
+        lambda s: s.drop_nulls(),
+    ],
+)
+@pytest.mark.parametrize("descending", [False, True])
+@pytest.mark.parametrize("dtype", [pl.Int64, pl.String, pl.Date])
+def test_sorted_flag_filter_slice(
+    op: Callable[[pl.Series], pl.Series], descending: bool, dtype: PolarsDataType
+) -> None:
+    s = pl.Series("a", [None, 1, 2, 2, 3, 5, 8]).cast(dtype)
+    s = s.sort(descending=descending)
+
+    out = op(s)
+    assert out.flags["SORTED_DESC" if descending else "SORTED_ASC"]
+    assert_sorted_flags_hold(out)
+
+
+@pytest.mark.parametrize("descending", [False, True])
+def test_sorted_flag_left_join(descending: bool) -> None:
+    left = pl.DataFrame({"a": [1, 2, 2, 3, 5]}).sort("a", descending=descending)
+    right = pl.DataFrame({"a": [2, 5, 2, 7], "b": [1, 2, 3, 4]})
+
+    out = left.join(right, on="a", how="left")
+    assert out["a"].flags["SORTED_DESC" if descending else "SORTED_ASC"]
+    assert_sorted_flags_hold(out["a"])
+
+
+def test_sort_sorted_flag_nulls() -> None:
+    s = pl.Series("a", [None, 1, 2]).set_sorted()
+    assert s.sort().to_list() == [None, 1, 2]
+    assert s.sort(nulls_last=True).to_list() == [1, 2, None]
+
+    df = pl.DataFrame({"a": [None, 1, 2, 3], "b": [4, 3, 2, 1]})
+    df = df.with_columns(pl.col("a").set_sorted())
+    assert_frame_equal(df.sort("a"), df)
+    assert_frame_equal(df.lazy().sort("a").head(2).collect(), df.head(2))
+    assert df.sort("a", nulls_last=True)["b"].to_list() == [3, 2, 1, 4]
+    assert df.sort("a", descending=True)["b"].to_list() == [4, 1, 2, 3]
+
+
+def test_sort_of_sorted_frame_removed_from_plan() -> None:
+    df = pl.DataFrame({"a": [None, 1, 2, 3], "b": [4, 3, 2, 1]}).sort("a")
+
+    lf = df.lazy().sort("a")
+    assert "SORT" not in lf.explain()
+    assert_frame_equal(lf.collect(), df)
+    lf = df.lazy().sort("a").head(2)
+    assert "SORT" not in lf.explain()
+    assert_frame_equal(lf.collect(), df.head(2))
+
+    # the rows are not in the requested order
+    for lf in [
+        df.lazy().sort("a", descending=True),
+        df.lazy().sort("a", nulls_last=True),
+        df.lazy().sort("b"),
+        df.lazy().sort("a", "b"),
+    ]:
+        assert "SORT" in lf.explain()
+
+
+def test_sort_removed_through_filter_slice_and_left_join() -> None:
+    df = pl.DataFrame({"a": [None, 1, 2, 3], "b": [4, 3, 2, 1]}).sort("a")
+    right = pl.DataFrame({"a": [3, 1, 3], "c": [1, 2, 3]})
+
+    for lf in [
+        df.lazy().filter(pl.col("b") > 1),
+        df.lazy().slice(1, 2),
+        df.lazy().select("a"),
+        df.lazy().join(right.lazy(), on="a", how="left"),
+    ]:
+        out = lf.sort("a")
+        assert "SORT" not in out.explain()
+        assert_frame_equal(out.collect(), lf.collect().sort("a"))
+
+    # the order of the rows is not known
+    for lf in [
+        df.lazy().with_columns(pl.col("b").alias("a")),
+        df.lazy().join(right.lazy(), on="a", how="inner"),
+        right.lazy().join(df.lazy(), on="a", how="left"),
+    ]:
+        assert "SORT" in lf.sort("a").explain()
+
+    lf = df.lazy().join(right.lazy(), on="a", how="left").sort("a")
+    assert "SORT" in lf.explain(streaming=True)
@@ -49,6 +49,41 @@ def test_hist() -> None:
     ).to_series().to_list() == [0, 3, 4]
 
 
+def test_hist_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "b", "b", "b"],
+            "x": [1, 3, 8, 8, 2],
+            "edges": [0.0, 2.0, 5.0, 6.0, 7.0],
+        }
+    )
+    result = df.group_by("g", maintain_order=True).agg(
+        pl.col("x").hist(bins=[0, 2]).alias("lit"),
+        pl.col("x").hist(bins=pl.col("edges")).alias("col"),
+        pl.col("x")
+        .hist(bins=pl.col("edges").implode(), include_breakpoint=True)
+        .alias("list"),
+    )
+    assert result["lit"].to_list() == [[0, 1, 1], [0, 1, 2]]
+    assert result["col"].to_list() == [[0, 1, 1], [1, 0, 0, 2]]
+    assert result["list"].to_list() == [
+        [
+            {"breakpoint": 0.0, "count": 0},
+            {"breakpoint": 2.0, "count": 1},
+            {"breakpoint": float("inf"), "count": 1},
+        ],
+        [
+            {"breakpoint": 5.0, "count": 1},
+            {"breakpoint": 6.0, "count": 0},
+            {"breakpoint": 7.0, "count": 0},
+            {"breakpoint": float("inf"), "count": 2},
+        ],
+    ]
+
+    with pytest.raises(pl.ShapeError, match="single list"):
+        df.select(pl.col("x").hist(bins=pl.Series([[1.0], [2.0]])))
+
+
 @pytest.mark.parametrize("values", [[], [None]])
 def test_hist_empty_or_all_null(values: list[None]) -> None:
     ser = pl.Series(values, dtype=pl.Float64)
@@ -165,12 +165,13 @@ def test_cumulative_eval_window_functions() -> None:
         }
     )
 
-    result = df.with_columns(
-        pl.col("val")
-        .cumulative_eval(pl.element().max())
-        .over("group")
-        .alias("cumulative_eval_max")
-    )
+    with pytest.deprecated_call():
+        result = df.with_columns(
+            pl.col("val")
+            .cumulative_eval(pl.element().max())
+            .over("group")
+            .alias("cumulative_eval_max")
+        )
     expected = pl.DataFrame(
         {
             "group": [0, 0, 0, 1, 1, 1],
@@ -182,9 +183,10 @@ def test_cumulative_eval_window_functions() -> None:
 
     # 6394
     df = pl.DataFrame({"group": [1, 1, 2, 3], "value": [1, None, 3, None]})
-    result = df.select(
-        pl.col("value").cumulative_eval(pl.element().mean()).over("group")
-    )
+    with pytest.deprecated_call():
+        result = df.select(
+            pl.col("value").cumulative_eval(pl.element().mean()).over("group")
+        )
     expected = pl.DataFrame({"value": [1.0, 1.0, 3.0, None]})
     assert_frame_equal(result, expected)
 
@@ -494,3 +496,29 @@ def test_window_order_by_8662() -> None:
         "x_lag0": [None, 10, 20, 30, None, 10, 20, 30],
         "x_lag1": [None, 10, 20, 30, 40, None, 20, 30],
     }
+
+
+def test_window_frame() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "b", "a", "b", "a", "a"],
+            "t": [3, 1, 1, 2, 2, 4],
+            "x": [1, 2, 3, 4, 5, 6],
+        }
+    )
+    out = df.select(
+        moving=pl.col("x").sum().over("g", order_by="t", frame=(1, 1)),
+        cumulative=pl.col("x").sum().over("g", order_by="t", frame=(None, 0)),
+        total=pl.col("x").sum().over([], order_by="t", frame=(None, None)),
+    )
+    expected = pl.DataFrame(
+        {
+            "moving": [12, 6, 8, 6, 9, 7],
+            "cumulative": [9, 2, 3, 6, 8, 15],
+            "total": [21] * 6,
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    with pytest.raises(ValueError, match="requires `order_by`"):
+        pl.col("x").sum().over("g", frame=(1, 1))
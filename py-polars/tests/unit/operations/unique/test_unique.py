@@ -154,3 +154,44 @@ def test_unique_with_null() -> None:
         {"a": [1, 2, 3, 4], "b": ["a", "b", "c", "c"], "c": [None, None, None, None]}
     )
     assert_frame_equal(df.unique(maintain_order=True), expected_df)
+
+
+def test_unique_order_by() -> None:
+    df = pl.DataFrame(
+        {
+            "key": ["a", "b", "a", "b", "a"],
+            "ts": [3, 1, None, 4, 3],
+            "val": [10, 20, 30, 40, 50],
+        }
+    )
+
+    result = df.unique("key", keep="last", order_by="ts", maintain_order=True)
+    expected = pl.DataFrame({"key": ["a", "b"], "ts": [3, 4], "val": [50, 40]})
+    assert_frame_equal(result, expected)
+
+    result = df.unique("key", keep="first", order_by="ts", maintain_order=True)
+    expected = pl.DataFrame({"key": ["a", "b"], "ts": [None, 1], "val": [30, 20]})
+    assert_frame_equal(result, expected)
+
+    result = df.unique("key", keep="first", order_by=["ts", "val"]).sort("key")
+    expected = pl.DataFrame({"key": ["a", "b"], "ts": [None, 1], "val": [30, 20]})
+    assert_frame_equal(result, expected)
+
+    # Projection pushdown must keep the order_by column that is not selected.
+    result = (
+        df.lazy()
+        .unique("key", keep="last", order_by="ts", maintain_order=True)
+        .select("val")
+        .collect()
+    )
+    assert result["val"].to_list() == [50, 40]
+
+
+def test_unique_order_by_keep_none_any() -> None:
+    df = pl.DataFrame({"key": [1, 1, 2], "ts": [2, 1, 3]})
+
+    result = df.unique("key", keep="none", order_by="ts")
+    assert_frame_equal(result, pl.DataFrame({"key": [2], "ts": [3]}))
+
+    result = df.unique("key", keep="any", order_by="ts", maintain_order=True)
+    assert result["key"].to_list() == [1, 2]
@@ -1524,6 +1524,26 @@ def test_peak_max_peak_min() -> None:
     assert_series_equal(result, expected)
 
 
+def test_find_peaks() -> None:
+    s = pl.Series("a", [1, 3, 3, 1, None, 2, 1, 4, 4, 4, 0])
+    result = s.find_peaks()
+    expected = pl.Series(
+        "a", [False, True, False, False, None, False, False, False, True, False, False]
+    )
+    assert_series_equal(result, expected)
+
+    s = pl.Series("a", [0.0, 3.0, 1.0, 2.0, 1.0, 5.0, 5.0, 0.0])
+    assert s.find_peaks().arg_true().to_list() == [1, 3, 5]
+    assert s.find_peaks(distance=1).arg_true().to_list() == [1, 3, 5]
+    assert s.find_peaks(distance=3).arg_true().to_list() == [1, 5]
+    assert s.find_peaks(distance=5).arg_true().to_list() == [5]
+    assert s.find_peaks(prominence=2).arg_true().to_list() == [1, 5]
+    assert s.find_peaks(prominence=3).arg_true().to_list() == [5]
+
+    with pytest.raises(pl.InvalidOperationError, match="distance"):
+        s.find_peaks(distance=0)
+
+
 def test_shrink_to_fit() -> None:
     s = pl.Series("a", [4, 1, 3, 2, 5])
     sf = s.shrink_to_fit(in_place=True)
@@ -1787,13 +1807,16 @@ def test_cumulative_eval() -> None:
 
     expected1 = pl.Series("values", [1, 1, 1, 1, 1])
     expected2 = pl.Series("values", [1, 4, 9, 16, 25])
-    assert_series_equal(s.cumulative_eval(expr1), expected1)
-    assert_series_equal(s.cumulative_eval(expr2), expected2)
+    with pytest.deprecated_call():
+        assert_series_equal(s.cumulative_eval(expr1), expected1)
+    with pytest.deprecated_call():
+        assert_series_equal(s.cumulative_eval(expr2), expected2)
 
     # evaluate combined expressions and validate
     expr3 = expr1 - expr2
     expected3 = pl.Series("values", [0, -3, -8, -15, -24])
-    assert_series_equal(s.cumulative_eval(expr3), expected3)
+    with pytest.deprecated_call():
+        assert_series_equal(s.cumulative_eval(expr3), expected3)
 
 
 def test_reverse() -> None:
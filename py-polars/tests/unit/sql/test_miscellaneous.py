@@ -269,3 +269,55 @@ def test_read_csv(tmp_path: Path) -> None:
         match="`read_csv` expects a single file path; found 3 arguments",
     ):
         pl.sql("SELECT * FROM read_csv('a','b','c')")
+
+
+@pytest.mark.write_disk()
+def test_read_csv_options(tmp_path: Path) -> None:
+    csv_target = tmp_path / "test_sql_read_options.csv"
+    csv_target.write_text("x;y\n1;a\n2;NA\n3;c\n")
+
+    res = pl.sql(
+        f"""
+        SELECT * FROM read_csv(
+          '{csv_target}', separator => ';', null_value => 'NA', skip_rows => 1,
+          has_header => false
+        )
+        """
+    ).collect()
+    expected = pl.DataFrame({"column_1": [1, 2, 3], "column_2": ["a", None, "c"]})
+    assert_frame_equal(res, expected)
+
+    with pytest.raises(
+        SQLInterfaceError,
+        match="`read_csv` does not support the `sep` option",
+    ):
+        pl.sql(f"SELECT * FROM read_csv('{csv_target}', sep => ';')")
+
+    with pytest.raises(
+        SQLSyntaxError,
+        match="option `has_header` expects a boolean",
+    ):
+        pl.sql(f"SELECT * FROM read_csv('{csv_target}', has_header => 'no')")
+
+
+@pytest.mark.write_disk()
+def test_read_parquet_hive_partitioning(tmp_path: Path) -> None:
+    for part in (1, 2):
+        (tmp_path / f"part={part}").mkdir()
+        pl.DataFrame({"x": [part * 10, part * 10 + 1]}).write_parquet(
+            tmp_path / f"part={part}" / "data.parquet"
+        )
+    source = tmp_path / "**" / "*.parquet"
+
+    res = pl.sql(
+        f"""
+        SELECT * FROM read_parquet('{source}', hive_partitioning => true)
+        ORDER BY x
+        """
+    ).collect()
+    assert res.to_dict(as_series=False) == {"x": [10, 11, 20, 21], "part": [1, 1, 2, 2]}
+
+    res = pl.sql(
+        f"SELECT * FROM read_parquet('{source}', hive_partitioning => false)"
+    ).collect()
+    assert res.columns == ["x"]
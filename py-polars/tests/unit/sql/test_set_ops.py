@@ -39,18 +39,22 @@ def test_except_intersect() -> None:
         assert_frame_equal(pl.DataFrame({"x": [2], "y": [2]}), res)
 
 
+def test_except_intersect_all() -> None:
+    df1 = pl.DataFrame({"x": [1, 1, 1, 2, None, None], "y": ["a"] * 6})  # noqa: F841
+    df2 = pl.DataFrame({"x": [1, 2, 2, None], "y": ["a"] * 4})  # noqa: F841
+
+    res_e = pl.sql("SELECT * FROM df1 EXCEPT ALL SELECT * FROM df2", eager=True)
+    res_i = pl.sql("SELECT * FROM df1 INTERSECT ALL SELECT * FROM df2", eager=True)
+
+    assert res_e.rows() == [(1, "a"), (1, "a"), (None, "a")]
+    assert res_i.rows() == [(1, "a"), (2, "a"), (None, "a")]
+
+
 @pytest.mark.parametrize("op", ["EXCEPT", "INTERSECT", "UNION"])
 def test_except_intersect_errors(op: str) -> None:
     df1 = pl.DataFrame({"x": [1, 9, 1, 1], "y": [2, 3, 4, 4], "z": [5, 5, 5, 5]})  # noqa: F841
     df2 = pl.DataFrame({"x": [1, 9, 1], "y": [2, None, 4], "z": [7, 6, 5]})  # noqa: F841
 
-    if op != "UNION":
-        with pytest.raises(
-            SQLInterfaceError,
-            match=f"'{op} ALL' is not supported",
-        ):
-            pl.sql(f"SELECT * FROM df1 {op} ALL SELECT * FROM df2", eager=False)
-
     with pytest.raises(
         SQLInterfaceError,
         match=f"{op} requires equal number of columns in each table",
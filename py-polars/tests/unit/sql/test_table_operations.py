@@ -81,3 +81,36 @@ def test_truncate_table(truncate_sql: str, test_frame: pl.LazyFrame) -> None:
 
         res = ctx.execute("SELECT * FROM frame")
         assert_frame_equal(res, expected)
+
+
+def test_insert_into(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        res = ctx.execute_many(
+            """
+            CREATE TABLE tbl AS SELECT x, y FROM frame WHERE x > 1;
+            INSERT INTO tbl VALUES (10, 'ddd');
+            INSERT INTO tbl (y) SELECT y FROM frame WHERE x = 1;
+            SELECT * FROM tbl;
+            """
+        )
+        assert_frame_equal(
+            res,
+            pl.DataFrame(
+                {
+                    "x": [2, 3, 10, None],
+                    "y": ["bbb", "ccc", "ddd", "aaa"],
+                },
+                schema_overrides={"x": pl.UInt8},
+            ),
+        )
+
+        with pytest.raises(SQLInterfaceError, match="expected 2 columns, got 1"):
+            ctx.execute("INSERT INTO tbl VALUES (1)")
+        with pytest.raises(pl.SchemaError, match="cannot insert str values"):
+            ctx.execute("INSERT INTO tbl (x) SELECT y FROM frame")
+        with pytest.raises(pl.SchemaError, match="cannot insert f64 values"):
+            ctx.execute("INSERT INTO tbl VALUES (1.5, 'eee')")
+        with pytest.raises(SQLInterfaceError, match="specified more than once"):
+            ctx.execute("INSERT INTO tbl (y, y) VALUES ('eee', 'fff')")
+        with pytest.raises(SQLInterfaceError, match="'missing' does not exist"):
+            ctx.execute("INSERT INTO missing SELECT * FROM frame")
@@ -0,0 +1,133 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import SQLInterfaceError, SQLSyntaxError
+from polars.testing import assert_frame_equal
+
+
+@pytest.fixture()
+def df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "grp": ["a", "b", "a", "b", "a"],
+            "t": [5, 1, 3, 2, 4],
+            "val": [10, 20, 30, 40, 50],
+        }
+    )
+
+
+def test_window_row_number(df: pl.DataFrame) -> None:
+    res = df.sql(
+        """
+        SELECT
+          ROW_NUMBER() OVER (PARTITION BY grp ORDER BY t) AS n,
+          ROW_NUMBER() OVER (ORDER BY val DESC) AS n_all
+        FROM self
+        """
+    )
+    expected = pl.DataFrame({"n": [3, 1, 1, 2, 2], "n_all": [5, 4, 3, 2, 1]})
+    assert_frame_equal(res, expected)
+
+
+def test_window_lag_lead(df: pl.DataFrame) -> None:
+    res = df.sql(
+        """
+        SELECT
+          LAG(val) OVER (PARTITION BY grp ORDER BY t) AS prev,
+          LEAD(val, 1, 0) OVER (PARTITION BY grp ORDER BY t) AS next,
+          LAG(val, 2) OVER (ORDER BY t DESC) AS prev2
+        FROM self
+        """
+    )
+    expected = pl.DataFrame(
+        {
+            "prev": [50, None, None, 20, 30],
+            "next": [0, 40, 50, 0, 10],
+            "prev2": [None, 30, 10, 50, None],
+        }
+    )
+    assert_frame_equal(res, expected)
+
+
+def test_window_running_aggregates(df: pl.DataFrame) -> None:
+    res = df.sql(
+        """
+        SELECT
+          SUM(val) OVER (PARTITION BY grp ORDER BY t) AS sum,
+          MIN(val) OVER (PARTITION BY grp ORDER BY t) AS min,
+          AVG(val) OVER (ORDER BY t) AS avg,
+          SUM(val) OVER (PARTITION BY grp) AS total
+        FROM self
+        """
+    )
+    expected = pl.DataFrame(
+        {
+            "sum": [90, 20, 30, 60, 80],
+            "min": [10, 20, 30, 20, 30],
+            "avg": [30.0, 20.0, 30.0, 30.0, 35.0],
+            "total": [90, 60, 90, 60, 90],
+        }
+    )
+    assert_frame_equal(res, expected)
+
+
+def test_window_rows_frame(df: pl.DataFrame) -> None:
+    res = df.sql(
+        """
+        SELECT
+          SUM(val) OVER (
+            PARTITION BY grp ORDER BY t ROWS BETWEEN 1 PRECEDING AND CURRENT ROW
+          ) AS sum,
+          AVG(val) OVER (ORDER BY t ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS avg,
+          MAX(val) OVER (
+            PARTITION BY grp ORDER BY t DESC
+            ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING
+          ) AS max
+        FROM self
+        """
+    )
+    expected = pl.DataFrame(
+        {
+            "sum": [60, 20, 30, 60, 80],
+            "avg": [30.0, 30.0, 40.0, 30.0, 30.0],
+            "max": [50, 20, 30, 40, 50],
+        }
+    )
+    assert_frame_equal(res, expected)
+
+    with pytest.raises(SQLInterfaceError, match="don't contain the current row"):
+        df.sql(
+            """
+            SELECT SUM(val) OVER (
+              ORDER BY t ROWS BETWEEN 1 FOLLOWING AND 2 FOLLOWING
+            ) FROM self
+            """
+        )
+
+    # functions that don't evaluate over the frame must not ignore it
+    for func in ("COUNT(val)", "FIRST(val)", "LAST(val)", "ROW_NUMBER()"):
+        with pytest.raises(SQLInterfaceError, match="window frames are not supported"):
+            df.sql(
+                f"""
+                SELECT {func} OVER (
+                  ORDER BY t ROWS BETWEEN 1 PRECEDING AND CURRENT ROW
+                ) FROM self
+                """
+            )
+    with pytest.raises(SQLInterfaceError, match="only ROWS window frames"):
+        df.sql(
+            """
+            SELECT SUM(val) OVER (
+              ORDER BY t RANGE BETWEEN 1 PRECEDING AND CURRENT ROW
+            ) FROM self
+            """
+        )
+
+
+def test_window_function_errors(df: pl.DataFrame) -> None:
+    with pytest.raises(SQLSyntaxError, match="ROW_NUMBER does not take arguments"):
+        df.sql("SELECT ROW_NUMBER(val) OVER (ORDER BY t) FROM self")
+    with pytest.raises(SQLSyntaxError, match="invalid number of arguments for LAG"):
+        df.sql("SELECT LAG() OVER (ORDER BY t) FROM self")
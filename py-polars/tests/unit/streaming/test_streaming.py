@@ -361,3 +361,32 @@ def test_streaming_with_hconcat(tmp_path: Path) -> None:
     )
 
     assert_frame_equal(result, expected)
+
+
+@pytest.mark.write_disk()
+def test_streaming_is_duplicated_is_unique(tmp_path: Path) -> None:
+    a = [*range(3000), *range(1000), None, None]
+    df = pl.DataFrame({"a": a, "b": ["x", "y"] * (len(a) // 2)})
+    path = tmp_path / "in.parquet"
+    df.write_parquet(path, row_group_size=500)
+
+    q = pl.scan_parquet(path).with_columns(
+        is_duplicated=pl.col("a").is_duplicated(),
+        is_unique=(pl.col("a") * 2).is_unique(),
+    )
+    assert q.explain(streaming=True).startswith("STREAMING")
+    expected = df.with_columns(
+        is_duplicated=pl.col("a").is_duplicated(),
+        is_unique=pl.col("a").is_unique(),
+    )
+    assert_frame_equal(q.collect(streaming=True), expected)
+
+    out_path = tmp_path / "out.parquet"
+    q.sink_parquet(out_path)
+    assert_frame_equal(pl.read_parquet(out_path), expected)
+
+    # Within a window the functions apply per group, which isn't rewritten.
+    q = pl.scan_parquet(path).select(pl.col("a").is_unique().over("b"))
+    assert_frame_equal(
+        q.collect(streaming=True), df.select(pl.col("a").is_unique().over("b"))
+    )
@@ -517,3 +517,33 @@ def test_streaming_group_by_boolean_mean_15610(
     )
 
     assert_frame_equal(out, expect)
+
+
+def test_streaming_group_by_first_last_non_null() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 2, 3] * 10,
+            "a": [None, 2, 3, 4, None, None] * 10,
+            "b": ["x", None, None, None, "y", None] * 10,
+        }
+    )
+    q = (
+        df.lazy()
+        .group_by("g")
+        .agg(
+            pl.col("a").first_non_null().alias("a_first"),
+            pl.col("a").last_non_null().alias("a_last"),
+            pl.col("b").first_non_null().alias("b_first"),
+            pl.col("b").last_non_null().alias("b_last"),
+        )
+        .sort("g")
+    )
+    assert "STREAMING" in q.explain(streaming=True)
+    assert_frame_equal(q.collect(streaming=True), q.collect())
+    assert q.collect(streaming=True).to_dict(as_series=False) == {
+        "g": [1, 2, 3],
+        "a_first": [2, 4, None],
+        "a_last": [3, 4, None],
+        "b_first": ["x", "y", None],
+        "b_last": ["x", "y", None],
+    }
@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+from datetime import datetime
+from decimal import Decimal
 from typing import TYPE_CHECKING, Any
 from unittest.mock import patch
 
@@ -106,6 +108,31 @@ def test_sink_ipc(io_files_path: Path, tmp_path: Path) -> None:
         assert_frame_equal(result, df_read)
 
 
+@pytest.mark.write_disk()
+def test_sink_partitioned(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    lf = pl.LazyFrame({"a": range(100), "b": [i % 3 for i in range(100)]})
+
+    lf.sink_parquet(tmp_path / "parquet", partition_by="b", checkpoint=True)
+    assert not (tmp_path / "parquet" / "_polars_checkpoint").exists()
+    result = pl.scan_parquet(tmp_path / "parquet" / "**/*.parquet").collect()
+    assert_frame_equal(result.sort("a"), lf.collect(), check_column_order=False)
+
+    lf.sink_ipc(tmp_path / "ipc", partition_by=["b"])
+    assert sorted(p.name for p in (tmp_path / "ipc").iterdir()) == [
+        "b=0",
+        "b=1",
+        "b=2",
+    ]
+    (file,) = (tmp_path / "ipc" / "b=1").iterdir()
+    result = pl.read_ipc(file)
+    assert result.columns == ["a"]
+    assert result["a"].to_list() == list(range(1, 100, 3))
+
+    with pytest.raises(ValueError, match="`partition_by`"):
+        lf.sink_parquet(tmp_path / "out.parquet", checkpoint=True)
+
+
 @pytest.mark.write_disk()
 def test_sink_csv(io_files_path: Path, tmp_path: Path) -> None:
     source_file = io_files_path / "small.parquet"
@@ -232,6 +259,29 @@ def test_sink_ndjson_should_write_same_data(
     assert_frame_equal(df, expected)
 
 
+@pytest.mark.write_disk()
+def test_sink_ndjson_serialize_options(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    target_path = tmp_path / "options.ndjson"
+
+    lf = pl.LazyFrame(
+        {
+            "dt": [datetime(2024, 1, 1, 12, 30), None],
+            "dec": [Decimal("1.50"), Decimal("2.00")],
+        },
+        schema={"dt": pl.Datetime("ms"), "dec": pl.Decimal(10, 2)},
+    )
+    lf.sink_ndjson(
+        target_path,
+        temporal_format="iso8601",
+        decimal_as_string=True,
+        omit_nulls=True,
+    )
+
+    expected = '{"dt":"2024-01-01T12:30:00","dec":"1.50"}\n{"dec":"2.00"}\n'
+    assert target_path.read_text() == expected
+
+
 @pytest.mark.write_disk()
 def test_parquet_eq_statistics(monkeypatch: Any, capfd: Any, tmp_path: Path) -> None:
     tmp_path.mkdir(exist_ok=True)
@@ -280,3 +330,69 @@ def test_streaming_empty_parquet_16523(tmp_path: Path) -> None:
     q = pl.scan_parquet(file_path)
     q2 = pl.LazyFrame({"a": [1]}, schema={"a": pl.Int32})
     assert q.join(q2, on="a").collect(streaming=True).shape == (0, 1)
+
+
+def test_sink_batches() -> None:
+    lf = pl.LazyFrame({"a": range(1000)}).filter(pl.col("a") > 10)
+
+    batches: list[pl.DataFrame] = []
+    lf.sink_batches(batches.append, chunk_size=100)
+    assert [df.height for df in batches] == [100] * 9 + [89]
+    assert_frame_equal(pl.concat(batches), lf.collect())
+
+    batches.clear()
+    lf.sink_batches(batches.append)
+    assert_frame_equal(pl.concat(batches), lf.collect())
+
+
+def test_sink_batches_raises() -> None:
+    def fail(df: pl.DataFrame) -> None:
+        msg = "queue is full"
+        raise RuntimeError(msg)
+
+    lf = pl.LazyFrame({"a": range(10)})
+    with pytest.raises(pl.ComputeError, match="queue is full"):
+        lf.sink_batches(fail)
+    with pytest.raises(ValueError, match="must be positive"):
+        lf.sink_batches(print, chunk_size=0)
+
+
+def test_streaming_merge_sorted_scans(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    dfs = [
+        pl.DataFrame({"t": list(range(i, 3000, 3)), "file": i}) for i in range(3)
+    ]
+    for i, df in enumerate(dfs):
+        df.write_parquet(tmp_path / f"{i}.parquet", row_group_size=100)
+
+    q = pl.concat(
+        [pl.scan_parquet(tmp_path / f"{i}.parquet") for i in range(3)],
+        how="merge_sorted_by",
+        by="t",
+    )
+    assert "MERGE SORTED BY: t" in q.explain()
+    out = q.collect(streaming=True)
+    assert out["t"].to_list() == list(range(3000))
+    assert out["file"].to_list() == [t % 3 for t in range(3000)]
+    assert_frame_equal(out, q.collect())
+
+
+@pytest.mark.write_disk()
+def test_sink_multiple(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    lf = pl.LazyFrame({"a": range(1000), "b": ["x", "y"] * 500}).filter(
+        pl.col("a") > 10
+    )
+    expected = lf.collect()
+
+    paths = [tmp_path / f"out.{ext}" for ext in ("parquet", "arrow", "csv", "ndjson")]
+    lf.sink_multiple(paths)
+    assert_frame_equal(pl.read_parquet(paths[0]), expected)
+    assert_frame_equal(pl.read_ipc(paths[1]), expected)
+    assert_frame_equal(pl.read_csv(paths[2]), expected)
+    assert_frame_equal(pl.read_ndjson(paths[3]), expected)
+
+    with pytest.raises(ValueError, match="file format"):
+        lf.sink_multiple([tmp_path / "out.xlsx"])
+    with pytest.raises(ValueError, match="at least one path"):
+        lf.sink_multiple([])
@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import asyncio
 import sys
+import threading
 import time
 from functools import partial
 from typing import Any, Callable
@@ -95,6 +96,62 @@ def test_collect_async_task(
     _aio_run(main(), raises)
 
 
+def test_collect_async_cancel() -> None:
+    calls = []
+    started = threading.Event()
+
+    def slow(s: pl.Series) -> pl.Series:
+        calls.append(None)
+        started.set()
+        time.sleep(0.2)
+        return s
+
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+    for _ in range(5):
+        lf = lf.with_columns(pl.col("a").map_batches(slow))
+
+    async def main() -> None:
+        task = asyncio.ensure_future(lf.collect_async())
+        await asyncio.get_running_loop().run_in_executor(None, started.wait)
+        task.cancel()
+        with pytest.raises(asyncio.CancelledError):
+            await task
+        # give the query the time to run to completion if it wasn't stopped
+        await asyncio.sleep(1.5)
+
+    asyncio.run(main())
+    assert len(calls) < 5
+
+
+def test_collect_async_cancel_streaming() -> None:
+    calls = []
+    started = threading.Event()
+
+    def slow(s: pl.Series) -> pl.Series:
+        calls.append(None)
+        started.set()
+        time.sleep(0.05)
+        return s
+
+    lf = pl.LazyFrame({"a": range(1_000)}).with_columns(
+        pl.col("a").map_batches(slow, is_elementwise=True)
+    )
+
+    async def main() -> None:
+        task = asyncio.ensure_future(lf.collect_async(streaming=True))
+        await asyncio.get_running_loop().run_in_executor(None, started.wait)
+        task.cancel()
+        with pytest.raises(asyncio.CancelledError):
+            await task
+        # give the query the time to run to completion if it wasn't stopped
+        await asyncio.sleep(2)
+
+    with pl.Config(streaming_chunk_size=10):
+        asyncio.run(main())
+    # every batch of the streaming engine calls the function once
+    assert len(calls) < 100
+
+
 def _gevent_collect_async(raises: bool = False) -> Any:
     lf = (
         pl.LazyFrame(
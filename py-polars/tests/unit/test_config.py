@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import os
+from datetime import datetime, timedelta, timezone
 from pathlib import Path
 from typing import Any, Iterator
 
@@ -10,6 +11,8 @@ import polars as pl
 import polars.polars as plr
 from polars._utils.unstable import issue_unstable_warning
 from polars.config import _POLARS_CFG_ENV_VARS
+from polars.dependencies import zoneinfo
+from polars.testing import assert_series_equal
 
 
 @pytest.fixture(autouse=True)
@@ -742,6 +745,72 @@ def test_set_streaming_chunk_size() -> None:
         cfg.set_streaming_chunk_size(0)
 
 
+def test_set_streaming_memory_limit() -> None:
+    with pl.Config() as cfg:
+        cfg.set_streaming_memory_limit(1_000_000)
+        assert os.environ.get("POLARS_STREAMING_MEMORY_LIMIT") == "1000000"
+    assert "POLARS_STREAMING_MEMORY_LIMIT" not in os.environ
+
+    with pytest.raises(ValueError), pl.Config() as cfg:
+        cfg.set_streaming_memory_limit(-1)
+
+
+def test_set_tz_fallback(monkeypatch: pytest.MonkeyPatch) -> None:
+    import polars._utils.convert
+
+    def missing_tzdata(key: str) -> Any:
+        raise zoneinfo.ZoneInfoNotFoundError(key)
+
+    monkeypatch.setattr(polars._utils.convert, "string_to_zoneinfo", missing_tzdata)
+    s = pl.Series([datetime(2020, 1, 1), datetime(2020, 7, 1)]).dt.replace_time_zone(
+        "Europe/Amsterdam"
+    )
+    with pytest.raises(ValueError, match="unexpected time zone offset"):
+        s.to_list()
+
+    with pl.Config(tz_fallback="offset"):
+        assert s.to_list() == [
+            datetime(2020, 1, 1, tzinfo=timezone(timedelta(hours=1))),
+            datetime(2020, 7, 1, tzinfo=timezone(timedelta(hours=2))),
+        ]
+        assert s.to_list()[1].utcoffset() == timedelta(hours=2)
+
+    with pl.Config(tz_fallback="bundled"):
+        values = s.to_list()
+        assert [str(v.tzinfo) for v in values] == ["Europe/Amsterdam"] * 2
+        assert [v.utcoffset() for v in values] == [
+            timedelta(hours=1),
+            timedelta(hours=2),
+        ]
+        # the time zone follows the daylight saving time transitions
+        assert (values[0] + timedelta(days=182)).utcoffset() == timedelta(hours=2)
+
+        # the second occurrence of an ambiguous wall time is marked with `fold`
+        utc = pl.Series([datetime(2020, 10, 25, 0, 30), datetime(2020, 10, 25, 1, 30)])
+        values = utc.dt.replace_time_zone("UTC").dt.convert_time_zone(
+            "Europe/Amsterdam"
+        )
+        assert [(v.hour, v.fold) for v in values.to_list()] == [(2, 0), (2, 1)]
+        assert [v.dst() for v in values.to_list()] == [timedelta(hours=1), timedelta(0)]
+
+        # the datetimes can be passed back to polars
+        values = values.to_list()
+        assert_series_equal(pl.Series(values), utc.dt.replace_time_zone("UTC"))
+        assert pl.select(pl.lit(values[1])).item() == values[1]
+        assert pl.select(pl.lit(values[1])).dtypes == [
+            pl.Datetime("us", "Europe/Amsterdam")
+        ]
+
+        # parsing doesn't need the time zone data of the system
+        parsed = pl.Series(["2020-10-25 02:30"]).str.to_datetime(
+            time_zone="Europe/Amsterdam", ambiguous="latest"
+        )
+        assert parsed.to_list() == [values[1]]
+
+    with pytest.raises(ValueError, match="invalid time zone fallback mode"):
+        pl.Config.set_tz_fallback("utc")  # type: ignore[arg-type]
+
+
 def test_set_fmt_str_lengths_invalid_length() -> None:
     with pl.Config() as cfg:
         with pytest.raises(ValueError):
@@ -824,6 +893,7 @@ def test_warn_unstable(recwarn: pytest.WarningsRecorder) -> None:
         ),
         ("POLARS_STREAMING_CHUNK_SIZE", "set_streaming_chunk_size", 100, "100"),
         ("POLARS_TABLE_WIDTH", "set_tbl_width_chars", 80, "80"),
+        ("POLARS_TZ_FALLBACK", "set_tz_fallback", "offset", "offset"),
         ("POLARS_VERBOSE", "set_verbose", True, "1"),
         ("POLARS_WARN_UNSTABLE", "warn_unstable", True, "1"),
     ],
@@ -1,9 +1,13 @@
 import pytest
 
+import polars as pl
+
 from polars.exceptions import (
     CategoricalRemappingWarning,
+    ColumnNotFoundError,
     ComputeError,
     CustomUFuncWarning,
+    InvalidOperationError,
     MapWithoutReturnDtypeWarning,
     OutOfBoundsError,
     PerformanceWarning,
@@ -32,3 +36,24 @@ def test_performance_warning_base_class() -> None:
     assert isinstance(PolarsInefficientMapWarning(msg), PerformanceWarning)
     with pytest.raises(PerformanceWarning, match=msg):
         raise CategoricalRemappingWarning(msg)
+
+
+def test_polars_error_code_and_context() -> None:
+    s = pl.Series("a", ["1", "x"])
+    with pytest.raises(InvalidOperationError) as exc_info:
+        s.cast(pl.Int64)
+
+    err = exc_info.value
+    assert err.code == "invalid_operation"  # type: ignore[attr-defined]
+    assert err.context == []  # type: ignore[attr-defined]
+    assert "in column 'a'" in str(err)
+
+    with pytest.raises(ColumnNotFoundError) as exc_info:
+        pl.LazyFrame({"a": [1]}).select("b").collect()
+    assert exc_info.value.code == "column_not_found"  # type: ignore[attr-defined]
+    assert exc_info.value.context == [("operator", "select")]  # type: ignore[attr-defined]
+
+    with pytest.raises(ComputeError) as exc_info:
+        pl.DataFrame({"x": [0, 1]}).group_by([]).agg(pl.len())
+    assert exc_info.value.code == "compute"  # type: ignore[attr-defined]
+    assert exc_info.value.context == []  # type: ignore[attr-defined]
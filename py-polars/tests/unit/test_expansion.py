@@ -183,3 +183,34 @@ def test_struct_field_exclude_and_wildcard_expansion() -> None:
         "i": [3],
         "j": [4],
     }
+
+
+def test_struct_field_expand_regex_and_exclude() -> None:
+    df = pl.DataFrame(
+        {
+            "s": [{"id": 1, "sensor_a": 0.5, "sensor_b": 1.5, "status": "ok"}],
+            "t": [{"sensor_c": 2.5, "other": 3}],
+        }
+    )
+
+    assert df.select(pl.col("s").struct.field("^sensor_.*$")).columns == [
+        "sensor_a",
+        "sensor_b",
+    ]
+    assert df.select(pl.col("s").struct.exclude("^sensor_.*$")).columns == [
+        "id",
+        "status",
+    ]
+    assert df.select(pl.col("s").struct.exclude("id", "status")).columns == [
+        "sensor_a",
+        "sensor_b",
+    ]
+    assert df.select(pl.col("s", "t").struct.exclude(["id", "other"])).columns == [
+        "sensor_a",
+        "sensor_b",
+        "status",
+        "sensor_c",
+    ]
+    out = df.select(pl.col("s").struct.exclude("id").name.suffix("_x"))
+    assert out.columns == ["sensor_a_x", "sensor_b_x", "status_x"]
+    assert df.select(pl.col("t").struct.exclude("^.*$")).columns == []
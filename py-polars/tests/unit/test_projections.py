@@ -511,3 +511,43 @@ def test_projection_pushdown_semi_anti_no_selection(
     assert "PROJECT 1/2" in (
         q_a.join(q_b, left_on="a", right_on="b", how=how).explain()
     )
+
+
+def test_projection_pushdown_map_batches_required_columns() -> None:
+    lf = pl.LazyFrame({"a": [1, 2], "b": [3, 4], "c": [5, 6]})
+    seen: list[list[str]] = []
+
+    def total(df: pl.DataFrame) -> pl.DataFrame:
+        seen.append(df.columns)
+        a, b = pl.col("a"), pl.col("b")
+        return df.select(total=a + b, diff=a - b)
+
+    out = (
+        lf.map_batches(
+            total,
+            schema={"total": pl.Int64, "diff": pl.Int64},
+            required_columns=["a", "b"],
+            projection_pushdown=False,
+        )
+        .select("total")
+        .collect()
+    )
+    assert_frame_equal(out, pl.DataFrame({"total": [4, 6]}))
+    assert seen == [["a", "b"]]
+
+    # Without a schema, the columns that are selected afterwards are passed through.
+    seen.clear()
+
+    def double(df: pl.DataFrame) -> pl.DataFrame:
+        seen.append(df.columns)
+        return df * 2
+
+    out = lf.map_batches(double, required_columns=["a"]).select("b").collect()
+    assert_frame_equal(out, pl.DataFrame({"b": [6, 8]}))
+    assert sorted(seen[0]) == ["a", "b"]
+
+    out = lf.map_batches(double, required_columns=["a"]).collect()
+    assert out.columns == ["a", "b", "c"]
+
+    with pytest.raises(pl.ColumnNotFoundError, match="required column 'x'"):
+        lf.map_batches(double, required_columns=["x"]).select("a").collect()
@@ -220,6 +220,21 @@ def test_iter_rows() -> None:
     ]
 
 
+@pytest.mark.parametrize("buffer_size", [1, 3, 512])
+def test_iter_rows_chunked(buffer_size: int) -> None:
+    df = pl.concat(
+        [
+            pl.DataFrame({"id": [0, 1], "values": ["a", None], "n": [None, None]}),
+            pl.DataFrame({"id": [2, 3], "values": ["c", "d"], "n": [None, None]}),
+        ],
+        rechunk=False,
+    )
+    assert df.n_chunks() == 2
+
+    rows = list(df.iter_rows(buffer_size=buffer_size))
+    assert rows == [(0, "a", None), (1, None, None), (2, "c", None), (3, "d", None)]
+    assert list(df.iter_rows(named=True, buffer_size=buffer_size)) == df.to_dicts()
+
 @pytest.mark.parametrize(
     "primitive",
     [
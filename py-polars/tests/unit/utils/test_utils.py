@@ -171,6 +171,27 @@ def test_estimated_size() -> None:
         s.estimated_size("milkshake")  # type: ignore[arg-type]
 
 
+def test_memory_usage() -> None:
+    df = pl.DataFrame(
+        {
+            "n": list(range(100)),
+            "s": ["x" * 20, "y" * 20, "z" * 20, None] * 25,
+            "l": [[1, 2]] * 100,
+        },
+        schema_overrides={"n": pl.Int32},
+    )
+    result = df.memory_usage()
+    assert result.columns == ["column", "data", "validity", "offsets", "heap", "total"]
+    assert result.row(0) == ("n", 400, 0, 0, 0, 400)
+    assert result.row(1) == ("s", 1600, 13, 0, 1500, 3113)
+    assert result.select("data", "offsets").row(2) == (1600, 808)
+
+    # The rows that are sliced off still hold on to the heap of the strings.
+    sliced = df.select("s").slice(0, 1)
+    assert sliced.memory_usage().row(0) == ("s", 16, 1, 0, 20, 37)
+    assert sliced.memory_usage(deep=True).row(0) == ("s", 16, 1, 0, 1500, 1517)
+
+
 @pytest.mark.parametrize(
     ("v1", "v2"),
     [